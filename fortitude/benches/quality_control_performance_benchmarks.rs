@@ -469,6 +469,7 @@ fn bench_e2e_quality_workflow(c: &mut Criterion) {
                             memory_usage: 1024,
                             cache_hit_ratio: 0.5,
                         },
+                        disagreement: Default::default(),
                     },
                 )
             } else {
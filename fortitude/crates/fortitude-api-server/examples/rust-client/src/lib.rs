@@ -19,13 +19,23 @@
 
 use anyhow::Context;
 use chrono::{DateTime, Utc};
+use futures::future::{BoxFuture, FutureExt, Shared};
+use futures::stream::{Stream, StreamExt, TryStreamExt};
 use reqwest::{Client, ClientBuilder};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
 use std::env;
-use std::sync::Arc;
-use std::time::Duration;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context as TaskContext, Poll};
+use std::time::{Duration, Instant, SystemTime};
 use thiserror::Error;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
@@ -55,6 +65,15 @@ pub enum FortitudeError {
     
     #[error("Rate limit exceeded")]
     RateLimitError,
+
+    #[error("Authentication error: {0}")]
+    AuthError(String),
+
+    #[error("Request {request_id} was cancelled")]
+    Cancelled { request_id: Uuid },
+
+    #[error(transparent)]
+    Shared(Arc<FortitudeError>),
 }
 
 /// API response wrapper
@@ -64,6 +83,117 @@ pub struct ApiResponse<T> {
     pub timestamp: DateTime<Utc>,
     pub success: bool,
     pub data: T,
+    #[serde(default)]
+    pub warnings: Vec<Warning>,
+}
+
+impl<T> ApiResponse<T> {
+    /// Non-fatal conditions reported by the server, e.g. a stale cache hit
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+}
+
+/// Non-fatal condition reported alongside a successful response
+///
+/// Known codes are `classification_degraded`, `context_detection_degraded`,
+/// `stale_cache_served`, `provider_substituted`, `time_budget_applied` and
+/// `content_filtered`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Warning {
+    pub code: String,
+    pub message: String,
+}
+
+/// Callback invoked with the endpoint and each warning of a response
+pub type WarningHook = Arc<dyn Fn(&str, &Warning) + Send + Sync>;
+
+/// Response metadata in the v2 envelope
+#[derive(Debug, Deserialize)]
+struct ResponseMeta {
+    request_id: Uuid,
+    timestamp: DateTime<Utc>,
+    #[serde(default)]
+    warnings: Vec<Warning>,
+}
+
+/// Response envelope as sent on the wire by either API version
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum WireResponse<T> {
+    V2 { data: T, meta: ResponseMeta },
+    V1 {
+        request_id: Uuid,
+        timestamp: DateTime<Utc>,
+        success: bool,
+        data: T,
+        #[serde(default)]
+        warnings: Vec<Warning>,
+    },
+}
+
+impl<T> From<WireResponse<T>> for ApiResponse<T> {
+    fn from(wire: WireResponse<T>) -> Self {
+        match wire {
+            WireResponse::V2 { data, meta } => ApiResponse {
+                request_id: meta.request_id,
+                timestamp: meta.timestamp,
+                success: true,
+                data,
+                warnings: meta.warnings,
+            },
+            WireResponse::V1 { request_id, timestamp, success, data, warnings } => ApiResponse {
+                request_id,
+                timestamp,
+                success,
+                data,
+                warnings,
+            },
+        }
+    }
+}
+
+/// Supported API versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApiVersion {
+    V1,
+    #[default]
+    V2,
+}
+
+impl ApiVersion {
+    /// Path prefix for this version
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            ApiVersion::V1 => "/api/v1",
+            ApiVersion::V2 => "/api/v2",
+        }
+    }
+
+    /// Parse a version label ("v1", "v2", "1", "2")
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().trim_start_matches(['v', 'V']) {
+            "1" => Some(ApiVersion::V1),
+            "2" => Some(ApiVersion::V2),
+            _ => None,
+        }
+    }
+}
+
+/// Supported API versions reported by the server
+#[derive(Debug, Deserialize)]
+pub struct ApiVersionsResponse {
+    pub current: String,
+    pub versions: Vec<ApiVersionInfo>,
+}
+
+/// Lifecycle information for one API version
+#[derive(Debug, Deserialize)]
+pub struct ApiVersionInfo {
+    pub version: String,
+    pub base_path: String,
+    pub status: String,
+    pub sunset: Option<String>,
 }
 
 /// Error response from API
@@ -105,6 +235,31 @@ pub struct ResearchRequest {
     pub audience_context: Option<AudienceContext>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub domain_context: Option<DomainContext>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_timeout_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_profile: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_budget_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_feedback_token: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+/// Cancellation of running research
+#[derive(Debug, Deserialize)]
+pub struct ResearchCancelResponse {
+    pub request_id: Uuid,
+    pub cancelled: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -233,20 +388,302 @@ pub struct CacheTypeStats {
     pub average_quality: f64,
 }
 
+/// Bulk cache invalidation criteria; unset fields do not filter
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CacheInvalidateRequest {
+    /// Specific cache keys to evict
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keys: Option<Vec<String>>,
+    /// Key pattern; `*` and `?` are wildcards, otherwise a substring match
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Comma-separated research types, e.g. `"learning,decision"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub research_type: Option<String>,
+    /// Evict entries carrying any of these tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Evict entries older than this many seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age_seconds: Option<u64>,
+    /// Report what would be evicted without deleting anything
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+}
+
+/// Result of a cache invalidation
+#[derive(Debug, Deserialize)]
+pub struct CacheInvalidateResponse {
+    pub status: String,
+    pub invalidated_count: usize,
+    pub invalidated_keys: Vec<String>,
+    pub bytes_freed: u64,
+    pub dry_run: bool,
+    pub processing_time_ms: u64,
+}
+
+/// Rate limits and quota of the calling API key
+#[derive(Debug, Deserialize)]
+pub struct LimitsResponse {
+    pub key: String,
+    pub tier: String,
+    pub requests: Option<RequestLimitStatus>,
+    pub tokens: TokenQuotaStatus,
+    pub budget: BudgetStatus,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RequestLimitStatus {
+    pub limit: u32,
+    pub remaining: u32,
+    pub window_seconds: u64,
+    pub resets_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TokenQuotaStatus {
+    pub limit: Option<u64>,
+    pub used: u64,
+    pub remaining: Option<u64>,
+    pub resets_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BudgetStatus {
+    pub month: String,
+    pub requests: u64,
+    pub tokens: u64,
+    pub spent_usd: f64,
+    pub limit_usd: Option<f64>,
+    pub remaining_usd: Option<f64>,
+    pub resets_at: DateTime<Utc>,
+}
+
+/// Header naming a research submission so the server can replay it on retry
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Renew bearer tokens this long before they expire
+const TOKEN_EXPIRY_SKEW: Duration = Duration::from_secs(30);
+
+/// How the client authenticates to the server
+#[derive(Clone)]
+pub enum AuthMethod {
+    /// Static key sent as `X-API-Key`
+    ApiKey(String),
+    /// Bearer JWT; with `refresh` set it is renewed before expiry and after a 401
+    Jwt { token: String, refresh: Option<JwtRefresh> },
+    /// Bearer tokens obtained through the OAuth2 client credentials grant
+    OAuth2ClientCredentials(OAuth2ClientCredentials),
+}
+
+/// OAuth2 refresh token grant used to renew a JWT
+#[derive(Clone)]
+pub struct JwtRefresh {
+    pub token_url: String,
+    pub refresh_token: String,
+    pub client_id: Option<String>,
+}
+
+/// OAuth2 client credentials grant; the client authenticates with HTTP Basic
+#[derive(Clone)]
+pub struct OAuth2ClientCredentials {
+    pub token_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub scope: Option<String>,
+}
+
+impl AuthMethod {
+    /// Read credentials from the environment
+    ///
+    /// OAuth2 client credentials take precedence over a JWT, which takes
+    /// precedence over an API key.
+    pub fn from_env() -> Self {
+        let var = |name: &str| env::var(name).ok().filter(|v| !v.is_empty());
+        if let Some(token_url) = var("FORTITUDE_OAUTH_TOKEN_URL") {
+            return AuthMethod::OAuth2ClientCredentials(OAuth2ClientCredentials {
+                token_url,
+                client_id: var("FORTITUDE_OAUTH_CLIENT_ID").unwrap_or_default(),
+                client_secret: var("FORTITUDE_OAUTH_CLIENT_SECRET").unwrap_or_default(),
+                scope: var("FORTITUDE_OAUTH_SCOPE"),
+            });
+        }
+        if let Some(token) = var("FORTITUDE_JWT") {
+            let refresh = var("FORTITUDE_JWT_REFRESH_URL").zip(var("FORTITUDE_JWT_REFRESH_TOKEN")).map(|(token_url, refresh_token)| JwtRefresh {
+                token_url,
+                refresh_token,
+                client_id: var("FORTITUDE_OAUTH_CLIENT_ID"),
+            });
+            return AuthMethod::Jwt { token, refresh };
+        }
+        AuthMethod::ApiKey(env::var("FORTITUDE_API_KEY").unwrap_or_default())
+    }
+
+    /// Who requests are sent as; holds the credential, so only store it hashed
+    fn identity(&self) -> String {
+        match self {
+            AuthMethod::ApiKey(key) => format!("api-key:{}", key),
+            AuthMethod::Jwt { token, .. } => format!("jwt:{}", token),
+            AuthMethod::OAuth2ClientCredentials(creds) => {
+                format!("oauth2:{}:{}:{}", creds.token_url, creds.client_id, creds.scope.as_deref().unwrap_or_default())
+            }
+        }
+    }
+
+    fn validate(&self) -> Result<(), FortitudeError> {
+        let missing = |what: &str| Err(FortitudeError::ConfigError(what.to_string()));
+        match self {
+            AuthMethod::ApiKey(key) if key.is_empty() => missing("API key is required. Set FORTITUDE_API_KEY environment variable."),
+            AuthMethod::Jwt { token, .. } if token.is_empty() => missing("JWT is required. Set FORTITUDE_JWT environment variable."),
+            AuthMethod::OAuth2ClientCredentials(creds) if creds.client_id.is_empty() || creds.client_secret.is_empty() => {
+                missing("OAuth2 client credentials require FORTITUDE_OAUTH_CLIENT_ID and FORTITUDE_OAUTH_CLIENT_SECRET.")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether a rejected token can be replaced by a fresh one
+    fn can_refresh(&self) -> bool {
+        matches!(self, AuthMethod::Jwt { refresh: Some(_), .. } | AuthMethod::OAuth2ClientCredentials(_))
+    }
+}
+
+// Secrets stay out of logs and panics
+impl std::fmt::Debug for AuthMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthMethod::ApiKey(_) => f.write_str("ApiKey(<redacted>)"),
+            AuthMethod::Jwt { refresh, .. } => f
+                .debug_struct("Jwt")
+                .field("token", &"<redacted>")
+                .field("refresh_url", &refresh.as_ref().map(|r| r.token_url.as_str()))
+                .finish(),
+            AuthMethod::OAuth2ClientCredentials(creds) => f
+                .debug_struct("OAuth2ClientCredentials")
+                .field("token_url", &creds.token_url)
+                .field("client_id", &creds.client_id)
+                .field("scope", &creds.scope)
+                .finish(),
+        }
+    }
+}
+
+/// Token endpoint response (RFC 6749 section 5.1)
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+    refresh_token: Option<String>,
+}
+
+#[derive(Default)]
+struct TokenState {
+    access_token: Option<String>,
+    refresh_token: Option<String>,
+    expires_at: Option<Instant>,
+}
+
+/// Attaches credentials to requests, fetching and renewing bearer tokens
+///
+/// The state lock is held across a token request so concurrent calls wait
+/// for one renewal instead of each hitting the token endpoint.
+struct Authenticator {
+    method: AuthMethod,
+    http: Client,
+    state: tokio::sync::Mutex<TokenState>,
+}
+
+impl Authenticator {
+    fn new(method: AuthMethod, http: Client) -> Self {
+        Self { method, http, state: tokio::sync::Mutex::new(TokenState::default()) }
+    }
+
+    async fn apply(&self, request: reqwest::RequestBuilder) -> Result<reqwest::RequestBuilder, FortitudeError> {
+        match &self.method {
+            AuthMethod::ApiKey(key) => Ok(request.header("X-API-Key", key)),
+            _ => Ok(request.bearer_auth(self.access_token().await?)),
+        }
+    }
+
+    /// Mark the current token as expired so the next request renews it
+    async fn invalidate(&self) {
+        self.state.lock().await.expires_at = Some(Instant::now());
+    }
+
+    async fn access_token(&self) -> Result<String, FortitudeError> {
+        let mut state = self.state.lock().await;
+        if let Some(token) = &state.access_token {
+            let fresh = state.expires_at.is_none_or(|at| Instant::now() + TOKEN_EXPIRY_SKEW < at);
+            if fresh || !self.method.can_refresh() {
+                return Ok(token.clone());
+            }
+        } else if let AuthMethod::Jwt { token, refresh } = &self.method {
+            state.access_token = Some(token.clone());
+            state.refresh_token = refresh.as_ref().map(|r| r.refresh_token.clone());
+            return Ok(token.clone());
+        }
+        self.fetch_token(&mut state).await
+    }
+
+    async fn fetch_token(&self, state: &mut TokenState) -> Result<String, FortitudeError> {
+        let request = match &self.method {
+            AuthMethod::OAuth2ClientCredentials(creds) => {
+                let mut form = vec![("grant_type", "client_credentials".to_string())];
+                if let Some(scope) = &creds.scope {
+                    form.push(("scope", scope.clone()));
+                }
+                self.http.post(&creds.token_url).basic_auth(&creds.client_id, Some(&creds.client_secret)).form(&form)
+            }
+            AuthMethod::Jwt { refresh: Some(refresh), .. } => {
+                let refresh_token = state.refresh_token.clone().unwrap_or_else(|| refresh.refresh_token.clone());
+                let mut form = vec![("grant_type", "refresh_token".to_string()), ("refresh_token", refresh_token)];
+                if let Some(client_id) = &refresh.client_id {
+                    form.push(("client_id", client_id.clone()));
+                }
+                self.http.post(&refresh.token_url).form(&form)
+            }
+            _ => return Err(FortitudeError::AuthError("credentials cannot be refreshed".to_string())),
+        };
+
+        debug!("Requesting access token");
+        let response = request.send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(FortitudeError::AuthError(format!("token endpoint returned {}: {}", status, text)));
+        }
+        let token: TokenResponse = response.json().await?;
+
+        state.access_token = Some(token.access_token.clone());
+        state.expires_at = token.expires_in.map(|secs| Instant::now() + Duration::from_secs(secs));
+        if token.refresh_token.is_some() {
+            state.refresh_token = token.refresh_token;
+        }
+        info!("Obtained access token (expires in {:?}s)", token.expires_in);
+        Ok(token.access_token)
+    }
+}
+
 /// Client configuration
 #[derive(Debug, Clone)]
 pub struct ClientConfig {
-    pub api_key: String,
+    pub auth: AuthMethod,
     pub base_url: String,
     pub timeout: Duration,
     pub max_retries: u32,
     pub user_agent: String,
+    pub api_version: ApiVersion,
+    pub coalesce_requests: bool,
+    /// Also coalesce identical POSTs; off by default since POSTs may not be idempotent
+    pub coalesce_post: bool,
+    /// Keep GET responses locally and revalidate them instead of re-downloading
+    pub response_cache: Option<ResponseCacheConfig>,
 }
 
 impl Default for ClientConfig {
     fn default() -> Self {
         Self {
-            api_key: env::var("FORTITUDE_API_KEY").unwrap_or_default(),
+            auth: AuthMethod::from_env(),
             base_url: env::var("FORTITUDE_BASE_URL").unwrap_or_else(|_| "http://localhost:8080".to_string()),
             timeout: Duration::from_secs(
                 env::var("FORTITUDE_TIMEOUT")
@@ -259,15 +696,233 @@ impl Default for ClientConfig {
                 .parse()
                 .unwrap_or(3),
             user_agent: "Fortitude-Rust-Client/1.0.0".to_string(),
+            api_version: env::var("FORTITUDE_API_VERSION")
+                .ok()
+                .and_then(|v| ApiVersion::parse(&v))
+                .unwrap_or_default(),
+            coalesce_requests: env::var("FORTITUDE_COALESCE_REQUESTS")
+                .map(|v| v != "false" && v != "0")
+                .unwrap_or(true),
+            coalesce_post: env::var("FORTITUDE_COALESCE_POST")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            response_cache: ResponseCacheConfig::from_env(),
+        }
+    }
+}
+
+/// Where cached GET responses are kept
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResponseCacheStore {
+    Memory,
+    /// One file per response in this directory, so the cache survives restarts
+    Disk(PathBuf),
+}
+
+/// Local cache of GET responses, revalidated with `ETag` / `Last-Modified`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseCacheConfig {
+    pub store: ResponseCacheStore,
+    /// Serve a cached response without contacting the server for this long;
+    /// zero revalidates every call
+    pub max_age: Duration,
+}
+
+impl ResponseCacheConfig {
+    pub fn memory() -> Self {
+        Self { store: ResponseCacheStore::Memory, max_age: Duration::ZERO }
+    }
+
+    pub fn disk(dir: impl Into<PathBuf>) -> Self {
+        Self { store: ResponseCacheStore::Disk(dir.into()), max_age: Duration::ZERO }
+    }
+
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// `FORTITUDE_RESPONSE_CACHE` is `memory` or a cache directory;
+    /// `FORTITUDE_RESPONSE_CACHE_MAX_AGE` is in seconds
+    fn from_env() -> Option<Self> {
+        let config = match env::var("FORTITUDE_RESPONSE_CACHE").ok()?.as_str() {
+            "" | "off" | "false" | "0" => return None,
+            "memory" => Self::memory(),
+            dir => Self::disk(dir),
+        };
+        let max_age = env::var("FORTITUDE_RESPONSE_CACHE_MAX_AGE")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or_default();
+        Some(config.with_max_age(max_age))
+    }
+}
+
+/// Response cache counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResponseCacheStats {
+    /// Calls answered from the cache without contacting the server
+    pub fresh_hits: u64,
+    /// Calls the server answered with 304 Not Modified
+    pub revalidated: u64,
+    /// Calls that downloaded the response
+    pub misses: u64,
+}
+
+/// Cached response body with its validators
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedResponse {
+    body: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    /// When the server last confirmed the body
+    validated_at: SystemTime,
+}
+
+impl CachedResponse {
+    fn is_fresh(&self, max_age: Duration) -> bool {
+        !max_age.is_zero() && self.validated_at.elapsed().is_ok_and(|age| age < max_age)
+    }
+
+    /// `If-None-Match` / `If-Modified-Since` headers revalidating this response
+    fn conditional_headers(&self, headers: &mut reqwest::header::HeaderMap) {
+        let validators = [
+            (reqwest::header::IF_NONE_MATCH, &self.etag),
+            (reqwest::header::IF_MODIFIED_SINCE, &self.last_modified),
+        ];
+        for (name, value) in validators {
+            if let Some(value) = value.as_deref().and_then(|v| reqwest::header::HeaderValue::from_str(v).ok()) {
+                headers.insert(name, value);
+            }
+        }
+    }
+}
+
+/// GET responses keyed by server, endpoint and caller
+struct ResponseCache {
+    config: ResponseCacheConfig,
+    memory: Mutex<HashMap<String, CachedResponse>>,
+    fresh_hits: AtomicU64,
+    revalidated: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ResponseCache {
+    fn new(config: ResponseCacheConfig) -> Self {
+        Self {
+            config,
+            memory: Mutex::new(HashMap::new()),
+            fresh_hits: AtomicU64::new(0),
+            revalidated: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// SHA-256 of everything that decides the response, so a disk cache
+    /// shared between clients never serves one caller's data to another
+    fn key(base_url: &str, method: &reqwest::Method, endpoint: &str, auth: &AuthMethod) -> String {
+        let mut hasher = Sha256::new();
+        for part in [base_url, method.as_str(), endpoint, &auth.identity()] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+        hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    fn path(dir: &std::path::Path, key: &str) -> PathBuf {
+        dir.join(format!("{}.json", key))
+    }
+
+    async fn get(&self, key: &str) -> Option<CachedResponse> {
+        match &self.config.store {
+            ResponseCacheStore::Memory => self.memory.lock().unwrap_or_else(|e| e.into_inner()).get(key).cloned(),
+            ResponseCacheStore::Disk(dir) => {
+                let bytes = tokio::fs::read(Self::path(dir, key)).await.ok()?;
+                serde_json::from_slice(&bytes).ok()
+            }
+        }
+    }
+
+    async fn put(&self, key: &str, response: CachedResponse) {
+        match &self.config.store {
+            ResponseCacheStore::Memory => {
+                self.memory.lock().unwrap_or_else(|e| e.into_inner()).insert(key.to_string(), response);
+            }
+            ResponseCacheStore::Disk(dir) => {
+                let write = async {
+                    tokio::fs::create_dir_all(dir).await?;
+                    tokio::fs::write(Self::path(dir, key), serde_json::to_vec(&response)?).await
+                };
+                if let Err(e) = write.await {
+                    warn!("Failed to write response cache entry {}: {}", key, e);
+                }
+            }
+        }
+    }
+
+    async fn clear(&self) {
+        match &self.config.store {
+            ResponseCacheStore::Memory => self.memory.lock().unwrap_or_else(|e| e.into_inner()).clear(),
+            ResponseCacheStore::Disk(dir) => {
+                let Ok(mut entries) = tokio::fs::read_dir(dir).await else { return };
+                while let Ok(Some(entry)) = entries.next_entry().await {
+                    if entry.path().extension().is_some_and(|ext| ext == "json") {
+                        let _ = tokio::fs::remove_file(entry.path()).await;
+                    }
+                }
+            }
+        }
+    }
+
+    fn stats(&self) -> ResponseCacheStats {
+        ResponseCacheStats {
+            fresh_hits: self.fresh_hits.load(Ordering::Relaxed),
+            revalidated: self.revalidated.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
         }
     }
 }
 
+/// Body of a successful response with its cache validators
+#[derive(Debug, Clone)]
+struct RawResponse {
+    body: Arc<[u8]>,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    /// The server answered 304 and `body` is empty
+    not_modified: bool,
+}
+
+/// Single-flight coalescing counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CoalescingStats {
+    /// Requests actually sent to the server
+    pub dispatched: u64,
+    /// Calls that joined an identical in-flight request instead of sending their own
+    pub coalesced: u64,
+}
+
+type SharedResponse = Shared<BoxFuture<'static, Result<RawResponse, Arc<FortitudeError>>>>;
+
+/// In-flight requests keyed by method, endpoint and body hash
+#[derive(Default)]
+struct Coalescer {
+    inflight: Mutex<HashMap<u64, SharedResponse>>,
+    dispatched: AtomicU64,
+    coalesced: AtomicU64,
+}
+
 /// Fortitude API client
 #[derive(Clone)]
 pub struct FortitudeClient {
     client: Arc<Client>,
+    auth: Arc<Authenticator>,
     config: ClientConfig,
+    coalescer: Arc<Coalescer>,
+    coalesce: bool,
+    warning_hook: Option<WarningHook>,
+    response_cache: Option<Arc<ResponseCache>>,
 }
 
 impl FortitudeClient {
@@ -279,18 +934,13 @@ impl FortitudeClient {
 
     /// Create a new client with custom configuration
     pub fn with_config(config: ClientConfig) -> Result<Self, FortitudeError> {
-        if config.api_key.is_empty() {
-            return Err(FortitudeError::ConfigError(
-                "API key is required. Set FORTITUDE_API_KEY environment variable.".to_string()
-            ));
+        config.auth.validate()?;
+        if let AuthMethod::ApiKey(key) = &config.auth {
+            reqwest::header::HeaderValue::from_str(key)
+                .map_err(|e| FortitudeError::ConfigError(format!("Invalid API key: {}", e)))?;
         }
 
         let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            "X-API-Key",
-            reqwest::header::HeaderValue::from_str(&config.api_key)
-                .map_err(|e| FortitudeError::ConfigError(format!("Invalid API key: {}", e)))?
-        );
         headers.insert(
             "User-Agent",
             reqwest::header::HeaderValue::from_str(&config.user_agent)
@@ -306,8 +956,26 @@ impl FortitudeClient {
         info!("Initialized Fortitude client for {}", config.base_url);
 
         Ok(Self {
+            auth: Arc::new(Authenticator::new(config.auth.clone(), client.clone())),
             client: Arc::new(client),
+            coalesce: config.coalesce_requests,
+            response_cache: config.response_cache.clone().map(|cache| Arc::new(ResponseCache::new(cache))),
             config,
+            coalescer: Arc::new(Coalescer::default()),
+            warning_hook: None,
+        })
+    }
+
+    /// Call `hook` with the endpoint and each warning of every response
+    pub fn with_warning_hook(mut self, hook: impl Fn(&str, &Warning) + Send + Sync + 'static) -> Self {
+        self.warning_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Log every response warning through `tracing`
+    pub fn with_warning_logging(self) -> Self {
+        self.with_warning_hook(|endpoint, warning| {
+            warn!("{} returned warning {}: {}", endpoint, warning.code, warning.message);
         })
     }
 
@@ -316,117 +984,291 @@ impl FortitudeClient {
         ClientBuilder::new()
     }
 
-    /// Make an HTTP request with retries and error handling
+    /// Disable single-flight coalescing for calls made through the returned client
+    ///
+    /// The returned client shares the connection pool and coalescing metrics
+    /// with `self`, e.g. `client.without_coalescing().research(query)`.
+    pub fn without_coalescing(&self) -> Self {
+        Self {
+            coalesce: false,
+            ..self.clone()
+        }
+    }
+
+    /// Snapshot of single-flight coalescing counters
+    pub fn coalescing_stats(&self) -> CoalescingStats {
+        CoalescingStats {
+            dispatched: self.coalescer.dispatched.load(Ordering::Relaxed),
+            coalesced: self.coalescer.coalesced.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Snapshot of response cache counters; `None` without a response cache
+    pub fn response_cache_stats(&self) -> Option<ResponseCacheStats> {
+        self.response_cache.as_ref().map(|cache| cache.stats())
+    }
+
+    /// Drop every cached GET response, e.g. after submitting research that
+    /// changes listings cached with a `max_age`
+    pub async fn clear_response_cache(&self) {
+        if let Some(cache) = &self.response_cache {
+            cache.clear().await;
+        }
+    }
+
+    /// Make an HTTP request with retries, error handling and single-flight coalescing
     async fn make_request<T, R>(&self, method: reqwest::Method, endpoint: &str, body: Option<&T>) -> Result<ApiResponse<R>, FortitudeError>
     where
         T: Serialize,
         R: for<'de> Deserialize<'de>,
     {
-        let url = format!("{}{}", self.config.base_url, endpoint);
-        
-        for attempt in 0..=self.config.max_retries {
-            let mut request = self.client.request(method.clone(), &url);
-            
-            if let Some(data) = body {
-                request = request.json(data);
-            }
-
-            debug!("Making request: {} {} (attempt {})", method, endpoint, attempt + 1);
-            
-            match request.send().await {
-                Ok(response) => {
-                    let status = response.status();
-                    
-                    if status.is_success() {
-                        let result: ApiResponse<R> = response.json().await?;
-                        debug!("Request successful: {} {}", method, endpoint);
-                        return Ok(result);
-                    }
-                    
-                    // Handle retryable errors
-                    if (status == 429 || status.is_server_error()) && attempt < self.config.max_retries {
-                        let delay = Duration::from_millis(1000 * (2_u64.pow(attempt)));
-                        warn!("Request failed with {}, retrying in {:?}...", status, delay);
-                        tokio::time::sleep(delay).await;
-                        continue;
-                    }
-                    
-                    // Handle client errors and final server errors
-                    let error_response: Result<ErrorResponse, _> = response.json().await;
-                    
-                    match error_response {
-                        Ok(err) => {
-                            error!("API error: {} - {}", err.error_code, err.message);
-                            return Err(FortitudeError::ApiError {
-                                status_code: status.as_u16(),
-                                code: err.error_code,
-                                message: err.message,
-                                request_id: err.request_id,
-                                details: err.details,
-                            });
-                        }
-                        Err(_) => {
-                            let text = response.text().await.unwrap_or_default();
-                            return Err(FortitudeError::ApiError {
-                                status_code: status.as_u16(),
-                                code: "UNKNOWN_ERROR".to_string(),
-                                message: format!("HTTP {}: {}", status, text),
-                                request_id: None,
-                                details: None,
-                            });
-                        }
-                    }
-                }
-                Err(e) => {
-                    if attempt < self.config.max_retries {
-                        let delay = Duration::from_millis(1000 * (2_u64.pow(attempt)));
-                        warn!("Request error: {}, retrying in {:?}...", e, delay);
-                        tokio::time::sleep(delay).await;
-                        continue;
-                    }
-                    
-                    error!("Request failed after {} attempts: {}", self.config.max_retries + 1, e);
-                    return Err(FortitudeError::HttpError(e));
-                }
-            }
-        }
-        
-        Err(FortitudeError::ConfigError("Max retries exceeded".to_string()))
+        self.make_request_with_headers(method, endpoint, Default::default(), body).await
     }
 
-    // Health endpoints
+    /// Make an HTTP request with extra headers sent on every attempt
+    async fn make_request_with_headers<T, R>(&self, method: reqwest::Method, endpoint: &str, headers: reqwest::header::HeaderMap, body: Option<&T>) -> Result<ApiResponse<R>, FortitudeError>
+    where
+        T: Serialize,
+        R: for<'de> Deserialize<'de>,
+    {
+        let endpoint = self.versioned_endpoint(endpoint);
+        let body = body.map(serde_json::to_vec).transpose()?;
 
-    /// Get public health status
-    pub async fn get_health(&self) -> Result<HealthResponse, FortitudeError> {
-        let response: ApiResponse<HealthResponse> = self.make_request(reqwest::Method::GET, "/health", None::<&()>).await?;
-        Ok(response.data)
+        let bytes = match &self.response_cache {
+            Some(cache) if method == reqwest::Method::GET => self.send_cached(cache, endpoint.clone(), headers).await?,
+            _ => self.send(method, endpoint.clone(), headers, body).await?.body,
+        };
+
+        self.decode_response(&endpoint, &bytes)
     }
 
-    /// Get detailed health status (requires authentication)
-    pub async fn get_protected_health(&self) -> Result<HealthResponse, FortitudeError> {
-        let response: ApiResponse<HealthResponse> = self.make_request(reqwest::Method::GET, "/api/v1/health/protected", None::<&()>).await?;
-        Ok(response.data)
+    /// Send a request, coalescing it with identical in-flight calls when enabled
+    async fn send(&self, method: reqwest::Method, endpoint: String, headers: reqwest::header::HeaderMap, body: Option<Vec<u8>>) -> Result<RawResponse, FortitudeError> {
+        if self.coalesces(&method) {
+            self.send_coalesced(method, endpoint, headers, body).await
+        } else {
+            self.coalescer.dispatched.fetch_add(1, Ordering::Relaxed);
+            send_with_retries(self.client.clone(), self.auth.clone(), self.config.clone(), method, endpoint, headers, body).await
+        }
     }
 
-    // Research endpoints
+    /// GET through the response cache
+    ///
+    /// A response younger than `max_age` is returned without a request;
+    /// otherwise the cached copy is revalidated and reused on 304 Not Modified.
+    async fn send_cached(&self, cache: &ResponseCache, endpoint: String, headers: reqwest::header::HeaderMap) -> Result<Arc<[u8]>, FortitudeError> {
+        let key = ResponseCache::key(&self.config.base_url, &reqwest::Method::GET, &endpoint, &self.config.auth);
+        let cached = cache.get(&key).await;
+        if let Some(cached) = cached.as_ref().filter(|c| c.is_fresh(cache.config.max_age)) {
+            cache.fresh_hits.fetch_add(1, Ordering::Relaxed);
+            debug!("Serving {} from the response cache", endpoint);
+            return Ok(Arc::from(cached.body.as_bytes()));
+        }
 
-    /// Perform a research query
-    pub async fn research(&self, query: &str) -> Result<ResearchResponse, FortitudeError> {
-        let request = ResearchRequest {
-            query: query.to_string(),
-            context: None,
-            priority: Some("medium".to_string()),
-            audience_context: None,
+        let mut conditional = headers.clone();
+        if let Some(cached) = &cached {
+            cached.conditional_headers(&mut conditional);
+        }
+        let mut response = self.send(reqwest::Method::GET, endpoint.clone(), conditional, None).await?;
+
+        if response.not_modified {
+            if let Some(mut cached) = cached {
+                cache.revalidated.fetch_add(1, Ordering::Relaxed);
+                debug!("{} not modified, using cached response", endpoint);
+                cached.validated_at = SystemTime::now();
+                let body = Arc::from(cached.body.as_bytes());
+                cache.put(&key, cached).await;
+                return Ok(body);
+            }
+            // A coalesced call may have revalidated a copy this call no longer has
+            response = self.send(reqwest::Method::GET, endpoint.clone(), headers, None).await?;
+        }
+
+        cache.misses.fetch_add(1, Ordering::Relaxed);
+        let cacheable = response.etag.is_some() || response.last_modified.is_some() || !cache.config.max_age.is_zero();
+        if let (true, Ok(body)) = (cacheable, std::str::from_utf8(&response.body)) {
+            let entry = CachedResponse {
+                body: body.to_string(),
+                etag: response.etag.clone(),
+                last_modified: response.last_modified.clone(),
+                validated_at: SystemTime::now(),
+            };
+            cache.put(&key, entry).await;
+        }
+        Ok(response.body)
+    }
+
+    /// Unwrap the response envelope and report its warnings
+    fn decode_response<R>(&self, endpoint: &str, bytes: &[u8]) -> Result<ApiResponse<R>, FortitudeError>
+    where
+        R: for<'de> Deserialize<'de>,
+    {
+        let wire: WireResponse<R> = serde_json::from_slice(bytes)?;
+        let response = ApiResponse::from(wire);
+        if let Some(hook) = &self.warning_hook {
+            for warning in response.warnings() {
+                hook(endpoint, warning);
+            }
+        }
+        Ok(response)
+    }
+
+    /// Whether calls with `method` join identical in-flight requests
+    ///
+    /// Only GET and HEAD are safe to share by default; POST is opt-in through
+    /// `ClientConfig::coalesce_post`.
+    fn coalesces(&self, method: &reqwest::Method) -> bool {
+        self.coalesce
+            && (*method == reqwest::Method::GET
+                || *method == reqwest::Method::HEAD
+                || (*method == reqwest::Method::POST && self.config.coalesce_post))
+    }
+
+    /// Share one in-flight request between identical concurrent calls
+    ///
+    /// Joining calls share the leader's response, so only the leader's headers are sent.
+    async fn send_coalesced(&self, method: reqwest::Method, endpoint: String, headers: reqwest::header::HeaderMap, body: Option<Vec<u8>>) -> Result<RawResponse, FortitudeError> {
+        let key = request_key(&method, &endpoint, body.as_deref());
+
+        let (request, is_leader) = {
+            let mut inflight = self.coalescer.inflight.lock().unwrap_or_else(|e| e.into_inner());
+            match inflight.get(&key) {
+                // A completed entry is stale (its leader was dropped before cleanup)
+                Some(existing) if existing.peek().is_none() => (existing.clone(), false),
+                _ => {
+                    let request = send_with_retries(self.client.clone(), self.auth.clone(), self.config.clone(), method.clone(), endpoint.clone(), headers, body)
+                        .map(|result| result.map_err(Arc::new))
+                        .boxed()
+                        .shared();
+                    inflight.insert(key, request.clone());
+                    (request, true)
+                }
+            }
+        };
+
+        if is_leader {
+            self.coalescer.dispatched.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.coalescer.coalesced.fetch_add(1, Ordering::Relaxed);
+            debug!("Coalesced request: {} {}", method, endpoint);
+        }
+
+        let result = request.clone().await;
+
+        if is_leader {
+            let mut inflight = self.coalescer.inflight.lock().unwrap_or_else(|e| e.into_inner());
+            if inflight.get(&key).is_some_and(|current| current.ptr_eq(&request)) {
+                inflight.remove(&key);
+            }
+        }
+
+        result.map_err(|e| Arc::try_unwrap(e).unwrap_or_else(FortitudeError::Shared))
+    }
+
+    /// Rewrite a `/api/v1` endpoint onto the configured API version
+    fn versioned_endpoint(&self, endpoint: &str) -> String {
+        match endpoint.strip_prefix(ApiVersion::V1.prefix()) {
+            Some(rest) => format!("{}{}", self.config.api_version.prefix(), rest),
+            None => endpoint.to_string(),
+        }
+    }
+
+    /// List API versions supported by the server
+    pub async fn get_api_versions(&self) -> Result<ApiVersionsResponse, FortitudeError> {
+        let url = format!("{}/api/versions", self.config.base_url);
+        let response = self.client.get(&url).send().await?;
+        Ok(response.json().await?)
+    }
+
+    // Health endpoints
+
+    /// Get public health status
+    pub async fn get_health(&self) -> Result<HealthResponse, FortitudeError> {
+        let response: ApiResponse<HealthResponse> = self.make_request(reqwest::Method::GET, "/health", None::<&()>).await?;
+        Ok(response.data)
+    }
+
+    /// Get detailed health status (requires authentication)
+    pub async fn get_protected_health(&self) -> Result<HealthResponse, FortitudeError> {
+        let response: ApiResponse<HealthResponse> = self.make_request(reqwest::Method::GET, "/api/v1/health/protected", None::<&()>).await?;
+        Ok(response.data)
+    }
+
+    // Research endpoints
+
+    /// Perform a research query
+    pub async fn research(&self, query: &str) -> Result<ResearchResponse, FortitudeError> {
+        let request = ResearchRequest {
+            query: query.to_string(),
+            context: None,
+            priority: Some("medium".to_string()),
+            audience_context: None,
             domain_context: None,
+            dry_run: None,
+            parent_id: None,
+            code: None,
+            wait_timeout_ms: None,
+            budget_profile: None,
+            time_budget_ms: None,
+            include_feedback_token: None,
+            provider: None,
+            model: None,
         };
         
-        let response: ApiResponse<ResearchResponse> = self.make_request(reqwest::Method::POST, "/api/v1/research", Some(&request)).await?;
-        Ok(response.data)
+        self.research_detailed(request).await
     }
 
     /// Perform a detailed research query with context
+    ///
+    /// Each call sends a fresh `Idempotency-Key`, reused by its retries, so a
+    /// retry after a lost response replays the original result instead of
+    /// running the research again.
     pub async fn research_detailed(&self, request: ResearchRequest) -> Result<ResearchResponse, FortitudeError> {
-        let response: ApiResponse<ResearchResponse> = self.make_request(reqwest::Method::POST, "/api/v1/research", Some(&request)).await?;
+        let headers = idempotency_headers(&Uuid::new_v4())?;
+        let response: ApiResponse<ResearchResponse> = self.make_request_with_headers(reqwest::Method::POST, "/api/v1/research", headers, Some(&request)).await?;
+        Ok(response.data)
+    }
+
+    /// Perform a research query that stops when `cancel` is cancelled
+    ///
+    /// The request is sent with a fresh `x-request-id`, also used as its
+    /// `Idempotency-Key`. On cancellation the
+    /// connection is dropped and the server is asked to cancel that request ID,
+    /// which also stops research it already continued as a job. Simply dropping
+    /// the returned future aborts the request too, without the explicit cancel.
+    /// Cancellable requests are never coalesced.
+    pub async fn research_cancellable(&self, request: ResearchRequest, cancel: &CancellationToken) -> Result<ResearchResponse, FortitudeError> {
+        let request_id = Uuid::new_v4();
+        let endpoint = self.versioned_endpoint("/api/v1/research");
+        let body = serde_json::to_vec(&request)?;
+        let mut headers = idempotency_headers(&request_id)?;
+        headers.insert(
+            "x-request-id",
+            reqwest::header::HeaderValue::from_str(&request_id.to_string())
+                .map_err(|e| FortitudeError::ConfigError(format!("Invalid request ID: {}", e)))?,
+        );
+
+        self.coalescer.dispatched.fetch_add(1, Ordering::Relaxed);
+        let send = send_with_retries(self.client.clone(), self.auth.clone(), self.config.clone(), reqwest::Method::POST, endpoint.clone(), headers, Some(body));
+        tokio::select! {
+            raw = send => {
+                let response: ApiResponse<ResearchResponse> = self.decode_response(&endpoint, &raw?.body)?;
+                Ok(response.data)
+            }
+            _ = cancel.cancelled() => {
+                // Research that already finished or never started has nothing to cancel
+                if let Err(e) = self.cancel_research(&request_id).await {
+                    debug!("Cancelling research request {}: {}", request_id, e);
+                }
+                Err(FortitudeError::Cancelled { request_id })
+            }
+        }
+    }
+
+    /// Cancel running research by the request ID it was submitted with
+    pub async fn cancel_research(&self, request_id: &Uuid) -> Result<ResearchCancelResponse, FortitudeError> {
+        let endpoint = format!("/api/v1/research/requests/{}", request_id);
+        let response: ApiResponse<ResearchCancelResponse> = self.make_request(reqwest::Method::DELETE, &endpoint, None::<&()>).await?;
         Ok(response.data)
     }
 
@@ -461,6 +1303,11 @@ impl FortitudeClient {
         Ok(response.data)
     }
 
+    /// Page through every research result, optionally filtered by query text
+    pub fn research_results_pager(&self, query: Option<&str>) -> ResearchResultsPager {
+        ResearchResultsPager::new(self.clone(), query.map(str::to_string))
+    }
+
     // Classification endpoints
 
     /// Classify content
@@ -489,6 +1336,28 @@ impl FortitudeClient {
         Ok(response.data)
     }
 
+    /// Evict cache entries by key, pattern, research type, tag or age (requires admin)
+    pub async fn invalidate_cache(&self, request: CacheInvalidateRequest) -> Result<CacheInvalidateResponse, FortitudeError> {
+        let response: ApiResponse<CacheInvalidateResponse> = self.make_request(reqwest::Method::POST, "/api/v1/cache/invalidate", Some(&request)).await?;
+        Ok(response.data)
+    }
+
+    /// Evict a single cache entry (requires admin); succeeds if the entry is already gone
+    pub async fn delete_cache_entry(&self, cache_key: &str) -> Result<(), FortitudeError> {
+        let endpoint = self.versioned_endpoint(&format!("/api/v1/cache/entries/{}", urlencoding::encode(cache_key)));
+        // The endpoint answers 204 with no body, so skip response decoding and coalescing
+        send_with_retries(self.client.clone(), self.auth.clone(), self.config.clone(), reqwest::Method::DELETE, endpoint, Default::default(), None).await?;
+        Ok(())
+    }
+
+    // Limits endpoints
+
+    /// Get the rate limits, token quota and monthly budget of this API key
+    pub async fn get_limits(&self) -> Result<LimitsResponse, FortitudeError> {
+        let response: ApiResponse<LimitsResponse> = self.make_request(reqwest::Method::GET, "/api/v1/limits/me", None::<&()>).await?;
+        Ok(response.data)
+    }
+
     /// Test API connectivity
     pub async fn test_connection(&self) -> Result<bool, FortitudeError> {
         match self.get_health().await {
@@ -502,4 +1371,977 @@ impl FortitudeClient {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Largest page the server accepts for `/api/v1/research`
+pub const MAX_RESEARCH_PAGE_SIZE: u32 = 100;
+
+/// Default page size of [`ResearchResultsPager`]
+pub const DEFAULT_RESEARCH_PAGE_SIZE: u32 = 20;
+
+type PageFuture = BoxFuture<'static, Result<ResearchListResponse, FortitudeError>>;
+
+/// Stream of research results that fetches pages as it is consumed
+///
+/// Follows `pagination.has_next`, advancing the offset by the number of
+/// results each page actually returned. A failed page is yielded as an error
+/// and ends the stream.
+pub struct ResearchResultsPager {
+    client: FortitudeClient,
+    query: Option<String>,
+    page_size: u32,
+    offset: u32,
+    buffered: VecDeque<ResearchResult>,
+    pending: Option<PageFuture>,
+    exhausted: bool,
+}
+
+impl ResearchResultsPager {
+    fn new(client: FortitudeClient, query: Option<String>) -> Self {
+        Self { client, query, page_size: DEFAULT_RESEARCH_PAGE_SIZE, offset: 0, buffered: VecDeque::new(), pending: None, exhausted: false }
+    }
+
+    /// Results requested per page, clamped to 1..=MAX_RESEARCH_PAGE_SIZE
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size.clamp(1, MAX_RESEARCH_PAGE_SIZE);
+        self
+    }
+
+    /// Skip the first `offset` results
+    pub fn starting_at(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Collect up to `max` results, fetching only the pages needed
+    pub async fn collect_all(self, max: usize) -> Result<Vec<ResearchResult>, FortitudeError> {
+        self.take(max).try_collect().await
+    }
+
+    fn fetch_page(&self) -> PageFuture {
+        let client = self.client.clone();
+        let query = self.query.clone();
+        let (limit, offset) = (self.page_size, self.offset);
+        async move { client.list_research_results(Some(limit), Some(offset), query.as_deref()).await }.boxed()
+    }
+}
+
+impl Stream for ResearchResultsPager {
+    type Item = Result<ResearchResult, FortitudeError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(result) = self.buffered.pop_front() {
+                return Poll::Ready(Some(Ok(result)));
+            }
+            if self.exhausted {
+                return Poll::Ready(None);
+            }
+            if self.pending.is_none() {
+                self.pending = Some(self.fetch_page());
+            }
+
+            let page = match self.pending.as_mut().expect("page request in flight").poll_unpin(cx) {
+                Poll::Ready(page) => page,
+                Poll::Pending => return Poll::Pending,
+            };
+            self.pending = None;
+            match page {
+                Ok(page) => {
+                    let received = page.results.len() as u32;
+                    self.offset += received;
+                    // An empty page would otherwise be requested forever
+                    self.exhausted = !page.pagination.has_next || received == 0;
+                    self.buffered.extend(page.results);
+                }
+                Err(e) => {
+                    self.exhausted = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+}
+
+/// Coalescing key: method, endpoint and a hash of the request body
+fn request_key(method: &reqwest::Method, endpoint: &str, body: Option<&[u8]>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    method.as_str().hash(&mut hasher);
+    endpoint.hash(&mut hasher);
+    body.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Headers carrying `key` as the request's `Idempotency-Key`
+fn idempotency_headers(key: &Uuid) -> Result<reqwest::header::HeaderMap, FortitudeError> {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        IDEMPOTENCY_KEY_HEADER,
+        reqwest::header::HeaderValue::from_str(&key.to_string())
+            .map_err(|e| FortitudeError::ConfigError(format!("Invalid idempotency key: {}", e)))?,
+    );
+    Ok(headers)
+}
+
+/// Send a request with retries, returning the raw body of a successful response
+///
+/// 304 Not Modified counts as success, with an empty body.
+async fn send_with_retries(client: Arc<Client>, auth: Arc<Authenticator>, config: ClientConfig, method: reqwest::Method, endpoint: String, headers: reqwest::header::HeaderMap, body: Option<Vec<u8>>) -> Result<RawResponse, FortitudeError> {
+    let url = format!("{}{}", config.base_url, endpoint);
+    let mut reauthenticated = false;
+
+    for attempt in 0..=config.max_retries {
+        let mut request = auth.apply(client.request(method.clone(), &url).headers(headers.clone())).await?;
+
+        if let Some(data) = &body {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(data.clone());
+        }
+
+        debug!("Making request: {} {} (attempt {})", method, endpoint, attempt + 1);
+
+        match request.send().await {
+            Ok(response) => {
+                let status = response.status();
+
+                if let Some(deprecation) = response.headers().get("deprecation") {
+                    let sunset = response
+                        .headers()
+                        .get("sunset")
+                        .and_then(|v| v.to_str().ok())
+                        .unwrap_or("unscheduled");
+                    warn!(
+                        "API endpoint {} is deprecated ({:?}), sunset: {}",
+                        endpoint, deprecation, sunset
+                    );
+                }
+
+                if status.is_success() || status == reqwest::StatusCode::NOT_MODIFIED {
+                    let validator = |name| response.headers().get(name).and_then(|v: &reqwest::header::HeaderValue| v.to_str().ok()).map(str::to_string);
+                    let etag = validator(reqwest::header::ETAG);
+                    let last_modified = validator(reqwest::header::LAST_MODIFIED);
+                    let bytes = response.bytes().await?;
+                    debug!("Request successful: {} {} ({})", method, endpoint, status);
+                    return Ok(RawResponse {
+                        body: Arc::from(bytes.as_ref()),
+                        etag,
+                        last_modified,
+                        not_modified: status == reqwest::StatusCode::NOT_MODIFIED,
+                    });
+                }
+
+                // A rejected bearer token may have been revoked early; renew it once
+                if status == 401 && !reauthenticated && auth.method.can_refresh() && attempt < config.max_retries {
+                    warn!("Request unauthorized, renewing access token");
+                    auth.invalidate().await;
+                    reauthenticated = true;
+                    continue;
+                }
+
+                // Handle retryable errors
+                if (status == 429 || status.is_server_error()) && attempt < config.max_retries {
+                    let delay = Duration::from_millis(1000 * (2_u64.pow(attempt)));
+                    warn!("Request failed with {}, retrying in {:?}...", status, delay);
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+
+                // Handle client errors and final server errors
+                let text = response.text().await.unwrap_or_default();
+
+                match serde_json::from_str::<ErrorResponse>(&text) {
+                    Ok(err) => {
+                        error!("API error: {} - {}", err.error_code, err.message);
+                        return Err(FortitudeError::ApiError {
+                            status_code: status.as_u16(),
+                            code: err.error_code,
+                            message: err.message,
+                            request_id: err.request_id,
+                            details: err.details,
+                        });
+                    }
+                    Err(_) => {
+                        return Err(FortitudeError::ApiError {
+                            status_code: status.as_u16(),
+                            code: "UNKNOWN_ERROR".to_string(),
+                            message: format!("HTTP {}: {}", status, text),
+                            request_id: None,
+                            details: None,
+                        });
+                    }
+                }
+            }
+            Err(e) => {
+                if attempt < config.max_retries {
+                    let delay = Duration::from_millis(1000 * (2_u64.pow(attempt)));
+                    warn!("Request error: {}, retrying in {:?}...", e, delay);
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+
+                error!("Request failed after {} attempts: {}", config.max_retries + 1, e);
+                return Err(FortitudeError::HttpError(e));
+            }
+        }
+    }
+
+    Err(FortitudeError::ConfigError("Max retries exceeded".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::atomic::AtomicUsize;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Request as seen by [`mock_server`]
+    struct MockRequest {
+        method: String,
+        path: String,
+        headers: String,
+        body: String,
+    }
+
+    /// Serve `handler` on a local port, answering every request after `delay`
+    ///
+    /// Returns the base URL and the number of requests received so far.
+    async fn mock_server<F>(delay: Duration, handler: F) -> (String, Arc<AtomicUsize>)
+    where
+        F: Fn(&MockRequest) -> (u16, String) + Send + Sync + 'static,
+    {
+        mock_server_with_headers(delay, move |request| {
+            let (status, body) = handler(request);
+            (status, Vec::new(), body)
+        })
+        .await
+    }
+
+    /// [`mock_server`] whose handler also returns extra response headers
+    async fn mock_server_with_headers<F>(delay: Duration, handler: F) -> (String, Arc<AtomicUsize>)
+    where
+        F: Fn(&MockRequest) -> (u16, Vec<(&'static str, String)>, String) + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let handler = Arc::new(handler);
+
+        let counter = hits.clone();
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else { return };
+                let counter = counter.clone();
+                let handler = handler.clone();
+                tokio::spawn(async move {
+                    let Some(request) = read_request(&mut socket).await else { return };
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    tokio::time::sleep(delay).await;
+                    let (status, headers, body) = handler(&request);
+                    let headers: String = headers.iter().map(|(name, value)| format!("{}: {}\r\n", name, value)).collect();
+                    let response = format!(
+                        "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n{}",
+                        status,
+                        body.len(),
+                        headers,
+                        body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        (base_url, hits)
+    }
+
+    async fn read_request(socket: &mut tokio::net::TcpStream) -> Option<MockRequest> {
+        let mut buf = Vec::new();
+        let mut chunk = [0u8; 4096];
+        let header_end = loop {
+            let n = socket.read(&mut chunk).await.ok()?;
+            if n == 0 {
+                return None;
+            }
+            buf.extend_from_slice(&chunk[..n]);
+            if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                break pos + 4;
+            }
+        };
+
+        let headers = String::from_utf8_lossy(&buf[..header_end]).to_string();
+        let content_length = headers
+            .lines()
+            .find_map(|line| line.to_ascii_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap_or(0)))
+            .unwrap_or(0);
+        while buf.len() < header_end + content_length {
+            let n = socket.read(&mut chunk).await.ok()?;
+            if n == 0 {
+                break;
+            }
+            buf.extend_from_slice(&chunk[..n]);
+        }
+
+        let mut request_line = headers.lines().next()?.split_whitespace();
+        Some(MockRequest {
+            method: request_line.next()?.to_string(),
+            path: request_line.next()?.to_string(),
+            body: String::from_utf8_lossy(&buf[header_end..]).to_string(),
+            headers,
+        })
+    }
+
+    /// Wrap `data` in the v1 response envelope
+    fn envelope(data: serde_json::Value) -> String {
+        json!({
+            "request_id": Uuid::nil(),
+            "timestamp": "2025-01-01T00:00:00Z",
+            "success": true,
+            "data": data,
+        })
+        .to_string()
+    }
+
+    fn test_config(base_url: String) -> ClientConfig {
+        ClientConfig {
+            auth: AuthMethod::ApiKey("test-key".to_string()),
+            base_url,
+            timeout: Duration::from_secs(5),
+            max_retries: 0,
+            user_agent: "Fortitude-Rust-Client/test".to_string(),
+            api_version: ApiVersion::V1,
+            coalesce_requests: true,
+            coalesce_post: false,
+            response_cache: None,
+        }
+    }
+
+    fn api_status(error: &FortitudeError) -> Option<u16> {
+        match error {
+            FortitudeError::ApiError { status_code, .. } => Some(*status_code),
+            FortitudeError::Shared(inner) => api_status(inner),
+            _ => None,
+        }
+    }
+
+    fn header<'a>(request: &'a MockRequest, name: &str) -> Option<&'a str> {
+        request.headers.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    }
+
+    /// Token endpoint response granting `token` for `expires_in` seconds
+    fn token_response(token: &str, expires_in: u64, refresh_token: Option<&str>) -> (u16, String) {
+        (200, json!({"access_token": token, "expires_in": expires_in, "refresh_token": refresh_token}).to_string())
+    }
+
+    fn query_param(request: &MockRequest, name: &str) -> Option<u32> {
+        let (_, query) = request.path.split_once('?')?;
+        query.split('&').find_map(|pair| pair.strip_prefix(name)?.strip_prefix('=')?.parse().ok())
+    }
+
+    /// One page of a `total`-result listing, titled by absolute position
+    fn research_page(request: &MockRequest, total: u32, has_next: Option<bool>) -> (u16, String) {
+        let limit = query_param(request, "limit").unwrap_or(DEFAULT_RESEARCH_PAGE_SIZE);
+        let offset = query_param(request, "offset").unwrap_or(0);
+        let results: Vec<_> = (offset..total.min(offset + limit))
+            .map(|i| {
+                json!({
+                    "id": Uuid::new_v4(),
+                    "title": format!("result-{}", i),
+                    "content": "",
+                    "relevance_score": 1.0,
+                    "source": null,
+                    "created_at": "2025-01-01T00:00:00Z",
+                })
+            })
+            .collect();
+        let data = json!({
+            "results": results,
+            "total_count": total,
+            "pagination": {
+                "limit": limit,
+                "offset": offset,
+                "total_count": total,
+                "has_next": has_next.unwrap_or(offset + limit < total),
+                "has_previous": offset > 0,
+            },
+        });
+        (200, envelope(data))
+    }
+
+    fn titles(results: &[ResearchResult]) -> Vec<String> {
+        results.iter().map(|r| r.title.clone()).collect()
+    }
+
+    async fn get(client: &FortitudeClient, endpoint: &str) -> Result<ApiResponse<serde_json::Value>, FortitudeError> {
+        client.make_request(reqwest::Method::GET, endpoint, None::<&()>).await
+    }
+
+    async fn post(client: &FortitudeClient, endpoint: &str, body: &serde_json::Value) -> Result<ApiResponse<serde_json::Value>, FortitudeError> {
+        client.make_request(reqwest::Method::POST, endpoint, Some(body)).await
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_research_is_cancelled_on_the_server() {
+        let submitted = Arc::new(Mutex::new(None::<String>));
+        let seen = submitted.clone();
+        let (base_url, hits) = mock_server(Duration::from_millis(300), move |request| {
+            if request.method == "POST" {
+                let request_id = request
+                    .headers
+                    .lines()
+                    .find_map(|line| line.to_ascii_lowercase().strip_prefix("x-request-id:").map(|v| v.trim().to_string()));
+                *seen.lock().unwrap() = request_id;
+                return (200, envelope(json!({})));
+            }
+            assert_eq!(request.method, "DELETE");
+            let request_id = request.path.rsplit('/').next().unwrap().to_string();
+            (200, envelope(json!({"request_id": request_id, "cancelled": true})))
+        })
+        .await;
+        let client = FortitudeClient::with_config(test_config(base_url)).unwrap();
+        let cancel = CancellationToken::new();
+
+        let canceller = cancel.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            canceller.cancel();
+        });
+        let request = ResearchRequest {
+            query: "tokio vs async-std".to_string(),
+            context: None,
+            priority: None,
+            audience_context: None,
+            domain_context: None,
+            dry_run: None,
+            parent_id: None,
+            code: None,
+            wait_timeout_ms: None,
+            budget_profile: None,
+            time_budget_ms: None,
+            include_feedback_token: None,
+            provider: None,
+            model: None,
+        };
+        let error = client.research_cancellable(request, &cancel).await.unwrap_err();
+
+        let FortitudeError::Cancelled { request_id } = error else {
+            panic!("unexpected error: {error}");
+        };
+        assert_eq!(submitted.lock().unwrap().clone(), Some(request_id.to_string()));
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_research_retries_reuse_their_idempotency_key() {
+        let keys = Arc::new(Mutex::new(Vec::<String>::new()));
+        let seen = keys.clone();
+        let (base_url, hits) = mock_server(Duration::ZERO, move |request| {
+            let mut keys = seen.lock().unwrap();
+            keys.push(header(request, IDEMPOTENCY_KEY_HEADER).unwrap().to_string());
+            // The first attempt fails as if its response was lost
+            if keys.len() == 1 {
+                return (503, String::new());
+            }
+            (200, envelope(json!({})))
+        })
+        .await;
+        let client = FortitudeClient::with_config(ClientConfig {
+            max_retries: 1,
+            ..test_config(base_url)
+        })
+        .unwrap();
+
+        let _ = client.research("tokio vs async-std").await;
+        let _ = client.research("tokio vs async-std").await;
+
+        let keys = keys.lock().unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        assert_eq!(keys[0], keys[1]);
+        assert_ne!(keys[1], keys[2]);
+        assert!(Uuid::parse_str(&keys[0]).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_research_cancelled_on_the_server_is_not_retried() {
+        let (base_url, hits) = mock_server(Duration::ZERO, |_| {
+            let error = json!({
+                "error_code": "RESEARCH_CANCELLED",
+                "message": "Research request was cancelled",
+                "details": null,
+                "request_id": null,
+                "timestamp": "2025-01-01T00:00:00Z",
+                "path": null,
+            });
+            (409, error.to_string())
+        })
+        .await;
+        let client = FortitudeClient::with_config(ClientConfig {
+            max_retries: 3,
+            ..test_config(base_url)
+        })
+        .unwrap();
+
+        let error = client.research("tokio vs async-std").await.unwrap_err();
+        assert_eq!(api_status(&error), Some(409));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    /// Server answering `/api/v1/cache/stats` with ETag `tag`, honoring If-None-Match
+    async fn etag_server(tag: &'static str) -> (String, Arc<AtomicUsize>, Arc<Mutex<Vec<Option<String>>>>) {
+        let conditions = Arc::new(Mutex::new(Vec::new()));
+        let seen = conditions.clone();
+        let (base_url, hits) = mock_server_with_headers(Duration::ZERO, move |request| {
+            let if_none_match = header(request, "if-none-match").map(str::to_string);
+            let unchanged = if_none_match.as_deref() == Some(tag);
+            seen.lock().unwrap().push(if_none_match);
+            let headers = vec![("ETag", tag.to_string())];
+            if unchanged {
+                return (304, headers, String::new());
+            }
+            (200, headers, envelope(json!({"entries": 42})))
+        })
+        .await;
+        (base_url, hits, conditions)
+    }
+
+    #[tokio::test]
+    async fn test_cached_get_is_revalidated_with_its_etag() {
+        let (base_url, hits, conditions) = etag_server("W/\"v1\"").await;
+        let client = FortitudeClient::with_config(ClientConfig {
+            response_cache: Some(ResponseCacheConfig::memory()),
+            ..test_config(base_url)
+        })
+        .unwrap();
+
+        let first = get(&client, "/api/v1/cache/stats").await.unwrap();
+        let second = get(&client, "/api/v1/cache/stats").await.unwrap();
+
+        assert_eq!(first.data, second.data);
+        assert_eq!(second.data["entries"], 42);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        assert_eq!(*conditions.lock().unwrap(), vec![None, Some("W/\"v1\"".to_string())]);
+        assert_eq!(
+            client.response_cache_stats(),
+            Some(ResponseCacheStats { fresh_hits: 0, revalidated: 1, misses: 1 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fresh_cached_get_skips_the_server() {
+        let (base_url, hits, _) = etag_server("\"v1\"").await;
+        let client = FortitudeClient::with_config(ClientConfig {
+            response_cache: Some(ResponseCacheConfig::memory().with_max_age(Duration::from_secs(60))),
+            ..test_config(base_url)
+        })
+        .unwrap();
+
+        get(&client, "/api/v1/cache/stats").await.unwrap();
+        get(&client, "/api/v1/cache/stats").await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        client.clear_response_cache().await;
+        get(&client, "/api/v1/cache/stats").await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        assert_eq!(client.response_cache_stats().unwrap().fresh_hits, 1);
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_is_shared_across_clients() {
+        let dir = std::env::temp_dir().join(format!("fortitude-response-cache-{}", Uuid::new_v4()));
+        let (base_url, _, conditions) = etag_server("\"v1\"").await;
+        let config = ClientConfig {
+            response_cache: Some(ResponseCacheConfig::disk(&dir)),
+            ..test_config(base_url)
+        };
+
+        get(&FortitudeClient::with_config(config.clone()).unwrap(), "/api/v1/cache/stats").await.unwrap();
+        let restarted = FortitudeClient::with_config(config).unwrap();
+        let response = get(&restarted, "/api/v1/cache/stats").await.unwrap();
+
+        assert_eq!(response.data["entries"], 42);
+        assert_eq!(conditions.lock().unwrap()[1].as_deref(), Some("\"v1\""));
+        assert_eq!(restarted.response_cache_stats().unwrap().revalidated, 1);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_is_not_shared_across_callers_or_servers() {
+        let dir = std::env::temp_dir().join(format!("fortitude-response-cache-{}", Uuid::new_v4()));
+        let (base_url, _, conditions) = etag_server("\"v1\"").await;
+        let (other_url, _, other_conditions) = etag_server("\"v1\"").await;
+        let config = ClientConfig {
+            response_cache: Some(ResponseCacheConfig::disk(&dir)),
+            ..test_config(base_url)
+        };
+
+        get(&FortitudeClient::with_config(config.clone()).unwrap(), "/api/v1/cache/stats").await.unwrap();
+        let other_caller = FortitudeClient::with_config(ClientConfig {
+            auth: AuthMethod::ApiKey("other-key".to_string()),
+            ..config.clone()
+        })
+        .unwrap();
+        get(&other_caller, "/api/v1/cache/stats").await.unwrap();
+        let other_server = FortitudeClient::with_config(ClientConfig { base_url: other_url, ..config }).unwrap();
+        get(&other_server, "/api/v1/cache/stats").await.unwrap();
+
+        assert_eq!(*conditions.lock().unwrap(), vec![None, None]);
+        assert_eq!(*other_conditions.lock().unwrap(), vec![None]);
+        let files: Vec<_> = std::fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().file_name()).collect();
+        assert_eq!(files.len(), 3);
+        assert!(files.iter().all(|name| name.to_string_lossy().len() == 64 + ".json".len()));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn test_identical_in_flight_gets_share_one_request() {
+        let (base_url, hits) = mock_server(Duration::from_millis(200), |request| {
+            assert!(request.headers.to_ascii_lowercase().contains("x-api-key: test-key"));
+            (200, envelope(json!({"ok": true})))
+        })
+        .await;
+        let client = FortitudeClient::with_config(test_config(base_url)).unwrap();
+
+        let (a, b) = tokio::join!(get(&client, "/api/v1/cache/stats"), get(&client, "/api/v1/cache/stats"));
+        assert_eq!(a.unwrap().data, json!({"ok": true}));
+        assert_eq!(b.unwrap().data, json!({"ok": true}));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        assert_eq!(client.coalescing_stats(), CoalescingStats { dispatched: 1, coalesced: 1 });
+
+        // A completed request is not reused by later calls
+        get(&client, "/api/v1/cache/stats").await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        assert_eq!(client.coalescing_stats(), CoalescingStats { dispatched: 2, coalesced: 1 });
+    }
+
+    #[tokio::test]
+    async fn test_different_endpoints_are_not_merged() {
+        let (base_url, hits) = mock_server(Duration::from_millis(100), |request| (200, envelope(json!(request.path)))).await;
+        let client = FortitudeClient::with_config(test_config(base_url)).unwrap();
+
+        let (a, b) = tokio::join!(get(&client, "/api/v1/research/a"), get(&client, "/api/v1/research/b"));
+        assert_eq!(a.unwrap().data, json!("/api/v1/research/a"));
+        assert_eq!(b.unwrap().data, json!("/api/v1/research/b"));
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        assert_eq!(client.coalescing_stats().coalesced, 0);
+    }
+
+    #[tokio::test]
+    async fn test_without_coalescing_sends_every_call() {
+        let (base_url, hits) = mock_server(Duration::from_millis(100), |_| (200, envelope(json!(null)))).await;
+        let client = FortitudeClient::with_config(test_config(base_url)).unwrap();
+        let uncoalesced = client.without_coalescing();
+
+        let (a, b) = tokio::join!(get(&uncoalesced, "/api/v1/cache/stats"), get(&uncoalesced, "/api/v1/cache/stats"));
+        a.unwrap();
+        b.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        // Metrics are shared with the original client
+        assert_eq!(client.coalescing_stats(), CoalescingStats { dispatched: 2, coalesced: 0 });
+    }
+
+    #[tokio::test]
+    async fn test_posts_are_only_coalesced_when_enabled() {
+        let body = json!({"query": "tokio vs async-std"});
+
+        let (base_url, hits) = mock_server(Duration::from_millis(100), |request| {
+            assert_eq!(request.method, "POST");
+            (200, envelope(serde_json::from_str(&request.body).unwrap()))
+        })
+        .await;
+        let client = FortitudeClient::with_config(test_config(base_url.clone())).unwrap();
+        let (a, b) = tokio::join!(post(&client, "/api/v1/research", &body), post(&client, "/api/v1/research", &body));
+        assert_eq!(a.unwrap().data, body);
+        assert_eq!(b.unwrap().data, body);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        assert_eq!(client.coalescing_stats().coalesced, 0);
+
+        let client = FortitudeClient::with_config(ClientConfig { coalesce_post: true, ..test_config(base_url) }).unwrap();
+        let (a, b) = tokio::join!(post(&client, "/api/v1/research", &body), post(&client, "/api/v1/research", &body));
+        a.unwrap();
+        b.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        assert_eq!(client.coalescing_stats(), CoalescingStats { dispatched: 1, coalesced: 1 });
+    }
+
+    #[tokio::test]
+    async fn test_error_is_delivered_to_every_coalesced_caller() {
+        let (base_url, hits) = mock_server(Duration::from_millis(200), |_| {
+            let error = json!({
+                "error_code": "NOT_FOUND",
+                "message": "no such entry",
+                "details": null,
+                "request_id": null,
+                "timestamp": "2025-01-01T00:00:00Z",
+                "path": null,
+            });
+            (404, error.to_string())
+        })
+        .await;
+        let client = FortitudeClient::with_config(test_config(base_url)).unwrap();
+
+        let (a, b) = tokio::join!(get(&client, "/api/v1/research/missing"), get(&client, "/api/v1/research/missing"));
+        assert_eq!(api_status(&a.unwrap_err()), Some(404));
+        assert_eq!(api_status(&b.unwrap_err()), Some(404));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        assert_eq!(client.coalescing_stats(), CoalescingStats { dispatched: 1, coalesced: 1 });
+    }
+
+    #[tokio::test]
+    async fn test_oauth2_token_is_fetched_once_and_cached() {
+        let token_requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = token_requests.clone();
+        let (base_url, hits) = mock_server(Duration::ZERO, move |request| {
+            if request.path == "/oauth/token" {
+                seen.lock().unwrap().push((header(request, "authorization").map(str::to_string), request.body.clone()));
+                return token_response("oauth-token", 3600, None);
+            }
+            match header(request, "authorization") {
+                Some("Bearer oauth-token") => (200, envelope(json!(null))),
+                _ => (401, String::new()),
+            }
+        })
+        .await;
+        let config = ClientConfig {
+            auth: AuthMethod::OAuth2ClientCredentials(OAuth2ClientCredentials {
+                token_url: format!("{}/oauth/token", base_url),
+                client_id: "client".to_string(),
+                client_secret: "secret".to_string(),
+                scope: Some("research:read".to_string()),
+            }),
+            ..test_config(base_url)
+        };
+        let client = FortitudeClient::with_config(config).unwrap();
+
+        get(&client, "/api/v1/cache/stats").await.unwrap();
+        get(&client, "/api/v1/limits/me").await.unwrap();
+
+        let token_requests = token_requests.lock().unwrap();
+        assert_eq!(token_requests.len(), 1);
+        let (authorization, body) = &token_requests[0];
+        // base64("client:secret")
+        assert_eq!(authorization.as_deref(), Some("Basic Y2xpZW50OnNlY3JldA=="));
+        assert!(body.contains("grant_type=client_credentials"));
+        assert!(body.contains("scope=research%3Aread"));
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_oauth2_token_is_renewed_before_expiry() {
+        let issued = Arc::new(AtomicUsize::new(0));
+        let counter = issued.clone();
+        let (base_url, _) = mock_server(Duration::ZERO, move |request| {
+            if request.path == "/oauth/token" {
+                let n = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                // Expires inside TOKEN_EXPIRY_SKEW, so it is stale as soon as it arrives
+                return token_response(&format!("token-{}", n), 5, None);
+            }
+            (200, envelope(json!(header(request, "authorization"))))
+        })
+        .await;
+        let config = ClientConfig {
+            auth: AuthMethod::OAuth2ClientCredentials(OAuth2ClientCredentials {
+                token_url: format!("{}/oauth/token", base_url),
+                client_id: "client".to_string(),
+                client_secret: "secret".to_string(),
+                scope: None,
+            }),
+            ..test_config(base_url)
+        };
+        let client = FortitudeClient::with_config(config).unwrap();
+
+        assert_eq!(get(&client, "/api/v1/cache/stats").await.unwrap().data, json!("Bearer token-1"));
+        assert_eq!(get(&client, "/api/v1/cache/stats").await.unwrap().data, json!("Bearer token-2"));
+        assert_eq!(issued.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_jwt_is_refreshed_after_401_and_before_expiry() {
+        let refresh_bodies = Arc::new(Mutex::new(Vec::new()));
+        let seen = refresh_bodies.clone();
+        let (base_url, _) = mock_server(Duration::ZERO, move |request| {
+            if request.path == "/oauth/token" {
+                let mut seen = seen.lock().unwrap();
+                seen.push(request.body.clone());
+                let n = seen.len();
+                return token_response(&format!("jwt-{}", n), 5, Some(&format!("refresh-{}", n)));
+            }
+            match header(request, "authorization") {
+                Some("Bearer revoked") => (401, String::new()),
+                authorization => (200, envelope(json!(authorization))),
+            }
+        })
+        .await;
+        let config = ClientConfig {
+            auth: AuthMethod::Jwt {
+                token: "revoked".to_string(),
+                refresh: Some(JwtRefresh {
+                    token_url: format!("{}/oauth/token", base_url),
+                    refresh_token: "refresh-0".to_string(),
+                    client_id: Some("cli".to_string()),
+                }),
+            },
+            max_retries: 1,
+            ..test_config(base_url)
+        };
+        let client = FortitudeClient::with_config(config).unwrap();
+
+        // The pre-issued JWT is rejected, renewed once and the request retried
+        assert_eq!(get(&client, "/api/v1/cache/stats").await.unwrap().data, json!("Bearer jwt-1"));
+        // jwt-1 expires within the skew, so it is renewed with the rotated refresh token
+        assert_eq!(get(&client, "/api/v1/cache/stats").await.unwrap().data, json!("Bearer jwt-2"));
+
+        let refresh_bodies = refresh_bodies.lock().unwrap();
+        assert_eq!(refresh_bodies.len(), 2);
+        assert!(refresh_bodies[0].contains("grant_type=refresh_token"));
+        assert!(refresh_bodies[0].contains("refresh_token=refresh-0"));
+        assert!(refresh_bodies[0].contains("client_id=cli"));
+        assert!(refresh_bodies[1].contains("refresh_token=refresh-1"));
+    }
+
+    #[tokio::test]
+    async fn test_401_is_retried_only_once() {
+        let issued = Arc::new(AtomicUsize::new(0));
+        let counter = issued.clone();
+        let (base_url, hits) = mock_server(Duration::ZERO, move |request| {
+            if request.path == "/oauth/token" {
+                counter.fetch_add(1, Ordering::SeqCst);
+                return token_response("still-rejected", 3600, None);
+            }
+            (401, String::new())
+        })
+        .await;
+        let config = ClientConfig {
+            auth: AuthMethod::OAuth2ClientCredentials(OAuth2ClientCredentials {
+                token_url: format!("{}/oauth/token", base_url),
+                client_id: "client".to_string(),
+                client_secret: "secret".to_string(),
+                scope: None,
+            }),
+            max_retries: 3,
+            ..test_config(base_url)
+        };
+        let client = FortitudeClient::with_config(config).unwrap();
+
+        let error = get(&client, "/api/v1/cache/stats").await.unwrap_err();
+        assert_eq!(api_status(&error), Some(401));
+        // Initial fetch plus one renewal; two API attempts
+        assert_eq!(issued.load(Ordering::SeqCst), 2);
+        assert_eq!(hits.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_failing_token_endpoint_surfaces_auth_error() {
+        let (base_url, hits) = mock_server(Duration::ZERO, |request| {
+            if request.path == "/oauth/token" {
+                return (400, json!({"error": "invalid_client"}).to_string());
+            }
+            (200, envelope(json!(null)))
+        })
+        .await;
+        let config = ClientConfig {
+            auth: AuthMethod::OAuth2ClientCredentials(OAuth2ClientCredentials {
+                token_url: format!("{}/oauth/token", base_url),
+                client_id: "client".to_string(),
+                client_secret: "wrong".to_string(),
+                scope: None,
+            }),
+            ..test_config(base_url)
+        };
+        // Uncoalesced, so the error is returned as-is rather than shared
+        let client = FortitudeClient::with_config(config).unwrap().without_coalescing();
+
+        match get(&client, "/api/v1/cache/stats").await.unwrap_err() {
+            FortitudeError::AuthError(message) => assert!(message.contains("invalid_client"), "{}", message),
+            other => panic!("expected an auth error, got {}", other),
+        }
+        // Only the token endpoint was contacted
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_pager_follows_has_next_until_last_page() {
+        let offsets = Arc::new(Mutex::new(Vec::new()));
+        let seen = offsets.clone();
+        let (base_url, hits) = mock_server(Duration::ZERO, move |request| {
+            assert!(request.path.contains("query=async"));
+            seen.lock().unwrap().push(query_param(request, "offset"));
+            research_page(request, 5, None)
+        })
+        .await;
+        let client = FortitudeClient::with_config(test_config(base_url)).unwrap();
+
+        let results: Vec<_> = client.research_results_pager(Some("async")).with_page_size(2).try_collect().await.unwrap();
+        assert_eq!(titles(&results), ["result-0", "result-1", "result-2", "result-3", "result-4"]);
+        assert_eq!(*offsets.lock().unwrap(), [Some(0), Some(2), Some(4)]);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_pager_stops_on_empty_page() {
+        // The server keeps claiming there is more, but runs out after 3 results
+        let (base_url, hits) = mock_server(Duration::ZERO, |request| research_page(request, 3, Some(true))).await;
+        let client = FortitudeClient::with_config(test_config(base_url)).unwrap();
+
+        let results: Vec<_> = client.research_results_pager(None).with_page_size(2).starting_at(1).try_collect().await.unwrap();
+        assert_eq!(titles(&results), ["result-1", "result-2"]);
+        // Page at offset 1, then an empty page at offset 3
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_pager_error_ends_stream() {
+        let (base_url, hits) = mock_server(Duration::ZERO, |request| match query_param(request, "offset") {
+            Some(0) => research_page(request, 10, None),
+            _ => (400, json!({"error_code": "BAD_OFFSET", "message": "offset out of range", "timestamp": "2025-01-01T00:00:00Z"}).to_string()),
+        })
+        .await;
+        let client = FortitudeClient::with_config(test_config(base_url)).unwrap();
+        let mut pager = client.research_results_pager(None).with_page_size(3);
+
+        for expected in ["result-0", "result-1", "result-2"] {
+            assert_eq!(pager.next().await.unwrap().unwrap().title, expected);
+        }
+        let error = pager.next().await.unwrap().unwrap_err();
+        assert_eq!(api_status(&error), Some(400));
+        assert!(pager.next().await.is_none());
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_collect_all_fetches_only_needed_pages() {
+        let (base_url, hits) = mock_server(Duration::ZERO, |request| research_page(request, 50, None)).await;
+        let client = FortitudeClient::with_config(test_config(base_url)).unwrap();
+
+        let results = client.research_results_pager(None).with_page_size(10).collect_all(15).await.unwrap();
+        assert_eq!(results.len(), 15);
+        assert_eq!(results[14].title, "result-14");
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+
+        assert!(client.research_results_pager(None).collect_all(0).await.unwrap().is_empty());
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_pager_page_size_is_clamped() {
+        let client = FortitudeClient::with_config(test_config("http://localhost:0".to_string())).unwrap();
+        assert_eq!(client.research_results_pager(None).with_page_size(0).page_size, 1);
+        assert_eq!(client.research_results_pager(None).with_page_size(500).page_size, MAX_RESEARCH_PAGE_SIZE);
+    }
+
+    #[test]
+    fn test_request_key_distinguishes_method_endpoint_and_body() {
+        let get = reqwest::Method::GET;
+        let post = reqwest::Method::POST;
+        let key = request_key(&post, "/api/v1/research", Some(b"{}"));
+
+        assert_eq!(key, request_key(&post, "/api/v1/research", Some(b"{}")));
+        assert_ne!(key, request_key(&get, "/api/v1/research", Some(b"{}")));
+        assert_ne!(key, request_key(&post, "/api/v1/classify", Some(b"{}")));
+        assert_ne!(key, request_key(&post, "/api/v1/research", Some(b"{\"a\":1}")));
+        assert_ne!(key, request_key(&post, "/api/v1/research", None));
+    }
+}
@@ -172,7 +172,8 @@ async fn single_research_request(client: &FortitudeClient, query: &str) -> (Dura
 async fn test_concurrent_health_checks(num_requests: usize, max_concurrency: usize) -> Result<PerformanceResults> {
     info!("🏥 Testing {} concurrent health checks with {} max concurrency...", num_requests, max_concurrency);
     
-    let client = FortitudeClient::new()?;
+    // Load tests measure the server, so every request must actually be sent
+    let client = FortitudeClient::new()?.without_coalescing();
     let semaphore = Arc::new(Semaphore::new(max_concurrency));
     let mut results = PerformanceResults::new();
     
@@ -210,7 +211,7 @@ async fn test_concurrent_health_checks(num_requests: usize, max_concurrency: usi
 async fn test_concurrent_research_requests(num_requests: usize, max_concurrency: usize) -> Result<PerformanceResults> {
     info!("🔬 Testing {} concurrent research requests with {} max concurrency...", num_requests, max_concurrency);
     
-    let client = FortitudeClient::new()?;
+    let client = FortitudeClient::new()?.without_coalescing();
     let semaphore = Arc::new(Semaphore::new(max_concurrency));
     let mut results = PerformanceResults::new();
     
@@ -257,7 +258,7 @@ async fn test_concurrent_research_requests(num_requests: usize, max_concurrency:
 async fn test_cache_hit_rate() -> Result<(Vec<Duration>, f64)> {
     info!("🗄️ Testing cache hit rate effectiveness...");
     
-    let client = FortitudeClient::new()?;
+    let client = FortitudeClient::new()?.without_coalescing();
     let query = "Cache effectiveness test query for performance validation";
     let mut times = Vec::new();
     
@@ -303,7 +304,7 @@ async fn test_cache_hit_rate() -> Result<(Vec<Duration>, f64)> {
 async fn test_rate_limiting() -> Result<(usize, usize, Duration)> {
     info!("🚦 Testing rate limiting behavior...");
     
-    let client = FortitudeClient::new()?;
+    let client = FortitudeClient::new()?.without_coalescing();
     let mut successful_requests = 0;
     let mut rate_limit_hits = 0;
     let start_time = Instant::now();
@@ -464,7 +465,7 @@ async fn main() -> Result<()> {
     println!("{}", "=".repeat(60));
     
     // Check API connectivity first
-    let client = FortitudeClient::new()?;
+    let client = FortitudeClient::new()?.without_coalescing();
     match client.test_connection().await {
         Ok(_) => {
             let health = client.get_health().await?;
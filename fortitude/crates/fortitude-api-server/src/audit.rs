@@ -0,0 +1,164 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Audit log of operations that modify stored research
+// Keeps recent entries in memory and optionally appends them to a JSON lines file
+
+use crate::config::AuditConfig;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tokio::io::AsyncWriteExt;
+use tracing::{error, info};
+use uuid::Uuid;
+
+/// Whether an audited operation took effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    Success,
+    Failure,
+}
+
+/// One audited operation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    /// Token subject that performed the operation
+    pub actor: String,
+    /// Operation name, e.g. `research.bulk_update`
+    pub action: String,
+    pub outcome: AuditOutcome,
+    /// Operation-specific parameters and results
+    pub details: serde_json::Value,
+}
+
+/// Shared audit log
+#[derive(Debug, Clone)]
+pub struct AuditLog {
+    entries: Arc<Mutex<VecDeque<AuditEntry>>>,
+    max_entries: usize,
+    log_path: Option<PathBuf>,
+}
+
+impl Default for AuditLog {
+    fn default() -> Self {
+        Self::new(&AuditConfig::default())
+    }
+}
+
+impl AuditLog {
+    pub fn new(config: &AuditConfig) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(VecDeque::new())),
+            max_entries: config.max_entries.max(1),
+            log_path: config.log_path.as_ref().map(PathBuf::from),
+        }
+    }
+
+    /// Record an operation
+    ///
+    /// A failure to append to the log file is logged and does not fail the
+    /// operation; the entry is still kept in memory.
+    pub async fn record(
+        &self,
+        actor: &str,
+        action: &str,
+        outcome: AuditOutcome,
+        details: serde_json::Value,
+    ) -> AuditEntry {
+        let entry = AuditEntry {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            actor: actor.to_string(),
+            action: action.to_string(),
+            outcome,
+            details,
+        };
+        info!(
+            target: "audit",
+            "{} by {}: {:?} ({})", entry.action, entry.actor, entry.outcome, entry.id
+        );
+
+        {
+            let mut entries = self.entries.lock().unwrap();
+            entries.push_back(entry.clone());
+            while entries.len() > self.max_entries {
+                entries.pop_front();
+            }
+        }
+
+        if let Some(path) = &self.log_path {
+            if let Err(e) = append_line(path, &entry).await {
+                error!("Failed to write audit entry to {}: {}", path.display(), e);
+            }
+        }
+        entry
+    }
+
+    /// Most recent entries, newest first
+    pub fn recent(&self, limit: usize) -> Vec<AuditEntry> {
+        let entries = self.entries.lock().unwrap();
+        entries.iter().rev().take(limit).cloned().collect()
+    }
+}
+
+async fn append_line(path: &PathBuf, entry: &AuditEntry) -> std::io::Result<()> {
+    let mut line = serde_json::to_vec(entry)?;
+    line.push(b'\n');
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    file.write_all(&line).await?;
+    // tokio completes writes in the background; flush so the entry is on disk on return
+    file.flush().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_audit_log_keeps_recent_entries_and_appends_to_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let log = AuditLog::new(&AuditConfig {
+            log_path: Some(path.to_string_lossy().to_string()),
+            max_entries: 2,
+        });
+
+        for n in 0..3 {
+            log.record("alice", "test.op", AuditOutcome::Success, json!({ "n": n }))
+                .await;
+        }
+
+        let recent = log.recent(10);
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].details["n"], 2);
+
+        let lines: Vec<AuditEntry> = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].actor, "alice");
+    }
+}
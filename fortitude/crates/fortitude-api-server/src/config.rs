@@ -17,6 +17,8 @@
 // Reuses patterns from MCP server with HTTP-specific extensions
 
 use anyhow::{anyhow, Result};
+use fortitude::learning::DEFAULT_LEARNING_STORE_PATH;
+use fortitude_core::connectors::{ConflictPolicy, ConfluenceConfig, NotionConfig, SyncFilter};
 use serde::{Deserialize, Serialize};
 use std::env;
 use validator::Validate;
@@ -60,6 +62,59 @@ pub struct ApiServerConfig {
 
     /// Feature flags
     pub features: std::collections::HashMap<String, bool>,
+
+    /// API versioning and deprecation policy
+    #[serde(default)]
+    pub versioning: VersioningConfig,
+
+    /// Background maintenance scheduler
+    #[serde(default)]
+    pub maintenance: MaintenanceConfig,
+
+    /// Per-key token and monthly budget quotas
+    #[serde(default)]
+    pub quota: QuotaConfig,
+
+    /// Audit log of administrative and curation operations
+    #[serde(default)]
+    pub audit: AuditConfig,
+
+    /// Per-result feedback tokens for embedded feedback widgets
+    #[serde(default)]
+    #[validate(nested)]
+    pub feedback: FeedbackTokenConfig,
+
+    /// Per-key preference profiles applied as research defaults
+    #[serde(default)]
+    pub preferences: PreferencesConfig,
+
+    /// Replay of responses to research POSTs repeated with an `Idempotency-Key`
+    #[serde(default)]
+    #[validate(nested)]
+    pub idempotency: IdempotencyConfig,
+
+    /// Role-based API keys accepted alongside JWTs
+    #[serde(default)]
+    pub api_keys: ApiKeysConfig,
+
+    /// Mirroring of stored research into Notion and Confluence
+    #[serde(default)]
+    pub wiki_sync: WikiSyncConfig,
+
+    /// Distributed trace export
+    #[serde(default)]
+    #[validate(nested)]
+    pub telemetry: TelemetryConfig,
+
+    /// Graceful shutdown on SIGINT/SIGTERM
+    #[serde(default)]
+    #[validate(nested)]
+    pub shutdown: ShutdownConfig,
+
+    /// Components `/health/ready` requires before reporting ready
+    #[serde(default)]
+    #[validate(nested)]
+    pub readiness: ReadinessConfig,
 }
 
 /// Authentication configuration
@@ -83,15 +138,28 @@ pub struct AuthConfig {
 
 /// CORS configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CorsConfig {
-    /// Allowed origins (empty means all)
+    /// Allowed origins including scheme; `*` allows any origin (without credentials)
     pub allowed_origins: Vec<String>,
 
+    /// Allowed request methods
+    pub allowed_methods: Vec<String>,
+
+    /// Allowed request headers; `*` allows any (without credentials)
+    pub allowed_headers: Vec<String>,
+
+    /// Response headers readable by browser scripts
+    pub exposed_headers: Vec<String>,
+
     /// Allow credentials
     pub allow_credentials: bool,
 
     /// Maximum age for preflight requests
     pub max_age: u32,
+
+    /// Paths readable from any origin without credentials; a trailing `*` matches a prefix
+    pub public_paths: Vec<String>,
 }
 
 /// Rate limiting configuration
@@ -136,6 +204,283 @@ pub struct SecurityConfig {
     pub enable_security_headers: bool,
 }
 
+/// API versioning configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersioningConfig {
+    /// Mark v1 as deprecated (adds `Deprecation` and successor `Link` headers)
+    pub v1_deprecated: bool,
+
+    /// HTTP-date after which v1 will be removed (sent as the `Sunset` header)
+    pub v1_sunset: Option<String>,
+}
+
+/// Maintenance scheduler configuration
+///
+/// Schedules are cron expressions with a seconds field
+/// (`sec min hour day-of-month month day-of-week`); `None` disables a task.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MaintenanceConfig {
+    /// Run the scheduler inside the server process
+    pub enabled: bool,
+
+    /// Remove expired cache entries
+    pub cache_cleanup: Option<String>,
+
+    /// Drop index entries whose backing files are gone
+    pub gc: Option<String>,
+
+    /// Rebuild the search index
+    pub index_refresh: Option<String>,
+
+    /// Re-check stored research older than `stale_after_hours`
+    pub stale_revalidation: Option<String>,
+
+    /// Record cache and request metrics snapshots
+    pub metrics_snapshot: Option<String>,
+
+    /// Mirror stored research into the wikis configured in `wiki_sync`
+    pub wiki_sync: Option<String>,
+
+    /// Age in hours after which stored research is considered stale
+    pub stale_after_hours: u64,
+
+    /// Number of metrics snapshots kept in memory
+    pub snapshot_retention: usize,
+}
+
+/// Quota tiers assigned to API keys
+///
+/// Tiers set per-key request rates, overriding `auth.rate_limit`, and add
+/// token and monthly budget caps on research requests.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaConfig {
+    /// Tier for keys without an entry in `key_tiers`
+    pub default_tier: String,
+
+    /// Available tiers by name
+    pub tiers: std::collections::HashMap<String, QuotaTier>,
+
+    /// Tier assignments keyed by the token subject (`sub` claim)
+    pub key_tiers: std::collections::HashMap<String, String>,
+}
+
+/// Audit log configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuditConfig {
+    /// File that audit entries are appended to as JSON lines; in memory only when unset
+    pub log_path: Option<String>,
+
+    /// Number of recent entries kept in memory
+    pub max_entries: usize,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            log_path: None,
+            max_entries: 1000,
+        }
+    }
+}
+
+/// Feedback token configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(default)]
+pub struct FeedbackTokenConfig {
+    /// Hours a feedback token stays valid after the result is returned
+    #[validate(range(min = 1, max = 2160))] // 1 hour to 90 days
+    pub token_ttl_hours: u32,
+
+    /// Feedback submissions accepted per minute for each issuing key
+    #[validate(range(min = 1))]
+    pub max_submissions_per_minute: u32,
+
+    /// Learning store file submitted feedback is appended to
+    pub learning_store_path: String,
+}
+
+impl Default for FeedbackTokenConfig {
+    fn default() -> Self {
+        Self {
+            token_ttl_hours: 168, // 7 days
+            max_submissions_per_minute: 30,
+            learning_store_path: DEFAULT_LEARNING_STORE_PATH.to_string(),
+        }
+    }
+}
+
+/// Idempotency key settings
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(default)]
+pub struct IdempotencyConfig {
+    /// Seconds a response is replayed for repeats of its idempotency key
+    #[validate(range(min = 1, max = 604800))] // 1 second to 7 days
+    pub ttl_seconds: u64,
+
+    /// Number of keys kept in memory
+    pub max_entries: usize,
+}
+
+impl Default for IdempotencyConfig {
+    fn default() -> Self {
+        Self {
+            ttl_seconds: 86400, // 24 hours
+            max_entries: 10000,
+        }
+    }
+}
+
+/// Preference profile storage
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PreferencesConfig {
+    /// JSON file profiles are loaded from and saved to; in memory only when unset
+    pub store_path: Option<String>,
+}
+
+/// API key storage
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApiKeysConfig {
+    /// JSON file key records are loaded from and saved to; in memory only when unset
+    pub store_path: Option<String>,
+
+    /// Key granted the admin role at startup so the first keys can be created
+    pub bootstrap_admin_key: Option<String>,
+}
+
+/// OpenTelemetry trace export
+///
+/// Spans are exported over OTLP/HTTP when an endpoint is set and the server
+/// is built with the `otel` feature.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(default)]
+pub struct TelemetryConfig {
+    /// OTLP collector base URL, e.g. `http://localhost:4318`
+    pub otlp_endpoint: Option<String>,
+
+    /// Service name reported with every span
+    pub service_name: String,
+
+    /// Fraction of new traces sampled (0.0-1.0); requests with a sampled parent are always traced
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub sample_ratio: f64,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            otlp_endpoint: None,
+            service_name: "fortitude-api-server".to_string(),
+            sample_ratio: 1.0,
+        }
+    }
+}
+
+/// Graceful shutdown configuration
+///
+/// On SIGINT/SIGTERM `/health` reports `draining` for `health_grace_seconds`
+/// while connections are still accepted, so load balancers stop routing new
+/// traffic; the listener then closes and in-flight requests get up to
+/// `drain_timeout_seconds` to finish.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(default)]
+pub struct ShutdownConfig {
+    /// Seconds in-flight requests may take to finish after the listener closes
+    #[validate(range(min = 1, max = 600))]
+    pub drain_timeout_seconds: u64,
+
+    /// Seconds `/health` reports draining before the listener closes
+    #[validate(range(max = 120))]
+    pub health_grace_seconds: u64,
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        Self {
+            drain_timeout_seconds: 30,
+            health_grace_seconds: 0,
+        }
+    }
+}
+
+/// Readiness probe criteria
+///
+/// `/health/live` only reports that the process is serving requests.
+/// `/health/ready` checks each component and fails when a required one is
+/// down; components that are not required are still reported.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(default)]
+pub struct ReadinessConfig {
+    /// Result storage must answer a stats query
+    pub require_storage: bool,
+
+    /// At least one research provider must pass its health check
+    pub require_provider: bool,
+
+    /// A vector database endpoint must be healthy
+    pub require_vector_db: bool,
+
+    /// Milliseconds each component check may take before counting as down
+    #[validate(range(min = 100, max = 30000))]
+    pub check_timeout_ms: u64,
+
+    /// Seconds a readiness result is reused, so frequent probes do not call
+    /// providers on every request
+    #[validate(range(max = 300))]
+    pub cache_seconds: u64,
+}
+
+impl Default for ReadinessConfig {
+    fn default() -> Self {
+        Self {
+            require_storage: true,
+            require_provider: true,
+            require_vector_db: false,
+            check_timeout_ms: 2000,
+            cache_seconds: 5,
+        }
+    }
+}
+
+/// Wiki sync targets and selection
+///
+/// The sync runs as the `wiki_sync` maintenance task; each connector is
+/// enabled by configuring it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WikiSyncConfig {
+    /// JSON file mapping cache keys to the pages written for them
+    pub state_path: String,
+
+    /// Results to sync by tag and research type
+    pub filter: SyncFilter,
+
+    /// What to do with pages edited in the wiki since the last sync
+    pub conflict_policy: ConflictPolicy,
+
+    pub notion: Option<NotionConfig>,
+
+    pub confluence: Option<ConfluenceConfig>,
+}
+
+/// Limits of a single quota tier; `None` means unlimited
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaTier {
+    /// Requests a key may make per rate-limit window; `auth.rate_limit` when unset
+    pub max_requests_per_window: Option<u32>,
+
+    /// Tokens a key may use per rate-limit window
+    pub max_tokens_per_window: Option<u64>,
+
+    /// Estimated provider spend a key may accrue per calendar month
+    pub monthly_budget_usd: Option<f64>,
+}
+
 impl Default for ApiServerConfig {
     fn default() -> Self {
         let mut features = std::collections::HashMap::new();
@@ -152,6 +497,18 @@ impl Default for ApiServerConfig {
             performance: PerformanceConfig::default(),
             security: SecurityConfig::default(),
             features,
+            versioning: VersioningConfig::default(),
+            maintenance: MaintenanceConfig::default(),
+            quota: QuotaConfig::default(),
+            audit: AuditConfig::default(),
+            feedback: FeedbackTokenConfig::default(),
+            preferences: PreferencesConfig::default(),
+            idempotency: IdempotencyConfig::default(),
+            api_keys: ApiKeysConfig::default(),
+            wiki_sync: WikiSyncConfig::default(),
+            telemetry: TelemetryConfig::default(),
+            shutdown: ShutdownConfig::default(),
+            readiness: ReadinessConfig::default(),
         }
     }
 }
@@ -172,8 +529,19 @@ impl Default for CorsConfig {
     fn default() -> Self {
         Self {
             allowed_origins: vec!["http://localhost:3000".to_string()],
+            allowed_methods: ["GET", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"]
+                .map(String::from)
+                .to_vec(),
+            allowed_headers: ["authorization", "content-type", "x-api-key", "x-request-id"]
+                .map(String::from)
+                .to_vec(),
+            exposed_headers: vec!["x-request-id".to_string()],
             allow_credentials: true,
             max_age: 86400, // 24 hours
+            public_paths: vec![
+                "/health".to_string(),
+                crate::feedback::FEEDBACK_TOKEN_PATH.to_string(),
+            ],
         }
     }
 }
@@ -207,6 +575,136 @@ impl Default for SecurityConfig {
     }
 }
 
+impl Default for VersioningConfig {
+    fn default() -> Self {
+        Self {
+            v1_deprecated: true,
+            v1_sunset: None,
+        }
+    }
+}
+
+impl Default for MaintenanceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            cache_cleanup: Some("0 0 * * * *".to_string()), // hourly
+            gc: Some("0 30 3 * * *".to_string()),           // daily at 03:30
+            index_refresh: Some("0 */15 * * * *".to_string()),
+            stale_revalidation: Some("0 0 4 * * *".to_string()),
+            metrics_snapshot: Some("0 */5 * * * *".to_string()),
+            wiki_sync: None,         // opt-in, needs a configured connector
+            stale_after_hours: 168,  // 7 days
+            snapshot_retention: 288, // 24 hours at 5 minute intervals
+        }
+    }
+}
+
+impl Default for WikiSyncConfig {
+    fn default() -> Self {
+        Self {
+            state_path: ".fortitude/wiki-sync.json".to_string(),
+            filter: SyncFilter::default(),
+            conflict_policy: ConflictPolicy::default(),
+            notion: None,
+            confluence: None,
+        }
+    }
+}
+
+impl Default for QuotaConfig {
+    fn default() -> Self {
+        Self {
+            default_tier: "standard".to_string(),
+            tiers: std::collections::HashMap::from([(
+                "standard".to_string(),
+                QuotaTier::default(),
+            )]),
+            key_tiers: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl QuotaConfig {
+    /// Name and limits of the tier assigned to a key
+    pub fn tier_for(&self, key: &str) -> (&str, QuotaTier) {
+        let name = self
+            .key_tiers
+            .get(key)
+            .map(String::as_str)
+            .unwrap_or(&self.default_tier);
+        (name, self.tiers.get(name).cloned().unwrap_or_default())
+    }
+
+    /// Check that the default tier and every key assignment name a defined tier
+    pub fn validate_tiers(&self) -> Result<()> {
+        if !self.tiers.contains_key(&self.default_tier) {
+            return Err(anyhow!(
+                "Default quota tier '{}' is not defined",
+                self.default_tier
+            ));
+        }
+        for (key, tier) in &self.key_tiers {
+            if !self.tiers.contains_key(tier) {
+                return Err(anyhow!(
+                    "Quota tier '{tier}' assigned to key {key} is not defined"
+                ));
+            }
+        }
+        for (name, tier) in &self.tiers {
+            if tier
+                .monthly_budget_usd
+                .is_some_and(|b| !b.is_finite() || b < 0.0)
+            {
+                return Err(anyhow!("Invalid monthly budget for quota tier '{name}'"));
+            }
+        }
+        Ok(())
+    }
+
+    fn default_tier_mut(&mut self) -> &mut QuotaTier {
+        self.tiers.entry(self.default_tier.clone()).or_default()
+    }
+}
+
+impl MaintenanceConfig {
+    /// Configured schedule for each task, keyed by task name
+    pub fn schedules(&self) -> [(&'static str, Option<&str>); 6] {
+        [
+            ("cache_cleanup", self.cache_cleanup.as_deref()),
+            ("gc", self.gc.as_deref()),
+            ("index_refresh", self.index_refresh.as_deref()),
+            ("stale_revalidation", self.stale_revalidation.as_deref()),
+            ("metrics_snapshot", self.metrics_snapshot.as_deref()),
+            ("wiki_sync", self.wiki_sync.as_deref()),
+        ]
+    }
+
+    fn schedule_mut(&mut self, task: &str) -> Option<&mut Option<String>> {
+        match task {
+            "cache_cleanup" => Some(&mut self.cache_cleanup),
+            "gc" => Some(&mut self.gc),
+            "index_refresh" => Some(&mut self.index_refresh),
+            "stale_revalidation" => Some(&mut self.stale_revalidation),
+            "metrics_snapshot" => Some(&mut self.metrics_snapshot),
+            "wiki_sync" => Some(&mut self.wiki_sync),
+            _ => None,
+        }
+    }
+
+    /// Check that every enabled schedule is a valid cron expression
+    pub fn validate_schedules(&self) -> Result<()> {
+        for (task, schedule) in self.schedules() {
+            if let Some(expression) = schedule {
+                expression
+                    .parse::<cron::Schedule>()
+                    .map_err(|e| anyhow!("Invalid schedule for maintenance task {task}: {e}"))?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl ApiServerConfig {
     /// Load configuration from environment variables
     pub fn from_env() -> Result<Self> {
@@ -269,6 +767,22 @@ impl ApiServerConfig {
             config.cors.allow_credentials = credentials.to_lowercase() == "true";
         }
 
+        if let Ok(methods) = env::var("FORTITUDE_API_CORS_METHODS") {
+            config.cors.allowed_methods =
+                methods.split(',').map(|s| s.trim().to_string()).collect();
+        }
+
+        if let Ok(headers) = env::var("FORTITUDE_API_CORS_HEADERS") {
+            config.cors.allowed_headers =
+                headers.split(',').map(|s| s.trim().to_string()).collect();
+        }
+
+        if let Ok(max_age) = env::var("FORTITUDE_API_CORS_MAX_AGE") {
+            config.cors.max_age = max_age
+                .parse()
+                .map_err(|_| anyhow!("Invalid FORTITUDE_API_CORS_MAX_AGE"))?;
+        }
+
         // Performance settings
         if let Ok(buffer_size) = env::var("FORTITUDE_API_REQUEST_BUFFER_SIZE") {
             config.performance.request_buffer_size = buffer_size
@@ -289,6 +803,251 @@ impl ApiServerConfig {
                 .map_err(|_| anyhow!("Invalid FORTITUDE_API_MAX_REQUEST_BODY_SIZE"))?;
         }
 
+        // Versioning settings
+        if let Ok(deprecated) = env::var("FORTITUDE_API_V1_DEPRECATED") {
+            config.versioning.v1_deprecated = deprecated.to_lowercase() == "true";
+        }
+
+        if let Ok(sunset) = env::var("FORTITUDE_API_V1_SUNSET") {
+            config.versioning.v1_sunset = Some(sunset);
+        }
+
+        // Maintenance settings
+        if let Ok(enabled) = env::var("FORTITUDE_API_MAINTENANCE_ENABLED") {
+            config.maintenance.enabled = enabled.to_lowercase() == "true";
+        }
+
+        for (task, _) in MaintenanceConfig::default().schedules() {
+            let var = format!("FORTITUDE_API_MAINTENANCE_{}", task.to_uppercase());
+            if let Ok(schedule) = env::var(&var) {
+                if let Some(slot) = config.maintenance.schedule_mut(task) {
+                    *slot = match schedule.trim() {
+                        "" | "off" | "disabled" => None,
+                        expression => Some(expression.to_string()),
+                    };
+                }
+            }
+        }
+
+        if let Ok(hours) = env::var("FORTITUDE_API_MAINTENANCE_STALE_AFTER_HOURS") {
+            config.maintenance.stale_after_hours = hours
+                .parse()
+                .map_err(|_| anyhow!("Invalid FORTITUDE_API_MAINTENANCE_STALE_AFTER_HOURS"))?;
+        }
+
+        // Quota settings
+        if let Ok(tier) = env::var("FORTITUDE_API_QUOTA_DEFAULT_TIER") {
+            config.quota.default_tier = tier;
+        }
+
+        if let Ok(requests) = env::var("FORTITUDE_API_QUOTA_REQUESTS_PER_WINDOW") {
+            config.quota.default_tier_mut().max_requests_per_window = Some(
+                requests
+                    .parse()
+                    .map_err(|_| anyhow!("Invalid FORTITUDE_API_QUOTA_REQUESTS_PER_WINDOW"))?,
+            );
+        }
+
+        if let Ok(tokens) = env::var("FORTITUDE_API_QUOTA_TOKENS_PER_WINDOW") {
+            config.quota.default_tier_mut().max_tokens_per_window = Some(
+                tokens
+                    .parse()
+                    .map_err(|_| anyhow!("Invalid FORTITUDE_API_QUOTA_TOKENS_PER_WINDOW"))?,
+            );
+        }
+
+        if let Ok(budget) = env::var("FORTITUDE_API_QUOTA_MONTHLY_BUDGET_USD") {
+            config.quota.default_tier_mut().monthly_budget_usd = Some(
+                budget
+                    .parse()
+                    .map_err(|_| anyhow!("Invalid FORTITUDE_API_QUOTA_MONTHLY_BUDGET_USD"))?,
+            );
+        }
+
+        // Audit settings
+        if let Ok(path) = env::var("FORTITUDE_API_AUDIT_LOG_PATH") {
+            config.audit.log_path = Some(path);
+        }
+
+        // Feedback token settings
+        if let Ok(hours) = env::var("FORTITUDE_API_FEEDBACK_TOKEN_TTL_HOURS") {
+            config.feedback.token_ttl_hours = hours
+                .parse()
+                .map_err(|_| anyhow!("Invalid FORTITUDE_API_FEEDBACK_TOKEN_TTL_HOURS"))?;
+        }
+
+        if let Ok(limit) = env::var("FORTITUDE_API_FEEDBACK_RATE_LIMIT_PER_MINUTE") {
+            config.feedback.max_submissions_per_minute = limit
+                .parse()
+                .map_err(|_| anyhow!("Invalid FORTITUDE_API_FEEDBACK_RATE_LIMIT_PER_MINUTE"))?;
+        }
+
+        if let Ok(path) = env::var("FORTITUDE_API_LEARNING_STORE_PATH") {
+            config.feedback.learning_store_path = path;
+        }
+
+        // Idempotency key settings
+        if let Ok(seconds) = env::var("FORTITUDE_API_IDEMPOTENCY_TTL_SECONDS") {
+            config.idempotency.ttl_seconds = seconds
+                .parse()
+                .map_err(|_| anyhow!("Invalid FORTITUDE_API_IDEMPOTENCY_TTL_SECONDS"))?;
+        }
+
+        // Preference profile settings
+        if let Ok(path) = env::var("FORTITUDE_API_PREFERENCES_PATH") {
+            config.preferences.store_path = Some(path);
+        }
+
+        // API key settings
+        if let Ok(path) = env::var("FORTITUDE_API_KEYS_PATH") {
+            config.api_keys.store_path = Some(path);
+        }
+
+        if let Ok(key) = env::var("FORTITUDE_API_BOOTSTRAP_ADMIN_KEY") {
+            config.api_keys.bootstrap_admin_key = Some(key);
+        }
+
+        // Telemetry settings, using the standard OpenTelemetry variables
+        if let Ok(endpoint) = env::var("OTEL_EXPORTER_OTLP_ENDPOINT") {
+            config.telemetry.otlp_endpoint = Some(endpoint);
+        }
+
+        if let Ok(name) = env::var("OTEL_SERVICE_NAME") {
+            config.telemetry.service_name = name;
+        }
+
+        if let Ok(ratio) = env::var("FORTITUDE_API_TRACE_SAMPLE_RATIO") {
+            config.telemetry.sample_ratio = ratio
+                .parse()
+                .map_err(|_| anyhow!("Invalid FORTITUDE_API_TRACE_SAMPLE_RATIO"))?;
+        }
+
+        // Graceful shutdown settings
+        if let Ok(seconds) = env::var("FORTITUDE_API_SHUTDOWN_DRAIN_SECONDS") {
+            config.shutdown.drain_timeout_seconds = seconds
+                .parse()
+                .map_err(|_| anyhow!("Invalid FORTITUDE_API_SHUTDOWN_DRAIN_SECONDS"))?;
+        }
+
+        if let Ok(seconds) = env::var("FORTITUDE_API_SHUTDOWN_HEALTH_GRACE_SECONDS") {
+            config.shutdown.health_grace_seconds = seconds
+                .parse()
+                .map_err(|_| anyhow!("Invalid FORTITUDE_API_SHUTDOWN_HEALTH_GRACE_SECONDS"))?;
+        }
+
+        // Readiness probe criteria
+        if let Ok(required) = env::var("FORTITUDE_API_READY_REQUIRE_STORAGE") {
+            config.readiness.require_storage = required.to_lowercase() == "true";
+        }
+
+        if let Ok(required) = env::var("FORTITUDE_API_READY_REQUIRE_PROVIDER") {
+            config.readiness.require_provider = required.to_lowercase() == "true";
+        }
+
+        if let Ok(required) = env::var("FORTITUDE_API_READY_REQUIRE_VECTOR_DB") {
+            config.readiness.require_vector_db = required.to_lowercase() == "true";
+        }
+
+        if let Ok(timeout) = env::var("FORTITUDE_API_READY_CHECK_TIMEOUT_MS") {
+            config.readiness.check_timeout_ms = timeout
+                .parse()
+                .map_err(|_| anyhow!("Invalid FORTITUDE_API_READY_CHECK_TIMEOUT_MS"))?;
+        }
+
+        if let Ok(seconds) = env::var("FORTITUDE_API_READY_CACHE_SECONDS") {
+            config.readiness.cache_seconds = seconds
+                .parse()
+                .map_err(|_| anyhow!("Invalid FORTITUDE_API_READY_CACHE_SECONDS"))?;
+        }
+
+        // Wiki sync settings
+        if let Ok(path) = env::var("FORTITUDE_API_WIKI_SYNC_STATE_PATH") {
+            config.wiki_sync.state_path = path;
+        }
+
+        if let Ok(tags) = env::var("FORTITUDE_API_WIKI_SYNC_TAGS") {
+            config.wiki_sync.filter.tags = tags
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+
+        if let Ok(types) = env::var("FORTITUDE_API_WIKI_SYNC_TYPES") {
+            config.wiki_sync.filter.research_types = types
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(|t| t.parse())
+                .collect::<Result<_, _>>()
+                .map_err(|_| anyhow!("Invalid FORTITUDE_API_WIKI_SYNC_TYPES"))?;
+        }
+
+        if let Ok(policy) = env::var("FORTITUDE_API_WIKI_SYNC_CONFLICT_POLICY") {
+            config.wiki_sync.conflict_policy = ConflictPolicy::parse(&policy)
+                .ok_or_else(|| anyhow!("Invalid FORTITUDE_API_WIKI_SYNC_CONFLICT_POLICY"))?;
+        }
+
+        if let Ok(token) = env::var("FORTITUDE_API_NOTION_TOKEN") {
+            config
+                .wiki_sync
+                .notion
+                .get_or_insert_with(Default::default)
+                .token = token;
+        }
+
+        if let Ok(database_id) = env::var("FORTITUDE_API_NOTION_DATABASE_ID") {
+            config
+                .wiki_sync
+                .notion
+                .get_or_insert_with(Default::default)
+                .database_id = database_id;
+        }
+
+        if let Ok(base_url) = env::var("FORTITUDE_API_CONFLUENCE_URL") {
+            config
+                .wiki_sync
+                .confluence
+                .get_or_insert_with(Default::default)
+                .base_url = base_url;
+        }
+
+        if let Ok(space_key) = env::var("FORTITUDE_API_CONFLUENCE_SPACE") {
+            config
+                .wiki_sync
+                .confluence
+                .get_or_insert_with(Default::default)
+                .space_key = space_key;
+        }
+
+        if let Ok(parent) = env::var("FORTITUDE_API_CONFLUENCE_PARENT_PAGE_ID") {
+            config
+                .wiki_sync
+                .confluence
+                .get_or_insert_with(Default::default)
+                .parent_page_id = Some(parent);
+        }
+
+        if let Ok(username) = env::var("FORTITUDE_API_CONFLUENCE_USERNAME") {
+            config
+                .wiki_sync
+                .confluence
+                .get_or_insert_with(Default::default)
+                .username = username;
+        }
+
+        if let Ok(token) = env::var("FORTITUDE_API_CONFLUENCE_TOKEN") {
+            config
+                .wiki_sync
+                .confluence
+                .get_or_insert_with(Default::default)
+                .api_token = token;
+        }
+
+        config.maintenance.validate_schedules()?;
+        config.quota.validate_tiers()?;
+        crate::middleware::cors::validate_cors_config(&config.cors)?;
+
         // Validate configuration
         config
             .validate()
@@ -350,6 +1109,19 @@ mod tests {
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_trace_sample_ratio_range() {
+        let mut config = ApiServerConfig::default();
+        assert_eq!(config.telemetry.sample_ratio, 1.0);
+        assert!(config.telemetry.otlp_endpoint.is_none());
+
+        config.telemetry.sample_ratio = 1.5;
+        assert!(config.validate().is_err());
+
+        config.telemetry.sample_ratio = 0.25;
+        assert!(config.validate().is_ok());
+    }
+
     #[test]
     fn test_invalid_jwt_secret_length() {
         let mut config = ApiServerConfig::default();
@@ -357,6 +1129,44 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_maintenance_schedule_validation() {
+        let mut maintenance = MaintenanceConfig::default();
+        assert!(maintenance.validate_schedules().is_ok());
+
+        maintenance.gc = None;
+        assert!(maintenance.validate_schedules().is_ok());
+
+        maintenance.index_refresh = Some("every five minutes".to_string());
+        let err = maintenance.validate_schedules().unwrap_err();
+        assert!(err.to_string().contains("index_refresh"));
+    }
+
+    #[test]
+    fn test_quota_tier_validation() {
+        let mut quota = QuotaConfig::default();
+        assert!(quota.validate_tiers().is_ok());
+        assert_eq!(quota.tier_for("alice").0, "standard");
+
+        quota
+            .key_tiers
+            .insert("alice".to_string(), "pro".to_string());
+        assert!(quota.validate_tiers().is_err());
+
+        quota.tiers.insert(
+            "pro".to_string(),
+            QuotaTier {
+                max_requests_per_window: None,
+                max_tokens_per_window: Some(1000),
+                monthly_budget_usd: Some(25.0),
+            },
+        );
+        assert!(quota.validate_tiers().is_ok());
+        let (name, tier) = quota.tier_for("alice");
+        assert_eq!(name, "pro");
+        assert_eq!(tier.max_tokens_per_window, Some(1000));
+    }
+
     #[test]
     fn test_bind_address() {
         let config = ApiServerConfig::default();
@@ -0,0 +1,345 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Signed, expiring per-result feedback tokens for embedded feedback widgets
+// Tokens tie anonymous feedback to a result and the issuing key, and are rate limited per key.
+// Submitted feedback is appended to the learning store.
+
+use crate::config::{ApiServerConfig, FeedbackTokenConfig};
+use crate::models::errors::ApiError;
+use crate::preferences::PreferenceStore;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use fortitude::learning::{FileLearningStorage, LearningStorageService, UserFeedback};
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+use tokio::sync::OnceCell;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+/// Issuer of feedback tokens; differs from API tokens so neither is accepted as the other
+pub const FEEDBACK_TOKEN_ISSUER: &str = "fortitude-feedback";
+
+/// Endpoint feedback tokens are submitted to
+pub const FEEDBACK_TOKEN_PATH: &str = "/api/v1/feedback/token";
+
+/// Claims carried by a feedback token
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeedbackClaims {
+    /// Research result the feedback is about
+    pub sub: String,
+    /// Subject of the API key the result was issued to
+    pub key: String,
+    /// Permissions of that key when the token was issued
+    pub scope: Vec<String>,
+    /// Expiration time (timestamp)
+    pub exp: i64,
+    /// Issued at time (timestamp)
+    pub iat: i64,
+    /// Issuer
+    pub iss: String,
+}
+
+/// Feedback submitted with a token
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenFeedback {
+    pub id: Uuid,
+    pub result_id: String,
+    pub key: String,
+    pub scope: Vec<String>,
+    pub helpful: bool,
+    pub rating: Option<u8>,
+    pub comment: Option<String>,
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// A freshly issued token and when it stops being accepted
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssuedFeedbackToken {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Issues and redeems feedback tokens, recording the submitted feedback in
+/// the learning store
+#[derive(Clone)]
+pub struct FeedbackTokens {
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    config: FeedbackTokenConfig,
+    submissions: Arc<RwLock<HashMap<String, VecDeque<DateTime<Utc>>>>>,
+    /// Opened from `config.learning_store_path` on the first submission
+    learning_storage: Arc<OnceCell<Arc<dyn LearningStorageService>>>,
+    preferences: PreferenceStore,
+}
+
+impl std::fmt::Debug for FeedbackTokens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FeedbackTokens")
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl Default for FeedbackTokens {
+    fn default() -> Self {
+        Self::new(&ApiServerConfig::default())
+    }
+}
+
+impl FeedbackTokens {
+    /// Sign tokens with the server's JWT secret
+    pub fn new(config: &ApiServerConfig) -> Self {
+        let secret = config.auth.jwt_secret.as_bytes();
+        Self {
+            encoding_key: EncodingKey::from_secret(secret),
+            decoding_key: DecodingKey::from_secret(secret),
+            config: config.feedback.clone(),
+            submissions: Arc::new(RwLock::new(HashMap::new())),
+            learning_storage: Arc::new(OnceCell::new()),
+            preferences: PreferenceStore::default(),
+        }
+    }
+
+    /// Record submitted feedback in the given learning store instead of the
+    /// configured file
+    pub fn with_learning_storage(
+        mut self,
+        learning_storage: Arc<dyn LearningStorageService>,
+    ) -> Self {
+        self.learning_storage = Arc::new(OnceCell::new_with(Some(learning_storage)));
+        self
+    }
+
+    /// Share preference profiles that feedback is learned into
+    pub fn with_preferences(mut self, preferences: PreferenceStore) -> Self {
+        self.preferences = preferences;
+        self
+    }
+
+    /// Preference profiles of the keys feedback tokens are issued to
+    pub fn preferences(&self) -> &PreferenceStore {
+        &self.preferences
+    }
+
+    /// Issue a token for feedback on `result_id`, on behalf of `key`
+    pub fn issue(
+        &self,
+        result_id: &str,
+        key: &str,
+        scope: &[String],
+    ) -> Result<IssuedFeedbackToken, ApiError> {
+        let now = Utc::now();
+        let expires_at = now + Duration::hours(self.config.token_ttl_hours as i64);
+        let claims = FeedbackClaims {
+            sub: result_id.to_string(),
+            key: key.to_string(),
+            scope: scope.to_vec(),
+            exp: expires_at.timestamp(),
+            iat: now.timestamp(),
+            iss: FEEDBACK_TOKEN_ISSUER.to_string(),
+        };
+        let token = encode(&Header::default(), &claims, &self.encoding_key).map_err(|e| {
+            ApiError::InternalError {
+                message: format!("Failed to sign feedback token: {e}"),
+            }
+        })?;
+        Ok(IssuedFeedbackToken {
+            token,
+            // Match the whole-second expiry in the token
+            expires_at: Utc
+                .timestamp_opt(claims.exp, 0)
+                .single()
+                .unwrap_or(expires_at),
+        })
+    }
+
+    /// Check a token's signature, issuer and expiry
+    pub fn verify(&self, token: &str) -> Result<FeedbackClaims, ApiError> {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_issuer(&[FEEDBACK_TOKEN_ISSUER]);
+        validation.leeway = 0;
+        decode::<FeedbackClaims>(token, &self.decoding_key, &validation)
+            .map(|data| data.claims)
+            .map_err(|e| {
+                debug!("Rejected feedback token: {}", e);
+                ApiError::Unauthorized
+            })
+    }
+
+    /// Record feedback for the result a token was issued for
+    ///
+    /// Submissions are limited per issuing key, so anonymous visitors of one
+    /// embedding site share that site's allowance.
+    pub async fn submit(
+        &self,
+        token: &str,
+        helpful: bool,
+        rating: Option<u8>,
+        comment: Option<String>,
+    ) -> Result<TokenFeedback, ApiError> {
+        let claims = self.verify(token)?;
+        self.check_rate_limit(&claims.key)?;
+
+        let feedback = TokenFeedback {
+            id: Uuid::new_v4(),
+            result_id: claims.sub,
+            key: claims.key,
+            scope: claims.scope,
+            helpful,
+            rating,
+            comment,
+            submitted_at: Utc::now(),
+        };
+        self.learning_storage()
+            .await?
+            .store_feedback(&learning_feedback(&feedback))
+            .await
+            .map_err(|e| ApiError::InternalError {
+                message: format!("Failed to record feedback: {e}"),
+            })?;
+        Ok(feedback)
+    }
+
+    async fn learning_storage(&self) -> Result<&Arc<dyn LearningStorageService>, ApiError> {
+        self.learning_storage
+            .get_or_try_init(|| async {
+                let storage = FileLearningStorage::open(&self.config.learning_store_path)
+                    .await
+                    .map_err(|e| ApiError::InternalError {
+                        message: format!("Failed to open learning store: {e}"),
+                    })?;
+                Ok(Arc::new(storage) as Arc<dyn LearningStorageService>)
+            })
+            .await
+    }
+
+    fn check_rate_limit(&self, key: &str) -> Result<(), ApiError> {
+        let now = Utc::now();
+        let window_start = now - Duration::minutes(1);
+        let mut submissions = self.submissions.write().unwrap();
+        let recent = submissions.entry(key.to_string()).or_default();
+        while recent.front().is_some_and(|at| *at <= window_start) {
+            recent.pop_front();
+        }
+        if recent.len() >= self.config.max_submissions_per_minute as usize {
+            warn!("Feedback token rate limit exceeded for key: {}", key);
+            return Err(ApiError::RateLimitExceeded);
+        }
+        recent.push_back(now);
+        Ok(())
+    }
+}
+
+/// Learning store entry for token feedback, attributed to the issuing key
+///
+/// Ratings of 1-5 map onto the store's 0.0-1.0 scale; unrated feedback scores
+/// 1.0 when helpful and 0.0 otherwise.
+fn learning_feedback(feedback: &TokenFeedback) -> UserFeedback {
+    let (feedback_type, score) = match feedback.rating {
+        Some(rating) => ("quality_rating", f64::from(rating.clamp(1, 5) - 1) / 4.0),
+        None => ("helpfulness", if feedback.helpful { 1.0 } else { 0.0 }),
+    };
+    let mut entry = UserFeedback::new(
+        feedback.key.clone(),
+        feedback.result_id.clone(),
+        feedback_type.to_string(),
+        Some(score),
+        feedback.comment.clone(),
+    )
+    .with_metadata("source".to_string(), "feedback_token".into())
+    .with_metadata("helpful".to_string(), feedback.helpful.into())
+    .with_metadata("scope".to_string(), feedback.scope.clone().into());
+    entry.id = feedback.id.to_string();
+    entry.timestamp = feedback.submitted_at;
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_feedback_tokens_are_signed_scoped_and_rate_limited() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let store_path = temp_dir.path().join("learning_store.json");
+        let mut config = ApiServerConfig::default();
+        config.feedback.max_submissions_per_minute = 2;
+        config.feedback.learning_store_path = store_path.to_string_lossy().into_owned();
+        let tokens = FeedbackTokens::new(&config);
+
+        let issued = tokens
+            .issue("result-1", "portal", &["research:read".to_string()])
+            .unwrap();
+        assert!(issued.expires_at > Utc::now());
+        let claims = tokens.verify(&issued.token).unwrap();
+        assert_eq!(claims.sub, "result-1");
+        assert_eq!(claims.key, "portal");
+
+        let feedback = tokens
+            .submit(&issued.token, true, Some(5), Some("Spot on".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(feedback.result_id, "result-1");
+        assert_eq!(feedback.scope, vec!["research:read".to_string()]);
+        tokens
+            .submit(&issued.token, false, None, None)
+            .await
+            .unwrap();
+        assert!(matches!(
+            tokens.submit(&issued.token, true, None, None).await,
+            Err(ApiError::RateLimitExceeded)
+        ));
+
+        // Both submissions survive a restart in the learning store
+        let stored = FileLearningStorage::open(&store_path)
+            .await
+            .unwrap()
+            .all_feedback()
+            .await;
+        assert_eq!(stored.len(), 2);
+        assert_eq!(stored[0].id, feedback.id.to_string());
+        assert_eq!(stored[0].user_id, "portal");
+        assert_eq!(stored[0].content_id, "result-1");
+        assert_eq!(stored[0].feedback_type, "quality_rating");
+        assert_eq!(stored[0].score, Some(1.0));
+        assert_eq!(stored[0].text_feedback.as_deref(), Some("Spot on"));
+        assert_eq!(stored[1].feedback_type, "helpfulness");
+        assert_eq!(stored[1].score, Some(0.0));
+
+        // Tampered, foreign and expired tokens are rejected
+        let tampered = format!("{}x", issued.token);
+        assert!(matches!(
+            tokens.verify(&tampered),
+            Err(ApiError::Unauthorized)
+        ));
+        let mut other = ApiServerConfig::default();
+        other.auth.jwt_secret = "another-secret-that-is-at-least-32-characters".to_string();
+        let foreign = FeedbackTokens::new(&other)
+            .issue("result-1", "portal", &[])
+            .unwrap();
+        assert!(tokens.verify(&foreign.token).is_err());
+        let expired = FeedbackClaims {
+            sub: "result-1".to_string(),
+            key: "portal".to_string(),
+            scope: vec![],
+            exp: Utc::now().timestamp() - 10,
+            iat: Utc::now().timestamp() - 20,
+            iss: FEEDBACK_TOKEN_ISSUER.to_string(),
+        };
+        let expired = encode(&Header::default(), &expired, &tokens.encoding_key).unwrap();
+        assert!(tokens.verify(&expired).is_err());
+    }
+}
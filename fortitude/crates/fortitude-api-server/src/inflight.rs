@@ -0,0 +1,277 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Registry of requests currently being handled, for admin introspection
+// Tracks endpoint, pipeline stage and provider per request and lets admins cancel one
+
+use crate::models::errors::ApiError;
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tokio::sync::watch;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Paths that are not tracked: health probes and the introspection endpoint itself
+const UNTRACKED_PREFIXES: &[&str] = &[
+    "/health",
+    "/api/v1/admin/inflight",
+    "/api/v2/admin/inflight",
+];
+
+/// Point-in-time view of an in-flight request
+#[derive(Debug, Clone, PartialEq)]
+pub struct InflightSnapshot {
+    pub id: Uuid,
+    pub method: String,
+    pub endpoint: String,
+    pub stage: String,
+    pub provider: Option<String>,
+    pub user: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub elapsed_ms: u64,
+    pub cancel_requested: bool,
+}
+
+#[derive(Debug)]
+struct Tracked {
+    method: String,
+    endpoint: String,
+    stage: String,
+    provider: Option<String>,
+    user: Option<String>,
+    started_at: DateTime<Utc>,
+    cancel: watch::Sender<bool>,
+}
+
+/// Shared registry of in-flight requests
+#[derive(Debug, Clone, Default)]
+pub struct InflightRegistry {
+    requests: Arc<RwLock<HashMap<Uuid, Tracked>>>,
+}
+
+impl InflightRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start tracking a request; it is removed when the guard is dropped
+    pub fn register(&self, method: &str, endpoint: &str) -> InflightGuard {
+        let id = Uuid::new_v4();
+        let (cancel, cancelled) = watch::channel(false);
+        self.requests.write().unwrap().insert(
+            id,
+            Tracked {
+                method: method.to_string(),
+                endpoint: endpoint.to_string(),
+                stage: "received".to_string(),
+                provider: None,
+                user: None,
+                started_at: Utc::now(),
+                cancel,
+            },
+        );
+        InflightGuard {
+            handle: InflightHandle {
+                id,
+                registry: self.clone(),
+                cancelled,
+            },
+        }
+    }
+
+    /// In-flight requests, longest-running first
+    pub fn list(&self) -> Vec<InflightSnapshot> {
+        let now = Utc::now();
+        let mut snapshots: Vec<InflightSnapshot> = self
+            .requests
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(id, tracked)| InflightSnapshot {
+                id: *id,
+                method: tracked.method.clone(),
+                endpoint: tracked.endpoint.clone(),
+                stage: tracked.stage.clone(),
+                provider: tracked.provider.clone(),
+                user: tracked.user.clone(),
+                started_at: tracked.started_at,
+                elapsed_ms: (now - tracked.started_at).num_milliseconds().max(0) as u64,
+                cancel_requested: *tracked.cancel.borrow(),
+            })
+            .collect();
+        snapshots.sort_by_key(|s| s.started_at);
+        snapshots
+    }
+
+    /// Ask an in-flight request to stop; returns false if it is not running
+    pub fn cancel(&self, id: Uuid) -> bool {
+        match self.requests.read().unwrap().get(&id) {
+            Some(tracked) => {
+                tracked.cancel.send_replace(true);
+                info!("Cancellation requested for in-flight request {}", id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.requests.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn update(&self, id: Uuid, f: impl FnOnce(&mut Tracked)) {
+        if let Some(tracked) = self.requests.write().unwrap().get_mut(&id) {
+            f(tracked);
+        }
+    }
+}
+
+/// Handle a handler uses to report progress on its own request
+#[derive(Debug, Clone)]
+pub struct InflightHandle {
+    id: Uuid,
+    registry: InflightRegistry,
+    cancelled: watch::Receiver<bool>,
+}
+
+impl InflightHandle {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn set_stage(&self, stage: &str) {
+        self.registry
+            .update(self.id, |tracked| tracked.stage = stage.to_string());
+    }
+
+    pub fn set_provider(&self, provider: &str) {
+        self.registry.update(self.id, |tracked| {
+            tracked.provider = Some(provider.to_string())
+        });
+    }
+
+    pub fn set_user(&self, user: &str) {
+        self.registry
+            .update(self.id, |tracked| tracked.user = Some(user.to_string()));
+    }
+
+    /// Resolves once an admin cancels the request
+    pub async fn cancelled(&self) {
+        let mut cancelled = self.cancelled.clone();
+        if cancelled.wait_for(|c| *c).await.is_err() {
+            // The registry entry is gone, so the request can no longer be cancelled
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+/// Keeps a request registered for as long as it is held
+#[derive(Debug)]
+pub struct InflightGuard {
+    handle: InflightHandle,
+}
+
+impl InflightGuard {
+    pub fn handle(&self) -> InflightHandle {
+        self.handle.clone()
+    }
+}
+
+impl Drop for InflightGuard {
+    fn drop(&mut self) {
+        self.handle
+            .registry
+            .requests
+            .write()
+            .unwrap()
+            .remove(&self.handle.id);
+    }
+}
+
+/// Middleware tracking each request in the registry
+///
+/// Handlers find their [`InflightHandle`] in the request extensions. A
+/// cancelled request stops being polled and gets a 503; work it already
+/// handed to a background job keeps running.
+pub async fn inflight_middleware(
+    State(registry): State<InflightRegistry>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let path = request.uri().path().to_string();
+    if UNTRACKED_PREFIXES.iter().any(|p| path.starts_with(p)) {
+        return next.run(request).await;
+    }
+
+    let guard = registry.register(request.method().as_str(), &path);
+    let handle = guard.handle();
+    request.extensions_mut().insert(handle.clone());
+
+    tokio::select! {
+        response = next.run(request) => response,
+        _ = handle.cancelled() => {
+            warn!("In-flight request {} to {} was cancelled", handle.id(), path);
+            ApiError::ServiceUnavailable {
+                reason: format!("Request {} was cancelled by an administrator", handle.id()),
+            }
+            .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_registry_tracks_and_cancels_requests() {
+        let registry = InflightRegistry::new();
+        let guard = registry.register("POST", "/api/v1/research");
+        let handle = guard.handle();
+        handle.set_stage("research");
+        handle.set_provider("claude");
+
+        let listed = registry.list();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].endpoint, "/api/v1/research");
+        assert_eq!(listed[0].stage, "research");
+        assert_eq!(listed[0].provider.as_deref(), Some("claude"));
+
+        let waiter = tokio::spawn({
+            let handle = handle.clone();
+            async move { handle.cancelled().await }
+        });
+        assert!(registry.cancel(handle.id()));
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(registry.list()[0].cancel_requested);
+
+        drop(guard);
+        assert!(registry.is_empty());
+        assert!(!registry.cancel(handle.id()));
+    }
+}
@@ -15,14 +15,25 @@
 // ABOUTME: Fortitude API Server library providing production-ready JSON REST API
 // Includes authentication, caching, research endpoints, and comprehensive testing
 
+pub mod audit;
 pub mod config;
 pub mod extractors;
+pub mod feedback;
+pub mod inflight;
+pub mod maintenance;
 pub mod middleware;
 pub mod models;
 pub mod monitoring_types;
+pub mod preferences;
+pub mod quota;
+pub mod research_jobs;
+pub mod research_requests;
 pub mod routes;
 pub mod server;
+pub mod shutdown;
+pub mod supervisor;
+pub mod telemetry;
 
 pub use config::ApiServerConfig;
 pub use models::{HealthCheckRequest, LearningInsight, MonitoringMetricsQuery};
-pub use server::ApiServer;
+pub use server::{ApiServer, ApiServerBuilder};
@@ -17,26 +17,58 @@
 
 use anyhow::Result;
 use fortitude_api_server::config::ApiServerConfig;
-use fortitude_api_server::server::ApiServer;
+use fortitude_api_server::server::{openapi_document, ApiServer};
+use fortitude_api_server::telemetry;
 use tracing::{error, info};
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    // Initialize tracing
-    tracing_subscriber::fmt::init();
+/// Command-line flag used by the Windows service registration
+const WINDOWS_SERVICE_FLAG: &str = "--windows-service";
 
-    info!("Starting Fortitude API Server");
+/// Command-line flag printing the generated OpenAPI document and exiting
+const PRINT_OPENAPI_FLAG: &str = "--print-openapi";
+
+fn main() -> Result<()> {
+    if std::env::args().any(|arg| arg == PRINT_OPENAPI_FLAG) {
+        println!("{}", serde_json::to_string_pretty(&openapi_document())?);
+        return Ok(());
+    }
 
     // Load configuration
     let config = ApiServerConfig::from_env()?;
 
+    // Initialize tracing; the guard flushes exported spans on exit
+    let _telemetry = telemetry::init(&config.telemetry)?;
+
+    if std::env::args().any(|arg| arg == WINDOWS_SERVICE_FLAG) {
+        return run_windows_service();
+    }
+
+    tokio::runtime::Runtime::new()?.block_on(run(config))
+}
+
+async fn run(config: ApiServerConfig) -> Result<()> {
+    info!("Starting Fortitude API Server");
+
     // Create and run server
     let server = ApiServer::new(config).await?;
 
+    // Return the error rather than exiting so the telemetry guard still flushes
     if let Err(e) = server.run().await {
         error!("Server error: {:?}", e);
-        std::process::exit(1);
+        return Err(e);
     }
 
     Ok(())
 }
+
+#[cfg(windows)]
+fn run_windows_service() -> Result<()> {
+    info!("Starting Fortitude API Server as a Windows service");
+    fortitude_api_server::supervisor::windows::run()?;
+    Ok(())
+}
+
+#[cfg(not(windows))]
+fn run_windows_service() -> Result<()> {
+    anyhow::bail!("{WINDOWS_SERVICE_FLAG} is only supported on Windows")
+}
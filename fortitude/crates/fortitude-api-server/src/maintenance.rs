@@ -0,0 +1,587 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Embedded maintenance scheduler for the API server
+// Runs cache cleanup, GC, index refresh, stale-research revalidation, metrics snapshots and wiki sync on cron schedules
+
+use crate::config::{MaintenanceConfig, WikiSyncConfig};
+use crate::middleware::monitoring::ApiMonitoringService;
+use crate::models::responses::{
+    MaintenanceMetricsSnapshot, MaintenanceStatusResponse, MaintenanceTaskStatus,
+};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use cron::Schedule;
+use fortitude_core::connectors::{
+    ConfluenceConnector, ConnectorError, NotionConnector, SyncReport, SyncStateStore, WikiSync,
+};
+use fortitude_core::storage::FileStorage;
+use fortitude_types::Storage;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// Maintenance tasks run by the scheduler
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaintenanceTask {
+    CacheCleanup,
+    Gc,
+    IndexRefresh,
+    StaleRevalidation,
+    MetricsSnapshot,
+    WikiSync,
+}
+
+impl MaintenanceTask {
+    /// All tasks in reporting order
+    pub fn all() -> [MaintenanceTask; 6] {
+        [
+            MaintenanceTask::CacheCleanup,
+            MaintenanceTask::Gc,
+            MaintenanceTask::IndexRefresh,
+            MaintenanceTask::StaleRevalidation,
+            MaintenanceTask::MetricsSnapshot,
+            MaintenanceTask::WikiSync,
+        ]
+    }
+
+    /// Task name as used in configuration
+    pub fn name(&self) -> &'static str {
+        match self {
+            MaintenanceTask::CacheCleanup => "cache_cleanup",
+            MaintenanceTask::Gc => "gc",
+            MaintenanceTask::IndexRefresh => "index_refresh",
+            MaintenanceTask::StaleRevalidation => "stale_revalidation",
+            MaintenanceTask::MetricsSnapshot => "metrics_snapshot",
+            MaintenanceTask::WikiSync => "wiki_sync",
+        }
+    }
+
+    /// Parse a task name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().into_iter().find(|task| task.name() == name)
+    }
+}
+
+/// Outcome of the most recent runs of a task
+#[derive(Debug, Clone, Default)]
+struct TaskState {
+    last_run: Option<DateTime<Utc>>,
+    last_duration_ms: Option<u64>,
+    last_success: Option<bool>,
+    last_message: Option<String>,
+    run_count: u64,
+    failure_count: u64,
+}
+
+struct SchedulerInner {
+    config: MaintenanceConfig,
+    schedules: HashMap<MaintenanceTask, Schedule>,
+    storage: Option<Arc<FileStorage>>,
+    monitoring: Option<Arc<ApiMonitoringService>>,
+    wiki_sync: Option<WikiSync>,
+    state: RwLock<HashMap<MaintenanceTask, TaskState>>,
+    snapshots: RwLock<VecDeque<MaintenanceMetricsSnapshot>>,
+    handles: std::sync::Mutex<Vec<JoinHandle<()>>>,
+}
+
+/// Cron-style scheduler for background maintenance tasks
+#[derive(Clone)]
+pub struct MaintenanceScheduler {
+    inner: Arc<SchedulerInner>,
+}
+
+impl MaintenanceScheduler {
+    /// Create a scheduler; tasks do not run until [`MaintenanceScheduler::start`]
+    ///
+    /// Tasks with invalid schedules are disabled with a warning.
+    pub fn new(
+        config: MaintenanceConfig,
+        storage: Option<Arc<FileStorage>>,
+        monitoring: Option<Arc<ApiMonitoringService>>,
+        wiki_sync: Option<WikiSync>,
+    ) -> Self {
+        let mut schedules = HashMap::new();
+        for task in MaintenanceTask::all() {
+            let Some(expression) = config
+                .schedules()
+                .into_iter()
+                .find(|(name, _)| *name == task.name())
+                .and_then(|(_, schedule)| schedule)
+            else {
+                continue;
+            };
+
+            match expression.parse::<Schedule>() {
+                Ok(schedule) => {
+                    schedules.insert(task, schedule);
+                }
+                Err(e) => warn!(
+                    "Disabling maintenance task {}: invalid schedule '{}': {}",
+                    task.name(),
+                    expression,
+                    e
+                ),
+            }
+        }
+
+        Self {
+            inner: Arc::new(SchedulerInner {
+                config,
+                schedules,
+                storage,
+                monitoring,
+                wiki_sync,
+                state: RwLock::new(HashMap::new()),
+                snapshots: RwLock::new(VecDeque::new()),
+                handles: std::sync::Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Spawn one loop per scheduled task; no-op when disabled or already started
+    pub fn start(&self) {
+        if !self.inner.config.enabled {
+            info!("Maintenance scheduler disabled");
+            return;
+        }
+
+        let mut handles = self.inner.handles.lock().unwrap();
+        if !handles.is_empty() {
+            return;
+        }
+
+        for (task, schedule) in &self.inner.schedules {
+            let scheduler = self.clone();
+            let task = *task;
+            let schedule = schedule.clone();
+            handles.push(tokio::spawn(async move {
+                while let Some(next) = schedule.upcoming(Utc).next() {
+                    let wait = (next - Utc::now()).to_std().unwrap_or_default();
+                    tokio::time::sleep(wait).await;
+                    // Outcome is logged and recorded in the task status
+                    let _ = scheduler.run_task(task).await;
+                }
+            }));
+        }
+
+        info!(
+            "Maintenance scheduler started with {} tasks",
+            self.inner.schedules.len()
+        );
+    }
+
+    /// Stop all scheduled task loops
+    pub fn shutdown(&self) {
+        let mut handles = self.inner.handles.lock().unwrap();
+        for handle in handles.drain(..) {
+            handle.abort();
+        }
+    }
+
+    /// Whether task loops are running
+    pub fn is_running(&self) -> bool {
+        self.inner
+            .handles
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|handle| !handle.is_finished())
+    }
+
+    /// Run a task immediately and record its outcome
+    pub async fn run_task(&self, task: MaintenanceTask) -> Result<String, String> {
+        debug!("Running maintenance task {}", task.name());
+        let started_at = Utc::now();
+        let start = Instant::now();
+
+        let result = match task {
+            MaintenanceTask::CacheCleanup => self.cache_cleanup().await,
+            MaintenanceTask::Gc => self.gc().await,
+            MaintenanceTask::IndexRefresh => self.index_refresh().await,
+            MaintenanceTask::StaleRevalidation => self.stale_revalidation().await,
+            MaintenanceTask::MetricsSnapshot => self.metrics_snapshot().await,
+            MaintenanceTask::WikiSync => self.wiki_sync().await,
+        };
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        match &result {
+            Ok(message) => info!("Maintenance task {} completed: {}", task.name(), message),
+            Err(message) => warn!("Maintenance task {} failed: {}", task.name(), message),
+        }
+
+        let mut state = self.inner.state.write().await;
+        let entry = state.entry(task).or_default();
+        entry.last_run = Some(started_at);
+        entry.last_duration_ms = Some(duration_ms);
+        entry.last_success = Some(result.is_ok());
+        entry.last_message = Some(match &result {
+            Ok(message) | Err(message) => message.clone(),
+        });
+        entry.run_count += 1;
+        if result.is_err() {
+            entry.failure_count += 1;
+        }
+
+        result
+    }
+
+    /// Current schedule and run status for every task
+    pub async fn status(&self) -> MaintenanceStatusResponse {
+        let running = self.is_running();
+        let state = self.inner.state.read().await;
+        let tasks = MaintenanceTask::all()
+            .into_iter()
+            .map(|task| {
+                let schedule = self.inner.schedules.get(&task);
+                let task_state = state.get(&task).cloned().unwrap_or_default();
+                MaintenanceTaskStatus {
+                    name: task.name().to_string(),
+                    schedule: schedule.map(|s| s.source().to_string()),
+                    enabled: self.inner.config.enabled && schedule.is_some(),
+                    next_run: schedule
+                        .filter(|_| running)
+                        .and_then(|s| s.upcoming(Utc).next()),
+                    last_run: task_state.last_run,
+                    last_duration_ms: task_state.last_duration_ms,
+                    last_success: task_state.last_success,
+                    last_message: task_state.last_message,
+                    run_count: task_state.run_count,
+                    failure_count: task_state.failure_count,
+                }
+            })
+            .collect();
+
+        let snapshots = self.inner.snapshots.read().await;
+        MaintenanceStatusResponse {
+            enabled: self.inner.config.enabled,
+            running,
+            tasks,
+            latest_snapshot: snapshots.back().cloned(),
+            snapshot_count: snapshots.len(),
+        }
+    }
+
+    /// Retained metrics snapshots, oldest first
+    pub async fn snapshots(&self) -> Vec<MaintenanceMetricsSnapshot> {
+        self.inner.snapshots.read().await.iter().cloned().collect()
+    }
+
+    fn storage(&self) -> Result<&Arc<FileStorage>, String> {
+        self.inner
+            .storage
+            .as_ref()
+            .ok_or_else(|| "Cache storage is unavailable".to_string())
+    }
+
+    async fn cache_cleanup(&self) -> Result<String, String> {
+        let removed = self
+            .storage()?
+            .cleanup_expired()
+            .await
+            .map_err(|e| format!("Cache cleanup failed: {e}"))?;
+        Ok(format!("Removed {removed} expired entries"))
+    }
+
+    async fn gc(&self) -> Result<String, String> {
+        let pruned = self
+            .storage()?
+            .prune_missing_entries()
+            .await
+            .map_err(|e| format!("Garbage collection failed: {e}"))?;
+        Ok(format!("Pruned {pruned} entries with missing files"))
+    }
+
+    async fn index_refresh(&self) -> Result<String, String> {
+        self.storage()?
+            .update_index()
+            .await
+            .map_err(|e| format!("Index refresh failed: {e}"))?;
+        Ok("Search index refreshed".to_string())
+    }
+
+    async fn stale_revalidation(&self) -> Result<String, String> {
+        let storage = self.storage()?;
+        let cutoff = Utc::now() - ChronoDuration::hours(self.inner.config.stale_after_hours as i64);
+        let entries = storage
+            .list_cache_entries()
+            .await
+            .map_err(|e| format!("Failed to list cache entries: {e}"))?;
+
+        let (mut checked, mut expired, mut removed) = (0usize, 0usize, 0usize);
+        for entry in entries.iter().filter(|entry| entry.created_at < cutoff) {
+            checked += 1;
+            match storage.retrieve(&entry.key).await {
+                Ok(Some(_)) => {}
+                Ok(None) => expired += 1,
+                Err(e) => {
+                    warn!("Stored research {} failed revalidation: {}", entry.key, e);
+                    if storage.delete(&entry.key).await.is_ok() {
+                        removed += 1;
+                    }
+                }
+            }
+        }
+        removed += storage.prune_missing_entries().await.unwrap_or(0) as usize;
+
+        Ok(format!(
+            "Revalidated {checked} stale entries: {expired} expired, {removed} removed"
+        ))
+    }
+
+    async fn metrics_snapshot(&self) -> Result<String, String> {
+        let stats = self
+            .storage()?
+            .get_cache_stats()
+            .await
+            .map_err(|e| format!("Failed to read cache statistics: {e}"))?;
+
+        let performance = match &self.inner.monitoring {
+            Some(monitoring) => monitoring.get_performance_summary().await.ok(),
+            None => None,
+        };
+
+        let snapshot = MaintenanceMetricsSnapshot {
+            timestamp: Utc::now(),
+            cache_entries: stats.total_entries,
+            cache_size_bytes: stats.total_size_bytes,
+            cache_hit_rate: stats.hit_rate,
+            total_requests: performance.as_ref().map(|p| p.total_requests),
+            error_rate: performance.as_ref().map(|p| p.error_rate),
+            avg_response_time_ms: performance.as_ref().map(|p| p.avg_response_time_ms),
+        };
+
+        let mut snapshots = self.inner.snapshots.write().await;
+        snapshots.push_back(snapshot);
+        while snapshots.len() > self.inner.config.snapshot_retention.max(1) {
+            snapshots.pop_front();
+        }
+
+        Ok(format!(
+            "Recorded snapshot ({} cache entries)",
+            stats.total_entries
+        ))
+    }
+
+    async fn wiki_sync(&self) -> Result<String, String> {
+        let sync = self
+            .inner
+            .wiki_sync
+            .as_ref()
+            .ok_or_else(|| "No wiki connectors configured".to_string())?;
+        let storage = self.storage()?;
+        let entries = storage
+            .list_cache_entries()
+            .await
+            .map_err(|e| format!("Failed to list cache entries: {e}"))?;
+
+        // Skip reading results whose type the filter excludes anyway
+        let mut results = Vec::new();
+        for entry in entries
+            .iter()
+            .filter(|entry| sync.filter().matches_type(&entry.research_type))
+        {
+            match storage.retrieve(&entry.key).await {
+                Ok(Some(result)) => results.push(result),
+                Ok(None) => {}
+                Err(e) => warn!("Skipping {} in wiki sync: {}", entry.key, e),
+            }
+        }
+
+        let reports = sync
+            .sync(&results)
+            .await
+            .map_err(|e| format!("Wiki sync failed: {e}"))?;
+        let summary = reports
+            .iter()
+            .map(SyncReport::summary)
+            .collect::<Vec<_>>()
+            .join("; ");
+        if reports.iter().any(|report| !report.failed.is_empty()) {
+            return Err(summary);
+        }
+        Ok(summary)
+    }
+}
+
+/// Wiki sync for the configured connectors; `None` when no connector is configured
+pub fn wiki_sync_from_config(config: &WikiSyncConfig) -> Result<Option<WikiSync>, ConnectorError> {
+    if config.notion.is_none() && config.confluence.is_none() {
+        return Ok(None);
+    }
+
+    let mut sync = WikiSync::new(SyncStateStore::open(&config.state_path)?)
+        .with_filter(config.filter.clone())
+        .with_conflict_policy(config.conflict_policy);
+    if let Some(notion) = &config.notion {
+        sync = sync.with_connector(Arc::new(NotionConnector::new(notion.clone())?));
+    }
+    if let Some(confluence) = &config.confluence {
+        sync = sync.with_connector(Arc::new(ConfluenceConnector::new(confluence.clone())?));
+    }
+    Ok(Some(sync))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_types::StorageConfig;
+    use tempfile::TempDir;
+
+    async fn create_scheduler(config: MaintenanceConfig) -> (MaintenanceScheduler, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = FileStorage::new(StorageConfig {
+            base_path: temp_dir.path().to_path_buf(),
+            cache_expiration_seconds: 3600,
+            max_cache_size_bytes: 1024 * 1024,
+            enable_content_addressing: true,
+            index_update_interval_seconds: 300,
+        })
+        .await
+        .unwrap();
+
+        let scheduler = MaintenanceScheduler::new(
+            config,
+            Some(Arc::new(storage)),
+            Some(Arc::new(ApiMonitoringService::for_api_server())),
+            None,
+        );
+        (scheduler, temp_dir)
+    }
+
+    #[test]
+    fn test_task_names_round_trip() {
+        for task in MaintenanceTask::all() {
+            assert_eq!(MaintenanceTask::from_name(task.name()), Some(task));
+        }
+        assert_eq!(MaintenanceTask::from_name("defrag"), None);
+    }
+
+    #[tokio::test]
+    async fn test_run_task_records_status() {
+        let (scheduler, _temp_dir) = create_scheduler(MaintenanceConfig::default()).await;
+
+        let message = scheduler
+            .run_task(MaintenanceTask::CacheCleanup)
+            .await
+            .unwrap();
+        assert_eq!(message, "Removed 0 expired entries");
+
+        let status = scheduler.status().await;
+        assert!(!status.running);
+        let cleanup = status
+            .tasks
+            .iter()
+            .find(|t| t.name == "cache_cleanup")
+            .unwrap();
+        assert_eq!(cleanup.run_count, 1);
+        assert_eq!(cleanup.last_success, Some(true));
+        assert!(cleanup.last_run.is_some());
+        assert!(cleanup.next_run.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_metrics_snapshot_retention() {
+        let config = MaintenanceConfig {
+            snapshot_retention: 2,
+            ..Default::default()
+        };
+        let (scheduler, _temp_dir) = create_scheduler(config).await;
+
+        for _ in 0..3 {
+            scheduler
+                .run_task(MaintenanceTask::MetricsSnapshot)
+                .await
+                .unwrap();
+        }
+
+        let status = scheduler.status().await;
+        assert_eq!(status.snapshot_count, 2);
+        assert_eq!(status.latest_snapshot.unwrap().total_requests, Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_and_disabled_schedules() {
+        let config = MaintenanceConfig {
+            gc: None,
+            index_refresh: Some("not a cron expression".to_string()),
+            ..Default::default()
+        };
+        let (scheduler, _temp_dir) = create_scheduler(config).await;
+
+        let status = scheduler.status().await;
+        let enabled: Vec<_> = status
+            .tasks
+            .iter()
+            .filter(|t| t.enabled)
+            .map(|t| t.name.as_str())
+            .collect();
+        assert_eq!(
+            enabled,
+            vec!["cache_cleanup", "stale_revalidation", "metrics_snapshot"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_start_and_shutdown() {
+        let (scheduler, _temp_dir) = create_scheduler(MaintenanceConfig::default()).await;
+
+        scheduler.start();
+        assert!(scheduler.is_running());
+        let status = scheduler.status().await;
+        assert!(status
+            .tasks
+            .iter()
+            .filter(|t| t.enabled)
+            .all(|t| t.next_run.is_some()));
+
+        scheduler.shutdown();
+        tokio::task::yield_now().await;
+        assert!(!scheduler.is_running());
+    }
+
+    #[tokio::test]
+    async fn test_wiki_sync_requires_connectors() {
+        assert!(wiki_sync_from_config(&WikiSyncConfig::default())
+            .unwrap()
+            .is_none());
+        let incomplete = WikiSyncConfig {
+            notion: Some(Default::default()),
+            ..Default::default()
+        };
+        assert!(wiki_sync_from_config(&incomplete).is_err());
+
+        let (scheduler, _temp_dir) = create_scheduler(MaintenanceConfig::default()).await;
+        let status = scheduler.status().await;
+        assert!(!status
+            .tasks
+            .iter()
+            .any(|t| t.name == "wiki_sync" && t.enabled));
+        let err = scheduler
+            .run_task(MaintenanceTask::WikiSync)
+            .await
+            .unwrap_err();
+        assert_eq!(err, "No wiki connectors configured");
+    }
+
+    #[tokio::test]
+    async fn test_storage_unavailable() {
+        let scheduler = MaintenanceScheduler::new(MaintenanceConfig::default(), None, None, None);
+        let err = scheduler.run_task(MaintenanceTask::Gc).await.unwrap_err();
+        assert_eq!(err, "Cache storage is unavailable");
+        assert_eq!(scheduler.status().await.tasks[1].failure_count, 1);
+    }
+}
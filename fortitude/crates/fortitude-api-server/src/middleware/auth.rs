@@ -16,22 +16,20 @@
 // Adapts JWT authentication from MCP server for HTTP Bearer tokens
 
 use crate::config::ApiServerConfig;
+use crate::middleware::rbac::{extract_api_key, ApiKeyStore};
 use crate::models::errors::ApiError;
 use anyhow::{anyhow, Result};
 use axum::{
     extract::{Request, State},
-    http::{header::AUTHORIZATION, HeaderMap, HeaderValue},
+    http::{header::AUTHORIZATION, HeaderMap},
     middleware::Next,
     response::Response,
 };
 use chrono::{Duration, Utc};
 use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Instant;
-use tokio::sync::RwLock;
 use tracing::{debug, info, instrument, warn};
 
 /// JWT claims structure containing user information and permissions
@@ -184,15 +182,6 @@ impl ApiPermissionLevel {
     }
 }
 
-/// Rate limiter state for a single client
-#[derive(Debug, Clone)]
-struct RateLimitState {
-    /// Number of requests in current window
-    request_count: u32,
-    /// Start time of current window
-    window_start: Instant,
-}
-
 /// Authentication and authorization manager for HTTP API
 pub struct AuthManager {
     /// JWT encoding key
@@ -203,8 +192,8 @@ pub struct AuthManager {
     validation: Validation,
     /// Server configuration
     config: Arc<ApiServerConfig>,
-    /// Rate limiting state per client
-    rate_limits: Arc<RwLock<HashMap<String, RateLimitState>>>,
+    /// API keys and their roles
+    api_keys: ApiKeyStore,
 }
 
 impl AuthManager {
@@ -224,10 +213,15 @@ impl AuthManager {
             decoding_key,
             validation,
             config: config.clone(),
-            rate_limits: Arc::new(RwLock::new(HashMap::new())),
+            api_keys: ApiKeyStore::new(&config.api_keys),
         })
     }
 
+    /// API keys accepted in the `X-API-Key` header
+    pub fn api_keys(&self) -> &ApiKeyStore {
+        &self.api_keys
+    }
+
     /// Generate JWT token for a user with given permissions
     #[instrument(skip(self, permissions))]
     pub async fn generate_token(
@@ -302,72 +296,6 @@ impl AuthManager {
         Ok(())
     }
 
-    /// Check rate limit for a client
-    #[instrument(skip(self))]
-    pub async fn check_rate_limit(&self, client_id: &str) -> Result<()> {
-        let mut rate_limits = self.rate_limits.write().await;
-        let now = Instant::now();
-
-        let rate_limit =
-            rate_limits
-                .entry(client_id.to_string())
-                .or_insert_with(|| RateLimitState {
-                    request_count: 0,
-                    window_start: now,
-                });
-
-        // Check if we need to reset the window
-        if now.duration_since(rate_limit.window_start).as_secs()
-            >= self.config.auth.rate_limit.window_seconds
-        {
-            rate_limit.request_count = 0;
-            rate_limit.window_start = now;
-        }
-
-        // Check if limit exceeded
-        if rate_limit.request_count >= self.config.auth.rate_limit.max_requests_per_minute {
-            warn!("Rate limit exceeded for client: {}", client_id);
-            return Err(anyhow!("Rate limit exceeded"));
-        }
-
-        // Increment request count
-        rate_limit.request_count += 1;
-
-        debug!(
-            "Rate limit check passed for client: {} ({}/{})",
-            client_id,
-            rate_limit.request_count,
-            self.config.auth.rate_limit.max_requests_per_minute
-        );
-
-        Ok(())
-    }
-
-    /// Get remaining requests for a client
-    #[instrument(skip(self))]
-    pub async fn get_remaining_requests(&self, client_id: &str) -> u32 {
-        let rate_limits = self.rate_limits.read().await;
-
-        if let Some(rate_limit) = rate_limits.get(client_id) {
-            let now = Instant::now();
-
-            // If window has expired, return full limit
-            if now.duration_since(rate_limit.window_start).as_secs()
-                >= self.config.auth.rate_limit.window_seconds
-            {
-                return self.config.auth.rate_limit.max_requests_per_minute;
-            }
-
-            self.config
-                .auth
-                .rate_limit
-                .max_requests_per_minute
-                .saturating_sub(rate_limit.request_count)
-        } else {
-            self.config.auth.rate_limit.max_requests_per_minute
-        }
-    }
-
     /// Create a default admin token for development
     pub async fn create_default_admin_token(&self) -> Result<String> {
         self.generate_token("admin", Permission::all()).await
@@ -377,29 +305,6 @@ impl AuthManager {
     pub fn is_auth_enabled(&self) -> bool {
         self.config.auth.enabled
     }
-
-    /// Get rate limit headers for response
-    pub async fn get_rate_limit_headers(&self, client_id: &str) -> HashMap<String, String> {
-        let remaining = self.get_remaining_requests(client_id).await;
-
-        let mut headers = HashMap::new();
-        headers.insert(
-            "X-RateLimit-Limit".to_string(),
-            self.config
-                .auth
-                .rate_limit
-                .max_requests_per_minute
-                .to_string(),
-        );
-        headers.insert("X-RateLimit-Remaining".to_string(), remaining.to_string());
-        headers.insert(
-            "X-RateLimit-Reset".to_string(),
-            (Utc::now().timestamp() + self.config.auth.rate_limit.window_seconds as i64)
-                .to_string(),
-        );
-
-        headers
-    }
 }
 
 /// Extract Bearer token from Authorization header
@@ -420,7 +325,7 @@ fn extract_bearer_token(headers: &HeaderMap) -> Result<String, ApiError> {
 }
 
 /// Get client ID from request headers and connection info
-fn get_client_id(headers: &HeaderMap) -> String {
+pub(crate) fn get_client_id(headers: &HeaderMap) -> String {
     // Try to get client ID from various headers, fallback to "unknown"
     headers
         .get("x-forwarded-for")
@@ -445,11 +350,12 @@ pub struct AuthState {
 /// JWT authentication middleware for protecting routes
 ///
 /// This middleware:
-/// 1. Extracts Bearer tokens from Authorization headers
-/// 2. Validates JWT tokens using the AuthManager
-/// 3. Checks rate limits per client
-/// 4. Adds rate limit headers to responses
-/// 5. Returns 401/403 for authentication/authorization failures
+/// 1. Extracts Bearer tokens from Authorization headers, or API keys from X-API-Key
+/// 2. Validates them using the AuthManager
+/// 3. Adds the resulting claims to request extensions
+/// 4. Returns 401 for authentication failures
+///
+/// Rate limiting runs after this middleware, keyed by the claims subject.
 #[instrument(skip_all)]
 pub async fn jwt_auth_middleware(
     State(auth_state): State<AuthState>,
@@ -457,7 +363,6 @@ pub async fn jwt_auth_middleware(
     next: Next,
 ) -> Result<Response, ApiError> {
     let headers = request.headers();
-    let client_id = get_client_id(headers);
 
     // Skip authentication if disabled
     if !auth_state.auth_manager.is_auth_enabled() {
@@ -465,42 +370,29 @@ pub async fn jwt_auth_middleware(
         return Ok(next.run(request).await);
     }
 
-    // Check rate limit first
-    auth_state
-        .auth_manager
-        .check_rate_limit(&client_id)
-        .await
-        .map_err(|_| ApiError::RateLimitExceeded)?;
-
-    // Extract and verify token
-    let token = extract_bearer_token(headers)?;
-    let claims = auth_state
-        .auth_manager
-        .verify_token(&token)
-        .await
-        .map_err(|_| ApiError::Unauthorized)?;
+    // Authenticate with an API key if one is sent, otherwise a bearer token
+    let claims = match extract_api_key(headers) {
+        Some(key) => auth_state
+            .auth_manager
+            .api_keys()
+            .authenticate(key)
+            .map(|record| record.claims())
+            .ok_or(ApiError::Unauthorized)?,
+        None => {
+            let token = extract_bearer_token(headers)?;
+            auth_state
+                .auth_manager
+                .verify_token(&token)
+                .await
+                .map_err(|_| ApiError::Unauthorized)?
+        }
+    };
 
     // Add claims to request extensions for use in handlers
     request.extensions_mut().insert(claims.clone());
 
     // Process request
-    let mut response = next.run(request).await;
-
-    // Add rate limit headers to response
-    let rate_limit_headers = auth_state
-        .auth_manager
-        .get_rate_limit_headers(&client_id)
-        .await;
-    let response_headers = response.headers_mut();
-
-    for (key, value) in rate_limit_headers {
-        if let Ok(header_value) = HeaderValue::from_str(&value) {
-            response_headers.insert(
-                axum::http::HeaderName::from_bytes(key.as_bytes()).unwrap(),
-                header_value,
-            );
-        }
-    }
+    let response = next.run(request).await;
 
     info!(
         "Request authenticated successfully for user: {}",
@@ -704,22 +596,6 @@ mod tests {
             .is_ok());
     }
 
-    #[tokio::test]
-    async fn test_rate_limiting() {
-        let config = create_test_config();
-        let auth_manager = AuthManager::new(config).unwrap();
-
-        let client_id = "test_client";
-
-        // Make requests up to the limit
-        for _ in 0..auth_manager.config.auth.rate_limit.max_requests_per_minute {
-            assert!(auth_manager.check_rate_limit(client_id).await.is_ok());
-        }
-
-        // Next request should fail
-        assert!(auth_manager.check_rate_limit(client_id).await.is_err());
-    }
-
     #[tokio::test]
     async fn test_default_admin_token() {
         let config = create_test_config();
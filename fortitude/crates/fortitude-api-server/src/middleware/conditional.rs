@@ -0,0 +1,193 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: ETag and If-None-Match handling for JSON GET responses
+// Pollers of unchanged research results get 304 Not Modified instead of the full body
+
+use crate::middleware::versioning::API_VERSION_HEADER;
+use axum::{
+    body::{to_bytes, Body},
+    extract::Request,
+    http::{header, HeaderMap, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+/// Middleware tagging successful JSON GET responses with a weak ETag
+///
+/// The tag covers the envelope's `data` and `warnings` but not its
+/// `request_id` or `timestamp`, which differ on every response, so an
+/// unchanged result keeps its tag across polls. A request whose
+/// `If-None-Match` lists the current tag gets an empty 304 response.
+/// The tag is weak because compression changes the bytes on the wire.
+pub async fn conditional_get_middleware(request: Request, next: Next) -> Response {
+    if request.method() != Method::GET && request.method() != Method::HEAD {
+        return next.run(request).await;
+    }
+    let if_none_match = request.headers().get(header::IF_NONE_MATCH).cloned();
+    let api_version = request.headers().get(API_VERSION_HEADER).cloned();
+
+    let response = next.run(request).await;
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("application/json"));
+    if response.status() != StatusCode::OK || !is_json {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            warn!("Failed to buffer response for ETag: {}", e);
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+
+    let etag = entity_tag(&bytes, api_version.as_ref());
+    let Ok(etag_value) = HeaderValue::from_str(&etag) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+    parts.headers.insert(header::ETAG, etag_value);
+
+    if if_none_match.is_some_and(|v| matches_any(&v, &etag)) {
+        parts.status = StatusCode::NOT_MODIFIED;
+        strip_content_headers(&mut parts.headers);
+        return Response::from_parts(parts, Body::empty());
+    }
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+/// Weak entity tag for a response body
+///
+/// The requested API version is included because v1 and v2 wrap the same
+/// data in different envelopes.
+fn entity_tag(body: &[u8], api_version: Option<&HeaderValue>) -> String {
+    let mut hasher = Sha256::new();
+    match serde_json::from_slice::<Value>(body) {
+        Ok(Value::Object(map)) if map.contains_key("data") => {
+            hasher.update(map.get("data").map(Value::to_string).unwrap_or_default());
+            hasher.update(b"\0");
+            hasher.update(
+                map.get("warnings")
+                    .map(Value::to_string)
+                    .unwrap_or_default(),
+            );
+        }
+        _ => hasher.update(body),
+    }
+    if let Some(version) = api_version {
+        hasher.update(b"\0");
+        hasher.update(version.as_bytes());
+    }
+    let tag: String = hasher.finalize()[..16]
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    format!("W/\"{tag}\"")
+}
+
+/// Whether an `If-None-Match` value lists `etag`, using weak comparison
+fn matches_any(if_none_match: &HeaderValue, etag: &str) -> bool {
+    let Ok(value) = if_none_match.to_str() else {
+        return false;
+    };
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    value
+        .split(',')
+        .any(|tag| tag.trim() == "*" || opaque(tag) == opaque(etag))
+}
+
+/// A 304 carries no body, so headers describing one must go
+fn strip_content_headers(headers: &mut HeaderMap) {
+    headers.remove(header::CONTENT_LENGTH);
+    headers.remove(header::CONTENT_TYPE);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{routing::get, Json, Router};
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        let polls = Arc::new(AtomicUsize::new(0));
+        Router::new()
+            .route(
+                "/api/v1/research/{id}",
+                get(move || {
+                    let poll = polls.fetch_add(1, Ordering::SeqCst);
+                    async move {
+                        Json(json!({
+                            "data": {"content": "unchanged"},
+                            "request_id": uuid::Uuid::new_v4(),
+                            "timestamp": format!("2025-01-01T00:00:0{poll}Z"),
+                            "success": true,
+                        }))
+                    }
+                }),
+            )
+            .layer(axum::middleware::from_fn(conditional_get_middleware))
+    }
+
+    async fn fetch(app: &Router, if_none_match: Option<&str>) -> Response {
+        let mut request = Request::builder().uri("/api/v1/research/1");
+        if let Some(tag) = if_none_match {
+            request = request.header(header::IF_NONE_MATCH, tag);
+        }
+        app.clone()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_data_is_not_modified() {
+        let app = app();
+
+        let first = fetch(&app, None).await;
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first.headers()[header::ETAG].to_str().unwrap().to_string();
+        assert!(etag.starts_with("W/\""));
+
+        let second = fetch(&app, Some(&etag)).await;
+        assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(second.headers()[header::ETAG], etag.as_str());
+        let body = to_bytes(second.into_body(), usize::MAX).await.unwrap();
+        assert!(body.is_empty());
+
+        let stale = fetch(&app, Some("W/\"stale\", \"other\"")).await;
+        assert_eq!(stale.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn test_if_none_match_uses_weak_comparison() {
+        let etag = "W/\"abc\"";
+        assert!(matches_any(&HeaderValue::from_static("\"abc\""), etag));
+        assert!(matches_any(
+            &HeaderValue::from_static("\"x\", W/\"abc\""),
+            etag
+        ));
+        assert!(matches_any(&HeaderValue::from_static("*"), etag));
+        assert!(!matches_any(&HeaderValue::from_static("W/\"abd\""), etag));
+    }
+}
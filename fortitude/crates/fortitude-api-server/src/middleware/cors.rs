@@ -14,26 +14,188 @@
 
 // ABOUTME: CORS middleware configuration for API server cross-origin requests
 
-use axum::http::Method;
-use tower_http::cors::{Any, CorsLayer};
+use crate::config::CorsConfig;
+use anyhow::{anyhow, Result};
+use axum::http::{HeaderName, HeaderValue, Method, Request, Response};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tower::{Layer, Service};
+use tower_http::cors::{AllowHeaders, AllowOrigin, Any, Cors, CorsLayer, ResponseFuture};
 
-/// Create CORS layer for the API server
-///
-/// This configures cross-origin resource sharing to allow requests from
-/// various origins during development and production.
+/// Wildcard accepted in origin and header lists
+const WILDCARD: &str = "*";
+
+/// Create CORS layer for the API server from the default configuration
 pub fn create_cors_layer() -> CorsLayer {
+    cors_layer_from_config(&CorsConfig::default()).expect("default CORS configuration is valid")
+}
+
+/// Build the CORS layer for API routes from configuration
+///
+/// Only the listed origins are allowed; `*` allows any origin but cannot be
+/// combined with credentials, since browsers reject that pairing.
+pub fn cors_layer_from_config(config: &CorsConfig) -> Result<CorsLayer> {
+    validate_cors_config(config)?;
+
+    let allow_origin = if config.allowed_origins.iter().any(|o| o == WILDCARD) {
+        AllowOrigin::any()
+    } else {
+        let origins = config
+            .allowed_origins
+            .iter()
+            .map(|origin| {
+                HeaderValue::from_str(origin.trim_end_matches('/'))
+                    .map_err(|_| anyhow!("Invalid CORS origin: {origin}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        AllowOrigin::list(origins)
+    };
+
+    let methods = config
+        .allowed_methods
+        .iter()
+        .map(|method| {
+            Method::from_bytes(method.to_uppercase().as_bytes())
+                .map_err(|_| anyhow!("Invalid CORS method: {method}"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let allow_headers = if config.allowed_headers.iter().any(|h| h == WILDCARD) {
+        AllowHeaders::any()
+    } else {
+        AllowHeaders::list(parse_header_names(&config.allowed_headers)?)
+    };
+
+    Ok(CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods(methods)
+        .allow_headers(allow_headers)
+        .expose_headers(parse_header_names(&config.exposed_headers)?)
+        .allow_credentials(config.allow_credentials)
+        .max_age(Duration::from_secs(config.max_age as u64)))
+}
+
+/// CORS layer for public endpoints such as `/health`
+///
+/// Any origin may read them, but never with credentials.
+pub fn create_public_cors_layer(max_age: u32) -> CorsLayer {
     CorsLayer::new()
         .allow_origin(Any)
-        .allow_methods([
-            Method::GET,
-            Method::POST,
-            Method::PUT,
-            Method::DELETE,
-            Method::PATCH,
-            Method::OPTIONS,
-        ])
+        .allow_methods([Method::GET, Method::HEAD, Method::OPTIONS])
         .allow_headers(Any)
         .allow_credentials(false)
+        .max_age(Duration::from_secs(max_age as u64))
+}
+
+/// Check a CORS configuration for combinations browsers would refuse
+pub fn validate_cors_config(config: &CorsConfig) -> Result<()> {
+    if config.allow_credentials {
+        if config.allowed_origins.iter().any(|o| o == WILDCARD) {
+            return Err(anyhow!(
+                "CORS allowed origin '*' cannot be used with credentials"
+            ));
+        }
+        if config.allowed_headers.iter().any(|h| h == WILDCARD) {
+            return Err(anyhow!(
+                "CORS allowed header '*' cannot be used with credentials"
+            ));
+        }
+    }
+
+    for origin in config.allowed_origins.iter().filter(|o| *o != WILDCARD) {
+        if !(origin.starts_with("http://") || origin.starts_with("https://")) {
+            return Err(anyhow!(
+                "CORS origin must include the scheme, e.g. https://app.example.com: {origin}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn parse_header_names(names: &[String]) -> Result<Vec<HeaderName>> {
+    names
+        .iter()
+        .map(|name| {
+            HeaderName::from_bytes(name.to_lowercase().as_bytes())
+                .map_err(|_| anyhow!("Invalid CORS header: {name}"))
+        })
+        .collect()
+}
+
+/// Applies the public CORS policy to public paths and the configured policy elsewhere
+#[derive(Clone)]
+pub struct CorsPolicyLayer {
+    default: CorsLayer,
+    public: CorsLayer,
+    public_paths: Arc<[String]>,
+}
+
+impl CorsPolicyLayer {
+    pub fn from_config(config: &CorsConfig) -> Result<Self> {
+        Ok(Self {
+            default: cors_layer_from_config(config)?,
+            public: create_public_cors_layer(config.max_age),
+            public_paths: config.public_paths.clone().into(),
+        })
+    }
+}
+
+impl<S: Clone> Layer<S> for CorsPolicyLayer {
+    type Service = CorsPolicy<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CorsPolicy {
+            default: self.default.layer(inner.clone()),
+            public: self.public.layer(inner),
+            public_paths: self.public_paths.clone(),
+        }
+    }
+}
+
+/// Service produced by [`CorsPolicyLayer`]
+#[derive(Clone)]
+pub struct CorsPolicy<S> {
+    default: Cors<S>,
+    public: Cors<S>,
+    public_paths: Arc<[String]>,
+}
+
+impl<S> CorsPolicy<S> {
+    fn is_public(&self, path: &str) -> bool {
+        self.public_paths
+            .iter()
+            .any(|pattern| match pattern.strip_suffix(WILDCARD) {
+                Some(prefix) => path.starts_with(prefix),
+                None => path == pattern,
+            })
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for CorsPolicy<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    ResBody: Default,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Both wrap clones of the same router, which is always ready
+        match self.default.poll_ready(cx) {
+            Poll::Ready(Ok(())) => self.public.poll_ready(cx),
+            other => other,
+        }
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        if self.is_public(request.uri().path()) {
+            self.public.call(request)
+        } else {
+            self.default.call(request)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -46,4 +208,22 @@ mod tests {
         let _cors_layer = create_cors_layer();
         // If we reach here, the layer was created successfully
     }
+
+    #[test]
+    fn test_cors_config_validation() {
+        let mut config = CorsConfig::default();
+        assert!(CorsPolicyLayer::from_config(&config).is_ok());
+
+        config.allowed_origins = vec!["*".to_string()];
+        assert!(validate_cors_config(&config).is_err());
+        config.allow_credentials = false;
+        assert!(cors_layer_from_config(&config).is_ok());
+
+        config.allowed_origins = vec!["dashboard.internal".to_string()];
+        assert!(validate_cors_config(&config).is_err());
+
+        config.allowed_origins = vec!["https://dashboard.internal".to_string()];
+        config.allowed_methods = vec!["NOT A METHOD".to_string()];
+        assert!(cors_layer_from_config(&config).is_err());
+    }
 }
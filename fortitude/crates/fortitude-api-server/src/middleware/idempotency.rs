@@ -0,0 +1,413 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Idempotency-Key handling so retried POSTs replay the original response
+// Successful responses are kept per caller and key for a TTL; a duplicate arriving while the original runs waits for it
+
+use crate::config::{IdempotencyConfig, SecurityConfig};
+use crate::middleware::auth::Claims;
+use crate::models::errors::ApiError;
+use axum::{
+    body::{to_bytes, Body, Bytes},
+    extract::{Request, State},
+    http::{header, HeaderName, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use http_body_util::LengthLimitError;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+use tracing::{debug, info, warn};
+
+/// Request header carrying the client's idempotency key
+pub const IDEMPOTENCY_KEY_HEADER: HeaderName = HeaderName::from_static("idempotency-key");
+
+/// Response header set on replayed responses
+pub const IDEMPOTENT_REPLAY_HEADER: HeaderName = HeaderName::from_static("idempotent-replayed");
+
+/// Longest accepted idempotency key
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+/// Response recorded for a key
+#[derive(Debug, Clone)]
+struct StoredResponse {
+    status: StatusCode,
+    content_type: Option<HeaderValue>,
+    body: Bytes,
+}
+
+impl StoredResponse {
+    fn replay(&self) -> Response {
+        let mut response = Response::new(Body::from(self.body.clone()));
+        *response.status_mut() = self.status;
+        let headers = response.headers_mut();
+        if let Some(content_type) = &self.content_type {
+            headers.insert(header::CONTENT_TYPE, content_type.clone());
+        }
+        headers.insert(IDEMPOTENT_REPLAY_HEADER, HeaderValue::from_static("true"));
+        response
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    /// Hash of the request body the key was first used with
+    fingerprint: [u8; 32],
+    created_at: Instant,
+    /// `None` while the original request runs
+    response: watch::Sender<Option<StoredResponse>>,
+}
+
+/// What to do with a request carrying a key
+enum Claim {
+    /// First use of the key; run the request and record its response
+    New(ClaimGuard),
+    Replay(StoredResponse),
+    /// The original request is still running
+    Wait(watch::Receiver<Option<StoredResponse>>),
+    /// The key was first used with a different request body
+    Mismatch,
+}
+
+/// Recorded responses keyed by caller and idempotency key
+#[derive(Debug, Clone)]
+pub struct IdempotencyStore {
+    entries: Arc<Mutex<HashMap<String, Entry>>>,
+    ttl: Duration,
+    max_entries: usize,
+    /// Largest request body read to fingerprint a keyed request
+    max_body_size: usize,
+}
+
+impl Default for IdempotencyStore {
+    fn default() -> Self {
+        Self::new(&IdempotencyConfig::default())
+    }
+}
+
+impl IdempotencyStore {
+    pub fn new(config: &IdempotencyConfig) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(HashMap::new())),
+            ttl: Duration::from_secs(config.ttl_seconds),
+            max_entries: config.max_entries,
+            max_body_size: SecurityConfig::default().max_request_body_size,
+        }
+    }
+
+    /// Refuse keyed requests whose body is larger than `max_body_size` bytes
+    pub fn with_max_body_size(mut self, max_body_size: usize) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    /// Number of keys currently held
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn claim(&self, key: &str, fingerprint: [u8; 32]) -> Claim {
+        let mut entries = self.entries.lock().unwrap();
+        let ttl = self.ttl;
+        entries.retain(|_, entry| entry.created_at.elapsed() < ttl);
+
+        if let Some(entry) = entries.get(key) {
+            if entry.fingerprint != fingerprint {
+                return Claim::Mismatch;
+            }
+            return match entry.response.borrow().clone() {
+                Some(stored) => Claim::Replay(stored),
+                None => Claim::Wait(entry.response.subscribe()),
+            };
+        }
+
+        if entries.len() >= self.max_entries {
+            // Drop the oldest completed keys; running requests are never evicted
+            let mut completed: Vec<(String, Instant)> = entries
+                .iter()
+                .filter(|(_, entry)| entry.response.borrow().is_some())
+                .map(|(key, entry)| (key.clone(), entry.created_at))
+                .collect();
+            completed.sort_by_key(|(_, created_at)| *created_at);
+            let excess = entries.len() + 1 - self.max_entries;
+            for (key, _) in completed.into_iter().take(excess) {
+                entries.remove(&key);
+            }
+        }
+
+        let (response, _) = watch::channel(None);
+        entries.insert(
+            key.to_string(),
+            Entry {
+                fingerprint,
+                created_at: Instant::now(),
+                response,
+            },
+        );
+        Claim::New(ClaimGuard {
+            store: self.clone(),
+            key: key.to_string(),
+            completed: false,
+        })
+    }
+}
+
+/// Holds a key while its original request runs
+///
+/// Dropped without a recorded response (the request failed or was
+/// cancelled), the key is released so a retry runs the request again.
+struct ClaimGuard {
+    store: IdempotencyStore,
+    key: String,
+    completed: bool,
+}
+
+impl ClaimGuard {
+    fn complete(mut self, stored: StoredResponse) {
+        if let Some(entry) = self.store.entries.lock().unwrap().get(&self.key) {
+            entry.response.send_replace(Some(stored));
+        }
+        self.completed = true;
+    }
+}
+
+impl Drop for ClaimGuard {
+    fn drop(&mut self) {
+        if !self.completed {
+            self.store.entries.lock().unwrap().remove(&self.key);
+        }
+    }
+}
+
+/// Middleware replaying the recorded response for a repeated `Idempotency-Key`
+///
+/// Keys are scoped to the authenticated caller. Only successful responses are
+/// recorded; a request that fails releases its key. Reusing a key with a
+/// different request body is rejected as a validation error, and a body over
+/// the store's size limit with 413.
+pub async fn idempotency_middleware(
+    State(store): State<IdempotencyStore>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(key) = request.headers().get(&IDEMPOTENCY_KEY_HEADER) else {
+        return next.run(request).await;
+    };
+    let key = match key.to_str() {
+        Ok(key) if !key.trim().is_empty() && key.len() <= MAX_IDEMPOTENCY_KEY_LENGTH => {
+            key.trim().to_string()
+        }
+        _ => {
+            let message = format!(
+                "Idempotency-Key must be 1-{MAX_IDEMPOTENCY_KEY_LENGTH} visible ASCII characters"
+            );
+            return ApiError::BadRequest { message }.into_response();
+        }
+    };
+    let caller = request
+        .extensions()
+        .get::<Claims>()
+        .map(|claims| claims.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string());
+    let scoped_key = format!("{caller}:{key}");
+
+    let (parts, body) = request.into_parts();
+    let body = match to_bytes(body, store.max_body_size).await {
+        Ok(body) => body,
+        Err(e) if std::error::Error::source(&e).is_some_and(|e| e.is::<LengthLimitError>()) => {
+            return ApiError::PayloadTooLarge {
+                limit: store.max_body_size,
+            }
+            .into_response()
+        }
+        Err(e) => {
+            return ApiError::BadRequest {
+                message: format!("Failed to read request body: {e}"),
+            }
+            .into_response()
+        }
+    };
+    let mut fingerprint = Sha256::new();
+    fingerprint.update(parts.method.as_str().as_bytes());
+    fingerprint.update(parts.uri.path().as_bytes());
+    fingerprint.update(&body);
+    let fingerprint: [u8; 32] = fingerprint.finalize().into();
+
+    loop {
+        match store.claim(&scoped_key, fingerprint) {
+            Claim::New(guard) => {
+                let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+                if !response.status().is_success() {
+                    return response;
+                }
+                let (mut response_parts, response_body) = response.into_parts();
+                let response_body = match to_bytes(response_body, usize::MAX).await {
+                    Ok(bytes) => bytes,
+                    Err(e) => {
+                        warn!("Failed to buffer response for idempotency key: {}", e);
+                        return Response::from_parts(response_parts, Body::empty());
+                    }
+                };
+                guard.complete(StoredResponse {
+                    status: response_parts.status,
+                    content_type: response_parts.headers.get(header::CONTENT_TYPE).cloned(),
+                    body: response_body.clone(),
+                });
+                response_parts.headers.remove(header::CONTENT_LENGTH);
+                return Response::from_parts(response_parts, Body::from(response_body));
+            }
+            Claim::Replay(stored) => {
+                info!("Replaying response for idempotency key of {}", caller);
+                return stored.replay();
+            }
+            Claim::Wait(mut receiver) => {
+                debug!("Waiting for original request with the same idempotency key");
+                if let Ok(stored) = receiver.wait_for(Option::is_some).await {
+                    if let Some(stored) = stored.clone() {
+                        return stored.replay();
+                    }
+                }
+                // The original request failed and released the key; run this one
+            }
+            Claim::Mismatch => {
+                return ApiError::ValidationError {
+                    message: "Idempotency-Key was already used with a different request"
+                        .to_string(),
+                }
+                .into_response()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{middleware::from_fn_with_state, routing::post, Router};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tower::ServiceExt;
+
+    fn app(store: IdempotencyStore, runs: Arc<AtomicUsize>) -> Router {
+        Router::new().route(
+            "/api/v1/research",
+            post(move |body: String| {
+                let runs = runs.clone();
+                async move {
+                    let run = runs.fetch_add(1, Ordering::SeqCst) + 1;
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    if body == "fail" {
+                        return (StatusCode::INTERNAL_SERVER_ERROR, "failed".to_string());
+                    }
+                    (StatusCode::CREATED, format!("run {run}: {body}"))
+                }
+            })
+            .layer(from_fn_with_state(store, idempotency_middleware)),
+        )
+    }
+
+    fn request(key: Option<&str>, body: &str) -> Request {
+        let mut builder = Request::post("/api/v1/research");
+        if let Some(key) = key {
+            builder = builder.header(IDEMPOTENCY_KEY_HEADER, key);
+        }
+        builder.body(Body::from(body.to_string())).unwrap()
+    }
+
+    async fn body_text(response: Response) -> String {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_duplicates_replay_the_original_response() {
+        let store = IdempotencyStore::default();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let app = app(store.clone(), runs.clone());
+
+        // A duplicate sent while the original runs waits for it
+        let (first, second) = tokio::join!(
+            app.clone().oneshot(request(Some("key-1"), "tokio")),
+            app.clone().oneshot(request(Some("key-1"), "tokio")),
+        );
+        let (first, second) = (first.unwrap(), second.unwrap());
+        assert_eq!(first.status(), StatusCode::CREATED);
+        assert_eq!(second.status(), StatusCode::CREATED);
+        assert!(second.headers().contains_key(IDEMPOTENT_REPLAY_HEADER));
+        assert_eq!(body_text(second).await, "run 1: tokio");
+
+        let later = app
+            .clone()
+            .oneshot(request(Some("key-1"), "tokio"))
+            .await
+            .unwrap();
+        assert_eq!(body_text(later).await, "run 1: tokio");
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        let reused = app
+            .clone()
+            .oneshot(request(Some("key-1"), "async-std"))
+            .await
+            .unwrap();
+        assert_eq!(reused.status(), StatusCode::BAD_REQUEST);
+
+        let unkeyed = app.oneshot(request(None, "tokio")).await.unwrap();
+        assert_eq!(body_text(unkeyed).await, "run 2: tokio");
+        assert_eq!(store.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_request_releases_its_key() {
+        let store = IdempotencyStore::default();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let app = app(store.clone(), runs.clone());
+
+        let failed = app
+            .clone()
+            .oneshot(request(Some("key-2"), "fail"))
+            .await
+            .unwrap();
+        assert_eq!(failed.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(store.is_empty());
+
+        let retried = app.oneshot(request(Some("key-2"), "fail")).await.unwrap();
+        assert!(!retried.headers().contains_key(IDEMPOTENT_REPLAY_HEADER));
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_is_refused() {
+        let store = IdempotencyStore::default().with_max_body_size(8);
+        let runs = Arc::new(AtomicUsize::new(0));
+        let app = app(store.clone(), runs.clone());
+
+        let refused = app
+            .clone()
+            .oneshot(request(Some("key-3"), "tokio vs async-std"))
+            .await
+            .unwrap();
+        assert_eq!(refused.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert!(store.is_empty());
+
+        let accepted = app.oneshot(request(Some("key-3"), "tokio")).await.unwrap();
+        assert_eq!(accepted.status(), StatusCode::CREATED);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+}
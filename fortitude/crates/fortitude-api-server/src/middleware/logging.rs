@@ -14,8 +14,36 @@
 
 // ABOUTME: Logging middleware for request/response tracing and monitoring
 
-use tower_http::trace::{DefaultMakeSpan, DefaultOnRequest, DefaultOnResponse, TraceLayer};
-use tracing::Level;
+use crate::telemetry;
+use axum::http::Request;
+use tower_http::trace::{DefaultOnRequest, DefaultOnResponse, MakeSpan, TraceLayer};
+use tracing::{Level, Span};
+
+/// Root span of an HTTP request
+///
+/// Carries the `x-request-id` assigned to the request and continues the
+/// caller's trace when a W3C `traceparent` header is present.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RequestSpan;
+
+impl<B> MakeSpan<B> for RequestSpan {
+    fn make_span(&mut self, request: &Request<B>) -> Span {
+        let request_id = request
+            .headers()
+            .get("x-request-id")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        let span = tracing::info_span!(
+            "request",
+            method = %request.method(),
+            uri = %request.uri(),
+            version = ?request.version(),
+            request_id = %request_id,
+        );
+        telemetry::set_remote_parent(&span, request.headers());
+        span
+    }
+}
 
 /// Create a tracing layer for request/response logging
 ///
@@ -23,12 +51,12 @@ use tracing::Level;
 /// including timing information and error details.
 pub fn create_trace_layer<B>() -> TraceLayer<
     tower_http::classify::SharedClassifier<tower_http::classify::ServerErrorsAsFailures>,
-    DefaultMakeSpan,
+    RequestSpan,
     DefaultOnRequest,
     DefaultOnResponse,
 > {
     TraceLayer::new_for_http()
-        .make_span_with(DefaultMakeSpan::new().level(Level::INFO))
+        .make_span_with(RequestSpan)
         .on_request(DefaultOnRequest::new().level(Level::INFO))
         .on_response(DefaultOnResponse::new().level(Level::INFO))
 }
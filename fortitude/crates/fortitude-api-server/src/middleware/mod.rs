@@ -13,11 +13,15 @@
 // limitations under the License.
 
 // ABOUTME: HTTP middleware for Fortitude API server
-// Provides authentication, CORS, logging, rate limiting, and security middleware
+// Provides authentication, conditional GETs, CORS, idempotency, logging, rate limiting, versioning, and security middleware
 
 pub mod auth;
+pub mod conditional;
 pub mod cors;
+pub mod idempotency;
 pub mod logging;
 pub mod monitoring;
 pub mod pattern_tracking;
 pub mod rate_limit;
+pub mod rbac;
+pub mod versioning;
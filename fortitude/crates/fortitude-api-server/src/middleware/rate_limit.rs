@@ -12,28 +12,296 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-// ABOUTME: Rate limiting middleware using tower-governor for API protection
-// NOTE: Placeholder implementation - will be completed in Task 2: Basic Route Implementation
+// ABOUTME: Token-bucket rate limiting of authenticated requests per API key
+// Request limits come from the key's quota tier; responses carry remaining request and token quota headers
 
-/// Create a rate limiting layer (placeholder implementation)
+use crate::config::{QuotaConfig, RateLimitConfig};
+use crate::middleware::auth::{get_client_id, Claims};
+use crate::models::errors::ApiError;
+use crate::quota::QuotaTracker;
+use axum::{
+    extract::{Request, State},
+    http::{header::RETRY_AFTER, HeaderMap, HeaderName, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+const LIMIT_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+const REMAINING_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
+const RESET_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-reset");
+const TOKEN_LIMIT_HEADER: HeaderName = HeaderName::from_static("x-quota-tokens-limit");
+const TOKEN_REMAINING_HEADER: HeaderName = HeaderName::from_static("x-quota-tokens-remaining");
+const TOKEN_RESET_HEADER: HeaderName = HeaderName::from_static("x-quota-tokens-reset");
+
+/// Request bucket of one key
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Request allowance of a key at one point in time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitStatus {
+    /// Requests the key may burst; refilled evenly over the window
+    pub limit: u32,
+    /// Requests the key can make right now
+    pub remaining: u32,
+    /// Time until the bucket is full again
+    pub reset_after: Duration,
+    /// Time until the next request is allowed; `None` when one is allowed now
+    pub retry_after: Option<Duration>,
+}
+
+/// Per-key token-bucket request limiter
 ///
-/// This limits requests per IP address to prevent abuse and ensure fair usage.
-/// Default configuration allows 60 requests per minute per IP.
+/// Each key holds up to its tier's `max_requests_per_window` requests
+/// (`rate_limit.max_requests_per_minute` when the tier sets none), refilled
+/// at that many per window. A noisy key drains only its own bucket.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    default_limit: u32,
+    window: Duration,
+    quota: Arc<QuotaConfig>,
+    buckets: Arc<Mutex<HashMap<String, TokenBucket>>>,
+}
+
+impl RateLimiter {
+    pub fn new(rate_limit: &RateLimitConfig, quota: QuotaConfig) -> Self {
+        Self {
+            default_limit: rate_limit.max_requests_per_minute,
+            window: Duration::from_secs(rate_limit.window_seconds.max(1)),
+            quota: Arc::new(quota),
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Requests per window allowed for a key
+    pub fn limit_for(&self, key: &str) -> u32 {
+        self.quota
+            .tier_for(key)
+            .1
+            .max_requests_per_window
+            .unwrap_or(self.default_limit)
+            .max(1)
+    }
+
+    /// Length of the refill window in seconds
+    pub fn window_seconds(&self) -> u64 {
+        self.window.as_secs()
+    }
+
+    /// Take one request from a key's bucket
+    ///
+    /// The returned status has `retry_after` set when the bucket was empty
+    /// and the request must be rejected.
+    pub fn acquire(&self, key: &str) -> RateLimitStatus {
+        self.acquire_at(key, Instant::now())
+    }
+
+    /// Allowance of a key without using any of it
+    pub fn status(&self, key: &str) -> RateLimitStatus {
+        self.status_at(key, Instant::now())
+    }
+
+    fn acquire_at(&self, key: &str, now: Instant) -> RateLimitStatus {
+        let limit = self.limit_for(key);
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(key.to_string()).or_insert(TokenBucket {
+            tokens: limit as f64,
+            updated: now,
+        });
+        self.refill(bucket, limit, now);
+
+        let allowed = bucket.tokens >= 1.0;
+        if allowed {
+            bucket.tokens -= 1.0;
+        }
+        let mut status = self.describe(bucket.tokens, limit);
+        if !allowed {
+            status.retry_after = Some(self.time_to_refill(1.0 - bucket.tokens, limit));
+        }
+        status
+    }
+
+    fn status_at(&self, key: &str, now: Instant) -> RateLimitStatus {
+        let limit = self.limit_for(key);
+        let tokens = match self.buckets.lock().unwrap().get(key) {
+            Some(bucket) => {
+                let mut bucket = *bucket;
+                self.refill(&mut bucket, limit, now);
+                bucket.tokens
+            }
+            None => limit as f64,
+        };
+        self.describe(tokens, limit)
+    }
+
+    fn refill(&self, bucket: &mut TokenBucket, limit: u32, now: Instant) {
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        let rate = limit as f64 / self.window.as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rate).min(limit as f64);
+        bucket.updated = now;
+    }
+
+    fn time_to_refill(&self, tokens: f64, limit: u32) -> Duration {
+        let rate = limit as f64 / self.window.as_secs_f64();
+        Duration::from_secs_f64(tokens.max(0.0) / rate)
+    }
+
+    fn describe(&self, tokens: f64, limit: u32) -> RateLimitStatus {
+        RateLimitStatus {
+            limit,
+            remaining: tokens.floor() as u32,
+            reset_after: self.time_to_refill(limit as f64 - tokens, limit),
+            retry_after: None,
+        }
+    }
+}
+
+/// Rate limit middleware state
+#[derive(Clone)]
+pub struct RateLimitState {
+    pub limiter: RateLimiter,
+    /// Token and budget accounting, reported in quota headers
+    pub quota: QuotaTracker,
+}
+
+/// Rate limit requests per API key
 ///
-/// TODO: Implement actual rate limiting with tower-governor in next sprint task
-pub fn create_rate_limit_layer() -> tower::layer::util::Identity {
-    // Placeholder - returns identity layer that doesn't modify requests
-    tower::layer::util::Identity::new()
+/// Runs after `jwt_auth_middleware` and keys buckets by the claims subject,
+/// falling back to the client address. Rejected requests get `429` with
+/// `Retry-After`; so do requests a handler rejects for an exhausted token
+/// or budget quota.
+pub async fn rate_limit_middleware(
+    State(state): State<RateLimitState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let key = request
+        .extensions()
+        .get::<Claims>()
+        .map(|claims| claims.sub.clone())
+        .unwrap_or_else(|| format!("ip:{}", get_client_id(request.headers())));
+
+    let status = state.limiter.acquire(&key);
+    let mut response = match status.retry_after {
+        Some(retry_after) => {
+            warn!(
+                "Rate limit exceeded for key {}: retry after {:?}",
+                key, retry_after
+            );
+            let mut response = ApiError::RateLimitExceeded.into_response();
+            set_header(response.headers_mut(), RETRY_AFTER, ceil_secs(retry_after));
+            response
+        }
+        None => next.run(request).await,
+    };
+
+    if response.status() == StatusCode::TOO_MANY_REQUESTS
+        && !response.headers().contains_key(RETRY_AFTER)
+    {
+        if let Some(until) = state.quota.exhausted_until(&key) {
+            let wait = (until - Utc::now()).to_std().unwrap_or_default();
+            set_header(response.headers_mut(), RETRY_AFTER, ceil_secs(wait));
+        }
+    }
+
+    let headers = response.headers_mut();
+    set_header(headers, LIMIT_HEADER, status.limit as u64);
+    set_header(headers, REMAINING_HEADER, status.remaining as u64);
+    set_header(
+        headers,
+        RESET_HEADER,
+        (Utc::now().timestamp() as u64) + ceil_secs(status.reset_after),
+    );
+
+    let tokens = state.quota.token_status(&key);
+    if let (Some(limit), Some(remaining)) = (tokens.limit, tokens.remaining) {
+        set_header(headers, TOKEN_LIMIT_HEADER, limit);
+        set_header(headers, TOKEN_REMAINING_HEADER, remaining);
+        set_header(
+            headers,
+            TOKEN_RESET_HEADER,
+            tokens.resets_at.timestamp().max(0) as u64,
+        );
+    }
+
+    response
+}
+
+fn set_header(headers: &mut HeaderMap, name: HeaderName, value: u64) {
+    headers.insert(name, HeaderValue::from(value));
+}
+
+/// Whole seconds to wait, never zero so clients do not retry immediately
+fn ceil_secs(duration: Duration) -> u64 {
+    duration.as_secs_f64().ceil().max(1.0) as u64
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::QuotaTier;
+
+    fn limiter() -> RateLimiter {
+        let mut quota = QuotaConfig::default();
+        quota.tiers.insert(
+            "trial".to_string(),
+            QuotaTier {
+                max_requests_per_window: Some(2),
+                ..Default::default()
+            },
+        );
+        quota
+            .key_tiers
+            .insert("alice".to_string(), "trial".to_string());
+        let rate_limit = RateLimitConfig {
+            max_requests_per_minute: 5,
+            window_seconds: 60,
+        };
+        RateLimiter::new(&rate_limit, quota)
+    }
+
+    #[test]
+    fn test_bucket_is_per_key_and_refills() {
+        let limiter = limiter();
+        let now = Instant::now();
+
+        assert_eq!(limiter.limit_for("alice"), 2);
+        assert_eq!(limiter.limit_for("bob"), 5);
+
+        assert!(limiter.acquire_at("alice", now).retry_after.is_none());
+        let status = limiter.acquire_at("alice", now);
+        assert!(status.retry_after.is_none());
+        assert_eq!(status.remaining, 0);
+
+        // Alice is out of requests; a refill takes 30s at 2 per minute
+        let rejected = limiter.acquire_at("alice", now);
+        assert_eq!(rejected.retry_after, Some(Duration::from_secs(30)));
+        assert_eq!(rejected.reset_after, Duration::from_secs(60));
+
+        // Other keys are unaffected
+        assert_eq!(limiter.acquire_at("bob", now).remaining, 4);
+
+        let later = now + Duration::from_secs(30);
+        assert_eq!(limiter.status_at("alice", later).remaining, 1);
+        assert!(limiter.acquire_at("alice", later).retry_after.is_none());
+        assert!(limiter.acquire_at("alice", later).retry_after.is_some());
+    }
 
     #[test]
-    fn test_rate_limit_layer_creation() {
-        // This test ensures the rate limit layer can be created without panic
-        let _rate_limit_layer = create_rate_limit_layer();
-        // If we reach here, the layer was created successfully
+    fn test_status_of_unseen_key_is_full() {
+        let limiter = limiter();
+        let status = limiter.status("carol");
+        assert_eq!(status.remaining, 5);
+        assert_eq!(status.reset_after, Duration::ZERO);
+        assert_eq!(ceil_secs(Duration::from_millis(1500)), 2);
+        assert_eq!(ceil_secs(Duration::ZERO), 1);
     }
 }
@@ -0,0 +1,396 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Role-based access control with API keys mapped to read-only, researcher and admin roles
+// Keys authenticate as claims carrying their role's permissions, checked per route by require_permission
+
+use crate::config::ApiKeysConfig;
+use crate::middleware::auth::{Claims, Permission};
+use axum::http::HeaderMap;
+use chrono::{DateTime, Utc};
+use fortitude_core::write_json_atomic_async;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use tracing::{error, info, warn};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Header API keys are sent in
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Prefix of generated API keys
+const API_KEY_PREFIX: &str = "ftk_";
+
+/// Leading characters of a key kept to identify it in listings
+const DISPLAY_PREFIX_LEN: usize = 12;
+
+/// Claims subject prefix of API key callers
+const KEY_SUBJECT_PREFIX: &str = "key:";
+
+/// Role granted to an API key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum Role {
+    /// Runs research and reads results, cache, learning and monitoring data
+    ReadOnly,
+    /// Read-only plus curation, imports, feedback and proactive research control
+    Researcher,
+    /// Everything, including cache invalidation, configuration and key management
+    Admin,
+}
+
+impl Role {
+    /// Permissions carried by callers with this role
+    pub fn permissions(&self) -> Vec<Permission> {
+        let read_only = [
+            Permission::ResearchRead,
+            Permission::ResourcesRead,
+            Permission::ConfigRead,
+            Permission::LearningRead,
+            Permission::MonitoringRead,
+        ];
+        match self {
+            Role::ReadOnly => read_only.to_vec(),
+            Role::Researcher => read_only
+                .into_iter()
+                .chain([Permission::ReadWrite, Permission::LearningWrite])
+                .collect(),
+            Role::Admin => Permission::all(),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::ReadOnly => "read-only",
+            Role::Researcher => "researcher",
+            Role::Admin => "admin",
+        }
+    }
+}
+
+impl std::str::FromStr for Role {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('_', "-").as_str() {
+            "read-only" | "readonly" => Ok(Role::ReadOnly),
+            "researcher" => Ok(Role::Researcher),
+            "admin" => Ok(Role::Admin),
+            other => Err(format!(
+                "Invalid role: {other} (expected read-only, researcher or admin)"
+            )),
+        }
+    }
+}
+
+/// Stored API key; only a hash of the secret is kept
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKeyRecord {
+    pub id: String,
+    pub name: String,
+    pub role: Role,
+    /// Leading characters of the secret, for telling keys apart
+    pub prefix: String,
+    key_hash: String,
+    pub created_at: DateTime<Utc>,
+    pub rotated_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKeyRecord {
+    /// Claims of requests authenticated with this key
+    pub fn claims(&self) -> Claims {
+        let now = Utc::now().timestamp();
+        Claims {
+            sub: format!("{KEY_SUBJECT_PREFIX}{}", self.id),
+            permissions: self
+                .role
+                .permissions()
+                .iter()
+                .map(|p| p.as_str().to_string())
+                .collect(),
+            exp: i64::MAX,
+            iat: now,
+            iss: "fortitude-api-server".to_string(),
+        }
+    }
+}
+
+fn hash_key(key: &str) -> String {
+    Sha256::digest(key.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn generate_key() -> String {
+    format!(
+        "{API_KEY_PREFIX}{}{}",
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    )
+}
+
+/// ID of the key configured with `bootstrap_admin_key`
+const BOOTSTRAP_KEY_ID: &str = "bootstrap";
+
+/// API keys by ID, shared by the auth middleware and the key management routes
+#[derive(Debug, Clone)]
+pub struct ApiKeyStore {
+    keys: Arc<RwLock<HashMap<String, ApiKeyRecord>>>,
+    store_path: Option<PathBuf>,
+    // Serializes file writes so an older snapshot never replaces a newer one
+    save_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl Default for ApiKeyStore {
+    fn default() -> Self {
+        Self::new(&ApiKeysConfig::default())
+    }
+}
+
+impl ApiKeyStore {
+    /// Create a store, loading saved keys when a store file is configured
+    ///
+    /// A configured bootstrap key is added as an admin key if no stored key
+    /// matches it. It is held in memory only and never written to the store
+    /// file, so removing it from the configuration removes it on restart.
+    pub fn new(config: &ApiKeysConfig) -> Self {
+        let store_path = config.store_path.as_ref().map(PathBuf::from);
+        let mut keys = match &store_path {
+            Some(path) if path.exists() => match load_keys(path) {
+                Ok(keys) => {
+                    info!("Loaded {} API keys from {}", keys.len(), path.display());
+                    keys
+                }
+                Err(e) => {
+                    warn!("Failed to load API keys from {}: {}", path.display(), e);
+                    HashMap::new()
+                }
+            },
+            _ => HashMap::new(),
+        };
+        // Stores written by earlier versions may hold a stale bootstrap record
+        keys.remove(BOOTSTRAP_KEY_ID);
+
+        if let Some(secret) = config
+            .bootstrap_admin_key
+            .as_deref()
+            .filter(|k| !k.is_empty())
+        {
+            let key_hash = hash_key(secret);
+            if !keys.values().any(|record| record.key_hash == key_hash) {
+                keys.insert(
+                    BOOTSTRAP_KEY_ID.to_string(),
+                    ApiKeyRecord {
+                        id: BOOTSTRAP_KEY_ID.to_string(),
+                        name: "bootstrap admin".to_string(),
+                        role: Role::Admin,
+                        prefix: secret.chars().take(DISPLAY_PREFIX_LEN).collect(),
+                        key_hash,
+                        created_at: Utc::now(),
+                        rotated_at: None,
+                    },
+                );
+            }
+        }
+
+        Self {
+            keys: Arc::new(RwLock::new(keys)),
+            store_path,
+            save_lock: Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    /// Record of the key with this secret
+    pub fn authenticate(&self, secret: &str) -> Option<ApiKeyRecord> {
+        let key_hash = hash_key(secret);
+        self.keys
+            .read()
+            .unwrap()
+            .values()
+            .find(|record| record.key_hash == key_hash)
+            .cloned()
+    }
+
+    /// All keys, oldest first
+    pub fn list(&self) -> Vec<ApiKeyRecord> {
+        let mut records: Vec<_> = self.keys.read().unwrap().values().cloned().collect();
+        records.sort_by_key(|record| record.created_at);
+        records
+    }
+
+    /// Create a key; the secret is returned once and never stored
+    pub async fn create(&self, name: &str, role: Role) -> (ApiKeyRecord, String) {
+        let secret = generate_key();
+        let record = ApiKeyRecord {
+            id: Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            role,
+            prefix: secret.chars().take(DISPLAY_PREFIX_LEN).collect(),
+            key_hash: hash_key(&secret),
+            created_at: Utc::now(),
+            rotated_at: None,
+        };
+        self.keys
+            .write()
+            .unwrap()
+            .insert(record.id.clone(), record.clone());
+        self.save().await;
+        (record, secret)
+    }
+
+    /// Replace the secret of a key, invalidating the old one
+    pub async fn rotate(&self, id: &str) -> Option<(ApiKeyRecord, String)> {
+        let secret = generate_key();
+        let record = {
+            let mut keys = self.keys.write().unwrap();
+            let record = keys.get_mut(id)?;
+            record.prefix = secret.chars().take(DISPLAY_PREFIX_LEN).collect();
+            record.key_hash = hash_key(&secret);
+            record.rotated_at = Some(Utc::now());
+            record.clone()
+        };
+        self.save().await;
+        Some((record, secret))
+    }
+
+    /// Delete a key; returns whether it existed
+    pub async fn revoke(&self, id: &str) -> bool {
+        let removed = self.keys.write().unwrap().remove(id).is_some();
+        if removed {
+            self.save().await;
+        }
+        removed
+    }
+
+    /// Write all keys but the bootstrap key to the store file, if configured
+    ///
+    /// Failures are logged; the keys stay in memory.
+    async fn save(&self) {
+        let Some(path) = &self.store_path else {
+            return;
+        };
+        let _guard = self.save_lock.lock().await;
+        let snapshot: HashMap<_, _> = self
+            .keys
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(id, _)| id.as_str() != BOOTSTRAP_KEY_ID)
+            .map(|(id, record)| (id.clone(), record.clone()))
+            .collect();
+        if let Err(e) = write_json_atomic_async(path, &snapshot).await {
+            error!("Failed to save API keys to {}: {}", path.display(), e);
+        }
+    }
+}
+
+fn load_keys(path: &Path) -> std::io::Result<HashMap<String, ApiKeyRecord>> {
+    let content = std::fs::read(path)?;
+    Ok(serde_json::from_slice(&content)?)
+}
+
+/// API key sent with a request, if any
+pub(crate) fn extract_api_key(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(API_KEY_HEADER)
+        .and_then(|h| h.to_str().ok())
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_permissions() {
+        let read_only = Role::ReadOnly.permissions();
+        assert!(read_only.contains(&Permission::ResearchRead));
+        assert!(!read_only.contains(&Permission::ReadWrite));
+        assert!(Role::Researcher
+            .permissions()
+            .contains(&Permission::ReadWrite));
+        assert!(!Role::Researcher.permissions().contains(&Permission::Admin));
+        assert!(Role::Admin.permissions().contains(&Permission::Admin));
+        assert_eq!("read_only".parse::<Role>().unwrap(), Role::ReadOnly);
+        assert!("owner".parse::<Role>().is_err());
+    }
+
+    #[test]
+    fn test_key_claims() {
+        let claims = ApiKeyRecord {
+            id: "k".to_string(),
+            name: "ci".to_string(),
+            role: Role::Researcher,
+            prefix: String::new(),
+            key_hash: String::new(),
+            created_at: Utc::now(),
+            rotated_at: None,
+        }
+        .claims();
+        assert_eq!(claims.sub, "key:k");
+        assert!(claims
+            .permissions
+            .contains(&Permission::ReadWrite.as_str().to_string()));
+        assert!(!claims
+            .permissions
+            .contains(&Permission::Admin.as_str().to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_key_lifecycle_and_persistence() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = ApiKeysConfig {
+            store_path: Some(temp_dir.path().join("keys.json").display().to_string()),
+            bootstrap_admin_key: Some("bootstrap-secret".to_string()),
+        };
+        let store = ApiKeyStore::new(&config);
+        assert_eq!(
+            store.authenticate("bootstrap-secret").unwrap().role,
+            Role::Admin
+        );
+
+        let (record, secret) = store.create("ci", Role::ReadOnly).await;
+        assert!(secret.starts_with(API_KEY_PREFIX));
+        assert_eq!(store.authenticate(&secret).unwrap().id, record.id);
+
+        let (rotated, new_secret) = store.rotate(&record.id).await.unwrap();
+        assert!(rotated.rotated_at.is_some());
+        assert!(store.authenticate(&secret).is_none());
+        assert!(store.authenticate(&new_secret).is_some());
+
+        // Saved keys survive a restart; secrets and the bootstrap key are never written
+        let saved = std::fs::read_to_string(temp_dir.path().join("keys.json")).unwrap();
+        assert!(!saved.contains(&new_secret));
+        assert!(!saved.contains(&hash_key("bootstrap-secret")));
+        let saved: HashMap<String, ApiKeyRecord> = serde_json::from_str(&saved).unwrap();
+        assert_eq!(saved.keys().collect::<Vec<_>>(), [&record.id]);
+        let reloaded = ApiKeyStore::new(&config);
+        assert_eq!(reloaded.authenticate(&new_secret).unwrap().name, "ci");
+        assert!(reloaded.authenticate("bootstrap-secret").is_some());
+        let without_bootstrap = ApiKeyStore::new(&ApiKeysConfig {
+            bootstrap_admin_key: None,
+            ..config.clone()
+        });
+        assert!(without_bootstrap.authenticate("bootstrap-secret").is_none());
+
+        assert!(reloaded.revoke(&record.id).await);
+        assert!(!reloaded.revoke(&record.id).await);
+        assert!(reloaded.authenticate(&new_secret).is_none());
+    }
+}
@@ -0,0 +1,304 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: API version routing, negotiation and deprecation headers
+// Serves /api/v2 from the shared v1 handlers and reshapes responses per version
+
+use crate::config::VersioningConfig;
+use axum::{
+    body::{to_bytes, Body},
+    extract::{Request, State},
+    http::{header, HeaderValue, Uri},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    routing::any,
+    Router,
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tower::ServiceExt;
+use tracing::{debug, warn};
+
+/// Request/response header carrying the API version
+pub const API_VERSION_HEADER: &str = "api-version";
+
+/// Supported API versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiVersion {
+    V1,
+    V2,
+}
+
+impl ApiVersion {
+    /// Latest API version
+    pub const CURRENT: ApiVersion = ApiVersion::V2;
+
+    /// All supported versions, oldest first
+    pub fn all() -> [ApiVersion; 2] {
+        [ApiVersion::V1, ApiVersion::V2]
+    }
+
+    /// Short version label ("v1", "v2")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiVersion::V1 => "v1",
+            ApiVersion::V2 => "v2",
+        }
+    }
+
+    /// Path prefix for this version
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            ApiVersion::V1 => "/api/v1",
+            ApiVersion::V2 => "/api/v2",
+        }
+    }
+
+    /// Parse a version label ("v2", "V2" or "2")
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().trim_start_matches(['v', 'V']) {
+            "1" => Some(ApiVersion::V1),
+            "2" => Some(ApiVersion::V2),
+            _ => None,
+        }
+    }
+
+    /// Determine the version addressed by a request path
+    pub fn from_path(path: &str) -> Option<Self> {
+        Self::all().into_iter().find(|version| {
+            path.strip_prefix(version.prefix())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    }
+}
+
+/// Mount `/api/v2/*` on the router, forwarding to the shared v1 handlers
+///
+/// Must be called after all `/api/v1` routes are registered.
+pub fn with_v2_routes(app: Router) -> Router {
+    let inner = app.clone();
+    app.route(
+        "/api/v2/{*path}",
+        any(forward_to_shared_handlers).with_state(inner),
+    )
+}
+
+/// Rewrite a v2 request onto the v1 route table
+async fn forward_to_shared_handlers(State(inner): State<Router>, mut req: Request) -> Response {
+    let path_and_query = req
+        .uri()
+        .path_and_query()
+        .map(|pq| pq.as_str())
+        .unwrap_or("/");
+    let rewritten = path_and_query.replacen(ApiVersion::V2.prefix(), ApiVersion::V1.prefix(), 1);
+
+    match rewritten.parse::<Uri>() {
+        Ok(uri) => *req.uri_mut() = uri,
+        Err(e) => {
+            warn!(
+                "Failed to rewrite v2 request path '{}': {}",
+                path_and_query, e
+            );
+            return axum::http::StatusCode::BAD_REQUEST.into_response();
+        }
+    }
+    req.extensions_mut().insert(ApiVersion::V2);
+
+    match inner.oneshot(req).await {
+        Ok(response) => response,
+        Err(never) => match never {},
+    }
+}
+
+/// Negotiate the response version and attach version/deprecation headers
+///
+/// The path selects the version; a `/api/v1` request may also opt into the
+/// v2 response shape with an `API-Version: v2` header.
+pub async fn versioning_middleware(
+    State(config): State<Arc<VersioningConfig>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let path = req.uri().path().to_string();
+    let Some(path_version) = ApiVersion::from_path(&path) else {
+        return next.run(req).await;
+    };
+
+    let requested = req
+        .headers()
+        .get(API_VERSION_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(ApiVersion::parse);
+    let version = match (path_version, requested) {
+        (ApiVersion::V1, Some(ApiVersion::V2)) => ApiVersion::V2,
+        _ => path_version,
+    };
+
+    let response = next.run(req).await;
+    let mut response = match version {
+        ApiVersion::V1 => response,
+        ApiVersion::V2 => serialize_v2(response).await,
+    };
+
+    let headers = response.headers_mut();
+    headers.insert(
+        API_VERSION_HEADER,
+        HeaderValue::from_static(version.as_str()),
+    );
+
+    if version == ApiVersion::V1 && config.v1_deprecated {
+        headers.insert("deprecation", HeaderValue::from_static("true"));
+        if let Some(sunset) = config
+            .v1_sunset
+            .as_deref()
+            .and_then(|s| HeaderValue::from_str(s).ok())
+        {
+            headers.insert("sunset", sunset);
+        }
+        let successor = path.replacen(ApiVersion::V1.prefix(), ApiVersion::V2.prefix(), 1);
+        if let Ok(link) =
+            HeaderValue::from_str(&format!("<{successor}>; rel=\"successor-version\""))
+        {
+            headers.insert(header::LINK, link);
+        }
+    }
+
+    response
+}
+
+/// Re-serialize a JSON response body with the v2 envelope
+async fn serialize_v2(response: Response) -> Response {
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("application/json"));
+    if !is_json {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            warn!("Failed to buffer response for v2 serialization: {}", e);
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+
+    let body = match serde_json::from_slice::<Value>(&bytes) {
+        Ok(value) => serde_json::to_vec(&v2_envelope(value)).unwrap_or_else(|_| bytes.to_vec()),
+        Err(_) => {
+            debug!("Response body is not valid JSON, passing through unchanged");
+            bytes.to_vec()
+        }
+    };
+
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// Convert a v1 `ApiResponse` envelope into the v2 shape
+///
+/// v1: `{ "data", "request_id", "timestamp", "success", "warnings"? }`
+/// v2: `{ "data", "meta": { "request_id", "timestamp", "api_version", "warnings"? } }`
+///
+/// Values that are not v1 envelopes (e.g. error bodies) are returned unchanged.
+pub fn v2_envelope(value: Value) -> Value {
+    let Value::Object(mut map) = value else {
+        return value;
+    };
+    if !(map.contains_key("data") && map.contains_key("success")) {
+        return Value::Object(map);
+    }
+
+    let data = map.remove("data").unwrap_or(Value::Null);
+    let mut envelope = json!({
+        "data": data,
+        "meta": {
+            "request_id": map.remove("request_id").unwrap_or(Value::Null),
+            "timestamp": map.remove("timestamp").unwrap_or(Value::Null),
+            "api_version": ApiVersion::V2.as_str(),
+        }
+    });
+    if let Some(warnings) = map.remove("warnings") {
+        envelope["meta"]["warnings"] = warnings;
+    }
+    envelope
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_from_path() {
+        assert_eq!(
+            ApiVersion::from_path("/api/v1/research"),
+            Some(ApiVersion::V1)
+        );
+        assert_eq!(
+            ApiVersion::from_path("/api/v2/cache/stats"),
+            Some(ApiVersion::V2)
+        );
+        assert_eq!(ApiVersion::from_path("/api/v10/research"), None);
+        assert_eq!(ApiVersion::from_path("/health"), None);
+    }
+
+    #[test]
+    fn test_version_parse() {
+        assert_eq!(ApiVersion::parse("v2"), Some(ApiVersion::V2));
+        assert_eq!(ApiVersion::parse("V1"), Some(ApiVersion::V1));
+        assert_eq!(ApiVersion::parse("2"), Some(ApiVersion::V2));
+        assert_eq!(ApiVersion::parse("v3"), None);
+    }
+
+    #[test]
+    fn test_v2_envelope_reshapes_api_response() {
+        let v1 = json!({
+            "data": {"id": "abc"},
+            "request_id": "00000000-0000-0000-0000-000000000000",
+            "timestamp": "2025-01-01T00:00:00Z",
+            "success": true
+        });
+
+        let v2 = v2_envelope(v1);
+        assert_eq!(v2["data"]["id"], "abc");
+        assert_eq!(v2["meta"]["api_version"], "v2");
+        assert_eq!(v2["meta"]["timestamp"], "2025-01-01T00:00:00Z");
+        assert!(v2.get("success").is_none());
+        assert!(v2["meta"].get("warnings").is_none());
+    }
+
+    #[test]
+    fn test_v2_envelope_moves_warnings_into_meta() {
+        let v1 = json!({
+            "data": {"id": "abc"},
+            "request_id": "00000000-0000-0000-0000-000000000000",
+            "timestamp": "2025-01-01T00:00:00Z",
+            "success": true,
+            "warnings": [{"code": "stale_cache_served", "message": "old"}]
+        });
+
+        let v2 = v2_envelope(v1);
+        assert_eq!(v2["meta"]["warnings"][0]["code"], "stale_cache_served");
+        assert!(v2.get("warnings").is_none());
+    }
+
+    #[test]
+    fn test_v2_envelope_passes_through_errors() {
+        let error = json!({"error_code": "NOT_FOUND", "message": "missing"});
+        assert_eq!(v2_envelope(error.clone()), error);
+    }
+}
@@ -48,7 +48,7 @@ pub struct ErrorResponse {
 }
 
 /// Application error types
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum ApiError {
     #[error("Validation error: {message}")]
     ValidationError { message: String },
@@ -65,6 +65,9 @@ pub enum ApiError {
     #[error("Conflict: {resource}")]
     Conflict { resource: String },
 
+    #[error("Research request {request_id} was cancelled")]
+    ResearchCancelled { request_id: Uuid },
+
     #[error("Rate limit exceeded")]
     RateLimitExceeded,
 
@@ -77,6 +80,9 @@ pub enum ApiError {
     #[error("Bad request: {message}")]
     BadRequest { message: String },
 
+    #[error("Request body exceeds {limit} bytes")]
+    PayloadTooLarge { limit: usize },
+
     #[error("Research engine error: {message}")]
     ResearchError { message: String },
 
@@ -96,8 +102,10 @@ impl ApiError {
             ApiError::Forbidden { .. } => StatusCode::FORBIDDEN,
             ApiError::NotFound { .. } => StatusCode::NOT_FOUND,
             ApiError::Conflict { .. } => StatusCode::CONFLICT,
+            ApiError::ResearchCancelled { .. } => StatusCode::CONFLICT,
             ApiError::RateLimitExceeded => StatusCode::TOO_MANY_REQUESTS,
             ApiError::BadRequest { .. } => StatusCode::BAD_REQUEST,
+            ApiError::PayloadTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
             ApiError::ServiceUnavailable { .. } => StatusCode::SERVICE_UNAVAILABLE,
             ApiError::InternalError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ApiError::ResearchError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
@@ -114,8 +122,10 @@ impl ApiError {
             ApiError::Forbidden { .. } => "FORBIDDEN",
             ApiError::NotFound { .. } => "NOT_FOUND",
             ApiError::Conflict { .. } => "CONFLICT",
+            ApiError::ResearchCancelled { .. } => "RESEARCH_CANCELLED",
             ApiError::RateLimitExceeded => "RATE_LIMIT_EXCEEDED",
             ApiError::BadRequest { .. } => "BAD_REQUEST",
+            ApiError::PayloadTooLarge { .. } => "PAYLOAD_TOO_LARGE",
             ApiError::ServiceUnavailable { .. } => "SERVICE_UNAVAILABLE",
             ApiError::InternalError { .. } => "INTERNAL_ERROR",
             ApiError::ResearchError { .. } => "RESEARCH_ERROR",
@@ -179,6 +189,15 @@ impl From<serde_json::Error> for ApiError {
     }
 }
 
+// Convert search query parse errors
+impl From<fortitude_core::QueryParseError> for ApiError {
+    fn from(err: fortitude_core::QueryParseError) -> Self {
+        ApiError::BadRequest {
+            message: format!("Invalid search query: {err}"),
+        }
+    }
+}
+
 // Convert fortitude-core errors
 impl From<fortitude_core::api::error::ApiError> for ApiError {
     fn from(err: fortitude_core::api::error::ApiError) -> Self {
@@ -262,6 +281,16 @@ mod tests {
             ApiError::RateLimitExceeded.status_code(),
             StatusCode::TOO_MANY_REQUESTS
         );
+
+        let cancelled = ApiError::ResearchCancelled {
+            request_id: Uuid::new_v4(),
+        };
+        assert_eq!(cancelled.status_code(), StatusCode::CONFLICT);
+        assert_eq!(cancelled.error_code(), "RESEARCH_CANCELLED");
+        assert_eq!(
+            ApiError::PayloadTooLarge { limit: 1024 }.status_code(),
+            StatusCode::PAYLOAD_TOO_LARGE
+        );
     }
 
     #[test]
@@ -33,7 +33,7 @@ pub struct ResearchRequest {
     #[validate(length(max = 5000, message = "Context must be less than 5000 characters"))]
     pub context: Option<String>,
 
-    /// Research priority level (low, medium, high)
+    /// Research priority level (low, medium, high, critical)
     pub priority: Option<String>,
 
     /// Optional audience context override
@@ -41,6 +41,283 @@ pub struct ResearchRequest {
 
     /// Optional domain context override
     pub domain_context: Option<DomainContextRequest>,
+
+    /// Return the execution plan without calling any provider
+    #[serde(default)]
+    pub dry_run: Option<bool>,
+
+    /// ID of an earlier research result this question follows up on
+    #[serde(default)]
+    #[validate(length(
+        min = 1,
+        max = 256,
+        message = "Parent ID must be between 1 and 256 characters"
+    ))]
+    pub parent_id: Option<String>,
+
+    /// Optional Rust source whose items are summarized into the research context
+    #[serde(default)]
+    #[validate(length(max = 100000, message = "Code must be less than 100000 characters"))]
+    pub code: Option<String>,
+
+    /// Hold the request open up to this many milliseconds for the result;
+    /// if research is still running a job reference is returned instead
+    #[serde(default)]
+    #[validate(range(
+        max = 120000,
+        message = "Wait timeout must be at most 120000 milliseconds"
+    ))]
+    pub wait_timeout_ms: Option<u64>,
+
+    /// Prompt-budget profile (e.g. concise, standard, thorough); defaults to
+    /// the profile configured for the classified research type
+    #[serde(default)]
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "Budget profile must be between 1 and 64 characters"
+    ))]
+    pub budget_profile: Option<String>,
+
+    /// Return the best answer achievable within this many milliseconds,
+    /// skipping or reducing costly steps to fit
+    #[serde(default)]
+    #[validate(range(
+        min = 100,
+        max = 600000,
+        message = "Time budget must be between 100 and 600000 milliseconds"
+    ))]
+    pub time_budget_ms: Option<u64>,
+
+    /// Include a signed, expiring token that lets anyone holding the result
+    /// submit feedback on it without an API key
+    #[serde(default)]
+    pub include_feedback_token: Option<bool>,
+
+    /// Provider to research with (openai, claude, gemini), overriding the
+    /// caller's preference; must be a configured provider
+    #[serde(default)]
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "Provider must be between 1 and 64 characters"
+    ))]
+    pub provider: Option<String>,
+
+    /// Model to research with; selects its provider when `provider` is unset
+    #[serde(default)]
+    #[validate(length(
+        min = 1,
+        max = 128,
+        message = "Model must be between 1 and 128 characters"
+    ))]
+    pub model: Option<String>,
+
+    /// Research session this question continues; earlier questions and
+    /// answers of the session are summarized into its context, and it
+    /// follows up on the session's latest result unless `parent_id` is set
+    #[serde(default)]
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "Session ID must be between 1 and 64 characters"
+    ))]
+    pub session_id: Option<String>,
+
+    /// Research the question even when the server's duplicate check finds
+    /// earlier research on a nearly identical question
+    #[serde(default)]
+    pub force: Option<bool>,
+}
+
+/// Request to start a research session
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CreateSessionRequest {
+    /// Optional label shown when listing sessions
+    #[serde(default)]
+    #[validate(length(max = 200, message = "Title must be at most 200 characters"))]
+    pub title: Option<String>,
+}
+
+/// Feedback submitted with a per-result feedback token
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema)]
+pub struct FeedbackTokenRequest {
+    /// Token returned with the research result
+    #[validate(length(
+        min = 1,
+        max = 4096,
+        message = "Token must be between 1 and 4096 characters"
+    ))]
+    pub token: String,
+
+    /// Whether the result was helpful
+    pub helpful: bool,
+
+    /// Optional rating from 1 to 5
+    #[serde(default)]
+    #[validate(range(min = 1, max = 5, message = "Rating must be between 1 and 5"))]
+    pub rating: Option<u8>,
+
+    /// Optional free-form comment
+    #[serde(default)]
+    #[validate(length(max = 2000, message = "Comment must be at most 2000 characters"))]
+    pub comment: Option<String>,
+}
+
+/// Preference profile of the calling key, replacing any earlier one
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Validate, ToSchema)]
+pub struct PreferencesRequest {
+    /// Default audience level (beginner, intermediate, advanced)
+    #[serde(default)]
+    #[validate(length(min = 1, max = 50))]
+    pub audience_level: Option<String>,
+
+    /// Default output format (markdown, json, plain)
+    #[serde(default)]
+    #[validate(length(min = 1, max = 20))]
+    pub output_format: Option<String>,
+
+    /// Default prompt-budget profile controlling answer length (concise, standard, thorough)
+    #[serde(default)]
+    #[validate(length(min = 1, max = 64))]
+    pub output_style: Option<String>,
+
+    /// Default programming language or technology (rust, python, etc.)
+    #[serde(default)]
+    #[validate(length(min = 1, max = 50))]
+    pub language: Option<String>,
+
+    /// Preferred provider when multi-provider research is enabled
+    #[serde(default)]
+    #[validate(length(min = 1, max = 64))]
+    pub provider: Option<String>,
+
+    /// Adjust audience level and output style from feedback on your results
+    #[serde(default)]
+    pub learn_from_feedback: Option<bool>,
+}
+
+/// Query parameters for fetching a research job
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Validate, ToSchema, IntoParams)]
+pub struct ResearchJobQuery {
+    /// Wait up to this many milliseconds for the job to finish
+    #[validate(range(
+        max = 120000,
+        message = "Wait timeout must be at most 120000 milliseconds"
+    ))]
+    pub wait_timeout_ms: Option<u64>,
+}
+
+/// Cost estimation request for a research query
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema)]
+pub struct ResearchEstimateRequest {
+    /// The research query or topic
+    #[validate(length(
+        min = 1,
+        max = 1000,
+        message = "Query must be between 1 and 1000 characters"
+    ))]
+    pub query: String,
+
+    /// Restrict estimates to one provider (openai, claude, gemini)
+    #[validate(length(min = 1, max = 50))]
+    pub provider: Option<String>,
+
+    /// Optional audience context override
+    pub audience_context: Option<AudienceContextRequest>,
+
+    /// Optional domain context override
+    pub domain_context: Option<DomainContextRequest>,
+}
+
+/// Bulk metadata update over stored research results
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema)]
+pub struct BulkUpdateRequest {
+    /// Results to update; at least one criterion is required
+    #[validate(nested)]
+    pub filter: BulkUpdateFilter,
+
+    /// Changes applied to every selected result
+    #[validate(nested)]
+    pub mutations: BulkUpdateMutations,
+
+    /// Report the planned changes without writing them
+    pub dry_run: Option<bool>,
+}
+
+/// Correction of a stored research result's research type
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema)]
+pub struct ClassificationCorrectionRequest {
+    /// Research type the result should have (decision, implementation, troubleshooting, learning, validation)
+    #[validate(length(min = 1, max = 50))]
+    pub research_type: String,
+
+    /// Why the original classification was wrong
+    #[validate(length(max = 500))]
+    pub reason: Option<String>,
+}
+
+/// Import of externally produced research documents
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema)]
+pub struct ResearchImportRequest {
+    /// Document format: jsonl (one record per line) or markdown (YAML frontmatter plus body)
+    pub format: String,
+
+    /// Document content
+    #[validate(length(min = 1, max = 10_000_000))]
+    pub content: String,
+
+    /// Name recorded as the document origin in the report
+    #[validate(length(max = 500))]
+    pub source_name: Option<String>,
+
+    /// Also index imported results for vector search when it is enabled
+    pub index: Option<bool>,
+
+    /// Report what would be imported without writing anything
+    pub dry_run: Option<bool>,
+}
+
+/// Selection of research results for a bulk update; all set criteria must match
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Validate, ToSchema)]
+pub struct BulkUpdateFilter {
+    /// Text contained in the original query (case-insensitive)
+    #[validate(length(min = 1, max = 1000))]
+    pub query: Option<String>,
+
+    /// Research type (decision, implementation, troubleshooting, learning, validation)
+    pub research_type: Option<String>,
+
+    /// Tag the result must carry
+    #[validate(length(min = 1, max = 100))]
+    pub tag: Option<String>,
+
+    /// Completed at or after this time
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Completed at or before this time
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Metadata mutations of a bulk update
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Validate, ToSchema)]
+pub struct BulkUpdateMutations {
+    /// Tags to add
+    #[serde(default)]
+    #[validate(length(max = 100))]
+    pub add_tags: Vec<String>,
+
+    /// Tags to remove
+    #[serde(default)]
+    #[validate(length(max = 100))]
+    pub remove_tags: Vec<String>,
+
+    /// Replace the quality score (0.0-1.0)
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub quality_override: Option<f64>,
+
+    /// Retention class (ephemeral, standard, extended, permanent)
+    pub retention_class: Option<String>,
 }
 
 /// Audience context parameters for research customization
@@ -83,7 +360,10 @@ pub struct ResearchListRequest {
     /// Filter by research type
     pub research_type: Option<String>,
 
-    /// Filter by keywords (searches in query, content, and tags)
+    /// Filter by keywords (searches in query, content, and tags); accepts the search
+    /// query language, e.g. `tag:rust AND (tokio OR async-std) -deprecated`.
+    /// Matches are ranked by BM25 relevance. Also accepted as `query`.
+    #[serde(alias = "query")]
     pub keywords: Option<String>,
 
     /// Filter by minimum quality score (0.0-1.0)
@@ -105,6 +385,22 @@ pub struct ResearchListRequest {
     pub sort: Option<String>,
 }
 
+/// Knowledge graph query parameters
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema, IntoParams)]
+pub struct KnowledgeGraphRequest {
+    /// Topic or question; results matching it seed the neighborhood
+    #[validate(length(
+        min = 1,
+        max = 1000,
+        message = "Query must be between 1 and 1000 characters"
+    ))]
+    pub query: String,
+
+    /// Number of hops to follow from the matching results (0-5, default 2)
+    #[validate(range(min = 0, max = 5))]
+    pub depth: Option<usize>,
+}
+
 /// Classification request parameters for advanced multi-dimensional classification
 #[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema)]
 pub struct ClassificationRequest {
@@ -237,7 +533,7 @@ pub struct TokenRefreshRequest {
 /// Cache search request parameters for filtering and pagination
 #[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema, IntoParams)]
 pub struct CacheSearchRequest {
-    /// Search query for content matching
+    /// Search query, e.g. `type:troubleshooting AND (tokio OR async-std) -deprecated`
     pub query: Option<String>,
 
     /// Filter by research type
@@ -281,6 +577,36 @@ pub struct CacheSearchRequest {
     pub sort: Option<String>,
 }
 
+/// Cache statistics request parameters for the by-type breakdown
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Validate, ToSchema, IntoParams)]
+pub struct CacheStatsRequest {
+    /// Comma-separated research types to include (e.g. "decision,learning")
+    pub research_types: Option<String>,
+
+    /// Sort order for the breakdown (entries, size, hit_rate, hits, name)
+    pub sort: Option<String>,
+
+    /// Maximum research types to return (1-100)
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<usize>,
+
+    /// Research type offset for pagination
+    #[validate(range(min = 0))]
+    pub offset: Option<usize>,
+
+    /// Time window in hours for recent operations (1-720, default 24)
+    #[validate(range(min = 1, max = 720))]
+    pub window_hours: Option<u32>,
+}
+
+/// Cache statistics request parameters for a single research type
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Validate, ToSchema, IntoParams)]
+pub struct CacheTypeStatsRequest {
+    /// Time window in hours for recent operations (1-720, default 24)
+    #[validate(range(min = 1, max = 720))]
+    pub window_hours: Option<u32>,
+}
+
 /// Cache invalidation request for bulk operations
 #[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema)]
 pub struct CacheInvalidateRequest {
@@ -427,6 +753,21 @@ pub struct ProactiveNotificationListRequest {
     pub sort: Option<String>,
 }
 
+/// API key creation request
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema)]
+pub struct ApiKeyCreateRequest {
+    /// Human-readable name of the key holder
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+
+    /// Role granted to the key (read-only, researcher, admin)
+    pub role: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -448,6 +789,17 @@ mod tests {
                 frameworks: vec!["clap".to_string()],
                 tags: vec!["async".to_string()],
             }),
+            dry_run: None,
+            parent_id: None,
+            code: None,
+            wait_timeout_ms: None,
+            budget_profile: None,
+            time_budget_ms: None,
+            include_feedback_token: None,
+            provider: None,
+            model: None,
+            session_id: None,
+            force: None,
         };
 
         assert!(valid_request.validate().is_ok());
@@ -458,11 +810,33 @@ mod tests {
             priority: None,
             audience_context: None,
             domain_context: None,
+            dry_run: None,
+            parent_id: None,
+            code: None,
+            wait_timeout_ms: None,
+            budget_profile: None,
+            time_budget_ms: None,
+            include_feedback_token: None,
+            provider: None,
+            model: None,
+            session_id: None,
+            force: None,
         };
 
         assert!(invalid_request.validate().is_err());
     }
 
+    #[test]
+    fn test_research_request_dry_run_defaults_to_none() {
+        let request: ResearchRequest =
+            serde_json::from_str(r#"{"query": "What is Rust?"}"#).unwrap();
+        assert_eq!(request.dry_run, None);
+
+        let request: ResearchRequest =
+            serde_json::from_str(r#"{"query": "What is Rust?", "dry_run": true}"#).unwrap();
+        assert_eq!(request.dry_run, Some(true));
+    }
+
     #[test]
     fn test_research_list_request_validation() {
         let valid_request = ResearchListRequest {
@@ -620,6 +994,24 @@ mod tests {
         assert!(invalid_request.validate().is_err());
     }
 
+    #[test]
+    fn test_cache_stats_request_validation() {
+        let valid_request = CacheStatsRequest {
+            research_types: Some("decision,learning".to_string()),
+            sort: Some("size".to_string()),
+            limit: Some(5),
+            offset: Some(0),
+            window_hours: Some(168),
+        };
+        assert!(valid_request.validate().is_ok());
+
+        let invalid_request = CacheStatsRequest {
+            window_hours: Some(0), // Invalid - window must be at least an hour
+            ..Default::default()
+        };
+        assert!(invalid_request.validate().is_err());
+    }
+
     #[test]
     fn test_cache_invalidate_request_validation() {
         let valid_request = CacheInvalidateRequest {
@@ -15,6 +15,7 @@
 // ABOUTME: Response model definitions for API endpoints
 
 use chrono::{DateTime, Utc};
+use fortitude_core::PipelineWarning;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -33,6 +34,81 @@ pub struct ApiResponse<T> {
 
     /// Success status
     pub success: bool,
+
+    /// Non-fatal conditions that affected the response
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
+}
+
+/// Non-fatal condition reported alongside a successful response
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+pub struct Warning {
+    /// Machine-readable warning code
+    pub code: WarningCode,
+
+    /// Human-readable description
+    pub message: String,
+}
+
+/// Kind of non-fatal condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningCode {
+    /// Advanced classification fell back to a simpler classifier
+    ClassificationDegraded,
+    /// Context detection fell back to defaults or failed
+    ContextDetectionDegraded,
+    /// The result came from a cache entry past its staleness threshold
+    StaleCacheServed,
+    /// A different provider or a placeholder answered in place of the requested one
+    ProviderSubstituted,
+    /// Steps were skipped or reduced to fit the requested time budget
+    TimeBudgetApplied,
+    /// Content filter rules blocked, annotated or rewrote the result
+    ContentFiltered,
+    /// The answer recommends a crate version with a known security advisory
+    VulnerableVersion,
+    /// The answer's markdown had problems that could not be repaired
+    MarkdownUnrepaired,
+    /// A URL cited by the result failed or did not answer
+    BrokenCitation,
+    /// The result's quality score stayed below the threshold after re-querying
+    LowQuality,
+}
+
+impl Warning {
+    /// Warnings recorded by the pipeline in result metadata tags
+    pub fn from_tags(tags: &std::collections::HashMap<String, String>) -> Vec<Self> {
+        PipelineWarning::from_tags(tags)
+            .into_iter()
+            .map(Self::from)
+            .collect()
+    }
+}
+
+impl From<PipelineWarning> for Warning {
+    fn from(warning: PipelineWarning) -> Self {
+        let code = match warning.code {
+            fortitude_core::WarningCode::ClassificationDegraded => {
+                WarningCode::ClassificationDegraded
+            }
+            fortitude_core::WarningCode::ContextDetectionDegraded => {
+                WarningCode::ContextDetectionDegraded
+            }
+            fortitude_core::WarningCode::StaleCacheServed => WarningCode::StaleCacheServed,
+            fortitude_core::WarningCode::ProviderSubstituted => WarningCode::ProviderSubstituted,
+            fortitude_core::WarningCode::TimeBudgetApplied => WarningCode::TimeBudgetApplied,
+            fortitude_core::WarningCode::ContentFiltered => WarningCode::ContentFiltered,
+            fortitude_core::WarningCode::VulnerableVersion => WarningCode::VulnerableVersion,
+            fortitude_core::WarningCode::MarkdownUnrepaired => WarningCode::MarkdownUnrepaired,
+            fortitude_core::WarningCode::BrokenCitation => WarningCode::BrokenCitation,
+            fortitude_core::WarningCode::LowQuality => WarningCode::LowQuality,
+        };
+        Self {
+            code,
+            message: warning.message,
+        }
+    }
 }
 
 /// Complete research response with structured result
@@ -47,6 +123,9 @@ pub struct ResearchResponse {
     /// Classified research type
     pub research_type: String,
 
+    /// Every research type the query was labeled with, primary type first
+    pub labels: Vec<ClassificationLabel>,
+
     /// Immediate answer (first layer of progressive disclosure)
     pub immediate_answer: String,
 
@@ -59,10 +138,528 @@ pub struct ResearchResponse {
     /// Research metadata
     pub metadata: ResearchMetadata,
 
+    /// ID of the result this follow-up was derived from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+
+    /// Feedback widget metadata, present when `include_feedback_token` was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feedback: Option<FeedbackWidget>,
+
+    /// Earlier results answering similar questions, most relevant first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_results: Vec<RelatedResearch>,
+
     /// Processing time in milliseconds
     pub processing_time_ms: u64,
 }
 
+/// Research refused because nearly identical questions were already researched
+///
+/// Returned with 409 Conflict; resubmit with `force: true` to research anyway.
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct SimilarResearchResponse {
+    /// Error code, always `SIMILAR_RESEARCH_EXISTS`
+    pub error_code: String,
+
+    /// Human-readable error message
+    pub message: String,
+
+    /// Earlier results on nearly identical questions, most similar first
+    pub candidates: Vec<RelatedResearch>,
+
+    /// Response timestamp
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Earlier research result answering a similar question
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct RelatedResearch {
+    /// Research result ID for retrieval
+    pub id: String,
+
+    /// Question the earlier result answers
+    pub query: String,
+
+    /// Classified research type
+    pub research_type: String,
+
+    /// Similarity of the questions (0.0-1.0)
+    pub relevance: f64,
+}
+
+/// What an embedding page needs to render a one-click feedback widget
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct FeedbackWidget {
+    /// Signed token to send with the feedback; no API key is needed
+    pub token: String,
+
+    /// Endpoint the feedback is posted to
+    pub submit_url: String,
+
+    /// When the token stops being accepted
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Acknowledgement of feedback submitted with a feedback token
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct FeedbackTokenResponse {
+    /// Feedback ID
+    pub id: String,
+
+    /// Research result the feedback was recorded for
+    pub result_id: String,
+
+    /// When the feedback was recorded
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// Preference profile applied as defaults to the calling key's research requests
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct PreferencesResponse {
+    /// Key the profile belongs to (token subject, or `anonymous` without auth)
+    pub key: String,
+
+    /// Default audience level
+    pub audience_level: Option<String>,
+
+    /// Default output format
+    pub output_format: Option<String>,
+
+    /// Default prompt-budget profile
+    pub output_style: Option<String>,
+
+    /// Default programming language or technology
+    pub language: Option<String>,
+
+    /// Preferred provider
+    pub provider: Option<String>,
+
+    /// Whether feedback adjusts the profile
+    pub learn_from_feedback: bool,
+
+    /// When the profile last changed; absent for keys without a profile
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Research session continued by research requests that name its ID
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct SessionResponse {
+    /// Session ID to pass as `session_id` in research requests
+    pub id: String,
+
+    /// Label given when the session was created
+    pub title: Option<String>,
+
+    /// When the session was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the session last gained a result
+    pub updated_at: DateTime<Utc>,
+
+    /// IDs of the session's research results, oldest first
+    pub turn_ids: Vec<String>,
+}
+
+/// Research type correction applied to a stored result
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ClassificationCorrectionResponse {
+    /// Research result ID
+    pub id: String,
+
+    /// Query of the corrected result
+    pub query: String,
+
+    /// Research type before the correction
+    pub previous_type: String,
+
+    /// Research type after the correction
+    pub research_type: String,
+
+    /// Whether the research type changed; false when the label was confirmed
+    pub changed: bool,
+
+    /// Reason given for the correction
+    pub reason: Option<String>,
+
+    /// When the correction was made
+    pub corrected_at: DateTime<Utc>,
+}
+
+/// Chain of research results leading to a follow-up question
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ResearchLineageResponse {
+    /// Research result the lineage was requested for
+    pub id: String,
+
+    /// Results from the root question down to `id`
+    pub chain: Vec<ResearchLineageEntry>,
+
+    /// Number of ancestors above `id`
+    pub depth: usize,
+}
+
+/// Single result in a research lineage chain
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ResearchLineageEntry {
+    /// Research result ID
+    pub id: String,
+
+    /// ID of the parent result, absent for the root question
+    pub parent_id: Option<String>,
+
+    /// Question asked at this step
+    pub query: String,
+
+    /// Classified research type
+    pub research_type: String,
+
+    /// When the result was produced
+    pub completed_at: DateTime<Utc>,
+}
+
+/// Research results related to a topic and the links between them
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct KnowledgeGraphResponse {
+    /// Topic the neighborhood was requested for
+    pub query: String,
+
+    /// Number of hops followed from the seed results
+    pub depth: usize,
+
+    /// IDs of the results matching the query
+    pub seeds: Vec<String>,
+
+    /// Results in the neighborhood, nearest first
+    pub nodes: Vec<KnowledgeGraphNode>,
+
+    /// Links between results in the neighborhood
+    pub edges: Vec<KnowledgeGraphEdge>,
+}
+
+/// Research result in a knowledge graph neighborhood
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct KnowledgeGraphNode {
+    /// Research result ID
+    pub id: String,
+
+    /// Question the result answers
+    pub query: String,
+
+    /// Classified research type
+    pub research_type: String,
+
+    /// Domain tags of the result
+    pub tags: Vec<String>,
+
+    /// Overall quality score (0.0-1.0)
+    pub quality_score: f64,
+
+    /// When the result was produced
+    pub completed_at: DateTime<Utc>,
+
+    /// Hops from the nearest seed result
+    pub distance: usize,
+}
+
+/// Link between two related research results
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct KnowledgeGraphEdge {
+    /// Research result ID at one end
+    pub source: String,
+
+    /// Research result ID at the other end
+    pub target: String,
+
+    /// Strength of the link (0.0-1.0)
+    pub weight: f64,
+
+    /// Reasons the results are related
+    pub relations: Vec<KnowledgeGraphRelation>,
+}
+
+/// One reason two research results are related
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct KnowledgeGraphRelation {
+    /// Kind of relation (shared_tags, citation_overlap, semantic_similarity)
+    pub kind: String,
+
+    /// Strength of this relation (0.0-1.0)
+    pub score: f64,
+
+    /// Tags or sources the results have in common
+    pub shared: Vec<String>,
+}
+
+/// Cancellation of running research
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ResearchCancelResponse {
+    /// Request ID the research was submitted with
+    pub request_id: Uuid,
+
+    /// Whether the research was found and told to stop
+    pub cancelled: bool,
+}
+
+/// Research job that is still running or was picked up after a wait
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ResearchJobResponse {
+    /// Job ID for polling
+    pub job_id: String,
+
+    /// Job status (running, completed, failed)
+    pub status: String,
+
+    /// URL to fetch the job status and result from
+    pub status_url: String,
+
+    /// When the job was submitted
+    pub submitted_at: DateTime<Utc>,
+
+    /// When the job finished
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+
+    /// Research result once the job has completed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<ResearchResponse>,
+
+    /// Error message if the job failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Execution plan returned for a dry-run research request
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ResearchPlanResponse {
+    /// Original query
+    pub query: String,
+
+    /// Classified research type
+    pub research_type: String,
+
+    /// Classification confidence (0.0-1.0)
+    pub classification_confidence: f64,
+
+    /// Detected audience level, if context detection ran
+    pub detected_audience: Option<String>,
+
+    /// Detected technical domain, if context detection ran
+    pub detected_domain: Option<String>,
+
+    /// Cache key the result would be stored under
+    pub cache_key: String,
+
+    /// Whether a cached result already exists
+    pub cache_hit: bool,
+
+    /// Number of context documents retrieved for RAG
+    pub context_documents: usize,
+
+    /// Provider that would handle the request
+    pub provider: String,
+
+    /// Prompt template that would be rendered
+    pub template: Option<String>,
+
+    /// Estimated prompt tokens
+    pub estimated_input_tokens: u32,
+
+    /// Estimated completion tokens
+    pub estimated_output_tokens: u32,
+
+    /// Upper-bound estimated cost in USD
+    pub estimated_cost_usd: f64,
+
+    /// Cost ranges per candidate provider model
+    pub provider_estimates: Vec<ProviderEstimate>,
+
+    /// Estimated processing time in milliseconds
+    pub estimated_processing_time_ms: u64,
+}
+
+/// Token usage and cost range for one candidate provider model
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ProviderEstimate {
+    /// Provider name
+    pub provider: String,
+
+    /// Model identifier
+    pub model: String,
+
+    /// Estimated prompt tokens
+    pub input_tokens: u32,
+
+    /// Lower bound of completion tokens
+    pub output_tokens_min: u32,
+
+    /// Upper bound of completion tokens
+    pub output_tokens_max: u32,
+
+    /// Lower bound of cost in USD
+    pub cost_min_usd: f64,
+
+    /// Upper bound of cost in USD
+    pub cost_max_usd: f64,
+
+    /// Whether the prompt fits in the model context window
+    pub fits_context: bool,
+}
+
+/// Cost estimate for a research request
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ResearchEstimateResponse {
+    /// Original query
+    pub query: String,
+
+    /// Classified research type
+    pub research_type: String,
+
+    /// Whether a cached result exists (no provider cost would be incurred)
+    pub cache_hit: bool,
+
+    /// Estimated prompt tokens of the prepared prompt
+    pub estimated_input_tokens: u32,
+
+    /// Expected completion tokens
+    pub estimated_output_tokens: u32,
+
+    /// Cost ranges per candidate provider model
+    pub estimates: Vec<ProviderEstimate>,
+}
+
+/// Outcome of a bulk metadata update
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct BulkUpdateResponse {
+    /// Whether this was a preview; nothing is written on a dry run
+    pub dry_run: bool,
+
+    /// Number of results selected by the filter
+    pub matched: usize,
+
+    /// Number of results whose metadata changed (or would change)
+    pub updated: usize,
+
+    /// Per-result changes
+    pub changes: Vec<BulkUpdateChange>,
+
+    /// Audit log entry of the update; absent for dry runs
+    pub audit_id: Option<Uuid>,
+}
+
+/// Metadata change to one research result
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct BulkUpdateChange {
+    /// Research result ID
+    pub id: String,
+
+    /// Original query
+    pub query: String,
+
+    /// Metadata before the update
+    pub before: CuratedMetadata,
+
+    /// Metadata after the update
+    pub after: CuratedMetadata,
+}
+
+/// Outcome of a research import
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ResearchImportResponse {
+    /// Whether this was a preview; nothing is written on a dry run
+    pub dry_run: bool,
+
+    /// Stored (or storable) documents
+    pub imported: Vec<ImportedResearch>,
+
+    /// Documents skipped because the same content is already stored
+    pub duplicates: Vec<ImportDuplicate>,
+
+    /// Documents rejected by validation or storage
+    pub invalid: Vec<ImportIssue>,
+
+    /// Number of imported documents added to the vector index
+    pub indexed: usize,
+
+    /// Imported documents that could not be indexed
+    pub index_failures: Vec<ImportIssue>,
+
+    /// Audit log entry of the import; absent for dry runs
+    pub audit_id: Option<Uuid>,
+}
+
+/// A stored research document
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ImportedResearch {
+    /// Document location (origin and line)
+    pub location: String,
+
+    /// Research result ID
+    pub id: String,
+
+    /// Original query
+    pub query: String,
+}
+
+/// A document whose content is already stored
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ImportDuplicate {
+    /// Document location (origin and line)
+    pub location: String,
+
+    /// ID of the result already holding the content
+    pub existing_id: String,
+}
+
+/// A document that could not be imported or indexed
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ImportIssue {
+    /// Document location (origin and line)
+    pub location: String,
+
+    /// Why it failed
+    pub reason: String,
+}
+
+/// Curatable metadata of a research result
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CuratedMetadata {
+    /// Categorization tags
+    pub tags: Vec<String>,
+
+    /// Quality score (0.0-1.0)
+    pub quality_score: f64,
+
+    /// Retention class (ephemeral, standard, extended, permanent)
+    pub retention_class: Option<String>,
+}
+
+/// API version capability response
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ApiVersionsResponse {
+    /// Latest API version
+    pub current: String,
+
+    /// All versions served by this server
+    pub versions: Vec<ApiVersionInfo>,
+}
+
+/// Status of a single API version
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ApiVersionInfo {
+    /// Version label (v1, v2)
+    pub version: String,
+
+    /// Path prefix for this version
+    pub base_path: String,
+
+    /// Lifecycle status (current, supported, deprecated)
+    pub status: String,
+
+    /// HTTP-date after which this version will be removed
+    pub sunset: Option<String>,
+}
+
 /// Research listing response with pagination
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct ResearchListResponse {
@@ -91,9 +688,18 @@ pub struct ResearchSummary {
     /// Research type
     pub research_type: String,
 
-    /// Brief summary of the answer
+    /// Every research type the result is labeled with, primary type first
+    pub research_types: Vec<String>,
+
+    /// Brief summary of the answer, with matched terms wrapped in `**`
     pub summary: String,
 
+    /// Keyword relevance score (0.0-1.0)
+    pub relevance_score: f64,
+
+    /// Search terms found in the result
+    pub matched_terms: Vec<String>,
+
     /// Quality score (0.0-1.0)
     pub quality_score: f64,
 
@@ -151,6 +757,14 @@ pub struct ResearchMetadata {
     /// Quality score (0.0-1.0)
     pub quality_score: f64,
 
+    /// Provider selected for the request, when one was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+
+    /// Model selected for the request, when one was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
     /// Additional metadata tags
     pub tags: std::collections::HashMap<String, String>,
 }
@@ -208,10 +822,23 @@ pub struct ClassificationResult {
     /// Rule priority that was applied
     pub rule_priority: u32,
 
+    /// Research types assigned to the content, primary type first
+    pub labels: Vec<ClassificationLabel>,
+
     /// All candidate classifications considered
     pub candidates: Vec<ClassificationCandidate>,
 }
 
+/// Research type assigned to a query, with the classifier's confidence in it
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ClassificationLabel {
+    /// Research type
+    pub research_type: String,
+
+    /// Confidence score (0.0-1.0)
+    pub confidence: f64,
+}
+
 /// Context detection response across all dimensions
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct ContextDetectionResponse {
@@ -448,6 +1075,35 @@ pub struct ComponentHealth {
     pub details: Option<String>,
 }
 
+/// Readiness probe response
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    /// `ready`, `not_ready` or the shutdown phase
+    pub status: String,
+
+    /// Uptime in seconds
+    pub uptime_seconds: u64,
+
+    /// Status of each checked component
+    pub components: std::collections::HashMap<String, ReadinessComponent>,
+}
+
+/// Readiness of one component
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ReadinessComponent {
+    /// `healthy`, `unhealthy`, `unavailable` or `not_configured`
+    pub status: String,
+
+    /// Whether readiness fails when this component is not healthy
+    pub required: bool,
+
+    /// Last check timestamp
+    pub last_check: DateTime<Utc>,
+
+    /// Additional details
+    pub details: Option<String>,
+}
+
 /// Cache operation response
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct CacheResponse {
@@ -485,9 +1141,17 @@ pub struct CacheStatsResponse {
     /// Average entry age in seconds
     pub average_age_seconds: f64,
 
-    /// Statistics by research type
+    /// Statistics by research type (filtered and paginated)
     pub by_research_type: std::collections::HashMap<String, CacheTypeStatsResponse>,
 
+    /// Research types in `by_research_type`, in the requested sort order
+    #[serde(default)]
+    pub research_type_order: Vec<String>,
+
+    /// Pagination over the research type breakdown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub research_type_pagination: Option<PaginationInfo>,
+
     /// Storage efficiency metrics
     pub storage_efficiency: StorageEfficiencyResponse,
 
@@ -517,6 +1181,25 @@ pub struct CacheTypeStatsResponse {
     pub average_quality: f64,
 }
 
+/// Cache statistics drill-down for a single research type
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CacheTypeDetailResponse {
+    /// Research type name
+    pub research_type: String,
+
+    /// Aggregate statistics for this type
+    pub stats: CacheTypeStatsResponse,
+
+    /// Number of expired entries for this type
+    pub expired_entries: usize,
+
+    /// Average entry age in seconds for this type
+    pub average_age_seconds: f64,
+
+    /// Recent operations for this type within the requested window
+    pub recent_operations: RecentOperationsResponse,
+}
+
 /// Storage efficiency metrics
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct StorageEfficiencyResponse {
@@ -563,6 +1246,14 @@ pub struct RecentOperationsResponse {
 
     /// Most frequently accessed items
     pub top_accessed: Vec<String>,
+
+    /// Requested time window in hours
+    #[serde(default)]
+    pub window_hours: u32,
+
+    /// Operations within the requested window
+    #[serde(default)]
+    pub in_window: u64,
 }
 
 /// Cache search results response
@@ -1570,6 +2261,14 @@ pub struct MonitoringCurrentMetricsResponse {
     /// Resource utilization metrics
     pub resource_metrics: MonitoringResourceMetricsResponse,
 
+    /// Classification, context detection and research stage metrics
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pipeline_metrics: Option<MonitoringPipelineMetricsResponse>,
+
+    /// Research queue occupancy and per-priority wait times
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue_metrics: Option<MonitoringQueueMetricsResponse>,
+
     /// Metrics collection timestamp
     pub timestamp: DateTime<Utc>,
 }
@@ -1643,6 +2342,148 @@ pub struct MonitoringQualityMetricsResponse {
     pub evaluations_by_type: std::collections::HashMap<String, u64>,
 }
 
+/// Research pipeline stage metrics for monitoring
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MonitoringPipelineMetricsResponse {
+    /// Latency of each pipeline stage
+    pub stages: Vec<MonitoringStageLatencyResponse>,
+
+    /// Classification and context detection outcomes
+    pub classification: MonitoringClassificationOutcomesResponse,
+
+    /// Content filter decisions
+    pub content_filter: MonitoringContentFilterOutcomesResponse,
+}
+
+/// Research queue metrics for monitoring
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MonitoringQueueMetricsResponse {
+    /// Queries researched at once before new ones wait
+    pub max_concurrent: usize,
+
+    /// Queries researching now
+    pub running: usize,
+
+    /// Queries waiting for a slot
+    pub queued: usize,
+
+    /// Queue activity by priority, least urgent first
+    pub priorities: Vec<MonitoringPriorityQueueResponse>,
+}
+
+/// Queue activity of a single priority level
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MonitoringPriorityQueueResponse {
+    /// Priority level (low, medium, high, critical)
+    pub priority: String,
+
+    /// Queries of this priority waiting now
+    pub queued: usize,
+
+    /// Queries that were given a slot
+    pub started: u64,
+
+    /// Queries that finished researching
+    pub completed: u64,
+
+    /// Queries promoted to a higher priority after waiting too long
+    pub promoted: u64,
+
+    /// Mean wait for a slot in milliseconds
+    pub mean_wait_ms: f64,
+
+    /// Longest wait for a slot in milliseconds
+    pub max_wait_ms: f64,
+
+    /// Mean time from queueing to finishing research in milliseconds
+    pub mean_latency_ms: f64,
+
+    /// Longest time from queueing to finishing research in milliseconds
+    pub max_latency_ms: f64,
+}
+
+/// Aggregated latency of a single pipeline stage
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MonitoringStageLatencyResponse {
+    /// Stage name (classification, context_detection, research)
+    pub stage: String,
+
+    /// Number of timed executions
+    pub count: u64,
+
+    /// Total time spent in the stage in milliseconds
+    pub total_ms: f64,
+
+    /// Mean latency in milliseconds
+    pub mean_ms: f64,
+
+    /// Slowest execution in milliseconds
+    pub max_ms: f64,
+
+    /// Cumulative latency histogram
+    pub latency_histogram: Vec<MonitoringHistogramBucket>,
+}
+
+/// Cumulative histogram bucket
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MonitoringHistogramBucket {
+    /// Upper bound in milliseconds; absent for the unbounded bucket
+    pub le_ms: Option<f64>,
+
+    /// Executions at or below the bound
+    pub count: u64,
+}
+
+/// Classification and context detection outcomes for monitoring
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MonitoringClassificationOutcomesResponse {
+    /// Queries classified successfully
+    pub classified: u64,
+
+    /// Failed classifications, including threshold misses
+    pub failures: u64,
+
+    /// Classifications rejected for not meeting the confidence threshold
+    pub threshold_misses: u64,
+
+    /// Advanced classifications that used the fallback path
+    pub fallback_used: u64,
+
+    /// Successful classifications by research type
+    pub by_type: std::collections::HashMap<String, u64>,
+
+    /// Context detections that produced a result
+    pub context_detections: u64,
+
+    /// Context detections that failed
+    pub context_detection_failures: u64,
+
+    /// Context detections that fell back to defaults
+    pub context_fallback_used: u64,
+}
+
+/// Content filter decisions for monitoring
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MonitoringContentFilterOutcomesResponse {
+    /// Results checked by response filters
+    pub checked: u64,
+
+    /// Results changed or withheld by any rule
+    pub filtered: u64,
+
+    /// Block decisions
+    pub blocked: u64,
+
+    /// Annotate decisions
+    pub annotated: u64,
+
+    /// Rewrite decisions
+    pub rewritten: u64,
+
+    /// Matching results by rule name
+    pub by_rule: std::collections::HashMap<String, u64>,
+}
+
 /// Cache performance metrics for monitoring
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct MonitoringCacheMetricsResponse {
@@ -1972,8 +2813,272 @@ impl<T> ApiResponse<T> {
             request_id,
             timestamp: Utc::now(),
             success: true,
+            warnings: Vec::new(),
         }
     }
+
+    /// Attach non-fatal warnings to the response
+    pub fn with_warnings(mut self, warnings: Vec<Warning>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+}
+
+/// Maintenance scheduler status
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MaintenanceStatusResponse {
+    /// Whether the scheduler is enabled in configuration
+    pub enabled: bool,
+
+    /// Whether scheduled task loops are running
+    pub running: bool,
+
+    /// Schedule and last-run status per task
+    pub tasks: Vec<MaintenanceTaskStatus>,
+
+    /// Most recent metrics snapshot
+    pub latest_snapshot: Option<MaintenanceMetricsSnapshot>,
+
+    /// Number of retained metrics snapshots
+    pub snapshot_count: usize,
+}
+
+/// Status of a single maintenance task
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MaintenanceTaskStatus {
+    /// Task name (cache_cleanup, gc, index_refresh, stale_revalidation, metrics_snapshot, wiki_sync)
+    pub name: String,
+
+    /// Cron schedule, if the task is scheduled
+    pub schedule: Option<String>,
+
+    /// Whether the task runs on its schedule
+    pub enabled: bool,
+
+    /// Next scheduled run while the scheduler is running
+    pub next_run: Option<DateTime<Utc>>,
+
+    /// Start time of the last run
+    pub last_run: Option<DateTime<Utc>>,
+
+    /// Duration of the last run in milliseconds
+    pub last_duration_ms: Option<u64>,
+
+    /// Whether the last run succeeded
+    pub last_success: Option<bool>,
+
+    /// Result or error message from the last run
+    pub last_message: Option<String>,
+
+    /// Total number of runs
+    pub run_count: u64,
+
+    /// Number of failed runs
+    pub failure_count: u64,
+}
+
+/// Requests the server is currently handling
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct InflightListResponse {
+    /// In-flight requests, longest-running first
+    pub requests: Vec<InflightRequestInfo>,
+
+    /// Number of in-flight requests
+    pub total_count: usize,
+}
+
+/// A single in-flight request
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct InflightRequestInfo {
+    /// In-flight request ID, used to cancel it
+    pub id: Uuid,
+
+    /// HTTP method
+    pub method: String,
+
+    /// Request path
+    pub endpoint: String,
+
+    /// Current stage (received, classification, research, finalizing)
+    pub stage: String,
+
+    /// Research provider in use, once known
+    pub provider: Option<String>,
+
+    /// Token subject that made the request, once authenticated
+    pub user: Option<String>,
+
+    /// When the request arrived
+    pub started_at: DateTime<Utc>,
+
+    /// Time spent so far in milliseconds
+    pub elapsed_ms: u64,
+
+    /// Whether cancellation has been requested
+    pub cancel_requested: bool,
+}
+
+/// Result of cancelling an in-flight request
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct InflightCancelResponse {
+    /// In-flight request ID
+    pub id: Uuid,
+
+    /// Whether the request was found and told to stop
+    pub cancelled: bool,
+}
+
+/// API key as shown in listings; the secret itself is never returned here
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ApiKeyInfo {
+    /// Key ID
+    pub id: String,
+
+    /// Name of the key holder
+    pub name: String,
+
+    /// Role granted to the key (read-only, researcher, admin)
+    pub role: String,
+
+    /// Leading characters of the key
+    pub prefix: String,
+
+    /// When the key was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the key was last rotated
+    pub rotated_at: Option<DateTime<Utc>>,
+}
+
+/// Configured API keys
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ApiKeyListResponse {
+    /// Keys, oldest first
+    pub keys: Vec<ApiKeyInfo>,
+
+    /// Number of keys
+    pub total_count: usize,
+}
+
+/// Newly created or rotated API key
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ApiKeySecretResponse {
+    /// The key record
+    pub key: ApiKeyInfo,
+
+    /// The key to send in the X-API-Key header; shown only once
+    pub secret: String,
+}
+
+/// Result of revoking an API key
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ApiKeyRevokeResponse {
+    /// Key ID
+    pub id: String,
+
+    /// Whether the key was revoked
+    pub revoked: bool,
+}
+
+/// Point-in-time cache and request metrics recorded by the scheduler
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MaintenanceMetricsSnapshot {
+    /// Snapshot time
+    pub timestamp: DateTime<Utc>,
+
+    /// Number of cache entries
+    pub cache_entries: usize,
+
+    /// Total cache size in bytes
+    pub cache_size_bytes: u64,
+
+    /// Cache hit rate (0.0-1.0)
+    pub cache_hit_rate: f64,
+
+    /// Total HTTP requests served
+    pub total_requests: Option<u64>,
+
+    /// HTTP error rate (0.0-1.0)
+    pub error_rate: Option<f64>,
+
+    /// Average response time in milliseconds
+    pub avg_response_time_ms: Option<u64>,
+}
+
+/// Rate limits, token quota and monthly budget of the calling API key
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct LimitsResponse {
+    /// Key the quota is tracked under (token subject, or `anonymous` without auth)
+    pub key: String,
+
+    /// Quota tier assigned to the key
+    pub tier: String,
+
+    /// Request rate limit; absent when authentication and rate limiting are disabled
+    pub requests: Option<RequestLimitStatus>,
+
+    /// Token usage in the current window
+    pub tokens: TokenQuotaStatus,
+
+    /// Estimated provider spend in the current calendar month
+    pub budget: BudgetStatus,
+}
+
+/// Request rate limit window of the caller
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct RequestLimitStatus {
+    /// Requests allowed per window, and the most that can be made in a burst
+    pub limit: u32,
+
+    /// Requests that can be made right now
+    pub remaining: u32,
+
+    /// Window length in seconds
+    pub window_seconds: u64,
+
+    /// When the full allowance is available again
+    pub resets_at: DateTime<Utc>,
+}
+
+/// Token quota window of the caller
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct TokenQuotaStatus {
+    /// Tokens allowed per window; absent when unlimited
+    pub limit: Option<u64>,
+
+    /// Tokens used in the current window
+    pub used: u64,
+
+    /// Tokens left in the current window; absent when unlimited
+    pub remaining: Option<u64>,
+
+    /// When the current window resets
+    pub resets_at: DateTime<Utc>,
+}
+
+/// Monthly budget consumption of the caller
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct BudgetStatus {
+    /// Calendar month being tracked (`YYYY-MM`, UTC)
+    pub month: String,
+
+    /// Research requests recorded this month
+    pub requests: u64,
+
+    /// Tokens recorded this month
+    pub tokens: u64,
+
+    /// Estimated spend this month in USD
+    pub spent_usd: f64,
+
+    /// Monthly budget in USD; absent when unlimited
+    pub limit_usd: Option<f64>,
+
+    /// Budget left this month in USD; absent when unlimited
+    pub remaining_usd: Option<f64>,
+
+    /// Start of the next month, when the budget resets
+    pub resets_at: DateTime<Utc>,
 }
 
 #[cfg(test)]
@@ -1990,6 +3095,26 @@ mod tests {
         assert_eq!(response.data, data);
         assert_eq!(response.request_id, request_id);
         assert!(response.success);
+        assert!(response.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_api_response_warnings_from_tags() {
+        let mut tags = std::collections::HashMap::new();
+        PipelineWarning::new(
+            fortitude_core::WarningCode::ProviderSubstituted,
+            "placeholder answer",
+        )
+        .apply_to_tags(&mut tags);
+
+        let response =
+            ApiResponse::success("data", Uuid::new_v4()).with_warnings(Warning::from_tags(&tags));
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["warnings"][0]["code"], "provider_substituted");
+        assert_eq!(json["warnings"][0]["message"], "placeholder answer");
+
+        let plain = serde_json::to_value(ApiResponse::success("data", Uuid::new_v4())).unwrap();
+        assert!(plain.get("warnings").is_none());
     }
 
     #[test]
@@ -1998,6 +3123,10 @@ mod tests {
             id: "test-id".to_string(),
             query: "Test query".to_string(),
             research_type: "implementation".to_string(),
+            labels: vec![ClassificationLabel {
+                research_type: "implementation".to_string(),
+                confidence: 0.9,
+            }],
             immediate_answer: "Test answer".to_string(),
             supporting_evidence: vec![],
             implementation_details: vec![],
@@ -2006,13 +3135,19 @@ mod tests {
                 processing_time_ms: 100,
                 sources_consulted: vec![],
                 quality_score: 0.8,
+                provider: None,
+                model: None,
                 tags: std::collections::HashMap::new(),
             },
+            parent_id: None,
+            feedback: None,
+            related_results: vec![],
             processing_time_ms: 100,
         };
 
         let serialized = serde_json::to_string(&response);
         assert!(serialized.is_ok());
+        assert!(!serialized.unwrap().contains("parent_id"));
     }
 
     #[test]
@@ -2043,6 +3178,7 @@ mod tests {
                 confidence: 0.85,
                 matched_keywords: vec!["implement".to_string(), "build".to_string()],
                 rule_priority: 1,
+                labels: vec![],
                 candidates: vec![],
             },
             context: Some(ContextDetectionResponse {
@@ -2115,6 +3251,8 @@ mod tests {
             misses: 150,
             average_age_seconds: 3600.0,
             by_research_type: std::collections::HashMap::new(),
+            research_type_order: vec![],
+            research_type_pagination: None,
             storage_efficiency: StorageEfficiencyResponse {
                 utilization_percent: 75.0,
                 duplicate_entries: 3,
@@ -2130,6 +3268,8 @@ mod tests {
                     last_day: 1200,
                     peak_hour: "14:00-15:00".to_string(),
                     top_accessed: vec!["key1".to_string(), "key2".to_string()],
+                    window_hours: 24,
+                    in_window: 1200,
                 },
             },
         };
@@ -0,0 +1,366 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Per-key preference profiles applied as defaults to research requests
+// Kept in memory, optionally saved to a JSON file, and adjusted from feedback when opted in
+
+use crate::config::PreferencesConfig;
+use crate::feedback::TokenFeedback;
+use chrono::{DateTime, Utc};
+use fortitude_core::write_json_atomic_async;
+use fortitude_types::{AudienceContext, DomainContext};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use tracing::{error, info, warn};
+
+/// Audience levels from least to most experienced
+const AUDIENCE_LEVELS: [&str; 3] = ["beginner", "intermediate", "advanced"];
+
+/// Feedback phrases asking for a more advanced audience level
+const MORE_ADVANCED: [&str; 4] = ["too basic", "too simple", "too obvious", "more advanced"];
+
+/// Feedback phrases asking for a less advanced audience level
+const LESS_ADVANCED: [&str; 4] = ["too advanced", "too complex", "too technical", "simpler"];
+
+/// Feedback phrases asking for shorter answers
+const SHORTER: [&str; 4] = ["too long", "too verbose", "too wordy", "shorter"];
+
+/// Feedback phrases asking for longer answers
+const LONGER: [&str; 4] = ["too short", "too brief", "more detail", "more depth"];
+
+/// Defaults one key prefers for its research requests
+///
+/// Values a request sets explicitly always win over the profile.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PreferenceProfile {
+    /// Audience level (beginner, intermediate, advanced)
+    pub audience_level: Option<String>,
+    /// Output format (markdown, json, plain)
+    pub output_format: Option<String>,
+    /// Prompt-budget profile controlling answer length (concise, standard, thorough)
+    pub output_style: Option<String>,
+    /// Programming language or technology the research is about
+    pub language: Option<String>,
+    /// Provider to research with when multi-provider research is enabled
+    pub provider: Option<String>,
+    /// Adjust audience level and output style from submitted feedback
+    pub learn_from_feedback: bool,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl PreferenceProfile {
+    /// Audience context for a request that did not send one
+    pub fn audience_context(&self, requested: Option<AudienceContext>) -> Option<AudienceContext> {
+        if requested.is_some() || (self.audience_level.is_none() && self.output_format.is_none()) {
+            return requested;
+        }
+        let defaults = AudienceContext::default();
+        Some(AudienceContext {
+            level: self.audience_level.clone().unwrap_or(defaults.level),
+            format: self.output_format.clone().unwrap_or(defaults.format),
+            ..defaults
+        })
+    }
+
+    /// Domain context for a request that did not send one
+    pub fn domain_context(&self, requested: Option<DomainContext>) -> Option<DomainContext> {
+        match (&requested, &self.language) {
+            (None, Some(language)) => Some(DomainContext {
+                technology: language.clone(),
+                ..DomainContext::default()
+            }),
+            _ => requested,
+        }
+    }
+
+    /// Adjust the profile to a feedback comment; returns whether anything changed
+    ///
+    /// Only comments on unhelpful or low-rated results are taken into account.
+    pub fn learn(&mut self, helpful: bool, rating: Option<u8>, comment: Option<&str>) -> bool {
+        let negative = !helpful || rating.is_some_and(|rating| rating <= 2);
+        let Some(comment) = comment.filter(|_| negative) else {
+            return false;
+        };
+        let comment = comment.to_lowercase();
+        let mentions = |phrases: &[&str]| phrases.iter().any(|phrase| comment.contains(phrase));
+
+        let mut changed = false;
+        let level_step = match (mentions(&MORE_ADVANCED), mentions(&LESS_ADVANCED)) {
+            (true, false) => Some(1),
+            (false, true) => Some(-1),
+            _ => None,
+        };
+        if let Some(step) = level_step {
+            let current = self.audience_level.as_deref().unwrap_or("intermediate");
+            if let Some(index) = AUDIENCE_LEVELS.iter().position(|level| *level == current) {
+                let next = (index as i32 + step).clamp(0, AUDIENCE_LEVELS.len() as i32 - 1);
+                let level = AUDIENCE_LEVELS[next as usize];
+                if level != current {
+                    self.audience_level = Some(level.to_string());
+                    changed = true;
+                }
+            }
+        }
+
+        let style = match (mentions(&SHORTER), mentions(&LONGER)) {
+            (true, false) => Some("concise"),
+            (false, true) => Some("thorough"),
+            _ => None,
+        };
+        if let Some(style) = style.filter(|style| self.output_style.as_deref() != Some(style)) {
+            self.output_style = Some(style.to_string());
+            changed = true;
+        }
+        changed
+    }
+}
+
+/// Preference profiles keyed by token subject, shared by the research,
+/// feedback and preferences routes
+#[derive(Debug, Clone)]
+pub struct PreferenceStore {
+    profiles: Arc<RwLock<HashMap<String, PreferenceProfile>>>,
+    store_path: Option<PathBuf>,
+    // Serializes file writes so an older snapshot never replaces a newer one
+    save_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl Default for PreferenceStore {
+    fn default() -> Self {
+        Self::new(&PreferencesConfig::default())
+    }
+}
+
+impl PreferenceStore {
+    /// Create a store, loading saved profiles when a store file is configured
+    ///
+    /// A missing file starts an empty store; an unreadable one is logged and
+    /// replaced on the next save.
+    pub fn new(config: &PreferencesConfig) -> Self {
+        let store_path = config.store_path.as_ref().map(PathBuf::from);
+        let profiles = match &store_path {
+            Some(path) if path.exists() => match load_profiles(path) {
+                Ok(profiles) => {
+                    info!(
+                        "Loaded {} preference profiles from {}",
+                        profiles.len(),
+                        path.display()
+                    );
+                    profiles
+                }
+                Err(e) => {
+                    warn!(
+                        "Failed to load preference profiles from {}: {}",
+                        path.display(),
+                        e
+                    );
+                    HashMap::new()
+                }
+            },
+            _ => HashMap::new(),
+        };
+        Self {
+            profiles: Arc::new(RwLock::new(profiles)),
+            store_path,
+            save_lock: Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    /// Profile of a key, empty when it has none
+    pub fn get(&self, key: &str) -> PreferenceProfile {
+        self.profiles
+            .read()
+            .unwrap()
+            .get(key)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Replace the profile of a key
+    pub async fn set(&self, key: &str, mut profile: PreferenceProfile) -> PreferenceProfile {
+        profile.updated_at = Some(Utc::now());
+        self.profiles
+            .write()
+            .unwrap()
+            .insert(key.to_string(), profile.clone());
+        self.save().await;
+        profile
+    }
+
+    /// Remove the profile of a key; returns whether it had one
+    pub async fn remove(&self, key: &str) -> bool {
+        let removed = self.profiles.write().unwrap().remove(key).is_some();
+        if removed {
+            self.save().await;
+        }
+        removed
+    }
+
+    /// Adjust the issuing key's profile to feedback on one of its results
+    ///
+    /// Returns the updated profile when the key opted in and the feedback changed it.
+    pub async fn learn_from_feedback(&self, feedback: &TokenFeedback) -> Option<PreferenceProfile> {
+        let updated = {
+            let mut profiles = self.profiles.write().unwrap();
+            let profile = profiles
+                .get_mut(&feedback.key)
+                .filter(|profile| profile.learn_from_feedback)?;
+            if !profile.learn(
+                feedback.helpful,
+                feedback.rating,
+                feedback.comment.as_deref(),
+            ) {
+                return None;
+            }
+            profile.updated_at = Some(Utc::now());
+            profile.clone()
+        };
+        info!(
+            "Updated preference profile of {} from feedback {}",
+            feedback.key, feedback.id
+        );
+        self.save().await;
+        Some(updated)
+    }
+
+    /// Write all profiles to the store file, if configured
+    ///
+    /// Failures are logged; the profiles stay in memory.
+    async fn save(&self) {
+        let Some(path) = &self.store_path else {
+            return;
+        };
+        let _guard = self.save_lock.lock().await;
+        let snapshot = self.profiles.read().unwrap().clone();
+        if let Err(e) = write_json_atomic_async(path, &snapshot).await {
+            error!(
+                "Failed to save preference profiles to {}: {}",
+                path.display(),
+                e
+            );
+        }
+    }
+}
+
+fn load_profiles(path: &Path) -> std::io::Result<HashMap<String, PreferenceProfile>> {
+    let content = std::fs::read(path)?;
+    Ok(serde_json::from_slice(&content)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn feedback(key: &str, helpful: bool, comment: &str) -> TokenFeedback {
+        TokenFeedback {
+            id: Uuid::new_v4(),
+            result_id: "result-1".to_string(),
+            key: key.to_string(),
+            scope: vec![],
+            helpful,
+            rating: None,
+            comment: Some(comment.to_string()),
+            submitted_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_profile_fills_only_missing_context() {
+        let profile = PreferenceProfile {
+            audience_level: Some("advanced".to_string()),
+            language: Some("python".to_string()),
+            ..Default::default()
+        };
+
+        let audience = profile.audience_context(None).unwrap();
+        assert_eq!(audience.level, "advanced");
+        assert_eq!(audience.format, "markdown");
+        let explicit = AudienceContext {
+            level: "beginner".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            profile.audience_context(Some(explicit.clone())),
+            Some(explicit)
+        );
+        assert_eq!(profile.domain_context(None).unwrap().technology, "python");
+        assert!(PreferenceProfile::default()
+            .audience_context(None)
+            .is_none());
+        assert!(PreferenceProfile::default().domain_context(None).is_none());
+    }
+
+    #[test]
+    fn test_profile_learns_from_negative_feedback_comments() {
+        let mut profile = PreferenceProfile::default();
+        assert!(!profile.learn(true, Some(5), Some("Way too basic")));
+        assert!(profile.learn(false, None, Some("Way too basic and too long")));
+        assert_eq!(profile.audience_level.as_deref(), Some("advanced"));
+        assert_eq!(profile.output_style.as_deref(), Some("concise"));
+        assert!(!profile.learn(false, None, Some("Still too basic")));
+
+        assert!(profile.learn(true, Some(1), Some("Too complex, needs more detail")));
+        assert_eq!(profile.audience_level.as_deref(), Some("intermediate"));
+        assert_eq!(profile.output_style.as_deref(), Some("thorough"));
+        assert!(!profile.learn(false, None, Some("Wrong answer")));
+    }
+
+    #[tokio::test]
+    async fn test_store_persists_profiles_and_learns_when_opted_in() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = PreferencesConfig {
+            store_path: Some(dir.path().join("prefs/profiles.json").display().to_string()),
+        };
+        let store = PreferenceStore::new(&config);
+
+        store
+            .set(
+                "alice",
+                PreferenceProfile {
+                    provider: Some("openai".to_string()),
+                    learn_from_feedback: true,
+                    ..Default::default()
+                },
+            )
+            .await;
+        store.set("bob", PreferenceProfile::default()).await;
+
+        let updated = store
+            .learn_from_feedback(&feedback("alice", false, "too short"))
+            .await
+            .unwrap();
+        assert_eq!(updated.output_style.as_deref(), Some("thorough"));
+        assert!(store
+            .learn_from_feedback(&feedback("bob", false, "too short"))
+            .await
+            .is_none());
+        assert!(store
+            .learn_from_feedback(&feedback("carol", false, "too short"))
+            .await
+            .is_none());
+
+        let reloaded = PreferenceStore::new(&config);
+        assert_eq!(reloaded.get("alice"), updated);
+        assert!(reloaded.remove("bob").await);
+        assert!(!reloaded.remove("bob").await);
+        assert_eq!(
+            PreferenceStore::new(&config).get("bob"),
+            PreferenceProfile::default()
+        );
+    }
+}
@@ -0,0 +1,378 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: In-memory per-key token and monthly budget accounting for research requests
+// Enforces the configured quota tiers and reports usage for `/api/v1/limits/me`
+
+use crate::config::{QuotaConfig, QuotaTier};
+use crate::models::errors::ApiError;
+use crate::models::responses::{BudgetStatus, TokenQuotaStatus};
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, TimeZone, Utc};
+use fortitude_core::{estimate_token_count, PipelineConfig};
+use fortitude_types::ResearchResult;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tracing::warn;
+
+/// Usage recorded for one key
+#[derive(Debug, Clone)]
+struct KeyUsage {
+    window_start: DateTime<Utc>,
+    window_tokens: u64,
+    month: String,
+    monthly_requests: u64,
+    monthly_tokens: u64,
+    monthly_cost_usd: f64,
+}
+
+impl KeyUsage {
+    fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            window_start: now,
+            window_tokens: 0,
+            month: month_key(now),
+            monthly_requests: 0,
+            monthly_tokens: 0,
+            monthly_cost_usd: 0.0,
+        }
+    }
+
+    /// Start a new window or month once the current one has passed
+    fn roll(&mut self, now: DateTime<Utc>, window: ChronoDuration) {
+        if now - self.window_start >= window {
+            self.window_start = now;
+            self.window_tokens = 0;
+        }
+        let month = month_key(now);
+        if month != self.month {
+            *self = Self {
+                window_start: self.window_start,
+                window_tokens: self.window_tokens,
+                ..Self::new(now)
+            };
+        }
+    }
+}
+
+/// Per-key token and budget tracker shared by the research and limits routes
+#[derive(Debug, Clone)]
+pub struct QuotaTracker {
+    config: Arc<QuotaConfig>,
+    window: ChronoDuration,
+    usage: Arc<RwLock<HashMap<String, KeyUsage>>>,
+}
+
+impl Default for QuotaTracker {
+    fn default() -> Self {
+        Self::new(QuotaConfig::default(), 60)
+    }
+}
+
+impl QuotaTracker {
+    /// Create a tracker whose token windows last `window_seconds`
+    pub fn new(config: QuotaConfig, window_seconds: u64) -> Self {
+        Self {
+            config: Arc::new(config),
+            window: ChronoDuration::seconds(window_seconds as i64),
+            usage: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Name and limits of the tier assigned to a key
+    pub fn tier_for(&self, key: &str) -> (String, QuotaTier) {
+        let (name, tier) = self.config.tier_for(key);
+        (name.to_string(), tier)
+    }
+
+    /// Reject a request when the key has used up its token window or monthly budget
+    pub fn check(&self, key: &str) -> Result<(), ApiError> {
+        self.check_at(key, Utc::now())
+    }
+
+    /// When a key with an exhausted token window or monthly budget may retry
+    pub fn exhausted_until(&self, key: &str) -> Option<DateTime<Utc>> {
+        self.exhausted_until_at(key, Utc::now())
+    }
+
+    /// Record the tokens and estimated cost of a completed request
+    pub fn record(&self, key: &str, tokens: u64, cost_usd: f64) {
+        self.record_at(key, tokens, cost_usd, Utc::now());
+    }
+
+    /// Token usage of a key in the current window
+    pub fn token_status(&self, key: &str) -> TokenQuotaStatus {
+        let now = Utc::now();
+        let usage = self.current_usage(key, now);
+        let limit = self.tier_for(key).1.max_tokens_per_window;
+        TokenQuotaStatus {
+            limit,
+            used: usage.window_tokens,
+            remaining: limit.map(|limit| limit.saturating_sub(usage.window_tokens)),
+            resets_at: usage.window_start + self.window,
+        }
+    }
+
+    /// Budget consumption of a key in the current month
+    pub fn budget_status(&self, key: &str) -> BudgetStatus {
+        let now = Utc::now();
+        let usage = self.current_usage(key, now);
+        let limit_usd = self.tier_for(key).1.monthly_budget_usd;
+        BudgetStatus {
+            month: usage.month,
+            requests: usage.monthly_requests,
+            tokens: usage.monthly_tokens,
+            spent_usd: usage.monthly_cost_usd,
+            limit_usd,
+            remaining_usd: limit_usd.map(|limit| (limit - usage.monthly_cost_usd).max(0.0)),
+            resets_at: next_month_start(now),
+        }
+    }
+
+    fn check_at(&self, key: &str, now: DateTime<Utc>) -> Result<(), ApiError> {
+        let (tier_name, tier) = self.tier_for(key);
+        let usage = self.current_usage(key, now);
+
+        let tokens_exhausted = tier
+            .max_tokens_per_window
+            .is_some_and(|limit| usage.window_tokens >= limit);
+        let budget_exhausted = tier
+            .monthly_budget_usd
+            .is_some_and(|budget| usage.monthly_cost_usd >= budget);
+
+        if tokens_exhausted || budget_exhausted {
+            warn!(
+                "Quota exhausted for key {} (tier {}): tokens={}, spent=${:.4}",
+                key, tier_name, usage.window_tokens, usage.monthly_cost_usd
+            );
+            return Err(ApiError::RateLimitExceeded);
+        }
+        Ok(())
+    }
+
+    fn exhausted_until_at(&self, key: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let tier = self.tier_for(key).1;
+        let usage = self.current_usage(key, now);
+
+        if tier
+            .monthly_budget_usd
+            .is_some_and(|budget| usage.monthly_cost_usd >= budget)
+        {
+            return Some(next_month_start(now));
+        }
+        if tier
+            .max_tokens_per_window
+            .is_some_and(|limit| usage.window_tokens >= limit)
+        {
+            return Some(usage.window_start + self.window);
+        }
+        None
+    }
+
+    fn record_at(&self, key: &str, tokens: u64, cost_usd: f64, now: DateTime<Utc>) {
+        let mut usage = self.usage.write().unwrap();
+        let entry = usage
+            .entry(key.to_string())
+            .or_insert_with(|| KeyUsage::new(now));
+        entry.roll(now, self.window);
+        entry.window_tokens += tokens;
+        entry.monthly_requests += 1;
+        entry.monthly_tokens += tokens;
+        entry.monthly_cost_usd += cost_usd;
+    }
+
+    fn current_usage(&self, key: &str, now: DateTime<Utc>) -> KeyUsage {
+        let usage = self.usage.read().unwrap();
+        let mut current = usage
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| KeyUsage::new(now));
+        current.roll(now, self.window);
+        current
+    }
+}
+
+/// Tokens used and estimated provider cost of a research result
+///
+/// Input tokens come from the prompt-budget report when present, and the
+/// model or provider recorded on the result sets the price. Results produced
+/// without a research engine cost nothing.
+pub fn estimate_research_usage(
+    result: &ResearchResult,
+    config: &PipelineConfig,
+    engine_configured: bool,
+) -> (u64, f64) {
+    let input_tokens = result
+        .metadata
+        .tags
+        .get("prompt_tokens")
+        .and_then(|tokens| tokens.parse().ok())
+        .unwrap_or_else(|| estimate_token_count(&result.request.original_query));
+    let output_tokens = estimate_token_count(&result.immediate_answer)
+        + result
+            .supporting_evidence
+            .iter()
+            .map(|e| estimate_token_count(&e.content))
+            .sum::<u32>()
+        + result
+            .implementation_details
+            .iter()
+            .map(|d| estimate_token_count(&d.content))
+            .sum::<u32>();
+
+    // Priced at the model or provider the request selected, if any
+    let catalog = &config.model_catalog;
+    let tags = &result.metadata.tags;
+    let pricing = match (tags.get("model"), tags.get("provider")) {
+        (Some(model), _) => catalog.get(model),
+        (None, Some(provider)) => catalog.for_provider(provider).into_iter().next(),
+        (None, None) => config
+            .prompt_budget
+            .model
+            .as_deref()
+            .and_then(|model| catalog.get(model))
+            .or_else(|| {
+                catalog
+                    .for_provider(&config.default_provider)
+                    .into_iter()
+                    .next()
+            }),
+    };
+    let cost_usd = match pricing {
+        Some(pricing) if engine_configured => pricing.cost_usd(input_tokens, output_tokens),
+        _ => 0.0,
+    };
+
+    (input_tokens as u64 + output_tokens as u64, cost_usd)
+}
+
+fn month_key(time: DateTime<Utc>) -> String {
+    time.format("%Y-%m").to_string()
+}
+
+fn next_month_start(time: DateTime<Utc>) -> DateTime<Utc> {
+    let (year, month) = if time.month() == 12 {
+        (time.year() + 1, 1)
+    } else {
+        (time.year(), time.month() + 1)
+    };
+    Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0)
+        .single()
+        .unwrap_or(time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limited_tracker() -> QuotaTracker {
+        let mut config = QuotaConfig::default();
+        config.tiers.insert(
+            "trial".to_string(),
+            QuotaTier {
+                max_requests_per_window: None,
+                max_tokens_per_window: Some(100),
+                monthly_budget_usd: Some(1.0),
+            },
+        );
+        config
+            .key_tiers
+            .insert("alice".to_string(), "trial".to_string());
+        QuotaTracker::new(config, 60)
+    }
+
+    #[test]
+    fn test_token_window_is_enforced_and_resets() {
+        let tracker = limited_tracker();
+        let now = Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+
+        assert!(tracker.check_at("alice", now).is_ok());
+        tracker.record_at("alice", 120, 0.01, now);
+        assert!(matches!(
+            tracker.check_at("alice", now),
+            Err(ApiError::RateLimitExceeded)
+        ));
+        assert_eq!(
+            tracker.exhausted_until_at("alice", now),
+            Some(now + ChronoDuration::seconds(60))
+        );
+        // Unassigned keys fall back to the unlimited default tier
+        tracker.record_at("bob", 120, 0.01, now);
+        assert!(tracker.check_at("bob", now).is_ok());
+
+        let later = now + ChronoDuration::seconds(61);
+        assert!(tracker.check_at("alice", later).is_ok());
+        let usage = tracker.current_usage("alice", later);
+        assert_eq!(usage.window_tokens, 0);
+        assert_eq!(usage.monthly_tokens, 120);
+        assert_eq!(usage.monthly_requests, 1);
+    }
+
+    #[test]
+    fn test_monthly_budget_is_enforced_and_resets() {
+        let tracker = limited_tracker();
+        let now = Utc.with_ymd_and_hms(2025, 12, 31, 23, 0, 0).unwrap();
+
+        tracker.record_at("alice", 10, 1.5, now);
+        assert!(tracker
+            .check_at("alice", now + ChronoDuration::seconds(120))
+            .is_err());
+
+        let next_month = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 1).unwrap();
+        assert!(tracker.check_at("alice", next_month).is_ok());
+        assert_eq!(tracker.current_usage("alice", next_month).month, "2026-01");
+        assert_eq!(
+            next_month_start(now),
+            next_month - ChronoDuration::seconds(1)
+        );
+    }
+
+    #[test]
+    fn test_usage_is_priced_at_the_selected_model() {
+        let request = fortitude_types::ClassifiedRequest::new(
+            "How do lifetimes work?".to_string(),
+            fortitude_types::ResearchType::Learning,
+            Default::default(),
+            Default::default(),
+            0.9,
+            vec![],
+        );
+        let mut result = ResearchResult::new(
+            request,
+            "a".repeat(4000),
+            vec![],
+            vec![],
+            fortitude_types::ResearchMetadata {
+                completed_at: Utc::now(),
+                processing_time_ms: 1,
+                sources_consulted: vec![],
+                quality_score: 0.8,
+                cache_key: String::new(),
+                tags: HashMap::new(),
+            },
+        );
+        let config = PipelineConfig {
+            default_provider: "claude".to_string(),
+            ..Default::default()
+        };
+
+        let (_, default_cost) = estimate_research_usage(&result, &config, true);
+        result
+            .metadata
+            .tags
+            .insert("model".to_string(), "gpt-4".to_string());
+        let (_, gpt4_cost) = estimate_research_usage(&result, &config, true);
+        assert!(gpt4_cost > default_cost);
+        assert_eq!(estimate_research_usage(&result, &config, false).1, 0.0);
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: In-memory registry of research jobs that outlive their submitting request
+// Lets clients wait server-side for a result and pick it up later by job ID if the wait times out
+
+use crate::models::{errors::ApiError, responses::ResearchResponse};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::sync::watch;
+use uuid::Uuid;
+
+/// Longest a client may ask the server to hold a request open
+pub const MAX_WAIT_TIMEOUT_MS: u64 = 120_000;
+
+/// Finished jobs are kept this long for pickup
+const FINISHED_JOB_RETENTION_MINUTES: i64 = 60;
+
+/// Upper bound on tracked jobs; the oldest finished jobs are dropped first
+const MAX_TRACKED_JOBS: usize = 1000;
+
+/// Current state of a research job
+#[derive(Debug, Clone)]
+pub enum JobState {
+    Running,
+    Completed {
+        response: Box<ResearchResponse>,
+        finished_at: DateTime<Utc>,
+    },
+    Failed {
+        error: ApiError,
+        finished_at: DateTime<Utc>,
+    },
+}
+
+impl JobState {
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, JobState::Running)
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self {
+            JobState::Running => "running",
+            JobState::Completed { .. } => "completed",
+            JobState::Failed { .. } => "failed",
+        }
+    }
+
+    fn finished_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            JobState::Running => None,
+            JobState::Completed { finished_at, .. } | JobState::Failed { finished_at, .. } => {
+                Some(*finished_at)
+            }
+        }
+    }
+}
+
+/// Point-in-time view of a job
+#[derive(Debug, Clone)]
+pub struct JobSnapshot {
+    pub id: String,
+    pub submitted_at: DateTime<Utc>,
+    pub state: JobState,
+}
+
+struct JobEntry {
+    submitted_at: DateTime<Utc>,
+    state: watch::Sender<JobState>,
+}
+
+/// Registry of background research jobs
+#[derive(Clone, Default)]
+pub struct ResearchJobs {
+    jobs: Arc<RwLock<HashMap<String, JobEntry>>>,
+}
+
+impl ResearchJobs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `work` in the background and return its job ID immediately
+    pub fn spawn<F>(&self, work: F) -> String
+    where
+        F: Future<Output = Result<ResearchResponse, ApiError>> + Send + 'static,
+    {
+        let id = Uuid::new_v4().to_string();
+        let (sender, _) = watch::channel(JobState::Running);
+        {
+            let mut jobs = self.jobs.write().unwrap();
+            Self::evict(&mut jobs);
+            jobs.insert(
+                id.clone(),
+                JobEntry {
+                    submitted_at: Utc::now(),
+                    state: sender.clone(),
+                },
+            );
+        }
+
+        tokio::spawn(async move {
+            let outcome = work.await;
+            let finished_at = Utc::now();
+            let state = match outcome {
+                Ok(response) => JobState::Completed {
+                    response: Box::new(response),
+                    finished_at,
+                },
+                Err(error) => JobState::Failed { error, finished_at },
+            };
+            sender.send_replace(state);
+        });
+
+        id
+    }
+
+    /// Current state of a job, or `None` if it is unknown or expired
+    pub fn get(&self, id: &str) -> Option<JobSnapshot> {
+        let jobs = self.jobs.read().unwrap();
+        jobs.get(id).map(|entry| JobSnapshot {
+            id: id.to_string(),
+            submitted_at: entry.submitted_at,
+            state: entry.state.borrow().clone(),
+        })
+    }
+
+    /// Wait up to `timeout` for the job to finish, then return its state
+    pub async fn wait(&self, id: &str, timeout: Duration) -> Option<JobSnapshot> {
+        let mut receiver = {
+            let jobs = self.jobs.read().unwrap();
+            jobs.get(id)?.state.subscribe()
+        };
+        if !timeout.is_zero() {
+            // Timing out just means the caller gets the running state
+            let _ = tokio::time::timeout(timeout, receiver.wait_for(JobState::is_finished)).await;
+        }
+        self.get(id)
+    }
+
+    /// Number of jobs currently tracked
+    pub fn len(&self) -> usize {
+        self.jobs.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn evict(jobs: &mut HashMap<String, JobEntry>) {
+        let cutoff = Utc::now() - ChronoDuration::minutes(FINISHED_JOB_RETENTION_MINUTES);
+        jobs.retain(|_, entry| {
+            entry
+                .state
+                .borrow()
+                .finished_at()
+                .is_none_or(|finished| finished > cutoff)
+        });
+
+        if jobs.len() >= MAX_TRACKED_JOBS {
+            let mut finished: Vec<(String, DateTime<Utc>)> = jobs
+                .iter()
+                .filter_map(|(id, entry)| {
+                    entry
+                        .state
+                        .borrow()
+                        .finished_at()
+                        .map(|at| (id.clone(), at))
+                })
+                .collect();
+            finished.sort_by_key(|(_, at)| *at);
+            let excess = jobs.len() + 1 - MAX_TRACKED_JOBS;
+            for (id, _) in finished.into_iter().take(excess) {
+                jobs.remove(&id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::responses::ResearchMetadata;
+
+    fn response(id: &str) -> ResearchResponse {
+        ResearchResponse {
+            id: id.to_string(),
+            query: "query".to_string(),
+            research_type: "learning".to_string(),
+            labels: vec![],
+            immediate_answer: "answer".to_string(),
+            supporting_evidence: vec![],
+            implementation_details: vec![],
+            metadata: ResearchMetadata {
+                completed_at: Utc::now(),
+                processing_time_ms: 1,
+                sources_consulted: vec![],
+                quality_score: 0.5,
+                provider: None,
+                model: None,
+                tags: HashMap::new(),
+            },
+            parent_id: None,
+            feedback: None,
+            related_results: vec![],
+            processing_time_ms: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_returns_finished_result() {
+        let jobs = ResearchJobs::new();
+        let id = jobs.spawn(async { Ok(response("result-1")) });
+
+        let snapshot = jobs.wait(&id, Duration::from_secs(5)).await.unwrap();
+        match snapshot.state {
+            JobState::Completed { response, .. } => assert_eq!(response.id, "result-1"),
+            other => panic!("unexpected state: {other:?}"),
+        }
+        assert!(jobs.get("missing").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_wait_times_out_while_running() {
+        let jobs = ResearchJobs::new();
+        let (release, released) = tokio::sync::oneshot::channel::<()>();
+        let id = jobs.spawn(async move {
+            let _ = released.await;
+            Err(ApiError::ResearchError {
+                message: "provider failed".to_string(),
+            })
+        });
+
+        let snapshot = jobs.wait(&id, Duration::from_millis(20)).await.unwrap();
+        assert_eq!(snapshot.state.status(), "running");
+
+        release.send(()).unwrap();
+        let snapshot = jobs.wait(&id, Duration::from_secs(5)).await.unwrap();
+        assert_eq!(snapshot.state.status(), "failed");
+    }
+}
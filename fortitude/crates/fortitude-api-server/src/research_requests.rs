@@ -0,0 +1,213 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Registry of running research requests by request ID, for cancellation by their callers
+// A request is cancelled explicitly through the API or when its client disconnects before the response
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tokio::sync::watch;
+use tracing::info;
+use uuid::Uuid;
+
+/// Outcome of a cancellation request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CancelOutcome {
+    Cancelled,
+    /// No research with this request ID is running
+    NotFound,
+    /// The research belongs to another user
+    NotOwner,
+}
+
+#[derive(Debug)]
+struct Tracked {
+    user: String,
+    cancel: watch::Sender<bool>,
+}
+
+/// Shared registry of running research requests
+#[derive(Debug, Clone, Default)]
+pub struct ResearchRequests {
+    requests: Arc<RwLock<HashMap<Uuid, Tracked>>>,
+}
+
+impl ResearchRequests {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start tracking research for `request_id`
+    ///
+    /// Returns `None` if research with the same request ID is already running.
+    /// The request stays registered until the returned guard is dropped.
+    pub fn register(&self, request_id: Uuid, user: &str) -> Option<ResearchRequestGuard> {
+        let mut requests = self.requests.write().unwrap();
+        if requests.contains_key(&request_id) {
+            return None;
+        }
+        let (cancel, cancelled) = watch::channel(false);
+        requests.insert(
+            request_id,
+            Tracked {
+                user: user.to_string(),
+                cancel,
+            },
+        );
+        Some(ResearchRequestGuard {
+            request_id,
+            registry: self.clone(),
+            cancelled,
+        })
+    }
+
+    /// Cancel running research on behalf of `user`; `None` skips the ownership check
+    pub fn cancel(&self, request_id: Uuid, user: Option<&str>) -> CancelOutcome {
+        let requests = self.requests.read().unwrap();
+        let Some(tracked) = requests.get(&request_id) else {
+            return CancelOutcome::NotFound;
+        };
+        if user.is_some_and(|user| user != tracked.user) {
+            return CancelOutcome::NotOwner;
+        }
+        tracked.cancel.send_replace(true);
+        info!("Cancellation requested for research request {}", request_id);
+        CancelOutcome::Cancelled
+    }
+
+    /// Cancel `request_id` when the returned guard is dropped before being disarmed
+    pub fn cancel_on_drop(&self, request_id: Uuid) -> CancelOnDrop {
+        CancelOnDrop {
+            registry: self.clone(),
+            request_id,
+            armed: true,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.requests.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Registration of one running research request
+#[derive(Debug)]
+pub struct ResearchRequestGuard {
+    request_id: Uuid,
+    registry: ResearchRequests,
+    cancelled: watch::Receiver<bool>,
+}
+
+impl ResearchRequestGuard {
+    pub fn request_id(&self) -> Uuid {
+        self.request_id
+    }
+
+    /// Resolves once the request is cancelled
+    pub async fn cancelled(&self) {
+        let mut cancelled = self.cancelled.clone();
+        if cancelled.wait_for(|c| *c).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+impl Drop for ResearchRequestGuard {
+    fn drop(&mut self) {
+        self.registry
+            .requests
+            .write()
+            .unwrap()
+            .remove(&self.request_id);
+    }
+}
+
+/// Cancels detached research if the handler waiting on it is dropped
+///
+/// Axum drops a handler's future when its client disconnects, which is the
+/// only signal a handler gets that nobody will read its response.
+#[derive(Debug)]
+pub struct CancelOnDrop {
+    registry: ResearchRequests,
+    request_id: Uuid,
+    armed: bool,
+}
+
+impl CancelOnDrop {
+    /// Keep the research running after the guard is dropped
+    pub fn disarm(mut self) {
+        self.armed = false;
+    }
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if self.armed {
+            info!(
+                "Client of research request {} disconnected, cancelling",
+                self.request_id
+            );
+            self.registry.cancel(self.request_id, None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_cancel_checks_owner_and_wakes_request() {
+        let requests = ResearchRequests::new();
+        let id = Uuid::new_v4();
+        let guard = requests.register(id, "alice").unwrap();
+        assert!(requests.register(id, "bob").is_none());
+
+        assert_eq!(requests.cancel(id, Some("bob")), CancelOutcome::NotOwner);
+        assert_eq!(
+            requests.cancel(Uuid::new_v4(), None),
+            CancelOutcome::NotFound
+        );
+        assert_eq!(requests.cancel(id, Some("alice")), CancelOutcome::Cancelled);
+        tokio::time::timeout(Duration::from_secs(1), guard.cancelled())
+            .await
+            .unwrap();
+
+        drop(guard);
+        assert!(requests.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_on_drop_unless_disarmed() {
+        let requests = ResearchRequests::new();
+        let kept = requests.register(Uuid::new_v4(), "alice").unwrap();
+        let dropped = requests.register(Uuid::new_v4(), "alice").unwrap();
+
+        requests.cancel_on_drop(kept.request_id()).disarm();
+        drop(requests.cancel_on_drop(dropped.request_id()));
+
+        tokio::time::timeout(Duration::from_secs(1), dropped.cancelled())
+            .await
+            .unwrap();
+        assert!(
+            tokio::time::timeout(Duration::from_millis(20), kept.cancelled())
+                .await
+                .is_err()
+        );
+    }
+}
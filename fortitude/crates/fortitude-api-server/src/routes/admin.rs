@@ -0,0 +1,401 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Administrative endpoints for server operations
+// Exposes maintenance scheduler status, in-flight request introspection and API key management
+
+use crate::inflight::{InflightRegistry, InflightSnapshot};
+use crate::maintenance::MaintenanceScheduler;
+use crate::middleware::auth::Claims;
+use crate::middleware::rbac::{ApiKeyRecord, ApiKeyStore, Role};
+use crate::models::errors::ApiError;
+use crate::models::requests::ApiKeyCreateRequest;
+use crate::models::responses::{
+    ApiKeyInfo, ApiKeyListResponse, ApiKeyRevokeResponse, ApiKeySecretResponse, ApiResponse,
+    InflightCancelResponse, InflightListResponse, InflightRequestInfo, MaintenanceStatusResponse,
+};
+use axum::{
+    extract::{Path, State},
+    response::Json,
+    Extension,
+};
+use tracing::{debug, info, instrument};
+use utoipa;
+use uuid::Uuid;
+use validator::Validate;
+
+/// GET /api/v1/admin/maintenance - Maintenance scheduler status
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/maintenance",
+    responses(
+        (status = 200, description = "Maintenance task schedules and last-run status", body = ApiResponse<MaintenanceStatusResponse>),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - admin permission required"),
+    ),
+    tag = "Admin",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn get_maintenance_status(
+    State(scheduler): State<MaintenanceScheduler>,
+    claims_ext: Option<Extension<Claims>>,
+) -> Result<Json<ApiResponse<MaintenanceStatusResponse>>, ApiError> {
+    if let Some(Extension(claims)) = claims_ext.as_ref() {
+        debug!("Getting maintenance status for user: {}", claims.sub);
+    } else {
+        debug!("Getting maintenance status (auth disabled)");
+    }
+
+    let status = scheduler.status().await;
+    Ok(Json(ApiResponse::success(status, Uuid::new_v4())))
+}
+
+/// GET /api/v1/admin/inflight - Requests currently being handled
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/inflight",
+    responses(
+        (status = 200, description = "In-flight requests with stage, provider and elapsed time", body = ApiResponse<InflightListResponse>),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - admin permission required"),
+    ),
+    tag = "Admin",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn list_inflight_requests(
+    State(registry): State<InflightRegistry>,
+    claims_ext: Option<Extension<Claims>>,
+) -> Result<Json<ApiResponse<InflightListResponse>>, ApiError> {
+    if let Some(Extension(claims)) = claims_ext.as_ref() {
+        debug!("Listing in-flight requests for user: {}", claims.sub);
+    } else {
+        debug!("Listing in-flight requests (auth disabled)");
+    }
+
+    let requests: Vec<InflightRequestInfo> = registry
+        .list()
+        .into_iter()
+        .map(convert_inflight_snapshot)
+        .collect();
+    let response = InflightListResponse {
+        total_count: requests.len(),
+        requests,
+    };
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
+
+/// DELETE /api/v1/admin/inflight/{id} - Cancel an in-flight request
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/inflight/{id}",
+    params(("id" = Uuid, Path, description = "In-flight request ID")),
+    responses(
+        (status = 200, description = "Cancellation requested", body = ApiResponse<InflightCancelResponse>),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - admin permission required"),
+        (status = 404, description = "No in-flight request with this ID"),
+    ),
+    tag = "Admin",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all, fields(id = %id))]
+pub async fn cancel_inflight_request(
+    State(registry): State<InflightRegistry>,
+    claims_ext: Option<Extension<Claims>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<InflightCancelResponse>>, ApiError> {
+    let actor = claims_ext
+        .as_ref()
+        .map(|ext| ext.0.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string());
+
+    if !registry.cancel(id) {
+        return Err(ApiError::NotFound {
+            resource: format!("In-flight request with ID: {id}"),
+        });
+    }
+    info!("In-flight request {} cancelled by {}", id, actor);
+
+    let response = InflightCancelResponse {
+        id,
+        cancelled: true,
+    };
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
+
+/// GET /api/v1/admin/keys - List API keys
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/keys",
+    responses(
+        (status = 200, description = "API keys with their roles; secrets are not included", body = ApiResponse<ApiKeyListResponse>),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - admin permission required"),
+    ),
+    tag = "Admin",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn list_api_keys(
+    State(store): State<ApiKeyStore>,
+    claims_ext: Option<Extension<Claims>>,
+) -> Result<Json<ApiResponse<ApiKeyListResponse>>, ApiError> {
+    if let Some(Extension(claims)) = claims_ext.as_ref() {
+        debug!("Listing API keys for user: {}", claims.sub);
+    } else {
+        debug!("Listing API keys (auth disabled)");
+    }
+
+    let keys: Vec<ApiKeyInfo> = store.list().iter().map(convert_api_key).collect();
+    let response = ApiKeyListResponse {
+        total_count: keys.len(),
+        keys,
+    };
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
+
+/// POST /api/v1/admin/keys - Create an API key
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/keys",
+    request_body = ApiKeyCreateRequest,
+    responses(
+        (status = 200, description = "Key created; the secret is only returned once", body = ApiResponse<ApiKeySecretResponse>),
+        (status = 400, description = "Invalid name or role"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - admin permission required"),
+    ),
+    tag = "Admin",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn create_api_key(
+    State(store): State<ApiKeyStore>,
+    claims_ext: Option<Extension<Claims>>,
+    Json(request): Json<ApiKeyCreateRequest>,
+) -> Result<Json<ApiResponse<ApiKeySecretResponse>>, ApiError> {
+    request.validate().map_err(|e| ApiError::BadRequest {
+        message: format!("Validation failed: {e}"),
+    })?;
+    let role: Role = request
+        .role
+        .parse()
+        .map_err(|message| ApiError::BadRequest { message })?;
+
+    let actor = claims_ext
+        .as_ref()
+        .map(|ext| ext.0.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string());
+
+    let (record, secret) = store.create(&request.name, role).await;
+    info!(
+        "API key {} ({}) created with role {} by {}",
+        record.id,
+        record.name,
+        role.as_str(),
+        actor
+    );
+
+    let response = ApiKeySecretResponse {
+        key: convert_api_key(&record),
+        secret,
+    };
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
+
+/// POST /api/v1/admin/keys/{id}/rotate - Replace the secret of an API key
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/keys/{id}/rotate",
+    params(("id" = String, Path, description = "API key ID")),
+    responses(
+        (status = 200, description = "Key rotated; the old secret stops working immediately", body = ApiResponse<ApiKeySecretResponse>),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - admin permission required"),
+        (status = 404, description = "No API key with this ID"),
+    ),
+    tag = "Admin",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all, fields(id = %id))]
+pub async fn rotate_api_key(
+    State(store): State<ApiKeyStore>,
+    claims_ext: Option<Extension<Claims>>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<ApiKeySecretResponse>>, ApiError> {
+    let actor = claims_ext
+        .as_ref()
+        .map(|ext| ext.0.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string());
+
+    let (record, secret) = store.rotate(&id).await.ok_or_else(|| ApiError::NotFound {
+        resource: format!("API key with ID: {id}"),
+    })?;
+    info!("API key {} rotated by {}", id, actor);
+
+    let response = ApiKeySecretResponse {
+        key: convert_api_key(&record),
+        secret,
+    };
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
+
+/// DELETE /api/v1/admin/keys/{id} - Revoke an API key
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/keys/{id}",
+    params(("id" = String, Path, description = "API key ID")),
+    responses(
+        (status = 200, description = "Key revoked", body = ApiResponse<ApiKeyRevokeResponse>),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - admin permission required"),
+        (status = 404, description = "No API key with this ID"),
+    ),
+    tag = "Admin",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all, fields(id = %id))]
+pub async fn revoke_api_key(
+    State(store): State<ApiKeyStore>,
+    claims_ext: Option<Extension<Claims>>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<ApiKeyRevokeResponse>>, ApiError> {
+    let actor = claims_ext
+        .as_ref()
+        .map(|ext| ext.0.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string());
+
+    if !store.revoke(&id).await {
+        return Err(ApiError::NotFound {
+            resource: format!("API key with ID: {id}"),
+        });
+    }
+    info!("API key {} revoked by {}", id, actor);
+
+    let response = ApiKeyRevokeResponse { id, revoked: true };
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
+
+fn convert_api_key(record: &ApiKeyRecord) -> ApiKeyInfo {
+    ApiKeyInfo {
+        id: record.id.clone(),
+        name: record.name.clone(),
+        role: record.role.as_str().to_string(),
+        prefix: record.prefix.clone(),
+        created_at: record.created_at,
+        rotated_at: record.rotated_at,
+    }
+}
+
+fn convert_inflight_snapshot(snapshot: InflightSnapshot) -> InflightRequestInfo {
+    InflightRequestInfo {
+        id: snapshot.id,
+        method: snapshot.method,
+        endpoint: snapshot.endpoint,
+        stage: snapshot.stage,
+        provider: snapshot.provider,
+        user: snapshot.user,
+        started_at: snapshot.started_at,
+        elapsed_ms: snapshot.elapsed_ms,
+        cancel_requested: snapshot.cancel_requested,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MaintenanceConfig;
+
+    #[tokio::test]
+    async fn test_get_maintenance_status() {
+        let scheduler = MaintenanceScheduler::new(MaintenanceConfig::default(), None, None, None);
+
+        let response = get_maintenance_status(State(scheduler), None)
+            .await
+            .unwrap();
+
+        assert!(response.0.success);
+        assert!(response.0.data.enabled);
+        assert!(!response.0.data.running);
+        assert_eq!(response.0.data.tasks.len(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_list_and_cancel_inflight_requests() {
+        let registry = InflightRegistry::new();
+        let guard = registry.register("POST", "/api/v1/research");
+        guard.handle().set_stage("research");
+
+        let response = list_inflight_requests(State(registry.clone()), None)
+            .await
+            .unwrap();
+        assert_eq!(response.0.data.total_count, 1);
+        assert_eq!(response.0.data.requests[0].stage, "research");
+
+        let id = guard.handle().id();
+        let response = cancel_inflight_request(State(registry.clone()), None, Path(id))
+            .await
+            .unwrap();
+        assert!(response.0.data.cancelled);
+
+        drop(guard);
+        let result = cancel_inflight_request(State(registry), None, Path(id)).await;
+        assert!(matches!(result, Err(ApiError::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_api_key_management() {
+        let store = ApiKeyStore::default();
+
+        let request = ApiKeyCreateRequest {
+            name: "ci".to_string(),
+            role: "owner".to_string(),
+        };
+        let result = create_api_key(State(store.clone()), None, Json(request)).await;
+        assert!(matches!(result, Err(ApiError::BadRequest { .. })));
+
+        let request = ApiKeyCreateRequest {
+            name: "ci".to_string(),
+            role: "researcher".to_string(),
+        };
+        let created = create_api_key(State(store.clone()), None, Json(request))
+            .await
+            .unwrap()
+            .0
+            .data;
+        assert_eq!(created.key.role, "researcher");
+        assert!(store.authenticate(&created.secret).is_some());
+
+        let id = created.key.id.clone();
+        let rotated = rotate_api_key(State(store.clone()), None, Path(id.clone()))
+            .await
+            .unwrap()
+            .0
+            .data;
+        assert_ne!(rotated.secret, created.secret);
+        assert!(store.authenticate(&created.secret).is_none());
+
+        let listed = list_api_keys(State(store.clone()), None).await.unwrap();
+        assert_eq!(listed.0.data.total_count, 1);
+
+        let revoked = revoke_api_key(State(store.clone()), None, Path(id.clone()))
+            .await
+            .unwrap();
+        assert!(revoked.0.data.revoked);
+        let result = revoke_api_key(State(store), None, Path(id)).await;
+        assert!(matches!(result, Err(ApiError::NotFound { .. })));
+    }
+}
@@ -19,20 +19,25 @@ use crate::config::ApiServerConfig;
 use crate::extractors::SafeQuery;
 use crate::middleware::auth::Claims;
 use crate::models::errors::ApiError;
-use crate::models::requests::{CacheInvalidateRequest, CacheSearchRequest};
+use crate::models::requests::{
+    CacheInvalidateRequest, CacheSearchRequest, CacheStatsRequest, CacheTypeStatsRequest,
+};
 use crate::models::responses::{
     AgeDistribution, ApiResponse, CacheCleanupResponse, CacheInvalidateResponse,
     CacheInvalidationCriteria, CacheItemResponse, CachePerformanceResponse, CacheSearchMetadata,
-    CacheSearchResponse, CacheStatsResponse, CacheTypeStatsResponse, CleanupSummary,
-    PaginationInfo, RecentOperationsResponse, SizeDistribution, StorageEfficiencyResponse,
+    CacheSearchResponse, CacheStatsResponse, CacheTypeDetailResponse, CacheTypeStatsResponse,
+    CleanupSummary, PaginationInfo, RecentOperationsResponse, SizeDistribution,
+    StorageEfficiencyResponse,
 };
 use axum::{
     extract::{Path, State},
-    http::StatusCode,
-    response::Json,
+    http::{header, StatusCode},
+    response::{IntoResponse, Json, Response},
     Extension,
 };
+use chrono::{DateTime, Timelike, Utc};
 use fortitude_core::storage::FileStorage;
+use fortitude_core::SearchExpression;
 use fortitude_types::{CacheEntry, ResearchType, SearchQuery, Storage};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -80,8 +85,12 @@ impl CacheState {
 #[utoipa::path(
     get,
     path = "/api/v1/cache/stats",
+    params(
+        CacheStatsRequest
+    ),
     responses(
         (status = 200, description = "Cache statistics retrieved successfully", body = ApiResponse<CacheStatsResponse>),
+        (status = 400, description = "Invalid statistics parameters"),
         (status = 401, description = "Unauthorized - JWT token required"),
         (status = 403, description = "Forbidden - insufficient permissions"),
         (status = 500, description = "Internal server error"),
@@ -93,6 +102,7 @@ impl CacheState {
 pub async fn get_cache_stats(
     State(cache_state): State<CacheState>,
     claims_ext: Option<Extension<Claims>>,
+    SafeQuery(stats_request): SafeQuery<CacheStatsRequest>,
 ) -> Result<Json<ApiResponse<CacheStatsResponse>>, ApiError> {
     if let Some(Extension(claims)) = claims_ext.as_ref() {
         debug!("Getting cache statistics for user: {}", claims.sub);
@@ -109,22 +119,13 @@ pub async fn get_cache_stats(
             message: "Failed to retrieve cache statistics".to_string(),
         }
     })?;
+    let entries = list_entries(&cache_state).await?;
 
-    // Convert storage stats to response format
-    let mut by_research_type = HashMap::new();
-    for (research_type, type_stats) in &storage_stats.by_research_type {
-        by_research_type.insert(
-            research_type.to_string(),
-            CacheTypeStatsResponse {
-                entries: type_stats.entries,
-                size_bytes: type_stats.size_bytes,
-                hit_rate: type_stats.hit_rate,
-                hits: type_stats.hits,
-                misses: type_stats.misses,
-                average_quality: 0.85, // Placeholder - would calculate from actual data
-            },
-        );
-    }
+    // Filter, sort and paginate the per-type breakdown
+    let breakdown = build_type_breakdown(&storage_stats, &stats_request)?;
+    let window_hours = stats_request
+        .window_hours
+        .unwrap_or(DEFAULT_STATS_WINDOW_HOURS);
 
     let response = CacheStatsResponse {
         total_entries: storage_stats.total_entries,
@@ -134,7 +135,13 @@ pub async fn get_cache_stats(
         hits: storage_stats.hits,
         misses: storage_stats.misses,
         average_age_seconds: storage_stats.average_age_seconds,
-        by_research_type,
+        research_type_order: breakdown
+            .rows
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect(),
+        research_type_pagination: Some(breakdown.pagination),
+        by_research_type: breakdown.rows.into_iter().collect(),
         storage_efficiency: StorageEfficiencyResponse {
             utilization_percent: calculate_utilization_percent(&storage_stats),
             duplicate_entries: 0, // Placeholder - would track duplicates
@@ -145,12 +152,7 @@ pub async fn get_cache_stats(
             avg_retrieval_time_ms: 15.5, // Placeholder - would track actual metrics
             avg_storage_time_ms: 25.0,   // Placeholder - would track actual metrics
             warming_status: "ready".to_string(),
-            recent_operations: RecentOperationsResponse {
-                last_hour: 0, // Placeholder - would track actual operations
-                last_day: 0,  // Placeholder - would track actual operations
-                peak_hour: "N/A".to_string(),
-                top_accessed: vec![],
-            },
+            recent_operations: summarize_recent_operations(&entries, window_hours, Utc::now()),
         },
     };
 
@@ -165,6 +167,158 @@ pub async fn get_cache_stats(
     Ok(Json(ApiResponse::success(response, request_id)))
 }
 
+/// GET /api/v1/cache/stats/export - Export the per-type cache statistics as CSV
+#[utoipa::path(
+    get,
+    path = "/api/v1/cache/stats/export",
+    params(
+        CacheStatsRequest
+    ),
+    responses(
+        (status = 200, description = "CSV report of cache statistics by research type", content_type = "text/csv", body = String),
+        (status = 400, description = "Invalid statistics parameters"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "Cache",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn export_cache_stats(
+    State(cache_state): State<CacheState>,
+    claims_ext: Option<Extension<Claims>>,
+    SafeQuery(stats_request): SafeQuery<CacheStatsRequest>,
+) -> Result<Response, ApiError> {
+    if let Some(Extension(claims)) = claims_ext.as_ref() {
+        debug!("Exporting cache statistics for user: {}", claims.sub);
+    } else {
+        debug!("Exporting cache statistics (auth disabled)");
+    }
+
+    let storage_stats = cache_state.storage.get_cache_stats().await.map_err(|e| {
+        error!("Failed to get cache stats: {}", e);
+        ApiError::InternalError {
+            message: "Failed to retrieve cache statistics".to_string(),
+        }
+    })?;
+    let breakdown = build_type_breakdown(&storage_stats, &stats_request)?;
+
+    info!(
+        "Cache statistics exported as CSV ({} research types)",
+        breakdown.rows.len()
+    );
+
+    Ok((
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"cache-stats.csv\"",
+            ),
+        ],
+        type_stats_csv(&breakdown.rows),
+    )
+        .into_response())
+}
+
+/// GET /api/v1/cache/stats/{research_type} - Get cache statistics for one research type
+#[utoipa::path(
+    get,
+    path = "/api/v1/cache/stats/{research_type}",
+    params(
+        ("research_type" = String, Path, description = "Research type (decision, implementation, troubleshooting, learning, validation)"),
+        CacheTypeStatsRequest
+    ),
+    responses(
+        (status = 200, description = "Research type statistics retrieved successfully", body = ApiResponse<CacheTypeDetailResponse>),
+        (status = 400, description = "Invalid statistics parameters"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Unknown research type"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "Cache",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn get_cache_type_stats(
+    State(cache_state): State<CacheState>,
+    claims_ext: Option<Extension<Claims>>,
+    Path(research_type_str): Path<String>,
+    SafeQuery(stats_request): SafeQuery<CacheTypeStatsRequest>,
+) -> Result<Json<ApiResponse<CacheTypeDetailResponse>>, ApiError> {
+    if let Some(Extension(claims)) = claims_ext.as_ref() {
+        debug!(
+            "Getting {} cache statistics for user: {}",
+            research_type_str, claims.sub
+        );
+    } else {
+        debug!(
+            "Getting {} cache statistics (auth disabled)",
+            research_type_str
+        );
+    }
+
+    let research_type =
+        research_type_str
+            .parse::<ResearchType>()
+            .map_err(|_| ApiError::NotFound {
+                resource: format!("Research type {research_type_str}"),
+            })?;
+    let window_hours = stats_request
+        .window_hours
+        .unwrap_or(DEFAULT_STATS_WINDOW_HOURS);
+
+    let storage_stats = cache_state.storage.get_cache_stats().await.map_err(|e| {
+        error!("Failed to get cache stats: {}", e);
+        ApiError::InternalError {
+            message: "Failed to retrieve cache statistics".to_string(),
+        }
+    })?;
+    let entries: Vec<CacheEntry> = list_entries(&cache_state)
+        .await?
+        .into_iter()
+        .filter(|entry| entry.research_type == research_type)
+        .collect();
+
+    let stats = storage_stats
+        .by_research_type
+        .get(&research_type)
+        .map(type_stats_to_response)
+        .unwrap_or(CacheTypeStatsResponse {
+            entries: 0,
+            size_bytes: 0,
+            hit_rate: 0.0,
+            hits: 0,
+            misses: 0,
+            average_quality: 0.0,
+        });
+
+    let now = Utc::now();
+    let expired_entries = entries.iter().filter(|e| e.expires_at <= now).count();
+    let average_age_seconds = if entries.is_empty() {
+        0.0
+    } else {
+        entries
+            .iter()
+            .map(|e| (now - e.created_at).num_seconds().max(0) as f64)
+            .sum::<f64>()
+            / entries.len() as f64
+    };
+
+    let response = CacheTypeDetailResponse {
+        research_type: research_type.to_string(),
+        stats,
+        expired_entries,
+        average_age_seconds,
+        recent_operations: summarize_recent_operations(&entries, window_hours, now),
+    };
+
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
+
 /// GET /api/v1/cache/search - Search cached content with filters
 #[utoipa::path(
     get,
@@ -203,6 +357,7 @@ pub async fn search_cache(
     let start_time = Instant::now();
 
     // Validation is already handled by SafeQuery extractor
+    let expression = SearchExpression::parse(search_request.query.as_deref().unwrap_or_default())?;
 
     // Build search query for storage
     let mut search_query = SearchQuery::new(search_request.query.clone().unwrap_or_default());
@@ -251,7 +406,7 @@ pub async fn search_cache(
         })?;
 
     // Apply additional filters that aren't supported by the storage search
-    let filtered_entries = filter_cache_entries(&all_entries, &search_request);
+    let filtered_entries = filter_cache_entries(&all_entries, &search_request, &expression);
 
     // Convert to response format
     let results: Vec<CacheItemResponse> = filtered_entries
@@ -424,6 +579,31 @@ pub async fn delete_cache_item(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// DELETE /api/v1/cache/entries/{key} - Evict a single cache entry
+#[utoipa::path(
+    delete,
+    path = "/api/v1/cache/entries/{key}",
+    params(
+        ("key" = String, Path, description = "Cache key to evict")
+    ),
+    responses(
+        (status = 204, description = "Cache entry evicted (or was already absent)"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "Cache",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn delete_cache_entry(
+    state: State<CacheState>,
+    claims: Extension<Claims>,
+    cache_key: Path<String>,
+) -> Result<StatusCode, ApiError> {
+    delete_cache_item(state, claims, cache_key).await
+}
+
 /// POST /api/v1/cache/invalidate - Invalidate cache entries (bulk operation)
 #[utoipa::path(
     post,
@@ -458,60 +638,59 @@ pub async fn invalidate_cache(
             message: format!("Invalid invalidation request: {validation_errors:?}"),
         });
     }
+    parse_research_type_filter(invalidate_request.research_type.as_deref())?;
 
-    let mut invalidated_keys = Vec::new();
-    let mut bytes_freed = 0u64;
-
-    // Handle specific keys
-    if let Some(keys) = &invalidate_request.keys {
-        for key in keys {
-            if !invalidate_request.dry_run.unwrap_or(false) {
-                if let Err(e) = cache_state.storage.delete(key).await {
-                    warn!("Failed to delete cache key {}: {}", key, e);
-                } else {
-                    invalidated_keys.push(key.clone());
-                    // Note: In a real implementation, we'd track the actual bytes freed
-                    bytes_freed += 1024; // Placeholder
-                }
-            } else {
-                invalidated_keys.push(key.clone());
+    let dry_run = invalidate_request.dry_run.unwrap_or(false);
+    let all_entries = cache_state
+        .storage
+        .list_cache_entries()
+        .await
+        .map_err(|e| {
+            error!("Failed to list cache entries for invalidation: {}", e);
+            ApiError::InternalError {
+                message: "Failed to list cache entries".to_string(),
             }
-        }
-    }
+        })?;
+    let sizes: HashMap<&str, u64> = all_entries
+        .iter()
+        .map(|entry| (entry.key.as_str(), entry.size_bytes))
+        .collect();
 
-    // Handle pattern-based invalidation
+    // Specific keys first, then every indexed entry matching the filters
+    let mut targets: Vec<&str> = invalidate_request
+        .keys
+        .iter()
+        .flatten()
+        .map(String::as_str)
+        .collect();
     if invalidate_request.pattern.is_some()
         || invalidate_request.research_type.is_some()
         || invalidate_request.tags.is_some()
         || invalidate_request.max_age_seconds.is_some()
         || invalidate_request.min_quality.is_some()
     {
-        let all_entries = cache_state
-            .storage
-            .list_cache_entries()
-            .await
-            .map_err(|e| {
-                error!("Failed to list cache entries for bulk invalidation: {}", e);
-                ApiError::InternalError {
-                    message: "Failed to list cache entries".to_string(),
-                }
-            })?;
+        targets.extend(
+            all_entries
+                .iter()
+                .filter(|entry| should_invalidate_entry(entry, &invalidate_request))
+                .map(|entry| entry.key.as_str()),
+        );
+    }
 
-        for entry in all_entries {
-            if should_invalidate_entry(&entry, &invalidate_request) {
-                if !invalidate_request.dry_run.unwrap_or(false) {
-                    if let Err(e) = cache_state.storage.delete(&entry.key).await {
-                        warn!("Failed to delete cache key {}: {}", entry.key, e);
-                    } else {
-                        invalidated_keys.push(entry.key.clone());
-                        bytes_freed += entry.size_bytes;
-                    }
-                } else {
-                    invalidated_keys.push(entry.key.clone());
-                    bytes_freed += entry.size_bytes;
-                }
+    let mut invalidated_keys = Vec::new();
+    let mut bytes_freed = 0u64;
+    for key in targets {
+        if invalidated_keys.iter().any(|k| k == key) {
+            continue;
+        }
+        if !dry_run {
+            if let Err(e) = cache_state.storage.delete(key).await {
+                warn!("Failed to delete cache key {}: {}", key, e);
+                continue;
             }
         }
+        invalidated_keys.push(key.to_string());
+        bytes_freed += sizes.get(key).copied().unwrap_or(0);
     }
 
     let criteria = CacheInvalidationCriteria {
@@ -528,7 +707,7 @@ pub async fn invalidate_cache(
         invalidated_count: invalidated_keys.len(),
         invalidated_keys,
         bytes_freed,
-        dry_run: invalidate_request.dry_run.unwrap_or(false),
+        dry_run,
         criteria,
         processing_time_ms: start_time.elapsed().as_millis() as u64,
     };
@@ -638,6 +817,175 @@ pub async fn cleanup_cache(
 
 // Helper functions
 
+/// Default recent-operations window for cache statistics
+const DEFAULT_STATS_WINDOW_HOURS: u32 = 24;
+
+/// Number of entries reported in `top_accessed`
+const TOP_ACCESSED_LIMIT: usize = 5;
+
+/// Filtered, sorted and paginated per-type statistics
+struct TypeBreakdown {
+    rows: Vec<(String, CacheTypeStatsResponse)>,
+    pagination: PaginationInfo,
+}
+
+async fn list_entries(cache_state: &CacheState) -> Result<Vec<CacheEntry>, ApiError> {
+    cache_state.storage.list_cache_entries().await.map_err(|e| {
+        error!("Failed to list cache entries: {}", e);
+        ApiError::InternalError {
+            message: "Failed to list cache entries".to_string(),
+        }
+    })
+}
+
+fn type_stats_to_response(type_stats: &fortitude_types::CacheTypeStats) -> CacheTypeStatsResponse {
+    CacheTypeStatsResponse {
+        entries: type_stats.entries,
+        size_bytes: type_stats.size_bytes,
+        hit_rate: type_stats.hit_rate,
+        hits: type_stats.hits,
+        misses: type_stats.misses,
+        average_quality: 0.85, // Placeholder - would calculate from actual data
+    }
+}
+
+fn build_type_breakdown(
+    storage_stats: &fortitude_types::CacheStats,
+    request: &CacheStatsRequest,
+) -> Result<TypeBreakdown, ApiError> {
+    let type_filter = parse_research_type_filter(request.research_types.as_deref())?;
+
+    let mut rows: Vec<(String, CacheTypeStatsResponse)> = storage_stats
+        .by_research_type
+        .iter()
+        .filter(|(research_type, _)| {
+            type_filter
+                .as_ref()
+                .is_none_or(|types| types.contains(research_type))
+        })
+        .map(|(research_type, type_stats)| {
+            (
+                research_type.to_string(),
+                type_stats_to_response(type_stats),
+            )
+        })
+        .collect();
+    sort_type_rows(&mut rows, request.sort.as_deref())?;
+
+    let total = rows.len();
+    let offset = request.offset.unwrap_or(0);
+    let limit = request.limit.unwrap_or(total.max(1));
+
+    Ok(TypeBreakdown {
+        rows: rows.into_iter().skip(offset).take(limit).collect(),
+        pagination: PaginationInfo {
+            offset,
+            limit,
+            total_pages: total.div_ceil(limit),
+            has_more: offset + limit < total,
+        },
+    })
+}
+
+fn parse_research_type_filter(filter: Option<&str>) -> Result<Option<Vec<ResearchType>>, ApiError> {
+    let Some(filter) = filter.filter(|f| !f.trim().is_empty()) else {
+        return Ok(None);
+    };
+
+    filter
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<ResearchType>()
+                .map_err(|_| ApiError::ValidationError {
+                    message: format!("Unknown research type '{s}'"),
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Some)
+}
+
+fn sort_type_rows(
+    rows: &mut [(String, CacheTypeStatsResponse)],
+    sort: Option<&str>,
+) -> Result<(), ApiError> {
+    match sort.unwrap_or("entries") {
+        "entries" => rows.sort_by(|a, b| b.1.entries.cmp(&a.1.entries).then(a.0.cmp(&b.0))),
+        "size" => rows.sort_by(|a, b| b.1.size_bytes.cmp(&a.1.size_bytes).then(a.0.cmp(&b.0))),
+        "hit_rate" => {
+            rows.sort_by(|a, b| b.1.hit_rate.total_cmp(&a.1.hit_rate).then(a.0.cmp(&b.0)))
+        }
+        "hits" => rows.sort_by(|a, b| b.1.hits.cmp(&a.1.hits).then(a.0.cmp(&b.0))),
+        "name" => rows.sort_by(|a, b| a.0.cmp(&b.0)),
+        other => {
+            return Err(ApiError::ValidationError {
+                message: format!(
+                    "Unsupported sort '{other}', expected entries, size, hit_rate, hits or name"
+                ),
+            })
+        }
+    }
+    Ok(())
+}
+
+fn summarize_recent_operations(
+    entries: &[CacheEntry],
+    window_hours: u32,
+    now: DateTime<Utc>,
+) -> RecentOperationsResponse {
+    let accessed_since = |hours: i64| {
+        let cutoff = now - chrono::Duration::hours(hours);
+        entries.iter().filter(move |e| e.last_accessed >= cutoff)
+    };
+
+    let mut in_window: Vec<&CacheEntry> = accessed_since(window_hours as i64).collect();
+    in_window.sort_by_key(|e| std::cmp::Reverse(e.last_accessed));
+
+    let mut by_hour = [0u64; 24];
+    for entry in &in_window {
+        by_hour[entry.last_accessed.hour() as usize] += 1;
+    }
+    let peak_hour = by_hour
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count > 0)
+        .max_by(|(ha, a), (hb, b)| a.cmp(b).then(hb.cmp(ha)))
+        .map(|(hour, _)| format!("{:02}:00-{:02}:00", hour, (hour + 1) % 24))
+        .unwrap_or_else(|| "N/A".to_string());
+
+    RecentOperationsResponse {
+        last_hour: accessed_since(1).count() as u64,
+        last_day: accessed_since(24).count() as u64,
+        peak_hour,
+        top_accessed: in_window
+            .iter()
+            .take(TOP_ACCESSED_LIMIT)
+            .map(|e| e.key.clone())
+            .collect(),
+        window_hours,
+        in_window: in_window.len() as u64,
+    }
+}
+
+fn type_stats_csv(rows: &[(String, CacheTypeStatsResponse)]) -> String {
+    let mut csv =
+        String::from("research_type,entries,size_bytes,hit_rate,hits,misses,average_quality\n");
+    for (research_type, stats) in rows {
+        csv.push_str(&format!(
+            "{},{},{},{:.4},{},{},{:.4}\n",
+            research_type,
+            stats.entries,
+            stats.size_bytes,
+            stats.hit_rate,
+            stats.hits,
+            stats.misses,
+            stats.average_quality
+        ));
+    }
+    csv
+}
+
 fn calculate_utilization_percent(stats: &fortitude_types::CacheStats) -> f64 {
     if stats.total_entries == 0 {
         0.0
@@ -646,10 +994,18 @@ fn calculate_utilization_percent(stats: &fortitude_types::CacheStats) -> f64 {
     }
 }
 
-fn filter_cache_entries(entries: &[CacheEntry], request: &CacheSearchRequest) -> Vec<CacheEntry> {
+fn filter_cache_entries(
+    entries: &[CacheEntry],
+    request: &CacheSearchRequest,
+    expression: &SearchExpression,
+) -> Vec<CacheEntry> {
     let mut filtered = Vec::new();
 
     for entry in entries {
+        if !expression.matches(entry) {
+            continue;
+        }
+
         // Apply age filters
         if let Some(min_age) = request.min_age_seconds {
             if entry.age_seconds() < min_age {
@@ -770,10 +1126,12 @@ fn build_filters_applied(request: &CacheSearchRequest) -> Vec<String> {
 }
 
 fn should_invalidate_entry(entry: &CacheEntry, request: &CacheInvalidateRequest) -> bool {
-    // Check research type filter
-    if let Some(research_type_str) = &request.research_type {
-        if entry.research_type.to_string() != *research_type_str {
-            return false;
+    // Check research type filter (a comma-separated list of types)
+    if request.research_type.is_some() {
+        match parse_research_type_filter(request.research_type.as_deref()) {
+            Ok(Some(types)) if !types.contains(&entry.research_type) => return false,
+            Err(_) => return false,
+            _ => {}
         }
     }
 
@@ -784,14 +1142,14 @@ fn should_invalidate_entry(entry: &CacheEntry, request: &CacheInvalidateRequest)
         }
     }
 
-    // Check pattern matching (simplified - would use proper glob matching)
+    // Check pattern matching
     if let Some(pattern) = &request.pattern {
-        if !entry.key.contains(pattern) {
+        if !matches_key_pattern(&entry.key, pattern) {
             return false;
         }
     }
 
-    // Check tags filter (placeholder - would check actual tags)
+    // Check tags filter against the request tags recorded at store time
     if let Some(tags) = &request.tags {
         let entry_tags = entry
             .metadata
@@ -811,6 +1169,26 @@ fn should_invalidate_entry(entry: &CacheEntry, request: &CacheInvalidateRequest)
     true
 }
 
+/// Match a cache key against a `*`/`?` glob, or as a substring when the pattern has no wildcards
+fn matches_key_pattern(key: &str, pattern: &str) -> bool {
+    if !pattern.contains(['*', '?']) {
+        return key.contains(pattern);
+    }
+
+    fn glob(key: &[char], pattern: &[char]) -> bool {
+        match pattern.split_first() {
+            None => key.is_empty(),
+            Some(('*', rest)) => (0..=key.len()).any(|skip| glob(&key[skip..], rest)),
+            Some(('?', rest)) => !key.is_empty() && glob(&key[1..], rest),
+            Some((c, rest)) => key.first() == Some(c) && glob(&key[1..], rest),
+        }
+    }
+
+    let key: Vec<char> = key.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    glob(&key, &pattern)
+}
+
 fn generate_content_summary(query: &str) -> String {
     // Simple summary generation - in a real implementation, this would
     // extract content from the actual research result
@@ -896,14 +1274,141 @@ mod tests {
         let (cache_state, _temp_dir) = create_test_cache_state().await;
         let claims = create_test_claims();
 
-        let result =
-            get_cache_stats(axum::extract::State(cache_state), Some(Extension(claims))).await;
+        let result = get_cache_stats(
+            axum::extract::State(cache_state),
+            Some(Extension(claims)),
+            SafeQuery(CacheStatsRequest::default()),
+        )
+        .await;
 
         assert!(result.is_ok());
         let _response = result.unwrap();
         // total_entries is unsigned, so it's always >= 0
     }
 
+    #[tokio::test]
+    async fn test_export_cache_stats_csv() {
+        let (cache_state, _temp_dir) = create_test_cache_state().await;
+
+        let response = export_cache_stats(
+            axum::extract::State(cache_state),
+            None,
+            SafeQuery(CacheStatsRequest::default()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/csv; charset=utf-8"
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(String::from_utf8(body.to_vec())
+            .unwrap()
+            .starts_with("research_type,entries,size_bytes"));
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_stats_rejects_unknown_sort() {
+        let (cache_state, _temp_dir) = create_test_cache_state().await;
+
+        let request = CacheStatsRequest {
+            sort: Some("popularity".to_string()),
+            ..Default::default()
+        };
+        let result =
+            get_cache_stats(axum::extract::State(cache_state), None, SafeQuery(request)).await;
+
+        assert!(matches!(result, Err(ApiError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_type_stats_unknown_type() {
+        let (cache_state, _temp_dir) = create_test_cache_state().await;
+
+        let result = get_cache_type_stats(
+            axum::extract::State(cache_state),
+            None,
+            Path("astrology".to_string()),
+            SafeQuery(CacheTypeStatsRequest::default()),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::NotFound { .. })));
+    }
+
+    #[test]
+    fn test_sort_type_rows() {
+        let stats = |entries: usize, size_bytes: u64| CacheTypeStatsResponse {
+            entries,
+            size_bytes,
+            hit_rate: 0.5,
+            hits: 1,
+            misses: 1,
+            average_quality: 0.85,
+        };
+        let mut rows = vec![
+            ("Decision".to_string(), stats(2, 4096)),
+            ("Learning".to_string(), stats(5, 1024)),
+            ("Validation".to_string(), stats(1, 8192)),
+        ];
+
+        sort_type_rows(&mut rows, None).unwrap();
+        assert_eq!(rows[0].0, "Learning");
+
+        sort_type_rows(&mut rows, Some("size")).unwrap();
+        assert_eq!(rows[0].0, "Validation");
+
+        sort_type_rows(&mut rows, Some("name")).unwrap();
+        assert_eq!(rows[0].0, "Decision");
+    }
+
+    #[test]
+    fn test_parse_research_type_filter() {
+        assert!(parse_research_type_filter(None).unwrap().is_none());
+
+        let types = parse_research_type_filter(Some("decision, Learning"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(types, vec![ResearchType::Decision, ResearchType::Learning]);
+
+        assert!(parse_research_type_filter(Some("decision,bogus")).is_err());
+    }
+
+    #[test]
+    fn test_summarize_recent_operations_window() {
+        let now = Utc::now();
+        let mut recent = CacheEntry::new(
+            "recent".to_string(),
+            PathBuf::from("recent.json"),
+            ResearchType::Learning,
+            "recent query".to_string(),
+            512,
+            "hash-recent".to_string(),
+            3600,
+        );
+        recent.last_accessed = now - chrono::Duration::minutes(10);
+        let mut older = recent.clone();
+        older.key = "older".to_string();
+        older.last_accessed = now - chrono::Duration::hours(30);
+
+        let entries = vec![older, recent];
+
+        let day = summarize_recent_operations(&entries, 24, now);
+        assert_eq!(day.last_hour, 1);
+        assert_eq!(day.in_window, 1);
+        assert_eq!(day.top_accessed, vec!["recent".to_string()]);
+        assert_ne!(day.peak_hour, "N/A");
+
+        let week = summarize_recent_operations(&entries, 168, now);
+        assert_eq!(week.window_hours, 168);
+        assert_eq!(week.in_window, 2);
+        assert_eq!(week.top_accessed[0], "recent");
+    }
+
     #[tokio::test]
     async fn test_search_cache_empty() {
         let (cache_state, _temp_dir) = create_test_cache_state().await;
@@ -1056,7 +1561,22 @@ mod tests {
             sort: Some("newest".to_string()),
         };
 
-        let filtered = filter_cache_entries(&entries, &request);
+        let filtered =
+            filter_cache_entries(&entries, &request, &SearchExpression::parse("").unwrap());
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].key, "key1");
+
+        let request = CacheSearchRequest {
+            keywords: None,
+            min_size_bytes: None,
+            max_size_bytes: None,
+            ..request
+        };
+        let expression = SearchExpression::parse("type:learning OR (rust -python)").unwrap();
+        let filtered = filter_cache_entries(&entries, &request, &expression);
+        assert_eq!(filtered.len(), 2);
+        let expression = SearchExpression::parse("async AND NOT type:learning").unwrap();
+        let filtered = filter_cache_entries(&entries, &request, &expression);
         assert_eq!(filtered.len(), 1);
         assert_eq!(filtered[0].key, "key1");
     }
@@ -1098,6 +1618,77 @@ mod tests {
         assert!(!should_invalidate_entry(&entry, &request_no_match));
     }
 
+    #[test]
+    fn test_invalidation_filters() {
+        let mut entry = CacheEntry::new(
+            "impl_async_traits".to_string(),
+            PathBuf::from("test.json"),
+            ResearchType::Implementation,
+            "async traits".to_string(),
+            1024,
+            "hash".to_string(),
+            3600,
+        );
+        entry
+            .metadata
+            .insert("tags".to_string(), "rust,async".to_string());
+
+        let request = |research_type: Option<&str>, pattern: Option<&str>, tags: Option<&str>| {
+            CacheInvalidateRequest {
+                keys: None,
+                pattern: pattern.map(String::from),
+                research_type: research_type.map(String::from),
+                tags: tags.map(|t| vec![t.to_string()]),
+                max_age_seconds: None,
+                min_quality: None,
+                dry_run: None,
+            }
+        };
+
+        assert!(should_invalidate_entry(
+            &entry,
+            &request(Some("learning,implementation"), None, None)
+        ));
+        assert!(!should_invalidate_entry(
+            &entry,
+            &request(Some("decision"), None, None)
+        ));
+        assert!(should_invalidate_entry(
+            &entry,
+            &request(None, Some("impl_*_traits"), Some("async"))
+        ));
+        assert!(!should_invalidate_entry(
+            &entry,
+            &request(None, Some("impl_?"), None)
+        ));
+        assert!(!should_invalidate_entry(
+            &entry,
+            &request(None, None, Some("python"))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_cache_rejects_unknown_research_type() {
+        let (cache_state, _temp_dir) = create_test_cache_state().await;
+
+        let result = invalidate_cache(
+            axum::extract::State(cache_state),
+            Extension(create_test_claims()),
+            Json(CacheInvalidateRequest {
+                keys: None,
+                pattern: None,
+                research_type: Some("gossip".to_string()),
+                tags: None,
+                max_age_seconds: None,
+                min_quality: None,
+                dry_run: Some(true),
+            }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::ValidationError { .. })));
+    }
+
     #[test]
     fn test_cache_entry_to_response() {
         let entry = CacheEntry::new(
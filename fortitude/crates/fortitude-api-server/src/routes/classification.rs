@@ -22,7 +22,8 @@ use crate::models::{
     requests::{ClassificationListRequest, ClassificationOptions, ClassificationRequest},
     responses::{
         ApiResponse, AudienceLevelInfo, ClassificationCandidate as ApiClassificationCandidate,
-        ClassificationListResponse, ClassificationMetadata, ClassificationResponse,
+        ClassificationLabel as ApiClassificationLabel, ClassificationListResponse,
+        ClassificationMetadata, ClassificationResponse,
         ClassificationResult as ApiClassificationResult, ClassificationSummary,
         ClassificationSystemInfo, ClassificationTypesResponse, ContextDetectionResponse,
         DimensionConfidence, PaginationInfo, ResearchTypeInfo, TechnicalDomainInfo,
@@ -664,6 +665,14 @@ fn convert_to_api_response(
             confidence: classifier_result.confidence,
             matched_keywords: classifier_result.matched_keywords.clone(),
             rule_priority: classifier_result.rule_priority,
+            labels: classifier_result
+                .labels
+                .iter()
+                .map(|label| ApiClassificationLabel {
+                    research_type: label.research_type.to_string(),
+                    confidence: label.confidence,
+                })
+                .collect(),
             candidates: classifier_result
                 .candidates
                 .iter()
@@ -787,7 +796,11 @@ fn classify_with_custom_threshold(
         best_candidate.matched_keywords.clone(),
         best_candidate.rule_priority,
         candidates,
-    ))
+    )
+    .with_secondary_labels(&ClassificationConfig {
+        default_threshold: custom_threshold,
+        ..Default::default()
+    }))
 }
 
 /// Convert classification error to API error
@@ -0,0 +1,133 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Token-based feedback endpoint for embedded research results
+// Accepts feedback carrying a per-result token instead of an API key
+
+use crate::feedback::FeedbackTokens;
+use crate::models::errors::ApiError;
+use crate::models::requests::FeedbackTokenRequest;
+use crate::models::responses::{ApiResponse, FeedbackTokenResponse};
+use axum::{extract::State, http::StatusCode, Json};
+use tracing::{info, instrument};
+use utoipa;
+use uuid::Uuid;
+use validator::Validate;
+
+/// POST /api/v1/feedback/token - Submit feedback with a feedback token
+///
+/// The token comes from a research response requested with
+/// `include_feedback_token`. No API key is needed; submissions are rate
+/// limited per key the token was issued to and recorded in the learning
+/// store. Keys that opted into learning have their preference profile
+/// adjusted from the comment.
+#[utoipa::path(
+    post,
+    path = "/api/v1/feedback/token",
+    request_body = FeedbackTokenRequest,
+    responses(
+        (status = 201, description = "Feedback recorded", body = ApiResponse<FeedbackTokenResponse>),
+        (status = 400, description = "Invalid feedback"),
+        (status = 401, description = "Invalid or expired feedback token"),
+        (status = 429, description = "Too much feedback for the issuing key"),
+    ),
+    tag = "Feedback"
+)]
+#[instrument(skip_all)]
+pub async fn submit_token_feedback(
+    State(feedback): State<FeedbackTokens>,
+    Json(request): Json<FeedbackTokenRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<FeedbackTokenResponse>>), ApiError> {
+    request.validate().map_err(|e| ApiError::BadRequest {
+        message: format!("Request validation failed: {e}"),
+    })?;
+
+    let recorded = feedback
+        .submit(
+            &request.token,
+            request.helpful,
+            request.rating,
+            request.comment,
+        )
+        .await?;
+    info!(
+        "Recorded token feedback {} for result {} (key: {})",
+        recorded.id, recorded.result_id, recorded.key
+    );
+    feedback.preferences().learn_from_feedback(&recorded).await;
+
+    let response = FeedbackTokenResponse {
+        id: recorded.id.to_string(),
+        result_id: recorded.result_id,
+        submitted_at: recorded.submitted_at,
+    };
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(response, Uuid::new_v4())),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude::learning::FileLearningStorage;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_submit_token_feedback() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let learning_storage = Arc::new(
+            FileLearningStorage::open(temp_dir.path().join("learning_store.json"))
+                .await
+                .unwrap(),
+        );
+        let feedback = FeedbackTokens::default().with_learning_storage(learning_storage.clone());
+        let issued = feedback.issue("result-1", "portal", &[]).unwrap();
+
+        let request = FeedbackTokenRequest {
+            token: issued.token,
+            helpful: true,
+            rating: Some(4),
+            comment: None,
+        };
+        let (status, Json(response)) =
+            submit_token_feedback(State(feedback.clone()), Json(request.clone()))
+                .await
+                .unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(response.data.result_id, "result-1");
+        let stored = learning_storage.all_feedback().await;
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].id, response.data.id);
+        assert_eq!(stored[0].score, Some(0.75));
+
+        let invalid = FeedbackTokenRequest {
+            rating: Some(9),
+            ..request.clone()
+        };
+        assert!(matches!(
+            submit_token_feedback(State(feedback.clone()), Json(invalid)).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+
+        let forged = FeedbackTokenRequest {
+            token: "not-a-token".to_string(),
+            ..request
+        };
+        assert!(matches!(
+            submit_token_feedback(State(feedback), Json(forged)).await,
+            Err(ApiError::Unauthorized)
+        ));
+    }
+}
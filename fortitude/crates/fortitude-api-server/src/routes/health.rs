@@ -13,13 +13,25 @@
 // limitations under the License.
 
 // ABOUTME: Health check endpoints for API server monitoring and status verification
+// Reports 503 with shutdown progress once the server starts draining
+// Liveness and readiness probes are split so provider outages do not restart the process
 
+use crate::config::ReadinessConfig;
 use crate::middleware::auth::Claims;
-use crate::models::responses::{ComponentHealth, HealthResponse};
-use axum::{http::StatusCode, response::Json, Extension};
+use crate::models::responses::{
+    ComponentHealth, HealthResponse, ReadinessComponent, ReadinessResponse,
+};
+use crate::shutdown::{ShutdownPhase, ShutdownState};
+use axum::{extract::State, http::StatusCode, response::Json, Extension};
 use chrono::Utc;
+use fortitude_core::ResearchPipeline;
+use fortitude_types::Storage;
 use std::collections::HashMap;
-use tracing::instrument;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::{instrument, warn};
 use utoipa;
 
 /// Simple health check endpoint
@@ -28,11 +40,14 @@ use utoipa;
     path = "/health",
     responses(
         (status = 200, description = "Health check successful", body = HealthResponse),
+        (status = 503, description = "Server is shutting down", body = HealthResponse),
     ),
     tag = "Health"
 )]
-#[instrument]
-pub async fn health_check() -> (StatusCode, Json<HealthResponse>) {
+#[instrument(skip(shutdown))]
+pub async fn health_check(
+    shutdown: Option<Extension<ShutdownState>>,
+) -> (StatusCode, Json<HealthResponse>) {
     let mut components = HashMap::new();
 
     // Check basic system health
@@ -65,14 +80,40 @@ pub async fn health_check() -> (StatusCode, Json<HealthResponse>) {
         },
     );
 
+    // Tell load balancers to stop routing here once shutdown has begun
+    let mut status = "healthy".to_string();
+    let mut code = StatusCode::OK;
+    if let Some(Extension(shutdown)) = shutdown {
+        let progress = shutdown.status();
+        if progress.phase != ShutdownPhase::Running {
+            status = progress.phase.as_str().to_string();
+            code = StatusCode::SERVICE_UNAVAILABLE;
+            let since = progress
+                .requested_at
+                .map(|at| format!(" since {}", at.to_rfc3339()))
+                .unwrap_or_default();
+            components.insert(
+                "shutdown".to_string(),
+                ComponentHealth {
+                    status: progress.phase.as_str().to_string(),
+                    last_check: Utc::now(),
+                    details: Some(format!(
+                        "Shutting down{since}; {} requests in flight",
+                        progress.in_flight
+                    )),
+                },
+            );
+        }
+    }
+
     let response = HealthResponse {
-        status: "healthy".to_string(),
+        status,
         version: env!("CARGO_PKG_VERSION").to_string(),
         uptime_seconds: 0, // In production, this would track actual uptime
         components,
     };
 
-    (StatusCode::OK, Json(response))
+    (code, Json(response))
 }
 
 /// Protected health check endpoint that requires authentication
@@ -171,3 +212,278 @@ pub async fn protected_health_check(
 
     (StatusCode::OK, Json(response))
 }
+
+/// Last readiness result: when it was checked, whether ready, and components
+type ReadinessCheck = (Instant, bool, HashMap<String, ReadinessComponent>);
+
+/// State shared by the liveness and readiness probes
+#[derive(Clone)]
+pub struct ProbeState {
+    config: ReadinessConfig,
+    started_at: Instant,
+    storage: Option<Arc<dyn Storage + Send + Sync>>,
+    pipeline: Option<Arc<ResearchPipeline>>,
+    shutdown: ShutdownState,
+    last_check: Arc<Mutex<Option<ReadinessCheck>>>,
+}
+
+impl ProbeState {
+    pub fn new(config: ReadinessConfig, shutdown: ShutdownState) -> Self {
+        Self {
+            config,
+            started_at: Instant::now(),
+            storage: None,
+            pipeline: None,
+            shutdown,
+            last_check: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Check this storage for readiness
+    pub fn with_storage(mut self, storage: Arc<dyn Storage + Send + Sync>) -> Self {
+        self.storage = Some(storage);
+        self
+    }
+
+    /// Check the research engine and vector database of this pipeline
+    pub fn with_pipeline(mut self, pipeline: Arc<ResearchPipeline>) -> Self {
+        self.pipeline = Some(pipeline);
+        self
+    }
+
+    fn uptime_seconds(&self) -> u64 {
+        self.started_at.elapsed().as_secs()
+    }
+
+    /// Check every component, reusing a recent result when one is cached
+    async fn readiness(&self) -> (bool, HashMap<String, ReadinessComponent>) {
+        let mut last_check = self.last_check.lock().await;
+        let max_age = Duration::from_secs(self.config.cache_seconds);
+        if let Some((checked_at, ready, components)) = last_check.as_ref() {
+            if checked_at.elapsed() < max_age {
+                return (*ready, components.clone());
+            }
+        }
+
+        let (storage, provider, vector_db) = tokio::join!(
+            self.check_storage(),
+            self.check_provider(),
+            self.check_vector_db()
+        );
+        let components = HashMap::from([
+            ("storage".to_string(), storage),
+            ("provider".to_string(), provider),
+            ("vector_db".to_string(), vector_db),
+        ]);
+        let ready = components
+            .values()
+            .all(|component| !component.required || component.status == "healthy");
+        if !ready {
+            let failing: Vec<&str> = components
+                .iter()
+                .filter(|(_, component)| component.required && component.status != "healthy")
+                .map(|(name, _)| name.as_str())
+                .collect();
+            warn!("Not ready: {} failing", failing.join(", "));
+        }
+
+        *last_check = Some((Instant::now(), ready, components.clone()));
+        (ready, components)
+    }
+
+    async fn check_storage(&self) -> ReadinessComponent {
+        let required = self.config.require_storage;
+        let Some(storage) = &self.storage else {
+            return component(
+                "unavailable",
+                required,
+                "Result storage failed to initialize",
+            );
+        };
+        match self.within_timeout(storage.get_cache_stats()).await {
+            Some(Ok(stats)) => component(
+                "healthy",
+                required,
+                &format!("{} cached results", stats.total_entries),
+            ),
+            Some(Err(e)) => component("unhealthy", required, &e.to_string()),
+            None => component("unhealthy", required, &self.timeout_details()),
+        }
+    }
+
+    async fn check_provider(&self) -> ReadinessComponent {
+        let required = self.config.require_provider;
+        let Some(pipeline) = &self.pipeline else {
+            return component(
+                "unavailable",
+                required,
+                "Research pipeline failed to initialize",
+            );
+        };
+        match self.within_timeout(pipeline.check_research_engine()).await {
+            Some(Some(Ok(()))) => {
+                component("healthy", required, "A research provider is available")
+            }
+            Some(Some(Err(e))) => component("unhealthy", required, &e.to_string()),
+            Some(None) => component(
+                "not_configured",
+                required,
+                "No research provider configured",
+            ),
+            None => component("unhealthy", required, &self.timeout_details()),
+        }
+    }
+
+    /// Vector search is optional, so the endpoint health already tracked by
+    /// the storage is reported rather than probing the database
+    async fn check_vector_db(&self) -> ReadinessComponent {
+        let required = self.config.require_vector_db;
+        let Some(vector_storage) = self
+            .pipeline
+            .as_ref()
+            .and_then(|pipeline| pipeline.vector_storage())
+        else {
+            return component("not_configured", required, "Vector search is not enabled");
+        };
+        let endpoints = vector_storage.endpoint_health();
+        if endpoints.is_empty() {
+            return component("healthy", required, "Endpoint health is not tracked");
+        }
+        let healthy = endpoints.iter().filter(|endpoint| endpoint.healthy).count();
+        let status = if healthy > 0 { "healthy" } else { "unhealthy" };
+        component(
+            status,
+            required,
+            &format!("{healthy} of {} endpoints healthy", endpoints.len()),
+        )
+    }
+
+    async fn within_timeout<F: Future>(&self, check: F) -> Option<F::Output> {
+        tokio::time::timeout(Duration::from_millis(self.config.check_timeout_ms), check)
+            .await
+            .ok()
+    }
+
+    fn timeout_details(&self) -> String {
+        format!("No response within {}ms", self.config.check_timeout_ms)
+    }
+}
+
+fn component(status: &str, required: bool, details: &str) -> ReadinessComponent {
+    ReadinessComponent {
+        status: status.to_string(),
+        required,
+        last_check: Utc::now(),
+        details: Some(details.to_string()),
+    }
+}
+
+/// Liveness probe: the process is up and serving requests
+///
+/// Never checks dependencies, so an orchestrator does not restart the server
+/// because a provider is down.
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    responses(
+        (status = 200, description = "Server process is alive", body = ReadinessResponse),
+    ),
+    tag = "Health"
+)]
+#[instrument(skip(state))]
+pub async fn liveness_check(State(state): State<ProbeState>) -> Json<ReadinessResponse> {
+    Json(ReadinessResponse {
+        status: "alive".to_string(),
+        uptime_seconds: state.uptime_seconds(),
+        components: HashMap::new(),
+    })
+}
+
+/// Readiness probe: required components are healthy and the server is not
+/// shutting down
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    responses(
+        (status = 200, description = "Server is ready for traffic", body = ReadinessResponse),
+        (status = 503, description = "A required component is down or the server is shutting down", body = ReadinessResponse),
+    ),
+    tag = "Health"
+)]
+#[instrument(skip(state))]
+pub async fn readiness_check(
+    State(state): State<ProbeState>,
+) -> (StatusCode, Json<ReadinessResponse>) {
+    let progress = state.shutdown.status();
+    if progress.phase != ShutdownPhase::Running {
+        let response = ReadinessResponse {
+            status: progress.phase.as_str().to_string(),
+            uptime_seconds: state.uptime_seconds(),
+            components: HashMap::new(),
+        };
+        return (StatusCode::SERVICE_UNAVAILABLE, Json(response));
+    }
+
+    let (ready, components) = state.readiness().await;
+    let (status, code) = if ready {
+        ("ready", StatusCode::OK)
+    } else {
+        ("not_ready", StatusCode::SERVICE_UNAVAILABLE)
+    };
+    let response = ReadinessResponse {
+        status: status.to_string(),
+        uptime_seconds: state.uptime_seconds(),
+        components,
+    };
+    (code, Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_core::FileStorage;
+    use fortitude_types::StorageConfig;
+    use tempfile::TempDir;
+
+    async fn file_storage(dir: &TempDir) -> Arc<dyn Storage + Send + Sync> {
+        let config = StorageConfig {
+            base_path: dir.path().to_path_buf(),
+            ..StorageConfig::default()
+        };
+        Arc::new(FileStorage::new(config).await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_readiness_follows_required_components() {
+        let dir = TempDir::new().unwrap();
+        let shutdown = ShutdownState::new();
+
+        // No pipeline means no provider, which readiness requires by default
+        let state = ProbeState::new(ReadinessConfig::default(), shutdown.clone())
+            .with_storage(file_storage(&dir).await);
+        let (code, Json(response)) = readiness_check(State(state)).await;
+        assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.status, "not_ready");
+        assert_eq!(response.components["storage"].status, "healthy");
+        assert_eq!(response.components["provider"].status, "unavailable");
+        assert!(!response.components["vector_db"].required);
+
+        let config = ReadinessConfig {
+            require_provider: false,
+            ..ReadinessConfig::default()
+        };
+        let state =
+            ProbeState::new(config, shutdown.clone()).with_storage(file_storage(&dir).await);
+        let (code, Json(response)) = readiness_check(State(state.clone())).await;
+        assert_eq!(code, StatusCode::OK);
+        assert_eq!(response.status, "ready");
+
+        // Draining takes the server out of rotation while it stays alive
+        shutdown.begin_drain(0);
+        let (code, Json(response)) = readiness_check(State(state.clone())).await;
+        assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.status, "draining");
+        let Json(response) = liveness_check(State(state)).await;
+        assert_eq!(response.status, "alive");
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Knowledge graph endpoint for exploring related research results
+// Results are linked by shared tags, overlapping citations and similar questions
+
+use crate::extractors::SafeQuery;
+use crate::middleware::auth::Claims;
+use crate::models::errors::ApiError;
+use crate::models::requests::KnowledgeGraphRequest;
+use crate::models::responses::{
+    ApiResponse, KnowledgeGraphEdge, KnowledgeGraphNode, KnowledgeGraphRelation,
+    KnowledgeGraphResponse,
+};
+use crate::routes::research::{check_research_permission, ResearchState};
+use axum::{
+    extract::{Extension, State},
+    response::Json,
+};
+use fortitude_core::{GraphEdge, Neighborhood};
+use tracing::{error, info, instrument};
+use utoipa;
+use uuid::Uuid;
+
+/// Hops followed from the matching results when no depth is given
+const DEFAULT_DEPTH: usize = 2;
+
+/// GET /api/v1/knowledge/graph - Research results related to a topic
+///
+/// Results matching the query and everything within `depth` links of them,
+/// with the links between them. The graph is rebuilt when stored results
+/// have changed since it was last built.
+#[utoipa::path(
+    get,
+    path = "/api/v1/knowledge/graph",
+    params(
+        KnowledgeGraphRequest
+    ),
+    responses(
+        (status = 200, description = "Knowledge graph neighborhood", body = ApiResponse<KnowledgeGraphResponse>),
+        (status = 400, description = "Invalid request parameters"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "Knowledge",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip(state, claims))]
+pub async fn get_knowledge_graph(
+    State(state): State<ResearchState>,
+    Extension(claims): Extension<Claims>,
+    SafeQuery(request): SafeQuery<KnowledgeGraphRequest>,
+) -> Result<Json<ApiResponse<KnowledgeGraphResponse>>, ApiError> {
+    check_research_permission(&claims)?;
+
+    let graph = state
+        .knowledge_graph
+        .current(state.pipeline.storage().as_ref())
+        .await
+        .map_err(|e| {
+            error!("Failed to build the knowledge graph: {}", e);
+            ApiError::InternalError {
+                message: "Failed to build the knowledge graph".to_string(),
+            }
+        })?;
+    let neighborhood = graph.neighborhood(&request.query, request.depth.unwrap_or(DEFAULT_DEPTH));
+    info!(
+        "Knowledge graph for '{}': {} results, {} links",
+        request.query,
+        neighborhood.nodes.len(),
+        neighborhood.edges.len()
+    );
+
+    Ok(Json(ApiResponse::success(
+        convert_neighborhood(neighborhood),
+        Uuid::new_v4(),
+    )))
+}
+
+fn convert_neighborhood(neighborhood: Neighborhood) -> KnowledgeGraphResponse {
+    KnowledgeGraphResponse {
+        query: neighborhood.query,
+        depth: neighborhood.depth,
+        seeds: neighborhood.seeds,
+        nodes: neighborhood
+            .nodes
+            .into_iter()
+            .map(|n| KnowledgeGraphNode {
+                id: n.node.id,
+                query: n.node.query,
+                research_type: n.node.research_type.to_string(),
+                tags: n.node.tags,
+                quality_score: n.node.quality_score,
+                completed_at: n.node.completed_at,
+                distance: n.distance,
+            })
+            .collect(),
+        edges: neighborhood.edges.into_iter().map(convert_edge).collect(),
+    }
+}
+
+fn convert_edge(edge: GraphEdge) -> KnowledgeGraphEdge {
+    KnowledgeGraphEdge {
+        source: edge.source,
+        target: edge.target,
+        weight: edge.weight,
+        relations: edge
+            .relations
+            .into_iter()
+            .map(|r| KnowledgeGraphRelation {
+                kind: r.kind.to_string(),
+                score: r.score,
+                shared: r.shared,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use fortitude_core::{
+        BasicClassifier, FileStorage, KnowledgeGraphStore, PipelineBuilder, KNOWLEDGE_GRAPH_FILE,
+    };
+    use fortitude_types::{
+        AudienceContext, ClassificationConfig, ClassifiedRequest, DomainContext, ResearchMetadata,
+        ResearchResult, ResearchType, Storage, StorageConfig,
+    };
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn result(query: &str, tags: &[&str]) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            query.to_string(),
+            ResearchType::Learning,
+            AudienceContext::default(),
+            DomainContext {
+                tags: tags.iter().map(|t| t.to_string()).collect(),
+                ..DomainContext::default()
+            },
+            0.8,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 0,
+            sources_consulted: vec![],
+            quality_score: 0.8,
+            cache_key: String::new(),
+            tags: HashMap::new(),
+        };
+        ResearchResult::new(request, "Answer".to_string(), vec![], vec![], metadata)
+    }
+
+    #[tokio::test]
+    async fn test_knowledge_graph_returns_related_results() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = FileStorage::new(StorageConfig {
+            base_path: dir.path().to_path_buf(),
+            ..StorageConfig::default()
+        })
+        .await
+        .unwrap();
+        let ownership = storage
+            .store(&result("What is ownership in Rust?", &["rust", "memory"]))
+            .await
+            .unwrap();
+        let borrowing = storage
+            .store(&result("How does borrowing work?", &["rust", "memory"]))
+            .await
+            .unwrap();
+        storage
+            .store(&result("How do I write a Dockerfile?", &["docker"]))
+            .await
+            .unwrap();
+        let pipeline = PipelineBuilder::new().build(
+            Arc::new(BasicClassifier::new(ClassificationConfig::default())),
+            Arc::new(storage),
+        );
+        let state = ResearchState::from_pipeline(Arc::new(pipeline)).with_knowledge_graph(
+            KnowledgeGraphStore::open(dir.path().join(KNOWLEDGE_GRAPH_FILE)),
+        );
+        let claims = Extension(Claims {
+            sub: "alice".to_string(),
+            permissions: vec!["fortitude:research:read".to_string()],
+            exp: Utc::now().timestamp() + 3600,
+            iat: Utc::now().timestamp(),
+            iss: "fortitude-api-server".to_string(),
+        });
+
+        let Json(response) = get_knowledge_graph(
+            State(state),
+            claims,
+            SafeQuery(KnowledgeGraphRequest {
+                query: "ownership".to_string(),
+                depth: None,
+            }),
+        )
+        .await
+        .unwrap();
+        let graph = response.data;
+        assert_eq!(graph.seeds, [ownership.as_str()]);
+        let ids: Vec<&str> = graph.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, [ownership.as_str(), borrowing.as_str()]);
+        assert_eq!(graph.edges.len(), 1);
+        assert!(graph.edges[0]
+            .relations
+            .iter()
+            .any(|r| r.kind == "shared_tags"));
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Rate limit and quota introspection endpoint
+// Reports the calling key's tier, remaining requests and tokens, and monthly budget use
+
+use crate::middleware::auth::Claims;
+use crate::middleware::rate_limit::RateLimiter;
+use crate::models::errors::ApiError;
+use crate::models::responses::{ApiResponse, LimitsResponse, RequestLimitStatus};
+use crate::quota::QuotaTracker;
+use axum::{extract::State, response::Json, Extension};
+use chrono::Utc;
+use tracing::{debug, instrument};
+use utoipa;
+use uuid::Uuid;
+
+/// Limits endpoint state
+#[derive(Clone)]
+pub struct LimitsState {
+    /// Per-key request rate limiter; `None` when authentication is disabled
+    pub rate_limiter: Option<RateLimiter>,
+    /// Token and budget accounting shared with the research routes
+    pub quota: QuotaTracker,
+}
+
+/// GET /api/v1/limits/me - Rate limits and quota of the calling key
+///
+/// Request rate limits and token and budget quotas are all tracked per
+/// token subject.
+#[utoipa::path(
+    get,
+    path = "/api/v1/limits/me",
+    responses(
+        (status = 200, description = "Rate limit tier, remaining requests and tokens, and monthly budget use", body = ApiResponse<LimitsResponse>),
+        (status = 401, description = "Unauthorized - JWT token required"),
+    ),
+    tag = "Limits",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn get_my_limits(
+    State(state): State<LimitsState>,
+    claims_ext: Option<Extension<Claims>>,
+) -> Result<Json<ApiResponse<LimitsResponse>>, ApiError> {
+    let key = claims_ext
+        .as_ref()
+        .map(|ext| ext.0.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string());
+    debug!("Getting limits for key: {}", key);
+
+    let requests = state.rate_limiter.as_ref().map(|limiter| {
+        let status = limiter.status(&key);
+        RequestLimitStatus {
+            limit: status.limit,
+            remaining: status.remaining,
+            window_seconds: limiter.window_seconds(),
+            resets_at: Utc::now()
+                + chrono::Duration::from_std(status.reset_after).unwrap_or_default(),
+        }
+    });
+
+    let response = LimitsResponse {
+        tier: state.quota.tier_for(&key).0,
+        requests,
+        tokens: state.quota.token_status(&key),
+        budget: state.quota.budget_status(&key),
+        key,
+    };
+
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{QuotaConfig, QuotaTier, RateLimitConfig};
+
+    #[tokio::test]
+    async fn test_get_my_limits_reports_quota_usage() {
+        let mut config = QuotaConfig::default();
+        config.tiers.insert(
+            "trial".to_string(),
+            QuotaTier {
+                max_requests_per_window: Some(10),
+                max_tokens_per_window: Some(1000),
+                monthly_budget_usd: Some(5.0),
+            },
+        );
+        config
+            .key_tiers
+            .insert("alice".to_string(), "trial".to_string());
+        let quota = QuotaTracker::new(config.clone(), 60);
+        quota.record("alice", 250, 1.25);
+
+        let rate_limiter = RateLimiter::new(&RateLimitConfig::default(), config.clone());
+        rate_limiter.acquire("alice");
+        let state = LimitsState {
+            rate_limiter: Some(rate_limiter),
+            quota,
+        };
+        let claims = Claims {
+            sub: "alice".to_string(),
+            permissions: vec![],
+            exp: 0,
+            iat: 0,
+            iss: "fortitude-api-server".to_string(),
+        };
+
+        let response = get_my_limits(State(state), Some(Extension(claims)))
+            .await
+            .unwrap();
+        let limits = response.0.data;
+
+        assert_eq!(limits.key, "alice");
+        assert_eq!(limits.tier, "trial");
+        let requests = limits.requests.unwrap();
+        assert_eq!(requests.limit, 10);
+        assert_eq!(requests.remaining, 9);
+        assert_eq!(limits.tokens.used, 250);
+        assert_eq!(limits.tokens.remaining, Some(750));
+        assert_eq!(limits.budget.requests, 1);
+        assert_eq!(limits.budget.remaining_usd, Some(3.75));
+        assert!(limits.budget.resets_at > limits.tokens.resets_at);
+    }
+}
@@ -13,14 +13,21 @@
 // limitations under the License.
 
 // ABOUTME: HTTP route handlers for Fortitude API server
-// Organizes endpoint handlers by domain (research, classification, cache, health, proactive, providers)
+// Organizes endpoint handlers by domain (admin, research, classification, cache, feedback, health, knowledge, limits, preferences, proactive, providers, sessions, versions)
 
+pub mod admin;
 pub mod cache;
 pub mod classification;
+pub mod feedback;
 pub mod health;
+pub mod knowledge;
 pub mod learning;
+pub mod limits;
 pub mod monitoring;
+pub mod preferences;
 pub mod proactive;
 pub mod providers;
 pub mod quality;
 pub mod research;
+pub mod sessions;
+pub mod versions;
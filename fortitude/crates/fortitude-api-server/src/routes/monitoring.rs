@@ -17,12 +17,16 @@
 use crate::models::responses::{
     MonitoringAlertResponse, MonitoringAlertSeverity, MonitoringAlertsResponse,
     MonitoringApiMetricsResponse, MonitoringCacheMetricsResponse,
-    MonitoringComponentHealthResponse, MonitoringCurrentMetricsResponse,
+    MonitoringClassificationOutcomesResponse, MonitoringComponentHealthResponse,
+    MonitoringContentFilterOutcomesResponse, MonitoringCurrentMetricsResponse,
     MonitoringDashboardResponse, MonitoringDataPoint, MonitoringHealthResponse,
-    MonitoringHealthStatus, MonitoringHealthStatusResponse, MonitoringLearningMetricsResponse,
-    MonitoringMetricsResponse, MonitoringPerformanceSummaryResponse,
-    MonitoringProviderMetricsResponse, MonitoringQualityMetricsResponse,
-    MonitoringResourceMetricsResponse, MonitoringSystemOverviewResponse, PaginationInfo,
+    MonitoringHealthStatus, MonitoringHealthStatusResponse, MonitoringHistogramBucket,
+    MonitoringLearningMetricsResponse, MonitoringMetricsResponse,
+    MonitoringPerformanceSummaryResponse, MonitoringPipelineMetricsResponse,
+    MonitoringPriorityQueueResponse, MonitoringProviderMetricsResponse,
+    MonitoringQualityMetricsResponse, MonitoringQueueMetricsResponse,
+    MonitoringResourceMetricsResponse, MonitoringStageLatencyResponse,
+    MonitoringSystemOverviewResponse, PaginationInfo,
 };
 use crate::monitoring_types::{
     AlertManager, AlertSeverity, HealthChecker, HealthStatus, MetricsCollector,
@@ -33,6 +37,7 @@ use axum::{
     response::Json,
 };
 use chrono::Utc;
+use fortitude_core::{QueueMetricsSnapshot, ResearchQueue, StageMetrics, StageMetricsSnapshot};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -47,6 +52,10 @@ pub struct MonitoringState {
     pub health_checker: Arc<HealthChecker>,
     /// Alert manager instance
     pub alert_manager: Arc<AlertManager>,
+    /// Stage metrics of the research pipeline, when research is available
+    pub stage_metrics: Option<Arc<StageMetrics>>,
+    /// Research queue of the pipeline, when research is available
+    pub research_queue: Option<ResearchQueue>,
 }
 
 impl MonitoringState {
@@ -62,8 +71,22 @@ impl MonitoringState {
             metrics_collector,
             health_checker,
             alert_manager,
+            stage_metrics: None,
+            research_queue: None,
         })
     }
+
+    /// Report stage metrics from the research pipeline
+    pub fn with_stage_metrics(mut self, stage_metrics: Arc<StageMetrics>) -> Self {
+        self.stage_metrics = Some(stage_metrics);
+        self
+    }
+
+    /// Report occupancy of the research pipeline's queue
+    pub fn with_research_queue(mut self, research_queue: ResearchQueue) -> Self {
+        self.research_queue = Some(research_queue);
+        self
+    }
 }
 
 /// Query parameters for monitoring endpoints
@@ -453,10 +476,87 @@ async fn get_current_metrics(
         cache_metrics,
         learning_metrics,
         resource_metrics,
+        pipeline_metrics: state
+            .stage_metrics
+            .as_ref()
+            .map(|metrics| convert_stage_metrics(metrics.snapshot())),
+        queue_metrics: state
+            .research_queue
+            .as_ref()
+            .map(|queue| convert_queue_metrics(queue.snapshot())),
         timestamp: Utc::now(),
     })
 }
 
+fn convert_stage_metrics(snapshot: StageMetricsSnapshot) -> MonitoringPipelineMetricsResponse {
+    let stages = snapshot
+        .stages
+        .into_iter()
+        .map(|stage| MonitoringStageLatencyResponse {
+            stage: stage.stage,
+            count: stage.count,
+            total_ms: stage.total_ms,
+            mean_ms: stage.mean_ms,
+            max_ms: stage.max_ms,
+            latency_histogram: stage
+                .latency_histogram
+                .into_iter()
+                .map(|bucket| MonitoringHistogramBucket {
+                    le_ms: bucket.le_ms,
+                    count: bucket.count,
+                })
+                .collect(),
+        })
+        .collect();
+    let outcomes = snapshot.classification;
+    let filter = snapshot.content_filter;
+
+    MonitoringPipelineMetricsResponse {
+        stages,
+        classification: MonitoringClassificationOutcomesResponse {
+            classified: outcomes.classified,
+            failures: outcomes.failures,
+            threshold_misses: outcomes.threshold_misses,
+            fallback_used: outcomes.fallback_used,
+            by_type: outcomes.by_type,
+            context_detections: outcomes.context_detections,
+            context_detection_failures: outcomes.context_detection_failures,
+            context_fallback_used: outcomes.context_fallback_used,
+        },
+        content_filter: MonitoringContentFilterOutcomesResponse {
+            checked: filter.checked,
+            filtered: filter.filtered,
+            blocked: filter.blocked,
+            annotated: filter.annotated,
+            rewritten: filter.rewritten,
+            by_rule: filter.by_rule,
+        },
+    }
+}
+
+fn convert_queue_metrics(snapshot: QueueMetricsSnapshot) -> MonitoringQueueMetricsResponse {
+    MonitoringQueueMetricsResponse {
+        max_concurrent: snapshot.max_concurrent,
+        running: snapshot.running,
+        queued: snapshot.queued,
+        priorities: snapshot
+            .priorities
+            .into_iter()
+            .map(|priority| MonitoringPriorityQueueResponse {
+                priority: priority.priority,
+                queued: priority.queued,
+                started: priority.started,
+                completed: priority.completed,
+                promoted: priority.promoted,
+                mean_wait_ms: priority.mean_wait_ms,
+                max_wait_ms: priority.max_wait_ms,
+                mean_latency_ms: priority.mean_latency_ms,
+                max_latency_ms: priority.max_latency_ms,
+            })
+            .collect(),
+    }
+}
+
 async fn get_health_status(
     state: &MonitoringState,
 ) -> Result<MonitoringHealthStatusResponse, Box<dyn std::error::Error + Send + Sync>> {
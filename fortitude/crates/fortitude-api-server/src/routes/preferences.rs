@@ -0,0 +1,198 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Preference profile endpoints for the calling key
+// Profiles supply research defaults for audience level, output style, language and provider
+
+use crate::middleware::auth::Claims;
+use crate::models::errors::ApiError;
+use crate::models::requests::PreferencesRequest;
+use crate::models::responses::{ApiResponse, PreferencesResponse};
+use crate::preferences::{PreferenceProfile, PreferenceStore};
+use axum::{extract::State, http::StatusCode, response::Json, Extension};
+use tracing::{info, instrument};
+use utoipa;
+use uuid::Uuid;
+use validator::Validate;
+
+/// GET /api/v1/preferences - Preference profile of the calling key
+#[utoipa::path(
+    get,
+    path = "/api/v1/preferences",
+    responses(
+        (status = 200, description = "Preference profile; empty when none was set", body = ApiResponse<PreferencesResponse>),
+        (status = 401, description = "Unauthorized - JWT token required"),
+    ),
+    tag = "Preferences",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn get_preferences(
+    State(preferences): State<PreferenceStore>,
+    claims_ext: Option<Extension<Claims>>,
+) -> Result<Json<ApiResponse<PreferencesResponse>>, ApiError> {
+    let key = preference_key(claims_ext.as_ref());
+    let response = convert_profile(&key, preferences.get(&key));
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
+
+/// PUT /api/v1/preferences - Replace the preference profile of the calling key
+///
+/// Profile values fill in whatever a research request leaves unset.
+#[utoipa::path(
+    put,
+    path = "/api/v1/preferences",
+    request_body = PreferencesRequest,
+    responses(
+        (status = 200, description = "Preference profile saved", body = ApiResponse<PreferencesResponse>),
+        (status = 400, description = "Invalid preferences"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+    ),
+    tag = "Preferences",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn update_preferences(
+    State(preferences): State<PreferenceStore>,
+    claims_ext: Option<Extension<Claims>>,
+    Json(request): Json<PreferencesRequest>,
+) -> Result<Json<ApiResponse<PreferencesResponse>>, ApiError> {
+    request.validate().map_err(|e| ApiError::BadRequest {
+        message: format!("Request validation failed: {e}"),
+    })?;
+
+    let key = preference_key(claims_ext.as_ref());
+    let profile = PreferenceProfile {
+        audience_level: request.audience_level,
+        output_format: request.output_format,
+        output_style: request.output_style,
+        language: request.language,
+        provider: request.provider,
+        learn_from_feedback: request.learn_from_feedback.unwrap_or(false),
+        updated_at: None,
+    };
+    let saved = preferences.set(&key, profile).await;
+    info!("Updated preference profile of {}", key);
+
+    Ok(Json(ApiResponse::success(
+        convert_profile(&key, saved),
+        Uuid::new_v4(),
+    )))
+}
+
+/// DELETE /api/v1/preferences - Remove the preference profile of the calling key
+#[utoipa::path(
+    delete,
+    path = "/api/v1/preferences",
+    responses(
+        (status = 204, description = "Preference profile removed"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 404, description = "No preference profile set"),
+    ),
+    tag = "Preferences",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn delete_preferences(
+    State(preferences): State<PreferenceStore>,
+    claims_ext: Option<Extension<Claims>>,
+) -> Result<StatusCode, ApiError> {
+    let key = preference_key(claims_ext.as_ref());
+    if !preferences.remove(&key).await {
+        return Err(ApiError::NotFound {
+            resource: format!("Preference profile for key: {key}"),
+        });
+    }
+    info!("Removed preference profile of {}", key);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Profiles are kept per token subject, like quotas
+fn preference_key(claims_ext: Option<&Extension<Claims>>) -> String {
+    claims_ext
+        .map(|ext| ext.0.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string())
+}
+
+fn convert_profile(key: &str, profile: PreferenceProfile) -> PreferencesResponse {
+    PreferencesResponse {
+        key: key.to_string(),
+        audience_level: profile.audience_level,
+        output_format: profile.output_format,
+        output_style: profile.output_style,
+        language: profile.language,
+        provider: profile.provider,
+        learn_from_feedback: profile.learn_from_feedback,
+        updated_at: profile.updated_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_preferences_round_trip_per_key() {
+        let store = PreferenceStore::default();
+        let claims = Claims {
+            sub: "alice".to_string(),
+            permissions: vec![],
+            exp: 0,
+            iat: 0,
+            iss: "fortitude-api-server".to_string(),
+        };
+
+        let empty = get_preferences(State(store.clone()), Some(Extension(claims.clone())))
+            .await
+            .unwrap();
+        assert_eq!(empty.0.data.key, "alice");
+        assert!(empty.0.data.updated_at.is_none());
+
+        let request = PreferencesRequest {
+            audience_level: Some("beginner".to_string()),
+            output_style: Some("concise".to_string()),
+            learn_from_feedback: Some(true),
+            ..Default::default()
+        };
+        let saved = update_preferences(
+            State(store.clone()),
+            Some(Extension(claims.clone())),
+            Json(request),
+        )
+        .await
+        .unwrap();
+        assert_eq!(saved.0.data.audience_level.as_deref(), Some("beginner"));
+        assert!(saved.0.data.learn_from_feedback);
+        assert_eq!(store.get("alice").output_style.as_deref(), Some("concise"));
+        assert_eq!(store.get("anonymous"), PreferenceProfile::default());
+
+        let invalid = PreferencesRequest {
+            audience_level: Some(String::new()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            update_preferences(State(store.clone()), None, Json(invalid)).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+
+        let status = delete_preferences(State(store.clone()), Some(Extension(claims.clone())))
+            .await
+            .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert!(matches!(
+            delete_preferences(State(store), Some(Extension(claims))).await,
+            Err(ApiError::NotFound { .. })
+        ));
+    }
+}
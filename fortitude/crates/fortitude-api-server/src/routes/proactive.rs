@@ -184,7 +184,7 @@ impl ProactiveState {
         (status = 500, description = "Failed to start proactive research")
     ),
     security(
-        ("bearer_auth" = ["ResearchWrite"])
+        ("jwt_auth" = ["ResearchWrite"])
     )
 )]
 #[instrument(skip(state, claims), fields(user_id = %claims.sub))]
@@ -288,7 +288,7 @@ pub async fn start_proactive_research(
         (status = 500, description = "Failed to stop proactive research")
     ),
     security(
-        ("bearer_auth" = ["ResearchWrite"])
+        ("jwt_auth" = ["ResearchWrite"])
     )
 )]
 #[instrument(skip(state, claims), fields(user_id = %claims.sub))]
@@ -365,7 +365,7 @@ pub async fn stop_proactive_research(
         (status = 500, description = "Failed to get status")
     ),
     security(
-        ("bearer_auth" = ["ResourcesRead"])
+        ("jwt_auth" = ["ResourcesRead"])
     )
 )]
 #[instrument(skip(state, claims), fields(user_id = %claims.sub))]
@@ -454,7 +454,7 @@ pub async fn get_proactive_status(
         (status = 500, description = "Failed to get configuration")
     ),
     security(
-        ("bearer_auth" = ["ResourcesRead"])
+        ("jwt_auth" = ["ResourcesRead"])
     )
 )]
 #[instrument(skip(state, claims), fields(user_id = %claims.sub))]
@@ -516,7 +516,7 @@ pub async fn get_proactive_config(
         (status = 500, description = "Failed to update configuration")
     ),
     security(
-        ("bearer_auth" = ["Admin"])
+        ("jwt_auth" = ["Admin"])
     )
 )]
 #[instrument(skip(state, claims, request), fields(user_id = %claims.sub))]
@@ -584,7 +584,7 @@ pub async fn update_proactive_config(
         (status = 500, description = "Failed to retrieve tasks")
     ),
     security(
-        ("bearer_auth" = ["ResourcesRead"])
+        ("jwt_auth" = ["ResourcesRead"])
     )
 )]
 #[instrument(skip(state, claims, query), fields(user_id = %claims.sub))]
@@ -657,7 +657,7 @@ pub async fn list_proactive_tasks(
         (status = 500, description = "Failed to retrieve notifications")
     ),
     security(
-        ("bearer_auth" = ["ResourcesRead"])
+        ("jwt_auth" = ["ResourcesRead"])
     )
 )]
 #[instrument(skip(state, claims, query), fields(user_id = %claims.sub))]
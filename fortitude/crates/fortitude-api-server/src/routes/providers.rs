@@ -22,6 +22,7 @@ use axum::{
     routing::{get, post, put},
     Router,
 };
+use fortitude_core::{CostSummary, CostTracker};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -140,6 +141,14 @@ pub struct PerformanceQuery {
     pub aggregation: Option<String>,
 }
 
+/// Query parameters for the spend report
+#[derive(Debug, Deserialize)]
+pub struct CostQuery {
+    /// Days to report, including today (default 30)
+    pub days: Option<u32>,
+    pub provider: Option<String>,
+}
+
 /// Query parameters for health checks
 #[derive(Debug, Deserialize)]
 pub struct HealthCheckQuery {
@@ -153,6 +162,8 @@ pub struct HealthCheckQuery {
 pub struct ProviderState {
     pub initialized: bool,
     pub provider_count: u32,
+    /// Provider spend ledger shared with the provider manager
+    pub cost_tracker: Option<Arc<CostTracker>>,
 }
 
 impl Default for ProviderState {
@@ -160,36 +171,45 @@ impl Default for ProviderState {
         Self {
             initialized: true,
             provider_count: 3, // Mock: OpenAI, Claude, Gemini
+            cost_tracker: None,
         }
     }
 }
 
+impl ProviderState {
+    pub fn with_cost_tracker(mut self, tracker: Arc<CostTracker>) -> Self {
+        self.cost_tracker = Some(tracker);
+        self
+    }
+}
+
 /// Create router for provider management endpoints
 pub fn create_router() -> Router<Arc<ProviderState>> {
+    create_read_router().merge(create_admin_router())
+}
+
+/// Provider listing, performance and health check endpoints
+pub fn create_read_router() -> Router<Arc<ProviderState>> {
     Router::new()
         .route("/api/v1/providers", get(list_providers))
-        .route("/api/v1/providers/:provider_id", get(get_provider))
+        .route("/api/v1/providers/{provider_id}", get(get_provider))
         .route(
-            "/api/v1/providers/:provider_id/performance",
+            "/api/v1/providers/{provider_id}/performance",
             get(get_provider_performance),
         )
         .route(
-            "/api/v1/providers/:provider_id/health",
+            "/api/v1/providers/{provider_id}/health",
             get(check_provider_health),
         )
         .route(
-            "/api/v1/providers/:provider_id/health",
+            "/api/v1/providers/{provider_id}/health",
             post(force_health_check),
         )
-        .route("/api/v1/providers/switch", post(switch_provider))
         .route(
-            "/api/v1/providers/:provider_id/config",
+            "/api/v1/providers/{provider_id}/config",
             get(get_provider_config),
         )
-        .route(
-            "/api/v1/providers/:provider_id/config",
-            put(update_provider_config),
-        )
+        .route("/api/v1/providers/costs", get(get_provider_costs))
         .route(
             "/api/v1/providers/performance/aggregate",
             get(get_aggregate_performance),
@@ -200,6 +220,16 @@ pub fn create_router() -> Router<Arc<ProviderState>> {
         )
 }
 
+/// Provider switching and configuration endpoints
+pub fn create_admin_router() -> Router<Arc<ProviderState>> {
+    Router::new()
+        .route("/api/v1/providers/switch", post(switch_provider))
+        .route(
+            "/api/v1/providers/{provider_id}/config",
+            put(update_provider_config),
+        )
+}
+
 /// List all available providers with optional filtering
 #[tracing::instrument(skip(_state))]
 async fn list_providers(
@@ -594,6 +624,25 @@ async fn get_aggregate_performance(
     Ok(Json(mock_aggregate))
 }
 
+/// Token usage, spend and budget status per provider
+#[tracing::instrument(skip(state))]
+async fn get_provider_costs(
+    State(state): State<Arc<ProviderState>>,
+    Query(params): Query<CostQuery>,
+) -> Result<Json<CostSummary>, StatusCode> {
+    let tracker = state
+        .cost_tracker
+        .as_ref()
+        .ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+
+    let mut summary = tracker.summary(params.days.unwrap_or(30).clamp(1, 366));
+    if let Some(provider) = &params.provider {
+        summary.providers.retain(|name, _| name == provider);
+        summary.total_cost_usd = summary.providers.values().map(|s| s.cost_usd).sum();
+    }
+    Ok(Json(summary))
+}
+
 /// Check health status of all providers
 #[tracing::instrument(skip(_state))]
 async fn check_all_providers_health(
@@ -770,4 +819,34 @@ mod tests {
         assert!(config_response.validation_result.valid);
         assert_eq!(config_response.updated_fields.len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_provider_costs_report_ledger() {
+        use fortitude_core::{BudgetStatus, CostBudgetConfig};
+
+        let server =
+            TestServer::new(create_router().with_state(Arc::new(ProviderState::default())))
+                .unwrap();
+        let response = server.get("/api/v1/providers/costs").await;
+        assert_eq!(response.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let tracker = Arc::new(CostTracker::new(
+            CostBudgetConfig::default().with_daily_limit(1.0),
+        ));
+        tracker.record("claude", 120, 300, 0.9).unwrap();
+        tracker.record("openai", 80, 100, 0.05).unwrap();
+        let state = ProviderState::default().with_cost_tracker(tracker);
+        let server = TestServer::new(create_router().with_state(Arc::new(state))).unwrap();
+
+        let response = server
+            .get("/api/v1/providers/costs")
+            .add_query_param("provider", "claude")
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let summary: CostSummary = response.json();
+        assert_eq!(summary.providers.len(), 1);
+        assert_eq!(summary.providers["claude"].output_tokens, 300);
+        assert!((summary.total_cost_usd - 0.9).abs() < 1e-9);
+        assert_eq!(summary.budget.status, BudgetStatus::NearLimit);
+    }
 }
@@ -15,33 +15,58 @@
 // ABOUTME: Research endpoint handlers for API server
 // Provides HTTP endpoints for research pipeline integration with full fortitude-core integration
 
+use crate::audit::{AuditLog, AuditOutcome};
 use crate::extractors::SafeQuery;
+use crate::feedback::{FeedbackTokens, FEEDBACK_TOKEN_PATH};
+use crate::inflight::InflightHandle;
 use crate::middleware::auth::{Claims, Permission};
+use crate::middleware::idempotency::IdempotencyStore;
 use crate::models::{
     errors::ApiError,
-    requests::{ResearchListRequest, ResearchRequest},
+    requests::{
+        BulkUpdateFilter, BulkUpdateMutations, BulkUpdateRequest, ClassificationCorrectionRequest,
+        ResearchEstimateRequest, ResearchImportRequest, ResearchJobQuery, ResearchListRequest,
+        ResearchRequest,
+    },
     responses::{
-        ApiResponse, Detail, Evidence, PaginationInfo, ResearchListResponse, ResearchMetadata,
-        ResearchResponse, ResearchSummary,
+        ApiResponse, BulkUpdateChange, BulkUpdateResponse, ClassificationCorrectionResponse,
+        ClassificationLabel, CuratedMetadata, Detail, Evidence, FeedbackWidget, ImportDuplicate,
+        ImportIssue, ImportedResearch, PaginationInfo, ProviderEstimate, RelatedResearch,
+        ResearchCancelResponse, ResearchEstimateResponse, ResearchImportResponse,
+        ResearchJobResponse, ResearchLineageEntry, ResearchLineageResponse, ResearchListResponse,
+        ResearchMetadata, ResearchPlanResponse, ResearchResponse, ResearchSummary,
+        SimilarResearchResponse, Warning,
     },
 };
+use crate::preferences::PreferenceStore;
+use crate::quota::{estimate_research_usage, QuotaTracker};
+use crate::research_jobs::{JobSnapshot, JobState, ResearchJobs};
+use crate::research_requests::{CancelOutcome, ResearchRequestGuard, ResearchRequests};
+use crate::routes::sessions;
+use crate::telemetry;
 use axum::{
     extract::{Extension, Path, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
 use fortitude_core::api::ClaudeConfig;
 use fortitude_core::{
-    BasicClassifier, ClaudeResearchEngine, FileStorage, PipelineBuilder, ResearchPipeline,
+    parse_documents, BasicClassifier, BulkFilter, ClassificationTrainingStore,
+    ClaudeResearchEngine, ContentFilterConfig, DuplicateCheckConfig, FileStorage, ImportFormat,
+    KnowledgeGraphStore, MetadataMutation, ObjectStoreConfig, ObjectStoreStorage, PipelineBuilder,
+    PipelineConfig, ProviderCostEstimate, RelatedResult, RequestPriority, ResearchImporter,
+    ResearchOptions, ResearchPipeline, ResultMetadataView, RetentionClass, SearchExpression,
+    SessionStore, StageObserver, TraceContext, DEFAULT_CLASSIFICATION_TRAINING_PATH,
+    DEFAULT_RESEARCH_SESSIONS_PATH, KNOWLEDGE_GRAPH_FILE,
 };
 use fortitude_types::{
     AudienceContext, CacheOperation, CacheOperationType, ClassificationConfig, ClassificationError,
     DomainContext, PipelineError, ResearchType, SearchQuery, Storage, StorageConfig, StorageError,
 };
 use std::sync::Arc;
-use std::time::Instant;
-use tracing::{debug, error, info, instrument};
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, instrument, Instrument};
 use utoipa;
 use uuid::Uuid;
 use validator::Validate;
@@ -50,18 +75,99 @@ use validator::Validate;
 #[derive(Clone)]
 pub struct ResearchState {
     pub pipeline: Arc<ResearchPipeline>,
+    /// Research still running after a client's wait timed out
+    pub jobs: ResearchJobs,
+    /// Running research by request ID, for cancellation
+    pub requests: ResearchRequests,
+    /// Per-key token and budget accounting
+    pub quota: QuotaTracker,
+    /// Record of bulk updates to stored results
+    pub audit: AuditLog,
+    /// Issues per-result feedback tokens
+    pub feedback: FeedbackTokens,
+    /// Per-key defaults for audience, output style, language and provider
+    pub preferences: PreferenceStore,
+    /// Responses replayed for research submissions repeated with an idempotency key
+    pub idempotency: IdempotencyStore,
+    /// Relations between stored results, rebuilt when they change
+    pub knowledge_graph: KnowledgeGraphStore,
 }
 
 impl ResearchState {
+    /// Create research state around an existing pipeline
+    pub fn from_pipeline(pipeline: Arc<ResearchPipeline>) -> Self {
+        Self {
+            pipeline,
+            jobs: ResearchJobs::new(),
+            requests: ResearchRequests::new(),
+            quota: QuotaTracker::default(),
+            audit: AuditLog::default(),
+            feedback: FeedbackTokens::default(),
+            preferences: PreferenceStore::default(),
+            idempotency: IdempotencyStore::default(),
+            knowledge_graph: KnowledgeGraphStore::open(
+                StorageConfig::default()
+                    .base_path
+                    .join(KNOWLEDGE_GRAPH_FILE),
+            ),
+        }
+    }
+
+    /// Share a quota tracker with the limits endpoint
+    pub fn with_quota(mut self, quota: QuotaTracker) -> Self {
+        self.quota = quota;
+        self
+    }
+
+    /// Share an audit log with the rest of the server
+    pub fn with_audit(mut self, audit: AuditLog) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// Share feedback tokens with the feedback endpoint
+    pub fn with_feedback(mut self, feedback: FeedbackTokens) -> Self {
+        self.feedback = feedback;
+        self
+    }
+
+    /// Share preference profiles with the preferences endpoint
+    pub fn with_preferences(mut self, preferences: PreferenceStore) -> Self {
+        self.preferences = preferences;
+        self
+    }
+
+    /// Replay research responses with the given idempotency store
+    pub fn with_idempotency(mut self, idempotency: IdempotencyStore) -> Self {
+        self.idempotency = idempotency;
+        self
+    }
+
+    /// Keep the knowledge graph in the given store
+    pub fn with_knowledge_graph(mut self, knowledge_graph: KnowledgeGraphStore) -> Self {
+        self.knowledge_graph = knowledge_graph;
+        self
+    }
+
     /// Create new research state with pipeline
     pub async fn new() -> Result<Self, ApiError> {
-        // Initialize storage
+        // Initialize storage; an object store keeps results across redeploys
         let storage_config = StorageConfig::default();
-        let storage = Arc::new(FileStorage::new(storage_config).await.map_err(|e| {
-            ApiError::InternalError {
-                message: format!("Failed to initialize storage: {e}"),
-            }
-        })?);
+        let storage: Arc<dyn Storage + Send + Sync> =
+            match std::env::var("FORTITUDE_API_OBJECT_STORE_URL") {
+                Ok(url) => Arc::new(
+                    ObjectStoreStorage::open(ObjectStoreConfig::new(url), storage_config)
+                        .await
+                        .map_err(|e| ApiError::InternalError {
+                            message: format!("Failed to initialize object store storage: {e}"),
+                        })?,
+                ),
+                Err(_) => Arc::new(FileStorage::new(storage_config).await.map_err(|e| {
+                    ApiError::InternalError {
+                        message: format!("Failed to initialize storage: {e}"),
+                    }
+                })?),
+            };
 
         // Initialize classifier
         let classification_config = ClassificationConfig::default();
@@ -84,10 +190,37 @@ impl ResearchState {
             None
         };
 
+        // Content filter rules are optional; a broken rules file stops startup
+        let content_filter = match std::env::var("FORTITUDE_API_CONTENT_FILTER_PATH") {
+            Ok(path) => {
+                ContentFilterConfig::from_file(&path).map_err(|e| ApiError::InternalError {
+                    message: e.to_string(),
+                })?
+            }
+            Err(_) => ContentFilterConfig::default(),
+        };
+
+        // Research beyond this many concurrent queries waits in the priority queue
+        let max_concurrent = std::env::var("FORTITUDE_API_MAX_CONCURRENT_RESEARCH")
+            .ok()
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(PipelineConfig::default().max_concurrent);
+
+        // New questions nearly identical to earlier ones are refused unless forced
+        let duplicate_check = DuplicateCheckConfig {
+            enabled: std::env::var("FORTITUDE_API_DUPLICATE_CHECK")
+                .map(|value| value.to_lowercase() == "true")
+                .unwrap_or(false),
+            ..DuplicateCheckConfig::default()
+        };
+
         // Build pipeline
         let builder = PipelineBuilder::new()
+            .with_max_concurrent(max_concurrent)
             .with_caching(true)
             .with_context_detection(true)
+            .with_content_filter(content_filter)
+            .with_duplicate_check(duplicate_check)
             .with_advanced_classification(false); // Start with basic classification
 
         let pipeline = if let Some(engine) = research_engine {
@@ -98,9 +231,177 @@ impl ResearchState {
             builder.build(classifier, storage)
         };
 
-        Ok(Self {
-            pipeline: Arc::new(pipeline),
-        })
+        // Corrections made through the API train the classifier
+        let pipeline = pipeline
+            .with_classification_training(ClassificationTrainingStore::open(
+                DEFAULT_CLASSIFICATION_TRAINING_PATH,
+            ))
+            .with_sessions(SessionStore::open(DEFAULT_RESEARCH_SESSIONS_PATH));
+
+        Ok(Self::from_pipeline(Arc::new(pipeline)))
+    }
+}
+
+/// Request ID and trace context of an incoming research request
+///
+/// The response echoes the `x-request-id` assigned to the request when it is
+/// a UUID, so clients can match responses, logs and stored results.
+fn request_trace(headers: &HeaderMap) -> (Uuid, TraceContext) {
+    let header_id = headers
+        .get("x-request-id")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| Uuid::parse_str(value).ok());
+    let request_id = header_id.unwrap_or_else(Uuid::new_v4);
+
+    let mut trace = TraceContext::new(request_id.to_string());
+    if let Some(trace_id) = telemetry::current_trace_id() {
+        trace = trace.with_trace_id(trace_id);
+    }
+    (request_id, trace)
+}
+
+/// Inputs for one research run, detached from the HTTP request
+struct ResearchRun {
+    query: String,
+    code: Option<String>,
+    parent_id: Option<String>,
+    session_id: Option<String>,
+    budget_profile: Option<String>,
+    time_budget_ms: Option<u64>,
+    priority: Option<RequestPriority>,
+    provider_preference: Option<String>,
+    model_preference: Option<String>,
+    audience_context: Option<AudienceContext>,
+    domain_context: Option<DomainContext>,
+    user: String,
+    quota: QuotaTracker,
+    inflight: Option<InflightHandle>,
+    /// Issues a feedback token with the result when requested
+    feedback: Option<FeedbackTokens>,
+    /// Permissions of the calling key, recorded in feedback tokens
+    scope: Vec<String>,
+    /// Request and trace IDs recorded in the result metadata
+    trace: TraceContext,
+    /// Registration under the request ID; research stops when it is cancelled
+    cancellation: ResearchRequestGuard,
+}
+
+impl ResearchRun {
+    /// Process the query through the pipeline and convert the result
+    async fn execute(self, pipeline: Arc<ResearchPipeline>) -> Result<ResearchResponse, ApiError> {
+        let start_time = Instant::now();
+
+        // Process through pipeline, linking follow-ups to their parent result
+        let stage_observer = self.inflight.as_ref().map(|handle| {
+            let provider = match (&self.provider_preference, pipeline.has_research_engine()) {
+                (Some(provider), true) if pipeline.config().enable_multi_provider => {
+                    provider.as_str()
+                }
+                (_, true) => pipeline.config().default_provider.as_str(),
+                (_, false) => "placeholder",
+            };
+            handle.set_provider(provider);
+            let handle = handle.clone();
+            StageObserver::new(move |stage| handle.set_stage(stage.as_str()))
+        });
+        let options = ResearchOptions {
+            parent_id: self.parent_id,
+            code: self.code,
+            project_context: None,
+            conversation: false,
+            session_id: self.session_id,
+            budget_profile: self.budget_profile,
+            stage_observer,
+            time_budget_ms: self.time_budget_ms,
+            provider_preference: self.provider_preference,
+            model_preference: self.model_preference,
+            trace: Some(self.trace),
+            deepen: None,
+            bypass_cache: false,
+            bypass_semantic_cache: false,
+            priority: self.priority,
+        };
+        // Dropping the pipeline future aborts the provider call in progress
+        let research = pipeline.process_query_with_options(
+            &self.query,
+            options,
+            self.audience_context,
+            self.domain_context,
+        );
+        let result = tokio::select! {
+            result = research => result.map_err(convert_pipeline_error)?,
+            _ = self.cancellation.cancelled() => {
+                info!(
+                    "Research request {} cancelled for user: {}",
+                    self.cancellation.request_id(),
+                    self.user
+                );
+                return Err(ApiError::ResearchCancelled {
+                    request_id: self.cancellation.request_id(),
+                });
+            }
+        };
+
+        let processing_time = start_time.elapsed();
+        if let Some(handle) = &self.inflight {
+            handle.set_stage("finalizing");
+        }
+
+        let (tokens, cost_usd) =
+            estimate_research_usage(&result, pipeline.config(), pipeline.has_research_engine());
+        self.quota.record(&self.user, tokens, cost_usd);
+
+        // Record cache operation for performance monitoring
+        let cache_operation = CacheOperation {
+            timestamp: chrono::Utc::now(),
+            operation_type: if result.metadata.cache_key.starts_with("enhanced-") {
+                CacheOperationType::Hit // Assume enhanced keys indicate cache hits
+            } else {
+                CacheOperationType::Miss // Regular processing indicates cache miss
+            },
+            cache_key: result.metadata.cache_key.clone(),
+            duration_ms: processing_time.as_millis() as u64,
+            success: true,
+            context: std::collections::HashMap::from([
+                ("endpoint".to_string(), "research".to_string()),
+                (
+                    "research_type".to_string(),
+                    result.request.research_type.to_string(),
+                ),
+                ("user".to_string(), self.user.clone()),
+            ]),
+        };
+
+        // In a production system, we'd record this operation to the storage system
+        // For now, we'll just log it for monitoring purposes
+        debug!(
+            "Cache operation: {:?} for key: {} in {}ms",
+            cache_operation.operation_type, cache_operation.cache_key, cache_operation.duration_ms
+        );
+
+        info!(
+            "Research request completed in {:.2}s for user: {} (quality: {:.2})",
+            processing_time.as_secs_f64(),
+            self.user,
+            result.metadata.quality_score
+        );
+
+        let mut response = convert_research_result(&result, processing_time.as_millis() as u64);
+        response.related_results = pipeline
+            .related_results(&result)
+            .await
+            .into_iter()
+            .map(convert_related_result)
+            .collect();
+        if let Some(feedback) = &self.feedback {
+            let issued = feedback.issue(&response.id, &self.user, &self.scope)?;
+            response.feedback = Some(FeedbackWidget {
+                token: issued.token,
+                submit_url: FEEDBACK_TOKEN_PATH.to_string(),
+                expires_at: issued.expires_at,
+            });
+        }
+        Ok(response)
     }
 }
 
@@ -111,31 +412,76 @@ impl ResearchState {
 /// - Context detection (audience, domain, urgency)
 /// - Research generation using Claude API or fallback
 /// - Result caching for future retrieval
+///
+/// Contexts, budget profile and provider the request leaves unset default to
+/// the caller's preference profile (see `/api/v1/preferences`). An explicit
+/// `provider` or `model` must be configured on the server, and is echoed in
+/// the result metadata.
+///
+/// When the server is already researching its maximum number of queries, the
+/// request waits in a queue ordered by `priority` (low, medium, high or
+/// critical; medium by default). Requests waiting long enough are promoted so
+/// low-priority work is not starved.
+///
+/// A request repeated with the same `Idempotency-Key` header gets the
+/// original response instead of running the research again; a repeat that
+/// arrives while the original runs waits for it. Keys are kept for
+/// `idempotency.ttl_seconds` and must not be reused with a different body.
+///
+/// The research runs under the request's `x-request-id` (generated when
+/// absent) and stops if the client disconnects before the response is sent or
+/// the ID is cancelled through `/api/v1/research/requests/{request_id}`.
+/// Cancelled research answers 409 with the code `RESEARCH_CANCELLED`, which
+/// clients should not retry.
+///
+/// When the duplicate check is enabled (`FORTITUDE_API_DUPLICATE_CHECK=true`),
+/// a new question nearly identical to already researched ones is refused with
+/// 409 and the earlier results as candidates; `force: true` researches it
+/// anyway. Follow-ups with `parent_id` or `session_id` are not checked.
+///
+/// With `dry_run: true` the pipeline stops before calling any provider and the
+/// execution plan is returned instead.
+///
+/// With `wait_timeout_ms` the research runs as a job: the request is held open
+/// until it finishes (201 with the result) or the timeout passes (202 with a
+/// job reference to fetch from `/api/v1/research/jobs/{job_id}`).
 #[utoipa::path(
     post,
     path = "/api/v1/research",
     request_body = ResearchRequest,
     responses(
         (status = 201, description = "Research request submitted successfully", body = ApiResponse<ResearchResponse>),
+        (status = 200, description = "Dry-run execution plan", body = ApiResponse<ResearchPlanResponse>),
+        (status = 202, description = "Still running after wait_timeout_ms; poll the job", body = ApiResponse<ResearchJobResponse>),
         (status = 400, description = "Invalid request data"),
+        (status = 409, description = "Similar research already exists and `force` was not set, or research with this x-request-id is already running or was cancelled (`RESEARCH_CANCELLED`)", body = SimilarResearchResponse),
         (status = 401, description = "Unauthorized - JWT token required"),
         (status = 403, description = "Forbidden - insufficient permissions"),
         (status = 500, description = "Internal server error"),
     ),
     tag = "Research"
 )]
-#[instrument(skip(state, claims_ext))]
+#[instrument(skip(state, claims_ext, headers))]
 pub async fn submit_research(
     State(state): State<ResearchState>,
     claims_ext: Option<Extension<Claims>>,
+    inflight: Option<Extension<InflightHandle>>,
+    headers: HeaderMap,
     Json(request): Json<ResearchRequest>,
 ) -> Result<Response, ApiError> {
     let start_time = Instant::now();
+    let (request_id, trace) = request_trace(&headers);
 
     // Validate request
     request.validate().map_err(|e| ApiError::BadRequest {
         message: format!("Request validation failed: {e}"),
     })?;
+    let priority = request
+        .priority
+        .as_deref()
+        .map(str::parse::<RequestPriority>)
+        .transpose()
+        .map_err(|message| ApiError::BadRequest { message })?;
 
     if let Some(Extension(claims)) = claims_ext.as_ref() {
         info!(
@@ -151,7 +497,364 @@ pub async fn submit_research(
         );
     }
 
-    // Convert request contexts to pipeline types
+    let user = claims_ext
+        .as_ref()
+        .map(|ext| ext.0.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string());
+    let preferences = state.preferences.get(&user);
+
+    // Convert request contexts to pipeline types, defaulting to the caller's preferences
+    let audience_context =
+        preferences.audience_context(request.audience_context.map(|ctx| AudienceContext {
+            level: ctx.level,
+            domain: ctx.domain,
+            format: ctx.format,
+        }));
+
+    let domain_context =
+        preferences.domain_context(request.domain_context.map(|ctx| DomainContext {
+            technology: ctx.technology,
+            project_type: ctx.project_type,
+            frameworks: ctx.frameworks,
+            tags: ctx.tags,
+        }));
+
+    // A stored style naming a profile the pipeline no longer has is skipped
+    let budget_profile = request.budget_profile.or_else(|| {
+        preferences.output_style.filter(|style| {
+            state
+                .pipeline
+                .config()
+                .prompt_budget
+                .profiles
+                .contains_key(style)
+        })
+    });
+
+    // An explicit provider or model must be one this server can research with
+    let selection = state
+        .pipeline
+        .resolve_provider_selection(request.provider.as_deref(), request.model.as_deref())
+        .map_err(convert_pipeline_error)?;
+
+    if request.dry_run.unwrap_or(false) {
+        let plan = state
+            .pipeline
+            .plan_query(&request.query, audience_context, domain_context)
+            .await
+            .map_err(convert_pipeline_error)?;
+
+        info!(
+            "Dry run planned in {}ms: provider={}, estimated_cost=${:.4}",
+            start_time.elapsed().as_millis(),
+            plan.provider,
+            plan.estimated_cost_usd
+        );
+
+        let response = ResearchPlanResponse {
+            query: plan.query,
+            research_type: plan.research_type.to_string(),
+            classification_confidence: plan.classification_confidence,
+            detected_audience: plan.detected_audience,
+            detected_domain: plan.detected_domain,
+            cache_key: plan.cache_key,
+            cache_hit: plan.cache_hit,
+            context_documents: plan.context_documents,
+            provider: selection
+                .as_ref()
+                .map(|selection| selection.provider.clone())
+                .unwrap_or(plan.provider),
+            template: plan.template,
+            estimated_input_tokens: plan.estimated_input_tokens,
+            estimated_output_tokens: plan.estimated_output_tokens,
+            estimated_cost_usd: plan.estimated_cost_usd,
+            provider_estimates: plan
+                .provider_estimates
+                .iter()
+                .map(convert_provider_estimate)
+                .collect(),
+            estimated_processing_time_ms: plan.estimated_processing_time_ms,
+        };
+
+        return Ok((
+            StatusCode::OK,
+            Json(ApiResponse::success(response, Uuid::new_v4())),
+        )
+            .into_response());
+    }
+
+    if let Some(parent_id) = request.parent_id.as_deref() {
+        state
+            .pipeline
+            .get_result(parent_id)
+            .await
+            .map_err(convert_pipeline_error)?
+            .ok_or_else(|| ApiError::NotFound {
+                resource: format!("Parent research result with ID: {parent_id}"),
+            })?;
+    }
+    if let Some(session_id) = request.session_id.as_deref() {
+        sessions::owned_session(&state, &user, session_id)?;
+    }
+
+    // Follow-ups build on earlier answers, so only new questions are checked
+    if request.parent_id.is_none()
+        && request.session_id.is_none()
+        && !request.force.unwrap_or(false)
+    {
+        let candidates = state.pipeline.similar_research(&request.query).await;
+        if !candidates.is_empty() {
+            info!(
+                "Refused research on '{}': {} similar result(s) exist",
+                request.query,
+                candidates.len()
+            );
+            let response = SimilarResearchResponse {
+                error_code: "SIMILAR_RESEARCH_EXISTS".to_string(),
+                message: "Similar research already exists; resubmit with force to research anyway"
+                    .to_string(),
+                candidates: candidates.into_iter().map(convert_related_result).collect(),
+                timestamp: chrono::Utc::now(),
+            };
+            return Ok((StatusCode::CONFLICT, Json(response)).into_response());
+        }
+    }
+
+    state.quota.check(&user)?;
+    let cancellation =
+        state
+            .requests
+            .register(request_id, &user)
+            .ok_or_else(|| ApiError::Conflict {
+                resource: format!("Research request {request_id} is already running"),
+            })?;
+    let inflight = inflight.map(|Extension(handle)| handle);
+    if let Some(handle) = &inflight {
+        handle.set_user(&user);
+    }
+
+    let run = ResearchRun {
+        query: request.query,
+        code: request.code,
+        parent_id: request.parent_id,
+        session_id: request.session_id,
+        budget_profile,
+        time_budget_ms: request.time_budget_ms,
+        priority,
+        provider_preference: selection
+            .as_ref()
+            .map(|selection| selection.provider.clone())
+            .or(preferences.provider),
+        model_preference: selection.and_then(|selection| selection.model),
+        audience_context,
+        domain_context,
+        user,
+        quota: state.quota.clone(),
+        inflight,
+        feedback: request
+            .include_feedback_token
+            .unwrap_or(false)
+            .then(|| state.feedback.clone()),
+        scope: claims_ext
+            .as_ref()
+            .map(|ext| ext.0.permissions.clone())
+            .unwrap_or_default(),
+        trace,
+        cancellation,
+    };
+
+    let Some(wait_timeout_ms) = request.wait_timeout_ms else {
+        let response = run.execute(state.pipeline.clone()).await?;
+        let warnings = Warning::from_tags(&response.metadata.tags);
+        return Ok((
+            StatusCode::CREATED,
+            Json(ApiResponse::success(response, request_id).with_warnings(warnings)),
+        )
+            .into_response());
+    };
+
+    // Run as a job so the result outlives this request if the wait times out,
+    // but not a client that disconnects before it learns the job ID
+    let job_id = state
+        .jobs
+        .spawn(run.execute(state.pipeline.clone()).in_current_span());
+    let disconnect = state.requests.cancel_on_drop(request_id);
+    let snapshot = state
+        .jobs
+        .wait(&job_id, Duration::from_millis(wait_timeout_ms))
+        .await;
+    disconnect.disarm();
+    let snapshot = snapshot.ok_or_else(|| ApiError::InternalError {
+        message: format!("Research job {job_id} disappeared while waiting"),
+    })?;
+
+    match snapshot.state {
+        JobState::Completed { response, .. } => {
+            let warnings = Warning::from_tags(&response.metadata.tags);
+            Ok((
+                StatusCode::CREATED,
+                Json(ApiResponse::success(*response, request_id).with_warnings(warnings)),
+            )
+                .into_response())
+        }
+        JobState::Failed { error, .. } => Err(error),
+        JobState::Running => {
+            info!(
+                "Research still running after {}ms wait, returning job {}",
+                wait_timeout_ms, job_id
+            );
+            Ok((
+                StatusCode::ACCEPTED,
+                Json(ApiResponse::success(
+                    convert_job_snapshot(snapshot),
+                    request_id,
+                )),
+            )
+                .into_response())
+        }
+    }
+}
+
+/// Get the status of a research job
+///
+/// Returns the job state, and the research result once it has completed.
+/// With `wait_timeout_ms` the request is held open until the job finishes or
+/// the timeout passes, whichever comes first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/research/jobs/{job_id}",
+    params(
+        ("job_id" = String, Path, description = "Research job ID"),
+        ResearchJobQuery
+    ),
+    responses(
+        (status = 200, description = "Research job status", body = ApiResponse<ResearchJobResponse>),
+        (status = 400, description = "Invalid wait timeout"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Research job not found or expired"),
+    ),
+    tag = "Research"
+)]
+#[instrument(skip(state, claims_ext))]
+pub async fn get_research_job(
+    State(state): State<ResearchState>,
+    claims_ext: Option<Extension<Claims>>,
+    Path(job_id): Path<String>,
+    SafeQuery(query): SafeQuery<ResearchJobQuery>,
+) -> Result<Json<ApiResponse<ResearchJobResponse>>, ApiError> {
+    if let Some(Extension(claims)) = claims_ext.as_ref() {
+        check_research_permission(claims)?;
+    }
+    query.validate().map_err(|e| ApiError::BadRequest {
+        message: format!("Request validation failed: {e}"),
+    })?;
+
+    let wait = Duration::from_millis(query.wait_timeout_ms.unwrap_or(0));
+    let snapshot = state
+        .jobs
+        .wait(&job_id, wait)
+        .await
+        .ok_or_else(|| ApiError::NotFound {
+            resource: format!("Research job with ID: {job_id}"),
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        convert_job_snapshot(snapshot),
+        Uuid::new_v4(),
+    )))
+}
+
+/// Cancel running research
+///
+/// Stops research submitted with the given `x-request-id`, including research
+/// that continues as a job after `wait_timeout_ms`. The provider call in
+/// progress is aborted and the request (or job) fails as cancelled. Only the
+/// user who submitted the research, or an admin, may cancel it.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/research/requests/{request_id}",
+    params(("request_id" = Uuid, Path, description = "Request ID the research was submitted with")),
+    responses(
+        (status = 200, description = "Cancellation requested", body = ApiResponse<ResearchCancelResponse>),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - research belongs to another user"),
+        (status = 404, description = "No running research with this request ID"),
+    ),
+    tag = "Research"
+)]
+#[instrument(skip(state, claims_ext))]
+pub async fn cancel_research_request(
+    State(state): State<ResearchState>,
+    claims_ext: Option<Extension<Claims>>,
+    Path(request_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ResearchCancelResponse>>, ApiError> {
+    let owner = match claims_ext.as_ref() {
+        Some(Extension(claims)) => {
+            check_research_permission(claims)?;
+            let is_admin = claims
+                .permissions
+                .contains(&Permission::Admin.as_str().to_string());
+            (!is_admin).then(|| claims.sub.clone())
+        }
+        None => None,
+    };
+
+    match state.requests.cancel(request_id, owner.as_deref()) {
+        CancelOutcome::Cancelled => {}
+        CancelOutcome::NotFound => {
+            return Err(ApiError::NotFound {
+                resource: format!("Running research request with ID: {request_id}"),
+            })
+        }
+        CancelOutcome::NotOwner => {
+            return Err(ApiError::Forbidden {
+                reason: format!("Research request {request_id} belongs to another user"),
+            })
+        }
+    }
+
+    let response = ResearchCancelResponse {
+        request_id,
+        cancelled: true,
+    };
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
+
+/// Estimate token usage and cost for a research request
+///
+/// Prepares the prompt exactly as a real run would (classification, context
+/// detection, template selection, context retrieval) and prices it against
+/// every candidate model in the catalog. No provider is called.
+#[utoipa::path(
+    post,
+    path = "/api/v1/research/estimate",
+    request_body = ResearchEstimateRequest,
+    responses(
+        (status = 200, description = "Cost estimate computed", body = ApiResponse<ResearchEstimateResponse>),
+        (status = 400, description = "Invalid request data"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "Research"
+)]
+#[instrument(skip(state, claims_ext))]
+pub async fn estimate_research(
+    State(state): State<ResearchState>,
+    claims_ext: Option<Extension<Claims>>,
+    Json(request): Json<ResearchEstimateRequest>,
+) -> Result<Json<ApiResponse<ResearchEstimateResponse>>, ApiError> {
+    request.validate().map_err(|e| ApiError::BadRequest {
+        message: format!("Request validation failed: {e}"),
+    })?;
+
+    if let Some(Extension(claims)) = claims_ext.as_ref() {
+        check_research_permission(claims)?;
+    }
+
+    info!("Estimating research cost for: '{}'", request.query);
+
     let audience_context = request.audience_context.map(|ctx| AudienceContext {
         level: ctx.level,
         domain: ctx.domain,
@@ -165,104 +868,296 @@ pub async fn submit_research(
         tags: ctx.tags,
     });
 
-    // Process through pipeline
-    let result = state
+    let plan = state
         .pipeline
-        .process_query(&request.query, audience_context, domain_context)
+        .plan_query(&request.query, audience_context, domain_context)
         .await
         .map_err(convert_pipeline_error)?;
 
-    let processing_time = start_time.elapsed();
+    let estimates = plan
+        .provider_estimates
+        .iter()
+        .filter(|e| {
+            request
+                .provider
+                .as_deref()
+                .is_none_or(|p| e.provider.eq_ignore_ascii_case(p))
+        })
+        .map(convert_provider_estimate)
+        .collect();
 
-    // Record cache operation for performance monitoring
-    let cache_operation = CacheOperation {
-        timestamp: chrono::Utc::now(),
-        operation_type: if result.metadata.cache_key.starts_with("enhanced-") {
-            CacheOperationType::Hit // Assume enhanced keys indicate cache hits
-        } else {
-            CacheOperationType::Miss // Regular processing indicates cache miss
-        },
-        cache_key: result.metadata.cache_key.clone(),
-        duration_ms: processing_time.as_millis() as u64,
-        success: true,
-        context: std::collections::HashMap::from([
-            ("endpoint".to_string(), "research".to_string()),
-            (
-                "research_type".to_string(),
-                result.request.research_type.to_string(),
-            ),
-            (
-                "user".to_string(),
-                claims_ext
-                    .as_ref()
-                    .map(|ext| ext.0.sub.clone())
-                    .unwrap_or_else(|| "anonymous".to_string()),
-            ),
-        ]),
+    let response = ResearchEstimateResponse {
+        query: plan.query,
+        research_type: plan.research_type.to_string(),
+        cache_hit: plan.cache_hit,
+        estimated_input_tokens: plan.estimated_input_tokens,
+        estimated_output_tokens: plan.estimated_output_tokens,
+        estimates,
     };
 
-    // In a production system, we'd record this operation to the storage system
-    // For now, we'll just log it for monitoring purposes
-    debug!(
-        "Cache operation: {:?} for key: {} in {}ms",
-        cache_operation.operation_type, cache_operation.cache_key, cache_operation.duration_ms
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
+
+/// Bulk edit tags and curation metadata of stored research results
+///
+/// Selects results by query text, research type, tag and completion date
+/// range, then adds or removes tags, overrides the quality score or sets the
+/// retention class of each. All changes are computed before any is written
+/// and a failed write rolls back the others. With `dry_run: true` the planned
+/// changes are returned and nothing is written. Executed updates are recorded
+/// in the audit log.
+#[utoipa::path(
+    post,
+    path = "/api/v1/research/bulk-update",
+    request_body = BulkUpdateRequest,
+    responses(
+        (status = 200, description = "Bulk update applied or previewed", body = ApiResponse<BulkUpdateResponse>),
+        (status = 400, description = "Invalid filter or mutations"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - read-write permission required"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "Research"
+)]
+#[instrument(skip(state, claims_ext, request))]
+pub async fn bulk_update_research(
+    State(state): State<ResearchState>,
+    claims_ext: Option<Extension<Claims>>,
+    Json(request): Json<BulkUpdateRequest>,
+) -> Result<Json<ApiResponse<BulkUpdateResponse>>, ApiError> {
+    request.validate().map_err(|e| ApiError::BadRequest {
+        message: format!("Request validation failed: {e}"),
+    })?;
+    if let Some(Extension(claims)) = claims_ext.as_ref() {
+        check_curation_permission(claims)?;
+    }
+    let actor = claims_ext
+        .as_ref()
+        .map(|ext| ext.0.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string());
+
+    let filter = build_bulk_filter(&request.filter)?;
+    let mutation = build_metadata_mutation(&request.mutations)?;
+    let dry_run = request.dry_run.unwrap_or(false);
+    info!(
+        "Bulk update requested by {} (dry_run: {}): {:?}",
+        actor, dry_run, request.filter
     );
 
-    // Convert to API response format
-    let response = ResearchResponse {
-        id: result.cache_key().to_string(),
-        query: result.original_query().to_string(),
-        research_type: result.research_type().to_string(),
-        immediate_answer: result.immediate_answer.clone(),
-        supporting_evidence: result
-            .supporting_evidence
-            .iter()
-            .map(|e| Evidence {
-                source: e.source.clone(),
-                content: e.content.clone(),
-                relevance: e.relevance,
-                evidence_type: e.evidence_type.clone(),
-            })
-            .collect(),
-        implementation_details: result
-            .implementation_details
-            .iter()
-            .map(|d| Detail {
-                category: d.category.clone(),
-                content: d.content.clone(),
-                priority: d.priority.clone(),
-                prerequisites: d.prerequisites.clone(),
+    let outcome = state
+        .pipeline
+        .bulk_update(&filter, &mutation, dry_run)
+        .await;
+    let audit_id = if dry_run {
+        None
+    } else {
+        let (audit_outcome, result) = match &outcome {
+            Ok(report) => (
+                AuditOutcome::Success,
+                serde_json::json!({
+                    "matched": report.matched,
+                    "updated": report
+                        .changes
+                        .iter()
+                        .map(|change| change.cache_key.as_str())
+                        .collect::<Vec<_>>(),
+                }),
+            ),
+            Err(e) => (
+                AuditOutcome::Failure,
+                serde_json::json!({ "error": e.to_string() }),
+            ),
+        };
+        let entry = state
+            .audit
+            .record(
+                &actor,
+                "research.bulk_update",
+                audit_outcome,
+                serde_json::json!({
+                    "filter": request.filter,
+                    "mutations": request.mutations,
+                    "result": result,
+                }),
+            )
+            .await;
+        Some(entry.id)
+    };
+    let report = outcome.map_err(convert_pipeline_error)?;
+
+    let response = BulkUpdateResponse {
+        dry_run: report.dry_run,
+        matched: report.matched,
+        updated: report.changes.len(),
+        changes: report
+            .changes
+            .into_iter()
+            .map(|change| BulkUpdateChange {
+                id: change.cache_key,
+                query: change.original_query,
+                before: convert_curated_metadata(change.before),
+                after: convert_curated_metadata(change.after),
             })
             .collect(),
-        metadata: ResearchMetadata {
-            completed_at: result.metadata.completed_at,
-            processing_time_ms: result.metadata.processing_time_ms,
-            sources_consulted: result.metadata.sources_consulted.clone(),
-            quality_score: result.metadata.quality_score,
-            tags: result.metadata.tags.clone(),
-        },
-        processing_time_ms: processing_time.as_millis() as u64,
+        audit_id,
     };
 
-    let api_response = ApiResponse::success(response, Uuid::new_v4());
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
 
+/// Import externally produced research
+///
+/// Accepts JSONL (one record or full research result per line) or a Markdown
+/// document with YAML frontmatter. Each document is validated, deduplicated
+/// against stored results by a hash of its query and answer, stored, and
+/// with `index: true` added to the vector index. Invalid documents and
+/// duplicates are reported without failing the import. Executed imports are
+/// recorded in the audit log.
+#[utoipa::path(
+    post,
+    path = "/api/v1/research/import",
+    request_body = ResearchImportRequest,
+    responses(
+        (status = 200, description = "Import applied or previewed", body = ApiResponse<ResearchImportResponse>),
+        (status = 400, description = "Invalid format or request"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - read-write permission required"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "Research"
+)]
+#[instrument(skip(state, claims_ext, request))]
+pub async fn import_research(
+    State(state): State<ResearchState>,
+    claims_ext: Option<Extension<Claims>>,
+    Json(request): Json<ResearchImportRequest>,
+) -> Result<Json<ApiResponse<ResearchImportResponse>>, ApiError> {
+    request.validate().map_err(|e| ApiError::BadRequest {
+        message: format!("Request validation failed: {e}"),
+    })?;
+    if let Some(Extension(claims)) = claims_ext.as_ref() {
+        check_curation_permission(claims)?;
+    }
+    let actor = claims_ext
+        .as_ref()
+        .map(|ext| ext.0.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string());
+
+    let format: ImportFormat =
+        request
+            .format
+            .parse()
+            .map_err(|e: fortitude_core::ImportError| ApiError::BadRequest {
+                message: e.to_string(),
+            })?;
+    let origin = request.source_name.as_deref().unwrap_or("request");
+    let documents = parse_documents(format, &request.content, origin);
+    let dry_run = request.dry_run.unwrap_or(false);
     info!(
-        "Research request completed in {:.2}s for user: {} (quality: {:.2})",
-        processing_time.as_secs_f64(),
-        claims_ext
-            .as_ref()
-            .map(|ext| ext.0.sub.as_str())
-            .unwrap_or("anonymous"),
-        result.metadata.quality_score
+        "Research import of {} documents requested by {} (dry_run: {})",
+        documents.len(),
+        actor,
+        dry_run
     );
 
-    Ok((StatusCode::CREATED, Json(api_response)).into_response())
+    let mut importer = ResearchImporter::new(state.pipeline.storage().clone());
+    if request.index.unwrap_or(false) {
+        match state.pipeline.vector_storage() {
+            Some(vector_storage) => {
+                importer = importer.with_vector_storage(vector_storage.clone());
+            }
+            None => {
+                return Err(ApiError::BadRequest {
+                    message: "Vector search is not enabled on this server".to_string(),
+                })
+            }
+        }
+    }
+
+    let outcome = importer.import(documents, dry_run).await;
+    let audit_id = if dry_run {
+        None
+    } else {
+        let (audit_outcome, result) = match &outcome {
+            Ok(report) => (
+                AuditOutcome::Success,
+                serde_json::json!({
+                    "imported": report
+                        .imported
+                        .iter()
+                        .map(|doc| doc.cache_key.as_str())
+                        .collect::<Vec<_>>(),
+                    "duplicates": report.duplicates.len(),
+                    "invalid": report.invalid.len(),
+                }),
+            ),
+            Err(e) => (
+                AuditOutcome::Failure,
+                serde_json::json!({ "error": e.to_string() }),
+            ),
+        };
+        let entry = state
+            .audit
+            .record(
+                &actor,
+                "research.import",
+                audit_outcome,
+                serde_json::json!({
+                    "format": request.format,
+                    "source": origin,
+                    "result": result,
+                }),
+            )
+            .await;
+        Some(entry.id)
+    };
+    let report = outcome.map_err(|e| ApiError::InternalError {
+        message: format!("Import failed: {e}"),
+    })?;
+
+    let convert_issue = |issue: fortitude_core::research_import::ImportIssue| ImportIssue {
+        location: issue.location,
+        reason: issue.reason,
+    };
+    let response = ResearchImportResponse {
+        dry_run: report.dry_run,
+        imported: report
+            .imported
+            .into_iter()
+            .map(|doc| ImportedResearch {
+                location: doc.location,
+                id: doc.cache_key,
+                query: doc.query,
+            })
+            .collect(),
+        duplicates: report
+            .duplicates
+            .into_iter()
+            .map(|dup| ImportDuplicate {
+                location: dup.location,
+                existing_id: dup.existing_key,
+            })
+            .collect(),
+        invalid: report.invalid.into_iter().map(convert_issue).collect(),
+        indexed: report.indexed,
+        index_failures: report
+            .index_failures
+            .into_iter()
+            .map(convert_issue)
+            .collect(),
+        audit_id,
+    };
+
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
 }
 
 /// Retrieve a specific research result by ID
 ///
 /// Returns a cached research result using the cache key as the ID.
 /// Results are stored with content addressing for efficient retrieval.
+///
+/// Responses carry an `ETag`; pollers that send it back in `If-None-Match`
+/// get 304 Not Modified until the result changes.
 #[utoipa::path(
     get,
     path = "/api/v1/research/{id}",
@@ -271,6 +1166,7 @@ pub async fn submit_research(
     ),
     responses(
         (status = 200, description = "Research result retrieved successfully", body = ApiResponse<ResearchResponse>),
+        (status = 304, description = "Unchanged since the ETag given in If-None-Match"),
         (status = 401, description = "Unauthorized - JWT token required"),
         (status = 403, description = "Forbidden - insufficient permissions"),
         (status = 404, description = "Research result not found"),
@@ -279,9 +1175,9 @@ pub async fn submit_research(
     tag = "Research",
     security(("jwt_auth" = []))
 )]
-#[instrument(skip(_state, claims))]
+#[instrument(skip(state, claims))]
 pub async fn get_research_by_id(
-    State(_state): State<ResearchState>,
+    State(state): State<ResearchState>,
     Extension(claims): Extension<Claims>,
     Path(id): Path<String>,
 ) -> Result<Response, ApiError> {
@@ -295,17 +1191,10 @@ pub async fn get_research_by_id(
     // Check permission
     check_research_permission(&claims)?;
 
-    // Create temporary storage to retrieve directly
-    // TODO: Expose storage through ResearchPipeline in a future version
-    let storage_config = StorageConfig::default();
-    let storage = FileStorage::new(storage_config)
-        .await
-        .map_err(|e| ApiError::InternalError {
-            message: format!("Failed to initialize storage: {e}"),
-        })?;
-
-    // Retrieve from cache using Storage trait
-    let result = storage
+    // Retrieve from the pipeline's storage, whichever backend it uses
+    let result = state
+        .pipeline
+        .storage()
         .retrieve(&id)
         .await
         .map_err(convert_storage_error)?
@@ -337,43 +1226,17 @@ pub async fn get_research_by_id(
         cache_operation.cache_key, cache_operation.duration_ms
     );
 
-    // Convert to API response format
-    let response = ResearchResponse {
-        id: result.cache_key().to_string(),
-        query: result.original_query().to_string(),
-        research_type: result.research_type().to_string(),
-        immediate_answer: result.immediate_answer.clone(),
-        supporting_evidence: result
-            .supporting_evidence
-            .iter()
-            .map(|e| Evidence {
-                source: e.source.clone(),
-                content: e.content.clone(),
-                relevance: e.relevance,
-                evidence_type: e.evidence_type.clone(),
-            })
-            .collect(),
-        implementation_details: result
-            .implementation_details
-            .iter()
-            .map(|d| Detail {
-                category: d.category.clone(),
-                content: d.content.clone(),
-                priority: d.priority.clone(),
-                prerequisites: d.prerequisites.clone(),
-            })
-            .collect(),
-        metadata: ResearchMetadata {
-            completed_at: result.metadata.completed_at,
-            processing_time_ms: result.metadata.processing_time_ms,
-            sources_consulted: result.metadata.sources_consulted.clone(),
-            quality_score: result.metadata.quality_score,
-            tags: result.metadata.tags.clone(),
-        },
-        processing_time_ms: processing_time.as_millis() as u64,
-    };
+    let mut response = convert_research_result(&result, processing_time.as_millis() as u64);
+    response.related_results = state
+        .pipeline
+        .related_results(&result)
+        .await
+        .into_iter()
+        .map(convert_related_result)
+        .collect();
 
-    let api_response = ApiResponse::success(response, Uuid::new_v4());
+    let api_response = ApiResponse::success(response, Uuid::new_v4())
+        .with_warnings(Warning::from_tags(&result.metadata.tags));
 
     info!(
         "Research result retrieved in {:.2}ms for user: {}",
@@ -384,6 +1247,180 @@ pub async fn get_research_by_id(
     Ok((StatusCode::OK, Json(api_response)).into_response())
 }
 
+/// Get the lineage of a research result
+///
+/// Returns the chain of follow-up questions from the root question down to
+/// the requested result. Parents that are no longer stored end the chain.
+#[utoipa::path(
+    get,
+    path = "/api/v1/research/{id}/lineage",
+    params(
+        ("id" = String, Path, description = "Research result ID (cache key)")
+    ),
+    responses(
+        (status = 200, description = "Research lineage retrieved successfully", body = ApiResponse<ResearchLineageResponse>),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Research result not found"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "Research",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip(state, claims))]
+pub async fn get_research_lineage(
+    State(state): State<ResearchState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<ResearchLineageResponse>>, ApiError> {
+    info!(
+        "Retrieving research lineage: {} for user: {}",
+        id, claims.sub
+    );
+
+    check_research_permission(&claims)?;
+
+    let chain = state
+        .pipeline
+        .research_lineage(&id)
+        .await
+        .map_err(convert_pipeline_error)?;
+    if chain.is_empty() {
+        return Err(ApiError::NotFound {
+            resource: format!("Research result with ID: {id}"),
+        });
+    }
+
+    let response = ResearchLineageResponse {
+        id,
+        depth: chain.len() - 1,
+        chain: chain
+            .iter()
+            .map(|result| ResearchLineageEntry {
+                id: result.cache_key().to_string(),
+                parent_id: result.parent_id.clone(),
+                query: result.original_query().to_string(),
+                research_type: result.research_type().to_string(),
+                completed_at: result.metadata.completed_at,
+            })
+            .collect(),
+    };
+
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
+
+/// Correct the research type of a stored result
+///
+/// Re-labels the result, moving it under its new type in storage and the
+/// cache index, and records the query with its corrected type as a training
+/// example for the classifier. Submitting the type the result already has
+/// confirms the label without rewriting the result. Corrections are recorded
+/// in the audit log.
+#[utoipa::path(
+    patch,
+    path = "/api/v1/research/{id}/classification",
+    params(
+        ("id" = String, Path, description = "Research result ID (cache key)")
+    ),
+    request_body = ClassificationCorrectionRequest,
+    responses(
+        (status = 200, description = "Classification corrected", body = ApiResponse<ClassificationCorrectionResponse>),
+        (status = 400, description = "Invalid research type"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - read-write permission required"),
+        (status = 404, description = "Research result not found"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "Research"
+)]
+#[instrument(skip(state, claims_ext, request))]
+pub async fn correct_research_classification(
+    State(state): State<ResearchState>,
+    claims_ext: Option<Extension<Claims>>,
+    Path(id): Path<String>,
+    Json(request): Json<ClassificationCorrectionRequest>,
+) -> Result<Json<ApiResponse<ClassificationCorrectionResponse>>, ApiError> {
+    request.validate().map_err(|e| ApiError::BadRequest {
+        message: format!("Request validation failed: {e}"),
+    })?;
+    if let Some(Extension(claims)) = claims_ext.as_ref() {
+        check_curation_permission(claims)?;
+    }
+    let actor = claims_ext
+        .as_ref()
+        .map(|ext| ext.0.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string());
+    let research_type =
+        request
+            .research_type
+            .parse::<ResearchType>()
+            .map_err(|e| ApiError::BadRequest {
+                message: format!("Invalid research type: {e}"),
+            })?;
+
+    // Report a missing result as such rather than as a pipeline failure
+    if state
+        .pipeline
+        .get_result(&id)
+        .await
+        .map_err(convert_pipeline_error)?
+        .is_none()
+    {
+        return Err(ApiError::NotFound {
+            resource: format!("Research result with ID: {id}"),
+        });
+    }
+
+    let outcome = state
+        .pipeline
+        .correct_classification(&id, research_type, request.reason.as_deref())
+        .await;
+    let (audit_outcome, result) = match &outcome {
+        Ok(correction) => (
+            AuditOutcome::Success,
+            serde_json::json!({
+                "previous_type": correction.previous.to_string(),
+                "changed": correction.is_change(),
+            }),
+        ),
+        Err(e) => (
+            AuditOutcome::Failure,
+            serde_json::json!({ "error": e.to_string() }),
+        ),
+    };
+    state
+        .audit
+        .record(
+            &actor,
+            "research.classification_correct",
+            audit_outcome,
+            serde_json::json!({
+                "id": id,
+                "research_type": request.research_type,
+                "reason": request.reason,
+                "result": result,
+            }),
+        )
+        .await;
+    let correction = outcome.map_err(convert_pipeline_error)?;
+    info!(
+        "Classification of {} corrected by {}: {} -> {}",
+        id, actor, correction.previous, correction.corrected
+    );
+
+    let response = ClassificationCorrectionResponse {
+        id: correction.cache_key.clone(),
+        query: correction.query.clone(),
+        previous_type: correction.previous.to_string(),
+        research_type: correction.corrected.to_string(),
+        changed: correction.is_change(),
+        reason: correction.reason,
+        corrected_at: correction.corrected_at,
+    };
+
+    Ok(Json(ApiResponse::success(response, Uuid::new_v4())))
+}
+
 /// List research results with filtering and pagination
 ///
 /// Searches through cached research results with support for:
@@ -400,6 +1437,7 @@ pub async fn get_research_by_id(
     ),
     responses(
         (status = 200, description = "Research results retrieved successfully", body = ApiResponse<ResearchListResponse>),
+        (status = 304, description = "Unchanged since the ETag given in If-None-Match"),
         (status = 400, description = "Invalid request parameters"),
         (status = 401, description = "Unauthorized - JWT token required"),
         (status = 403, description = "Forbidden - insufficient permissions"),
@@ -454,7 +1492,13 @@ pub async fn list_research_results(
             id: sr.entry.cache_key.clone(),
             query: sr.entry.original_query.clone(),
             research_type: sr.entry.research_type.to_string(),
+            research_types: std::iter::once(&sr.entry.research_type)
+                .chain(&sr.entry.secondary_types)
+                .map(ToString::to_string)
+                .collect(),
             summary: create_summary(&sr.snippet, 200),
+            relevance_score: sr.relevance_score,
+            matched_terms: sr.matched_keywords.clone(),
             quality_score: sr.entry.quality_score,
             created_at: sr.entry.indexed_at,
             tags: sr.entry.tags.clone(),
@@ -486,7 +1530,7 @@ pub async fn list_research_results(
 }
 
 /// Helper function to check research permission
-fn check_research_permission(claims: &Claims) -> Result<(), ApiError> {
+pub(crate) fn check_research_permission(claims: &Claims) -> Result<(), ApiError> {
     let required_permission = Permission::ResearchRead.as_str();
 
     // Admin has all permissions
@@ -510,9 +1554,74 @@ fn check_research_permission(claims: &Claims) -> Result<(), ApiError> {
     Ok(())
 }
 
+/// Helper function to check permission to modify stored research
+fn check_curation_permission(claims: &Claims) -> Result<(), ApiError> {
+    let allowed = [Permission::Admin, Permission::ReadWrite]
+        .iter()
+        .any(|p| claims.permissions.contains(&p.as_str().to_string()));
+    if !allowed {
+        return Err(ApiError::Forbidden {
+            reason: format!("Permission denied: {}", Permission::ReadWrite.as_str()),
+        });
+    }
+    Ok(())
+}
+
+/// Convert a bulk update filter to its pipeline representation
+fn build_bulk_filter(filter: &BulkUpdateFilter) -> Result<BulkFilter, ApiError> {
+    let research_type = filter
+        .research_type
+        .as_deref()
+        .map(|t| {
+            t.parse::<ResearchType>().map_err(|e| ApiError::BadRequest {
+                message: format!("Invalid research type: {e}"),
+            })
+        })
+        .transpose()?;
+
+    Ok(BulkFilter {
+        query: filter.query.clone(),
+        research_type,
+        tag: filter.tag.clone(),
+        completed_after: filter.from,
+        completed_before: filter.to,
+    })
+}
+
+/// Convert bulk update mutations to their pipeline representation
+fn build_metadata_mutation(mutations: &BulkUpdateMutations) -> Result<MetadataMutation, ApiError> {
+    let retention_class = mutations
+        .retention_class
+        .as_deref()
+        .map(|class| {
+            RetentionClass::parse(class).ok_or_else(|| ApiError::BadRequest {
+                message: format!(
+                    "Invalid retention class: {class} (expected ephemeral, standard, extended or permanent)"
+                ),
+            })
+        })
+        .transpose()?;
+
+    Ok(MetadataMutation {
+        add_tags: mutations.add_tags.clone(),
+        remove_tags: mutations.remove_tags.clone(),
+        quality_override: mutations.quality_override,
+        retention_class,
+    })
+}
+
+fn convert_curated_metadata(view: ResultMetadataView) -> CuratedMetadata {
+    CuratedMetadata {
+        tags: view.tags,
+        quality_score: view.quality_score,
+        retention_class: view.retention_class.map(|class| class.to_string()),
+    }
+}
+
 /// Helper function to build search query from request
 fn build_search_query(request: &ResearchListRequest) -> Result<SearchQuery, ApiError> {
     let keywords = request.keywords.clone().unwrap_or_default();
+    SearchExpression::parse(&keywords)?;
 
     let mut query = SearchQuery::new(keywords)
         .with_limit(request.limit.unwrap_or(10))
@@ -547,12 +1656,118 @@ fn create_summary(content: &str, max_length: usize) -> String {
     if content.len() <= max_length {
         content.to_string()
     } else {
-        let truncated = &content[..max_length];
-        let last_space = truncated.rfind(' ').unwrap_or(max_length);
+        let mut end = max_length;
+        while !content.is_char_boundary(end) {
+            end -= 1;
+        }
+        let truncated = &content[..end];
+        let last_space = truncated.rfind(' ').unwrap_or(end);
         format!("{}...", &content[..last_space])
     }
 }
 
+/// Convert a catalog cost estimate to its API representation
+/// Convert a pipeline result to the API response format
+fn convert_research_result(
+    result: &fortitude_types::ResearchResult,
+    processing_time_ms: u64,
+) -> ResearchResponse {
+    ResearchResponse {
+        id: result.cache_key().to_string(),
+        query: result.original_query().to_string(),
+        research_type: result.research_type().to_string(),
+        labels: result
+            .request
+            .labels()
+            .into_iter()
+            .map(|label| ClassificationLabel {
+                research_type: label.research_type.to_string(),
+                confidence: label.confidence,
+            })
+            .collect(),
+        immediate_answer: result.immediate_answer.clone(),
+        supporting_evidence: result
+            .supporting_evidence
+            .iter()
+            .map(|e| Evidence {
+                source: e.source.clone(),
+                content: e.content.clone(),
+                relevance: e.relevance,
+                evidence_type: e.evidence_type.clone(),
+            })
+            .collect(),
+        implementation_details: result
+            .implementation_details
+            .iter()
+            .map(|d| Detail {
+                category: d.category.clone(),
+                content: d.content.clone(),
+                priority: d.priority.clone(),
+                prerequisites: d.prerequisites.clone(),
+            })
+            .collect(),
+        metadata: ResearchMetadata {
+            completed_at: result.metadata.completed_at,
+            processing_time_ms: result.metadata.processing_time_ms,
+            sources_consulted: result.metadata.sources_consulted.clone(),
+            quality_score: result.metadata.quality_score,
+            provider: result.metadata.tags.get("provider").cloned(),
+            model: result.metadata.tags.get("model").cloned(),
+            tags: result.metadata.tags.clone(),
+        },
+        parent_id: result.parent_id.clone(),
+        feedback: None,
+        related_results: Vec::new(),
+        processing_time_ms,
+    }
+}
+
+fn convert_related_result(related: RelatedResult) -> RelatedResearch {
+    RelatedResearch {
+        id: related.id,
+        query: related.query,
+        research_type: related.research_type.to_string(),
+        relevance: related.relevance,
+    }
+}
+
+fn convert_job_snapshot(snapshot: JobSnapshot) -> ResearchJobResponse {
+    let status = snapshot.state.status().to_string();
+    let (finished_at, result, error) = match snapshot.state {
+        JobState::Running => (None, None, None),
+        JobState::Completed {
+            response,
+            finished_at,
+        } => (Some(finished_at), Some(*response), None),
+        JobState::Failed { error, finished_at } => {
+            (Some(finished_at), None, Some(error.to_string()))
+        }
+    };
+
+    ResearchJobResponse {
+        status_url: format!("/api/v1/research/jobs/{}", snapshot.id),
+        job_id: snapshot.id,
+        status,
+        submitted_at: snapshot.submitted_at,
+        finished_at,
+        result,
+        error,
+    }
+}
+
+fn convert_provider_estimate(estimate: &ProviderCostEstimate) -> ProviderEstimate {
+    ProviderEstimate {
+        provider: estimate.provider.clone(),
+        model: estimate.model.clone(),
+        input_tokens: estimate.input_tokens,
+        output_tokens_min: estimate.output_tokens_min,
+        output_tokens_max: estimate.output_tokens_max,
+        cost_min_usd: estimate.cost_min_usd,
+        cost_max_usd: estimate.cost_max_usd,
+        fits_context: estimate.fits_context,
+    }
+}
+
 /// Convert pipeline error to API error
 fn convert_pipeline_error(err: PipelineError) -> ApiError {
     match err {
@@ -590,6 +1805,9 @@ fn convert_storage_error(err: StorageError) -> ApiError {
         StorageError::PermissionDenied(_) => ApiError::Forbidden {
             reason: "Access denied to research data".to_string(),
         },
+        StorageError::InvalidQuery(message) => ApiError::BadRequest {
+            message: format!("Invalid search query: {message}"),
+        },
         _ => {
             error!("Unexpected storage error: {:?}", err);
             ApiError::InternalError {
@@ -741,6 +1959,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_build_search_query_invalid_keywords() {
+        let request = ResearchListRequest {
+            research_type: None,
+            keywords: Some("rust AND (tokio".to_string()),
+            min_quality: None,
+            tags: None,
+            limit: None,
+            offset: None,
+            sort: None,
+        };
+
+        match build_search_query(&request).unwrap_err() {
+            ApiError::BadRequest { message } => {
+                assert!(message.contains("Missing closing parenthesis at position 9"));
+                assert!(message.contains("add a ')'"));
+            }
+            _ => panic!("Expected BadRequest error"),
+        }
+    }
+
+    #[test]
+    fn test_check_curation_permission() {
+        assert!(check_curation_permission(&create_admin_claims()).is_ok());
+        assert!(matches!(
+            check_curation_permission(&create_test_claims()),
+            Err(ApiError::Forbidden { .. })
+        ));
+    }
+
+    #[test]
+    fn test_build_bulk_update_inputs() {
+        let filter = build_bulk_filter(&BulkUpdateFilter {
+            research_type: Some("learning".to_string()),
+            tag: Some("tokio".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(filter.research_type, Some(ResearchType::Learning));
+        assert_eq!(filter.tag.as_deref(), Some("tokio"));
+
+        let mutation = build_metadata_mutation(&BulkUpdateMutations {
+            add_tags: vec!["runtime".to_string()],
+            retention_class: Some("permanent".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(mutation.retention_class, Some(RetentionClass::Permanent));
+
+        let invalid = build_metadata_mutation(&BulkUpdateMutations {
+            retention_class: Some("forever".to_string()),
+            ..Default::default()
+        });
+        assert!(matches!(invalid, Err(ApiError::BadRequest { .. })));
+    }
+
     #[test]
     fn test_create_summary_short() {
         let content = "Short content";
@@ -829,4 +2103,81 @@ mod tests {
             _ => panic!("Expected InternalError"),
         }
     }
+
+    #[tokio::test]
+    async fn test_duplicate_check_refuses_similar_questions_unless_forced() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = FileStorage::new(StorageConfig {
+            base_path: dir.path().to_path_buf(),
+            ..StorageConfig::default()
+        })
+        .await
+        .unwrap();
+        let earlier = fortitude_types::ResearchResult::new(
+            fortitude_types::ClassifiedRequest::new(
+                "How do I implement error handling in Rust?".to_string(),
+                ResearchType::Implementation,
+                AudienceContext::default(),
+                DomainContext::default(),
+                0.8,
+                vec![],
+            ),
+            "Return Result and use the ? operator".to_string(),
+            vec![],
+            vec![],
+            fortitude_types::ResearchMetadata {
+                completed_at: chrono::Utc::now(),
+                processing_time_ms: 0,
+                sources_consulted: vec![],
+                quality_score: 0.8,
+                cache_key: String::new(),
+                tags: std::collections::HashMap::new(),
+            },
+        );
+        let earlier_id = storage.store(&earlier).await.unwrap();
+        let pipeline = PipelineBuilder::new()
+            .with_duplicate_check(DuplicateCheckConfig {
+                enabled: true,
+                ..DuplicateCheckConfig::default()
+            })
+            .build(
+                Arc::new(BasicClassifier::new(ClassificationConfig {
+                    default_threshold: 0.1,
+                    ..ClassificationConfig::default()
+                })),
+                Arc::new(storage),
+            );
+        let state = ResearchState::from_pipeline(Arc::new(pipeline));
+        let request: ResearchRequest = serde_json::from_value(
+            serde_json::json!({ "query": "How to implement error handling in Rust" }),
+        )
+        .unwrap();
+
+        let response = submit_research(
+            State(state.clone()),
+            None,
+            None,
+            HeaderMap::new(),
+            Json(request.clone()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let refused: SimilarResearchResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(refused.error_code, "SIMILAR_RESEARCH_EXISTS");
+        assert_eq!(refused.candidates.len(), 1);
+        assert_eq!(refused.candidates[0].id, earlier_id);
+
+        let forced = ResearchRequest {
+            force: Some(true),
+            ..request
+        };
+        let response = submit_research(State(state), None, None, HeaderMap::new(), Json(forced))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
 }
@@ -0,0 +1,286 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Research session endpoints for conversational research
+// Research requests naming a session carry its earlier questions and answers into their context
+
+use crate::middleware::auth::Claims;
+use crate::models::errors::ApiError;
+use crate::models::requests::CreateSessionRequest;
+use crate::models::responses::{ApiResponse, SessionResponse};
+use crate::routes::research::{check_research_permission, ResearchState};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use fortitude_core::{ResearchSession, SessionStore};
+use tracing::{info, instrument};
+use utoipa;
+use uuid::Uuid;
+use validator::Validate;
+
+/// POST /api/v1/sessions - Start a research session
+///
+/// Pass the returned ID as `session_id` in research requests; each question
+/// then follows up on the previous answer in the session.
+#[utoipa::path(
+    post,
+    path = "/api/v1/sessions",
+    request_body = CreateSessionRequest,
+    responses(
+        (status = 201, description = "Session created", body = ApiResponse<SessionResponse>),
+        (status = 400, description = "Invalid session request"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+    ),
+    tag = "Sessions",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn create_session(
+    State(state): State<ResearchState>,
+    claims_ext: Option<Extension<Claims>>,
+    Json(request): Json<CreateSessionRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<SessionResponse>>), ApiError> {
+    request.validate().map_err(|e| ApiError::BadRequest {
+        message: format!("Request validation failed: {e}"),
+    })?;
+    let user = session_user(claims_ext.as_ref())?;
+
+    let session = session_store(&state)?.create(request.title, Some(user.clone()));
+    info!("Created research session {} for {}", session.id, user);
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            convert_session(session),
+            Uuid::new_v4(),
+        )),
+    ))
+}
+
+/// GET /api/v1/sessions - Research sessions of the caller, most recently active first
+#[utoipa::path(
+    get,
+    path = "/api/v1/sessions",
+    responses(
+        (status = 200, description = "Research sessions", body = ApiResponse<Vec<SessionResponse>>),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+    ),
+    tag = "Sessions",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn list_sessions(
+    State(state): State<ResearchState>,
+    claims_ext: Option<Extension<Claims>>,
+) -> Result<Json<ApiResponse<Vec<SessionResponse>>>, ApiError> {
+    let user = session_user(claims_ext.as_ref())?;
+    let sessions = session_store(&state)?
+        .list(Some(&user))
+        .into_iter()
+        .map(convert_session)
+        .collect();
+    Ok(Json(ApiResponse::success(sessions, Uuid::new_v4())))
+}
+
+/// GET /api/v1/sessions/{id} - A research session and its results so far
+#[utoipa::path(
+    get,
+    path = "/api/v1/sessions/{id}",
+    params(
+        ("id" = String, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 200, description = "Research session", body = ApiResponse<SessionResponse>),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Session not found"),
+    ),
+    tag = "Sessions",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip(state, claims_ext))]
+pub async fn get_session(
+    State(state): State<ResearchState>,
+    claims_ext: Option<Extension<Claims>>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<SessionResponse>>, ApiError> {
+    let user = session_user(claims_ext.as_ref())?;
+    let session = owned_session(&state, &user, &id)?;
+    Ok(Json(ApiResponse::success(
+        convert_session(session),
+        Uuid::new_v4(),
+    )))
+}
+
+/// DELETE /api/v1/sessions/{id} - End a research session
+///
+/// The session's research results stay stored.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/sessions/{id}",
+    params(
+        ("id" = String, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 204, description = "Session deleted"),
+        (status = 401, description = "Unauthorized - JWT token required"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Session not found"),
+    ),
+    tag = "Sessions",
+    security(("jwt_auth" = []))
+)]
+#[instrument(skip(state, claims_ext))]
+pub async fn delete_session(
+    State(state): State<ResearchState>,
+    claims_ext: Option<Extension<Claims>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let user = session_user(claims_ext.as_ref())?;
+    owned_session(&state, &user, &id)?;
+    session_store(&state)?.remove(&id);
+    info!("Deleted research session {} of {}", id, user);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Session `id` if it belongs to `user`
+///
+/// Sessions of other users are reported as missing rather than forbidden so
+/// their IDs cannot be probed.
+pub(crate) fn owned_session(
+    state: &ResearchState,
+    user: &str,
+    id: &str,
+) -> Result<ResearchSession, ApiError> {
+    session_store(state)?
+        .get(id)
+        .filter(|session| session.owner.as_deref() == Some(user))
+        .ok_or_else(|| ApiError::NotFound {
+            resource: format!("Research session with ID: {id}"),
+        })
+}
+
+fn session_store(state: &ResearchState) -> Result<&SessionStore, ApiError> {
+    state
+        .pipeline
+        .sessions()
+        .ok_or_else(|| ApiError::ServiceUnavailable {
+            reason: "Research sessions are not enabled".to_string(),
+        })
+}
+
+/// Sessions are kept per token subject, like preference profiles
+fn session_user(claims_ext: Option<&Extension<Claims>>) -> Result<String, ApiError> {
+    match claims_ext {
+        Some(Extension(claims)) => {
+            check_research_permission(claims)?;
+            Ok(claims.sub.clone())
+        }
+        None => Ok("anonymous".to_string()),
+    }
+}
+
+fn convert_session(session: ResearchSession) -> SessionResponse {
+    SessionResponse {
+        id: session.id,
+        title: session.title,
+        created_at: session.created_at,
+        updated_at: session.updated_at,
+        turn_ids: session.turn_ids,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_core::{BasicClassifier, FileStorage, PipelineBuilder};
+    use fortitude_types::{ClassificationConfig, StorageConfig};
+    use std::sync::Arc;
+
+    fn claims(sub: &str) -> Extension<Claims> {
+        Extension(Claims {
+            sub: sub.to_string(),
+            permissions: vec!["fortitude:research:read".to_string()],
+            exp: chrono::Utc::now().timestamp() + 3600,
+            iat: chrono::Utc::now().timestamp(),
+            iss: "fortitude-api-server".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_sessions_are_private_to_their_owner() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = FileStorage::new(StorageConfig {
+            base_path: dir.path().to_path_buf(),
+            ..StorageConfig::default()
+        })
+        .await
+        .unwrap();
+        let pipeline = PipelineBuilder::new()
+            .build(
+                Arc::new(BasicClassifier::new(ClassificationConfig::default())),
+                Arc::new(storage),
+            )
+            .with_sessions(SessionStore::in_memory());
+        let state = ResearchState::from_pipeline(Arc::new(pipeline));
+
+        let (status, Json(created)) = create_session(
+            State(state.clone()),
+            Some(claims("alice")),
+            Json(CreateSessionRequest {
+                title: Some("Async runtimes".to_string()),
+            }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+        let id = created.data.id.clone();
+        assert!(created.data.turn_ids.is_empty());
+
+        let Json(fetched) = get_session(
+            State(state.clone()),
+            Some(claims("alice")),
+            Path(id.clone()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(fetched.data, created.data);
+
+        let Json(listed) = list_sessions(State(state.clone()), Some(claims("bob")))
+            .await
+            .unwrap();
+        assert!(listed.data.is_empty());
+        assert!(matches!(
+            get_session(State(state.clone()), Some(claims("bob")), Path(id.clone())).await,
+            Err(ApiError::NotFound { .. })
+        ));
+        assert!(matches!(
+            delete_session(State(state.clone()), Some(claims("bob")), Path(id.clone())).await,
+            Err(ApiError::NotFound { .. })
+        ));
+
+        let status = delete_session(State(state.clone()), Some(claims("alice")), Path(id))
+            .await
+            .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let Json(listed) = list_sessions(State(state), Some(claims("alice")))
+            .await
+            .unwrap();
+        assert!(listed.data.is_empty());
+    }
+}
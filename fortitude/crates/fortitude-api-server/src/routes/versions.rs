@@ -0,0 +1,98 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: API version capability endpoint listing supported versions and their lifecycle
+
+use crate::config::VersioningConfig;
+use crate::middleware::versioning::ApiVersion;
+use crate::models::responses::{ApiVersionInfo, ApiVersionsResponse};
+use axum::{extract::State, response::Json};
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa;
+
+/// List supported API versions and their deprecation status
+#[utoipa::path(
+    get,
+    path = "/api/versions",
+    responses(
+        (status = 200, description = "Supported API versions", body = ApiVersionsResponse),
+    ),
+    tag = "Health"
+)]
+#[instrument(skip(config))]
+pub async fn get_api_versions(
+    State(config): State<Arc<VersioningConfig>>,
+) -> Json<ApiVersionsResponse> {
+    Json(build_versions_response(&config))
+}
+
+/// Build the capability response for a versioning policy
+pub fn build_versions_response(config: &VersioningConfig) -> ApiVersionsResponse {
+    let versions = ApiVersion::all()
+        .into_iter()
+        .map(|version| {
+            let (status, sunset) = match version {
+                v if v == ApiVersion::CURRENT => ("current", None),
+                ApiVersion::V1 if config.v1_deprecated => ("deprecated", config.v1_sunset.clone()),
+                _ => ("supported", None),
+            };
+            ApiVersionInfo {
+                version: version.as_str().to_string(),
+                base_path: version.prefix().to_string(),
+                status: status.to_string(),
+                sunset,
+            }
+        })
+        .collect();
+
+    ApiVersionsResponse {
+        current: ApiVersion::CURRENT.as_str().to_string(),
+        versions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_versions_response_marks_v1_deprecated() {
+        let config = VersioningConfig {
+            v1_deprecated: true,
+            v1_sunset: Some("Wed, 31 Dec 2025 23:59:59 GMT".to_string()),
+        };
+
+        let response = build_versions_response(&config);
+        assert_eq!(response.current, "v2");
+        assert_eq!(response.versions.len(), 2);
+
+        let v1 = &response.versions[0];
+        assert_eq!(v1.status, "deprecated");
+        assert_eq!(v1.sunset.as_deref(), Some("Wed, 31 Dec 2025 23:59:59 GMT"));
+        assert_eq!(response.versions[1].status, "current");
+    }
+
+    #[test]
+    fn test_versions_response_without_deprecation() {
+        let config = VersioningConfig {
+            v1_deprecated: false,
+            v1_sunset: None,
+        };
+
+        let response = build_versions_response(&config);
+        assert_eq!(response.versions[0].status, "supported");
+        assert!(response.versions[0].sunset.is_none());
+    }
+}
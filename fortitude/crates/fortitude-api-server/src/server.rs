@@ -15,31 +15,61 @@
 // ABOUTME: Main HTTP server implementation for Fortitude API server
 // Provides production-ready Axum-based server with middleware stack and graceful shutdown
 
-use crate::config::ApiServerConfig;
+use crate::audit::AuditLog;
+use crate::config::{ApiServerConfig, PerformanceConfig};
+use crate::feedback::{FeedbackTokens, FEEDBACK_TOKEN_PATH};
+use crate::inflight::{self, InflightRegistry};
+use crate::maintenance::{wiki_sync_from_config, MaintenanceScheduler};
 use crate::middleware::{
     auth::{AuthManager, AuthState},
-    cors, logging, monitoring, pattern_tracking,
+    conditional, cors,
+    idempotency::{self, IdempotencyStore},
+    logging, monitoring, pattern_tracking,
+    rate_limit::{RateLimitState, RateLimiter},
+    rbac::ApiKeyStore,
+    versioning,
 };
 use crate::models::errors::ApiError;
+use crate::preferences::PreferenceStore;
+use crate::quota::QuotaTracker;
 use crate::routes::{
-    cache, classification, health, learning, monitoring as routes_monitoring, proactive, providers,
-    research,
+    admin, cache, classification, feedback, health, knowledge, learning, limits,
+    monitoring as routes_monitoring, preferences, proactive, providers, research, sessions,
+    versions,
 };
+use crate::shutdown::{ShutdownPhase, ShutdownState};
 use anyhow::Result;
 use axum::{
     response::{IntoResponse, Response},
-    routing::{delete, get, post},
-    Router,
+    routing::{delete, get, patch, post},
+    Extension, Router,
 };
+use fortitude_core::supervisor;
+use fortitude_types::Storage;
+use std::future::{Future, IntoFuture};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpListener;
 use tower_http::{
     catch_panic::CatchPanicLayer,
-    compression::CompressionLayer,
+    compression::{
+        predicate::{NotForContentType, Predicate, SizeAbove},
+        CompressionLayer,
+    },
     request_id::{MakeRequestUuid, SetRequestIdLayer},
 };
-use tracing::{error, info, instrument};
-use utoipa::OpenApi;
+use tracing::{error, info, instrument, warn};
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityRequirement, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+/// Path of the generated OpenAPI document
+pub const OPENAPI_JSON_PATH: &str = "/api/v1/openapi.json";
+
+/// Interval between drain progress reports during shutdown
+const DRAIN_PROGRESS_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Security scheme named by the route annotations
+const JWT_SECURITY_SCHEME: &str = "jwt_auth";
 
 /// OpenAPI documentation definition
 #[derive(OpenApi)]
@@ -47,11 +77,28 @@ use utoipa::OpenApi;
     paths(
         // Health endpoints
         health::health_check,
+        health::liveness_check,
+        health::readiness_check,
         health::protected_health_check,
+        versions::get_api_versions,
         // Research endpoints
         research::submit_research,
+        research::estimate_research,
+        research::bulk_update_research,
+        research::import_research,
         research::get_research_by_id,
+        research::get_research_job,
+        research::cancel_research_request,
+        research::get_research_lineage,
+        research::correct_research_classification,
         research::list_research_results,
+        // Session endpoints
+        sessions::create_session,
+        sessions::list_sessions,
+        sessions::get_session,
+        sessions::delete_session,
+        // Knowledge graph endpoints
+        knowledge::get_knowledge_graph,
         // Classification endpoints
         classification::submit_classification,
         classification::get_classification_by_id,
@@ -59,9 +106,12 @@ use utoipa::OpenApi;
         classification::get_classification_types,
         // Cache endpoints
         cache::get_cache_stats,
+        cache::export_cache_stats,
+        cache::get_cache_type_stats,
         cache::search_cache,
         cache::get_cache_item,
         cache::delete_cache_item,
+        cache::delete_cache_entry,
         cache::invalidate_cache,
         cache::cleanup_cache,
         // Proactive research endpoints
@@ -83,16 +133,39 @@ use utoipa::OpenApi;
         routes_monitoring::get_monitoring_health,
         routes_monitoring::get_monitoring_alerts,
         routes_monitoring::get_monitoring_performance_summary,
+        // Feedback endpoints
+        feedback::submit_token_feedback,
+        // Limits endpoints
+        limits::get_my_limits,
+        // Preferences endpoints
+        preferences::get_preferences,
+        preferences::update_preferences,
+        preferences::delete_preferences,
+        // Admin endpoints
+        admin::get_maintenance_status,
+        admin::list_inflight_requests,
+        admin::cancel_inflight_request,
+        admin::list_api_keys,
+        admin::create_api_key,
+        admin::rotate_api_key,
+        admin::revoke_api_key,
     ),
     components(schemas()),
+    modifiers(&SecurityAddon),
     tags(
         (name = "Health", description = "Health monitoring and status endpoints"),
         (name = "Research", description = "AI-powered research and analysis operations"),
+        (name = "Sessions", description = "Conversational research sessions"),
+        (name = "Knowledge", description = "Relations between stored research results"),
         (name = "Classification", description = "Content classification and categorization"),
         (name = "Cache", description = "Cache management and statistics"),
         (name = "Proactive Research", description = "Automated proactive research and gap detection"),
         (name = "Learning", description = "Learning system metrics and dashboard monitoring"),
-        (name = "Monitoring", description = "System monitoring dashboard and observability endpoints")
+        (name = "Monitoring", description = "System monitoring dashboard and observability endpoints"),
+        (name = "Feedback", description = "Token-based feedback on research results"),
+        (name = "Limits", description = "Rate limit and quota introspection"),
+        (name = "Preferences", description = "Per-key defaults for research requests"),
+        (name = "Admin", description = "Server administration and maintenance")
     ),
     info(
         title = "Fortitude API Server",
@@ -108,6 +181,55 @@ use utoipa::OpenApi;
 )]
 struct ApiDoc;
 
+/// Defines the bearer JWT scheme and requires it on authenticated operations
+///
+/// Every `/api/v1` route sits behind the auth middleware except the feedback
+/// token endpoint, so operations there without an explicit requirement get
+/// the JWT one.
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            JWT_SECURITY_SCHEME,
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .description(Some("JWT issued by the server's auth manager"))
+                    .build(),
+            ),
+        );
+
+        for (path, item) in openapi.paths.paths.iter_mut() {
+            if !path.starts_with("/api/v1/") || path == FEEDBACK_TOKEN_PATH {
+                continue;
+            }
+            let operations = [
+                &mut item.get,
+                &mut item.put,
+                &mut item.post,
+                &mut item.delete,
+                &mut item.patch,
+            ];
+            for operation in operations.into_iter().flatten() {
+                operation.security.get_or_insert_with(|| {
+                    vec![SecurityRequirement::new(
+                        JWT_SECURITY_SCHEME,
+                        Vec::<String>::new(),
+                    )]
+                });
+            }
+        }
+    }
+}
+
+/// Generated OpenAPI document of every annotated route
+pub fn openapi_document() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
 /// Production-ready API server
 pub struct ApiServer {
     pub config: ApiServerConfig,
@@ -122,12 +244,37 @@ pub struct ApiServer {
     pub provider_state: Option<providers::ProviderState>,
     pub pattern_tracker: Option<pattern_tracking::PatternTracker>,
     pub monitoring_service: Option<std::sync::Arc<monitoring::ApiMonitoringService>>,
+    pub maintenance_scheduler: MaintenanceScheduler,
+    pub inflight: InflightRegistry,
+    pub shutdown: ShutdownState,
 }
 
-impl ApiServer {
-    /// Create new API server with configuration
-    #[instrument(skip(config))]
-    pub async fn new(config: ApiServerConfig) -> Result<Self> {
+/// Builder for [`ApiServer`] allowing service state to be supplied up front
+///
+/// Any state not provided is initialized the same way as [`ApiServer::new`].
+pub struct ApiServerBuilder {
+    config: ApiServerConfig,
+    research_state: Option<research::ResearchState>,
+    cache_state: Option<cache::CacheState>,
+}
+
+impl ApiServerBuilder {
+    /// Use a pre-built research pipeline instead of the default provider setup
+    pub fn with_research_state(mut self, state: research::ResearchState) -> Self {
+        self.research_state = Some(state);
+        self
+    }
+
+    /// Use pre-built cache storage instead of `./reference_library`
+    pub fn with_cache_state(mut self, state: cache::CacheState) -> Self {
+        self.cache_state = Some(state);
+        self
+    }
+
+    /// Initialize remaining services and build the router
+    #[instrument(skip(self))]
+    pub async fn build(self) -> Result<ApiServer> {
+        let config = self.config;
         info!(
             "Initializing API server with config: {}:{}",
             config.host, config.port
@@ -142,17 +289,48 @@ impl ApiServer {
             None
         };
 
+        // Token and budget quotas shared by the research and limits routes
+        let quota = QuotaTracker::new(config.quota.clone(), config.auth.rate_limit.window_seconds);
+
+        // Preference profiles applied as research defaults and learned from feedback
+        let preference_store = PreferenceStore::new(&config.preferences);
+
+        // Feedback tokens issued with research results and redeemed without an API key
+        let feedback = FeedbackTokens::new(&config).with_preferences(preference_store.clone());
+
         // Initialize research state
-        let research_state = match research::ResearchState::new().await {
-            Ok(state) => {
-                info!("Research pipeline initialized successfully");
-                Some(state)
-            }
-            Err(e) => {
-                error!("Failed to initialize research pipeline: {}", e);
-                info!("Research endpoints will be unavailable");
-                None
-            }
+        let research_state = match self.research_state {
+            Some(state) => Some(state),
+            None => match research::ResearchState::new().await {
+                Ok(state) => {
+                    info!("Research pipeline initialized successfully");
+                    Some(state)
+                }
+                Err(e) => {
+                    error!("Failed to initialize research pipeline: {}", e);
+                    info!("Research endpoints will be unavailable");
+                    None
+                }
+            },
+        }
+        .map(|state| {
+            state
+                .with_quota(quota.clone())
+                .with_audit(AuditLog::new(&config.audit))
+                .with_feedback(feedback.clone())
+                .with_preferences(preference_store.clone())
+                .with_idempotency(
+                    IdempotencyStore::new(&config.idempotency)
+                        .with_max_body_size(config.security.max_request_body_size),
+                )
+        });
+        // Per-key request buckets, enforced after authentication
+        let rate_limiter = auth_manager
+            .as_ref()
+            .map(|_| RateLimiter::new(&config.auth.rate_limit, config.quota.clone()));
+        let limits_state = limits::LimitsState {
+            rate_limiter,
+            quota,
         };
 
         // Initialize classification state
@@ -169,16 +347,19 @@ impl ApiServer {
         };
 
         // Initialize cache state
-        let cache_state = match cache::CacheState::new(&config).await {
-            Ok(state) => {
-                info!("Cache system initialized successfully");
-                Some(state)
-            }
-            Err(e) => {
-                error!("Failed to initialize cache system: {}", e);
-                info!("Cache endpoints will be unavailable");
-                None
-            }
+        let cache_state = match self.cache_state {
+            Some(state) => Some(state),
+            None => match cache::CacheState::new(&config).await {
+                Ok(state) => {
+                    info!("Cache system initialized successfully");
+                    Some(state)
+                }
+                Err(e) => {
+                    error!("Failed to initialize cache system: {}", e);
+                    info!("Cache endpoints will be unavailable");
+                    None
+                }
+            },
         };
 
         // Initialize proactive state
@@ -211,7 +392,12 @@ impl ApiServer {
         let monitoring_state = match routes_monitoring::MonitoringState::new().await {
             Ok(state) => {
                 info!("Monitoring system initialized successfully");
-                Some(state)
+                Some(match &research_state {
+                    Some(research) => state
+                        .with_stage_metrics(research.pipeline.stage_metrics())
+                        .with_research_queue(research.pipeline.research_queue()),
+                    None => state,
+                })
             }
             Err(e) => {
                 error!("Failed to initialize monitoring system: {}", e);
@@ -221,7 +407,15 @@ impl ApiServer {
         };
 
         // Initialize provider state
-        let provider_state = Some(providers::ProviderState::default());
+        let mut provider_state = providers::ProviderState::default();
+        match fortitude_core::CostTracker::load(
+            fortitude_core::DEFAULT_COST_LEDGER_PATH,
+            fortitude_core::CostBudgetConfig::from_env(),
+        ) {
+            Ok(tracker) => provider_state = provider_state.with_cost_tracker(Arc::new(tracker)),
+            Err(e) => error!("Provider spend reporting unavailable: {}", e),
+        }
+        let provider_state = Some(provider_state);
         info!("Provider management system initialized successfully");
 
         // Initialize pattern tracking
@@ -241,8 +435,46 @@ impl ApiServer {
         ));
         info!("API monitoring service initialized successfully");
 
+        // Initialize wiki sync connectors
+        let wiki_sync = match wiki_sync_from_config(&config.wiki_sync) {
+            Ok(Some(sync)) => {
+                info!("Wiki sync initialized successfully");
+                Some(sync)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                error!("Failed to initialize wiki sync: {}", e);
+                info!("Wiki sync will be unavailable");
+                None
+            }
+        };
+
+        // Initialize maintenance scheduler (started when the server runs)
+        let maintenance_scheduler = MaintenanceScheduler::new(
+            config.maintenance.clone(),
+            cache_state.as_ref().map(|state| state.storage.clone()),
+            monitoring_service.clone(),
+            wiki_sync,
+        );
+
+        // Requests currently being handled, for admin introspection
+        let inflight = InflightRegistry::new();
+
+        // Shutdown progress, reported by /health
+        let shutdown = ShutdownState::new();
+
+        // Liveness and readiness probes
+        let mut probes = health::ProbeState::new(config.readiness.clone(), shutdown.clone());
+        if let Some(research) = &research_state {
+            probes = probes
+                .with_storage(research.pipeline.storage().clone())
+                .with_pipeline(research.pipeline.clone());
+        } else if let Some(cache) = &cache_state {
+            probes = probes.with_storage(cache.storage.clone());
+        }
+
         // Build the application router
-        let app = Self::build_router(
+        let app = ApiServer::build_router(
             &config,
             auth_manager.as_ref(),
             research_state.as_ref(),
@@ -254,10 +486,17 @@ impl ApiServer {
             provider_state.as_ref(),
             pattern_tracker.as_ref(),
             monitoring_service.as_ref(),
+            &maintenance_scheduler,
+            &limits_state,
+            &inflight,
+            &feedback,
+            &preference_store,
+            &probes,
         )
-        .await?;
+        .await?
+        .layer(Extension(shutdown.clone()));
 
-        Ok(Self {
+        Ok(ApiServer {
             config,
             app,
             auth_manager,
@@ -270,13 +509,32 @@ impl ApiServer {
             provider_state,
             pattern_tracker,
             monitoring_service,
+            maintenance_scheduler,
+            inflight,
+            shutdown,
         })
     }
+}
+
+impl ApiServer {
+    /// Create new API server with configuration
+    pub async fn new(config: ApiServerConfig) -> Result<Self> {
+        Self::builder(config).build().await
+    }
+
+    /// Start building an API server with custom service state
+    pub fn builder(config: ApiServerConfig) -> ApiServerBuilder {
+        ApiServerBuilder {
+            config,
+            research_state: None,
+            cache_state: None,
+        }
+    }
 
     /// Build the main application router with middleware
     #[allow(clippy::too_many_arguments)]
     async fn build_router(
-        _config: &ApiServerConfig,
+        config: &ApiServerConfig,
         auth_manager: Option<&std::sync::Arc<AuthManager>>,
         research_state: Option<&research::ResearchState>,
         classification_state: Option<&classification::ClassificationState>,
@@ -287,25 +545,52 @@ impl ApiServer {
         provider_state: Option<&providers::ProviderState>,
         pattern_tracker: Option<&pattern_tracking::PatternTracker>,
         monitoring_service: Option<&std::sync::Arc<monitoring::ApiMonitoringService>>,
+        maintenance_scheduler: &MaintenanceScheduler,
+        limits_state: &limits::LimitsState,
+        inflight: &InflightRegistry,
+        feedback: &FeedbackTokens,
+        preference_store: &PreferenceStore,
+        probes: &health::ProbeState,
     ) -> Result<Router> {
         // Note: Using manual Swagger UI implementation instead of utoipa_swagger_ui crate integration
 
+        let versioning_config = Arc::new(config.versioning.clone());
+
         // Create the basic router with health check and documentation
         let mut app = Router::new()
             .route("/health", get(health::health_check))
+            .route(
+                "/health/live",
+                get(health::liveness_check).with_state(probes.clone()),
+            )
+            .route(
+                "/health/ready",
+                get(health::readiness_check).with_state(probes.clone()),
+            )
+            .route(
+                "/api/versions",
+                get(versions::get_api_versions).with_state(versioning_config.clone()),
+            )
             // Serve OpenAPI spec directly
             .route("/openapi.yaml", get(Self::serve_openapi_yaml))
+            .route(OPENAPI_JSON_PATH, get(Self::serve_openapi_json))
             .route("/api-docs/openapi.json", get(Self::serve_openapi_json))
             // Add Swagger UI routes manually
             .route("/docs", get(Self::redirect_to_docs))
             .route("/docs/", get(Self::serve_swagger_ui))
             .route("/docs/{*tail}", get(Self::serve_swagger_ui))
+            // Feedback tokens stand in for API keys, so this sits outside auth
+            .route(
+                FEEDBACK_TOKEN_PATH,
+                post(feedback::submit_token_feedback).with_state(feedback.clone()),
+            )
             // Fallback for unknown routes
             .fallback(Self::handle_404);
 
         // Add protected routes
         if let Some(auth_mgr) = auth_manager {
             // With authentication enabled, add middleware
+            use crate::middleware::auth::{require_permission, Permission};
             let auth_state = AuthState {
                 auth_manager: auth_mgr.clone(),
             };
@@ -318,9 +603,54 @@ impl ApiServer {
             // Add research routes if available
             if let Some(research_state) = research_state {
                 let research_routes = Router::new()
-                    .route("/api/v1/research", post(research::submit_research))
+                    .route(
+                        "/api/v1/research",
+                        post(research::submit_research).layer(
+                            axum::middleware::from_fn_with_state(
+                                research_state.idempotency.clone(),
+                                idempotency::idempotency_middleware,
+                            ),
+                        ),
+                    )
+                    .route(
+                        "/api/v1/research/estimate",
+                        post(research::estimate_research),
+                    )
+                    .route(
+                        "/api/v1/research/bulk-update",
+                        post(research::bulk_update_research),
+                    )
+                    .route("/api/v1/research/import", post(research::import_research))
+                    .route(
+                        "/api/v1/research/jobs/{job_id}",
+                        get(research::get_research_job),
+                    )
+                    .route(
+                        "/api/v1/research/requests/{request_id}",
+                        delete(research::cancel_research_request),
+                    )
                     .route("/api/v1/research/{id}", get(research::get_research_by_id))
+                    .route(
+                        "/api/v1/research/{id}/lineage",
+                        get(research::get_research_lineage),
+                    )
+                    .route(
+                        "/api/v1/research/{id}/classification",
+                        patch(research::correct_research_classification),
+                    )
                     .route("/api/v1/research", get(research::list_research_results))
+                    .route(
+                        "/api/v1/sessions",
+                        get(sessions::list_sessions).post(sessions::create_session),
+                    )
+                    .route(
+                        "/api/v1/sessions/{id}",
+                        get(sessions::get_session).delete(sessions::delete_session),
+                    )
+                    .route(
+                        "/api/v1/knowledge/graph",
+                        get(knowledge::get_knowledge_graph),
+                    )
                     .with_state(research_state.clone());
 
                 protected_routes = protected_routes.merge(research_routes);
@@ -352,11 +682,14 @@ impl ApiServer {
 
             // Add cache routes if available
             if let Some(cache_state) = cache_state {
-                use crate::middleware::auth::{require_permission, Permission};
-
                 // Cache read operations - require ResourcesRead permission
                 let cache_read_routes = Router::new()
                     .route("/api/v1/cache/stats", get(cache::get_cache_stats))
+                    .route("/api/v1/cache/stats/export", get(cache::export_cache_stats))
+                    .route(
+                        "/api/v1/cache/stats/{research_type}",
+                        get(cache::get_cache_type_stats),
+                    )
                     .route("/api/v1/cache/search", get(cache::search_cache))
                     .route("/api/v1/cache/{id}", get(cache::get_cache_item))
                     .route_layer(axum::middleware::from_fn(require_permission(
@@ -367,6 +700,10 @@ impl ApiServer {
                 // Cache admin operations - require Admin permission
                 let cache_admin_routes = Router::new()
                     .route("/api/v1/cache/{id}", delete(cache::delete_cache_item))
+                    .route(
+                        "/api/v1/cache/entries/{key}",
+                        delete(cache::delete_cache_entry),
+                    )
                     .route("/api/v1/cache/invalidate", post(cache::invalidate_cache))
                     .route("/api/v1/cache/cleanup", post(cache::cleanup_cache))
                     .route_layer(axum::middleware::from_fn(require_permission(
@@ -398,10 +735,6 @@ impl ApiServer {
                         "/api/v1/proactive/config",
                         get(proactive::get_proactive_config),
                     )
-                    .route(
-                        "/api/v1/proactive/config",
-                        axum::routing::put(proactive::update_proactive_config),
-                    )
                     .route(
                         "/api/v1/proactive/tasks",
                         get(proactive::list_proactive_tasks),
@@ -412,7 +745,20 @@ impl ApiServer {
                     )
                     .with_state(proactive_state.clone());
 
-                protected_routes = protected_routes.merge(proactive_routes);
+                // Proactive configuration changes - require Admin permission
+                let proactive_admin_routes = Router::new()
+                    .route(
+                        "/api/v1/proactive/config",
+                        axum::routing::put(proactive::update_proactive_config),
+                    )
+                    .route_layer(axum::middleware::from_fn(require_permission(
+                        Permission::Admin,
+                    )))
+                    .with_state(proactive_state.clone());
+
+                protected_routes = protected_routes
+                    .merge(proactive_routes)
+                    .merge(proactive_admin_routes);
             }
 
             // Add learning system routes if available
@@ -467,14 +813,57 @@ impl ApiServer {
                 protected_routes = protected_routes.merge(monitoring_routes);
             }
 
-            // Add provider management routes if available
+            // Add provider management routes if available; switching and
+            // configuration changes require Admin permission
             if let Some(provider_state) = provider_state {
+                let provider_state = Arc::new(provider_state.clone());
                 let provider_routes =
-                    providers::create_router().with_state(Arc::new(provider_state.clone()));
+                    providers::create_read_router().with_state(provider_state.clone());
+                let provider_admin_routes = providers::create_admin_router()
+                    .route_layer(axum::middleware::from_fn(require_permission(
+                        Permission::Admin,
+                    )))
+                    .with_state(provider_state);
 
-                protected_routes = protected_routes.merge(provider_routes);
+                protected_routes = protected_routes
+                    .merge(provider_routes)
+                    .merge(provider_admin_routes);
             }
 
+            // Quota introspection for the calling key
+            let limits_routes = Router::new()
+                .route("/api/v1/limits/me", get(limits::get_my_limits))
+                .with_state(limits_state.clone());
+            protected_routes = protected_routes.merge(limits_routes);
+
+            // Preference profile of the calling key
+            protected_routes = protected_routes.merge(Self::preference_routes(preference_store));
+
+            // Admin routes - require Admin permission
+            let admin_routes = Router::new()
+                .route(
+                    "/api/v1/admin/maintenance",
+                    get(admin::get_maintenance_status),
+                )
+                .with_state(maintenance_scheduler.clone())
+                .merge(Self::inflight_routes(inflight))
+                .merge(Self::api_key_routes(auth_state.auth_manager.api_keys()))
+                .route_layer(axum::middleware::from_fn(require_permission(
+                    Permission::Admin,
+                )));
+            protected_routes = protected_routes.merge(admin_routes);
+
+            // Rate limiting runs after authentication so buckets are per key
+            if let Some(limiter) = limits_state.rate_limiter.clone() {
+                let rate_limit_state = RateLimitState {
+                    limiter,
+                    quota: limits_state.quota.clone(),
+                };
+                protected_routes = protected_routes.layer(axum::middleware::from_fn_with_state(
+                    rate_limit_state,
+                    crate::middleware::rate_limit::rate_limit_middleware,
+                ));
+            }
             protected_routes = protected_routes.layer(axum::middleware::from_fn_with_state(
                 auth_state.clone(),
                 crate::middleware::auth::jwt_auth_middleware,
@@ -491,9 +880,54 @@ impl ApiServer {
             // Add research routes if available (without auth middleware)
             if let Some(research_state) = research_state {
                 let research_routes = Router::new()
-                    .route("/api/v1/research", post(research::submit_research))
+                    .route(
+                        "/api/v1/research",
+                        post(research::submit_research).layer(
+                            axum::middleware::from_fn_with_state(
+                                research_state.idempotency.clone(),
+                                idempotency::idempotency_middleware,
+                            ),
+                        ),
+                    )
+                    .route(
+                        "/api/v1/research/estimate",
+                        post(research::estimate_research),
+                    )
+                    .route(
+                        "/api/v1/research/bulk-update",
+                        post(research::bulk_update_research),
+                    )
+                    .route("/api/v1/research/import", post(research::import_research))
+                    .route(
+                        "/api/v1/research/jobs/{job_id}",
+                        get(research::get_research_job),
+                    )
+                    .route(
+                        "/api/v1/research/requests/{request_id}",
+                        delete(research::cancel_research_request),
+                    )
                     .route("/api/v1/research/{id}", get(research::get_research_by_id))
+                    .route(
+                        "/api/v1/research/{id}/lineage",
+                        get(research::get_research_lineage),
+                    )
+                    .route(
+                        "/api/v1/research/{id}/classification",
+                        patch(research::correct_research_classification),
+                    )
                     .route("/api/v1/research", get(research::list_research_results))
+                    .route(
+                        "/api/v1/sessions",
+                        get(sessions::list_sessions).post(sessions::create_session),
+                    )
+                    .route(
+                        "/api/v1/sessions/{id}",
+                        get(sessions::get_session).delete(sessions::delete_session),
+                    )
+                    .route(
+                        "/api/v1/knowledge/graph",
+                        get(knowledge::get_knowledge_graph),
+                    )
                     .with_state(research_state.clone());
 
                 protected_routes = protected_routes.merge(research_routes);
@@ -527,9 +961,18 @@ impl ApiServer {
             if let Some(cache_state) = cache_state {
                 let cache_routes = Router::new()
                     .route("/api/v1/cache/stats", get(cache::get_cache_stats))
+                    .route("/api/v1/cache/stats/export", get(cache::export_cache_stats))
+                    .route(
+                        "/api/v1/cache/stats/{research_type}",
+                        get(cache::get_cache_type_stats),
+                    )
                     .route("/api/v1/cache/search", get(cache::search_cache))
                     .route("/api/v1/cache/{id}", get(cache::get_cache_item))
                     .route("/api/v1/cache/{id}", delete(cache::delete_cache_item))
+                    .route(
+                        "/api/v1/cache/entries/{key}",
+                        delete(cache::delete_cache_entry),
+                    )
                     .route("/api/v1/cache/invalidate", post(cache::invalidate_cache))
                     .route("/api/v1/cache/cleanup", post(cache::cleanup_cache))
                     .with_state(cache_state.clone());
@@ -633,26 +1076,63 @@ impl ApiServer {
                 protected_routes = protected_routes.merge(provider_routes);
             }
 
+            // Quota introspection for the calling key
+            let limits_routes = Router::new()
+                .route("/api/v1/limits/me", get(limits::get_my_limits))
+                .with_state(limits_state.clone());
+            protected_routes = protected_routes.merge(limits_routes);
+
+            // Preference profile of the calling key
+            protected_routes = protected_routes.merge(Self::preference_routes(preference_store));
+
+            // Add admin routes (without auth middleware)
+            let admin_routes = Router::new()
+                .route(
+                    "/api/v1/admin/maintenance",
+                    get(admin::get_maintenance_status),
+                )
+                .with_state(maintenance_scheduler.clone())
+                .merge(Self::inflight_routes(inflight));
+            protected_routes = protected_routes.merge(admin_routes);
+
             app = app.merge(protected_routes);
         }
 
+        // Serve /api/v2 from the same handlers; the versioning middleware
+        // applies the per-version response shape and deprecation headers
+        let app = versioning::with_v2_routes(app);
+
         // Add middleware stack with comprehensive configuration
         // Note: Middleware is applied in reverse order (last = innermost)
         let mut app = app
+            .layer(axum::middleware::from_fn_with_state(
+                inflight.clone(),
+                inflight::inflight_middleware,
+            ))
+            // Tags are computed on the v1 envelope, before v2 reshaping
+            .layer(axum::middleware::from_fn(
+                conditional::conditional_get_middleware,
+            ))
+            .layer(axum::middleware::from_fn_with_state(
+                versioning_config,
+                versioning::versioning_middleware,
+            ))
             // Individual layers to avoid type compatibility issues
-            .layer(CompressionLayer::new())
-            .layer(cors::create_cors_layer())
+            .layer(Self::compression_layer(&config.performance))
+            .layer(cors::CorsPolicyLayer::from_config(&config.cors)?)
             .layer(logging::create_trace_layer::<axum::body::Body>())
             .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
             .layer(CatchPanicLayer::custom(Self::handle_panic));
 
         // Add pattern tracking middleware if enabled
         if let Some(tracker) = pattern_tracker {
+            // The extension layer must wrap the middleware so the tracker is
+            // already present when the middleware extracts it
             app = app
-                .layer(axum::Extension(tracker.clone()))
                 .layer(axum::middleware::from_fn(
                     pattern_tracking::pattern_tracking_middleware,
-                ));
+                ))
+                .layer(axum::Extension(tracker.clone()));
         }
 
         // Add monitoring middleware if enabled
@@ -668,6 +1148,44 @@ impl ApiServer {
         Ok(app)
     }
 
+    /// In-flight request introspection and cancellation
+    fn inflight_routes(inflight: &InflightRegistry) -> Router {
+        Router::new()
+            .route("/api/v1/admin/inflight", get(admin::list_inflight_requests))
+            .route(
+                "/api/v1/admin/inflight/{id}",
+                delete(admin::cancel_inflight_request),
+            )
+            .with_state(inflight.clone())
+    }
+
+    /// API key listing, creation, rotation and revocation
+    fn api_key_routes(api_keys: &ApiKeyStore) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/admin/keys",
+                get(admin::list_api_keys).post(admin::create_api_key),
+            )
+            .route(
+                "/api/v1/admin/keys/{id}/rotate",
+                post(admin::rotate_api_key),
+            )
+            .route("/api/v1/admin/keys/{id}", delete(admin::revoke_api_key))
+            .with_state(api_keys.clone())
+    }
+
+    /// Preference profile management for the calling key
+    fn preference_routes(preference_store: &PreferenceStore) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/preferences",
+                get(preferences::get_preferences)
+                    .put(preferences::update_preferences)
+                    .delete(preferences::delete_preferences),
+            )
+            .with_state(preference_store.clone())
+    }
+
     /// Handle 404 errors for unknown routes
     async fn handle_404() -> impl IntoResponse {
         let error = ApiError::NotFound {
@@ -698,7 +1216,7 @@ impl ApiServer {
         use axum::http::header;
 
         // Generate JSON from the OpenAPI struct
-        let openapi_json = serde_json::to_string_pretty(&ApiDoc::openapi()).unwrap_or_else(|_| {
+        let openapi_json = serde_json::to_string_pretty(&openapi_document()).unwrap_or_else(|_| {
             r#"{"openapi":"3.0.3","info":{"title":"Fortitude API","version":"0.1.0"}}"#.to_string()
         });
 
@@ -740,7 +1258,7 @@ impl ApiServer {
     <script>
         window.onload = function() {
             const ui = SwaggerUIBundle({
-                url: '/api-docs/openapi.json',
+                url: '/api/v1/openapi.json',
                 dom_id: '#swagger-ui',
                 deepLinking: true,
                 presets: [
@@ -770,16 +1288,23 @@ impl ApiServer {
         error.into_response()
     }
 
-    /// Run the server with graceful shutdown
+    /// Gzip/brotli compression for responses above the configured size
+    ///
+    /// Images, gRPC and event streams are left uncompressed, as with the
+    /// default predicate.
+    fn compression_layer(performance: &PerformanceConfig) -> CompressionLayer<impl Predicate> {
+        let threshold = u16::try_from(performance.compression_threshold).unwrap_or(u16::MAX);
+        CompressionLayer::new().compress_when(
+            SizeAbove::new(threshold)
+                .and(NotForContentType::GRPC)
+                .and(NotForContentType::IMAGES)
+                .and(NotForContentType::SSE),
+        )
+    }
+
+    /// Run the server with graceful shutdown on SIGINT/SIGTERM
     #[instrument(skip(self))]
     pub async fn run(self) -> Result<()> {
-        let bind_addr = self.config.bind_address();
-        info!("Starting server on {}", bind_addr);
-
-        // Create TCP listener
-        let listener = TcpListener::bind(&bind_addr).await?;
-        info!("Server listening on {}", bind_addr);
-
         // Set up graceful shutdown signal
         let shutdown_signal = async {
             let ctrl_c = async {
@@ -809,14 +1334,145 @@ impl ApiServer {
             }
         };
 
-        // Run server with graceful shutdown
-        axum::serve(listener, self.app)
-            .with_graceful_shutdown(shutdown_signal)
-            .await?;
+        self.run_until(shutdown_signal).await
+    }
+
+    /// Run the server until `shutdown` completes, then drain connections
+    ///
+    /// Adopts a systemd socket-activated listener when present and reports
+    /// readiness, watchdog keep-alives and shutdown to the service manager.
+    /// After `shutdown`, `/health` reports draining for the configured grace
+    /// period, the listener closes, in-flight requests get the drain timeout
+    /// to finish and in-memory state is flushed.
+    #[instrument(skip(self, shutdown))]
+    pub async fn run_until<F>(self, shutdown: F) -> Result<()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        // Create TCP listener
+        let listener = match supervisor::activated_listener()? {
+            Some(listener) => {
+                info!(
+                    "Server listening on socket-activated {}",
+                    listener.local_addr()?
+                );
+                listener
+            }
+            None => {
+                let bind_addr = self.config.bind_address();
+                info!("Starting server on {}", bind_addr);
+                let listener = TcpListener::bind(&bind_addr).await?;
+                info!("Server listening on {}", bind_addr);
+                listener
+            }
+        };
+
+        self.maintenance_scheduler.start();
+        let watchdog = supervisor::spawn_watchdog();
+        supervisor::notify_ready(&format!("Listening on {}", listener.local_addr()?));
+
+        let health_grace = Duration::from_secs(self.config.shutdown.health_grace_seconds);
+        let drain_timeout = Duration::from_secs(self.config.shutdown.drain_timeout_seconds);
+
+        let stop_accepting = {
+            let state = self.shutdown.clone();
+            let inflight = self.inflight.clone();
+            async move {
+                shutdown.await;
+                let pending = inflight.len();
+                state.begin_drain(pending);
+                info!("Shutdown requested with {} requests in flight", pending);
+                supervisor::notify_stopping("Draining connections");
+                if !health_grace.is_zero() {
+                    info!(
+                        "Reporting draining on /health for {}s before closing the listener",
+                        health_grace.as_secs()
+                    );
+                    tokio::time::sleep(health_grace).await;
+                }
+                info!("No longer accepting connections");
+            }
+        };
+
+        // Report progress while draining and give up at the deadline
+        let drain_deadline = {
+            let state = self.shutdown.clone();
+            let inflight = self.inflight.clone();
+            async move {
+                state.reached(ShutdownPhase::Draining).await;
+                let deadline = tokio::time::Instant::now() + health_grace + drain_timeout;
+                let mut progress = tokio::time::interval(DRAIN_PROGRESS_INTERVAL);
+                loop {
+                    tokio::select! {
+                        _ = progress.tick() => {
+                            let pending = inflight.len();
+                            state.set_in_flight(pending);
+                            if pending > 0 {
+                                info!("Draining: {} requests in flight", pending);
+                            }
+                        }
+                        _ = tokio::time::sleep_until(deadline) => return inflight.len(),
+                    }
+                }
+            }
+        };
+
+        let serve = axum::serve(listener, self.app.clone())
+            .with_graceful_shutdown(stop_accepting)
+            .into_future();
+        let result = tokio::select! {
+            result = serve => result,
+            abandoned = drain_deadline => {
+                warn!(
+                    "Drain timeout of {}s passed; exiting with {} requests still in flight",
+                    drain_timeout.as_secs(),
+                    abandoned
+                );
+                Ok(())
+            }
+        };
+
+        self.shutdown.set_in_flight(self.inflight.len());
+        self.shutdown.advance(ShutdownPhase::Flushing);
+        self.flush().await;
+        self.shutdown.advance(ShutdownPhase::Stopped);
+
+        if let Some(watchdog) = watchdog {
+            watchdog.abort();
+        }
+        result?;
 
         info!("Server shutdown complete");
         Ok(())
     }
+
+    /// Stop background work and write in-memory state before exiting
+    async fn flush(&self) {
+        info!("Flushing server state");
+        self.maintenance_scheduler.shutdown();
+
+        if let Some(cache) = &self.cache_state {
+            match cache.storage.flush().await {
+                Ok(()) => info!("Flushed cache index"),
+                Err(e) => error!("Failed to flush cache index: {}", e),
+            }
+        }
+        if let Some(research) = &self.research_state {
+            if let Err(e) = research.pipeline.storage().flush().await {
+                error!("Failed to flush research storage: {}", e);
+            }
+        }
+
+        if let Some(monitoring) = &self.monitoring_service {
+            match monitoring.get_performance_summary().await {
+                Ok(summary) => info!(
+                    "Final request metrics: {} requests, {:.1}% errors, p95 {}ms",
+                    summary.total_requests, summary.error_rate, summary.p95_response_time_ms
+                ),
+                Err(e) => warn!("Failed to read final request metrics: {}", e),
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -834,8 +1490,29 @@ mod tests {
     #[tokio::test]
     async fn test_router_building() {
         let config = ApiServerConfig::default();
+        let maintenance = MaintenanceScheduler::new(config.maintenance.clone(), None, None, None);
+        let limits_state = limits::LimitsState {
+            rate_limiter: None,
+            quota: QuotaTracker::default(),
+        };
         let router = ApiServer::build_router(
-            &config, None, None, None, None, None, None, None, None, None, None,
+            &config,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            &maintenance,
+            &limits_state,
+            &InflightRegistry::new(),
+            &FeedbackTokens::default(),
+            &PreferenceStore::default(),
+            &health::ProbeState::new(Default::default(), ShutdownState::new()),
         )
         .await
         .unwrap();
@@ -853,4 +1530,28 @@ mod tests {
         let server = ApiServer::new(config).await.unwrap();
         assert!(!server.config.bind_address().is_empty());
     }
+
+    #[tokio::test]
+    async fn test_run_until_drains_and_stops() {
+        let config = ApiServerConfig {
+            host: "127.0.0.1".to_string(),
+            port: 0,
+            ..Default::default()
+        };
+        let server = ApiServer::new(config).await.unwrap();
+        let state = server.shutdown.clone();
+        assert!(state.is_running());
+
+        let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+        let run = tokio::spawn(server.run_until(async move {
+            let _ = stopped.await;
+        }));
+        stop.send(()).unwrap();
+        run.await.unwrap().unwrap();
+
+        let status = state.status();
+        assert_eq!(status.phase, ShutdownPhase::Stopped);
+        assert!(status.requested_at.is_some());
+        assert_eq!(status.in_flight, 0);
+    }
 }
@@ -0,0 +1,147 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Shutdown progress shared between the server loop and the health endpoint
+// Tracks whether the server is running, draining requests or flushing state on exit
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+/// Step of the shutdown sequence the server is in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShutdownPhase {
+    Running,
+    /// A shutdown signal arrived; in-flight requests are finishing
+    Draining,
+    /// Requests are done; monitoring and cache indices are being written
+    Flushing,
+    Stopped,
+}
+
+impl ShutdownPhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ShutdownPhase::Running => "running",
+            ShutdownPhase::Draining => "draining",
+            ShutdownPhase::Flushing => "flushing",
+            ShutdownPhase::Stopped => "stopped",
+        }
+    }
+}
+
+/// Point-in-time view of the shutdown sequence
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ShutdownStatus {
+    pub phase: ShutdownPhase,
+    /// When the shutdown signal arrived
+    pub requested_at: Option<DateTime<Utc>>,
+    /// Requests still being handled at the last progress check
+    pub in_flight: usize,
+}
+
+/// Shared shutdown progress
+#[derive(Debug, Clone)]
+pub struct ShutdownState {
+    status: Arc<watch::Sender<ShutdownStatus>>,
+}
+
+impl Default for ShutdownState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShutdownState {
+    pub fn new() -> Self {
+        let (status, _) = watch::channel(ShutdownStatus {
+            phase: ShutdownPhase::Running,
+            requested_at: None,
+            in_flight: 0,
+        });
+        Self {
+            status: Arc::new(status),
+        }
+    }
+
+    pub fn status(&self) -> ShutdownStatus {
+        self.status.borrow().clone()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status.borrow().phase == ShutdownPhase::Running
+    }
+
+    /// Record the shutdown signal with the requests still in flight
+    pub fn begin_drain(&self, in_flight: usize) {
+        self.status.send_modify(|status| {
+            status.phase = ShutdownPhase::Draining;
+            status.requested_at = Some(Utc::now());
+            status.in_flight = in_flight;
+        });
+    }
+
+    pub fn set_in_flight(&self, in_flight: usize) {
+        self.status
+            .send_modify(|status| status.in_flight = in_flight);
+    }
+
+    /// Move to a later phase; earlier phases are ignored
+    pub fn advance(&self, phase: ShutdownPhase) {
+        self.status.send_if_modified(|status| {
+            if phase > status.phase {
+                status.phase = phase;
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    /// Resolves once the server has reached `phase`
+    pub async fn reached(&self, phase: ShutdownPhase) {
+        let mut status = self.status.subscribe();
+        // The sender lives in `self`, so the channel cannot close while waiting
+        let _ = status.wait_for(|status| status.phase >= phase).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_phases_only_move_forward() {
+        let state = ShutdownState::new();
+        assert!(state.is_running());
+
+        let waiter = {
+            let state = state.clone();
+            tokio::spawn(async move { state.reached(ShutdownPhase::Draining).await })
+        };
+        state.begin_drain(3);
+        waiter.await.unwrap();
+
+        let status = state.status();
+        assert_eq!(status.phase, ShutdownPhase::Draining);
+        assert_eq!(status.in_flight, 3);
+        assert!(status.requested_at.is_some());
+
+        state.advance(ShutdownPhase::Flushing);
+        state.advance(ShutdownPhase::Draining);
+        assert_eq!(state.status().phase, ShutdownPhase::Flushing);
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Process supervisor entry points for the API server
+// systemd integration lives in fortitude_core::supervisor; this adds the Windows service mode
+
+/// Windows service wrapper for the API server
+#[cfg(windows)]
+pub mod windows {
+    use crate::config::ApiServerConfig;
+    use crate::server::ApiServer;
+    use fortitude_core::supervisor::windows::run_service;
+    use std::ffi::OsString;
+    use tracing::error;
+    use windows_service::service_dispatcher;
+
+    /// Service name registered with the Service Control Manager
+    pub const SERVICE_NAME: &str = "FortitudeApiServer";
+
+    windows_service::define_windows_service!(ffi_service_main, service_main);
+
+    /// Hand control to the Service Control Manager; blocks until the service stops
+    pub fn run() -> windows_service::Result<()> {
+        service_dispatcher::start(SERVICE_NAME, ffi_service_main)
+    }
+
+    fn service_main(_arguments: Vec<OsString>) {
+        let result = run_service(SERVICE_NAME, |stop| async move {
+            let config = ApiServerConfig::from_env()?;
+            let server = ApiServer::new(config).await?;
+            server.run_until(stop).await
+        });
+        if let Err(e) = result {
+            error!("Windows service failed: {:?}", e);
+        }
+    }
+}
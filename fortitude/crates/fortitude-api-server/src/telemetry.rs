@@ -0,0 +1,178 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Tracing subscriber setup with optional OpenTelemetry OTLP span export
+// Also links request spans to incoming W3C trace context and reads the current trace ID
+
+use crate::config::TelemetryConfig;
+use anyhow::Result;
+use axum::http::HeaderMap;
+use tracing::Span;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
+
+/// Keeps the span exporter alive; pending spans are flushed when dropped
+#[must_use = "spans are only exported while the guard is alive"]
+pub struct TelemetryGuard {
+    #[cfg(feature = "otel")]
+    provider: Option<opentelemetry_sdk::trace::SdkTracerProvider>,
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "otel")]
+        if let Some(provider) = self.provider.take() {
+            if let Err(e) = provider.shutdown() {
+                eprintln!("Failed to flush trace spans: {e}");
+            }
+        }
+    }
+}
+
+/// Install the global tracing subscriber
+///
+/// Logs go to stdout filtered by `RUST_LOG`. With the `otel` feature and an
+/// OTLP endpoint configured, spans are also exported to the collector.
+pub fn init(config: &TelemetryConfig) -> Result<TelemetryGuard> {
+    let fmt_layer = tracing_subscriber::fmt::layer().with_filter(EnvFilter::from_default_env());
+    let registry = tracing_subscriber::registry().with(fmt_layer);
+
+    #[cfg(feature = "otel")]
+    {
+        let provider = match &config.otlp_endpoint {
+            Some(endpoint) => Some(otel::tracer_provider(config, endpoint)?),
+            None => None,
+        };
+        let otel_layer = provider.as_ref().map(otel::layer);
+        registry.with(otel_layer).try_init()?;
+        if let Some(endpoint) = &config.otlp_endpoint {
+            tracing::info!("Exporting trace spans to {}", endpoint);
+        }
+        Ok(TelemetryGuard { provider })
+    }
+
+    #[cfg(not(feature = "otel"))]
+    {
+        registry.try_init()?;
+        if config.otlp_endpoint.is_some() {
+            tracing::warn!(
+                "OTLP endpoint configured but the server was built without the `otel` feature; spans are not exported"
+            );
+        }
+        Ok(TelemetryGuard {})
+    }
+}
+
+/// Trace ID of the current span, when it is part of an exported trace
+pub fn current_trace_id() -> Option<String> {
+    #[cfg(feature = "otel")]
+    {
+        use opentelemetry::trace::TraceContextExt;
+        use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+        let context = Span::current().context();
+        let span_context = context.span().span_context().clone();
+        span_context
+            .is_valid()
+            .then(|| span_context.trace_id().to_string())
+    }
+
+    #[cfg(not(feature = "otel"))]
+    {
+        None
+    }
+}
+
+/// Continue the caller's trace when the request carries a `traceparent` header
+pub fn set_remote_parent(span: &Span, headers: &HeaderMap) {
+    #[cfg(feature = "otel")]
+    {
+        use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+        let parent = opentelemetry::global::get_text_map_propagator(|propagator| {
+            propagator.extract(&otel::HeaderExtractor(headers))
+        });
+        // Fails only when the span is disabled, in which case nothing is exported
+        let _ = span.set_parent(parent);
+    }
+
+    #[cfg(not(feature = "otel"))]
+    {
+        let _ = (span, headers);
+    }
+}
+
+#[cfg(feature = "otel")]
+mod otel {
+    use crate::config::TelemetryConfig;
+    use anyhow::Result;
+    use axum::http::HeaderMap;
+    use opentelemetry::propagation::Extractor;
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
+    use opentelemetry_sdk::Resource;
+    use tracing::Subscriber;
+    use tracing_subscriber::filter::LevelFilter;
+    use tracing_subscriber::registry::LookupSpan;
+    use tracing_subscriber::Layer;
+
+    pub(super) fn tracer_provider(
+        config: &TelemetryConfig,
+        endpoint: &str,
+    ) -> Result<SdkTracerProvider> {
+        let exporter = SpanExporter::builder()
+            .with_http()
+            .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+            .build()?;
+
+        let sampler =
+            Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(config.sample_ratio)));
+
+        let provider = SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_sampler(sampler)
+            .with_resource(
+                Resource::builder()
+                    .with_service_name(config.service_name.clone())
+                    .build(),
+            )
+            .build();
+
+        opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+        Ok(provider)
+    }
+
+    pub(super) fn layer<S>(provider: &SdkTracerProvider) -> impl Layer<S>
+    where
+        S: Subscriber + for<'span> LookupSpan<'span>,
+    {
+        let tracer = provider.tracer("fortitude");
+        tracing_opentelemetry::layer()
+            .with_tracer(tracer)
+            .with_filter(LevelFilter::INFO)
+    }
+
+    pub(super) struct HeaderExtractor<'a>(pub &'a HeaderMap);
+
+    impl Extractor for HeaderExtractor<'_> {
+        fn get(&self, key: &str) -> Option<&str> {
+            self.0.get(key).and_then(|value| value.to_str().ok())
+        }
+
+        fn keys(&self) -> Vec<&str> {
+            self.0.keys().map(|name| name.as_str()).collect()
+        }
+    }
+}
@@ -235,6 +235,17 @@ async fn anchor_error_handling_never_panics() {
         priority: None,
         audience_context: None,
         domain_context: None,
+        dry_run: None,
+        parent_id: None,
+        code: None,
+        wait_timeout_ms: None,
+        budget_profile: None,
+        time_budget_ms: None,
+        include_feedback_token: None,
+        provider: None,
+        model: None,
+        session_id: None,
+        force: None,
     };
 
     // This should return an error, not panic
@@ -251,7 +262,10 @@ async fn anchor_middleware_stability() {
     for _i in 0..3 {
         let _cors = cors::create_cors_layer();
         let _trace = logging::create_trace_layer::<axum::body::Body>();
-        let _rate_limit = rate_limit::create_rate_limit_layer();
+        let _rate_limit = rate_limit::RateLimiter::new(
+            &fortitude_api_server::config::RateLimitConfig::default(),
+            fortitude_api_server::config::QuotaConfig::default(),
+        );
     }
 
     // If we reach here, middleware is stable
@@ -273,6 +287,17 @@ async fn anchor_model_serialization_stability() {
         priority: Some("high".to_string()),
         audience_context: None,
         domain_context: None,
+        dry_run: None,
+        parent_id: None,
+        code: None,
+        wait_timeout_ms: None,
+        budget_profile: None,
+        time_budget_ms: None,
+        include_feedback_token: None,
+        provider: None,
+        model: None,
+        session_id: None,
+        force: None,
     };
 
     let serialized = serde_json::to_string(&request).expect("Failed to serialize request");
@@ -406,6 +431,8 @@ async fn anchor_cache_models_stability() {
         misses: 150,
         average_age_seconds: 3600.0,
         by_research_type: HashMap::new(),
+        research_type_order: vec![],
+        research_type_pagination: None,
         storage_efficiency: responses::StorageEfficiencyResponse {
             utilization_percent: 75.0,
             duplicate_entries: 3,
@@ -421,6 +448,8 @@ async fn anchor_cache_models_stability() {
                 last_day: 1200,
                 peak_hour: "14:00-15:00".to_string(),
                 top_accessed: vec!["key1".to_string(), "key2".to_string()],
+                window_hours: 24,
+                in_window: 1200,
             },
         },
     };
@@ -571,6 +600,7 @@ async fn anchor_cache_error_handling() {
 async fn anchor_cache_api_contract() {
     use axum::{extract::State, Extension};
     use fortitude_api_server::{
+        extractors::SafeQuery,
         middleware::auth::Claims,
         routes::cache::{get_cache_stats, CacheState},
     };
@@ -609,7 +639,12 @@ async fn anchor_cache_api_contract() {
     };
 
     // Test cache stats endpoint maintains contract
-    let result = get_cache_stats(State(cache_state), Some(Extension(claims))).await;
+    let result = get_cache_stats(
+        State(cache_state),
+        Some(Extension(claims)),
+        SafeQuery(Default::default()),
+    )
+    .await;
 
     assert!(result.is_ok());
     let response = result.unwrap();
@@ -141,6 +141,82 @@ async fn test_cors_headers() {
     assert!(headers.contains_key("access-control-allow-methods"));
 }
 
+/// Test browser preflight handling against the configured CORS policy
+#[tokio::test]
+async fn test_cors_preflight_policy() {
+    let mut config = ApiServerConfig::default();
+    config.cors.allowed_origins = vec!["https://dashboard.example.com".to_string()];
+    let server = ApiServer::new(config)
+        .await
+        .expect("Failed to create server");
+
+    let preflight = |origin: &str, uri: &str| {
+        Request::builder()
+            .uri(uri)
+            .method("OPTIONS")
+            .header("origin", origin)
+            .header("access-control-request-method", "POST")
+            .header(
+                "access-control-request-headers",
+                "authorization,content-type",
+            )
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    // Allowed origin gets its origin echoed back with credentials
+    let response = server
+        .app
+        .clone()
+        .oneshot(preflight(
+            "https://dashboard.example.com",
+            "/api/v1/research",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let headers = response.headers();
+    assert_eq!(
+        headers["access-control-allow-origin"],
+        "https://dashboard.example.com"
+    );
+    assert_eq!(headers["access-control-allow-credentials"], "true");
+    assert_eq!(headers["access-control-max-age"], "86400");
+    let allowed_headers = headers["access-control-allow-headers"].to_str().unwrap();
+    assert!(allowed_headers.contains("authorization"));
+    assert!(allowed_headers.contains("content-type"));
+
+    // Other origins are not granted access
+    let response = server
+        .app
+        .clone()
+        .oneshot(preflight("https://evil.example.com", "/api/v1/research"))
+        .await
+        .unwrap();
+    assert!(!response
+        .headers()
+        .contains_key("access-control-allow-origin"));
+
+    // The public health endpoint is readable from anywhere, without credentials
+    let response = server
+        .app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/health")
+                .header("origin", "https://status.example.org")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["access-control-allow-origin"], "*");
+    assert!(!response
+        .headers()
+        .contains_key("access-control-allow-credentials"));
+}
+
 /// Test request ID header is added
 #[tokio::test]
 async fn test_request_id_middleware() {
@@ -176,7 +252,10 @@ async fn test_middleware_compilation() {
     // Test that middleware can be created without panicking
     let _cors_layer = cors::create_cors_layer();
     let _trace_layer = logging::create_trace_layer::<axum::body::Body>();
-    let _rate_limit_layer = rate_limit::create_rate_limit_layer();
+    let _rate_limit_layer = rate_limit::RateLimiter::new(
+        &fortitude_api_server::config::RateLimitConfig::default(),
+        fortitude_api_server::config::QuotaConfig::default(),
+    );
 
     // If we reach here, middleware compilation works
     // Test passes if no panics occur
@@ -195,6 +274,17 @@ async fn test_models_serialization() {
         priority: Some("high".to_string()),
         audience_context: None,
         domain_context: None,
+        dry_run: None,
+        parent_id: None,
+        code: None,
+        wait_timeout_ms: None,
+        budget_profile: None,
+        time_budget_ms: None,
+        include_feedback_token: None,
+        provider: None,
+        model: None,
+        session_id: None,
+        force: None,
     };
 
     let serialized = serde_json::to_string(&research_req);
@@ -501,6 +591,123 @@ async fn test_classification_endpoint_auth_disabled() {
     assert_eq!(response.status(), StatusCode::OK);
 }
 
+/// Test v1 responses carry version and deprecation headers
+#[tokio::test]
+async fn test_v1_deprecation_headers() {
+    let mut config = ApiServerConfig::default();
+    config.auth.enabled = false;
+    config.versioning.v1_sunset = Some("Wed, 31 Dec 2025 23:59:59 GMT".to_string());
+
+    let server = ApiServer::new(config)
+        .await
+        .expect("Failed to create server");
+
+    let request = Request::builder()
+        .uri("/api/v1/classify/types")
+        .method("GET")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let headers = response.headers();
+    assert_eq!(headers.get("api-version").unwrap(), "v1");
+    assert_eq!(headers.get("deprecation").unwrap(), "true");
+    assert_eq!(
+        headers.get("sunset").unwrap(),
+        "Wed, 31 Dec 2025 23:59:59 GMT"
+    );
+    assert!(headers
+        .get("link")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .contains("</api/v2/classify/types>"));
+}
+
+/// Test v2 routes reuse the v1 handlers with the v2 envelope
+#[tokio::test]
+async fn test_v2_routes_use_v2_envelope() {
+    let mut config = ApiServerConfig::default();
+    config.auth.enabled = false;
+
+    let server = ApiServer::new(config)
+        .await
+        .expect("Failed to create server");
+
+    let request = Request::builder()
+        .uri("/api/v2/classify/types")
+        .method("GET")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get("api-version").unwrap(), "v2");
+    assert!(response.headers().get("deprecation").is_none());
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json_value: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    assert!(json_value["data"]["research_types"].is_array());
+    assert_eq!(json_value["meta"]["api_version"], "v2");
+    assert!(json_value["meta"]["request_id"].is_string());
+    assert!(json_value.get("success").is_none());
+}
+
+/// Test v2 routes still enforce authentication
+#[tokio::test]
+async fn test_v2_routes_require_auth() {
+    let mut config = ApiServerConfig::default();
+    config.auth.enabled = true;
+    config.auth.jwt_secret = "test_secret_key_at_least_32_characters_long".to_string();
+
+    let server = ApiServer::new(config)
+        .await
+        .expect("Failed to create server");
+
+    let request = Request::builder()
+        .uri("/api/v2/cache/stats")
+        .method("GET")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// Test the version capability endpoint lists supported versions
+#[tokio::test]
+async fn test_api_versions_endpoint() {
+    let config = ApiServerConfig::default();
+    let server = ApiServer::new(config)
+        .await
+        .expect("Failed to create server");
+
+    let request = Request::builder()
+        .uri("/api/versions")
+        .method("GET")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json_value: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json_value["current"], "v2");
+    let versions = json_value["versions"].as_array().unwrap();
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[0]["status"], "deprecated");
+    assert_eq!(versions[1]["base_path"], "/api/v2");
+}
+
 /// Test cache stats endpoint with authentication
 #[tokio::test]
 async fn test_cache_stats_endpoint() {
@@ -549,6 +756,174 @@ async fn test_cache_stats_endpoint() {
     assert!(json_value["data"]["performance_metrics"].is_object());
 }
 
+/// Test cache stats drill-down and CSV export for a single research type
+#[tokio::test]
+async fn test_cache_type_stats_endpoint() {
+    let mut config = ApiServerConfig::default();
+    config.auth.enabled = false;
+
+    let server = ApiServer::new(config)
+        .await
+        .expect("Failed to create server");
+
+    let request = Request::builder()
+        .uri("/api/v1/cache/stats/learning?window_hours=168")
+        .method("GET")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json_value: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json_value["data"]["research_type"], "Learning");
+    assert_eq!(json_value["data"]["recent_operations"]["window_hours"], 168);
+
+    let request = Request::builder()
+        .uri("/api/v1/cache/stats/export?sort=size")
+        .method("GET")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response
+        .headers()
+        .get("content-type")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .starts_with("text/csv"));
+
+    let request = Request::builder()
+        .uri("/api/v1/cache/stats/astrology")
+        .method("GET")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// Test maintenance scheduler status endpoint requires admin permission
+#[tokio::test]
+async fn test_admin_maintenance_endpoint() {
+    let mut config = ApiServerConfig::default();
+    config.auth.enabled = true;
+    config.auth.jwt_secret = "test_secret_key_at_least_32_characters_long".to_string();
+
+    let server = ApiServer::new(config.clone())
+        .await
+        .expect("Failed to create server");
+
+    let auth_manager = AuthManager::new(std::sync::Arc::new(config)).unwrap();
+    let read_token = auth_manager
+        .generate_token("test_user", vec![Permission::ResourcesRead])
+        .await
+        .unwrap();
+    let admin_token = auth_manager
+        .generate_token("admin_user", vec![Permission::Admin])
+        .await
+        .unwrap();
+
+    let request = Request::builder()
+        .uri("/api/v1/admin/maintenance")
+        .method("GET")
+        .header("Authorization", format!("Bearer {read_token}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let request = Request::builder()
+        .uri("/api/v1/admin/maintenance")
+        .method("GET")
+        .header("Authorization", format!("Bearer {admin_token}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json_value: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let tasks = json_value["data"]["tasks"].as_array().unwrap();
+    assert_eq!(tasks.len(), 6);
+    assert_eq!(tasks[0]["name"], "cache_cleanup");
+    assert_eq!(json_value["data"]["running"], false);
+}
+
+/// Test API keys authenticate with their role and key management is admin-only
+#[tokio::test]
+async fn test_api_key_roles() {
+    let mut config = ApiServerConfig::default();
+    config.auth.enabled = true;
+    config.auth.jwt_secret = "test_secret_key_at_least_32_characters_long".to_string();
+    config.api_keys.bootstrap_admin_key = Some("bootstrap-admin-key".to_string());
+
+    let server = ApiServer::new(config)
+        .await
+        .expect("Failed to create server");
+
+    let request = Request::builder()
+        .uri("/api/v1/admin/keys")
+        .method("POST")
+        .header("X-API-Key", "bootstrap-admin-key")
+        .header("content-type", "application/json")
+        .body(Body::from(r#"{"name": "dashboard", "role": "read-only"}"#))
+        .unwrap();
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json_value: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let key_id = json_value["data"]["key"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let read_key = json_value["data"]["secret"].as_str().unwrap().to_string();
+
+    // Read-only keys can read but not invalidate the cache or manage keys
+    for (method, uri, expected) in [
+        ("GET", "/api/v1/cache/stats", StatusCode::OK),
+        ("GET", "/api/v1/admin/keys", StatusCode::FORBIDDEN),
+        ("POST", "/api/v1/cache/cleanup", StatusCode::FORBIDDEN),
+    ] {
+        let request = Request::builder()
+            .uri(uri)
+            .method(method)
+            .header("X-API-Key", &read_key)
+            .body(Body::empty())
+            .unwrap();
+        let response = server.app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), expected, "{method} {uri}");
+    }
+
+    let request = Request::builder()
+        .uri(format!("/api/v1/admin/keys/{key_id}/rotate"))
+        .method("POST")
+        .header("X-API-Key", "bootstrap-admin-key")
+        .body(Body::empty())
+        .unwrap();
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // The old secret stops working once rotated
+    let request = Request::builder()
+        .uri("/api/v1/cache/stats")
+        .method("GET")
+        .header("X-API-Key", &read_key)
+        .body(Body::empty())
+        .unwrap();
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
 /// Test cache search endpoint with filters
 #[tokio::test]
 async fn test_cache_search_endpoint() {
@@ -918,6 +1293,17 @@ async fn test_research_endpoint_with_auth() {
         priority: Some("medium".to_string()),
         audience_context: None,
         domain_context: None,
+        dry_run: None,
+        parent_id: None,
+        code: None,
+        wait_timeout_ms: None,
+        budget_profile: None,
+        time_budget_ms: None,
+        include_feedback_token: None,
+        provider: None,
+        model: None,
+        session_id: None,
+        force: None,
     };
 
     // Create HTTP request
@@ -982,6 +1368,17 @@ async fn test_research_endpoint_no_auth() {
         priority: None,
         audience_context: None,
         domain_context: None,
+        dry_run: None,
+        parent_id: None,
+        code: None,
+        wait_timeout_ms: None,
+        budget_profile: None,
+        time_budget_ms: None,
+        include_feedback_token: None,
+        provider: None,
+        model: None,
+        session_id: None,
+        force: None,
     };
 
     // Create request without authorization header
@@ -1001,6 +1398,202 @@ async fn test_research_endpoint_no_auth() {
     assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
 }
 
+/// Test feedback tokens returned with research are accepted without an API key
+#[tokio::test]
+async fn test_research_feedback_token_round_trip() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut config = ApiServerConfig::default();
+    config.feedback.learning_store_path = temp_dir
+        .path()
+        .join("learning_store.json")
+        .to_string_lossy()
+        .into_owned();
+    config.auth.enabled = true;
+    config.auth.jwt_secret = "test_secret_key_at_least_32_characters_long".to_string();
+
+    let server = ApiServer::new(config.clone())
+        .await
+        .expect("Failed to create server");
+    let auth_manager = AuthManager::new(std::sync::Arc::new(config)).unwrap();
+    let api_token = auth_manager
+        .generate_token("portal", vec![Permission::ResearchRead])
+        .await
+        .unwrap();
+
+    let body = serde_json::json!({
+        "query": "Rust retry backoff crates",
+        "include_feedback_token": true
+    });
+    let request = Request::builder()
+        .uri("/api/v1/research")
+        .method("POST")
+        .header("Authorization", format!("Bearer {api_token}"))
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let widget = &json["data"]["feedback"];
+    assert_eq!(widget["submit_url"], "/api/v1/feedback/token");
+    let feedback_token = widget["token"].as_str().unwrap().to_string();
+    let result_id = json["data"]["id"].clone();
+
+    // No Authorization header: the feedback token stands in for the API key
+    let submit = |token: String| {
+        Request::builder()
+            .uri("/api/v1/feedback/token")
+            .method("POST")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::json!({ "token": token, "helpful": true, "rating": 5 }).to_string(),
+            ))
+            .unwrap()
+    };
+    let response = server
+        .app
+        .clone()
+        .oneshot(submit(feedback_token.clone()))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["data"]["result_id"], result_id);
+    assert!(temp_dir.path().join("learning_store.json").exists());
+
+    // Neither token type is accepted in place of the other
+    let response = server.app.clone().oneshot(submit(api_token)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let request = Request::builder()
+        .uri("/api/v1/research/jobs/anything")
+        .header("Authorization", format!("Bearer {feedback_token}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// Test research cost estimate endpoint returns per-provider ranges
+#[tokio::test]
+async fn test_research_estimate_endpoint() {
+    let mut config = ApiServerConfig::default();
+    config.auth.enabled = false;
+
+    let server = ApiServer::new(config)
+        .await
+        .expect("Failed to create server");
+
+    let body = serde_json::json!({
+        "query": "Rust retry backoff crates",
+        "provider": "openai"
+    });
+
+    let request = Request::builder()
+        .uri("/api/v1/research/estimate")
+        .method("POST")
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json_value: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    assert!(json_value["success"].as_bool().unwrap());
+    assert!(
+        json_value["data"]["estimated_input_tokens"]
+            .as_u64()
+            .unwrap()
+            > 0
+    );
+
+    let estimates = json_value["data"]["estimates"].as_array().unwrap();
+    assert!(!estimates.is_empty());
+    for estimate in estimates {
+        assert_eq!(estimate["provider"], "openai");
+        assert!(
+            estimate["cost_min_usd"].as_f64().unwrap()
+                <= estimate["cost_max_usd"].as_f64().unwrap()
+        );
+    }
+}
+
+/// Test waiting server-side for research and picking up a job after the wait
+#[tokio::test]
+async fn test_research_wait_timeout_and_job_pickup() {
+    let mut config = ApiServerConfig::default();
+    config.auth.enabled = false;
+
+    let server = ApiServer::new(config)
+        .await
+        .expect("Failed to create server");
+
+    let submit = |wait_timeout_ms: u64| {
+        let body = serde_json::json!({
+            "query": "Rust retry backoff crates",
+            "wait_timeout_ms": wait_timeout_ms
+        });
+        Request::builder()
+            .uri("/api/v1/research")
+            .method("POST")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    };
+    let read_json = |response: axum::response::Response| async move {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+    };
+
+    // A generous wait returns the finished result directly
+    let response = server.app.clone().oneshot(submit(30_000)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = read_json(response).await;
+    assert!(json["data"]["immediate_answer"].is_string());
+
+    // No wait hands back a job reference
+    let response = server.app.clone().oneshot(submit(0)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let json = read_json(response).await;
+    assert_eq!(json["data"]["status"], "running");
+    let status_url = json["data"]["status_url"].as_str().unwrap().to_string();
+    assert!(status_url.ends_with(json["data"]["job_id"].as_str().unwrap()));
+
+    // Long-polling the job returns the result once it completes
+    let request = Request::builder()
+        .uri(format!("{status_url}?wait_timeout_ms=30000"))
+        .body(Body::empty())
+        .unwrap();
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = read_json(response).await;
+    assert_eq!(json["data"]["status"], "completed");
+    assert!(json["data"]["result"]["immediate_answer"].is_string());
+
+    // Unknown jobs and oversized waits are rejected
+    let request = Request::builder()
+        .uri("/api/v1/research/jobs/unknown-job")
+        .body(Body::empty())
+        .unwrap();
+    let response = server.app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = server.app.clone().oneshot(submit(600_000)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
 /// Test research by ID endpoint
 #[tokio::test]
 async fn test_research_by_id_endpoint() {
@@ -1285,6 +1878,33 @@ async fn test_research_invalid_request() {
     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
 
+/// Test research rejects providers and models the server is not configured for
+#[tokio::test]
+async fn test_research_rejects_unknown_provider_override() {
+    let mut config = ApiServerConfig::default();
+    config.auth.enabled = false;
+
+    let server = ApiServer::new(config)
+        .await
+        .expect("Failed to create server");
+
+    for body in [
+        serde_json::json!({"query": "How do lifetimes work?", "provider": "mistral"}),
+        serde_json::json!({"query": "How do lifetimes work?", "model": "no-such-model"}),
+        serde_json::json!({"query": "How do lifetimes work?", "provider": "claude", "model": "gpt-4"}),
+    ] {
+        let request = Request::builder()
+            .uri("/api/v1/research")
+            .method("POST")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+
+        let response = server.app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{body}");
+    }
+}
+
 /// Test all endpoint error responses contain proper JSON structure
 #[tokio::test]
 async fn test_error_responses_json_structure() {
@@ -1751,6 +2371,29 @@ async fn test_rate_limiting_enforcement() {
 
     let response3 = server.app.clone().oneshot(request3).await.unwrap();
     assert_eq!(response3.status(), StatusCode::TOO_MANY_REQUESTS);
+    let retry_after: u64 = response3.headers()["retry-after"]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!((1..=30).contains(&retry_after));
+    assert_eq!(response3.headers()["x-ratelimit-remaining"], "0");
+
+    // Limits are per key: another key from the same address is unaffected
+    let other_token = auth_manager
+        .generate_token("other_user", vec![Permission::ResearchRead])
+        .await
+        .unwrap();
+    let request4 = Request::builder()
+        .uri("/api/v1/health/protected")
+        .method("GET")
+        .header("Authorization", format!("Bearer {other_token}"))
+        .header("x-forwarded-for", "192.168.1.100")
+        .body(Body::empty())
+        .unwrap();
+
+    let response4 = server.app.clone().oneshot(request4).await.unwrap();
+    assert_eq!(response4.status(), StatusCode::OK);
 }
 
 /// Test authentication with malformed Bearer token
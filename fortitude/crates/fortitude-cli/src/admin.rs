@@ -0,0 +1,160 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Client for the API server's admin endpoints used by `fortitude admin`
+// Lists in-flight requests with their stage and provider, and cancels one by ID
+use crate::quota::ApiResponse;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// In-flight requests, as returned by `/api/v1/admin/inflight`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InflightList {
+    pub requests: Vec<InflightRequest>,
+    pub total_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InflightRequest {
+    pub id: String,
+    pub method: String,
+    pub endpoint: String,
+    pub stage: String,
+    pub provider: Option<String>,
+    pub user: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub elapsed_ms: u64,
+    pub cancel_requested: bool,
+}
+
+fn admin_request(
+    method: reqwest::Method,
+    url: &str,
+    token: Option<&str>,
+) -> reqwest::RequestBuilder {
+    let request = reqwest::Client::new().request(method, url);
+    match token {
+        Some(token) => request.bearer_auth(token),
+        None => request,
+    }
+}
+
+async fn check_status(
+    url: &str,
+    response: reqwest::Response,
+) -> Result<reqwest::Response, Box<dyn std::error::Error>> {
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("{url} returned {status}: {body}").into());
+    }
+    Ok(response)
+}
+
+/// Fetch the requests the server is currently handling
+pub async fn fetch_inflight(
+    server: &str,
+    token: Option<&str>,
+) -> Result<InflightList, Box<dyn std::error::Error>> {
+    let url = format!("{}/api/v1/admin/inflight", server.trim_end_matches('/'));
+    let response = admin_request(reqwest::Method::GET, &url, token)
+        .send()
+        .await?;
+    let response = check_status(&url, response).await?;
+    Ok(response.json::<ApiResponse<InflightList>>().await?.data)
+}
+
+/// Cancel an in-flight request by ID
+pub async fn cancel_inflight(
+    server: &str,
+    token: Option<&str>,
+    id: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let url = format!(
+        "{}/api/v1/admin/inflight/{}",
+        server.trim_end_matches('/'),
+        id
+    );
+    let response = admin_request(reqwest::Method::DELETE, &url, token)
+        .send()
+        .await?;
+    check_status(&url, response).await?;
+    Ok(())
+}
+
+/// Render in-flight requests as a table
+pub fn format_inflight(list: &InflightList) -> String {
+    if list.requests.is_empty() {
+        return "No requests in flight\n".to_string();
+    }
+
+    let mut output = format!(
+        "{:<36}  {:<6}  {:<28}  {:<18}  {:<12}  {:>10}\n",
+        "ID", "METHOD", "ENDPOINT", "STAGE", "PROVIDER", "ELAPSED"
+    );
+    for request in &list.requests {
+        let stage = if request.cancel_requested {
+            format!("{} (cancelling)", request.stage)
+        } else {
+            request.stage.clone()
+        };
+        output.push_str(&format!(
+            "{:<36}  {:<6}  {:<28}  {:<18}  {:<12}  {:>8.1}s\n",
+            request.id,
+            request.method,
+            request.endpoint,
+            stage,
+            request.provider.as_deref().unwrap_or("-"),
+            request.elapsed_ms as f64 / 1000.0
+        ));
+    }
+    output.push_str(&format!("\n{} request(s) in flight\n", list.total_count));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_inflight() {
+        let list: InflightList = serde_json::from_value(serde_json::json!({
+            "requests": [{
+                "id": "6f1c2a64-6d1e-4f43-9a43-2d0c7a4b8e11",
+                "method": "POST",
+                "endpoint": "/api/v1/research",
+                "stage": "research",
+                "provider": "claude",
+                "user": "alice",
+                "started_at": "2025-03-10T12:00:00Z",
+                "elapsed_ms": 12500,
+                "cancel_requested": true
+            }],
+            "total_count": 1
+        }))
+        .unwrap();
+
+        let output = format_inflight(&list);
+        assert!(output.contains("/api/v1/research"));
+        assert!(output.contains("research (cancelling)"));
+        assert!(output.contains("12.5s"));
+        assert!(output.contains("1 request(s) in flight"));
+
+        let empty = InflightList {
+            requests: vec![],
+            total_count: 0,
+        };
+        assert_eq!(format_inflight(&empty), "No requests in flight\n");
+    }
+}
@@ -0,0 +1,277 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Interactive chat mode for the CLI with slash commands and terminal markdown rendering
+// Each turn is stored as a research result linked to the previous turn
+use fortitude_types::ResearchResult;
+use std::path::PathBuf;
+
+/// Help text shown for `/help`
+pub const CHAT_HELP: &str = "\
+Type a question to research it with the conversation so far as context.
+
+  /search <query>              Search cached research results
+  /save [file]                 Write the transcript as markdown
+  /feedback <1-5> [comment]    Rate the last answer
+  /help                        Show this help
+  /quit                        Leave chat";
+
+/// One line of chat input
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChatCommand {
+    Ask(String),
+    Search(String),
+    Save(Option<PathBuf>),
+    Feedback { rating: u8, comment: Option<String> },
+    Help,
+    Quit,
+}
+
+impl ChatCommand {
+    /// Parse a line of input; blank lines yield `None`
+    pub fn parse(line: &str) -> Result<Option<Self>, String> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(None);
+        }
+        let Some(command) = line.strip_prefix('/') else {
+            return Ok(Some(Self::Ask(line.to_string())));
+        };
+
+        let (name, args) = match command.split_once(char::is_whitespace) {
+            Some((name, args)) => (name, args.trim()),
+            None => (command, ""),
+        };
+        let parsed = match name {
+            "search" if args.is_empty() => return Err("Usage: /search <query>".to_string()),
+            "search" => Self::Search(args.to_string()),
+            "save" => Self::Save((!args.is_empty()).then(|| PathBuf::from(args))),
+            "feedback" => {
+                let (rating, comment) = match args.split_once(char::is_whitespace) {
+                    Some((rating, comment)) => (rating, Some(comment.trim().to_string())),
+                    None => (args, None),
+                };
+                match rating.parse::<u8>() {
+                    Ok(rating @ 1..=5) => Self::Feedback { rating, comment },
+                    _ => return Err("Usage: /feedback <1-5> [comment]".to_string()),
+                }
+            }
+            "help" => Self::Help,
+            "quit" | "exit" => Self::Quit,
+            other => return Err(format!("Unknown command: /{other} (try /help)")),
+        };
+        Ok(Some(parsed))
+    }
+}
+
+/// Turns of the current conversation, oldest first
+#[derive(Debug, Default)]
+pub struct ChatSession {
+    turns: Vec<ResearchResult>,
+}
+
+impl ChatSession {
+    /// Continue a conversation from an earlier chain of results
+    pub fn resume(turns: Vec<ResearchResult>) -> Self {
+        Self { turns }
+    }
+
+    pub fn push(&mut self, turn: ResearchResult) {
+        self.turns.push(turn);
+    }
+
+    pub fn turns(&self) -> &[ResearchResult] {
+        &self.turns
+    }
+
+    /// Cache key of the latest turn, which the next turn links to
+    pub fn last_id(&self) -> Option<&str> {
+        self.turns.last().map(|turn| turn.cache_key())
+    }
+
+    /// Cache key of the first turn, used to name saved transcripts
+    pub fn root_id(&self) -> Option<&str> {
+        self.turns.first().map(|turn| turn.cache_key())
+    }
+
+    /// Render the transcript as markdown
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::from("# Research Chat\n");
+        for (i, turn) in self.turns.iter().enumerate() {
+            output.push_str(&format!(
+                "\n## {}. {}\n\n{}\n\n",
+                i + 1,
+                turn.original_query(),
+                turn.immediate_answer.trim_end()
+            ));
+            output.push_str(&format!(
+                "_Result `{}` · {}_\n",
+                turn.cache_key(),
+                turn.metadata.completed_at.format("%Y-%m-%d %H:%M:%S UTC")
+            ));
+        }
+        output
+    }
+}
+
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const CYAN: &str = "\x1b[36m";
+const YELLOW: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+/// Render markdown for the terminal
+///
+/// Headings, emphasis, inline code, code blocks and bullets are styled with
+/// ANSI escapes; with `color` off the markup is stripped instead.
+pub fn render_markdown(text: &str, color: bool) -> String {
+    let style = |code: &'static str| if color { code } else { "" };
+    let mut output = String::new();
+    let mut in_code_block = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            output.push_str(&format!("    {}{line}{}\n", style(YELLOW), style(RESET)));
+            continue;
+        }
+
+        let heading = trimmed.trim_start_matches('#');
+        if heading.len() < trimmed.len() && heading.starts_with(' ') {
+            output.push_str(&format!(
+                "{}{}{}{}\n",
+                style(BOLD),
+                style(CYAN),
+                render_inline(heading.trim(), color),
+                style(RESET)
+            ));
+        } else if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+        {
+            let indent = &line[..line.len() - trimmed.len()];
+            output.push_str(&format!("{indent}• {}\n", render_inline(item, color)));
+        } else {
+            output.push_str(&render_inline(line, color));
+            output.push('\n');
+        }
+    }
+    output
+}
+
+/// Style `**bold**` and `` `code` `` spans within a line
+fn render_inline(line: &str, color: bool) -> String {
+    let mut output = String::with_capacity(line.len());
+    let mut rest = line;
+    loop {
+        let bold = rest.find("**");
+        let code = rest.find('`');
+        let (start, marker, on) = match (bold, code) {
+            (Some(b), Some(c)) if c < b => (c, "`", YELLOW),
+            (Some(b), _) => (b, "**", BOLD),
+            (None, Some(c)) => (c, "`", YELLOW),
+            (None, None) => break,
+        };
+        let after = &rest[start + marker.len()..];
+        let Some(end) = after.find(marker) else {
+            break;
+        };
+        output.push_str(&rest[..start]);
+        if color {
+            output.push_str(on);
+        }
+        output.push_str(&after[..end]);
+        if color {
+            output.push_str(RESET);
+        }
+        rest = &after[end + marker.len()..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Dimmed footer printed under each answer
+pub fn render_turn_footer(result: &ResearchResult, color: bool) -> String {
+    let (on, off) = if color { (DIM, RESET) } else { ("", "") };
+    format!(
+        "{on}[{} · quality {:.2} · {}ms · id {}]{off}",
+        result.request.research_type,
+        result.metadata.quality_score,
+        result.metadata.processing_time_ms,
+        result.cache_key()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chat_commands() {
+        assert_eq!(ChatCommand::parse("   "), Ok(None));
+        assert_eq!(
+            ChatCommand::parse("What is Tokio?"),
+            Ok(Some(ChatCommand::Ask("What is Tokio?".to_string())))
+        );
+        assert_eq!(
+            ChatCommand::parse("/search retry backoff"),
+            Ok(Some(ChatCommand::Search("retry backoff".to_string())))
+        );
+        assert_eq!(
+            ChatCommand::parse("/save"),
+            Ok(Some(ChatCommand::Save(None)))
+        );
+        assert_eq!(
+            ChatCommand::parse("/save notes/chat.md"),
+            Ok(Some(ChatCommand::Save(Some(PathBuf::from(
+                "notes/chat.md"
+            )))))
+        );
+        assert_eq!(
+            ChatCommand::parse("/feedback 4 missed the timeout case"),
+            Ok(Some(ChatCommand::Feedback {
+                rating: 4,
+                comment: Some("missed the timeout case".to_string())
+            }))
+        );
+        assert_eq!(ChatCommand::parse("/exit"), Ok(Some(ChatCommand::Quit)));
+        assert!(ChatCommand::parse("/feedback 9").is_err());
+        assert!(ChatCommand::parse("/search").is_err());
+        assert!(ChatCommand::parse("/unknown").is_err());
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let text =
+            "## Answer\nUse **tokio::spawn** with `move`.\n- first\n```rust\nlet x = 1;\n```";
+
+        assert_eq!(
+            render_markdown(text, false),
+            "Answer\nUse tokio::spawn with move.\n• first\n    let x = 1;\n"
+        );
+
+        let colored = render_markdown(text, true);
+        assert!(colored.contains("\x1b[1m\x1b[36mAnswer\x1b[0m"));
+        assert!(colored.contains("\x1b[1mtokio::spawn\x1b[0m"));
+        assert!(colored.contains("\x1b[33mmove\x1b[0m"));
+        assert_eq!(
+            render_markdown("2 ** 3 and `unclosed", false),
+            "2 ** 3 and `unclosed\n"
+        );
+    }
+}
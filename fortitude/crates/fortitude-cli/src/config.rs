@@ -13,11 +13,20 @@
 // limitations under the License.
 
 // ABOUTME: Configuration management for the Fortitude CLI
+use fortitude_core::write_json_atomic;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+/// Configuration files tried in order when `FORTITUDE_CONFIG` is unset
+const DEFAULT_CONFIG_PATHS: [&str; 3] = [
+    "./fortitude.json",
+    "~/.config/fortitude/config.json",
+    "/etc/fortitude/config.json",
+];
+
 #[derive(Error, Debug)]
 pub enum ConfigError {
     #[error("Missing required configuration: {0}")]
@@ -26,6 +35,9 @@ pub enum ConfigError {
     #[error("Invalid configuration value: {0}")]
     InvalidValue(String),
 
+    #[error("Unknown configuration key: {0}")]
+    UnknownKey(String),
+
     #[error("Environment variable error: {0}")]
     #[allow(dead_code)]
     EnvironmentError(String),
@@ -61,6 +73,10 @@ pub struct Config {
 
     /// Vector database configuration
     pub vector: Option<VectorDatabaseConfig>,
+
+    /// Scheduled digests of new research results
+    #[serde(default)]
+    pub digest: fortitude_core::DigestConfig,
 }
 
 /// Claude API configuration
@@ -137,6 +153,47 @@ pub struct StorageConfig {
 
     /// Index update interval in seconds
     pub index_update_interval_seconds: u64,
+
+    /// Where results are kept
+    #[serde(default)]
+    pub backend: StorageBackend,
+
+    /// SQLite database file, defaults to `fortitude.db` under `base_path`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sqlite_path: Option<PathBuf>,
+
+    /// Object store location and settings used by the `object_store` backend
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub object_store: Option<fortitude_core::ObjectStoreConfig>,
+}
+
+/// Storage backend for research results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    /// One JSON file per result under `base_path`
+    #[default]
+    File,
+    /// A single SQLite database with full-text search
+    Sqlite,
+    /// An S3, GCS or Azure Blob Storage bucket, for hosts without persistent disks
+    #[serde(rename = "object_store")]
+    ObjectStore,
+}
+
+impl std::str::FromStr for StorageBackend {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "file" => Ok(Self::File),
+            "sqlite" => Ok(Self::Sqlite),
+            "object_store" => Ok(Self::ObjectStore),
+            _ => Err(ConfigError::InvalidValue(format!(
+                "Invalid storage backend: {s} (expected file, sqlite or object_store)"
+            ))),
+        }
+    }
 }
 
 /// Classification configuration
@@ -172,6 +229,37 @@ pub struct PipelineConfig {
 
     /// Processing timeout in seconds
     pub processing_timeout_seconds: u64,
+
+    /// Prompt-budget profiles and their selection per research type
+    #[serde(default)]
+    pub prompt_budget: fortitude_core::PromptBudgetConfig,
+
+    /// Content filter rules applied to every research result
+    #[serde(default)]
+    pub content_filter: fortitude_core::ContentFilterConfig,
+
+    /// Repair and normalization of provider markdown
+    #[serde(default)]
+    pub markdown: fortitude_core::MarkdownConfig,
+
+    /// Follow-up queries for low-confidence sections of an answer
+    #[serde(default)]
+    pub deepening: fortitude_core::DeepeningConfig,
+
+    /// Freshness windows per research type for `fortitude refresh`
+    #[serde(default)]
+    pub freshness: fortitude_core::FreshnessPolicy,
+
+    /// Serving cached results for near-duplicate queries
+    #[serde(default)]
+    pub semantic_cache: fortitude_core::SemanticCacheConfig,
+
+    /// Earlier results listed with each research result
+    #[serde(default)]
+    pub related_results: fortitude_core::RelatedResultsConfig,
+    /// Preflight that finds earlier research on a new question before researching it
+    #[serde(default)]
+    pub duplicate_check: fortitude_core::DuplicateCheckConfig,
 }
 
 /// Logging configuration
@@ -216,6 +304,43 @@ pub struct VectorDatabaseConfig {
 
     /// Connection pool configuration
     pub connection_pool: VectorConnectionPoolConfig,
+
+    /// Read replica URLs; searches fail over to these when the primary is slow or down
+    #[serde(default)]
+    pub replicas: Vec<String>,
+
+    /// Embedding backend used to vectorize documents and queries
+    #[serde(default)]
+    pub embedding: VectorEmbeddingConfig,
+}
+
+/// Embedding backend configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VectorEmbeddingConfig {
+    /// Backend: hash, onnx or openai
+    pub provider: fortitude_core::vector::EmbeddingProviderKind,
+
+    /// Model name; sent as the model for openai
+    pub model: String,
+
+    /// ONNX model file for the onnx provider
+    pub model_path: Option<PathBuf>,
+
+    /// tokenizer.json or vocab.txt for the onnx provider (defaults to the model's directory)
+    pub tokenizer_path: Option<PathBuf>,
+
+    /// Embeddings API base URL for the openai provider; point at a self-hosted server to stay offline
+    pub api_url: String,
+
+    /// Embeddings API key (optional for self-hosted servers)
+    pub api_key: Option<String>,
+
+    /// Output dimensions; must match vector_dimensions when set
+    pub dimensions: Option<usize>,
+
+    /// Number of texts embedded per request
+    pub batch_size: usize,
 }
 
 /// Vector database health check configuration
@@ -291,6 +416,9 @@ impl Default for StorageConfig {
             max_cache_size_bytes: 100 * 1024 * 1024, // 100MB
             enable_content_addressing: true,
             index_update_interval_seconds: 300, // 5 minutes
+            backend: StorageBackend::File,
+            sqlite_path: None,
+            object_store: None,
         }
     }
 }
@@ -418,6 +546,14 @@ impl Default for PipelineConfig {
             enable_caching: true,
             max_parallel_requests: 4,
             processing_timeout_seconds: 300,
+            prompt_budget: fortitude_core::PromptBudgetConfig::default(),
+            content_filter: fortitude_core::ContentFilterConfig::default(),
+            markdown: fortitude_core::MarkdownConfig::default(),
+            deepening: fortitude_core::DeepeningConfig::default(),
+            freshness: fortitude_core::FreshnessPolicy::default(),
+            semantic_cache: fortitude_core::SemanticCacheConfig::default(),
+            related_results: fortitude_core::RelatedResultsConfig::default(),
+            duplicate_check: fortitude_core::DuplicateCheckConfig::default(),
         }
     }
 }
@@ -444,10 +580,137 @@ impl Default for VectorDatabaseConfig {
             distance_metric: "cosine".to_string(),
             health_check: VectorHealthCheckConfig::default(),
             connection_pool: VectorConnectionPoolConfig::default(),
+            replicas: Vec::new(),
+            embedding: VectorEmbeddingConfig::default(),
+        }
+    }
+}
+
+impl Default for VectorEmbeddingConfig {
+    fn default() -> Self {
+        let core = fortitude_core::vector::EmbeddingConfig::default();
+        Self {
+            provider: core.backend.provider,
+            model: core.model_name,
+            model_path: None,
+            tokenizer_path: None,
+            api_url: core.backend.api_url,
+            api_key: None,
+            dimensions: None,
+            batch_size: core.batch_size,
+        }
+    }
+}
+
+impl StorageConfig {
+    /// SQLite database file used by the `sqlite` backend
+    pub fn sqlite_file(&self) -> PathBuf {
+        self.sqlite_path
+            .clone()
+            .unwrap_or_else(|| self.base_path.join(fortitude_core::DEFAULT_SQLITE_FILE))
+    }
+
+    /// Convert to the core storage configuration
+    pub fn to_core_config(&self) -> fortitude_types::StorageConfig {
+        fortitude_types::StorageConfig {
+            base_path: self.base_path.clone(),
+            cache_expiration_seconds: self.cache_expiration_seconds,
+            max_cache_size_bytes: self.max_cache_size_bytes,
+            enable_content_addressing: self.enable_content_addressing,
+            index_update_interval_seconds: self.index_update_interval_seconds,
         }
     }
 }
 
+impl ClaudeConfig {
+    /// Convert to the core Claude API configuration
+    pub fn to_core_config(&self) -> fortitude_core::api::ClaudeConfig {
+        use std::time::Duration;
+
+        fortitude_core::api::ClaudeConfig {
+            api_key: self.api_key.clone(),
+            base_url: self
+                .base_url
+                .clone()
+                .unwrap_or_else(|| "https://api.anthropic.com".to_string()),
+            timeout: Duration::from_secs(self.timeout_seconds.unwrap_or(300)),
+            rate_limit: fortitude_core::api::RateLimitConfig {
+                requests_per_minute: self.rate_limit.requests_per_minute,
+                input_tokens_per_minute: self.rate_limit.input_tokens_per_minute,
+                output_tokens_per_minute: self.rate_limit.output_tokens_per_minute,
+                max_concurrent_requests: self.rate_limit.max_concurrent_requests,
+            },
+            retry: fortitude_core::api::RetryConfig {
+                max_retries: self.retry.max_retries,
+                initial_delay: Duration::from_millis(self.retry.initial_delay_ms),
+                max_delay: Duration::from_millis(self.retry.max_delay_ms),
+                backoff_multiplier: self.retry.backoff_multiplier,
+                jitter: self.retry.jitter,
+            },
+            user_agent: format!("fortitude/{}", env!("CARGO_PKG_VERSION")),
+            model: self
+                .model
+                .clone()
+                .unwrap_or_else(|| "claude-3-sonnet-20240229".to_string()),
+        }
+    }
+}
+
+impl VectorDatabaseConfig {
+    /// Convert to the core vector configuration
+    pub fn to_core_config(&self) -> fortitude_core::vector::VectorConfig {
+        use fortitude_core::vector::{
+            DistanceMetric, EmbeddingBackendConfig, ReplicaEndpoint, ReplicationConfig,
+            VectorConfig,
+        };
+        use std::time::Duration;
+
+        let distance_metric = match self.distance_metric.to_lowercase().as_str() {
+            "euclidean" | "euclid" => DistanceMetric::Euclidean,
+            "dot" => DistanceMetric::Dot,
+            _ => DistanceMetric::Cosine,
+        };
+        let replication = self
+            .replicas
+            .iter()
+            .fold(ReplicationConfig::default(), |replication, url| {
+                replication.with_replica(ReplicaEndpoint::new(url.clone()))
+            });
+
+        let mut config = VectorConfig {
+            url: self.url.clone(),
+            api_key: self.api_key.clone(),
+            timeout: Duration::from_secs(self.timeout_seconds),
+            default_collection: self.default_collection.clone(),
+            vector_dimensions: self.vector_dimensions,
+            distance_metric,
+            replication,
+            ..VectorConfig::default()
+        };
+        config.health_check.enabled = self.health_check.enabled;
+        config.health_check.interval = Duration::from_secs(self.health_check.interval_seconds);
+        config.health_check.max_failures = self.health_check.max_failures;
+        config.health_check.timeout = Duration::from_secs(self.health_check.timeout_seconds);
+        config.connection_pool.max_connections = self.connection_pool.max_connections;
+        config.connection_pool.idle_timeout =
+            Duration::from_secs(self.connection_pool.idle_timeout_seconds);
+        config.connection_pool.connection_timeout =
+            Duration::from_secs(self.connection_pool.connection_timeout_seconds);
+        config.embedding.model_name = self.embedding.model.clone();
+        config.embedding.batch_size = self.embedding.batch_size;
+        config.embedding.backend = EmbeddingBackendConfig {
+            provider: self.embedding.provider,
+            model_path: self.embedding.model_path.clone(),
+            tokenizer_path: self.embedding.tokenizer_path.clone(),
+            api_url: self.embedding.api_url.clone(),
+            api_key: self.embedding.api_key.clone(),
+            dimensions: self.embedding.dimensions,
+            timeout_seconds: self.timeout_seconds,
+        };
+        config
+    }
+}
+
 impl Default for VectorHealthCheckConfig {
     fn default() -> Self {
         Self {
@@ -478,22 +741,16 @@ impl Config {
         config.load_from_env()?;
 
         // Load from config file if it exists
-        if let Ok(config_path) = env::var("FORTITUDE_CONFIG") {
-            config.load_from_file(&PathBuf::from(config_path))?;
-        } else {
-            // Try default config locations
-            let default_paths = [
-                PathBuf::from("./fortitude.json"),
-                PathBuf::from("~/.config/fortitude/config.json"),
-                PathBuf::from("/etc/fortitude/config.json"),
-            ];
-
-            for path in &default_paths {
-                if path.exists() {
-                    config.load_from_file(path)?;
-                    break;
-                }
-            }
+        if let Some(config_path) = Self::file_path() {
+            config.load_from_file(&config_path)?;
+        }
+
+        // Keeps the SMTP password out of the file that configures digest email
+        if let (Ok(password), Some(email)) = (
+            env::var("FORTITUDE_SMTP_PASSWORD"),
+            config.digest.email.as_mut(),
+        ) {
+            email.password = Some(password);
         }
 
         // Validate configuration
@@ -502,6 +759,17 @@ impl Config {
         Ok(config)
     }
 
+    /// Configuration file in use: `FORTITUDE_CONFIG`, else the first existing default location
+    pub fn file_path() -> Option<PathBuf> {
+        if let Ok(config_path) = env::var("FORTITUDE_CONFIG") {
+            return Some(PathBuf::from(config_path));
+        }
+        DEFAULT_CONFIG_PATHS
+            .iter()
+            .map(PathBuf::from)
+            .find(|path| path.exists())
+    }
+
     /// Load configuration from environment variables
     fn load_from_env(&mut self) -> Result<(), ConfigError> {
         // Claude API configuration
@@ -573,6 +841,17 @@ impl Config {
             self.storage.base_path = PathBuf::from(data_dir);
         }
 
+        if let Ok(backend) = env::var("FORTITUDE_STORAGE_BACKEND") {
+            self.storage.backend = backend.parse()?;
+        }
+
+        if let Ok(url) = env::var("FORTITUDE_OBJECT_STORE_URL") {
+            self.storage
+                .object_store
+                .get_or_insert_with(Default::default)
+                .url = url;
+        }
+
         if let Ok(cache_expiration) = env::var("FORTITUDE_CACHE_EXPIRATION_SECONDS") {
             self.storage.cache_expiration_seconds = cache_expiration.parse().map_err(|_| {
                 ConfigError::InvalidValue(format!(
@@ -805,6 +1084,56 @@ impl Config {
             vector_config.distance_metric = distance_metric;
         }
 
+        if let Ok(replicas) = env::var("QDRANT_REPLICA_URLS") {
+            let vector_config = self
+                .vector
+                .get_or_insert_with(VectorDatabaseConfig::default);
+            vector_config.replicas = replicas
+                .split(',')
+                .map(str::trim)
+                .filter(|url| !url.is_empty())
+                .map(String::from)
+                .collect();
+        }
+
+        // Embedding backend configuration
+        if let Ok(provider) = env::var("FORTITUDE_EMBEDDING_PROVIDER") {
+            let vector_config = self
+                .vector
+                .get_or_insert_with(VectorDatabaseConfig::default);
+            vector_config.embedding.provider = provider.parse().map_err(|_| {
+                ConfigError::InvalidValue(format!("Invalid embedding provider value: {provider}"))
+            })?;
+        }
+
+        if let Ok(model) = env::var("FORTITUDE_EMBEDDING_MODEL") {
+            let vector_config = self
+                .vector
+                .get_or_insert_with(VectorDatabaseConfig::default);
+            vector_config.embedding.model = model;
+        }
+
+        if let Ok(model_path) = env::var("FORTITUDE_EMBEDDING_MODEL_PATH") {
+            let vector_config = self
+                .vector
+                .get_or_insert_with(VectorDatabaseConfig::default);
+            vector_config.embedding.model_path = Some(PathBuf::from(model_path));
+        }
+
+        if let Ok(api_url) = env::var("FORTITUDE_EMBEDDING_API_URL") {
+            let vector_config = self
+                .vector
+                .get_or_insert_with(VectorDatabaseConfig::default);
+            vector_config.embedding.api_url = api_url;
+        }
+
+        if let Ok(api_key) = env::var("FORTITUDE_EMBEDDING_API_KEY") {
+            let vector_config = self
+                .vector
+                .get_or_insert_with(VectorDatabaseConfig::default);
+            vector_config.embedding.api_key = Some(api_key);
+        }
+
         // Vector health check configuration
         if let Ok(enabled) = env::var("QDRANT_HEALTH_CHECK_ENABLED") {
             let vector_config = self
@@ -922,6 +1251,8 @@ impl Config {
         if other.vector.is_some() {
             self.vector = other.vector;
         }
+
+        self.digest = other.digest;
     }
 
     /// Validate the configuration
@@ -1010,6 +1341,16 @@ impl Config {
             ));
         }
 
+        if self.storage.backend == StorageBackend::ObjectStore {
+            let object_store =
+                self.storage.object_store.as_ref().ok_or_else(|| {
+                    ConfigError::MissingRequired("storage.object_store".to_string())
+                })?;
+            object_store
+                .validate()
+                .map_err(|e| ConfigError::InvalidValue(format!("storage.object_store: {e}")))?;
+        }
+
         // Validate classification configuration
         if self.classification.default_threshold < 0.0
             || self.classification.default_threshold > 1.0
@@ -1141,6 +1482,42 @@ impl Config {
             ));
         }
 
+        self.pipeline
+            .prompt_budget
+            .validate()
+            .map_err(|e| ConfigError::InvalidValue(format!("pipeline.prompt_budget: {e}")))?;
+        self.pipeline
+            .content_filter
+            .validate()
+            .map_err(|e| ConfigError::InvalidValue(format!("pipeline.content_filter: {e}")))?;
+        self.pipeline
+            .markdown
+            .validate()
+            .map_err(|e| ConfigError::InvalidValue(format!("pipeline.markdown: {e}")))?;
+        self.pipeline
+            .deepening
+            .validate()
+            .map_err(|e| ConfigError::InvalidValue(format!("pipeline.deepening: {e}")))?;
+        self.pipeline
+            .freshness
+            .validate()
+            .map_err(|e| ConfigError::InvalidValue(format!("pipeline.freshness: {e}")))?;
+        self.pipeline
+            .semantic_cache
+            .validate()
+            .map_err(|e| ConfigError::InvalidValue(format!("pipeline.semantic_cache: {e}")))?;
+        self.pipeline
+            .related_results
+            .validate()
+            .map_err(|e| ConfigError::InvalidValue(format!("pipeline.related_results: {e}")))?;
+        self.pipeline
+            .duplicate_check
+            .validate()
+            .map_err(|e| ConfigError::InvalidValue(format!("pipeline.duplicate_check: {e}")))?;
+        self.digest
+            .validate()
+            .map_err(|e| ConfigError::InvalidValue(format!("digest: {e}")))?;
+
         // Validate logging configuration
         let valid_levels = ["trace", "debug", "info", "warn", "error"];
         if !valid_levels.contains(&self.logging.level.as_str()) {
@@ -1184,6 +1561,30 @@ impl Config {
                 )));
             }
 
+            // Validate embedding configuration
+            if let Some(dimensions) = vector.embedding.dimensions {
+                if dimensions != vector.vector_dimensions {
+                    return Err(ConfigError::InvalidValue(format!(
+                        "vector.embedding.dimensions ({dimensions}) must match vector.vector_dimensions ({})",
+                        vector.vector_dimensions
+                    )));
+                }
+            }
+
+            if vector.embedding.provider == fortitude_core::vector::EmbeddingProviderKind::Onnx
+                && vector.embedding.model_path.is_none()
+            {
+                return Err(ConfigError::InvalidValue(
+                    "vector.embedding.model_path is required for the onnx provider".to_string(),
+                ));
+            }
+
+            if vector.embedding.batch_size == 0 {
+                return Err(ConfigError::InvalidValue(
+                    "vector.embedding.batch_size must be greater than 0".to_string(),
+                ));
+            }
+
             // Validate health check configuration
             if vector.health_check.interval_seconds == 0 {
                 return Err(ConfigError::InvalidValue(
@@ -1303,12 +1704,169 @@ impl Config {
             pipeline: PipelineConfig::default(),
             logging: LoggingConfig::default(),
             vector: Some(VectorDatabaseConfig::default()),
+            digest: fortitude_core::DigestConfig::default(),
         };
 
         serde_json::to_string_pretty(&sample_config).unwrap()
     }
 }
 
+/// Edits a configuration file one dotted-path key at a time
+///
+/// Keys and value types are checked against the `Config` schema, and the edited
+/// document must still load and validate before it can be saved. Only the file
+/// is changed; environment overrides are never written back.
+#[derive(Debug, Clone)]
+pub struct ConfigEditor {
+    path: PathBuf,
+    document: Value,
+}
+
+impl ConfigEditor {
+    /// Open a configuration file, starting from the defaults when it does not exist
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, ConfigError> {
+        let path = path.into();
+        let document = if path.exists() {
+            serde_json::from_str(&std::fs::read_to_string(&path)?)?
+        } else {
+            serde_json::to_value(Config::default())?
+        };
+        Ok(Self { path, document })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Set a dotted-path key such as `claude.api_key` or `vector.url`
+    ///
+    /// The value is parsed as the type the key has in the schema. Unset
+    /// sections on the way to the key are filled with their defaults. Returns
+    /// the previous value; nothing changes when an error is returned.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<Value, ConfigError> {
+        let segments: Vec<&str> = key.split('.').collect();
+        if segments.iter().any(|segment| segment.is_empty()) {
+            return Err(ConfigError::UnknownKey(key.to_string()));
+        }
+
+        let schema = schema_template()?;
+        let mut expected = &schema;
+        for segment in &segments {
+            expected = expected
+                .get(segment)
+                .ok_or_else(|| ConfigError::UnknownKey(key.to_string()))?;
+        }
+        if expected.is_object() {
+            return Err(ConfigError::InvalidValue(format!(
+                "{key} is a section; set one of its keys instead"
+            )));
+        }
+        let value = parse_typed_value(key, value, expected)?;
+
+        let mut document = self.document.clone();
+        let (leaf, parents) = segments.split_last().expect("key has segments");
+        let mut node = &mut document;
+        let mut section_schema = &schema;
+        for segment in parents {
+            section_schema = &section_schema[*segment];
+            let section = node
+                .as_object_mut()
+                .ok_or_else(|| ConfigError::InvalidValue(format!("{key}: not a section")))?
+                .entry(segment.to_string())
+                .or_insert(Value::Null);
+            if section.is_null() {
+                *section = section_schema.clone();
+            }
+            node = section;
+        }
+        let previous = node
+            .as_object_mut()
+            .ok_or_else(|| ConfigError::InvalidValue(format!("{key}: not a section")))?
+            .insert(leaf.to_string(), value)
+            .unwrap_or(Value::Null);
+
+        let config: Config = serde_json::from_value(document.clone())
+            .map_err(|e| ConfigError::InvalidValue(format!("{key}: {e}")))?;
+        config.validate()?;
+        self.document = document;
+        Ok(previous)
+    }
+
+    /// Write the file atomically, keeping the previous version as `<file>.bak`
+    ///
+    /// Returns the backup path when there was a previous version.
+    pub fn save(&self) -> Result<Option<PathBuf>, ConfigError> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let backup = if self.path.exists() {
+            let backup = sibling_path(&self.path, "bak");
+            std::fs::copy(&self.path, &backup)?;
+            Some(backup)
+        } else {
+            None
+        };
+
+        write_json_atomic(&self.path, &self.document)?;
+        Ok(backup)
+    }
+}
+
+/// Configuration with every optional section present, used to look up keys and their types
+fn schema_template() -> Result<Value, ConfigError> {
+    let config = Config {
+        claude: Some(ClaudeConfig::default()),
+        vector: Some(VectorDatabaseConfig::default()),
+        ..Config::default()
+    };
+    Ok(serde_json::to_value(config)?)
+}
+
+/// Parse a command-line value as the JSON type `expected` has
+///
+/// Optional keys that are unset in the schema accept any JSON value and fall
+/// back to a string; the final load of the document checks them.
+fn parse_typed_value(key: &str, raw: &str, expected: &Value) -> Result<Value, ConfigError> {
+    let invalid =
+        |kind: &str| ConfigError::InvalidValue(format!("{key} expects {kind}, got '{raw}'"));
+    match expected {
+        Value::String(_) => Ok(Value::String(raw.to_string())),
+        Value::Bool(_) => raw
+            .parse::<bool>()
+            .map(Value::Bool)
+            .map_err(|_| invalid("true or false")),
+        Value::Number(number) if number.is_f64() => raw
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number)
+            .ok_or_else(|| invalid("a number")),
+        Value::Number(_) => raw
+            .parse::<u64>()
+            .map(Value::from)
+            .or_else(|_| raw.parse::<i64>().map(Value::from))
+            .map_err(|_| invalid("an integer")),
+        Value::Array(_) => match serde_json::from_str::<Value>(raw) {
+            Ok(array @ Value::Array(_)) => Ok(array),
+            _ => Ok(Value::Array(
+                raw.split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| Value::String(item.to_string()))
+                    .collect(),
+            )),
+        },
+        _ => Ok(serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()))),
+    }
+}
+
+fn sibling_path(path: &Path, extension: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(extension);
+    path.with_file_name(name)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1362,6 +1920,8 @@ mod tests {
         env::set_var("CLAUDE_API_KEY", "sk-test-key");
         env::set_var("CLAUDE_MODEL", "claude-3-sonnet-20240229");
         env::set_var("FORTITUDE_DATA_DIR", "/tmp/test");
+        env::set_var("FORTITUDE_STORAGE_BACKEND", "sqlite");
+        env::set_var("FORTITUDE_OBJECT_STORE_URL", "s3://bucket/library");
         env::set_var("FORTITUDE_LOG_LEVEL", "debug");
 
         let mut config = Config::default();
@@ -1372,15 +1932,92 @@ mod tests {
         assert_eq!(claude.api_key, "sk-test-key");
         assert_eq!(claude.model, Some("claude-3-sonnet-20240229".to_string()));
         assert_eq!(config.storage.base_path, PathBuf::from("/tmp/test"));
+        assert_eq!(config.storage.backend, StorageBackend::Sqlite);
+        assert_eq!(
+            config.storage.sqlite_file(),
+            PathBuf::from("/tmp/test/fortitude.db")
+        );
+        assert_eq!(
+            config.storage.object_store.as_ref().unwrap().url,
+            "s3://bucket/library"
+        );
         assert_eq!(config.logging.level, "debug");
 
         // Clean up
         env::remove_var("CLAUDE_API_KEY");
         env::remove_var("CLAUDE_MODEL");
         env::remove_var("FORTITUDE_DATA_DIR");
+        env::remove_var("FORTITUDE_STORAGE_BACKEND");
+        env::remove_var("FORTITUDE_OBJECT_STORE_URL");
         env::remove_var("FORTITUDE_LOG_LEVEL");
     }
 
+    #[test]
+    fn test_vector_config_conversion_with_replicas() {
+        let config = VectorDatabaseConfig {
+            distance_metric: "dot".to_string(),
+            replicas: vec![
+                "http://replica-1:6334".to_string(),
+                "http://replica-2:6334".to_string(),
+            ],
+            ..VectorDatabaseConfig::default()
+        };
+
+        let core = config.to_core_config();
+        assert_eq!(core.url, config.url);
+        assert!(matches!(
+            core.distance_metric,
+            fortitude_core::vector::DistanceMetric::Dot
+        ));
+        let urls: Vec<&str> = core
+            .replication
+            .replicas
+            .iter()
+            .map(|replica| replica.url.as_str())
+            .collect();
+        assert_eq!(urls, vec!["http://replica-1:6334", "http://replica-2:6334"]);
+        assert!(core.validate().is_ok());
+    }
+
+    #[test]
+    fn test_vector_embedding_config() {
+        use fortitude_core::vector::EmbeddingProviderKind;
+
+        let mut vector = VectorDatabaseConfig {
+            vector_dimensions: 768,
+            ..VectorDatabaseConfig::default()
+        };
+        vector.embedding = serde_json::from_str(
+            r#"{"provider": "openai", "model": "nomic-embed-text", "api_url": "http://ollama:11434/v1", "dimensions": 768}"#,
+        )
+        .unwrap();
+        assert_eq!(vector.embedding.batch_size, 32);
+
+        let core = vector.to_core_config();
+        assert_eq!(core.embedding.model_name, "nomic-embed-text");
+        assert_eq!(
+            core.embedding.backend.provider,
+            EmbeddingProviderKind::OpenAi
+        );
+        assert_eq!(core.embedding.backend.api_url, "http://ollama:11434/v1");
+        assert_eq!(core.embedding.backend.dimensions, Some(768));
+
+        let mut config = Config {
+            vector: Some(vector),
+            ..Config::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let embedding = &mut config.vector.as_mut().unwrap().embedding;
+        embedding.dimensions = Some(384);
+        assert!(config.validate().is_err());
+
+        let embedding = &mut config.vector.as_mut().unwrap().embedding;
+        embedding.dimensions = None;
+        embedding.provider = EmbeddingProviderKind::Onnx;
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_has_claude_config() {
         let mut config = Config::default();
@@ -1408,4 +2045,68 @@ mod tests {
         // Should be valid JSON
         let _: Config = serde_json::from_str(&sample).unwrap();
     }
+
+    #[test]
+    fn test_config_editor_sets_typed_values_with_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fortitude.json");
+
+        let mut editor = ConfigEditor::open(&path).unwrap();
+        editor.set("claude.api_key", "sk-first-key").unwrap();
+        editor.set("vector.url", "http://qdrant:6334").unwrap();
+        editor.set("pipeline.max_parallel_requests", "8").unwrap();
+        editor
+            .set("vector.replicas", "http://a:6334, http://b:6334")
+            .unwrap();
+        assert_eq!(editor.save().unwrap(), None);
+
+        let mut editor = ConfigEditor::open(&path).unwrap();
+        let previous = editor.set("claude.api_key", "sk-second-key").unwrap();
+        assert_eq!(previous, Value::String("sk-first-key".to_string()));
+        let backup = editor.save().unwrap().unwrap();
+        assert_eq!(backup, dir.path().join("fortitude.json.bak"));
+
+        let saved: Config = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved.claude.unwrap().api_key, "sk-second-key");
+        assert_eq!(saved.pipeline.max_parallel_requests, 8);
+        let vector = saved.vector.unwrap();
+        assert_eq!(vector.url, "http://qdrant:6334");
+        assert_eq!(vector.replicas.len(), 2);
+        let previous: Config =
+            serde_json::from_str(&std::fs::read_to_string(&backup).unwrap()).unwrap();
+        assert_eq!(previous.claude.unwrap().api_key, "sk-first-key");
+    }
+
+    #[test]
+    fn test_config_editor_rejects_invalid_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut editor = ConfigEditor::open(dir.path().join("fortitude.json")).unwrap();
+
+        assert!(matches!(
+            editor.set("claude.bogus", "x"),
+            Err(ConfigError::UnknownKey(_))
+        ));
+        assert!(matches!(
+            editor.set("vector..url", "x"),
+            Err(ConfigError::UnknownKey(_))
+        ));
+        assert!(matches!(
+            editor.set("storage", "x"),
+            Err(ConfigError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            editor.set("pipeline.max_parallel_requests", "many"),
+            Err(ConfigError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            editor.set("pipeline.enable_caching", "yes"),
+            Err(ConfigError::InvalidValue(_))
+        ));
+        // Schema-valid but fails validation; the document is left unchanged
+        assert!(editor.set("claude.api_key", "not-a-key").is_err());
+        assert_eq!(
+            editor.document,
+            serde_json::to_value(Config::default()).unwrap()
+        );
+    }
 }
@@ -0,0 +1,550 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Installation and runtime diagnostics behind `fortitude doctor`
+// Runs independent checks and turns problems into a prioritized fix-it list with CI exit codes
+use crate::config::{Config, ConfigError};
+use fortitude_core::api::{ApiClient, ApiConfig, ClaudeClient, HealthStatus};
+use fortitude_core::vector::{QdrantClient, VectorEndpoints};
+use fortitude_types::CacheEntry;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::net::TcpListener;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// API server port used when `FORTITUDE_API_PORT` is not set
+pub const DEFAULT_API_PORT: u16 = 3000;
+
+/// MCP server port used when `MCP_SERVER_PORT` is not set
+pub const DEFAULT_MCP_PORT: u16 = 8080;
+
+/// Exit code when every check passed, or only warnings were found without `--strict`
+pub const EXIT_OK: i32 = 0;
+
+/// Exit code for warnings under `--strict`
+pub const EXIT_WARNINGS: i32 = 1;
+
+/// Exit code when at least one check failed
+pub const EXIT_FAILURES: i32 = 2;
+
+/// Upper bound on each network probe
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Fail,
+    Warn,
+    Skip,
+    Pass,
+}
+
+impl CheckStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheckStatus::Fail => "FAIL",
+            CheckStatus::Warn => "WARN",
+            CheckStatus::Skip => "SKIP",
+            CheckStatus::Pass => "PASS",
+        }
+    }
+}
+
+/// Result of one diagnostic check
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckResult {
+    pub name: String,
+    pub status: CheckStatus,
+    pub message: String,
+    /// What to do about a failure or warning
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+impl CheckResult {
+    fn new(name: &str, status: CheckStatus, message: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            message: message.into(),
+            fix: None,
+        }
+    }
+
+    fn with_fix(mut self, fix: impl Into<String>) -> Self {
+        self.fix = Some(fix.into());
+        self
+    }
+}
+
+/// All check results, in the order the checks ran
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DoctorReport {
+    pub checks: Vec<CheckResult>,
+}
+
+impl DoctorReport {
+    /// Checks needing attention: failures first, then warnings, each in check order
+    ///
+    /// Checks run from the foundations up (configuration before the services
+    /// that depend on it), so check order is also fix priority.
+    pub fn fixes(&self) -> Vec<&CheckResult> {
+        let mut fixes: Vec<&CheckResult> = self
+            .checks
+            .iter()
+            .filter(|check| matches!(check.status, CheckStatus::Fail | CheckStatus::Warn))
+            .collect();
+        fixes.sort_by_key(|check| check.status);
+        fixes
+    }
+
+    /// Process exit code for CI preflight use
+    pub fn exit_code(&self, strict: bool) -> i32 {
+        if self.has(CheckStatus::Fail) {
+            EXIT_FAILURES
+        } else if strict && self.has(CheckStatus::Warn) {
+            EXIT_WARNINGS
+        } else {
+            EXIT_OK
+        }
+    }
+
+    fn has(&self, status: CheckStatus) -> bool {
+        self.checks.iter().any(|check| check.status == status)
+    }
+
+    /// Print the checks followed by the numbered fix-it list
+    pub fn print_table(&self) {
+        println!("{:<14} {:<6} MESSAGE", "CHECK", "STATUS");
+        for check in &self.checks {
+            println!(
+                "{:<14} {:<6} {}",
+                check.name,
+                check.status.as_str(),
+                check.message
+            );
+        }
+
+        let fixes = self.fixes();
+        println!();
+        if fixes.is_empty() {
+            println!("No problems found.");
+            return;
+        }
+        println!("Fix-it list (most important first):");
+        for (index, check) in fixes.iter().enumerate() {
+            let fix = check.fix.as_deref().unwrap_or(&check.message);
+            println!(
+                "{:>3}. [{}] {}: {}",
+                index + 1,
+                check.status.as_str(),
+                check.name,
+                fix
+            );
+        }
+    }
+}
+
+/// Options for [`run`]
+#[derive(Debug, Clone)]
+pub struct DoctorOptions {
+    /// Skip checks that contact the Claude API or vector database
+    pub offline: bool,
+    pub api_port: u16,
+    pub mcp_port: u16,
+}
+
+impl Default for DoctorOptions {
+    fn default() -> Self {
+        Self {
+            offline: false,
+            api_port: env_port("FORTITUDE_API_PORT").unwrap_or(DEFAULT_API_PORT),
+            mcp_port: env_port("MCP_SERVER_PORT").unwrap_or(DEFAULT_MCP_PORT),
+        }
+    }
+}
+
+/// Run every check
+///
+/// `loaded` is the result of loading the configuration from the environment
+/// and config files; `config` is the configuration actually in effect, which
+/// falls back to defaults when loading failed.
+pub async fn run(
+    loaded: Result<Config, ConfigError>,
+    config: &Config,
+    options: &DoctorOptions,
+) -> DoctorReport {
+    let mut checks = vec![check_config(loaded)];
+    checks.push(check_claude_key(config, options.offline).await);
+    checks.push(check_storage_dir(&config.storage.base_path));
+    checks.push(check_storage_index(&config.storage.base_path));
+    checks.push(check_vector_db(config, options.offline).await);
+    checks.push(check_port(
+        "api_port",
+        options.api_port,
+        "FORTITUDE_API_PORT",
+    ));
+    checks.push(check_port("mcp_port", options.mcp_port, "MCP_SERVER_PORT"));
+    DoctorReport { checks }
+}
+
+fn check_config(loaded: Result<Config, ConfigError>) -> CheckResult {
+    match loaded {
+        Ok(_) => CheckResult::new("config", CheckStatus::Pass, "Configuration is valid"),
+        Err(e) => CheckResult::new(
+            "config",
+            CheckStatus::Fail,
+            format!("Configuration could not be loaded: {e}; defaults are in use"),
+        )
+        .with_fix(
+            "Fix the reported setting in the environment or in the config file \
+             (FORTITUDE_CONFIG or ./fortitude.json; `fortitude config generate` writes a sample)",
+        ),
+    }
+}
+
+async fn check_claude_key(config: &Config, offline: bool) -> CheckResult {
+    const NAME: &str = "claude_key";
+    let Some(claude) = config.claude.as_ref().filter(|c| !c.api_key.is_empty()) else {
+        return CheckResult::new(
+            NAME,
+            CheckStatus::Warn,
+            "No Claude API key configured; research uses the Claude Code provider",
+        )
+        .with_fix("Set CLAUDE_API_KEY or claude.api_key in the config file");
+    };
+
+    let mut core_config = claude.to_core_config();
+    if let Err(e) = core_config.validate() {
+        return CheckResult::new(NAME, CheckStatus::Fail, format!("Claude API key: {e}"))
+            .with_fix("Use an Anthropic API key (it starts with 'sk-') for CLAUDE_API_KEY");
+    }
+    if offline {
+        return CheckResult::new(
+            NAME,
+            CheckStatus::Pass,
+            "Claude API key is well formed (not tested: --offline)",
+        );
+    }
+
+    // One quick attempt is enough to tell a rejected key from a working one
+    core_config.timeout = PROBE_TIMEOUT;
+    core_config.retry.max_retries = 0;
+    let health = match ClaudeClient::new(core_config) {
+        Ok(client) => tokio::time::timeout(PROBE_TIMEOUT, client.health_check()).await,
+        Err(e) => return CheckResult::new(NAME, CheckStatus::Fail, format!("Claude client: {e}")),
+    };
+    match health {
+        Ok(Ok(HealthStatus::Healthy)) => {
+            CheckResult::new(NAME, CheckStatus::Pass, "Claude API key accepted")
+        }
+        Ok(Ok(HealthStatus::Degraded(reason))) => CheckResult::new(
+            NAME,
+            CheckStatus::Warn,
+            format!("Claude API key accepted but the API is degraded: {reason}"),
+        )
+        .with_fix("Retry later or lower claude.rate_limit.requests_per_minute"),
+        Ok(Ok(HealthStatus::Unhealthy(reason))) => CheckResult::new(
+            NAME,
+            CheckStatus::Fail,
+            format!("Claude API key test failed: {reason}"),
+        )
+        .with_fix(
+            "Check that CLAUDE_API_KEY is current and that the API is reachable from this host",
+        ),
+        Ok(Err(e)) => CheckResult::new(
+            NAME,
+            CheckStatus::Fail,
+            format!("Claude API key test failed: {e}"),
+        )
+        .with_fix(
+            "Check that CLAUDE_API_KEY is current and that the API is reachable from this host",
+        ),
+        Err(_) => CheckResult::new(
+            NAME,
+            CheckStatus::Fail,
+            format!(
+                "Claude API did not answer within {}s",
+                PROBE_TIMEOUT.as_secs()
+            ),
+        )
+        .with_fix("Check network access to the Claude API or set CLAUDE_BASE_URL"),
+    }
+}
+
+fn check_storage_dir(base_path: &Path) -> CheckResult {
+    const NAME: &str = "storage_dir";
+    let fix = format!(
+        "Make {} writable by this user, or point --data-dir / FORTITUDE_DATA_DIR at a writable directory",
+        base_path.display()
+    );
+
+    if !base_path.exists() {
+        // The storage layer creates the directory on first use if it can
+        let parent = base_path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        return if is_writable(parent) {
+            CheckResult::new(
+                NAME,
+                CheckStatus::Pass,
+                format!("{} will be created on first use", base_path.display()),
+            )
+        } else {
+            CheckResult::new(
+                NAME,
+                CheckStatus::Fail,
+                format!(
+                    "{} does not exist and {} is not writable",
+                    base_path.display(),
+                    parent.display()
+                ),
+            )
+            .with_fix(fix)
+        };
+    }
+    if !base_path.is_dir() {
+        return CheckResult::new(
+            NAME,
+            CheckStatus::Fail,
+            format!("{} is not a directory", base_path.display()),
+        )
+        .with_fix(fix);
+    }
+    if !is_writable(base_path) {
+        return CheckResult::new(
+            NAME,
+            CheckStatus::Fail,
+            format!("{} is not writable", base_path.display()),
+        )
+        .with_fix(fix);
+    }
+    CheckResult::new(
+        NAME,
+        CheckStatus::Pass,
+        format!("{} is writable", base_path.display()),
+    )
+}
+
+fn check_storage_index(base_path: &Path) -> CheckResult {
+    const NAME: &str = "storage_index";
+    let index_path = base_path.join("index").join("cache_index.json");
+    let contents = match std::fs::read_to_string(&index_path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return CheckResult::new(NAME, CheckStatus::Pass, "No cache index yet");
+        }
+        Err(e) => {
+            return CheckResult::new(
+                NAME,
+                CheckStatus::Fail,
+                format!("Cannot read {}: {e}", index_path.display()),
+            )
+            .with_fix(format!(
+                "Make {} readable by this user",
+                index_path.display()
+            ));
+        }
+    };
+
+    let rebuild = format!(
+        "Remove {}; results are re-indexed as they are cached again",
+        index_path.display()
+    );
+    let entries: HashMap<String, CacheEntry> = match serde_json::from_str(&contents) {
+        Ok(entries) => entries,
+        Err(e) => {
+            return CheckResult::new(
+                NAME,
+                CheckStatus::Fail,
+                format!("Cache index is corrupt: {e}"),
+            )
+            .with_fix(rebuild);
+        }
+    };
+    let missing = entries
+        .values()
+        .filter(|entry| !entry.file_path.exists())
+        .count();
+    if missing > 0 {
+        return CheckResult::new(
+            NAME,
+            CheckStatus::Warn,
+            format!(
+                "{missing} of {} cache index entries point at missing files",
+                entries.len()
+            ),
+        )
+        .with_fix(rebuild);
+    }
+    CheckResult::new(
+        NAME,
+        CheckStatus::Pass,
+        format!("{} cache index entries, all present", entries.len()),
+    )
+}
+
+async fn check_vector_db(config: &Config, offline: bool) -> CheckResult {
+    const NAME: &str = "vector_db";
+    let Some(vector) = &config.vector else {
+        return CheckResult::new(
+            NAME,
+            CheckStatus::Skip,
+            "Vector database not configured; semantic search is unavailable",
+        );
+    };
+    if offline {
+        return CheckResult::new(NAME, CheckStatus::Skip, "Not tested: --offline");
+    }
+
+    let core_config = vector.to_core_config();
+    let health = match QdrantClient::new_lazy(core_config.clone())
+        .and_then(|primary| VectorEndpoints::connect(&core_config, Arc::new(primary)))
+    {
+        Ok(endpoints) => tokio::time::timeout(PROBE_TIMEOUT, endpoints.probe_all()).await,
+        Err(e) => {
+            return CheckResult::new(
+                NAME,
+                CheckStatus::Fail,
+                format!("Vector database configuration is invalid: {e}"),
+            )
+            .with_fix("Correct the vector section of the config file");
+        }
+    };
+    let Ok(health) = health else {
+        return CheckResult::new(
+            NAME,
+            CheckStatus::Fail,
+            format!(
+                "{} did not answer within {}s",
+                vector.url,
+                PROBE_TIMEOUT.as_secs()
+            ),
+        )
+        .with_fix(format!(
+            "Start Qdrant at {} or update vector.url",
+            vector.url
+        ));
+    };
+
+    let unhealthy: Vec<_> = health.iter().filter(|endpoint| !endpoint.healthy).collect();
+    let primary_down = unhealthy
+        .iter()
+        .any(|endpoint| endpoint.url == core_config.url);
+    if primary_down {
+        CheckResult::new(
+            NAME,
+            CheckStatus::Fail,
+            format!("Primary {} is unreachable", core_config.url),
+        )
+        .with_fix(format!(
+            "Start Qdrant at {} or update vector.url",
+            core_config.url
+        ))
+    } else if !unhealthy.is_empty() {
+        let urls: Vec<&str> = unhealthy
+            .iter()
+            .map(|endpoint| endpoint.url.as_str())
+            .collect();
+        CheckResult::new(
+            NAME,
+            CheckStatus::Warn,
+            format!("Replica(s) unreachable: {}", urls.join(", ")),
+        )
+        .with_fix("Start the listed replicas or remove them from vector.replicas")
+    } else {
+        CheckResult::new(
+            NAME,
+            CheckStatus::Pass,
+            format!("{} endpoint(s) healthy", health.len()),
+        )
+    }
+}
+
+fn check_port(name: &str, port: u16, env_var: &str) -> CheckResult {
+    match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(_) => CheckResult::new(name, CheckStatus::Pass, format!("Port {port} is free")),
+        Err(e) => CheckResult::new(
+            name,
+            CheckStatus::Warn,
+            format!("Port {port} is not available ({e}); a server may already be running"),
+        )
+        .with_fix(format!(
+            "Stop whatever is listening on {port} or set {env_var} to a free port"
+        )),
+    }
+}
+
+fn env_port(var: &str) -> Option<u16> {
+    std::env::var(var).ok()?.parse().ok()
+}
+
+/// Whether a file can be created in `dir`
+fn is_writable(dir: &Path) -> bool {
+    let probe = dir.join(format!(".fortitude-doctor-{}", std::process::id()));
+    match std::fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = std::fs::remove_file(&probe);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fix_list_order_and_exit_codes() {
+        let dir = tempfile::tempdir().unwrap();
+        let index_dir = dir.path().join("index");
+        std::fs::create_dir_all(&index_dir).unwrap();
+
+        assert_eq!(check_storage_dir(dir.path()).status, CheckStatus::Pass);
+        assert_eq!(
+            check_storage_dir(&dir.path().join("new")).status,
+            CheckStatus::Pass
+        );
+        assert_eq!(check_storage_index(dir.path()).status, CheckStatus::Pass);
+
+        std::fs::write(index_dir.join("cache_index.json"), "{not json").unwrap();
+        let corrupt = check_storage_index(dir.path());
+        assert_eq!(corrupt.status, CheckStatus::Fail);
+
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let busy = check_port("api_port", port, "FORTITUDE_API_PORT");
+        assert_eq!(busy.status, CheckStatus::Warn);
+
+        let mut report = DoctorReport {
+            checks: vec![
+                CheckResult::new("config", CheckStatus::Pass, "ok"),
+                busy,
+                corrupt,
+            ],
+        };
+        let fixes: Vec<&str> = report.fixes().iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(fixes, vec!["storage_index", "api_port"]);
+        assert_eq!(report.exit_code(false), EXIT_FAILURES);
+
+        report.checks.pop();
+        assert_eq!(report.exit_code(false), EXIT_OK);
+        assert_eq!(report.exit_code(true), EXIT_WARNINGS);
+    }
+}
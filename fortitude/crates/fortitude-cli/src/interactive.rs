@@ -0,0 +1,264 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Interactive shell running CLI commands against services started once
+// Input parsing, persistent history with `!!`/`!N` recall, and the shell's own commands
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Most history entries kept on disk
+pub const MAX_HISTORY: usize = 1000;
+
+/// Help text shown for `help`
+pub const INTERACTIVE_HELP: &str = "\
+Run any fortitude command without the `fortitude` prefix, e.g.
+  research \"tokio vs async-std\" --level advanced
+  search retry --limit 5
+Any other line is researched as a question. Successive research questions
+follow up on the previous answer unless --parent is given.
+
+  context        Show the result the next question follows up on
+  new            Start a fresh line of questions
+  history        List earlier input; !! repeats the last line, !N line N
+  help           Show this help
+  quit           Leave interactive mode";
+
+/// One line of interactive input
+#[derive(Debug, Clone, PartialEq)]
+pub enum InteractiveInput {
+    /// A CLI command, split into arguments
+    Command(Vec<String>),
+    Context,
+    New,
+    History,
+    Help,
+    Quit,
+}
+
+impl InteractiveInput {
+    /// Parse an expanded line; blank lines yield `None`
+    pub fn parse(line: &str) -> Result<Option<Self>, String> {
+        let args = split_args(line)?;
+        let Some(first) = args.first() else {
+            return Ok(None);
+        };
+        let input = match (first.as_str(), args.len()) {
+            ("context", 1) => Self::Context,
+            ("new", 1) => Self::New,
+            ("history", 1) => Self::History,
+            ("help", 1) => Self::Help,
+            ("quit" | "exit", 1) => Self::Quit,
+            _ => Self::Command(args),
+        };
+        Ok(Some(input))
+    }
+}
+
+/// Split a line into arguments the way a POSIX shell would for plain words,
+/// single and double quotes, and backslash escapes
+pub fn split_args(line: &str) -> Result<Vec<String>, String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => return Err("Unterminated single quote".to_string()),
+                    }
+                }
+            }
+            '"' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\')) => current.push(c),
+                            Some(c) => {
+                                current.push('\\');
+                                current.push(c);
+                            }
+                            None => return Err("Unterminated double quote".to_string()),
+                        },
+                        Some(c) => current.push(c),
+                        None => return Err("Unterminated double quote".to_string()),
+                    }
+                }
+            }
+            '\\' => {
+                in_word = true;
+                if let Some(c) = chars.next() {
+                    current.push(c);
+                }
+            }
+            c if c.is_whitespace() => {
+                if in_word {
+                    args.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            c => {
+                in_word = true;
+                current.push(c);
+            }
+        }
+    }
+    if in_word {
+        args.push(current);
+    }
+    Ok(args)
+}
+
+/// Input history, persisted one line per entry
+#[derive(Debug, Default)]
+pub struct History {
+    entries: Vec<String>,
+    path: Option<PathBuf>,
+}
+
+impl History {
+    /// Load history from `path`, appending new entries to it
+    ///
+    /// A missing or unreadable file starts an empty history.
+    pub fn load(path: &Path) -> Self {
+        let entries = std::fs::read_to_string(path)
+            .map(|text| text.lines().map(str::to_string).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let skip = entries.len().saturating_sub(MAX_HISTORY);
+        Self {
+            entries: entries.into_iter().skip(skip).collect(),
+            path: Some(path.to_path_buf()),
+        }
+    }
+
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// Replace a `!!` or `!N` line with the entry it recalls
+    pub fn expand(&self, line: &str) -> Result<String, String> {
+        let line = line.trim();
+        let Some(reference) = line.strip_prefix('!') else {
+            return Ok(line.to_string());
+        };
+        let entry = if reference == "!" {
+            self.entries.last()
+        } else {
+            let n: usize = reference
+                .parse()
+                .map_err(|_| format!("Unknown history reference: {line}"))?;
+            n.checked_sub(1).and_then(|i| self.entries.get(i))
+        };
+        entry
+            .cloned()
+            .ok_or_else(|| format!("No history entry for {line}"))
+    }
+
+    /// Record a line, skipping blanks and immediate repeats
+    pub fn push(&mut self, line: &str) {
+        let line = line.trim();
+        if line.is_empty() || self.entries.last().is_some_and(|last| last == line) {
+            return;
+        }
+        self.entries.push(line.to_string());
+
+        let Some(path) = &self.path else {
+            return;
+        };
+        if self.entries.len() > MAX_HISTORY {
+            self.entries.remove(0);
+            let _ = std::fs::write(path, self.entries.join("\n") + "\n");
+        } else if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+            let _ = writeln!(file, "{line}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_args() {
+        assert_eq!(
+            split_args(r#"research "tokio vs async-std" --level 'advanced'"#),
+            Ok(vec![
+                "research".to_string(),
+                "tokio vs async-std".to_string(),
+                "--level".to_string(),
+                "advanced".to_string(),
+            ])
+        );
+        assert_eq!(
+            split_args(r#"search a\ b "say \"hi\"" ''"#),
+            Ok(vec![
+                "search".to_string(),
+                "a b".to_string(),
+                "say \"hi\"".to_string(),
+                String::new(),
+            ])
+        );
+        assert_eq!(split_args("   "), Ok(vec![]));
+        assert!(split_args("research \"unclosed").is_err());
+    }
+
+    #[test]
+    fn test_parse_interactive_input() {
+        assert_eq!(InteractiveInput::parse(""), Ok(None));
+        assert_eq!(
+            InteractiveInput::parse("quit"),
+            Ok(Some(InteractiveInput::Quit))
+        );
+        assert_eq!(
+            InteractiveInput::parse("history"),
+            Ok(Some(InteractiveInput::History))
+        );
+        assert_eq!(
+            InteractiveInput::parse("new tokio features"),
+            Ok(Some(InteractiveInput::Command(vec![
+                "new".to_string(),
+                "tokio".to_string(),
+                "features".to_string(),
+            ])))
+        );
+    }
+
+    #[test]
+    fn test_history_recall_and_persistence() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history");
+
+        let mut history = History::load(&path);
+        history.push("research tokio");
+        history.push("research tokio");
+        history.push("  list  ");
+        assert_eq!(history.entries(), ["research tokio", "list"]);
+        assert_eq!(history.expand("!!"), Ok("list".to_string()));
+        assert_eq!(history.expand("!1"), Ok("research tokio".to_string()));
+        assert_eq!(history.expand("search x"), Ok("search x".to_string()));
+        assert!(history.expand("!3").is_err());
+        assert!(history.expand("!0").is_err());
+        assert!(history.expand("!x").is_err());
+
+        assert_eq!(History::load(&path).entries(), history.entries());
+    }
+}
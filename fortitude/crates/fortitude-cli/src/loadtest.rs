@@ -0,0 +1,598 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Load test harness behind `fortitude loadtest`
+// Ramps a weighted request mix against the API server and turns latencies and errors into a capacity report
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+
+/// Share of the sustainable rate suggested as the rate limit
+const RATE_LIMIT_HEADROOM: f64 = 0.8;
+
+/// Extra concurrency on top of what the sustainable rate needs
+const WORKER_HEADROOM: f64 = 1.5;
+
+/// Queries submitted once before the ramp so that cached requests hit the cache
+const WARM_QUERIES: &[&str] = &[
+    "How do I implement async traits in Rust?",
+    "Rust error handling with thiserror and anyhow",
+    "Best practices for structuring a Cargo workspace",
+    "How to write integration tests for an axum server",
+    "Tokio task cancellation patterns",
+];
+
+/// Search terms used by search requests
+const SEARCH_TERMS: &[&str] = &["async", "error handling", "testing", "tokio", "cargo"];
+
+/// Kind of request sent by the harness
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestKind {
+    /// Research on a query the server has already answered
+    Cached,
+    /// Research on a query the server has not seen; calls a provider
+    Research,
+    /// Cache search
+    Search,
+}
+
+impl RequestKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RequestKind::Cached => "cached",
+            RequestKind::Research => "research",
+            RequestKind::Search => "search",
+        }
+    }
+}
+
+/// Relative weights of the request kinds, e.g. `cached=70,search=30`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct RequestMix {
+    pub cached: u32,
+    pub research: u32,
+    pub search: u32,
+}
+
+impl Default for RequestMix {
+    /// New research calls a provider for every request, so it is opt-in
+    fn default() -> Self {
+        Self {
+            cached: 70,
+            research: 0,
+            search: 30,
+        }
+    }
+}
+
+impl FromStr for RequestMix {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut mix = RequestMix {
+            cached: 0,
+            research: 0,
+            search: 0,
+        };
+        for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (kind, weight) = part
+                .split_once('=')
+                .ok_or_else(|| format!("Expected kind=weight, got '{part}'"))?;
+            let weight: u32 = weight
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid weight in '{part}'"))?;
+            match kind.trim() {
+                "cached" => mix.cached = weight,
+                "research" => mix.research = weight,
+                "search" => mix.search = weight,
+                other => {
+                    return Err(format!(
+                        "Unknown request kind '{other}' (expected cached, research or search)"
+                    ))
+                }
+            }
+        }
+        if mix.total() == 0 {
+            return Err("The request mix needs at least one non-zero weight".to_string());
+        }
+        Ok(mix)
+    }
+}
+
+impl RequestMix {
+    fn total(&self) -> u32 {
+        self.cached + self.research + self.search
+    }
+
+    /// Kind of the `n`th request, interleaving kinds by weight
+    pub fn pick(&self, n: u64) -> RequestKind {
+        let slot = (n % u64::from(self.total())) as u32;
+        if slot < self.cached {
+            RequestKind::Cached
+        } else if slot < self.cached + self.research {
+            RequestKind::Research
+        } else {
+            RequestKind::Search
+        }
+    }
+}
+
+/// Settings for one load test run
+#[derive(Debug, Clone)]
+pub struct LoadTestOptions {
+    pub server: String,
+    pub token: Option<String>,
+    pub mix: RequestMix,
+    /// Rate of the first step in requests per second
+    pub start_rps: f64,
+    /// Rate added per step
+    pub step_rps: f64,
+    /// Rate of the last step
+    pub max_rps: f64,
+    pub step_duration: Duration,
+    pub request_timeout: Duration,
+    /// Highest error rate a sustainable step may have
+    pub max_error_rate: f64,
+    /// Highest p95 latency a sustainable step may have
+    pub max_p95_ms: f64,
+}
+
+impl Default for LoadTestOptions {
+    fn default() -> Self {
+        Self {
+            server: crate::quota::DEFAULT_API_URL.to_string(),
+            token: None,
+            mix: RequestMix::default(),
+            start_rps: 5.0,
+            step_rps: 5.0,
+            max_rps: 100.0,
+            step_duration: Duration::from_secs(30),
+            request_timeout: Duration::from_secs(30),
+            max_error_rate: 0.01,
+            max_p95_ms: 2000.0,
+        }
+    }
+}
+
+/// Outcome of a single request
+#[derive(Debug, Clone, Copy)]
+pub struct Sample {
+    pub kind: RequestKind,
+    pub latency: Duration,
+    pub ok: bool,
+    /// The server answered 429
+    pub rate_limited: bool,
+}
+
+/// Latency percentiles in milliseconds
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct LatencySummary {
+    pub count: usize,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
+impl LatencySummary {
+    pub fn from_latencies(latencies: impl IntoIterator<Item = Duration>) -> Self {
+        let mut ms: Vec<f64> = latencies
+            .into_iter()
+            .map(|l| l.as_secs_f64() * 1000.0)
+            .collect();
+        if ms.is_empty() {
+            return Self::default();
+        }
+        ms.sort_by(f64::total_cmp);
+        // Nearest-rank percentile
+        let percentile = |p: f64| ms[((p / 100.0 * ms.len() as f64).ceil() as usize).max(1) - 1];
+        Self {
+            count: ms.len(),
+            mean_ms: ms.iter().sum::<f64>() / ms.len() as f64,
+            p50_ms: percentile(50.0),
+            p90_ms: percentile(90.0),
+            p95_ms: percentile(95.0),
+            p99_ms: percentile(99.0),
+            max_ms: ms[ms.len() - 1],
+        }
+    }
+}
+
+/// Results of one ramp step
+#[derive(Debug, Clone, Serialize)]
+pub struct StepReport {
+    pub target_rps: f64,
+    /// Successful requests per second over the step
+    pub achieved_rps: f64,
+    pub requests: usize,
+    pub errors: usize,
+    pub rate_limited: usize,
+    pub error_rate: f64,
+    pub latency: LatencySummary,
+    pub by_kind: BTreeMap<RequestKind, LatencySummary>,
+    /// Error rate and p95 latency stayed within the limits
+    pub sustainable: bool,
+}
+
+impl StepReport {
+    pub fn from_samples(
+        target_rps: f64,
+        elapsed: Duration,
+        samples: &[Sample],
+        options: &LoadTestOptions,
+    ) -> Self {
+        let errors = samples.iter().filter(|s| !s.ok).count();
+        let error_rate = if samples.is_empty() {
+            0.0
+        } else {
+            errors as f64 / samples.len() as f64
+        };
+        let latency = LatencySummary::from_latencies(samples.iter().map(|s| s.latency));
+
+        let mut latencies_by_kind: BTreeMap<RequestKind, Vec<Duration>> = BTreeMap::new();
+        for sample in samples {
+            latencies_by_kind
+                .entry(sample.kind)
+                .or_default()
+                .push(sample.latency);
+        }
+
+        Self {
+            target_rps,
+            achieved_rps: (samples.len() - errors) as f64 / elapsed.as_secs_f64().max(f64::EPSILON),
+            requests: samples.len(),
+            errors,
+            rate_limited: samples.iter().filter(|s| s.rate_limited).count(),
+            error_rate,
+            sustainable: error_rate <= options.max_error_rate
+                && latency.p95_ms <= options.max_p95_ms,
+            latency,
+            by_kind: latencies_by_kind
+                .into_iter()
+                .map(|(kind, latencies)| (kind, LatencySummary::from_latencies(latencies)))
+                .collect(),
+        }
+    }
+}
+
+/// Server settings suggested from the highest sustainable step
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Recommendations {
+    /// Concurrent requests the server must handle (`max_connections`)
+    pub worker_pool_size: u32,
+    /// Global rate limit (`rate_limit.max_requests_per_minute`)
+    pub rate_limit_per_minute: u32,
+}
+
+impl Recommendations {
+    /// Size the pool by Little's law on p95 latency, and keep the limit below capacity
+    pub fn for_step(step: &StepReport) -> Self {
+        let in_flight = step.achieved_rps * step.latency.p95_ms / 1000.0;
+        Self {
+            worker_pool_size: ((in_flight * WORKER_HEADROOM).ceil() as u32).max(1),
+            rate_limit_per_minute: ((step.achieved_rps * 60.0 * RATE_LIMIT_HEADROOM).floor()
+                as u32)
+                .max(1),
+        }
+    }
+}
+
+/// Capacity report for a load test run
+#[derive(Debug, Clone, Serialize)]
+pub struct CapacityReport {
+    pub server: String,
+    pub mix: RequestMix,
+    pub max_error_rate: f64,
+    pub max_p95_ms: f64,
+    pub steps: Vec<StepReport>,
+    /// Achieved rate of the highest sustainable step
+    pub max_sustainable_rps: Option<f64>,
+    pub recommendations: Option<Recommendations>,
+}
+
+impl CapacityReport {
+    pub fn new(options: &LoadTestOptions, steps: Vec<StepReport>) -> Self {
+        let best = steps.iter().rev().find(|s| s.sustainable);
+        Self {
+            server: options.server.clone(),
+            mix: options.mix,
+            max_error_rate: options.max_error_rate,
+            max_p95_ms: options.max_p95_ms,
+            max_sustainable_rps: best.map(|s| s.achieved_rps),
+            recommendations: best.map(Recommendations::for_step),
+            steps,
+        }
+    }
+}
+
+/// Render a capacity report as a table with the suggested settings
+pub fn format_report(report: &CapacityReport) -> String {
+    let mut output = format!(
+        "Load test against {} (mix cached={} research={} search={})\n\n",
+        report.server, report.mix.cached, report.mix.research, report.mix.search
+    );
+    output.push_str(&format!(
+        "{:>8}  {:>8}  {:>8}  {:>7}  {:>8}  {:>8}  {:>8}  {:>8}  {}\n",
+        "TARGET", "ACHIEVED", "REQUESTS", "ERRORS", "P50 MS", "P95 MS", "P99 MS", "MAX MS", ""
+    ));
+    for step in &report.steps {
+        let mut note = if step.sustainable { "ok" } else { "saturated" }.to_string();
+        if step.rate_limited > 0 {
+            note.push_str(&format!(" ({} rate limited)", step.rate_limited));
+        }
+        output.push_str(&format!(
+            "{:>8.1}  {:>8.1}  {:>8}  {:>6.1}%  {:>8.0}  {:>8.0}  {:>8.0}  {:>8.0}  {}\n",
+            step.target_rps,
+            step.achieved_rps,
+            step.requests,
+            step.error_rate * 100.0,
+            step.latency.p50_ms,
+            step.latency.p95_ms,
+            step.latency.p99_ms,
+            step.latency.max_ms,
+            note
+        ));
+    }
+
+    output.push('\n');
+    match (&report.max_sustainable_rps, &report.recommendations) {
+        (Some(rps), Some(recommendations)) => {
+            output.push_str(&format!(
+                "Max sustainable rate: {rps:.1} req/s (error rate <= {:.1}%, p95 <= {:.0} ms)\n",
+                report.max_error_rate * 100.0,
+                report.max_p95_ms
+            ));
+            if let Some(step) = report.steps.iter().rev().find(|s| s.sustainable) {
+                let by_kind: Vec<String> = step
+                    .by_kind
+                    .iter()
+                    .map(|(kind, latency)| {
+                        format!("{} p95 {:.0} ms", kind.as_str(), latency.p95_ms)
+                    })
+                    .collect();
+                output.push_str(&format!("  at that rate: {}\n", by_kind.join(", ")));
+            }
+            output.push_str("Suggested settings:\n");
+            output.push_str(&format!(
+                "  max_connections = {}  (FORTITUDE_API_MAX_CONNECTIONS)\n",
+                recommendations.worker_pool_size
+            ));
+            output.push_str(&format!(
+                "  rate_limit.max_requests_per_minute = {}  (FORTITUDE_API_RATE_LIMIT_PER_MINUTE)\n",
+                recommendations.rate_limit_per_minute
+            ));
+        }
+        _ => output.push_str(
+            "No step was sustainable; lower --start-rps or raise --max-error-rate/--max-p95-ms\n",
+        ),
+    }
+    if report.steps.iter().any(|s| s.rate_limited > 0) {
+        output.push_str(
+            "Note: the server's rate limiter rejected requests; raise it for the test to measure capacity\n",
+        );
+    }
+    output
+}
+
+/// Sends individual requests of each kind
+#[derive(Clone)]
+struct Driver {
+    client: reqwest::Client,
+    server: String,
+    token: Option<String>,
+    run_id: String,
+}
+
+impl Driver {
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let request = self.client.request(
+            method,
+            format!("{}{path}", self.server.trim_end_matches('/')),
+        );
+        match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    fn research(&self, query: &str) -> reqwest::RequestBuilder {
+        self.request(reqwest::Method::POST, "/api/v1/research")
+            .json(&serde_json::json!({ "query": query }))
+    }
+
+    async fn send(&self, kind: RequestKind, n: u64) -> Sample {
+        let request = match kind {
+            RequestKind::Cached => self.research(WARM_QUERIES[n as usize % WARM_QUERIES.len()]),
+            RequestKind::Research => self.research(&format!(
+                "{} (load test {}-{n})",
+                WARM_QUERIES[n as usize % WARM_QUERIES.len()],
+                self.run_id
+            )),
+            RequestKind::Search => self
+                .request(reqwest::Method::GET, "/api/v1/cache/search")
+                .query(&[("query", SEARCH_TERMS[n as usize % SEARCH_TERMS.len()])]),
+        };
+
+        let started = Instant::now();
+        let result = request.send().await;
+        let latency = started.elapsed();
+        let status = result.as_ref().ok().map(|r| r.status());
+        Sample {
+            kind,
+            latency,
+            ok: status.is_some_and(|s| s.is_success()),
+            rate_limited: status == Some(reqwest::StatusCode::TOO_MANY_REQUESTS),
+        }
+    }
+}
+
+/// Warm the cache, then ramp the rate until a step is no longer sustainable
+///
+/// `on_step` is called after every step, e.g. to print progress.
+pub async fn run(
+    options: &LoadTestOptions,
+    mut on_step: impl FnMut(&StepReport),
+) -> Result<CapacityReport, Box<dyn std::error::Error>> {
+    if options.start_rps <= 0.0 || options.step_rps <= 0.0 || options.max_rps < options.start_rps {
+        return Err("Rates must be positive and --max-rps at least --start-rps".into());
+    }
+
+    let driver = Driver {
+        client: reqwest::Client::builder()
+            .timeout(options.request_timeout)
+            .build()?,
+        server: options.server.clone(),
+        token: options.token.clone(),
+        run_id: chrono::Utc::now().format("%Y%m%d%H%M%S").to_string(),
+    };
+
+    if options.mix.cached > 0 {
+        for query in WARM_QUERIES {
+            let response = driver.research(query).send().await?;
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(format!("Warming the cache failed with {status}: {body}").into());
+            }
+        }
+    }
+
+    let mut steps = Vec::new();
+    let mut rps = options.start_rps;
+    let mut n = 0u64;
+    while rps <= options.max_rps + f64::EPSILON {
+        let step = run_step(&driver, options, rps, &mut n).await;
+        on_step(&step);
+        let sustainable = step.sustainable;
+        steps.push(step);
+        if !sustainable {
+            break;
+        }
+        rps += options.step_rps;
+    }
+
+    Ok(CapacityReport::new(options, steps))
+}
+
+/// Send requests at a fixed rate for one step, independent of response times
+async fn run_step(driver: &Driver, options: &LoadTestOptions, rps: f64, n: &mut u64) -> StepReport {
+    let mut ticker = tokio::time::interval(Duration::from_secs_f64(1.0 / rps));
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Burst);
+    let mut in_flight = JoinSet::new();
+    let started = Instant::now();
+
+    while started.elapsed() < options.step_duration {
+        ticker.tick().await;
+        let driver = driver.clone();
+        let (kind, index) = (options.mix.pick(*n), *n);
+        in_flight.spawn(async move { driver.send(kind, index).await });
+        *n += 1;
+    }
+
+    let mut samples = Vec::new();
+    while let Some(sample) = in_flight.join_next().await {
+        if let Ok(sample) = sample {
+            samples.push(sample);
+        }
+    }
+    StepReport::from_samples(rps, started.elapsed(), &samples, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(kind: RequestKind, ms: u64, ok: bool) -> Sample {
+        Sample {
+            kind,
+            latency: Duration::from_millis(ms),
+            ok,
+            rate_limited: false,
+        }
+    }
+
+    #[test]
+    fn test_request_mix() {
+        let mix: RequestMix = "cached=2, research=1,search=1".parse().unwrap();
+        let kinds: Vec<_> = (0..8).map(|n| mix.pick(n)).collect();
+        assert_eq!(
+            kinds.iter().filter(|k| **k == RequestKind::Cached).count(),
+            4
+        );
+        assert_eq!(kinds[2], RequestKind::Research);
+        assert_eq!(kinds[3], RequestKind::Search);
+
+        assert!("cached=0".parse::<RequestMix>().is_err());
+        assert!("writes=1".parse::<RequestMix>().is_err());
+        assert_eq!(RequestMix::default().pick(99), RequestKind::Search);
+    }
+
+    #[test]
+    fn test_latency_percentiles() {
+        let summary = LatencySummary::from_latencies((1..=100).map(Duration::from_millis));
+        assert_eq!(summary.count, 100);
+        assert_eq!(summary.p50_ms, 50.0);
+        assert_eq!(summary.p95_ms, 95.0);
+        assert_eq!(summary.p99_ms, 99.0);
+        assert_eq!(summary.max_ms, 100.0);
+        assert_eq!(
+            LatencySummary::from_latencies([]),
+            LatencySummary::default()
+        );
+    }
+
+    #[test]
+    fn test_capacity_report_uses_last_sustainable_step() {
+        let options = LoadTestOptions {
+            max_error_rate: 0.1,
+            max_p95_ms: 500.0,
+            ..Default::default()
+        };
+        let fast: Vec<_> = (0..100)
+            .map(|_| sample(RequestKind::Cached, 200, true))
+            .collect();
+        let mut slow = fast.clone();
+        slow.extend((0..20).map(|_| sample(RequestKind::Search, 900, false)));
+
+        let steps = vec![
+            StepReport::from_samples(10.0, Duration::from_secs(10), &fast, &options),
+            StepReport::from_samples(20.0, Duration::from_secs(10), &slow, &options),
+        ];
+        assert!(steps[0].sustainable);
+        assert!(!steps[1].sustainable);
+        assert_eq!(steps[1].by_kind[&RequestKind::Search].count, 20);
+
+        let report = CapacityReport::new(&options, steps);
+        assert_eq!(report.max_sustainable_rps, Some(10.0));
+        // 10 req/s at 200 ms keeps 2 requests in flight
+        assert_eq!(
+            report.recommendations,
+            Some(Recommendations {
+                worker_pool_size: 3,
+                rate_limit_per_minute: 480,
+            })
+        );
+
+        let output = format_report(&report);
+        assert!(output.contains("saturated"));
+        assert!(output.contains("max_connections = 3"));
+        assert!(output.contains("max_requests_per_minute = 480"));
+    }
+}
@@ -14,27 +14,63 @@
 
 // ABOUTME: CLI application for the Fortitude research system
 #![allow(clippy::wildcard_in_or_patterns)]
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
 use fortitude_core::{
+    parse_documents,
+    refresh_stale,
+    research_vector_document,
     // Vector services
     vector::{
-        HybridSearchResult as VectorHybridSearchResult, HybridSearchService,
-        LocalEmbeddingService as EmbeddingService, MigrationService, QdrantClient,
-        SearchResult as VectorSearchResult, SemanticSearchService, VectorStorage,
+        export_dataset, import_dataset, verify_dataset, CollectionAnalytics, CollectionStats,
+        ExportOptions, HybridSearchRequest, HybridSearchResult as VectorHybridSearchResult,
+        HybridSearchResultSet, HybridSearchService, ImportOptions, KeywordSearcher,
+        LocalEmbeddingService as EmbeddingService, MigrationConfig, MigrationService,
+        MigrationSource, MigrationState, MigrationStatus, QdrantClient, SearchOptions,
+        SearchResult as VectorSearchResult, SearchStrategy, SemanticSearchService, ValidationLevel,
+        VectorEndpoints, VectorError, VectorStorage,
     },
     BasicClassifier,
     ClaudeResearchEngine,
+    Digest,
+    DigestConfig,
+    DigestFormat,
+    DigestMailer,
+    ExecutionPlan,
     FileStorage,
+    ImportFormat,
+    KnowledgeGraphStore,
+    ObjectStoreStorage,
     PipelineBuilder,
+    ProjectContextCollector,
+    RefreshReport,
+    RelatedResult,
+    ResearchImporter,
+    ResearchOptions,
     ResearchPipeline,
+    SqliteStorage,
+    TimeBudgetReport,
+    KNOWLEDGE_GRAPH_FILE,
+    SEMANTIC_CACHE_QUERY_TAG,
+    SEMANTIC_CACHE_SIMILARITY_TAG,
 };
 use fortitude_types::*;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tracing::{info, warn, Level};
 
+mod admin;
+mod chat;
 mod config;
-use config::Config;
+mod doctor;
+mod interactive;
+mod loadtest;
+mod quota;
+use chat::{ChatCommand, ChatSession};
+use config::{Config, ConfigEditor, StorageBackend};
+use interactive::{History, InteractiveInput};
+
+/// Directory under the data directory holding vector migration checkpoints
+const MIGRATION_STATE_DIR: &str = ".migrations";
 
 // Parameter structs to reduce function argument count
 #[derive(Debug)]
@@ -43,7 +79,6 @@ struct SemanticSearchParams {
     strategy: String,
     limit: usize,
     threshold: f64,
-    #[allow(dead_code)]
     format: String,
     collection: Option<String>,
     explain: bool,
@@ -56,7 +91,6 @@ struct HybridSearchParams {
     semantic_weight: f64,
     limit: usize,
     threshold: f64,
-    #[allow(dead_code)]
     format: String,
     collection: Option<String>,
     explain: bool,
@@ -102,13 +136,13 @@ enum Commands {
         #[arg(short, long, default_value = "markdown")]
         format: String,
 
-        /// Technology stack
-        #[arg(short, long, default_value = "rust")]
-        technology: String,
+        /// Technology stack [default: rust, or detected from --project-path]
+        #[arg(short, long)]
+        technology: Option<String>,
 
-        /// Project type (cli, web, library, etc.)
-        #[arg(short, long, default_value = "library")]
-        project_type: String,
+        /// Project type (cli, web, library, etc.) [default: library, or detected from --project-path]
+        #[arg(short, long)]
+        project_type: Option<String>,
 
         /// Framework tags (comma-separated)
         #[arg(long)]
@@ -122,6 +156,10 @@ enum Commands {
         #[arg(long)]
         no_cache: bool,
 
+        /// Research again instead of serving a cached result for a near-duplicate query
+        #[arg(long)]
+        no_semantic_cache: bool,
+
         /// Enable advanced multi-dimensional classification with context detection
         #[arg(
             long,
@@ -150,6 +188,86 @@ enum Commands {
             help = "Continue processing even if advanced classification fails"
         )]
         graceful_degradation: bool,
+
+        /// Show the execution plan without calling any provider
+        #[arg(long)]
+        dry_run: bool,
+
+        /// ID of an earlier result this question follows up on
+        #[arg(long, value_name = "ID")]
+        parent: Option<String>,
+
+        /// Rust source file whose items are summarized into the research context
+        #[arg(long, value_name = "FILE")]
+        code: Option<PathBuf>,
+
+        /// Rust project whose manifest, README and public API fill in the
+        /// domain context and are summarized into the research context
+        #[arg(long, value_name = "DIR")]
+        project_path: Option<PathBuf>,
+
+        /// Prompt-budget profile (concise, standard, thorough or a configured one)
+        #[arg(long, value_name = "PROFILE")]
+        budget_profile: Option<String>,
+
+        /// Return the best answer achievable within this many milliseconds
+        #[arg(long, value_name = "MS", value_parser = clap::value_parser!(u64).range(100..=600_000))]
+        time_budget_ms: Option<u64>,
+
+        /// Run follow-up queries for low-confidence sections of the answer
+        #[arg(long, conflicts_with = "time_budget_ms")]
+        deep: bool,
+
+        /// Research even when the duplicate check finds earlier research on a
+        /// nearly identical question
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Start an interactive research chat that carries context between turns
+    Chat {
+        /// Audience level (beginner, intermediate, advanced)
+        #[arg(short, long, default_value = "intermediate")]
+        level: String,
+
+        /// Domain context (rust, web, devops, etc.)
+        #[arg(long, default_value = "rust")]
+        domain: String,
+
+        /// Technology stack
+        #[arg(short, long, default_value = "rust")]
+        technology: String,
+
+        /// Project type (cli, web, library, etc.)
+        #[arg(short, long, default_value = "library")]
+        project_type: String,
+
+        /// ID of an earlier chat turn or research result to continue from
+        #[arg(long, value_name = "ID")]
+        resume: Option<String>,
+
+        /// Print answers without terminal styling
+        #[arg(long)]
+        no_color: bool,
+    },
+
+    /// Run commands in an interactive shell that keeps services running between them
+    ///
+    /// Storage, the research pipeline and vector services start once instead
+    /// of per command. Successive research questions follow up on the
+    /// previous answer.
+    Interactive {
+        /// ID of an earlier research result the first question follows up on
+        #[arg(long, value_name = "ID")]
+        resume: Option<String>,
+    },
+
+    /// Print a shell completion script
+    ///
+    /// For example `fortitude completions bash > ~/.local/share/bash-completion/completions/fortitude`.
+    Completions {
+        /// Shell to generate completions for
+        shell: clap_complete::Shell,
     },
 
     /// List cached research results
@@ -215,8 +333,75 @@ enum Commands {
     /// Clean up expired cache entries
     Cleanup {
         /// Show what would be deleted without actually deleting
-        #[arg(short, long)]
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Re-run queries whose cached results are past their freshness window
+    Refresh {
+        /// List stale entries without researching them again
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Most entries refreshed per run (defaults to pipeline.freshness.max_refreshes_per_run)
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Keep running, refreshing every this many minutes
+        #[arg(long)]
+        every: Option<u64>,
+
+        /// Output format (table, json)
+        #[arg(long, default_value = "table")]
+        output_format: String,
+    },
+
+    /// Summarize research results stored in the last hours
+    Digest {
+        /// Hours of results to include (defaults to digest.lookback_hours)
+        #[arg(long)]
+        hours: Option<u32>,
+
+        /// Printed format (markdown, html)
+        #[arg(long, default_value = "markdown")]
+        format: String,
+
+        /// Email the digest with the SMTP settings in digest.email instead of printing it
+        #[arg(long)]
+        send: bool,
+
+        /// Keep running, producing a digest of the last period every this many hours
+        #[arg(long)]
+        every: Option<u64>,
+    },
+
+    /// Explore how stored research results relate to each other
+    Knowledge {
+        #[command(subcommand)]
+        knowledge_command: KnowledgeCommand,
+    },
+
+    /// Import externally produced research into the research cache
+    Import {
+        /// JSONL or Markdown files, or directories searched for them
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+
+        /// Document format (jsonl, markdown); inferred from the file extension by default
+        #[arg(long)]
+        format: Option<String>,
+
+        /// Also index imported results in the vector database
+        #[arg(long)]
+        index: bool,
+
+        /// Show what would be imported without writing anything
+        #[arg(long)]
         dry_run: bool,
+
+        /// Output format (table, json)
+        #[arg(long, default_value = "table")]
+        output_format: String,
     },
 
     /// Configuration management
@@ -225,6 +410,103 @@ enum Commands {
         config_command: ConfigCommand,
     },
 
+    /// Show rate limits, token quota and monthly budget for your API key
+    Quota {
+        /// API server URL (defaults to FORTITUDE_API_URL or http://127.0.0.1:3000)
+        #[arg(long)]
+        server: Option<String>,
+
+        /// Bearer token (defaults to FORTITUDE_API_TOKEN)
+        #[arg(long)]
+        token: Option<String>,
+
+        /// Output format (table, json)
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+
+    /// Diagnose the installation and print a prioritized fix-it list
+    ///
+    /// Exits 0 when all checks pass, 1 for warnings with --strict and 2 when
+    /// a check fails, so it can gate CI jobs.
+    Doctor {
+        /// Skip checks that contact the Claude API or vector database
+        #[arg(long)]
+        offline: bool,
+
+        /// Treat warnings as failures for the exit code
+        #[arg(long)]
+        strict: bool,
+
+        /// API server port to check (defaults to FORTITUDE_API_PORT or 3000)
+        #[arg(long)]
+        api_port: Option<u16>,
+
+        /// MCP server port to check (defaults to MCP_SERVER_PORT or 8080)
+        #[arg(long)]
+        mcp_port: Option<u16>,
+
+        /// Output format (table, json)
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+
+    /// Server administration
+    Admin {
+        #[command(subcommand)]
+        admin_command: AdminCommand,
+    },
+
+    /// Ramp load against the API server and report its sustainable capacity
+    ///
+    /// Each step sends requests at a fixed rate; the ramp stops at the first
+    /// step whose error rate or p95 latency exceeds the limits.
+    Loadtest {
+        /// API server URL (defaults to FORTITUDE_API_URL or http://127.0.0.1:3000)
+        #[arg(long)]
+        server: Option<String>,
+
+        /// Bearer token (defaults to FORTITUDE_API_TOKEN)
+        #[arg(long)]
+        token: Option<String>,
+
+        /// Request mix as kind=weight pairs; `research` sends uncached queries to providers
+        #[arg(long, default_value = "cached=70,search=30")]
+        mix: String,
+
+        /// Requests per second of the first step
+        #[arg(long, default_value = "5")]
+        start_rps: f64,
+
+        /// Requests per second added per step
+        #[arg(long, default_value = "5")]
+        step_rps: f64,
+
+        /// Requests per second of the last step
+        #[arg(long, default_value = "100")]
+        max_rps: f64,
+
+        /// Duration of each step in seconds
+        #[arg(long, default_value = "30")]
+        step_seconds: u64,
+
+        /// Highest error rate (0.0-1.0) of a sustainable step
+        #[arg(long, default_value = "0.01")]
+        max_error_rate: f64,
+
+        /// Highest p95 latency in milliseconds of a sustainable step
+        #[arg(long, default_value = "2000")]
+        max_p95_ms: f64,
+
+        /// Also write the report as JSON to this file
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// Output format (table, json)
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+
     /// Vector database operations
     Vector {
         #[command(subcommand)]
@@ -250,7 +532,7 @@ enum Commands {
 
         /// Output format (table, json, detailed)
         #[arg(short, long, default_value = "table")]
-        _format: String,
+        format: String,
 
         /// Collection to search in
         #[arg(short, long)]
@@ -284,7 +566,7 @@ enum Commands {
 
         /// Output format (table, json, detailed)
         #[arg(short, long, default_value = "table")]
-        _format: String,
+        format: String,
 
         /// Collection to search in
         #[arg(short, long)]
@@ -310,7 +592,7 @@ enum Commands {
 
         /// Output format (table, json, detailed)
         #[arg(short, long, default_value = "table")]
-        _format: String,
+        format: String,
 
         /// Collection to search in
         #[arg(short, long)]
@@ -341,13 +623,13 @@ enum VectorCommand {
 
     /// Check vector database health
     Health {
-        /// Detailed health check
+        /// Show the last error for unhealthy endpoints
         #[arg(long)]
-        _detailed: bool,
+        detailed: bool,
 
         /// Output format (table, json)
         #[arg(short, long, default_value = "table")]
-        _format: String,
+        format: String,
     },
 
     /// Show vector database statistics
@@ -358,11 +640,11 @@ enum VectorCommand {
 
         /// Output format (table, json)
         #[arg(short, long, default_value = "table")]
-        _format: String,
+        format: String,
 
         /// Show detailed statistics
         #[arg(long)]
-        _detailed: bool,
+        detailed: bool,
     },
 
     /// Migrate data to vector database
@@ -448,11 +730,11 @@ enum VectorCommand {
 
         /// Output format (table, json)
         #[arg(short, long, default_value = "table")]
-        _format: String,
+        format: String,
 
         /// Include performance metrics
         #[arg(long)]
-        _performance: bool,
+        performance: bool,
     },
 
     /// Setup initial vector database
@@ -462,7 +744,7 @@ enum VectorCommand {
         collection: Option<String>,
 
         /// Vector dimensions
-        #[arg(short, long)]
+        #[arg(long)]
         dimensions: Option<usize>,
 
         /// Distance metric (cosine, euclidean, dot)
@@ -474,6 +756,46 @@ enum VectorCommand {
         force: bool,
     },
 
+    /// Export collections to a portable dataset (JSONL per collection plus manifest)
+    Export {
+        /// Dataset directory to write
+        output: PathBuf,
+
+        /// Collections to export (defaults to the configured collection)
+        #[arg(short, long, value_delimiter = ',')]
+        collection: Vec<String>,
+
+        /// Points read per page
+        #[arg(short, long, default_value = "256")]
+        batch_size: usize,
+
+        /// Continue an interrupted export in the same directory
+        #[arg(long)]
+        resume: bool,
+    },
+
+    /// Import a portable dataset into the configured vector database
+    Import {
+        /// Dataset directory written by `vector export`
+        input: PathBuf,
+
+        /// Points written per batch
+        #[arg(short, long, default_value = "256")]
+        batch_size: usize,
+
+        /// Continue an interrupted import of the same dataset
+        #[arg(long)]
+        resume: bool,
+
+        /// Import vectors produced by a different embedding model
+        #[arg(long)]
+        allow_model_mismatch: bool,
+
+        /// Only check the manifest, checksums and compatibility
+        #[arg(long)]
+        verify_only: bool,
+    },
+
     /// Index status and operations
     Index {
         #[command(subcommand)]
@@ -491,7 +813,7 @@ enum IndexCommand {
 
         /// Output format (table, json)
         #[arg(short, long, default_value = "table")]
-        _format: String,
+        format: String,
     },
 
     /// Rebuild index
@@ -513,6 +835,50 @@ enum IndexCommand {
     },
 }
 
+#[derive(Subcommand)]
+enum KnowledgeCommand {
+    /// Show the results linked to a query, up to a number of links away
+    Graph {
+        /// Cache key of a result, or words of the questions to start from
+        #[arg(long)]
+        query: String,
+
+        /// Links followed from the matching results
+        #[arg(long, default_value_t = 2)]
+        depth: usize,
+
+        /// Rebuild the graph even if no results changed since it was saved
+        #[arg(long)]
+        rebuild: bool,
+
+        /// Output format (table, json)
+        #[arg(long, default_value = "table")]
+        output_format: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum AdminCommand {
+    /// List requests the server is handling, or cancel one
+    Inflight {
+        /// Cancel the in-flight request with this ID instead of listing
+        #[arg(long)]
+        cancel: Option<String>,
+
+        /// API server URL (defaults to FORTITUDE_API_URL or http://127.0.0.1:3000)
+        #[arg(long)]
+        server: Option<String>,
+
+        /// Bearer token with admin permission (defaults to FORTITUDE_API_TOKEN)
+        #[arg(long)]
+        token: Option<String>,
+
+        /// Output format (table, json)
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+}
+
 #[derive(Subcommand)]
 enum ConfigCommand {
     /// Show current configuration
@@ -547,6 +913,10 @@ enum ConfigCommand {
 
         /// Configuration value
         value: String,
+
+        /// Configuration file to modify (defaults to the file in use, or ./fortitude.json)
+        #[arg(short, long)]
+        file: Option<PathBuf>,
     },
 }
 
@@ -554,6 +924,16 @@ enum ConfigCommand {
 async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
+    if let Commands::Completions { shell } = cli.command {
+        clap_complete::generate(
+            shell,
+            &mut Cli::command(),
+            "fortitude",
+            &mut std::io::stdout(),
+        );
+        return Ok(());
+    }
+
     // Setup logging
     let log_level = if cli.verbose {
         Level::DEBUG
@@ -579,10 +959,53 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         config.storage.base_path = cli.data_dir;
     }
 
+    // The doctor must work even when the application cannot start
+    if let Commands::Doctor {
+        offline,
+        strict,
+        api_port,
+        mcp_port,
+        format,
+    } = &cli.command
+    {
+        let defaults = doctor::DoctorOptions::default();
+        let options = doctor::DoctorOptions {
+            offline: *offline,
+            api_port: api_port.unwrap_or(defaults.api_port),
+            mcp_port: mcp_port.unwrap_or(defaults.mcp_port),
+        };
+        let report = doctor::run(Config::load(), &config, &options).await;
+        match format.as_str() {
+            "json" => println!("{}", serde_json::to_string_pretty(&report)?),
+            _ => report.print_table(),
+        }
+        std::process::exit(report.exit_code(*strict));
+    }
+
     // Initialize the application
     let app = App::new(config.clone()).await?;
 
-    match cli.command {
+    if let Commands::Interactive { resume } = cli.command {
+        if let Err(e) = app.handle_interactive(resume).await {
+            eprintln!("Error: {e}");
+            return Err(e);
+        }
+        return Ok(());
+    }
+    run_command(&app, &config, cli.command).await?;
+
+    Ok(())
+}
+
+/// Run one command against a started application
+///
+/// Returns the ID of the stored result for research commands.
+async fn run_command(
+    app: &App,
+    config: &Config,
+    command: Commands,
+) -> std::result::Result<Option<String>, Box<dyn std::error::Error>> {
+    match command {
         Commands::Research {
             topic,
             level,
@@ -593,12 +1016,21 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
             frameworks,
             tags,
             no_cache,
+            no_semantic_cache,
             advanced_classification,
             context_detection,
             context_threshold,
             graceful_degradation,
+            dry_run,
+            parent,
+            code,
+            project_path,
+            budget_profile,
+            time_budget_ms,
+            deep,
+            force,
         } => {
-            if let Err(e) = app
+            match app
                 .handle_research(
                     topic,
                     level,
@@ -609,12 +1041,51 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
                     frameworks,
                     tags,
                     !no_cache,
+                    no_semantic_cache,
                     advanced_classification,
                     context_detection,
                     context_threshold,
                     graceful_degradation,
+                    dry_run,
+                    parent,
+                    code,
+                    project_path,
+                    budget_profile,
+                    time_budget_ms,
+                    deep,
+                    force,
                 )
                 .await
+            {
+                Ok(result_id) => return Ok(result_id),
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    return Err(e);
+                }
+            }
+        }
+        Commands::Chat {
+            level,
+            domain,
+            technology,
+            project_type,
+            resume,
+            no_color,
+        } => {
+            let audience_context = AudienceContext {
+                level,
+                domain,
+                format: "markdown".to_string(),
+            };
+            let domain_context = DomainContext {
+                technology,
+                project_type,
+                frameworks: vec![],
+                tags: vec![],
+            };
+            if let Err(e) = app
+                .handle_chat(audience_context, domain_context, resume, !no_color)
+                .await
             {
                 eprintln!("Error: {e}");
                 return Err(e);
@@ -663,50 +1134,159 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
                 return Err(e);
             }
         }
-        Commands::Config { config_command } => {
-            if let Err(e) = handle_config_command(config_command, &config).await {
+        Commands::Refresh {
+            dry_run,
+            limit,
+            every,
+            output_format,
+        } => {
+            if let Err(e) = app
+                .handle_refresh(dry_run, limit, every, &output_format)
+                .await
+            {
                 eprintln!("Error: {e}");
                 return Err(e);
             }
         }
-        Commands::Vector { vector_command } => {
-            if let Err(e) = app.handle_vector_command(vector_command).await {
+        Commands::Digest {
+            hours,
+            format,
+            send,
+            every,
+        } => {
+            if let Err(e) = app.handle_digest(hours, &format, send, every).await {
                 eprintln!("Error: {e}");
                 return Err(e);
             }
         }
-        Commands::SemanticSearch {
-            query,
-            strategy,
-            limit,
-            threshold,
-            _format,
-            collection,
-            explain,
+        Commands::Knowledge { knowledge_command } => {
+            if let Err(e) = app.handle_knowledge_command(knowledge_command).await {
+                eprintln!("Error: {e}");
+                return Err(e);
+            }
+        }
+        Commands::Import {
+            paths,
+            format,
+            index,
+            dry_run,
+            output_format,
         } => {
             if let Err(e) = app
-                .handle_semantic_search(SemanticSearchParams {
-                    query,
-                    strategy,
-                    limit,
-                    threshold,
-                    format: _format,
-                    collection,
-                    explain,
-                })
+                .handle_import(paths, format, index, dry_run, &output_format)
                 .await
             {
                 eprintln!("Error: {e}");
                 return Err(e);
             }
         }
-        Commands::HybridSearch {
-            query,
-            keyword_weight,
-            semantic_weight,
-            limit,
+        Commands::Config { config_command } => {
+            if let Err(e) = handle_config_command(config_command, config).await {
+                eprintln!("Error: {e}");
+                return Err(e);
+            }
+        }
+        Commands::Quota {
+            server,
+            token,
+            format,
+        } => {
+            if let Err(e) = handle_quota_command(server, token, &format).await {
+                eprintln!("Error: {e}");
+                return Err(e);
+            }
+        }
+        Commands::Doctor { .. } => {
+            // Doctor runs before the application starts, so only interactive mode gets here
+            return Err("Run `fortitude doctor` outside interactive mode".into());
+        }
+        Commands::Completions { shell } => {
+            clap_complete::generate(
+                shell,
+                &mut Cli::command(),
+                "fortitude",
+                &mut std::io::stdout(),
+            );
+        }
+        Commands::Interactive { .. } => {
+            return Err("Already in interactive mode".into());
+        }
+        Commands::Admin { admin_command } => {
+            if let Err(e) = handle_admin_command(admin_command).await {
+                eprintln!("Error: {e}");
+                return Err(e);
+            }
+        }
+        Commands::Loadtest {
+            server,
+            token,
+            mix,
+            start_rps,
+            step_rps,
+            max_rps,
+            step_seconds,
+            max_error_rate,
+            max_p95_ms,
+            output,
+            format,
+        } => {
+            let options = loadtest::LoadTestOptions {
+                server: server
+                    .or_else(|| std::env::var("FORTITUDE_API_URL").ok())
+                    .unwrap_or_else(|| quota::DEFAULT_API_URL.to_string()),
+                token: token.or_else(|| std::env::var("FORTITUDE_API_TOKEN").ok()),
+                mix: mix.parse()?,
+                start_rps,
+                step_rps,
+                max_rps,
+                step_duration: std::time::Duration::from_secs(step_seconds),
+                max_error_rate,
+                max_p95_ms,
+                ..Default::default()
+            };
+            if let Err(e) = handle_loadtest_command(options, output, &format).await {
+                eprintln!("Error: {e}");
+                return Err(e);
+            }
+        }
+        Commands::Vector { vector_command } => {
+            if let Err(e) = app.handle_vector_command(vector_command).await {
+                eprintln!("Error: {e}");
+                return Err(e);
+            }
+        }
+        Commands::SemanticSearch {
+            query,
+            strategy,
+            limit,
+            threshold,
+            format,
+            collection,
+            explain,
+        } => {
+            if let Err(e) = app
+                .handle_semantic_search(SemanticSearchParams {
+                    query,
+                    strategy,
+                    limit,
+                    threshold,
+                    format,
+                    collection,
+                    explain,
+                })
+                .await
+            {
+                eprintln!("Error: {e}");
+                return Err(e);
+            }
+        }
+        Commands::HybridSearch {
+            query,
+            keyword_weight,
+            semantic_weight,
+            limit,
             threshold,
-            _format,
+            format,
             collection,
             explain,
         } => {
@@ -717,7 +1297,7 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
                     semantic_weight,
                     limit,
                     threshold,
-                    format: _format,
+                    format,
                     collection,
                     explain,
                 })
@@ -731,11 +1311,11 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
             content,
             limit,
             threshold,
-            _format,
+            format,
             collection,
         } => {
             if let Err(e) = app
-                .handle_find_similar(content, limit, threshold, _format, collection)
+                .handle_find_similar(content, limit, threshold, format, collection)
                 .await
             {
                 eprintln!("Error: {e}");
@@ -744,6 +1324,203 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    Ok(None)
+}
+
+/// Overwrite the current line with a collection's transfer progress
+fn print_transfer_progress(collection: &str, points: u64) {
+    use std::io::Write;
+    print!("\r{collection}: {points} points");
+    let _ = std::io::stdout().flush();
+}
+
+/// Print the stale entries of a refresh run and what changed in their answers
+fn print_refresh_report(
+    report: &RefreshReport,
+    dry_run: bool,
+    output_format: &str,
+) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    if output_format == "json" {
+        println!("{}", serde_json::to_string_pretty(report)?);
+        return Ok(());
+    }
+
+    if dry_run {
+        println!("DRY RUN: Nothing was researched");
+    }
+    for outcome in &report.outcomes {
+        let label = match (&outcome.diff, &outcome.error) {
+            (_, Some(_)) => "failed     ",
+            (Some(diff), _) if diff.significant => "CHANGED    ",
+            (Some(_), _) => "refreshed  ",
+            (None, None) => "stale      ",
+        };
+        println!(
+            "{label}{}  {} ({} days old)",
+            outcome.research_type, outcome.query, outcome.age_days
+        );
+        if let Some(diff) = &outcome.diff {
+            println!("           similarity {:.2}", diff.similarity);
+            for source in &diff.added_sources {
+                println!("           + {source}");
+            }
+            for source in &diff.removed_sources {
+                println!("           - {source}");
+            }
+        }
+        if let Some(error) = &outcome.error {
+            println!("           {error}");
+        }
+    }
+    println!();
+    println!(
+        "{} of {} entries stale, {} significant changes, {} failed, {} deferred",
+        report.stale,
+        report.checked,
+        report.significant_changes().count(),
+        report.failures().count(),
+        report.deferred
+    );
+    Ok(())
+}
+
+/// List earlier research on a nearly identical question and ask whether to research anyway
+///
+/// Without a terminal to ask on, the research is refused.
+fn confirm_despite_similar(
+    similar: &[RelatedResult],
+) -> std::result::Result<bool, Box<dyn std::error::Error>> {
+    use std::io::{BufRead, IsTerminal, Write};
+
+    println!("Similar research already exists:");
+    for related in similar {
+        println!(
+            "  {} ({}, similarity {:.2})",
+            related.query, related.id, related.relevance
+        );
+    }
+    if !std::io::stdin().is_terminal() {
+        return Err("similar research already exists; pass --force to research anyway".into());
+    }
+
+    print!("Research it anyway? [y/N] ");
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Files to import with their formats; directories are searched recursively
+///
+/// An explicit `format` applies to every file. Otherwise files are matched by
+/// extension, and a named file with an unknown extension is an error.
+fn collect_import_files(
+    paths: &[PathBuf],
+    format: Option<ImportFormat>,
+) -> std::result::Result<Vec<(PathBuf, ImportFormat)>, Box<dyn std::error::Error>> {
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            let mut entries = std::fs::read_dir(path)?
+                .map(|entry| entry.map(|e| e.path()))
+                .collect::<std::io::Result<Vec<_>>>()?;
+            entries.sort();
+            for entry in entries {
+                if entry.is_dir() {
+                    files.extend(collect_import_files(&[entry], format)?);
+                } else if let Some(file_format) = ImportFormat::from_path(&entry) {
+                    files.push((entry, format.unwrap_or(file_format)));
+                }
+            }
+        } else {
+            let file_format = format
+                .or_else(|| ImportFormat::from_path(path))
+                .ok_or_else(|| {
+                    format!(
+                        "Cannot infer the format of {}; pass --format",
+                        path.display()
+                    )
+                })?;
+            files.push((path.clone(), file_format));
+        }
+    }
+    Ok(files)
+}
+
+async fn handle_quota_command(
+    server: Option<String>,
+    token: Option<String>,
+    format: &str,
+) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let server = server
+        .or_else(|| std::env::var("FORTITUDE_API_URL").ok())
+        .unwrap_or_else(|| quota::DEFAULT_API_URL.to_string());
+    let token = token.or_else(|| std::env::var("FORTITUDE_API_TOKEN").ok());
+
+    let limits = quota::fetch_limits(&server, token.as_deref()).await?;
+    match format {
+        "json" => println!("{}", serde_json::to_string_pretty(&limits)?),
+        _ => print!("{}", quota::format_limits(&limits)),
+    }
+    Ok(())
+}
+
+async fn handle_loadtest_command(
+    options: loadtest::LoadTestOptions,
+    output: Option<PathBuf>,
+    format: &str,
+) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let report = loadtest::run(&options, |step| {
+        eprintln!(
+            "{:.1} req/s: {} requests, {:.1}% errors, p95 {:.0} ms{}",
+            step.target_rps,
+            step.requests,
+            step.error_rate * 100.0,
+            step.latency.p95_ms,
+            if step.sustainable { "" } else { " (saturated)" }
+        );
+    })
+    .await?;
+
+    if let Some(path) = output {
+        std::fs::write(&path, serde_json::to_string_pretty(&report)?)?;
+        eprintln!("Report written to {}", path.display());
+    }
+    match format {
+        "json" => println!("{}", serde_json::to_string_pretty(&report)?),
+        _ => print!("{}", loadtest::format_report(&report)),
+    }
+    Ok(())
+}
+
+async fn handle_admin_command(
+    admin_command: AdminCommand,
+) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    match admin_command {
+        AdminCommand::Inflight {
+            cancel,
+            server,
+            token,
+            format,
+        } => {
+            let server = server
+                .or_else(|| std::env::var("FORTITUDE_API_URL").ok())
+                .unwrap_or_else(|| quota::DEFAULT_API_URL.to_string());
+            let token = token.or_else(|| std::env::var("FORTITUDE_API_TOKEN").ok());
+
+            if let Some(id) = cancel {
+                admin::cancel_inflight(&server, token.as_deref(), &id).await?;
+                println!("Cancellation requested for {id}");
+                return Ok(());
+            }
+
+            let inflight = admin::fetch_inflight(&server, token.as_deref()).await?;
+            match format.as_str() {
+                "json" => println!("{}", serde_json::to_string_pretty(&inflight)?),
+                _ => print!("{}", admin::format_inflight(&inflight)),
+            }
+        }
+    }
     Ok(())
 }
 
@@ -800,10 +1577,18 @@ async fn handle_config_command(
             }
         }
 
-        ConfigCommand::Set { key, value } => {
-            println!("Setting configuration key '{key}' to '{value}'");
-            println!("Note: Configuration modification is not yet implemented.");
-            println!("Please edit the configuration file directly or use environment variables.");
+        ConfigCommand::Set { key, value, file } => {
+            let path = file
+                .or_else(Config::file_path)
+                .unwrap_or_else(|| PathBuf::from("./fortitude.json"));
+            let mut editor = ConfigEditor::open(&path)?;
+            editor.set(&key, &value)?;
+            let backup = editor.save()?;
+
+            println!("Set '{key}' in {}", editor.path().display());
+            if let Some(backup) = backup {
+                println!("Previous version saved to {}", backup.display());
+            }
         }
     }
 
@@ -814,14 +1599,35 @@ struct App {
     pipeline: ResearchPipeline,
     config: Config,
     // Vector services (optional)
-    qdrant_client: Option<QdrantClient>,
+    qdrant_client: Option<Arc<QdrantClient>>,
     #[allow(dead_code)] // TODO: Implement vector storage CLI commands
     vector_storage: Option<VectorStorage>,
-    semantic_search: Option<SemanticSearchService>,
-    hybrid_search: Option<HybridSearchService>,
+    semantic_search: Option<Arc<SemanticSearchService>>,
+    migration_service: Option<MigrationService>,
+    embedding_service: Option<Arc<EmbeddingService>>,
+    /// Why vector services could not be set up, reported by vector commands
+    vector_unavailable_reason: Option<String>,
+}
+
+/// Vector services built from the vector database configuration
+#[derive(Default)]
+struct VectorServices {
+    qdrant_client: Option<Arc<QdrantClient>>,
+    vector_storage: Option<VectorStorage>,
+    semantic_search: Option<Arc<SemanticSearchService>>,
     migration_service: Option<MigrationService>,
-    #[allow(dead_code)] // TODO: Implement embedding service CLI commands
-    embedding_service: Option<EmbeddingService>,
+    embedding_service: Option<Arc<EmbeddingService>>,
+    unavailable_reason: Option<String>,
+}
+
+impl VectorServices {
+    fn unavailable(qdrant_client: Option<Arc<QdrantClient>>, reason: String) -> Self {
+        Self {
+            qdrant_client,
+            unavailable_reason: Some(reason),
+            ..Default::default()
+        }
+    }
 }
 
 impl App {
@@ -832,16 +1638,26 @@ impl App {
         );
 
         // Setup storage
-        let storage_config = StorageConfig {
-            base_path: config.storage.base_path.clone(),
-            cache_expiration_seconds: config.storage.cache_expiration_seconds,
-            max_cache_size_bytes: config.storage.max_cache_size_bytes,
-            enable_content_addressing: config.storage.enable_content_addressing,
-            index_update_interval_seconds: config.storage.index_update_interval_seconds,
+        let storage: Arc<dyn Storage + Send + Sync> = match config.storage.backend {
+            StorageBackend::File => {
+                Arc::new(FileStorage::new(config.storage.to_core_config()).await?)
+            }
+            StorageBackend::Sqlite => Arc::new(
+                SqliteStorage::open(
+                    config.storage.sqlite_file(),
+                    config.storage.to_core_config(),
+                )
+                .await?,
+            ),
+            StorageBackend::ObjectStore => Arc::new(
+                ObjectStoreStorage::open(
+                    config.storage.object_store.clone().unwrap_or_default(),
+                    config.storage.to_core_config(),
+                )
+                .await?,
+            ),
         };
 
-        let storage = FileStorage::new(storage_config).await?;
-
         // Setup classifier
         let classification_config = ClassificationConfig {
             default_threshold: config.classification.default_threshold,
@@ -853,49 +1669,20 @@ impl App {
         let mut pipeline_builder = PipelineBuilder::new()
             .with_caching(config.pipeline.enable_caching)
             .with_context_detection(config.classification.enable_context_detection)
-            .with_advanced_classification(config.classification.enable_advanced);
+            .with_advanced_classification(config.classification.enable_advanced)
+            .with_prompt_budget(config.pipeline.prompt_budget.clone())
+            .with_content_filter(config.pipeline.content_filter.clone())
+            .with_markdown(config.pipeline.markdown.clone())
+            .with_deepening(config.pipeline.deepening.clone())
+            .with_semantic_cache(config.pipeline.semantic_cache.clone())
+            .with_related_results(config.pipeline.related_results.clone())
+            .with_duplicate_check(config.pipeline.duplicate_check.clone());
 
         // Add research engine if Claude API is configured
         if config.has_claude_config() {
             match config.get_claude_config() {
                 Ok(claude_config) => {
-                    // Convert CLI config to Claude config
-                    let claude_api_config = fortitude_core::api::ClaudeConfig {
-                        api_key: claude_config.api_key.clone(),
-                        base_url: claude_config
-                            .base_url
-                            .unwrap_or_else(|| "https://api.anthropic.com".to_string()),
-                        timeout: std::time::Duration::from_secs(
-                            claude_config.timeout_seconds.unwrap_or(300),
-                        ),
-                        rate_limit: fortitude_core::api::RateLimitConfig {
-                            requests_per_minute: claude_config.rate_limit.requests_per_minute,
-                            input_tokens_per_minute: claude_config
-                                .rate_limit
-                                .input_tokens_per_minute,
-                            output_tokens_per_minute: claude_config
-                                .rate_limit
-                                .output_tokens_per_minute,
-                            max_concurrent_requests: claude_config
-                                .rate_limit
-                                .max_concurrent_requests,
-                        },
-                        retry: fortitude_core::api::RetryConfig {
-                            max_retries: claude_config.retry.max_retries,
-                            initial_delay: std::time::Duration::from_millis(
-                                claude_config.retry.initial_delay_ms,
-                            ),
-                            max_delay: std::time::Duration::from_millis(
-                                claude_config.retry.max_delay_ms,
-                            ),
-                            backoff_multiplier: claude_config.retry.backoff_multiplier,
-                            jitter: claude_config.retry.jitter,
-                        },
-                        user_agent: format!("fortitude/{}", env!("CARGO_PKG_VERSION")),
-                        model: claude_config
-                            .model
-                            .unwrap_or_else(|| "claude-3-sonnet-20240229".to_string()),
-                    };
+                    let claude_api_config = claude_config.to_core_config();
 
                     match ClaudeResearchEngine::new(claude_api_config) {
                         Ok(engine) => {
@@ -932,61 +1719,124 @@ impl App {
             pipeline_builder = pipeline_builder.with_research_engine(Arc::new(claude_code_engine));
         }
 
-        let pipeline = pipeline_builder.build(Arc::new(classifier), Arc::new(storage));
+        let mut pipeline = pipeline_builder.build(Arc::new(classifier), storage);
 
         // Initialize vector services if configuration is available
-        let (
-            qdrant_client,
-            vector_storage,
-            semantic_search,
-            hybrid_search,
-            migration_service,
-            embedding_service,
-        ) = if let Some(vector_config) = &config.vector {
-            match Self::init_vector_services(vector_config).await {
-                Ok(services) => {
-                    info!("Vector services initialized successfully");
-                    services
-                }
-                Err(e) => {
-                    warn!("Failed to initialize vector services: {}. Vector commands will be unavailable.", e);
-                    (None, None, None, None, None, None)
-                }
+        let vector_services = if let Some(vector_config) = &config.vector {
+            let services = Self::init_vector_services(
+                vector_config,
+                config.storage.base_path.join(MIGRATION_STATE_DIR),
+            )
+            .await;
+            match &services.unavailable_reason {
+                Some(reason) => warn!(
+                    "Failed to initialize vector services: {}. Vector commands will be unavailable.",
+                    reason
+                ),
+                None => info!("Vector services initialized successfully"),
             }
+            services
         } else {
             info!("Vector database not configured. Vector commands will be unavailable.");
-            (None, None, None, None, None, None)
+            VectorServices::default()
         };
 
+        // Let the pipeline index queries and serve near-duplicates from the cache
+        if let Some(vector_storage) = &vector_services.vector_storage {
+            pipeline = pipeline.with_vector_storage(Arc::new(vector_storage.clone()));
+        }
+
         Ok(Self {
             pipeline,
             config,
-            qdrant_client,
-            vector_storage,
-            semantic_search,
-            hybrid_search,
-            migration_service,
-            embedding_service,
+            qdrant_client: vector_services.qdrant_client,
+            vector_storage: vector_services.vector_storage,
+            semantic_search: vector_services.semantic_search,
+            migration_service: vector_services.migration_service,
+            embedding_service: vector_services.embedding_service,
+            vector_unavailable_reason: vector_services.unavailable_reason,
         })
     }
 
+    /// Connect to Qdrant and build the services on top of the default collection
+    ///
+    /// A reachable server with a missing collection keeps the client so that
+    /// `vector setup` can create the collection. Migration checkpoints are kept
+    /// in `migration_state_dir`.
     async fn init_vector_services(
-        _vector_config: &config::VectorDatabaseConfig,
-    ) -> std::result::Result<
-        (
-            Option<QdrantClient>,
-            Option<VectorStorage>,
-            Option<SemanticSearchService>,
-            Option<HybridSearchService>,
-            Option<MigrationService>,
-            Option<EmbeddingService>,
-        ),
-        Box<dyn std::error::Error>,
-    > {
-        // Placeholder implementation until vector services API stabilizes
-        info!("Vector services initialization temporarily disabled - placeholder mode");
-
-        Ok((None, None, None, None, None, None))
+        vector_config: &config::VectorDatabaseConfig,
+        migration_state_dir: PathBuf,
+    ) -> VectorServices {
+        let core_config = vector_config.to_core_config();
+        let url = core_config.url.clone();
+        let collection = core_config.default_collection.clone();
+
+        // Connecting performs a health check against the primary
+        let qdrant_client = match QdrantClient::new(core_config.clone()).await {
+            Ok(client) => Arc::new(client),
+            Err(e) => {
+                return VectorServices::unavailable(
+                    None,
+                    format!(
+                        "cannot connect to Qdrant at {url}: {e}. Check that Qdrant is running and that vector.url is correct"
+                    ),
+                )
+            }
+        };
+
+        match qdrant_client.get_collection_info(&collection).await {
+            Ok(_) => {}
+            Err(VectorError::CollectionNotFound { .. }) => {
+                return VectorServices::unavailable(
+                    Some(qdrant_client),
+                    format!(
+                        "collection '{collection}' does not exist on {url}. Create it with `fortitude vector setup --collection {collection}` or set vector.default_collection"
+                    ),
+                )
+            }
+            Err(e) => {
+                return VectorServices::unavailable(
+                    Some(qdrant_client),
+                    format!("cannot read collection '{collection}' on {url}: {e}"),
+                )
+            }
+        }
+
+        let embedding_service = Arc::new(EmbeddingService::new(core_config.embedding.clone()));
+        if let Err(e) = embedding_service.initialize().await {
+            return VectorServices::unavailable(
+                Some(qdrant_client),
+                format!("failed to initialize the embedding service: {e}"),
+            );
+        }
+
+        let vector_storage = VectorStorage::new(qdrant_client.clone(), embedding_service.clone());
+        let semantic_search = Arc::new(SemanticSearchService::with_defaults(Arc::new(
+            vector_storage.clone(),
+        )));
+        let migration_service =
+            MigrationService::new(Arc::new(vector_storage.clone()), Some(migration_state_dir))
+                .with_embeddings(embedding_service.clone());
+        if let Err(e) = migration_service.initialize().await {
+            warn!("Failed to load migration checkpoints: {}", e);
+        }
+
+        VectorServices {
+            qdrant_client: Some(qdrant_client),
+            vector_storage: Some(vector_storage),
+            semantic_search: Some(semantic_search),
+            migration_service: Some(migration_service),
+            embedding_service: Some(embedding_service),
+            unavailable_reason: None,
+        }
+    }
+
+    /// Error for a vector command whose service is missing
+    fn vector_unavailable(&self, service: &str) -> String {
+        match &self.vector_unavailable_reason {
+            Some(reason) => format!("{service} not available: {reason}"),
+            None => format!("{service} not available. Please configure vector database."),
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -996,16 +1846,25 @@ impl App {
         level: String,
         domain: String,
         format: String,
-        technology: String,
-        project_type: String,
+        technology: Option<String>,
+        project_type: Option<String>,
         frameworks: Option<String>,
         tags: Option<String>,
         _enable_cache: bool,
+        no_semantic_cache: bool,
         advanced_classification: bool,
         context_detection: bool,
         context_threshold: f64,
         graceful_degradation: bool,
-    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        dry_run: bool,
+        parent: Option<String>,
+        code: Option<PathBuf>,
+        project_path: Option<PathBuf>,
+        budget_profile: Option<String>,
+        time_budget_ms: Option<u64>,
+        deep: bool,
+        force: bool,
+    ) -> std::result::Result<Option<String>, Box<dyn std::error::Error>> {
         info!("Processing research request: '{}'", topic);
 
         // Log classification options
@@ -1038,87 +1897,418 @@ impl App {
             format: format.clone(),
         };
 
-        let domain_context = DomainContext {
-            technology,
-            project_type,
+        let mut domain_context = DomainContext {
+            technology: technology.clone().unwrap_or_else(|| "rust".to_string()),
+            project_type: project_type
+                .clone()
+                .unwrap_or_else(|| "library".to_string()),
             frameworks: frameworks_vec,
             tags: tags_vec,
         };
 
-        // Process the research request
+        // Detected project details fill in whatever the flags left unset
+        let project_context = match project_path {
+            Some(path) => {
+                let (project, summary) = ProjectContextCollector::default()
+                    .summarize(&path)
+                    .map_err(|e| format!("Failed to collect project context: {e}"))?;
+                project.apply_to(&mut domain_context);
+                if let Some(technology) = technology {
+                    domain_context.technology = technology;
+                }
+                if let Some(project_type) = project_type {
+                    domain_context.project_type = project_type;
+                }
+                info!(
+                    "Using project context from {}: {} ({})",
+                    path.display(),
+                    project.name,
+                    domain_context.project_type
+                );
+                Some(summary)
+            }
+            None => None,
+        };
+
+        if dry_run {
+            let plan = self
+                .pipeline
+                .plan_query(&topic, Some(audience_context), Some(domain_context))
+                .await?;
+
+            match format.as_str() {
+                "json" => {
+                    let json = serde_json::to_string_pretty(&plan)?;
+                    println!("{json}");
+                }
+                _ => self.print_execution_plan(&plan),
+            }
+
+            return Ok(None);
+        }
+
+        let code_source = match code {
+            Some(path) => Some(
+                std::fs::read_to_string(&path)
+                    .map_err(|e| format!("Failed to read code file {}: {e}", path.display()))?,
+            ),
+            None => None,
+        };
+
+        if let Some(parent_id) = parent.as_deref() {
+            if self.pipeline.get_result(parent_id).await?.is_none() {
+                return Err(format!("Parent research result not found: {parent_id}").into());
+            }
+        }
+
+        // Follow-ups build on earlier answers, so only new questions are checked
+        if parent.is_none() && !force {
+            let similar = self.pipeline.similar_research(&topic).await;
+            if !similar.is_empty() && !confirm_despite_similar(&similar)? {
+                println!("Research skipped; the earlier results are listed above");
+                return Ok(None);
+            }
+        }
+
+        // Process the research request, linking follow-ups to their parent
+        let options = ResearchOptions {
+            parent_id: parent,
+            code: code_source,
+            project_context,
+            conversation: false,
+            session_id: None,
+            budget_profile,
+            stage_observer: None,
+            time_budget_ms,
+            provider_preference: None,
+            model_preference: None,
+            trace: None,
+            deepen: deep.then_some(true),
+            bypass_cache: false,
+            bypass_semantic_cache: no_semantic_cache,
+            priority: None,
+        };
         let result = self
             .pipeline
-            .process_query(&topic, Some(audience_context), Some(domain_context))
+            .process_query_with_options(
+                &topic,
+                options,
+                Some(audience_context),
+                Some(domain_context),
+            )
             .await?;
 
-        // Output the result
+        // Output the result with earlier research on similar questions
+        let related = self.pipeline.related_results(&result).await;
         match format.as_str() {
             "json" => {
-                let json = serde_json::to_string_pretty(&result)?;
-                println!("{json}");
+                let mut json = serde_json::to_value(&result)?;
+                if !related.is_empty() {
+                    json["related_results"] = serde_json::to_value(&related)?;
+                }
+                println!("{}", serde_json::to_string_pretty(&json)?);
             }
             "markdown" | _ => {
-                self.print_research_result_markdown(&result);
+                let ancestors = match result.parent_id() {
+                    Some(parent_id) => self
+                        .pipeline
+                        .research_lineage(parent_id)
+                        .await
+                        .unwrap_or_default(),
+                    None => Vec::new(),
+                };
+                self.print_research_result_markdown(&result, &ancestors, &related);
             }
         }
 
-        Ok(())
+        Ok(Some(result.cache_key().to_string()))
     }
 
-    async fn handle_list(
+    /// Read commands from stdin and run them against this application
+    async fn handle_interactive(
         &self,
-        research_type: Option<String>,
-        tag: Option<String>,
-        newer_than: Option<u64>,
-        format: String,
-        limit: usize,
+        resume: Option<String>,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        info!("Listing cached research results");
+        use std::io::{BufRead, Write};
 
-        let entries = self.pipeline.list_cached_results().await?;
-
-        // Apply filters
-        let filtered_entries: Vec<_> = entries
-            .into_iter()
-            .filter(|entry| {
-                // Filter by research type
-                if let Some(ref type_filter) = research_type {
-                    if entry.research_type.to_string().to_lowercase() != type_filter.to_lowercase()
-                    {
-                        return false;
-                    }
+        let mut context = match resume {
+            Some(id) => {
+                if self.pipeline.get_result(&id).await?.is_none() {
+                    return Err(format!("Research result not found: {id}").into());
                 }
+                Some(id)
+            }
+            None => None,
+        };
+        std::fs::create_dir_all(&self.config.storage.base_path)?;
+        let mut history = History::load(&self.config.storage.base_path.join(".cli_history"));
+        let subcommands: Vec<String> = Cli::command()
+            .get_subcommands()
+            .map(|command| command.get_name().to_string())
+            .collect();
+        println!("Fortitude interactive mode. Type help for commands, quit to leave.");
+
+        let stdin = std::io::stdin();
+        let mut line = String::new();
+        loop {
+            print!("\nfortitude> ");
+            std::io::stdout().flush()?;
+            line.clear();
+            if stdin.lock().read_line(&mut line)? == 0 {
+                println!();
+                break;
+            }
 
-                // Filter by tag
-                if let Some(ref tag_filter) = tag {
-                    if !entry
-                        .metadata
-                        .get("tags")
-                        .unwrap_or(&String::new())
-                        .contains(tag_filter)
-                    {
-                        return false;
+            let line = match history.expand(&line) {
+                Ok(line) => line,
+                Err(message) => {
+                    eprintln!("{message}");
+                    continue;
+                }
+            };
+            let input = match InteractiveInput::parse(&line) {
+                Ok(Some(input)) => input,
+                Ok(None) => continue,
+                Err(message) => {
+                    eprintln!("{message}");
+                    continue;
+                }
+            };
+            history.push(&line);
+
+            let mut args = match input {
+                InteractiveInput::Command(args) => args,
+                InteractiveInput::Context => {
+                    match &context {
+                        Some(id) => println!("Next question follows up on {id}"),
+                        None => println!("Next question starts a new line of research"),
                     }
+                    continue;
                 }
-
-                // Filter by age
-                if let Some(days) = newer_than {
-                    let age_days = entry.age_seconds() / 86400; // Convert to days
-                    if age_days > days {
-                        return false;
+                InteractiveInput::New => {
+                    context = None;
+                    println!("Next question starts a new line of research");
+                    continue;
+                }
+                InteractiveInput::History => {
+                    for (i, entry) in history.entries().iter().enumerate() {
+                        println!("{:>5}  {entry}", i + 1);
                     }
+                    continue;
+                }
+                InteractiveInput::Help => {
+                    println!("{}", interactive::INTERACTIVE_HELP);
+                    continue;
                 }
+                InteractiveInput::Quit => break,
+            };
 
-                true
-            })
-            .take(limit)
-            .collect();
+            // Anything that is not a command is a research question
+            if !subcommands.contains(&args[0]) && !args[0].starts_with('-') {
+                args = vec!["research".to_string(), line.clone()];
+            }
+            let mut command =
+                match Cli::try_parse_from(std::iter::once("fortitude".to_string()).chain(args)) {
+                    Ok(cli) => cli.command,
+                    Err(e) => {
+                        let _ = e.print();
+                        continue;
+                    }
+                };
+            if let Commands::Research {
+                parent: parent @ None,
+                dry_run: false,
+                ..
+            } = &mut command
+            {
+                *parent = context.clone();
+            }
 
-        // Output results
-        match format.as_str() {
-            "json" => {
-                let json = serde_json::to_string_pretty(&filtered_entries)?;
-                println!("{json}");
+            // Errors were already reported by the command
+            if let Ok(Some(result_id)) = run_command(self, &self.config, command).await {
+                context = Some(result_id);
+            }
+        }
+        Ok(())
+    }
+
+    async fn handle_chat(
+        &self,
+        audience_context: AudienceContext,
+        domain_context: DomainContext,
+        resume: Option<String>,
+        color: bool,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        use std::io::{BufRead, IsTerminal, Write};
+
+        let color =
+            color && std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+
+        let mut session = match resume {
+            Some(id) => {
+                let turns = self.pipeline.research_lineage(&id).await?;
+                if turns.is_empty() {
+                    return Err(format!("Research result not found: {id}").into());
+                }
+                println!("Resuming conversation with {} earlier turns.", turns.len());
+                ChatSession::resume(turns)
+            }
+            None => ChatSession::default(),
+        };
+        println!("Fortitude chat. Type /help for commands, /quit to leave.");
+
+        let stdin = std::io::stdin();
+        let mut line = String::new();
+        loop {
+            print!("\n> ");
+            std::io::stdout().flush()?;
+            line.clear();
+            if stdin.lock().read_line(&mut line)? == 0 {
+                println!();
+                break;
+            }
+
+            let command = match ChatCommand::parse(&line) {
+                Ok(Some(command)) => command,
+                Ok(None) => continue,
+                Err(message) => {
+                    eprintln!("{message}");
+                    continue;
+                }
+            };
+
+            match command {
+                ChatCommand::Ask(query) => {
+                    let result = match self
+                        .pipeline
+                        .process_conversation_turn(
+                            &query,
+                            session.last_id(),
+                            Some(audience_context.clone()),
+                            Some(domain_context.clone()),
+                        )
+                        .await
+                    {
+                        Ok(result) => result,
+                        Err(e) => {
+                            eprintln!("Research failed: {e}");
+                            continue;
+                        }
+                    };
+                    println!();
+                    print!("{}", chat::render_markdown(&result.immediate_answer, color));
+                    println!("{}", chat::render_turn_footer(&result, color));
+                    session.push(result);
+                }
+                ChatCommand::Search(query) => {
+                    if let Err(e) = self
+                        .handle_search(query, None, None, None, 5, "table".to_string())
+                        .await
+                    {
+                        eprintln!("Search failed: {e}");
+                    }
+                }
+                ChatCommand::Save(path) => {
+                    let Some(root_id) = session.root_id() else {
+                        eprintln!("Nothing to save yet");
+                        continue;
+                    };
+                    let path = path.unwrap_or_else(|| {
+                        self.config
+                            .storage
+                            .base_path
+                            .join("chats")
+                            .join(format!("{root_id}.md"))
+                    });
+                    if let Some(parent) = path.parent() {
+                        std::fs::create_dir_all(parent)?;
+                    }
+                    std::fs::write(&path, session.to_markdown())?;
+                    println!(
+                        "Saved {} turns to {}",
+                        session.turns().len(),
+                        path.display()
+                    );
+                }
+                ChatCommand::Feedback { rating, comment } => {
+                    let Some(last_id) = session.last_id() else {
+                        eprintln!("No answer to rate yet");
+                        continue;
+                    };
+                    match self
+                        .pipeline
+                        .record_user_feedback(last_id, rating, comment.as_deref())
+                        .await
+                    {
+                        Ok(_) => println!("Recorded rating {rating}/5 for {last_id}"),
+                        Err(e) => eprintln!("Failed to record feedback: {e}"),
+                    }
+                }
+                ChatCommand::Help => println!("{}", chat::CHAT_HELP),
+                ChatCommand::Quit => break,
+            }
+        }
+
+        if let Some(last_id) = session.last_id() {
+            println!("Conversation saved; continue with `fortitude chat --resume {last_id}`");
+        }
+        Ok(())
+    }
+
+    async fn handle_list(
+        &self,
+        research_type: Option<String>,
+        tag: Option<String>,
+        newer_than: Option<u64>,
+        format: String,
+        limit: usize,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        info!("Listing cached research results");
+
+        let entries = self.pipeline.list_cached_results().await?;
+
+        // Apply filters
+        let filtered_entries: Vec<_> = entries
+            .into_iter()
+            .filter(|entry| {
+                // Filter by research type
+                if let Some(ref type_filter) = research_type {
+                    if entry.research_type.to_string().to_lowercase() != type_filter.to_lowercase()
+                    {
+                        return false;
+                    }
+                }
+
+                // Filter by tag
+                if let Some(ref tag_filter) = tag {
+                    if !entry
+                        .metadata
+                        .get("tags")
+                        .unwrap_or(&String::new())
+                        .contains(tag_filter)
+                    {
+                        return false;
+                    }
+                }
+
+                // Filter by age
+                if let Some(days) = newer_than {
+                    let age_days = entry.age_seconds() / 86400; // Convert to days
+                    if age_days > days {
+                        return false;
+                    }
+                }
+
+                true
+            })
+            .take(limit)
+            .collect();
+
+        // Output results
+        match format.as_str() {
+            "json" => {
+                let json = serde_json::to_string_pretty(&filtered_entries)?;
+                println!("{json}");
             }
             "summary" => {
                 self.print_entries_summary(&filtered_entries);
@@ -1239,10 +2429,355 @@ impl App {
         Ok(())
     }
 
-    fn print_research_result_markdown(&self, result: &ResearchResult) {
+    async fn handle_refresh(
+        &self,
+        dry_run: bool,
+        limit: Option<usize>,
+        every: Option<u64>,
+        output_format: &str,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mut policy = self.config.pipeline.freshness.clone();
+        if let Some(limit) = limit {
+            policy = policy.with_max_refreshes_per_run(limit);
+        }
+        let Some(minutes) = every else {
+            let report = refresh_stale(&self.pipeline, &policy, dry_run).await?;
+            return print_refresh_report(&report, dry_run, output_format);
+        };
+        if minutes == 0 {
+            return Err("--every must be at least one minute".into());
+        }
+
+        info!("Refreshing stale entries every {} minutes", minutes);
+        let mut ticker = tokio::time::interval(std::time::Duration::from_secs(minutes * 60));
+        loop {
+            ticker.tick().await;
+            match refresh_stale(&self.pipeline, &policy, dry_run).await {
+                Ok(report) => print_refresh_report(&report, dry_run, output_format)?,
+                Err(e) => eprintln!("Refresh failed: {e}"),
+            }
+        }
+    }
+
+    async fn handle_digest(
+        &self,
+        hours: Option<u32>,
+        format: &str,
+        send: bool,
+        every: Option<u64>,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let format = format.parse::<DigestFormat>()?;
+        let mut config = self.config.digest.clone();
+        if let Some(hours) = hours {
+            config = config.with_lookback_hours(hours);
+        }
+        // Scheduled digests cover the time since the previous one by default
+        if let (None, Some(every)) = (hours, every) {
+            config = config.with_lookback_hours(u32::try_from(every).unwrap_or(u32::MAX));
+        }
+        config.validate()?;
+        let mailer = if send {
+            let email = config
+                .email
+                .clone()
+                .ok_or("digest.email must be configured to send digests")?;
+            Some(DigestMailer::new(email))
+        } else {
+            None
+        };
+
+        let Some(every) = every else {
+            return self.produce_digest(&config, format, mailer.as_ref()).await;
+        };
+        if every == 0 {
+            return Err("--every must be at least one hour".into());
+        }
+
+        info!("Producing a research digest every {} hours", every);
+        let mut ticker = tokio::time::interval(std::time::Duration::from_secs(every * 3600));
+        // The first tick fires immediately; the first digest covers a full period
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            if let Err(e) = self.produce_digest(&config, format, mailer.as_ref()).await {
+                eprintln!("Digest failed: {e}");
+            }
+        }
+    }
+
+    async fn produce_digest(
+        &self,
+        config: &DigestConfig,
+        format: DigestFormat,
+        mailer: Option<&DigestMailer>,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let digest =
+            Digest::compile(self.pipeline.storage().as_ref(), config, chrono::Utc::now()).await?;
+        match mailer {
+            Some(_) if digest.is_empty() => println!(
+                "No new research results in the last {} hours, nothing sent",
+                config.lookback_hours
+            ),
+            Some(mailer) => {
+                mailer.send(&digest).await?;
+                println!("Sent digest of {} results", digest.result_count());
+            }
+            None => print!("{}", digest.render(format)),
+        }
+        Ok(())
+    }
+
+    async fn handle_knowledge_command(
+        &self,
+        knowledge_command: KnowledgeCommand,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        match knowledge_command {
+            KnowledgeCommand::Graph {
+                query,
+                depth,
+                rebuild,
+                output_format,
+            } => {
+                self.handle_knowledge_graph(&query, depth, rebuild, &output_format)
+                    .await
+            }
+        }
+    }
+
+    async fn handle_knowledge_graph(
+        &self,
+        query: &str,
+        depth: usize,
+        rebuild: bool,
+        output_format: &str,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mut store =
+            KnowledgeGraphStore::open(self.config.storage.base_path.join(KNOWLEDGE_GRAPH_FILE));
+        // Hash embeddings carry no meaning; without a model, words compare results better
+        if let Some(embeddings) = self
+            .embedding_service
+            .clone()
+            .filter(|service| service.provider_name() != "hash")
+        {
+            store = store.with_embeddings(embeddings);
+        }
+        let storage = self.pipeline.storage().as_ref();
+        let graph = if rebuild {
+            store.rebuild(storage).await?
+        } else {
+            store.current(storage).await?
+        };
+        let neighborhood = graph.neighborhood(query, depth);
+
+        if output_format == "json" {
+            println!("{}", serde_json::to_string_pretty(&neighborhood)?);
+            return Ok(());
+        }
+        if neighborhood.seeds.is_empty() {
+            println!("No stored research matches '{query}'");
+            return Ok(());
+        }
+        for item in &neighborhood.nodes {
+            println!(
+                "{}{}  {} ({})",
+                "  ".repeat(item.distance),
+                item.node.id,
+                item.node.query,
+                item.node.research_type
+            );
+        }
+        println!();
+        for edge in &neighborhood.edges {
+            let relations: Vec<String> = edge
+                .relations
+                .iter()
+                .map(|relation| format!("{} {:.2}", relation.kind, relation.score))
+                .collect();
+            println!(
+                "{} -- {}  {}",
+                edge.source,
+                edge.target,
+                relations.join(", ")
+            );
+        }
+        println!();
+        println!(
+            "{} results and {} relations within {} links of {} matching results",
+            neighborhood.nodes.len(),
+            neighborhood.edges.len(),
+            depth,
+            neighborhood.seeds.len()
+        );
+        Ok(())
+    }
+
+    async fn handle_import(
+        &self,
+        paths: Vec<PathBuf>,
+        format: Option<String>,
+        index: bool,
+        dry_run: bool,
+        output_format: &str,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let format = format.map(|f| f.parse::<ImportFormat>()).transpose()?;
+        let files = collect_import_files(&paths, format)?;
+        if files.is_empty() {
+            return Err("No JSONL or Markdown files found to import".into());
+        }
+
+        let mut documents = Vec::new();
+        for (file, file_format) in &files {
+            let content = std::fs::read_to_string(file)?;
+            documents.extend(parse_documents(
+                *file_format,
+                &content,
+                &file.display().to_string(),
+            ));
+        }
+        info!(
+            "Importing {} documents from {} files (dry_run: {})",
+            documents.len(),
+            files.len(),
+            dry_run
+        );
+
+        let mut importer = ResearchImporter::new(self.pipeline.storage().clone());
+        if index {
+            let vector_storage = self
+                .vector_storage
+                .clone()
+                .ok_or_else(|| self.vector_unavailable("Vector storage"))?;
+            importer = importer.with_vector_storage(Arc::new(vector_storage));
+        }
+        let report = importer.import(documents, dry_run).await?;
+
+        if output_format == "json" {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+            return Ok(());
+        }
+
+        if dry_run {
+            println!("DRY RUN: Nothing was written");
+        }
+        for doc in &report.imported {
+            println!("imported   {}  {}", doc.location, doc.cache_key);
+        }
+        for dup in &report.duplicates {
+            println!(
+                "duplicate  {}  (already stored as {})",
+                dup.location, dup.existing_key
+            );
+        }
+        for issue in &report.invalid {
+            println!("invalid    {}  {}", issue.location, issue.reason);
+        }
+        for issue in &report.index_failures {
+            println!("unindexed  {}  {}", issue.location, issue.reason);
+        }
+        println!();
+        println!(
+            "{} imported, {} duplicates, {} invalid{}",
+            report.imported.len(),
+            report.duplicates.len(),
+            report.invalid.len(),
+            if index {
+                format!(", {} indexed", report.indexed)
+            } else {
+                String::new()
+            }
+        );
+
+        Ok(())
+    }
+
+    fn print_execution_plan(&self, plan: &ExecutionPlan) {
+        println!("# Execution Plan (dry run)");
+        println!();
+        println!("**Query:** {}", plan.query);
+        println!(
+            "**Type:** {} (confidence {:.2})",
+            plan.research_type, plan.classification_confidence
+        );
+        if let Some(ref audience) = plan.detected_audience {
+            println!("**Detected Audience:** {audience}");
+        }
+        if let Some(ref domain) = plan.detected_domain {
+            println!("**Detected Domain:** {domain}");
+        }
+        if let Some(ref urgency) = plan.detected_urgency {
+            println!("**Detected Urgency:** {urgency}");
+        }
+        println!();
+        println!("## Steps");
+        println!();
+        println!(
+            "- Cache: {} (key `{}`)",
+            if plan.cache_hit { "hit" } else { "miss" },
+            plan.cache_key
+        );
+        println!("- Context documents: {}", plan.context_documents);
+        println!(
+            "- Provider: {}{}",
+            plan.provider,
+            if plan.engine_configured {
+                ""
+            } else {
+                " (no engine configured, placeholder response)"
+            }
+        );
+        println!("- Template: {}", plan.template.as_deref().unwrap_or("none"));
+        println!("- Cross-validation: {}", plan.cross_validate);
+        println!();
+        println!("## Estimates");
+        println!();
+        println!(
+            "- Tokens: {} in / {} out",
+            plan.estimated_input_tokens, plan.estimated_output_tokens
+        );
+        println!("- Cost: ${:.4}", plan.estimated_cost_usd);
+        println!("- Time: {}ms", plan.estimated_processing_time_ms);
+
+        if !plan.provider_estimates.is_empty() {
+            println!();
+            println!("| Provider | Model | Cost Range (USD) | Fits Context |");
+            println!("|----------|-------|------------------|--------------|");
+            for estimate in &plan.provider_estimates {
+                println!(
+                    "| {} | {} | {:.4} - {:.4} | {} |",
+                    estimate.provider,
+                    estimate.model,
+                    estimate.cost_min_usd,
+                    estimate.cost_max_usd,
+                    if estimate.fits_context { "yes" } else { "no" }
+                );
+            }
+        }
+    }
+
+    /// `ancestors` is the lineage of the parent result, root question first
+    fn print_research_result_markdown(
+        &self,
+        result: &ResearchResult,
+        ancestors: &[ResearchResult],
+        related: &[RelatedResult],
+    ) {
         println!("# Research Result");
         println!();
         println!("**Query:** {}", result.request.original_query);
+        if let Some(parent_id) = result.parent_id() {
+            match ancestors.last() {
+                Some(parent) => println!(
+                    "**Derived from:** {} (`{}`)",
+                    parent.original_query(),
+                    parent_id
+                ),
+                None => println!("**Derived from:** `{parent_id}`"),
+            }
+            if ancestors.len() > 1 {
+                let chain: Vec<&str> = ancestors.iter().map(|r| r.original_query()).collect();
+                println!("**Lineage:** {}", chain.join(" → "));
+            }
+        }
         println!("**Type:** {}", result.request.research_type);
         println!("**Confidence:** {:.2}", result.request.confidence);
         println!(
@@ -1264,6 +2799,21 @@ impl App {
         if let Some(context_detection) = result.metadata.tags.get("context_detection") {
             println!("**Context Detection:** {context_detection}");
         }
+        if let Some(matched) = result.metadata.tags.get(SEMANTIC_CACHE_QUERY_TAG) {
+            let similarity = result
+                .metadata
+                .tags
+                .get(SEMANTIC_CACHE_SIMILARITY_TAG)
+                .map(String::as_str)
+                .unwrap_or("?");
+            println!("**Semantic Cache Hit:** \"{matched}\" (similarity {similarity})");
+        }
+        if let Some(report) = TimeBudgetReport::from_tags(&result.metadata.tags) {
+            println!("**Time Budget:** {}ms", report.budget_ms);
+            if let Some(summary) = report.summary() {
+                println!("**Shortcuts:** {summary}");
+            }
+        }
 
         println!();
 
@@ -1314,6 +2864,18 @@ impl App {
             println!();
         }
 
+        if !related.is_empty() {
+            println!("## Related Research");
+            println!();
+            for related in related {
+                println!(
+                    "- {} (`{}`, relevance {:.2})",
+                    related.query, related.id, related.relevance
+                );
+            }
+            println!();
+        }
+
         println!("**Cache Key:** {}", result.metadata.cache_key);
         println!("**Quality Score:** {:.2}", result.metadata.quality_score);
     }
@@ -1460,6 +3022,20 @@ impl App {
             stats.average_age_seconds / 3600.0
         );
 
+        if detailed && stats.dedup.logical_entries > 0 {
+            println!("\nDeduplication:");
+            println!(
+                "  {} entries share {} content blobs",
+                stats.dedup.logical_entries, stats.dedup.blobs
+            );
+            println!(
+                "  Content: {} logical, {} stored",
+                Self::format_size(stats.dedup.logical_bytes),
+                Self::format_size(stats.dedup.stored_bytes)
+            );
+            println!("  Saved: {}", Self::format_size(stats.dedup.saved_bytes()));
+        }
+
         if detailed && !stats.by_research_type.is_empty() {
             println!("\nBy research type:");
             for (research_type, type_stats) in &stats.by_research_type {
@@ -1501,17 +3077,14 @@ impl App {
                 self.handle_vector_config(url, api_key, collection, show)
                     .await
             }
-            VectorCommand::Health { _detailed, _format } => {
-                self.handle_vector_health(_detailed, _format).await
+            VectorCommand::Health { detailed, format } => {
+                self.handle_vector_health(detailed, format).await
             }
             VectorCommand::Stats {
                 collection,
-                _format,
-                _detailed,
-            } => {
-                self.handle_vector_stats(collection, _format, _detailed)
-                    .await
-            }
+                format,
+                detailed,
+            } => self.handle_vector_stats(collection, format, detailed).await,
             VectorCommand::Migrate {
                 source,
                 collection,
@@ -1540,10 +3113,10 @@ impl App {
             VectorCommand::Analytics {
                 period,
                 collection,
-                _format,
-                _performance,
+                format,
+                performance,
             } => {
-                self.handle_vector_analytics(period, collection, _format, _performance)
+                self.handle_vector_analytics(period, collection, format, performance)
                     .await
             }
             VectorCommand::Setup {
@@ -1555,6 +3128,30 @@ impl App {
                 self.handle_vector_setup(collection, dimensions, metric, force)
                     .await
             }
+            VectorCommand::Export {
+                output,
+                collection,
+                batch_size,
+                resume,
+            } => {
+                self.handle_vector_export(output, collection, batch_size, resume)
+                    .await
+            }
+            VectorCommand::Import {
+                input,
+                batch_size,
+                resume,
+                allow_model_mismatch,
+                verify_only,
+            } => {
+                let options = ImportOptions {
+                    batch_size,
+                    resume,
+                    allow_model_mismatch,
+                    ..Default::default()
+                };
+                self.handle_vector_import(input, options, verify_only).await
+            }
             VectorCommand::Index { index_command } => {
                 self.handle_index_command(index_command).await
             }
@@ -1565,34 +3162,53 @@ impl App {
         &self,
         params: SemanticSearchParams,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _semantic_search = self
+        let semantic_search = self
             .semantic_search
             .as_ref()
-            .ok_or("Semantic search service not available. Please configure vector database.")?;
+            .ok_or_else(|| self.vector_unavailable("Semantic search service"))?;
+
+        match params.strategy.as_str() {
+            "semantic" => {}
+            // Blend in keyword matches over the stored research results
+            "hybrid" | "combined" => {
+                return self
+                    .handle_hybrid_search(HybridSearchParams {
+                        query: params.query,
+                        keyword_weight: 0.5,
+                        semantic_weight: 0.5,
+                        limit: params.limit,
+                        threshold: params.threshold,
+                        format: params.format,
+                        collection: params.collection,
+                        explain: params.explain,
+                    })
+                    .await;
+            }
+            other => {
+                return Err(format!(
+                    "Unknown search strategy '{other}'. Use semantic, hybrid or combined"
+                )
+                .into())
+            }
+        }
 
         info!("Performing semantic search for: '{}'", params.query);
 
-        // Use provided collection or default
-        let collection_name = params.collection.unwrap_or_else(|| {
-            self.config
-                .vector
-                .as_ref()
-                .map(|v| v.default_collection.clone())
-                .unwrap_or_else(|| "fortitude_research".to_string())
-        });
+        let options = SearchOptions {
+            limit: params.limit,
+            threshold: Some(params.threshold),
+            collection: Some(self.collection_name(params.collection)),
+            include_explanations: params.explain,
+            ..Default::default()
+        };
+        let result_set = semantic_search
+            .search_similar(&params.query, options)
+            .await?;
 
-        // Placeholder semantic search implementation
-        println!("Semantic search query: '{}'", params.query);
-        println!("Collection: {collection_name}");
-        println!(
-            "Limit: {}, Threshold: {:.2}",
-            params.limit, params.threshold
+        print!(
+            "{}",
+            format_semantic_results(&result_set.results, &params.format, params.explain)?
         );
-        println!("Strategy: {}", params.strategy);
-        if params.explain {
-            println!("Explanation requested");
-        }
-        println!("Semantic search functionality not yet implemented");
 
         Ok(())
     }
@@ -1601,37 +3217,26 @@ impl App {
         &self,
         params: HybridSearchParams,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _hybrid_search = self
-            .hybrid_search
+        let semantic_search = self
+            .semantic_search
             .as_ref()
-            .ok_or("Hybrid search service not available. Please configure vector database.")?;
+            .ok_or_else(|| self.vector_unavailable("Hybrid search service"))?;
 
         info!("Performing hybrid search for: '{}'", params.query);
 
-        // Use provided collection or default
-        let collection_name = params.collection.unwrap_or_else(|| {
-            self.config
-                .vector
-                .as_ref()
-                .map(|v| v.default_collection.clone())
-                .unwrap_or_else(|| "fortitude_research".to_string())
-        });
-
-        // Placeholder hybrid search implementation
-        println!("Hybrid search query: '{}'", params.query);
-        println!("Collection: {collection_name}");
-        println!(
-            "Keyword weight: {:.2}, Semantic weight: {:.2}",
-            params.keyword_weight, params.semantic_weight
-        );
-        println!(
-            "Limit: {}, Threshold: {:.2}",
-            params.limit, params.threshold
+        let collection_name = self.collection_name(params.collection.clone());
+        let result_set = run_hybrid_search(
+            semantic_search.clone(),
+            self.pipeline.storage().as_ref(),
+            &params,
+            collection_name,
+        )
+        .await?;
+
+        print!(
+            "{}",
+            format_hybrid_results(&result_set.results, &params.format, params.explain)?
         );
-        if params.explain {
-            println!("Explanation requested");
-        }
-        println!("Hybrid search functionality not yet implemented");
 
         Ok(())
     }
@@ -1641,40 +3246,41 @@ impl App {
         content: String,
         limit: usize,
         threshold: f64,
-        _format: String,
+        format: String,
         collection: Option<String>,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _semantic_search = self
+        let semantic_search = self
             .semantic_search
             .as_ref()
-            .ok_or("Semantic search service not available. Please configure vector database.")?;
+            .ok_or_else(|| self.vector_unavailable("Semantic search service"))?;
 
         info!("Finding content similar to provided text");
 
-        // Use provided collection or default
-        let collection_name = collection.unwrap_or_else(|| {
+        let options = SearchOptions {
+            limit,
+            threshold: Some(threshold),
+            collection: Some(self.collection_name(collection)),
+            ..Default::default()
+        };
+        let result_set = semantic_search.search_similar(&content, options).await?;
+
+        print!(
+            "{}",
+            format_semantic_results(&result_set.results, &format, false)?
+        );
+
+        Ok(())
+    }
+
+    /// The given collection, or the configured default
+    fn collection_name(&self, collection: Option<String>) -> String {
+        collection.unwrap_or_else(|| {
             self.config
                 .vector
                 .as_ref()
                 .map(|v| v.default_collection.clone())
                 .unwrap_or_else(|| "fortitude_research".to_string())
-        });
-
-        // Placeholder find similar implementation
-        println!("Find similar content query");
-        println!("Collection: {collection_name}");
-        println!("Limit: {limit}, Threshold: {threshold:.2}");
-        println!(
-            "Content: {}",
-            if content.len() > 100 {
-                format!("{}...", &content[..100])
-            } else {
-                content.clone()
-            }
-        );
-        println!("Find similar functionality not yet implemented");
-
-        Ok(())
+        })
     }
 
     // Vector configuration and health
@@ -1727,18 +3333,57 @@ impl App {
 
     async fn handle_vector_health(
         &self,
-        _detailed: bool,
-        _format: String,
+        detailed: bool,
+        format: String,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _client = self
-            .qdrant_client
+        let vector_config = self
+            .config
+            .vector
             .as_ref()
-            .ok_or("Vector database client not available. Please configure vector database.")?;
+            .ok_or("Vector database is not configured. Please configure vector database.")?
+            .to_core_config();
 
         info!("Checking vector database health");
 
-        // Placeholder health check implementation
-        println!("Health check not yet implemented");
+        // Probe lazily-connected clients so an unreachable primary still shows up
+        let primary = Arc::new(QdrantClient::new_lazy(vector_config.clone())?);
+        let endpoints = VectorEndpoints::connect(&vector_config, primary)?;
+        let health = endpoints.probe_all().await;
+
+        match format.as_str() {
+            "json" => println!("{}", serde_json::to_string_pretty(&health)?),
+            _ => {
+                println!("Vector Database Health:");
+                println!(
+                    "{:<8} {:<40} {:<10} {:>12}",
+                    "ROLE", "URL", "STATUS", "LATENCY"
+                );
+                for endpoint in &health {
+                    let status = if endpoint.healthy {
+                        "healthy"
+                    } else {
+                        "unhealthy"
+                    };
+                    let latency = endpoint
+                        .latency_ms
+                        .map(|ms| format!("{ms:.1} ms"))
+                        .unwrap_or_else(|| "-".to_string());
+                    println!(
+                        "{:<8} {:<40} {:<10} {:>12}",
+                        endpoint.role, endpoint.url, status, latency
+                    );
+                    if detailed {
+                        if let Some(error) = &endpoint.last_error {
+                            println!("         last error: {error}");
+                        }
+                    }
+                }
+
+                let healthy = health.iter().filter(|endpoint| endpoint.healthy).count();
+                println!();
+                println!("{healthy}/{} endpoint(s) healthy", health.len());
+            }
+        }
 
         Ok(())
     }
@@ -1746,26 +3391,20 @@ impl App {
     async fn handle_vector_stats(
         &self,
         collection: Option<String>,
-        _format: String,
-        _detailed: bool,
+        format: String,
+        detailed: bool,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _client = self
+        let client = self
             .qdrant_client
             .as_ref()
-            .ok_or("Vector database client not available. Please configure vector database.")?;
+            .ok_or_else(|| self.vector_unavailable("Vector database client"))?;
 
         info!("Retrieving vector database statistics");
 
-        let collection_name = collection.unwrap_or_else(|| {
-            self.config
-                .vector
-                .as_ref()
-                .map(|v| v.default_collection.clone())
-                .unwrap_or_else(|| "fortitude_research".to_string())
-        });
-
-        // Placeholder stats implementation
-        println!("Collection stats not yet implemented for '{collection_name}'");
+        let stats = client
+            .collection_stats(&self.collection_name(collection))
+            .await?;
+        print!("{}", format_collection_stats(&stats, &format, detailed)?);
 
         Ok(())
     }
@@ -1780,59 +3419,220 @@ impl App {
         dry_run: bool,
         resume: Option<String>,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _migration_service = self
+        let validation_level: ValidationLevel = validation.parse()?;
+        let migration_service = self
             .migration_service
             .as_ref()
-            .ok_or("Migration service not available. Please configure vector database.")?;
+            .ok_or_else(|| self.vector_unavailable("Migration service"))?;
+
+        if let Some(checkpoint) = resume {
+            let migration_id = migration_service
+                .resume_from_checkpoint(&checkpoint)
+                .await?;
+            println!("Resuming migration {migration_id} from its last checkpoint");
+            return self
+                .wait_for_migration(migration_service, &migration_id)
+                .await;
+        }
 
         info!("Starting data migration from: {}", source);
 
-        let collection_name = collection.unwrap_or_else(|| {
-            self.config
-                .vector
-                .as_ref()
-                .map(|v| v.default_collection.clone())
-                .unwrap_or_else(|| "fortitude_research".to_string())
-        });
+        // Documents are stored through the vector storage, which writes to the default collection
+        let default_collection = self
+            .config
+            .vector
+            .as_ref()
+            .map(|v| v.default_collection.clone())
+            .unwrap_or_else(|| "fortitude_research".to_string());
+        let collection_name = collection.unwrap_or_else(|| default_collection.clone());
+        if collection_name != default_collection {
+            return Err(format!(
+                "Migrations write to the configured collection '{default_collection}'; set vector.default_collection to migrate into '{collection_name}'"
+            )
+            .into());
+        }
+
+        let source_path = PathBuf::from(&source);
+        let is_reference_library = matches!(
+            (
+                source_path.canonicalize(),
+                self.config.storage.base_path.canonicalize(),
+            ),
+            (Ok(a), Ok(b)) if a == b
+        );
+        let migration_source = if is_reference_library {
+            MigrationSource::StorageSystem {
+                storage_config: self.config.storage.to_core_config(),
+            }
+        } else if source_path.is_dir() {
+            MigrationSource::JsonDirectory {
+                directory_path: source_path,
+            }
+        } else {
+            MigrationSource::JsonFile {
+                file_path: source_path,
+            }
+        };
+        let migration_config = MigrationConfig {
+            batch_size,
+            validation_level,
+            dry_run,
+            ..Default::default()
+        };
+
+        println!("Migration from '{source}' to collection '{collection_name}'");
+        println!("Batch size: {batch_size}, Validation: {validation_level}");
+
+        let migration_id = migration_service
+            .start_migration_with_config(migration_source, migration_config)
+            .await?;
+        let report_path = migration_service.validation_report_path(&migration_id);
 
         if dry_run {
-            println!("DRY RUN: Migration simulation");
-            println!("Source: {source}");
-            println!("Collection: {collection_name}");
-            println!("Batch size: {batch_size}");
-            println!("Validation level: {validation}");
-            // Perform validation and report what would be migrated
+            println!("DRY RUN: nothing was migrated");
+            if let Some(report) = migration_service
+                .get_validation_report(&migration_id)
+                .await?
+            {
+                println!(
+                    "Validated {} documents: {} accepted, {} rejected",
+                    report.total_validated, report.accepted, report.rejected
+                );
+                for error in report.errors.iter().take(20) {
+                    println!(
+                        "  {} [{}] {}",
+                        error.item_id, error.error_type, error.message
+                    );
+                }
+                if report.errors.len() > 20 {
+                    println!("  ... {} more", report.errors.len() - 20);
+                }
+            }
+            println!("Validation report: {}", report_path.display());
             return Ok(());
         }
 
-        // Placeholder migration implementation
-        println!("Migration from '{source}' to collection '{collection_name}'");
-        println!("Batch size: {batch_size}, Validation: {validation}");
-        if let Some(checkpoint_id) = resume {
-            println!("Resume from checkpoint: {checkpoint_id}");
-        }
-        println!("Migration functionality not yet implemented");
+        println!("Migration started: {migration_id}");
+        self.wait_for_migration(migration_service, &migration_id)
+            .await?;
+        println!("Validation report: {}", report_path.display());
 
         Ok(())
     }
 
+    /// Follow a running migration until it stops, then print its outcome
+    async fn wait_for_migration(
+        &self,
+        migration_service: &MigrationService,
+        migration_id: &str,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mut last_reported = None;
+        let state = loop {
+            let state = migration_service.get_migration_status(migration_id).await?;
+            let progress = &state.progress;
+            let counts = (progress.processed_items, progress.failed_items);
+            if last_reported != Some(counts) {
+                println!(
+                    "  {}/{} migrated, {} failed ({:.0}%)",
+                    progress.processed_items,
+                    progress.total_items,
+                    progress.failed_items,
+                    progress.completion_percentage()
+                );
+                last_reported = Some(counts);
+            }
+            if !matches!(
+                state.status,
+                MigrationStatus::Planning | MigrationStatus::InProgress
+            ) {
+                break state;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        };
+
+        Self::print_migration_outcome(&state);
+        match state.status {
+            MigrationStatus::Completed => Ok(()),
+            MigrationStatus::Failed => Err(format!(
+                "Migration {migration_id} failed: {}. Resume with --resume {migration_id}",
+                state.error_message.as_deref().unwrap_or("unknown error")
+            )
+            .into()),
+            _ => {
+                println!("Resume with --resume {migration_id}");
+                Ok(())
+            }
+        }
+    }
+
+    fn print_migration_outcome(state: &MigrationState) {
+        println!("Migration {}: {:?}", state.id, state.status);
+        println!(
+            "  {} items migrated, {} failed",
+            state.completed_keys.len(),
+            state.failed_items.len()
+        );
+        for failed in state.failed_items.iter().take(10) {
+            println!("  {} {}", failed.item_id, failed.error);
+        }
+        if state.failed_items.len() > 10 {
+            println!("  ... {} more", state.failed_items.len() - 10);
+        }
+
+        let Some(verification) = &state.verification else {
+            return;
+        };
+        println!(
+            "Verification {}: {} source items, {} migrated, {}/{} documents found ({} in storage)",
+            if verification.passed {
+                "passed"
+            } else {
+                "FAILED"
+            },
+            verification.source_items,
+            verification.migrated_items,
+            verification.found_documents,
+            verification.expected_documents,
+            verification.storage_documents
+        );
+        for check in &verification.spot_checks {
+            let score = check
+                .score
+                .map_or_else(|| "not returned".to_string(), |s| format!("{s:.3}"));
+            println!(
+                "  {} {} similarity {}",
+                if check.passed { "ok  " } else { "FAIL" },
+                check.cache_key.as_deref().unwrap_or(&check.document_id),
+                score
+            );
+        }
+    }
+
     async fn handle_migration_status(
         &self,
         id: Option<String>,
         _format: String,
         all: bool,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _migration_service = self
+        let migration_service = self
             .migration_service
             .as_ref()
-            .ok_or("Migration service not available. Please configure vector database.")?;
+            .ok_or_else(|| self.vector_unavailable("Migration service"))?;
 
         if let Some(migration_id) = id {
-            println!("Migration status for ID: {migration_id}");
-            println!("Migration status functionality not yet implemented");
+            let state = migration_service
+                .get_migration_status(&migration_id)
+                .await?;
+            println!(
+                "Progress: {}/{} migrated, {} failed, phase {}",
+                state.progress.processed_items,
+                state.progress.total_items,
+                state.progress.failed_items,
+                state.progress.current_phase
+            );
+            Self::print_migration_outcome(&state);
         } else if all {
-            println!("Listing all migrations");
-            println!("Migration listing functionality not yet implemented");
+            return self.handle_migration_list(_format, false).await;
         } else {
             return Err("Please specify migration ID or use --all flag".into());
         }
@@ -1845,10 +3645,10 @@ impl App {
         id: String,
         force: bool,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _migration_service = self
+        let migration_service = self
             .migration_service
             .as_ref()
-            .ok_or("Migration service not available. Please configure vector database.")?;
+            .ok_or_else(|| self.vector_unavailable("Migration service"))?;
 
         info!("Resuming migration: {}", id);
 
@@ -1856,10 +3656,10 @@ impl App {
             info!("Force resuming migration (ignoring warnings)");
         }
 
-        println!("Resume migration: {id}");
-        println!("Migration resume functionality not yet implemented");
-
-        Ok(())
+        let migration_id = migration_service.resume_from_checkpoint(&id).await?;
+        println!("Resuming migration {migration_id} from its last checkpoint");
+        self.wait_for_migration(migration_service, &migration_id)
+            .await
     }
 
     async fn handle_migration_cancel(
@@ -1867,10 +3667,10 @@ impl App {
         id: String,
         force: bool,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _migration_service = self
+        let migration_service = self
             .migration_service
             .as_ref()
-            .ok_or("Migration service not available. Please configure vector database.")?;
+            .ok_or_else(|| self.vector_unavailable("Migration service"))?;
 
         info!("Cancelling migration: {}", id);
 
@@ -1878,8 +3678,8 @@ impl App {
             info!("Force cancelling migration");
         }
 
-        println!("Cancel migration: {id}");
-        println!("Migration cancel functionality not yet implemented");
+        migration_service.cancel_migration(&id).await?;
+        println!("Migration {id} cancelled");
 
         Ok(())
     }
@@ -1889,16 +3689,41 @@ impl App {
         _format: String,
         active: bool,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _migration_service = self
+        let migration_service = self
             .migration_service
             .as_ref()
-            .ok_or("Migration service not available. Please configure vector database.")?;
+            .ok_or_else(|| self.vector_unavailable("Migration service"))?;
 
-        println!(
-            "Listing {} migrations",
-            if active { "active" } else { "all" }
-        );
-        println!("Migration listing functionality not yet implemented");
+        let migrations: Vec<_> = migration_service
+            .list_migrations()
+            .await?
+            .into_iter()
+            .filter(|m| {
+                !active
+                    || matches!(
+                        m.status,
+                        MigrationStatus::Planning
+                            | MigrationStatus::InProgress
+                            | MigrationStatus::Paused
+                    )
+            })
+            .collect();
+
+        if migrations.is_empty() {
+            println!("No migrations found");
+            return Ok(());
+        }
+        for migration in migrations {
+            println!(
+                "{}  {:<10} {:>6}/{:<6} {}  {}",
+                migration.id,
+                format!("{:?}", migration.status),
+                migration.progress.processed_items,
+                migration.progress.total_items,
+                migration.source_type,
+                migration.created_at.format("%Y-%m-%d %H:%M")
+            );
+        }
 
         Ok(())
     }
@@ -1908,26 +3733,35 @@ impl App {
         &self,
         period: u32,
         collection: Option<String>,
-        _format: String,
-        _performance: bool,
+        format: String,
+        performance: bool,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _semantic_search = self
-            .semantic_search
+        let client = self
+            .qdrant_client
             .as_ref()
-            .ok_or("Semantic search service not available. Please configure vector database.")?;
+            .ok_or_else(|| self.vector_unavailable("Vector database client"))?;
 
         info!("Retrieving search analytics for {} days", period);
 
-        let collection_name = collection.unwrap_or_else(|| {
-            self.config
-                .vector
-                .as_ref()
-                .map(|v| v.default_collection.clone())
-                .unwrap_or_else(|| "fortitude_research".to_string())
-        });
+        let collection_name = self.collection_name(collection);
+        let since = chrono::Utc::now() - chrono::Duration::days(i64::from(period));
+        let analytics = client.collection_analytics(&collection_name, since).await?;
+        let stats = if performance {
+            Some(client.collection_stats(&collection_name).await?)
+        } else {
+            None
+        };
 
-        println!("Analytics for '{collection_name}' (last {period} days)");
-        println!("Analytics functionality not yet implemented");
+        print!(
+            "{}",
+            format_collection_analytics(
+                &collection_name,
+                period,
+                &analytics,
+                stats.as_ref(),
+                &format
+            )?
+        );
 
         Ok(())
     }
@@ -1942,7 +3776,7 @@ impl App {
         let _client = self
             .qdrant_client
             .as_ref()
-            .ok_or("Vector database client not available. Please configure vector database.")?;
+            .ok_or_else(|| self.vector_unavailable("Vector database client"))?;
 
         let collection_name = collection.unwrap_or_else(|| {
             self.config
@@ -1970,13 +3804,128 @@ impl App {
 
         info!("Setting up vector database collection: {}", collection_name);
 
-        if force {
-            info!("Force recreating collection (existing data will be lost)");
+        // Collections are created with the client's dimensions and metric
+        let setup_config = config::VectorDatabaseConfig {
+            vector_dimensions,
+            distance_metric: distance_metric.clone(),
+            ..self
+                .config
+                .vector
+                .clone()
+                .ok_or("Vector database is not configured. Please configure vector database.")?
         }
+        .to_core_config();
+        let client = QdrantClient::new_lazy(setup_config)?;
 
         println!("Setting up collection: {collection_name}");
         println!("Dimensions: {vector_dimensions}, Distance metric: {distance_metric}");
-        println!("Setup functionality not yet implemented");
+
+        if force {
+            info!("Force recreating collection (existing data will be lost)");
+            if let Err(e) = client.delete_collection(&collection_name).await {
+                warn!("Could not delete collection {}: {}", collection_name, e);
+            }
+        }
+        client.ensure_collection(&collection_name).await?;
+        println!("Collection '{collection_name}' is ready");
+
+        Ok(())
+    }
+
+    async fn handle_vector_export(
+        &self,
+        output: PathBuf,
+        collections: Vec<String>,
+        batch_size: usize,
+        resume: bool,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let vector_config = self
+            .config
+            .vector
+            .as_ref()
+            .ok_or("Vector database is not configured. Please configure vector database.")?
+            .to_core_config();
+        let collections = if collections.is_empty() {
+            vec![vector_config.default_collection.clone()]
+        } else {
+            collections
+        };
+
+        info!(
+            "Exporting {} collection(s) to {}",
+            collections.len(),
+            output.display()
+        );
+        let client = QdrantClient::new_lazy(vector_config.clone())?;
+        let options = ExportOptions { batch_size, resume };
+        let manifest = export_dataset(
+            &client,
+            &output,
+            &collections,
+            &vector_config.embedding.model_name,
+            &options,
+            print_transfer_progress,
+        )
+        .await?;
+        println!();
+
+        println!("Exported dataset to {}", output.display());
+        println!(
+            "{:<30} {:>10} {:>10} {:<34}",
+            "COLLECTION", "POINTS", "DIMENSION", "CHECKSUM (MD5)"
+        );
+        for collection in &manifest.collections {
+            println!(
+                "{:<30} {:>10} {:>10} {:<34}",
+                collection.name, collection.points, collection.dimension, collection.checksum
+            );
+        }
+        println!("Embedding model: {}", manifest.embedding_model);
+
+        Ok(())
+    }
+
+    async fn handle_vector_import(
+        &self,
+        input: PathBuf,
+        mut options: ImportOptions,
+        verify_only: bool,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let vector_config = self
+            .config
+            .vector
+            .as_ref()
+            .ok_or("Vector database is not configured. Please configure vector database.")?
+            .to_core_config();
+        options.embedding_model = Some(vector_config.embedding.model_name.clone());
+        options.dimension = Some(vector_config.vector_dimensions);
+
+        if verify_only {
+            let manifest = verify_dataset(&input, &options).await?;
+            let points: u64 = manifest.collections.iter().map(|c| c.points).sum();
+            println!(
+                "Dataset {} is valid: {} collection(s), {} point(s), embedding model {}",
+                input.display(),
+                manifest.collections.len(),
+                points,
+                manifest.embedding_model
+            );
+            return Ok(());
+        }
+
+        info!("Importing dataset from {}", input.display());
+        let client = QdrantClient::new_lazy(vector_config)?;
+        let summaries = import_dataset(&client, &input, &options, print_transfer_progress).await?;
+        println!();
+
+        println!("Imported dataset from {}", input.display());
+        println!("{:<30} {:>10} {:>10}", "COLLECTION", "IMPORTED", "RESUMED");
+        for summary in &summaries {
+            println!(
+                "{:<30} {:>10} {:>10}",
+                summary.name, summary.imported, summary.resumed
+            );
+        }
 
         Ok(())
     }
@@ -1986,10 +3935,9 @@ impl App {
         index_command: IndexCommand,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
         match index_command {
-            IndexCommand::Status {
-                collection,
-                _format,
-            } => self.handle_index_status(collection, _format).await,
+            IndexCommand::Status { collection, format } => {
+                self.handle_index_status(collection, format).await
+            }
             IndexCommand::Rebuild { collection, force } => {
                 self.handle_index_rebuild(collection, force).await
             }
@@ -2000,22 +3948,17 @@ impl App {
     async fn handle_index_status(
         &self,
         collection: Option<String>,
-        _format: String,
+        format: String,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _client = self
+        let client = self
             .qdrant_client
             .as_ref()
-            .ok_or("Vector database client not available. Please configure vector database.")?;
-
-        let collection_name = collection.unwrap_or_else(|| {
-            self.config
-                .vector
-                .as_ref()
-                .map(|v| v.default_collection.clone())
-                .unwrap_or_else(|| "fortitude_research".to_string())
-        });
+            .ok_or_else(|| self.vector_unavailable("Vector database client"))?;
 
-        println!("Index status for '{collection_name}' not yet implemented");
+        let stats = client
+            .collection_stats(&self.collection_name(collection))
+            .await?;
+        print!("{}", format_collection_stats(&stats, &format, true)?);
 
         Ok(())
     }
@@ -2025,26 +3968,35 @@ impl App {
         collection: Option<String>,
         force: bool,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _client = self
+        let client = self
             .qdrant_client
             .as_ref()
-            .ok_or("Vector database client not available. Please configure vector database.")?;
+            .ok_or_else(|| self.vector_unavailable("Vector database client"))?;
 
-        let collection_name = collection.unwrap_or_else(|| {
-            self.config
-                .vector
-                .as_ref()
-                .map(|v| v.default_collection.clone())
-                .unwrap_or_else(|| "fortitude_research".to_string())
-        });
+        let collection_name = self.collection_name(collection);
+
+        // Rebuilding while the optimizers run would restart their work
+        let stats = client.collection_stats(&collection_name).await?;
+        if stats.status == "yellow" && !force {
+            return Err(format!(
+                "Collection '{collection_name}' is still being optimized. Use --force to rebuild anyway"
+            )
+            .into());
+        }
 
         info!("Rebuilding index for collection: {}", collection_name);
 
-        if force {
-            info!("Force rebuilding index");
-        }
+        let rebuilt = client.rebuild_payload_indexes(&collection_name).await?;
+        client.optimize_collection(&collection_name).await?;
 
-        println!("Index rebuild for '{collection_name}' not yet implemented");
+        println!(
+            "Rebuilt {} payload index(es) on '{collection_name}'",
+            rebuilt.len()
+        );
+        for field in &rebuilt {
+            println!("  {field}");
+        }
+        println!("Vector index optimization started");
 
         Ok(())
     }
@@ -2053,72 +4005,106 @@ impl App {
         &self,
         collection: Option<String>,
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let _client = self
+        let client = self
             .qdrant_client
             .as_ref()
-            .ok_or("Vector database client not available. Please configure vector database.")?;
+            .ok_or_else(|| self.vector_unavailable("Vector database client"))?;
 
-        let collection_name = collection.unwrap_or_else(|| {
-            self.config
-                .vector
-                .as_ref()
-                .map(|v| v.default_collection.clone())
-                .unwrap_or_else(|| "fortitude_research".to_string())
-        });
+        let collection_name = self.collection_name(collection);
 
         info!("Optimizing index for collection: {}", collection_name);
 
-        println!("Index optimization for '{collection_name}' not yet implemented");
+        client.optimize_collection(&collection_name).await?;
+        println!("Optimization started for '{collection_name}'");
 
         Ok(())
     }
+}
 
-    // Output formatting methods
-    #[allow(dead_code)] // TODO: Use in semantic search result display
-    fn print_semantic_search_table(&self, results: &[VectorSearchResult]) {
-        if results.is_empty() {
-            println!("No search results found");
-            return;
+/// Keyword index over the stored research results, the keyword half of hybrid search
+async fn keyword_searcher_from_storage(
+    storage: &(dyn Storage + Send + Sync),
+) -> std::result::Result<KeywordSearcher, Box<dyn std::error::Error>> {
+    let mut documents = Vec::new();
+    for entry in storage.list_cache_entries().await? {
+        match storage.retrieve(&entry.key).await {
+            Ok(Some(result)) => documents.push(research_vector_document(&result)),
+            Ok(None) => {}
+            Err(e) => warn!("Skipping stored result {}: {}", entry.key, e),
         }
+    }
 
-        println!("{:<10} {:<40} {:<30}", "Score", "Content", "Source");
-        println!("{}", "-".repeat(80));
-
-        for result in results {
-            let content_short = if result.document.content.len() > 38 {
-                format!("{}...", &result.document.content[..35])
-            } else {
-                result.document.content.clone()
-            };
-
-            let source_short = match &result.document.metadata.source {
-                Some(source) if source.len() > 28 => format!("{}...", &source[..25]),
-                Some(source) => source.clone(),
-                None => "Unknown".to_string(),
-            };
+    let mut keyword_searcher = KeywordSearcher::new();
+    keyword_searcher.index_documents(documents).await?;
+    Ok(keyword_searcher)
+}
 
-            println!(
-                "{:<10.3} {:<40} {:<30}",
-                result.relevance_score, content_short, source_short
-            );
-        }
+/// Search the vector collection and the stored research results with the
+/// requested weights and fuse the matches
+///
+/// When the vector search fails the keyword matches are returned on their own.
+async fn run_hybrid_search(
+    semantic_search: Arc<SemanticSearchService>,
+    storage: &(dyn Storage + Send + Sync),
+    params: &HybridSearchParams,
+    collection_name: String,
+) -> std::result::Result<HybridSearchResultSet, Box<dyn std::error::Error>> {
+    let keyword_searcher = keyword_searcher_from_storage(storage).await?;
+    let hybrid_search =
+        HybridSearchService::with_defaults(semantic_search, Arc::new(keyword_searcher));
+
+    let request = HybridSearchRequest {
+        query: params.query.clone(),
+        strategy: Some(SearchStrategy::Custom {
+            vector_weight: params.semantic_weight,
+            keyword_weight: params.keyword_weight,
+        }),
+        fusion_method: None,
+        score_normalization: None,
+        rrf_k: None,
+        options: SearchOptions {
+            limit: params.limit,
+            collection: Some(collection_name),
+            include_explanations: params.explain,
+            ..Default::default()
+        },
+        include_explanations: params.explain,
+        custom_weights: None,
+        min_hybrid_score: Some(params.threshold),
+    };
+    Ok(hybrid_search.hybrid_search(request).await?)
+}
 
-        println!("\nTotal results: {}", results.len());
+/// `text` cut to at most `width` characters, marking the cut with an ellipsis
+fn shorten(text: &str, width: usize) -> String {
+    if text.chars().count() > width {
+        let kept: String = text.chars().take(width.saturating_sub(3)).collect();
+        format!("{kept}...")
+    } else {
+        text.to_string()
     }
+}
 
-    #[allow(dead_code)] // TODO: Use in detailed semantic search result display
-    fn print_semantic_search_detailed(&self, results: &[VectorSearchResult], explain: bool) {
-        if results.is_empty() {
-            println!("No search results found");
-            return;
-        }
-
-        println!("Semantic Search Results:");
-        println!("========================");
+/// Render semantic search results as a table, a detailed listing or JSON
+fn format_semantic_results(
+    results: &[VectorSearchResult],
+    format: &str,
+    explain: bool,
+) -> std::result::Result<String, serde_json::Error> {
+    if format == "json" {
+        return Ok(format!("{}\n", serde_json::to_string_pretty(results)?));
+    }
+    if results.is_empty() {
+        return Ok("No search results found\n".to_string());
+    }
 
+    let mut lines = Vec::new();
+    if format == "detailed" {
+        lines.push("Semantic Search Results:".to_string());
+        lines.push("========================".to_string());
         for (i, result) in results.iter().enumerate() {
-            println!("\n{}. Score: {:.3}", i + 1, result.relevance_score);
-            println!(
+            lines.push(format!("\n{}. Score: {:.3}", i + 1, result.relevance_score));
+            lines.push(format!(
                 "   Source: {}",
                 result
                     .document
@@ -2126,77 +4112,70 @@ impl App {
                     .source
                     .as_deref()
                     .unwrap_or("Unknown")
-            );
-            println!("   Content: {}", result.document.content);
-
+            ));
+            lines.push(format!("   Content: {}", result.document.content));
             if explain {
-                println!("   Explanation: Vector similarity search using cosine distance");
-                println!("   Similarity Score: {:.3}", result.similarity_score);
+                lines.push(format!(
+                    "   Similarity Score: {:.3}",
+                    result.similarity_score
+                ));
                 if let Some(explanation) = &result.explanation {
-                    println!("   Details: {}", explanation.calculation);
+                    lines.push(format!("   Details: {}", explanation.calculation));
                 }
             }
         }
-
-        println!("\nTotal results: {}", results.len());
-    }
-
-    #[allow(dead_code)] // TODO: Use in hybrid search result display
-    fn print_hybrid_search_table(&self, results: &[VectorHybridSearchResult]) {
-        if results.is_empty() {
-            println!("No search results found");
-            return;
-        }
-
-        println!(
-            "{:<10} {:<10} {:<30} {:<30}",
-            "Score", "Strategy", "Content", "Source"
-        );
-        println!("{}", "-".repeat(80));
-
+    } else {
+        lines.push(format!(
+            "{:<10} {:<40} {:<30}",
+            "Score", "Content", "Source"
+        ));
+        lines.push("-".repeat(80));
         for result in results {
-            let content_short = if result.document.content.len() > 28 {
-                format!("{}...", &result.document.content[..25])
-            } else {
-                result.document.content.clone()
-            };
-
-            let source_short = match &result.document.metadata.source {
-                Some(source) if source.len() > 28 => format!("{}...", &source[..25]),
-                Some(source) => source.clone(),
-                None => "Unknown".to_string(),
-            };
-
-            println!(
-                "{:<10.3} {:<10} {:<30} {:<30}",
-                result.hybrid_score,
-                format!("{:?}", result.strategy),
-                content_short,
-                source_short
-            );
+            lines.push(format!(
+                "{:<10.3} {:<40} {:<30}",
+                result.relevance_score,
+                shorten(&result.document.content, 38),
+                shorten(
+                    result
+                        .document
+                        .metadata
+                        .source
+                        .as_deref()
+                        .unwrap_or("Unknown"),
+                    28
+                )
+            ));
         }
-
-        println!("\nTotal results: {}", results.len());
     }
+    lines.push(format!("\nTotal results: {}", results.len()));
+    Ok(lines.join("\n") + "\n")
+}
 
-    #[allow(dead_code)] // TODO: Use in detailed hybrid search result display
-    fn print_hybrid_search_detailed(&self, results: &[VectorHybridSearchResult], explain: bool) {
-        if results.is_empty() {
-            println!("No search results found");
-            return;
-        }
-
-        println!("Hybrid Search Results:");
-        println!("=====================");
+/// Render hybrid search results as a table, a detailed listing or JSON
+fn format_hybrid_results(
+    results: &[VectorHybridSearchResult],
+    format: &str,
+    explain: bool,
+) -> std::result::Result<String, serde_json::Error> {
+    if format == "json" {
+        return Ok(format!("{}\n", serde_json::to_string_pretty(results)?));
+    }
+    if results.is_empty() {
+        return Ok("No search results found\n".to_string());
+    }
 
+    let mut lines = Vec::new();
+    if format == "detailed" {
+        lines.push("Hybrid Search Results:".to_string());
+        lines.push("=====================".to_string());
         for (i, result) in results.iter().enumerate() {
-            println!(
+            lines.push(format!(
                 "\n{}. Score: {:.3} (Strategy: {:?})",
                 i + 1,
                 result.hybrid_score,
                 result.strategy
-            );
-            println!(
+            ));
+            lines.push(format!(
                 "   Source: {}",
                 result
                     .document
@@ -2204,22 +4183,120 @@ impl App {
                     .source
                     .as_deref()
                     .unwrap_or("Unknown")
-            );
-            println!("   Content: {}", result.document.content);
-
+            ));
+            lines.push(format!("   Content: {}", result.document.content));
             if explain {
-                println!("   Explanation: Hybrid search combining vector similarity and keyword matching");
-                println!(
+                lines.push(format!(
                     "   Vector score: {:.3}, Keyword score: {:.3}",
                     result.vector_score.unwrap_or(0.0),
                     result.keyword_score.unwrap_or(0.0)
-                );
-                println!("   Fusion method: {:?}", result.fusion_method);
+                ));
+                lines.push(format!("   Fusion method: {:?}", result.fusion_method));
             }
         }
+    } else {
+        lines.push(format!(
+            "{:<10} {:<10} {:<30} {:<30}",
+            "Score", "Strategy", "Content", "Source"
+        ));
+        lines.push("-".repeat(80));
+        for result in results {
+            lines.push(format!(
+                "{:<10.3} {:<10} {:<30} {:<30}",
+                result.hybrid_score,
+                shorten(&format!("{:?}", result.strategy), 10),
+                shorten(&result.document.content, 28),
+                shorten(
+                    result
+                        .document
+                        .metadata
+                        .source
+                        .as_deref()
+                        .unwrap_or("Unknown"),
+                    28
+                )
+            ));
+        }
+    }
+    lines.push(format!("\nTotal results: {}", results.len()));
+    Ok(lines.join("\n") + "\n")
+}
 
-        println!("\nTotal results: {}", results.len());
+/// Render collection statistics as a table or JSON; `detailed` lists the
+/// payload indexes
+fn format_collection_stats(
+    stats: &CollectionStats,
+    format: &str,
+    detailed: bool,
+) -> std::result::Result<String, serde_json::Error> {
+    if format == "json" {
+        return Ok(format!("{}\n", serde_json::to_string_pretty(stats)?));
     }
+
+    let mut lines = vec![
+        format!("Collection: {}", stats.collection_name),
+        format!("Status: {}", stats.status),
+    ];
+    if let Some(error) = &stats.optimizer_error {
+        lines.push(format!("Optimizer error: {error}"));
+    }
+    lines.push(format!("Points: {}", stats.points_count));
+    lines.push(format!("Indexed vectors: {}", stats.indexed_vectors_count));
+    lines.push(format!("Segments: {}", stats.segments_count));
+    lines.push(format!("Payload indexes: {}", stats.payload_indexes.len()));
+    if detailed {
+        for (field, data_type) in &stats.payload_indexes {
+            lines.push(format!("  {field:<30} {data_type}"));
+        }
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Render collection analytics, with collection statistics when given, as a
+/// table or JSON
+fn format_collection_analytics(
+    collection_name: &str,
+    period: u32,
+    analytics: &CollectionAnalytics,
+    stats: Option<&CollectionStats>,
+    format: &str,
+) -> std::result::Result<String, serde_json::Error> {
+    if format == "json" {
+        let report = serde_json::json!({
+            "collection": collection_name,
+            "period_days": period,
+            "analytics": analytics,
+            "stats": stats,
+        });
+        return Ok(format!("{}\n", serde_json::to_string_pretty(&report)?));
+    }
+
+    let mut lines = vec![
+        format!("Analytics for '{collection_name}' (last {period} days)"),
+        format!("Total documents: {}", analytics.total_documents),
+        format!("Documents in period: {}", analytics.recent_documents),
+    ];
+    if let Some(quality) = analytics.average_quality {
+        lines.push(format!("Average quality: {quality:.2}"));
+    }
+    if !analytics.by_research_type.is_empty() {
+        lines.push("By research type:".to_string());
+        for (research_type, count) in &analytics.by_research_type {
+            lines.push(format!("  {research_type:<20} {count}"));
+        }
+    }
+    if !analytics.by_content_type.is_empty() {
+        lines.push("By content type:".to_string());
+        for (content_type, count) in &analytics.by_content_type {
+            lines.push(format!("  {content_type:<20} {count}"));
+        }
+    }
+    let mut output = lines.join("\n") + "\n";
+    if let Some(stats) = stats {
+        output.push('\n');
+        output.push_str(&format_collection_stats(stats, format, false)?);
+    }
+    Ok(output)
 }
 
 #[cfg(test)]
@@ -2246,6 +4323,107 @@ mod tests {
             .stdout(predicate::str::contains("0.1.0"));
     }
 
+    #[tokio::test]
+    async fn test_vector_services_report_unreachable_server() {
+        let vector_config = config::VectorDatabaseConfig {
+            url: "http://127.0.0.1:1".to_string(),
+            timeout_seconds: 2,
+            ..Default::default()
+        };
+
+        let services =
+            App::init_vector_services(&vector_config, PathBuf::from("./migrations")).await;
+        assert!(services.qdrant_client.is_none());
+        assert!(services.semantic_search.is_none());
+        let reason = services.unavailable_reason.unwrap();
+        assert!(reason.starts_with("cannot connect to Qdrant at http://127.0.0.1:1"));
+    }
+
+    fn stored_result(query: &str, answer: &str, cache_key: &str) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            query.to_string(),
+            ResearchType::Learning,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: chrono::Utc::now(),
+            processing_time_ms: 1000,
+            sources_consulted: vec![],
+            quality_score: 0.9,
+            cache_key: cache_key.to_string(),
+            tags: std::collections::HashMap::new(),
+        };
+        ResearchResult::new(request, answer.to_string(), vec![], vec![], metadata)
+    }
+
+    #[tokio::test]
+    async fn test_hybrid_search_finds_stored_results_without_vector_server() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = FileStorage::new(StorageConfig {
+            base_path: temp_dir.path().to_path_buf(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        storage
+            .store(&stored_result(
+                "How do Rust lifetimes work?",
+                "Lifetimes tie borrows to the scope of their owner.",
+                "lifetimes-key",
+            ))
+            .await
+            .unwrap();
+        storage
+            .store(&stored_result(
+                "What is a Python decorator?",
+                "A decorator wraps a function in another function.",
+                "decorators-key",
+            ))
+            .await
+            .unwrap();
+
+        // Vector search fails against the unreachable server, leaving the keyword matches
+        let vector_config = config::VectorDatabaseConfig {
+            url: "http://127.0.0.1:1".to_string(),
+            timeout_seconds: 2,
+            ..Default::default()
+        }
+        .to_core_config();
+        let qdrant_client = Arc::new(QdrantClient::new_lazy(vector_config.clone()).unwrap());
+        let embedding_service = Arc::new(EmbeddingService::new(vector_config.embedding));
+        let semantic_search = Arc::new(SemanticSearchService::with_defaults(Arc::new(
+            VectorStorage::new(qdrant_client, embedding_service),
+        )));
+
+        let params = HybridSearchParams {
+            query: "lifetimes".to_string(),
+            keyword_weight: 0.5,
+            semantic_weight: 0.5,
+            limit: 10,
+            threshold: 0.0,
+            format: "detailed".to_string(),
+            collection: None,
+            explain: false,
+        };
+        let result_set = run_hybrid_search(
+            semantic_search,
+            &storage,
+            &params,
+            "fortitude_research".to_string(),
+        )
+        .await
+        .unwrap();
+
+        let output = format_hybrid_results(&result_set.results, &params.format, false).unwrap();
+        assert!(output.contains("How do Rust lifetimes work?"));
+        assert!(output.contains("Lifetimes tie borrows to the scope of their owner."));
+        assert!(!output.contains("Python decorator"));
+        assert!(output.ends_with("Total results: 1\n"));
+    }
+
     #[test]
     fn test_format_size() {
         assert_eq!(App::format_size(512), "512.0 B");
@@ -2272,6 +4450,225 @@ mod tests {
         cmd.assert().failure(); // Expected to fail without proper configuration
     }
 
+    #[test]
+    fn test_cli_research_parent_argument() {
+        let cli = Cli::try_parse_from([
+            "fortitude",
+            "research",
+            "--parent",
+            "abc123",
+            "--code",
+            "src/lib.rs",
+            "--project-path",
+            ".",
+            "--budget-profile",
+            "concise",
+            "--time-budget-ms",
+            "10000",
+            "--force",
+            "How do I add retries?",
+        ])
+        .unwrap();
+
+        // Deepening would spend time the budget does not have
+        assert!(Cli::try_parse_from([
+            "fortitude",
+            "research",
+            "--time-budget-ms",
+            "10000",
+            "--deep",
+            "How do I add retries?",
+        ])
+        .is_err());
+
+        match cli.command {
+            Commands::Research {
+                topic,
+                parent,
+                code,
+                project_path,
+                technology,
+                budget_profile,
+                time_budget_ms,
+                force,
+                ..
+            } => {
+                assert_eq!(topic, "How do I add retries?");
+                assert_eq!(parent.as_deref(), Some("abc123"));
+                assert_eq!(code, Some(PathBuf::from("src/lib.rs")));
+                assert_eq!(project_path, Some(PathBuf::from(".")));
+                assert_eq!(technology, None);
+                assert_eq!(budget_profile.as_deref(), Some("concise"));
+                assert_eq!(time_budget_ms, Some(10000));
+                assert!(force);
+            }
+            _ => panic!("expected research command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_chat_arguments() {
+        let cli =
+            Cli::try_parse_from(["fortitude", "chat", "--resume", "abc123", "--no-color"]).unwrap();
+
+        match cli.command {
+            Commands::Chat {
+                level,
+                resume,
+                no_color,
+                ..
+            } => {
+                assert_eq!(level, "intermediate");
+                assert_eq!(resume.as_deref(), Some("abc123"));
+                assert!(no_color);
+            }
+            _ => panic!("expected chat command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_interactive_and_completions_arguments() {
+        let cli = Cli::try_parse_from(["fortitude", "interactive", "--resume", "abc123"]).unwrap();
+        match cli.command {
+            Commands::Interactive { resume } => assert_eq!(resume.as_deref(), Some("abc123")),
+            _ => panic!("expected interactive command"),
+        }
+
+        let cli = Cli::try_parse_from(["fortitude", "completions", "zsh"]).unwrap();
+        match cli.command {
+            Commands::Completions { shell } => assert_eq!(shell, clap_complete::Shell::Zsh),
+            _ => panic!("expected completions command"),
+        }
+        assert!(Cli::try_parse_from(["fortitude", "completions", "cmd"]).is_err());
+
+        let mut script = Vec::new();
+        clap_complete::generate(
+            clap_complete::Shell::Bash,
+            &mut Cli::command(),
+            "fortitude",
+            &mut script,
+        );
+        let script = String::from_utf8(script).unwrap();
+        assert!(script.contains("interactive"));
+        assert!(script.contains("semantic-search"));
+    }
+
+    #[test]
+    fn test_cli_vector_export_import_arguments() {
+        let cli = Cli::try_parse_from([
+            "fortitude",
+            "vector",
+            "export",
+            "/tmp/dataset",
+            "--collection",
+            "research,patterns",
+            "--resume",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Vector {
+                vector_command:
+                    VectorCommand::Export {
+                        output,
+                        collection,
+                        batch_size,
+                        resume,
+                    },
+            } => {
+                assert_eq!(output, PathBuf::from("/tmp/dataset"));
+                assert_eq!(collection, vec!["research", "patterns"]);
+                assert_eq!(batch_size, 256);
+                assert!(resume);
+            }
+            _ => panic!("expected vector export command"),
+        }
+
+        let cli = Cli::try_parse_from([
+            "fortitude",
+            "vector",
+            "import",
+            "/tmp/dataset",
+            "--allow-model-mismatch",
+            "--verify-only",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Vector {
+                vector_command:
+                    VectorCommand::Import {
+                        input,
+                        resume,
+                        allow_model_mismatch,
+                        verify_only,
+                        ..
+                    },
+            } => {
+                assert_eq!(input, PathBuf::from("/tmp/dataset"));
+                assert!(!resume);
+                assert!(allow_model_mismatch);
+                assert!(verify_only);
+            }
+            _ => panic!("expected vector import command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_import_arguments() {
+        let cli = Cli::try_parse_from([
+            "fortitude",
+            "import",
+            "notes.jsonl",
+            "docs",
+            "--index",
+            "--dry-run",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Import {
+                paths,
+                format,
+                index,
+                dry_run,
+                output_format,
+            } => {
+                assert_eq!(
+                    paths,
+                    vec![PathBuf::from("notes.jsonl"), PathBuf::from("docs")]
+                );
+                assert!(format.is_none());
+                assert!(index);
+                assert!(dry_run);
+                assert_eq!(output_format, "table");
+            }
+            _ => panic!("expected import command"),
+        }
+        assert!(Cli::try_parse_from(["fortitude", "import"]).is_err());
+    }
+
+    #[test]
+    fn test_collect_import_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let nested = temp_dir.path().join("nested");
+        std::fs::create_dir(&nested).unwrap();
+        std::fs::write(temp_dir.path().join("a.jsonl"), "").unwrap();
+        std::fs::write(temp_dir.path().join("skip.txt"), "").unwrap();
+        std::fs::write(nested.join("b.md"), "").unwrap();
+
+        let files = collect_import_files(&[temp_dir.path().to_path_buf()], None).unwrap();
+        assert_eq!(
+            files,
+            vec![
+                (temp_dir.path().join("a.jsonl"), ImportFormat::Jsonl),
+                (nested.join("b.md"), ImportFormat::Markdown),
+            ]
+        );
+
+        let unknown = temp_dir.path().join("skip.txt");
+        assert!(collect_import_files(std::slice::from_ref(&unknown), None).is_err());
+        let forced = collect_import_files(&[unknown], Some(ImportFormat::Jsonl)).unwrap();
+        assert_eq!(forced[0].1, ImportFormat::Jsonl);
+    }
+
     #[test]
     fn test_cli_config_commands() {
         let mut cmd = Command::cargo_bin("fortitude").unwrap();
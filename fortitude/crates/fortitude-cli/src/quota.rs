@@ -0,0 +1,162 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Client for the API server's quota introspection endpoint used by `fortitude quota`
+// Fetches the caller's rate limits, token quota and monthly budget and renders them as a table
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// API server used when neither `--server` nor `FORTITUDE_API_URL` is set
+pub const DEFAULT_API_URL: &str = "http://127.0.0.1:3000";
+
+/// Rate limits and quota of the calling key, as returned by `/api/v1/limits/me`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Limits {
+    pub key: String,
+    pub tier: String,
+    pub requests: Option<RequestLimit>,
+    pub tokens: TokenQuota,
+    pub budget: Budget,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestLimit {
+    pub limit: u32,
+    pub remaining: u32,
+    pub window_seconds: u64,
+    pub resets_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenQuota {
+    pub limit: Option<u64>,
+    pub used: u64,
+    pub remaining: Option<u64>,
+    pub resets_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Budget {
+    pub month: String,
+    pub requests: u64,
+    pub tokens: u64,
+    pub spent_usd: f64,
+    pub limit_usd: Option<f64>,
+    pub remaining_usd: Option<f64>,
+    pub resets_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ApiResponse<T> {
+    pub data: T,
+}
+
+/// Fetch the limits of the key identified by `token`
+pub async fn fetch_limits(
+    server: &str,
+    token: Option<&str>,
+) -> Result<Limits, Box<dyn std::error::Error>> {
+    let url = format!("{}/api/v1/limits/me", server.trim_end_matches('/'));
+    let mut request = reqwest::Client::new().get(&url);
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request.send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("{url} returned {status}: {body}").into());
+    }
+    Ok(response.json::<ApiResponse<Limits>>().await?.data)
+}
+
+/// Render limits as a human-readable table
+pub fn format_limits(limits: &Limits) -> String {
+    let unlimited = || "unlimited".to_string();
+    let mut output = format!("Key:  {}\nTier: {}\n\n", limits.key, limits.tier);
+
+    match &limits.requests {
+        Some(requests) => output.push_str(&format!(
+            "Requests: {}/{} remaining per {}s (resets {})\n",
+            requests.remaining,
+            requests.limit,
+            requests.window_seconds,
+            requests.resets_at.format("%Y-%m-%d %H:%M:%S UTC")
+        )),
+        None => output.push_str("Requests: not rate limited\n"),
+    }
+
+    let tokens = &limits.tokens;
+    output.push_str(&format!(
+        "Tokens:   {} used, {} remaining of {} (resets {})\n",
+        tokens.used,
+        tokens.remaining.map_or_else(unlimited, |r| r.to_string()),
+        tokens.limit.map_or_else(unlimited, |l| l.to_string()),
+        tokens.resets_at.format("%Y-%m-%d %H:%M:%S UTC")
+    ));
+
+    let budget = &limits.budget;
+    output.push_str(&format!(
+        "Budget:   ${:.4} spent in {}, {} remaining of {} ({} requests, {} tokens; resets {})\n",
+        budget.spent_usd,
+        budget.month,
+        budget
+            .remaining_usd
+            .map_or_else(unlimited, |r| format!("${r:.4}")),
+        budget
+            .limit_usd
+            .map_or_else(unlimited, |l| format!("${l:.2}")),
+        budget.requests,
+        budget.tokens,
+        budget.resets_at.format("%Y-%m-%d")
+    ));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_limits() {
+        let limits: Limits = serde_json::from_value(serde_json::json!({
+            "key": "alice",
+            "tier": "trial",
+            "requests": null,
+            "tokens": {
+                "limit": 1000,
+                "used": 250,
+                "remaining": 750,
+                "resets_at": "2025-03-10T12:01:00Z"
+            },
+            "budget": {
+                "month": "2025-03",
+                "requests": 2,
+                "tokens": 250,
+                "spent_usd": 1.25,
+                "limit_usd": null,
+                "remaining_usd": null,
+                "resets_at": "2025-04-01T00:00:00Z"
+            }
+        }))
+        .unwrap();
+
+        let output = format_limits(&limits);
+        assert!(output.contains("Tier: trial"));
+        assert!(output.contains("Requests: not rate limited"));
+        assert!(output.contains("250 used, 750 remaining of 1000"));
+        assert!(output.contains("$1.2500 spent in 2025-03, unlimited remaining of unlimited"));
+    }
+}
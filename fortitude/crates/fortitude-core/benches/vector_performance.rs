@@ -44,6 +44,7 @@ fn create_test_config() -> VectorConfig {
         },
         connection_pool: fortitude_core::vector::ConnectionPoolConfig::default(),
         embedding: fortitude_core::vector::EmbeddingConfig::default(),
+        replication: Default::default(),
     }
 }
 
@@ -0,0 +1,748 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: RustSec advisory database used to reference known advisories in research answers
+// Downloads the RustSec advisories from the OSV crates.io export, caches them on disk and matches crates and versions in queries, manifests and answers
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+
+/// OSV export of every crates.io advisory, including the RustSec database
+pub const DEFAULT_ADVISORY_SOURCE_URL: &str =
+    "https://osv-vulnerabilities.storage.googleapis.com/crates.io/all.zip";
+
+/// Default location of the cached advisory database
+pub const DEFAULT_ADVISORY_CACHE_PATH: &str = ".fortitude/advisories.json";
+
+/// Metadata tag listing the advisories referenced by a result
+pub const ADVISORIES_TAG: &str = "advisories";
+
+/// Advisories described per crate in the prompt context
+const MAX_ADVISORIES_PER_CRATE: usize = 5;
+
+/// Words that make a query a security question
+const SECURITY_TERMS: &[&str] = &[
+    "security",
+    "vulnerab",
+    "advisory",
+    "advisories",
+    "rustsec",
+    "cve-",
+    "exploit",
+    "unsound",
+    "audit",
+    "safe to use",
+];
+
+/// Errors refreshing or reading the advisory database
+#[derive(Error, Debug)]
+pub enum AdvisoryError {
+    #[error("Failed to download advisories from {url}: {message}")]
+    Download { url: String, message: String },
+
+    #[error("Invalid advisory archive: {0}")]
+    Archive(String),
+
+    #[error("Failed to access advisory cache at {path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Invalid advisory cache at {path}: {source}")]
+    Parse {
+        path: String,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// Versions affected by an advisory: from `introduced` up to, not including, `fixed`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AffectedRange {
+    pub introduced: Option<String>,
+    pub fixed: Option<String>,
+}
+
+/// One RustSec advisory against a crate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Advisory {
+    /// RustSec identifier, e.g. `RUSTSEC-2021-0124`
+    pub id: String,
+    pub package: String,
+    pub title: String,
+    /// CVE and GHSA identifiers of the same issue
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// CVSS vector when the advisory has one
+    pub severity: Option<String>,
+    /// Set for informational advisories (unmaintained, unsound, notice)
+    pub informational: Option<String>,
+    #[serde(default)]
+    pub ranges: Vec<AffectedRange>,
+    pub url: String,
+}
+
+impl Advisory {
+    /// Parse an OSV record, `None` for records that are not RustSec advisories
+    pub fn from_osv(record: &Value) -> Option<Self> {
+        let id = record["id"].as_str()?;
+        if !id.starts_with("RUSTSEC-") || record.get("withdrawn").is_some_and(|w| !w.is_null()) {
+            return None;
+        }
+        let affected = record["affected"].as_array()?.first()?;
+        let package = affected["package"]["name"].as_str()?.to_string();
+        let ranges = affected["ranges"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|range| range["type"] == "SEMVER")
+            .flat_map(|range| events_to_ranges(&range["events"]))
+            .collect();
+        let url = record["references"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|reference| reference["type"] == "ADVISORY")
+            .and_then(|reference| reference["url"].as_str())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("https://rustsec.org/advisories/{id}.html"));
+        Some(Self {
+            id: id.to_string(),
+            package,
+            title: record["summary"]
+                .as_str()
+                .or_else(|| record["details"].as_str())
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+            aliases: record["aliases"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|alias| alias.as_str().map(str::to_string))
+                .collect(),
+            severity: record["severity"]
+                .as_array()
+                .and_then(|severity| severity.first())
+                .and_then(|severity| severity["score"].as_str())
+                .map(str::to_string),
+            informational: affected["database_specific"]["informational"]
+                .as_str()
+                .map(str::to_string),
+            ranges,
+            url,
+        })
+    }
+
+    /// Whether the advisory applies to `version`
+    pub fn affects(&self, version: &Version) -> bool {
+        self.ranges.iter().any(|range| {
+            let after_introduced = range
+                .introduced
+                .as_deref()
+                .and_then(parse_version)
+                .is_none_or(|introduced| *version >= introduced);
+            let before_fixed = range
+                .fixed
+                .as_deref()
+                .and_then(parse_version)
+                .is_none_or(|fixed| *version < fixed);
+            after_introduced && before_fixed
+        })
+    }
+
+    /// Versions that fix the advisory
+    pub fn patched_versions(&self) -> Vec<String> {
+        self.ranges
+            .iter()
+            .filter_map(|range| range.fixed.clone())
+            .collect()
+    }
+
+    /// One-line summary used in prompts and warnings
+    pub fn summary(&self) -> String {
+        let mut line = format!("{} ({}): {}", self.id, self.package, self.title);
+        if let Some(kind) = &self.informational {
+            line.push_str(&format!(" [{kind}]"));
+        }
+        let patched = self.patched_versions();
+        if patched.is_empty() {
+            line.push_str("; no patched release");
+        } else {
+            line.push_str(&format!("; patched in >= {}", patched.join(", >= ")));
+        }
+        line
+    }
+}
+
+fn events_to_ranges(events: &Value) -> Vec<AffectedRange> {
+    let mut ranges = Vec::new();
+    let mut current: Option<AffectedRange> = None;
+    for event in events.as_array().into_iter().flatten() {
+        if let Some(introduced) = event["introduced"].as_str() {
+            if let Some(open) = current.take() {
+                ranges.push(open);
+            }
+            current = Some(AffectedRange {
+                introduced: Some(introduced.to_string()),
+                fixed: None,
+            });
+        } else if let Some(fixed) = event["fixed"].as_str() {
+            let mut range = current.take().unwrap_or(AffectedRange {
+                introduced: None,
+                fixed: None,
+            });
+            range.fixed = Some(fixed.to_string());
+            ranges.push(range);
+        }
+    }
+    ranges.extend(current);
+    ranges
+}
+
+/// Parse a full or shortened (`1`, `1.2`) version
+fn parse_version(version: &str) -> Option<Version> {
+    let version = version.trim().trim_start_matches(['v', '=', '^', '~']);
+    Version::parse(version).ok().or_else(|| {
+        let parts: Vec<&str> = version.split('.').collect();
+        match parts.len() {
+            1 => Version::parse(&format!("{}.0.0", parts[0])).ok(),
+            2 => Version::parse(&format!("{}.{}.0", parts[0], parts[1])).ok(),
+            _ => None,
+        }
+    })
+}
+
+/// Normalized crate name; crates.io treats `-` and `_` as the same
+fn crate_key(name: &str) -> String {
+    name.to_lowercase().replace('-', "_")
+}
+
+/// A crate named in a query, manifest or answer
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CrateMention {
+    pub name: String,
+    /// Version or version requirement given with the crate
+    pub version: Option<String>,
+}
+
+/// An advisory relevant to a mentioned crate
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdvisoryMatch {
+    pub advisory: Advisory,
+    pub mention: CrateMention,
+    /// Whether the mentioned version is affected, `None` when no version was given
+    pub affected: Option<bool>,
+}
+
+/// A version in an answer that has a known advisory
+#[derive(Debug, Clone, PartialEq)]
+pub struct VulnerableRecommendation {
+    pub krate: String,
+    pub version: String,
+    pub advisory: Advisory,
+    /// A patched release is semver compatible with the recommended requirement
+    pub compatible_fix: bool,
+}
+
+impl VulnerableRecommendation {
+    pub fn message(&self) -> String {
+        let fix = if self.compatible_fix {
+            "a compatible patched release exists, make sure the lock file picks it up".to_string()
+        } else if self.advisory.patched_versions().is_empty() {
+            "no patched release exists".to_string()
+        } else {
+            format!(
+                "upgrade to >= {}",
+                self.advisory.patched_versions().join(" or >= ")
+            )
+        };
+        format!(
+            "{} {} is affected by {} ({}); {}",
+            self.krate, self.version, self.advisory.id, self.advisory.title, fix
+        )
+    }
+}
+
+/// Crates with versions in a text: manifest entries, `name@version` and `name 1.2.3`
+fn versioned_mentions(text: &str) -> Vec<CrateMention> {
+    static PATTERNS: OnceLock<Vec<Regex>> = OnceLock::new();
+    let patterns = PATTERNS.get_or_init(|| {
+        [
+            // Cargo.toml: name = "1.2" / name = { version = "1.2", ... }
+            r#"\b([A-Za-z][A-Za-z0-9_-]*)\s*=\s*(?:\{[^}\n]*?version\s*=\s*)?"([~^=<>]*\s*\d+(?:\.\d+){0,2}[^"]*)""#,
+            // Cargo.lock: name = "foo" followed by version = "1.2.3"
+            r#"(?m)^name\s*=\s*"([A-Za-z0-9_-]+)"\s*\n\s*version\s*=\s*"(\d+\.\d+\.\d+[^"]*)""#,
+            // name@1.2.3
+            r"\b([A-Za-z][A-Za-z0-9_-]*)@v?(\d+\.\d+(?:\.\d+)?(?:-[0-9A-Za-z.]+)?)",
+            // name 1.2.3, `name` v1.2
+            r"`?\b([A-Za-z][A-Za-z0-9_-]*)`?\s+(?:version\s+)?v?(\d+\.\d+(?:\.\d+)?(?:-[0-9A-Za-z.]+)?)\b",
+        ]
+        .iter()
+        .map(|pattern| Regex::new(pattern).expect("valid regex"))
+        .collect()
+    });
+    let mut mentions = BTreeSet::new();
+    for pattern in patterns {
+        for captures in pattern.captures_iter(text) {
+            let name = &captures[1];
+            if matches!(name, "version" | "edition" | "rust-version" | "name") {
+                continue;
+            }
+            mentions.insert(CrateMention {
+                name: name.to_string(),
+                version: Some(captures[2].trim().to_string()),
+            });
+        }
+    }
+    mentions.into_iter().collect()
+}
+
+/// Whether a query asks about security
+pub fn is_security_query(query: &str) -> bool {
+    let query = query.to_lowercase();
+    SECURITY_TERMS.iter().any(|term| query.contains(term))
+}
+
+/// RustSec advisories grouped by crate
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdvisoryDatabase {
+    pub fetched_at: Option<DateTime<Utc>>,
+    advisories: HashMap<String, Vec<Advisory>>,
+}
+
+impl AdvisoryDatabase {
+    pub fn new(advisories: impl IntoIterator<Item = Advisory>) -> Self {
+        let mut database = Self::default();
+        for advisory in advisories {
+            database
+                .advisories
+                .entry(crate_key(&advisory.package))
+                .or_default()
+                .push(advisory);
+        }
+        for advisories in database.advisories.values_mut() {
+            advisories.sort_by(|a, b| b.id.cmp(&a.id));
+        }
+        database
+    }
+
+    /// Build the database from the OSV zip export, keeping RustSec advisories
+    pub fn from_osv_zip(bytes: &[u8]) -> Result<Self, AdvisoryError> {
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes))
+            .map_err(|e| AdvisoryError::Archive(e.to_string()))?;
+        let mut advisories = Vec::new();
+        for index in 0..archive.len() {
+            let mut file = archive
+                .by_index(index)
+                .map_err(|e| AdvisoryError::Archive(e.to_string()))?;
+            if !file.name().ends_with(".json") {
+                continue;
+            }
+            let mut content = String::new();
+            if file.read_to_string(&mut content).is_err() {
+                continue;
+            }
+            match serde_json::from_str::<Value>(&content) {
+                Ok(record) => advisories.extend(Advisory::from_osv(&record)),
+                Err(e) => debug!("Skipping advisory {}: {}", file.name(), e),
+            }
+        }
+        Ok(Self::new(advisories))
+    }
+
+    pub fn len(&self) -> usize {
+        self.advisories.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.advisories.is_empty()
+    }
+
+    /// Advisories against a crate, newest first
+    pub fn for_crate(&self, name: &str) -> &[Advisory] {
+        self.advisories
+            .get(&crate_key(name))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    fn has_crate(&self, name: &str) -> bool {
+        self.advisories.contains_key(&crate_key(name))
+    }
+
+    /// Crates with advisories named in a query or attached manifest
+    ///
+    /// Versioned mentions and `crate::path` mentions always count; bare
+    /// crate names only count in security questions, since many crate names
+    /// (`time`, `url`, `regex`) are ordinary words.
+    pub fn mentioned_crates(&self, query: &str, attachment: Option<&str>) -> Vec<CrateMention> {
+        let mut mentions: BTreeSet<CrateMention> = versioned_mentions(query)
+            .into_iter()
+            .chain(attachment.map(versioned_mentions).unwrap_or_default())
+            .filter(|mention| self.has_crate(&mention.name))
+            .collect();
+        let mut named: BTreeSet<String> = crate::crate_docs::mentioned_crate_paths(query)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        if is_security_query(query) {
+            named.extend(
+                query
+                    .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '-')
+                    .filter(|word| word.len() > 1)
+                    .map(str::to_lowercase),
+            );
+        }
+        for name in named {
+            let covered = mentions
+                .iter()
+                .any(|mention| crate_key(&mention.name) == crate_key(&name));
+            if !covered && self.has_crate(&name) {
+                mentions.insert(CrateMention {
+                    name,
+                    version: None,
+                });
+            }
+        }
+        mentions.into_iter().collect()
+    }
+
+    /// Advisories for the crates mentioned in a query or attached manifest
+    pub fn matches(&self, query: &str, attachment: Option<&str>) -> Vec<AdvisoryMatch> {
+        let mut matches = Vec::new();
+        for mention in self.mentioned_crates(query, attachment) {
+            let version = mention.version.as_deref().and_then(parse_version);
+            for advisory in self.for_crate(&mention.name) {
+                let affected = version.as_ref().map(|version| advisory.affects(version));
+                // A pinned version only needs the advisories that apply to it
+                if affected == Some(false) {
+                    continue;
+                }
+                matches.push(AdvisoryMatch {
+                    advisory: advisory.clone(),
+                    mention: mention.clone(),
+                    affected,
+                });
+            }
+        }
+        matches
+    }
+
+    /// Prompt section describing the advisories, `None` when there are none
+    pub fn prompt_context(&self, matches: &[AdvisoryMatch]) -> Option<String> {
+        if matches.is_empty() {
+            return None;
+        }
+        let mut context = String::from(
+            "Known RustSec advisories for crates in this question (reference them and do not recommend affected versions):",
+        );
+        let mut per_crate: HashMap<String, usize> = HashMap::new();
+        for advisory_match in matches {
+            let count = per_crate
+                .entry(crate_key(&advisory_match.mention.name))
+                .or_default();
+            *count += 1;
+            if *count > MAX_ADVISORIES_PER_CRATE {
+                continue;
+            }
+            context.push_str("\n- ");
+            context.push_str(&advisory_match.advisory.summary());
+            if let (Some(true), Some(version)) =
+                (advisory_match.affected, &advisory_match.mention.version)
+            {
+                context.push_str(&format!(" (affects the mentioned version {version})"));
+            }
+        }
+        Some(context)
+    }
+
+    /// Versions recommended in an answer that have known advisories
+    pub fn vulnerable_recommendations(&self, answer: &str) -> Vec<VulnerableRecommendation> {
+        let mut found = Vec::new();
+        for mention in versioned_mentions(answer) {
+            let Some(requirement) = mention.version.as_deref() else {
+                continue;
+            };
+            let Some(minimum) = parse_version(requirement) else {
+                continue;
+            };
+            let allowed = VersionReq::parse(requirement).ok();
+            for advisory in self.for_crate(&mention.name) {
+                if advisory.informational.is_some() || !advisory.affects(&minimum) {
+                    continue;
+                }
+                let compatible_fix = allowed.as_ref().is_some_and(|allowed| {
+                    advisory
+                        .patched_versions()
+                        .iter()
+                        .filter_map(|fixed| parse_version(fixed))
+                        .any(|fixed| allowed.matches(&fixed))
+                });
+                found.push(VulnerableRecommendation {
+                    krate: mention.name.clone(),
+                    version: requirement.to_string(),
+                    advisory: advisory.clone(),
+                    compatible_fix,
+                });
+            }
+        }
+        found
+    }
+
+    /// Read a cached database, `None` when there is no cache yet
+    pub fn load(path: &Path) -> Result<Option<Self>, AdvisoryError> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => {
+                return Err(AdvisoryError::Io {
+                    path: path.display().to_string(),
+                    source,
+                })
+            }
+        };
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|source| AdvisoryError::Parse {
+                path: path.display().to_string(),
+                source,
+            })
+    }
+
+    /// Write the database cache, creating the parent directory when needed
+    pub fn save(&self, path: &Path) -> Result<(), AdvisoryError> {
+        let io_error = |source| AdvisoryError::Io {
+            path: path.display().to_string(),
+            source,
+        };
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let content = serde_json::to_string(self).map_err(|source| AdvisoryError::Parse {
+            path: path.display().to_string(),
+            source,
+        })?;
+        std::fs::write(path, content).map_err(io_error)
+    }
+}
+
+/// Keeps the advisory database loaded and refreshed on a schedule
+pub struct AdvisoryService {
+    client: reqwest::Client,
+    source_url: String,
+    cache_path: PathBuf,
+    refresh_interval: Duration,
+    database: RwLock<Arc<AdvisoryDatabase>>,
+}
+
+impl std::fmt::Debug for AdvisoryService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdvisoryService")
+            .field("source_url", &self.source_url)
+            .field("cache_path", &self.cache_path)
+            .field("refresh_interval", &self.refresh_interval)
+            .finish()
+    }
+}
+
+impl AdvisoryService {
+    pub fn new(cache_path: impl Into<PathBuf>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(120))
+            .user_agent(concat!("fortitude/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .unwrap_or_default();
+        Self {
+            client,
+            source_url: DEFAULT_ADVISORY_SOURCE_URL.to_string(),
+            cache_path: cache_path.into(),
+            refresh_interval: Duration::from_secs(24 * 60 * 60),
+            database: RwLock::new(Arc::new(AdvisoryDatabase::default())),
+        }
+    }
+
+    pub fn with_source_url(mut self, source_url: impl Into<String>) -> Self {
+        self.source_url = source_url.into();
+        self
+    }
+
+    pub fn with_refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    /// Start from a database that is already loaded
+    pub fn with_database(self, database: AdvisoryDatabase) -> Self {
+        Self {
+            database: RwLock::new(Arc::new(database)),
+            ..self
+        }
+    }
+
+    pub async fn database(&self) -> Arc<AdvisoryDatabase> {
+        self.database.read().await.clone()
+    }
+
+    /// Load the on-disk cache, keeping the current database when there is none
+    pub async fn load_cached(&self) -> Result<bool, AdvisoryError> {
+        match AdvisoryDatabase::load(&self.cache_path)? {
+            Some(database) => {
+                debug!(
+                    "Loaded {} advisories from {}",
+                    database.len(),
+                    self.cache_path.display()
+                );
+                *self.database.write().await = Arc::new(database);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Download the advisory database and replace the cached copy
+    pub async fn refresh(&self) -> Result<usize, AdvisoryError> {
+        let download_error = |message: String| AdvisoryError::Download {
+            url: self.source_url.clone(),
+            message,
+        };
+        let bytes = self
+            .client
+            .get(&self.source_url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| download_error(e.to_string()))?
+            .bytes()
+            .await
+            .map_err(|e| download_error(e.to_string()))?;
+        let mut database =
+            tokio::task::spawn_blocking(move || AdvisoryDatabase::from_osv_zip(&bytes))
+                .await
+                .map_err(|e| AdvisoryError::Archive(e.to_string()))??;
+        database.fetched_at = Some(Utc::now());
+        if let Err(e) = database.save(&self.cache_path) {
+            warn!("Failed to cache advisory database: {}", e);
+        }
+        let count = database.len();
+        *self.database.write().await = Arc::new(database);
+        info!("Refreshed advisory database: {} advisories", count);
+        Ok(count)
+    }
+
+    /// Whether the loaded database is older than the refresh interval
+    pub async fn is_stale(&self) -> bool {
+        let fetched_at = self.database.read().await.fetched_at;
+        fetched_at.is_none_or(|fetched_at| {
+            (Utc::now() - fetched_at)
+                .to_std()
+                .is_ok_and(|age| age >= self.refresh_interval)
+        })
+    }
+
+    /// Refresh in the background now if stale, then whenever the database goes stale
+    pub fn spawn_refresh(self: Arc<Self>) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker =
+                tokio::time::interval(self.refresh_interval.min(Duration::from_secs(60 * 60)));
+            loop {
+                ticker.tick().await;
+                if self.is_stale().await {
+                    if let Err(e) = self.refresh().await {
+                        warn!("Advisory database refresh failed: {}", e);
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn database() -> AdvisoryDatabase {
+        let record = json!({
+            "id": "RUSTSEC-2021-0078",
+            "summary": "Lenient header parsing of Content-Length",
+            "aliases": ["CVE-2021-32715"],
+            "affected": [{
+                "package": {"ecosystem": "crates.io", "name": "hyper"},
+                "ranges": [{"type": "SEMVER", "events": [
+                    {"introduced": "0.0.0-0"}, {"fixed": "0.14.10"}
+                ]}]
+            }],
+            "references": [{"type": "ADVISORY", "url": "https://rustsec.org/advisories/RUSTSEC-2021-0078.html"}]
+        });
+        let ghsa = json!({"id": "GHSA-f3pg-qwvg-p99c", "affected": []});
+        AdvisoryDatabase::new(
+            [record, ghsa]
+                .iter()
+                .filter_map(Advisory::from_osv)
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn test_matches_mentions_and_flags_vulnerable_recommendations() {
+        let database = database();
+        assert_eq!(database.len(), 1);
+        let advisory = &database.for_crate("hyper")[0];
+        assert!(advisory.affects(&Version::new(0, 14, 9)));
+        assert!(!advisory.affects(&Version::new(0, 14, 10)));
+
+        // Bare names only count in security questions
+        assert!(database.matches("How do I use hyper?", None).is_empty());
+        let matches = database.matches("Is hyper safe to use?", None);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].affected, None);
+
+        let manifest = "[dependencies]\nhyper = { version = \"0.14.2\", features = [\"full\"] }\n";
+        let matches = database.matches("Review my dependencies", Some(manifest));
+        assert_eq!(matches[0].affected, Some(true));
+        assert!(database
+            .prompt_context(&matches)
+            .unwrap()
+            .contains("RUSTSEC-2021-0078 (hyper): Lenient header parsing of Content-Length; patched in >= 0.14.10 (affects the mentioned version 0.14.2)"));
+        assert!(database
+            .matches("Upgrade to hyper@0.14.12", None)
+            .is_empty());
+
+        let flagged = database.vulnerable_recommendations("Add `hyper = \"0.13\"` to Cargo.toml");
+        assert_eq!(flagged.len(), 1);
+        assert!(!flagged[0].compatible_fix);
+        assert!(flagged[0].message().contains("upgrade to >= 0.14.10"));
+        let flagged = database.vulnerable_recommendations("hyper = \"0.14.2\"");
+        assert!(flagged[0].compatible_fix);
+        assert!(database
+            .vulnerable_recommendations("hyper = \"0.14.10\"")
+            .is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Atomic replacement of JSON state files
+//! Stores, checkpoints and snapshots are written to a temporary sibling file
+//! that is then renamed over the original, so a crash mid-write never leaves
+//! a truncated file behind. Missing parent directories are created.
+
+use serde::Serialize;
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Replace `path` with `value` as pretty-printed JSON
+pub fn write_json_atomic<T: Serialize + ?Sized>(path: &Path, value: &T) -> io::Result<()> {
+    let content = serde_json::to_vec_pretty(value)?;
+    if let Some(parent) = parent_dir(path) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let temp_path = temp_path(path);
+    std::fs::write(&temp_path, content)?;
+    std::fs::rename(&temp_path, path)
+}
+
+/// Async variant of [`write_json_atomic`]
+pub async fn write_json_atomic_async<T: Serialize + ?Sized>(
+    path: &Path,
+    value: &T,
+) -> io::Result<()> {
+    let content = serde_json::to_vec_pretty(value)?;
+    if let Some(parent) = parent_dir(path) {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let temp_path = temp_path(path);
+    tokio::fs::write(&temp_path, content).await?;
+    tokio::fs::rename(&temp_path, path).await
+}
+
+fn parent_dir(path: &Path) -> Option<&Path> {
+    path.parent().filter(|p| !p.as_os_str().is_empty())
+}
+
+/// `<file name>.tmp` next to `path`, so the rename stays on one file system
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path
+        .file_name()
+        .map(OsString::from)
+        .unwrap_or_else(|| OsString::from("state"));
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[tokio::test]
+    async fn test_write_json_atomic_replaces_file_without_leftovers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("store.json");
+
+        write_json_atomic(&path, &BTreeMap::from([("a", 1)])).unwrap();
+        write_json_atomic_async(&path, &BTreeMap::from([("b", 2)]))
+            .await
+            .unwrap();
+
+        let stored: BTreeMap<String, i32> =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(stored, BTreeMap::from([("b".to_string(), 2)]));
+        let files: Vec<_> = std::fs::read_dir(path.parent().unwrap())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(files, ["store.json"]);
+    }
+}
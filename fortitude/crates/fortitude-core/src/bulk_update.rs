@@ -0,0 +1,377 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Query-based selection and metadata mutations for bulk editing of stored research
+// Filters pick results by query text, type, tag and completion date; mutations retag them,
+// override their quality score or assign a retention class
+use chrono::{DateTime, Utc};
+use fortitude_types::{ResearchResult, ResearchType};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+
+/// Metadata tag holding a result's retention class
+pub const RETENTION_CLASS_TAG: &str = "retention_class";
+
+/// Metadata tag holding the quality score a result had before its first override
+pub const ORIGINAL_QUALITY_TAG: &str = "original_quality_score";
+
+/// Metadata tag marking a curator-set quality score
+pub const QUALITY_OVERRIDE_TAG: &str = "quality_override";
+
+/// Invalid bulk update request
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum BulkUpdateError {
+    #[error("Bulk update filter must set at least one criterion")]
+    EmptyFilter,
+
+    #[error("Bulk update must contain at least one mutation")]
+    EmptyMutation,
+
+    #[error("Quality override must be between 0.0 and 1.0, got {0}")]
+    InvalidQuality(f64),
+
+    #[error("Tags must not be blank")]
+    BlankTag,
+
+    #[error("Date range is empty: {from} is after {to}")]
+    InvalidDateRange {
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    },
+}
+
+/// How long a curated result should be kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetentionClass {
+    Ephemeral,
+    Standard,
+    Extended,
+    Permanent,
+}
+
+impl RetentionClass {
+    pub const ALL: [RetentionClass; 4] = [
+        RetentionClass::Ephemeral,
+        RetentionClass::Standard,
+        RetentionClass::Extended,
+        RetentionClass::Permanent,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RetentionClass::Ephemeral => "ephemeral",
+            RetentionClass::Standard => "standard",
+            RetentionClass::Extended => "extended",
+            RetentionClass::Permanent => "permanent",
+        }
+    }
+
+    /// Parse a retention class from its snake_case name
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == value)
+    }
+
+    /// Retention class recorded on a result, if any
+    pub fn of(result: &ResearchResult) -> Option<Self> {
+        result
+            .metadata
+            .tags
+            .get(RETENTION_CLASS_TAG)
+            .and_then(|value| Self::parse(value))
+    }
+}
+
+impl fmt::Display for RetentionClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Selection of stored results; all set criteria must match
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BulkFilter {
+    /// Case-insensitive text contained in the original query
+    pub query: Option<String>,
+    pub research_type: Option<ResearchType>,
+    /// Tag the result must carry
+    pub tag: Option<String>,
+    /// Completed at or after this time
+    pub completed_after: Option<DateTime<Utc>>,
+    /// Completed at or before this time
+    pub completed_before: Option<DateTime<Utc>>,
+}
+
+impl BulkFilter {
+    pub fn validate(&self) -> Result<(), BulkUpdateError> {
+        let has_query = self.query.as_deref().is_some_and(|q| !q.trim().is_empty());
+        let has_tag = self.tag.as_deref().is_some_and(|t| !t.trim().is_empty());
+        if !has_query
+            && !has_tag
+            && self.research_type.is_none()
+            && self.completed_after.is_none()
+            && self.completed_before.is_none()
+        {
+            return Err(BulkUpdateError::EmptyFilter);
+        }
+        if let (Some(from), Some(to)) = (self.completed_after, self.completed_before) {
+            if from > to {
+                return Err(BulkUpdateError::InvalidDateRange { from, to });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn matches(&self, result: &ResearchResult) -> bool {
+        let query_matches = self
+            .query
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+            .is_none_or(|q| {
+                result
+                    .request
+                    .original_query
+                    .to_lowercase()
+                    .contains(&q.to_lowercase())
+            });
+        let tag_matches = self
+            .tag
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .is_none_or(|t| {
+                result
+                    .request
+                    .domain_context
+                    .tags
+                    .iter()
+                    .any(|tag| tag == t)
+            });
+        let completed_at = result.metadata.completed_at;
+
+        query_matches
+            && tag_matches
+            && self
+                .research_type
+                .as_ref()
+                .is_none_or(|t| result.request.has_research_type(t))
+            && self.completed_after.is_none_or(|from| completed_at >= from)
+            && self.completed_before.is_none_or(|to| completed_at <= to)
+    }
+}
+
+/// Metadata changes applied to every selected result
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetadataMutation {
+    #[serde(default)]
+    pub add_tags: Vec<String>,
+    #[serde(default)]
+    pub remove_tags: Vec<String>,
+    /// Replace the quality score (0.0-1.0)
+    pub quality_override: Option<f64>,
+    pub retention_class: Option<RetentionClass>,
+}
+
+impl MetadataMutation {
+    pub fn validate(&self) -> Result<(), BulkUpdateError> {
+        if self.add_tags.is_empty()
+            && self.remove_tags.is_empty()
+            && self.quality_override.is_none()
+            && self.retention_class.is_none()
+        {
+            return Err(BulkUpdateError::EmptyMutation);
+        }
+        if let Some(quality) = self.quality_override {
+            if !(0.0..=1.0).contains(&quality) {
+                return Err(BulkUpdateError::InvalidQuality(quality));
+            }
+        }
+        if self
+            .add_tags
+            .iter()
+            .chain(&self.remove_tags)
+            .any(|tag| tag.trim().is_empty())
+        {
+            return Err(BulkUpdateError::BlankTag);
+        }
+        Ok(())
+    }
+
+    /// Apply the mutation, returning whether the result changed
+    ///
+    /// Removals run before additions, so a tag in both lists ends up present.
+    /// The first quality override keeps the generated score in
+    /// `original_quality_score`.
+    pub fn apply(&self, result: &mut ResearchResult) -> bool {
+        let before = ResultMetadataView::of(result);
+
+        let tags = &mut result.request.domain_context.tags;
+        tags.retain(|tag| !self.remove_tags.iter().any(|r| r.trim() == tag));
+        for tag in &self.add_tags {
+            let tag = tag.trim();
+            if !tags.iter().any(|t| t == tag) {
+                tags.push(tag.to_string());
+            }
+        }
+
+        let metadata = &mut result.metadata;
+        if let Some(quality) = self.quality_override {
+            if metadata.quality_score != quality {
+                metadata
+                    .tags
+                    .entry(ORIGINAL_QUALITY_TAG.to_string())
+                    .or_insert_with(|| metadata.quality_score.to_string());
+                metadata
+                    .tags
+                    .insert(QUALITY_OVERRIDE_TAG.to_string(), "true".to_string());
+                metadata.quality_score = quality;
+            }
+        }
+        if let Some(class) = self.retention_class {
+            metadata
+                .tags
+                .insert(RETENTION_CLASS_TAG.to_string(), class.to_string());
+        }
+
+        ResultMetadataView::of(result) != before
+    }
+}
+
+/// The curatable metadata of one result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResultMetadataView {
+    pub tags: Vec<String>,
+    pub quality_score: f64,
+    pub retention_class: Option<RetentionClass>,
+}
+
+impl ResultMetadataView {
+    pub fn of(result: &ResearchResult) -> Self {
+        Self {
+            tags: result.request.domain_context.tags.clone(),
+            quality_score: result.metadata.quality_score,
+            retention_class: RetentionClass::of(result),
+        }
+    }
+}
+
+/// Planned or applied change to one result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkUpdateChange {
+    pub cache_key: String,
+    pub original_query: String,
+    pub before: ResultMetadataView,
+    pub after: ResultMetadataView,
+}
+
+/// Outcome of a bulk update
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkUpdateReport {
+    /// Nothing was written
+    pub dry_run: bool,
+    /// Results selected by the filter
+    pub matched: usize,
+    /// Results whose metadata changed (or would change)
+    pub changes: Vec<BulkUpdateChange>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use fortitude_types::{AudienceContext, ClassifiedRequest, DomainContext, ResearchMetadata};
+    use std::collections::HashMap;
+
+    fn result(query: &str, tags: &[&str], day: u32) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            query.to_string(),
+            ResearchType::Learning,
+            AudienceContext::default(),
+            DomainContext {
+                tags: tags.iter().map(|t| t.to_string()).collect(),
+                ..DomainContext::default()
+            },
+            0.8,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc.with_ymd_and_hms(2025, 3, day, 12, 0, 0).unwrap(),
+            processing_time_ms: 10,
+            sources_consulted: vec![],
+            quality_score: 0.6,
+            cache_key: format!("key-{day}"),
+            tags: HashMap::new(),
+        };
+        ResearchResult::new(request, "answer".to_string(), vec![], vec![], metadata)
+    }
+
+    #[test]
+    fn test_filter_matches_all_criteria() {
+        let filter = BulkFilter {
+            query: Some("ASYNC".to_string()),
+            tag: Some("tokio".to_string()),
+            completed_after: Some(Utc.with_ymd_and_hms(2025, 3, 2, 0, 0, 0).unwrap()),
+            ..Default::default()
+        };
+        assert!(filter.validate().is_ok());
+        assert!(filter.matches(&result("async runtimes", &["tokio"], 5)));
+        assert!(!filter.matches(&result("async runtimes", &["tokio"], 1)));
+        assert!(!filter.matches(&result("async runtimes", &["smol"], 5)));
+        assert!(!filter.matches(&result("error handling", &["tokio"], 5)));
+
+        assert_eq!(
+            BulkFilter::default().validate(),
+            Err(BulkUpdateError::EmptyFilter)
+        );
+    }
+
+    #[test]
+    fn test_mutation_retags_and_overrides_quality() {
+        let mutation = MetadataMutation {
+            add_tags: vec!["runtime".to_string(), "tokio".to_string()],
+            remove_tags: vec!["legacy".to_string()],
+            quality_override: Some(0.9),
+            retention_class: Some(RetentionClass::Permanent),
+        };
+        assert!(mutation.validate().is_ok());
+
+        let mut curated = result("async runtimes", &["tokio", "legacy"], 5);
+        assert!(mutation.apply(&mut curated));
+        assert_eq!(
+            curated.request.domain_context.tags,
+            vec!["tokio", "runtime"]
+        );
+        assert_eq!(curated.metadata.quality_score, 0.9);
+        assert_eq!(curated.metadata.tags[ORIGINAL_QUALITY_TAG], "0.6");
+        assert_eq!(
+            RetentionClass::of(&curated),
+            Some(RetentionClass::Permanent)
+        );
+
+        // Reapplying is a no-op and keeps the original score
+        assert!(!mutation.apply(&mut curated));
+        assert_eq!(curated.metadata.tags[ORIGINAL_QUALITY_TAG], "0.6");
+
+        assert_eq!(
+            MetadataMutation {
+                quality_override: Some(1.5),
+                ..Default::default()
+            }
+            .validate(),
+            Err(BulkUpdateError::InvalidQuality(1.5))
+        );
+    }
+}
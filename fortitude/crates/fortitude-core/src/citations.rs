@@ -0,0 +1,499 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Citation extraction from provider answers and verification of cited URLs
+//! Every sentence of the answer, evidence and implementation details that
+//! names a URL or quotes a source becomes a [`Citation`] mapping that claim
+//! to its source; evidence whose source is a URL cites it for the evidence's
+//! first sentence. [`CitationValidator`] then checks each URL with a `HEAD`
+//! request (falling back to `GET` for servers that refuse `HEAD`) under a
+//! timeout and records whether it was reachable.
+
+use fortitude_types::{Citation, CitationStatus, ResearchResult};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::task::JoinSet;
+use tracing::debug;
+
+/// Metadata tag holding the number of cited URLs that answered
+pub const CITATIONS_VERIFIED_TAG: &str = "citations_verified";
+/// Metadata tag holding the number of cited URLs that failed or did not answer
+pub const CITATIONS_BROKEN_TAG: &str = "citations_broken";
+
+/// Claims longer than this are cut, so one run-on line does not bloat results
+const MAX_CLAIM_CHARS: usize = 300;
+
+/// Quotes with fewer words are emphasis rather than quoted sources
+const MIN_QUOTE_WORDS: usize = 3;
+
+/// Characters trimmed from the end of a URL matched in prose
+const URL_TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?', '*', '_'];
+
+fn url_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r#"https?://[^\s<>()\[\]"'`]+"#).unwrap())
+}
+
+fn quote_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r#""([^"\n]+)"|“([^”\n]+)”"#).unwrap())
+}
+
+/// Extract the citations of a result, mapping each claim to its sources
+pub fn extract_citations(result: &ResearchResult) -> Vec<Citation> {
+    let mut citations = Vec::new();
+    cite_text(&result.immediate_answer, "answer", &mut citations);
+
+    for evidence in &result.supporting_evidence {
+        let source = evidence.source.trim();
+        if is_url(source) {
+            if let Some(claim) = sentences(&evidence.content).into_iter().next() {
+                citations.push(citation(&claim, "evidence", Some(source.to_string()), None));
+            }
+        }
+        cite_text(&evidence.content, "evidence", &mut citations);
+    }
+
+    for detail in &result.implementation_details {
+        cite_text(&detail.content, "detail", &mut citations);
+    }
+
+    let mut seen = HashSet::new();
+    citations.retain(|c| seen.insert((c.claim.clone(), c.url.clone(), c.quote.clone())));
+    citations
+}
+
+/// Cite the sentences of a text that name a URL or quote a source
+fn cite_text(text: &str, section: &str, citations: &mut Vec<Citation>) {
+    for sentence in sentences(text) {
+        let urls = urls_in(&sentence);
+        let quote = quote_in(&sentence);
+        if urls.is_empty() {
+            if quote.is_some() {
+                citations.push(citation(&sentence, section, None, quote));
+            }
+            continue;
+        }
+        for url in urls {
+            citations.push(citation(&sentence, section, Some(url), quote.clone()));
+        }
+    }
+}
+
+fn citation(claim: &str, section: &str, url: Option<String>, quote: Option<String>) -> Citation {
+    Citation {
+        claim: claim.chars().take(MAX_CLAIM_CHARS).collect(),
+        section: section.to_string(),
+        url,
+        quote,
+        status: CitationStatus::Unverified,
+        http_status: None,
+    }
+}
+
+/// Split text into sentences, one line at a time
+///
+/// A sentence ends at `.`, `!` or `?` followed by whitespace, so the dots
+/// inside URLs and version numbers do not split it.
+fn sentences(text: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    for line in text.lines() {
+        let line = line.trim().trim_start_matches(['>', '-', '*']).trim();
+        let mut start = 0;
+        let mut chars = line.char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            let at_boundary = matches!(c, '.' | '!' | '?')
+                && chars.peek().is_none_or(|(_, next)| next.is_whitespace());
+            if at_boundary {
+                let end = index + c.len_utf8();
+                push_sentence(&mut sentences, &line[start..end]);
+                start = end;
+            }
+        }
+        push_sentence(&mut sentences, &line[start..]);
+    }
+    sentences
+}
+
+fn push_sentence(sentences: &mut Vec<String>, sentence: &str) {
+    let sentence = sentence.trim();
+    if !sentence.is_empty() {
+        sentences.push(sentence.to_string());
+    }
+}
+
+fn urls_in(text: &str) -> Vec<String> {
+    let mut urls: Vec<String> = url_pattern()
+        .find_iter(text)
+        .map(|m| {
+            m.as_str()
+                .trim_end_matches(URL_TRAILING_PUNCTUATION)
+                .to_string()
+        })
+        .filter(|url| is_url(url))
+        .collect();
+    urls.dedup();
+    urls
+}
+
+fn quote_in(text: &str) -> Option<String> {
+    quote_pattern()
+        .captures_iter(text)
+        .filter_map(|captures| captures.get(1).or_else(|| captures.get(2)))
+        .map(|m| m.as_str().trim().to_string())
+        .find(|quote| quote.split_whitespace().count() >= MIN_QUOTE_WORDS)
+}
+
+fn is_url(text: &str) -> bool {
+    url::Url::parse(text)
+        .map(|url| matches!(url.scheme(), "http" | "https") && url.host().is_some())
+        .unwrap_or(false)
+}
+
+/// Checks that cited URLs answer
+#[derive(Debug, Clone)]
+pub struct CitationValidator {
+    client: reqwest::Client,
+    timeout: Duration,
+    max_urls: usize,
+}
+
+impl Default for CitationValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CitationValidator {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .user_agent(concat!("fortitude/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .unwrap_or_default();
+        Self {
+            client,
+            timeout: Duration::from_secs(5),
+            max_urls: 20,
+        }
+    }
+
+    /// Time each URL has to answer
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Check at most this many distinct URLs per result; the rest stay unverified
+    pub fn with_max_urls(mut self, max_urls: usize) -> Self {
+        self.max_urls = max_urls;
+        self
+    }
+
+    /// Check one URL
+    pub async fn check(&self, url: &str) -> (CitationStatus, Option<u16>) {
+        check_url(&self.client, url, self.timeout).await
+    }
+
+    /// Check the URLs of the citations concurrently and record the outcome on each
+    pub async fn validate(&self, citations: &mut [Citation]) {
+        let mut urls: Vec<String> = Vec::new();
+        for url in citations.iter().filter_map(|c| c.url.as_ref()) {
+            if !urls.contains(url) && urls.len() < self.max_urls {
+                urls.push(url.clone());
+            }
+        }
+
+        let mut checks = JoinSet::new();
+        for url in urls {
+            let client = self.client.clone();
+            let timeout = self.timeout;
+            checks.spawn(async move {
+                let outcome = check_url(&client, &url, timeout).await;
+                (url, outcome)
+            });
+        }
+        let mut outcomes = HashMap::new();
+        while let Some(joined) = checks.join_next().await {
+            if let Ok((url, outcome)) = joined {
+                outcomes.insert(url, outcome);
+            }
+        }
+
+        for citation in citations.iter_mut() {
+            let Some(url) = &citation.url else {
+                continue;
+            };
+            if let Some((status, http_status)) = outcomes.get(url) {
+                citation.status = *status;
+                citation.http_status = *http_status;
+            }
+        }
+    }
+}
+
+async fn check_url(
+    client: &reqwest::Client,
+    url: &str,
+    timeout: Duration,
+) -> (CitationStatus, Option<u16>) {
+    let head = client.head(url).timeout(timeout).send().await;
+    let response = match head {
+        // Some servers refuse HEAD outright; ask for the page instead
+        Ok(response)
+            if matches!(
+                response.status(),
+                reqwest::StatusCode::METHOD_NOT_ALLOWED | reqwest::StatusCode::NOT_IMPLEMENTED
+            ) =>
+        {
+            client.get(url).timeout(timeout).send().await
+        }
+        other => other,
+    };
+    match response {
+        Ok(response) => {
+            let status = response.status();
+            let verified = status.is_success() || status.is_redirection();
+            (
+                if verified {
+                    CitationStatus::Verified
+                } else {
+                    CitationStatus::Broken
+                },
+                Some(status.as_u16()),
+            )
+        }
+        Err(e) => {
+            debug!("Cited URL {} did not answer: {}", url, e);
+            (CitationStatus::Unreachable, None)
+        }
+    }
+}
+
+/// Record how many cited URLs answered and how many did not
+pub fn record_citation_checks(result: &mut ResearchResult) {
+    let mut verified = HashSet::new();
+    let mut broken = HashSet::new();
+    for citation in &result.citations {
+        let Some(url) = citation.url.as_deref() else {
+            continue;
+        };
+        match citation.status {
+            CitationStatus::Verified => {
+                verified.insert(url);
+            }
+            CitationStatus::Broken | CitationStatus::Unreachable => {
+                broken.insert(url);
+            }
+            CitationStatus::Unverified => {}
+        }
+    }
+    let (verified, broken) = (verified.len(), broken.len());
+    let tags = &mut result.metadata.tags;
+    tags.insert(CITATIONS_VERIFIED_TAG.to_string(), verified.to_string());
+    tags.insert(CITATIONS_BROKEN_TAG.to_string(), broken.to_string());
+}
+
+/// Cited URLs that failed or did not answer
+pub fn broken_urls(citations: &[Citation]) -> Vec<&str> {
+    let mut urls: Vec<&str> = citations
+        .iter()
+        .filter(|c| {
+            matches!(
+                c.status,
+                CitationStatus::Broken | CitationStatus::Unreachable
+            )
+        })
+        .filter_map(|c| c.url.as_deref())
+        .collect();
+    urls.dedup();
+    urls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use fortitude_types::{
+        AudienceContext, ClassifiedRequest, Detail, DomainContext, Evidence, ResearchMetadata,
+        ResearchType,
+    };
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn result(answer: &str, evidence: Vec<Evidence>, details: Vec<Detail>) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            "How do I share state between tasks?".to_string(),
+            ResearchType::Learning,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        );
+        ResearchResult::new(
+            request,
+            answer.to_string(),
+            evidence,
+            details,
+            ResearchMetadata {
+                completed_at: Utc::now(),
+                processing_time_ms: 10,
+                sources_consulted: vec![],
+                quality_score: 0.8,
+                cache_key: "key".to_string(),
+                tags: HashMap::new(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_extracts_claims_with_urls_and_quotes() {
+        let answer = "Wrap shared state in Arc<Mutex<T>> (see https://docs.rs/tokio/1.0/tokio/sync/struct.Mutex.html). \
+            The tokio docs say \"the feature that the async mutex offers over the blocking mutex is the ability to keep it locked across an await point\". \
+            Channels avoid locking entirely.\n\
+            > Prefer message passing - [the book](https://doc.rust-lang.org/book/ch16-02-message-passing.html)";
+        let evidence = vec![Evidence {
+            source: "https://tokio.rs/tokio/tutorial/shared-state".to_string(),
+            content: "Use std::sync::Mutex for short critical sections. It is faster.".to_string(),
+            relevance: 0.9,
+            evidence_type: "documentation".to_string(),
+        }];
+        let details = vec![Detail {
+            category: "code".to_string(),
+            content: "let state = Arc::new(Mutex::new(0));".to_string(),
+            priority: "high".to_string(),
+            prerequisites: vec![],
+        }];
+
+        let citations = extract_citations(&result(answer, evidence, details));
+        assert_eq!(citations.len(), 4);
+
+        assert_eq!(citations[0].section, "answer");
+        assert_eq!(
+            citations[0].url.as_deref(),
+            Some("https://docs.rs/tokio/1.0/tokio/sync/struct.Mutex.html")
+        );
+        assert!(citations[0].claim.starts_with("Wrap shared state"));
+        assert_eq!(citations[0].status, CitationStatus::Unverified);
+
+        assert_eq!(citations[1].url, None);
+        assert!(citations[1]
+            .quote
+            .as_deref()
+            .unwrap()
+            .starts_with("the feature that the async mutex"));
+
+        assert_eq!(
+            citations[2].url.as_deref(),
+            Some("https://doc.rust-lang.org/book/ch16-02-message-passing.html")
+        );
+        assert_eq!(citations[2].claim, "Prefer message passing - [the book](https://doc.rust-lang.org/book/ch16-02-message-passing.html)");
+
+        assert_eq!(citations[3].section, "evidence");
+        assert_eq!(
+            citations[3].claim,
+            "Use std::sync::Mutex for short critical sections."
+        );
+        assert_eq!(
+            citations[3].url.as_deref(),
+            Some("https://tokio.rs/tokio/tutorial/shared-state")
+        );
+    }
+
+    #[test]
+    fn test_ignores_short_quotes_and_non_urls() {
+        let citations = extract_citations(&result(
+            "Call it \"cheap\" cloning. Version 1.2.3 works; see docs.rs or file:///etc/passwd.",
+            vec![],
+            vec![],
+        ));
+        assert!(citations.is_empty());
+    }
+
+    /// Serve `HEAD /ok`, `GET /no-head` (HEAD gets 405) and 404 for anything else
+    async fn spawn_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut buffer = [0u8; 1024];
+                    let read = socket.read(&mut buffer).await.unwrap_or(0);
+                    let request = String::from_utf8_lossy(&buffer[..read]);
+                    let status = match request.split_whitespace().take(2).collect::<Vec<_>>()[..] {
+                        [_, "/ok"] => "200 OK",
+                        ["HEAD", "/no-head"] => "405 Method Not Allowed",
+                        ["GET", "/no-head"] => "200 OK",
+                        [_, "/slow"] => {
+                            tokio::time::sleep(Duration::from_secs(5)).await;
+                            "200 OK"
+                        }
+                        _ => "404 Not Found",
+                    };
+                    let response = format!(
+                        "HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        format!("http://{address}")
+    }
+
+    #[tokio::test]
+    async fn test_validate_records_url_status() {
+        let base = spawn_server().await;
+        let url = |path: &str| Some(format!("{base}{path}"));
+        let mut citations = vec![
+            citation("a", "answer", url("/ok"), None),
+            citation("b", "answer", url("/no-head"), None),
+            citation("c", "answer", url("/gone"), None),
+            citation("d", "answer", url("/slow"), None),
+            citation("e", "answer", None, Some("quoted text here".to_string())),
+            citation("f", "evidence", url("/ok"), None),
+        ];
+
+        CitationValidator::new()
+            .with_timeout(Duration::from_millis(300))
+            .validate(&mut citations)
+            .await;
+
+        let statuses: Vec<_> = citations
+            .iter()
+            .map(|c| (c.status, c.http_status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (CitationStatus::Verified, Some(200)),
+                (CitationStatus::Verified, Some(200)),
+                (CitationStatus::Broken, Some(404)),
+                (CitationStatus::Unreachable, None),
+                (CitationStatus::Unverified, None),
+                (CitationStatus::Verified, Some(200)),
+            ]
+        );
+        assert_eq!(
+            broken_urls(&citations),
+            vec![format!("{base}/gone"), format!("{base}/slow")]
+        );
+
+        let mut result = result("", vec![], vec![]);
+        result.citations = citations;
+        record_citation_checks(&mut result);
+        assert_eq!(result.metadata.tags[CITATIONS_VERIFIED_TAG], "2");
+        assert_eq!(result.metadata.tags[CITATIONS_BROKEN_TAG], "2");
+    }
+}
@@ -426,7 +426,8 @@ impl Classifier for AdvancedClassifier {
             enhanced_result.matched_keywords,
             enhanced_result.rule_priority,
             enhanced_result.candidates,
-        ))
+        )
+        .with_secondary_labels(&self.config.basic_config))
     }
 
     fn get_confidence(&self, query: &str, research_type: &ResearchType) -> f64 {
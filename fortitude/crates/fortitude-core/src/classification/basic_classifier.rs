@@ -317,7 +317,8 @@ impl Classifier for BasicClassifier {
             best_candidate.matched_keywords.clone(),
             best_candidate.rule_priority,
             candidates,
-        ))
+        )
+        .with_secondary_labels(&self.config))
     }
 
     fn get_confidence(&self, query: &str, research_type: &ResearchType) -> f64 {
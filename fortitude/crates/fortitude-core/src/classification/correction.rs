@@ -0,0 +1,178 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: User corrections of a stored result's research type
+//! A correction re-labels a stored result with the research type a user says
+//! it should have had. The first classifier label is kept in a metadata tag so
+//! repeated corrections do not lose it, and every correction doubles as a
+//! training example for the trainable classifier.
+
+use crate::classification::{TrainingExample, TrainingSource};
+use chrono::{DateTime, Utc};
+use fortitude_types::{ResearchResult, ResearchType};
+use serde::{Deserialize, Serialize};
+
+/// Metadata tag holding the research type the classifier originally assigned
+pub const CLASSIFICATION_CORRECTED_FROM_TAG: &str = "classification_corrected_from";
+
+/// Metadata tag holding the reason given for the latest correction
+pub const CLASSIFICATION_CORRECTION_REASON_TAG: &str = "classification_correction_reason";
+
+/// Research type change applied to a stored result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassificationCorrection {
+    pub cache_key: String,
+    pub query: String,
+    /// Research type the result had before this correction
+    pub previous: ResearchType,
+    pub corrected: ResearchType,
+    pub reason: Option<String>,
+    pub corrected_at: DateTime<Utc>,
+}
+
+impl ClassificationCorrection {
+    /// Whether the correction changed the result's research type
+    pub fn is_change(&self) -> bool {
+        self.previous != self.corrected
+    }
+
+    /// Labeled query for training the classifier
+    pub fn training_example(&self) -> TrainingExample {
+        TrainingExample::new(
+            self.query.clone(),
+            self.corrected.clone(),
+            TrainingSource::Correction,
+        )
+    }
+}
+
+/// Re-label `result` with `research_type`
+///
+/// A user-assigned label is certain, so the classification confidence becomes
+/// 1.0. Re-labeling with the type the result already has leaves it untouched
+/// and returns a correction for which [`ClassificationCorrection::is_change`]
+/// is false.
+pub fn relabel_result(
+    result: &mut ResearchResult,
+    research_type: ResearchType,
+    reason: Option<&str>,
+) -> ClassificationCorrection {
+    let previous = result.request.research_type.clone();
+    let reason = reason
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(str::to_string);
+
+    if previous != research_type {
+        let tags = &mut result.metadata.tags;
+        tags.entry(CLASSIFICATION_CORRECTED_FROM_TAG.to_string())
+            .or_insert_with(|| previous.to_string());
+        match &reason {
+            Some(reason) => {
+                tags.insert(
+                    CLASSIFICATION_CORRECTION_REASON_TAG.to_string(),
+                    reason.clone(),
+                );
+            }
+            None => {
+                tags.remove(CLASSIFICATION_CORRECTION_REASON_TAG);
+            }
+        }
+        result.request.research_type = research_type.clone();
+        result.request.confidence = 1.0;
+    }
+
+    ClassificationCorrection {
+        cache_key: result.cache_key().to_string(),
+        query: result.original_query().to_string(),
+        previous,
+        corrected: research_type,
+        reason,
+        corrected_at: Utc::now(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_types::{
+        AudienceContext, ClassifiedRequest, DomainContext, ResearchMetadata, ResearchResult,
+    };
+    use std::collections::HashMap;
+
+    fn result(research_type: ResearchType) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            "How do I fix a borrow checker error?".to_string(),
+            research_type,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.4,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 10,
+            sources_consulted: vec![],
+            quality_score: 0.8,
+            cache_key: "borrow-key".to_string(),
+            tags: HashMap::new(),
+        };
+        ResearchResult::new(request, "Answer".to_string(), vec![], vec![], metadata)
+    }
+
+    #[test]
+    fn test_relabel_keeps_original_classifier_label() {
+        let mut stored = result(ResearchType::Learning);
+
+        let first = relabel_result(
+            &mut stored,
+            ResearchType::Troubleshooting,
+            Some("asks about an error"),
+        );
+        assert!(first.is_change());
+        assert_eq!(first.previous, ResearchType::Learning);
+        assert_eq!(stored.request.research_type, ResearchType::Troubleshooting);
+        assert_eq!(stored.request.confidence, 1.0);
+        assert_eq!(
+            stored.metadata.tags[CLASSIFICATION_CORRECTION_REASON_TAG],
+            "asks about an error"
+        );
+
+        let second = relabel_result(&mut stored, ResearchType::Implementation, None);
+        assert_eq!(second.previous, ResearchType::Troubleshooting);
+        assert_eq!(
+            stored.metadata.tags[CLASSIFICATION_CORRECTED_FROM_TAG],
+            "Learning"
+        );
+        assert!(!stored
+            .metadata
+            .tags
+            .contains_key(CLASSIFICATION_CORRECTION_REASON_TAG));
+
+        let example = second.training_example();
+        assert_eq!(example.query, "How do I fix a borrow checker error?");
+        assert_eq!(example.research_type, ResearchType::Implementation);
+        assert_eq!(example.source, TrainingSource::Correction);
+    }
+
+    #[test]
+    fn test_relabel_with_same_type_is_not_a_change() {
+        let mut stored = result(ResearchType::Decision);
+
+        let correction = relabel_result(&mut stored, ResearchType::Decision, Some("correct"));
+        assert!(!correction.is_change());
+        assert_eq!(stored.request.confidence, 0.4);
+        assert!(stored.metadata.tags.is_empty());
+    }
+}
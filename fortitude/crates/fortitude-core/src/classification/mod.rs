@@ -33,3 +33,11 @@ pub use rules::*;
 // Scoring and signal composition
 pub mod scoring;
 pub use scoring::*;
+
+// Linear classifier trained from corrections and feedback
+pub mod trainable_classifier;
+pub use trainable_classifier::*;
+
+// User corrections of stored results' research types
+pub mod correction;
+pub use correction::*;
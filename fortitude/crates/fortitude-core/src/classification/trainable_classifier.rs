@@ -0,0 +1,757 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Linear research type classifier trained from corrections and feedback
+//! Queries are embedded as hashed word, word-pair and character trigram
+//! features and scored by a softmax linear model. The model is trained from
+//! labeled queries collected in a [`ClassificationTrainingStore`]: corrections
+//! made by users and results whose classification feedback confirmed.
+//!
+//! Select it with [`ClassifierKind::Trainable`] in the [`ClassificationConfig`];
+//! [`create_classifier`] falls back to the keyword rules while no trained model
+//! exists.
+
+use crate::classification::BasicClassifier;
+use chrono::{DateTime, Utc};
+use fortitude_types::{
+    ClassificationCandidate, ClassificationConfig, ClassificationError, ClassificationResult,
+    Classifier, ClassifierKind, ResearchType,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+/// File a trained classifier model is saved to by default
+pub const DEFAULT_CLASSIFIER_MODEL_PATH: &str = ".fortitude/classifier_model.json";
+
+/// Log labeled training queries are appended to by default
+pub const DEFAULT_CLASSIFICATION_TRAINING_PATH: &str = ".fortitude/classification_training.jsonl";
+
+/// Feedback rating at or above which a result's classification counts as confirmed
+pub const FEEDBACK_CONFIRMATION_THRESHOLD: f64 = 0.7;
+
+/// Query terms reported as matched keywords per candidate
+const MAX_MATCHED_TERMS: usize = 5;
+
+/// Where a labeled training query came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrainingSource {
+    /// A user re-labeled a misclassified query
+    Correction,
+    /// A user rated the result of a classified query highly
+    Feedback,
+    /// A curated labeled query
+    Fixture,
+}
+
+/// Query labeled with the research type it should be classified as
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrainingExample {
+    pub query: String,
+    pub research_type: ResearchType,
+    pub source: TrainingSource,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl TrainingExample {
+    pub fn new(
+        query: impl Into<String>,
+        research_type: ResearchType,
+        source: TrainingSource,
+    ) -> Self {
+        Self {
+            query: query.into(),
+            research_type,
+            source,
+            recorded_at: Utc::now(),
+        }
+    }
+
+    /// Example confirming a classification, if the feedback rating is high enough
+    pub fn from_feedback(query: &str, research_type: &ResearchType, rating: f64) -> Option<Self> {
+        (rating >= FEEDBACK_CONFIRMATION_THRESHOLD)
+            .then(|| Self::new(query, research_type.clone(), TrainingSource::Feedback))
+    }
+}
+
+/// Append-only log of labeled training queries
+#[derive(Debug, Clone)]
+pub struct ClassificationTrainingStore {
+    path: PathBuf,
+}
+
+impl ClassificationTrainingStore {
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn append(&self, example: &TrainingExample) -> Result<(), ClassificationError> {
+        let io_error = |e: std::io::Error| {
+            ClassificationError::Failed(format!(
+                "Cannot write training data {}: {e}",
+                self.path.display()
+            ))
+        };
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let line = serde_json::to_string(example)
+            .map_err(|e| ClassificationError::Failed(e.to_string()))?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+        writeln!(file, "{line}").map_err(io_error)
+    }
+
+    /// One example per query, oldest first
+    ///
+    /// When a query was labeled more than once, its most recent label wins, so
+    /// a correction overrides earlier feedback.
+    pub fn examples(&self) -> Result<Vec<TrainingExample>, ClassificationError> {
+        let contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                return Err(ClassificationError::Failed(format!(
+                    "Cannot read training data {}: {e}",
+                    self.path.display()
+                )))
+            }
+        };
+
+        let mut examples: Vec<TrainingExample> = Vec::new();
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            let example: TrainingExample = match serde_json::from_str(line) {
+                Ok(example) => example,
+                Err(e) => {
+                    warn!("Skipping unreadable training example: {}", e);
+                    continue;
+                }
+            };
+            let key = normalize_query(&example.query);
+            examples.retain(|existing| normalize_query(&existing.query) != key);
+            examples.push(example);
+        }
+        Ok(examples)
+    }
+}
+
+fn normalize_query(query: &str) -> String {
+    tokens(query).join(" ")
+}
+
+/// Lowercase alphanumeric words of a query
+fn tokens(query: &str) -> Vec<String> {
+    query
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Bucket of a feature name in a `dimension`-sized vector (FNV-1a)
+fn feature_index(feature: &str, dimension: usize) -> usize {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in feature.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    (hash % dimension as u64) as usize
+}
+
+/// Sparse unit-length feature vector of a query
+fn features(query: &str, dimension: usize) -> Vec<(usize, f32)> {
+    let words = tokens(query);
+    let mut counts: HashMap<usize, f32> = HashMap::new();
+    let mut add = |feature: String| {
+        *counts
+            .entry(feature_index(&feature, dimension))
+            .or_default() += 1.0
+    };
+
+    for word in &words {
+        add(format!("w:{word}"));
+        let padded: Vec<char> = format!("^{word}$").chars().collect();
+        for trigram in padded.windows(3) {
+            add(format!("c:{}", trigram.iter().collect::<String>()));
+        }
+    }
+    for pair in words.windows(2) {
+        add(format!("b:{} {}", pair[0], pair[1]));
+    }
+
+    let norm = counts.values().map(|v| v * v).sum::<f32>().sqrt();
+    let mut features: Vec<(usize, f32)> = counts
+        .into_iter()
+        .map(|(index, value)| (index, value / norm))
+        .collect();
+    features.sort_by_key(|(index, _)| *index);
+    features
+}
+
+/// Hyperparameters for training a [`TrainableClassifier`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrainingOptions {
+    /// Passes over the training examples
+    pub epochs: usize,
+    /// Initial step size, decayed each epoch
+    pub learning_rate: f32,
+    /// L2 regularization strength
+    pub l2: f32,
+    /// Size of the hashed feature vector
+    pub dimension: usize,
+}
+
+impl Default for TrainingOptions {
+    fn default() -> Self {
+        Self {
+            epochs: 40,
+            learning_rate: 0.5,
+            l2: 1e-4,
+            dimension: 4096,
+        }
+    }
+}
+
+/// Weights of a softmax linear model over hashed query features
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinearClassifierModel {
+    pub dimension: usize,
+    pub labels: Vec<ResearchType>,
+    weights: Vec<Vec<f32>>,
+    bias: Vec<f32>,
+    /// Number of examples the model was trained on
+    pub trained_examples: usize,
+    pub trained_at: DateTime<Utc>,
+}
+
+impl LinearClassifierModel {
+    fn new(dimension: usize) -> Self {
+        let labels = ResearchType::all();
+        Self {
+            dimension,
+            weights: vec![vec![0.0; dimension]; labels.len()],
+            bias: vec![0.0; labels.len()],
+            labels,
+            trained_examples: 0,
+            trained_at: Utc::now(),
+        }
+    }
+
+    /// Probability of each label, in label order
+    fn probabilities(&self, features: &[(usize, f32)]) -> Vec<f64> {
+        let logits: Vec<f64> = self
+            .weights
+            .iter()
+            .zip(&self.bias)
+            .map(|(weights, bias)| {
+                f64::from(*bias)
+                    + features
+                        .iter()
+                        .map(|(index, value)| f64::from(weights[*index] * value))
+                        .sum::<f64>()
+            })
+            .collect();
+        let max = logits.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let exp: Vec<f64> = logits.iter().map(|logit| (logit - max).exp()).collect();
+        let total: f64 = exp.iter().sum();
+        exp.into_iter().map(|e| e / total).collect()
+    }
+
+    fn sgd_step(&mut self, features: &[(usize, f32)], label: usize, learning_rate: f32, l2: f32) {
+        let probabilities = self.probabilities(features);
+        for (k, probability) in probabilities.into_iter().enumerate() {
+            let target = if k == label { 1.0 } else { 0.0 };
+            let gradient = (probability - target) as f32;
+            self.bias[k] -= learning_rate * gradient;
+            for (index, value) in features {
+                let weight = &mut self.weights[k][*index];
+                *weight -= learning_rate * (gradient * value + l2 * *weight);
+            }
+        }
+    }
+}
+
+/// Research type classifier backed by a trained [`LinearClassifierModel`]
+pub struct TrainableClassifier {
+    config: ClassificationConfig,
+    model: LinearClassifierModel,
+}
+
+impl TrainableClassifier {
+    /// Train a model on labeled queries
+    ///
+    /// Needs examples of at least two research types.
+    pub fn train(
+        config: ClassificationConfig,
+        examples: &[TrainingExample],
+        options: &TrainingOptions,
+    ) -> Result<Self, ClassificationError> {
+        let mut distinct: Vec<&ResearchType> = examples.iter().map(|e| &e.research_type).collect();
+        distinct.sort_by_key(|research_type| research_type.to_string());
+        distinct.dedup();
+        if distinct.len() < 2 {
+            return Err(ClassificationError::Failed(format!(
+                "Training needs examples of at least two research types, got {}",
+                distinct.len()
+            )));
+        }
+
+        let mut model = LinearClassifierModel::new(options.dimension);
+        let samples: Vec<(Vec<(usize, f32)>, usize)> = examples
+            .iter()
+            .filter_map(|example| {
+                let label = model
+                    .labels
+                    .iter()
+                    .position(|l| l == &example.research_type)?;
+                Some((features(&example.query, options.dimension), label))
+            })
+            .collect();
+
+        // Deterministic shuffle so the same data always trains the same model
+        let mut order: Vec<usize> = (0..samples.len()).collect();
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        for epoch in 0..options.epochs {
+            for i in (1..order.len()).rev() {
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                order.swap(i, (state >> 33) as usize % (i + 1));
+            }
+            let learning_rate = options.learning_rate / (1.0 + epoch as f32 * 0.1);
+            for &i in &order {
+                let (features, label) = &samples[i];
+                model.sgd_step(features, *label, learning_rate, options.l2);
+            }
+        }
+
+        model.trained_examples = samples.len();
+        model.trained_at = Utc::now();
+        info!(
+            "Trained classifier on {} example(s) of {} research type(s)",
+            samples.len(),
+            distinct.len()
+        );
+        Ok(Self { config, model })
+    }
+
+    /// Load a model saved with [`Self::save`]
+    pub fn load(config: ClassificationConfig, path: &Path) -> Result<Self, ClassificationError> {
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            ClassificationError::Failed(format!(
+                "Cannot read classifier model {}: {e}",
+                path.display()
+            ))
+        })?;
+        let model: LinearClassifierModel = serde_json::from_str(&contents).map_err(|e| {
+            ClassificationError::Failed(format!("Invalid classifier model {}: {e}", path.display()))
+        })?;
+        debug!(
+            "Loaded classifier model trained on {} example(s)",
+            model.trained_examples
+        );
+        Ok(Self { config, model })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ClassificationError> {
+        let io_error = |e: std::io::Error| {
+            ClassificationError::Failed(format!(
+                "Cannot write classifier model {}: {e}",
+                path.display()
+            ))
+        };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let json = serde_json::to_string(&self.model)
+            .map_err(|e| ClassificationError::Failed(e.to_string()))?;
+        std::fs::write(path, json).map_err(io_error)
+    }
+
+    pub fn model(&self) -> &LinearClassifierModel {
+        &self.model
+    }
+
+    /// Candidates for every research type, most likely first
+    fn candidates(&self, query: &str) -> Vec<ClassificationCandidate> {
+        let features = features(query, self.model.dimension);
+        let mut candidates: Vec<ClassificationCandidate> = self
+            .model
+            .probabilities(&features)
+            .into_iter()
+            .enumerate()
+            .map(|(label, probability)| {
+                ClassificationCandidate::new(
+                    self.model.labels[label].clone(),
+                    probability,
+                    self.matched_terms(query, label),
+                    0,
+                )
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        candidates.truncate(self.config.max_candidates.max(1));
+        candidates
+    }
+
+    /// Query words weighing most towards `label`
+    fn matched_terms(&self, query: &str, label: usize) -> Vec<String> {
+        let weights = &self.model.weights[label];
+        let mut terms: Vec<(String, f32)> = tokens(query)
+            .into_iter()
+            .map(|word| {
+                let weight = weights[feature_index(&format!("w:{word}"), self.model.dimension)];
+                (word, weight)
+            })
+            .filter(|(_, weight)| *weight > 0.0)
+            .collect();
+        terms.sort_by(|a, b| b.1.total_cmp(&a.1));
+        terms.dedup_by(|a, b| a.0 == b.0);
+        terms
+            .into_iter()
+            .take(MAX_MATCHED_TERMS)
+            .map(|(word, _)| word)
+            .collect()
+    }
+}
+
+impl Classifier for TrainableClassifier {
+    fn classify(&self, query: &str) -> Result<ClassificationResult, ClassificationError> {
+        if query.trim().is_empty() {
+            return Err(ClassificationError::InvalidInput(
+                "Query cannot be empty".to_string(),
+            ));
+        }
+
+        let candidates = self.candidates(query);
+        let best = &candidates[0];
+        if best.confidence < self.config.default_threshold {
+            warn!(
+                "Best classification confidence ({:.2}) below threshold ({:.2}) for query: '{}'",
+                best.confidence, self.config.default_threshold, query
+            );
+            return Err(ClassificationError::LowConfidence {
+                actual: best.confidence,
+                threshold: self.config.default_threshold,
+            });
+        }
+
+        debug!(
+            "Model classified query '{}' as {} with confidence {:.2}",
+            query, best.research_type, best.confidence
+        );
+        Ok(ClassificationResult::new(
+            best.research_type.clone(),
+            best.confidence,
+            best.matched_keywords.clone(),
+            best.rule_priority,
+            candidates.clone(),
+        )
+        .with_secondary_labels(&self.config))
+    }
+
+    fn get_confidence(&self, query: &str, research_type: &ResearchType) -> f64 {
+        let features = features(query, self.model.dimension);
+        self.model
+            .labels
+            .iter()
+            .position(|label| label == research_type)
+            .map_or(0.0, |label| self.model.probabilities(&features)[label])
+    }
+
+    fn get_all_classifications(&self, query: &str) -> Vec<ClassificationCandidate> {
+        self.candidates(query)
+    }
+}
+
+/// Build the classifier selected by `config`
+///
+/// A trainable classifier without a loadable model falls back to the keyword
+/// rules, so research keeps working before the first training run.
+pub fn create_classifier(config: &ClassificationConfig) -> Arc<dyn Classifier + Send + Sync> {
+    match config.classifier {
+        ClassifierKind::Rules => Arc::new(BasicClassifier::new(config.clone())),
+        ClassifierKind::Trainable => {
+            let path = config
+                .model_path
+                .clone()
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_CLASSIFIER_MODEL_PATH));
+            match TrainableClassifier::load(config.clone(), &path) {
+                Ok(classifier) => Arc::new(classifier),
+                Err(e) => {
+                    warn!("{}; using the keyword classifier", e);
+                    Arc::new(BasicClassifier::new(config.clone()))
+                }
+            }
+        }
+    }
+}
+
+/// Accuracy of a classifier on the queries of one research type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TypeEvaluation {
+    pub research_type: ResearchType,
+    pub total: usize,
+    pub correct: usize,
+}
+
+/// Query a classifier labeled differently from its expected type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Misclassification {
+    pub query: String,
+    pub expected: ResearchType,
+    /// `None` when the classifier returned an error, e.g. low confidence
+    pub predicted: Option<ResearchType>,
+}
+
+/// Accuracy of a classifier on a labeled query set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassificationEvaluation {
+    pub total: usize,
+    pub correct: usize,
+    pub per_type: Vec<TypeEvaluation>,
+    pub misclassified: Vec<Misclassification>,
+}
+
+impl ClassificationEvaluation {
+    pub fn accuracy(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.correct as f64 / self.total as f64
+        }
+    }
+}
+
+/// Classify every labeled query and compare against its label
+pub fn evaluate_classifier(
+    classifier: &dyn Classifier,
+    labeled: &[(String, ResearchType)],
+) -> ClassificationEvaluation {
+    let mut per_type: Vec<TypeEvaluation> = ResearchType::all()
+        .into_iter()
+        .map(|research_type| TypeEvaluation {
+            research_type,
+            total: 0,
+            correct: 0,
+        })
+        .collect();
+    let mut misclassified = Vec::new();
+
+    for (query, expected) in labeled {
+        let predicted = classifier.classify(query).ok().map(|r| r.research_type);
+        let correct = predicted.as_ref() == Some(expected);
+        if let Some(entry) = per_type.iter_mut().find(|e| &e.research_type == expected) {
+            entry.total += 1;
+            entry.correct += usize::from(correct);
+        }
+        if !correct {
+            misclassified.push(Misclassification {
+                query: query.clone(),
+                expected: expected.clone(),
+                predicted,
+            });
+        }
+    }
+
+    per_type.retain(|entry| entry.total > 0);
+    ClassificationEvaluation {
+        total: labeled.len(),
+        correct: labeled.len() - misclassified.len(),
+        per_type,
+        misclassified,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn examples() -> Vec<TrainingExample> {
+        [
+            ("Should I choose Postgres or MySQL", ResearchType::Decision),
+            (
+                "Compare axum versus actix for an API",
+                ResearchType::Decision,
+            ),
+            (
+                "Which serialization format should we pick",
+                ResearchType::Decision,
+            ),
+            (
+                "Tokio or async-std, which is the better choice",
+                ResearchType::Decision,
+            ),
+            (
+                "Fix the borrow checker error in my parser",
+                ResearchType::Troubleshooting,
+            ),
+            (
+                "Why does my service panic on startup",
+                ResearchType::Troubleshooting,
+            ),
+            (
+                "Debug a deadlock between two mutexes",
+                ResearchType::Troubleshooting,
+            ),
+            (
+                "Cargo build fails with a linker error",
+                ResearchType::Troubleshooting,
+            ),
+            ("Explain how ownership works", ResearchType::Learning),
+            (
+                "What is a monad in functional programming",
+                ResearchType::Learning,
+            ),
+            ("Explain the actor model", ResearchType::Learning),
+            (
+                "What are lifetimes and why do they exist",
+                ResearchType::Learning,
+            ),
+        ]
+        .into_iter()
+        .map(|(query, research_type)| {
+            TrainingExample::new(query, research_type, TrainingSource::Fixture)
+        })
+        .collect()
+    }
+
+    fn config() -> ClassificationConfig {
+        ClassificationConfig {
+            default_threshold: 0.3,
+            classifier: ClassifierKind::Trainable,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_trained_model_classifies_similar_queries() {
+        let classifier =
+            TrainableClassifier::train(config(), &examples(), &TrainingOptions::default()).unwrap();
+
+        let result = classifier
+            .classify("Explain what ownership is in Rust")
+            .unwrap();
+        assert_eq!(result.research_type, ResearchType::Learning);
+        assert!(result.matched_keywords.contains(&"explain".to_string()));
+
+        let result = classifier
+            .classify("My build fails with a linker error")
+            .unwrap();
+        assert_eq!(result.research_type, ResearchType::Troubleshooting);
+
+        let candidates = classifier.get_all_classifications("Should I choose Redis");
+        assert_eq!(candidates[0].research_type, ResearchType::Decision);
+        let total: f64 = ResearchType::all()
+            .iter()
+            .map(|t| classifier.get_confidence("Should I choose Redis", t))
+            .sum();
+        assert!((total - 1.0).abs() < 1e-6);
+
+        assert!(classifier.classify("  ").is_err());
+        let single_type: Vec<TrainingExample> = examples().into_iter().take(4).collect();
+        assert!(
+            TrainableClassifier::train(config(), &single_type, &TrainingOptions::default())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_store_keeps_latest_label_and_model_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ClassificationTrainingStore::open(dir.path().join("data/training.jsonl"));
+        assert!(store.examples().unwrap().is_empty());
+
+        for example in examples() {
+            store.append(&example).unwrap();
+        }
+        assert!(
+            TrainingExample::from_feedback("Explain traits", &ResearchType::Learning, 0.4)
+                .is_none()
+        );
+        let confirmed =
+            TrainingExample::from_feedback("Explain traits", &ResearchType::Learning, 0.9).unwrap();
+        store.append(&confirmed).unwrap();
+        store
+            .append(&TrainingExample::new(
+                "explain  traits?",
+                ResearchType::Implementation,
+                TrainingSource::Correction,
+            ))
+            .unwrap();
+
+        let stored = store.examples().unwrap();
+        assert_eq!(stored.len(), examples().len() + 1);
+        let last = stored.last().unwrap();
+        assert_eq!(last.research_type, ResearchType::Implementation);
+        assert_eq!(last.source, TrainingSource::Correction);
+
+        let trained =
+            TrainableClassifier::train(config(), &stored, &TrainingOptions::default()).unwrap();
+        let path = dir.path().join("model.json");
+        trained.save(&path).unwrap();
+        let loaded = TrainableClassifier::load(config(), &path).unwrap();
+        assert_eq!(loaded.model(), trained.model());
+
+        let selected = create_classifier(&ClassificationConfig {
+            model_path: Some(path),
+            ..config()
+        });
+        assert_eq!(
+            selected
+                .classify("Explain the actor model")
+                .unwrap()
+                .research_type,
+            ResearchType::Learning
+        );
+    }
+
+    #[test]
+    fn test_evaluation_reports_accuracy_and_fallback_without_model() {
+        let fallback = create_classifier(&ClassificationConfig {
+            model_path: Some(PathBuf::from("/nonexistent/model.json")),
+            default_threshold: 0.1,
+            ..config()
+        });
+        let labeled: Vec<(String, ResearchType)> = vec![
+            (
+                "How to debug this error".to_string(),
+                ResearchType::Troubleshooting,
+            ),
+            (
+                "Explain the actor model".to_string(),
+                ResearchType::Decision,
+            ),
+        ];
+
+        let evaluation = evaluate_classifier(fallback.as_ref(), &labeled);
+        assert_eq!(evaluation.total, 2);
+        assert_eq!(evaluation.correct, 1);
+        assert_eq!(evaluation.accuracy(), 0.5);
+        assert_eq!(evaluation.per_type.len(), 2);
+        assert_eq!(evaluation.misclassified[0].expected, ResearchType::Decision);
+    }
+}
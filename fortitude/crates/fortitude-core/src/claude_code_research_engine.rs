@@ -20,7 +20,7 @@ use async_trait::async_trait;
 use chrono::Utc;
 use std::collections::HashMap;
 use std::time::Instant;
-use tracing::{debug, info, warn};
+use tracing::{debug, info, instrument, warn};
 
 use crate::research_engine::{ResearchEngine, ResearchEngineError};
 use crate::vector::VectorDocument;
@@ -331,6 +331,11 @@ The research combines semantic understanding with up-to-date web sources to prov
 
 #[async_trait]
 impl ResearchEngine for ClaudeCodeResearchEngine {
+    #[instrument(
+        name = "provider.generate",
+        skip_all,
+        fields(provider = "claude_code", research_type = %request.research_type)
+    )]
     async fn generate_research(
         &self,
         request: &ClassifiedRequest,
@@ -0,0 +1,670 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Code context extraction for research requests with attached Rust code
+// Parses code with syn and renders a compact summary of its items for the prompt
+use quote::ToTokens;
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::sync::OnceLock;
+use thiserror::Error;
+
+/// Default size budget in characters for a rendered code context summary
+pub const DEFAULT_CODE_CONTEXT_BUDGET: usize = 2000;
+
+/// Longest signature kept for a single item before it is shortened
+const MAX_SIGNATURE_CHARS: usize = 240;
+
+/// Crate roots that are not reported as dependencies
+const NON_DEPENDENCY_ROOTS: &[&str] = &["crate", "self", "super", "std", "core", "alloc"];
+
+/// Errors that can occur while extracting code context
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum CodeContextError {
+    #[error("Failed to parse Rust code: {0}")]
+    Parse(String),
+
+    #[error("No Rust items found in attached code")]
+    Empty,
+}
+
+/// Kind of Rust item captured in a code context summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodeItemKind {
+    Function,
+    Struct,
+    Enum,
+    Trait,
+    Impl,
+    TypeAlias,
+    Const,
+}
+
+impl std::fmt::Display for CodeItemKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Function => "fn",
+            Self::Struct => "struct",
+            Self::Enum => "enum",
+            Self::Trait => "trait",
+            Self::Impl => "impl",
+            Self::TypeAlias => "type",
+            Self::Const => "const",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A single item from the attached code
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeItem {
+    /// Item kind
+    pub kind: CodeItemKind,
+    /// Item name (the self type for impl blocks), including any module path
+    pub name: String,
+    /// Compact signature without bodies
+    pub signature: String,
+    /// Whether the item name appears in the research query
+    pub mentioned: bool,
+}
+
+/// Structured summary of attached Rust code
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CodeContextSummary {
+    /// Items ordered by relevance: items named in the query first, then source order
+    pub items: Vec<CodeItem>,
+    /// External crates referenced by `use` declarations
+    pub dependencies: Vec<String>,
+    /// Cargo features referenced by `cfg` attributes
+    pub features: Vec<String>,
+    /// Trait bounds declared on generic parameters and where clauses
+    pub trait_bounds: Vec<String>,
+}
+
+impl CodeContextSummary {
+    /// Render the summary as prompt text, keeping it within `budget` characters
+    ///
+    /// Items that do not fit are dropped and counted in a trailing note.
+    pub fn render(&self, budget: usize) -> String {
+        let mut lines = vec!["Attached code context:".to_string()];
+        if !self.dependencies.is_empty() {
+            lines.push(format!("Dependencies: {}", self.dependencies.join(", ")));
+        }
+        if !self.features.is_empty() {
+            lines.push(format!("Features: {}", self.features.join(", ")));
+        }
+        if !self.trait_bounds.is_empty() {
+            lines.push(format!("Trait bounds: {}", self.trait_bounds.join("; ")));
+        }
+
+        let mut output = String::new();
+        for line in lines {
+            if !push_line(&mut output, &line, budget) {
+                return output;
+            }
+        }
+
+        if self.items.is_empty() {
+            return output;
+        }
+        if !push_line(&mut output, "Items:", budget) {
+            return output;
+        }
+
+        let omitted_note = |count: usize| format!("({count} more items omitted)");
+        for (index, item) in self.items.iter().enumerate() {
+            let line = format!("- {}", item.signature);
+            let remaining = self.items.len() - index;
+            // Keep room for the omission note in case a later item does not fit
+            let reserved = if remaining > 1 {
+                omitted_note(remaining - 1).len() + 1
+            } else {
+                0
+            };
+            if output.len() + line.len() + 1 + reserved > budget {
+                push_line(&mut output, &omitted_note(remaining), budget);
+                break;
+            }
+            push_line(&mut output, &line, budget);
+        }
+
+        output
+    }
+}
+
+/// Extracts code context summaries from attached Rust code
+#[derive(Debug, Clone)]
+pub struct CodeContextExtractor {
+    budget: usize,
+}
+
+impl Default for CodeContextExtractor {
+    fn default() -> Self {
+        Self::new(DEFAULT_CODE_CONTEXT_BUDGET)
+    }
+}
+
+impl CodeContextExtractor {
+    /// Create an extractor that renders summaries within `budget` characters
+    pub fn new(budget: usize) -> Self {
+        Self { budget }
+    }
+
+    /// Size budget for rendered summaries
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Parse `source` and summarize its items, ranking those named in `query` first
+    ///
+    /// Accepts a complete file or a bare sequence of items and statements.
+    pub fn extract(
+        &self,
+        source: &str,
+        query: &str,
+    ) -> Result<CodeContextSummary, CodeContextError> {
+        let items = parse_items(source)?;
+
+        let mut collector = Collector::default();
+        collector.visit_items(&items, "");
+        if collector.items.is_empty() {
+            return Err(CodeContextError::Empty);
+        }
+
+        let query_words: BTreeSet<String> = query
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        for item in &mut collector.items {
+            let short_name = item.name.rsplit("::").next().unwrap_or(&item.name);
+            item.mentioned = query_words.contains(&short_name.to_lowercase());
+        }
+        // Stable sort keeps source order within each group
+        collector.items.sort_by_key(|item| !item.mentioned);
+
+        Ok(CodeContextSummary {
+            items: collector.items,
+            dependencies: collector.dependencies.into_iter().collect(),
+            features: collector.features.into_iter().collect(),
+            trait_bounds: collector.trait_bounds.into_iter().collect(),
+        })
+    }
+
+    /// Extract and render a summary in one step
+    pub fn summarize(&self, source: &str, query: &str) -> Result<String, CodeContextError> {
+        Ok(self.extract(source, query)?.render(self.budget))
+    }
+}
+
+/// Append `line` to `output` if it fits within `budget`
+fn push_line(output: &mut String, line: &str, budget: usize) -> bool {
+    if output.len() + line.len() + 1 > budget {
+        return false;
+    }
+    output.push_str(line);
+    output.push('\n');
+    true
+}
+
+fn parse_items(source: &str) -> Result<Vec<syn::Item>, CodeContextError> {
+    match syn::parse_file(source) {
+        Ok(file) => Ok(file.items),
+        Err(file_error) => {
+            // Snippets often mix items with statements, so retry as a block body
+            let block: syn::Block = syn::parse_str(&format!("{{\n{source}\n}}"))
+                .map_err(|_| CodeContextError::Parse(file_error.to_string()))?;
+            Ok(block
+                .stmts
+                .into_iter()
+                .filter_map(|stmt| match stmt {
+                    syn::Stmt::Item(item) => Some(item),
+                    _ => None,
+                })
+                .collect())
+        }
+    }
+}
+
+#[derive(Default)]
+struct Collector {
+    items: Vec<CodeItem>,
+    dependencies: BTreeSet<String>,
+    features: BTreeSet<String>,
+    trait_bounds: BTreeSet<String>,
+}
+
+impl Collector {
+    fn visit_items(&mut self, items: &[syn::Item], prefix: &str) {
+        for item in items {
+            self.visit_item(item, prefix);
+        }
+    }
+
+    fn visit_item(&mut self, item: &syn::Item, prefix: &str) {
+        match item {
+            syn::Item::Fn(item_fn) => {
+                self.collect_attrs(&item_fn.attrs);
+                self.collect_generics(&item_fn.sig.generics);
+                let signature = format!("{}{}", visibility(&item_fn.vis), tokens(&item_fn.sig));
+                self.push(
+                    CodeItemKind::Function,
+                    prefix,
+                    &item_fn.sig.ident,
+                    signature,
+                );
+            }
+            syn::Item::Struct(item_struct) => {
+                self.collect_attrs(&item_struct.attrs);
+                self.collect_generics(&item_struct.generics);
+                let fields = fields_summary(&item_struct.fields);
+                let signature = format!(
+                    "{}struct {}{}{}",
+                    visibility(&item_struct.vis),
+                    item_struct.ident,
+                    tokens(&item_struct.generics),
+                    fields
+                );
+                self.push(CodeItemKind::Struct, prefix, &item_struct.ident, signature);
+            }
+            syn::Item::Enum(item_enum) => {
+                self.collect_attrs(&item_enum.attrs);
+                self.collect_generics(&item_enum.generics);
+                let variants: Vec<String> = item_enum
+                    .variants
+                    .iter()
+                    .map(|variant| format!("{}{}", variant.ident, fields_summary(&variant.fields)))
+                    .collect();
+                let signature = format!(
+                    "{}enum {}{} {{ {} }}",
+                    visibility(&item_enum.vis),
+                    item_enum.ident,
+                    tokens(&item_enum.generics),
+                    variants.join(", ")
+                );
+                self.push(CodeItemKind::Enum, prefix, &item_enum.ident, signature);
+            }
+            syn::Item::Trait(item_trait) => {
+                self.collect_attrs(&item_trait.attrs);
+                self.collect_generics(&item_trait.generics);
+                let supertraits = if item_trait.supertraits.is_empty() {
+                    String::new()
+                } else {
+                    format!(": {}", tokens(&item_trait.supertraits))
+                };
+                let methods: Vec<String> = item_trait
+                    .items
+                    .iter()
+                    .filter_map(|trait_item| match trait_item {
+                        syn::TraitItem::Fn(method) => Some(format!("{};", tokens(&method.sig))),
+                        syn::TraitItem::Type(assoc) => Some(format!("type {};", assoc.ident)),
+                        _ => None,
+                    })
+                    .collect();
+                let signature = format!(
+                    "{}trait {}{}{} {{ {} }}",
+                    visibility(&item_trait.vis),
+                    item_trait.ident,
+                    tokens(&item_trait.generics),
+                    supertraits,
+                    methods.join(" ")
+                );
+                self.push(CodeItemKind::Trait, prefix, &item_trait.ident, signature);
+            }
+            syn::Item::Impl(item_impl) => {
+                self.collect_attrs(&item_impl.attrs);
+                self.collect_generics(&item_impl.generics);
+                let self_ty = tokens(&item_impl.self_ty);
+                let header = match &item_impl.trait_ {
+                    Some((negative, path, _)) => format!(
+                        "impl{} {}{} for {}",
+                        tokens(&item_impl.generics),
+                        if negative.is_some() { "!" } else { "" },
+                        tokens(path),
+                        self_ty
+                    ),
+                    None => format!("impl{} {}", tokens(&item_impl.generics), self_ty),
+                };
+                let methods: Vec<String> = item_impl
+                    .items
+                    .iter()
+                    .filter_map(|impl_item| match impl_item {
+                        syn::ImplItem::Fn(method) => {
+                            self.collect_generics(&method.sig.generics);
+                            Some(format!(
+                                "{}{}",
+                                visibility(&method.vis),
+                                tokens(&method.sig)
+                            ))
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                let signature = if methods.is_empty() {
+                    header
+                } else {
+                    format!("{header} {{ {} }}", methods.join("; "))
+                };
+                let name = qualified(prefix, &self_ty);
+                self.items.push(CodeItem {
+                    kind: CodeItemKind::Impl,
+                    name,
+                    signature: shorten(&signature),
+                    mentioned: false,
+                });
+            }
+            syn::Item::Type(item_type) => {
+                self.collect_attrs(&item_type.attrs);
+                let signature = format!(
+                    "{}type {}{} = {};",
+                    visibility(&item_type.vis),
+                    item_type.ident,
+                    tokens(&item_type.generics),
+                    tokens(&item_type.ty)
+                );
+                self.push(CodeItemKind::TypeAlias, prefix, &item_type.ident, signature);
+            }
+            syn::Item::Const(item_const) => {
+                self.collect_attrs(&item_const.attrs);
+                let signature = format!(
+                    "{}const {}: {}",
+                    visibility(&item_const.vis),
+                    item_const.ident,
+                    tokens(&item_const.ty)
+                );
+                self.push(CodeItemKind::Const, prefix, &item_const.ident, signature);
+            }
+            syn::Item::Mod(item_mod) => {
+                self.collect_attrs(&item_mod.attrs);
+                if let Some((_, nested)) = &item_mod.content {
+                    let prefix = qualified(prefix, &item_mod.ident.to_string());
+                    self.visit_items(nested, &prefix);
+                }
+            }
+            syn::Item::Use(item_use) => {
+                self.collect_attrs(&item_use.attrs);
+                self.collect_use_roots(&item_use.tree);
+            }
+            syn::Item::ExternCrate(extern_crate) => {
+                self.dependencies.insert(extern_crate.ident.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    fn push(&mut self, kind: CodeItemKind, prefix: &str, ident: &syn::Ident, signature: String) {
+        self.items.push(CodeItem {
+            kind,
+            name: qualified(prefix, &ident.to_string()),
+            signature: shorten(&signature),
+            mentioned: false,
+        });
+    }
+
+    fn collect_use_roots(&mut self, tree: &syn::UseTree) {
+        match tree {
+            syn::UseTree::Path(path) => self.insert_dependency(&path.ident),
+            syn::UseTree::Name(name) => self.insert_dependency(&name.ident),
+            syn::UseTree::Rename(rename) => self.insert_dependency(&rename.ident),
+            syn::UseTree::Group(group) => {
+                for nested in &group.items {
+                    self.collect_use_roots(nested);
+                }
+            }
+            syn::UseTree::Glob(_) => {}
+        }
+    }
+
+    fn insert_dependency(&mut self, ident: &syn::Ident) {
+        let root = ident.to_string();
+        if !NON_DEPENDENCY_ROOTS.contains(&root.as_str()) {
+            self.dependencies.insert(root);
+        }
+    }
+
+    fn collect_attrs(&mut self, attrs: &[syn::Attribute]) {
+        for attr in attrs {
+            if attr.path().is_ident("cfg") || attr.path().is_ident("cfg_attr") {
+                let text = tokens(&attr.meta);
+                for capture in feature_regex().captures_iter(&text) {
+                    self.features.insert(capture[1].to_string());
+                }
+            }
+        }
+    }
+
+    fn collect_generics(&mut self, generics: &syn::Generics) {
+        for param in &generics.params {
+            if let syn::GenericParam::Type(type_param) = param {
+                if !type_param.bounds.is_empty() {
+                    self.trait_bounds.insert(format!(
+                        "{}: {}",
+                        type_param.ident,
+                        tokens(&type_param.bounds)
+                    ));
+                }
+            }
+        }
+        if let Some(where_clause) = &generics.where_clause {
+            for predicate in &where_clause.predicates {
+                self.trait_bounds.insert(tokens(predicate));
+            }
+        }
+    }
+}
+
+fn feature_regex() -> &'static Regex {
+    static FEATURE: OnceLock<Regex> = OnceLock::new();
+    FEATURE.get_or_init(|| Regex::new(r#"feature\s*=\s*"([^"]+)""#).unwrap())
+}
+
+fn fields_summary(fields: &syn::Fields) -> String {
+    match fields {
+        syn::Fields::Named(named) => {
+            let fields: Vec<String> = named
+                .named
+                .iter()
+                .map(|field| {
+                    format!(
+                        "{}{}: {}",
+                        visibility(&field.vis),
+                        field
+                            .ident
+                            .as_ref()
+                            .map(|i| i.to_string())
+                            .unwrap_or_default(),
+                        tokens(&field.ty)
+                    )
+                })
+                .collect();
+            format!(" {{ {} }}", fields.join(", "))
+        }
+        syn::Fields::Unnamed(unnamed) => {
+            let fields: Vec<String> = unnamed.unnamed.iter().map(|f| tokens(&f.ty)).collect();
+            format!("({})", fields.join(", "))
+        }
+        syn::Fields::Unit => String::new(),
+    }
+}
+
+fn visibility(vis: &syn::Visibility) -> String {
+    match vis {
+        syn::Visibility::Inherited => String::new(),
+        other => format!("{} ", tokens(other)),
+    }
+}
+
+fn qualified(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{prefix}::{name}")
+    }
+}
+
+fn shorten(signature: &str) -> String {
+    if signature.chars().count() > MAX_SIGNATURE_CHARS {
+        let truncated: String = signature.chars().take(MAX_SIGNATURE_CHARS - 3).collect();
+        format!("{truncated}...")
+    } else {
+        signature.to_string()
+    }
+}
+
+/// Print tokens with the spacing a reader would write by hand
+fn tokens<T: ToTokens>(node: &T) -> String {
+    let mut text = node.to_token_stream().to_string();
+    for (from, to) in [
+        (" :: ", "::"),
+        (":: ", "::"),
+        (" ::", "::"),
+        (" : ", ": "),
+        (" , ", ", "),
+        (" ,", ","),
+        (" ;", ";"),
+        ("& ", "&"),
+        ("< ", "<"),
+        (" <", "<"),
+        (" >", ">"),
+        ("( ", "("),
+        (" (", "("),
+        (" )", ")"),
+        ("[ ", "["),
+        (" ]", "]"),
+        (" ?", "?"),
+        ("->(", "-> ("),
+        ("=(", "= ("),
+        (":(", ": ("),
+        (",(", ", ("),
+    ] {
+        text = text.replace(from, to);
+    }
+    // Where clauses are printed with a trailing comma
+    text.trim_end_matches(',').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Config {
+    pub retries: u32,
+    endpoints: HashMap<String, String>,
+}
+
+pub enum Mode {
+    Fast,
+    Careful(u8),
+}
+
+pub trait Fetcher: Send + Sync {
+    async fn fetch(&self, url: &str) -> Result<String, FetchError>;
+}
+
+impl Config {
+    pub fn new(retries: u32) -> Self {
+        Self { retries, endpoints: HashMap::new() }
+    }
+}
+
+#[cfg(feature = "cache")]
+pub async fn load_config<T>(path: &str, store: &RwLock<T>) -> Result<Config, std::io::Error>
+where
+    T: Clone + Send,
+{
+    todo!()
+}
+"#;
+
+    #[test]
+    fn test_extract_summarizes_items_dependencies_and_features() {
+        let summary = CodeContextExtractor::default()
+            .extract(SAMPLE, "Why does load_config fail?")
+            .unwrap();
+
+        assert_eq!(summary.dependencies, vec!["serde", "tokio"]);
+        assert_eq!(summary.features, vec!["cache"]);
+        assert_eq!(summary.trait_bounds, vec!["T: Clone + Send"]);
+
+        // The function named in the query is ranked first
+        let first = &summary.items[0];
+        assert_eq!(first.kind, CodeItemKind::Function);
+        assert!(first.mentioned);
+        assert_eq!(
+            first.signature,
+            "pub async fn load_config<T>(path: &str, store: &RwLock<T>) -> Result<Config, std::io::Error> where T: Clone + Send"
+        );
+
+        let config = summary.items.iter().find(|i| i.name == "Config").unwrap();
+        assert_eq!(
+            config.signature,
+            "pub struct Config { pub retries: u32, endpoints: HashMap<String, String> }"
+        );
+        let fetcher = summary.items.iter().find(|i| i.name == "Fetcher").unwrap();
+        assert_eq!(
+            fetcher.signature,
+            "pub trait Fetcher: Send + Sync { async fn fetch(&self, url: &str) -> Result<String, FetchError>; }"
+        );
+        assert!(summary.items.iter().any(|i| i.kind == CodeItemKind::Impl
+            && i.signature.contains("pub fn new(retries: u32) -> Self")));
+    }
+
+    #[test]
+    fn test_extract_accepts_statement_snippets() {
+        let snippet = "let x = compute();\nfn compute() -> u64 { 42 }";
+        let summary = CodeContextExtractor::default()
+            .extract(snippet, "")
+            .unwrap();
+
+        assert_eq!(summary.items.len(), 1);
+        assert_eq!(summary.items[0].signature, "fn compute() -> u64");
+    }
+
+    #[test]
+    fn test_extract_errors() {
+        let extractor = CodeContextExtractor::default();
+        assert!(matches!(
+            extractor.extract("fn broken( {", ""),
+            Err(CodeContextError::Parse(_))
+        ));
+        assert_eq!(
+            extractor.extract("use serde::Serialize;", ""),
+            Err(CodeContextError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_render_respects_budget() {
+        let extractor = CodeContextExtractor::default();
+        let summary = extractor.extract(SAMPLE, "load_config").unwrap();
+
+        let full = summary.render(DEFAULT_CODE_CONTEXT_BUDGET);
+        assert!(full.starts_with("Attached code context:\n"));
+        assert!(full.contains("Dependencies: serde, tokio"));
+        assert!(full.contains("Features: cache"));
+        assert!(!full.contains("omitted"));
+
+        let budget = 260;
+        let compact = summary.render(budget);
+        assert!(compact.len() <= budget);
+        assert!(compact.contains("load_config"));
+        assert!(compact.contains("more items omitted"));
+    }
+}
@@ -0,0 +1,352 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Confluence output connector writing research pages into a space
+// Converts page markdown to storage format and relies on Confluence page versions for conflict detection
+
+use super::{ConnectorError, OutputConnector, RemotePage, RequestPacer, WikiPage};
+use async_trait::async_trait;
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// Characters of the cache key appended to titles, which must be unique per space
+const TITLE_KEY_CHARS: usize = 8;
+
+/// Confluence connector settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfluenceConfig {
+    /// Site URL, e.g. `https://example.atlassian.net/wiki`
+    pub base_url: String,
+    /// Space that research pages are created in
+    pub space_key: String,
+    /// Page that research pages are created under
+    pub parent_page_id: Option<String>,
+    /// Account email for API token auth; empty to send the token as a bearer token
+    pub username: String,
+    pub api_token: String,
+    pub requests_per_second: f64,
+    pub max_retries: u32,
+    pub timeout_seconds: u64,
+}
+
+impl Default for ConfluenceConfig {
+    fn default() -> Self {
+        Self {
+            base_url: String::new(),
+            space_key: String::new(),
+            parent_page_id: None,
+            username: String::new(),
+            api_token: String::new(),
+            requests_per_second: 5.0,
+            max_retries: 3,
+            timeout_seconds: 30,
+        }
+    }
+}
+
+/// Writes research pages into a Confluence space
+#[derive(Debug)]
+pub struct ConfluenceConnector {
+    config: ConfluenceConfig,
+    client: reqwest::Client,
+    pacer: RequestPacer,
+}
+
+impl ConfluenceConnector {
+    pub fn new(config: ConfluenceConfig) -> Result<Self, ConnectorError> {
+        if config.base_url.trim().is_empty()
+            || config.space_key.trim().is_empty()
+            || config.api_token.trim().is_empty()
+        {
+            return Err(ConnectorError::Config(
+                "Confluence requires a base URL, a space key and an API token".to_string(),
+            ));
+        }
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_seconds))
+            .build()?;
+        let pacer = RequestPacer::new(config.requests_per_second, config.max_retries);
+        Ok(Self {
+            config,
+            client,
+            pacer,
+        })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!(
+            "{}/rest/api/content{path}",
+            self.config.base_url.trim_end_matches('/')
+        )
+    }
+
+    async fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Value, ConnectorError> {
+        let url = self.url(path);
+        let response = self
+            .pacer
+            .send(self.name(), || {
+                let request = self.client.request(method.clone(), &url);
+                let request = if self.config.username.is_empty() {
+                    request.bearer_auth(&self.config.api_token)
+                } else {
+                    request.basic_auth(&self.config.username, Some(&self.config.api_token))
+                };
+                match body {
+                    Some(body) => request.json(body),
+                    None => request,
+                }
+            })
+            .await?;
+        Ok(response.json().await?)
+    }
+
+    fn title(page: &WikiPage) -> String {
+        let key: String = page.cache_key.chars().take(TITLE_KEY_CHARS).collect();
+        format!("{} [{key}]", page.title)
+    }
+
+    fn remote_page(&self, content: &Value) -> Result<RemotePage, ConnectorError> {
+        let id = content["id"].as_str().ok_or_else(|| ConnectorError::Api {
+            connector: self.name().to_string(),
+            status: 200,
+            message: "Page response has no id".to_string(),
+        })?;
+        let url = match (
+            content["_links"]["base"].as_str(),
+            content["_links"]["webui"].as_str(),
+        ) {
+            (Some(base), Some(webui)) => Some(format!("{base}{webui}")),
+            _ => None,
+        };
+        Ok(RemotePage {
+            id: id.to_string(),
+            version: content["version"]["number"]
+                .as_u64()
+                .unwrap_or_default()
+                .to_string(),
+            url,
+        })
+    }
+}
+
+#[async_trait]
+impl OutputConnector for ConfluenceConnector {
+    fn name(&self) -> &str {
+        "confluence"
+    }
+
+    async fn create_page(&self, page: &WikiPage) -> Result<RemotePage, ConnectorError> {
+        let mut body = json!({
+            "type": "page",
+            "title": Self::title(page),
+            "space": { "key": self.config.space_key },
+            "body": { "storage": { "value": markdown_to_storage(&page.markdown), "representation": "storage" } },
+        });
+        if let Some(parent) = &self.config.parent_page_id {
+            body["ancestors"] = json!([{ "id": parent }]);
+        }
+        let created = self.request(reqwest::Method::POST, "", Some(&body)).await?;
+        self.remote_page(&created)
+    }
+
+    async fn update_page(
+        &self,
+        current: &RemotePage,
+        page: &WikiPage,
+    ) -> Result<RemotePage, ConnectorError> {
+        let version: u64 = current.version.parse().unwrap_or_default();
+        let body = json!({
+            "id": current.id,
+            "type": "page",
+            "title": Self::title(page),
+            "space": { "key": self.config.space_key },
+            "version": { "number": version + 1 },
+            "body": { "storage": { "value": markdown_to_storage(&page.markdown), "representation": "storage" } },
+        });
+        // Confluence rejects the update when someone saved a newer version meanwhile
+        match self
+            .request(
+                reqwest::Method::PUT,
+                &format!("/{}", current.id),
+                Some(&body),
+            )
+            .await
+        {
+            Ok(updated) => self.remote_page(&updated),
+            Err(ConnectorError::Api { status: 409, .. }) => Err(ConnectorError::Conflict {
+                page_id: current.id.clone(),
+            }),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn fetch_page(&self, page_id: &str) -> Result<Option<RemotePage>, ConnectorError> {
+        let content = match self
+            .request(
+                reqwest::Method::GET,
+                &format!("/{page_id}?expand=version"),
+                None,
+            )
+            .await
+        {
+            Ok(content) => content,
+            Err(ConnectorError::Api { status: 404, .. }) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        if content["status"].as_str().is_some_and(|s| s != "current") {
+            return Ok(None);
+        }
+        self.remote_page(&content).map(Some)
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Convert page markdown to Confluence storage format
+///
+/// Code blocks become code macros; raw HTML in the markdown is escaped
+/// rather than passed through.
+pub fn markdown_to_storage(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut in_table_head = false;
+    let mut in_code = false;
+
+    for event in Parser::new_ext(
+        markdown,
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH,
+    ) {
+        match event {
+            Event::Start(tag) => match tag {
+                Tag::Paragraph => out.push_str("<p>"),
+                Tag::Heading { level, .. } => out.push_str(&format!("<{level}>")),
+                Tag::BlockQuote(_) => out.push_str("<blockquote>"),
+                Tag::List(Some(_)) => out.push_str("<ol>"),
+                Tag::List(None) => out.push_str("<ul>"),
+                Tag::Item => out.push_str("<li>"),
+                Tag::Emphasis => out.push_str("<em>"),
+                Tag::Strong => out.push_str("<strong>"),
+                Tag::Strikethrough => out.push_str("<s>"),
+                Tag::Link { dest_url, .. } => {
+                    out.push_str(&format!("<a href=\"{}\">", escape(&dest_url)))
+                }
+                Tag::Table(_) => out.push_str("<table><tbody>"),
+                Tag::TableHead => {
+                    in_table_head = true;
+                    out.push_str("<tr>");
+                }
+                Tag::TableRow => out.push_str("<tr>"),
+                Tag::TableCell => out.push_str(if in_table_head { "<th>" } else { "<td>" }),
+                Tag::CodeBlock(kind) => {
+                    in_code = true;
+                    out.push_str("<ac:structured-macro ac:name=\"code\">");
+                    if let CodeBlockKind::Fenced(info) = kind {
+                        if let Some(language) = info.split_whitespace().next() {
+                            out.push_str(&format!(
+                                "<ac:parameter ac:name=\"language\">{}</ac:parameter>",
+                                escape(language)
+                            ));
+                        }
+                    }
+                    out.push_str("<ac:plain-text-body><![CDATA[");
+                }
+                _ => {}
+            },
+            Event::End(tag) => match tag {
+                TagEnd::Paragraph => out.push_str("</p>"),
+                TagEnd::Heading(level) => out.push_str(&format!("</{level}>")),
+                TagEnd::BlockQuote(_) => out.push_str("</blockquote>"),
+                TagEnd::List(true) => out.push_str("</ol>"),
+                TagEnd::List(false) => out.push_str("</ul>"),
+                TagEnd::Item => out.push_str("</li>"),
+                TagEnd::Emphasis => out.push_str("</em>"),
+                TagEnd::Strong => out.push_str("</strong>"),
+                TagEnd::Strikethrough => out.push_str("</s>"),
+                TagEnd::Link => out.push_str("</a>"),
+                TagEnd::Table => out.push_str("</tbody></table>"),
+                TagEnd::TableHead => {
+                    in_table_head = false;
+                    out.push_str("</tr>");
+                }
+                TagEnd::TableRow => out.push_str("</tr>"),
+                TagEnd::TableCell => out.push_str(if in_table_head { "</th>" } else { "</td>" }),
+                TagEnd::CodeBlock => {
+                    in_code = false;
+                    out.push_str("]]></ac:plain-text-body></ac:structured-macro>");
+                }
+                _ => {}
+            },
+            // CDATA cannot contain its own terminator, so split it across sections
+            Event::Text(text) if in_code => out.push_str(&text.replace("]]>", "]]]]><![CDATA[>")),
+            Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => {
+                out.push_str(&escape(&text))
+            }
+            Event::Code(text) => out.push_str(&format!("<code>{}</code>", escape(&text))),
+            Event::SoftBreak => out.push(' '),
+            Event::HardBreak => out.push_str("<br/>"),
+            Event::Rule => out.push_str("<hr/>"),
+            _ => {}
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_to_storage() {
+        let storage = markdown_to_storage(
+            "## Steps\n\nUse **serde** & `derive`.\n\n1. add\n2. build\n\n```rust\nlet a = b[c[0]]>1;\n```\n\n<script>x</script>\n\n| a | b |\n|---|---|\n| 1 | 2 |",
+        );
+        assert!(storage.starts_with(
+            "<h2>Steps</h2><p>Use <strong>serde</strong> &amp; <code>derive</code>.</p>"
+        ));
+        assert!(storage.contains("<ol><li>add</li><li>build</li></ol>"));
+        assert!(storage.contains(
+            "<ac:parameter ac:name=\"language\">rust</ac:parameter><ac:plain-text-body><![CDATA[let a = b[c[0]]]]><![CDATA[>1;\n]]></ac:plain-text-body>"
+        ));
+        assert!(storage.contains("&lt;script&gt;"));
+        assert!(storage.contains("<tr><th>a</th><th>b</th></tr><tr><td>1</td><td>2</td></tr>"));
+    }
+
+    #[test]
+    fn test_titles_carry_cache_key() {
+        let page = WikiPage {
+            cache_key: "0123456789abcdef".to_string(),
+            title: "Async traits".to_string(),
+            markdown: String::new(),
+            research_type: fortitude_types::ResearchType::Learning,
+            content_hash: String::new(),
+        };
+        assert_eq!(ConfluenceConnector::title(&page), "Async traits [01234567]");
+        assert!(matches!(
+            ConfluenceConnector::new(ConfluenceConfig::default()),
+            Err(ConnectorError::Config(_))
+        ));
+    }
+}
@@ -0,0 +1,702 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Output connectors that mirror stored research into external wikis
+//! Research results are mapped to wiki pages keyed by their cache key. A
+//! [`WikiSync`] run creates missing pages, updates pages whose content changed
+//! and leaves pages that were edited in the wiki alone unless told to overwrite.
+
+pub mod confluence;
+pub mod notion;
+pub mod state;
+
+pub use confluence::{ConfluenceConfig, ConfluenceConnector};
+pub use notion::{NotionConfig, NotionConnector};
+pub use state::{SyncRecord, SyncStateStore};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use fortitude_types::{ResearchResult, ResearchType};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+/// Characters of the original query used as page title
+const MAX_TITLE_CHARS: usize = 120;
+
+/// Characters of an error response body kept in error messages
+const MAX_ERROR_BODY_CHARS: usize = 300;
+
+/// Errors raised while syncing to an external wiki
+#[derive(Error, Debug)]
+pub enum ConnectorError {
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("{connector} API error ({status}): {message}")]
+    Api {
+        connector: String,
+        status: u16,
+        message: String,
+    },
+
+    #[error("{connector} rate limit still exceeded after {attempts} attempts")]
+    RateLimited { connector: String, attempts: u32 },
+
+    #[error("Remote page {page_id} was changed since the last sync")]
+    Conflict { page_id: String },
+
+    #[error("Invalid connector configuration: {0}")]
+    Config(String),
+
+    #[error("Sync state error: {0}")]
+    State(String),
+}
+
+/// A research result rendered as a wiki page
+#[derive(Debug, Clone, PartialEq)]
+pub struct WikiPage {
+    /// Cache key of the result, used to find the page again on later syncs
+    pub cache_key: String,
+    pub title: String,
+    /// Page body as markdown; connectors convert it to their own format
+    pub markdown: String,
+    pub research_type: ResearchType,
+    /// Hash of title and body, compared against the last synced hash
+    pub content_hash: String,
+}
+
+impl WikiPage {
+    /// Map a research result to a page: answer first, then evidence and details
+    pub fn from_result(result: &ResearchResult) -> Self {
+        let query = result.request.original_query.trim();
+        let title = if query.chars().count() > MAX_TITLE_CHARS {
+            let truncated: String = query.chars().take(MAX_TITLE_CHARS - 3).collect();
+            format!("{}...", truncated.trim_end())
+        } else {
+            query.to_string()
+        };
+
+        let mut markdown = result.immediate_answer.trim().to_string();
+        if !result.supporting_evidence.is_empty() {
+            markdown.push_str("\n\n## Evidence\n");
+            for evidence in &result.supporting_evidence {
+                markdown.push_str(&format!(
+                    "\n- **{}** ({}): {}",
+                    evidence.source,
+                    evidence.evidence_type,
+                    evidence.content.trim()
+                ));
+            }
+        }
+        if !result.implementation_details.is_empty() {
+            markdown.push_str("\n\n## Implementation Details\n");
+            for detail in &result.implementation_details {
+                markdown.push_str(&format!(
+                    "\n### {} ({} priority)\n\n{}\n",
+                    detail.category,
+                    detail.priority,
+                    detail.content.trim()
+                ));
+                if !detail.prerequisites.is_empty() {
+                    markdown.push_str(&format!(
+                        "\nPrerequisites: {}\n",
+                        detail.prerequisites.join(", ")
+                    ));
+                }
+            }
+        }
+
+        let tags = &result.request.domain_context.tags;
+        markdown.push_str(&format!(
+            "\n\n---\n\nResearch type: {} | Quality: {:.2} | Completed: {} | Cache key: `{}`",
+            result.request.research_type,
+            result.metadata.quality_score,
+            result.metadata.completed_at.format("%Y-%m-%d %H:%M UTC"),
+            result.metadata.cache_key
+        ));
+        if !tags.is_empty() {
+            markdown.push_str(&format!(" | Tags: {}", tags.join(", ")));
+        }
+        markdown.push('\n');
+
+        let content_hash = format!("{:x}", md5::compute(format!("{title}\n{markdown}")));
+        Self {
+            cache_key: result.metadata.cache_key.clone(),
+            title,
+            markdown,
+            research_type: result.request.research_type.clone(),
+            content_hash,
+        }
+    }
+}
+
+/// A page as it currently exists in the wiki
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemotePage {
+    pub id: String,
+    /// Opaque revision marker; a change means the page was edited
+    pub version: String,
+    pub url: Option<String>,
+}
+
+/// A wiki that research pages can be written to
+#[async_trait]
+pub trait OutputConnector: Send + Sync {
+    /// Connector name, used to key its sync state
+    fn name(&self) -> &str;
+
+    /// Create a page for a result that was never synced
+    async fn create_page(&self, page: &WikiPage) -> Result<RemotePage, ConnectorError>;
+
+    /// Replace title and content of an existing page
+    ///
+    /// `current` is the page as just fetched; connectors with optimistic
+    /// locking return [`ConnectorError::Conflict`] when it is outdated.
+    async fn update_page(
+        &self,
+        current: &RemotePage,
+        page: &WikiPage,
+    ) -> Result<RemotePage, ConnectorError>;
+
+    /// Current state of a page, `None` when it was deleted or archived
+    async fn fetch_page(&self, page_id: &str) -> Result<Option<RemotePage>, ConnectorError>;
+}
+
+/// Selection of results to sync; empty lists match everything
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyncFilter {
+    /// Sync results carrying any of these tags
+    pub tags: Vec<String>,
+    /// Sync results of any of these research types
+    pub research_types: Vec<ResearchType>,
+}
+
+impl SyncFilter {
+    /// Whether results of this research type can match
+    pub fn matches_type(&self, research_type: &ResearchType) -> bool {
+        self.research_types.is_empty() || self.research_types.contains(research_type)
+    }
+
+    pub fn matches(&self, result: &ResearchResult) -> bool {
+        self.matches_type(&result.request.research_type)
+            && (self.tags.is_empty()
+                || result
+                    .request
+                    .domain_context
+                    .tags
+                    .iter()
+                    .any(|tag| self.tags.iter().any(|t| t.trim() == tag)))
+    }
+}
+
+/// What to do with a page that was edited in the wiki since the last sync
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictPolicy {
+    /// Keep the wiki edits and report the conflict
+    #[default]
+    Skip,
+    /// Replace the wiki edits with the stored research
+    Overwrite,
+}
+
+impl ConflictPolicy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "skip" => Some(Self::Skip),
+            "overwrite" => Some(Self::Overwrite),
+            _ => None,
+        }
+    }
+}
+
+/// Spaces requests to stay under a wiki's rate limit and retries throttled ones
+#[derive(Debug)]
+pub struct RequestPacer {
+    min_interval: Duration,
+    max_retries: u32,
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl RequestPacer {
+    pub fn new(requests_per_second: f64, max_retries: u32) -> Self {
+        let min_interval = if requests_per_second > 0.0 {
+            Duration::from_secs_f64(1.0 / requests_per_second)
+        } else {
+            Duration::ZERO
+        };
+        Self {
+            min_interval,
+            max_retries,
+            next_slot: Mutex::new(None),
+        }
+    }
+
+    async fn wait_turn(&self) {
+        let mut next_slot = self.next_slot.lock().await;
+        let now = Instant::now();
+        if let Some(slot) = *next_slot {
+            if slot > now {
+                tokio::time::sleep(slot - now).await;
+            }
+        }
+        *next_slot = Some(Instant::now() + self.min_interval);
+    }
+
+    /// Send a request built by `build`, retrying on HTTP 429
+    ///
+    /// Waits for `Retry-After` when the server sends it and backs off
+    /// exponentially otherwise. Non-success responses become
+    /// [`ConnectorError::Api`].
+    pub async fn send<F>(
+        &self,
+        connector: &str,
+        build: F,
+    ) -> Result<reqwest::Response, ConnectorError>
+    where
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        let mut attempts = 0;
+        loop {
+            self.wait_turn().await;
+            attempts += 1;
+            let response = build().send().await?;
+            let status = response.status();
+
+            if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                if attempts > self.max_retries {
+                    return Err(ConnectorError::RateLimited {
+                        connector: connector.to_string(),
+                        attempts,
+                    });
+                }
+                let delay = response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.trim().parse::<u64>().ok())
+                    .map(Duration::from_secs)
+                    .unwrap_or_else(|| Duration::from_millis(500 * 2u64.pow(attempts.min(6))));
+                debug!("{} rate limited, retrying in {:?}", connector, delay);
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(ConnectorError::Api {
+                    connector: connector.to_string(),
+                    status: status.as_u16(),
+                    message: body.chars().take(MAX_ERROR_BODY_CHARS).collect(),
+                });
+            }
+            return Ok(response);
+        }
+    }
+}
+
+/// Counts of one connector's sync run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyncReport {
+    pub connector: String,
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    /// Cache keys whose pages were edited in the wiki and left alone
+    pub conflicts: Vec<String>,
+    /// Cache keys that failed, with the error
+    pub failed: Vec<(String, String)>,
+}
+
+impl SyncReport {
+    pub fn summary(&self) -> String {
+        format!(
+            "{}: {} created, {} updated, {} unchanged, {} conflicts, {} failed",
+            self.connector,
+            self.created,
+            self.updated,
+            self.unchanged,
+            self.conflicts.len(),
+            self.failed.len()
+        )
+    }
+}
+
+enum SyncOutcome {
+    Created,
+    Updated,
+    Unchanged,
+    Conflict,
+}
+
+/// Mirrors research results into one or more wikis
+#[derive(Clone)]
+pub struct WikiSync {
+    connectors: Vec<Arc<dyn OutputConnector>>,
+    state: SyncStateStore,
+    filter: SyncFilter,
+    conflict_policy: ConflictPolicy,
+}
+
+impl WikiSync {
+    pub fn new(state: SyncStateStore) -> Self {
+        Self {
+            connectors: Vec::new(),
+            state,
+            filter: SyncFilter::default(),
+            conflict_policy: ConflictPolicy::default(),
+        }
+    }
+
+    pub fn with_connector(mut self, connector: Arc<dyn OutputConnector>) -> Self {
+        self.connectors.push(connector);
+        self
+    }
+
+    pub fn with_filter(mut self, filter: SyncFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn with_conflict_policy(mut self, conflict_policy: ConflictPolicy) -> Self {
+        self.conflict_policy = conflict_policy;
+        self
+    }
+
+    pub fn filter(&self) -> &SyncFilter {
+        &self.filter
+    }
+
+    pub fn state(&self) -> &SyncStateStore {
+        &self.state
+    }
+
+    pub fn has_connectors(&self) -> bool {
+        !self.connectors.is_empty()
+    }
+
+    /// Sync every result matching the filter to every connector
+    ///
+    /// Failures of single pages are reported rather than aborting the run;
+    /// the sync state is saved once all connectors are done.
+    pub async fn sync(
+        &self,
+        results: &[ResearchResult],
+    ) -> Result<Vec<SyncReport>, ConnectorError> {
+        let pages: Vec<WikiPage> = results
+            .iter()
+            .filter(|result| self.filter.matches(result))
+            .map(WikiPage::from_result)
+            .collect();
+
+        let mut reports = Vec::with_capacity(self.connectors.len());
+        for connector in &self.connectors {
+            let mut report = SyncReport {
+                connector: connector.name().to_string(),
+                ..Default::default()
+            };
+            for page in &pages {
+                match self.sync_page(connector.as_ref(), page).await {
+                    Ok(SyncOutcome::Created) => report.created += 1,
+                    Ok(SyncOutcome::Updated) => report.updated += 1,
+                    Ok(SyncOutcome::Unchanged) => report.unchanged += 1,
+                    Ok(SyncOutcome::Conflict) => report.conflicts.push(page.cache_key.clone()),
+                    Err(e) => {
+                        warn!(
+                            "Failed to sync {} to {}: {}",
+                            page.cache_key,
+                            connector.name(),
+                            e
+                        );
+                        report.failed.push((page.cache_key.clone(), e.to_string()));
+                    }
+                }
+            }
+            reports.push(report);
+        }
+
+        self.state.save().await?;
+        Ok(reports)
+    }
+
+    async fn sync_page(
+        &self,
+        connector: &dyn OutputConnector,
+        page: &WikiPage,
+    ) -> Result<SyncOutcome, ConnectorError> {
+        let Some(record) = self.state.get(connector.name(), &page.cache_key) else {
+            let created = connector.create_page(page).await?;
+            self.record(connector, page, created);
+            return Ok(SyncOutcome::Created);
+        };
+        if record.content_hash == page.content_hash {
+            return Ok(SyncOutcome::Unchanged);
+        }
+
+        // Pages deleted in the wiki are recreated rather than reported
+        let Some(current) = connector.fetch_page(&record.remote_id).await? else {
+            let created = connector.create_page(page).await?;
+            self.record(connector, page, created);
+            return Ok(SyncOutcome::Created);
+        };
+        if current.version != record.remote_version && self.conflict_policy == ConflictPolicy::Skip
+        {
+            return Ok(SyncOutcome::Conflict);
+        }
+
+        match connector.update_page(&current, page).await {
+            Ok(updated) => {
+                self.record(connector, page, updated);
+                Ok(SyncOutcome::Updated)
+            }
+            Err(ConnectorError::Conflict { .. })
+                if self.conflict_policy == ConflictPolicy::Skip =>
+            {
+                Ok(SyncOutcome::Conflict)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn record(&self, connector: &dyn OutputConnector, page: &WikiPage, remote: RemotePage) {
+        self.state.insert(
+            connector.name(),
+            &page.cache_key,
+            SyncRecord {
+                remote_id: remote.id,
+                remote_version: remote.version,
+                content_hash: page.content_hash.clone(),
+                url: remote.url,
+                synced_at: Utc::now(),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_types::{
+        AudienceContext, ClassifiedRequest, Detail, DomainContext, Evidence, ResearchMetadata,
+    };
+    use std::collections::HashMap;
+    use std::sync::Mutex as StdMutex;
+
+    fn result(
+        key: &str,
+        answer: &str,
+        research_type: ResearchType,
+        tags: &[&str],
+    ) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            format!("How do I use {key}?"),
+            research_type,
+            AudienceContext::default(),
+            DomainContext {
+                tags: tags.iter().map(|t| t.to_string()).collect(),
+                ..DomainContext::default()
+            },
+            0.8,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 10,
+            sources_consulted: vec![],
+            quality_score: 0.9,
+            cache_key: key.to_string(),
+            tags: HashMap::new(),
+        };
+        ResearchResult::new(
+            request,
+            answer.to_string(),
+            vec![Evidence {
+                source: "docs.rs".to_string(),
+                content: "Official docs".to_string(),
+                relevance: 0.9,
+                evidence_type: "documentation".to_string(),
+            }],
+            vec![Detail {
+                category: "code".to_string(),
+                content: "```rust\nfn main() {}\n```".to_string(),
+                priority: "high".to_string(),
+                prerequisites: vec!["tokio".to_string()],
+            }],
+            metadata,
+        )
+    }
+
+    /// In-memory wiki whose pages can be edited behind the sync's back
+    #[derive(Default)]
+    struct MemoryWiki {
+        pages: StdMutex<HashMap<String, (String, u32)>>,
+    }
+
+    impl MemoryWiki {
+        fn edit(&self, id: &str) {
+            let mut pages = self.pages.lock().unwrap();
+            let page = pages.get_mut(id).unwrap();
+            page.0.push_str("\nedited in the wiki");
+            page.1 += 1;
+        }
+
+        fn content(&self, id: &str) -> String {
+            self.pages.lock().unwrap()[id].0.clone()
+        }
+    }
+
+    #[async_trait]
+    impl OutputConnector for MemoryWiki {
+        fn name(&self) -> &str {
+            "memory"
+        }
+
+        async fn create_page(&self, page: &WikiPage) -> Result<RemotePage, ConnectorError> {
+            let mut pages = self.pages.lock().unwrap();
+            let id = format!("page-{}", pages.len() + 1);
+            pages.insert(id.clone(), (page.markdown.clone(), 1));
+            Ok(RemotePage {
+                id,
+                version: "1".to_string(),
+                url: None,
+            })
+        }
+
+        async fn update_page(
+            &self,
+            current: &RemotePage,
+            page: &WikiPage,
+        ) -> Result<RemotePage, ConnectorError> {
+            let mut pages = self.pages.lock().unwrap();
+            let entry = pages.get_mut(&current.id).unwrap();
+            *entry = (page.markdown.clone(), entry.1 + 1);
+            Ok(RemotePage {
+                id: current.id.clone(),
+                version: entry.1.to_string(),
+                url: None,
+            })
+        }
+
+        async fn fetch_page(&self, page_id: &str) -> Result<Option<RemotePage>, ConnectorError> {
+            Ok(self
+                .pages
+                .lock()
+                .unwrap()
+                .get(page_id)
+                .map(|(_, version)| RemotePage {
+                    id: page_id.to_string(),
+                    version: version.to_string(),
+                    url: None,
+                }))
+        }
+    }
+
+    #[test]
+    fn test_page_from_result() {
+        let page = WikiPage::from_result(&result(
+            "serde",
+            "Use derive.",
+            ResearchType::Learning,
+            &["rust"],
+        ));
+        assert_eq!(page.title, "How do I use serde?");
+        assert_eq!(page.cache_key, "serde");
+        assert!(page.markdown.starts_with("Use derive.\n\n## Evidence"));
+        assert!(page.markdown.contains("### code (high priority)"));
+        assert!(page.markdown.contains("Prerequisites: tokio"));
+        assert!(page.markdown.contains("Cache key: `serde` | Tags: rust"));
+
+        let changed = WikiPage::from_result(&result(
+            "serde",
+            "Use serde_derive.",
+            ResearchType::Learning,
+            &["rust"],
+        ));
+        assert_ne!(page.content_hash, changed.content_hash);
+    }
+
+    #[test]
+    fn test_filter_by_tag_and_type() {
+        let filter = SyncFilter {
+            tags: vec!["async".to_string()],
+            research_types: vec![ResearchType::Implementation],
+        };
+        assert!(filter.matches(&result(
+            "a",
+            "x",
+            ResearchType::Implementation,
+            &["async", "rust"]
+        )));
+        assert!(!filter.matches(&result("b", "x", ResearchType::Learning, &["async"])));
+        assert!(!filter.matches(&result("c", "x", ResearchType::Implementation, &["rust"])));
+        assert!(SyncFilter::default().matches(&result("d", "x", ResearchType::Decision, &[])));
+    }
+
+    #[tokio::test]
+    async fn test_sync_creates_updates_and_detects_conflicts() {
+        let wiki = Arc::new(MemoryWiki::default());
+        let sync = WikiSync::new(SyncStateStore::default())
+            .with_connector(wiki.clone())
+            .with_filter(SyncFilter {
+                tags: vec!["wiki".to_string()],
+                ..Default::default()
+            });
+
+        let results = vec![
+            result("tokio", "First answer.", ResearchType::Learning, &["wiki"]),
+            result("private", "Not for the wiki.", ResearchType::Learning, &[]),
+        ];
+        let reports = sync.sync(&results).await.unwrap();
+        assert_eq!(reports[0].created, 1);
+        assert_eq!(sync.state().get("memory", "private"), None);
+
+        let reports = sync.sync(&results).await.unwrap();
+        assert_eq!(reports[0].unchanged, 1);
+
+        let updated = vec![result(
+            "tokio",
+            "Second answer.",
+            ResearchType::Learning,
+            &["wiki"],
+        )];
+        let reports = sync.sync(&updated).await.unwrap();
+        assert_eq!(reports[0].updated, 1);
+        assert!(wiki.content("page-1").starts_with("Second answer."));
+
+        wiki.edit("page-1");
+        let edited = vec![result(
+            "tokio",
+            "Third answer.",
+            ResearchType::Learning,
+            &["wiki"],
+        )];
+        let reports = sync.sync(&edited).await.unwrap();
+        assert_eq!(reports[0].conflicts, vec!["tokio".to_string()]);
+        assert!(wiki.content("page-1").ends_with("edited in the wiki"));
+
+        let overwrite = sync.clone().with_conflict_policy(ConflictPolicy::Overwrite);
+        let reports = overwrite.sync(&edited).await.unwrap();
+        assert_eq!(reports[0].updated, 1);
+        assert!(wiki.content("page-1").starts_with("Third answer."));
+        assert_eq!(
+            reports[0].summary(),
+            "memory: 0 created, 1 updated, 0 unchanged, 0 conflicts, 0 failed"
+        );
+    }
+}
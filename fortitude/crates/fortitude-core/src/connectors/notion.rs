@@ -0,0 +1,504 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Notion output connector writing research pages into a database
+// Converts page markdown to Notion blocks and uses last_edited_time as the page version
+
+use super::{ConnectorError, OutputConnector, RemotePage, RequestPacer, WikiPage};
+use async_trait::async_trait;
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// Notion API version sent with every request
+const NOTION_VERSION: &str = "2022-06-28";
+
+/// Characters allowed in one rich text object
+const MAX_TEXT_CHARS: usize = 2000;
+
+/// Blocks allowed in one create or append request
+const MAX_BLOCKS_PER_REQUEST: usize = 100;
+
+/// Code block languages Notion accepts that research answers commonly use
+const CODE_LANGUAGES: &[&str] = &[
+    "bash",
+    "c",
+    "c++",
+    "css",
+    "go",
+    "html",
+    "java",
+    "javascript",
+    "json",
+    "markdown",
+    "python",
+    "rust",
+    "shell",
+    "sql",
+    "toml",
+    "typescript",
+    "yaml",
+];
+
+/// Notion connector settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotionConfig {
+    /// Integration token with access to the database
+    pub token: String,
+    /// Database that research pages are created in
+    pub database_id: String,
+    /// Name of the database's title property
+    pub title_property: String,
+    /// Rich text property receiving the cache key, if the database has one
+    pub cache_key_property: Option<String>,
+    pub api_url: String,
+    /// Notion allows an average of three requests per second
+    pub requests_per_second: f64,
+    pub max_retries: u32,
+    pub timeout_seconds: u64,
+}
+
+impl Default for NotionConfig {
+    fn default() -> Self {
+        Self {
+            token: String::new(),
+            database_id: String::new(),
+            title_property: "Name".to_string(),
+            cache_key_property: None,
+            api_url: "https://api.notion.com".to_string(),
+            requests_per_second: 3.0,
+            max_retries: 3,
+            timeout_seconds: 30,
+        }
+    }
+}
+
+/// Writes research pages into a Notion database
+#[derive(Debug)]
+pub struct NotionConnector {
+    config: NotionConfig,
+    client: reqwest::Client,
+    pacer: RequestPacer,
+}
+
+impl NotionConnector {
+    pub fn new(config: NotionConfig) -> Result<Self, ConnectorError> {
+        if config.token.trim().is_empty() || config.database_id.trim().is_empty() {
+            return Err(ConnectorError::Config(
+                "Notion requires a token and a database id".to_string(),
+            ));
+        }
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_seconds))
+            .build()?;
+        let pacer = RequestPacer::new(config.requests_per_second, config.max_retries);
+        Ok(Self {
+            config,
+            client,
+            pacer,
+        })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/v1/{path}", self.config.api_url.trim_end_matches('/'))
+    }
+
+    async fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Value, ConnectorError> {
+        let url = self.url(path);
+        let response = self
+            .pacer
+            .send(self.name(), || {
+                let request = self
+                    .client
+                    .request(method.clone(), &url)
+                    .bearer_auth(&self.config.token)
+                    .header("Notion-Version", NOTION_VERSION);
+                match body {
+                    Some(body) => request.json(body),
+                    None => request,
+                }
+            })
+            .await?;
+        Ok(response.json().await?)
+    }
+
+    fn properties(&self, page: &WikiPage) -> Value {
+        let mut properties = json!({
+            &self.config.title_property: { "title": rich_text(&page.title) }
+        });
+        if let Some(property) = &self.config.cache_key_property {
+            properties[property] = json!({ "rich_text": rich_text(&page.cache_key) });
+        }
+        properties
+    }
+
+    async fn append_blocks(&self, page_id: &str, blocks: &[Value]) -> Result<(), ConnectorError> {
+        for chunk in blocks.chunks(MAX_BLOCKS_PER_REQUEST) {
+            self.request(
+                reqwest::Method::PATCH,
+                &format!("blocks/{page_id}/children"),
+                Some(&json!({ "children": chunk })),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn child_block_ids(&self, page_id: &str) -> Result<Vec<String>, ConnectorError> {
+        let mut ids = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut path = format!("blocks/{page_id}/children?page_size=100");
+            if let Some(cursor) = &cursor {
+                path.push_str(&format!("&start_cursor={cursor}"));
+            }
+            let response = self.request(reqwest::Method::GET, &path, None).await?;
+            ids.extend(
+                response["results"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|block| block["id"].as_str().map(str::to_string)),
+            );
+            cursor = response["next_cursor"].as_str().map(str::to_string);
+            if cursor.is_none() || response["has_more"] != json!(true) {
+                return Ok(ids);
+            }
+        }
+    }
+
+    /// Final page state after writing, since appending blocks bumps the edit time
+    async fn written_page(&self, page_id: &str) -> Result<RemotePage, ConnectorError> {
+        self.fetch_page(page_id)
+            .await?
+            .ok_or_else(|| ConnectorError::Api {
+                connector: self.name().to_string(),
+                status: 404,
+                message: format!("Page {page_id} disappeared after writing"),
+            })
+    }
+}
+
+#[async_trait]
+impl OutputConnector for NotionConnector {
+    fn name(&self) -> &str {
+        "notion"
+    }
+
+    async fn create_page(&self, page: &WikiPage) -> Result<RemotePage, ConnectorError> {
+        let blocks = markdown_to_blocks(&page.markdown);
+        let (first, rest) = blocks.split_at(blocks.len().min(MAX_BLOCKS_PER_REQUEST));
+        let created = self
+            .request(
+                reqwest::Method::POST,
+                "pages",
+                Some(&json!({
+                    "parent": { "database_id": self.config.database_id },
+                    "properties": self.properties(page),
+                    "children": first,
+                })),
+            )
+            .await?;
+        let page_id = created["id"]
+            .as_str()
+            .ok_or_else(|| ConnectorError::Api {
+                connector: self.name().to_string(),
+                status: 200,
+                message: "Created page has no id".to_string(),
+            })?
+            .to_string();
+
+        self.append_blocks(&page_id, rest).await?;
+        self.written_page(&page_id).await
+    }
+
+    async fn update_page(
+        &self,
+        current: &RemotePage,
+        page: &WikiPage,
+    ) -> Result<RemotePage, ConnectorError> {
+        self.request(
+            reqwest::Method::PATCH,
+            &format!("pages/{}", current.id),
+            Some(&json!({ "properties": self.properties(page) })),
+        )
+        .await?;
+
+        // Notion has no "replace content" call: drop the old blocks, append the new ones
+        for block_id in self.child_block_ids(&current.id).await? {
+            self.request(reqwest::Method::DELETE, &format!("blocks/{block_id}"), None)
+                .await?;
+        }
+        self.append_blocks(&current.id, &markdown_to_blocks(&page.markdown))
+            .await?;
+        self.written_page(&current.id).await
+    }
+
+    async fn fetch_page(&self, page_id: &str) -> Result<Option<RemotePage>, ConnectorError> {
+        let page = match self
+            .request(reqwest::Method::GET, &format!("pages/{page_id}"), None)
+            .await
+        {
+            Ok(page) => page,
+            Err(ConnectorError::Api { status: 404, .. }) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        if page["archived"] == json!(true) || page["in_trash"] == json!(true) {
+            return Ok(None);
+        }
+        Ok(Some(RemotePage {
+            id: page_id.to_string(),
+            version: page["last_edited_time"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            url: page["url"].as_str().map(str::to_string),
+        }))
+    }
+}
+
+/// Rich text objects for plain text, split at Notion's length limit
+fn rich_text(text: &str) -> Vec<Value> {
+    annotated_text(&[Run {
+        text: text.to_string(),
+        ..Default::default()
+    }])
+}
+
+/// A stretch of inline text with uniform formatting
+#[derive(Debug, Clone, Default)]
+struct Run {
+    text: String,
+    bold: bool,
+    italic: bool,
+    code: bool,
+}
+
+fn annotated_text(runs: &[Run]) -> Vec<Value> {
+    let mut objects = Vec::new();
+    for run in runs.iter().filter(|run| !run.text.is_empty()) {
+        let chars: Vec<char> = run.text.chars().collect();
+        for chunk in chars.chunks(MAX_TEXT_CHARS) {
+            objects.push(json!({
+                "type": "text",
+                "text": { "content": chunk.iter().collect::<String>() },
+                "annotations": { "bold": run.bold, "italic": run.italic, "code": run.code },
+            }));
+        }
+    }
+    objects
+}
+
+fn block(kind: &str, runs: &[Run]) -> Value {
+    json!({ "object": "block", "type": kind, kind: { "rich_text": annotated_text(runs) } })
+}
+
+/// Convert page markdown to Notion blocks
+///
+/// Headings, paragraphs, list items, quotes, code blocks and rules map to
+/// their Notion counterparts; table rows become paragraphs.
+pub fn markdown_to_blocks(markdown: &str) -> Vec<Value> {
+    let mut blocks = Vec::new();
+    let mut runs: Vec<Run> = Vec::new();
+    let (mut bold, mut italic) = (false, false);
+    let mut code_language: Option<String> = None;
+    let mut lists: Vec<bool> = Vec::new();
+    let mut quote_depth = 0usize;
+
+    let flush = |runs: &mut Vec<Run>, blocks: &mut Vec<Value>, kind: &str| {
+        if runs.iter().any(|run| !run.text.trim().is_empty()) {
+            if let Some(first) = runs.first_mut() {
+                first.text = first.text.trim_start().to_string();
+            }
+            blocks.push(block(kind, runs));
+        }
+        runs.clear();
+    };
+    let text_kind = |lists: &[bool], quote_depth: usize| match lists.last() {
+        Some(true) => "numbered_list_item",
+        Some(false) => "bulleted_list_item",
+        None if quote_depth > 0 => "quote",
+        None => "paragraph",
+    };
+
+    for event in Parser::new_ext(
+        markdown,
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH,
+    ) {
+        match event {
+            Event::Start(Tag::Strong) => bold = true,
+            Event::End(TagEnd::Strong) => bold = false,
+            Event::Start(Tag::Emphasis) => italic = true,
+            Event::End(TagEnd::Emphasis) => italic = false,
+            Event::Start(Tag::List(start)) => {
+                flush(&mut runs, &mut blocks, text_kind(&lists, quote_depth));
+                lists.push(start.is_some());
+            }
+            Event::End(TagEnd::List(_)) => {
+                lists.pop();
+            }
+            Event::Start(Tag::BlockQuote(_)) => quote_depth += 1,
+            Event::End(TagEnd::BlockQuote(_)) => quote_depth = quote_depth.saturating_sub(1),
+            Event::Start(Tag::CodeBlock(kind)) => {
+                flush(&mut runs, &mut blocks, text_kind(&lists, quote_depth));
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or_default()
+                        .to_lowercase(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                code_language = Some(if CODE_LANGUAGES.contains(&language.as_str()) {
+                    language
+                } else {
+                    "plain text".to_string()
+                });
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                let language = code_language.take().unwrap_or_default();
+                if let Some(last) = runs.last_mut() {
+                    last.text = last.text.trim_end_matches('\n').to_string();
+                }
+                blocks.push(json!({
+                    "object": "block",
+                    "type": "code",
+                    "code": { "rich_text": annotated_text(&runs), "language": language },
+                }));
+                runs.clear();
+            }
+            Event::End(TagEnd::Heading(level)) => {
+                let kind = match level {
+                    HeadingLevel::H1 => "heading_1",
+                    HeadingLevel::H2 => "heading_2",
+                    _ => "heading_3",
+                };
+                flush(&mut runs, &mut blocks, kind);
+            }
+            Event::End(TagEnd::Paragraph | TagEnd::Item | TagEnd::TableRow | TagEnd::TableHead) => {
+                flush(&mut runs, &mut blocks, text_kind(&lists, quote_depth));
+            }
+            Event::End(TagEnd::TableCell) => runs.push(Run {
+                text: " | ".to_string(),
+                ..Default::default()
+            }),
+            Event::Rule => {
+                flush(&mut runs, &mut blocks, text_kind(&lists, quote_depth));
+                blocks.push(json!({ "object": "block", "type": "divider", "divider": {} }));
+            }
+            Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => runs.push(Run {
+                text: text.to_string(),
+                bold,
+                italic,
+                code: false,
+            }),
+            Event::Code(text) => runs.push(Run {
+                text: text.to_string(),
+                bold,
+                italic,
+                code: true,
+            }),
+            Event::SoftBreak => runs.push(Run {
+                text: " ".to_string(),
+                ..Default::default()
+            }),
+            Event::HardBreak => runs.push(Run {
+                text: "\n".to_string(),
+                ..Default::default()
+            }),
+            _ => {}
+        }
+    }
+    flush(&mut runs, &mut blocks, "paragraph");
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_to_blocks() {
+        let blocks = markdown_to_blocks(
+            "Use **tokio** with `spawn`.\n\n## Steps\n\n- add the crate\n- write code\n\n```rust\nfn main() {}\n```\n\n```brainfuck\n+.\n```\n\n---\n\n> note",
+        );
+        let kinds: Vec<&str> = blocks.iter().map(|b| b["type"].as_str().unwrap()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "paragraph",
+                "heading_2",
+                "bulleted_list_item",
+                "bulleted_list_item",
+                "code",
+                "code",
+                "divider",
+                "quote"
+            ]
+        );
+
+        let text = blocks[0]["paragraph"]["rich_text"].as_array().unwrap();
+        assert_eq!(text[1]["text"]["content"], "tokio");
+        assert_eq!(text[1]["annotations"]["bold"], true);
+        assert_eq!(text[3]["annotations"]["code"], true);
+        assert_eq!(blocks[4]["code"]["language"], "rust");
+        assert_eq!(
+            blocks[4]["code"]["rich_text"][0]["text"]["content"],
+            "fn main() {}"
+        );
+        assert_eq!(blocks[5]["code"]["language"], "plain text");
+    }
+
+    #[test]
+    fn test_long_text_is_split() {
+        let text = rich_text(&"a".repeat(MAX_TEXT_CHARS + 10));
+        assert_eq!(text.len(), 2);
+        assert_eq!(text[1]["text"]["content"], "a".repeat(10));
+    }
+
+    #[test]
+    fn test_requires_token_and_database() {
+        assert!(matches!(
+            NotionConnector::new(NotionConfig::default()),
+            Err(ConnectorError::Config(_))
+        ));
+        let connector = NotionConnector::new(NotionConfig {
+            token: "secret".to_string(),
+            database_id: "db".to_string(),
+            cache_key_property: Some("Cache Key".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        let page = WikiPage {
+            cache_key: "key-1".to_string(),
+            title: "Title".to_string(),
+            markdown: String::new(),
+            research_type: fortitude_types::ResearchType::Learning,
+            content_hash: String::new(),
+        };
+        let properties = connector.properties(&page);
+        assert_eq!(properties["Name"]["title"][0]["text"]["content"], "Title");
+        assert_eq!(
+            properties["Cache Key"]["rich_text"][0]["text"]["content"],
+            "key-1"
+        );
+    }
+}
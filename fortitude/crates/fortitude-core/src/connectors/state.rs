@@ -0,0 +1,161 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Sync state linking cached research to the wiki pages it was written to
+// Kept per connector and cache key, optionally saved to a JSON file between runs
+
+use super::ConnectorError;
+use crate::atomic_file::write_json_atomic_async;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+/// Records keyed by connector name, then by cache key
+type Records = HashMap<String, HashMap<String, SyncRecord>>;
+
+/// Where one result was last written and what it looked like then
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncRecord {
+    pub remote_id: String,
+    /// Remote page version right after the last sync
+    pub remote_version: String,
+    /// Content hash of the page that was written
+    pub content_hash: String,
+    pub url: Option<String>,
+    pub synced_at: DateTime<Utc>,
+}
+
+/// Sync state shared between sync runs
+#[derive(Debug, Clone, Default)]
+pub struct SyncStateStore {
+    records: Arc<RwLock<Records>>,
+    path: Option<PathBuf>,
+    save_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl SyncStateStore {
+    /// Open a state file, starting empty when it does not exist yet
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, ConnectorError> {
+        let path = path.into();
+        let records = match std::fs::read(&path) {
+            Ok(content) => serde_json::from_slice(&content).map_err(|e| {
+                ConnectorError::State(format!("Invalid sync state in {}: {e}", path.display()))
+            })?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Records::new(),
+            Err(e) => {
+                return Err(ConnectorError::State(format!(
+                    "Failed to read {}: {e}",
+                    path.display()
+                )))
+            }
+        };
+        Ok(Self {
+            records: Arc::new(RwLock::new(records)),
+            path: Some(path),
+            save_lock: Arc::default(),
+        })
+    }
+
+    pub fn get(&self, connector: &str, cache_key: &str) -> Option<SyncRecord> {
+        self.records
+            .read()
+            .unwrap()
+            .get(connector)
+            .and_then(|records| records.get(cache_key))
+            .cloned()
+    }
+
+    pub fn insert(&self, connector: &str, cache_key: &str, record: SyncRecord) {
+        self.records
+            .write()
+            .unwrap()
+            .entry(connector.to_string())
+            .or_default()
+            .insert(cache_key.to_string(), record);
+    }
+
+    /// Forget a page so the next sync creates it again
+    pub fn remove(&self, connector: &str, cache_key: &str) -> Option<SyncRecord> {
+        self.records
+            .write()
+            .unwrap()
+            .get_mut(connector)
+            .and_then(|records| records.remove(cache_key))
+    }
+
+    /// Number of synced pages for a connector
+    pub fn len(&self, connector: &str) -> usize {
+        self.records
+            .read()
+            .unwrap()
+            .get(connector)
+            .map_or(0, HashMap::len)
+    }
+
+    /// Write the state file; no-op for in-memory stores
+    pub async fn save(&self) -> Result<(), ConnectorError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let _guard = self.save_lock.lock().await;
+        let snapshot = self.records.read().unwrap().clone();
+        write_json_atomic_async(path, &snapshot)
+            .await
+            .map_err(|e| ConnectorError::State(format!("Failed to save {}: {e}", path.display())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn record(id: &str) -> SyncRecord {
+        SyncRecord {
+            remote_id: id.to_string(),
+            remote_version: "1".to_string(),
+            content_hash: "abc".to_string(),
+            url: None,
+            synced_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_state_persists_per_connector() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("sync/state.json");
+
+        let store = SyncStateStore::open(&path).unwrap();
+        let notion = record("page-1");
+        store.insert("notion", "key-1", notion.clone());
+        store.insert("confluence", "key-1", record("42"));
+        store.save().await.unwrap();
+
+        let reopened = SyncStateStore::open(&path).unwrap();
+        assert_eq!(reopened.get("notion", "key-1"), Some(notion));
+        assert_eq!(reopened.get("confluence", "key-1").unwrap().remote_id, "42");
+        assert_eq!(reopened.len("notion"), 1);
+
+        assert!(reopened.remove("notion", "key-1").is_some());
+        assert_eq!(reopened.get("notion", "key-1"), None);
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(matches!(
+            SyncStateStore::open(&path),
+            Err(ConnectorError::State(_))
+        ));
+    }
+}
@@ -0,0 +1,545 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Post-provider content filtering of research results
+// Response filters run after generation and can block, annotate or rewrite a result; the built-in rules engine matches regexes, keywords and license text
+use async_trait::async_trait;
+use fortitude_types::ResearchResult;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+use thiserror::Error;
+
+/// Metadata tag listing the filter decisions as `rule:action:matches` entries
+pub const CONTENT_FILTER_TAG: &str = "content_filter";
+
+/// Text substituted for matches by rewrite rules without a replacement
+pub const DEFAULT_REPLACEMENT: &str = "[filtered]";
+
+/// Licenses detected by a license rule that does not list any
+pub const DEFAULT_LICENSES: &[&str] = &["GPL", "AGPL", "LGPL", "SSPL"];
+
+/// What a rule does to a result that matches it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterAction {
+    /// Withhold the answer, evidence and implementation details
+    Block,
+    /// Keep the content and append a note to the answer
+    Annotate,
+    /// Replace every match with the rule's replacement text
+    Rewrite,
+}
+
+impl FilterAction {
+    pub const ALL: [FilterAction; 3] = [
+        FilterAction::Block,
+        FilterAction::Annotate,
+        FilterAction::Rewrite,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FilterAction::Block => "block",
+            FilterAction::Annotate => "annotate",
+            FilterAction::Rewrite => "rewrite",
+        }
+    }
+
+    pub fn parse(action: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.as_str() == action)
+    }
+}
+
+impl fmt::Display for FilterAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How a rule finds matching content
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DetectorConfig {
+    /// Regular expression, matched as written
+    Regex { pattern: String },
+    /// Whole-word keywords, case-insensitive unless `case_sensitive` is set
+    Keywords {
+        keywords: Vec<String>,
+        #[serde(default)]
+        case_sensitive: bool,
+    },
+    /// License headers and SPDX identifiers, e.g. `GPL` or `AGPL`
+    ///
+    /// Known licenses also match their full name; other identifiers only
+    /// match as `SPDX-License-Identifier: <id>`. An empty list selects
+    /// [`DEFAULT_LICENSES`].
+    License {
+        #[serde(default)]
+        licenses: Vec<String>,
+    },
+}
+
+/// A single filtering rule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilterRuleConfig {
+    /// Rule name recorded in decisions and metrics
+    pub name: String,
+    pub detector: DetectorConfig,
+    pub action: FilterAction,
+    /// Note appended by annotate rules, or the notice shown by block rules
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Replacement text for rewrite rules
+    #[serde(default)]
+    pub replacement: Option<String>,
+}
+
+impl FilterRuleConfig {
+    pub fn new(name: impl Into<String>, detector: DetectorConfig, action: FilterAction) -> Self {
+        Self {
+            name: name.into(),
+            detector,
+            action,
+            message: None,
+            replacement: None,
+        }
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn with_replacement(mut self, replacement: impl Into<String>) -> Self {
+        self.replacement = Some(replacement.into());
+        self
+    }
+}
+
+/// Configuration of the built-in rules engine
+///
+/// Rules run in order; the first block decision stops the remaining rules.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContentFilterConfig {
+    pub enabled: bool,
+    pub rules: Vec<FilterRuleConfig>,
+}
+
+impl Default for ContentFilterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            rules: Vec::new(),
+        }
+    }
+}
+
+impl ContentFilterConfig {
+    pub fn with_rule(mut self, rule: FilterRuleConfig) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Load a JSON rules file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ContentFilterError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| ContentFilterError::Load(format!("{}: {e}", path.display())))?;
+        let config: Self = serde_json::from_str(&content)
+            .map_err(|e| ContentFilterError::Load(format!("{}: {e}", path.display())))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check that rule names are unique and every detector compiles
+    pub fn validate(&self) -> Result<(), ContentFilterError> {
+        RulesFilter::from_config(self).map(|_| ())
+    }
+}
+
+/// Errors raised while building content filter rules
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ContentFilterError {
+    #[error("Invalid content filter rule name '{0}': names must be non-empty and cannot contain ':' or ','")]
+    InvalidRuleName(String),
+    #[error("Duplicate content filter rule: {0}")]
+    DuplicateRule(String),
+    #[error("Content filter rule '{0}' has nothing to match")]
+    EmptyDetector(String),
+    #[error("Invalid pattern in content filter rule '{rule}': {message}")]
+    InvalidPattern { rule: String, message: String },
+    #[error("Failed to load content filter rules from {0}")]
+    Load(String),
+}
+
+/// Outcome of one rule that matched a result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilterDecision {
+    pub rule: String,
+    pub action: FilterAction,
+    /// Number of matches across the answer, evidence and implementation details
+    pub matches: usize,
+}
+
+/// Hook point for checking research results after the provider has answered
+///
+/// Filters run in registration order on fresh and cached results alike, so
+/// they must tolerate seeing content they have already filtered.
+#[async_trait]
+pub trait ResponseFilter: Send + Sync {
+    /// Apply the filter in place and return the decisions it made
+    async fn filter(&self, result: &mut ResearchResult) -> Vec<FilterDecision>;
+}
+
+/// Every decision made for one result
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilterReport {
+    pub decisions: Vec<FilterDecision>,
+}
+
+impl FilterReport {
+    pub fn is_empty(&self) -> bool {
+        self.decisions.is_empty()
+    }
+
+    pub fn blocked(&self) -> bool {
+        self.decisions
+            .iter()
+            .any(|decision| decision.action == FilterAction::Block)
+    }
+
+    /// Write the decisions to the `content_filter` tag, or remove it when there are none
+    pub fn apply_to_tags(&self, tags: &mut HashMap<String, String>) {
+        if self.is_empty() {
+            tags.remove(CONTENT_FILTER_TAG);
+            return;
+        }
+        let decisions: Vec<String> = self
+            .decisions
+            .iter()
+            .map(|d| format!("{}:{}:{}", d.rule, d.action, d.matches))
+            .collect();
+        tags.insert(CONTENT_FILTER_TAG.to_string(), decisions.join(","));
+    }
+
+    pub fn from_tags(tags: &HashMap<String, String>) -> Self {
+        let decisions = tags
+            .get(CONTENT_FILTER_TAG)
+            .map(|value| {
+                value
+                    .split(',')
+                    .filter_map(|entry| {
+                        let mut parts = entry.splitn(3, ':');
+                        Some(FilterDecision {
+                            rule: parts.next()?.to_string(),
+                            action: FilterAction::parse(parts.next()?)?,
+                            matches: parts.next()?.parse().ok()?,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { decisions }
+    }
+
+    /// One-line description used as the warning message
+    pub fn summary(&self) -> String {
+        let rules: Vec<String> = self
+            .decisions
+            .iter()
+            .map(|d| format!("{} ({})", d.rule, d.action))
+            .collect();
+        format!("Content filter rules matched: {}", rules.join(", "))
+    }
+}
+
+struct CompiledRule {
+    config: FilterRuleConfig,
+    pattern: Regex,
+}
+
+/// Built-in rules engine backed by [`ContentFilterConfig`]
+pub struct RulesFilter {
+    rules: Vec<CompiledRule>,
+}
+
+impl RulesFilter {
+    pub fn from_config(config: &ContentFilterConfig) -> Result<Self, ContentFilterError> {
+        let mut names = HashSet::new();
+        let mut rules = Vec::with_capacity(config.rules.len());
+        for rule in &config.rules {
+            if rule.name.is_empty() || rule.name.contains([':', ',']) {
+                return Err(ContentFilterError::InvalidRuleName(rule.name.clone()));
+            }
+            if !names.insert(rule.name.as_str()) {
+                return Err(ContentFilterError::DuplicateRule(rule.name.clone()));
+            }
+            rules.push(CompiledRule {
+                pattern: compile_detector(&rule.name, &rule.detector)?,
+                config: rule.clone(),
+            });
+        }
+        Ok(Self { rules })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    fn count_matches(pattern: &Regex, result: &ResearchResult) -> usize {
+        let evidence = result.supporting_evidence.iter().map(|e| &e.content);
+        let details = result.implementation_details.iter().map(|d| &d.content);
+        std::iter::once(&result.immediate_answer)
+            .chain(evidence)
+            .chain(details)
+            .map(|text| pattern.find_iter(text).count())
+            .sum()
+    }
+}
+
+#[async_trait]
+impl ResponseFilter for RulesFilter {
+    async fn filter(&self, result: &mut ResearchResult) -> Vec<FilterDecision> {
+        let mut decisions = Vec::new();
+        for rule in &self.rules {
+            let matches = Self::count_matches(&rule.pattern, result);
+            if matches == 0 {
+                continue;
+            }
+            let name = &rule.config.name;
+            match rule.config.action {
+                FilterAction::Block => {
+                    result.immediate_answer = rule.config.message.clone().unwrap_or_else(|| {
+                        format!("This response was withheld by content filter rule '{name}'.")
+                    });
+                    result.supporting_evidence.clear();
+                    result.implementation_details.clear();
+                }
+                FilterAction::Annotate => {
+                    let note = rule.config.message.clone().unwrap_or_else(|| {
+                        format!("This response matched content filter rule '{name}'.")
+                    });
+                    let note = format!("\n\nNote: {note}");
+                    if !result.immediate_answer.ends_with(&note) {
+                        result.immediate_answer.push_str(&note);
+                    }
+                }
+                FilterAction::Rewrite => {
+                    let replacement = rule
+                        .config
+                        .replacement
+                        .as_deref()
+                        .unwrap_or(DEFAULT_REPLACEMENT);
+                    let rewrite = |text: &mut String| {
+                        *text = rule
+                            .pattern
+                            .replace_all(text, regex::NoExpand(replacement))
+                            .into_owned();
+                    };
+                    rewrite(&mut result.immediate_answer);
+                    result
+                        .supporting_evidence
+                        .iter_mut()
+                        .for_each(|e| rewrite(&mut e.content));
+                    result
+                        .implementation_details
+                        .iter_mut()
+                        .for_each(|d| rewrite(&mut d.content));
+                }
+            }
+            decisions.push(FilterDecision {
+                rule: name.clone(),
+                action: rule.config.action,
+                matches,
+            });
+            if rule.config.action == FilterAction::Block {
+                break;
+            }
+        }
+        decisions
+    }
+}
+
+fn compile_detector(rule: &str, detector: &DetectorConfig) -> Result<Regex, ContentFilterError> {
+    let (pattern, case_insensitive) = match detector {
+        DetectorConfig::Regex { pattern } => (pattern.clone(), false),
+        DetectorConfig::Keywords {
+            keywords,
+            case_sensitive,
+        } => {
+            let keywords: Vec<String> = keywords
+                .iter()
+                .map(|k| k.trim())
+                .filter(|k| !k.is_empty())
+                .map(regex::escape)
+                .collect();
+            if keywords.is_empty() {
+                return Err(ContentFilterError::EmptyDetector(rule.to_string()));
+            }
+            (format!(r"\b(?:{})\b", keywords.join("|")), !case_sensitive)
+        }
+        DetectorConfig::License { licenses } => {
+            let patterns: Vec<String> = if licenses.is_empty() {
+                DEFAULT_LICENSES
+                    .iter()
+                    .map(|l| license_pattern(l))
+                    .collect()
+            } else {
+                licenses.iter().map(|l| license_pattern(l)).collect()
+            };
+            (patterns.join("|"), true)
+        }
+    };
+    if pattern.is_empty() {
+        return Err(ContentFilterError::EmptyDetector(rule.to_string()));
+    }
+    RegexBuilder::new(&pattern)
+        .case_insensitive(case_insensitive)
+        .build()
+        .map_err(|e| ContentFilterError::InvalidPattern {
+            rule: rule.to_string(),
+            message: e.to_string(),
+        })
+}
+
+fn license_pattern(license: &str) -> String {
+    let spdx = format!(
+        r"SPDX-License-Identifier:\s*{}\b",
+        regex::escape(license.trim())
+    );
+    let name = match license.trim().to_ascii_uppercase().as_str() {
+        "GPL" => r"GNU General Public License",
+        "AGPL" => r"GNU Affero General Public License",
+        "LGPL" => r"GNU (?:Lesser|Library) General Public License",
+        "MPL" => r"Mozilla Public License",
+        "SSPL" => r"Server Side Public License",
+        _ => return spdx,
+    };
+    format!("{spdx}|{name}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use fortitude_types::{
+        AudienceContext, ClassifiedRequest, DomainContext, Evidence, ResearchMetadata, ResearchType,
+    };
+
+    fn result(answer: &str, evidence: &str) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            "query".to_string(),
+            ResearchType::Learning,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.9,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 1,
+            sources_consulted: vec![],
+            quality_score: 0.9,
+            cache_key: "key".to_string(),
+            tags: HashMap::new(),
+        };
+        let evidence = Evidence {
+            source: "docs".to_string(),
+            content: evidence.to_string(),
+            relevance: 0.9,
+            evidence_type: "documentation".to_string(),
+        };
+        ResearchResult::new(
+            request,
+            answer.to_string(),
+            vec![evidence],
+            vec![],
+            metadata,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_rules_rewrite_annotate_and_block() {
+        let config = ContentFilterConfig::default()
+            .with_rule(
+                FilterRuleConfig::new(
+                    "secrets",
+                    DetectorConfig::Regex {
+                        pattern: r"sk-[a-z0-9]{8}".to_string(),
+                    },
+                    FilterAction::Rewrite,
+                )
+                .with_replacement("<redacted>"),
+            )
+            .with_rule(
+                FilterRuleConfig::new(
+                    "unsafe",
+                    DetectorConfig::Keywords {
+                        keywords: vec!["unsafe".to_string()],
+                        case_sensitive: false,
+                    },
+                    FilterAction::Annotate,
+                )
+                .with_message("Review unsafe code before use."),
+            )
+            .with_rule(FilterRuleConfig::new(
+                "copyleft",
+                DetectorConfig::License { licenses: vec![] },
+                FilterAction::Block,
+            ));
+        let filter = RulesFilter::from_config(&config).unwrap();
+
+        let mut clean = result("Use UNSAFE blocks sparingly with sk-abcd1234", "unsafety");
+        let decisions = filter.filter(&mut clean).await;
+        assert_eq!(decisions.len(), 2);
+        assert_eq!(decisions[0].action, FilterAction::Rewrite);
+        assert_eq!(
+            clean.immediate_answer,
+            "Use UNSAFE blocks sparingly with <redacted>\n\nNote: Review unsafe code before use."
+        );
+        // Filtering again must not stack annotations
+        filter.filter(&mut clean).await;
+        assert_eq!(clean.immediate_answer.matches("Note:").count(), 1);
+
+        let mut licensed = result("Copy this", "SPDX-License-Identifier: AGPL-3.0-only");
+        let decisions = filter.filter(&mut licensed).await;
+        let report = FilterReport { decisions };
+        assert!(report.blocked());
+        assert!(licensed.supporting_evidence.is_empty());
+        assert!(licensed.immediate_answer.contains("copyleft"));
+
+        report.apply_to_tags(&mut licensed.metadata.tags);
+        assert_eq!(
+            licensed.metadata.tags[CONTENT_FILTER_TAG],
+            "copyleft:block:1"
+        );
+        assert_eq!(FilterReport::from_tags(&licensed.metadata.tags), report);
+
+        let invalid = ContentFilterConfig::default().with_rule(FilterRuleConfig::new(
+            "a:b",
+            DetectorConfig::Regex {
+                pattern: "x".to_string(),
+            },
+            FilterAction::Block,
+        ));
+        assert!(matches!(
+            invalid.validate(),
+            Err(ContentFilterError::InvalidRuleName(_))
+        ));
+    }
+}
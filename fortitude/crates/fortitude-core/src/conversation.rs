@@ -0,0 +1,142 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Conversation context for research queries that continue earlier turns
+// Renders the previous questions and answers of a result chain into a compact prompt block
+use fortitude_types::ResearchResult;
+
+/// Default size budget in characters for a rendered conversation context
+pub const DEFAULT_CONVERSATION_CONTEXT_BUDGET: usize = 2000;
+
+/// Longest answer kept for a single earlier turn before it is shortened
+const MAX_ANSWER_CHARS: usize = 600;
+
+const HEADER: &str = "Earlier in this conversation (oldest first):\n";
+
+/// Summarize earlier turns of a conversation for the prompt
+///
+/// `turns` is ordered oldest first, as returned by
+/// `ResearchPipeline::research_lineage`. The most recent turns are kept when
+/// the budget is exceeded. Returns `None` when no turn fits.
+pub fn summarize_conversation(turns: &[ResearchResult], budget: usize) -> Option<String> {
+    let mut kept: Vec<String> = Vec::new();
+    let mut used = HEADER.len();
+
+    for turn in turns.iter().rev() {
+        let entry = format!(
+            "Q: {}\nA: {}\n",
+            single_line(turn.original_query()),
+            shorten(&single_line(&turn.immediate_answer), MAX_ANSWER_CHARS)
+        );
+        if used + entry.len() > budget {
+            break;
+        }
+        used += entry.len();
+        kept.push(entry);
+    }
+
+    if kept.is_empty() {
+        return None;
+    }
+
+    let mut summary = String::from(HEADER);
+    let omitted = turns.len() - kept.len();
+    if omitted > 0 {
+        summary.push_str(&format!("({omitted} earlier turns omitted)\n"));
+    }
+    for entry in kept.iter().rev() {
+        summary.push_str(entry);
+    }
+    Some(summary)
+}
+
+fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn shorten(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut shortened: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    shortened.push('…');
+    shortened
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use fortitude_types::{
+        AudienceContext, ClassifiedRequest, DomainContext, ResearchMetadata, ResearchType,
+    };
+    use std::collections::HashMap;
+
+    fn turn(query: &str, answer: &str) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            query.to_string(),
+            ResearchType::Learning,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 10,
+            sources_consulted: vec![],
+            quality_score: 0.8,
+            cache_key: format!("key-{query}"),
+            tags: HashMap::new(),
+        };
+        ResearchResult::new(request, answer.to_string(), vec![], vec![], metadata)
+    }
+
+    #[test]
+    fn test_summarize_keeps_turns_in_order() {
+        let turns = vec![
+            turn("What is Tokio?", "An async runtime.\n\nIt schedules tasks."),
+            turn("How do I spawn a task?", "Use tokio::spawn."),
+        ];
+
+        let summary = summarize_conversation(&turns, DEFAULT_CONVERSATION_CONTEXT_BUDGET).unwrap();
+        assert_eq!(
+            summary,
+            "Earlier in this conversation (oldest first):\n\
+             Q: What is Tokio?\nA: An async runtime. It schedules tasks.\n\
+             Q: How do I spawn a task?\nA: Use tokio::spawn.\n"
+        );
+        assert!(summarize_conversation(&[], DEFAULT_CONVERSATION_CONTEXT_BUDGET).is_none());
+    }
+
+    #[test]
+    fn test_summarize_drops_oldest_turns_over_budget() {
+        let long_answer = "word ".repeat(400);
+        let turns = vec![
+            turn("First question", &long_answer),
+            turn("Second question", &long_answer),
+            turn("Third question", "Short answer."),
+        ];
+
+        let summary = summarize_conversation(&turns, 800).unwrap();
+        assert!(summary.len() <= 800);
+        assert!(summary.contains("(1 earlier turns omitted)"));
+        assert!(!summary.contains("First question"));
+        assert!(summary.contains("Second question"));
+        assert!(summary.contains('…'));
+        assert!(summary.ends_with("Q: Third question\nA: Short answer.\n"));
+
+        assert!(summarize_conversation(&turns, 20).is_none());
+    }
+}
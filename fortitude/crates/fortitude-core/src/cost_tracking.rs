@@ -0,0 +1,494 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Provider token usage and spend ledger with per-day and per-month budgets
+//! Every completed research request is recorded against the provider that
+//! answered it. Usage is aggregated per day and persisted as JSON so the CLI
+//! and API server can report spend, and the budget status tells the provider
+//! manager when to move to cheaper providers or stop sending requests.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use thiserror::Error;
+
+/// Default location of the persisted cost ledger
+pub const DEFAULT_COST_LEDGER_PATH: &str = ".fortitude/costs.json";
+
+/// Errors reading or writing the cost ledger
+#[derive(Error, Debug)]
+pub enum CostTrackerError {
+    #[error("Failed to access cost ledger at {path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Invalid cost ledger at {path}: {source}")]
+    Parse {
+        path: String,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// Spending limits for provider usage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CostBudgetConfig {
+    /// Maximum spend per UTC day in USD, unlimited when unset
+    pub daily_limit_usd: Option<f64>,
+    /// Maximum spend per calendar month in USD, unlimited when unset
+    pub monthly_limit_usd: Option<f64>,
+    /// Fraction of a limit after which the cheapest provider is preferred
+    pub failover_threshold: f64,
+}
+
+impl Default for CostBudgetConfig {
+    fn default() -> Self {
+        Self {
+            daily_limit_usd: None,
+            monthly_limit_usd: None,
+            failover_threshold: 0.8,
+        }
+    }
+}
+
+impl CostBudgetConfig {
+    pub fn with_daily_limit(mut self, limit_usd: f64) -> Self {
+        self.daily_limit_usd = Some(limit_usd);
+        self
+    }
+
+    pub fn with_monthly_limit(mut self, limit_usd: f64) -> Self {
+        self.monthly_limit_usd = Some(limit_usd);
+        self
+    }
+
+    pub fn with_failover_threshold(mut self, threshold: f64) -> Self {
+        self.failover_threshold = threshold;
+        self
+    }
+
+    /// Limits from `FORTITUDE_DAILY_BUDGET_USD`, `FORTITUDE_MONTHLY_BUDGET_USD`
+    /// and `FORTITUDE_BUDGET_FAILOVER_THRESHOLD`, ignoring unparsable values
+    pub fn from_env() -> Self {
+        let var = |name: &str| std::env::var(name).ok().and_then(|v| v.parse::<f64>().ok());
+        let defaults = Self::default();
+        Self {
+            daily_limit_usd: var("FORTITUDE_DAILY_BUDGET_USD"),
+            monthly_limit_usd: var("FORTITUDE_MONTHLY_BUDGET_USD"),
+            failover_threshold: var("FORTITUDE_BUDGET_FAILOVER_THRESHOLD")
+                .unwrap_or(defaults.failover_threshold),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, limit) in [
+            ("daily_limit_usd", self.daily_limit_usd),
+            ("monthly_limit_usd", self.monthly_limit_usd),
+        ] {
+            if let Some(limit) = limit {
+                if limit <= 0.0 {
+                    return Err(format!("{name} must be greater than 0, got {limit}"));
+                }
+            }
+        }
+        if !(0.0..=1.0).contains(&self.failover_threshold) {
+            return Err(format!(
+                "failover_threshold must be between 0.0 and 1.0, got {}",
+                self.failover_threshold
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Token usage and spend of one provider
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProviderSpend {
+    pub requests: u64,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost_usd: f64,
+}
+
+impl ProviderSpend {
+    fn add(&mut self, other: &ProviderSpend) {
+        self.requests += other.requests;
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cost_usd += other.cost_usd;
+    }
+}
+
+/// Usage aggregated over one UTC day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyCost {
+    pub date: NaiveDate,
+    pub providers: BTreeMap<String, ProviderSpend>,
+}
+
+impl DailyCost {
+    fn new(date: NaiveDate) -> Self {
+        Self {
+            date,
+            providers: BTreeMap::new(),
+        }
+    }
+
+    /// Spend across all providers
+    pub fn total_cost_usd(&self) -> f64 {
+        self.providers.values().map(|spend| spend.cost_usd).sum()
+    }
+}
+
+/// How current spend compares to the configured limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetStatus {
+    WithinBudget,
+    /// Past the failover threshold of a limit; prefer the cheapest provider
+    NearLimit,
+    /// A limit is used up; no further requests should be sent
+    Exceeded,
+}
+
+/// Spend against the daily and monthly limits
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpendReport {
+    pub status: BudgetStatus,
+    pub daily_spend_usd: f64,
+    pub daily_limit_usd: Option<f64>,
+    pub monthly_spend_usd: f64,
+    pub monthly_limit_usd: Option<f64>,
+    /// When the exhausted limit resets, set while the budget is exceeded
+    pub resets_at: Option<DateTime<Utc>>,
+}
+
+/// Usage over a reporting period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostSummary {
+    /// Daily aggregates, oldest first
+    pub days: Vec<DailyCost>,
+    /// Totals per provider over the period
+    pub providers: BTreeMap<String, ProviderSpend>,
+    pub total_cost_usd: f64,
+    pub budget: SpendReport,
+}
+
+/// Records provider usage and enforces spending limits
+///
+/// The ledger is written after every recorded request when a path is set.
+#[derive(Debug)]
+pub struct CostTracker {
+    config: CostBudgetConfig,
+    path: Option<PathBuf>,
+    days: Mutex<BTreeMap<NaiveDate, DailyCost>>,
+}
+
+impl CostTracker {
+    /// In-memory tracker that is not persisted
+    pub fn new(config: CostBudgetConfig) -> Self {
+        Self {
+            config,
+            path: None,
+            days: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Tracker backed by the ledger at `path`, which need not exist yet
+    pub fn load(
+        path: impl Into<PathBuf>,
+        config: CostBudgetConfig,
+    ) -> Result<Self, CostTrackerError> {
+        let path = path.into();
+        let days = match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str::<Vec<DailyCost>>(&content)
+                .map_err(|source| CostTrackerError::Parse {
+                    path: path.display().to_string(),
+                    source,
+                })?
+                .into_iter()
+                .map(|day| (day.date, day))
+                .collect(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(source) => {
+                return Err(CostTrackerError::Io {
+                    path: path.display().to_string(),
+                    source,
+                })
+            }
+        };
+        Ok(Self {
+            config,
+            path: Some(path),
+            days: Mutex::new(days),
+        })
+    }
+
+    pub fn config(&self) -> &CostBudgetConfig {
+        &self.config
+    }
+
+    /// Record one research request answered by `provider`
+    pub fn record(
+        &self,
+        provider: &str,
+        input_tokens: u32,
+        output_tokens: u32,
+        cost_usd: f64,
+    ) -> Result<(), CostTrackerError> {
+        self.record_on(
+            Utc::now().date_naive(),
+            provider,
+            input_tokens,
+            output_tokens,
+            cost_usd,
+        )
+    }
+
+    fn record_on(
+        &self,
+        date: NaiveDate,
+        provider: &str,
+        input_tokens: u32,
+        output_tokens: u32,
+        cost_usd: f64,
+    ) -> Result<(), CostTrackerError> {
+        let mut days = self.days.lock().unwrap();
+        days.entry(date)
+            .or_insert_with(|| DailyCost::new(date))
+            .providers
+            .entry(provider.to_string())
+            .or_default()
+            .add(&ProviderSpend {
+                requests: 1,
+                input_tokens: input_tokens as u64,
+                output_tokens: output_tokens as u64,
+                cost_usd,
+            });
+
+        match &self.path {
+            Some(path) => save(path, days.values()),
+            None => Ok(()),
+        }
+    }
+
+    /// Current spend against the configured limits
+    pub fn budget(&self) -> SpendReport {
+        self.budget_at(Utc::now())
+    }
+
+    fn budget_at(&self, now: DateTime<Utc>) -> SpendReport {
+        let today = now.date_naive();
+        let month_start = today.with_day(1).unwrap_or(today);
+        let days = self.days.lock().unwrap();
+
+        let daily_spend_usd = days
+            .get(&today)
+            .map(DailyCost::total_cost_usd)
+            .unwrap_or(0.0);
+        let monthly_spend_usd = days
+            .range(month_start..=today)
+            .map(|(_, day)| day.total_cost_usd())
+            .sum();
+
+        let daily_reset = start_of(today + Duration::days(1));
+        let monthly_reset = start_of(
+            month_start
+                .checked_add_months(chrono::Months::new(1))
+                .unwrap_or(today),
+        );
+
+        let mut status = BudgetStatus::WithinBudget;
+        let mut resets_at = None;
+        for (spend, limit, reset) in [
+            (
+                monthly_spend_usd,
+                self.config.monthly_limit_usd,
+                monthly_reset,
+            ),
+            (daily_spend_usd, self.config.daily_limit_usd, daily_reset),
+        ] {
+            let Some(limit) = limit else { continue };
+            if spend >= limit {
+                status = BudgetStatus::Exceeded;
+                resets_at.get_or_insert(reset);
+            } else if spend >= limit * self.config.failover_threshold
+                && status == BudgetStatus::WithinBudget
+            {
+                status = BudgetStatus::NearLimit;
+            }
+        }
+
+        SpendReport {
+            status,
+            daily_spend_usd,
+            daily_limit_usd: self.config.daily_limit_usd,
+            monthly_spend_usd,
+            monthly_limit_usd: self.config.monthly_limit_usd,
+            resets_at,
+        }
+    }
+
+    /// Usage over the last `days` days, including today
+    pub fn summary(&self, days: u32) -> CostSummary {
+        let today = Utc::now().date_naive();
+        let since = today - Duration::days(days.saturating_sub(1) as i64);
+
+        let period: Vec<DailyCost> = self
+            .days
+            .lock()
+            .unwrap()
+            .range(since..=today)
+            .map(|(_, day)| day.clone())
+            .collect();
+
+        let mut providers: BTreeMap<String, ProviderSpend> = BTreeMap::new();
+        for day in &period {
+            for (name, spend) in &day.providers {
+                providers.entry(name.clone()).or_default().add(spend);
+            }
+        }
+        let total_cost_usd = providers.values().map(|spend| spend.cost_usd).sum();
+
+        CostSummary {
+            days: period,
+            providers,
+            total_cost_usd,
+            budget: self.budget(),
+        }
+    }
+}
+
+fn start_of(date: NaiveDate) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap_or_default())
+}
+
+fn save<'a>(
+    path: &Path,
+    days: impl Iterator<Item = &'a DailyCost>,
+) -> Result<(), CostTrackerError> {
+    let io_error = |source| CostTrackerError::Io {
+        path: path.display().to_string(),
+        source,
+    };
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    let days: Vec<&DailyCost> = days.collect();
+    let content =
+        serde_json::to_string_pretty(&days).map_err(|source| CostTrackerError::Parse {
+            path: path.display().to_string(),
+            source,
+        })?;
+    std::fs::write(path, content).map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_usage_is_aggregated_per_day_and_persisted() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("nested").join("costs.json");
+        let yesterday = Utc::now().date_naive() - Duration::days(1);
+
+        let tracker = CostTracker::load(&path, CostBudgetConfig::default()).unwrap();
+        tracker.record("claude", 100, 50, 0.25).unwrap();
+        tracker.record("claude", 200, 80, 0.5).unwrap();
+        tracker.record("openai", 10, 5, 0.1).unwrap();
+        tracker.record_on(yesterday, "openai", 40, 20, 1.0).unwrap();
+
+        let reloaded = CostTracker::load(&path, CostBudgetConfig::default()).unwrap();
+        let summary = reloaded.summary(1);
+        assert_eq!(summary.days.len(), 1);
+        assert_eq!(
+            summary.providers["claude"],
+            ProviderSpend {
+                requests: 2,
+                input_tokens: 300,
+                output_tokens: 130,
+                cost_usd: 0.75,
+            }
+        );
+        assert!((summary.total_cost_usd - 0.85).abs() < 1e-9);
+
+        let summary = reloaded.summary(7);
+        assert_eq!(summary.days.len(), 2);
+        assert_eq!(summary.providers["openai"].requests, 2);
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(matches!(
+            CostTracker::load(&path, CostBudgetConfig::default()),
+            Err(CostTrackerError::Parse { .. })
+        ));
+    }
+
+    #[test]
+    fn test_budget_status_follows_limits() {
+        let config = CostBudgetConfig::default()
+            .with_daily_limit(1.0)
+            .with_monthly_limit(10.0);
+        let tracker = CostTracker::new(config);
+        let now = Utc.with_ymd_and_hms(2025, 3, 15, 12, 0, 0).unwrap();
+        let today = now.date_naive();
+
+        assert_eq!(tracker.budget_at(now).status, BudgetStatus::WithinBudget);
+
+        tracker.record_on(today, "claude", 0, 0, 0.85).unwrap();
+        assert_eq!(tracker.budget_at(now).status, BudgetStatus::NearLimit);
+
+        tracker.record_on(today, "claude", 0, 0, 0.2).unwrap();
+        let report = tracker.budget_at(now);
+        assert_eq!(report.status, BudgetStatus::Exceeded);
+        assert_eq!(
+            report.resets_at,
+            Some(Utc.with_ymd_and_hms(2025, 3, 16, 0, 0, 0).unwrap())
+        );
+
+        // Earlier days of the month count toward the monthly limit only
+        tracker
+            .record_on(today - Duration::days(3), "openai", 0, 0, 9.5)
+            .unwrap();
+        let report = tracker.budget_at(now);
+        assert!((report.monthly_spend_usd - 10.55).abs() < 1e-9);
+        assert_eq!(
+            report.resets_at,
+            Some(Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(CostBudgetConfig::default().validate().is_ok());
+        assert!(CostBudgetConfig::default()
+            .with_daily_limit(0.0)
+            .validate()
+            .is_err());
+        assert!(CostBudgetConfig::default()
+            .with_failover_threshold(1.5)
+            .validate()
+            .is_err());
+    }
+}
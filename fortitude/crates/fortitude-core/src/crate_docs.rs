@@ -0,0 +1,602 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Crate documentation lookup tool backed by docs.rs and crates.io
+// Fetches and caches crate metadata and item pages, extracts signatures and docs and turns them into evidence
+use crate::tools::{ResearchTool, ToolDefinition, ToolError};
+use async_trait::async_trait;
+use fortitude_types::Evidence;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// Relevance given to evidence taken from a crate's own documentation
+const CRATE_DOCS_RELEVANCE: f64 = 0.95;
+
+/// Characters of item documentation kept per item
+const MAX_DOC_CHARS: usize = 1200;
+
+/// Item paths looked up per call
+const MAX_PATHS: usize = 8;
+
+/// Crates whose docs live on doc.rust-lang.org rather than docs.rs
+const STD_CRATES: &[&str] = &["std", "core", "alloc", "proc_macro", "test"];
+
+/// Path prefixes that never name a crate
+const NON_CRATE_PREFIXES: &[&str] = &["self", "super", "crate", "Self"];
+
+/// Documentation of one item in a crate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrateDocItem {
+    /// Full item path, e.g. `tokio::sync::Mutex`
+    pub path: String,
+    /// Item kind (struct, enum, trait, fn, macro, mod, ...)
+    pub kind: String,
+    /// Declaration as shown by rustdoc
+    pub signature: Option<String>,
+    /// Leading documentation text
+    pub docs: String,
+    /// Link to the item page
+    pub url: String,
+}
+
+/// Crate metadata and the documentation of the requested items
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrateDocs {
+    pub name: String,
+    pub version: String,
+    pub description: Option<String>,
+    /// Link to the crate documentation root
+    pub docs_url: String,
+    pub repository: Option<String>,
+    pub items: Vec<CrateDocItem>,
+    /// Requested paths that have no documentation page
+    pub missing: Vec<String>,
+}
+
+impl CrateDocs {
+    /// Turn the crate summary and each item into documentation evidence
+    pub fn to_evidence(&self) -> Vec<Evidence> {
+        let mut evidence = Vec::with_capacity(self.items.len() + 1);
+        if let Some(description) = &self.description {
+            evidence.push(Evidence {
+                source: self.docs_url.clone(),
+                content: format!("{} {}: {}", self.name, self.version, description),
+                relevance: CRATE_DOCS_RELEVANCE,
+                evidence_type: "documentation".to_string(),
+            });
+        }
+        evidence.extend(self.items.iter().map(|item| {
+            let mut content = String::new();
+            if let Some(signature) = &item.signature {
+                content.push_str(signature);
+                content.push_str("\n\n");
+            }
+            content.push_str(&item.docs);
+            Evidence {
+                source: item.url.clone(),
+                content: content.trim().to_string(),
+                relevance: CRATE_DOCS_RELEVANCE,
+                evidence_type: "documentation".to_string(),
+            }
+        }));
+        evidence
+    }
+
+    /// Plain text rendering handed back to the model
+    pub fn render(&self) -> String {
+        let mut out = format!("{} {}", self.name, self.version);
+        if let Some(description) = &self.description {
+            out.push_str(&format!(": {description}"));
+        }
+        out.push_str(&format!("\ndocs: {}", self.docs_url));
+        if let Some(repository) = &self.repository {
+            out.push_str(&format!("\nrepository: {repository}"));
+        }
+        for item in &self.items {
+            out.push_str(&format!(
+                "\n\n## {} {} ({})",
+                item.kind, item.path, item.url
+            ));
+            if let Some(signature) = &item.signature {
+                out.push_str(&format!("\n```rust\n{signature}\n```"));
+            }
+            if !item.docs.is_empty() {
+                out.push_str(&format!("\n{}", item.docs));
+            }
+        }
+        if !self.missing.is_empty() {
+            out.push_str(&format!(
+                "\n\nNo documentation found for: {}",
+                self.missing.join(", ")
+            ));
+        }
+        out
+    }
+}
+
+/// Crate item paths mentioned in free text, grouped by crate
+///
+/// Standard library paths and `self`/`super`/`crate` prefixes are skipped;
+/// a bare crate name is only picked up when written as a path.
+pub fn mentioned_crate_paths(text: &str) -> Vec<(String, Vec<String>)> {
+    static PATH: OnceLock<Regex> = OnceLock::new();
+    let pattern = PATH.get_or_init(|| {
+        Regex::new(r"\b([a-z][a-z0-9_]*)((?:::[A-Za-z_][A-Za-z0-9_]*)+)").expect("valid regex")
+    });
+    let mut grouped: Vec<(String, Vec<String>)> = Vec::new();
+    for captures in pattern.captures_iter(text) {
+        let krate = &captures[1];
+        if STD_CRATES.contains(&krate) || NON_CRATE_PREFIXES.contains(&krate) {
+            continue;
+        }
+        let path = captures[0].to_string();
+        match grouped.iter_mut().find(|(name, _)| name == krate) {
+            Some((_, paths)) if !paths.contains(&path) => paths.push(path),
+            Some(_) => {}
+            None => grouped.push((krate.to_string(), vec![path])),
+        }
+    }
+    grouped
+}
+
+/// Looks up item signatures and docs for a crate on docs.rs
+pub struct CrateDocsTool {
+    client: reqwest::Client,
+    docs_base_url: String,
+    registry_base_url: String,
+    cache_ttl: Duration,
+    max_cache_entries: usize,
+    cache: Mutex<HashMap<String, (Instant, String)>>,
+}
+
+impl Default for CrateDocsTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for CrateDocsTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CrateDocsTool")
+            .field("docs_base_url", &self.docs_base_url)
+            .field("registry_base_url", &self.registry_base_url)
+            .field("cache_ttl", &self.cache_ttl)
+            .finish()
+    }
+}
+
+impl CrateDocsTool {
+    pub const NAME: &'static str = "crate_docs";
+
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .user_agent(concat!("fortitude/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .unwrap_or_default();
+        Self {
+            client,
+            docs_base_url: "https://docs.rs".to_string(),
+            registry_base_url: "https://crates.io/api/v1".to_string(),
+            cache_ttl: Duration::from_secs(3600),
+            max_cache_entries: 256,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_docs_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.docs_base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_registry_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.registry_base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    pub fn with_max_cache_entries(mut self, max_entries: usize) -> Self {
+        self.max_cache_entries = max_entries.max(1);
+        self
+    }
+
+    /// Fetch crate metadata and the docs of `paths` for a crate version
+    ///
+    /// Without a version the latest stable release is used. Paths may be
+    /// written with or without the crate prefix; an empty list returns the
+    /// crate root documentation.
+    pub async fn lookup(
+        &self,
+        name: &str,
+        version: Option<&str>,
+        paths: &[String],
+    ) -> Result<CrateDocs, ToolError> {
+        let name = name.trim();
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(ToolError::InvalidArguments {
+                tool: Self::NAME.to_string(),
+                message: format!("invalid crate name '{name}'"),
+            });
+        }
+        if STD_CRATES.contains(&name) {
+            return Err(ToolError::InvalidArguments {
+                tool: Self::NAME.to_string(),
+                message: format!("'{name}' is documented on doc.rust-lang.org, not docs.rs"),
+            });
+        }
+
+        let metadata_url = format!("{}/crates/{}", self.registry_base_url, name);
+        let metadata: Value = match self.fetch(&metadata_url).await? {
+            Some(body) => serde_json::from_str(&body).map_err(|e| self.failed(e.to_string()))?,
+            None => return Err(self.failed(format!("no crate named '{name}' exists"))),
+        };
+        let krate = &metadata["crate"];
+        let text = |key: &str| {
+            krate[key]
+                .as_str()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+        let version = version
+            .map(str::trim)
+            .filter(|version| !version.is_empty() && *version != "latest")
+            .map(str::to_string)
+            .or_else(|| text("max_stable_version"))
+            .or_else(|| text("max_version"))
+            .unwrap_or_else(|| "latest".to_string());
+
+        let ident = name.replace('-', "_");
+        let root = format!("{}/{}/{}/{}", self.docs_base_url, name, version, ident);
+        let mut docs = CrateDocs {
+            name: name.to_string(),
+            version,
+            description: text("description"),
+            docs_url: format!("{root}/"),
+            repository: text("repository"),
+            items: Vec::new(),
+            missing: Vec::new(),
+        };
+
+        let requested: Vec<String> = if paths.is_empty() {
+            vec![ident.clone()]
+        } else {
+            paths.iter().take(MAX_PATHS).cloned().collect()
+        };
+        let index = if requested
+            .iter()
+            .any(|path| relative_path(&ident, path).is_some())
+        {
+            self.fetch(&format!("{root}/all.html"))
+                .await?
+                .map(|html| item_index(&html))
+                .unwrap_or_default()
+        } else {
+            HashMap::new()
+        };
+
+        for path in requested {
+            let (kind, page) = match relative_path(&ident, &path) {
+                None => ("crate".to_string(), "index.html".to_string()),
+                Some(relative) => match index.get(&relative) {
+                    Some(page) => (item_kind(page), page.clone()),
+                    None => (
+                        "mod".to_string(),
+                        format!("{}/index.html", relative.replace("::", "/")),
+                    ),
+                },
+            };
+            let url = format!("{root}/{page}");
+            match self.fetch(&url).await? {
+                Some(html) => docs.items.push(CrateDocItem {
+                    path: normalized_path(&ident, &path),
+                    kind,
+                    signature: item_signature(&html),
+                    docs: item_docs(&html),
+                    url,
+                }),
+                None => docs.missing.push(path),
+            }
+        }
+        debug!(
+            "Looked up {} item(s) of {} {} ({} missing)",
+            docs.items.len(),
+            docs.name,
+            docs.version,
+            docs.missing.len()
+        );
+        Ok(docs)
+    }
+
+    /// Fetch a page through the cache; `None` when the page does not exist
+    async fn fetch(&self, url: &str) -> Result<Option<String>, ToolError> {
+        if let Some(body) = self.cached(url) {
+            return Ok(Some(body));
+        }
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| self.failed(e.to_string()))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let body = response
+            .error_for_status()
+            .map_err(|e| self.failed(e.to_string()))?
+            .text()
+            .await
+            .map_err(|e| self.failed(e.to_string()))?;
+        self.remember(url, &body);
+        Ok(Some(body))
+    }
+
+    fn cached(&self, url: &str) -> Option<String> {
+        let cache = self.cache.lock().ok()?;
+        cache
+            .get(url)
+            .filter(|(fetched, _)| fetched.elapsed() < self.cache_ttl)
+            .map(|(_, body)| body.clone())
+    }
+
+    fn remember(&self, url: &str, body: &str) {
+        let Ok(mut cache) = self.cache.lock() else {
+            warn!("Crate docs cache lock poisoned; not caching {}", url);
+            return;
+        };
+        let ttl = self.cache_ttl;
+        cache.retain(|_, (fetched, _)| fetched.elapsed() < ttl);
+        while cache.len() >= self.max_cache_entries {
+            let Some(oldest) = cache
+                .iter()
+                .min_by_key(|(_, (fetched, _))| *fetched)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            cache.remove(&oldest);
+        }
+        cache.insert(url.to_string(), (Instant::now(), body.to_string()));
+    }
+
+    fn failed(&self, message: String) -> ToolError {
+        ToolError::Failed {
+            tool: Self::NAME.to_string(),
+            message,
+        }
+    }
+}
+
+#[async_trait]
+impl ResearchTool for CrateDocsTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: Self::NAME.to_string(),
+            description: "Fetch item signatures and documentation of a Rust crate from docs.rs"
+                .to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "crate": {"type": "string", "description": "Crate name"},
+                    "version": {"type": "string", "description": "Crate version, latest when omitted"},
+                    "paths": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Item paths such as tokio::sync::Mutex; the crate root when omitted"
+                    }
+                },
+                "required": ["crate"]
+            }),
+        }
+    }
+
+    async fn call(&self, arguments: Value) -> Result<String, ToolError> {
+        let name = arguments
+            .get("crate")
+            .and_then(Value::as_str)
+            .ok_or_else(|| ToolError::InvalidArguments {
+                tool: Self::NAME.to_string(),
+                message: "missing string argument 'crate'".to_string(),
+            })?;
+        let version = arguments.get("version").and_then(Value::as_str);
+        let paths: Vec<String> = arguments
+            .get("paths")
+            .and_then(Value::as_array)
+            .map(|paths| {
+                paths
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(self.lookup(name, version, &paths).await?.render())
+    }
+}
+
+/// Item path below the crate root, `None` for the root itself
+fn relative_path(ident: &str, path: &str) -> Option<String> {
+    let path = path.trim().trim_start_matches("::");
+    let relative = path
+        .strip_prefix(ident)
+        .and_then(|rest| rest.strip_prefix("::").or((rest.is_empty()).then_some("")))
+        .unwrap_or(path);
+    (!relative.is_empty()).then(|| relative.to_string())
+}
+
+fn normalized_path(ident: &str, path: &str) -> String {
+    match relative_path(ident, path) {
+        Some(relative) => format!("{ident}::{relative}"),
+        None => ident.to_string(),
+    }
+}
+
+/// Map of `module::Item` paths to their page in a rustdoc `all.html`
+fn item_index(html: &str) -> HashMap<String, String> {
+    static LINK: OnceLock<Regex> = OnceLock::new();
+    let pattern = LINK.get_or_init(|| {
+        Regex::new(r##"<a href="([^"#]+\.html)">([^<]+)</a>"##).expect("valid regex")
+    });
+    pattern
+        .captures_iter(html)
+        .map(|captures| (decode_entities(&captures[2]), captures[1].to_string()))
+        .collect()
+}
+
+/// Item kind from a rustdoc page name such as `sync/struct.Mutex.html`
+fn item_kind(page: &str) -> String {
+    page.rsplit('/')
+        .next()
+        .and_then(|file| file.split_once('.'))
+        .map(|(kind, _)| kind.to_string())
+        .unwrap_or_else(|| "item".to_string())
+}
+
+fn item_signature(html: &str) -> Option<String> {
+    static DECL: OnceLock<Regex> = OnceLock::new();
+    let pattern = DECL.get_or_init(|| {
+        Regex::new(r#"(?s)<pre class="rust item-decl">(.*?)</pre>"#).expect("valid regex")
+    });
+    pattern
+        .captures(html)
+        .map(|captures| strip_tags(&captures[1]).trim().to_string())
+        .filter(|signature| !signature.is_empty())
+}
+
+fn item_docs(html: &str) -> String {
+    static TOP_DOC: OnceLock<Regex> = OnceLock::new();
+    let pattern = TOP_DOC.get_or_init(|| {
+        Regex::new(r#"(?s)<details class="toggle top-doc"[^>]*>.*?<div class="docblock">(.*?)</div></details>"#)
+            .expect("valid regex")
+    });
+    let Some(captures) = pattern.captures(html) else {
+        return String::new();
+    };
+    let text = strip_tags(&captures[1].replace("</p>", "\n\n"));
+    let mut docs = text
+        .split("\n\n")
+        .map(|paragraph| paragraph.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    if docs.chars().count() > MAX_DOC_CHARS {
+        docs = docs.chars().take(MAX_DOC_CHARS).collect::<String>() + "...";
+    }
+    docs
+}
+
+fn strip_tags(html: &str) -> String {
+    static TAG: OnceLock<Regex> = OnceLock::new();
+    let pattern = TAG.get_or_init(|| Regex::new(r"<[^>]*>").expect("valid regex"));
+    decode_entities(&pattern.replace_all(html, ""))
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extracts_paths_and_item_docs() {
+        let mentioned = mentioned_crate_paths(
+            "Should I use tokio::sync::Mutex or std::sync::Mutex? See tokio::sync::Mutex and serde_json::Value",
+        );
+        assert_eq!(
+            mentioned,
+            vec![
+                ("tokio".to_string(), vec!["tokio::sync::Mutex".to_string()]),
+                (
+                    "serde_json".to_string(),
+                    vec!["serde_json::Value".to_string()]
+                ),
+            ]
+        );
+
+        let all = r#"<ul class="all-items"><li><a href="sync/struct.Mutex.html">sync::Mutex</a></li><li><a href="fn.spawn.html">spawn</a></li></ul>"#;
+        let index = item_index(all);
+        assert_eq!(index["sync::Mutex"], "sync/struct.Mutex.html");
+        assert_eq!(item_kind(&index["spawn"]), "fn");
+        assert_eq!(
+            relative_path("tokio", "tokio::sync::Mutex").as_deref(),
+            Some("sync::Mutex")
+        );
+        assert_eq!(
+            relative_path("tokio", "sync::Mutex").as_deref(),
+            Some("sync::Mutex")
+        );
+        assert_eq!(relative_path("tokio", "tokio"), None);
+        assert_eq!(
+            normalized_path("tokio", "sync::Mutex"),
+            "tokio::sync::Mutex"
+        );
+
+        let page = r#"<pre class="rust item-decl"><code>pub struct Mutex&lt;T: ?<a href="x">Sized</a>&gt; { /* private fields */ }</code></pre>
+<details class="toggle top-doc" open><summary>Expand</summary><div class="docblock"><p>An asynchronous <code>Mutex</code>-like type.</p>
+<p>This type acts similarly to   <a href="y">std::sync::Mutex</a>.</p></div></details>"#;
+        assert_eq!(
+            item_signature(page).as_deref(),
+            Some("pub struct Mutex<T: ?Sized> { /* private fields */ }")
+        );
+        assert_eq!(
+            item_docs(page),
+            "An asynchronous Mutex-like type.\n\nThis type acts similarly to std::sync::Mutex."
+        );
+
+        let docs = CrateDocs {
+            name: "tokio".to_string(),
+            version: "1.40.0".to_string(),
+            description: Some("An event-driven runtime".to_string()),
+            docs_url: "https://docs.rs/tokio/1.40.0/tokio/".to_string(),
+            repository: None,
+            items: vec![CrateDocItem {
+                path: "tokio::sync::Mutex".to_string(),
+                kind: "struct".to_string(),
+                signature: item_signature(page),
+                docs: item_docs(page),
+                url: "https://docs.rs/tokio/1.40.0/tokio/sync/struct.Mutex.html".to_string(),
+            }],
+            missing: vec!["tokio::nope".to_string()],
+        };
+        let evidence = docs.to_evidence();
+        assert_eq!(evidence.len(), 2);
+        assert_eq!(evidence[1].source, docs.items[0].url);
+        assert_eq!(evidence[1].evidence_type, "documentation");
+        assert!(evidence[1].content.starts_with("pub struct Mutex"));
+        let rendered = docs.render();
+        assert!(rendered.contains("## struct tokio::sync::Mutex"));
+        assert!(rendered.contains("No documentation found for: tokio::nope"));
+    }
+}
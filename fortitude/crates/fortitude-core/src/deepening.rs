@@ -0,0 +1,392 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Iterative deepening of research results through targeted follow-up queries
+//! After the first provider answer, sections with low confidence (weak
+//! evidence, a low overall quality score, or missing implementation details
+//! for an implementation question) each get a follow-up query. The pipeline
+//! runs the follow-ups within a count and time budget and merges their
+//! answers back into the original result with [`merge_follow_up`]. The
+//! queries that ran are written to the result metadata tags.
+
+use fortitude_types::{ResearchResult, ResearchType};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Metadata tag holding the JSON list of follow-up queries that were merged
+pub const DEEPENING_QUERIES_TAG: &str = "deepening_queries";
+/// Metadata tag holding the number of planned follow-ups skipped for budget
+pub const DEEPENING_SKIPPED_TAG: &str = "deepening_skipped";
+
+/// Characters of an evidence item used to describe its topic
+const TOPIC_CHARS: usize = 120;
+
+/// Configuration for iterative deepening
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeepeningConfig {
+    /// Run follow-up queries for low-confidence sections
+    pub enabled: bool,
+    /// Sections scoring below this get a follow-up (0.0-1.0)
+    pub confidence_threshold: f64,
+    /// Most follow-up queries run for one research query
+    pub max_follow_ups: usize,
+    /// Most rounds of follow-ups; later rounds look at what earlier ones added
+    pub max_rounds: usize,
+    /// Wall-clock budget for all follow-ups in milliseconds
+    pub time_budget_ms: Option<u64>,
+}
+
+impl Default for DeepeningConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            confidence_threshold: 0.6,
+            max_follow_ups: 3,
+            max_rounds: 2,
+            time_budget_ms: Some(60_000),
+        }
+    }
+}
+
+impl DeepeningConfig {
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn with_confidence_threshold(mut self, threshold: f64) -> Self {
+        self.confidence_threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_max_follow_ups(mut self, max_follow_ups: usize) -> Self {
+        self.max_follow_ups = max_follow_ups;
+        self
+    }
+
+    pub fn with_max_rounds(mut self, max_rounds: usize) -> Self {
+        self.max_rounds = max_rounds;
+        self
+    }
+
+    pub fn with_time_budget_ms(mut self, time_budget_ms: u64) -> Self {
+        self.time_budget_ms = Some(time_budget_ms);
+        self
+    }
+
+    /// Check that the threshold is a valid score
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&self.confidence_threshold) {
+            return Err(format!(
+                "confidence_threshold must be between 0.0 and 1.0, got {}",
+                self.confidence_threshold
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Part of a result a follow-up query targets
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "section", content = "index")]
+pub enum WeakSection {
+    /// The answer as a whole scored below the threshold
+    Answer,
+    /// Evidence item at this position scored below the threshold
+    Evidence(usize),
+    /// An implementation question came back without implementation details
+    ImplementationDetails,
+}
+
+/// Follow-up query planned for a weak section
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FollowUpQuery {
+    #[serde(flatten)]
+    pub section: WeakSection,
+    pub query: String,
+}
+
+/// Plan follow-up queries for the low-confidence sections of a result
+///
+/// Queries already in `asked` are not planned again, so later rounds only
+/// target what earlier follow-ups did not settle.
+pub fn plan_follow_ups(
+    result: &ResearchResult,
+    config: &DeepeningConfig,
+    asked: &HashSet<String>,
+) -> Vec<FollowUpQuery> {
+    let original = result.request.original_query.trim();
+    let mut planned = Vec::new();
+
+    if result.metadata.quality_score < config.confidence_threshold
+        || result.supporting_evidence.is_empty()
+    {
+        planned.push(FollowUpQuery {
+            section: WeakSection::Answer,
+            query: format!("{original}: explain in depth, with concrete examples and trade-offs"),
+        });
+    }
+
+    if result.request.research_type == ResearchType::Implementation
+        && result.implementation_details.is_empty()
+    {
+        planned.push(FollowUpQuery {
+            section: WeakSection::ImplementationDetails,
+            query: format!("{original}: step-by-step implementation with code"),
+        });
+    }
+
+    let mut weak_evidence: Vec<(usize, f64)> = result
+        .supporting_evidence
+        .iter()
+        .enumerate()
+        .filter(|(_, evidence)| evidence.relevance < config.confidence_threshold)
+        .map(|(index, evidence)| (index, evidence.relevance))
+        .collect();
+    // Weakest first, so a tight budget goes where it helps most
+    weak_evidence.sort_by(|a, b| a.1.total_cmp(&b.1));
+    for (index, _) in weak_evidence {
+        let evidence = &result.supporting_evidence[index];
+        let topic = topic_of(&evidence.content);
+        if topic.is_empty() {
+            continue;
+        }
+        planned.push(FollowUpQuery {
+            section: WeakSection::Evidence(index),
+            query: format!("{original}: {topic}"),
+        });
+    }
+
+    let mut seen = HashSet::new();
+    planned.retain(|follow_up| {
+        !asked.contains(&follow_up.query) && seen.insert(follow_up.query.clone())
+    });
+    planned
+}
+
+/// Merge the answer to a follow-up query into the result being deepened
+///
+/// Evidence and details the result does not already contain are added,
+/// evidence is re-ordered best first, sources are combined, and the quality
+/// score is raised to the follow-up's when that is higher.
+pub fn merge_follow_up(result: &mut ResearchResult, follow_up: ResearchResult) {
+    let known_evidence: HashSet<String> = result
+        .supporting_evidence
+        .iter()
+        .map(|evidence| normalize(&evidence.content))
+        .collect();
+    result.supporting_evidence.extend(
+        follow_up
+            .supporting_evidence
+            .into_iter()
+            .filter(|evidence| !known_evidence.contains(&normalize(&evidence.content))),
+    );
+    result
+        .supporting_evidence
+        .sort_by(|a, b| b.relevance.total_cmp(&a.relevance));
+
+    let known_details: HashSet<String> = result
+        .implementation_details
+        .iter()
+        .map(|detail| normalize(&detail.content))
+        .collect();
+    result.implementation_details.extend(
+        follow_up
+            .implementation_details
+            .into_iter()
+            .filter(|detail| !known_details.contains(&normalize(&detail.content))),
+    );
+
+    for source in follow_up.metadata.sources_consulted {
+        if !result.metadata.sources_consulted.contains(&source) {
+            result.metadata.sources_consulted.push(source);
+        }
+    }
+    result.metadata.quality_score = result
+        .metadata
+        .quality_score
+        .max(follow_up.metadata.quality_score);
+}
+
+/// Record the follow-ups that ran and how many were skipped
+pub fn record_deepening(result: &mut ResearchResult, queries: &[String], skipped: usize) {
+    if queries.is_empty() && skipped == 0 {
+        return;
+    }
+    let tags = &mut result.metadata.tags;
+    tags.insert(
+        DEEPENING_QUERIES_TAG.to_string(),
+        serde_json::to_string(queries).unwrap_or_default(),
+    );
+    tags.insert(DEEPENING_SKIPPED_TAG.to_string(), skipped.to_string());
+}
+
+/// First sentence of evidence content, cut to a query-sized topic
+fn topic_of(content: &str) -> String {
+    let sentence = content
+        .split_terminator(['.', '\n'])
+        .map(str::trim)
+        .find(|sentence| !sentence.is_empty())
+        .unwrap_or_default();
+    sentence.chars().take(TOPIC_CHARS).collect()
+}
+
+fn normalize(content: &str) -> String {
+    content.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use fortitude_types::{
+        AudienceContext, ClassifiedRequest, Detail, DomainContext, Evidence, ResearchMetadata,
+    };
+    use std::collections::HashMap;
+
+    fn evidence(content: &str, relevance: f64) -> Evidence {
+        Evidence {
+            source: "docs.rs".to_string(),
+            content: content.to_string(),
+            relevance,
+            evidence_type: "documentation".to_string(),
+        }
+    }
+
+    fn result(
+        research_type: ResearchType,
+        quality: f64,
+        evidence: Vec<Evidence>,
+    ) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            "How should I structure a plugin system?".to_string(),
+            research_type,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        );
+        ResearchResult::new(
+            request,
+            "Use trait objects.".to_string(),
+            evidence,
+            vec![],
+            ResearchMetadata {
+                completed_at: Utc::now(),
+                processing_time_ms: 10,
+                sources_consulted: vec!["docs.rs".to_string()],
+                quality_score: quality,
+                cache_key: "key".to_string(),
+                tags: HashMap::new(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_plan_targets_weak_sections_weakest_first() {
+        let config = DeepeningConfig::default();
+        let result = result(
+            ResearchType::Implementation,
+            0.9,
+            vec![
+                evidence("Dynamic loading with libloading. Needs unsafe.", 0.5),
+                evidence("Trait objects give open extension points.", 0.9),
+                evidence("ABI stability is not guaranteed\nacross compilers", 0.2),
+            ],
+        );
+
+        let planned = plan_follow_ups(&result, &config, &HashSet::new());
+        let sections: Vec<_> = planned.iter().map(|f| f.section.clone()).collect();
+        assert_eq!(
+            sections,
+            vec![
+                WeakSection::ImplementationDetails,
+                WeakSection::Evidence(2),
+                WeakSection::Evidence(0),
+            ]
+        );
+        assert_eq!(
+            planned[1].query,
+            "How should I structure a plugin system?: ABI stability is not guaranteed"
+        );
+
+        // Queries asked in an earlier round are not planned again
+        let asked: HashSet<String> = planned.iter().map(|f| f.query.clone()).collect();
+        assert!(plan_follow_ups(&result, &config, &asked).is_empty());
+    }
+
+    #[test]
+    fn test_plan_deepens_low_quality_answer() {
+        let config = DeepeningConfig::default();
+        let planned = plan_follow_ups(
+            &result(ResearchType::Decision, 0.4, vec![evidence("Strong.", 0.95)]),
+            &config,
+            &HashSet::new(),
+        );
+        assert_eq!(planned.len(), 1);
+        assert_eq!(planned[0].section, WeakSection::Answer);
+
+        let confident = result(ResearchType::Decision, 0.9, vec![evidence("Strong.", 0.95)]);
+        assert!(plan_follow_ups(&confident, &config, &HashSet::new()).is_empty());
+    }
+
+    #[test]
+    fn test_merge_adds_new_content_only() {
+        let mut original = result(
+            ResearchType::Implementation,
+            0.5,
+            vec![evidence("Trait objects give open extension points.", 0.7)],
+        );
+        let mut follow_up = result(
+            ResearchType::Implementation,
+            0.8,
+            vec![
+                evidence("Trait  objects give open extension points.", 0.6),
+                evidence("Register plugins in an inventory at startup.", 0.9),
+            ],
+        );
+        follow_up.implementation_details.push(Detail {
+            category: "code".to_string(),
+            content: "inventory::submit! { Plugin::new() }".to_string(),
+            priority: "high".to_string(),
+            prerequisites: vec![],
+        });
+        follow_up
+            .metadata
+            .sources_consulted
+            .push("github.com".to_string());
+
+        merge_follow_up(&mut original, follow_up);
+
+        assert_eq!(original.supporting_evidence.len(), 2);
+        assert_eq!(original.supporting_evidence[0].relevance, 0.9);
+        assert_eq!(original.implementation_details.len(), 1);
+        assert_eq!(
+            original.metadata.sources_consulted,
+            vec!["docs.rs".to_string(), "github.com".to_string()]
+        );
+        assert_eq!(original.metadata.quality_score, 0.8);
+
+        record_deepening(&mut original, &["q1".to_string()], 2);
+        assert_eq!(
+            original.metadata.tags.get(DEEPENING_QUERIES_TAG).unwrap(),
+            r#"["q1"]"#
+        );
+        assert_eq!(
+            original.metadata.tags.get(DEEPENING_SKIPPED_TAG).unwrap(),
+            "2"
+        );
+    }
+}
@@ -0,0 +1,641 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Digests of recently stored research results, rendered for reading or email
+//! A digest collects the results stored in the last few hours, grouped by
+//! research type, so a team can read one summary instead of watching the CLI.
+//! Each research type has an inclusion rule deciding which of its results
+//! make it in. [`DigestMailer`] sends a digest over SMTP with Markdown and
+//! HTML alternatives.
+
+use chrono::{DateTime, Duration, Utc};
+use fortitude_types::{ResearchType, Storage};
+use lettre::message::{header::ContentType, Mailbox, MultiPart, SinglePart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Write;
+use thiserror::Error;
+use tracing::{info, warn};
+
+#[derive(Error, Debug)]
+pub enum DigestError {
+    #[error("Failed to read stored results: {0}")]
+    Storage(String),
+
+    #[error("Invalid email address '{address}': {error}")]
+    InvalidAddress { address: String, error: String },
+
+    #[error("Failed to build digest email: {0}")]
+    Message(String),
+
+    #[error("Failed to send digest email: {0}")]
+    Smtp(String),
+}
+
+/// Which results of one research type a digest includes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DigestRule {
+    /// Leave the research type out of digests when false
+    pub include: bool,
+    /// Results scoring below this quality are left out (0.0-1.0)
+    pub min_quality: f64,
+    /// Most results listed for the type, newest first; the rest are counted
+    pub max_results: Option<usize>,
+}
+
+impl Default for DigestRule {
+    fn default() -> Self {
+        Self {
+            include: true,
+            min_quality: 0.0,
+            max_results: None,
+        }
+    }
+}
+
+/// Contents and delivery of research digests
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DigestConfig {
+    /// Hours of stored results a digest covers
+    pub lookback_hours: u32,
+    /// Rule per research type (e.g. `"learning": {"include": false}`);
+    /// unlisted types use the default rule
+    pub research_types: HashMap<String, DigestRule>,
+    /// Longest answer excerpt per result, in characters
+    pub excerpt_chars: usize,
+    /// SMTP delivery; without it digests can only be printed
+    pub email: Option<DigestEmailConfig>,
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self {
+            lookback_hours: 24,
+            research_types: HashMap::new(),
+            excerpt_chars: 400,
+            email: None,
+        }
+    }
+}
+
+impl DigestConfig {
+    pub fn with_lookback_hours(mut self, lookback_hours: u32) -> Self {
+        self.lookback_hours = lookback_hours;
+        self
+    }
+
+    pub fn with_rule(mut self, research_type: &ResearchType, rule: DigestRule) -> Self {
+        self.research_types
+            .insert(research_type.to_string().to_lowercase(), rule);
+        self
+    }
+
+    /// Inclusion rule for results of `research_type`
+    pub fn rule_for(&self, research_type: &ResearchType) -> DigestRule {
+        self.research_types
+            .iter()
+            .find(|(name, _)| name.parse::<ResearchType>().ok().as_ref() == Some(research_type))
+            .map(|(_, rule)| rule.clone())
+            .unwrap_or_default()
+    }
+
+    /// Check the lookback, research type names, quality bounds and email settings
+    pub fn validate(&self) -> Result<(), String> {
+        if self.lookback_hours == 0 {
+            return Err("lookback_hours must be at least one hour".to_string());
+        }
+        for (name, rule) in &self.research_types {
+            if name.parse::<ResearchType>().is_err() {
+                return Err(format!("unknown research type '{name}'"));
+            }
+            if !(0.0..=1.0).contains(&rule.min_quality) {
+                return Err(format!(
+                    "min_quality for {name} must be between 0.0 and 1.0, got {}",
+                    rule.min_quality
+                ));
+            }
+        }
+        if let Some(email) = &self.email {
+            email.validate().map_err(|e| format!("email: {e}"))?;
+        }
+        Ok(())
+    }
+}
+
+/// How the connection to the SMTP server is secured
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpSecurity {
+    /// Upgrade a plain connection with STARTTLS (port 587)
+    #[default]
+    StartTls,
+    /// TLS from the start of the connection (port 465)
+    Tls,
+    /// No encryption, for local relays only (port 25)
+    None,
+}
+
+impl SmtpSecurity {
+    pub fn default_port(&self) -> u16 {
+        match self {
+            SmtpSecurity::StartTls => 587,
+            SmtpSecurity::Tls => 465,
+            SmtpSecurity::None => 25,
+        }
+    }
+}
+
+/// SMTP server and recipients digests are sent to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DigestEmailConfig {
+    pub smtp_host: String,
+    /// Defaults to the standard port of `security`
+    #[serde(default)]
+    pub smtp_port: Option<u16>,
+    #[serde(default)]
+    pub security: SmtpSecurity,
+    #[serde(default)]
+    pub username: Option<String>,
+    /// Also read from `FORTITUDE_SMTP_PASSWORD`
+    #[serde(default)]
+    pub password: Option<String>,
+    /// Sender address, e.g. `Fortitude <research@example.com>`
+    pub from: String,
+    pub to: Vec<String>,
+    #[serde(default = "default_digest_subject")]
+    pub subject: String,
+}
+
+fn default_digest_subject() -> String {
+    "Fortitude research digest".to_string()
+}
+
+impl DigestEmailConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.smtp_host.trim().is_empty() {
+            return Err("smtp_host cannot be empty".to_string());
+        }
+        if self.to.is_empty() {
+            return Err("at least one recipient is required".to_string());
+        }
+        if self.username.is_some() != self.password.is_some() {
+            return Err("username and password must be set together".to_string());
+        }
+        for address in std::iter::once(&self.from).chain(&self.to) {
+            parse_mailbox(address).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_mailbox(address: &str) -> Result<Mailbox, DigestError> {
+    address
+        .parse::<Mailbox>()
+        .map_err(|e| DigestError::InvalidAddress {
+            address: address.to_string(),
+            error: e.to_string(),
+        })
+}
+
+/// Output format of a rendered digest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigestFormat {
+    Markdown,
+    Html,
+}
+
+impl std::str::FromStr for DigestFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "markdown" | "md" => Ok(DigestFormat::Markdown),
+            "html" => Ok(DigestFormat::Html),
+            _ => Err(format!(
+                "Invalid digest format: {s} (expected markdown or html)"
+            )),
+        }
+    }
+}
+
+/// One result listed in a digest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DigestItem {
+    pub cache_key: String,
+    pub query: String,
+    pub created_at: DateTime<Utc>,
+    pub quality_score: f64,
+    /// Start of the immediate answer on one line
+    pub excerpt: String,
+}
+
+/// Results of one research type, newest first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DigestSection {
+    pub research_type: ResearchType,
+    pub items: Vec<DigestItem>,
+    /// Results left out by the type's `max_results`
+    pub omitted: usize,
+}
+
+/// Research results stored between `since` and `until`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Digest {
+    pub since: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+    /// Sections in research type order; types without results are left out
+    pub sections: Vec<DigestSection>,
+}
+
+impl Digest {
+    /// Collect the results stored in the lookback window ending at `now`
+    pub async fn compile(
+        storage: &(dyn Storage + Send + Sync),
+        config: &DigestConfig,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DigestError> {
+        let since = now - Duration::hours(i64::from(config.lookback_hours));
+        let entries = storage
+            .list_cache_entries()
+            .await
+            .map_err(|e| DigestError::Storage(e.to_string()))?;
+
+        let mut by_type: HashMap<ResearchType, Vec<DigestItem>> = HashMap::new();
+        for entry in entries {
+            if entry.created_at < since || entry.created_at > now {
+                continue;
+            }
+            let rule = config.rule_for(&entry.research_type);
+            if !rule.include {
+                continue;
+            }
+            let result = match storage.retrieve(&entry.key).await {
+                Ok(Some(result)) => result,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!("Leaving {} out of the digest: {}", entry.key, e);
+                    continue;
+                }
+            };
+            if result.metadata.quality_score < rule.min_quality {
+                continue;
+            }
+            by_type
+                .entry(entry.research_type.clone())
+                .or_default()
+                .push(DigestItem {
+                    cache_key: entry.key.clone(),
+                    query: entry.original_query.clone(),
+                    created_at: entry.created_at,
+                    quality_score: result.metadata.quality_score,
+                    excerpt: excerpt(&result.immediate_answer, config.excerpt_chars),
+                });
+        }
+
+        let sections = ResearchType::all()
+            .into_iter()
+            .filter_map(|research_type| {
+                let mut items = by_type.remove(&research_type)?;
+                items.sort_by_key(|item| std::cmp::Reverse(item.created_at));
+                let limit = config.rule_for(&research_type).max_results;
+                let omitted = limit.map_or(0, |limit| items.len().saturating_sub(limit));
+                items.truncate(items.len() - omitted);
+                Some(DigestSection {
+                    research_type,
+                    items,
+                    omitted,
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            since,
+            until: now,
+            sections,
+        })
+    }
+
+    /// Results stored in the window, including those left out by `max_results`
+    pub fn result_count(&self) -> usize {
+        self.sections
+            .iter()
+            .map(|section| section.items.len() + section.omitted)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+
+    pub fn render(&self, format: DigestFormat) -> String {
+        match format {
+            DigestFormat::Markdown => self.to_markdown(),
+            DigestFormat::Html => self.to_html(),
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "# Research digest\n\n{} new results from {} to {}\n",
+            self.result_count(),
+            self.since.format("%Y-%m-%d %H:%M UTC"),
+            self.until.format("%Y-%m-%d %H:%M UTC")
+        );
+        for section in &self.sections {
+            let _ = write!(out, "\n## {}\n\n", section.research_type.display_name());
+            for item in &section.items {
+                let _ = writeln!(
+                    out,
+                    "- **{}** ({}, quality {:.2}, `{}`)\n  {}",
+                    item.query,
+                    item.created_at.format("%Y-%m-%d %H:%M"),
+                    item.quality_score,
+                    item.cache_key,
+                    item.excerpt
+                );
+            }
+            if section.omitted > 0 {
+                let _ = writeln!(out, "- …and {} more", section.omitted);
+            }
+        }
+        out
+    }
+
+    /// HTML email body; queries and answers are escaped
+    pub fn to_html(&self) -> String {
+        let mut out = format!(
+            "<html><body>\n<h1>Research digest</h1>\n<p>{} new results from {} to {}</p>\n",
+            self.result_count(),
+            self.since.format("%Y-%m-%d %H:%M UTC"),
+            self.until.format("%Y-%m-%d %H:%M UTC")
+        );
+        for section in &self.sections {
+            let _ = writeln!(
+                out,
+                "<h2>{}</h2>\n<ul>",
+                escape_html(section.research_type.display_name())
+            );
+            for item in &section.items {
+                let _ = writeln!(
+                    out,
+                    "<li><strong>{}</strong> ({}, quality {:.2}, <code>{}</code>)<br>{}</li>",
+                    escape_html(&item.query),
+                    item.created_at.format("%Y-%m-%d %H:%M"),
+                    item.quality_score,
+                    escape_html(&item.cache_key),
+                    escape_html(&item.excerpt)
+                );
+            }
+            if section.omitted > 0 {
+                let _ = writeln!(out, "<li>…and {} more</li>", section.omitted);
+            }
+            out.push_str("</ul>\n");
+        }
+        out.push_str("</body></html>\n");
+        out
+    }
+}
+
+/// First `max_chars` characters of `text` with whitespace collapsed
+fn excerpt(text: &str, max_chars: usize) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= max_chars {
+        return collapsed;
+    }
+    let truncated: String = collapsed.chars().take(max_chars).collect();
+    format!("{}…", truncated.trim_end())
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Sends digests to the configured recipients over SMTP
+pub struct DigestMailer {
+    config: DigestEmailConfig,
+}
+
+impl DigestMailer {
+    pub fn new(config: DigestEmailConfig) -> Self {
+        Self { config }
+    }
+
+    /// Email with the digest as Markdown text and HTML alternatives
+    pub fn message(&self, digest: &Digest) -> Result<Message, DigestError> {
+        let mut builder = Message::builder()
+            .from(parse_mailbox(&self.config.from)?)
+            .subject(format!(
+                "{} ({} results)",
+                self.config.subject,
+                digest.result_count()
+            ));
+        for address in &self.config.to {
+            builder = builder.to(parse_mailbox(address)?);
+        }
+        builder
+            .multipart(
+                MultiPart::alternative()
+                    .singlepart(
+                        SinglePart::builder()
+                            .header(ContentType::TEXT_PLAIN)
+                            .body(digest.to_markdown()),
+                    )
+                    .singlepart(
+                        SinglePart::builder()
+                            .header(ContentType::TEXT_HTML)
+                            .body(digest.to_html()),
+                    ),
+            )
+            .map_err(|e| DigestError::Message(e.to_string()))
+    }
+
+    pub async fn send(&self, digest: &Digest) -> Result<(), DigestError> {
+        let message = self.message(digest)?;
+        let host = self.config.smtp_host.as_str();
+        let builder = match self.config.security {
+            SmtpSecurity::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host),
+            SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(host),
+            SmtpSecurity::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(
+                host,
+            )),
+        }
+        .map_err(|e| DigestError::Smtp(e.to_string()))?;
+        let mut builder = builder.port(
+            self.config
+                .smtp_port
+                .unwrap_or_else(|| self.config.security.default_port()),
+        );
+        if let (Some(username), Some(password)) = (&self.config.username, &self.config.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        builder
+            .build()
+            .send(message)
+            .await
+            .map_err(|e| DigestError::Smtp(e.to_string()))?;
+        info!(
+            "Sent research digest with {} results to {} recipients",
+            digest.result_count(),
+            self.config.to.len()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::FileStorage;
+    use fortitude_types::{
+        AudienceContext, ClassifiedRequest, DomainContext, ResearchMetadata, ResearchResult,
+        StorageConfig,
+    };
+
+    fn result(query: &str, research_type: ResearchType, quality: f64) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            query.to_string(),
+            research_type,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 0,
+            sources_consulted: vec![],
+            quality_score: quality,
+            cache_key: String::new(),
+            tags: HashMap::new(),
+        };
+        ResearchResult::new(
+            request,
+            format!("Answer to {query}:\n  use <T> & friends"),
+            vec![],
+            vec![],
+            metadata,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_digest_applies_rules_and_renders_email() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = FileStorage::new(StorageConfig {
+            base_path: temp_dir.path().to_path_buf(),
+            ..StorageConfig::default()
+        })
+        .await
+        .unwrap();
+        for (query, research_type, quality) in [
+            ("fix borrow error", ResearchType::Troubleshooting, 0.9),
+            ("fix lifetime error", ResearchType::Troubleshooting, 0.8),
+            ("fix <script> error", ResearchType::Troubleshooting, 0.7),
+            ("weak answer", ResearchType::Troubleshooting, 0.2),
+            ("learn async", ResearchType::Learning, 0.9),
+            ("choose a database", ResearchType::Decision, 0.9),
+        ] {
+            storage
+                .store(&result(query, research_type, quality))
+                .await
+                .unwrap();
+        }
+
+        let config = DigestConfig::default()
+            .with_rule(
+                &ResearchType::Troubleshooting,
+                DigestRule {
+                    min_quality: 0.5,
+                    max_results: Some(2),
+                    ..DigestRule::default()
+                },
+            )
+            .with_rule(
+                &ResearchType::Learning,
+                DigestRule {
+                    include: false,
+                    ..DigestRule::default()
+                },
+            );
+        assert!(config.validate().is_ok());
+
+        let now = Utc::now();
+        let digest = Digest::compile(&storage, &config, now).await.unwrap();
+        let types: Vec<_> = digest.sections.iter().map(|s| &s.research_type).collect();
+        assert_eq!(
+            types,
+            [&ResearchType::Decision, &ResearchType::Troubleshooting]
+        );
+        let troubleshooting = &digest.sections[1];
+        assert_eq!(troubleshooting.items.len(), 2);
+        assert_eq!(troubleshooting.omitted, 1);
+        assert_eq!(digest.result_count(), 4);
+        assert!(troubleshooting.items[0]
+            .excerpt
+            .ends_with("use <T> & friends"));
+
+        let markdown = digest.to_markdown();
+        assert!(markdown.contains("## Troubleshooting"));
+        assert!(markdown.contains("…and 1 more"));
+        assert!(!markdown.contains("weak answer"));
+        assert!(!markdown.contains("learn async"));
+        let html = digest.to_html();
+        assert!(html.contains("use &lt;T&gt; &amp; friends"));
+        assert!(!html.contains("<T>"));
+
+        // Results stored before the window are left out
+        let later = Digest::compile(&storage, &config, now + Duration::hours(25))
+            .await
+            .unwrap();
+        assert!(later.is_empty());
+
+        let email = DigestEmailConfig {
+            smtp_host: "smtp.example.com".to_string(),
+            smtp_port: None,
+            security: SmtpSecurity::default(),
+            username: None,
+            password: None,
+            from: "Fortitude <research@example.com>".to_string(),
+            to: vec!["team@example.com".to_string()],
+            subject: default_digest_subject(),
+        };
+        assert!(email.validate().is_ok());
+        let message = DigestMailer::new(email.clone()).message(&digest).unwrap();
+        let raw = String::from_utf8(message.formatted()).unwrap();
+        assert!(raw.contains("Subject: Fortitude research digest (4 results)"));
+        assert!(raw.contains("multipart/alternative"));
+        assert!(raw.contains("text/html"));
+
+        let invalid = DigestEmailConfig {
+            to: vec!["not an address".to_string()],
+            ..email
+        };
+        assert!(invalid.validate().unwrap_err().contains("not an address"));
+    }
+}
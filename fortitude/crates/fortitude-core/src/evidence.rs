@@ -0,0 +1,496 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Scores supporting evidence for relevance and credibility and prunes weak items
+//! Each evidence item gets a relevance rating (embedding similarity to the
+//! query when an embedding generator is available, term overlap otherwise,
+//! blended with the provider's own relevance) and a credibility rating from
+//! source and content heuristics. Items scoring below the threshold are
+//! dropped, the rest are ordered best first, and every pruning decision is
+//! written to the result metadata tags so it can be audited.
+
+use crate::vector::EmbeddingGenerator;
+use fortitude_types::{Evidence, ResearchResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// Metadata tag holding the number of evidence items kept
+pub const EVIDENCE_KEPT_TAG: &str = "evidence_kept";
+/// Metadata tag holding the number of evidence items pruned
+pub const EVIDENCE_PRUNED_TAG: &str = "evidence_pruned";
+/// Metadata tag holding the JSON list of pruning decisions
+pub const EVIDENCE_PRUNING_TAG: &str = "evidence_pruning";
+
+/// Sources treated as authoritative for Rust research
+const TRUSTED_SOURCES: &[&str] = &[
+    "doc.rust-lang.org",
+    "rust-lang.org",
+    "docs.rs",
+    "crates.io",
+    "rustsec.org",
+    "github.com",
+    "tokio.rs",
+    "developer.mozilla.org",
+];
+
+/// Sources that say nothing about where the evidence came from
+const ANONYMOUS_SOURCES: &[&str] = &["", "unknown", "n/a", "none", "placeholder"];
+
+/// Evidence shorter than this is unlikely to support anything
+const MIN_USEFUL_CONTENT_CHARS: usize = 20;
+
+/// Characters of content kept in a pruning decision
+const PREVIEW_CHARS: usize = 80;
+
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "with", "how", "what", "why", "when", "does", "use", "using", "from",
+    "into", "are", "can", "should", "this", "that", "you", "your",
+];
+
+/// Configuration for evidence scoring
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EvidenceScoringConfig {
+    /// Score and prune evidence before results are returned
+    pub enabled: bool,
+    /// Items scoring below this are pruned (0.0-1.0)
+    pub min_score: f64,
+    /// Keep at most this many items after pruning
+    pub max_items: Option<usize>,
+    /// Weight of relevance in the final score; credibility gets the rest
+    pub relevance_weight: f64,
+}
+
+impl Default for EvidenceScoringConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_score: 0.35,
+            max_items: None,
+            relevance_weight: 0.6,
+        }
+    }
+}
+
+impl EvidenceScoringConfig {
+    pub fn with_min_score(mut self, min_score: f64) -> Self {
+        self.min_score = min_score.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    pub fn with_relevance_weight(mut self, weight: f64) -> Self {
+        self.relevance_weight = weight.clamp(0.0, 1.0);
+        self
+    }
+}
+
+/// Why an evidence item was pruned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PruneReason {
+    /// Score fell below the configured threshold
+    BelowThreshold,
+    /// Same content as an item that was kept
+    Duplicate,
+    /// Scored high enough but exceeded `max_items`
+    OverLimit,
+}
+
+/// Ratings for one evidence item
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvidenceScore {
+    pub relevance: f64,
+    pub credibility: f64,
+    pub score: f64,
+}
+
+/// Audit record for a pruned evidence item
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PruningDecision {
+    pub source: String,
+    pub evidence_type: String,
+    pub preview: String,
+    #[serde(flatten)]
+    pub score: EvidenceScore,
+    pub reason: PruneReason,
+}
+
+/// Outcome of scoring a set of evidence
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvidenceScoringReport {
+    /// Surviving evidence, best first, with `relevance` set to the final score
+    pub kept: Vec<Evidence>,
+    pub pruned: Vec<PruningDecision>,
+}
+
+/// Rates, prunes and orders supporting evidence
+#[derive(Clone, Default)]
+pub struct EvidenceScorer {
+    config: EvidenceScoringConfig,
+    embeddings: Option<Arc<dyn EmbeddingGenerator>>,
+}
+
+impl std::fmt::Debug for EvidenceScorer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EvidenceScorer")
+            .field("config", &self.config)
+            .field("embeddings", &self.embeddings.is_some())
+            .finish()
+    }
+}
+
+impl EvidenceScorer {
+    pub fn new(config: EvidenceScoringConfig) -> Self {
+        Self {
+            config,
+            embeddings: None,
+        }
+    }
+
+    /// Use embedding similarity instead of term overlap for relevance
+    pub fn with_embeddings(mut self, embeddings: Arc<dyn EmbeddingGenerator>) -> Self {
+        self.embeddings = Some(embeddings);
+        self
+    }
+
+    pub fn config(&self) -> &EvidenceScoringConfig {
+        &self.config
+    }
+
+    /// Score `evidence` against `query` and split it into kept and pruned items
+    pub async fn score(&self, query: &str, evidence: Vec<Evidence>) -> EvidenceScoringReport {
+        let similarities = self.similarities(query, &evidence).await;
+
+        let mut scored: Vec<(Evidence, EvidenceScore)> = evidence
+            .into_iter()
+            .zip(similarities)
+            .map(|(item, similarity)| {
+                let score = self.rate(&item, similarity);
+                (item, score)
+            })
+            .collect();
+        // Stable sort keeps provider order for ties
+        scored.sort_by(|a, b| b.1.score.total_cmp(&a.1.score));
+
+        let mut kept = Vec::new();
+        let mut pruned = Vec::new();
+        let mut seen_content = HashSet::new();
+        for (mut item, score) in scored {
+            let reason = if score.score < self.config.min_score {
+                Some(PruneReason::BelowThreshold)
+            } else if !seen_content.insert(normalize(&item.content)) {
+                Some(PruneReason::Duplicate)
+            } else if self.config.max_items.is_some_and(|max| kept.len() >= max) {
+                Some(PruneReason::OverLimit)
+            } else {
+                None
+            };
+
+            match reason {
+                Some(reason) => pruned.push(PruningDecision {
+                    source: item.source,
+                    evidence_type: item.evidence_type,
+                    preview: item.content.chars().take(PREVIEW_CHARS).collect(),
+                    score,
+                    reason,
+                }),
+                None => {
+                    item.relevance = round(score.score);
+                    kept.push(item);
+                }
+            }
+        }
+
+        EvidenceScoringReport { kept, pruned }
+    }
+
+    /// Score the result's evidence in place and record the decisions in its tags
+    pub async fn apply(&self, result: &mut ResearchResult) {
+        if !self.config.enabled || result.supporting_evidence.is_empty() {
+            return;
+        }
+
+        let evidence = std::mem::take(&mut result.supporting_evidence);
+        let report = self.score(&result.request.original_query, evidence).await;
+        debug!(
+            "Evidence scoring kept {} and pruned {} item(s) for: {}",
+            report.kept.len(),
+            report.pruned.len(),
+            result.request.original_query
+        );
+
+        let tags = &mut result.metadata.tags;
+        tags.insert(EVIDENCE_KEPT_TAG.to_string(), report.kept.len().to_string());
+        tags.insert(
+            EVIDENCE_PRUNED_TAG.to_string(),
+            report.pruned.len().to_string(),
+        );
+        if !report.pruned.is_empty() {
+            match serde_json::to_string(&report.pruned) {
+                Ok(decisions) => {
+                    tags.insert(EVIDENCE_PRUNING_TAG.to_string(), decisions);
+                }
+                Err(e) => warn!("Failed to record evidence pruning decisions: {e}"),
+            }
+        }
+        result.supporting_evidence = report.kept;
+    }
+
+    /// Similarity of each item to the query in 0.0-1.0
+    async fn similarities(&self, query: &str, evidence: &[Evidence]) -> Vec<f64> {
+        if let Some(embeddings) = &self.embeddings {
+            let mut texts = Vec::with_capacity(evidence.len() + 1);
+            texts.push(query.to_string());
+            texts.extend(evidence.iter().map(|item| item.content.clone()));
+            match embeddings.generate_embeddings(&texts).await {
+                Ok(vectors) if vectors.len() == texts.len() => {
+                    return vectors[1..]
+                        .iter()
+                        .map(|vector| cosine_similarity(&vectors[0], vector).max(0.0))
+                        .collect();
+                }
+                Ok(_) => warn!("Embedding count mismatch, falling back to term overlap"),
+                Err(e) => warn!("Evidence embedding failed, falling back to term overlap: {e}"),
+            }
+        }
+
+        let query_terms = terms(query);
+        evidence
+            .iter()
+            .map(|item| term_overlap(&query_terms, &format!("{} {}", item.source, item.content)))
+            .collect()
+    }
+
+    fn rate(&self, item: &Evidence, similarity: f64) -> EvidenceScore {
+        let relevance = (similarity + item.relevance.clamp(0.0, 1.0)) / 2.0;
+        let credibility = credibility(item);
+        let weight = self.config.relevance_weight;
+        EvidenceScore {
+            relevance: round(relevance),
+            credibility: round(credibility),
+            score: round(weight * relevance + (1.0 - weight) * credibility),
+        }
+    }
+}
+
+/// Heuristic credibility from the source, evidence type and content
+fn credibility(item: &Evidence) -> f64 {
+    let source = item.source.trim().to_lowercase();
+    let mut credibility: f64 = if ANONYMOUS_SOURCES.contains(&source.as_str()) {
+        0.2
+    } else if TRUSTED_SOURCES
+        .iter()
+        .any(|trusted| source.contains(trusted))
+    {
+        0.9
+    } else if source.starts_with("http://") || source.starts_with("https://") {
+        0.6
+    } else {
+        0.5
+    };
+
+    credibility += match item.evidence_type.to_lowercase().as_str() {
+        "documentation" => 0.1,
+        "reference" => 0.05,
+        _ => 0.0,
+    };
+    if item.content.trim().chars().count() < MIN_USEFUL_CONTENT_CHARS {
+        credibility -= 0.3;
+    }
+    credibility.clamp(0.0, 1.0)
+}
+
+fn terms(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .map(str::to_lowercase)
+        .filter(|term| term.len() > 2 && !STOP_WORDS.contains(&term.as_str()))
+        .collect()
+}
+
+/// Fraction of query terms that appear in `text`
+fn term_overlap(query_terms: &HashSet<String>, text: &str) -> f64 {
+    if query_terms.is_empty() {
+        return 0.5;
+    }
+    let text_terms = terms(text);
+    query_terms.intersection(&text_terms).count() as f64 / query_terms.len() as f64
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        (dot / (norm_a * norm_b)) as f64
+    }
+}
+
+fn normalize(content: &str) -> String {
+    content
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn round(value: f64) -> f64 {
+    (value * 1000.0).round() / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use fortitude_types::{
+        AudienceContext, ClassifiedRequest, DomainContext, ResearchMetadata, ResearchType,
+    };
+    use std::collections::HashMap;
+
+    fn evidence(source: &str, content: &str, relevance: f64, evidence_type: &str) -> Evidence {
+        Evidence {
+            source: source.to_string(),
+            content: content.to_string(),
+            relevance,
+            evidence_type: evidence_type.to_string(),
+        }
+    }
+
+    fn result_with(evidence: Vec<Evidence>) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            "How to handle async errors in tokio".to_string(),
+            ResearchType::Implementation,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 10,
+            sources_consulted: vec![],
+            quality_score: 0.8,
+            cache_key: "key".to_string(),
+            tags: HashMap::new(),
+        };
+        ResearchResult::new(request, "answer".to_string(), evidence, vec![], metadata)
+    }
+
+    #[tokio::test]
+    async fn test_scoring_orders_and_prunes_evidence() {
+        let scorer = EvidenceScorer::new(EvidenceScoringConfig::default());
+        let report = scorer
+            .score(
+                "How to handle async errors in tokio",
+                vec![
+                    evidence("blog", "Unrelated gardening tips", 0.3, "example"),
+                    evidence(
+                        "https://docs.rs/tokio",
+                        "Tokio tasks return a JoinError; handle async errors with ?",
+                        0.7,
+                        "documentation",
+                    ),
+                    evidence(
+                        "https://tokio.rs/tokio/tutorial",
+                        "Handle errors from async tasks in tokio by matching on JoinHandle",
+                        0.9,
+                        "documentation",
+                    ),
+                    evidence("unknown", "tokio", 0.9, "example"),
+                ],
+            )
+            .await;
+
+        let sources: Vec<&str> = report.kept.iter().map(|e| e.source.as_str()).collect();
+        assert_eq!(
+            sources,
+            vec!["https://tokio.rs/tokio/tutorial", "https://docs.rs/tokio"]
+        );
+        assert!(report.kept[0].relevance >= report.kept[1].relevance);
+        assert_eq!(report.pruned.len(), 2);
+        assert!(report
+            .pruned
+            .iter()
+            .all(|decision| decision.reason == PruneReason::BelowThreshold
+                && decision.score.score < 0.35));
+    }
+
+    #[tokio::test]
+    async fn test_duplicates_and_limit_are_pruned() {
+        let scorer = EvidenceScorer::new(EvidenceScoringConfig::default().with_max_items(1));
+        let content = "Use tokio::select! to handle async errors from several tasks";
+        let report = scorer
+            .score(
+                "handle async errors tokio",
+                vec![
+                    evidence("https://docs.rs/tokio", content, 0.9, "documentation"),
+                    evidence("https://github.com/tokio-rs", content, 0.9, "reference"),
+                    evidence(
+                        "https://tokio.rs",
+                        "Async errors in tokio propagate through JoinHandle",
+                        0.8,
+                        "documentation",
+                    ),
+                ],
+            )
+            .await;
+
+        assert_eq!(report.kept.len(), 1);
+        let reasons: Vec<PruneReason> = report.pruned.iter().map(|d| d.reason).collect();
+        assert!(reasons.contains(&PruneReason::Duplicate));
+        assert!(reasons.contains(&PruneReason::OverLimit));
+    }
+
+    #[tokio::test]
+    async fn test_apply_records_pruning_in_metadata() {
+        let scorer = EvidenceScorer::new(EvidenceScoringConfig::default());
+        let mut result = result_with(vec![
+            evidence(
+                "https://docs.rs/tokio",
+                "Handle async errors in tokio tasks with JoinHandle results",
+                0.8,
+                "documentation",
+            ),
+            evidence("", "n/a", 0.1, "example"),
+        ]);
+
+        scorer.apply(&mut result).await;
+
+        assert_eq!(result.supporting_evidence.len(), 1);
+        assert_eq!(result.metadata.tags[EVIDENCE_KEPT_TAG], "1");
+        assert_eq!(result.metadata.tags[EVIDENCE_PRUNED_TAG], "1");
+        let decisions: Vec<PruningDecision> =
+            serde_json::from_str(&result.metadata.tags[EVIDENCE_PRUNING_TAG]).unwrap();
+        assert_eq!(decisions[0].preview, "n/a");
+        assert_eq!(decisions[0].reason, PruneReason::BelowThreshold);
+
+        // Disabled scoring leaves evidence untouched
+        let disabled = EvidenceScorer::new(EvidenceScoringConfig {
+            enabled: false,
+            ..Default::default()
+        });
+        let mut untouched = result_with(vec![evidence("", "n/a", 0.1, "example")]);
+        disabled.apply(&mut untouched).await;
+        assert_eq!(untouched.supporting_evidence.len(), 1);
+        assert!(untouched.metadata.tags.is_empty());
+    }
+}
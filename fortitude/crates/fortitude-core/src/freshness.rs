@@ -0,0 +1,487 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Per-research-type freshness windows and refresh of stale cached results
+//! Each research type has a freshness window: troubleshooting answers go
+//! stale within days, decision records last for months. [`refresh_stale`]
+//! re-runs the queries of cached results older than their window, compares
+//! the new answer with the old one and records the comparison in the new
+//! result's metadata tags. [`spawn_scheduled_refresh`] does the same on an
+//! interval in the background.
+
+use crate::pipeline::{ResearchOptions, ResearchPipeline};
+use chrono::{DateTime, Duration, Utc};
+use fortitude_types::{CacheEntry, PipelineError, ResearchResult, ResearchType};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// Metadata tag holding the RFC 3339 time a result was last refreshed
+pub const REFRESHED_AT_TAG: &str = "refreshed_at";
+/// Metadata tag holding the word similarity between the refreshed and previous answer
+pub const REFRESH_SIMILARITY_TAG: &str = "refresh_similarity";
+/// Metadata tag set to `significant` or `minor` after a refresh
+pub const REFRESH_CHANGE_TAG: &str = "refresh_change";
+
+/// Freshness windows per research type and limits for a refresh run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FreshnessPolicy {
+    /// Days a decision result stays fresh
+    pub decision_days: u32,
+    /// Days an implementation result stays fresh
+    pub implementation_days: u32,
+    /// Days a troubleshooting result stays fresh
+    pub troubleshooting_days: u32,
+    /// Days a learning result stays fresh
+    pub learning_days: u32,
+    /// Days a validation result stays fresh
+    pub validation_days: u32,
+    /// Refreshed answers less similar than this to the old one are significant changes (0.0-1.0)
+    pub significant_change_threshold: f64,
+    /// Most entries refreshed in one run, oldest first
+    pub max_refreshes_per_run: usize,
+}
+
+impl Default for FreshnessPolicy {
+    fn default() -> Self {
+        Self {
+            decision_days: 180,
+            implementation_days: 30,
+            troubleshooting_days: 7,
+            learning_days: 90,
+            validation_days: 30,
+            significant_change_threshold: 0.5,
+            max_refreshes_per_run: 20,
+        }
+    }
+}
+
+impl FreshnessPolicy {
+    pub fn with_window_days(mut self, research_type: &ResearchType, days: u32) -> Self {
+        match research_type {
+            ResearchType::Decision => self.decision_days = days,
+            ResearchType::Implementation => self.implementation_days = days,
+            ResearchType::Troubleshooting => self.troubleshooting_days = days,
+            ResearchType::Learning => self.learning_days = days,
+            ResearchType::Validation => self.validation_days = days,
+        }
+        self
+    }
+
+    pub fn with_significant_change_threshold(mut self, threshold: f64) -> Self {
+        self.significant_change_threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_max_refreshes_per_run(mut self, max_refreshes_per_run: usize) -> Self {
+        self.max_refreshes_per_run = max_refreshes_per_run;
+        self
+    }
+
+    /// How long a result of this type stays fresh
+    pub fn window_for(&self, research_type: &ResearchType) -> Duration {
+        let days = match research_type {
+            ResearchType::Decision => self.decision_days,
+            ResearchType::Implementation => self.implementation_days,
+            ResearchType::Troubleshooting => self.troubleshooting_days,
+            ResearchType::Learning => self.learning_days,
+            ResearchType::Validation => self.validation_days,
+        };
+        Duration::days(i64::from(days))
+    }
+
+    /// Whether a cache entry has outlived its freshness window at `now`
+    pub fn is_stale(&self, entry: &CacheEntry, now: DateTime<Utc>) -> bool {
+        now - entry.created_at > self.window_for(&entry.research_type)
+    }
+
+    /// Check that windows are non-zero and the threshold is a valid score
+    pub fn validate(&self) -> Result<(), String> {
+        for research_type in ResearchType::all() {
+            if self.window_for(&research_type).is_zero() {
+                return Err(format!(
+                    "freshness window for {} must be at least one day",
+                    research_type.display_name()
+                ));
+            }
+        }
+        if !(0.0..=1.0).contains(&self.significant_change_threshold) {
+            return Err(format!(
+                "significant_change_threshold must be between 0.0 and 1.0, got {}",
+                self.significant_change_threshold
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Comparison of a refreshed answer with the one it replaces
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnswerDiff {
+    /// Jaccard similarity of the answers' word sets (0.0-1.0)
+    pub similarity: f64,
+    /// Evidence sources cited only by the new result
+    pub added_sources: Vec<String>,
+    /// Evidence sources the new result no longer cites
+    pub removed_sources: Vec<String>,
+    /// Similarity fell below the policy's threshold
+    pub significant: bool,
+}
+
+impl AnswerDiff {
+    pub fn between(old: &ResearchResult, new: &ResearchResult, threshold: f64) -> Self {
+        let similarity = word_similarity(&old.immediate_answer, &new.immediate_answer);
+        let old_sources = evidence_sources(old);
+        let new_sources = evidence_sources(new);
+        let mut added_sources: Vec<String> = new_sources
+            .difference(&old_sources)
+            .map(|s| s.to_string())
+            .collect();
+        let mut removed_sources: Vec<String> = old_sources
+            .difference(&new_sources)
+            .map(|s| s.to_string())
+            .collect();
+        added_sources.sort();
+        removed_sources.sort();
+
+        Self {
+            similarity,
+            added_sources,
+            removed_sources,
+            significant: similarity < threshold,
+        }
+    }
+
+    /// Record the comparison in a result's metadata tags
+    pub fn apply_to_tags(
+        &self,
+        tags: &mut std::collections::HashMap<String, String>,
+        now: DateTime<Utc>,
+    ) {
+        tags.insert(REFRESHED_AT_TAG.to_string(), now.to_rfc3339());
+        tags.insert(
+            REFRESH_SIMILARITY_TAG.to_string(),
+            format!("{:.2}", self.similarity),
+        );
+        let change = if self.significant {
+            "significant"
+        } else {
+            "minor"
+        };
+        tags.insert(REFRESH_CHANGE_TAG.to_string(), change.to_string());
+    }
+}
+
+fn words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+fn word_similarity(a: &str, b: &str) -> f64 {
+    let a = words(a);
+    let b = words(b);
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    a.intersection(&b).count() as f64 / a.union(&b).count() as f64
+}
+
+fn evidence_sources(result: &ResearchResult) -> HashSet<&str> {
+    result
+        .supporting_evidence
+        .iter()
+        .map(|e| e.source.as_str())
+        .collect()
+}
+
+/// Outcome of refreshing one stale entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RefreshOutcome {
+    pub cache_key: String,
+    pub query: String,
+    pub research_type: ResearchType,
+    /// Age of the replaced entry in days
+    pub age_days: i64,
+    /// Comparison with the previous answer, absent on a dry run or failure
+    pub diff: Option<AnswerDiff>,
+    /// Cache key of the refreshed result
+    pub new_cache_key: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Summary of a refresh run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RefreshReport {
+    /// Cache entries looked at
+    pub checked: usize,
+    /// Entries past their freshness window
+    pub stale: usize,
+    /// Stale entries left for a later run because of `max_refreshes_per_run`
+    pub deferred: usize,
+    pub outcomes: Vec<RefreshOutcome>,
+}
+
+impl RefreshReport {
+    /// Outcomes whose answer changed significantly
+    pub fn significant_changes(&self) -> impl Iterator<Item = &RefreshOutcome> {
+        self.outcomes
+            .iter()
+            .filter(|o| o.diff.as_ref().is_some_and(|d| d.significant))
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &RefreshOutcome> {
+        self.outcomes.iter().filter(|o| o.error.is_some())
+    }
+}
+
+/// Cache entries past their freshness window, oldest first
+pub async fn stale_entries(
+    pipeline: &ResearchPipeline,
+    policy: &FreshnessPolicy,
+    now: DateTime<Utc>,
+) -> Result<(usize, Vec<CacheEntry>), PipelineError> {
+    let entries =
+        pipeline
+            .storage()
+            .list_cache_entries()
+            .await
+            .map_err(|e| PipelineError::StageFailed {
+                stage: "freshness".to_string(),
+                error: e.to_string(),
+            })?;
+    let checked = entries.len();
+    let mut stale: Vec<CacheEntry> = entries
+        .into_iter()
+        .filter(|entry| policy.is_stale(entry, now))
+        .collect();
+    stale.sort_by_key(|entry| entry.created_at);
+    Ok((checked, stale))
+}
+
+/// Re-run the queries of stale entries and replace them with the new results
+///
+/// On a dry run the stale entries are reported without researching them.
+pub async fn refresh_stale(
+    pipeline: &ResearchPipeline,
+    policy: &FreshnessPolicy,
+    dry_run: bool,
+) -> Result<RefreshReport, PipelineError> {
+    let now = Utc::now();
+    let (checked, stale) = stale_entries(pipeline, policy, now).await?;
+    let mut report = RefreshReport {
+        checked,
+        stale: stale.len(),
+        deferred: stale.len().saturating_sub(policy.max_refreshes_per_run),
+        outcomes: Vec::new(),
+    };
+
+    for entry in stale.into_iter().take(policy.max_refreshes_per_run) {
+        let mut outcome = RefreshOutcome {
+            cache_key: entry.key.clone(),
+            query: entry.original_query.clone(),
+            research_type: entry.research_type.clone(),
+            age_days: (now - entry.created_at).num_days(),
+            diff: None,
+            new_cache_key: None,
+            error: None,
+        };
+        if !dry_run {
+            match refresh_entry(pipeline, policy, &entry, now).await {
+                Ok((diff, new_cache_key)) => {
+                    outcome.diff = Some(diff);
+                    outcome.new_cache_key = Some(new_cache_key);
+                }
+                Err(e) => {
+                    warn!("Failed to refresh '{}': {}", entry.original_query, e);
+                    outcome.error = Some(e.to_string());
+                }
+            }
+        }
+        report.outcomes.push(outcome);
+    }
+    Ok(report)
+}
+
+async fn refresh_entry(
+    pipeline: &ResearchPipeline,
+    policy: &FreshnessPolicy,
+    entry: &CacheEntry,
+    now: DateTime<Utc>,
+) -> Result<(AnswerDiff, String), PipelineError> {
+    let storage = pipeline.storage();
+    let stage_error = |e: fortitude_types::StorageError| PipelineError::StageFailed {
+        stage: "freshness".to_string(),
+        error: e.to_string(),
+    };
+    let old = storage
+        .retrieve(&entry.key)
+        .await
+        .map_err(stage_error)?
+        .ok_or_else(|| PipelineError::StageFailed {
+            stage: "freshness".to_string(),
+            error: format!("cached result {} is missing", entry.key),
+        })?;
+
+    let mut new = pipeline
+        .process_query_with_options(
+            &old.request.original_query,
+            ResearchOptions::default().with_bypass_cache(true),
+            Some(old.request.audience_context.clone()),
+            Some(old.request.domain_context.clone()),
+        )
+        .await?;
+
+    let diff = AnswerDiff::between(&old, &new, policy.significant_change_threshold);
+    diff.apply_to_tags(&mut new.metadata.tags, now);
+    let new_cache_key = storage.store(&new).await.map_err(stage_error)?;
+    if new_cache_key != entry.key {
+        storage.delete(&entry.key).await.map_err(stage_error)?;
+    }
+    info!(
+        "Refreshed '{}' (similarity {:.2}{})",
+        entry.original_query,
+        diff.similarity,
+        if diff.significant {
+            ", significant change"
+        } else {
+            ""
+        }
+    );
+    Ok((diff, new_cache_key))
+}
+
+/// Refresh stale entries every `interval` until the returned task is aborted
+pub fn spawn_scheduled_refresh(
+    pipeline: Arc<ResearchPipeline>,
+    policy: FreshnessPolicy,
+    interval: std::time::Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match refresh_stale(&pipeline, &policy, false).await {
+                Ok(report) => info!(
+                    "Scheduled refresh: {} stale of {}, {} refreshed, {} significant changes, {} deferred",
+                    report.stale,
+                    report.checked,
+                    report.outcomes.len() - report.failures().count(),
+                    report.significant_changes().count(),
+                    report.deferred
+                ),
+                Err(e) => warn!("Scheduled refresh failed: {}", e),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_types::{
+        AudienceContext, ClassifiedRequest, DomainContext, Evidence, ResearchMetadata,
+    };
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn entry(research_type: ResearchType, age_days: i64, now: DateTime<Utc>) -> CacheEntry {
+        let mut entry = CacheEntry::new(
+            "key".to_string(),
+            PathBuf::from("key.json"),
+            research_type,
+            "query".to_string(),
+            0,
+            "hash".to_string(),
+            3600,
+        );
+        entry.created_at = now - Duration::days(age_days);
+        entry
+    }
+
+    fn result(answer: &str, sources: &[&str]) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            "query".to_string(),
+            ResearchType::Implementation,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 0,
+            sources_consulted: vec![],
+            quality_score: 0.8,
+            cache_key: "key".to_string(),
+            tags: HashMap::new(),
+        };
+        let evidence = sources
+            .iter()
+            .map(|source| Evidence {
+                source: source.to_string(),
+                content: String::new(),
+                relevance: 0.8,
+                evidence_type: "documentation".to_string(),
+            })
+            .collect();
+        ResearchResult::new(request, answer.to_string(), evidence, vec![], metadata)
+    }
+
+    #[test]
+    fn test_windows_differ_by_research_type() {
+        let policy = FreshnessPolicy::default();
+        let now = Utc::now();
+        assert!(policy.is_stale(&entry(ResearchType::Troubleshooting, 10, now), now));
+        assert!(!policy.is_stale(&entry(ResearchType::Decision, 10, now), now));
+        assert!(policy.is_stale(&entry(ResearchType::Decision, 200, now), now));
+
+        let policy = policy.with_window_days(&ResearchType::Decision, 5);
+        assert!(policy.is_stale(&entry(ResearchType::Decision, 10, now), now));
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_window() {
+        assert!(FreshnessPolicy::default().validate().is_ok());
+        let policy = FreshnessPolicy::default().with_window_days(&ResearchType::Learning, 0);
+        assert!(policy.validate().unwrap_err().contains("Learning"));
+    }
+
+    #[test]
+    fn test_answer_diff_flags_significant_changes() {
+        let old = result("Use tokio::spawn for the task", &["docs.rs/tokio", "blog"]);
+        let same = result("Use tokio::spawn for the task.", &["docs.rs/tokio", "blog"]);
+        let diff = AnswerDiff::between(&old, &same, 0.5);
+        assert_eq!(diff.similarity, 1.0);
+        assert!(!diff.significant);
+
+        let changed = result(
+            "Prefer a JoinSet to track spawned work",
+            &["docs.rs/tokio", "rfc"],
+        );
+        let diff = AnswerDiff::between(&old, &changed, 0.5);
+        assert!(diff.significant);
+        assert_eq!(diff.added_sources, vec!["rfc"]);
+        assert_eq!(diff.removed_sources, vec!["blog"]);
+
+        let mut tags = HashMap::new();
+        diff.apply_to_tags(&mut tags, Utc::now());
+        assert_eq!(tags[REFRESH_CHANGE_TAG], "significant");
+        assert!(tags.contains_key(REFRESHED_AT_TAG));
+    }
+}
@@ -0,0 +1,258 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Inverted keyword index with BM25 ranking over cached research results
+//! [`KeywordIndex`] maps terms to the cached results containing them and
+//! scores matches with BM25. Storage backends keep one next to their search
+//! index entries and update it on every write and delete, so keyword search
+//! no longer scans every entry's content.
+//!
+//! A result's document is its original query (counted twice, so query matches
+//! outrank passing mentions), keywords, tags and content.
+
+use fortitude_types::IndexEntry;
+use std::collections::{HashMap, HashSet};
+
+/// BM25 term frequency saturation
+const K1: f64 = 1.2;
+
+/// BM25 document length normalization
+const B: f64 = 0.75;
+
+/// Marker wrapped around matched terms in highlighted snippets
+pub const HIGHLIGHT_MARKER: &str = "**";
+
+/// Inverted index from terms to the results containing them
+#[derive(Debug, Clone, Default)]
+pub struct KeywordIndex {
+    /// Term -> cache key -> occurrences
+    postings: HashMap<String, HashMap<String, u32>>,
+    /// Cache key -> distinct terms, for removal
+    documents: HashMap<String, Vec<String>>,
+    /// Cache key -> document length in terms
+    lengths: HashMap<String, usize>,
+    total_length: usize,
+}
+
+impl KeywordIndex {
+    /// Create an empty index
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build an index over existing search index entries
+    pub fn from_entries<'a>(entries: impl IntoIterator<Item = &'a IndexEntry>) -> Self {
+        let mut index = Self::new();
+        for entry in entries {
+            index.insert(entry);
+        }
+        index
+    }
+
+    /// Number of indexed results
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    /// Whether no results are indexed
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Index an entry, replacing any earlier version with the same cache key
+    pub fn insert(&mut self, entry: &IndexEntry) {
+        self.remove(&entry.cache_key);
+
+        let mut terms = tokenize(&entry.original_query);
+        terms.extend(tokenize(&entry.original_query));
+        for keyword in entry.keywords.iter().chain(&entry.tags) {
+            terms.extend(tokenize(keyword));
+        }
+        terms.extend(tokenize(&entry.content));
+
+        let mut frequencies: HashMap<String, u32> = HashMap::new();
+        for term in &terms {
+            *frequencies.entry(term.clone()).or_default() += 1;
+        }
+        for (term, frequency) in &frequencies {
+            self.postings
+                .entry(term.clone())
+                .or_default()
+                .insert(entry.cache_key.clone(), *frequency);
+        }
+        self.documents
+            .insert(entry.cache_key.clone(), frequencies.into_keys().collect());
+        self.lengths.insert(entry.cache_key.clone(), terms.len());
+        self.total_length += terms.len();
+    }
+
+    /// Drop a result from the index
+    pub fn remove(&mut self, cache_key: &str) {
+        let Some(terms) = self.documents.remove(cache_key) else {
+            return;
+        };
+        for term in terms {
+            if let Some(posting) = self.postings.get_mut(&term) {
+                posting.remove(cache_key);
+                if posting.is_empty() {
+                    self.postings.remove(&term);
+                }
+            }
+        }
+        if let Some(length) = self.lengths.remove(cache_key) {
+            self.total_length -= length;
+        }
+    }
+
+    /// Whether a result contains a term
+    pub fn contains(&self, cache_key: &str, term: &str) -> bool {
+        self.postings
+            .get(term)
+            .is_some_and(|posting| posting.contains_key(cache_key))
+    }
+
+    /// BM25 scores of the results containing at least one of `terms`
+    pub fn score(&self, terms: &[String]) -> HashMap<String, f64> {
+        let mut scores = HashMap::new();
+        if self.documents.is_empty() {
+            return scores;
+        }
+        let documents = self.documents.len() as f64;
+        let average_length = self.total_length as f64 / documents;
+
+        let unique: HashSet<&String> = terms.iter().collect();
+        for term in unique {
+            let Some(posting) = self.postings.get(term) else {
+                continue;
+            };
+            let frequency = posting.len() as f64;
+            let idf = (1.0 + (documents - frequency + 0.5) / (frequency + 0.5)).ln();
+            for (cache_key, occurrences) in posting {
+                let occurrences = *occurrences as f64;
+                let length = self.lengths.get(cache_key).copied().unwrap_or_default() as f64;
+                let normalization = K1 * (1.0 - B + B * length / average_length.max(1.0));
+                *scores.entry(cache_key.clone()).or_insert(0.0) +=
+                    idf * occurrences * (K1 + 1.0) / (occurrences + normalization);
+            }
+        }
+        scores
+    }
+}
+
+/// Split text into lowercase alphanumeric terms
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|term| !term.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Wrap the words of `text` that are among `terms` in [`HIGHLIGHT_MARKER`]
+pub fn highlight_terms(text: &str, terms: &[String]) -> String {
+    let terms: HashSet<&str> = terms.iter().map(String::as_str).collect();
+    let mut highlighted = String::with_capacity(text.len());
+    let mut word = String::new();
+    let flush = |word: &mut String, out: &mut String| {
+        if terms.contains(word.to_lowercase().as_str()) {
+            out.push_str(HIGHLIGHT_MARKER);
+            out.push_str(word);
+            out.push_str(HIGHLIGHT_MARKER);
+        } else {
+            out.push_str(word);
+        }
+        word.clear();
+    };
+
+    for c in text.chars() {
+        if c.is_alphanumeric() || c == '_' {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut highlighted);
+            highlighted.push(c);
+        }
+    }
+    flush(&mut word, &mut highlighted);
+    highlighted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_types::ResearchType;
+
+    fn entry(cache_key: &str, query: &str, content: &str) -> IndexEntry {
+        IndexEntry::new(
+            cache_key.to_string(),
+            ResearchType::Implementation,
+            query.to_string(),
+            content.to_string(),
+            vec![],
+            vec![],
+            0.8,
+        )
+    }
+
+    #[test]
+    fn test_bm25_ranks_focused_results_higher() {
+        let index = KeywordIndex::from_entries(&[
+            entry(
+                "focused",
+                "Tokio runtime",
+                "Tokio schedules tasks on the tokio runtime.",
+            ),
+            entry(
+                "passing",
+                "Web frameworks",
+                "Axum, actix and warp; axum builds on tokio among many other crates.",
+            ),
+            entry(
+                "unrelated",
+                "Serde derive",
+                "Derive Serialize and Deserialize.",
+            ),
+        ]);
+
+        let scores = index.score(&["tokio".to_string()]);
+        assert_eq!(scores.len(), 2);
+        assert!(scores["focused"] > scores["passing"]);
+        assert!(!scores.contains_key("unrelated"));
+    }
+
+    #[test]
+    fn test_insert_replaces_and_remove_drops_postings() {
+        let mut index = KeywordIndex::new();
+        index.insert(&entry("key", "Pinning", "Use Box::pin"));
+        index.insert(&entry("key", "Pinning", "Use the pin! macro"));
+        assert_eq!(index.len(), 1);
+        assert!(index.contains("key", "macro"));
+        assert!(!index.contains("key", "box"));
+
+        index.remove("key");
+        assert!(index.is_empty());
+        assert!(index.score(&["pin".to_string()]).is_empty());
+        assert_eq!(index.total_length, 0);
+    }
+
+    #[test]
+    fn test_highlight_terms() {
+        assert_eq!(
+            highlight_terms("Use Box::pin to pin a future.", &["pin".to_string()]),
+            "Use Box::**pin** to **pin** a future."
+        );
+        assert_eq!(
+            highlight_terms("Pinned futures", &["pin".to_string()]),
+            "Pinned futures"
+        );
+    }
+}
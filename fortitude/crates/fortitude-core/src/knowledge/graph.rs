@@ -0,0 +1,766 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Graph of research results linked by shared tags, cited sources and similarity
+//! Every stored result is a node. Two results are linked when they share
+//! domain tags, cite the same sources, or ask and answer similar things; the
+//! edge between them records each relation found with its score. Similarity
+//! compares embeddings when an [`EmbeddingGenerator`] is given and the words
+//! of query and answer otherwise. [`KnowledgeGraphStore`] saves the graph in
+//! the reference library's index and rebuilds it once the stored results change.
+
+use crate::atomic_file::write_json_atomic;
+use crate::citations::extract_citations;
+use crate::vector::EmbeddingGenerator;
+use chrono::{DateTime, Utc};
+use fortitude_types::{CacheEntry, ResearchResult, ResearchType, Storage};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+/// Graph file, relative to the storage base path
+pub const KNOWLEDGE_GRAPH_FILE: &str = "index/knowledge_graph.json";
+
+/// Most nodes a neighborhood query starts from
+const MAX_SEEDS: usize = 5;
+
+#[derive(Error, Debug)]
+pub enum KnowledgeGraphError {
+    #[error("Failed to read stored results: {0}")]
+    Storage(String),
+
+    #[error("Failed to embed results: {0}")]
+    Embedding(String),
+
+    #[error("Knowledge graph file {path}: {error}")]
+    File { path: String, error: String },
+}
+
+/// Why two results are linked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelationKind {
+    SharedTags,
+    CitationOverlap,
+    SemanticSimilarity,
+}
+
+impl std::fmt::Display for RelationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            RelationKind::SharedTags => "shared_tags",
+            RelationKind::CitationOverlap => "citation_overlap",
+            RelationKind::SemanticSimilarity => "semantic_similarity",
+        })
+    }
+}
+
+/// Thresholds deciding which results are linked
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GraphConfig {
+    /// Least Jaccard overlap of two results' domain tags that links them (0.0-1.0)
+    pub min_tag_overlap: f64,
+    /// Least Jaccard overlap of two results' cited sources that links them (0.0-1.0)
+    pub min_citation_overlap: f64,
+    /// Least cosine similarity of two results' embeddings that links them
+    pub min_semantic_similarity: f64,
+    /// Least word overlap of query and answer that links two results without embeddings
+    pub min_lexical_similarity: f64,
+    /// Strongest edges kept per result; an edge stays while either end keeps it
+    pub max_edges_per_node: usize,
+}
+
+impl Default for GraphConfig {
+    fn default() -> Self {
+        Self {
+            min_tag_overlap: 0.25,
+            min_citation_overlap: 0.2,
+            min_semantic_similarity: 0.8,
+            min_lexical_similarity: 0.4,
+            max_edges_per_node: 10,
+        }
+    }
+}
+
+impl GraphConfig {
+    /// Check that every threshold is a score and edges are kept
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, value) in [
+            ("min_tag_overlap", self.min_tag_overlap),
+            ("min_citation_overlap", self.min_citation_overlap),
+            ("min_semantic_similarity", self.min_semantic_similarity),
+            ("min_lexical_similarity", self.min_lexical_similarity),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(format!("{name} must be between 0.0 and 1.0, got {value}"));
+            }
+        }
+        if self.max_edges_per_node == 0 {
+            return Err("max_edges_per_node must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// A stored research result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphNode {
+    /// Cache key of the result
+    pub id: String,
+    pub query: String,
+    pub research_type: ResearchType,
+    pub tags: Vec<String>,
+    pub quality_score: f64,
+    pub completed_at: DateTime<Utc>,
+}
+
+/// One reason two results are linked
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Relation {
+    pub kind: RelationKind,
+    /// Strength of the relation (0.0-1.0)
+    pub score: f64,
+    /// Tags or sources both results have; empty for similarity
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shared: Vec<String>,
+}
+
+/// Link between two results, `source` sorting before `target`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphEdge {
+    pub source: String,
+    pub target: String,
+    /// Score of the strongest relation
+    pub weight: f64,
+    pub relations: Vec<Relation>,
+}
+
+impl GraphEdge {
+    /// The end of the edge that is not `id`, if the edge touches `id`
+    pub fn other(&self, id: &str) -> Option<&str> {
+        if self.source == id {
+            Some(&self.target)
+        } else if self.target == id {
+            Some(&self.source)
+        } else {
+            None
+        }
+    }
+}
+
+/// Result reached from the query, with its distance in edges
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NeighborhoodNode {
+    #[serde(flatten)]
+    pub node: GraphNode,
+    pub distance: usize,
+}
+
+/// Results within `depth` edges of those matching a query
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Neighborhood {
+    pub query: String,
+    pub depth: usize,
+    /// Results matching the query, at distance 0
+    pub seeds: Vec<String>,
+    /// Nearest first
+    pub nodes: Vec<NeighborhoodNode>,
+    /// Edges between the listed nodes
+    pub edges: Vec<GraphEdge>,
+}
+
+/// Research results and the relations between them
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KnowledgeGraph {
+    pub built_at: DateTime<Utc>,
+    /// Sorted by ID
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+impl KnowledgeGraph {
+    /// Link every pair of results that is related enough under `config`
+    ///
+    /// Results are compared pairwise, so building takes time quadratic in
+    /// the number of results.
+    pub async fn build(
+        results: &[ResearchResult],
+        config: &GraphConfig,
+        embeddings: Option<&dyn EmbeddingGenerator>,
+    ) -> Result<Self, KnowledgeGraphError> {
+        let mut results: Vec<&ResearchResult> = results.iter().collect();
+        results.sort_by(|a, b| a.cache_key().cmp(b.cache_key()));
+
+        let tags: Vec<HashSet<String>> = results.iter().map(|r| result_tags(r)).collect();
+        let sources: Vec<HashSet<String>> = results.iter().map(|r| cited_sources(r)).collect();
+        let texts: Vec<String> = results
+            .iter()
+            .map(|r| format!("{}\n{}", r.original_query(), r.immediate_answer))
+            .collect();
+        let similarity = match embeddings {
+            Some(generator) => Similarity::Embeddings(
+                generator
+                    .generate_embeddings(&texts)
+                    .await
+                    .map_err(|e| KnowledgeGraphError::Embedding(e.to_string()))?,
+                config.min_semantic_similarity,
+            ),
+            None => Similarity::Words(
+                texts.iter().map(|text| words(text)).collect(),
+                config.min_lexical_similarity,
+            ),
+        };
+
+        let mut edges = Vec::new();
+        for i in 0..results.len() {
+            for j in (i + 1)..results.len() {
+                let mut relations = Vec::new();
+                if let Some(relation) = overlap(
+                    RelationKind::SharedTags,
+                    &tags[i],
+                    &tags[j],
+                    config.min_tag_overlap,
+                ) {
+                    relations.push(relation);
+                }
+                if let Some(relation) = overlap(
+                    RelationKind::CitationOverlap,
+                    &sources[i],
+                    &sources[j],
+                    config.min_citation_overlap,
+                ) {
+                    relations.push(relation);
+                }
+                if let Some(score) = similarity.between(i, j) {
+                    relations.push(Relation {
+                        kind: RelationKind::SemanticSimilarity,
+                        score,
+                        shared: Vec::new(),
+                    });
+                }
+                if relations.is_empty() {
+                    continue;
+                }
+                let weight = relations.iter().map(|r| r.score).fold(0.0, f64::max);
+                edges.push(GraphEdge {
+                    source: results[i].cache_key().to_string(),
+                    target: results[j].cache_key().to_string(),
+                    weight,
+                    relations,
+                });
+            }
+        }
+
+        Ok(Self {
+            built_at: Utc::now(),
+            nodes: results.iter().map(|r| graph_node(r)).collect(),
+            edges: strongest_edges(edges, config.max_edges_per_node),
+        })
+    }
+
+    pub fn node(&self, id: &str) -> Option<&GraphNode> {
+        self.nodes
+            .binary_search_by(|node| node.id.as_str().cmp(id))
+            .ok()
+            .map(|index| &self.nodes[index])
+    }
+
+    /// Whether the graph covers exactly `entries`, none stored after it was built
+    pub fn is_current(&self, entries: &[CacheEntry]) -> bool {
+        let stored: BTreeSet<&str> = entries.iter().map(|e| e.key.as_str()).collect();
+        let graphed: BTreeSet<&str> = self.nodes.iter().map(|n| n.id.as_str()).collect();
+        stored == graphed && entries.iter().all(|e| e.created_at <= self.built_at)
+    }
+
+    /// Results whose ID is `query`, else those whose query and tags share the most words with it
+    pub fn find(&self, query: &str) -> Vec<&GraphNode> {
+        if let Some(node) = self.node(query.trim()) {
+            return vec![node];
+        }
+        let wanted = words(query);
+        let scored: Vec<(usize, &GraphNode)> = self
+            .nodes
+            .iter()
+            .map(|node| {
+                let mut node_words = words(&node.query);
+                node_words.extend(node.tags.iter().flat_map(|tag| words(tag)));
+                (wanted.intersection(&node_words).count(), node)
+            })
+            .filter(|(score, _)| *score > 0)
+            .collect();
+        let best = scored.iter().map(|(score, _)| *score).max().unwrap_or(0);
+        scored
+            .into_iter()
+            .filter(|(score, _)| *score == best)
+            .map(|(_, node)| node)
+            .take(MAX_SEEDS)
+            .collect()
+    }
+
+    /// Results within `depth` edges of those matching `query`
+    pub fn neighborhood(&self, query: &str, depth: usize) -> Neighborhood {
+        let seeds: Vec<String> = self.find(query).iter().map(|n| n.id.clone()).collect();
+
+        let mut distances: HashMap<&str, usize> = HashMap::new();
+        let mut frontier: VecDeque<&str> = VecDeque::new();
+        for seed in &seeds {
+            distances.insert(seed, 0);
+            frontier.push_back(seed);
+        }
+        while let Some(id) = frontier.pop_front() {
+            let distance = distances[id];
+            if distance == depth {
+                continue;
+            }
+            for edge in &self.edges {
+                if let Some(next) = edge.other(id) {
+                    if !distances.contains_key(next) {
+                        distances.insert(next, distance + 1);
+                        frontier.push_back(next);
+                    }
+                }
+            }
+        }
+
+        let mut nodes: Vec<NeighborhoodNode> = distances
+            .iter()
+            .filter_map(|(id, distance)| {
+                Some(NeighborhoodNode {
+                    node: self.node(id)?.clone(),
+                    distance: *distance,
+                })
+            })
+            .collect();
+        nodes.sort_by(|a, b| (a.distance, &a.node.id).cmp(&(b.distance, &b.node.id)));
+        let edges = self
+            .edges
+            .iter()
+            .filter(|e| {
+                distances.contains_key(e.source.as_str())
+                    && distances.contains_key(e.target.as_str())
+            })
+            .cloned()
+            .collect();
+
+        Neighborhood {
+            query: query.to_string(),
+            depth,
+            seeds,
+            nodes,
+            edges,
+        }
+    }
+
+    /// Load a saved graph; `None` if there is none yet
+    pub fn load(path: &Path) -> Result<Option<Self>, KnowledgeGraphError> {
+        let content = match std::fs::read(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(file_error(path, e)),
+        };
+        serde_json::from_slice(&content)
+            .map(Some)
+            .map_err(|e| file_error(path, e))
+    }
+
+    /// Replace the graph file atomically
+    pub fn save(&self, path: &Path) -> Result<(), KnowledgeGraphError> {
+        write_json_atomic(path, self).map_err(|e| file_error(path, e))
+    }
+}
+
+fn file_error(path: &Path, error: impl std::fmt::Display) -> KnowledgeGraphError {
+    KnowledgeGraphError::File {
+        path: path.display().to_string(),
+        error: error.to_string(),
+    }
+}
+
+enum Similarity {
+    Embeddings(Vec<Vec<f32>>, f64),
+    Words(Vec<HashSet<String>>, f64),
+}
+
+impl Similarity {
+    /// Similarity of two results when it reaches the threshold
+    fn between(&self, i: usize, j: usize) -> Option<f64> {
+        let (score, threshold) = match self {
+            Similarity::Embeddings(vectors, threshold) => {
+                (cosine_similarity(&vectors[i], &vectors[j]), *threshold)
+            }
+            Similarity::Words(words, threshold) => (jaccard(&words[i], &words[j]), *threshold),
+        };
+        (score >= threshold && score > 0.0).then_some(score)
+    }
+}
+
+fn graph_node(result: &ResearchResult) -> GraphNode {
+    GraphNode {
+        id: result.cache_key().to_string(),
+        query: result.original_query().to_string(),
+        research_type: result.research_type().clone(),
+        tags: result.request.domain_context.tags.clone(),
+        quality_score: result.metadata.quality_score,
+        completed_at: result.metadata.completed_at,
+    }
+}
+
+fn result_tags(result: &ResearchResult) -> HashSet<String> {
+    result
+        .request
+        .domain_context
+        .tags
+        .iter()
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+/// Evidence sources and cited URLs, normalized for comparison
+fn cited_sources(result: &ResearchResult) -> HashSet<String> {
+    let extracted;
+    let citations = if result.citations.is_empty() {
+        extracted = extract_citations(result);
+        &extracted
+    } else {
+        &result.citations
+    };
+    result
+        .supporting_evidence
+        .iter()
+        .map(|evidence| evidence.source.as_str())
+        .chain(citations.iter().filter_map(|c| c.url.as_deref()))
+        .map(|source| source.trim().trim_end_matches('/').to_lowercase())
+        .filter(|source| !source.is_empty())
+        .collect()
+}
+
+fn overlap(
+    kind: RelationKind,
+    a: &HashSet<String>,
+    b: &HashSet<String>,
+    threshold: f64,
+) -> Option<Relation> {
+    let score = jaccard(a, b);
+    if score == 0.0 || score < threshold {
+        return None;
+    }
+    let mut shared: Vec<String> = a.intersection(b).cloned().collect();
+    shared.sort();
+    Some(Relation {
+        kind,
+        score,
+        shared,
+    })
+}
+
+pub(crate) fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    let dot: f64 = a
+        .iter()
+        .zip(b)
+        .map(|(x, y)| f64::from(*x) * f64::from(*y))
+        .sum();
+    let norm_a = a.iter().map(|x| f64::from(*x).powi(2)).sum::<f64>().sqrt();
+    let norm_b = b.iter().map(|x| f64::from(*x).powi(2)).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+/// Lowercase words of at least three characters
+pub(crate) fn words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 3)
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+/// Keep each node's strongest edges; an edge survives if either end keeps it
+fn strongest_edges(edges: Vec<GraphEdge>, max_per_node: usize) -> Vec<GraphEdge> {
+    let mut by_node: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (index, edge) in edges.iter().enumerate() {
+        by_node.entry(&edge.source).or_default().push(index);
+        by_node.entry(&edge.target).or_default().push(index);
+    }
+    let mut kept = vec![false; edges.len()];
+    for indices in by_node.values_mut() {
+        indices.sort_by(|a, b| edges[*b].weight.total_cmp(&edges[*a].weight));
+        for index in indices.iter().take(max_per_node) {
+            kept[*index] = true;
+        }
+    }
+    edges
+        .into_iter()
+        .zip(kept)
+        .filter_map(|(edge, kept)| kept.then_some(edge))
+        .collect()
+}
+
+/// Knowledge graph of a reference library, saved to a file and rebuilt when stale
+#[derive(Clone)]
+pub struct KnowledgeGraphStore {
+    path: PathBuf,
+    config: GraphConfig,
+    embeddings: Option<Arc<dyn EmbeddingGenerator>>,
+    graph: Arc<Mutex<Option<Arc<KnowledgeGraph>>>>,
+}
+
+impl std::fmt::Debug for KnowledgeGraphStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KnowledgeGraphStore")
+            .field("path", &self.path)
+            .field("config", &self.config)
+            .field("embeddings", &self.embeddings.is_some())
+            .finish()
+    }
+}
+
+impl KnowledgeGraphStore {
+    /// Store saving its graph to `path`, typically `<base_path>/KNOWLEDGE_GRAPH_FILE`
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            config: GraphConfig::default(),
+            embeddings: None,
+            graph: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn with_config(mut self, config: GraphConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Compare results by embedding instead of by words
+    pub fn with_embeddings(mut self, embeddings: Arc<dyn EmbeddingGenerator>) -> Self {
+        self.embeddings = Some(embeddings);
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Graph of the results in `storage`, rebuilt and saved if they changed since it was built
+    pub async fn current(
+        &self,
+        storage: &(dyn Storage + Send + Sync),
+    ) -> Result<Arc<KnowledgeGraph>, KnowledgeGraphError> {
+        let mut graph = self.graph.lock().await;
+        let entries = storage
+            .list_cache_entries()
+            .await
+            .map_err(|e| KnowledgeGraphError::Storage(e.to_string()))?;
+        if graph.is_none() {
+            match KnowledgeGraph::load(&self.path) {
+                Ok(saved) => *graph = saved.map(Arc::new),
+                Err(e) => warn!("Rebuilding the knowledge graph: {}", e),
+            }
+        }
+        if let Some(current) = graph.as_ref().filter(|g| g.is_current(&entries)) {
+            return Ok(current.clone());
+        }
+
+        let rebuilt = Arc::new(self.build(storage, &entries).await?);
+        *graph = Some(rebuilt.clone());
+        Ok(rebuilt)
+    }
+
+    /// Build the graph from scratch even if the saved one is current
+    pub async fn rebuild(
+        &self,
+        storage: &(dyn Storage + Send + Sync),
+    ) -> Result<Arc<KnowledgeGraph>, KnowledgeGraphError> {
+        let mut graph = self.graph.lock().await;
+        let entries = storage
+            .list_cache_entries()
+            .await
+            .map_err(|e| KnowledgeGraphError::Storage(e.to_string()))?;
+        let rebuilt = Arc::new(self.build(storage, &entries).await?);
+        *graph = Some(rebuilt.clone());
+        Ok(rebuilt)
+    }
+
+    async fn build(
+        &self,
+        storage: &(dyn Storage + Send + Sync),
+        entries: &[CacheEntry],
+    ) -> Result<KnowledgeGraph, KnowledgeGraphError> {
+        let mut results = Vec::with_capacity(entries.len());
+        for entry in entries {
+            match storage.retrieve(&entry.key).await {
+                Ok(Some(mut result)) => {
+                    // The index key is what clients look results up by
+                    result.metadata.cache_key = entry.key.clone();
+                    results.push(result);
+                }
+                Ok(None) => {}
+                Err(e) => warn!("Leaving {} out of the knowledge graph: {}", entry.key, e),
+            }
+        }
+
+        let graph =
+            KnowledgeGraph::build(&results, &self.config, self.embeddings.as_deref()).await?;
+        info!(
+            "Built knowledge graph of {} results and {} relations",
+            graph.nodes.len(),
+            graph.edges.len()
+        );
+        // An unsaved graph still answers; it is rebuilt on the next start
+        if let Err(e) = graph.save(&self.path) {
+            warn!("Failed to save the knowledge graph: {}", e);
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::FileStorage;
+    use fortitude_types::{
+        AudienceContext, ClassifiedRequest, DomainContext, Evidence, ResearchMetadata,
+        StorageConfig,
+    };
+
+    fn result(query: &str, answer: &str, tags: &[&str], sources: &[&str]) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            query.to_string(),
+            ResearchType::Implementation,
+            AudienceContext::default(),
+            DomainContext {
+                tags: tags.iter().map(|t| t.to_string()).collect(),
+                ..DomainContext::default()
+            },
+            0.8,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 0,
+            sources_consulted: vec![],
+            quality_score: 0.8,
+            cache_key: String::new(),
+            tags: HashMap::new(),
+        };
+        let evidence = sources
+            .iter()
+            .map(|source| Evidence {
+                source: source.to_string(),
+                content: String::new(),
+                relevance: 0.8,
+                evidence_type: "documentation".to_string(),
+            })
+            .collect();
+        ResearchResult::new(request, answer.to_string(), evidence, vec![], metadata)
+    }
+
+    #[tokio::test]
+    async fn test_graph_links_results_and_answers_neighborhoods() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = FileStorage::new(StorageConfig {
+            base_path: dir.path().to_path_buf(),
+            ..StorageConfig::default()
+        })
+        .await
+        .unwrap();
+        let mut keys = Vec::new();
+        for result in [
+            result(
+                "How do I spawn tasks in tokio?",
+                "Use tokio::spawn",
+                &["async", "tokio"],
+                &["https://docs.rs/tokio/"],
+            ),
+            result(
+                "How do I cancel tokio tasks?",
+                "Abort the JoinHandle",
+                &["tokio"],
+                &["https://docs.rs/tokio"],
+            ),
+            result(
+                "Which channel should async code use?",
+                "Use an mpsc channel",
+                &["async", "channels"],
+                &[],
+            ),
+            result(
+                "How do I parse TOML?",
+                "Use the toml crate",
+                &["config"],
+                &[],
+            ),
+        ] {
+            keys.push(storage.store(&result).await.unwrap());
+        }
+
+        let store = KnowledgeGraphStore::open(dir.path().join(KNOWLEDGE_GRAPH_FILE));
+        let graph = store.current(&storage).await.unwrap();
+        assert_eq!(graph.nodes.len(), 4);
+        let spawn_cancel = graph
+            .edges
+            .iter()
+            .find(|e| e.other(&keys[0]) == Some(keys[1].as_str()))
+            .unwrap();
+        let kinds: Vec<RelationKind> = spawn_cancel.relations.iter().map(|r| r.kind).collect();
+        assert!(kinds.contains(&RelationKind::SharedTags));
+        assert!(kinds.contains(&RelationKind::CitationOverlap));
+        assert!(graph.edges.iter().all(|e| e.other(&keys[3]).is_none()));
+
+        // Depth bounds how far the neighborhood reaches from the matching result
+        let near = graph.neighborhood("cancel tokio tasks", 1);
+        assert_eq!(near.seeds, [keys[1].clone()]);
+        assert_eq!(near.nodes.len(), 2);
+        let far = graph.neighborhood(&keys[1], 2);
+        let reached: Vec<(&str, usize)> = far
+            .nodes
+            .iter()
+            .map(|n| (n.node.id.as_str(), n.distance))
+            .collect();
+        assert!(reached.contains(&(keys[2].as_str(), 2)));
+        assert_eq!(far.edges.len(), 2);
+        assert!(graph.neighborhood("kubernetes", 2).nodes.is_empty());
+
+        // The saved graph is reused until the stored results change
+        let reopened = KnowledgeGraphStore::open(dir.path().join(KNOWLEDGE_GRAPH_FILE));
+        assert_eq!(
+            reopened.current(&storage).await.unwrap().built_at,
+            graph.built_at
+        );
+        storage
+            .store(&result(
+                "How do I time out a future?",
+                "Use tokio::time::timeout",
+                &["tokio"],
+                &[],
+            ))
+            .await
+            .unwrap();
+        assert_eq!(reopened.current(&storage).await.unwrap().nodes.len(), 5);
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Structure derived from the stored research results as a whole
+//! Individual results answer one question each; this module relates them to
+//! each other so neighbouring research can be found and visualized.
+
+pub mod graph;
+
+pub use graph::{
+    GraphConfig, GraphEdge, GraphNode, KnowledgeGraph, KnowledgeGraphError, KnowledgeGraphStore,
+    Neighborhood, NeighborhoodNode, Relation, RelationKind, KNOWLEDGE_GRAPH_FILE,
+};
@@ -17,20 +17,56 @@
 //! system, including classification engines, storage systems, and pipeline
 //! orchestration.
 
+pub mod advisories;
 pub mod api;
+pub mod atomic_file;
+pub mod bulk_update;
+pub mod citations;
 pub mod classification;
 pub mod claude_code_integration_example;
 pub mod claude_code_provider;
 pub mod claude_code_research_engine;
+pub mod code_context;
+pub mod connectors;
+pub mod content_filter;
+pub mod conversation;
+pub mod cost_tracking;
+pub mod crate_docs;
+pub mod deepening;
+pub mod digest;
 pub mod error_handling;
+pub mod evidence;
+pub mod freshness;
+pub mod keyword_index;
+pub mod knowledge;
+pub mod markdown;
+pub mod model_catalog;
 pub mod multi_provider_research_engine;
+pub mod object_store_storage;
 pub mod pipeline;
+pub mod project_context;
+pub mod prompt_budget;
 pub mod prompts;
+pub mod quality_gate;
+pub mod query_language;
+pub mod related;
 pub mod research_engine;
 pub mod research_feedback;
+pub mod research_import;
+pub mod research_queue;
+pub mod research_session;
 pub mod resilient_research_engine;
+pub mod semantic_cache;
+pub mod sqlite_storage;
+pub mod stage_metrics;
 pub mod storage;
+pub mod structured_output;
+pub mod supervisor;
+pub mod time_budget;
+pub mod tools;
+pub mod trace_context;
 pub mod vector;
+pub mod warnings;
 
 #[cfg(test)]
 mod integration_tests;
@@ -39,23 +75,126 @@ mod integration_tests;
 // pub mod enhanced_pipeline_example;
 
 // Re-export specific types to avoid naming conflicts
+pub use advisories::{
+    is_security_query, Advisory, AdvisoryDatabase, AdvisoryError, AdvisoryMatch, AdvisoryService,
+    CrateMention, VulnerableRecommendation, ADVISORIES_TAG, DEFAULT_ADVISORY_CACHE_PATH,
+};
 pub use api::{ApiClient, ApiConfig, HealthStatus, RateLimitConfig, RequestCost, RetryConfig};
+pub use atomic_file::{write_json_atomic, write_json_atomic_async};
+pub use bulk_update::{
+    BulkFilter, BulkUpdateChange, BulkUpdateError, BulkUpdateReport, MetadataMutation,
+    ResultMetadataView, RetentionClass,
+};
+pub use citations::{
+    extract_citations, CitationValidator, CITATIONS_BROKEN_TAG, CITATIONS_VERIFIED_TAG,
+};
 pub use classification::*;
 pub use claude_code_provider::{ClaudeCodeProvider, ClaudeCodeProviderConfig};
 pub use claude_code_research_engine::{ClaudeCodeResearchEngine, ClaudeCodeResearchEngineConfig};
+pub use code_context::{
+    CodeContextError, CodeContextExtractor, CodeContextSummary, CodeItem, CodeItemKind,
+    DEFAULT_CODE_CONTEXT_BUDGET,
+};
+pub use connectors::{
+    ConflictPolicy, ConfluenceConfig, ConfluenceConnector, ConnectorError, NotionConfig,
+    NotionConnector, OutputConnector, RemotePage, SyncFilter, SyncReport, SyncStateStore, WikiPage,
+    WikiSync,
+};
+pub use content_filter::{
+    ContentFilterConfig, ContentFilterError, DetectorConfig, FilterAction, FilterDecision,
+    FilterReport, FilterRuleConfig, ResponseFilter, RulesFilter, CONTENT_FILTER_TAG,
+};
+pub use conversation::{summarize_conversation, DEFAULT_CONVERSATION_CONTEXT_BUDGET};
+pub use cost_tracking::{
+    BudgetStatus, CostBudgetConfig, CostSummary, CostTracker, CostTrackerError, DailyCost,
+    ProviderSpend, SpendReport, DEFAULT_COST_LEDGER_PATH,
+};
+pub use crate_docs::{mentioned_crate_paths, CrateDocItem, CrateDocs, CrateDocsTool};
+pub use deepening::{
+    DeepeningConfig, FollowUpQuery, WeakSection, DEEPENING_QUERIES_TAG, DEEPENING_SKIPPED_TAG,
+};
+pub use digest::{
+    Digest, DigestConfig, DigestEmailConfig, DigestError, DigestFormat, DigestItem, DigestMailer,
+    DigestRule, DigestSection, SmtpSecurity,
+};
+pub use evidence::{
+    EvidenceScore, EvidenceScorer, EvidenceScoringConfig, EvidenceScoringReport, PruneReason,
+    PruningDecision,
+};
+pub use freshness::{
+    refresh_stale, spawn_scheduled_refresh, stale_entries, AnswerDiff, FreshnessPolicy,
+    RefreshOutcome, RefreshReport, REFRESHED_AT_TAG, REFRESH_CHANGE_TAG, REFRESH_SIMILARITY_TAG,
+};
+pub use keyword_index::{highlight_terms, tokenize, KeywordIndex, HIGHLIGHT_MARKER};
+pub use knowledge::{
+    GraphConfig, GraphEdge, GraphNode, KnowledgeGraph, KnowledgeGraphError, KnowledgeGraphStore,
+    Neighborhood, NeighborhoodNode, Relation, RelationKind, KNOWLEDGE_GRAPH_FILE,
+};
+pub use markdown::{
+    MarkdownConfig, MarkdownIssue, MarkdownRepair, MarkdownSanitizer, SanitizeReport,
+    MARKDOWN_REPAIRS_TAG,
+};
+pub use model_catalog::{estimate_token_count, ModelCatalog, ModelPricing, ProviderCostEstimate};
 pub use multi_provider_research_engine::{
     MultiProviderConfig, MultiProviderResearchEngine, MultiProviderResearchError,
 };
+pub use object_store_storage::{
+    ObjectStoreConfig, ObjectStoreStorage, DEFAULT_OBJECT_STORE_METADATA_FILE,
+};
 pub use pipeline::*;
+pub use project_context::{
+    ProjectContext, ProjectContextCollector, ProjectContextError, DEFAULT_PROJECT_CONTEXT_BUDGET,
+};
+pub use prompt_budget::{
+    BpeTokenizer, BudgetReport, HeuristicTokenizer, OverflowStrategy, PromptBudgetConfig,
+    PromptBudgetError, PromptBudgetProfile, PromptBudgeter, Tokenizer, TokenizerRegistry,
+    WhitespaceTokenizer,
+};
 pub use prompts::*;
+pub use quality_gate::{
+    refine_request, QualityAssessment, ResultScorer, QUALITY_DIMENSION_TAG_PREFIX,
+    QUALITY_REQUERIES_TAG,
+};
+pub use query_language::{
+    Comparison, QueryNode, QueryParseError, SearchExpression, Searchable, QUERY_FIELDS,
+};
+pub use related::{
+    lexical_related, semantic_related, DuplicateCheckConfig, RelatedResult, RelatedResultsConfig,
+};
 pub use research_engine::*;
 pub use research_feedback::*;
+pub use research_import::{
+    content_hash, parse_documents, ImportDocument, ImportError, ImportFormat, ImportRecord,
+    ImportReport, ResearchImporter,
+};
+pub use research_queue::{
+    PriorityQueueSnapshot, QueueMetricsSnapshot, QueuePermit, RequestPriority, ResearchQueue,
+};
+pub use research_session::{ResearchSession, SessionStore, DEFAULT_RESEARCH_SESSIONS_PATH};
 pub use resilient_research_engine::*;
+pub use semantic_cache::{
+    SemanticCacheConfig, SemanticMatch, QUERY_CONTENT_TYPE, SEMANTIC_CACHE_QUERY_TAG,
+    SEMANTIC_CACHE_SIMILARITY_TAG,
+};
+pub use sqlite_storage::{SqliteStorage, DEFAULT_SQLITE_FILE};
+pub use stage_metrics::{
+    ClassificationOutcomes, ContentFilterOutcomes, HistogramBucket, PipelineStage,
+    StageLatencySnapshot, StageMetrics, StageMetricsSnapshot, StageTimings, LATENCY_BUCKETS_MS,
+};
 pub use storage::*;
+pub use time_budget::{
+    Shortcut, TimeBudgetPlan, TimeBudgetReport, TIME_BUDGET_SHORTCUTS_TAG, TIME_BUDGET_TAG,
+};
+pub use tools::{
+    CrateLookupTool, LocalSearchTool, ResearchTool, ToolCall, ToolDefinition, ToolError,
+    ToolMessage, ToolOutput, ToolRegistry, ToolTurn, DEFAULT_MAX_TOOL_ROUNDS,
+};
+pub use trace_context::{TraceContext, REQUEST_ID_TAG, TRACE_ID_TAG};
 pub use vector::{
     BatchSearchRequest,
     BatchSearchResult,
     DocumentMetadata,
+    FusionContribution,
     FusionMethod,
     HybridMatchMetadata,
     HybridSearchAnalytics,
@@ -74,6 +213,7 @@ pub use vector::{
     QdrantClient,
     QueryAnalysis,
     QueryType,
+    ScoreNormalization,
     SearchExplanation,
     SearchOptions,
     SearchResult,
@@ -97,3 +237,4 @@ pub use vector::{
     // Vector storage exports
     VectorStorageService,
 };
+pub use warnings::{PipelineWarning, WarningCode, WARNING_TAG_PREFIX};
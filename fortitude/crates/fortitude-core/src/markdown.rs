@@ -0,0 +1,688 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Markdown sanitization and normalization for provider answers
+// Repairs unterminated code fences and broken tables, strips unsafe HTML and links, shifts headings to fit the result template and flags what could not be repaired
+use fortitude_types::ResearchResult;
+use pulldown_cmark::{CowStr, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use pulldown_cmark_to_cmark::{
+    calculate_code_block_token_count, cmark_with_options, Options as CmarkOptions,
+};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
+use std::sync::OnceLock;
+
+/// Metadata tag listing the repairs made to a result's markdown
+pub const MARKDOWN_REPAIRS_TAG: &str = "markdown_repairs";
+
+/// Elements whose content is dropped along with the tags
+const DANGEROUS_CONTAINERS: &[&str] = &[
+    "script", "style", "iframe", "object", "embed", "noscript", "template", "textarea",
+];
+
+/// Sanitizing passes before giving up on reaching a stable document
+const MAX_PASSES: usize = 3;
+
+/// URL schemes that run code when a link is followed
+const UNSAFE_SCHEMES: &[&str] = &["javascript:", "vbscript:", "data:", "file:"];
+
+/// Markdown sanitization settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MarkdownConfig {
+    pub enabled: bool,
+    /// Level of the top heading in an answer; answers render below a `##` heading
+    pub answer_heading_level: u8,
+    /// Level of the top heading in evidence and details, which render below `###` headings
+    pub section_heading_level: u8,
+    /// HTML tags kept (without attributes); every other tag is removed
+    pub allowed_html_tags: Vec<String>,
+}
+
+impl Default for MarkdownConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            answer_heading_level: 3,
+            section_heading_level: 4,
+            allowed_html_tags: ["br", "sup", "sub", "kbd", "details", "summary"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        }
+    }
+}
+
+impl MarkdownConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, level) in [
+            ("answer_heading_level", self.answer_heading_level),
+            ("section_heading_level", self.section_heading_level),
+        ] {
+            if !(1..=6).contains(&level) {
+                return Err(format!("{name} must be between 1 and 6, got {level}"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A problem that was fixed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarkdownRepair {
+    CodeFenceClosed,
+    TableSeparatorInserted,
+    TableRowsPadded,
+    HtmlStripped,
+    UnsafeLinkRemoved,
+    HeadingsShifted,
+}
+
+impl MarkdownRepair {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarkdownRepair::CodeFenceClosed => "code_fence_closed",
+            MarkdownRepair::TableSeparatorInserted => "table_separator_inserted",
+            MarkdownRepair::TableRowsPadded => "table_rows_padded",
+            MarkdownRepair::HtmlStripped => "html_stripped",
+            MarkdownRepair::UnsafeLinkRemoved => "unsafe_link_removed",
+            MarkdownRepair::HeadingsShifted => "headings_shifted",
+        }
+    }
+}
+
+/// A problem left in the document after sanitizing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarkdownIssue {
+    /// Table rows that still do not parse as a table
+    BrokenTable,
+    /// Nothing but unsafe content was left
+    EmptyAfterSanitizing,
+    /// The repaired document could not be written back as markdown
+    RenderFailed,
+}
+
+impl MarkdownIssue {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarkdownIssue::BrokenTable => "broken_table",
+            MarkdownIssue::EmptyAfterSanitizing => "empty_after_sanitizing",
+            MarkdownIssue::RenderFailed => "render_failed",
+        }
+    }
+}
+
+/// What sanitizing a document changed and what it could not fix
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SanitizeReport {
+    pub repairs: BTreeSet<MarkdownRepair>,
+    pub issues: BTreeSet<MarkdownIssue>,
+}
+
+impl SanitizeReport {
+    pub fn merge(&mut self, other: SanitizeReport) {
+        self.repairs.extend(other.repairs);
+        self.issues.extend(other.issues);
+    }
+
+    /// Whether some problem could not be repaired
+    pub fn has_issues(&self) -> bool {
+        !self.issues.is_empty()
+    }
+
+    /// Record the repairs as the `markdown_repairs` tag
+    pub fn apply_to_tags(&self, tags: &mut HashMap<String, String>) {
+        if self.repairs.is_empty() {
+            tags.remove(MARKDOWN_REPAIRS_TAG);
+        } else {
+            let repairs: Vec<&str> = self.repairs.iter().map(MarkdownRepair::as_str).collect();
+            tags.insert(MARKDOWN_REPAIRS_TAG.to_string(), repairs.join(","));
+        }
+    }
+
+    /// Description of the unrepaired issues, `None` when there are none
+    pub fn issues_summary(&self) -> Option<String> {
+        if self.issues.is_empty() {
+            return None;
+        }
+        let issues: Vec<&str> = self.issues.iter().map(MarkdownIssue::as_str).collect();
+        Some(format!(
+            "Markdown could not be fully repaired: {}",
+            issues.join(", ")
+        ))
+    }
+}
+
+/// Repairs and normalizes markdown written by providers
+#[derive(Debug, Clone, Default)]
+pub struct MarkdownSanitizer {
+    config: MarkdownConfig,
+}
+
+impl MarkdownSanitizer {
+    pub fn new(config: MarkdownConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &MarkdownConfig {
+        &self.config
+    }
+
+    /// Sanitize the answer, evidence and implementation details of a result
+    pub fn sanitize_result(&self, result: &mut ResearchResult) -> SanitizeReport {
+        let mut report = SanitizeReport::default();
+        if !self.config.enabled {
+            return report;
+        }
+        let (answer, answer_report) =
+            self.sanitize(&result.immediate_answer, self.config.answer_heading_level);
+        result.immediate_answer = answer;
+        report.merge(answer_report);
+        let section_level = self.config.section_heading_level;
+        for evidence in &mut result.supporting_evidence {
+            let (content, section_report) = self.sanitize(&evidence.content, section_level);
+            evidence.content = content;
+            report.merge(section_report);
+        }
+        for detail in &mut result.implementation_details {
+            let (content, section_report) = self.sanitize(&detail.content, section_level);
+            detail.content = content;
+            report.merge(section_report);
+        }
+        report
+    }
+
+    /// Sanitize one document so its top heading is at `top_heading_level`
+    ///
+    /// Documents that need no repair are returned unchanged; the others are
+    /// parsed and written back, which also normalizes their formatting.
+    /// Stripping HTML can turn the rest of an HTML block into markdown, so
+    /// passes repeat until nothing changes.
+    pub fn sanitize(&self, text: &str, top_heading_level: u8) -> (String, SanitizeReport) {
+        let mut report = SanitizeReport::default();
+        let mut output = text.to_string();
+        for _ in 0..MAX_PASSES {
+            let mut pass = SanitizeReport::default();
+            let rendered = self.sanitize_pass(&output, top_heading_level, &mut pass);
+            report.merge(pass);
+            match rendered {
+                Some(rendered) if rendered != output => output = rendered,
+                _ => break,
+            }
+        }
+
+        if !text.trim().is_empty() && output.trim().is_empty() {
+            report.issues.insert(MarkdownIssue::EmptyAfterSanitizing);
+        }
+        if has_stray_table_rows(&output) {
+            report.issues.insert(MarkdownIssue::BrokenTable);
+        }
+        (output, report)
+    }
+
+    /// Repair and clean a document once, `None` when it needed no repair
+    fn sanitize_pass(
+        &self,
+        text: &str,
+        top_heading_level: u8,
+        report: &mut SanitizeReport,
+    ) -> Option<String> {
+        let repaired = repair_blocks(text, report);
+        let events: Vec<Event> = Parser::new_ext(&repaired, parser_options()).collect();
+        let events = self.clean_events(events, top_heading_level, report);
+        if report.repairs.is_empty() {
+            return None;
+        }
+        let options = CmarkOptions {
+            code_block_token_count: calculate_code_block_token_count(events.iter())
+                .unwrap_or(3)
+                .max(3),
+            list_token: '-',
+            ..Default::default()
+        };
+        let mut rendered = String::new();
+        match cmark_with_options(events.iter(), &mut rendered, options) {
+            Ok(_) => Some(rendered),
+            Err(_) => {
+                report.issues.insert(MarkdownIssue::RenderFailed);
+                Some(repaired)
+            }
+        }
+    }
+
+    /// Strip unsafe HTML and links and shift heading levels
+    fn clean_events<'a>(
+        &self,
+        events: Vec<Event<'a>>,
+        top_heading_level: u8,
+        report: &mut SanitizeReport,
+    ) -> Vec<Event<'a>> {
+        let shift = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Start(Tag::Heading { level, .. }) => Some(*level as i32),
+                _ => None,
+            })
+            .min()
+            .map_or(0, |top| i32::from(top_heading_level.clamp(1, 6)) - top);
+        if shift != 0 {
+            report.repairs.insert(MarkdownRepair::HeadingsShifted);
+        }
+        let shifted = |level: HeadingLevel| {
+            HeadingLevel::try_from((level as i32 + shift).clamp(1, 6) as usize).unwrap_or(level)
+        };
+
+        let mut cleaned = Vec::with_capacity(events.len());
+        let mut skipping: Option<String> = None;
+        let mut dropped_links: Vec<bool> = Vec::new();
+        for event in events {
+            match event {
+                Event::Html(html) | Event::InlineHtml(html) if !html.is_empty() => {
+                    let inline = is_inline_html(&cleaned);
+                    let clean = sanitize_html(&html, &self.config.allowed_html_tags, &mut skipping);
+                    if clean != *html {
+                        report.repairs.insert(MarkdownRepair::HtmlStripped);
+                    }
+                    if !clean.is_empty() {
+                        let clean = CowStr::from(clean);
+                        cleaned.push(if inline {
+                            Event::InlineHtml(clean)
+                        } else {
+                            Event::Html(clean)
+                        });
+                    }
+                }
+                Event::Text(_) | Event::Code(_) | Event::SoftBreak | Event::HardBreak
+                    if skipping.is_some() =>
+                {
+                    report.repairs.insert(MarkdownRepair::HtmlStripped);
+                }
+                Event::Start(Tag::Link { ref dest_url, .. })
+                | Event::Start(Tag::Image { ref dest_url, .. }) => {
+                    let unsafe_url = is_unsafe_url(dest_url);
+                    dropped_links.push(unsafe_url);
+                    if unsafe_url {
+                        report.repairs.insert(MarkdownRepair::UnsafeLinkRemoved);
+                    } else {
+                        cleaned.push(event);
+                    }
+                }
+                Event::End(TagEnd::Link) | Event::End(TagEnd::Image) => {
+                    if !dropped_links.pop().unwrap_or(false) {
+                        cleaned.push(event);
+                    }
+                }
+                Event::Start(Tag::Heading {
+                    level,
+                    id,
+                    classes,
+                    attrs,
+                }) => cleaned.push(Event::Start(Tag::Heading {
+                    level: shifted(level),
+                    id,
+                    classes,
+                    attrs,
+                })),
+                Event::End(TagEnd::Heading(level)) => {
+                    cleaned.push(Event::End(TagEnd::Heading(shifted(level))))
+                }
+                event => cleaned.push(event),
+            }
+        }
+        cleaned
+    }
+}
+
+/// Inline HTML sits inside a paragraph-like block, block HTML inside an HTML block
+fn is_inline_html(cleaned: &[Event]) -> bool {
+    let mut depth = 0usize;
+    for event in cleaned.iter().rev() {
+        match event {
+            Event::End(_) => depth += 1,
+            Event::Start(tag) if depth == 0 => return !matches!(tag, Tag::HtmlBlock),
+            Event::Start(_) => depth -= 1,
+            _ => {}
+        }
+    }
+    false
+}
+
+fn parser_options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS
+}
+
+fn is_unsafe_url(url: &str) -> bool {
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_lowercase();
+    UNSAFE_SCHEMES.iter().any(|scheme| url.starts_with(scheme))
+}
+
+/// Keep allowed tags without attributes, drop the rest and the content of dangerous elements
+fn sanitize_html(fragment: &str, allowed: &[String], skipping: &mut Option<String>) -> String {
+    static TAG: OnceLock<Regex> = OnceLock::new();
+    let tag = TAG.get_or_init(|| {
+        Regex::new(r"(?s)<!--.*?-->|<(/?)([A-Za-z][A-Za-z0-9-]*)\b[^>]*?(/?)>")
+            .expect("valid regex")
+    });
+    let mut clean = String::new();
+    let mut last = 0;
+    for captures in tag.captures_iter(fragment) {
+        let whole = captures.get(0).expect("match");
+        if skipping.is_none() {
+            clean.push_str(&fragment[last..whole.start()].replace('<', "&lt;"));
+        }
+        last = whole.end();
+        let Some(name) = captures.get(2) else {
+            continue; // comment
+        };
+        let name = name.as_str().to_lowercase();
+        let closing = !captures[1].is_empty();
+        let self_closing = !captures[3].is_empty();
+        if let Some(open) = skipping.as_deref() {
+            if closing && open == name {
+                *skipping = None;
+            }
+            continue;
+        }
+        if DANGEROUS_CONTAINERS.contains(&name.as_str()) {
+            if !closing && !self_closing {
+                *skipping = Some(name);
+            }
+            continue;
+        }
+        if allowed
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(&name))
+        {
+            clean.push_str(&format!("<{}{}>", if closing { "/" } else { "" }, name));
+        }
+    }
+    if skipping.is_none() {
+        clean.push_str(&fragment[last..].replace('<', "&lt;"));
+    }
+    clean
+}
+
+/// Opening fence character, fence length and info string of a fence line
+fn fence_marker(line: &str) -> Option<(char, usize, &str)> {
+    let trimmed = line.trim_start();
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let marker = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let length = trimmed.chars().take_while(|c| *c == marker).count();
+    let info = &trimmed[length..];
+    (length >= 3 && !(marker == '`' && info.contains('`'))).then_some((marker, length, info))
+}
+
+fn is_table_line(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    line.len() - trimmed.len() <= 3 && trimmed.starts_with('|')
+}
+
+fn split_cells(line: &str) -> Vec<String> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = match line.strip_suffix('|') {
+        Some(rest) if !rest.ends_with('\\') => rest,
+        _ => line,
+    };
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut escaped = false;
+    for c in line.chars() {
+        if c == '|' && !escaped {
+            cells.push(cell.trim().to_string());
+            cell.clear();
+        } else {
+            cell.push(c);
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+fn is_separator(cells: &[String]) -> bool {
+    !cells.is_empty()
+        && cells.iter().all(|cell| {
+            let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+            !dashes.is_empty() && dashes.chars().all(|c| c == '-')
+        })
+}
+
+fn table_row(cells: &[String]) -> String {
+    format!("| {} |", cells.join(" | "))
+}
+
+/// Insert a missing separator row and pad rows to the same number of cells
+fn repair_table(lines: &[&str], out: &mut Vec<String>, report: &mut SanitizeReport) {
+    if lines.len() < 2 {
+        out.extend(lines.iter().map(|line| line.to_string()));
+        return;
+    }
+    let header = split_cells(lines[0]);
+    let second = split_cells(lines[1]);
+    let (mut separator, body) = if is_separator(&second) {
+        (second, &lines[2..])
+    } else {
+        report
+            .repairs
+            .insert(MarkdownRepair::TableSeparatorInserted);
+        (Vec::new(), &lines[1..])
+    };
+    let mut rows: Vec<Vec<String>> = body.iter().map(|line| split_cells(line)).collect();
+    let width = rows
+        .iter()
+        .map(Vec::len)
+        .chain([header.len(), separator.len()])
+        .max()
+        .unwrap_or(1);
+    let uneven = header.len() != width
+        || (!separator.is_empty() && separator.len() != width)
+        || rows.iter().any(|row| row.len() != width);
+    if !separator.is_empty() && !uneven {
+        out.extend(lines.iter().map(|line| line.to_string()));
+        return;
+    }
+    if uneven {
+        report.repairs.insert(MarkdownRepair::TableRowsPadded);
+    }
+
+    let mut header = header;
+    header.resize(width, String::new());
+    separator.resize(width, "---".to_string());
+    if out.last().is_some_and(|line| !line.trim().is_empty()) {
+        out.push(String::new());
+    }
+    out.push(table_row(&header));
+    out.push(table_row(&separator));
+    for row in &mut rows {
+        row.resize(width, String::new());
+        out.push(table_row(row));
+    }
+}
+
+/// Close unterminated code fences and repair pipe tables outside code blocks
+fn repair_blocks(text: &str, report: &mut SanitizeReport) -> String {
+    let mut out: Vec<String> = Vec::new();
+    let mut fence: Option<(char, usize)> = None;
+    let mut table: Vec<&str> = Vec::new();
+    for line in text.lines() {
+        if let Some((marker, length)) = fence {
+            if fence_marker(line).is_some_and(|(closing, closing_length, info)| {
+                closing == marker && closing_length >= length && info.trim().is_empty()
+            }) {
+                fence = None;
+            }
+            out.push(line.to_string());
+            continue;
+        }
+        if is_table_line(line) {
+            table.push(line);
+            continue;
+        }
+        repair_table(&table, &mut out, report);
+        table.clear();
+        if let Some((marker, length, _)) = fence_marker(line) {
+            fence = Some((marker, length));
+        }
+        out.push(line.to_string());
+    }
+    repair_table(&table, &mut out, report);
+    if let Some((marker, length)) = fence {
+        out.push(marker.to_string().repeat(length));
+        report.repairs.insert(MarkdownRepair::CodeFenceClosed);
+    }
+    let mut repaired = out.join("\n");
+    if text.ends_with('\n') {
+        repaired.push('\n');
+    }
+    repaired
+}
+
+/// Whether lines starting with `|` remain outside tables and code blocks
+fn has_stray_table_rows(text: &str) -> bool {
+    let mut covered: Vec<Range<usize>> = Vec::new();
+    for (event, range) in Parser::new_ext(text, parser_options()).into_offset_iter() {
+        if let Event::Start(Tag::Table(_) | Tag::CodeBlock(_) | Tag::HtmlBlock) = event {
+            covered.push(range);
+        }
+    }
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        if is_table_line(line) && !covered.iter().any(|range| range.contains(&start)) {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn sanitize(text: &str) -> (String, SanitizeReport) {
+        MarkdownSanitizer::default().sanitize(text, 3)
+    }
+
+    #[test]
+    fn test_repairs_common_provider_markdown_problems() {
+        let clean = "### Setup\n\nUse `tokio::spawn`.\n\n- one\n- two\n";
+        assert_eq!(
+            sanitize(clean),
+            (clean.to_string(), SanitizeReport::default())
+        );
+
+        let (output, report) = sanitize(
+            "# Answer\n\n## Details\n\n| Crate | Use |\n| serde | serialization | derive |\n| tokio |\n\n```rust\nfn main() {}\n",
+        );
+        assert!(output.starts_with("### Answer"));
+        assert!(output.contains("#### Details"));
+        assert!(output.contains("|tokio|||"));
+        assert!(output.trim_end().ends_with("```"));
+        assert_eq!(
+            report.repairs,
+            BTreeSet::from([
+                MarkdownRepair::CodeFenceClosed,
+                MarkdownRepair::TableSeparatorInserted,
+                MarkdownRepair::TableRowsPadded,
+                MarkdownRepair::HeadingsShifted,
+            ])
+        );
+        assert!(!report.has_issues());
+        let tables = Parser::new_ext(&output, parser_options())
+            .filter(|event| matches!(event, Event::Start(Tag::Table(_))))
+            .count();
+        assert_eq!(tables, 1);
+
+        let (output, report) = sanitize(
+            "See <b onclick=\"x()\">this</b> and <script>alert(1)</script> [link](javascript:alert(1)) <br>\n\n<div style=\"x\"><iframe src=\"e\"></iframe>kept</div>\n",
+        );
+        assert!(
+            !output.contains("script") && !output.contains("alert") && !output.contains("iframe")
+        );
+        assert!(!output.contains("onclick") && !output.contains("<div"));
+        assert!(output.contains("this") && output.contains("link") && output.contains("<br>"));
+        assert!(output.contains("kept"));
+        assert!(report.repairs.contains(&MarkdownRepair::HtmlStripped));
+        assert!(report.repairs.contains(&MarkdownRepair::UnsafeLinkRemoved));
+
+        let (_, report) = sanitize("<script>alert(1)</script>");
+        assert!(report.issues.contains(&MarkdownIssue::EmptyAfterSanitizing));
+        let (_, report) = sanitize("> | quoted | row |\n");
+        assert!(!report.has_issues());
+    }
+
+    proptest! {
+        /// Answer formatting contract: whatever the provider sends, the result has
+        /// closed fences, no script content and no headings above the template level
+        #[test]
+        fn test_sanitized_answers_meet_formatting_contract(
+            lines in proptest::collection::vec(
+                prop_oneof![
+                    Just("# Title".to_string()),
+                    Just("## Section".to_string()),
+                    Just("```rust".to_string()),
+                    Just("~~~".to_string()),
+                    Just("| a | b |".to_string()),
+                    Just("|---|---|".to_string()),
+                    Just("| 1 | 2 | 3 |".to_string()),
+                    Just("<script>alert('x')</script>".to_string()),
+                    Just("<img src=x onerror=alert(1)>".to_string()),
+                    Just("[x](javascript:alert(1))".to_string()),
+                    Just(String::new()),
+                    "[a-z ]{0,20}",
+                ],
+                0..12,
+            )
+        ) {
+            let input = lines.join("\n");
+            let (output, _) = sanitize(&input);
+            let mut in_code = false;
+            for event in Parser::new_ext(&output, parser_options()) {
+                match event {
+                    Event::Start(Tag::CodeBlock(_)) => in_code = true,
+                    Event::End(TagEnd::CodeBlock) => in_code = false,
+                    Event::Start(Tag::Heading { level, .. }) => {
+                        prop_assert!(level >= HeadingLevel::H3);
+                    }
+                    Event::Html(html) | Event::InlineHtml(html) => {
+                        let html = html.to_lowercase();
+                        prop_assert!(!html.contains("<script") && !html.contains("onerror"));
+                    }
+                    Event::Start(Tag::Link { dest_url, .. }) => {
+                        prop_assert!(!is_unsafe_url(&dest_url));
+                    }
+                    _ => {}
+                }
+            }
+            prop_assert!(!in_code);
+            let mut fences = SanitizeReport::default();
+            repair_blocks(&output, &mut fences);
+            prop_assert!(!fences.repairs.contains(&MarkdownRepair::CodeFenceClosed));
+            prop_assert_eq!(sanitize(&output).0, output);
+        }
+    }
+}
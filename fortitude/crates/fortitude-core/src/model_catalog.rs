@@ -0,0 +1,272 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Model catalog with per-model pricing used for research cost estimation
+//! This module provides a catalog of known LLM models and their token pricing,
+//! along with helpers to turn token counts into per-provider cost ranges.
+
+use serde::{Deserialize, Serialize};
+
+/// Pricing and limits for a single model
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ModelPricing {
+    /// Provider name (openai, claude, gemini)
+    pub provider: String,
+    /// Model identifier
+    pub model: String,
+    /// Input cost per 1K tokens (USD)
+    pub input_cost_per_1k_tokens: f64,
+    /// Output cost per 1K tokens (USD)
+    pub output_cost_per_1k_tokens: f64,
+    /// Maximum context window in tokens
+    pub context_length: u32,
+    /// Name of the tokenizer used to count this model's prompt tokens
+    #[serde(default = "default_tokenizer")]
+    pub tokenizer: String,
+}
+
+fn default_tokenizer() -> String {
+    crate::prompt_budget::DEFAULT_TOKENIZER.to_string()
+}
+
+impl ModelPricing {
+    /// Create a new model pricing entry
+    pub fn new(
+        provider: impl Into<String>,
+        model: impl Into<String>,
+        input_cost_per_1k_tokens: f64,
+        output_cost_per_1k_tokens: f64,
+        context_length: u32,
+    ) -> Self {
+        Self {
+            provider: provider.into(),
+            model: model.into(),
+            input_cost_per_1k_tokens,
+            output_cost_per_1k_tokens,
+            context_length,
+            tokenizer: default_tokenizer(),
+        }
+    }
+
+    /// Set the tokenizer used for this model
+    pub fn with_tokenizer(mut self, tokenizer: impl Into<String>) -> Self {
+        self.tokenizer = tokenizer.into();
+        self
+    }
+
+    /// Cost in USD for the given token counts
+    pub fn cost_usd(&self, input_tokens: u32, output_tokens: u32) -> f64 {
+        (input_tokens as f64 / 1000.0) * self.input_cost_per_1k_tokens
+            + (output_tokens as f64 / 1000.0) * self.output_cost_per_1k_tokens
+    }
+}
+
+/// Estimated token usage and cost range for one candidate model
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProviderCostEstimate {
+    /// Provider name
+    pub provider: String,
+    /// Model identifier
+    pub model: String,
+    /// Prompt tokens
+    pub input_tokens: u32,
+    /// Lower bound of completion tokens
+    pub output_tokens_min: u32,
+    /// Upper bound of completion tokens
+    pub output_tokens_max: u32,
+    /// Lower bound of cost (USD)
+    pub cost_min_usd: f64,
+    /// Upper bound of cost (USD)
+    pub cost_max_usd: f64,
+    /// Whether the prompt fits in the model context window
+    pub fits_context: bool,
+}
+
+/// Catalog of models available for cost estimation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ModelCatalog {
+    models: Vec<ModelPricing>,
+}
+
+impl Default for ModelCatalog {
+    fn default() -> Self {
+        Self {
+            models: vec![
+                ModelPricing::new(
+                    "claude",
+                    "claude-3-5-sonnet-20241022",
+                    0.003,
+                    0.015,
+                    200_000,
+                ),
+                ModelPricing::new(
+                    "claude",
+                    "claude-3-haiku-20240307",
+                    0.00025,
+                    0.00125,
+                    200_000,
+                ),
+                ModelPricing::new("openai", "gpt-4", 0.03, 0.06, 8192).with_tokenizer("cl100k"),
+                ModelPricing::new("openai", "gpt-4-turbo", 0.01, 0.03, 128_000)
+                    .with_tokenizer("cl100k"),
+                ModelPricing::new("openai", "gpt-3.5-turbo", 0.001, 0.002, 16_385)
+                    .with_tokenizer("cl100k"),
+                ModelPricing::new("gemini", "gemini-pro", 0.0005, 0.0015, 32_768),
+            ],
+        }
+    }
+}
+
+impl ModelCatalog {
+    /// Create a catalog from a list of models
+    pub fn new(models: Vec<ModelPricing>) -> Self {
+        Self { models }
+    }
+
+    /// Add or replace a model entry
+    pub fn with_model(mut self, pricing: ModelPricing) -> Self {
+        self.models
+            .retain(|m| !(m.provider == pricing.provider && m.model == pricing.model));
+        self.models.push(pricing);
+        self
+    }
+
+    /// All models in the catalog
+    pub fn models(&self) -> &[ModelPricing] {
+        &self.models
+    }
+
+    /// Look up a model by identifier
+    pub fn get(&self, model: &str) -> Option<&ModelPricing> {
+        self.models.iter().find(|m| m.model == model)
+    }
+
+    /// Provider names in catalog order, without duplicates
+    pub fn providers(&self) -> Vec<&str> {
+        let mut providers: Vec<&str> = Vec::new();
+        for pricing in &self.models {
+            if !providers.contains(&pricing.provider.as_str()) {
+                providers.push(&pricing.provider);
+            }
+        }
+        providers
+    }
+
+    /// Models offered by a provider
+    pub fn for_provider(&self, provider: &str) -> Vec<&ModelPricing> {
+        self.models
+            .iter()
+            .filter(|m| m.provider.eq_ignore_ascii_case(provider))
+            .collect()
+    }
+
+    /// Estimate cost ranges for every model, or only the given provider's models
+    pub fn estimate(
+        &self,
+        provider: Option<&str>,
+        input_tokens: u32,
+        output_tokens_min: u32,
+        output_tokens_max: u32,
+    ) -> Vec<ProviderCostEstimate> {
+        let candidates: Vec<&ModelPricing> = match provider {
+            Some(p) if !self.for_provider(p).is_empty() => self.for_provider(p),
+            _ => self.models.iter().collect(),
+        };
+
+        candidates
+            .into_iter()
+            .map(|pricing| ProviderCostEstimate {
+                provider: pricing.provider.clone(),
+                model: pricing.model.clone(),
+                input_tokens,
+                output_tokens_min,
+                output_tokens_max,
+                cost_min_usd: pricing.cost_usd(input_tokens, output_tokens_min),
+                cost_max_usd: pricing.cost_usd(input_tokens, output_tokens_max),
+                fits_context: input_tokens.saturating_add(output_tokens_max)
+                    <= pricing.context_length,
+            })
+            .collect()
+    }
+}
+
+/// Count tokens in a prompt using the ~4 characters per token heuristic
+pub fn estimate_token_count(text: &str) -> u32 {
+    text.chars().count().div_ceil(4) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cost_usd() {
+        let pricing = ModelPricing::new("openai", "gpt-4", 0.03, 0.06, 8192);
+        let cost = pricing.cost_usd(1000, 500);
+        assert!((cost - 0.06).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_estimate_filters_by_provider() {
+        let catalog = ModelCatalog::default();
+
+        let claude = catalog.estimate(Some("claude"), 1000, 500, 1500);
+        assert!(!claude.is_empty());
+        assert!(claude.iter().all(|e| e.provider == "claude"));
+        assert!(claude.iter().all(|e| e.cost_min_usd <= e.cost_max_usd));
+
+        // Unknown provider falls back to every model
+        let all = catalog.estimate(Some("auto"), 1000, 500, 1500);
+        assert_eq!(all.len(), catalog.models().len());
+    }
+
+    #[test]
+    fn test_estimate_context_fit() {
+        let catalog = ModelCatalog::default();
+        let estimates = catalog.estimate(Some("openai"), 10_000, 500, 1500);
+        let gpt4 = estimates.iter().find(|e| e.model == "gpt-4").unwrap();
+        assert!(!gpt4.fits_context);
+        let turbo = estimates.iter().find(|e| e.model == "gpt-4-turbo").unwrap();
+        assert!(turbo.fits_context);
+    }
+
+    #[test]
+    fn test_with_model_replaces_entry() {
+        let catalog = ModelCatalog::default()
+            .with_model(ModelPricing::new("openai", "gpt-4", 0.02, 0.04, 8192));
+        let gpt4 = catalog.get("gpt-4").unwrap();
+        assert_eq!(gpt4.input_cost_per_1k_tokens, 0.02);
+        assert_eq!(catalog.for_provider("openai").len(), 3);
+    }
+
+    #[test]
+    fn test_tokenizer_defaults_to_heuristic() {
+        let pricing = ModelPricing::new("openai", "gpt-4", 0.03, 0.06, 8192);
+        assert_eq!(pricing.tokenizer, "heuristic");
+        assert_eq!(pricing.with_tokenizer("cl100k").tokenizer, "cl100k");
+
+        let parsed: ModelPricing = serde_json::from_str(
+            r#"{"provider":"local","model":"m","input_cost_per_1k_tokens":0.0,"output_cost_per_1k_tokens":0.0,"context_length":4096}"#,
+        )
+        .unwrap();
+        assert_eq!(parsed.tokenizer, "heuristic");
+    }
+
+    #[test]
+    fn test_estimate_token_count() {
+        assert_eq!(estimate_token_count(""), 0);
+        assert_eq!(estimate_token_count("abcd"), 1);
+        assert_eq!(estimate_token_count("abcde"), 2);
+    }
+}
@@ -74,8 +74,15 @@
 //! }
 //! ```
 
-use crate::prompts::{DefaultTemplateFactory, ParameterValue, QualityValidator, TemplateRegistry};
+use crate::prompts::{
+    request_parameters, ComplexityLevel, DefaultTemplateFactory, ExperimentAssignment,
+    ParameterValue, PromptAdjustments, PromptExperimentRunner, PromptTemplate,
+    PromptTemplateRegistry, QualityValidator, TemplateRegistry, PROMPT_EXPERIMENT_ARM_TAG,
+    PROMPT_EXPERIMENT_TAG, PROMPT_TEMPLATE_TAG, PROMPT_TEMPLATE_VERSION_TAG,
+};
 use crate::research_engine::{ResearchEngine, ResearchEngineError};
+use crate::structured_output;
+use crate::tools::ToolRegistry;
 use crate::vector::{HybridSearchService, VectorDocument};
 use fortitude_types::{
     ClassifiedRequest, Detail, Evidence, ResearchMetadata, ResearchResult, ResearchType,
@@ -88,7 +95,10 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use thiserror::Error;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, instrument, warn};
+
+/// Immediate answer, supporting evidence and implementation details
+type ParsedResponse = (String, Vec<Evidence>, Vec<Detail>);
 
 // Forward declaration - provider types will be resolved at compile time
 pub trait ProviderManagerTrait: Send + Sync {
@@ -98,6 +108,16 @@ pub trait ProviderManagerTrait: Send + Sync {
         request: &ClassifiedRequest,
     ) -> impl std::future::Future<Output = Result<String, Box<dyn std::error::Error + Send + Sync>>> + Send;
 
+    /// Execute research, offering `tools` to providers that support tool calling
+    fn execute_research_with_tools(
+        &self,
+        request: &ClassifiedRequest,
+        _tools: &ToolRegistry,
+    ) -> impl std::future::Future<Output = Result<String, Box<dyn std::error::Error + Send + Sync>>> + Send
+    {
+        self.execute_research(request)
+    }
+
     /// Get performance statistics for all providers
     fn get_performance_stats(
         &self,
@@ -194,6 +214,19 @@ pub struct MultiProviderConfig {
 
     /// Latency optimization weight (0.0 = ignore latency, 1.0 = only latency)
     pub latency_optimization_weight: f64,
+
+    /// Ask providers for schema-conforming JSON instead of free text
+    #[serde(default)]
+    pub enable_structured_output: bool,
+
+    /// Repair requests sent after a malformed structured reply before falling
+    /// back to text parsing
+    #[serde(default = "default_max_structured_output_repairs")]
+    pub max_structured_output_repairs: u32,
+}
+
+fn default_max_structured_output_repairs() -> u32 {
+    2
 }
 
 impl Default for MultiProviderConfig {
@@ -212,6 +245,8 @@ impl Default for MultiProviderConfig {
             cost_optimization_weight: 0.3,
             quality_optimization_weight: 0.5,
             latency_optimization_weight: 0.2,
+            enable_structured_output: false,
+            max_structured_output_repairs: default_max_structured_output_repairs(),
         }
     }
 }
@@ -220,10 +255,12 @@ impl Default for MultiProviderConfig {
 pub struct MultiProviderResearchEngine<T: ProviderManagerTrait> {
     provider_manager: Arc<T>,
     config: MultiProviderConfig,
-    #[allow(dead_code)]
     template_registry: TemplateRegistry,
     quality_validator: QualityValidator,
     vector_search: Option<Arc<HybridSearchService>>,
+    prompt_adjustments: Option<Arc<PromptAdjustments>>,
+    prompt_templates: Option<Arc<PromptTemplateRegistry>>,
+    prompt_experiments: Option<Arc<PromptExperimentRunner>>,
 }
 
 impl<T: ProviderManagerTrait> MultiProviderResearchEngine<T> {
@@ -241,6 +278,9 @@ impl<T: ProviderManagerTrait> MultiProviderResearchEngine<T> {
             template_registry,
             quality_validator,
             vector_search: None,
+            prompt_adjustments: None,
+            prompt_templates: None,
+            prompt_experiments: None,
         })
     }
 
@@ -259,13 +299,113 @@ impl<T: ProviderManagerTrait> MultiProviderResearchEngine<T> {
             template_registry,
             quality_validator,
             vector_search: Some(vector_search),
+            prompt_adjustments: None,
+            prompt_templates: None,
+            prompt_experiments: None,
         })
     }
 
+    /// Append applied template adjustments to every research prompt
+    pub fn with_prompt_adjustments(mut self, adjustments: Arc<PromptAdjustments>) -> Self {
+        self.prompt_adjustments = Some(adjustments);
+        self
+    }
+
+    /// Build prompts from versioned templates instead of sending the bare query
+    pub fn with_prompt_templates(mut self, templates: Arc<PromptTemplateRegistry>) -> Self {
+        self.prompt_templates = Some(templates);
+        self
+    }
+
+    /// Route requests matching an experiment to its control or variant template
+    pub fn with_prompt_experiments(mut self, experiments: Arc<PromptExperimentRunner>) -> Self {
+        self.prompt_experiments = Some(experiments);
+        self
+    }
+
+    /// Versioned template for a request and its requested provider, if any
+    ///
+    /// Requests taking part in an experiment get the template version of
+    /// their arm, returned along with the assignment.
+    fn prompt_template_for(
+        &self,
+        request: &ClassifiedRequest,
+    ) -> (Option<&PromptTemplate>, Option<ExperimentAssignment>) {
+        let Some(templates) = self.prompt_templates.as_ref() else {
+            return (None, None);
+        };
+        let provider = request
+            .provider_selection
+            .as_ref()
+            .map(|selection| selection.provider.as_str());
+
+        if let Some(runner) = &self.prompt_experiments {
+            if let Some(assignment) = runner.assign(request) {
+                let experiment_provider = runner
+                    .experiments()
+                    .iter()
+                    .find(|experiment| experiment.name == assignment.experiment)
+                    .and_then(|experiment| experiment.provider.as_deref());
+                let template = templates
+                    .versions(&request.research_type, experiment_provider)
+                    .into_iter()
+                    .find(|template| template.version == assignment.template_version);
+                match template {
+                    Some(template) => return (Some(template), Some(assignment)),
+                    None => warn!(
+                        "Experiment '{}' uses missing template version {}; using the latest version",
+                        assignment.experiment, assignment.template_version
+                    ),
+                }
+            }
+        }
+        (templates.resolve(&request.research_type, provider), None)
+    }
+
+    /// Name of the template used for a research type
+    fn template_name_for(&self, research_type: &ResearchType) -> Option<String> {
+        self.template_registry
+            .get_best_for_type(research_type, ComplexityLevel::Basic)
+            .ok()
+            .map(|template| template.get_name().to_string())
+    }
+
+    /// Request carrying the applied adjustments for its template, if any
+    fn with_adjustments(
+        &self,
+        request: &ClassifiedRequest,
+        template_name: Option<&str>,
+    ) -> ClassifiedRequest {
+        let mut adjusted = request.clone();
+        if let (Some(adjustments), Some(template_name)) = (&self.prompt_adjustments, template_name)
+        {
+            if let Some(instructions) =
+                adjustments.instructions_for(template_name, &request.research_type)
+            {
+                adjusted.original_query = format!("{}\n\n{instructions}", request.original_query);
+            }
+        }
+        adjusted
+    }
+
     /// Execute research with cross-validation if enabled
     async fn execute_research_with_validation(
         &self,
         request: &ClassifiedRequest,
+    ) -> Result<ResearchResult, MultiProviderResearchError> {
+        self.execute_validated_research(request, None).await
+    }
+
+    /// Execute research, optionally offering tools, and validate the result
+    #[instrument(
+        name = "provider.generate",
+        skip_all,
+        fields(provider = "multi", research_type = %request.research_type)
+    )]
+    async fn execute_validated_research(
+        &self,
+        request: &ClassifiedRequest,
+        tools: Option<&ToolRegistry>,
     ) -> Result<ResearchResult, MultiProviderResearchError> {
         let start_time = Instant::now();
 
@@ -278,16 +418,31 @@ impl<T: ProviderManagerTrait> MultiProviderResearchEngine<T> {
             request.research_type, request.confidence
         );
 
-        // Execute research through provider manager
-        let response_text = self
-            .provider_manager
-            .execute_research(request)
-            .await
-            .map_err(|e| MultiProviderResearchError::ProviderError(e.to_string()))?;
-
-        // Parse the response into structured format
-        let (immediate_answer, supporting_evidence, implementation_details) =
-            self.parse_research_response(&response_text, request);
+        let (prompt_template, experiment) = self.prompt_template_for(request);
+        let template_name = match prompt_template {
+            Some(template) => Some(template.name.clone()),
+            None => self.template_name_for(&request.research_type),
+        };
+        let mut prompt_request = request.clone();
+        if let Some(template) = prompt_template {
+            prompt_request.original_query = template
+                .render(&request_parameters(request))
+                .map_err(|e| MultiProviderResearchError::ConfigurationError(e.to_string()))?;
+        }
+        let prompt_request = self.with_adjustments(&prompt_request, template_name.as_deref());
+
+        // Execute research through provider manager and parse the response
+        let (response_format, (immediate_answer, supporting_evidence, implementation_details)) =
+            if self.config.enable_structured_output {
+                self.execute_structured_research(&prompt_request, tools)
+                    .await?
+            } else {
+                let response_text = self.dispatch(&prompt_request, tools).await?;
+                (
+                    "text",
+                    self.parse_research_response(&response_text, request),
+                )
+            };
 
         // Validate quality if enabled
         let quality_score = if self.config.enable_quality_validation {
@@ -350,6 +505,35 @@ impl<T: ProviderManagerTrait> MultiProviderResearchEngine<T> {
             cache_key: String::new(),
             tags: HashMap::new(),
         };
+        metadata
+            .tags
+            .insert("response_format".to_string(), response_format.to_string());
+        if let Some(template_name) = template_name {
+            metadata
+                .tags
+                .insert(PROMPT_TEMPLATE_TAG.to_string(), template_name);
+        }
+        if let Some(template) = prompt_template {
+            metadata.tags.insert(
+                PROMPT_TEMPLATE_VERSION_TAG.to_string(),
+                template.version.to_string(),
+            );
+        }
+        if let Some(assignment) = &experiment {
+            metadata.tags.insert(
+                PROMPT_EXPERIMENT_TAG.to_string(),
+                assignment.experiment.clone(),
+            );
+            metadata.tags.insert(
+                PROMPT_EXPERIMENT_ARM_TAG.to_string(),
+                assignment.arm.to_string(),
+            );
+            if let Some(runner) = &self.prompt_experiments {
+                if let Err(e) = runner.record(assignment, request.id, quality_score) {
+                    warn!("Failed to record experiment observation: {}", e);
+                }
+            }
+        }
 
         // Add performance statistics to metadata
         let performance_stats = self.provider_manager.get_performance_stats().await;
@@ -394,6 +578,68 @@ impl<T: ProviderManagerTrait> MultiProviderResearchEngine<T> {
         Ok(result)
     }
 
+    /// Send a request through the provider manager, offering tools if given
+    async fn dispatch(
+        &self,
+        request: &ClassifiedRequest,
+        tools: Option<&ToolRegistry>,
+    ) -> Result<String, MultiProviderResearchError> {
+        let response = match tools {
+            Some(tools) => {
+                self.provider_manager
+                    .execute_research_with_tools(request, tools)
+                    .await
+            }
+            None => self.provider_manager.execute_research(request).await,
+        };
+        response.map_err(|e| MultiProviderResearchError::ProviderError(e.to_string()))
+    }
+
+    /// Request a JSON answer, sending repair requests for malformed replies
+    ///
+    /// Falls back to text parsing of the last reply once the repair budget is
+    /// spent. Returns the response format used alongside the parsed parts.
+    async fn execute_structured_research(
+        &self,
+        request: &ClassifiedRequest,
+        tools: Option<&ToolRegistry>,
+    ) -> Result<(&'static str, ParsedResponse), MultiProviderResearchError> {
+        let mut attempt = request.clone();
+        attempt.original_query = format!(
+            "{}\n\n{}",
+            request.original_query,
+            structured_output::structured_output_instructions()
+        );
+        let mut response_text = self.dispatch(&attempt, tools).await?;
+
+        for repair in 0..=self.config.max_structured_output_repairs {
+            let error = match structured_output::parse_structured_response(&response_text) {
+                Ok(response) => return Ok(("structured", response.into_parts())),
+                Err(e) => e,
+            };
+            if repair == self.config.max_structured_output_repairs {
+                warn!(
+                    "Structured output rejected after {} repair attempts, falling back to text parsing: {}",
+                    repair, error
+                );
+                break;
+            }
+
+            debug!("Requesting structured output repair: {}", error);
+            attempt.original_query = format!(
+                "{}\n\n{}",
+                request.original_query,
+                structured_output::repair_instructions(&error, &response_text)
+            );
+            response_text = self.dispatch(&attempt, tools).await?;
+        }
+
+        Ok((
+            "text",
+            self.parse_research_response(&response_text, request),
+        ))
+    }
+
     /// Execute research with cross-validation across multiple providers (placeholder)
     #[allow(dead_code)]
     async fn execute_with_cross_validation(
@@ -602,6 +848,8 @@ Ensure your response is:
             query: request.original_query.clone(),
             strategy: Some(search_strategy),
             fusion_method: Some(FusionMethod::ReciprocalRankFusion),
+            score_normalization: None,
+            rrf_k: None,
             options: SearchOptions {
                 limit: self.config.max_context_documents,
                 threshold: Some(self.config.context_relevance_threshold),
@@ -731,6 +979,20 @@ impl<T: ProviderManagerTrait> ResearchEngine for MultiProviderResearchEngine<T>
         }
     }
 
+    async fn generate_research_with_tools(
+        &self,
+        request: &ClassifiedRequest,
+        tools: &ToolRegistry,
+    ) -> Result<ResearchResult, ResearchEngineError> {
+        self.execute_validated_research(request, Some(tools))
+            .await
+            .map_err(|e| {
+                ResearchEngineError::ApiError(crate::api::ApiError::ServiceUnavailable(
+                    e.to_string(),
+                ))
+            })
+    }
+
     async fn generate_research_with_context(
         &self,
         request: &ClassifiedRequest,
@@ -1056,4 +1318,276 @@ This is the implementation guidance with code examples.
             "This is the implementation guidance with code examples."
         );
     }
+
+    /// Replies with scripted responses in order and records every prompt
+    #[derive(Debug, Default)]
+    struct ScriptedProviderManager {
+        responses: std::sync::Mutex<std::collections::VecDeque<String>>,
+        prompts: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl ScriptedProviderManager {
+        fn new(responses: &[&str]) -> Self {
+            Self {
+                responses: std::sync::Mutex::new(responses.iter().map(|r| r.to_string()).collect()),
+                prompts: Default::default(),
+            }
+        }
+
+        fn prompts(&self) -> Vec<String> {
+            self.prompts.lock().unwrap().clone()
+        }
+    }
+
+    impl ProviderManagerTrait for ScriptedProviderManager {
+        async fn execute_research(
+            &self,
+            request: &ClassifiedRequest,
+        ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+            self.prompts
+                .lock()
+                .unwrap()
+                .push(request.original_query.clone());
+            self.responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .ok_or_else(|| "no scripted response left".into())
+        }
+
+        async fn get_performance_stats(&self) -> HashMap<String, ProviderPerformanceStats> {
+            HashMap::new()
+        }
+
+        async fn health_check_all(
+            &self,
+        ) -> Result<HashMap<String, ProviderHealthStatus>, Box<dyn std::error::Error + Send + Sync>>
+        {
+            Ok(HashMap::new())
+        }
+    }
+
+    fn structured_config() -> MultiProviderConfig {
+        MultiProviderConfig {
+            enable_structured_output: true,
+            enable_quality_validation: false,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_structured_output_repairs_malformed_json() {
+        let manager = Arc::new(ScriptedProviderManager::new(&[
+            r#"{"immediate_answer": "Use channels", "evidence": [}"#,
+            r#"{"immediate_answer": "Use channels", "evidence": [{"content": "mpsc is bounded"}], "implementation_details": []}"#,
+        ]));
+        let engine = MultiProviderResearchEngine::new(manager.clone(), structured_config())
+            .await
+            .unwrap();
+
+        let result = engine
+            .generate_research(&create_test_request())
+            .await
+            .unwrap();
+
+        assert_eq!(result.immediate_answer, "Use channels");
+        assert_eq!(result.supporting_evidence[0].content, "mpsc is bounded");
+        assert_eq!(result.metadata.tags["response_format"], "structured");
+
+        let prompts = manager.prompts();
+        assert_eq!(prompts.len(), 2);
+        assert!(prompts[0].starts_with("Test research query"));
+        assert!(prompts[0].contains("\"immediate_answer\""));
+        assert!(prompts[1].contains("Your previous reply was rejected: invalid JSON"));
+    }
+
+    #[tokio::test]
+    async fn test_structured_output_falls_back_to_text_parsing() {
+        let manager = Arc::new(ScriptedProviderManager::new(&[
+            "not json",
+            "## Answer\nStill not json.\n\n## Evidence\nSome evidence.",
+        ]));
+        let config = MultiProviderConfig {
+            max_structured_output_repairs: 1,
+            ..structured_config()
+        };
+        let engine = MultiProviderResearchEngine::new(manager.clone(), config)
+            .await
+            .unwrap();
+
+        let result = engine
+            .generate_research(&create_test_request())
+            .await
+            .unwrap();
+
+        assert_eq!(manager.prompts().len(), 2);
+        assert_eq!(result.immediate_answer, "Still not json.");
+        assert_eq!(result.supporting_evidence[0].content, "Some evidence.");
+        assert_eq!(result.metadata.tags["response_format"], "text");
+    }
+
+    #[tokio::test]
+    async fn test_applied_prompt_adjustments_are_sent() {
+        use crate::prompts::TemplateAdjustment;
+
+        let manager = Arc::new(ScriptedProviderManager::new(&[
+            "## Answer\nUse channels.",
+            "## Answer\nUse channels.",
+        ]));
+        let adjustments = Arc::new(PromptAdjustments::new());
+        let config = MultiProviderConfig {
+            enable_quality_validation: false,
+            ..Default::default()
+        };
+        let engine = MultiProviderResearchEngine::new(manager.clone(), config)
+            .await
+            .unwrap()
+            .with_prompt_adjustments(adjustments.clone());
+        let request = create_test_request();
+        let template_name = engine.template_name_for(&request.research_type).unwrap();
+
+        let proposed = TemplateAdjustment::new(
+            template_name.clone(),
+            request.research_type.clone(),
+            "Include a runnable code example",
+            "low ratings",
+            0.9,
+        );
+        adjustments.propose(proposed.clone());
+        let result = engine.generate_research(&request).await.unwrap();
+        assert_eq!(result.metadata.tags[PROMPT_TEMPLATE_TAG], template_name);
+
+        adjustments.apply(&proposed.id, "auto").unwrap();
+        let result = engine.generate_research(&request).await.unwrap();
+
+        let prompts = manager.prompts();
+        assert!(!prompts[0].contains("Include a runnable code example"));
+        assert!(prompts[1].ends_with("- Include a runnable code example"));
+        assert_eq!(result.request.original_query, request.original_query);
+    }
+
+    #[tokio::test]
+    async fn test_versioned_prompt_template_is_rendered_and_tagged() {
+        use crate::prompts::PromptTemplate;
+        use fortitude_types::ProviderSelection;
+
+        let manager = Arc::new(ScriptedProviderManager::new(&[
+            "## Answer\nUse channels.",
+            "## Answer\nUse channels.",
+        ]));
+        let mut templates = PromptTemplateRegistry::new();
+        for (provider, version, template) in [
+            (None, 2, "Implement {{feature}} with {{technology}}"),
+            (Some("claude"), 5, "<task>{{query}}</task>"),
+        ] {
+            templates.insert(PromptTemplate {
+                name: "Implementation".to_string(),
+                research_type: ResearchType::Implementation,
+                provider: provider.map(str::to_string),
+                version,
+                description: String::new(),
+                template: template.to_string(),
+                source: None,
+            });
+        }
+        let config = MultiProviderConfig {
+            enable_quality_validation: false,
+            ..Default::default()
+        };
+        let engine = MultiProviderResearchEngine::new(manager.clone(), config)
+            .await
+            .unwrap()
+            .with_prompt_templates(Arc::new(templates));
+
+        let mut request = create_test_request();
+        let result = engine.generate_research(&request).await.unwrap();
+        assert_eq!(result.metadata.tags[PROMPT_TEMPLATE_TAG], "Implementation");
+        assert_eq!(result.metadata.tags[PROMPT_TEMPLATE_VERSION_TAG], "2");
+
+        request.provider_selection = Some(ProviderSelection {
+            provider: "claude".to_string(),
+            model: None,
+        });
+        let result = engine.generate_research(&request).await.unwrap();
+        assert_eq!(result.metadata.tags[PROMPT_TEMPLATE_VERSION_TAG], "5");
+
+        let prompts = manager.prompts();
+        assert_eq!(
+            prompts[0],
+            "Implement Test research query with rust (tokio)"
+        );
+        assert_eq!(prompts[1], "<task>Test research query</task>");
+    }
+
+    #[tokio::test]
+    async fn test_experiment_routes_to_arm_version_and_records_quality() {
+        use crate::prompts::{ExperimentArm, PromptExperiment, PromptTemplate};
+
+        let manager = Arc::new(ScriptedProviderManager::new(&[
+            "## Answer\nUse channels.",
+            "## Answer\nUse channels.",
+        ]));
+        let mut templates = PromptTemplateRegistry::new();
+        for version in [1, 2] {
+            templates.insert(PromptTemplate {
+                name: "Implementation".to_string(),
+                research_type: ResearchType::Implementation,
+                provider: None,
+                version,
+                description: String::new(),
+                template: format!("v{version}: {{{{query}}}}"),
+                source: None,
+            });
+        }
+        let experiment = |traffic_percentage| PromptExperiment {
+            name: "implementation-v2".to_string(),
+            research_type: ResearchType::Implementation,
+            provider: None,
+            control_version: 1,
+            variant_version: 2,
+            traffic_percentage,
+            enabled: true,
+        };
+        let templates = Arc::new(templates);
+        let config = MultiProviderConfig {
+            enable_quality_validation: false,
+            ..Default::default()
+        };
+
+        let request = create_test_request();
+        for (traffic_percentage, arm, version) in [
+            (100.0, ExperimentArm::Variant, "2"),
+            (0.0, ExperimentArm::Control, "1"),
+        ] {
+            let runner = Arc::new(PromptExperimentRunner::new(vec![experiment(
+                traffic_percentage,
+            )]));
+            let engine = MultiProviderResearchEngine::new(manager.clone(), config.clone())
+                .await
+                .unwrap()
+                .with_prompt_templates(templates.clone())
+                .with_prompt_experiments(runner.clone());
+
+            let result = engine.generate_research(&request).await.unwrap();
+            assert_eq!(result.metadata.tags[PROMPT_TEMPLATE_VERSION_TAG], version);
+            assert_eq!(
+                result.metadata.tags[PROMPT_EXPERIMENT_TAG],
+                "implementation-v2"
+            );
+            assert_eq!(
+                result.metadata.tags[PROMPT_EXPERIMENT_ARM_TAG],
+                arm.as_str()
+            );
+
+            let observations = runner.observations();
+            assert_eq!(observations.len(), 1);
+            assert_eq!(observations[0].arm, arm);
+            assert_eq!(observations[0].request_id, request.id);
+            assert_eq!(observations[0].quality_score, 0.8);
+        }
+
+        let prompts = manager.prompts();
+        assert_eq!(prompts[0], "v2: Test research query");
+        assert_eq!(prompts[1], "v1: Test research query");
+    }
 }
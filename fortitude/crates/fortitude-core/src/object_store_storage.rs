@@ -0,0 +1,820 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Object-store storage backend keeping results in S3, GCS or Azure Blob Storage
+//! [`ObjectStoreStorage`] implements [`Storage`] on top of the `object_store`
+//! crate so the reference library survives redeploys of stateless hosts.
+//! Results are laid out like [`FileStorage`](crate::storage::FileStorage)'s,
+//! as `<prefix>/research_results/<type>/<key>.json`, and large results are
+//! uploaded in parts. The cache index and keyword search index are kept in a
+//! local metadata cache file; when that file is missing the index is rebuilt
+//! by listing each research type's prefix in the store.
+
+use crate::keyword_index::KeywordIndex;
+use crate::sqlite_storage::fallback_cache_key;
+use crate::storage::{entry_metadata, index_entry_for, search_index_entries};
+use chrono::Utc;
+use fortitude_types::{
+    CacheAnalytics, CacheEntry, CacheOperation, CachePerformanceMonitor, CachePerformanceStatus,
+    CacheStats, CacheWarmingStats, HitRateTrend, IndexEntry, ResearchResult, ResearchType,
+    SearchQuery, SearchResult, Storage, StorageConfig, StorageError,
+};
+use futures::TryStreamExt;
+use object_store::path::Path as ObjectPath;
+use object_store::{ObjectStore, PutPayload, WriteMultipart};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, info, warn};
+
+/// Metadata cache file name used under the storage base path
+pub const DEFAULT_OBJECT_STORE_METADATA_FILE: &str = "object_store_metadata.json";
+
+/// Part size for multipart uploads; S3 rejects smaller non-final parts
+const MULTIPART_PART_BYTES: usize = 5 * 1024 * 1024;
+
+/// Environment variable prefixes read as store options, as the store builders' `from_env` do
+const CREDENTIAL_ENV_PREFIXES: [&str; 3] = ["AWS_", "GOOGLE_", "AZURE_"];
+
+/// Object store location and upload settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectStoreConfig {
+    /// Store URL such as `s3://bucket/prefix`, `gs://bucket` or `az://container/prefix`
+    pub url: String,
+    /// Store builder options such as `aws_region`; `AWS_*`, `GOOGLE_*` and
+    /// `AZURE_*` environment variables fill in options not given here
+    pub options: HashMap<String, String>,
+    /// Results at least this large are uploaded in parts
+    pub multipart_threshold_bytes: usize,
+    /// Local metadata cache file (defaults to `<base_path>/object_store_metadata.json`)
+    pub metadata_cache_path: Option<PathBuf>,
+}
+
+impl Default for ObjectStoreConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            options: HashMap::new(),
+            multipart_threshold_bytes: 8 * 1024 * 1024,
+            metadata_cache_path: None,
+        }
+    }
+}
+
+impl ObjectStoreConfig {
+    /// Create a configuration for the store at `url`
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set a store builder option
+    pub fn with_option(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.options.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the size from which results are uploaded in parts
+    pub fn with_multipart_threshold_bytes(mut self, bytes: usize) -> Self {
+        self.multipart_threshold_bytes = bytes;
+        self
+    }
+
+    /// Set the local metadata cache file
+    pub fn with_metadata_cache_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.metadata_cache_path = Some(path.into());
+        self
+    }
+
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<(), String> {
+        if self.url.trim().is_empty() {
+            return Err("url must not be empty".to_string());
+        }
+        url::Url::parse(&self.url).map_err(|e| format!("invalid url {:?}: {e}", self.url))?;
+        if self.multipart_threshold_bytes == 0 {
+            return Err("multipart_threshold_bytes must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+
+    /// Configured options plus credential environment variables not set explicitly
+    fn store_options(&self) -> HashMap<String, String> {
+        let mut options = self.options.clone();
+        for (key, value) in std::env::vars() {
+            if CREDENTIAL_ENV_PREFIXES.iter().any(|p| key.starts_with(p)) {
+                options.entry(key.to_lowercase()).or_insert(value);
+            }
+        }
+        options
+    }
+}
+
+/// Cache and search index entries persisted in the local metadata cache
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ObjectMetadata {
+    entries: HashMap<String, CacheEntry>,
+    index: HashMap<String, IndexEntry>,
+    /// Rebuilt from `index` when the metadata cache is loaded
+    #[serde(skip)]
+    keywords: KeywordIndex,
+}
+
+impl ObjectMetadata {
+    fn insert(&mut self, entry: CacheEntry, index_entry: IndexEntry) {
+        self.keywords.insert(&index_entry);
+        self.index.insert(entry.key.clone(), index_entry);
+        self.entries.insert(entry.key.clone(), entry);
+    }
+
+    fn remove(&mut self, cache_key: &str) -> Option<CacheEntry> {
+        self.keywords.remove(cache_key);
+        self.index.remove(cache_key);
+        self.entries.remove(cache_key)
+    }
+}
+
+/// Storage backed by an object store bucket or container
+pub struct ObjectStoreStorage {
+    store: Arc<dyn ObjectStore>,
+    prefix: ObjectPath,
+    config: StorageConfig,
+    object_config: ObjectStoreConfig,
+    metadata_path: PathBuf,
+    metadata: tokio::sync::Mutex<ObjectMetadata>,
+    analytics: Mutex<CacheAnalytics>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ObjectStoreStorage {
+    /// Connect to the store at `object_config.url`
+    pub async fn open(
+        object_config: ObjectStoreConfig,
+        config: StorageConfig,
+    ) -> Result<Self, StorageError> {
+        object_config.validate().map_err(StorageError::Cache)?;
+        let url = url::Url::parse(&object_config.url)
+            .map_err(|e| StorageError::Cache(format!("Invalid object store URL: {e}")))?;
+        let (store, prefix) = object_store::parse_url_opts(&url, object_config.store_options())
+            .map_err(store_error)?;
+        Self::with_store(Arc::from(store), prefix, object_config, config).await
+    }
+
+    /// Use an existing store, keeping results under `prefix`
+    pub async fn with_store(
+        store: Arc<dyn ObjectStore>,
+        prefix: ObjectPath,
+        object_config: ObjectStoreConfig,
+        config: StorageConfig,
+    ) -> Result<Self, StorageError> {
+        let metadata_path = object_config
+            .metadata_cache_path
+            .clone()
+            .unwrap_or_else(|| config.base_path.join(DEFAULT_OBJECT_STORE_METADATA_FILE));
+        let cached = match tokio::fs::read_to_string(&metadata_path).await {
+            Ok(content) => match serde_json::from_str::<ObjectMetadata>(&content) {
+                Ok(mut metadata) => {
+                    metadata.keywords = KeywordIndex::from_entries(metadata.index.values());
+                    Some(metadata)
+                }
+                Err(e) => {
+                    warn!(
+                        "Ignoring unreadable object store metadata cache {}: {}",
+                        metadata_path.display(),
+                        e
+                    );
+                    None
+                }
+            },
+            Err(_) => None,
+        };
+
+        let storage = Self {
+            store,
+            prefix,
+            config,
+            object_config,
+            metadata_path,
+            metadata: tokio::sync::Mutex::new(cached.unwrap_or_default()),
+            analytics: Mutex::new(CacheAnalytics::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        };
+        if storage.metadata.lock().await.entries.is_empty() {
+            storage.sync_metadata().await?;
+        }
+        info!("Opened object store storage at {}", storage.prefix);
+        Ok(storage)
+    }
+
+    /// Path of the local metadata cache file
+    pub fn metadata_cache_path(&self) -> &std::path::Path {
+        &self.metadata_path
+    }
+
+    /// Prefix holding the results of one research type
+    pub fn research_type_prefix(&self, research_type: &ResearchType) -> ObjectPath {
+        self.prefix
+            .child("research_results")
+            .child(research_type.to_string().to_lowercase())
+    }
+
+    fn object_path(&self, cache_key: &str, research_type: &ResearchType) -> ObjectPath {
+        self.research_type_prefix(research_type)
+            .child(format!("{cache_key}.json"))
+    }
+
+    /// Rebuild the local metadata from the store, listing each research type's
+    /// prefix and reading only objects missing from or changed since the
+    /// cached metadata. Returns the number of objects read.
+    pub async fn sync_metadata(&self) -> Result<usize, StorageError> {
+        let mut metadata = self.metadata.lock().await;
+        let mut synced = ObjectMetadata::default();
+        let mut fetched = 0;
+
+        for research_type in ResearchType::all() {
+            let prefix = self.research_type_prefix(&research_type);
+            let objects: Vec<_> = self
+                .store
+                .list(Some(&prefix))
+                .try_collect()
+                .await
+                .map_err(store_error)?;
+
+            for object in objects {
+                let Some(cache_key) = object
+                    .location
+                    .filename()
+                    .and_then(|name| name.strip_suffix(".json"))
+                    .map(str::to_string)
+                else {
+                    continue;
+                };
+                let unchanged = metadata.entries.get(&cache_key).filter(|entry| {
+                    entry.size_bytes == object.size as u64
+                        && entry.created_at >= object.last_modified
+                });
+                if let (Some(entry), Some(index_entry)) =
+                    (unchanged, metadata.index.get(&cache_key))
+                {
+                    synced.insert(entry.clone(), index_entry.clone());
+                    continue;
+                }
+
+                let Some(result) = self.read_object(&object.location).await? else {
+                    continue;
+                };
+                fetched += 1;
+                let mut entry =
+                    self.cache_entry_for(&result, &cache_key, &object.location, object.size);
+                entry.created_at = object.last_modified;
+                entry.last_accessed = object.last_modified;
+                entry.expires_at = object.last_modified
+                    + chrono::Duration::seconds(self.config.cache_expiration_seconds as i64);
+                synced.insert(entry, index_entry_for(&result, &cache_key));
+            }
+        }
+
+        *metadata = synced;
+        self.save_metadata(&metadata).await?;
+        info!(
+            "Synced object store metadata: {} entries, {} read from the store",
+            metadata.entries.len(),
+            fetched
+        );
+        Ok(fetched)
+    }
+
+    async fn read_object(
+        &self,
+        location: &ObjectPath,
+    ) -> Result<Option<ResearchResult>, StorageError> {
+        let bytes = match self.store.get(location).await {
+            Ok(object) => object.bytes().await.map_err(store_error)?,
+            Err(object_store::Error::NotFound { .. }) => return Ok(None),
+            Err(e) => return Err(store_error(e)),
+        };
+        match serde_json::from_slice(&bytes) {
+            Ok(result) => Ok(Some(result)),
+            Err(e) => {
+                warn!("Skipping unreadable object {}: {}", location, e);
+                Ok(None)
+            }
+        }
+    }
+
+    async fn write_object(&self, location: &ObjectPath, json: Vec<u8>) -> Result<(), StorageError> {
+        if json.len() < self.object_config.multipart_threshold_bytes {
+            self.store
+                .put(location, PutPayload::from(json))
+                .await
+                .map_err(store_error)?;
+            return Ok(());
+        }
+
+        debug!("Uploading {} bytes to {} in parts", json.len(), location);
+        let upload = self
+            .store
+            .put_multipart(location)
+            .await
+            .map_err(store_error)?;
+        let mut writer = WriteMultipart::new_with_chunk_size(upload, MULTIPART_PART_BYTES);
+        writer.write(&json);
+        writer.finish().await.map_err(store_error)?;
+        Ok(())
+    }
+
+    fn cache_entry_for(
+        &self,
+        result: &ResearchResult,
+        cache_key: &str,
+        location: &ObjectPath,
+        size_bytes: usize,
+    ) -> CacheEntry {
+        let json = serde_json::to_vec(result).unwrap_or_default();
+        let mut entry = CacheEntry::new(
+            cache_key.to_string(),
+            PathBuf::from(location.as_ref()),
+            result.request.research_type.clone(),
+            result.request.original_query.clone(),
+            size_bytes as u64,
+            format!("{:x}", md5::compute(&json)),
+            self.config.cache_expiration_seconds,
+        );
+        entry.metadata.extend(entry_metadata(&result.request));
+        entry
+    }
+
+    async fn save_metadata(&self, metadata: &ObjectMetadata) -> Result<(), StorageError> {
+        if let Some(parent) = self
+            .metadata_path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+        {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(StorageError::Io)?;
+        }
+        let json = serde_json::to_string(metadata)
+            .map_err(|e| StorageError::Serialization(e.to_string()))?;
+        tokio::fs::write(&self.metadata_path, json)
+            .await
+            .map_err(StorageError::Io)
+    }
+
+    /// Find a result stored by another instance since the metadata was synced
+    async fn probe_store(
+        &self,
+        cache_key: &str,
+    ) -> Result<Option<(ResearchResult, ObjectPath)>, StorageError> {
+        for research_type in ResearchType::all() {
+            let location = self.object_path(cache_key, &research_type);
+            if let Some(result) = self.read_object(&location).await? {
+                return Ok(Some((result, location)));
+            }
+        }
+        Ok(None)
+    }
+
+    fn hit_rate(&self) -> (u64, u64, f64) {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let rate = if hits + misses > 0 {
+            hits as f64 / (hits + misses) as f64
+        } else {
+            0.0
+        };
+        (hits, misses, rate)
+    }
+}
+
+#[async_trait::async_trait]
+impl Storage for ObjectStoreStorage {
+    async fn store(&self, result: &ResearchResult) -> Result<String, StorageError> {
+        let cache_key = if !result.metadata.cache_key.is_empty() {
+            result.metadata.cache_key.clone()
+        } else {
+            fallback_cache_key(result)
+        };
+        debug!("Storing research result in object store: {}", cache_key);
+
+        let json = serde_json::to_vec_pretty(result)
+            .map_err(|e| StorageError::Serialization(e.to_string()))?;
+        let size = json.len();
+        let location = self.object_path(&cache_key, &result.request.research_type);
+        self.write_object(&location, json).await?;
+
+        let mut metadata = self.metadata.lock().await;
+        if let Some(previous) = metadata.entries.get(&cache_key) {
+            // A result re-stored under another research type leaves its old object behind
+            let previous = ObjectPath::from(previous.file_path.to_string_lossy().as_ref());
+            if previous != location {
+                if let Err(e) = self.store.delete(&previous).await {
+                    warn!("Failed to delete superseded object {}: {}", previous, e);
+                }
+            }
+        }
+        let entry = self.cache_entry_for(result, &cache_key, &location, size);
+        metadata.insert(entry, index_entry_for(result, &cache_key));
+        self.save_metadata(&metadata).await?;
+
+        info!("Stored research result in object store: {}", location);
+        Ok(cache_key)
+    }
+
+    async fn retrieve(&self, cache_key: &str) -> Result<Option<ResearchResult>, StorageError> {
+        let location = {
+            let metadata = self.metadata.lock().await;
+            metadata.entries.get(cache_key).map(|entry| {
+                (
+                    ObjectPath::from(entry.file_path.to_string_lossy().as_ref()),
+                    entry.is_expired(),
+                )
+            })
+        };
+
+        let expired = matches!(location, Some((_, true)));
+        let found = match location {
+            Some((_, true)) => {
+                warn!("Cache entry expired: {}", cache_key);
+                None
+            }
+            Some((location, false)) => self
+                .read_object(&location)
+                .await?
+                .map(|result| (result, location)),
+            None => self.probe_store(cache_key).await?,
+        };
+
+        let mut metadata = self.metadata.lock().await;
+        match found {
+            Some((result, location)) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                match metadata.entries.get_mut(cache_key) {
+                    Some(entry) => entry.touch(),
+                    None => {
+                        let size = serde_json::to_vec_pretty(&result)
+                            .map(|json| json.len())
+                            .unwrap_or_default();
+                        let entry = self.cache_entry_for(&result, cache_key, &location, size);
+                        metadata.insert(entry, index_entry_for(&result, cache_key));
+                        self.save_metadata(&metadata).await?;
+                    }
+                }
+                Ok(Some(result))
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                // The object was deleted from the store by someone else
+                if !expired && metadata.remove(cache_key).is_some() {
+                    self.save_metadata(&metadata).await?;
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    async fn delete(&self, cache_key: &str) -> Result<(), StorageError> {
+        let mut metadata = self.metadata.lock().await;
+        let locations = match metadata.remove(cache_key) {
+            Some(entry) => vec![ObjectPath::from(entry.file_path.to_string_lossy().as_ref())],
+            None => ResearchType::all()
+                .iter()
+                .map(|research_type| self.object_path(cache_key, research_type))
+                .collect(),
+        };
+        for location in locations {
+            match self.store.delete(&location).await {
+                Ok(()) | Err(object_store::Error::NotFound { .. }) => {}
+                Err(e) => return Err(store_error(e)),
+            }
+        }
+        self.save_metadata(&metadata).await?;
+        info!("Deleted research result from object store: {}", cache_key);
+        Ok(())
+    }
+
+    async fn list_cache_entries(&self) -> Result<Vec<CacheEntry>, StorageError> {
+        let metadata = self.metadata.lock().await;
+        let mut entries: Vec<_> = metadata.entries.values().cloned().collect();
+        entries.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(entries)
+    }
+
+    async fn get_cache_stats(&self) -> Result<CacheStats, StorageError> {
+        let entries = self.list_cache_entries().await?;
+        let (hits, misses, hit_rate) = self.hit_rate();
+        let mut stats = CacheStats {
+            total_entries: entries.len(),
+            hits,
+            misses,
+            hit_rate,
+            ..CacheStats::default()
+        };
+
+        for entry in &entries {
+            stats.total_size_bytes += entry.size_bytes;
+            if entry.is_expired() {
+                stats.expired_entries += 1;
+            }
+            let type_stats = stats
+                .by_research_type
+                .entry(entry.research_type.clone())
+                .or_default();
+            type_stats.entries += 1;
+            type_stats.size_bytes += entry.size_bytes;
+        }
+        if !entries.is_empty() {
+            let total_age: u64 = entries.iter().map(|e| e.age_seconds()).sum();
+            stats.average_age_seconds = total_age as f64 / entries.len() as f64;
+        }
+        stats.newest_entry = entries.into_iter().max_by_key(|e| e.created_at);
+        stats.analytics = self.analytics.lock().unwrap().clone();
+        Ok(stats)
+    }
+
+    async fn cleanup_expired(&self) -> Result<u64, StorageError> {
+        let expired: Vec<String> = {
+            let metadata = self.metadata.lock().await;
+            metadata
+                .entries
+                .values()
+                .filter(|entry| entry.is_expired())
+                .map(|entry| entry.key.clone())
+                .collect()
+        };
+        for cache_key in &expired {
+            self.delete(cache_key).await?;
+        }
+        info!("Cleaned up {} expired object store entries", expired.len());
+        Ok(expired.len() as u64)
+    }
+
+    async fn search(&self, query: &SearchQuery) -> Result<Vec<SearchResult>, StorageError> {
+        let metadata = self.metadata.lock().await;
+        search_index_entries(&metadata.index, &metadata.keywords, query)
+    }
+
+    async fn update_index(&self) -> Result<(), StorageError> {
+        self.sync_metadata().await?;
+        Ok(())
+    }
+
+    async fn record_cache_operation(&self, operation: CacheOperation) -> Result<(), StorageError> {
+        debug!("Recording cache operation: {:?}", operation.operation_type);
+        Ok(())
+    }
+
+    async fn get_performance_monitor(&self) -> Result<CachePerformanceMonitor, StorageError> {
+        let (hits, misses, current_hit_rate) = self.hit_rate();
+        let target_hit_rate = 0.8;
+        let status = if hits + misses == 0 {
+            CachePerformanceStatus::Unknown
+        } else if current_hit_rate >= target_hit_rate {
+            CachePerformanceStatus::Optimal
+        } else if current_hit_rate >= target_hit_rate * 0.8 {
+            CachePerformanceStatus::Degraded
+        } else {
+            CachePerformanceStatus::Critical
+        };
+        Ok(CachePerformanceMonitor {
+            target_hit_rate,
+            current_hit_rate,
+            status,
+            recent_operations: Vec::new(),
+            alerts: Vec::new(),
+        })
+    }
+
+    async fn update_analytics(&self, analytics: CacheAnalytics) -> Result<(), StorageError> {
+        *self.analytics.lock().unwrap() = analytics;
+        Ok(())
+    }
+
+    async fn get_key_optimization_recommendations(&self) -> Result<Vec<String>, StorageError> {
+        let (_, _, hit_rate) = self.hit_rate();
+        let mut recommendations = Vec::new();
+        if hit_rate < 0.8 {
+            recommendations.push(
+                "Consider implementing cache warming for frequently accessed content".to_string(),
+            );
+        }
+        if recommendations.is_empty() {
+            recommendations
+                .push("Cache performance is optimal - no specific recommendations".to_string());
+        }
+        Ok(recommendations)
+    }
+
+    async fn warm_cache(&self, entries: Vec<String>) -> Result<CacheWarmingStats, StorageError> {
+        let mut stats = CacheWarmingStats {
+            active_strategies: vec!["manual_warming".to_string()],
+            ..Default::default()
+        };
+        let requested = entries.len() as u64;
+        let found = {
+            let metadata = self.metadata.lock().await;
+            entries
+                .iter()
+                .filter(|key| metadata.entries.contains_key(key.as_str()))
+                .count() as u64
+        };
+        stats.items_warmed = found;
+        stats.warming_failures = requested - found;
+        if requested > 0 {
+            stats.strategy_effectiveness = found as f64 / requested as f64;
+        }
+        Ok(stats)
+    }
+
+    async fn get_hit_rate_trends(
+        &self,
+        _timeframe_hours: u64,
+    ) -> Result<Vec<HitRateTrend>, StorageError> {
+        let (hits, misses, hit_rate) = self.hit_rate();
+        Ok(vec![HitRateTrend {
+            timestamp: Utc::now(),
+            hit_rate,
+            request_count: hits + misses,
+            context: "current_period".to_string(),
+        }])
+    }
+}
+
+fn store_error(e: object_store::Error) -> StorageError {
+    StorageError::Cache(format!("Object store error: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_types::{AudienceContext, ClassifiedRequest, DomainContext, ResearchMetadata};
+    use object_store::memory::InMemory;
+    use tempfile::TempDir;
+
+    async fn open_storage(
+        store: Arc<dyn ObjectStore>,
+        temp_dir: &TempDir,
+        object_config: ObjectStoreConfig,
+    ) -> ObjectStoreStorage {
+        ObjectStoreStorage::with_store(
+            store,
+            ObjectPath::from("fortitude"),
+            object_config,
+            StorageConfig {
+                base_path: temp_dir.path().to_path_buf(),
+                ..StorageConfig::default()
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    fn result(
+        cache_key: &str,
+        research_type: ResearchType,
+        query: &str,
+        answer: &str,
+    ) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            query.to_string(),
+            research_type,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 10,
+            sources_consulted: vec![],
+            quality_score: 0.8,
+            cache_key: cache_key.to_string(),
+            tags: HashMap::new(),
+        };
+        ResearchResult::new(request, answer.to_string(), vec![], vec![], metadata)
+    }
+
+    #[tokio::test]
+    async fn test_store_lays_out_results_by_research_type() {
+        let temp_dir = TempDir::new().unwrap();
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let storage = open_storage(store.clone(), &temp_dir, ObjectStoreConfig::default()).await;
+
+        let stored = result(
+            "pin",
+            ResearchType::Implementation,
+            "How do I pin?",
+            "Use Box::pin.",
+        );
+        storage.store(&stored).await.unwrap();
+        storage
+            .store(&result(
+                "axum",
+                ResearchType::Decision,
+                "Axum or actix?",
+                "Axum.",
+            ))
+            .await
+            .unwrap();
+
+        let prefix = storage.research_type_prefix(&ResearchType::Implementation);
+        let listed: Vec<_> = store.list(Some(&prefix)).try_collect().await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(
+            listed[0].location.as_ref(),
+            "fortitude/research_results/implementation/pin.json"
+        );
+        assert_eq!(storage.retrieve("pin").await.unwrap().unwrap(), stored);
+
+        let found = storage
+            .search(&SearchQuery::new("axum".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].entry.cache_key, "axum");
+
+        storage.delete("pin").await.unwrap();
+        assert!(storage.retrieve("pin").await.unwrap().is_none());
+        assert_eq!(storage.list_cache_entries().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_metadata_rebuilt_from_store_after_redeploy() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let first_host = TempDir::new().unwrap();
+        let storage = open_storage(store.clone(), &first_host, ObjectStoreConfig::default()).await;
+        let stored = result(
+            "pin",
+            ResearchType::Learning,
+            "What is pinning?",
+            "Pinning...",
+        );
+        storage.store(&stored).await.unwrap();
+        assert!(storage.metadata_cache_path().exists());
+
+        // A fresh host has no metadata cache and lists the store instead
+        let second_host = TempDir::new().unwrap();
+        let redeployed = open_storage(store, &second_host, ObjectStoreConfig::default()).await;
+        let entries = redeployed.list_cache_entries().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].research_type, ResearchType::Learning);
+        assert_eq!(redeployed.retrieve("pin").await.unwrap().unwrap(), stored);
+        let found = redeployed
+            .search(&SearchQuery::new("pinning".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+
+        // Unchanged objects are not read again on the next sync
+        assert_eq!(redeployed.sync_metadata().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_large_results_use_multipart_upload() {
+        let temp_dir = TempDir::new().unwrap();
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let storage = open_storage(
+            store,
+            &temp_dir,
+            ObjectStoreConfig::default().with_multipart_threshold_bytes(1024),
+        )
+        .await;
+
+        let answer = "Large answer. ".repeat(1000);
+        let stored = result("large", ResearchType::Validation, "Validate it", &answer);
+        storage.store(&stored).await.unwrap();
+        assert_eq!(storage.retrieve("large").await.unwrap().unwrap(), stored);
+        assert!(storage.list_cache_entries().await.unwrap()[0].size_bytes > 1024);
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(ObjectStoreConfig::default().validate().is_err());
+        assert!(ObjectStoreConfig::new("s3://bucket/prefix")
+            .validate()
+            .is_ok());
+        assert!(ObjectStoreConfig::new("not a url").validate().is_err());
+        assert!(ObjectStoreConfig::new("gs://bucket")
+            .with_multipart_threshold_bytes(0)
+            .validate()
+            .is_err());
+    }
+}
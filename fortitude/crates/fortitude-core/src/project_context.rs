@@ -0,0 +1,610 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Project context collection for research queries about a local repository
+//! Scans a Rust project's manifest, README and crate roots so queries can be
+//! researched against the project without spelling out its technology,
+//! frameworks and structure by hand. The findings fill the request's
+//! [`DomainContext`] and a compact summary is added to the prompt.
+
+use fortitude_types::DomainContext;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Default size budget in characters for a rendered project summary
+pub const DEFAULT_PROJECT_CONTEXT_BUDGET: usize = 1500;
+
+/// Longest README excerpt kept in the summary
+const MAX_README_CHARS: usize = 300;
+
+/// Most dependencies listed in the summary
+const MAX_LISTED_DEPENDENCIES: usize = 20;
+
+/// Dependencies reported as frameworks, with the project type they imply
+const KNOWN_FRAMEWORKS: &[(&str, Option<&str>)] = &[
+    ("actix-web", Some("web")),
+    ("axum", Some("web")),
+    ("rocket", Some("web")),
+    ("warp", Some("web")),
+    ("poem", Some("web")),
+    ("leptos", Some("web")),
+    ("yew", Some("web")),
+    ("tonic", Some("service")),
+    ("clap", Some("cli")),
+    ("ratatui", Some("cli")),
+    ("bevy", Some("game")),
+    ("tauri", Some("desktop")),
+    ("embassy-executor", Some("embedded")),
+    ("tokio", None),
+    ("async-std", None),
+    ("hyper", None),
+    ("reqwest", None),
+    ("serde", None),
+    ("diesel", None),
+    ("sqlx", None),
+    ("sea-orm", None),
+    ("tracing", None),
+];
+
+/// Errors that can occur while collecting project context
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ProjectContextError {
+    #[error("No Cargo.toml found in {0}")]
+    NoManifest(PathBuf),
+
+    #[error("Failed to read {path}: {message}")]
+    Io { path: PathBuf, message: String },
+
+    #[error("Invalid manifest {path}: {message}")]
+    Manifest { path: PathBuf, message: String },
+}
+
+/// What was learned about a project
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProjectContext {
+    /// Package name, or the directory name of a virtual workspace
+    pub name: String,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    /// Project type implied by the frameworks and crate roots (web, cli, library, ...)
+    pub project_type: String,
+    /// Well-known frameworks among the dependencies
+    pub frameworks: Vec<String>,
+    /// Edition, workspace and async runtime markers
+    pub tags: Vec<String>,
+    /// All dependency names, sorted
+    pub dependencies: Vec<String>,
+    /// Workspace member package names
+    pub members: Vec<String>,
+    /// First paragraph of the README
+    pub readme_excerpt: Option<String>,
+    /// Modules declared by the crate roots, as `crate::module`
+    pub modules: Vec<String>,
+    /// Public item signatures of the crate roots
+    pub signatures: Vec<String>,
+}
+
+impl ProjectContext {
+    /// Fill in a domain context from the project
+    ///
+    /// Frameworks and tags already present are kept and the project's are
+    /// added after them.
+    pub fn apply_to(&self, domain_context: &mut DomainContext) {
+        domain_context.technology = "rust".to_string();
+        domain_context.project_type = self.project_type.clone();
+        for framework in &self.frameworks {
+            if !domain_context.frameworks.contains(framework) {
+                domain_context.frameworks.push(framework.clone());
+            }
+        }
+        for tag in &self.tags {
+            if !domain_context.tags.contains(tag) {
+                domain_context.tags.push(tag.clone());
+            }
+        }
+    }
+
+    /// Domain context describing the project alone
+    pub fn domain_context(&self) -> DomainContext {
+        let mut domain_context = DomainContext {
+            frameworks: Vec::new(),
+            tags: Vec::new(),
+            ..DomainContext::default()
+        };
+        self.apply_to(&mut domain_context);
+        domain_context
+    }
+
+    /// Render the project as prompt text within `budget` characters
+    ///
+    /// Signatures that do not fit are dropped and counted in a trailing note.
+    pub fn render(&self, budget: usize) -> String {
+        let mut header = format!("Project context: {}", self.name);
+        if let Some(version) = &self.version {
+            header.push_str(&format!(" {version}"));
+        }
+        if let Some(description) = &self.description {
+            header.push_str(&format!(" - {description}"));
+        }
+
+        let mut lines = vec![header, format!("Project type: {}", self.project_type)];
+        if !self.members.is_empty() {
+            lines.push(format!("Workspace members: {}", self.members.join(", ")));
+        }
+        if !self.dependencies.is_empty() {
+            let listed = &self.dependencies[..self.dependencies.len().min(MAX_LISTED_DEPENDENCIES)];
+            let mut line = format!("Dependencies: {}", listed.join(", "));
+            if self.dependencies.len() > listed.len() {
+                line.push_str(&format!(
+                    " (+{} more)",
+                    self.dependencies.len() - listed.len()
+                ));
+            }
+            lines.push(line);
+        }
+        if let Some(excerpt) = &self.readme_excerpt {
+            lines.push(format!("README: {excerpt}"));
+        }
+        if !self.modules.is_empty() {
+            lines.push(format!("Modules: {}", self.modules.join(", ")));
+        }
+
+        let mut output = String::new();
+        for line in lines {
+            if !push_line(&mut output, &line, budget) {
+                return output;
+            }
+        }
+        if self.signatures.is_empty() || !push_line(&mut output, "Public items:", budget) {
+            return output;
+        }
+        for (index, signature) in self.signatures.iter().enumerate() {
+            let line = format!("- {signature}");
+            let remaining = self.signatures.len() - index;
+            let note = format!("({} more items omitted)", remaining - 1);
+            let reserved = if remaining > 1 { note.len() + 1 } else { 0 };
+            if output.len() + line.len() + 1 + reserved > budget {
+                push_line(
+                    &mut output,
+                    &format!("({remaining} more items omitted)"),
+                    budget,
+                );
+                break;
+            }
+            push_line(&mut output, &line, budget);
+        }
+        output
+    }
+}
+
+/// Builds project context from a repository on disk
+#[derive(Debug, Clone)]
+pub struct ProjectContextCollector {
+    budget: usize,
+}
+
+impl Default for ProjectContextCollector {
+    fn default() -> Self {
+        Self::new(DEFAULT_PROJECT_CONTEXT_BUDGET)
+    }
+}
+
+impl ProjectContextCollector {
+    /// Create a collector that renders summaries within `budget` characters
+    pub fn new(budget: usize) -> Self {
+        Self { budget }
+    }
+
+    /// Size budget for rendered summaries
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Scan the project rooted at `root`
+    ///
+    /// Reads the root `Cargo.toml`, the manifests of workspace members, the
+    /// README and each crate's `src/lib.rs` or `src/main.rs`. Crate roots
+    /// that fail to parse are skipped.
+    pub fn collect(&self, root: &Path) -> Result<ProjectContext, ProjectContextError> {
+        let manifest_path = root.join("Cargo.toml");
+        if !manifest_path.is_file() {
+            return Err(ProjectContextError::NoManifest(root.to_path_buf()));
+        }
+        let manifest = read_manifest(&manifest_path)?;
+
+        let mut context = ProjectContext::default();
+        let package = manifest.get("package").and_then(toml::Value::as_table);
+        context.name = package
+            .and_then(|p| p.get("name"))
+            .and_then(toml::Value::as_str)
+            .map(str::to_string)
+            .or_else(|| {
+                root.canonicalize()
+                    .ok()
+                    .and_then(|p| p.file_name().map(|n| n.to_string_lossy().into_owned()))
+            })
+            .unwrap_or_else(|| "project".to_string());
+        context.version = package_field(package, "version");
+        context.description = package_field(package, "description");
+
+        let mut dependencies = BTreeSet::new();
+        let mut crate_dirs = Vec::new();
+        let mut editions = BTreeSet::new();
+        if package.is_some() {
+            collect_dependencies(&manifest, &mut dependencies);
+            crate_dirs.push(root.to_path_buf());
+            editions.extend(package_field(package, "edition"));
+        }
+
+        if let Some(workspace) = manifest.get("workspace").and_then(toml::Value::as_table) {
+            if let Some(shared) = workspace
+                .get("dependencies")
+                .and_then(toml::Value::as_table)
+            {
+                dependencies.extend(shared.keys().cloned());
+            }
+            editions.extend(
+                workspace
+                    .get("package")
+                    .and_then(|p| p.get("edition"))
+                    .and_then(toml::Value::as_str)
+                    .map(str::to_string),
+            );
+            for member_dir in workspace_members(root, workspace) {
+                let Ok(member) = read_manifest(&member_dir.join("Cargo.toml")) else {
+                    continue;
+                };
+                let member_package = member.get("package").and_then(toml::Value::as_table);
+                if let Some(name) = package_field(member_package, "name") {
+                    if name != context.name {
+                        context.members.push(name);
+                    }
+                }
+                collect_dependencies(&member, &mut dependencies);
+                crate_dirs.push(member_dir);
+            }
+            context.tags.push("workspace".to_string());
+        }
+        context.members.sort();
+        context.dependencies = dependencies.into_iter().collect();
+
+        let mut has_lib = false;
+        let mut has_bin = false;
+        for dir in &crate_dirs {
+            has_lib |= dir.join("src/lib.rs").is_file();
+            has_bin |= dir.join("src/main.rs").is_file() || dir.join("src/bin").is_dir();
+            if let Some(source_root) = crate_root(dir) {
+                let crate_name = source_root
+                    .parent()
+                    .and_then(Path::parent)
+                    .filter(|dir| *dir != root)
+                    .and_then(|dir| dir.file_name())
+                    .map(|name| name.to_string_lossy().replace('-', "_"))
+                    .unwrap_or_else(|| "crate".to_string());
+                summarize_crate_root(&source_root, &crate_name, &mut context);
+            }
+        }
+
+        let framework_types: Vec<(&str, Option<&str>)> = KNOWN_FRAMEWORKS
+            .iter()
+            .copied()
+            .filter(|(name, _)| context.dependencies.iter().any(|dep| dep == name))
+            .collect();
+        context.frameworks = framework_types
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect();
+        context.project_type = framework_types
+            .iter()
+            .find_map(|(_, project_type)| *project_type)
+            .map(str::to_string)
+            .unwrap_or_else(|| match (has_lib, has_bin) {
+                (false, true) => "cli".to_string(),
+                _ => "library".to_string(),
+            });
+
+        context
+            .tags
+            .extend(editions.into_iter().map(|e| format!("edition-{e}")));
+        if context
+            .dependencies
+            .iter()
+            .any(|dep| dep == "tokio" || dep == "async-std")
+        {
+            context.tags.push("async".to_string());
+        }
+
+        context.readme_excerpt = readme_excerpt(root);
+        Ok(context)
+    }
+
+    /// Collect and render in one step, returning the context and its summary
+    pub fn summarize(&self, root: &Path) -> Result<(ProjectContext, String), ProjectContextError> {
+        let context = self.collect(root)?;
+        let summary = context.render(self.budget);
+        Ok((context, summary))
+    }
+}
+
+fn read_manifest(path: &Path) -> Result<toml::Table, ProjectContextError> {
+    let contents = std::fs::read_to_string(path).map_err(|e| ProjectContextError::Io {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    toml::from_str(&contents).map_err(|e| ProjectContextError::Manifest {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })
+}
+
+fn package_field(package: Option<&toml::Table>, field: &str) -> Option<String> {
+    package
+        .and_then(|p| p.get(field))
+        .and_then(toml::Value::as_str)
+        .map(str::to_string)
+}
+
+/// Names from the dependency tables, including target-specific ones
+fn collect_dependencies(manifest: &toml::Table, dependencies: &mut BTreeSet<String>) {
+    let tables = ["dependencies", "build-dependencies"];
+    for table in tables {
+        if let Some(deps) = manifest.get(table).and_then(toml::Value::as_table) {
+            dependencies.extend(deps.keys().cloned());
+        }
+    }
+    if let Some(targets) = manifest.get("target").and_then(toml::Value::as_table) {
+        for target in targets.values().filter_map(toml::Value::as_table) {
+            for table in tables {
+                if let Some(deps) = target.get(table).and_then(toml::Value::as_table) {
+                    dependencies.extend(deps.keys().cloned());
+                }
+            }
+        }
+    }
+}
+
+/// Member directories, expanding a trailing `/*` in member paths
+fn workspace_members(root: &Path, workspace: &toml::Table) -> Vec<PathBuf> {
+    let patterns = workspace
+        .get("members")
+        .and_then(toml::Value::as_array)
+        .map(|members| members.iter().filter_map(toml::Value::as_str))
+        .into_iter()
+        .flatten();
+
+    let mut dirs = Vec::new();
+    for pattern in patterns {
+        match pattern.strip_suffix("/*") {
+            Some(parent) => {
+                let Ok(entries) = std::fs::read_dir(root.join(parent)) else {
+                    continue;
+                };
+                let mut children: Vec<PathBuf> = entries
+                    .filter_map(Result::ok)
+                    .map(|entry| entry.path())
+                    .filter(|path| path.join("Cargo.toml").is_file())
+                    .collect();
+                children.sort();
+                dirs.extend(children);
+            }
+            None => dirs.push(root.join(pattern)),
+        }
+    }
+    dirs
+}
+
+fn crate_root(dir: &Path) -> Option<PathBuf> {
+    ["src/lib.rs", "src/main.rs"]
+        .into_iter()
+        .map(|file| dir.join(file))
+        .find(|path| path.is_file())
+}
+
+/// Record a crate root's module declarations and public item signatures
+fn summarize_crate_root(path: &Path, crate_name: &str, context: &mut ProjectContext) {
+    let Ok(source) = std::fs::read_to_string(path) else {
+        return;
+    };
+    let Ok(file) = syn::parse_file(&source) else {
+        return;
+    };
+    for item in &file.items {
+        if let syn::Item::Mod(module) = item {
+            context
+                .modules
+                .push(format!("{crate_name}::{}", module.ident));
+        }
+    }
+    // Items in inline modules and impls are left to the code context extractor
+    let extractor = crate::code_context::CodeContextExtractor::default();
+    if let Ok(summary) = extractor.extract(&source, "") {
+        context.signatures.extend(
+            summary
+                .items
+                .into_iter()
+                .filter(|item| !item.name.contains("::"))
+                .filter(|item| item.signature.starts_with("pub "))
+                .map(|item| item.signature),
+        );
+    }
+}
+
+/// First prose paragraph of the README, skipping headings, badges and code
+fn readme_excerpt(root: &Path) -> Option<String> {
+    let contents = ["README.md", "README", "readme.md"]
+        .into_iter()
+        .find_map(|name| std::fs::read_to_string(root.join(name)).ok())?;
+
+    let mut paragraph = Vec::new();
+    let mut in_code = false;
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        let skipped = in_code
+            || line.starts_with('#')
+            || line.starts_with("[![")
+            || line.starts_with("![")
+            || line.starts_with('<');
+        if line.is_empty() || skipped {
+            if !paragraph.is_empty() {
+                break;
+            }
+            continue;
+        }
+        paragraph.push(line);
+    }
+    if paragraph.is_empty() {
+        return None;
+    }
+
+    let text = paragraph.join(" ");
+    if text.chars().count() <= MAX_README_CHARS {
+        return Some(text);
+    }
+    let cut: String = text.chars().take(MAX_README_CHARS).collect();
+    let cut = cut.rsplit_once(' ').map_or(cut.as_str(), |(head, _)| head);
+    Some(format!("{cut}..."))
+}
+
+/// Append `line` to `output` if it fits within `budget`
+fn push_line(output: &mut String, line: &str, budget: usize) -> bool {
+    if output.len() + line.len() + 1 > budget {
+        return false;
+    }
+    output.push_str(line);
+    output.push('\n');
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, path: &str, contents: &str) {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn test_collect_single_crate() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "Cargo.toml",
+            r#"
+[package]
+name = "shortener"
+version = "0.3.0"
+edition = "2021"
+description = "URL shortening service"
+
+[dependencies]
+axum = "0.8"
+tokio = { version = "1", features = ["full"] }
+serde = "1"
+"#,
+        );
+        write(
+            root,
+            "README.md",
+            "# Shortener\n\n[![CI](badge.svg)](ci)\n\nShortens URLs and tracks\nclick counts.\n\n## Usage\n",
+        );
+        write(
+            root,
+            "src/lib.rs",
+            "pub mod routes;\nmod store;\n\npub struct Link { pub slug: String }\n\nfn helper() {}\n\npub async fn shorten(url: &str) -> Link { todo!() }\n",
+        );
+
+        let context = ProjectContextCollector::default().collect(root).unwrap();
+        assert_eq!(context.name, "shortener");
+        assert_eq!(context.project_type, "web");
+        assert_eq!(context.frameworks, ["axum", "tokio", "serde"]);
+        assert_eq!(context.tags, ["edition-2021", "async"]);
+        assert_eq!(
+            context.readme_excerpt.as_deref(),
+            Some("Shortens URLs and tracks click counts.")
+        );
+        assert_eq!(context.modules, ["crate::routes", "crate::store"]);
+        assert_eq!(context.signatures.len(), 2);
+        assert!(context.signatures[1].starts_with("pub async fn shorten"));
+
+        let summary = context.render(DEFAULT_PROJECT_CONTEXT_BUDGET);
+        assert!(summary.starts_with("Project context: shortener 0.3.0 - URL shortening service\n"));
+        assert!(summary.contains("Dependencies: axum, serde, tokio\n"));
+
+        let mut domain_context = DomainContext {
+            frameworks: vec!["tower".to_string()],
+            ..DomainContext::default()
+        };
+        context.apply_to(&mut domain_context);
+        assert_eq!(domain_context.project_type, "web");
+        assert_eq!(
+            domain_context.frameworks,
+            ["tower", "axum", "tokio", "serde"]
+        );
+    }
+
+    #[test]
+    fn test_collect_virtual_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nedition = \"2021\"\n",
+        );
+        write(
+            root,
+            "crates/tool-cli/Cargo.toml",
+            "[package]\nname = \"tool-cli\"\n\n[dependencies]\nclap = \"4\"\n",
+        );
+        write(
+            root,
+            "crates/tool-cli/src/main.rs",
+            "mod args;\nfn main() {}\n",
+        );
+        write(
+            root,
+            "crates/tool-core/Cargo.toml",
+            "[package]\nname = \"tool-core\"\n\n[target.'cfg(unix)'.dependencies]\nnix = \"0.29\"\n",
+        );
+        write(root, "crates/tool-core/src/lib.rs", "pub fn run() {}\n");
+
+        let context = ProjectContextCollector::default().collect(root).unwrap();
+        assert_eq!(context.members, ["tool-cli", "tool-core"]);
+        assert_eq!(context.dependencies, ["clap", "nix"]);
+        assert_eq!(context.project_type, "cli");
+        assert_eq!(context.tags, ["workspace", "edition-2021"]);
+        assert_eq!(context.modules, ["tool_cli::args"]);
+        assert_eq!(context.signatures, ["pub fn run()"]);
+
+        let summary = context.render(80);
+        assert!(summary.len() <= 80);
+    }
+
+    #[test]
+    fn test_collect_requires_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(matches!(
+            ProjectContextCollector::default().collect(dir.path()),
+            Err(ProjectContextError::NoManifest(_))
+        ));
+    }
+}
@@ -0,0 +1,795 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Prompt-budget profiles and pluggable tokenizers for research prompts
+// Profiles cap context documents, conversation history and answer length per request or
+// research type, and decide how prompts that exceed their token budget are handled
+use crate::model_catalog::ModelCatalog;
+use fortitude_types::{ClassifiedRequest, PromptBudget, ResearchType};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+use tracing::warn;
+
+/// Tokenizer used when a model names none or names one that is not registered
+pub const DEFAULT_TOKENIZER: &str = "heuristic";
+
+/// Profile used when the configuration does not name one
+pub const DEFAULT_BUDGET_PROFILE: &str = "standard";
+
+/// Counts tokens the way a particular model family does
+pub trait Tokenizer: Send + Sync + std::fmt::Debug {
+    fn count_tokens(&self, text: &str) -> u32;
+}
+
+/// Character-ratio tokenizer; the default assumes about four characters per token
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeuristicTokenizer {
+    pub chars_per_token: f64,
+}
+
+impl Default for HeuristicTokenizer {
+    fn default() -> Self {
+        Self {
+            chars_per_token: 4.0,
+        }
+    }
+}
+
+impl Tokenizer for HeuristicTokenizer {
+    fn count_tokens(&self, text: &str) -> u32 {
+        (text.chars().count() as f64 / self.chars_per_token).ceil() as u32
+    }
+}
+
+/// Word-ratio tokenizer for models whose vocabularies split most words in one or two tokens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WhitespaceTokenizer {
+    pub tokens_per_word: f64,
+}
+
+impl Default for WhitespaceTokenizer {
+    fn default() -> Self {
+        Self {
+            tokens_per_word: 1.3,
+        }
+    }
+}
+
+impl Tokenizer for WhitespaceTokenizer {
+    fn count_tokens(&self, text: &str) -> u32 {
+        (text.split_whitespace().count() as f64 * self.tokens_per_word).ceil() as u32
+    }
+}
+
+/// Byte-pair-encoding estimate in the style of OpenAI's cl100k tokenizer
+///
+/// Text is split the way tiktoken pre-tokenizes it: a leading space or symbol
+/// joins the following word, numbers split into groups of three digits, and
+/// symbol and newline runs stand alone. Each piece is then charged by length
+/// rather than looked up in a merge table.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BpeTokenizer;
+
+impl BpeTokenizer {
+    /// Letters a common English piece usually merges into one token
+    const LETTERS_PER_TOKEN: usize = 6;
+}
+
+impl Tokenizer for BpeTokenizer {
+    fn count_tokens(&self, text: &str) -> u32 {
+        let is_symbol = |c: char| !c.is_alphanumeric() && !c.is_whitespace();
+        let chars: Vec<char> = text.chars().collect();
+        let run_end = |start: usize, matches: &dyn Fn(char) -> bool| {
+            chars[start..]
+                .iter()
+                .position(|&c| !matches(c))
+                .map_or(chars.len(), |offset| start + offset)
+        };
+
+        let mut tokens = 0usize;
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+
+            if c == '\'' {
+                let rest: String = chars[i + 1..]
+                    .iter()
+                    .take(2)
+                    .collect::<String>()
+                    .to_lowercase();
+                let contraction = ["re", "ve", "ll"]
+                    .iter()
+                    .find(|suffix| rest.starts_with(*suffix))
+                    .map(|suffix| suffix.len())
+                    .or_else(|| rest.starts_with(['s', 't', 'm', 'd']).then_some(1));
+                if let Some(len) = contraction {
+                    tokens += 1;
+                    i += 1 + len;
+                    continue;
+                }
+            }
+
+            let leads_word = !c.is_alphanumeric()
+                && c != '\n'
+                && c != '\r'
+                && next.is_some_and(char::is_alphabetic);
+            let leads_symbols = c == ' ' && next.is_some_and(is_symbol);
+            let start = if leads_word || leads_symbols {
+                i + 1
+            } else {
+                i
+            };
+
+            let first = chars[start];
+            let end = if first.is_alphabetic() {
+                let end = run_end(start, &|c| c.is_alphabetic());
+                let word = &chars[start..end];
+                tokens += if word.iter().all(char::is_ascii) {
+                    word.len().div_ceil(Self::LETTERS_PER_TOKEN)
+                } else {
+                    // Scripts outside ASCII rarely merge beyond a character
+                    word.len()
+                };
+                end
+            } else if first.is_numeric() {
+                let end = run_end(start, &|c| c.is_numeric());
+                tokens += (end - start).div_ceil(3);
+                end
+            } else if first.is_whitespace() {
+                tokens += 1;
+                run_end(start, &|c| c.is_whitespace())
+            } else {
+                let end = run_end(start, &is_symbol);
+                tokens += (end - start).div_ceil(2);
+                end
+            };
+            i = end;
+        }
+        tokens as u32
+    }
+}
+
+/// Named tokenizers that catalog models refer to
+#[derive(Debug, Clone)]
+pub struct TokenizerRegistry {
+    tokenizers: HashMap<String, Arc<dyn Tokenizer>>,
+}
+
+impl Default for TokenizerRegistry {
+    fn default() -> Self {
+        Self::new()
+            .with_tokenizer(DEFAULT_TOKENIZER, Arc::new(HeuristicTokenizer::default()))
+            .with_tokenizer("whitespace", Arc::new(WhitespaceTokenizer::default()))
+            .with_tokenizer("cl100k", Arc::new(BpeTokenizer))
+    }
+}
+
+impl TokenizerRegistry {
+    /// Create an empty registry; lookups fall back to the heuristic tokenizer
+    pub fn new() -> Self {
+        Self {
+            tokenizers: HashMap::new(),
+        }
+    }
+
+    /// Register a tokenizer under `name`, replacing any existing one
+    pub fn with_tokenizer(
+        mut self,
+        name: impl Into<String>,
+        tokenizer: Arc<dyn Tokenizer>,
+    ) -> Self {
+        self.tokenizers.insert(name.into(), tokenizer);
+        self
+    }
+
+    /// Names of the registered tokenizers
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.tokenizers.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Tokenizer registered under `name`, or the heuristic tokenizer
+    pub fn get(&self, name: &str) -> Arc<dyn Tokenizer> {
+        match self.tokenizers.get(name) {
+            Some(tokenizer) => tokenizer.clone(),
+            None => {
+                warn!("Tokenizer '{}' is not registered, using heuristic", name);
+                self.tokenizers
+                    .get(DEFAULT_TOKENIZER)
+                    .cloned()
+                    .unwrap_or_else(|| Arc::new(HeuristicTokenizer::default()))
+            }
+        }
+    }
+}
+
+/// What to do when a prompt exceeds its token budget
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OverflowStrategy {
+    /// Cut the oldest conversation history first, then the attached code context
+    #[default]
+    Truncate,
+    /// Condense the contexts to their leading sentences, truncating if still too long
+    Summarize,
+    /// Fail the request
+    Reject,
+}
+
+impl std::fmt::Display for OverflowStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Truncate => "truncate",
+            Self::Summarize => "summarize",
+            Self::Reject => "reject",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Limits applied to a research prompt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromptBudgetProfile {
+    /// Maximum context documents retrieved for the prompt
+    pub max_context_docs: usize,
+    /// Maximum earlier conversation turns carried into the prompt
+    pub max_history_turns: usize,
+    /// Maximum tokens requested for the answer
+    pub max_answer_tokens: u32,
+    /// Maximum tokens for the query and its attached contexts
+    pub max_prompt_tokens: u32,
+    /// Handling of prompts over `max_prompt_tokens`
+    #[serde(default)]
+    pub overflow: OverflowStrategy,
+}
+
+impl PromptBudgetProfile {
+    /// Short answers with little context
+    pub fn concise() -> Self {
+        Self {
+            max_context_docs: 2,
+            max_history_turns: 2,
+            max_answer_tokens: 1000,
+            max_prompt_tokens: 2000,
+            overflow: OverflowStrategy::Truncate,
+        }
+    }
+
+    /// Balanced defaults matching the engines' own limits
+    pub fn standard() -> Self {
+        Self {
+            max_context_docs: 5,
+            max_history_turns: 6,
+            max_answer_tokens: 4000,
+            max_prompt_tokens: 8000,
+            overflow: OverflowStrategy::Truncate,
+        }
+    }
+
+    /// Long answers drawing on more context
+    pub fn thorough() -> Self {
+        Self {
+            max_context_docs: 10,
+            max_history_turns: 12,
+            max_answer_tokens: 8000,
+            max_prompt_tokens: 24000,
+            overflow: OverflowStrategy::Summarize,
+        }
+    }
+}
+
+/// Prompt-budget profiles and how they are selected
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PromptBudgetConfig {
+    /// Profile used when neither the request nor its research type selects one
+    pub default_profile: String,
+    /// Available profiles by name
+    pub profiles: HashMap<String, PromptBudgetProfile>,
+    /// Profile name per research type (e.g. `"troubleshooting" = "thorough"`)
+    pub research_type_profiles: HashMap<String, String>,
+    /// Catalog model whose tokenizer and context window apply to the budget
+    pub model: Option<String>,
+}
+
+impl Default for PromptBudgetConfig {
+    fn default() -> Self {
+        Self {
+            default_profile: DEFAULT_BUDGET_PROFILE.to_string(),
+            profiles: HashMap::from([
+                ("concise".to_string(), PromptBudgetProfile::concise()),
+                (
+                    DEFAULT_BUDGET_PROFILE.to_string(),
+                    PromptBudgetProfile::standard(),
+                ),
+                ("thorough".to_string(), PromptBudgetProfile::thorough()),
+            ]),
+            research_type_profiles: HashMap::new(),
+            model: None,
+        }
+    }
+}
+
+impl PromptBudgetConfig {
+    /// Check that every referenced profile and research type exists
+    pub fn validate(&self) -> Result<(), PromptBudgetError> {
+        if !self.profiles.contains_key(&self.default_profile) {
+            return Err(PromptBudgetError::UnknownProfile(
+                self.default_profile.clone(),
+            ));
+        }
+        for (research_type, profile) in &self.research_type_profiles {
+            if research_type.parse::<ResearchType>().is_err() {
+                return Err(PromptBudgetError::UnknownResearchType(
+                    research_type.clone(),
+                ));
+            }
+            if !self.profiles.contains_key(profile) {
+                return Err(PromptBudgetError::UnknownProfile(profile.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Errors raised while applying a prompt budget
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum PromptBudgetError {
+    #[error("Unknown prompt budget profile: {0}")]
+    UnknownProfile(String),
+
+    #[error("Unknown research type in prompt budget configuration: {0}")]
+    UnknownResearchType(String),
+
+    #[error("Prompt of {tokens} tokens exceeds the '{profile}' budget of {limit} tokens")]
+    Exceeded {
+        profile: String,
+        tokens: u32,
+        limit: u32,
+    },
+}
+
+/// Outcome of applying a budget to one request
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetReport {
+    pub profile: String,
+    /// Tokens in the query and its contexts after any overflow handling
+    pub prompt_tokens: u32,
+    pub limit: u32,
+    /// Strategy applied when the prompt was over budget
+    pub overflow: Option<OverflowStrategy>,
+}
+
+impl BudgetReport {
+    /// Record the report as `prompt_budget_profile`, `prompt_tokens` and `prompt_budget_overflow` tags
+    pub fn apply_to_tags(&self, tags: &mut HashMap<String, String>) {
+        tags.insert("prompt_budget_profile".to_string(), self.profile.clone());
+        tags.insert("prompt_tokens".to_string(), self.prompt_tokens.to_string());
+        if let Some(overflow) = self.overflow {
+            tags.insert("prompt_budget_overflow".to_string(), overflow.to_string());
+        }
+    }
+}
+
+/// Selects prompt-budget profiles and fits requests into them
+#[derive(Debug, Clone, Default)]
+pub struct PromptBudgeter {
+    config: PromptBudgetConfig,
+    tokenizers: TokenizerRegistry,
+}
+
+impl PromptBudgeter {
+    pub fn new(config: PromptBudgetConfig) -> Self {
+        Self {
+            config,
+            tokenizers: TokenizerRegistry::default(),
+        }
+    }
+
+    /// Register a tokenizer that catalog models can name
+    pub fn with_tokenizer(
+        mut self,
+        name: impl Into<String>,
+        tokenizer: Arc<dyn Tokenizer>,
+    ) -> Self {
+        self.tokenizers = self.tokenizers.with_tokenizer(name, tokenizer);
+        self
+    }
+
+    pub fn config(&self) -> &PromptBudgetConfig {
+        &self.config
+    }
+
+    /// Pick the requested profile, else the research type's, else the default
+    pub fn resolve(
+        &self,
+        requested: Option<&str>,
+        research_type: &ResearchType,
+    ) -> Result<(&str, &PromptBudgetProfile), PromptBudgetError> {
+        let name = match requested {
+            Some(name) => name,
+            None => self
+                .config
+                .research_type_profiles
+                .iter()
+                .find(|(research, _)| {
+                    research.parse::<ResearchType>().ok().as_ref() == Some(research_type)
+                })
+                .map(|(_, profile)| profile.as_str())
+                .unwrap_or(&self.config.default_profile),
+        };
+        self.config
+            .profiles
+            .get_key_value(name)
+            .map(|(name, profile)| (name.as_str(), profile))
+            .ok_or_else(|| PromptBudgetError::UnknownProfile(name.to_string()))
+    }
+
+    /// Tokenizer and context window of the configured model
+    fn model_limits(&self, catalog: &ModelCatalog) -> (Arc<dyn Tokenizer>, Option<u32>) {
+        match self.config.model.as_deref().and_then(|m| catalog.get(m)) {
+            Some(model) => (
+                self.tokenizers.get(&model.tokenizer),
+                Some(model.context_length),
+            ),
+            None => (self.tokenizers.get(DEFAULT_TOKENIZER), None),
+        }
+    }
+
+    /// Fit the request's contexts into the profile and attach its limits
+    ///
+    /// Counts the parts of the prompt that vary per request: the query and the
+    /// conversation, code and project contexts. The limit is the profile's
+    /// `max_prompt_tokens`, lowered when the model's context window cannot
+    /// also hold `max_answer_tokens`.
+    pub fn apply(
+        &self,
+        request: &mut ClassifiedRequest,
+        profile_name: &str,
+        profile: &PromptBudgetProfile,
+        catalog: &ModelCatalog,
+    ) -> Result<BudgetReport, PromptBudgetError> {
+        let (tokenizer, context_length) = self.model_limits(catalog);
+        let limit = match context_length {
+            Some(context_length) => profile
+                .max_prompt_tokens
+                .min(context_length.saturating_sub(profile.max_answer_tokens)),
+            None => profile.max_prompt_tokens,
+        };
+
+        let count = |request: &ClassifiedRequest| {
+            tokenizer.count_tokens(&request.original_query)
+                + request
+                    .conversation_context
+                    .as_deref()
+                    .map_or(0, |c| tokenizer.count_tokens(c))
+                + request
+                    .code_context
+                    .as_deref()
+                    .map_or(0, |c| tokenizer.count_tokens(c))
+                + request
+                    .project_context
+                    .as_deref()
+                    .map_or(0, |c| tokenizer.count_tokens(c))
+        };
+
+        let mut tokens = count(request);
+        let mut overflow = None;
+        if tokens > limit {
+            overflow = Some(profile.overflow);
+            if profile.overflow == OverflowStrategy::Reject {
+                return Err(PromptBudgetError::Exceeded {
+                    profile: profile_name.to_string(),
+                    tokens,
+                    limit,
+                });
+            }
+            if profile.overflow == OverflowStrategy::Summarize {
+                request.conversation_context =
+                    request.conversation_context.as_deref().map(condense);
+                request.code_context = request.code_context.as_deref().map(condense);
+                request.project_context = request.project_context.as_deref().map(condense);
+                tokens = count(request);
+            }
+            if tokens > limit {
+                let query_tokens = tokenizer.count_tokens(&request.original_query);
+                let mut remaining = limit.saturating_sub(query_tokens);
+                // Recent history matters more than older turns, so it is cut from the front
+                let code_tokens = request
+                    .code_context
+                    .as_deref()
+                    .map_or(0, |c| tokenizer.count_tokens(c));
+                let history_budget = remaining.saturating_sub(code_tokens);
+                request.conversation_context = request
+                    .conversation_context
+                    .take()
+                    .and_then(|c| fit_tokens(&c, history_budget, tokenizer.as_ref(), true));
+                remaining = remaining.saturating_sub(
+                    request
+                        .conversation_context
+                        .as_deref()
+                        .map_or(0, |c| tokenizer.count_tokens(c)),
+                );
+                request.code_context = request
+                    .code_context
+                    .take()
+                    .and_then(|c| fit_tokens(&c, remaining, tokenizer.as_ref(), false));
+                // Project background is the first thing given up
+                remaining = remaining.saturating_sub(
+                    request
+                        .code_context
+                        .as_deref()
+                        .map_or(0, |c| tokenizer.count_tokens(c)),
+                );
+                request.project_context = request
+                    .project_context
+                    .take()
+                    .and_then(|c| fit_tokens(&c, remaining, tokenizer.as_ref(), false));
+                tokens = count(request);
+            }
+            if tokens > limit {
+                return Err(PromptBudgetError::Exceeded {
+                    profile: profile_name.to_string(),
+                    tokens,
+                    limit,
+                });
+            }
+        }
+
+        request.prompt_budget = Some(PromptBudget {
+            profile: profile_name.to_string(),
+            max_context_docs: profile.max_context_docs,
+            max_answer_tokens: profile.max_answer_tokens,
+        });
+
+        Ok(BudgetReport {
+            profile: profile_name.to_string(),
+            prompt_tokens: tokens,
+            limit,
+            overflow,
+        })
+    }
+}
+
+/// Keep the first sentence of every line
+fn condense(text: &str) -> String {
+    text.lines()
+        .map(|line| match line.find(". ") {
+            Some(end) => &line[..=end],
+            None => line,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Longest whole-line prefix (or suffix, with `keep_end`) of `text` within `budget` tokens
+fn fit_tokens(
+    text: &str,
+    budget: u32,
+    tokenizer: &dyn Tokenizer,
+    keep_end: bool,
+) -> Option<String> {
+    if tokenizer.count_tokens(text) <= budget {
+        return Some(text.to_string());
+    }
+    let lines: Vec<&str> = text.lines().collect();
+    let mut kept: Vec<&str> = Vec::new();
+    let mut used = 0;
+    let ordered: Box<dyn Iterator<Item = &&str>> = if keep_end {
+        Box::new(lines.iter().rev())
+    } else {
+        Box::new(lines.iter())
+    };
+    for line in ordered {
+        let tokens = tokenizer.count_tokens(line) + 1;
+        if used + tokens > budget {
+            break;
+        }
+        used += tokens;
+        kept.push(line);
+    }
+    if kept.is_empty() {
+        return None;
+    }
+    if keep_end {
+        kept.reverse();
+    }
+    Some(kept.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_catalog::ModelPricing;
+    use fortitude_types::{AudienceContext, DomainContext};
+
+    fn request(query: &str) -> ClassifiedRequest {
+        ClassifiedRequest::new(
+            query.to_string(),
+            ResearchType::Troubleshooting,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        )
+    }
+
+    fn profile(max_prompt_tokens: u32, overflow: OverflowStrategy) -> PromptBudgetProfile {
+        PromptBudgetProfile {
+            max_prompt_tokens,
+            overflow,
+            ..PromptBudgetProfile::standard()
+        }
+    }
+
+    #[test]
+    fn test_bpe_tokenizer_follows_tiktoken_pieces() {
+        let tokenizer = BpeTokenizer;
+        assert_eq!(tokenizer.count_tokens(""), 0);
+        // "Hello" + " world" + "!"
+        assert_eq!(tokenizer.count_tokens("Hello world!"), 3);
+        // "I" + "'ll" + " pay" + " " + "123" + "45"
+        assert_eq!(tokenizer.count_tokens("I'll pay  12345"), 6);
+        // "fn" + " main" + "()" + " {" + "\n" + "}"
+        assert_eq!(tokenizer.count_tokens("fn main() {\n}"), 6);
+        // Long identifiers split into several pieces
+        assert_eq!(tokenizer.count_tokens("internationalization"), 4);
+        assert!(TokenizerRegistry::default().names().contains(&"cl100k"));
+    }
+
+    #[test]
+    fn test_resolve_profile_precedence() {
+        let config = PromptBudgetConfig {
+            research_type_profiles: HashMap::from([(
+                "troubleshooting".to_string(),
+                "thorough".to_string(),
+            )]),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+        let budgeter = PromptBudgeter::new(config);
+
+        let (name, _) = budgeter.resolve(None, &ResearchType::Learning).unwrap();
+        assert_eq!(name, "standard");
+        let (name, profile) = budgeter
+            .resolve(None, &ResearchType::Troubleshooting)
+            .unwrap();
+        assert_eq!(name, "thorough");
+        assert_eq!(profile.overflow, OverflowStrategy::Summarize);
+        let (name, _) = budgeter
+            .resolve(Some("concise"), &ResearchType::Troubleshooting)
+            .unwrap();
+        assert_eq!(name, "concise");
+        assert_eq!(
+            budgeter.resolve(Some("huge"), &ResearchType::Learning),
+            Err(PromptBudgetError::UnknownProfile("huge".to_string()))
+        );
+
+        let invalid = PromptBudgetConfig {
+            research_type_profiles: HashMap::from([(
+                "debugging".to_string(),
+                "concise".to_string(),
+            )]),
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_apply_within_budget_attaches_limits() {
+        let budgeter = PromptBudgeter::default();
+        let mut request = request("Why does my build fail?");
+
+        let report = budgeter
+            .apply(
+                &mut request,
+                "concise",
+                &PromptBudgetProfile::concise(),
+                &ModelCatalog::default(),
+            )
+            .unwrap();
+
+        assert_eq!(report.prompt_tokens, 6);
+        assert_eq!(report.overflow, None);
+        let budget = request.prompt_budget.unwrap();
+        assert_eq!(budget.profile, "concise");
+        assert_eq!(budget.max_context_docs, 2);
+        assert_eq!(budget.max_answer_tokens, 1000);
+    }
+
+    #[test]
+    fn test_overflow_strategies() {
+        let budgeter = PromptBudgeter::default();
+        let catalog = ModelCatalog::default();
+        let history = (1..=20)
+            .map(|i| format!("Q: question {i}. More detail here.\nA: answer {i}."))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let over_budget = || request("Why?").with_conversation_context(history.clone());
+
+        let mut rejected = over_budget();
+        let error = budgeter
+            .apply(
+                &mut rejected,
+                "strict",
+                &profile(50, OverflowStrategy::Reject),
+                &catalog,
+            )
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            PromptBudgetError::Exceeded { limit: 50, .. }
+        ));
+
+        let mut truncated = over_budget();
+        let report = budgeter
+            .apply(
+                &mut truncated,
+                "small",
+                &profile(50, OverflowStrategy::Truncate),
+                &catalog,
+            )
+            .unwrap();
+        assert!(report.prompt_tokens <= 50);
+        assert_eq!(report.overflow, Some(OverflowStrategy::Truncate));
+        let kept = truncated.conversation_context.unwrap();
+        assert!(kept.ends_with("A: answer 20."));
+        assert!(!kept.contains("question 1."));
+
+        let mut summarized = over_budget();
+        budgeter
+            .apply(
+                &mut summarized,
+                "small",
+                &profile(200, OverflowStrategy::Summarize),
+                &catalog,
+            )
+            .unwrap();
+        let condensed = summarized.conversation_context.unwrap();
+        assert!(condensed.contains("Q: question 20."));
+        assert!(!condensed.contains("More detail here"));
+    }
+
+    #[test]
+    fn test_model_tokenizer_and_context_window() {
+        let catalog = ModelCatalog::default()
+            .with_model(ModelPricing::new("local", "tiny", 0.0, 0.0, 1100).with_tokenizer("words"));
+        let budgeter = PromptBudgeter::new(PromptBudgetConfig {
+            model: Some("tiny".to_string()),
+            ..Default::default()
+        })
+        .with_tokenizer(
+            "words",
+            Arc::new(WhitespaceTokenizer {
+                tokens_per_word: 1.0,
+            }),
+        );
+
+        let mut request = request("one two three four five six");
+        let report = budgeter
+            .apply(
+                &mut request,
+                "concise",
+                &PromptBudgetProfile::concise(),
+                &catalog,
+            )
+            .unwrap();
+        assert_eq!(report.prompt_tokens, 6);
+        assert_eq!(report.limit, 100);
+    }
+}
@@ -0,0 +1,257 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Learned prompt template adjustments with an audit log of applied changes
+//! Adjustments are extra instructions attached to a template for one research
+//! type. They start out proposed, and once applied are appended to every
+//! prompt built from that template. Each application is recorded in the audit log.
+
+use chrono::{DateTime, Utc};
+use fortitude_types::research::ResearchType;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+use thiserror::Error;
+
+/// Metadata tag naming the prompt template a result was researched with
+pub const PROMPT_TEMPLATE_TAG: &str = "prompt_template";
+
+/// Errors that can occur when applying adjustments
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum AdjustmentError {
+    #[error("No pending adjustment with id {0}")]
+    NotFound(String),
+}
+
+/// Instruction added to a template for one research type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateAdjustment {
+    pub id: String,
+    pub template_name: String,
+    pub research_type: ResearchType,
+    /// Instruction appended to prompts built from the template
+    pub instruction: String,
+    /// Evidence that motivated the adjustment
+    pub reason: String,
+    /// Confidence in the adjustment (0.0-1.0)
+    pub confidence: f64,
+    pub proposed_at: DateTime<Utc>,
+}
+
+impl TemplateAdjustment {
+    pub fn new(
+        template_name: impl Into<String>,
+        research_type: ResearchType,
+        instruction: impl Into<String>,
+        reason: impl Into<String>,
+        confidence: f64,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            template_name: template_name.into(),
+            research_type,
+            instruction: instruction.into(),
+            reason: reason.into(),
+            confidence,
+            proposed_at: Utc::now(),
+        }
+    }
+
+    fn targets(&self, template_name: &str, research_type: &ResearchType) -> bool {
+        self.template_name == template_name && &self.research_type == research_type
+    }
+}
+
+/// Audit record of an applied adjustment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdjustmentAuditEntry {
+    pub adjustment: TemplateAdjustment,
+    /// Who applied it, e.g. `auto` or a user id
+    pub applied_by: String,
+    pub applied_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Default)]
+struct AdjustmentState {
+    pending: Vec<TemplateAdjustment>,
+    applied: Vec<TemplateAdjustment>,
+    audit_log: Vec<AdjustmentAuditEntry>,
+}
+
+/// Proposed and applied template adjustments, shared between the learning
+/// system and the research engines
+#[derive(Debug, Default)]
+pub struct PromptAdjustments {
+    state: RwLock<AdjustmentState>,
+}
+
+impl PromptAdjustments {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a proposal
+    ///
+    /// Returns false if the same instruction is already pending or applied
+    /// for the template and research type.
+    pub fn propose(&self, adjustment: TemplateAdjustment) -> bool {
+        let mut state = self.state.write().unwrap();
+        let duplicate = state.pending.iter().chain(&state.applied).any(|existing| {
+            existing.targets(&adjustment.template_name, &adjustment.research_type)
+                && existing.instruction == adjustment.instruction
+        });
+        if !duplicate {
+            state.pending.push(adjustment);
+        }
+        !duplicate
+    }
+
+    /// Proposals awaiting application
+    pub fn pending(&self) -> Vec<TemplateAdjustment> {
+        self.state.read().unwrap().pending.clone()
+    }
+
+    /// Apply a pending proposal and record it in the audit log
+    pub fn apply(
+        &self,
+        adjustment_id: &str,
+        applied_by: &str,
+    ) -> Result<AdjustmentAuditEntry, AdjustmentError> {
+        let mut state = self.state.write().unwrap();
+        let index = state
+            .pending
+            .iter()
+            .position(|a| a.id == adjustment_id)
+            .ok_or_else(|| AdjustmentError::NotFound(adjustment_id.to_string()))?;
+        let adjustment = state.pending.remove(index);
+
+        let entry = AdjustmentAuditEntry {
+            adjustment: adjustment.clone(),
+            applied_by: applied_by.to_string(),
+            applied_at: Utc::now(),
+        };
+        state.applied.push(adjustment);
+        state.audit_log.push(entry.clone());
+        Ok(entry)
+    }
+
+    /// Applied adjustments for a template and research type, oldest first
+    pub fn applied_for(
+        &self,
+        template_name: &str,
+        research_type: &ResearchType,
+    ) -> Vec<TemplateAdjustment> {
+        self.state
+            .read()
+            .unwrap()
+            .applied
+            .iter()
+            .filter(|a| a.targets(template_name, research_type))
+            .cloned()
+            .collect()
+    }
+
+    /// Prompt section carrying the applied instructions, if there are any
+    pub fn instructions_for(
+        &self,
+        template_name: &str,
+        research_type: &ResearchType,
+    ) -> Option<String> {
+        let applied = self.applied_for(template_name, research_type);
+        if applied.is_empty() {
+            return None;
+        }
+        let mut section = String::from("Additional guidance learned from user feedback:");
+        for adjustment in applied {
+            section.push_str("\n- ");
+            section.push_str(&adjustment.instruction);
+        }
+        Some(section)
+    }
+
+    /// Every applied change, oldest first
+    pub fn audit_log(&self) -> Vec<AdjustmentAuditEntry> {
+        self.state.read().unwrap().audit_log.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adjustment(instruction: &str) -> TemplateAdjustment {
+        TemplateAdjustment::new(
+            "Feature Implementation Guide",
+            ResearchType::Implementation,
+            instruction,
+            "4 of 5 ratings below 0.5",
+            0.8,
+        )
+    }
+
+    #[test]
+    fn test_propose_skips_duplicates() {
+        let adjustments = PromptAdjustments::new();
+        assert!(adjustments.propose(adjustment("Include code examples")));
+        assert!(!adjustments.propose(adjustment("Include code examples")));
+        assert!(adjustments.propose(adjustment("Cite sources")));
+        assert_eq!(adjustments.pending().len(), 2);
+    }
+
+    #[test]
+    fn test_apply_records_audit_entry() {
+        let adjustments = PromptAdjustments::new();
+        let proposed = adjustment("Include code examples");
+        adjustments.propose(proposed.clone());
+
+        let entry = adjustments.apply(&proposed.id, "auto").unwrap();
+        assert_eq!(entry.adjustment, proposed);
+        assert_eq!(entry.applied_by, "auto");
+        assert!(adjustments.pending().is_empty());
+        assert_eq!(adjustments.audit_log(), vec![entry]);
+
+        assert_eq!(
+            adjustments.apply(&proposed.id, "auto"),
+            Err(AdjustmentError::NotFound(proposed.id.clone()))
+        );
+        // Applied instructions are not proposed again
+        assert!(!adjustments.propose(adjustment("Include code examples")));
+    }
+
+    #[test]
+    fn test_instructions_only_for_matching_template_and_type() {
+        let adjustments = PromptAdjustments::new();
+        let proposed = adjustment("Include code examples");
+        adjustments.propose(proposed.clone());
+        assert!(adjustments
+            .instructions_for(
+                "Feature Implementation Guide",
+                &ResearchType::Implementation
+            )
+            .is_none());
+
+        adjustments.apply(&proposed.id, "auto").unwrap();
+        assert_eq!(
+            adjustments
+                .instructions_for(
+                    "Feature Implementation Guide",
+                    &ResearchType::Implementation
+                )
+                .unwrap(),
+            "Additional guidance learned from user feedback:\n- Include code examples"
+        );
+        assert!(adjustments
+            .instructions_for("Feature Implementation Guide", &ResearchType::Decision)
+            .is_none());
+    }
+}
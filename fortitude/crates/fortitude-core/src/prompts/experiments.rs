@@ -0,0 +1,509 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: A/B experiments routing research requests between prompt template versions
+//! An experiment compares two versions of one prompt template variant. A
+//! configurable percentage of matching requests is routed to the variant
+//! version and the rest to the control version. Assignment is derived from
+//! the request id, so a request always lands in the same arm.
+//!
+//! Experiments are declared in a TOML file:
+//!
+//! ```toml
+//! [[experiment]]
+//! name = "decision-tradeoffs"
+//! research_type = "decision"
+//! provider = "claude"        # optional, defaults to every provider
+//! control_version = 2
+//! variant_version = 3
+//! traffic_percentage = 20.0
+//! ```
+//!
+//! The quality score of every experiment result is appended to an observation
+//! log, which the learning reports compare per arm.
+
+use crate::prompts::versioned::PromptTemplateError;
+use chrono::{DateTime, Utc};
+use fortitude_types::{ClassifiedRequest, ResearchType};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+/// File prompt experiments are declared in by default
+pub const DEFAULT_PROMPT_EXPERIMENTS_PATH: &str = ".fortitude/prompt_experiments.toml";
+
+/// Log experiment observations are appended to by default
+pub const DEFAULT_PROMPT_EXPERIMENT_LOG: &str = ".fortitude/prompt_experiments.jsonl";
+
+/// Metadata tag naming the experiment a result took part in
+pub const PROMPT_EXPERIMENT_TAG: &str = "prompt_experiment";
+
+/// Metadata tag holding the experiment arm a result was assigned to
+pub const PROMPT_EXPERIMENT_ARM_TAG: &str = "prompt_experiment_arm";
+
+/// Side of an experiment a request is routed to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExperimentArm {
+    Control,
+    Variant,
+}
+
+impl ExperimentArm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Control => "control",
+            Self::Variant => "variant",
+        }
+    }
+}
+
+impl std::fmt::Display for ExperimentArm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ExperimentArm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "control" => Ok(Self::Control),
+            "variant" => Ok(Self::Variant),
+            _ => Err(format!("Unknown experiment arm '{s}'")),
+        }
+    }
+}
+
+/// Comparison of two versions of one prompt template variant
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromptExperiment {
+    pub name: String,
+    pub research_type: ResearchType,
+    /// Provider variant under test; `None` tests the variant shared by all providers
+    pub provider: Option<String>,
+    pub control_version: u32,
+    pub variant_version: u32,
+    /// Share of matching requests routed to the variant version (0-100)
+    pub traffic_percentage: f64,
+    pub enabled: bool,
+}
+
+impl PromptExperiment {
+    /// Whether `request` belongs to this experiment
+    pub fn matches(&self, request: &ClassifiedRequest) -> bool {
+        if !self.enabled || self.research_type != request.research_type {
+            return false;
+        }
+        match &self.provider {
+            Some(provider) => request
+                .provider_selection
+                .as_ref()
+                .is_some_and(|selection| &selection.provider == provider),
+            None => true,
+        }
+    }
+
+    /// Arm `request_id` is routed to
+    pub fn arm_for(&self, request_id: &Uuid) -> ExperimentArm {
+        if bucket(&self.name, request_id) < self.traffic_percentage {
+            ExperimentArm::Variant
+        } else {
+            ExperimentArm::Control
+        }
+    }
+
+    /// Template version used by `arm`
+    pub fn version_for(&self, arm: ExperimentArm) -> u32 {
+        match arm {
+            ExperimentArm::Control => self.control_version,
+            ExperimentArm::Variant => self.variant_version,
+        }
+    }
+}
+
+/// Stable position of a request in `0.0..100.0` for one experiment
+fn bucket(experiment: &str, request_id: &Uuid) -> f64 {
+    // FNV-1a, so assignments survive restarts and toolchain upgrades
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in experiment.bytes().chain(*request_id.as_bytes()) {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    (hash % 10_000) as f64 / 100.0
+}
+
+/// Experiment file contents
+#[derive(Debug, Default, Deserialize)]
+struct ExperimentsFile {
+    #[serde(default)]
+    experiment: Vec<ExperimentEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExperimentEntry {
+    name: String,
+    research_type: String,
+    #[serde(default)]
+    provider: Option<String>,
+    control_version: u32,
+    variant_version: u32,
+    traffic_percentage: f64,
+    #[serde(default = "default_enabled")]
+    enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Experiment arm and template version chosen for one request
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExperimentAssignment {
+    pub experiment: String,
+    pub arm: ExperimentArm,
+    pub template_version: u32,
+}
+
+/// Quality score of one result produced under an experiment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExperimentObservation {
+    pub experiment: String,
+    pub arm: ExperimentArm,
+    pub template_version: u32,
+    pub request_id: Uuid,
+    pub quality_score: f64,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Routes requests into experiments and records what each arm produced
+#[derive(Debug, Default)]
+pub struct PromptExperimentRunner {
+    experiments: Vec<PromptExperiment>,
+    log_path: Option<PathBuf>,
+    observations: RwLock<Vec<ExperimentObservation>>,
+}
+
+impl PromptExperimentRunner {
+    /// Runner keeping its observations in memory only
+    pub fn new(experiments: Vec<PromptExperiment>) -> Self {
+        Self {
+            experiments,
+            ..Self::default()
+        }
+    }
+
+    /// Load experiments from `path` and earlier observations from `log_path`
+    ///
+    /// A missing experiment file yields a runner without experiments. New
+    /// observations are appended to `log_path`.
+    pub fn load(
+        path: impl AsRef<Path>,
+        log_path: impl Into<PathBuf>,
+    ) -> Result<Self, PromptTemplateError> {
+        let path = path.as_ref();
+        let log_path = log_path.into();
+        let experiments = match std::fs::read_to_string(path) {
+            Ok(contents) => parse_experiments(path, &contents)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(source) => {
+                return Err(PromptTemplateError::Io {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+        let observations = read_observations(&log_path)?;
+        debug!(
+            "Loaded {} prompt experiment(s) with {} observation(s)",
+            experiments.len(),
+            observations.len()
+        );
+        Ok(Self {
+            experiments,
+            log_path: Some(log_path),
+            observations: RwLock::new(observations),
+        })
+    }
+
+    pub fn experiments(&self) -> &[PromptExperiment] {
+        &self.experiments
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.experiments.is_empty()
+    }
+
+    /// Experiment arm for `request`, from the first enabled experiment it matches
+    pub fn assign(&self, request: &ClassifiedRequest) -> Option<ExperimentAssignment> {
+        let experiment = self
+            .experiments
+            .iter()
+            .find(|experiment| experiment.matches(request))?;
+        let arm = experiment.arm_for(&request.id);
+        Some(ExperimentAssignment {
+            experiment: experiment.name.clone(),
+            arm,
+            template_version: experiment.version_for(arm),
+        })
+    }
+
+    /// Record the quality score of a result produced under `assignment`
+    pub fn record(
+        &self,
+        assignment: &ExperimentAssignment,
+        request_id: Uuid,
+        quality_score: f64,
+    ) -> Result<(), PromptTemplateError> {
+        let observation = ExperimentObservation {
+            experiment: assignment.experiment.clone(),
+            arm: assignment.arm,
+            template_version: assignment.template_version,
+            request_id,
+            quality_score,
+            recorded_at: Utc::now(),
+        };
+        if let Some(log_path) = &self.log_path {
+            append_observation(log_path, &observation)?;
+        }
+        self.observations.write().unwrap().push(observation);
+        Ok(())
+    }
+
+    /// Every recorded observation, oldest first
+    pub fn observations(&self) -> Vec<ExperimentObservation> {
+        self.observations.read().unwrap().clone()
+    }
+}
+
+fn parse_experiments(
+    path: &Path,
+    contents: &str,
+) -> Result<Vec<PromptExperiment>, PromptTemplateError> {
+    let invalid = |message: String| PromptTemplateError::Invalid {
+        path: path.to_path_buf(),
+        message,
+    };
+    let file: ExperimentsFile = toml::from_str(contents).map_err(|e| invalid(e.to_string()))?;
+
+    let mut experiments: Vec<PromptExperiment> = Vec::new();
+    for entry in file.experiment {
+        let research_type = entry
+            .research_type
+            .parse()
+            .map_err(|_| invalid(format!("unknown research type '{}'", entry.research_type)))?;
+        if !(0.0..=100.0).contains(&entry.traffic_percentage) {
+            return Err(invalid(format!(
+                "experiment '{}' routes {}% of traffic; expected 0-100",
+                entry.name, entry.traffic_percentage
+            )));
+        }
+        if entry.control_version == entry.variant_version {
+            return Err(invalid(format!(
+                "experiment '{}' compares version {} with itself",
+                entry.name, entry.control_version
+            )));
+        }
+        if experiments.iter().any(|e| e.name == entry.name) {
+            return Err(invalid(format!("duplicate experiment '{}'", entry.name)));
+        }
+        experiments.push(PromptExperiment {
+            name: entry.name,
+            research_type,
+            provider: entry.provider,
+            control_version: entry.control_version,
+            variant_version: entry.variant_version,
+            traffic_percentage: entry.traffic_percentage,
+            enabled: entry.enabled,
+        });
+    }
+    Ok(experiments)
+}
+
+fn read_observations(log_path: &Path) -> Result<Vec<ExperimentObservation>, PromptTemplateError> {
+    let contents = match std::fs::read_to_string(log_path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(source) => {
+            return Err(PromptTemplateError::Io {
+                path: log_path.to_path_buf(),
+                source,
+            })
+        }
+    };
+    Ok(contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(observation) => Some(observation),
+            Err(e) => {
+                warn!("Skipping unreadable experiment observation: {}", e);
+                None
+            }
+        })
+        .collect())
+}
+
+fn append_observation(
+    log_path: &Path,
+    observation: &ExperimentObservation,
+) -> Result<(), PromptTemplateError> {
+    let io_error = |source| PromptTemplateError::Io {
+        path: log_path.to_path_buf(),
+        source,
+    };
+    if let Some(parent) = log_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    let line = serde_json::to_string(observation).map_err(|e| PromptTemplateError::Invalid {
+        path: log_path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path)
+        .map_err(io_error)?;
+    writeln!(file, "{line}").map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_types::{AudienceContext, DomainContext, ProviderSelection};
+
+    fn request(research_type: ResearchType) -> ClassifiedRequest {
+        ClassifiedRequest::new(
+            "Which queue should we use?".to_string(),
+            research_type,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.9,
+            vec![],
+        )
+    }
+
+    fn experiment(traffic_percentage: f64) -> PromptExperiment {
+        PromptExperiment {
+            name: "decision-tradeoffs".to_string(),
+            research_type: ResearchType::Decision,
+            provider: None,
+            control_version: 1,
+            variant_version: 2,
+            traffic_percentage,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_assignment_is_stable_and_follows_traffic_share() {
+        let runner = PromptExperimentRunner::new(vec![experiment(25.0)]);
+        assert!(runner.assign(&request(ResearchType::Learning)).is_none());
+
+        let mut variant = 0;
+        for _ in 0..2000 {
+            let request = request(ResearchType::Decision);
+            let assignment = runner.assign(&request).unwrap();
+            assert_eq!(runner.assign(&request), Some(assignment.clone()));
+            if assignment.arm == ExperimentArm::Variant {
+                assert_eq!(assignment.template_version, 2);
+                variant += 1;
+            }
+        }
+        assert!(
+            (400..600).contains(&variant),
+            "{variant} of 2000 in variant"
+        );
+
+        let none = PromptExperimentRunner::new(vec![experiment(0.0)]);
+        let all = PromptExperimentRunner::new(vec![experiment(100.0)]);
+        let request = request(ResearchType::Decision);
+        assert_eq!(none.assign(&request).unwrap().arm, ExperimentArm::Control);
+        assert_eq!(all.assign(&request).unwrap().arm, ExperimentArm::Variant);
+    }
+
+    #[test]
+    fn test_provider_experiment_only_matches_selected_provider() {
+        let runner = PromptExperimentRunner::new(vec![PromptExperiment {
+            provider: Some("claude".to_string()),
+            ..experiment(50.0)
+        }]);
+        let mut request = request(ResearchType::Decision);
+        assert!(runner.assign(&request).is_none());
+
+        request.provider_selection = Some(ProviderSelection {
+            provider: "claude".to_string(),
+            model: None,
+        });
+        assert!(runner.assign(&request).is_some());
+    }
+
+    #[test]
+    fn test_load_and_record_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("experiments.toml");
+        let log = dir.path().join("state").join("observations.jsonl");
+        std::fs::write(
+            &path,
+            r#"
+[[experiment]]
+name = "decision-tradeoffs"
+research_type = "decision"
+control_version = 1
+variant_version = 2
+traffic_percentage = 30.0
+
+[[experiment]]
+name = "paused"
+research_type = "learning"
+control_version = 1
+variant_version = 3
+traffic_percentage = 50.0
+enabled = false
+"#,
+        )
+        .unwrap();
+
+        let runner = PromptExperimentRunner::load(&path, &log).unwrap();
+        assert_eq!(runner.experiments().len(), 2);
+        assert!(runner.assign(&request(ResearchType::Learning)).is_none());
+
+        let request = request(ResearchType::Decision);
+        let assignment = runner.assign(&request).unwrap();
+        runner.record(&assignment, request.id, 0.82).unwrap();
+
+        let reloaded = PromptExperimentRunner::load(&path, &log).unwrap();
+        let observations = reloaded.observations();
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].arm, assignment.arm);
+        assert_eq!(observations[0].request_id, request.id);
+        assert_eq!(observations[0].quality_score, 0.82);
+
+        std::fs::write(
+            &path,
+            "[[experiment]]\nname = \"x\"\nresearch_type = \"decision\"\ncontrol_version = 1\nvariant_version = 1\ntraffic_percentage = 10.0\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            PromptExperimentRunner::load(&path, &log),
+            Err(PromptTemplateError::Invalid { .. })
+        ));
+        let missing = PromptExperimentRunner::load(dir.path().join("none.toml"), &log).unwrap();
+        assert!(missing.is_empty());
+    }
+}
@@ -17,15 +17,21 @@
 //! based on research type, with support for progressive disclosure and
 //! parameter substitution.
 
+pub mod adjustments;
+pub mod experiments;
 pub mod parameters;
 pub mod registry;
 pub mod substitution;
 pub mod templates;
 pub mod validation;
+pub mod versioned;
 
 // Re-export key types for easier access
+pub use adjustments::*;
+pub use experiments::*;
 pub use parameters::*;
 pub use registry::*;
 pub use substitution::*;
 pub use templates::*;
 pub use validation::*;
+pub use versioned::*;
@@ -0,0 +1,537 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Versioned prompt templates loaded from a directory with per-provider variants
+//! Templates live in a directory as TOML files or Handlebars-style `.hbs`
+//! files. The file name carries the research type, an optional provider and
+//! an optional version, e.g. `decision.hbs`, `learning.claude.v3.toml`.
+//! TOML files may set the same fields explicitly alongside `template`.
+//!
+//! A request uses the highest version of the variant for its provider, falling
+//! back to the variant shared by all providers. Editing a template saves a new
+//! version next to the old ones, so earlier versions stay available.
+
+use crate::prompts::parameters::ParameterValue;
+use crate::prompts::registry::DefaultTemplateFactory;
+use crate::prompts::substitution::{SubstitutionEngine, SubstitutionError};
+use fortitude_types::{ClassifiedRequest, ResearchType};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use tracing::{debug, warn};
+
+/// Directory prompt templates are loaded from by default
+pub const DEFAULT_PROMPT_TEMPLATE_DIR: &str = ".fortitude/prompts";
+
+/// Metadata tag holding the version of the prompt template a result used
+pub const PROMPT_TEMPLATE_VERSION_TAG: &str = "prompt_template_version";
+
+/// Errors raised while loading, saving or rendering prompt templates
+#[derive(Error, Debug)]
+pub enum PromptTemplateError {
+    #[error("Failed to access prompt templates at {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Invalid prompt template {path}: {message}")]
+    Invalid { path: PathBuf, message: String },
+    #[error("Failed to render prompt template '{name}': {source}")]
+    Render {
+        name: String,
+        #[source]
+        source: SubstitutionError,
+    },
+    #[error("Prompt template registry has no directory to save to")]
+    NoDirectory,
+}
+
+/// One version of a prompt template
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromptTemplate {
+    pub name: String,
+    pub research_type: ResearchType,
+    /// Provider this variant is for; `None` applies to every provider
+    pub provider: Option<String>,
+    pub version: u32,
+    pub description: String,
+    /// Prompt text with `{{parameter}}` placeholders
+    pub template: String,
+    /// File the template was loaded from; `None` for built-in templates
+    pub source: Option<PathBuf>,
+}
+
+impl PromptTemplate {
+    /// Render the template, failing on placeholders without a value
+    pub fn render(
+        &self,
+        params: &HashMap<String, ParameterValue>,
+    ) -> Result<String, PromptTemplateError> {
+        SubstitutionEngine::new()
+            .and_then(|engine| engine.substitute(&self.template, params))
+            .map(|rendered| rendered.trim().to_string())
+            .map_err(|source| PromptTemplateError::Render {
+                name: self.name.clone(),
+                source,
+            })
+    }
+
+    fn is_variant(&self, research_type: &ResearchType, provider: Option<&str>) -> bool {
+        &self.research_type == research_type && self.provider.as_deref() == provider
+    }
+}
+
+/// Template file contents; fields left out are taken from the file name
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TemplateFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    research_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    provider: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    version: Option<u32>,
+    #[serde(default)]
+    description: String,
+    template: String,
+}
+
+/// Prompt templates by research type, provider and version
+#[derive(Debug, Clone, Default)]
+pub struct PromptTemplateRegistry {
+    templates: Vec<PromptTemplate>,
+    directory: Option<PathBuf>,
+}
+
+impl PromptTemplateRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The templates compiled into Fortitude, as version 0 of each research type
+    pub fn builtin() -> Self {
+        let registry = DefaultTemplateFactory::create_default_registry();
+        let mut templates: Vec<PromptTemplate> = registry
+            .get_all_names()
+            .iter()
+            .filter_map(|name| registry.get(name))
+            .map(|template| PromptTemplate {
+                name: template.get_name().to_string(),
+                research_type: template.get_type(),
+                provider: None,
+                version: 0,
+                description: template.get_description().to_string(),
+                template: template.get_template_content().trim().to_string(),
+                source: None,
+            })
+            .collect();
+        templates.sort_by_key(|template| research_type_key(&template.research_type));
+        Self {
+            templates,
+            directory: None,
+        }
+    }
+
+    /// Load every `.toml` and `.hbs` template in `directory`
+    ///
+    /// A missing directory yields an empty registry that saves into it.
+    pub fn load(directory: impl Into<PathBuf>) -> Result<Self, PromptTemplateError> {
+        let directory = directory.into();
+        let mut registry = Self {
+            templates: Vec::new(),
+            directory: Some(directory.clone()),
+        };
+        let entries = match std::fs::read_dir(&directory) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(registry),
+            Err(source) => {
+                return Err(PromptTemplateError::Io {
+                    path: directory,
+                    source,
+                })
+            }
+        };
+
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                matches!(
+                    path.extension().and_then(|ext| ext.to_str()),
+                    Some("toml" | "hbs")
+                )
+            })
+            .collect();
+        paths.sort();
+        for path in paths {
+            registry.insert(read_template(&path)?);
+        }
+        debug!(
+            "Loaded {} prompt template(s) from {}",
+            registry.templates.len(),
+            directory.display()
+        );
+        Ok(registry)
+    }
+
+    /// Directory new versions are saved to
+    pub fn directory(&self) -> Option<&Path> {
+        self.directory.as_deref()
+    }
+
+    pub fn insert(&mut self, template: PromptTemplate) {
+        self.templates.push(template);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.templates.is_empty()
+    }
+
+    /// Every template version, ordered by research type, provider and version
+    pub fn templates(&self) -> Vec<&PromptTemplate> {
+        let mut templates: Vec<&PromptTemplate> = self.templates.iter().collect();
+        templates.sort_by(|a, b| {
+            research_type_key(&a.research_type)
+                .cmp(research_type_key(&b.research_type))
+                .then_with(|| a.provider.cmp(&b.provider))
+                .then_with(|| a.version.cmp(&b.version))
+        });
+        templates
+    }
+
+    /// Versions of one variant, oldest first
+    pub fn versions(
+        &self,
+        research_type: &ResearchType,
+        provider: Option<&str>,
+    ) -> Vec<&PromptTemplate> {
+        self.templates()
+            .into_iter()
+            .filter(|template| template.is_variant(research_type, provider))
+            .collect()
+    }
+
+    /// Latest version of one variant
+    pub fn latest(
+        &self,
+        research_type: &ResearchType,
+        provider: Option<&str>,
+    ) -> Option<&PromptTemplate> {
+        self.templates
+            .iter()
+            .filter(|template| template.is_variant(research_type, provider))
+            .max_by_key(|template| template.version)
+    }
+
+    /// Template a request for `provider` should use
+    ///
+    /// The provider's own variant wins over the one shared by all providers.
+    pub fn resolve(
+        &self,
+        research_type: &ResearchType,
+        provider: Option<&str>,
+    ) -> Option<&PromptTemplate> {
+        provider
+            .and_then(|provider| self.latest(research_type, Some(provider)))
+            .or_else(|| self.latest(research_type, None))
+    }
+
+    /// Version a newly saved template of this variant gets
+    pub fn next_version(&self, research_type: &ResearchType, provider: Option<&str>) -> u32 {
+        self.latest(research_type, provider)
+            .map_or(1, |template| template.version + 1)
+    }
+
+    /// Write `template` to the registry directory and add it
+    ///
+    /// Returns the path of the new file.
+    pub fn save(&mut self, mut template: PromptTemplate) -> Result<PathBuf, PromptTemplateError> {
+        let directory = self
+            .directory
+            .clone()
+            .ok_or(PromptTemplateError::NoDirectory)?;
+        std::fs::create_dir_all(&directory).map_err(|source| PromptTemplateError::Io {
+            path: directory.clone(),
+            source,
+        })?;
+
+        let mut stem = research_type_key(&template.research_type).to_string();
+        if let Some(provider) = &template.provider {
+            stem.push('.');
+            stem.push_str(provider);
+        }
+        let path = directory.join(format!("{stem}.v{}.toml", template.version));
+
+        let file = TemplateFile {
+            name: Some(template.name.clone()),
+            research_type: Some(research_type_key(&template.research_type).to_string()),
+            provider: template.provider.clone(),
+            version: Some(template.version),
+            description: template.description.clone(),
+            template: template.template.clone(),
+        };
+        let contents = toml::to_string_pretty(&file).map_err(|e| PromptTemplateError::Invalid {
+            path: path.clone(),
+            message: e.to_string(),
+        })?;
+        std::fs::write(&path, contents).map_err(|source| PromptTemplateError::Io {
+            path: path.clone(),
+            source,
+        })?;
+
+        template.source = Some(path.clone());
+        self.insert(template);
+        Ok(path)
+    }
+}
+
+/// Lowercase research type used in file names
+fn research_type_key(research_type: &ResearchType) -> &'static str {
+    match research_type {
+        ResearchType::Decision => "decision",
+        ResearchType::Implementation => "implementation",
+        ResearchType::Troubleshooting => "troubleshooting",
+        ResearchType::Learning => "learning",
+        ResearchType::Validation => "validation",
+    }
+}
+
+/// Research type, provider and version encoded in a file stem
+fn parse_stem(stem: &str) -> (Option<ResearchType>, Option<String>, Option<u32>) {
+    let mut parts: Vec<&str> = stem.split('.').collect();
+    let version = parts
+        .last()
+        .and_then(|part| part.strip_prefix('v'))
+        .and_then(|version| version.parse().ok());
+    if version.is_some() {
+        parts.pop();
+    }
+    let research_type = parts.first().and_then(|part| part.parse().ok());
+    let provider = parts.get(1).map(|provider| provider.to_string());
+    (research_type, provider, version)
+}
+
+fn read_template(path: &Path) -> Result<PromptTemplate, PromptTemplateError> {
+    let invalid = |message: String| PromptTemplateError::Invalid {
+        path: path.to_path_buf(),
+        message,
+    };
+    let contents = std::fs::read_to_string(path).map_err(|source| PromptTemplateError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let stem = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default();
+
+    let file = if path.extension().and_then(|ext| ext.to_str()) == Some("toml") {
+        toml::from_str(&contents).map_err(|e| invalid(e.to_string()))?
+    } else {
+        TemplateFile {
+            template: contents,
+            ..Default::default()
+        }
+    };
+
+    let (stem_type, stem_provider, stem_version) = parse_stem(stem);
+    let research_type = match file.research_type {
+        Some(research_type) => research_type
+            .parse()
+            .map_err(|_| invalid(format!("unknown research type '{research_type}'")))?,
+        None => stem_type.ok_or_else(|| {
+            invalid("file name must start with a research type, e.g. decision.hbs".to_string())
+        })?,
+    };
+
+    SubstitutionEngine::new()
+        .and_then(|engine| engine.validate_template(&file.template))
+        .map_err(|e| invalid(e.to_string()))?;
+    if file.template.trim().is_empty() {
+        warn!("Prompt template {} is empty", path.display());
+    }
+
+    Ok(PromptTemplate {
+        name: file.name.unwrap_or_else(|| stem.to_string()),
+        research_type,
+        provider: file.provider.or(stem_provider),
+        version: file.version.or(stem_version).unwrap_or(1),
+        description: file.description,
+        template: file.template,
+        source: Some(path.to_path_buf()),
+    })
+}
+
+/// Placeholder values available to prompt templates for a request
+///
+/// `query` is the research question. The parameter names used by the
+/// built-in templates (`problem`, `feature`, `concept`, ...) are filled too,
+/// so an edited copy of a built-in template renders unchanged.
+pub fn request_parameters(request: &ClassifiedRequest) -> HashMap<String, ParameterValue> {
+    let query = request.original_query.clone();
+    let audience = &request.audience_context;
+    let domain = &request.domain_context;
+    [
+        ("query", query.clone()),
+        ("problem", query.clone()),
+        ("feature", query.clone()),
+        ("concept", query.clone()),
+        ("approach", query),
+        (
+            "context",
+            format!(
+                "Technology: {}, Project: {}, Audience: {} level",
+                domain.technology, domain.project_type, audience.level
+            ),
+        ),
+        (
+            "technology",
+            format!("{} ({})", domain.technology, domain.frameworks.join(", ")),
+        ),
+        (
+            "symptoms",
+            format!(
+                "Context: {} project using {}",
+                domain.project_type, domain.technology
+            ),
+        ),
+        ("level", audience.level.clone()),
+        (
+            "criteria",
+            format!(
+                "Suitable for {} level developers in {} domain",
+                audience.level, audience.domain
+            ),
+        ),
+        ("domain", audience.domain.clone()),
+        ("project_type", domain.project_type.clone()),
+    ]
+    .into_iter()
+    .map(|(name, value)| (name.to_string(), ParameterValue::Text(value)))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_types::{AudienceContext, DomainContext};
+
+    fn request() -> ClassifiedRequest {
+        ClassifiedRequest::new(
+            "How do I share state across tasks?".to_string(),
+            ResearchType::Learning,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.9,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_load_resolves_provider_variant_and_latest_version() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("learning.hbs"), "Explain {{concept}}").unwrap();
+        std::fs::write(
+            dir.path().join("learning.v2.hbs"),
+            "Teach {{concept}} at {{level}} level",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("learning.claude.toml"),
+            "name = \"Claude learning\"\nversion = 4\ntemplate = \"<question>{{query}}</question>\"\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let registry = PromptTemplateRegistry::load(dir.path()).unwrap();
+        assert_eq!(registry.templates().len(), 3);
+
+        let shared = registry
+            .resolve(&ResearchType::Learning, Some("openai"))
+            .unwrap();
+        assert_eq!(shared.version, 2);
+        assert_eq!(
+            shared.render(&request_parameters(&request())).unwrap(),
+            "Teach How do I share state across tasks? at intermediate level"
+        );
+
+        let claude = registry
+            .resolve(&ResearchType::Learning, Some("claude"))
+            .unwrap();
+        assert_eq!(claude.name, "Claude learning");
+        assert_eq!(claude.version, 4);
+        assert!(registry.resolve(&ResearchType::Decision, None).is_none());
+    }
+
+    #[test]
+    fn test_invalid_templates_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("summary.hbs"), "{{query}}").unwrap();
+        assert!(matches!(
+            PromptTemplateRegistry::load(dir.path()),
+            Err(PromptTemplateError::Invalid { .. })
+        ));
+
+        let template = PromptTemplate {
+            name: "unknown".to_string(),
+            research_type: ResearchType::Decision,
+            provider: None,
+            version: 1,
+            description: String::new(),
+            template: "{{missing}}".to_string(),
+            source: None,
+        };
+        assert!(template.render(&request_parameters(&request())).is_err());
+    }
+
+    #[test]
+    fn test_save_writes_next_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut registry = PromptTemplateRegistry::load(dir.path().join("prompts")).unwrap();
+        assert!(registry.is_empty());
+
+        let builtin = PromptTemplateRegistry::builtin();
+        let base = builtin.resolve(&ResearchType::Decision, None).unwrap();
+        assert_eq!(base.version, 0);
+        assert!(base.render(&request_parameters(&request())).is_ok());
+
+        for content in ["First {{problem}}", "Second {{problem}}"] {
+            let version = registry.next_version(&ResearchType::Decision, Some("gemini"));
+            registry
+                .save(PromptTemplate {
+                    version,
+                    provider: Some("gemini".to_string()),
+                    template: content.to_string(),
+                    ..base.clone()
+                })
+                .unwrap();
+        }
+
+        let reloaded = PromptTemplateRegistry::load(dir.path().join("prompts")).unwrap();
+        let versions = reloaded.versions(&ResearchType::Decision, Some("gemini"));
+        assert_eq!(
+            versions.iter().map(|t| t.version).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(versions[1].template, "Second {{problem}}");
+        assert_eq!(versions[1].name, base.name);
+        assert!(versions[1]
+            .source
+            .as_ref()
+            .unwrap()
+            .ends_with("decision.gemini.v2.toml"));
+    }
+}
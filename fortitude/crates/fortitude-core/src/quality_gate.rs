@@ -0,0 +1,181 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Quality gate scoring research results before they are cached
+//! A [`ResultScorer`] rates every fresh result. Results whose composite score
+//! is below the pipeline's `quality_threshold` are researched again with a
+//! prompt that names the weak dimensions, and the best-scoring attempt is
+//! kept. Scores are recorded in the result metadata so cached results carry
+//! them too.
+
+use async_trait::async_trait;
+use fortitude_types::{ClassifiedRequest, ResearchResult};
+use std::collections::BTreeMap;
+
+/// Metadata tag holding the number of re-queries the quality gate made
+pub const QUALITY_REQUERIES_TAG: &str = "quality_requeries";
+/// Prefix of the metadata tags holding per-dimension scores
+pub const QUALITY_DIMENSION_TAG_PREFIX: &str = "quality.";
+
+/// Dimensions scoring below this are named in the refined prompt
+const WEAK_DIMENSION_THRESHOLD: f64 = 0.6;
+
+/// Score of one research result
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct QualityAssessment {
+    /// Weighted composite score (0.0-1.0)
+    pub composite: f64,
+    /// Score of each quality dimension by name (0.0-1.0)
+    pub dimensions: BTreeMap<String, f64>,
+}
+
+impl QualityAssessment {
+    /// Dimensions below [`WEAK_DIMENSION_THRESHOLD`], weakest first
+    pub fn weak_dimensions(&self) -> Vec<&str> {
+        let mut weak: Vec<(&str, f64)> = self
+            .dimensions
+            .iter()
+            .filter(|(_, score)| **score < WEAK_DIMENSION_THRESHOLD)
+            .map(|(name, score)| (name.as_str(), *score))
+            .collect();
+        weak.sort_by(|a, b| a.1.total_cmp(&b.1));
+        weak.into_iter().map(|(name, _)| name).collect()
+    }
+
+    /// Record the scores in the result's metadata
+    pub fn apply_to(&self, result: &mut ResearchResult) {
+        result.metadata.quality_score = self.composite;
+        for (dimension, score) in &self.dimensions {
+            result.metadata.tags.insert(
+                format!("{QUALITY_DIMENSION_TAG_PREFIX}{dimension}"),
+                format!("{score:.3}"),
+            );
+        }
+    }
+}
+
+/// Scores research results for the pipeline's quality gate
+#[async_trait]
+pub trait ResultScorer: Send + Sync {
+    async fn score(&self, result: &ResearchResult) -> Result<QualityAssessment, String>;
+}
+
+/// Request asking for an answer that fixes the weaknesses of `assessment`
+pub fn refine_request(
+    request: &ClassifiedRequest,
+    assessment: &QualityAssessment,
+) -> ClassifiedRequest {
+    let weak = assessment.weak_dimensions();
+    let focus = if weak.is_empty() {
+        "overall quality".to_string()
+    } else {
+        weak.join(", ")
+    };
+
+    let mut refined = request.clone();
+    refined.original_query = format!(
+        "{}\n\nA previous answer to this question scored {:.2} for quality. \
+         Give a more thorough answer that improves its {focus}, with concrete \
+         examples and sources.",
+        request.original_query, assessment.composite
+    );
+    refined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_types::{AudienceContext, DomainContext, ResearchMetadata, ResearchType};
+    use std::collections::HashMap;
+
+    fn assessment(composite: f64, dimensions: &[(&str, f64)]) -> QualityAssessment {
+        QualityAssessment {
+            composite,
+            dimensions: dimensions
+                .iter()
+                .map(|(name, score)| (name.to_string(), *score))
+                .collect(),
+        }
+    }
+
+    fn request() -> ClassifiedRequest {
+        ClassifiedRequest::new(
+            "How do I share state between tokio tasks?".to_string(),
+            ResearchType::Implementation,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_weak_dimensions_weakest_first() {
+        let assessment = assessment(
+            0.5,
+            &[
+                ("clarity", 0.9),
+                ("completeness", 0.2),
+                ("specificity", 0.4),
+            ],
+        );
+        assert_eq!(
+            assessment.weak_dimensions(),
+            vec!["completeness", "specificity"]
+        );
+    }
+
+    #[test]
+    fn test_refine_request_names_weak_dimensions() {
+        let refined = refine_request(
+            &request(),
+            &assessment(0.42, &[("completeness", 0.2), ("clarity", 0.9)]),
+        );
+        assert!(refined
+            .original_query
+            .starts_with("How do I share state between tokio tasks?"));
+        assert!(refined.original_query.contains("scored 0.42"));
+        assert!(refined
+            .original_query
+            .contains("improves its completeness,"));
+        assert!(!refined.original_query.contains("clarity"));
+
+        let refined = refine_request(&request(), &assessment(0.42, &[]));
+        assert!(refined
+            .original_query
+            .contains("improves its overall quality"));
+    }
+
+    #[test]
+    fn test_apply_to_records_scores() {
+        let mut result = ResearchResult::new(
+            request(),
+            "Use Arc<Mutex<T>>".to_string(),
+            vec![],
+            vec![],
+            ResearchMetadata {
+                completed_at: chrono::Utc::now(),
+                processing_time_ms: 0,
+                sources_consulted: vec![],
+                quality_score: 0.0,
+                cache_key: String::new(),
+                tags: HashMap::new(),
+            },
+        );
+
+        assessment(0.75, &[("relevance", 0.8)]).apply_to(&mut result);
+        assert_eq!(result.metadata.quality_score, 0.75);
+        assert_eq!(result.metadata.tags["quality.relevance"], "0.800");
+    }
+}
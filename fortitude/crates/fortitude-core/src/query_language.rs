@@ -0,0 +1,756 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Search query language shared by cache search, keyword search and the API
+// Supports AND/OR/NOT, parentheses, "phrases", -exclusion and type:/tag:/quality: filters
+//
+// Grammar (adjacent operands are joined with AND):
+//
+//   or      := and ("OR" and)*
+//   and     := unary (["AND"] unary)*
+//   unary   := ("NOT" | "-") unary | primary
+//   primary := "(" or ")" | field ":" value | "\"" phrase "\"" | term
+
+use crate::vector::VectorDocument;
+use fortitude_types::{CacheEntry, IndexEntry, ResearchType};
+use std::fmt;
+use thiserror::Error;
+
+/// Field names accepted in `field:value` filters
+pub const QUERY_FIELDS: [&str; 3] = ["type", "tag", "quality"];
+
+/// Query that could not be parsed
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("{message} at position {position}{}", suggestion.as_ref().map(|s| format!(" ({s})")).unwrap_or_default())]
+pub struct QueryParseError {
+    pub message: String,
+    /// Character offset into the query, starting at 0
+    pub position: usize,
+    pub suggestion: Option<String>,
+}
+
+impl QueryParseError {
+    fn new(message: impl Into<String>, position: usize) -> Self {
+        Self {
+            message: message.into(),
+            position,
+            suggestion: None,
+        }
+    }
+
+    fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+}
+
+/// Comparison used by `quality:` filters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Equal,
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+}
+
+impl Comparison {
+    fn holds(&self, actual: f64, expected: f64) -> bool {
+        match self {
+            Comparison::Equal => (actual - expected).abs() < f64::EPSILON,
+            Comparison::Greater => actual > expected,
+            Comparison::GreaterOrEqual => actual >= expected,
+            Comparison::Less => actual < expected,
+            Comparison::LessOrEqual => actual <= expected,
+        }
+    }
+}
+
+/// Node of a parsed query
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryNode {
+    /// Lowercased word contained in the searchable text
+    Term(String),
+    /// Lowercased exact phrase contained in the searchable text
+    Phrase(String),
+    Type(ResearchType),
+    Tag(String),
+    Quality(Comparison, f64),
+    Not(Box<QueryNode>),
+    And(Vec<QueryNode>),
+    Or(Vec<QueryNode>),
+}
+
+/// Something the query language can be evaluated against
+pub trait Searchable {
+    /// Free text matched by terms and phrases
+    fn search_text(&self) -> String;
+
+    /// Whether the target is labeled with the research type
+    fn has_type(&self, research_type: &ResearchType) -> bool;
+
+    fn has_tag(&self, tag: &str) -> bool;
+
+    fn searchable_quality(&self) -> Option<f64>;
+}
+
+/// Parsed search query
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchExpression {
+    root: Option<QueryNode>,
+    plain: bool,
+}
+
+impl SearchExpression {
+    /// Parse a query; a blank query parses to an empty expression
+    pub fn parse(input: &str) -> Result<Self, QueryParseError> {
+        let tokens = tokenize(input)?;
+        let plain = tokens.iter().all(|t| matches!(t.kind, TokenKind::Term(_)));
+        let mut parser = Parser { tokens, pos: 0 };
+        let root = if parser.tokens.is_empty() {
+            None
+        } else {
+            let node = parser.parse_or()?;
+            if let Some(token) = parser.peek() {
+                return Err(match token.kind {
+                    TokenKind::RParen => QueryParseError::new("Unexpected ')'", token.position)
+                        .with_suggestion("remove it or add a matching '('"),
+                    _ => QueryParseError::new("Unexpected input", token.position),
+                });
+            }
+            Some(node)
+        };
+        Ok(Self { root, plain })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Whether the query is only bare words, with no operators, filters or phrases
+    pub fn is_plain(&self) -> bool {
+        self.plain
+    }
+
+    pub fn root(&self) -> Option<&QueryNode> {
+        self.root.as_ref()
+    }
+
+    /// Evaluate the query against a target
+    ///
+    /// Plain queries keep the keyword search behaviour of matching any of
+    /// their words; all other queries are evaluated as written. An empty
+    /// query matches everything.
+    pub fn matches<T: Searchable + ?Sized>(&self, target: &T) -> bool {
+        let Some(root) = &self.root else {
+            return true;
+        };
+        let text = target.search_text().to_lowercase();
+        if self.plain {
+            return self
+                .positive_terms()
+                .iter()
+                .any(|term| text.contains(term.as_str()));
+        }
+        evaluate(root, target, &text)
+    }
+
+    /// Terms and phrases that are not excluded, for relevance scoring
+    pub fn positive_terms(&self) -> Vec<String> {
+        let mut terms = Vec::new();
+        if let Some(root) = &self.root {
+            collect_positive_terms(root, &mut terms);
+        }
+        terms
+    }
+}
+
+fn evaluate<T: Searchable + ?Sized>(node: &QueryNode, target: &T, text: &str) -> bool {
+    match node {
+        QueryNode::Term(term) | QueryNode::Phrase(term) => text.contains(term.as_str()),
+        QueryNode::Type(research_type) => target.has_type(research_type),
+        QueryNode::Tag(tag) => target.has_tag(tag),
+        QueryNode::Quality(comparison, expected) => target
+            .searchable_quality()
+            .is_some_and(|actual| comparison.holds(actual, *expected)),
+        QueryNode::Not(inner) => !evaluate(inner, target, text),
+        QueryNode::And(nodes) => nodes.iter().all(|n| evaluate(n, target, text)),
+        QueryNode::Or(nodes) => nodes.iter().any(|n| evaluate(n, target, text)),
+    }
+}
+
+fn collect_positive_terms(node: &QueryNode, terms: &mut Vec<String>) {
+    match node {
+        QueryNode::Term(term) | QueryNode::Phrase(term) => {
+            if !terms.contains(term) {
+                terms.push(term.clone());
+            }
+        }
+        QueryNode::And(nodes) | QueryNode::Or(nodes) => {
+            for n in nodes {
+                collect_positive_terms(n, terms);
+            }
+        }
+        QueryNode::Not(_) | QueryNode::Type(_) | QueryNode::Tag(_) | QueryNode::Quality(..) => {}
+    }
+}
+
+impl fmt::Display for QueryNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |f: &mut fmt::Formatter<'_>, nodes: &[QueryNode], op: &str| {
+            write!(f, "(")?;
+            for (i, node) in nodes.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " {op} ")?;
+                }
+                write!(f, "{node}")?;
+            }
+            write!(f, ")")
+        };
+        match self {
+            QueryNode::Term(term) => write!(f, "{term}"),
+            QueryNode::Phrase(phrase) => write!(f, "\"{phrase}\""),
+            QueryNode::Type(research_type) => {
+                write!(f, "type:{}", research_type.display_name().to_lowercase())
+            }
+            QueryNode::Tag(tag) => write!(f, "tag:{tag}"),
+            QueryNode::Quality(comparison, value) => {
+                let op = match comparison {
+                    Comparison::Equal => "",
+                    Comparison::Greater => ">",
+                    Comparison::GreaterOrEqual => ">=",
+                    Comparison::Less => "<",
+                    Comparison::LessOrEqual => "<=",
+                };
+                write!(f, "quality:{op}{value}")
+            }
+            QueryNode::Not(inner) => write!(f, "NOT {inner}"),
+            QueryNode::And(nodes) => join(f, nodes, "AND"),
+            QueryNode::Or(nodes) => join(f, nodes, "OR"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    LParen,
+    RParen,
+    And,
+    Or,
+    Not,
+    Term(String),
+    Phrase(String),
+    Field(QueryNode),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    position: usize,
+}
+
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || c == '(' || c == ')' || c == '"'
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, QueryParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token {
+                    kind: TokenKind::LParen,
+                    position: start,
+                });
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token {
+                    kind: TokenKind::RParen,
+                    position: start,
+                });
+                i += 1;
+            }
+            '"' => {
+                let (phrase, next) = read_phrase(&chars, i)?;
+                tokens.push(Token {
+                    kind: TokenKind::Phrase(phrase),
+                    position: start,
+                });
+                i = next;
+            }
+            '-' if chars.get(i + 1).is_some_and(|n| !n.is_whitespace()) => {
+                tokens.push(Token {
+                    kind: TokenKind::Not,
+                    position: start,
+                });
+                i += 1;
+            }
+            _ => {
+                while i < chars.len() && !is_delimiter(chars[i]) {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                let kind = match word.as_str() {
+                    "AND" | "&&" => TokenKind::And,
+                    "OR" | "||" => TokenKind::Or,
+                    "NOT" => TokenKind::Not,
+                    _ => match split_field(&word) {
+                        Some((name, value)) => {
+                            // Phrase values: tag:"machine learning"
+                            let value = if value.is_empty() && chars.get(i) == Some(&'"') {
+                                let (phrase, next) = read_phrase(&chars, i)?;
+                                i = next;
+                                phrase
+                            } else {
+                                value.to_string()
+                            };
+                            match parse_field(name, &value, start)? {
+                                Some(node) => TokenKind::Field(node),
+                                None => TokenKind::Term(word.to_lowercase()),
+                            }
+                        }
+                        None => TokenKind::Term(word.to_lowercase()),
+                    },
+                };
+                tokens.push(Token {
+                    kind,
+                    position: start,
+                });
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Read a quoted phrase starting at the opening quote
+fn read_phrase(chars: &[char], open: usize) -> Result<(String, usize), QueryParseError> {
+    let close = chars[open + 1..]
+        .iter()
+        .position(|c| *c == '"')
+        .map(|offset| open + 1 + offset)
+        .ok_or_else(|| {
+            QueryParseError::new("Unterminated phrase", open).with_suggestion("add a closing '\"'")
+        })?;
+    let phrase: String = chars[open + 1..close].iter().collect();
+    let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+    if phrase.is_empty() {
+        return Err(QueryParseError::new("Empty phrase", open)
+            .with_suggestion("put words between the quotes or remove them"));
+    }
+    Ok((phrase.to_lowercase(), close + 1))
+}
+
+/// Split `name:value` where the name is a plain identifier; `std::io` and URLs are left alone
+fn split_field(word: &str) -> Option<(&str, &str)> {
+    let (name, value) = word.split_once(':')?;
+    if name.is_empty()
+        || !name.chars().all(|c| c.is_ascii_alphabetic() || c == '_')
+        || value.starts_with(':')
+        || value.starts_with('/')
+    {
+        return None;
+    }
+    Some((name, value))
+}
+
+/// Parse a field filter; unknown fields that are not close to a known one are plain terms
+fn parse_field(
+    name: &str,
+    value: &str,
+    position: usize,
+) -> Result<Option<QueryNode>, QueryParseError> {
+    let field = name.to_lowercase();
+    if !QUERY_FIELDS.contains(&field.as_str()) {
+        return match closest(&field, QUERY_FIELDS.iter().copied()) {
+            Some(known) => Err(
+                QueryParseError::new(format!("Unknown field '{name}'"), position)
+                    .with_suggestion(format!("did you mean '{known}:'?")),
+            ),
+            None => Ok(None),
+        };
+    }
+
+    let value_position = position + name.chars().count() + 1;
+    if value.is_empty() {
+        let example = match field.as_str() {
+            "type" => "type:troubleshooting",
+            "tag" => "tag:rust",
+            _ => "quality:>=0.8",
+        };
+        return Err(QueryParseError::new(
+            format!("Missing value for field '{name}'"),
+            value_position,
+        )
+        .with_suggestion(format!("for example {example}")));
+    }
+
+    let node = match field.as_str() {
+        "type" => {
+            let research_type = value.parse::<ResearchType>().map_err(|_| {
+                let names: Vec<String> = ResearchType::all()
+                    .iter()
+                    .map(|t| t.display_name().to_lowercase())
+                    .collect();
+                let suggestion =
+                    match closest(&value.to_lowercase(), names.iter().map(|n| n.as_str())) {
+                        Some(known) => format!("did you mean '{known}'?"),
+                        None => format!("expected one of: {}", names.join(", ")),
+                    };
+                QueryParseError::new(format!("Unknown research type '{value}'"), value_position)
+                    .with_suggestion(suggestion)
+            })?;
+            QueryNode::Type(research_type)
+        }
+        "tag" => QueryNode::Tag(value.to_string()),
+        _ => {
+            let (comparison, number) = if let Some(rest) = value.strip_prefix(">=") {
+                (Comparison::GreaterOrEqual, rest)
+            } else if let Some(rest) = value.strip_prefix("<=") {
+                (Comparison::LessOrEqual, rest)
+            } else if let Some(rest) = value.strip_prefix('>') {
+                (Comparison::Greater, rest)
+            } else if let Some(rest) = value.strip_prefix('<') {
+                (Comparison::Less, rest)
+            } else {
+                (Comparison::Equal, value)
+            };
+            let quality = number
+                .parse::<f64>()
+                .ok()
+                .filter(|q| (0.0..=1.0).contains(q))
+                .ok_or_else(|| {
+                    QueryParseError::new(format!("Invalid quality '{value}'"), value_position)
+                        .with_suggestion(
+                            "expected a number between 0.0 and 1.0, e.g. quality:>=0.8",
+                        )
+                })?;
+            QueryNode::Quality(comparison, quality)
+        }
+    };
+    Ok(Some(node))
+}
+
+/// Closest candidate within two edits
+fn closest<'a>(word: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .map(|candidate| (edit_distance(word, candidate), candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous + usize::from(ca != *cb);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(previous + 1);
+        }
+    }
+    row[b.len()]
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn end_position(&self) -> usize {
+        self.tokens
+            .last()
+            .map(|t| t.position + 1)
+            .unwrap_or_default()
+    }
+
+    fn parse_or(&mut self) -> Result<QueryNode, QueryParseError> {
+        let mut nodes = vec![self.parse_and()?];
+        while self.peek().is_some_and(|t| t.kind == TokenKind::Or) {
+            self.next();
+            nodes.push(self.parse_and()?);
+        }
+        Ok(if nodes.len() == 1 {
+            nodes.remove(0)
+        } else {
+            QueryNode::Or(nodes)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<QueryNode, QueryParseError> {
+        let mut nodes = vec![self.parse_unary()?];
+        loop {
+            match self.peek().map(|t| &t.kind) {
+                Some(TokenKind::And) => {
+                    self.next();
+                    nodes.push(self.parse_unary()?);
+                }
+                Some(TokenKind::Or) | Some(TokenKind::RParen) | None => break,
+                Some(_) => nodes.push(self.parse_unary()?),
+            }
+        }
+        Ok(if nodes.len() == 1 {
+            nodes.remove(0)
+        } else {
+            QueryNode::And(nodes)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<QueryNode, QueryParseError> {
+        if self.peek().is_some_and(|t| t.kind == TokenKind::Not) {
+            self.next();
+            return Ok(QueryNode::Not(Box::new(self.parse_unary()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<QueryNode, QueryParseError> {
+        let previous = self
+            .pos
+            .checked_sub(1)
+            .and_then(|p| self.tokens.get(p))
+            .cloned();
+        let Some(token) = self.next() else {
+            let operator = previous
+                .map(|t| operator_name(&t.kind))
+                .unwrap_or("operator");
+            return Err(QueryParseError::new(
+                format!("Expected a search term after '{operator}'"),
+                self.end_position(),
+            )
+            .with_suggestion(format!("remove the trailing '{operator}'")));
+        };
+
+        match token.kind {
+            TokenKind::Term(term) => Ok(QueryNode::Term(term)),
+            TokenKind::Phrase(phrase) => Ok(QueryNode::Phrase(phrase)),
+            TokenKind::Field(node) => Ok(node),
+            TokenKind::LParen => {
+                if self.peek().is_some_and(|t| t.kind == TokenKind::RParen) {
+                    return Err(QueryParseError::new("Empty group", token.position)
+                        .with_suggestion("put search terms between the parentheses"));
+                }
+                let node = self.parse_or()?;
+                match self.next() {
+                    Some(Token {
+                        kind: TokenKind::RParen,
+                        ..
+                    }) => Ok(node),
+                    _ => Err(
+                        QueryParseError::new("Missing closing parenthesis", token.position)
+                            .with_suggestion("add a ')'"),
+                    ),
+                }
+            }
+            TokenKind::RParen => Err(QueryParseError::new("Unexpected ')'", token.position)
+                .with_suggestion("remove it or add a matching '('")),
+            kind @ (TokenKind::And | TokenKind::Or) => {
+                let operator = operator_name(&kind);
+                Err(QueryParseError::new(
+                    format!("Expected a search term before '{operator}'"),
+                    token.position,
+                )
+                .with_suggestion(format!(
+                    "remove '{operator}' or quote it to search for the word"
+                )))
+            }
+            TokenKind::Not => unreachable!("NOT is handled by parse_unary"),
+        }
+    }
+}
+
+fn operator_name(kind: &TokenKind) -> &'static str {
+    match kind {
+        TokenKind::And => "AND",
+        TokenKind::Or => "OR",
+        TokenKind::Not => "NOT",
+        TokenKind::LParen => "(",
+        _ => "operator",
+    }
+}
+
+impl Searchable for IndexEntry {
+    fn search_text(&self) -> String {
+        format!(
+            "{}\n{}\n{}",
+            self.original_query,
+            self.content,
+            self.keywords.join(" ")
+        )
+    }
+
+    fn has_type(&self, research_type: &ResearchType) -> bool {
+        self.has_research_type(research_type)
+    }
+
+    fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    fn searchable_quality(&self) -> Option<f64> {
+        Some(self.quality_score)
+    }
+}
+
+impl Searchable for CacheEntry {
+    fn search_text(&self) -> String {
+        self.original_query.clone()
+    }
+
+    fn has_type(&self, research_type: &ResearchType) -> bool {
+        self.has_research_type(research_type)
+    }
+
+    fn has_tag(&self, tag: &str) -> bool {
+        self.metadata
+            .get("tags")
+            .is_some_and(|tags| tags.split(',').any(|t| t.trim().eq_ignore_ascii_case(tag)))
+    }
+
+    fn searchable_quality(&self) -> Option<f64> {
+        self.metadata
+            .get("quality_score")
+            .and_then(|q| q.parse().ok())
+    }
+}
+
+impl Searchable for VectorDocument {
+    fn search_text(&self) -> String {
+        let mut text = self.content.clone();
+        for value in self.metadata.custom_fields.values() {
+            if let Some(field) = value.as_str() {
+                text.push('\n');
+                text.push_str(field);
+            }
+        }
+        text
+    }
+
+    fn has_type(&self, research_type: &ResearchType) -> bool {
+        self.metadata.research_type.as_ref() == Some(research_type)
+    }
+
+    fn has_tag(&self, tag: &str) -> bool {
+        self.metadata
+            .tags
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    fn searchable_quality(&self) -> Option<f64> {
+        self.metadata.quality_score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(research_type: ResearchType, content: &str, tags: &[&str]) -> IndexEntry {
+        IndexEntry::new(
+            "key".to_string(),
+            research_type,
+            "query".to_string(),
+            content.to_string(),
+            vec![],
+            tags.iter().map(|t| t.to_string()).collect(),
+            0.8,
+        )
+    }
+
+    #[test]
+    fn test_boolean_query_with_fields_and_exclusion() {
+        let query =
+            SearchExpression::parse("type:troubleshooting AND (tokio OR async-std) -deprecated")
+                .unwrap();
+        assert!(!query.is_plain());
+        assert_eq!(query.positive_terms(), vec!["tokio", "async-std"]);
+
+        let matching = entry(ResearchType::Troubleshooting, "tokio runtime panics", &[]);
+        let deprecated = entry(ResearchType::Troubleshooting, "deprecated tokio APIs", &[]);
+        let wrong_type = entry(ResearchType::Learning, "tokio runtime", &[]);
+        assert!(query.matches(&matching));
+        assert!(!query.matches(&deprecated));
+        assert!(!query.matches(&wrong_type));
+
+        let phrase = SearchExpression::parse("\"error handling\" tag:rust quality:>=0.5").unwrap();
+        assert!(phrase.matches(&entry(
+            ResearchType::Learning,
+            "Error handling in Rust",
+            &["rust"]
+        )));
+        assert!(!phrase.matches(&entry(ResearchType::Learning, "handling errors", &["rust"])));
+
+        // Plain queries match any word, as keyword search always has
+        let plain = SearchExpression::parse("tokio smol").unwrap();
+        assert!(plain.is_plain());
+        assert!(plain.matches(&matching));
+        assert!(SearchExpression::parse("  ").unwrap().is_empty());
+        assert!(SearchExpression::parse("std::io http://docs.rs")
+            .unwrap()
+            .is_plain());
+    }
+
+    #[test]
+    fn test_parse_errors_report_position_and_suggestion() {
+        let err = SearchExpression::parse("tpye:learning").unwrap_err();
+        assert_eq!(err.position, 0);
+        assert_eq!(err.suggestion.as_deref(), Some("did you mean 'type:'?"));
+
+        let err = SearchExpression::parse("rust type:troubleshootng").unwrap_err();
+        assert_eq!(err.position, 10);
+        assert_eq!(
+            err.suggestion.as_deref(),
+            Some("did you mean 'troubleshooting'?")
+        );
+
+        let err = SearchExpression::parse("(tokio OR smol").unwrap_err();
+        assert_eq!(err.message, "Missing closing parenthesis");
+        assert_eq!(err.position, 0);
+
+        let err = SearchExpression::parse("rust AND").unwrap_err();
+        assert_eq!(err.message, "Expected a search term after 'AND'");
+
+        let err = SearchExpression::parse("async \"error handling").unwrap_err();
+        assert_eq!(err.position, 6);
+        assert_eq!(
+            err.to_string(),
+            "Unterminated phrase at position 6 (add a closing '\"')"
+        );
+
+        assert!(SearchExpression::parse("quality:>1.5").is_err());
+        assert!(SearchExpression::parse("OR rust").is_err());
+        assert!(SearchExpression::parse("rust)").is_err());
+    }
+}
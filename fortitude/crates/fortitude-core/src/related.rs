@@ -0,0 +1,306 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Earlier research results related to the one being returned
+//! Questions are often asked again in other words. When a result is returned,
+//! the earlier results whose queries are most similar are listed with it, and
+//! the optional duplicate check stops research on a question whose near twin
+//! was already researched. Similarity comes from the query documents the
+//! semantic cache indexes in vector storage; without vector storage, queries
+//! are compared by the words they share.
+
+use crate::knowledge::graph::{jaccard, words};
+use crate::semantic_cache::query_document_cache_key;
+use crate::vector::SimilaritySearchResult;
+use fortitude_types::{CacheEntry, ResearchType};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Settings for listing related results
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RelatedResultsConfig {
+    /// List related results with returned research
+    pub enabled: bool,
+    /// Most related results listed per result
+    pub max_results: usize,
+    /// Minimum embedding similarity of a related query (0.0-1.0)
+    pub min_similarity: f64,
+    /// Minimum share of words in common when no vector storage is configured (0.0-1.0)
+    pub min_lexical_similarity: f64,
+}
+
+impl Default for RelatedResultsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_results: 3,
+            min_similarity: 0.75,
+            min_lexical_similarity: 0.3,
+        }
+    }
+}
+
+impl RelatedResultsConfig {
+    /// Check that thresholds are similarities and results are listed
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, value) in [
+            ("min_similarity", self.min_similarity),
+            ("min_lexical_similarity", self.min_lexical_similarity),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(format!("{name} must be between 0.0 and 1.0, got {value}"));
+            }
+        }
+        if self.max_results == 0 {
+            return Err("max_results must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Settings for the preflight that finds earlier research on a new question
+///
+/// Thresholds are stricter than for related results: only near-duplicates
+/// stop research.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DuplicateCheckConfig {
+    /// Look for earlier research before researching a new question
+    pub enabled: bool,
+    /// Most earlier results listed as candidates
+    pub max_candidates: usize,
+    /// Minimum embedding similarity of an earlier query (0.0-1.0)
+    pub min_similarity: f64,
+    /// Minimum share of words in common when no vector storage is configured (0.0-1.0)
+    pub min_lexical_similarity: f64,
+}
+
+impl Default for DuplicateCheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_candidates: 5,
+            min_similarity: 0.9,
+            min_lexical_similarity: 0.6,
+        }
+    }
+}
+
+impl DuplicateCheckConfig {
+    /// Check that thresholds are similarities and candidates are listed
+    pub fn validate(&self) -> Result<(), String> {
+        self.lookup()
+            .validate()
+            .map_err(|e| e.replace("max_results", "max_candidates"))
+    }
+
+    /// The duplicate check as a related-results lookup
+    pub fn lookup(&self) -> RelatedResultsConfig {
+        RelatedResultsConfig {
+            enabled: self.enabled,
+            max_results: self.max_candidates,
+            min_similarity: self.min_similarity,
+            min_lexical_similarity: self.min_lexical_similarity,
+        }
+    }
+}
+
+/// Earlier result whose query resembles the one of a returned result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelatedResult {
+    /// Cache key of the earlier result
+    pub id: String,
+    /// The earlier query
+    pub query: String,
+    pub research_type: ResearchType,
+    /// Similarity between the queries (0.0-1.0)
+    pub relevance: f64,
+}
+
+/// Related results among the query documents found for a query
+///
+/// Only documents pointing at results still in `entries` are listed; the
+/// result stored under `exclude` is never listed.
+pub fn semantic_related(
+    exclude: Option<&str>,
+    candidates: &[SimilaritySearchResult],
+    entries: &[CacheEntry],
+    config: &RelatedResultsConfig,
+) -> Vec<RelatedResult> {
+    let stored: HashMap<&str, &CacheEntry> = entries.iter().map(|e| (e.key.as_str(), e)).collect();
+    let related = candidates
+        .iter()
+        .filter(|candidate| candidate.score >= config.min_similarity)
+        .filter_map(|candidate| {
+            let key = query_document_cache_key(&candidate.document)?;
+            let entry = stored.get(key)?;
+            Some(RelatedResult {
+                id: key.to_string(),
+                query: entry.original_query.clone(),
+                research_type: entry.research_type.clone(),
+                relevance: candidate.score,
+            })
+        });
+    strongest(exclude, related, config.max_results)
+}
+
+/// Related results among `entries` by the words their queries share with `query`
+pub fn lexical_related(
+    query: &str,
+    exclude: Option<&str>,
+    entries: &[CacheEntry],
+    config: &RelatedResultsConfig,
+) -> Vec<RelatedResult> {
+    let query_words = words(query);
+    let related = entries.iter().filter_map(|entry| {
+        let relevance = jaccard(&query_words, &words(&entry.original_query));
+        (relevance > 0.0 && relevance >= config.min_lexical_similarity).then(|| RelatedResult {
+            id: entry.key.clone(),
+            query: entry.original_query.clone(),
+            research_type: entry.research_type.clone(),
+            relevance,
+        })
+    });
+    strongest(exclude, related, config.max_results)
+}
+
+/// Most relevant results other than `exclude`, one per cache key
+fn strongest(
+    exclude: Option<&str>,
+    related: impl Iterator<Item = RelatedResult>,
+    max_results: usize,
+) -> Vec<RelatedResult> {
+    let mut best: HashMap<String, RelatedResult> = HashMap::new();
+    for candidate in related.filter(|r| Some(r.id.as_str()) != exclude) {
+        match best.get(&candidate.id) {
+            Some(known) if known.relevance >= candidate.relevance => {}
+            _ => {
+                best.insert(candidate.id.clone(), candidate);
+            }
+        }
+    }
+    let mut related: Vec<RelatedResult> = best.into_values().collect();
+    related.sort_by(|a, b| {
+        b.relevance
+            .total_cmp(&a.relevance)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    related.truncate(max_results);
+    related
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::semantic_cache::query_document_metadata;
+    use crate::vector::VectorDocument;
+    use chrono::Utc;
+    use fortitude_types::{
+        AudienceContext, ClassifiedRequest, DomainContext, ResearchMetadata, ResearchResult,
+    };
+    use std::path::PathBuf;
+
+    fn result(key: &str, query: &str) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            query.to_string(),
+            ResearchType::Learning,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 0,
+            sources_consulted: vec![],
+            quality_score: 0.8,
+            cache_key: key.to_string(),
+            tags: HashMap::new(),
+        };
+        ResearchResult::new(request, String::new(), vec![], vec![], metadata)
+    }
+
+    fn entry(key: &str, query: &str) -> CacheEntry {
+        CacheEntry::new(
+            key.to_string(),
+            PathBuf::from(format!("{key}.json")),
+            ResearchType::Learning,
+            query.to_string(),
+            0,
+            String::new(),
+            3600,
+        )
+    }
+
+    fn candidate(key: &str, query: &str, score: f64) -> SimilaritySearchResult {
+        SimilaritySearchResult {
+            document: VectorDocument {
+                id: format!("doc-{key}"),
+                content: query.to_string(),
+                embedding: vec![],
+                metadata: query_document_metadata(&result(key, query)),
+                stored_at: Utc::now(),
+            },
+            score,
+        }
+    }
+
+    #[test]
+    fn test_related_results_exclude_self_and_rank_by_relevance() {
+        let config = RelatedResultsConfig {
+            max_results: 2,
+            ..RelatedResultsConfig::default()
+        };
+        let query = "How do I handle errors in Rust?";
+        let entries = vec![
+            entry("current", "How do I handle errors in Rust?"),
+            entry("errors", "Handling errors in Rust"),
+            entry("anyhow", "How do I use anyhow for errors in Rust?"),
+            entry("toml", "How do I parse TOML files?"),
+        ];
+
+        let lexical = lexical_related(query, Some("current"), &entries, &config);
+        let ids: Vec<&str> = lexical.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["anyhow", "errors"]);
+        assert!(lexical[0].relevance >= lexical[1].relevance);
+
+        // The duplicate check only reports the near-identical question
+        let duplicates = lexical_related(
+            query,
+            None,
+            &entries,
+            &DuplicateCheckConfig::default().lookup(),
+        );
+        let ids: Vec<&str> = duplicates.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["current"]);
+
+        // Query documents of removed results and weak matches are skipped
+        let removed = candidate("removed", "Rust error handling", 0.95);
+        let mut answer = candidate("toml", "How do I parse TOML files?", 0.9);
+        answer.document.metadata.content_type = "research_result".to_string();
+        let candidates = vec![
+            candidate("current", "How do I handle errors in Rust?", 1.0),
+            removed,
+            answer,
+            candidate("anyhow", "How do I use anyhow for errors in Rust?", 0.82),
+            candidate("anyhow", "How do I use anyhow for errors in Rust?", 0.88),
+            candidate("errors", "Handling errors in Rust", 0.7),
+        ];
+        let semantic = semantic_related(Some("current"), &candidates, &entries, &config);
+        assert_eq!(semantic.len(), 1);
+        assert_eq!(semantic[0].id, "anyhow");
+        assert_eq!(semantic[0].relevance, 0.88);
+    }
+}
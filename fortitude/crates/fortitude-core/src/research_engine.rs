@@ -18,11 +18,12 @@ use chrono::Utc;
 use std::collections::HashMap;
 use std::time::Instant;
 use thiserror::Error;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, instrument, warn};
 
 use crate::api::{ApiClient, ApiError, ClaudeClient, ClaudeConfig, ClaudeRequest, Message};
 use crate::error_handling::PipelineError;
 use crate::prompts::{DefaultTemplateFactory, ParameterValue, QualityValidator, TemplateRegistry};
+use crate::tools::ToolRegistry;
 use crate::vector::{
     FusionMethod, HybridSearchRequest, HybridSearchService, SearchOptions, SearchStrategy,
     VectorDocument,
@@ -117,6 +118,17 @@ pub trait ResearchEngine: Send + Sync {
         request: &ClassifiedRequest,
     ) -> Result<ResearchResult, ResearchEngineError>;
 
+    /// Generate research, offering `tools` to providers that support tool calling
+    ///
+    /// Engines without tool support ignore the tools.
+    async fn generate_research_with_tools(
+        &self,
+        request: &ClassifiedRequest,
+        _tools: &ToolRegistry,
+    ) -> Result<ResearchResult, ResearchEngineError> {
+        self.generate_research(request).await
+    }
+
     /// Generate research with context discovery using vector search
     async fn generate_research_with_context(
         &self,
@@ -269,6 +281,26 @@ impl ClaudeResearchEngine {
         })
     }
 
+    /// Answer token limit, lowered by the request's prompt budget
+    fn max_tokens_for(&self, request: &ClassifiedRequest) -> u32 {
+        request
+            .prompt_budget
+            .as_ref()
+            .map_or(self.config.max_tokens, |b| {
+                b.max_answer_tokens.min(self.config.max_tokens)
+            })
+    }
+
+    /// Context document limit, lowered by the request's prompt budget
+    fn max_context_documents_for(&self, request: &ClassifiedRequest) -> usize {
+        request
+            .prompt_budget
+            .as_ref()
+            .map_or(self.config.max_context_documents, |b| {
+                b.max_context_docs.min(self.config.max_context_documents)
+            })
+    }
+
     /// Build the research prompt for a classified request using template system
     fn build_research_prompt(
         &self,
@@ -378,13 +410,29 @@ impl ClaudeResearchEngine {
             request.audience_context.format
         );
 
-        let domain_context = format!(
+        let mut domain_context = format!(
             "Technology: {}\nProject type: {}\nFrameworks: {}\nTags: {}",
             request.domain_context.technology,
             request.domain_context.project_type,
             request.domain_context.frameworks.join(", "),
             request.domain_context.tags.join(", ")
         );
+        if let Some(project_context) = &request.project_context {
+            domain_context.push_str("\n\n");
+            domain_context.push_str(project_context.trim_end());
+        }
+        if let Some(code_context) = &request.code_context {
+            domain_context.push_str("\n\n");
+            domain_context.push_str(code_context.trim_end());
+        }
+        if let Some(conversation_context) = &request.conversation_context {
+            domain_context.push_str("\n\n");
+            domain_context.push_str(conversation_context.trim_end());
+        }
+        if let Some(advisory_context) = &request.advisory_context {
+            domain_context.push_str("\n\n");
+            domain_context.push_str(advisory_context.trim_end());
+        }
 
         Ok(format!(
             r#"{}
@@ -525,6 +573,7 @@ Ensure your response is:
     }
 
     /// Validate the quality of generated research using the quality validator
+    #[instrument(name = "research.quality", skip_all)]
     fn validate_research_quality(
         &self,
         result: &ResearchResult,
@@ -555,6 +604,11 @@ Ensure your response is:
 
 #[async_trait]
 impl ResearchEngine for ClaudeResearchEngine {
+    #[instrument(
+        name = "provider.generate",
+        skip_all,
+        fields(provider = "claude", research_type = %request.research_type)
+    )]
     async fn generate_research(
         &self,
         request: &ClassifiedRequest,
@@ -576,7 +630,7 @@ impl ResearchEngine for ClaudeResearchEngine {
         // Create Claude API request
         let claude_request = ClaudeRequest {
             model: "claude-3-sonnet-20240229".to_string(),
-            max_tokens: self.config.max_tokens,
+            max_tokens: self.max_tokens_for(request),
             messages: vec![Message {
                 role: "user".to_string(),
                 content: user_prompt,
@@ -771,8 +825,10 @@ impl ClaudeResearchEngine {
             query: request.original_query.clone(),
             strategy: Some(search_strategy),
             fusion_method: Some(FusionMethod::ReciprocalRankFusion),
+            score_normalization: None,
+            rrf_k: None,
             options: SearchOptions {
-                limit: self.config.max_context_documents,
+                limit: self.max_context_documents_for(request),
                 threshold: Some(self.config.context_relevance_threshold),
                 filters: vec![], // TODO: Add research type filtering
                 ..Default::default()
@@ -835,7 +891,7 @@ impl ClaudeResearchEngine {
         // Create Claude API request
         let claude_request = ClaudeRequest {
             model: "claude-3-sonnet-20240229".to_string(),
-            max_tokens: self.config.max_tokens,
+            max_tokens: self.max_tokens_for(request),
             messages: vec![Message {
                 role: "user".to_string(),
                 content: user_prompt,
@@ -971,6 +1027,19 @@ mod tests {
         assert!(prompt.contains("summary"));
         assert!(prompt.contains("evidence"));
         assert!(prompt.contains("implementation"));
+        assert!(!prompt.contains("Attached code context"));
+
+        let request = request.with_code_context(
+            "Attached code context:\nItems:\n- pub async fn load_config(path: &str) -> Config\n",
+        );
+        let prompt = engine.build_research_prompt(&request).unwrap();
+        assert!(prompt.contains("Attached code context:"));
+        assert!(prompt.contains("pub async fn load_config(path: &str) -> Config"));
+
+        let request =
+            request.with_conversation_context("Earlier in this conversation:\nQ: What is Tokio?\n");
+        let prompt = engine.build_research_prompt(&request).unwrap();
+        assert!(prompt.contains("Earlier in this conversation:\nQ: What is Tokio?"));
     }
 
     #[test]
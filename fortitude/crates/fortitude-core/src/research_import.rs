@@ -0,0 +1,558 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Import of externally produced research from JSONL or Markdown-with-frontmatter documents
+// Validates each record, deduplicates by content hash and stores it, optionally indexing it for vector search
+use crate::vector::{DataConverter, VectorStorageService};
+use chrono::{DateTime, Utc};
+use fortitude_types::{
+    AudienceContext, ClassifiedRequest, DomainContext, Evidence, ResearchMetadata, ResearchResult,
+    ResearchType, Storage,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use thiserror::Error;
+use tracing::{info, warn};
+
+/// Metadata tag holding the hash used to deduplicate a result
+pub const CONTENT_HASH_TAG: &str = "content_hash";
+
+/// Metadata tag naming where an imported result came from
+pub const IMPORT_SOURCE_TAG: &str = "import_source";
+
+/// Cache key prefix of imported results without a key of their own
+const IMPORTED_KEY_PREFIX: &str = "imported_";
+
+/// Quality score of records that do not state one
+const DEFAULT_IMPORTED_QUALITY: f64 = 0.7;
+
+/// Import failures that stop the whole import
+#[derive(Error, Debug)]
+pub enum ImportError {
+    #[error("Unknown import format '{0}', expected jsonl or markdown")]
+    UnknownFormat(String),
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] fortitude_types::StorageError),
+}
+
+/// Serialization of the documents being imported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportFormat {
+    /// One JSON record per line, either an [`ImportRecord`] or a full `ResearchResult`
+    Jsonl,
+    /// A single document with YAML frontmatter; the body is the answer
+    Markdown,
+}
+
+impl ImportFormat {
+    /// Infer the format from a file extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "jsonl" | "ndjson" => Some(Self::Jsonl),
+            "md" | "markdown" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+}
+
+impl std::str::FromStr for ImportFormat {
+    type Err = ImportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "jsonl" | "ndjson" => Ok(Self::Jsonl),
+            "markdown" | "md" => Ok(Self::Markdown),
+            other => Err(ImportError::UnknownFormat(other.to_string())),
+        }
+    }
+}
+
+/// Evidence attached to an imported record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportEvidence {
+    pub source: String,
+    pub content: String,
+    #[serde(default)]
+    pub relevance: Option<f64>,
+}
+
+/// Externally produced research; also the frontmatter schema of Markdown documents
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ImportRecord {
+    pub query: String,
+    /// Research type name; defaults to learning
+    #[serde(default)]
+    pub research_type: Option<String>,
+    /// Answer text; taken from the body for Markdown documents
+    #[serde(default)]
+    pub answer: String,
+    #[serde(default)]
+    pub evidence: Vec<ImportEvidence>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub sources: Vec<String>,
+    #[serde(default)]
+    pub quality_score: Option<f64>,
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Cache key to store the record under; derived from its content hash when unset
+    #[serde(default)]
+    pub cache_key: Option<String>,
+    /// Where the record was produced, recorded as the `import_source` tag
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+impl ImportRecord {
+    /// Validate the record and convert it into a research result
+    pub fn into_result(self) -> Result<ResearchResult, String> {
+        let research_type = match self.research_type.as_deref() {
+            Some(name) => name
+                .parse::<ResearchType>()
+                .map_err(|_| format!("unknown research_type '{name}'"))?,
+            None => ResearchType::Learning,
+        };
+        let quality_score = self.quality_score.unwrap_or(DEFAULT_IMPORTED_QUALITY);
+        if !(0.0..=1.0).contains(&quality_score) {
+            return Err(format!(
+                "quality_score must be between 0.0 and 1.0, got {quality_score}"
+            ));
+        }
+        let evidence = self
+            .evidence
+            .into_iter()
+            .map(|evidence| {
+                let relevance = evidence.relevance.unwrap_or(1.0);
+                if !(0.0..=1.0).contains(&relevance) {
+                    return Err(format!(
+                        "evidence relevance must be between 0.0 and 1.0, got {relevance}"
+                    ));
+                }
+                Ok(Evidence {
+                    source: evidence.source,
+                    content: evidence.content,
+                    relevance,
+                    evidence_type: "imported".to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let domain_context = DomainContext {
+            tags: self.tags,
+            ..DomainContext::default()
+        };
+        let mut request = ClassifiedRequest::new(
+            self.query,
+            research_type,
+            AudienceContext::default(),
+            domain_context,
+            1.0,
+            Vec::new(),
+        );
+        let completed_at = self.completed_at.unwrap_or_else(Utc::now);
+        request.created_at = completed_at;
+
+        let mut tags = HashMap::new();
+        if let Some(source) = self.source.filter(|s| !s.trim().is_empty()) {
+            tags.insert(IMPORT_SOURCE_TAG.to_string(), source);
+        }
+        let metadata = ResearchMetadata {
+            completed_at,
+            processing_time_ms: 0,
+            sources_consulted: self.sources,
+            quality_score,
+            cache_key: self.cache_key.unwrap_or_default(),
+            tags,
+        };
+
+        let result = ResearchResult::new(request, self.answer, evidence, Vec::new(), metadata);
+        validate_result(&result)?;
+        Ok(result)
+    }
+}
+
+/// Check the fields every stored result needs
+fn validate_result(result: &ResearchResult) -> Result<(), String> {
+    if result.request.original_query.trim().is_empty() {
+        return Err("query must not be empty".to_string());
+    }
+    if result.immediate_answer.trim().is_empty() {
+        return Err("answer must not be empty".to_string());
+    }
+    if !(0.0..=1.0).contains(&result.metadata.quality_score) {
+        return Err(format!(
+            "quality_score must be between 0.0 and 1.0, got {}",
+            result.metadata.quality_score
+        ));
+    }
+    Ok(())
+}
+
+/// A parsed document, or why it could not be parsed
+#[derive(Debug, Clone)]
+pub struct ImportDocument {
+    /// `origin:line` for JSONL records, the origin for Markdown documents
+    pub location: String,
+    pub result: Result<ResearchResult, String>,
+}
+
+/// Parse documents in `format`; `origin` names them in the import report
+pub fn parse_documents(format: ImportFormat, content: &str, origin: &str) -> Vec<ImportDocument> {
+    match format {
+        ImportFormat::Jsonl => content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| ImportDocument {
+                location: format!("{origin}:{}", index + 1),
+                result: parse_json_record(line),
+            })
+            .collect(),
+        ImportFormat::Markdown => vec![ImportDocument {
+            location: origin.to_string(),
+            result: parse_markdown_document(content),
+        }],
+    }
+}
+
+/// Parse one JSONL line as a full research result or an import record
+fn parse_json_record(line: &str) -> Result<ResearchResult, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(line).map_err(|e| format!("invalid JSON: {e}"))?;
+    if value.get("immediate_answer").is_some() {
+        let result: ResearchResult =
+            serde_json::from_value(value).map_err(|e| format!("invalid research result: {e}"))?;
+        validate_result(&result)?;
+        return Ok(result);
+    }
+    let record: ImportRecord =
+        serde_json::from_value(value).map_err(|e| format!("invalid record: {e}"))?;
+    record.into_result()
+}
+
+/// Parse a Markdown document whose YAML frontmatter is an import record
+fn parse_markdown_document(content: &str) -> Result<ResearchResult, String> {
+    let content = content.trim_start_matches('\u{feff}');
+    let rest = content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))
+        .ok_or("missing YAML frontmatter")?;
+    let (frontmatter, body) = rest
+        .split_once("\n---\n")
+        .or_else(|| rest.split_once("\r\n---\r\n"))
+        .or_else(|| rest.strip_suffix("\n---").map(|fm| (fm, "")))
+        .ok_or("unterminated YAML frontmatter")?;
+
+    let mut record: ImportRecord =
+        serde_yaml::from_str(frontmatter).map_err(|e| format!("invalid frontmatter: {e}"))?;
+    if !body.trim().is_empty() {
+        record.answer = body.trim().to_string();
+    }
+    record.into_result()
+}
+
+/// Hash of a result's query and answer, ignoring whitespace differences
+pub fn content_hash(result: &ResearchResult) -> String {
+    let normalize = |text: &str| text.split_whitespace().collect::<Vec<_>>().join(" ");
+    format!(
+        "{:x}",
+        md5::compute(format!(
+            "{}\n{}",
+            normalize(&result.request.original_query),
+            normalize(&result.immediate_answer)
+        ))
+    )
+}
+
+/// A stored document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportedDocument {
+    pub location: String,
+    pub cache_key: String,
+    pub query: String,
+}
+
+/// A document skipped because the same content is already stored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateDocument {
+    pub location: String,
+    /// Result that already holds the content
+    pub existing_key: String,
+}
+
+/// A document that failed validation, storage or indexing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportIssue {
+    pub location: String,
+    pub reason: String,
+}
+
+/// Outcome of an import
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub imported: Vec<ImportedDocument>,
+    pub duplicates: Vec<DuplicateDocument>,
+    pub invalid: Vec<ImportIssue>,
+    /// Imported documents added to the vector index
+    pub indexed: usize,
+    /// Imported documents that are stored but could not be indexed
+    pub index_failures: Vec<ImportIssue>,
+}
+
+/// Stores imported research, skipping content that is already stored
+pub struct ResearchImporter {
+    storage: Arc<dyn Storage + Send + Sync>,
+    vector_storage: Option<Arc<dyn VectorStorageService + Send + Sync>>,
+}
+
+impl ResearchImporter {
+    pub fn new(storage: Arc<dyn Storage + Send + Sync>) -> Self {
+        Self {
+            storage,
+            vector_storage: None,
+        }
+    }
+
+    /// Also index imported documents for vector search
+    pub fn with_vector_storage(
+        mut self,
+        vector_storage: Arc<dyn VectorStorageService + Send + Sync>,
+    ) -> Self {
+        self.vector_storage = Some(vector_storage);
+        self
+    }
+
+    /// Content hashes and keys of everything already stored
+    async fn existing_content(&self) -> Result<HashMap<String, String>, ImportError> {
+        let mut hashes = HashMap::new();
+        for entry in self.storage.list_cache_entries().await? {
+            match self.storage.retrieve(&entry.key).await {
+                Ok(Some(result)) => {
+                    let hash = result
+                        .metadata
+                        .tags
+                        .get(CONTENT_HASH_TAG)
+                        .cloned()
+                        .unwrap_or_else(|| content_hash(&result));
+                    hashes.insert(hash, entry.key);
+                }
+                Ok(None) => {}
+                Err(e) => warn!(
+                    "Skipping unreadable result {} in dedup scan: {}",
+                    entry.key, e
+                ),
+            }
+        }
+        Ok(hashes)
+    }
+
+    /// Validate, deduplicate and store documents
+    ///
+    /// Invalid documents and duplicates are reported and skipped; the rest
+    /// are stored even if some fail. With `dry_run` nothing is written.
+    pub async fn import(
+        &self,
+        documents: Vec<ImportDocument>,
+        dry_run: bool,
+    ) -> Result<ImportReport, ImportError> {
+        let mut report = ImportReport {
+            dry_run,
+            ..Default::default()
+        };
+        let mut seen = self.existing_content().await?;
+        let stored_keys: HashSet<String> = seen.values().cloned().collect();
+        let converter = DataConverter::new();
+
+        for document in documents {
+            let mut result = match document.result {
+                Ok(result) => result,
+                Err(reason) => {
+                    report.invalid.push(ImportIssue {
+                        location: document.location,
+                        reason,
+                    });
+                    continue;
+                }
+            };
+
+            let hash = content_hash(&result);
+            if let Some(existing_key) = seen.get(&hash) {
+                report.duplicates.push(DuplicateDocument {
+                    location: document.location,
+                    existing_key: existing_key.clone(),
+                });
+                continue;
+            }
+            if result.metadata.cache_key.is_empty() {
+                result.metadata.cache_key = format!("{IMPORTED_KEY_PREFIX}{hash}");
+            } else if stored_keys.contains(&result.metadata.cache_key) {
+                report.invalid.push(ImportIssue {
+                    location: document.location,
+                    reason: format!(
+                        "cache key {} already holds different content",
+                        result.metadata.cache_key
+                    ),
+                });
+                continue;
+            }
+            result
+                .metadata
+                .tags
+                .insert(CONTENT_HASH_TAG.to_string(), hash.clone());
+
+            if !dry_run {
+                if let Err(e) = self.storage.store(&result).await {
+                    report.invalid.push(ImportIssue {
+                        location: document.location,
+                        reason: format!("failed to store: {e}"),
+                    });
+                    continue;
+                }
+                if let Some(vector_storage) = &self.vector_storage {
+                    let indexed = match converter.convert_research_result(&result) {
+                        Ok((content, metadata)) => vector_storage
+                            .store_document(&content, metadata)
+                            .await
+                            .map_err(|e| e.to_string()),
+                        Err(e) => Err(e.to_string()),
+                    };
+                    match indexed {
+                        Ok(_) => report.indexed += 1,
+                        Err(reason) => report.index_failures.push(ImportIssue {
+                            location: document.location.clone(),
+                            reason,
+                        }),
+                    }
+                }
+            }
+
+            seen.insert(hash, result.metadata.cache_key.clone());
+            report.imported.push(ImportedDocument {
+                location: document.location,
+                cache_key: result.metadata.cache_key.clone(),
+                query: result.request.original_query.clone(),
+            });
+        }
+
+        info!(
+            "Import {}: {} imported, {} duplicates, {} invalid",
+            if dry_run { "previewed" } else { "finished" },
+            report.imported.len(),
+            report.duplicates.len(),
+            report.invalid.len()
+        );
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::FileStorage;
+    use fortitude_types::StorageConfig;
+    use tempfile::TempDir;
+
+    async fn importer() -> (ResearchImporter, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = FileStorage::new(StorageConfig {
+            base_path: temp_dir.path().to_path_buf(),
+            ..StorageConfig::default()
+        })
+        .await
+        .unwrap();
+        (ResearchImporter::new(Arc::new(storage)), temp_dir)
+    }
+
+    #[test]
+    fn test_parse_jsonl_records() {
+        let content = r#"{"query": "Pin a future", "answer": "Use Box::pin.", "research_type": "implementation", "tags": ["async"]}
+
+{"query": "", "answer": "empty query"}
+{"query": "Typo", "answer": "x", "research_typ": "learning"}
+not json"#;
+        let documents = parse_documents(ImportFormat::Jsonl, content, "notes.jsonl");
+        assert_eq!(documents.len(), 4);
+        assert_eq!(documents[0].location, "notes.jsonl:1");
+        let result = documents[0].result.as_ref().unwrap();
+        assert_eq!(result.request.research_type, ResearchType::Implementation);
+        assert_eq!(result.request.domain_context.tags, vec!["async"]);
+        assert_eq!(documents[1].location, "notes.jsonl:3");
+        assert!(documents[1].result.as_ref().unwrap_err().contains("query"));
+        assert!(documents[2]
+            .result
+            .as_ref()
+            .unwrap_err()
+            .contains("research_typ"));
+        assert!(documents[3].result.is_err());
+    }
+
+    #[test]
+    fn test_parse_markdown_frontmatter() {
+        let content = "---\nquery: How do I share state in axum?\nresearch_type: Learning\nquality_score: 0.9\nsource: team-wiki\n---\n\nUse `State` with an `Arc`.\n";
+        let documents = parse_documents(ImportFormat::Markdown, content, "axum.md");
+        let result = documents[0].result.as_ref().unwrap();
+        assert_eq!(result.immediate_answer, "Use `State` with an `Arc`.");
+        assert_eq!(result.metadata.quality_score, 0.9);
+        assert_eq!(result.metadata.tags[IMPORT_SOURCE_TAG], "team-wiki");
+
+        let missing = parse_documents(ImportFormat::Markdown, "no frontmatter", "x.md");
+        assert!(missing[0].result.is_err());
+        assert_eq!(
+            ImportFormat::from_path(Path::new("a/b.MD")),
+            Some(ImportFormat::Markdown)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_deduplicates_by_content_hash() {
+        let (importer, _temp_dir) = importer().await;
+        let content = "{\"query\": \"Pin a future\", \"answer\": \"Use Box::pin.\"}\n{\"query\": \"Pin  a future\", \"answer\": \"Use Box::pin.\"}";
+
+        let preview = importer
+            .import(parse_documents(ImportFormat::Jsonl, content, "a"), true)
+            .await
+            .unwrap();
+        assert_eq!(preview.imported.len(), 1);
+        assert_eq!(preview.duplicates.len(), 1);
+        assert!(importer
+            .storage
+            .list_cache_entries()
+            .await
+            .unwrap()
+            .is_empty());
+
+        let report = importer
+            .import(parse_documents(ImportFormat::Jsonl, content, "a"), false)
+            .await
+            .unwrap();
+        assert_eq!(report.imported.len(), 1);
+        let key = &report.imported[0].cache_key;
+        assert!(key.starts_with(IMPORTED_KEY_PREFIX));
+        assert!(importer.storage.retrieve(key).await.unwrap().is_some());
+
+        let again = importer
+            .import(parse_documents(ImportFormat::Jsonl, content, "b"), false)
+            .await
+            .unwrap();
+        assert!(again.imported.is_empty());
+        assert_eq!(again.duplicates[0].existing_key, *key);
+    }
+}
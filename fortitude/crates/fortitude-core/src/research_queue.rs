@@ -0,0 +1,489 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Urgency-aware admission queue for research that calls a provider
+//! Bounds the number of queries researching at once to
+//! `PipelineConfig::max_concurrent`. When every slot is taken, queries wait
+//! and a freed slot goes to the most urgent waiter, oldest first within a
+//! priority. A waiter is promoted one priority level for every aging interval
+//! it has waited, so a steady stream of urgent work cannot starve low-priority
+//! queries indefinitely.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+/// Urgency of a research request
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestPriority {
+    Low,
+    #[default]
+    Medium,
+    High,
+    Critical,
+}
+
+impl RequestPriority {
+    pub const ALL: [RequestPriority; 4] = [
+        RequestPriority::Low,
+        RequestPriority::Medium,
+        RequestPriority::High,
+        RequestPriority::Critical,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RequestPriority::Low => "low",
+            RequestPriority::Medium => "medium",
+            RequestPriority::High => "high",
+            RequestPriority::Critical => "critical",
+        }
+    }
+
+    /// Priority `levels` steps more urgent, capped at `Critical`
+    fn promoted(self, levels: u32) -> Self {
+        let index = Self::ALL.iter().position(|p| *p == self).unwrap_or(0);
+        let promoted = index
+            .saturating_add(levels as usize)
+            .min(Self::ALL.len() - 1);
+        Self::ALL[promoted]
+    }
+}
+
+impl fmt::Display for RequestPriority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RequestPriority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "low" => Ok(RequestPriority::Low),
+            "medium" | "normal" => Ok(RequestPriority::Medium),
+            "high" => Ok(RequestPriority::High),
+            "critical" | "urgent" => Ok(RequestPriority::Critical),
+            other => Err(format!(
+                "Unknown priority '{other}', expected low, medium, high or critical"
+            )),
+        }
+    }
+}
+
+/// Queue activity of one priority level
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PriorityQueueSnapshot {
+    pub priority: String,
+    /// Requests waiting for a slot right now
+    pub queued: usize,
+    /// Requests that were given a slot
+    pub started: u64,
+    /// Requests that released their slot
+    pub completed: u64,
+    /// Started requests that were promoted while waiting
+    pub promoted: u64,
+    pub mean_wait_ms: f64,
+    pub max_wait_ms: f64,
+    /// Time from entering the queue to releasing the slot
+    pub mean_latency_ms: f64,
+    pub max_latency_ms: f64,
+}
+
+/// Point-in-time view of the research queue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueMetricsSnapshot {
+    pub max_concurrent: usize,
+    pub running: usize,
+    pub queued: usize,
+    /// Every priority level, least urgent first
+    pub priorities: Vec<PriorityQueueSnapshot>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct PriorityStats {
+    started: u64,
+    completed: u64,
+    promoted: u64,
+    total_wait_ms: f64,
+    max_wait_ms: f64,
+    total_latency_ms: f64,
+    max_latency_ms: f64,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    priority: RequestPriority,
+    enqueued_at: Instant,
+    seq: u64,
+    grant: oneshot::Sender<()>,
+}
+
+#[derive(Debug)]
+struct QueueState {
+    max_concurrent: usize,
+    aging: Duration,
+    running: usize,
+    waiters: Vec<Waiter>,
+    next_seq: u64,
+    stats: HashMap<RequestPriority, PriorityStats>,
+}
+
+impl QueueState {
+    fn effective_priority(&self, waiter: &Waiter, now: Instant) -> RequestPriority {
+        if self.aging.is_zero() {
+            return waiter.priority;
+        }
+        let waited = now.saturating_duration_since(waiter.enqueued_at);
+        let levels = (waited.as_millis() / self.aging.as_millis().max(1)).min(u32::MAX as u128);
+        waiter.priority.promoted(levels as u32)
+    }
+
+    fn record_start(&mut self, priority: RequestPriority, wait: Duration, promoted: bool) {
+        let wait_ms = duration_ms(wait);
+        let stats = self.stats.entry(priority).or_default();
+        stats.started += 1;
+        stats.total_wait_ms += wait_ms;
+        stats.max_wait_ms = stats.max_wait_ms.max(wait_ms);
+        if promoted {
+            stats.promoted += 1;
+        }
+    }
+
+    /// Hand free slots to the most urgent waiters, oldest first within a priority
+    fn dispatch(&mut self) {
+        let now = Instant::now();
+        while self.running < self.max_concurrent {
+            let Some(index) = self
+                .waiters
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, waiter)| {
+                    (
+                        self.effective_priority(waiter, now),
+                        std::cmp::Reverse(waiter.seq),
+                    )
+                })
+                .map(|(index, _)| index)
+            else {
+                return;
+            };
+            let effective = self.effective_priority(&self.waiters[index], now);
+            let waiter = self.waiters.swap_remove(index);
+            // The waiter's guard releases the slot if it stopped waiting before seeing the grant
+            let _ = waiter.grant.send(());
+            self.running += 1;
+            self.record_start(
+                waiter.priority,
+                now.saturating_duration_since(waiter.enqueued_at),
+                effective > waiter.priority,
+            );
+        }
+    }
+
+    fn release(&mut self, priority: RequestPriority, enqueued_at: Instant) {
+        self.running = self.running.saturating_sub(1);
+        let latency_ms = duration_ms(enqueued_at.elapsed());
+        let stats = self.stats.entry(priority).or_default();
+        stats.completed += 1;
+        stats.total_latency_ms += latency_ms;
+        stats.max_latency_ms = stats.max_latency_ms.max(latency_ms);
+        self.dispatch();
+    }
+}
+
+/// Admission queue bounding concurrent research, cheap to clone
+#[derive(Debug, Clone)]
+pub struct ResearchQueue {
+    state: Arc<Mutex<QueueState>>,
+}
+
+impl ResearchQueue {
+    /// Queue admitting `max_concurrent` requests at once (at least one)
+    ///
+    /// Waiters are promoted one priority level per `aging` interval; a zero
+    /// interval disables promotion.
+    pub fn new(max_concurrent: usize, aging: Duration) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(QueueState {
+                max_concurrent: max_concurrent.max(1),
+                aging,
+                running: 0,
+                waiters: Vec::new(),
+                next_seq: 0,
+                stats: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Wait for a research slot
+    ///
+    /// The slot is held until the returned permit is dropped. Dropping this
+    /// future while it waits leaves the queue without taking a slot.
+    pub async fn acquire(&self, priority: RequestPriority) -> QueuePermit {
+        let enqueued_at = Instant::now();
+        let (grant, granted) = oneshot::channel();
+        let seq = {
+            let mut state = self.state.lock().unwrap();
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            state.waiters.push(Waiter {
+                priority,
+                enqueued_at,
+                seq,
+                grant,
+            });
+            state.dispatch();
+            seq
+        };
+
+        let mut guard = WaitGuard {
+            state: self.state.clone(),
+            priority,
+            enqueued_at,
+            seq,
+            granted: false,
+        };
+        // The sender is only dropped after a successful send, or with the queue
+        let _ = granted.await;
+        guard.granted = true;
+
+        QueuePermit {
+            state: self.state.clone(),
+            priority,
+            enqueued_at,
+        }
+    }
+
+    /// Current queue activity per priority
+    pub fn snapshot(&self) -> QueueMetricsSnapshot {
+        let state = self.state.lock().unwrap();
+        let priorities = RequestPriority::ALL
+            .iter()
+            .map(|priority| {
+                let stats = state.stats.get(priority).cloned().unwrap_or_default();
+                PriorityQueueSnapshot {
+                    priority: priority.to_string(),
+                    queued: state
+                        .waiters
+                        .iter()
+                        .filter(|waiter| waiter.priority == *priority)
+                        .count(),
+                    started: stats.started,
+                    completed: stats.completed,
+                    promoted: stats.promoted,
+                    mean_wait_ms: mean(stats.total_wait_ms, stats.started),
+                    max_wait_ms: stats.max_wait_ms,
+                    mean_latency_ms: mean(stats.total_latency_ms, stats.completed),
+                    max_latency_ms: stats.max_latency_ms,
+                }
+            })
+            .collect();
+
+        QueueMetricsSnapshot {
+            max_concurrent: state.max_concurrent,
+            running: state.running,
+            queued: state.waiters.len(),
+            priorities,
+        }
+    }
+}
+
+/// Leaves the queue when an `acquire` future is dropped before it is granted
+struct WaitGuard {
+    state: Arc<Mutex<QueueState>>,
+    priority: RequestPriority,
+    enqueued_at: Instant,
+    seq: u64,
+    granted: bool,
+}
+
+impl Drop for WaitGuard {
+    fn drop(&mut self) {
+        if self.granted {
+            return;
+        }
+        let mut state = self.state.lock().unwrap();
+        match state.waiters.iter().position(|w| w.seq == self.seq) {
+            Some(index) => {
+                state.waiters.swap_remove(index);
+            }
+            // Granted after the waiter stopped listening; pass the slot on
+            None => state.release(self.priority, self.enqueued_at),
+        }
+    }
+}
+
+/// Research slot held until dropped
+#[derive(Debug)]
+pub struct QueuePermit {
+    state: Arc<Mutex<QueueState>>,
+    priority: RequestPriority,
+    enqueued_at: Instant,
+}
+
+impl QueuePermit {
+    pub fn priority(&self) -> RequestPriority {
+        self.priority
+    }
+}
+
+impl Drop for QueuePermit {
+    fn drop(&mut self) {
+        self.state
+            .lock()
+            .unwrap()
+            .release(self.priority, self.enqueued_at);
+    }
+}
+
+fn mean(total: f64, count: u64) -> f64 {
+    if count == 0 {
+        0.0
+    } else {
+        total / count as f64
+    }
+}
+
+fn duration_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Spawn a request that records its priority in `order` once it gets a slot
+    fn spawn_waiter(
+        queue: &ResearchQueue,
+        priority: RequestPriority,
+        order: Arc<Mutex<Vec<RequestPriority>>>,
+    ) -> tokio::task::JoinHandle<()> {
+        let queue = queue.clone();
+        tokio::spawn(async move {
+            let _permit = queue.acquire(priority).await;
+            order.lock().unwrap().push(priority);
+        })
+    }
+
+    async fn wait_for_queued(queue: &ResearchQueue, queued: usize) {
+        while queue.snapshot().queued < queued {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[test]
+    fn test_priority_parsing() {
+        assert_eq!("HIGH".parse::<RequestPriority>(), Ok(RequestPriority::High));
+        assert_eq!(
+            "urgent".parse::<RequestPriority>(),
+            Ok(RequestPriority::Critical)
+        );
+        assert_eq!(
+            "normal".parse::<RequestPriority>(),
+            Ok(RequestPriority::Medium)
+        );
+        assert!("asap".parse::<RequestPriority>().is_err());
+        assert_eq!(RequestPriority::Low.promoted(10), RequestPriority::Critical);
+    }
+
+    #[tokio::test]
+    async fn test_urgent_requests_preempt_queued_low_priority() {
+        let queue = ResearchQueue::new(1, Duration::ZERO);
+        let held = queue.acquire(RequestPriority::Low).await;
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let low = spawn_waiter(&queue, RequestPriority::Low, order.clone());
+        wait_for_queued(&queue, 1).await;
+        let medium = spawn_waiter(&queue, RequestPriority::Medium, order.clone());
+        wait_for_queued(&queue, 2).await;
+        let critical = spawn_waiter(&queue, RequestPriority::Critical, order.clone());
+        wait_for_queued(&queue, 3).await;
+
+        drop(held);
+        for handle in [low, medium, critical] {
+            handle.await.unwrap();
+        }
+        assert_eq!(
+            *order.lock().unwrap(),
+            vec![
+                RequestPriority::Critical,
+                RequestPriority::Medium,
+                RequestPriority::Low
+            ]
+        );
+
+        let snapshot = queue.snapshot();
+        assert_eq!((snapshot.running, snapshot.queued), (0, 0));
+        assert_eq!(snapshot.priorities.len(), 4);
+        let low = &snapshot.priorities[0];
+        assert_eq!(low.priority, "low");
+        assert_eq!((low.started, low.completed), (2, 2));
+        assert!(low.max_wait_ms >= snapshot.priorities[3].max_wait_ms);
+        assert!(low.mean_latency_ms > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_aged_low_priority_request_is_promoted() {
+        let queue = ResearchQueue::new(1, Duration::from_millis(20));
+        let held = queue.acquire(RequestPriority::Medium).await;
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let low = spawn_waiter(&queue, RequestPriority::Low, order.clone());
+        wait_for_queued(&queue, 1).await;
+        // Two aging intervals lift the low request to high, ahead of a fresh high
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let high = spawn_waiter(&queue, RequestPriority::High, order.clone());
+        wait_for_queued(&queue, 2).await;
+
+        drop(held);
+        low.await.unwrap();
+        high.await.unwrap();
+        assert_eq!(
+            *order.lock().unwrap(),
+            vec![RequestPriority::Low, RequestPriority::High]
+        );
+        assert_eq!(queue.snapshot().priorities[0].promoted, 1);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_waiter_gives_up_its_place() {
+        let queue = ResearchQueue::new(1, Duration::ZERO);
+        let held = queue.acquire(RequestPriority::Medium).await;
+
+        let cancelled = {
+            let queue = queue.clone();
+            tokio::spawn(async move {
+                let _permit = queue.acquire(RequestPriority::High).await;
+            })
+        };
+        wait_for_queued(&queue, 1).await;
+        cancelled.abort();
+        let _ = cancelled.await;
+        assert_eq!(queue.snapshot().queued, 0);
+
+        drop(held);
+        let permit = queue.acquire(RequestPriority::Low).await;
+        assert_eq!(permit.priority(), RequestPriority::Low);
+        assert_eq!(queue.snapshot().running, 1);
+    }
+}
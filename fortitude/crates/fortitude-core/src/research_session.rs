@@ -0,0 +1,212 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Research sessions grouping successive questions into one conversation
+//! A session names a conversation so clients can continue it without tracking
+//! result IDs themselves. Each research query run in a session follows up on
+//! the session's latest turn, so earlier questions and answers are summarized
+//! into its prompt, and is then recorded as the session's new latest turn.
+
+use crate::atomic_file::write_json_atomic;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Default file sessions are saved to
+pub const DEFAULT_RESEARCH_SESSIONS_PATH: &str = "./sessions/sessions.json";
+
+/// Named conversation of research results
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResearchSession {
+    pub id: String,
+    pub title: Option<String>,
+    /// User the session belongs to; `None` when created without authentication
+    pub owner: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Cache keys of the session's results, oldest first
+    pub turn_ids: Vec<String>,
+}
+
+impl ResearchSession {
+    /// Result the next query in the session follows up on
+    pub fn last_turn_id(&self) -> Option<&str> {
+        self.turn_ids.last().map(String::as_str)
+    }
+}
+
+/// Shared research sessions, optionally saved to a JSON file
+#[derive(Debug, Clone, Default)]
+pub struct SessionStore {
+    sessions: Arc<RwLock<HashMap<String, ResearchSession>>>,
+    path: Option<PathBuf>,
+}
+
+impl SessionStore {
+    /// Store kept in memory only
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Store saved to `path`, loading sessions already saved there
+    ///
+    /// A missing file starts an empty store; an unreadable one is logged and
+    /// replaced on the next save.
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let sessions = match std::fs::read(&path) {
+            Ok(content) => {
+                match serde_json::from_slice::<HashMap<String, ResearchSession>>(&content) {
+                    Ok(sessions) => {
+                        info!(
+                            "Loaded {} research sessions from {}",
+                            sessions.len(),
+                            path.display()
+                        );
+                        sessions
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Failed to load research sessions from {}: {}",
+                            path.display(),
+                            e
+                        );
+                        HashMap::new()
+                    }
+                }
+            }
+            Err(_) => HashMap::new(),
+        };
+        Self {
+            sessions: Arc::new(RwLock::new(sessions)),
+            path: Some(path),
+        }
+    }
+
+    pub fn create(&self, title: Option<String>, owner: Option<String>) -> ResearchSession {
+        let now = Utc::now();
+        let session = ResearchSession {
+            id: Uuid::new_v4().to_string(),
+            title: title
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty()),
+            owner,
+            created_at: now,
+            updated_at: now,
+            turn_ids: Vec::new(),
+        };
+        self.sessions
+            .write()
+            .unwrap()
+            .insert(session.id.clone(), session.clone());
+        self.save();
+        session
+    }
+
+    pub fn get(&self, id: &str) -> Option<ResearchSession> {
+        self.sessions.read().unwrap().get(id).cloned()
+    }
+
+    /// Sessions of `owner`, or all sessions for `None`, most recently active first
+    pub fn list(&self, owner: Option<&str>) -> Vec<ResearchSession> {
+        let mut sessions: Vec<ResearchSession> = self
+            .sessions
+            .read()
+            .unwrap()
+            .values()
+            .filter(|session| owner.is_none() || session.owner.as_deref() == owner)
+            .cloned()
+            .collect();
+        sessions.sort_by_key(|session| std::cmp::Reverse(session.updated_at));
+        sessions
+    }
+
+    /// Append a result to a session; `None` if the session does not exist
+    pub fn record_turn(&self, id: &str, turn_id: &str) -> Option<ResearchSession> {
+        let session = {
+            let mut sessions = self.sessions.write().unwrap();
+            let session = sessions.get_mut(id)?;
+            session.turn_ids.push(turn_id.to_string());
+            session.updated_at = Utc::now();
+            session.clone()
+        };
+        self.save();
+        Some(session)
+    }
+
+    /// Delete a session; its results stay stored
+    pub fn remove(&self, id: &str) -> bool {
+        let removed = self.sessions.write().unwrap().remove(id).is_some();
+        if removed {
+            self.save();
+        }
+        removed
+    }
+
+    /// Write all sessions to the store file, if any
+    ///
+    /// Failures are logged; the sessions stay in memory.
+    fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let snapshot = self.sessions.read().unwrap().clone();
+        if let Err(e) = write_json_atomic(path, &snapshot) {
+            warn!(
+                "Failed to save research sessions to {}: {}",
+                path.display(),
+                e
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sessions_record_turns_and_persist() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sessions.json");
+        let store = SessionStore::open(&path);
+
+        let session = store.create(
+            Some("  Async runtimes ".to_string()),
+            Some("alice".to_string()),
+        );
+        assert_eq!(session.title.as_deref(), Some("Async runtimes"));
+        assert_eq!(session.last_turn_id(), None);
+        store.create(None, Some("bob".to_string()));
+
+        store.record_turn(&session.id, "first").unwrap();
+        let updated = store.record_turn(&session.id, "second").unwrap();
+        assert_eq!(updated.turn_ids, ["first", "second"]);
+        assert_eq!(updated.last_turn_id(), Some("second"));
+        assert!(store.record_turn("missing", "x").is_none());
+
+        let reopened = SessionStore::open(&path);
+        assert_eq!(reopened.get(&session.id), Some(updated));
+        assert_eq!(reopened.list(Some("alice")).len(), 1);
+        assert_eq!(reopened.list(None).len(), 2);
+
+        assert!(reopened.remove(&session.id));
+        assert!(!reopened.remove(&session.id));
+        assert!(SessionStore::open(&path).get(&session.id).is_none());
+    }
+}
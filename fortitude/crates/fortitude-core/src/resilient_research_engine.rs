@@ -30,6 +30,7 @@ use crate::error_handling::{
     CircuitBreaker, CircuitBreakerConfig, PipelineError, RetryConfig, RetryExecutor,
 };
 use crate::research_engine::{ResearchEngine, ResearchEngineError};
+use crate::tools::ToolRegistry;
 use crate::vector::VectorDocument;
 use fortitude_types::{ClassifiedRequest, ResearchResult};
 
@@ -201,6 +202,25 @@ impl<T: ResearchEngine> ResearchEngine for ResilientResearchEngine<T> {
         .await
     }
 
+    /// Generate research with tools using resilience patterns
+    async fn generate_research_with_tools(
+        &self,
+        request: &ClassifiedRequest,
+        tools: &ToolRegistry,
+    ) -> Result<ResearchResult, ResearchEngineError> {
+        let request_clone = request.clone();
+        let tools = tools.clone();
+        let inner = self.inner.clone();
+
+        self.execute_with_resilience("generate_research_with_tools", move || {
+            let inner = inner.clone();
+            let request = request_clone.clone();
+            let tools = tools.clone();
+            async move { inner.generate_research_with_tools(&request, &tools).await }
+        })
+        .await
+    }
+
     /// Generate research with context discovery using resilience patterns
     async fn generate_research_with_context(
         &self,
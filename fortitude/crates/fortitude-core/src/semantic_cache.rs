@@ -0,0 +1,214 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Semantic cache matching new queries against embeddings of earlier ones
+//! Every freshly researched query is indexed in vector storage as a small
+//! query document that points at the cache key of its result. Before a query
+//! is researched, the pipeline looks for an earlier query of the same
+//! research type whose embedding is at least `similarity_threshold` similar
+//! and serves that query's cached result instead, tagged as a semantic hit.
+
+use crate::vector::{DocumentMetadata, SimilaritySearchResult, VectorDocument};
+use fortitude_types::{ResearchResult, ResearchType};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Metadata tag holding the earlier query a semantic cache hit matched
+pub const SEMANTIC_CACHE_QUERY_TAG: &str = "semantic_cache.matched_query";
+/// Metadata tag holding the similarity of the semantic cache hit (0.0-1.0)
+pub const SEMANTIC_CACHE_SIMILARITY_TAG: &str = "semantic_cache.similarity";
+/// Content type of the query documents indexed for the semantic cache
+pub const QUERY_CONTENT_TYPE: &str = "research_query";
+
+/// Custom field of a query document holding the cache key of its result
+const CACHE_KEY_FIELD: &str = "cache_key";
+
+/// Settings for semantic cache lookups
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SemanticCacheConfig {
+    /// Look up and index queries when vector storage is available
+    pub enabled: bool,
+    /// Minimum query similarity served from the cache (0.0-1.0)
+    pub similarity_threshold: f64,
+    /// Nearest query documents inspected per lookup
+    pub max_candidates: usize,
+}
+
+impl Default for SemanticCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            similarity_threshold: 0.95,
+            max_candidates: 5,
+        }
+    }
+}
+
+impl SemanticCacheConfig {
+    /// Check that the threshold is a similarity and candidates are looked up
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&self.similarity_threshold) {
+            return Err(format!(
+                "similarity_threshold must be between 0.0 and 1.0, got {}",
+                self.similarity_threshold
+            ));
+        }
+        if self.max_candidates == 0 {
+            return Err("max_candidates must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Earlier query whose cached result can answer a new one
+#[derive(Debug, Clone, PartialEq)]
+pub struct SemanticMatch {
+    /// Cache key of the earlier query's result
+    pub cache_key: String,
+    /// The earlier query
+    pub query: String,
+    /// Similarity between the queries (0.0-1.0)
+    pub similarity: f64,
+}
+
+impl SemanticMatch {
+    /// Tag a cached result as served for a semantically similar query
+    pub fn annotate(&self, result: &mut ResearchResult) {
+        result
+            .metadata
+            .tags
+            .insert(SEMANTIC_CACHE_QUERY_TAG.to_string(), self.query.clone());
+        result.metadata.tags.insert(
+            SEMANTIC_CACHE_SIMILARITY_TAG.to_string(),
+            format!("{:.3}", self.similarity),
+        );
+    }
+}
+
+/// Metadata for the query document indexed for a freshly researched result
+pub fn query_document_metadata(result: &ResearchResult) -> DocumentMetadata {
+    let mut custom_fields = HashMap::new();
+    custom_fields.insert(
+        CACHE_KEY_FIELD.to_string(),
+        serde_json::Value::String(result.metadata.cache_key.clone()),
+    );
+
+    DocumentMetadata {
+        research_type: Some(result.request.research_type.clone()),
+        content_type: QUERY_CONTENT_TYPE.to_string(),
+        quality_score: Some(result.metadata.quality_score),
+        source: Some("fortitude_semantic_cache".to_string()),
+        tags: Vec::new(),
+        custom_fields,
+    }
+}
+
+/// Cache key of the result a query document points at; `None` for other documents
+pub(crate) fn query_document_cache_key(document: &VectorDocument) -> Option<&str> {
+    if document.metadata.content_type != QUERY_CONTENT_TYPE {
+        return None;
+    }
+    document
+        .metadata
+        .custom_fields
+        .get(CACHE_KEY_FIELD)?
+        .as_str()
+        .filter(|key| !key.is_empty())
+}
+
+/// Best query document among `candidates` that can answer a query of `research_type`
+///
+/// Result documents, other research types and matches below the threshold are skipped.
+pub fn best_match(
+    candidates: &[SimilaritySearchResult],
+    research_type: &ResearchType,
+    config: &SemanticCacheConfig,
+) -> Option<SemanticMatch> {
+    candidates
+        .iter()
+        .filter(|candidate| {
+            let metadata = &candidate.document.metadata;
+            candidate.score >= config.similarity_threshold
+                && metadata.content_type == QUERY_CONTENT_TYPE
+                && metadata.research_type.as_ref() == Some(research_type)
+        })
+        .filter_map(|candidate| {
+            let cache_key = query_document_cache_key(&candidate.document)?;
+            Some(SemanticMatch {
+                cache_key: cache_key.to_string(),
+                query: candidate.document.content.clone(),
+                similarity: candidate.score,
+            })
+        })
+        .max_by(|a, b| a.similarity.total_cmp(&b.similarity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(
+        query: &str,
+        score: f64,
+        research_type: ResearchType,
+        content_type: &str,
+    ) -> SimilaritySearchResult {
+        let mut custom_fields = HashMap::new();
+        custom_fields.insert(
+            CACHE_KEY_FIELD.to_string(),
+            serde_json::Value::String(format!("key-{query}")),
+        );
+        SimilaritySearchResult {
+            document: VectorDocument {
+                id: format!("doc-{query}"),
+                content: query.to_string(),
+                embedding: Vec::new(),
+                metadata: DocumentMetadata {
+                    research_type: Some(research_type),
+                    content_type: content_type.to_string(),
+                    quality_score: None,
+                    source: None,
+                    tags: Vec::new(),
+                    custom_fields,
+                },
+                stored_at: chrono::Utc::now(),
+            },
+            score,
+        }
+    }
+
+    #[test]
+    fn test_best_match_requires_threshold_type_and_query_document() {
+        let config = SemanticCacheConfig::default();
+        let candidates = vec![
+            candidate("too far", 0.90, ResearchType::Learning, QUERY_CONTENT_TYPE),
+            candidate("answer", 0.99, ResearchType::Learning, "research_result"),
+            candidate(
+                "other type",
+                0.99,
+                ResearchType::Decision,
+                QUERY_CONTENT_TYPE,
+            ),
+            candidate("close", 0.96, ResearchType::Learning, QUERY_CONTENT_TYPE),
+            candidate("closest", 0.98, ResearchType::Learning, QUERY_CONTENT_TYPE),
+        ];
+
+        let found = best_match(&candidates, &ResearchType::Learning, &config).unwrap();
+        assert_eq!(found.query, "closest");
+        assert_eq!(found.cache_key, "key-closest");
+
+        assert!(best_match(&candidates, &ResearchType::Validation, &config).is_none());
+    }
+}
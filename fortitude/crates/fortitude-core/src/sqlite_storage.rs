@@ -0,0 +1,764 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: SQLite storage backend keeping results and their search index in one file
+//! [`SqliteStorage`] implements [`Storage`] like
+//! [`FileStorage`](crate::storage::FileStorage), but keeps every result, its
+//! cache index columns and the keyword search index in a single SQLite
+//! database. Keyword search runs on an FTS5 table over the query, answer,
+//! keywords and tags; query-language filters and exclusions are then applied
+//! to the FTS5 matches, as `FileStorage` applies them to its index.
+
+use crate::query_language::SearchExpression;
+use crate::storage::{entry_metadata, index_entry_for};
+use chrono::{DateTime, TimeZone, Utc};
+use fortitude_types::{
+    CacheAnalytics, CacheEntry, CacheOperation, CachePerformanceMonitor, CachePerformanceStatus,
+    CacheStats, CacheWarmingStats, HitRateTrend, IndexEntry, ResearchResult, ResearchType,
+    SearchQuery, SearchResult, Storage, StorageConfig, StorageError,
+};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, info, warn};
+
+/// Database file name used under the storage base path
+pub const DEFAULT_SQLITE_FILE: &str = "fortitude.db";
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS results (
+    cache_key TEXT PRIMARY KEY,
+    research_type TEXT NOT NULL,
+    original_query TEXT NOT NULL,
+    result_json TEXT NOT NULL,
+    content_hash TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    last_accessed INTEGER NOT NULL,
+    expires_at INTEGER NOT NULL,
+    metadata TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS results_expires_at ON results(expires_at);
+CREATE VIRTUAL TABLE IF NOT EXISTS results_fts USING fts5(
+    cache_key UNINDEXED, original_query, content, keywords, tags
+);
+";
+
+/// Storage backed by a single SQLite database
+pub struct SqliteStorage {
+    path: PathBuf,
+    config: StorageConfig,
+    connection: Arc<Mutex<Connection>>,
+    analytics: Mutex<CacheAnalytics>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl SqliteStorage {
+    /// Open or create the database at `path`
+    pub async fn open(path: impl AsRef<Path>, config: StorageConfig) -> Result<Self, StorageError> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|_| StorageError::DirectoryNotFound(parent.to_path_buf()))?;
+        }
+
+        let db_path = path.clone();
+        let connection = tokio::task::spawn_blocking(move || {
+            let connection = Connection::open(&db_path)?;
+            connection.pragma_update(None, "journal_mode", "WAL")?;
+            connection.execute_batch(SCHEMA)?;
+            Ok::<_, rusqlite::Error>(connection)
+        })
+        .await
+        .map_err(task_error)?
+        .map_err(sql_error)?;
+
+        info!("Opened SQLite storage: {}", path.display());
+        Ok(Self {
+            path,
+            config,
+            connection: Arc::new(Mutex::new(connection)),
+            analytics: Mutex::new(CacheAnalytics::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        })
+    }
+
+    /// Path of the database file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Run `f` against the connection on the blocking thread pool
+    async fn with_connection<T, F>(&self, f: F) -> Result<T, StorageError>
+    where
+        F: FnOnce(&mut Connection) -> Result<T, StorageError> + Send + 'static,
+        T: Send + 'static,
+    {
+        let connection = self.connection.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut connection = connection
+                .lock()
+                .map_err(|_| StorageError::Cache("SQLite connection lock poisoned".to_string()))?;
+            f(&mut connection)
+        })
+        .await
+        .map_err(task_error)?
+    }
+
+    fn hit_rate(&self) -> (u64, u64, f64) {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let rate = if hits + misses > 0 {
+            hits as f64 / (hits + misses) as f64
+        } else {
+            0.0
+        };
+        (hits, misses, rate)
+    }
+
+    async fn cache_entries(&self) -> Result<Vec<CacheEntry>, StorageError> {
+        let path = self.path.clone();
+        self.with_connection(move |connection| {
+            let mut statement = connection
+                .prepare(
+                    "SELECT cache_key, research_type, original_query, content_hash, size_bytes,
+                            created_at, last_accessed, expires_at, metadata
+                     FROM results ORDER BY cache_key",
+                )
+                .map_err(sql_error)?;
+            let rows = statement
+                .query_map([], |row| cache_entry_from_row(row, &path))
+                .map_err(sql_error)?;
+            rows.collect::<Result<Vec<_>, _>>().map_err(sql_error)
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl Storage for SqliteStorage {
+    async fn store(&self, result: &ResearchResult) -> Result<String, StorageError> {
+        let cache_key = if !result.metadata.cache_key.is_empty() {
+            result.metadata.cache_key.clone()
+        } else {
+            fallback_cache_key(result)
+        };
+        debug!("Storing research result in SQLite: {}", cache_key);
+
+        let json = serde_json::to_string(result)
+            .map_err(|e| StorageError::Serialization(e.to_string()))?;
+        let content_hash = format!("{:x}", md5::compute(json.as_bytes()));
+        let index_entry = index_entry_for(result, &cache_key);
+        let metadata = serde_json::to_string(&entry_metadata(&result.request))
+            .map_err(|e| StorageError::Serialization(e.to_string()))?;
+        let now = Utc::now().timestamp_millis();
+        let expires_at = now + (self.config.cache_expiration_seconds as i64) * 1000;
+        let research_type = result.request.research_type.to_string();
+        let key = cache_key.clone();
+
+        self.with_connection(move |connection| {
+            let transaction = connection.transaction().map_err(sql_error)?;
+            transaction
+                .execute(
+                    "INSERT OR REPLACE INTO results
+                     (cache_key, research_type, original_query, result_json, content_hash,
+                      size_bytes, created_at, last_accessed, expires_at, metadata)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7, ?8, ?9)",
+                    params![
+                        key,
+                        research_type,
+                        index_entry.original_query,
+                        json,
+                        content_hash,
+                        json.len() as i64,
+                        now,
+                        expires_at,
+                        metadata,
+                    ],
+                )
+                .map_err(sql_error)?;
+            transaction
+                .execute("DELETE FROM results_fts WHERE cache_key = ?1", params![key])
+                .map_err(sql_error)?;
+            transaction
+                .execute(
+                    "INSERT INTO results_fts (cache_key, original_query, content, keywords, tags)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![
+                        key,
+                        index_entry.original_query,
+                        index_entry.content,
+                        index_entry.keywords.join(" "),
+                        index_entry.tags.join(" "),
+                    ],
+                )
+                .map_err(sql_error)?;
+            transaction.commit().map_err(sql_error)
+        })
+        .await?;
+
+        info!("Stored research result in SQLite: {}", cache_key);
+        Ok(cache_key)
+    }
+
+    async fn retrieve(&self, cache_key: &str) -> Result<Option<ResearchResult>, StorageError> {
+        let key = cache_key.to_string();
+        let now = Utc::now().timestamp_millis();
+        let row = self
+            .with_connection(move |connection| {
+                let row = connection
+                    .query_row(
+                        "SELECT result_json, expires_at FROM results WHERE cache_key = ?1",
+                        params![key],
+                        |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)),
+                    )
+                    .optional()
+                    .map_err(sql_error)?;
+                if matches!(row, Some((_, expires_at)) if expires_at > now) {
+                    connection
+                        .execute(
+                            "UPDATE results SET last_accessed = ?2 WHERE cache_key = ?1",
+                            params![key, now],
+                        )
+                        .map_err(sql_error)?;
+                }
+                Ok(row)
+            })
+            .await?;
+
+        match row {
+            Some((_, expires_at)) if expires_at <= now => {
+                warn!("Cache entry expired: {}", cache_key);
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                Ok(None)
+            }
+            Some((json, _)) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                let result = serde_json::from_str(&json)
+                    .map_err(|e| StorageError::Serialization(e.to_string()))?;
+                Ok(Some(result))
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                Ok(None)
+            }
+        }
+    }
+
+    async fn delete(&self, cache_key: &str) -> Result<(), StorageError> {
+        let key = cache_key.to_string();
+        self.with_connection(move |connection| {
+            let transaction = connection.transaction().map_err(sql_error)?;
+            transaction
+                .execute("DELETE FROM results WHERE cache_key = ?1", params![key])
+                .map_err(sql_error)?;
+            transaction
+                .execute("DELETE FROM results_fts WHERE cache_key = ?1", params![key])
+                .map_err(sql_error)?;
+            transaction.commit().map_err(sql_error)
+        })
+        .await?;
+        info!("Deleted research result from SQLite: {}", cache_key);
+        Ok(())
+    }
+
+    async fn list_cache_entries(&self) -> Result<Vec<CacheEntry>, StorageError> {
+        self.cache_entries().await
+    }
+
+    async fn get_cache_stats(&self) -> Result<CacheStats, StorageError> {
+        let entries = self.cache_entries().await?;
+        let (hits, misses, hit_rate) = self.hit_rate();
+        let mut stats = CacheStats {
+            total_entries: entries.len(),
+            hits,
+            misses,
+            hit_rate,
+            ..CacheStats::default()
+        };
+
+        for entry in &entries {
+            stats.total_size_bytes += entry.size_bytes;
+            if entry.is_expired() {
+                stats.expired_entries += 1;
+            }
+            let type_stats = stats
+                .by_research_type
+                .entry(entry.research_type.clone())
+                .or_default();
+            type_stats.entries += 1;
+            type_stats.size_bytes += entry.size_bytes;
+        }
+        if !entries.is_empty() {
+            let total_age: u64 = entries.iter().map(|e| e.age_seconds()).sum();
+            stats.average_age_seconds = total_age as f64 / entries.len() as f64;
+        }
+        stats.newest_entry = entries.into_iter().max_by_key(|e| e.created_at);
+        stats.analytics = self.analytics.lock().unwrap().clone();
+        Ok(stats)
+    }
+
+    async fn cleanup_expired(&self) -> Result<u64, StorageError> {
+        let now = Utc::now().timestamp_millis();
+        let deleted = self
+            .with_connection(move |connection| {
+                let transaction = connection.transaction().map_err(sql_error)?;
+                transaction
+                    .execute(
+                        "DELETE FROM results_fts WHERE cache_key IN
+                         (SELECT cache_key FROM results WHERE expires_at <= ?1)",
+                        params![now],
+                    )
+                    .map_err(sql_error)?;
+                let deleted = transaction
+                    .execute("DELETE FROM results WHERE expires_at <= ?1", params![now])
+                    .map_err(sql_error)?;
+                transaction.commit().map_err(sql_error)?;
+                Ok(deleted as u64)
+            })
+            .await?;
+        info!("Cleaned up {} expired SQLite entries", deleted);
+        Ok(deleted)
+    }
+
+    async fn search(&self, query: &SearchQuery) -> Result<Vec<SearchResult>, StorageError> {
+        let expression = SearchExpression::parse(&query.query)
+            .map_err(|e| StorageError::InvalidQuery(e.to_string()))?;
+        if expression.is_empty() {
+            return Ok(Vec::new());
+        }
+        let terms = expression.positive_terms();
+        let fts_query = terms
+            .iter()
+            .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+
+        // (cache key, result JSON, bm25 rank, snippet); lower ranks match better
+        let rows = self
+            .with_connection(move |connection| {
+                let mut statement;
+                let rows = if fts_query.is_empty() {
+                    statement = connection
+                        .prepare("SELECT cache_key, result_json, 0.0, '' FROM results")
+                        .map_err(sql_error)?;
+                    statement.query_map([], read_search_row)
+                } else {
+                    statement = connection
+                        .prepare(
+                            "SELECT r.cache_key, r.result_json, bm25(results_fts),
+                                    snippet(results_fts, 2, '', '', '...', 24)
+                             FROM results_fts JOIN results r ON r.cache_key = results_fts.cache_key
+                             WHERE results_fts MATCH ?1
+                             ORDER BY bm25(results_fts)",
+                        )
+                        .map_err(sql_error)?;
+                    statement.query_map(params![fts_query], read_search_row)
+                }
+                .map_err(sql_error)?;
+                rows.collect::<Result<Vec<_>, _>>().map_err(sql_error)
+            })
+            .await?;
+
+        let mut results = Vec::new();
+        for (cache_key, json, rank, snippet) in rows {
+            let Ok(result) = serde_json::from_str::<ResearchResult>(&json) else {
+                warn!("Skipping unreadable SQLite entry: {}", cache_key);
+                continue;
+            };
+            let entry = index_entry_for(&result, &cache_key);
+            if query
+                .research_type
+                .as_ref()
+                .is_some_and(|t| !entry.has_research_type(t))
+                || query.min_quality.is_some_and(|q| entry.quality_score < q)
+                || (!query.tags.is_empty() && !query.tags.iter().any(|t| entry.tags.contains(t)))
+                || !expression.matches(&entry)
+            {
+                continue;
+            }
+
+            let content = entry.content.to_lowercase();
+            let matched_keywords = terms
+                .iter()
+                .filter(|term| content.contains(term.as_str()))
+                .cloned()
+                .collect();
+            // Queries made only of filters and exclusions match without scoring terms
+            let relevance_score = if terms.is_empty() {
+                1.0
+            } else {
+                -rank / (1.0 - rank)
+            };
+            let snippet = if snippet.is_empty() {
+                entry.content.chars().take(150).collect()
+            } else {
+                snippet
+            };
+            results.push(SearchResult::new(
+                entry,
+                relevance_score,
+                matched_keywords,
+                snippet,
+            ));
+        }
+
+        let offset = query.offset.unwrap_or(0);
+        let limit = query.limit.unwrap_or(10);
+        Ok(results.into_iter().skip(offset).take(limit).collect())
+    }
+
+    async fn update_index(&self) -> Result<(), StorageError> {
+        let rows = self
+            .with_connection(|connection| {
+                let mut statement = connection
+                    .prepare("SELECT cache_key, result_json FROM results")
+                    .map_err(sql_error)?;
+                let rows = statement
+                    .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+                    .map_err(sql_error)?;
+                rows.collect::<Result<Vec<(String, String)>, _>>()
+                    .map_err(sql_error)
+            })
+            .await?;
+        let entries: Vec<IndexEntry> = rows
+            .into_iter()
+            .filter_map(|(cache_key, json)| {
+                serde_json::from_str::<ResearchResult>(&json)
+                    .ok()
+                    .map(|result| index_entry_for(&result, &cache_key))
+            })
+            .collect();
+        let count = entries.len();
+
+        self.with_connection(move |connection| {
+            let transaction = connection.transaction().map_err(sql_error)?;
+            transaction
+                .execute("DELETE FROM results_fts", [])
+                .map_err(sql_error)?;
+            for entry in entries {
+                transaction
+                    .execute(
+                        "INSERT INTO results_fts (cache_key, original_query, content, keywords, tags)
+                         VALUES (?1, ?2, ?3, ?4, ?5)",
+                        params![
+                            entry.cache_key,
+                            entry.original_query,
+                            entry.content,
+                            entry.keywords.join(" "),
+                            entry.tags.join(" "),
+                        ],
+                    )
+                    .map_err(sql_error)?;
+            }
+            transaction.commit().map_err(sql_error)
+        })
+        .await?;
+
+        info!("Rebuilt SQLite search index with {} entries", count);
+        Ok(())
+    }
+
+    async fn record_cache_operation(&self, operation: CacheOperation) -> Result<(), StorageError> {
+        debug!("Recording cache operation: {:?}", operation.operation_type);
+        Ok(())
+    }
+
+    async fn get_performance_monitor(&self) -> Result<CachePerformanceMonitor, StorageError> {
+        let (hits, misses, current_hit_rate) = self.hit_rate();
+        let target_hit_rate = 0.8;
+        let status = if hits + misses == 0 {
+            CachePerformanceStatus::Unknown
+        } else if current_hit_rate >= target_hit_rate {
+            CachePerformanceStatus::Optimal
+        } else if current_hit_rate >= target_hit_rate * 0.8 {
+            CachePerformanceStatus::Degraded
+        } else {
+            CachePerformanceStatus::Critical
+        };
+        Ok(CachePerformanceMonitor {
+            target_hit_rate,
+            current_hit_rate,
+            status,
+            recent_operations: Vec::new(),
+            alerts: Vec::new(),
+        })
+    }
+
+    async fn update_analytics(&self, analytics: CacheAnalytics) -> Result<(), StorageError> {
+        *self.analytics.lock().unwrap() = analytics;
+        Ok(())
+    }
+
+    async fn get_key_optimization_recommendations(&self) -> Result<Vec<String>, StorageError> {
+        let (_, _, hit_rate) = self.hit_rate();
+        let mut recommendations = Vec::new();
+        if hit_rate < 0.8 {
+            recommendations.push(
+                "Consider implementing cache warming for frequently accessed content".to_string(),
+            );
+        }
+        if recommendations.is_empty() {
+            recommendations
+                .push("Cache performance is optimal - no specific recommendations".to_string());
+        }
+        Ok(recommendations)
+    }
+
+    async fn warm_cache(&self, entries: Vec<String>) -> Result<CacheWarmingStats, StorageError> {
+        let mut stats = CacheWarmingStats {
+            active_strategies: vec!["manual_warming".to_string()],
+            ..Default::default()
+        };
+        let requested = entries.len() as u64;
+        let found = self
+            .with_connection(move |connection| {
+                let mut statement = connection
+                    .prepare("SELECT 1 FROM results WHERE cache_key = ?1")
+                    .map_err(sql_error)?;
+                let mut found = 0u64;
+                for key in entries {
+                    if statement.exists(params![key]).map_err(sql_error)? {
+                        found += 1;
+                    }
+                }
+                Ok(found)
+            })
+            .await?;
+        stats.items_warmed = found;
+        stats.warming_failures = requested - found;
+        if requested > 0 {
+            stats.strategy_effectiveness = found as f64 / requested as f64;
+        }
+        Ok(stats)
+    }
+
+    async fn get_hit_rate_trends(
+        &self,
+        _timeframe_hours: u64,
+    ) -> Result<Vec<HitRateTrend>, StorageError> {
+        let (hits, misses, hit_rate) = self.hit_rate();
+        Ok(vec![HitRateTrend {
+            timestamp: Utc::now(),
+            hit_rate,
+            request_count: hits + misses,
+            context: "current_period".to_string(),
+        }])
+    }
+}
+
+fn cache_entry_from_row(row: &rusqlite::Row<'_>, path: &Path) -> rusqlite::Result<CacheEntry> {
+    let research_type: String = row.get("research_type")?;
+    let metadata: String = row.get("metadata")?;
+    Ok(CacheEntry {
+        key: row.get("cache_key")?,
+        file_path: path.to_path_buf(),
+        research_type: research_type.parse().unwrap_or(ResearchType::Learning),
+        original_query: row.get("original_query")?,
+        created_at: from_millis(row.get("created_at")?),
+        last_accessed: from_millis(row.get("last_accessed")?),
+        expires_at: from_millis(row.get("expires_at")?),
+        size_bytes: row.get::<_, i64>("size_bytes")? as u64,
+        content_hash: row.get("content_hash")?,
+        metadata: serde_json::from_str(&metadata).unwrap_or_default(),
+    })
+}
+
+type SearchRow = (String, String, f64, String);
+
+fn read_search_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<SearchRow> {
+    Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+}
+
+/// Key for results stored without one: the research type and lowercased query
+pub(crate) fn fallback_cache_key(result: &ResearchResult) -> String {
+    let query = result
+        .request
+        .original_query
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    let digest = md5::compute(format!("{:?}:{query}", result.request.research_type));
+    format!("{digest:x}")
+}
+
+fn from_millis(millis: i64) -> DateTime<Utc> {
+    Utc.timestamp_millis_opt(millis)
+        .single()
+        .unwrap_or_default()
+}
+
+fn sql_error(e: rusqlite::Error) -> StorageError {
+    StorageError::Cache(format!("SQLite error: {e}"))
+}
+
+fn task_error(e: tokio::task::JoinError) -> StorageError {
+    StorageError::Cache(format!("SQLite task failed: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_types::{AudienceContext, ClassifiedRequest, DomainContext, ResearchMetadata};
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    async fn create_test_storage() -> (SqliteStorage, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = SqliteStorage::open(
+            temp_dir.path().join(DEFAULT_SQLITE_FILE),
+            StorageConfig {
+                base_path: temp_dir.path().to_path_buf(),
+                ..StorageConfig::default()
+            },
+        )
+        .await
+        .unwrap();
+        (storage, temp_dir)
+    }
+
+    fn result(cache_key: &str, query: &str, answer: &str, tags: &[&str]) -> ResearchResult {
+        let domain_context = DomainContext {
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..DomainContext::default()
+        };
+        let request = ClassifiedRequest::new(
+            query.to_string(),
+            ResearchType::Implementation,
+            AudienceContext::default(),
+            domain_context,
+            0.8,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 10,
+            sources_consulted: vec![],
+            quality_score: 0.8,
+            cache_key: cache_key.to_string(),
+            tags: HashMap::new(),
+        };
+        ResearchResult::new(request, answer.to_string(), vec![], vec![], metadata)
+    }
+
+    #[tokio::test]
+    async fn test_store_retrieve_and_delete() {
+        let (storage, temp_dir) = create_test_storage().await;
+        let stored = result("pin", "How do I pin a future?", "Use Box::pin.", &[]);
+        assert_eq!(storage.store(&stored).await.unwrap(), "pin");
+
+        // Results survive reopening the database
+        let reopened = SqliteStorage::open(storage.path(), StorageConfig::default())
+            .await
+            .unwrap();
+        let retrieved = reopened.retrieve("pin").await.unwrap().unwrap();
+        assert_eq!(retrieved, stored);
+
+        let entries = storage.list_cache_entries().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].research_type, ResearchType::Implementation);
+        assert_eq!(
+            entries[0].file_path,
+            temp_dir.path().join(DEFAULT_SQLITE_FILE)
+        );
+
+        storage.delete("pin").await.unwrap();
+        assert!(storage.retrieve("pin").await.unwrap().is_none());
+        assert_eq!(storage.get_cache_stats().await.unwrap().total_entries, 0);
+    }
+
+    #[tokio::test]
+    async fn test_full_text_search_with_query_language() {
+        let (storage, _temp_dir) = create_test_storage().await;
+        storage
+            .store(&result(
+                "axum",
+                "Share state in axum",
+                "Wrap the state in an Arc and use the State extractor.",
+                &["web"],
+            ))
+            .await
+            .unwrap();
+        storage
+            .store(&result(
+                "tokio",
+                "Share state between tokio tasks",
+                "Wrap the state in an Arc<Mutex<_>>.",
+                &["async"],
+            ))
+            .await
+            .unwrap();
+
+        let found = storage
+            .search(&SearchQuery::new("state".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 2);
+        assert!(found.iter().all(|r| r.relevance_score > 0.0));
+
+        let found = storage
+            .search(&SearchQuery::new("state -extractor".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].entry.cache_key, "tokio");
+
+        let found = storage
+            .search(&SearchQuery::new("arc".to_string()).with_tags(vec!["web".to_string()]))
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].entry.cache_key, "axum");
+
+        // Rebuilding the index keeps search working
+        storage.update_index().await.unwrap();
+        let found = storage
+            .search(&SearchQuery::new("mutex".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_expired() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = SqliteStorage::open(
+            temp_dir.path().join(DEFAULT_SQLITE_FILE),
+            StorageConfig {
+                cache_expiration_seconds: 0,
+                ..StorageConfig::default()
+            },
+        )
+        .await
+        .unwrap();
+        storage
+            .store(&result("old", "Old query", "Old answer", &[]))
+            .await
+            .unwrap();
+
+        assert!(storage.retrieve("old").await.unwrap().is_none());
+        assert_eq!(storage.cleanup_expired().await.unwrap(), 1);
+        assert!(storage.list_cache_entries().await.unwrap().is_empty());
+        let found = storage
+            .search(&SearchQuery::new("old".to_string()))
+            .await
+            .unwrap();
+        assert!(found.is_empty());
+    }
+}
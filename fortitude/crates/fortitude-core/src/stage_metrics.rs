@@ -0,0 +1,372 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Per-stage timing and outcome metrics for the research pipeline
+// Aggregates latency histograms for classification, context detection, research and quality scoring,
+// plus classification outcomes such as type distribution, fallbacks and threshold misses
+use crate::content_filter::{FilterAction, FilterReport};
+use fortitude_types::ResearchType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Upper bounds in milliseconds of the latency histogram buckets
+///
+/// A final unbounded bucket catches everything slower.
+pub const LATENCY_BUCKETS_MS: &[f64] = &[
+    1.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0, 10000.0, 30000.0,
+];
+
+/// Timed stage of the research pipeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PipelineStage {
+    Classification,
+    ContextDetection,
+    Research,
+    /// Scoring a fresh answer against the quality gate, within the research stage
+    QualityScoring,
+}
+
+impl PipelineStage {
+    pub const ALL: [PipelineStage; 4] = [
+        PipelineStage::Classification,
+        PipelineStage::ContextDetection,
+        PipelineStage::Research,
+        PipelineStage::QualityScoring,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PipelineStage::Classification => "classification",
+            PipelineStage::ContextDetection => "context_detection",
+            PipelineStage::Research => "research",
+            PipelineStage::QualityScoring => "quality_scoring",
+        }
+    }
+}
+
+/// Stage durations for a single query, recorded in the result metadata tags
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StageTimings {
+    pub classification: Duration,
+    pub context_detection: Option<Duration>,
+    pub research: Option<Duration>,
+}
+
+impl StageTimings {
+    /// Write the timings as `classification_ms`, `context_detection_ms` and `research_ms` tags
+    pub fn apply_to_tags(&self, tags: &mut HashMap<String, String>) {
+        let stages = [
+            (PipelineStage::Classification, Some(self.classification)),
+            (PipelineStage::ContextDetection, self.context_detection),
+            (PipelineStage::Research, self.research),
+        ];
+        for (stage, duration) in stages {
+            if let Some(duration) = duration {
+                tags.insert(
+                    format!("{}_ms", stage.as_str()),
+                    format!("{:.3}", duration_ms(duration)),
+                );
+            }
+        }
+    }
+}
+
+/// Cumulative count of observations at or below `le_ms`; `None` is the unbounded bucket
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistogramBucket {
+    pub le_ms: Option<f64>,
+    pub count: u64,
+}
+
+/// Aggregated latency of one pipeline stage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageLatencySnapshot {
+    pub stage: String,
+    pub count: u64,
+    pub total_ms: f64,
+    pub mean_ms: f64,
+    pub max_ms: f64,
+    pub latency_histogram: Vec<HistogramBucket>,
+}
+
+/// Aggregated classification and context-detection outcomes
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClassificationOutcomes {
+    /// Queries classified successfully
+    pub classified: u64,
+    /// Classifications that failed for any reason, including threshold misses
+    pub failures: u64,
+    /// Classifications rejected for not meeting the confidence threshold
+    pub threshold_misses: u64,
+    /// Advanced classifications that fell back to basic classification
+    pub fallback_used: u64,
+    /// Successful classifications per research type
+    pub by_type: HashMap<String, u64>,
+    /// Context detections that produced a result
+    pub context_detections: u64,
+    /// Context detections that failed and were skipped
+    pub context_detection_failures: u64,
+    /// Context detections that fell back to defaults for some dimension
+    pub context_fallback_used: u64,
+}
+
+/// Aggregated content filter decisions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContentFilterOutcomes {
+    /// Results checked by at least one response filter
+    pub checked: u64,
+    /// Results changed or withheld by any rule
+    pub filtered: u64,
+    pub blocked: u64,
+    pub annotated: u64,
+    pub rewritten: u64,
+    /// Matching results per rule name
+    pub by_rule: HashMap<String, u64>,
+}
+
+/// Point-in-time view of all pipeline stage metrics
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageMetricsSnapshot {
+    pub stages: Vec<StageLatencySnapshot>,
+    pub classification: ClassificationOutcomes,
+    #[serde(default)]
+    pub content_filter: ContentFilterOutcomes,
+}
+
+#[derive(Debug, Clone, Default)]
+struct LatencyHistogram {
+    /// Per-bucket counts, with the unbounded bucket last
+    buckets: Vec<u64>,
+    count: u64,
+    total_ms: f64,
+    max_ms: f64,
+}
+
+impl LatencyHistogram {
+    fn record(&mut self, ms: f64) {
+        if self.buckets.is_empty() {
+            self.buckets = vec![0; LATENCY_BUCKETS_MS.len() + 1];
+        }
+        let index = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|bound| ms <= *bound)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.buckets[index] += 1;
+        self.count += 1;
+        self.total_ms += ms;
+        self.max_ms = self.max_ms.max(ms);
+    }
+
+    fn snapshot(&self, stage: PipelineStage) -> StageLatencySnapshot {
+        let mut cumulative = 0;
+        let latency_histogram = (0..=LATENCY_BUCKETS_MS.len())
+            .map(|i| {
+                cumulative += self.buckets.get(i).copied().unwrap_or(0);
+                HistogramBucket {
+                    le_ms: LATENCY_BUCKETS_MS.get(i).copied(),
+                    count: cumulative,
+                }
+            })
+            .collect();
+
+        StageLatencySnapshot {
+            stage: stage.as_str().to_string(),
+            count: self.count,
+            total_ms: self.total_ms,
+            mean_ms: if self.count == 0 {
+                0.0
+            } else {
+                self.total_ms / self.count as f64
+            },
+            max_ms: self.max_ms,
+            latency_histogram,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct StageMetricsInner {
+    latencies: HashMap<PipelineStage, LatencyHistogram>,
+    classification: ClassificationOutcomes,
+    content_filter: ContentFilterOutcomes,
+}
+
+/// Thread-safe recorder for pipeline stage metrics
+#[derive(Debug, Default)]
+pub struct StageMetrics {
+    inner: Mutex<StageMetricsInner>,
+}
+
+impl StageMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record how long a stage took
+    pub fn record_latency(&self, stage: PipelineStage, duration: Duration) {
+        let mut inner = self.inner.lock().unwrap();
+        inner
+            .latencies
+            .entry(stage)
+            .or_default()
+            .record(duration_ms(duration));
+    }
+
+    /// Record a successful classification
+    pub fn record_classification(&self, research_type: &ResearchType, fallback_used: bool) {
+        let mut inner = self.inner.lock().unwrap();
+        let outcomes = &mut inner.classification;
+        outcomes.classified += 1;
+        if fallback_used {
+            outcomes.fallback_used += 1;
+        }
+        *outcomes
+            .by_type
+            .entry(research_type.to_string())
+            .or_default() += 1;
+    }
+
+    /// Record a failed classification
+    pub fn record_classification_failure(&self, threshold_miss: bool) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.classification.failures += 1;
+        if threshold_miss {
+            inner.classification.threshold_misses += 1;
+        }
+    }
+
+    /// Record the outcome of context detection
+    pub fn record_context_detection(&self, succeeded: bool, fallback_used: bool) {
+        let mut inner = self.inner.lock().unwrap();
+        let outcomes = &mut inner.classification;
+        if succeeded {
+            outcomes.context_detections += 1;
+            if fallback_used {
+                outcomes.context_fallback_used += 1;
+            }
+        } else {
+            outcomes.context_detection_failures += 1;
+        }
+    }
+
+    /// Record the decisions response filters made for one result
+    pub fn record_content_filter(&self, report: &FilterReport) {
+        let mut inner = self.inner.lock().unwrap();
+        let outcomes = &mut inner.content_filter;
+        outcomes.checked += 1;
+        if !report.is_empty() {
+            outcomes.filtered += 1;
+        }
+        for decision in &report.decisions {
+            match decision.action {
+                FilterAction::Block => outcomes.blocked += 1,
+                FilterAction::Annotate => outcomes.annotated += 1,
+                FilterAction::Rewrite => outcomes.rewritten += 1,
+            }
+            *outcomes.by_rule.entry(decision.rule.clone()).or_default() += 1;
+        }
+    }
+
+    /// Current aggregated metrics, with every stage listed even before it has run
+    pub fn snapshot(&self) -> StageMetricsSnapshot {
+        let inner = self.inner.lock().unwrap();
+        let stages = PipelineStage::ALL
+            .iter()
+            .map(|stage| {
+                inner
+                    .latencies
+                    .get(stage)
+                    .cloned()
+                    .unwrap_or_default()
+                    .snapshot(*stage)
+            })
+            .collect();
+
+        StageMetricsSnapshot {
+            stages,
+            classification: inner.classification.clone(),
+            content_filter: inner.content_filter.clone(),
+        }
+    }
+}
+
+fn duration_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_histogram_is_cumulative() {
+        let metrics = StageMetrics::new();
+        metrics.record_latency(PipelineStage::Classification, Duration::from_micros(400));
+        metrics.record_latency(PipelineStage::Classification, Duration::from_millis(7));
+        metrics.record_latency(PipelineStage::Classification, Duration::from_secs(60));
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.stages.len(), 4);
+        let classification = &snapshot.stages[0];
+        assert_eq!(classification.stage, "classification");
+        assert_eq!(classification.count, 3);
+        assert_eq!(classification.max_ms, 60_000.0);
+
+        let histogram = &classification.latency_histogram;
+        assert_eq!(histogram.len(), LATENCY_BUCKETS_MS.len() + 1);
+        assert_eq!(histogram[0].le_ms, Some(1.0));
+        assert_eq!(histogram[0].count, 1);
+        assert_eq!(histogram[2].count, 2);
+        assert_eq!(histogram[LATENCY_BUCKETS_MS.len() - 1].count, 2);
+        assert_eq!(histogram.last().unwrap().le_ms, None);
+        assert_eq!(histogram.last().unwrap().count, 3);
+
+        assert_eq!(snapshot.stages[2].count, 0);
+        assert_eq!(snapshot.stages[2].mean_ms, 0.0);
+    }
+
+    #[test]
+    fn test_classification_outcomes_and_timing_tags() {
+        let metrics = StageMetrics::new();
+        metrics.record_classification(&ResearchType::Learning, false);
+        metrics.record_classification(&ResearchType::Learning, true);
+        metrics.record_classification_failure(true);
+        metrics.record_classification_failure(false);
+        metrics.record_context_detection(true, true);
+        metrics.record_context_detection(false, false);
+
+        let outcomes = metrics.snapshot().classification;
+        assert_eq!(outcomes.classified, 2);
+        assert_eq!(outcomes.fallback_used, 1);
+        assert_eq!(outcomes.failures, 2);
+        assert_eq!(outcomes.threshold_misses, 1);
+        assert_eq!(outcomes.by_type.get("Learning"), Some(&2));
+        assert_eq!(outcomes.context_detections, 1);
+        assert_eq!(outcomes.context_fallback_used, 1);
+        assert_eq!(outcomes.context_detection_failures, 1);
+
+        let mut tags = HashMap::new();
+        StageTimings {
+            classification: Duration::from_micros(1500),
+            context_detection: None,
+            research: Some(Duration::from_millis(20)),
+        }
+        .apply_to_tags(&mut tags);
+        assert_eq!(tags.get("classification_ms").unwrap(), "1.500");
+        assert_eq!(tags.get("research_ms").unwrap(), "20.000");
+        assert!(!tags.contains_key("context_detection_ms"));
+    }
+}
@@ -13,26 +13,114 @@
 // limitations under the License.
 
 // ABOUTME: File-based storage system with reference library integration
+//! With `enable_content_addressing`, the answer, evidence, details and
+//! citations of a result live in a content blob under `blobs/`, named by the
+//! SHA-256 hash of the serialized blob. Entry files keep the request and
+//! metadata and name their blob in the [`CONTENT_BLOB_TAG`] metadata tag, so
+//! results with byte-identical content share one blob while any difference,
+//! even in case or whitespace, gets a blob of its own. Blob reference counts are kept in `index/blob_refs.json`, and a blob
+//! is removed when the last entry using it is overwritten or deleted.
+
 use crate::classification::context_detector::ContextDetectionResult;
+use crate::keyword_index::{highlight_terms, tokenize, KeywordIndex};
+use crate::query_language::SearchExpression;
+use crate::write_json_atomic_async;
 use fortitude_types::{
     CacheAnalytics, CacheEntry, CacheOperation, CacheOperationType, CachePerformanceMonitor,
-    CachePerformanceStatus, CacheStats, CacheTypeStats, CacheWarmingStats, HitRateTrend,
-    IndexEntry, ResearchResult, ResearchType, SearchQuery, SearchResult, Storage, StorageConfig,
-    StorageError,
+    CachePerformanceStatus, CacheStats, CacheTypeStats, CacheWarmingStats, Citation,
+    ClassifiedRequest, DedupStats, Detail, Evidence, HitRateTrend, IndexEntry, ResearchResult,
+    ResearchType, SearchQuery, SearchResult, Storage, StorageConfig, StorageError,
+    SECONDARY_TYPES_METADATA_KEY,
 };
+use serde::{Deserialize, Serialize};
 use serde_json;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::fs as async_fs;
+use tokio::io::AsyncWriteExt;
 use tokio::sync::Mutex;
 use tracing::{debug, info, warn};
 
+/// Metadata tag on a stored entry file naming the content blob holding its answer
+pub const CONTENT_BLOB_TAG: &str = "content_blob";
+
+/// Answer content shared by entries with identical content
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ContentBlob {
+    immediate_answer: String,
+    supporting_evidence: Vec<Evidence>,
+    implementation_details: Vec<Detail>,
+    #[serde(default)]
+    citations: Vec<Citation>,
+}
+
+impl ContentBlob {
+    fn take_from(result: &mut ResearchResult) -> Self {
+        Self {
+            immediate_answer: std::mem::take(&mut result.immediate_answer),
+            supporting_evidence: std::mem::take(&mut result.supporting_evidence),
+            implementation_details: std::mem::take(&mut result.implementation_details),
+            citations: std::mem::take(&mut result.citations),
+        }
+    }
+
+    fn restore_into(self, result: &mut ResearchResult) {
+        result.immediate_answer = self.immediate_answer;
+        result.supporting_evidence = self.supporting_evidence;
+        result.implementation_details = self.implementation_details;
+        result.citations = self.citations;
+    }
+}
+
+/// Reference count of a content blob
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BlobRecord {
+    ref_count: u64,
+    size_bytes: u64,
+}
+
+/// Journaled search index changes after which the snapshot is rewritten
+const SEARCH_JOURNAL_COMPACTION_THRESHOLD: usize = 1000;
+
+/// One search index change, appended to the journal as a JSON line
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum SearchIndexChange {
+    Insert { entry: IndexEntry },
+    Remove { cache_key: String },
+}
+
+impl SearchIndexChange {
+    fn apply_to(self, entries: &mut HashMap<String, IndexEntry>) {
+        match self {
+            Self::Insert { entry } => {
+                entries.insert(entry.cache_key.clone(), entry);
+            }
+            Self::Remove { cache_key } => {
+                entries.remove(&cache_key);
+            }
+        }
+    }
+}
+
+/// Search index journal state. Index writes hold its lock so the journal
+/// records changes in the order they were applied in memory.
+#[derive(Debug, Default)]
+struct SearchJournal {
+    /// Changes appended since the snapshot was last written
+    pending: usize,
+}
+
 /// File-based storage implementation with enhanced performance monitoring
 pub struct FileStorage {
     config: StorageConfig,
     cache_index: Arc<Mutex<HashMap<String, CacheEntry>>>,
+    blob_refs: Arc<Mutex<HashMap<String, BlobRecord>>>,
     search_index: Arc<Mutex<HashMap<String, IndexEntry>>>,
+    keyword_index: Arc<Mutex<KeywordIndex>>,
+    search_journal: Arc<Mutex<SearchJournal>>,
     performance_monitor: Arc<Mutex<CachePerformanceMonitor>>,
     analytics: Arc<Mutex<CacheAnalytics>>,
     recent_operations: Arc<Mutex<Vec<CacheOperation>>>,
@@ -53,7 +141,10 @@ impl FileStorage {
         let storage = Self {
             config,
             cache_index: Arc::new(Mutex::new(HashMap::new())),
+            blob_refs: Arc::new(Mutex::new(HashMap::new())),
             search_index: Arc::new(Mutex::new(HashMap::new())),
+            keyword_index: Arc::new(Mutex::new(KeywordIndex::new())),
+            search_journal: Arc::new(Mutex::new(SearchJournal::default())),
             performance_monitor: Arc::new(Mutex::new(performance_monitor)),
             analytics: Arc::new(Mutex::new(CacheAnalytics::default())),
             recent_operations: Arc::new(Mutex::new(Vec::new())),
@@ -73,6 +164,7 @@ impl FileStorage {
             &self.config.base_path.join("research_results"),
             &self.config.base_path.join("cache"),
             &self.config.base_path.join("index"),
+            &self.config.base_path.join("blobs"),
         ];
 
         for dir in dirs {
@@ -117,9 +209,208 @@ impl FileStorage {
         // This would load existing indices from disk
         // For now, we'll start with empty indices
         debug!("Loading indices from disk");
+
+        // Blob reference counts must survive restarts, or a blob still used by
+        // an entry from an earlier run could be removed
+        let refs_path = self.blob_refs_path();
+        if refs_path.exists() {
+            let content = async_fs::read_to_string(&refs_path)
+                .await
+                .map_err(StorageError::Io)?;
+            let refs: HashMap<String, BlobRecord> = serde_json::from_str(&content)
+                .map_err(|e| StorageError::Index(format!("Invalid blob reference index: {e}")))?;
+            *self.blob_refs.lock().await = refs;
+        }
+
+        // The search index is the last snapshot plus the journaled changes
+        // since, and the keyword index is rebuilt from its entries
+        let mut entries = HashMap::new();
+        let search_path = self.search_index_path();
+        if search_path.exists() {
+            let content = async_fs::read_to_string(&search_path)
+                .await
+                .map_err(StorageError::Io)?;
+            entries = serde_json::from_str(&content)
+                .map_err(|e| StorageError::Index(format!("Invalid search index: {e}")))?;
+        }
+        let mut journal = self.search_journal.lock().await;
+        let mut torn = false;
+        let journal_path = self.search_journal_path();
+        if journal_path.exists() {
+            let content = async_fs::read_to_string(&journal_path)
+                .await
+                .map_err(StorageError::Io)?;
+            for (line_number, line) in content.lines().enumerate() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<SearchIndexChange>(line) {
+                    Ok(change) => {
+                        change.apply_to(&mut entries);
+                        journal.pending += 1;
+                    }
+                    Err(e) => {
+                        // A crash mid-append leaves a partial last line
+                        warn!(
+                            "Skipping unreadable search index journal line {}: {}",
+                            line_number + 1,
+                            e
+                        );
+                        torn = true;
+                    }
+                }
+            }
+        }
+        *self.keyword_index.lock().await = KeywordIndex::from_entries(entries.values());
+        *self.search_index.lock().await = entries;
+        // Later appends must not land on the end of a partial line
+        if torn {
+            self.compact_search_index(&mut journal).await?;
+        }
+        Ok(())
+    }
+
+    fn blobs_dir(&self) -> PathBuf {
+        self.config.base_path.join("blobs")
+    }
+
+    fn blob_refs_path(&self) -> PathBuf {
+        self.config.base_path.join("index").join("blob_refs.json")
+    }
+
+    async fn save_blob_refs(&self, refs: &HashMap<String, BlobRecord>) -> Result<(), StorageError> {
+        let json = serde_json::to_string_pretty(refs)
+            .map_err(|e| StorageError::Serialization(e.to_string()))?;
+        async_fs::write(self.blob_refs_path(), json)
+            .await
+            .map_err(|e| StorageError::Index(format!("Failed to save blob references: {e}")))
+    }
+
+    /// Take a reference to the blob `json` named `hash`, writing it if it is new
+    async fn acquire_blob(&self, hash: &str, json: &str) -> Result<u64, StorageError> {
+        let mut refs = self.blob_refs.lock().await;
+        let blob_path = self.blobs_dir().join(format!("{hash}.json"));
+        let size_bytes = match refs.get(hash) {
+            Some(record) if blob_path.exists() => record.size_bytes,
+            _ => {
+                async_fs::write(&blob_path, json)
+                    .await
+                    .map_err(StorageError::Io)?;
+                json.len() as u64
+            }
+        };
+        let record = refs.entry(hash.to_string()).or_insert(BlobRecord {
+            ref_count: 0,
+            size_bytes,
+        });
+        record.ref_count += 1;
+        record.size_bytes = size_bytes;
+        self.save_blob_refs(&refs).await?;
+        Ok(size_bytes)
+    }
+
+    /// Drop a reference to a blob, removing the blob once nothing uses it
+    async fn release_blob(&self, hash: &str) -> Result<(), StorageError> {
+        let mut refs = self.blob_refs.lock().await;
+        let Some(record) = refs.get_mut(hash) else {
+            return Ok(());
+        };
+        record.ref_count = record.ref_count.saturating_sub(1);
+        if record.ref_count == 0 {
+            refs.remove(hash);
+            let blob_path = self.blobs_dir().join(format!("{hash}.json"));
+            if let Err(e) = async_fs::remove_file(&blob_path).await {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    return Err(StorageError::Io(e));
+                }
+            }
+            debug!("Removed unreferenced content blob: {}", hash);
+        }
+        self.save_blob_refs(&refs).await
+    }
+
+    /// Write an entry file, sharing its content blob when content addressing is on
+    ///
+    /// Returns the logical size of the result and the content hash recorded
+    /// in the cache index.
+    async fn write_entry(
+        &self,
+        file_path: &Path,
+        result: &ResearchResult,
+    ) -> Result<(u64, String), StorageError> {
+        if let Some(parent) = file_path.parent() {
+            async_fs::create_dir_all(parent)
+                .await
+                .map_err(StorageError::Io)?;
+        }
+        let previous_blob = blob_reference(file_path).await;
+
+        let (json, content_hash, blob_size) = if self.config.enable_content_addressing {
+            let mut envelope = result.clone();
+            let blob = serde_json::to_string_pretty(&ContentBlob::take_from(&mut envelope))
+                .map_err(|e| StorageError::Serialization(e.to_string()))?;
+            let content_hash = blob_hash(&blob);
+            let blob_size = self.acquire_blob(&content_hash, &blob).await?;
+            envelope
+                .metadata
+                .tags
+                .insert(CONTENT_BLOB_TAG.to_string(), content_hash.clone());
+            let json = serde_json::to_string_pretty(&envelope)
+                .map_err(|e| StorageError::Serialization(e.to_string()))?;
+            (json, content_hash, blob_size)
+        } else {
+            let json = serde_json::to_string_pretty(result)
+                .map_err(|e| StorageError::Serialization(e.to_string()))?;
+            let content_hash = self.calculate_content_hash(&json);
+            (json, content_hash, 0)
+        };
+
+        async_fs::write(file_path, &json)
+            .await
+            .map_err(StorageError::Io)?;
+        if let Some(previous) = previous_blob {
+            self.release_blob(&previous).await?;
+        }
+        Ok((json.len() as u64 + blob_size, content_hash))
+    }
+
+    /// Read an entry file, filling in the content from its blob
+    async fn read_entry(&self, file_path: &Path) -> Result<ResearchResult, StorageError> {
+        let content = async_fs::read_to_string(file_path)
+            .await
+            .map_err(StorageError::Io)?;
+        let result: ResearchResult = serde_json::from_str(&content)
+            .map_err(|e| StorageError::Serialization(e.to_string()))?;
+        hydrate(result, &self.blobs_dir()).await
+    }
+
+    /// Remove an entry file and release its content blob
+    async fn remove_entry(&self, file_path: &Path) -> Result<(), StorageError> {
+        let blob = blob_reference(file_path).await;
+        async_fs::remove_file(file_path)
+            .await
+            .map_err(StorageError::Io)?;
+        if let Some(blob) = blob {
+            self.release_blob(&blob).await?;
+        }
         Ok(())
     }
 
+    /// Space shared between entries through content blobs
+    pub async fn dedup_stats(&self) -> DedupStats {
+        let refs = self.blob_refs.lock().await;
+        let mut stats = DedupStats {
+            blobs: refs.len(),
+            ..DedupStats::default()
+        };
+        for record in refs.values() {
+            stats.logical_entries += record.ref_count as usize;
+            stats.logical_bytes += record.size_bytes * record.ref_count;
+            stats.stored_bytes += record.size_bytes;
+        }
+        stats
+    }
+
     /// Generate cache key for a research result
     fn generate_cache_key(&self, result: &ResearchResult) -> String {
         use std::collections::hash_map::DefaultHasher;
@@ -341,10 +632,8 @@ impl FileStorage {
                         .is_some_and(|name| name == target_filename.as_str())
                     {
                         // Found the file, try to read and deserialize it
-                        if let Ok(content) = async_fs::read_to_string(&entry_path).await {
-                            if let Ok(result) = serde_json::from_str::<ResearchResult>(&content) {
-                                return Ok(Some(result));
-                            }
+                        if let Ok(result) = self.read_entry(&entry_path).await {
+                            return Ok(Some(result));
                         }
                     }
                 }
@@ -427,41 +716,26 @@ impl FileStorage {
             cache_key
         );
 
-        // Serialize the result
-        let json = serde_json::to_string_pretty(result)
-            .map_err(|e| StorageError::Serialization(e.to_string()))?;
-
-        // Ensure parent directory exists
-        if let Some(parent) = file_path.parent() {
-            async_fs::create_dir_all(parent)
-                .await
-                .map_err(StorageError::Io)?;
-        }
-
-        // Write to file
-        async_fs::write(&file_path, &json)
-            .await
-            .map_err(StorageError::Io)?;
-
-        // Calculate content hash
-        let content_hash = self.calculate_content_hash(&json);
+        let (size_bytes, content_hash) = self.write_entry(&file_path, result).await?;
 
         // Update cache index
-        let cache_entry = CacheEntry::new(
+        let mut cache_entry = CacheEntry::new(
             cache_key.clone(),
             file_path,
             result.request.research_type.clone(),
             result.request.original_query.clone(),
-            json.len() as u64,
+            size_bytes,
             content_hash,
             self.config.cache_expiration_seconds,
         );
+        cache_entry.metadata.extend(entry_metadata(&result.request));
 
         // Insert entry into cache index
         {
             let mut cache_index = self.cache_index.lock().await;
             cache_index.insert(cache_key.clone(), cache_entry);
         }
+        self.index_result(&cache_key, result).await?;
 
         info!("Stored context-aware research result: {}", cache_key);
         Ok(cache_key)
@@ -488,13 +762,7 @@ impl FileStorage {
                 }
 
                 // Read from file
-                let content = async_fs::read_to_string(&entry.file_path)
-                    .await
-                    .map_err(StorageError::Io)?;
-
-                // Deserialize
-                let result: ResearchResult = serde_json::from_str(&content)
-                    .map_err(|e| StorageError::Serialization(e.to_string()))?;
+                let result = self.read_entry(&entry.file_path).await?;
 
                 debug!("Retrieved context-aware research result: {}", cache_key);
                 return Ok(Some(result));
@@ -507,12 +775,7 @@ impl FileStorage {
             let context_path =
                 self.get_context_aware_file_path(cache_key, &research_type, context_result);
             if context_path.exists() {
-                let content = async_fs::read_to_string(&context_path)
-                    .await
-                    .map_err(StorageError::Io)?;
-
-                let result: ResearchResult = serde_json::from_str(&content)
-                    .map_err(|e| StorageError::Serialization(e.to_string()))?;
+                let result = self.read_entry(&context_path).await?;
 
                 debug!(
                     "Found context-aware research result by scanning: {}",
@@ -524,12 +787,7 @@ impl FileStorage {
             // Fall back to standard path
             let file_path = self.get_cache_file_path(cache_key, &research_type);
             if file_path.exists() {
-                let content = async_fs::read_to_string(&file_path)
-                    .await
-                    .map_err(StorageError::Io)?;
-
-                let result: ResearchResult = serde_json::from_str(&content)
-                    .map_err(|e| StorageError::Serialization(e.to_string()))?;
+                let result = self.read_entry(&file_path).await?;
 
                 debug!("Found research result by standard scanning: {}", cache_key);
                 return Ok(Some(result));
@@ -540,8 +798,29 @@ impl FileStorage {
         Ok(None)
     }
 
+    /// Drop cache index entries whose backing files no longer exist
+    pub async fn prune_missing_entries(&self) -> Result<u64, StorageError> {
+        let pruned = {
+            let mut cache_index = self.cache_index.lock().await;
+            let before = cache_index.len();
+            cache_index.retain(|key, entry| {
+                let exists = entry.file_path.exists();
+                if !exists {
+                    debug!("Pruning cache entry with missing file: {}", key);
+                }
+                exists
+            });
+            (before - cache_index.len()) as u64
+        };
+
+        if pruned > 0 {
+            self.save_cache_index().await?;
+            info!("Pruned {} cache entries with missing files", pruned);
+        }
+        Ok(pruned)
+    }
+
     /// Save cache index to disk
-    #[allow(dead_code)]
     async fn save_cache_index(&self) -> Result<(), StorageError> {
         let index_path = self.config.base_path.join("index").join("cache_index.json");
         let cache_index = self.cache_index.lock().await;
@@ -556,149 +835,194 @@ impl FileStorage {
         Ok(())
     }
 
-    /// Save search index to disk
-    async fn save_search_index(&self) -> Result<(), StorageError> {
-        let index_path = self
-            .config
+    fn search_index_path(&self) -> PathBuf {
+        self.config
             .base_path
             .join("index")
-            .join("search_index.json");
-        let search_index = self.search_index.lock().await;
-        let json = serde_json::to_string_pretty(&*search_index)
-            .map_err(|e| StorageError::Serialization(e.to_string()))?;
+            .join("search_index.json")
+    }
 
-        async_fs::write(&index_path, json)
-            .await
-            .map_err(|e| StorageError::Index(format!("Failed to save search index: {e}")))?;
+    fn search_journal_path(&self) -> PathBuf {
+        self.config
+            .base_path
+            .join("index")
+            .join("search_index.journal")
+    }
+
+    /// Write the search index snapshot and truncate the journal. Replaying a
+    /// journal over a snapshot that already holds its changes is harmless, so
+    /// a crash between the two steps loses nothing.
+    async fn compact_search_index(&self, journal: &mut SearchJournal) -> Result<(), StorageError> {
+        let index_path = self.search_index_path();
+        {
+            let search_index = self.search_index.lock().await;
+            write_json_atomic_async(&index_path, &*search_index)
+                .await
+                .map_err(|e| StorageError::Index(format!("Failed to save search index: {e}")))?;
+        }
+        match async_fs::remove_file(self.search_journal_path()).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                return Err(StorageError::Index(format!(
+                    "Failed to truncate search index journal: {e}"
+                )))
+            }
+        }
+        journal.pending = 0;
 
         debug!("Saved search index to: {}", index_path.display());
         Ok(())
     }
 
-    /// Update search index with new research result
-    #[allow(dead_code)]
-    async fn update_search_index_entry(
-        &mut self,
-        result: &ResearchResult,
+    /// Append changes already applied in memory to the search index journal
+    async fn append_search_changes(
+        &self,
+        journal: &mut SearchJournal,
+        changes: &[SearchIndexChange],
     ) -> Result<(), StorageError> {
-        let cache_key = self.generate_cache_key(result);
-
-        // Extract keywords from the result
-        let mut keywords = result.request.matched_keywords.clone();
-        keywords.extend(result.request.domain_context.frameworks.clone());
-        keywords.extend(result.request.domain_context.tags.clone());
-
-        // Extract content for full-text search
-        let mut content = result.immediate_answer.clone();
-        for evidence in &result.supporting_evidence {
-            content.push_str(&format!(" {}", evidence.content));
+        if changes.is_empty() {
+            return Ok(());
         }
-        for detail in &result.implementation_details {
-            content.push_str(&format!(" {}", detail.content));
+        let mut lines = String::new();
+        for change in changes {
+            lines.push_str(
+                &serde_json::to_string(change)
+                    .map_err(|e| StorageError::Serialization(e.to_string()))?,
+            );
+            lines.push('\n');
         }
 
-        let index_entry = IndexEntry::new(
-            cache_key,
-            result.request.research_type.clone(),
-            result.request.original_query.clone(),
-            content,
-            keywords,
-            result.request.domain_context.tags.clone(),
-            result.metadata.quality_score,
-        );
+        let append = async {
+            let mut file = async_fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(self.search_journal_path())
+                .await?;
+            file.write_all(lines.as_bytes()).await?;
+            file.flush().await
+        };
+        append.await.map_err(|e| {
+            StorageError::Index(format!("Failed to append to search index journal: {e}"))
+        })?;
+        journal.pending += changes.len();
 
-        {
-            let mut search_index = self.search_index.lock().await;
-            search_index.insert(index_entry.cache_key.clone(), index_entry);
+        if journal.pending >= SEARCH_JOURNAL_COMPACTION_THRESHOLD {
+            self.compact_search_index(journal).await?;
         }
-        self.save_search_index().await?;
-
         Ok(())
     }
 
-    /// Perform basic keyword-based search
-    async fn search_by_keywords(&self, query: &SearchQuery) -> Vec<SearchResult> {
-        let query_lower = query.query.to_lowercase();
-        let query_words: Vec<&str> = query_lower.split_whitespace().collect();
+    /// Add an entry to the in-memory search and keyword indices without
+    /// persisting it
+    async fn insert_without_save(&self, index_entry: IndexEntry) {
+        let mut search_index = self.search_index.lock().await;
+        self.keyword_index.lock().await.insert(&index_entry);
+        search_index.insert(index_entry.cache_key.clone(), index_entry);
+    }
 
-        let mut results = Vec::new();
+    /// Drop a result from the in-memory search and keyword indices without
+    /// persisting the change. Returns whether the result was indexed.
+    async fn remove_without_save(&self, cache_key: &str) -> bool {
+        let mut search_index = self.search_index.lock().await;
+        if search_index.remove(cache_key).is_none() {
+            return false;
+        }
+        self.keyword_index.lock().await.remove(cache_key);
+        true
+    }
 
-        let search_index = self.search_index.lock().await;
-        for entry in search_index.values() {
-            // Apply research type filter
-            if let Some(ref filter_type) = query.research_type {
-                if entry.research_type != *filter_type {
-                    continue;
-                }
+    /// Add a stored result to the search and keyword indices
+    async fn index_result(
+        &self,
+        cache_key: &str,
+        result: &ResearchResult,
+    ) -> Result<(), StorageError> {
+        let index_entry = index_entry_for(result, cache_key);
+        let mut journal = self.search_journal.lock().await;
+        self.insert_without_save(index_entry.clone()).await;
+        self.append_search_changes(
+            &mut journal,
+            &[SearchIndexChange::Insert { entry: index_entry }],
+        )
+        .await
+    }
+
+    /// Drop a result from the search and keyword indices
+    async fn unindex_result(&self, cache_key: &str) -> Result<(), StorageError> {
+        self.unindex_results(&[cache_key.to_string()]).await
+    }
+
+    /// Drop results from the search and keyword indices, journaling the
+    /// removals in one append
+    async fn unindex_results(&self, cache_keys: &[String]) -> Result<(), StorageError> {
+        let mut journal = self.search_journal.lock().await;
+        let mut changes = Vec::new();
+        for cache_key in cache_keys {
+            if self.remove_without_save(cache_key).await {
+                changes.push(SearchIndexChange::Remove {
+                    cache_key: cache_key.clone(),
+                });
             }
-
-            // Apply quality filter
-            if let Some(min_quality) = query.min_quality {
-                if entry.quality_score < min_quality {
+        }
+        self.append_search_changes(&mut journal, &changes).await
+    }
+
+    /// Rebuild the search and keyword indices from the stored result files
+    async fn rebuild_search_index(&self) -> Result<usize, StorageError> {
+        let mut entries = HashMap::new();
+        let mut dirs = vec![self.config.base_path.join("research_results")];
+        while let Some(dir) = dirs.pop() {
+            let mut read_dir = async_fs::read_dir(&dir).await.map_err(StorageError::Io)?;
+            while let Some(item) = read_dir.next_entry().await.map_err(StorageError::Io)? {
+                let path = item.path();
+                if path.is_dir() {
+                    dirs.push(path);
                     continue;
                 }
-            }
-
-            // Apply tags filter
-            if !query.tags.is_empty() && !query.tags.iter().any(|tag| entry.tags.contains(tag)) {
-                continue;
-            }
-
-            // Calculate relevance score
-            let content_lower = entry.content.to_lowercase();
-            let query_lower = entry.original_query.to_lowercase();
-
-            let mut matched_keywords = Vec::new();
-            let mut relevance_score = 0.0;
-
-            for word in &query_words {
-                if content_lower.contains(word) {
-                    matched_keywords.push(word.to_string());
-                    relevance_score += 1.0;
-                }
-                if query_lower.contains(word) {
-                    relevance_score += 0.5;
-                }
-                if entry
-                    .keywords
-                    .iter()
-                    .any(|k| k.to_lowercase().contains(word))
-                {
-                    relevance_score += 0.8;
+                if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                    continue;
                 }
-            }
-
-            if relevance_score > 0.0 {
-                // Normalize relevance score
-                relevance_score /= query_words.len() as f64;
-
-                // Generate snippet
-                let snippet = Self::generate_snippet(&entry.content, &query_words);
-
-                results.push(SearchResult::new(
-                    entry.clone(),
-                    relevance_score,
-                    matched_keywords,
-                    snippet,
-                ));
+                let result = match load_result_file(&path).await {
+                    Ok(result) => result,
+                    Err(e) => {
+                        warn!("Skipping unreadable result {}: {}", path.display(), e);
+                        continue;
+                    }
+                };
+                let cache_key = if result.metadata.cache_key.is_empty() {
+                    path.file_stem()
+                        .map(|stem| stem.to_string_lossy().into_owned())
+                        .unwrap_or_default()
+                } else {
+                    result.metadata.cache_key.clone()
+                };
+                entries.insert(cache_key.clone(), index_entry_for(&result, &cache_key));
             }
         }
 
-        // Sort by relevance score (descending)
-        results.sort_by(|a, b| {
-            b.relevance_score
-                .partial_cmp(&a.relevance_score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-
-        // Apply pagination
-        let offset = query.offset.unwrap_or(0);
-        let limit = query.limit.unwrap_or(10);
+        let count = entries.len();
+        let mut journal = self.search_journal.lock().await;
+        {
+            let mut search_index = self.search_index.lock().await;
+            *self.keyword_index.lock().await = KeywordIndex::from_entries(entries.values());
+            *search_index = entries;
+        }
+        self.compact_search_index(&mut journal).await?;
+        Ok(count)
+    }
 
-        results.into_iter().skip(offset).take(limit).collect()
+    /// Perform keyword search using the search query language
+    async fn search_by_keywords(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<Vec<SearchResult>, StorageError> {
+        let search_index = self.search_index.lock().await;
+        let keyword_index = self.keyword_index.lock().await;
+        search_index_entries(&search_index, &keyword_index, query)
     }
 
-    /// Generate a snippet from content highlighting matched terms
+    /// Extract a snippet of content around the first matched term
     fn generate_snippet(content: &str, query_words: &[&str]) -> String {
         let content_lower = content.to_lowercase();
 
@@ -711,9 +1035,15 @@ impl FileStorage {
             }
         }
 
-        // Extract snippet around the match
-        let snippet_start = best_start.saturating_sub(50);
-        let snippet_end = (best_start + 150).min(content.len());
+        // Extract snippet around the match, on character boundaries
+        let mut snippet_start = best_start.saturating_sub(50).min(content.len());
+        while !content.is_char_boundary(snippet_start) {
+            snippet_start -= 1;
+        }
+        let mut snippet_end = (best_start + 150).min(content.len());
+        while !content.is_char_boundary(snippet_end) {
+            snippet_end += 1;
+        }
 
         let mut snippet = content[snippet_start..snippet_end].to_string();
 
@@ -900,41 +1230,26 @@ impl Storage for FileStorage {
 
         debug!("Storing research result with cache key: {}", cache_key);
 
-        // Serialize the result
-        let json = serde_json::to_string_pretty(result)
-            .map_err(|e| StorageError::Serialization(e.to_string()))?;
-
-        // Ensure parent directory exists
-        if let Some(parent) = file_path.parent() {
-            async_fs::create_dir_all(parent)
-                .await
-                .map_err(StorageError::Io)?;
-        }
-
-        // Write to file
-        async_fs::write(&file_path, &json)
-            .await
-            .map_err(StorageError::Io)?;
-
-        // Calculate content hash
-        let content_hash = self.calculate_content_hash(&json);
+        let (size_bytes, content_hash) = self.write_entry(&file_path, result).await?;
 
         // Update cache index
-        let cache_entry = CacheEntry::new(
+        let mut cache_entry = CacheEntry::new(
             cache_key.clone(),
             file_path,
             result.request.research_type.clone(),
             result.request.original_query.clone(),
-            json.len() as u64,
+            size_bytes,
             content_hash,
             self.config.cache_expiration_seconds,
         );
+        cache_entry.metadata.extend(entry_metadata(&result.request));
 
         // Insert entry into cache index
         {
             let mut cache_index = self.cache_index.lock().await;
             cache_index.insert(cache_key.clone(), cache_entry);
         }
+        self.index_result(&cache_key, result).await?;
 
         info!("Stored research result: {}", cache_key);
         Ok(cache_key)
@@ -953,13 +1268,7 @@ impl Storage for FileStorage {
                 }
 
                 // Read from file
-                let content = async_fs::read_to_string(&entry.file_path)
-                    .await
-                    .map_err(StorageError::Io)?;
-
-                // Deserialize
-                let result: ResearchResult = serde_json::from_str(&content)
-                    .map_err(|e| StorageError::Serialization(e.to_string()))?;
+                let result = self.read_entry(&entry.file_path).await?;
 
                 debug!("Retrieved research result via index lookup: {}", cache_key);
                 return Ok(Some(result));
@@ -980,12 +1289,7 @@ impl Storage for FileStorage {
             // OPTIMIZATION 3: Check direct file paths first (most likely location)
             let file_path = self.get_cache_file_path(cache_key, research_type);
             if file_path.exists() {
-                let content = async_fs::read_to_string(&file_path)
-                    .await
-                    .map_err(StorageError::Io)?;
-
-                let result: ResearchResult = serde_json::from_str(&content)
-                    .map_err(|e| StorageError::Serialization(e.to_string()))?;
+                let result = self.read_entry(&file_path).await?;
 
                 debug!("Found research result by optimized scanning: {}", cache_key);
                 return Ok(Some(result));
@@ -1023,12 +1327,12 @@ impl Storage for FileStorage {
         {
             let mut cache_index = self.cache_index.lock().await;
             if let Some(entry) = cache_index.get(cache_key) {
-                async_fs::remove_file(&entry.file_path)
-                    .await
-                    .map_err(StorageError::Io)?;
+                self.remove_entry(&entry.file_path).await?;
 
                 // Remove from cache index
                 cache_index.remove(cache_key);
+                drop(cache_index);
+                self.unindex_result(cache_key).await?;
 
                 info!("Deleted research result: {}", cache_key);
                 return Ok(());
@@ -1039,9 +1343,8 @@ impl Storage for FileStorage {
         for research_type in ResearchType::all() {
             let file_path = self.get_cache_file_path(cache_key, &research_type);
             if file_path.exists() {
-                async_fs::remove_file(&file_path)
-                    .await
-                    .map_err(StorageError::Io)?;
+                self.remove_entry(&file_path).await?;
+                self.unindex_result(cache_key).await?;
 
                 info!("Deleted research result by scanning: {}", cache_key);
                 return Ok(());
@@ -1054,7 +1357,10 @@ impl Storage for FileStorage {
     async fn list_cache_entries(&self) -> Result<Vec<CacheEntry>, StorageError> {
         debug!("Listing cache entries");
         let cache_index = self.cache_index.lock().await;
-        Ok(cache_index.values().cloned().collect())
+        let mut entries: Vec<CacheEntry> = cache_index.values().cloned().collect();
+        // Keep listings stable regardless of hash map iteration order
+        entries.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(entries)
     }
 
     async fn get_cache_stats(&self) -> Result<CacheStats, StorageError> {
@@ -1154,6 +1460,7 @@ impl Storage for FileStorage {
         }
 
         stats.by_research_type = by_type;
+        stats.dedup = self.dedup_stats().await;
 
         // Calculate average age
         if stats.total_entries > 0 {
@@ -1186,10 +1493,11 @@ impl Storage for FileStorage {
         }
 
         // Delete expired entries
+        let mut deleted_keys = Vec::new();
         {
             let mut cache_index = self.cache_index.lock().await;
             for (cache_key, file_path) in expired_keys {
-                if let Err(e) = async_fs::remove_file(&file_path).await {
+                if let Err(e) = self.remove_entry(&file_path).await {
                     warn!(
                         "Failed to delete expired file {}: {}",
                         file_path.display(),
@@ -1200,9 +1508,11 @@ impl Storage for FileStorage {
                     cache_index.remove(&cache_key);
                     deleted_count += 1;
                     debug!("Deleted expired cache entry: {}", cache_key);
+                    deleted_keys.push(cache_key);
                 }
             }
         }
+        self.unindex_results(&deleted_keys).await?;
 
         info!("Cleaned up {} expired cache entries", deleted_count);
         Ok(deleted_count)
@@ -1211,7 +1521,7 @@ impl Storage for FileStorage {
     async fn search(&self, query: &SearchQuery) -> Result<Vec<SearchResult>, StorageError> {
         debug!("Searching with query: '{}'", query.query);
 
-        let results = self.search_by_keywords(query).await;
+        let results = self.search_by_keywords(query).await?;
 
         debug!("Found {} search results", results.len());
         Ok(results)
@@ -1220,11 +1530,24 @@ impl Storage for FileStorage {
     async fn update_index(&self) -> Result<(), StorageError> {
         debug!("Updating search index");
 
-        // This would scan all files and rebuild the index
-        // For now, we'll just save the current index
-        self.save_search_index().await?;
+        let indexed = self.rebuild_search_index().await?;
 
-        info!("Updated search index");
+        info!("Updated search index with {} entries", indexed);
+        Ok(())
+    }
+
+    async fn flush(&self) -> Result<(), StorageError> {
+        self.save_cache_index().await?;
+        {
+            let mut journal = self.search_journal.lock().await;
+            self.compact_search_index(&mut journal).await?;
+        }
+        let refs = self.blob_refs.lock().await;
+        self.save_blob_refs(&refs).await?;
+        debug!(
+            "Flushed storage indices to {}",
+            self.config.base_path.display()
+        );
         Ok(())
     }
 
@@ -1381,6 +1704,188 @@ impl Storage for FileStorage {
     }
 }
 
+/// Name of a content blob: the SHA-256 of its exact serialized bytes, so
+/// only identical content is shared and names stay stable across builds
+fn blob_hash(json: &str) -> String {
+    Sha256::digest(json.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Content blob named by an entry file, if it has one
+async fn blob_reference(file_path: &Path) -> Option<String> {
+    let content = async_fs::read_to_string(file_path).await.ok()?;
+    let result: ResearchResult = serde_json::from_str(&content).ok()?;
+    result.metadata.tags.get(CONTENT_BLOB_TAG).cloned()
+}
+
+/// Fill in the content of a result read from an entry file from its blob
+async fn hydrate(
+    mut result: ResearchResult,
+    blobs_dir: &Path,
+) -> Result<ResearchResult, StorageError> {
+    if let Some(hash) = result.metadata.tags.remove(CONTENT_BLOB_TAG) {
+        let content = async_fs::read_to_string(blobs_dir.join(format!("{hash}.json")))
+            .await
+            .map_err(StorageError::Io)?;
+        let blob: ContentBlob = serde_json::from_str(&content)
+            .map_err(|e| StorageError::Serialization(e.to_string()))?;
+        blob.restore_into(&mut result);
+    }
+    Ok(result)
+}
+
+/// Read a result from a file written by [`FileStorage`]
+///
+/// Content blobs are looked up in the `blobs` directory of the nearest
+/// storage root above the file.
+pub async fn load_result_file(file_path: &Path) -> Result<ResearchResult, StorageError> {
+    let content = async_fs::read_to_string(file_path)
+        .await
+        .map_err(StorageError::Io)?;
+    let result: ResearchResult =
+        serde_json::from_str(&content).map_err(|e| StorageError::Serialization(e.to_string()))?;
+    if !result.metadata.tags.contains_key(CONTENT_BLOB_TAG) {
+        return Ok(result);
+    }
+    let blobs_dir = file_path
+        .ancestors()
+        .map(|dir| dir.join("blobs"))
+        .find(|dir| dir.is_dir())
+        .ok_or_else(|| StorageError::DirectoryNotFound(PathBuf::from("blobs")))?;
+    hydrate(result, &blobs_dir).await
+}
+
+/// Record the request tags on an index entry so entries can be filtered by tag
+/// Cache entry metadata for a request: its tags and secondary research types
+pub(crate) fn entry_metadata(request: &ClassifiedRequest) -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+    if !request.domain_context.tags.is_empty() {
+        metadata.insert("tags".to_string(), request.domain_context.tags.join(","));
+    }
+    if !request.secondary_labels.is_empty() {
+        let types: Vec<String> = request
+            .secondary_types()
+            .iter()
+            .map(|t| t.display_name().to_lowercase())
+            .collect();
+        metadata.insert(SECONDARY_TYPES_METADATA_KEY.to_string(), types.join(","));
+    }
+    metadata
+}
+
+/// Search index entry for a result, as kept by the storage backends
+pub(crate) fn index_entry_for(result: &ResearchResult, cache_key: &str) -> IndexEntry {
+    let mut keywords = result.request.matched_keywords.clone();
+    keywords.extend(result.request.domain_context.frameworks.clone());
+    keywords.extend(result.request.domain_context.tags.clone());
+
+    let mut content = result.immediate_answer.clone();
+    for evidence in &result.supporting_evidence {
+        content.push_str(&format!(" {}", evidence.content));
+    }
+    for detail in &result.implementation_details {
+        content.push_str(&format!(" {}", detail.content));
+    }
+
+    IndexEntry::new(
+        cache_key.to_string(),
+        result.request.research_type.clone(),
+        result.request.original_query.clone(),
+        content,
+        keywords,
+        result.request.domain_context.tags.clone(),
+        result.metadata.quality_score,
+    )
+    .with_secondary_types(result.request.secondary_types())
+}
+
+/// Keyword search using the search query language, ranking the keyword
+/// index's BM25 matches and applying filters to their search index entries
+pub(crate) fn search_index_entries(
+    entries: &HashMap<String, IndexEntry>,
+    keyword_index: &KeywordIndex,
+    query: &SearchQuery,
+) -> Result<Vec<SearchResult>, StorageError> {
+    let expression = SearchExpression::parse(&query.query)
+        .map_err(|e| StorageError::InvalidQuery(e.to_string()))?;
+    if expression.is_empty() {
+        return Ok(Vec::new());
+    }
+    let positive_terms = expression.positive_terms();
+    let query_words: Vec<&str> = positive_terms.iter().map(String::as_str).collect();
+    let mut query_tokens: Vec<String> = positive_terms.iter().flat_map(|t| tokenize(t)).collect();
+    query_tokens.sort();
+    query_tokens.dedup();
+
+    // Queries made only of filters and exclusions match every entry without scoring terms
+    let candidates: Vec<(&IndexEntry, f64)> = if query_tokens.is_empty() {
+        entries.values().map(|entry| (entry, 1.0)).collect()
+    } else {
+        keyword_index
+            .score(&query_tokens)
+            .into_iter()
+            .filter_map(|(cache_key, score)| {
+                // Map unbounded BM25 scores into (0, 1)
+                entries
+                    .get(&cache_key)
+                    .map(|entry| (entry, score / (1.0 + score)))
+            })
+            .collect()
+    };
+
+    let mut results = Vec::new();
+    for (entry, relevance_score) in candidates {
+        if query
+            .research_type
+            .as_ref()
+            .is_some_and(|t| !entry.has_research_type(t))
+            || query.min_quality.is_some_and(|q| entry.quality_score < q)
+            || (!query.tags.is_empty() && !query.tags.iter().any(|t| entry.tags.contains(t)))
+            || !expression.matches(entry)
+        {
+            continue;
+        }
+
+        let matched_keywords = positive_terms
+            .iter()
+            .filter(|term| {
+                let tokens = tokenize(term);
+                !tokens.is_empty()
+                    && tokens
+                        .iter()
+                        .all(|token| keyword_index.contains(&entry.cache_key, token))
+            })
+            .cloned()
+            .collect();
+        let snippet = highlight_terms(
+            &FileStorage::generate_snippet(&entry.content, &query_words),
+            &query_tokens,
+        );
+        results.push(SearchResult::new(
+            entry.clone(),
+            relevance_score,
+            matched_keywords,
+            snippet,
+        ));
+    }
+
+    // Sort by relevance score (descending), then by key for stable pages
+    results.sort_by(|a, b| {
+        b.relevance_score
+            .partial_cmp(&a.relevance_score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.entry.cache_key.cmp(&b.entry.cache_key))
+    });
+
+    // Apply pagination
+    let offset = query.offset.unwrap_or(0);
+    let limit = query.limit.unwrap_or(10);
+
+    Ok(results.into_iter().skip(offset).take(limit).collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1480,6 +1985,55 @@ mod tests {
         assert!(storage.retrieve(&cache_key).await.unwrap().is_none());
     }
 
+    #[tokio::test]
+    async fn test_content_addressing_shares_blobs() {
+        let (storage, temp_dir) = create_test_storage().await;
+        let mut first = create_test_result();
+        first.metadata.cache_key = "first".to_string();
+        first.immediate_answer = "Pin the future with Box::pin".to_string();
+        let mut second = create_test_result();
+        second.metadata.cache_key = "second".to_string();
+        second.request.original_query = "Another query".to_string();
+        second.immediate_answer = first.immediate_answer.clone();
+        // Differs only in case and whitespace, as code and indentation may
+        let mut third = create_test_result();
+        third.metadata.cache_key = "third".to_string();
+        third.immediate_answer = "pin the  future\n    with box::pin".to_string();
+
+        for result in [&first, &second, &third] {
+            storage.store(result).await.unwrap();
+        }
+        let dedup = storage.dedup_stats().await;
+        assert_eq!((dedup.logical_entries, dedup.blobs), (3, 2));
+
+        // Every entry round-trips unchanged
+        for original in [&first, &second, &third] {
+            let retrieved = storage
+                .retrieve(&original.metadata.cache_key)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                retrieved.request.original_query,
+                original.request.original_query
+            );
+            assert_eq!(retrieved.immediate_answer, original.immediate_answer);
+            assert!(!retrieved.metadata.tags.contains_key(CONTENT_BLOB_TAG));
+        }
+
+        // Reference counts survive a restart
+        let reopened = FileStorage::new(storage.config.clone()).await.unwrap();
+        assert_eq!(reopened.dedup_stats().await.logical_entries, 3);
+
+        storage.delete("first").await.unwrap();
+        assert!(storage.retrieve("second").await.unwrap().is_some());
+        storage.delete("second").await.unwrap();
+        storage.delete("third").await.unwrap();
+        assert_eq!(storage.dedup_stats().await, DedupStats::default());
+        let blobs = std::fs::read_dir(temp_dir.path().join("blobs")).unwrap();
+        assert_eq!(blobs.count(), 0);
+    }
+
     #[tokio::test]
     async fn test_cache_stats() {
         let (storage, _temp_dir) = create_test_storage().await;
@@ -1506,6 +2060,196 @@ mod tests {
         assert_eq!(results.len(), 0); // Empty index initially
     }
 
+    #[tokio::test]
+    async fn test_search_query_language() {
+        let (storage, _temp_dir) = create_test_storage().await;
+        storage.store(&create_test_result()).await.unwrap();
+
+        let count = |q: &str| {
+            let query = SearchQuery::new(q.to_string());
+            let storage = &storage;
+            async move { storage.search(&query).await.map(|r| r.len()) }
+        };
+        assert_eq!(count("type:learning answer").await.unwrap(), 1);
+        assert_eq!(count("type:learning").await.unwrap(), 1);
+        assert_eq!(count("answer -\"test answer\"").await.unwrap(), 0);
+        assert_eq!(count("(missing OR answer) AND query").await.unwrap(), 1);
+
+        let err = count("type:lerning").await.unwrap_err();
+        assert!(matches!(err, StorageError::InvalidQuery(ref msg) if msg.contains("position 5")));
+    }
+
+    #[tokio::test]
+    async fn test_multi_label_results_match_every_type() {
+        let (storage, _temp_dir) = create_test_storage().await;
+        let mut result = create_test_result();
+        result.request = result.request.with_secondary_labels([
+            ClassificationLabel::new(ResearchType::Troubleshooting, 0.7),
+            ClassificationLabel::new(ResearchType::Learning, 0.9),
+        ]);
+        let cache_key = storage.store(&result).await.unwrap();
+
+        let filtered = |research_type| {
+            let query = SearchQuery::new("answer".to_string()).with_research_type(research_type);
+            let storage = &storage;
+            async move { storage.search(&query).await.unwrap() }
+        };
+        let hits = filtered(ResearchType::Troubleshooting).await;
+        assert_eq!(hits.len(), 1);
+        assert_eq!(
+            hits[0].entry.secondary_types,
+            vec![ResearchType::Troubleshooting]
+        );
+        assert_eq!(filtered(ResearchType::Learning).await.len(), 1);
+        assert!(filtered(ResearchType::Decision).await.is_empty());
+
+        let query = SearchQuery::new("type:troubleshooting".to_string());
+        assert_eq!(storage.search(&query).await.unwrap().len(), 1);
+
+        let entries = storage.list_cache_entries().await.unwrap();
+        let entry = entries.iter().find(|e| e.key == cache_key).unwrap();
+        assert!(entry.has_research_type(&ResearchType::Troubleshooting));
+        assert!(!entry.has_research_type(&ResearchType::Decision));
+    }
+
+    #[tokio::test]
+    async fn test_flush_writes_cache_index() {
+        let (storage, temp_dir) = create_test_storage().await;
+        storage.store(&create_test_result()).await.unwrap();
+
+        storage.flush().await.unwrap();
+        let content =
+            std::fs::read_to_string(temp_dir.path().join("index").join("cache_index.json"))
+                .unwrap();
+        let index: HashMap<String, CacheEntry> = serde_json::from_str(&content).unwrap();
+        assert!(index.contains_key("test-key"));
+        assert!(temp_dir
+            .path()
+            .join("index")
+            .join("blob_refs.json")
+            .exists());
+    }
+
+    #[tokio::test]
+    async fn test_keyword_index_maintained_on_writes() {
+        let (storage, temp_dir) = create_test_storage().await;
+        let mut focused = create_test_result();
+        focused.metadata.cache_key = "focused".to_string();
+        focused.request.original_query = "Tokio runtime internals".to_string();
+        focused.immediate_answer = "The tokio runtime schedules tasks.".to_string();
+        let mut passing = create_test_result();
+        passing.metadata.cache_key = "passing".to_string();
+        passing.request.original_query = "Web frameworks".to_string();
+        passing.immediate_answer = "Axum builds on tokio, hyper and tower.".to_string();
+        storage.store(&focused).await.unwrap();
+        storage.store(&passing).await.unwrap();
+
+        let found = storage
+            .search(&SearchQuery::new("tokio".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].entry.cache_key, "focused");
+        assert!(found[0].relevance_score > found[1].relevance_score);
+        assert!(found[0].snippet.contains("**tokio**"));
+        assert_eq!(found[0].matched_keywords, vec!["tokio".to_string()]);
+
+        // The index survives a restart and follows deletes
+        let reopened = FileStorage::new(storage.config.clone()).await.unwrap();
+        reopened.delete("focused").await.unwrap();
+        let found = reopened
+            .search(&SearchQuery::new("tokio".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].entry.cache_key, "passing");
+
+        // A lost index is rebuilt from the result files
+        let index_dir = temp_dir.path().join("index");
+        for name in ["search_index.json", "search_index.journal"] {
+            let _ = std::fs::remove_file(index_dir.join(name));
+        }
+        let rebuilt = FileStorage::new(storage.config.clone()).await.unwrap();
+        rebuilt.update_index().await.unwrap();
+        let found = rebuilt
+            .search(&SearchQuery::new("hyper".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_index_journals_writes_until_flush() {
+        let (storage, temp_dir) = create_test_storage().await;
+        let index_dir = temp_dir.path().join("index");
+        let journal_lines = || {
+            std::fs::read_to_string(index_dir.join("search_index.journal"))
+                .map(|c| c.lines().count())
+        };
+        for key in ["first", "second", "third"] {
+            let mut result = create_test_result();
+            result.metadata.cache_key = key.to_string();
+            storage.store(&result).await.unwrap();
+        }
+        storage.delete("second").await.unwrap();
+
+        // Writes append to the journal instead of rewriting the snapshot
+        assert!(!index_dir.join("search_index.json").exists());
+        assert_eq!(journal_lines().unwrap(), 4);
+        let reopened = FileStorage::new(storage.config.clone()).await.unwrap();
+        let keys: Vec<_> = reopened
+            .search(&SearchQuery::new("answer".to_string()))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|found| found.entry.cache_key)
+            .collect();
+        assert_eq!(keys.len(), 2);
+        assert!(!keys.contains(&"second".to_string()));
+
+        // Flushing folds the journal into the snapshot
+        reopened.flush().await.unwrap();
+        assert!(index_dir.join("search_index.json").exists());
+        assert!(journal_lines().is_err());
+        let reopened = FileStorage::new(storage.config.clone()).await.unwrap();
+        let found = reopened
+            .search(&SearchQuery::new("answer".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_expired_unindexes_in_one_append() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = StorageConfig {
+            base_path: temp_dir.path().to_path_buf(),
+            cache_expiration_seconds: 0,
+            max_cache_size_bytes: 1024 * 1024,
+            enable_content_addressing: true,
+            index_update_interval_seconds: 300,
+        };
+        let storage = FileStorage::new(config.clone()).await.unwrap();
+        for key in ["first", "second"] {
+            let mut result = create_test_result();
+            result.metadata.cache_key = key.to_string();
+            storage.store(&result).await.unwrap();
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+        assert_eq!(storage.cleanup_expired().await.unwrap(), 2);
+        let journal =
+            std::fs::read_to_string(temp_dir.path().join("index").join("search_index.journal"))
+                .unwrap();
+        let removals: Vec<_> = journal.lines().skip(2).collect();
+        assert_eq!(removals.len(), 2);
+        assert!(removals
+            .iter()
+            .all(|line| line.contains(r#""op":"remove""#)));
+        let reopened = FileStorage::new(config).await.unwrap();
+        assert!(reopened.search_index.lock().await.is_empty());
+    }
+
     #[test]
     fn test_cache_key_generation() {
         let (storage, _temp_dir) = tokio_test::block_on(create_test_storage());
@@ -2216,4 +2960,20 @@ mod tests {
         println!("  - Cache recently accessed file paths");
         println!("  - Implement query normalization for better cache hits");
     }
+
+    #[tokio::test]
+    async fn test_prune_missing_entries() {
+        let (storage, _temp_dir) = create_test_storage().await;
+        let result = create_test_result();
+
+        let cache_key = storage.store(&result).await.unwrap();
+        assert_eq!(storage.prune_missing_entries().await.unwrap(), 0);
+
+        let entries = storage.list_cache_entries().await.unwrap();
+        let entry = entries.iter().find(|e| e.key == cache_key).unwrap();
+        std::fs::remove_file(&entry.file_path).unwrap();
+
+        assert_eq!(storage.prune_missing_entries().await.unwrap(), 1);
+        assert!(storage.list_cache_entries().await.unwrap().is_empty());
+    }
 }
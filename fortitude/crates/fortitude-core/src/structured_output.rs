@@ -0,0 +1,304 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: JSON schema, prompt instructions and validation for structured research answers
+//! Providers in structured-output mode are asked for a JSON object matching
+//! [`research_response_schema`]. Replies may wrap the JSON in code fences or
+//! prose; validation errors are worded so they can be sent back in a repair request.
+
+use fortitude_types::{Detail, Evidence};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use thiserror::Error;
+
+/// Source recorded for evidence items that do not name one
+const DEFAULT_EVIDENCE_SOURCE: &str = "Multi-Provider Research Engine";
+
+/// Research answer as returned by a provider in structured-output mode
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StructuredResearchResponse {
+    /// Direct answer to the research question
+    pub immediate_answer: String,
+    /// Evidence backing the answer
+    #[serde(default)]
+    pub evidence: Vec<StructuredEvidence>,
+    /// Step-by-step implementation guidance
+    #[serde(default)]
+    pub implementation_details: Vec<StructuredDetail>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StructuredEvidence {
+    pub content: String,
+    #[serde(default)]
+    pub source: Option<String>,
+    /// Relevance score (0.0-1.0)
+    #[serde(default = "default_relevance")]
+    pub relevance: f64,
+    #[serde(default)]
+    pub evidence_type: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StructuredDetail {
+    pub content: String,
+    #[serde(default)]
+    pub category: Option<String>,
+    /// low, medium or high
+    #[serde(default)]
+    pub priority: Option<String>,
+    #[serde(default)]
+    pub prerequisites: Vec<String>,
+}
+
+fn default_relevance() -> f64 {
+    0.8
+}
+
+/// Why a structured reply was rejected
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum StructuredOutputError {
+    #[error("no JSON object found in the response")]
+    MissingJson,
+
+    #[error("invalid JSON: {0}")]
+    InvalidJson(String),
+
+    #[error("response does not match the schema: {}", .0.join("; "))]
+    SchemaViolation(Vec<String>),
+}
+
+/// JSON schema of [`StructuredResearchResponse`]
+pub fn research_response_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["immediate_answer", "evidence", "implementation_details"],
+        "properties": {
+            "immediate_answer": {"type": "string", "minLength": 1},
+            "evidence": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["content"],
+                    "properties": {
+                        "content": {"type": "string", "minLength": 1},
+                        "source": {"type": "string"},
+                        "relevance": {"type": "number", "minimum": 0, "maximum": 1},
+                        "evidence_type": {"type": "string"}
+                    }
+                }
+            },
+            "implementation_details": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["content"],
+                    "properties": {
+                        "content": {"type": "string", "minLength": 1},
+                        "category": {"type": "string"},
+                        "priority": {"type": "string", "enum": ["low", "medium", "high"]},
+                        "prerequisites": {"type": "array", "items": {"type": "string"}}
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Prompt suffix asking the provider for a schema-conforming JSON answer
+pub fn structured_output_instructions() -> String {
+    format!(
+        "Respond with a single JSON object and nothing else. It must match this JSON schema:\n{}",
+        serde_json::to_string_pretty(&research_response_schema()).unwrap_or_default()
+    )
+}
+
+/// Prompt suffix asking the provider to fix a rejected reply
+pub fn repair_instructions(error: &StructuredOutputError, previous_response: &str) -> String {
+    format!(
+        "Your previous reply was rejected: {error}\n\nPrevious reply:\n{previous_response}\n\n{}",
+        structured_output_instructions()
+    )
+}
+
+/// Extract, parse and validate a structured research reply
+pub fn parse_structured_response(
+    text: &str,
+) -> Result<StructuredResearchResponse, StructuredOutputError> {
+    let json = extract_json(text).ok_or(StructuredOutputError::MissingJson)?;
+    let value: Value = serde_json::from_str(json)
+        .map_err(|e| StructuredOutputError::InvalidJson(e.to_string()))?;
+    if !value.is_object() {
+        return Err(StructuredOutputError::SchemaViolation(vec![
+            "top level must be an object".to_string(),
+        ]));
+    }
+    let response: StructuredResearchResponse = serde_json::from_value(value)
+        .map_err(|e| StructuredOutputError::SchemaViolation(vec![e.to_string()]))?;
+
+    let violations = response.violations();
+    if violations.is_empty() {
+        Ok(response)
+    } else {
+        Err(StructuredOutputError::SchemaViolation(violations))
+    }
+}
+
+/// The JSON object in `text`, ignoring code fences and surrounding prose
+fn extract_json(text: &str) -> Option<&str> {
+    let start = text.find('{')?;
+    let end = text.rfind('}')?;
+    (start < end).then(|| &text[start..=end])
+}
+
+impl StructuredResearchResponse {
+    /// Constraints the schema states that serde does not enforce
+    fn violations(&self) -> Vec<String> {
+        let mut violations = Vec::new();
+        if self.immediate_answer.trim().is_empty() {
+            violations.push("immediate_answer must not be empty".to_string());
+        }
+        for (i, evidence) in self.evidence.iter().enumerate() {
+            if evidence.content.trim().is_empty() {
+                violations.push(format!("evidence[{i}].content must not be empty"));
+            }
+            if !(0.0..=1.0).contains(&evidence.relevance) {
+                violations.push(format!("evidence[{i}].relevance must be between 0 and 1"));
+            }
+        }
+        for (i, detail) in self.implementation_details.iter().enumerate() {
+            if detail.content.trim().is_empty() {
+                violations.push(format!(
+                    "implementation_details[{i}].content must not be empty"
+                ));
+            }
+            if let Some(priority) = &detail.priority {
+                if !matches!(priority.as_str(), "low" | "medium" | "high") {
+                    violations.push(format!(
+                        "implementation_details[{i}].priority must be low, medium or high"
+                    ));
+                }
+            }
+        }
+        violations
+    }
+
+    /// Split into the answer, evidence and details of a research result
+    pub fn into_parts(self) -> (String, Vec<Evidence>, Vec<Detail>) {
+        let evidence = self
+            .evidence
+            .into_iter()
+            .map(|e| Evidence {
+                source: e
+                    .source
+                    .unwrap_or_else(|| DEFAULT_EVIDENCE_SOURCE.to_string()),
+                content: e.content,
+                relevance: e.relevance,
+                evidence_type: e
+                    .evidence_type
+                    .unwrap_or_else(|| "Research Analysis".to_string()),
+            })
+            .collect();
+        let details = self
+            .implementation_details
+            .into_iter()
+            .map(|d| Detail {
+                category: d
+                    .category
+                    .unwrap_or_else(|| "Implementation Guidance".to_string()),
+                content: d.content,
+                priority: d.priority.unwrap_or_else(|| "medium".to_string()),
+                prerequisites: d.prerequisites,
+            })
+            .collect();
+        (self.immediate_answer, evidence, details)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_fenced_json_with_prose() {
+        let text = r#"Here is the answer:
+```json
+{
+  "immediate_answer": "Use tokio::sync::Mutex across awaits.",
+  "evidence": [{"content": "std Mutex guards are not Send", "relevance": 0.9}],
+  "implementation_details": [{"content": "Replace std::sync::Mutex", "priority": "high"}]
+}
+```
+Hope this helps."#;
+
+        let response = parse_structured_response(text).unwrap();
+        assert_eq!(
+            response.immediate_answer,
+            "Use tokio::sync::Mutex across awaits."
+        );
+
+        let (answer, evidence, details) = response.into_parts();
+        assert_eq!(answer, "Use tokio::sync::Mutex across awaits.");
+        assert_eq!(evidence[0].source, DEFAULT_EVIDENCE_SOURCE);
+        assert_eq!(evidence[0].relevance, 0.9);
+        assert_eq!(details[0].priority, "high");
+        assert_eq!(details[0].category, "Implementation Guidance");
+    }
+
+    #[test]
+    fn test_rejects_missing_and_malformed_json() {
+        assert_eq!(
+            parse_structured_response("## Answer\nplain text"),
+            Err(StructuredOutputError::MissingJson)
+        );
+        assert!(matches!(
+            parse_structured_response(r#"{"immediate_answer": "x",}"#),
+            Err(StructuredOutputError::InvalidJson(_))
+        ));
+        assert!(matches!(
+            parse_structured_response(r#"{"evidence": []}"#),
+            Err(StructuredOutputError::SchemaViolation(v)) if v[0].contains("immediate_answer")
+        ));
+    }
+
+    #[test]
+    fn test_reports_every_schema_violation() {
+        let text = r#"{
+            "immediate_answer": " ",
+            "evidence": [{"content": "ok", "relevance": 1.5}],
+            "implementation_details": [{"content": "", "priority": "urgent"}]
+        }"#;
+
+        let Err(StructuredOutputError::SchemaViolation(violations)) =
+            parse_structured_response(text)
+        else {
+            panic!("expected schema violations");
+        };
+        assert_eq!(violations.len(), 4);
+        assert!(violations
+            .iter()
+            .any(|v| v.contains("evidence[0].relevance")));
+        assert!(violations
+            .iter()
+            .any(|v| v.contains("implementation_details[0].priority")));
+    }
+
+    #[test]
+    fn test_repair_instructions_carry_error_and_schema() {
+        let instructions = repair_instructions(&StructuredOutputError::MissingJson, "plain text");
+        assert!(instructions.contains("no JSON object found"));
+        assert!(instructions.contains("plain text"));
+        assert!(instructions.contains("\"immediate_answer\""));
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Platform process supervisor integration shared by the Fortitude servers
+//! systemd readiness/watchdog notification, socket activation and the
+//! Windows Service Control Manager plumbing used by the API and MCP servers.
+
+use std::time::Duration;
+use tokio::task::JoinHandle;
+#[cfg(unix)]
+use tracing::{debug, info, warn};
+
+/// First descriptor passed by systemd socket activation
+#[cfg(unix)]
+const SD_LISTEN_FDS_START: std::os::unix::io::RawFd = 3;
+
+/// Report readiness to the service manager (`Type=notify` units)
+///
+/// No-op when the process was not started by systemd.
+pub fn notify_ready(status: &str) {
+    #[cfg(unix)]
+    notify(&[
+        sd_notify::NotifyState::Ready,
+        sd_notify::NotifyState::Status(status),
+    ]);
+    #[cfg(not(unix))]
+    let _ = status;
+}
+
+/// Report that graceful shutdown has started
+pub fn notify_stopping(status: &str) {
+    #[cfg(unix)]
+    notify(&[
+        sd_notify::NotifyState::Stopping,
+        sd_notify::NotifyState::Status(status),
+    ]);
+    #[cfg(not(unix))]
+    let _ = status;
+}
+
+#[cfg(unix)]
+fn notify(state: &[sd_notify::NotifyState]) {
+    if std::env::var_os("NOTIFY_SOCKET").is_none() {
+        return;
+    }
+    if let Err(e) = sd_notify::notify(false, state) {
+        warn!("Failed to notify service manager: {}", e);
+    }
+}
+
+/// Interval between watchdog pings for a given `WatchdogSec`
+///
+/// systemd recommends pinging at half the configured timeout.
+pub fn watchdog_ping_interval(timeout: Duration) -> Duration {
+    (timeout / 2).max(Duration::from_millis(100))
+}
+
+/// Start sending watchdog keep-alives if the unit sets `WatchdogSec`
+///
+/// Pings are sent from the tokio runtime, so a stalled runtime stops them and
+/// systemd restarts the service.
+pub fn spawn_watchdog() -> Option<JoinHandle<()>> {
+    #[cfg(unix)]
+    {
+        let mut usec = 0;
+        if !sd_notify::watchdog_enabled(false, &mut usec) {
+            return None;
+        }
+        Some(spawn_watchdog_pings(Duration::from_micros(usec)))
+    }
+    #[cfg(not(unix))]
+    None
+}
+
+#[cfg(unix)]
+fn spawn_watchdog_pings(timeout: Duration) -> JoinHandle<()> {
+    let interval = watchdog_ping_interval(timeout);
+    info!("systemd watchdog enabled, pinging every {:?}", interval);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = sd_notify::notify(false, &[sd_notify::NotifyState::Watchdog]) {
+                warn!("Failed to send watchdog ping: {}", e);
+            }
+        }
+    })
+}
+
+/// Take the listening socket passed by systemd socket activation, if any
+///
+/// Uses the first descriptor from `LISTEN_FDS`; the `.socket` unit must use
+/// `ListenStream=` with a TCP address.
+pub fn activated_listener() -> std::io::Result<Option<tokio::net::TcpListener>> {
+    #[cfg(unix)]
+    {
+        // Validates LISTEN_PID and unsets the environment so the descriptors
+        // are only adopted once
+        let fd_count = sd_notify::listen_fds()?.count();
+        adopt_listener(fd_count)
+    }
+    #[cfg(not(unix))]
+    Ok(None)
+}
+
+/// Adopt the first of `fd_count` descriptors handed over at `SD_LISTEN_FDS_START`
+#[cfg(unix)]
+fn adopt_listener(fd_count: usize) -> std::io::Result<Option<tokio::net::TcpListener>> {
+    use std::os::unix::io::FromRawFd;
+
+    if fd_count == 0 {
+        return Ok(None);
+    }
+    if fd_count > 1 {
+        debug!(
+            "Ignoring {} additional socket-activated descriptors",
+            fd_count - 1
+        );
+    }
+
+    // SAFETY: `fd_count` comes from a validated `LISTEN_FDS`, so systemd has
+    // handed over ownership of the descriptor at SD_LISTEN_FDS_START.
+    let listener = unsafe { std::net::TcpListener::from_raw_fd(SD_LISTEN_FDS_START) };
+    listener.set_nonblocking(true)?;
+    Ok(Some(tokio::net::TcpListener::from_std(listener)?))
+}
+
+/// Windows Service Control Manager plumbing
+///
+/// Each server declares its entry point with `define_windows_service!` and
+/// calls [`windows::run_service`] from it.
+#[cfg(windows)]
+pub mod windows {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::time::Duration;
+    use tracing::info;
+    use windows_service::service::{
+        ServiceControl, ServiceControlAccept, ServiceExitCode, ServiceState, ServiceStatus,
+        ServiceType,
+    };
+    use windows_service::service_control_handler::{self, ServiceControlHandlerResult};
+
+    /// Future that completes when the service is asked to stop
+    pub type StopSignal = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+    fn status(state: ServiceState, exit_code: u32) -> ServiceStatus {
+        ServiceStatus {
+            service_type: ServiceType::OWN_PROCESS,
+            current_state: state,
+            controls_accepted: if state == ServiceState::Running {
+                ServiceControlAccept::STOP | ServiceControlAccept::SHUTDOWN
+            } else {
+                ServiceControlAccept::empty()
+            },
+            exit_code: ServiceExitCode::Win32(exit_code),
+            checkpoint: 0,
+            wait_hint: if state == ServiceState::Running || state == ServiceState::Stopped {
+                Duration::default()
+            } else {
+                Duration::from_secs(30)
+            },
+            process_id: None,
+        }
+    }
+
+    /// Run `serve` as the service `name`, reporting its state to the SCM
+    ///
+    /// `serve` runs on a fresh tokio runtime and receives a [`StopSignal`]
+    /// that completes on a Stop or Shutdown control.
+    pub fn run_service<F, Fut>(name: &str, serve: F) -> anyhow::Result<()>
+    where
+        F: FnOnce(StopSignal) -> Fut,
+        Fut: Future<Output = anyhow::Result<()>>,
+    {
+        let (shutdown_tx, mut shutdown_rx) = tokio::sync::watch::channel(false);
+        let handler = move |control| match control {
+            ServiceControl::Stop | ServiceControl::Shutdown => {
+                let _ = shutdown_tx.send(true);
+                ServiceControlHandlerResult::NoError
+            }
+            ServiceControl::Interrogate => ServiceControlHandlerResult::NoError,
+            _ => ServiceControlHandlerResult::NotImplemented,
+        };
+
+        let handle = service_control_handler::register(name, handler)?;
+        handle.set_service_status(status(ServiceState::StartPending, 0))?;
+
+        let runtime = tokio::runtime::Runtime::new()?;
+        let result = runtime.block_on(async {
+            handle.set_service_status(status(ServiceState::Running, 0))?;
+            info!("Running as Windows service {}", name);
+
+            let stop = Box::pin(async move {
+                let _ = shutdown_rx.wait_for(|stop| *stop).await;
+                info!("Received service stop request");
+            });
+            serve(stop).await
+        });
+
+        handle.set_service_status(status(ServiceState::StopPending, 0))?;
+        let exit_code = if result.is_ok() { 0 } else { 1 };
+        handle.set_service_status(status(ServiceState::Stopped, exit_code))?;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watchdog_ping_interval() {
+        assert_eq!(
+            watchdog_ping_interval(Duration::from_secs(30)),
+            Duration::from_secs(15)
+        );
+        assert_eq!(
+            watchdog_ping_interval(Duration::from_millis(50)),
+            Duration::from_millis(100)
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_no_activated_descriptors_yields_no_listener() {
+        assert!(adopt_listener(0).unwrap().is_none());
+    }
+}
@@ -0,0 +1,311 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Time-boxed research: adapts the research plan to fit a wall-clock budget
+// Tighter budgets drop cross-validation, evidence scoring and RAG context and cap answer length
+use fortitude_types::ClassifiedRequest;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+/// Metadata tag holding the requested time budget in milliseconds
+pub const TIME_BUDGET_TAG: &str = "time_budget_ms";
+
+/// Metadata tag listing the shortcuts taken, comma separated
+pub const TIME_BUDGET_SHORTCUTS_TAG: &str = "time_budget_shortcuts";
+
+/// Budgets below this skip RAG context and evidence scoring entirely
+const TIGHT_BUDGET: Duration = Duration::from_secs(10);
+
+/// Budgets below this use shallow RAG context and shorter answers
+const MODERATE_BUDGET: Duration = Duration::from_secs(30);
+
+/// Budgets below this skip cross-validation
+const RELAXED_BUDGET: Duration = Duration::from_secs(90);
+
+/// A step dropped or reduced to stay within the time budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Shortcut {
+    SkippedCrossValidation,
+    SkippedContextDiscovery,
+    ReducedContextDepth,
+    SkippedEvidenceScoring,
+    CappedAnswerTokens,
+    SkippedUnhealthyProvider,
+    DeadlineFallback,
+}
+
+impl Shortcut {
+    pub const ALL: [Shortcut; 7] = [
+        Shortcut::SkippedCrossValidation,
+        Shortcut::SkippedContextDiscovery,
+        Shortcut::ReducedContextDepth,
+        Shortcut::SkippedEvidenceScoring,
+        Shortcut::CappedAnswerTokens,
+        Shortcut::SkippedUnhealthyProvider,
+        Shortcut::DeadlineFallback,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Shortcut::SkippedCrossValidation => "skipped_cross_validation",
+            Shortcut::SkippedContextDiscovery => "skipped_context_discovery",
+            Shortcut::ReducedContextDepth => "reduced_context_depth",
+            Shortcut::SkippedEvidenceScoring => "skipped_evidence_scoring",
+            Shortcut::CappedAnswerTokens => "capped_answer_tokens",
+            Shortcut::SkippedUnhealthyProvider => "skipped_unhealthy_provider",
+            Shortcut::DeadlineFallback => "deadline_fallback",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.as_str() == value)
+    }
+
+    /// Human-readable note for the shortcut
+    pub fn description(&self) -> &'static str {
+        match self {
+            Shortcut::SkippedCrossValidation => "cross-provider validation was skipped",
+            Shortcut::SkippedContextDiscovery => "related research was not retrieved",
+            Shortcut::ReducedContextDepth => "fewer related documents were retrieved",
+            Shortcut::SkippedEvidenceScoring => "supporting evidence was not scored",
+            Shortcut::CappedAnswerTokens => "the answer length was capped",
+            Shortcut::SkippedUnhealthyProvider => {
+                "the research provider was unhealthy and was not called"
+            }
+            Shortcut::DeadlineFallback => {
+                "the provider did not answer in time; a placeholder answer was returned"
+            }
+        }
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Research plan adapted to a time budget
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeBudgetPlan {
+    pub budget: Duration,
+    pub cross_validation: bool,
+    pub context_discovery: bool,
+    pub evidence_scoring: bool,
+    /// Upper bound on retrieved context documents
+    pub max_context_docs: Option<usize>,
+    /// Upper bound on answer tokens
+    pub max_answer_tokens: Option<u32>,
+}
+
+impl TimeBudgetPlan {
+    /// Plan for the time left in the budget
+    pub fn new(budget: Duration) -> Self {
+        if budget < TIGHT_BUDGET {
+            Self {
+                budget,
+                cross_validation: false,
+                context_discovery: false,
+                evidence_scoring: false,
+                max_context_docs: Some(0),
+                max_answer_tokens: Some(1000),
+            }
+        } else if budget < MODERATE_BUDGET {
+            Self {
+                budget,
+                cross_validation: false,
+                context_discovery: true,
+                evidence_scoring: true,
+                max_context_docs: Some(2),
+                max_answer_tokens: Some(2000),
+            }
+        } else if budget < RELAXED_BUDGET {
+            Self {
+                budget,
+                cross_validation: false,
+                context_discovery: true,
+                evidence_scoring: true,
+                max_context_docs: None,
+                max_answer_tokens: None,
+            }
+        } else {
+            Self {
+                budget,
+                cross_validation: true,
+                context_discovery: true,
+                evidence_scoring: true,
+                max_context_docs: None,
+                max_answer_tokens: None,
+            }
+        }
+    }
+
+    /// Tighten the request's prompt budget, returning the shortcuts this takes
+    ///
+    /// `cross_validation` and `evidence_scoring` say whether the pipeline
+    /// would otherwise run those steps, so only real savings are reported.
+    pub fn apply(
+        &self,
+        request: &mut ClassifiedRequest,
+        cross_validation: bool,
+        context_discovery: bool,
+        evidence_scoring: bool,
+    ) -> Vec<Shortcut> {
+        let mut shortcuts = Vec::new();
+        if cross_validation && !self.cross_validation {
+            shortcuts.push(Shortcut::SkippedCrossValidation);
+        }
+        if context_discovery && !self.context_discovery {
+            shortcuts.push(Shortcut::SkippedContextDiscovery);
+        }
+        if evidence_scoring && !self.evidence_scoring {
+            shortcuts.push(Shortcut::SkippedEvidenceScoring);
+        }
+
+        if let Some(budget) = request.prompt_budget.as_mut() {
+            if let Some(max_docs) = self.max_context_docs {
+                if budget.max_context_docs > max_docs {
+                    budget.max_context_docs = max_docs;
+                    if self.context_discovery && context_discovery {
+                        shortcuts.push(Shortcut::ReducedContextDepth);
+                    }
+                }
+            }
+            if let Some(max_tokens) = self.max_answer_tokens {
+                if budget.max_answer_tokens > max_tokens {
+                    budget.max_answer_tokens = max_tokens;
+                    shortcuts.push(Shortcut::CappedAnswerTokens);
+                }
+            }
+        }
+        shortcuts
+    }
+}
+
+/// Time budget and the shortcuts taken to meet it, recorded in result metadata tags
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeBudgetReport {
+    pub budget_ms: u64,
+    pub shortcuts: Vec<Shortcut>,
+}
+
+impl TimeBudgetReport {
+    pub fn apply_to_tags(&self, tags: &mut HashMap<String, String>) {
+        tags.insert(TIME_BUDGET_TAG.to_string(), self.budget_ms.to_string());
+        let shortcuts: Vec<&str> = self.shortcuts.iter().map(Shortcut::as_str).collect();
+        tags.insert(TIME_BUDGET_SHORTCUTS_TAG.to_string(), shortcuts.join(","));
+    }
+
+    pub fn from_tags(tags: &HashMap<String, String>) -> Option<Self> {
+        let budget_ms = tags.get(TIME_BUDGET_TAG)?.parse().ok()?;
+        let shortcuts = tags
+            .get(TIME_BUDGET_SHORTCUTS_TAG)
+            .map(|value| value.split(',').filter_map(Shortcut::parse).collect())
+            .unwrap_or_default();
+        Some(Self {
+            budget_ms,
+            shortcuts,
+        })
+    }
+
+    /// One-line summary of the shortcuts, if any were taken
+    pub fn summary(&self) -> Option<String> {
+        if self.shortcuts.is_empty() {
+            return None;
+        }
+        let notes: Vec<&str> = self.shortcuts.iter().map(Shortcut::description).collect();
+        Some(format!(
+            "To fit the {}ms time budget: {}",
+            self.budget_ms,
+            notes.join("; ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_types::{AudienceContext, DomainContext, PromptBudget, ResearchType};
+
+    #[test]
+    fn test_plan_tightens_with_budget_and_reports_shortcuts() {
+        let mut request = ClassifiedRequest::new(
+            "How do I use tokio?".to_string(),
+            ResearchType::Learning,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        );
+        request.prompt_budget = Some(PromptBudget {
+            profile: "standard".to_string(),
+            max_context_docs: 5,
+            max_answer_tokens: 4000,
+        });
+
+        let generous = TimeBudgetPlan::new(Duration::from_secs(120));
+        assert!(generous
+            .apply(&mut request.clone(), true, true, true)
+            .is_empty());
+
+        let moderate = TimeBudgetPlan::new(Duration::from_secs(20));
+        let mut moderate_request = request.clone();
+        assert_eq!(
+            moderate.apply(&mut moderate_request, true, true, true),
+            vec![
+                Shortcut::SkippedCrossValidation,
+                Shortcut::ReducedContextDepth,
+                Shortcut::CappedAnswerTokens
+            ]
+        );
+        assert_eq!(
+            moderate_request
+                .prompt_budget
+                .as_ref()
+                .unwrap()
+                .max_context_docs,
+            2
+        );
+
+        let tight = TimeBudgetPlan::new(Duration::from_secs(5));
+        let shortcuts = tight.apply(&mut request, false, true, true);
+        assert_eq!(
+            shortcuts,
+            vec![
+                Shortcut::SkippedContextDiscovery,
+                Shortcut::SkippedEvidenceScoring,
+                Shortcut::CappedAnswerTokens
+            ]
+        );
+        assert_eq!(
+            request.prompt_budget.as_ref().unwrap().max_answer_tokens,
+            1000
+        );
+
+        let report = TimeBudgetReport {
+            budget_ms: 5000,
+            shortcuts,
+        };
+        let mut tags = HashMap::new();
+        report.apply_to_tags(&mut tags);
+        assert_eq!(TimeBudgetReport::from_tags(&tags), Some(report.clone()));
+        assert!(report
+            .summary()
+            .unwrap()
+            .starts_with("To fit the 5000ms time budget: related research was not retrieved"));
+    }
+}
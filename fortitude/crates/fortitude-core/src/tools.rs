@@ -0,0 +1,404 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Tools that tool-calling providers can invoke while answering a research query
+// Defines tool schemas, the conversation turns of a tool-call loop, a registry and built-in local search and crate lookup tools
+use async_trait::async_trait;
+use fortitude_types::{SearchQuery, Storage};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tracing::{debug, warn};
+
+/// Default number of tool-call rounds before the model must answer
+pub const DEFAULT_MAX_TOOL_ROUNDS: usize = 5;
+
+/// Schema of a tool as offered to the model
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolDefinition {
+    pub name: String,
+    pub description: String,
+    /// JSON Schema of the arguments object
+    pub input_schema: Value,
+}
+
+/// A tool invocation requested by the model
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolCall {
+    /// Provider-assigned identifier echoed back with the output
+    pub id: String,
+    pub name: String,
+    pub arguments: Value,
+}
+
+/// Output of a tool call, sent back to the model
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolOutput {
+    pub call_id: String,
+    pub content: String,
+    /// The tool failed; `content` holds the error message
+    pub is_error: bool,
+}
+
+/// One model response in a tool-calling conversation
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToolTurn {
+    pub text: String,
+    /// Tools the model wants called before it continues; empty for a final answer
+    pub tool_calls: Vec<ToolCall>,
+}
+
+/// Message in a tool-calling conversation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ToolMessage {
+    User(String),
+    Assistant(ToolTurn),
+    ToolResults(Vec<ToolOutput>),
+}
+
+/// Errors raised by tools
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ToolError {
+    #[error("Unknown tool: {0}")]
+    UnknownTool(String),
+    #[error("Invalid arguments for tool {tool}: {message}")]
+    InvalidArguments { tool: String, message: String },
+    #[error("Tool {tool} failed: {message}")]
+    Failed { tool: String, message: String },
+}
+
+/// A tool that can be offered to tool-calling providers
+#[async_trait]
+pub trait ResearchTool: Send + Sync {
+    fn definition(&self) -> ToolDefinition;
+
+    /// Run the tool and return text for the model
+    async fn call(&self, arguments: Value) -> Result<String, ToolError>;
+}
+
+/// Tools offered to the model, in registration order
+#[derive(Clone)]
+pub struct ToolRegistry {
+    tools: Vec<Arc<dyn ResearchTool>>,
+    max_rounds: usize,
+}
+
+impl Default for ToolRegistry {
+    fn default() -> Self {
+        Self {
+            tools: Vec::new(),
+            max_rounds: DEFAULT_MAX_TOOL_ROUNDS,
+        }
+    }
+}
+
+impl std::fmt::Debug for ToolRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToolRegistry")
+            .field("tools", &self.names())
+            .field("max_rounds", &self.max_rounds)
+            .finish()
+    }
+}
+
+impl ToolRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a tool, replacing any earlier tool with the same name
+    pub fn with_tool(mut self, tool: Arc<dyn ResearchTool>) -> Self {
+        self.register(tool);
+        self
+    }
+
+    pub fn with_max_rounds(mut self, max_rounds: usize) -> Self {
+        self.max_rounds = max_rounds;
+        self
+    }
+
+    /// Add a tool, replacing any earlier tool with the same name
+    pub fn register(&mut self, tool: Arc<dyn ResearchTool>) {
+        let name = tool.definition().name;
+        self.tools.retain(|t| t.definition().name != name);
+        self.tools.push(tool);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tools.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tools.len()
+    }
+
+    /// Tool-call rounds allowed before the loop gives up
+    pub fn max_rounds(&self) -> usize {
+        self.max_rounds
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.tools.iter().map(|t| t.definition().name).collect()
+    }
+
+    pub fn definitions(&self) -> Vec<ToolDefinition> {
+        self.tools.iter().map(|t| t.definition()).collect()
+    }
+
+    /// Run a call, turning failures into error outputs the model can read
+    pub async fn execute(&self, call: &ToolCall) -> ToolOutput {
+        let tool = self.tools.iter().find(|t| t.definition().name == call.name);
+        let result = match tool {
+            Some(tool) => tool.call(call.arguments.clone()).await,
+            None => Err(ToolError::UnknownTool(call.name.clone())),
+        };
+        match result {
+            Ok(content) => {
+                debug!("Tool {} returned {} bytes", call.name, content.len());
+                ToolOutput {
+                    call_id: call.id.clone(),
+                    content,
+                    is_error: false,
+                }
+            }
+            Err(e) => {
+                warn!("Tool call failed: {}", e);
+                ToolOutput {
+                    call_id: call.id.clone(),
+                    content: e.to_string(),
+                    is_error: true,
+                }
+            }
+        }
+    }
+}
+
+fn string_argument(tool: &str, arguments: &Value, name: &str) -> Result<String, ToolError> {
+    arguments
+        .get(name)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| ToolError::InvalidArguments {
+            tool: tool.to_string(),
+            message: format!("missing string argument '{name}'"),
+        })
+}
+
+/// Searches earlier research results in local storage
+pub struct LocalSearchTool {
+    storage: Arc<dyn Storage + Send + Sync>,
+    limit: usize,
+}
+
+impl LocalSearchTool {
+    pub const NAME: &'static str = "local_search";
+
+    pub fn new(storage: Arc<dyn Storage + Send + Sync>) -> Self {
+        Self { storage, limit: 5 }
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit.max(1);
+        self
+    }
+}
+
+#[async_trait]
+impl ResearchTool for LocalSearchTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: Self::NAME.to_string(),
+            description: "Search earlier research results stored locally".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "query": {"type": "string", "description": "Search text"},
+                    "limit": {"type": "integer", "minimum": 1, "description": "Maximum results"}
+                },
+                "required": ["query"]
+            }),
+        }
+    }
+
+    async fn call(&self, arguments: Value) -> Result<String, ToolError> {
+        let query = string_argument(Self::NAME, &arguments, "query")?;
+        let limit = arguments
+            .get("limit")
+            .and_then(Value::as_u64)
+            .map_or(self.limit, |limit| (limit as usize).clamp(1, self.limit));
+        let results = self
+            .storage
+            .search(&SearchQuery::new(query).with_limit(limit))
+            .await
+            .map_err(|e| ToolError::Failed {
+                tool: Self::NAME.to_string(),
+                message: e.to_string(),
+            })?;
+        if results.is_empty() {
+            return Ok("No stored research matched.".to_string());
+        }
+        let lines: Vec<String> = results
+            .iter()
+            .map(|result| {
+                format!(
+                    "- [{}] {} ({:.2}): {}",
+                    result.entry.cache_key,
+                    result.entry.original_query,
+                    result.relevance_score,
+                    result.snippet
+                )
+            })
+            .collect();
+        Ok(lines.join("\n"))
+    }
+}
+
+/// Looks up a Rust crate on a crates.io compatible registry
+pub struct CrateLookupTool {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl Default for CrateLookupTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CrateLookupTool {
+    pub const NAME: &'static str = "crate_lookup";
+
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .user_agent(concat!("fortitude/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .unwrap_or_default();
+        Self {
+            client,
+            base_url: "https://crates.io/api/v1".to_string(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+}
+
+#[async_trait]
+impl ResearchTool for CrateLookupTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: Self::NAME.to_string(),
+            description: "Look up the latest version, description and links of a Rust crate"
+                .to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "name": {"type": "string", "description": "Crate name"}
+                },
+                "required": ["name"]
+            }),
+        }
+    }
+
+    async fn call(&self, arguments: Value) -> Result<String, ToolError> {
+        let name = string_argument(Self::NAME, &arguments, "name")?;
+        let failed = |message: String| ToolError::Failed {
+            tool: Self::NAME.to_string(),
+            message,
+        };
+        let response = self
+            .client
+            .get(format!("{}/crates/{}", self.base_url, name))
+            .send()
+            .await
+            .map_err(|e| failed(e.to_string()))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(format!("No crate named '{name}' exists."));
+        }
+        let body: Value = response
+            .error_for_status()
+            .map_err(|e| failed(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| failed(e.to_string()))?;
+        let krate = &body["crate"];
+        let field = |key: &str| krate[key].as_str().unwrap_or("unknown").to_string();
+        Ok(format!(
+            "{} {}: {}\ndocumentation: {}\nrepository: {}\ndownloads: {}",
+            name,
+            field("max_stable_version"),
+            field("description").trim(),
+            field("documentation"),
+            field("repository"),
+            krate["downloads"].as_u64().unwrap_or(0)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct EchoTool;
+
+    #[async_trait]
+    impl ResearchTool for EchoTool {
+        fn definition(&self) -> ToolDefinition {
+            ToolDefinition {
+                name: "echo".to_string(),
+                description: "Echo the text argument".to_string(),
+                input_schema: json!({"type": "object"}),
+            }
+        }
+
+        async fn call(&self, arguments: Value) -> Result<String, ToolError> {
+            string_argument("echo", &arguments, "text")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registry_executes_and_reports_errors() {
+        let registry = ToolRegistry::new()
+            .with_tool(Arc::new(EchoTool))
+            .with_tool(Arc::new(EchoTool))
+            .with_max_rounds(2);
+        assert_eq!(registry.names(), vec!["echo"]);
+        assert_eq!(registry.max_rounds(), 2);
+
+        let call = |name: &str, arguments: Value| ToolCall {
+            id: "call-1".to_string(),
+            name: name.to_string(),
+            arguments,
+        };
+        let output = registry.execute(&call("echo", json!({"text": "hi"}))).await;
+        assert_eq!(output.content, "hi");
+        assert!(!output.is_error);
+        assert_eq!(output.call_id, "call-1");
+
+        let output = registry.execute(&call("echo", json!({}))).await;
+        assert!(output.is_error);
+        assert!(output.content.contains("missing string argument 'text'"));
+
+        let output = registry.execute(&call("missing", json!({}))).await;
+        assert!(output.is_error);
+        assert_eq!(output.content, "Unknown tool: missing");
+    }
+}
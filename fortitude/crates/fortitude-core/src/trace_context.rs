@@ -0,0 +1,77 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Request and distributed-trace identifiers carried through a research query
+// Recorded in result metadata so a stored result can be matched to the request and trace that produced it
+use std::collections::HashMap;
+
+/// Metadata tag holding the ID of the request that produced a result
+pub const REQUEST_ID_TAG: &str = "request_id";
+
+/// Metadata tag holding the distributed trace ID of that request
+pub const TRACE_ID_TAG: &str = "trace_id";
+
+/// Identifiers of the request a research query runs for
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TraceContext {
+    /// Caller-visible request ID, e.g. the `x-request-id` header
+    pub request_id: Option<String>,
+    /// W3C trace ID (32 hex characters) when the request is traced
+    pub trace_id: Option<String>,
+}
+
+impl TraceContext {
+    pub fn new(request_id: impl Into<String>) -> Self {
+        Self {
+            request_id: Some(request_id.into()),
+            trace_id: None,
+        }
+    }
+
+    pub fn with_trace_id(mut self, trace_id: impl Into<String>) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+
+    /// Record the identifiers in result metadata tags
+    pub fn apply_to_tags(&self, tags: &mut HashMap<String, String>) {
+        if let Some(request_id) = &self.request_id {
+            tags.insert(REQUEST_ID_TAG.to_string(), request_id.clone());
+        }
+        if let Some(trace_id) = &self.trace_id {
+            tags.insert(TRACE_ID_TAG.to_string(), trace_id.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_to_tags_records_present_ids() {
+        let mut tags = HashMap::new();
+        TraceContext::default().apply_to_tags(&mut tags);
+        assert!(tags.is_empty());
+
+        TraceContext::new("req-1")
+            .with_trace_id("4bf92f3577b34da6a3ce929d0e0e4736")
+            .apply_to_tags(&mut tags);
+        assert_eq!(tags.get(REQUEST_ID_TAG).map(String::as_str), Some("req-1"));
+        assert_eq!(
+            tags.get(TRACE_ID_TAG).map(String::as_str),
+            Some("4bf92f3577b34da6a3ce929d0e0e4736")
+        );
+    }
+}
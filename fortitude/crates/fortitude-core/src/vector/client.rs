@@ -16,18 +16,25 @@
 use crate::api::{ApiClient, HealthStatus, RequestCost};
 use crate::vector::{VectorConfig, VectorError, VectorResult};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use qdrant_client::config::QdrantConfig;
 use qdrant_client::qdrant::vectors_config::Config;
 use qdrant_client::qdrant::{
-    CreateCollection, Distance, HealthCheckReply, VectorParams, VectorsConfig,
+    CollectionInfo, CollectionStatus, CreateCollection, CreateFieldIndexCollectionBuilder,
+    DeleteFieldIndexCollectionBuilder, Distance, FieldType, HealthCheckReply,
+    OptimizersConfigDiffBuilder, PayloadSchemaType, ScrollPointsBuilder, UpdateCollectionBuilder,
+    VectorParams, VectorsConfig,
 };
 use qdrant_client::Qdrant;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
+
+/// Points read per page when summarizing a collection
+const ANALYTICS_PAGE_SIZE: u32 = 256;
 
 /// Vector database client for Qdrant operations
 pub struct QdrantClient {
@@ -63,6 +70,80 @@ impl Default for ClientHealthStatus {
     }
 }
 
+/// Point, segment and index counts reported for a collection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectionStats {
+    pub collection_name: String,
+    /// Qdrant collection status (green, yellow, grey, red)
+    pub status: String,
+    /// Optimizer failure, `None` while the optimizers are healthy
+    pub optimizer_error: Option<String>,
+    pub points_count: u64,
+    /// Vectors already in the HNSW index; small segments are searched without one
+    pub indexed_vectors_count: u64,
+    pub segments_count: u64,
+    /// Payload index data type by field name
+    pub payload_indexes: BTreeMap<String, String>,
+}
+
+/// Documents of a collection grouped by research and content type
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CollectionAnalytics {
+    pub total_documents: usize,
+    /// Documents stored since the start of the reporting period
+    pub recent_documents: usize,
+    /// Recent documents by research type
+    pub by_research_type: BTreeMap<String, usize>,
+    /// Recent documents by content type
+    pub by_content_type: BTreeMap<String, usize>,
+    /// Mean quality score of recent documents that have one
+    pub average_quality: Option<f64>,
+    #[serde(skip)]
+    quality_total: f64,
+    #[serde(skip)]
+    quality_count: usize,
+}
+
+impl CollectionAnalytics {
+    /// Count one point payload, as written by vector storage
+    pub fn record(
+        &mut self,
+        payload: &serde_json::Map<String, serde_json::Value>,
+        since: DateTime<Utc>,
+    ) {
+        self.total_documents += 1;
+        let stored_at = payload
+            .get("stored_at")
+            .and_then(|v| v.as_str())
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok());
+        if stored_at.is_none_or(|stored_at| stored_at < since) {
+            return;
+        }
+
+        self.recent_documents += 1;
+        let label = |key: &str| {
+            payload
+                .get(key)
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown")
+                .to_string()
+        };
+        *self
+            .by_research_type
+            .entry(label("research_type"))
+            .or_default() += 1;
+        *self
+            .by_content_type
+            .entry(label("content_type"))
+            .or_default() += 1;
+        if let Some(quality) = payload.get("quality_score").and_then(|v| v.as_f64()) {
+            self.quality_total += quality;
+            self.quality_count += 1;
+            self.average_quality = Some(self.quality_total / self.quality_count as f64);
+        }
+    }
+}
+
 /// Request type for vector operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VectorRequest {
@@ -95,6 +176,18 @@ pub struct VectorResponse {
 impl QdrantClient {
     /// Create a new Qdrant client
     pub async fn new(config: VectorConfig) -> VectorResult<Self> {
+        let qdrant_client = Self::new_lazy(config)?;
+
+        // Perform initial health check
+        qdrant_client.perform_health_check().await?;
+
+        info!("Successfully connected to Qdrant vector database");
+        Ok(qdrant_client)
+    }
+
+    /// Create a client without contacting the server, for endpoints that
+    /// may be unavailable at startup
+    pub fn new_lazy(config: VectorConfig) -> VectorResult<Self> {
         config.validate()?;
 
         info!("Connecting to Qdrant at: {}", config.url);
@@ -110,17 +203,11 @@ impl QdrantClient {
             VectorError::from_connection_error(format!("Failed to create Qdrant client: {e}"))
         })?;
 
-        let qdrant_client = Self {
+        Ok(Self {
             client,
             config,
             health_status: Arc::new(RwLock::new(ClientHealthStatus::default())),
-        };
-
-        // Perform initial health check
-        qdrant_client.perform_health_check().await?;
-
-        info!("Successfully connected to Qdrant vector database");
-        Ok(qdrant_client)
+        })
     }
 
     /// Create a collection if it doesn't exist
@@ -232,7 +319,18 @@ impl QdrantClient {
     ) -> VectorResult<serde_json::Value> {
         debug!("Getting collection info for: {}", collection_name);
 
-        let _info = self
+        self.fetch_collection_info(collection_name).await?;
+
+        // For now, return a simple JSON representation
+        // In the future, this can be enhanced with proper serialization
+        Ok(serde_json::json!({
+            "collection_name": collection_name,
+            "status": "exists"
+        }))
+    }
+
+    async fn fetch_collection_info(&self, collection_name: &str) -> VectorResult<CollectionInfo> {
+        let response = self
             .client
             .collection_info(collection_name)
             .await
@@ -247,13 +345,147 @@ impl QdrantClient {
                     format!("Failed to get collection info: {e}"),
                 ),
             })?;
+        response.result.ok_or_else(|| {
+            VectorError::from_operation_failed(
+                "get_collection_info",
+                format!("Qdrant returned no info for collection {collection_name}"),
+            )
+        })
+    }
 
-        // For now, return a simple JSON representation
-        // In the future, this can be enhanced with proper serialization
-        Ok(serde_json::json!({
-            "collection_name": collection_name,
-            "status": "exists"
-        }))
+    /// Point, segment and index counts of a collection
+    pub async fn collection_stats(&self, collection_name: &str) -> VectorResult<CollectionStats> {
+        let info = self.fetch_collection_info(collection_name).await?;
+        let status = CollectionStatus::try_from(info.status)
+            .unwrap_or(CollectionStatus::UnknownCollectionStatus);
+        let payload_indexes = info
+            .payload_schema
+            .iter()
+            .map(|(field, schema)| {
+                let data_type = PayloadSchemaType::try_from(schema.data_type)
+                    .unwrap_or(PayloadSchemaType::UnknownType);
+                (field.clone(), data_type.as_str_name().to_lowercase())
+            })
+            .collect();
+
+        Ok(CollectionStats {
+            collection_name: collection_name.to_string(),
+            status: status.as_str_name().to_lowercase(),
+            optimizer_error: info
+                .optimizer_status
+                .filter(|optimizer| !optimizer.ok)
+                .map(|optimizer| optimizer.error),
+            points_count: info.points_count.unwrap_or_default(),
+            indexed_vectors_count: info.indexed_vectors_count.unwrap_or_default(),
+            segments_count: info.segments_count,
+            payload_indexes,
+        })
+    }
+
+    /// Summarize the documents of a collection, counting those stored at or
+    /// after `since` by type. Payloads are read page by page without vectors.
+    pub async fn collection_analytics(
+        &self,
+        collection_name: &str,
+        since: DateTime<Utc>,
+    ) -> VectorResult<CollectionAnalytics> {
+        let mut analytics = CollectionAnalytics::default();
+        let mut offset = None;
+        loop {
+            let mut request = ScrollPointsBuilder::new(collection_name)
+                .limit(ANALYTICS_PAGE_SIZE)
+                .with_payload(true)
+                .with_vectors(false);
+            if let Some(offset) = offset.take() {
+                request = request.offset(offset);
+            }
+            let response = self
+                .client
+                .scroll(request)
+                .await
+                .map_err(|e| VectorError::from_operation_failed("scroll", e.to_string()))?;
+            for point in response.result {
+                let payload = point
+                    .payload
+                    .into_iter()
+                    .map(|(key, value)| (key, value.into()))
+                    .collect();
+                analytics.record(&payload, since);
+            }
+            match response.next_page_offset {
+                Some(next) => offset = Some(next),
+                None => break,
+            }
+        }
+        Ok(analytics)
+    }
+
+    /// Drop and re-create every payload index of a collection with its
+    /// current type and parameters, returning the rebuilt field names
+    pub async fn rebuild_payload_indexes(
+        &self,
+        collection_name: &str,
+    ) -> VectorResult<Vec<String>> {
+        let info = self.fetch_collection_info(collection_name).await?;
+        let index_error = |e: qdrant_client::QdrantError| {
+            VectorError::from_operation_failed(
+                "rebuild_payload_index",
+                format!("Failed to rebuild payload index on {collection_name}: {e}"),
+            )
+        };
+
+        let mut rebuilt = Vec::new();
+        for (field, schema) in info.payload_schema {
+            let Some(field_type) = field_type(schema.data_type) else {
+                warn!("Skipping payload index {} with an unknown type", field);
+                continue;
+            };
+            self.client
+                .delete_field_index(
+                    DeleteFieldIndexCollectionBuilder::new(collection_name, &field).wait(true),
+                )
+                .await
+                .map_err(index_error)?;
+            let mut request =
+                CreateFieldIndexCollectionBuilder::new(collection_name, &field, field_type)
+                    .wait(true);
+            if let Some(params) = schema.params.and_then(|params| params.index_params) {
+                request = request.field_index_params(params);
+            }
+            self.client
+                .create_field_index(request)
+                .await
+                .map_err(index_error)?;
+            rebuilt.push(field);
+        }
+        rebuilt.sort();
+
+        info!(
+            "Rebuilt {} payload indexes on {}",
+            rebuilt.len(),
+            collection_name
+        );
+        Ok(rebuilt)
+    }
+
+    /// Restart the collection's optimizers, which merge small segments and
+    /// build the vector index for segments past the indexing threshold
+    pub async fn optimize_collection(&self, collection_name: &str) -> VectorResult<()> {
+        self.fetch_collection_info(collection_name).await?;
+        self.client
+            .update_collection(
+                UpdateCollectionBuilder::new(collection_name)
+                    .optimizers_config(OptimizersConfigDiffBuilder::default()),
+            )
+            .await
+            .map_err(|e| {
+                VectorError::from_operation_failed(
+                    "optimize_collection",
+                    format!("Failed to optimize collection {collection_name}: {e}"),
+                )
+            })?;
+        info!("Triggered optimization of collection: {}", collection_name);
+        Ok(())
     }
 
     /// Delete a collection
@@ -296,6 +528,22 @@ impl QdrantClient {
     }
 }
 
+/// Field index type matching a payload schema type
+fn field_type(data_type: i32) -> Option<FieldType> {
+    let field_type = match PayloadSchemaType::try_from(data_type).ok()? {
+        PayloadSchemaType::Keyword => FieldType::Keyword,
+        PayloadSchemaType::Integer => FieldType::Integer,
+        PayloadSchemaType::Float => FieldType::Float,
+        PayloadSchemaType::Geo => FieldType::Geo,
+        PayloadSchemaType::Text => FieldType::Text,
+        PayloadSchemaType::Bool => FieldType::Bool,
+        PayloadSchemaType::Datetime => FieldType::Datetime,
+        PayloadSchemaType::Uuid => FieldType::Uuid,
+        PayloadSchemaType::UnknownType => return None,
+    };
+    Some(field_type)
+}
+
 #[async_trait]
 impl ApiClient for QdrantClient {
     type Request = VectorRequest;
@@ -440,6 +688,7 @@ mod tests {
             },
             connection_pool: ConnectionPoolConfig::default(),
             embedding: EmbeddingConfig::default(),
+            replication: Default::default(),
         }
     }
 
@@ -844,4 +1093,37 @@ mod tests {
             assert_eq!(data.get("collection").unwrap(), "new_collection");
         }
     }
+
+    #[test]
+    fn test_collection_analytics_counts_recent_documents_by_type() {
+        let since = chrono::Utc::now() - chrono::Duration::days(7);
+        let payload = |stored_at: DateTime<Utc>, research_type: &str, quality: f64| {
+            serde_json::json!({
+                "stored_at": stored_at.to_rfc3339(),
+                "research_type": research_type,
+                "content_type": "research_result",
+                "quality_score": quality,
+            })
+            .as_object()
+            .cloned()
+            .unwrap()
+        };
+
+        let mut analytics = CollectionAnalytics::default();
+        analytics.record(&payload(Utc::now(), "learning", 0.9), since);
+        analytics.record(&payload(Utc::now(), "learning", 0.7), since);
+        analytics.record(&payload(Utc::now(), "decision", 0.5), since);
+        analytics.record(
+            &payload(since - chrono::Duration::days(1), "decision", 0.1),
+            since,
+        );
+        analytics.record(&serde_json::Map::new(), since);
+
+        assert_eq!(analytics.total_documents, 5);
+        assert_eq!(analytics.recent_documents, 3);
+        assert_eq!(analytics.by_research_type["learning"], 2);
+        assert_eq!(analytics.by_research_type["decision"], 1);
+        assert_eq!(analytics.by_content_type["research_result"], 3);
+        assert!((analytics.average_quality.unwrap() - 0.7).abs() < 1e-9);
+    }
 }
@@ -15,6 +15,7 @@
 // ABOUTME: Configuration for vector database operations
 use crate::vector::embeddings::EmbeddingConfig;
 use crate::vector::error::{VectorError, VectorResult};
+use crate::vector::replicas::ReplicationConfig;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use tracing::warn;
@@ -40,10 +41,13 @@ pub struct VectorConfig {
     pub connection_pool: ConnectionPoolConfig,
     /// Embedding generation configuration
     pub embedding: EmbeddingConfig,
+    /// Read replicas and primary write buffering
+    #[serde(default)]
+    pub replication: ReplicationConfig,
 }
 
 /// Distance metrics supported by Qdrant
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum DistanceMetric {
     #[default]
     Cosine,
@@ -118,6 +122,7 @@ impl Default for VectorConfig {
             health_check: HealthCheckConfig::default(),
             connection_pool: ConnectionPoolConfig::default(),
             embedding: EmbeddingConfig::default(),
+            replication: ReplicationConfig::default(),
         }
     }
 }
@@ -149,6 +154,8 @@ impl VectorConfig {
             ));
         }
 
+        self.replication.validate()?;
+
         // Validate that vector dimensions match embedding model output
         if self.vector_dimensions != self.embedding.max_sequence_length.min(2048) {
             warn!(
@@ -202,6 +209,12 @@ impl VectorConfig {
         self
     }
 
+    /// Create configuration with read replicas and write buffering settings
+    pub fn with_replication(mut self, replication: ReplicationConfig) -> Self {
+        self.replication = replication;
+        self
+    }
+
     /// Update vector dimensions to match embedding model
     pub fn sync_dimensions_with_embedding(&mut self) {
         // For sentence-transformers models, common dimensions are 384, 512, 768
@@ -483,6 +496,7 @@ mod tests {
                 connection_timeout: Duration::from_secs(15),
             },
             embedding: EmbeddingConfig::default(),
+            replication: ReplicationConfig::default(),
         };
 
         assert!(config.validate().is_ok());
@@ -674,6 +674,7 @@ mod tests {
             },
             connection_pool: crate::vector::ConnectionPoolConfig::default(),
             embedding: crate::vector::EmbeddingConfig::default(),
+            replication: Default::default(),
         };
 
         let monitor = PerformanceMonitor::new(MonitoringConfig::default());
@@ -705,6 +706,7 @@ mod tests {
             },
             connection_pool: crate::vector::ConnectionPoolConfig::default(),
             embedding: crate::vector::EmbeddingConfig::default(),
+            replication: Default::default(),
         };
 
         // This would normally require an actual QdrantClient
@@ -0,0 +1,960 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Pluggable embedding backends selected through `vector.embedding` configuration
+//! This module defines the [`EmbeddingProvider`] trait and its backends: a
+//! deterministic hash backend for development, a local ONNX backend for
+//! air-gapped deployments and an OpenAI-compatible HTTP backend.
+
+use crate::vector::embeddings::EmbeddingConfig;
+use crate::vector::error::{VectorError, VectorResult};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tracing::{debug, info};
+
+/// Output dimension of the hash backend and of all-MiniLM-L6-v2
+pub const DEFAULT_EMBEDDING_DIMENSION: usize = 384;
+
+/// Default endpoint of the OpenAI embeddings API
+pub const OPENAI_API_URL: &str = "https://api.openai.com/v1";
+
+/// Embedding backend to use
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmbeddingProviderKind {
+    /// Deterministic hash vectors; no model, no semantic similarity
+    #[default]
+    Hash,
+    /// Local ONNX sentence-transformer model
+    Onnx,
+    /// OpenAI embeddings API or any server exposing the same endpoint
+    #[serde(rename = "openai")]
+    OpenAi,
+}
+
+impl std::fmt::Display for EmbeddingProviderKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Hash => "hash",
+            Self::Onnx => "onnx",
+            Self::OpenAi => "openai",
+        })
+    }
+}
+
+impl std::str::FromStr for EmbeddingProviderKind {
+    type Err = VectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "hash" => Ok(Self::Hash),
+            "onnx" => Ok(Self::Onnx),
+            "openai" => Ok(Self::OpenAi),
+            other => Err(VectorError::ConfigurationError(format!(
+                "Unknown embedding provider '{other}', expected hash, onnx or openai"
+            ))),
+        }
+    }
+}
+
+/// Backend-specific embedding settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmbeddingBackendConfig {
+    /// Which backend generates embeddings
+    pub provider: EmbeddingProviderKind,
+    /// ONNX model file (onnx)
+    pub model_path: Option<PathBuf>,
+    /// `tokenizer.json` or `vocab.txt`; defaults to the model's directory (onnx)
+    pub tokenizer_path: Option<PathBuf>,
+    /// Base URL of the embeddings API (openai)
+    pub api_url: String,
+    /// API key; may be empty for self-hosted servers (openai)
+    pub api_key: Option<String>,
+    /// Output dimension; discovered from the model when not set
+    pub dimensions: Option<usize>,
+    /// Request timeout in seconds (openai)
+    pub timeout_seconds: u64,
+}
+
+impl Default for EmbeddingBackendConfig {
+    fn default() -> Self {
+        Self {
+            provider: EmbeddingProviderKind::default(),
+            model_path: None,
+            tokenizer_path: None,
+            api_url: OPENAI_API_URL.to_string(),
+            api_key: None,
+            dimensions: None,
+            timeout_seconds: 30,
+        }
+    }
+}
+
+/// A backend that turns text into vectors
+///
+/// Implementations receive already-preprocessed text; caching and batching
+/// are handled by [`LocalEmbeddingService`](crate::vector::LocalEmbeddingService).
+#[async_trait]
+pub trait EmbeddingProvider: Send + Sync {
+    /// Short backend name for logs and diagnostics
+    fn name(&self) -> &str;
+
+    /// Output dimension, or 0 while it is not yet known
+    fn dimension(&self) -> usize;
+
+    /// Load models or check connectivity before the first request
+    async fn initialize(&self) -> VectorResult<()> {
+        Ok(())
+    }
+
+    /// Embed a batch of texts, returning one vector per input in order
+    async fn embed(&self, texts: &[String]) -> VectorResult<Vec<Vec<f32>>>;
+}
+
+/// Build the provider selected by the configuration
+pub fn create_embedding_provider(config: &EmbeddingConfig) -> Arc<dyn EmbeddingProvider> {
+    let backend = &config.backend;
+    match backend.provider {
+        EmbeddingProviderKind::Hash => Arc::new(HashEmbeddingProvider::new(
+            backend.dimensions.unwrap_or(DEFAULT_EMBEDDING_DIMENSION),
+        )),
+        EmbeddingProviderKind::Onnx => Arc::new(OnnxEmbeddingProvider::new(
+            backend,
+            config.max_sequence_length,
+            config.preprocessing.lowercase,
+        )),
+        EmbeddingProviderKind::OpenAi => {
+            Arc::new(OpenAiEmbeddingProvider::new(backend, &config.model_name))
+        }
+    }
+}
+
+/// Scale a vector to unit length in place
+pub fn normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for value in vector.iter_mut() {
+            *value /= norm;
+        }
+    }
+}
+
+/// Deterministic pseudo-random vectors derived from a hash of the text
+///
+/// Identical texts map to identical vectors, which is enough for tests and
+/// exact-match lookups but carries no semantic similarity.
+#[derive(Debug, Clone)]
+pub struct HashEmbeddingProvider {
+    dimension: usize,
+}
+
+impl HashEmbeddingProvider {
+    pub fn new(dimension: usize) -> Self {
+        Self { dimension }
+    }
+
+    fn embed_one(&self, text: &str) -> Vec<f32> {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        let mut rng_state = hasher.finish();
+
+        let mut embedding: Vec<f32> = (0..self.dimension)
+            .map(|_| {
+                // Simple linear congruential generator for deterministic pseudo-randomness
+                rng_state = rng_state.wrapping_mul(1103515245).wrapping_add(12345);
+                (rng_state % 1000) as f32 / 1000.0 - 0.5
+            })
+            .collect();
+        normalize(&mut embedding);
+        embedding
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for HashEmbeddingProvider {
+    fn name(&self) -> &str {
+        "hash"
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    async fn initialize(&self) -> VectorResult<()> {
+        // Simulate model loading time so callers exercise the same code paths
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        Ok(())
+    }
+
+    async fn embed(&self, texts: &[String]) -> VectorResult<Vec<Vec<f32>>> {
+        // Simulate some processing time
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        Ok(texts.iter().map(|text| self.embed_one(text)).collect())
+    }
+}
+
+/// Token ids and attention mask for one input
+#[derive(Debug, Clone, PartialEq)]
+pub struct Encoding {
+    pub input_ids: Vec<i64>,
+    pub attention_mask: Vec<i64>,
+}
+
+/// BERT-style WordPiece tokenizer loaded from `vocab.txt` or `tokenizer.json`
+#[derive(Debug, Clone)]
+pub struct WordPieceTokenizer {
+    vocab: HashMap<String, i64>,
+    unk_token: String,
+    continuing_prefix: String,
+    lowercase: bool,
+}
+
+impl WordPieceTokenizer {
+    const CLS: &'static str = "[CLS]";
+    const SEP: &'static str = "[SEP]";
+    /// Words longer than this become a single unknown token
+    const MAX_WORD_CHARS: usize = 100;
+
+    pub fn from_vocab(tokens: impl IntoIterator<Item = String>, lowercase: bool) -> Self {
+        Self {
+            vocab: tokens.into_iter().zip(0..).collect(),
+            unk_token: "[UNK]".to_string(),
+            continuing_prefix: "##".to_string(),
+            lowercase,
+        }
+    }
+
+    /// Load a `vocab.txt` (one token per line) or a Hugging Face `tokenizer.json`
+    pub fn from_file(path: &Path, lowercase: bool) -> VectorResult<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            VectorError::TokenizationError(format!("Cannot read tokenizer {}: {e}", path.display()))
+        })?;
+        if path.extension().is_some_and(|ext| ext == "json") {
+            Self::from_tokenizer_json(&content, lowercase)
+        } else {
+            Ok(Self::from_vocab(
+                content.lines().map(|line| line.trim_end().to_string()),
+                lowercase,
+            ))
+        }
+    }
+
+    fn from_tokenizer_json(content: &str, lowercase: bool) -> VectorResult<Self> {
+        let parsed: Value = serde_json::from_str(content)
+            .map_err(|e| VectorError::TokenizationError(format!("Invalid tokenizer.json: {e}")))?;
+        let model = &parsed["model"];
+        if model["type"]
+            .as_str()
+            .is_some_and(|kind| kind != "WordPiece")
+        {
+            return Err(VectorError::TokenizationError(format!(
+                "Unsupported tokenizer model {}, only WordPiece is supported",
+                model["type"]
+            )));
+        }
+        let vocab = model["vocab"]
+            .as_object()
+            .ok_or_else(|| {
+                VectorError::TokenizationError("tokenizer.json has no model.vocab".to_string())
+            })?
+            .iter()
+            .filter_map(|(token, id)| Some((token.clone(), id.as_i64()?)))
+            .collect();
+        Ok(Self {
+            vocab,
+            unk_token: model["unk_token"].as_str().unwrap_or("[UNK]").to_string(),
+            continuing_prefix: model["continuing_subword_prefix"]
+                .as_str()
+                .unwrap_or("##")
+                .to_string(),
+            lowercase: parsed["normalizer"]["lowercase"]
+                .as_bool()
+                .unwrap_or(lowercase),
+        })
+    }
+
+    fn id(&self, token: &str) -> Option<i64> {
+        self.vocab.get(token).copied()
+    }
+
+    fn unk_id(&self) -> i64 {
+        self.id(&self.unk_token).unwrap_or(0)
+    }
+
+    /// Split on whitespace and make every punctuation character its own word
+    fn basic_tokens(&self, text: &str) -> Vec<String> {
+        let text = if self.lowercase {
+            text.to_lowercase()
+        } else {
+            text.to_string()
+        };
+        let mut words = Vec::new();
+        for chunk in text.split_whitespace() {
+            let mut current = String::new();
+            for c in chunk.chars() {
+                if c.is_ascii_punctuation() || (!c.is_alphanumeric() && !c.is_whitespace()) {
+                    if !current.is_empty() {
+                        words.push(std::mem::take(&mut current));
+                    }
+                    words.push(c.to_string());
+                } else {
+                    current.push(c);
+                }
+            }
+            if !current.is_empty() {
+                words.push(current);
+            }
+        }
+        words
+    }
+
+    /// Greedy longest-match-first split of one word into vocabulary pieces
+    fn word_pieces(&self, word: &str) -> Vec<i64> {
+        let chars: Vec<char> = word.chars().collect();
+        if chars.len() > Self::MAX_WORD_CHARS {
+            return vec![self.unk_id()];
+        }
+        let mut pieces = Vec::new();
+        let mut start = 0;
+        while start < chars.len() {
+            let mut end = chars.len();
+            let mut found = None;
+            while start < end {
+                let mut piece: String = chars[start..end].iter().collect();
+                if start > 0 {
+                    piece.insert_str(0, &self.continuing_prefix);
+                }
+                if let Some(id) = self.id(&piece) {
+                    found = Some(id);
+                    break;
+                }
+                end -= 1;
+            }
+            match found {
+                Some(id) => pieces.push(id),
+                None => return vec![self.unk_id()],
+            }
+            start = end;
+        }
+        pieces
+    }
+
+    /// Encode text as `[CLS] pieces… [SEP]`, truncated to `max_length` tokens
+    pub fn encode(&self, text: &str, max_length: usize) -> Encoding {
+        let budget = max_length.saturating_sub(2);
+        let mut input_ids = vec![self.id(Self::CLS).unwrap_or(self.unk_id())];
+        input_ids.extend(
+            self.basic_tokens(text)
+                .iter()
+                .flat_map(|word| self.word_pieces(word))
+                .take(budget),
+        );
+        input_ids.push(self.id(Self::SEP).unwrap_or(self.unk_id()));
+        let attention_mask = vec![1; input_ids.len()];
+        Encoding {
+            input_ids,
+            attention_mask,
+        }
+    }
+}
+
+/// Average token embeddings over the attended positions and normalize
+pub fn mean_pool(token_embeddings: &[Vec<f32>], attention_mask: &[i64]) -> Vec<f32> {
+    let dimension = token_embeddings.first().map_or(0, Vec::len);
+    let mut pooled = vec![0.0; dimension];
+    let mut count = 0.0;
+    for (token, _) in token_embeddings
+        .iter()
+        .zip(attention_mask)
+        .filter(|(_, mask)| **mask != 0)
+    {
+        for (sum, value) in pooled.iter_mut().zip(token) {
+            *sum += value;
+        }
+        count += 1.0;
+    }
+    if count > 0.0 {
+        pooled.iter_mut().for_each(|value| *value /= count);
+    }
+    normalize(&mut pooled);
+    pooled
+}
+
+/// Local sentence-transformer model exported to ONNX
+///
+/// Tokenization and pooling run in-process so no text leaves the machine.
+/// Executing the graph needs the `onnx` cargo feature, which loads the ONNX
+/// Runtime shared library at runtime (`ORT_DYLIB_PATH`); builds without it
+/// fail at initialization rather than silently falling back to hashing.
+pub struct OnnxEmbeddingProvider {
+    model_path: Option<PathBuf>,
+    tokenizer_path: Option<PathBuf>,
+    max_sequence_length: usize,
+    lowercase: bool,
+    dimension: usize,
+    tokenizer: RwLock<Option<WordPieceTokenizer>>,
+    #[cfg(feature = "onnx")]
+    session: RwLock<Option<Arc<std::sync::Mutex<ort::session::Session>>>>,
+}
+
+impl OnnxEmbeddingProvider {
+    pub fn new(
+        config: &EmbeddingBackendConfig,
+        max_sequence_length: usize,
+        lowercase: bool,
+    ) -> Self {
+        Self {
+            model_path: config.model_path.clone(),
+            tokenizer_path: config.tokenizer_path.clone(),
+            max_sequence_length,
+            lowercase,
+            dimension: config.dimensions.unwrap_or(DEFAULT_EMBEDDING_DIMENSION),
+            tokenizer: RwLock::new(None),
+            #[cfg(feature = "onnx")]
+            session: RwLock::new(None),
+        }
+    }
+
+    fn model_path(&self) -> VectorResult<&Path> {
+        self.model_path.as_deref().ok_or_else(|| {
+            VectorError::ConfigurationError(
+                "The onnx embedding provider requires vector.embedding.model_path".to_string(),
+            )
+        })
+    }
+
+    /// The configured tokenizer, or `tokenizer.json`/`vocab.txt` next to the model
+    fn tokenizer_path(&self, model_path: &Path) -> VectorResult<PathBuf> {
+        if let Some(path) = &self.tokenizer_path {
+            return Ok(path.clone());
+        }
+        let dir = model_path.parent().unwrap_or(Path::new("."));
+        ["tokenizer.json", "vocab.txt"]
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.exists())
+            .ok_or_else(|| {
+                VectorError::ConfigurationError(format!(
+                    "No tokenizer.json or vocab.txt next to {}; set vector.embedding.tokenizer_path",
+                    model_path.display()
+                ))
+            })
+    }
+
+    /// Load the model graph; loading the runtime library panics inside `ort`
+    /// when it is missing, so that is reported as a load error too
+    #[cfg(feature = "onnx")]
+    async fn load_session(model_path: &Path) -> VectorResult<ort::session::Session> {
+        let path = model_path.to_path_buf();
+        let load_error = |reason: String| VectorError::ModelLoadError {
+            model: model_path.display().to_string(),
+            reason,
+        };
+        tokio::task::spawn_blocking(move || {
+            ort::session::Session::builder().and_then(|builder| builder.commit_from_file(&path))
+        })
+        .await
+        .map_err(|_| {
+            load_error(
+                "the ONNX Runtime library could not be loaded; set ORT_DYLIB_PATH".to_string(),
+            )
+        })?
+        .map_err(|e| load_error(e.to_string()))
+    }
+
+    /// Run a padded batch through the model and pool one vector per text
+    #[cfg(feature = "onnx")]
+    fn run_inference(
+        session: &mut ort::session::Session,
+        encodings: &[Encoding],
+    ) -> VectorResult<Vec<Vec<f32>>> {
+        use ort::value::Tensor;
+
+        let inference_error =
+            |e: ort::Error| VectorError::EmbeddingError(format!("ONNX inference failed: {e}"));
+        let batch = encodings.len();
+        let width = encodings
+            .iter()
+            .map(|e| e.input_ids.len())
+            .max()
+            .unwrap_or(0);
+        let mut input_ids = vec![0i64; batch * width];
+        let mut attention_mask = vec![0i64; batch * width];
+        for (row, encoding) in encodings.iter().enumerate() {
+            let start = row * width;
+            input_ids[start..start + encoding.input_ids.len()].copy_from_slice(&encoding.input_ids);
+            attention_mask[start..start + encoding.attention_mask.len()]
+                .copy_from_slice(&encoding.attention_mask);
+        }
+        let shape = [batch as i64, width as i64];
+
+        // BERT exports take token_type_ids, most sentence-transformer exports do not
+        let wants_token_types = session
+            .inputs
+            .iter()
+            .any(|input| input.name == "token_type_ids");
+        let mut inputs = ort::inputs![
+            "input_ids" => Tensor::from_array((shape, input_ids)).map_err(inference_error)?,
+            "attention_mask" => Tensor::from_array((shape, attention_mask.clone())).map_err(inference_error)?,
+        ];
+        if wants_token_types {
+            inputs.push((
+                "token_type_ids".into(),
+                Tensor::from_array((shape, vec![0i64; batch * width]))
+                    .map_err(inference_error)?
+                    .into(),
+            ));
+        }
+
+        let outputs = session.run(inputs).map_err(inference_error)?;
+        let (output_shape, values) = outputs[0]
+            .try_extract_tensor::<f32>()
+            .map_err(inference_error)?;
+
+        match **output_shape {
+            // Already pooled, e.g. a `sentence_embedding` output
+            [rows, hidden] if rows as usize == batch => Ok(values
+                .chunks(hidden as usize)
+                .map(|row| {
+                    let mut row = row.to_vec();
+                    normalize(&mut row);
+                    row
+                })
+                .collect()),
+            // Token embeddings (`last_hidden_state`)
+            [rows, tokens, hidden] if rows as usize == batch && tokens as usize == width => {
+                let hidden = hidden as usize;
+                Ok(values
+                    .chunks(width * hidden)
+                    .zip(attention_mask.chunks(width))
+                    .map(|(row, mask)| {
+                        let tokens: Vec<Vec<f32>> =
+                            row.chunks(hidden).map(<[f32]>::to_vec).collect();
+                        mean_pool(&tokens, mask)
+                    })
+                    .collect())
+            }
+            _ => Err(VectorError::EmbeddingError(format!(
+                "Unexpected ONNX output shape {output_shape:?}"
+            ))),
+        }
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for OnnxEmbeddingProvider {
+    fn name(&self) -> &str {
+        "onnx"
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    async fn initialize(&self) -> VectorResult<()> {
+        let model_path = self.model_path()?;
+        if !model_path.is_file() {
+            return Err(VectorError::ModelLoadError {
+                model: model_path.display().to_string(),
+                reason: "file does not exist".to_string(),
+            });
+        }
+
+        let tokenizer_path = self.tokenizer_path(model_path)?;
+        let tokenizer = WordPieceTokenizer::from_file(&tokenizer_path, self.lowercase)?;
+        debug!(
+            "Loaded tokenizer {} with {} tokens",
+            tokenizer_path.display(),
+            tokenizer.vocab.len()
+        );
+
+        #[cfg(not(feature = "onnx"))]
+        return Err(VectorError::ModelLoadError {
+            model: model_path.display().to_string(),
+            reason: "this build does not include the ONNX runtime; rebuild with the `onnx` feature"
+                .to_string(),
+        });
+
+        #[cfg(feature = "onnx")]
+        {
+            let session = Self::load_session(model_path).await?;
+            info!("Loaded ONNX embedding model {}", model_path.display());
+            *self.tokenizer.write().await = Some(tokenizer);
+            *self.session.write().await = Some(Arc::new(std::sync::Mutex::new(session)));
+            Ok(())
+        }
+    }
+
+    async fn embed(&self, texts: &[String]) -> VectorResult<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let encodings: Vec<Encoding> = {
+            let guard = self.tokenizer.read().await;
+            let tokenizer = guard.as_ref().ok_or_else(|| {
+                VectorError::EmbeddingError("ONNX embedding provider not initialized".to_string())
+            })?;
+            texts
+                .iter()
+                .map(|text| tokenizer.encode(text, self.max_sequence_length))
+                .collect()
+        };
+
+        #[cfg(not(feature = "onnx"))]
+        return Err(VectorError::EmbeddingError(format!(
+            "Cannot run {} encoded texts without the `onnx` feature",
+            encodings.len()
+        )));
+
+        #[cfg(feature = "onnx")]
+        {
+            let session = self.session.read().await.clone().ok_or_else(|| {
+                VectorError::EmbeddingError("ONNX embedding provider not initialized".to_string())
+            })?;
+            // Inference is CPU-bound; keep it off the async workers
+            tokio::task::spawn_blocking(move || {
+                let mut session = session.lock().map_err(|_| {
+                    VectorError::EmbeddingError("ONNX session lock poisoned".to_string())
+                })?;
+                Self::run_inference(&mut session, &encodings)
+            })
+            .await
+            .map_err(|e| VectorError::EmbeddingError(format!("ONNX inference task failed: {e}")))?
+        }
+    }
+}
+
+/// OpenAI `/embeddings` endpoint, also served by vLLM, Ollama and TEI
+///
+/// Pointing `api_url` at a self-hosted server keeps embeddings inside the
+/// network; the API key is only required for the public OpenAI endpoint.
+pub struct OpenAiEmbeddingProvider {
+    client: reqwest::Client,
+    endpoint: String,
+    api_key: Option<String>,
+    model: String,
+    /// Dimension requested from models that support shortening
+    requested_dimensions: Option<usize>,
+    dimension: AtomicUsize,
+}
+
+impl OpenAiEmbeddingProvider {
+    pub fn new(config: &EmbeddingBackendConfig, model: &str) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_seconds))
+            .build()
+            .unwrap_or_default();
+        let shortenable = model.starts_with("text-embedding-3");
+        let dimension = config
+            .dimensions
+            .or_else(|| Self::known_dimension(model))
+            .unwrap_or(0);
+        Self {
+            client,
+            endpoint: format!("{}/embeddings", config.api_url.trim_end_matches('/')),
+            api_key: config.api_key.clone().filter(|key| !key.is_empty()),
+            model: model.to_string(),
+            requested_dimensions: config.dimensions.filter(|_| shortenable),
+            dimension: AtomicUsize::new(dimension),
+        }
+    }
+
+    fn known_dimension(model: &str) -> Option<usize> {
+        match model {
+            "text-embedding-3-small" | "text-embedding-ada-002" => Some(1536),
+            "text-embedding-3-large" => Some(3072),
+            _ => None,
+        }
+    }
+
+    async fn request(&self, texts: &[String]) -> VectorResult<Vec<Vec<f32>>> {
+        let mut body = json!({
+            "model": self.model,
+            "input": texts,
+            "encoding_format": "float",
+        });
+        if let Some(dimensions) = self.requested_dimensions {
+            body["dimensions"] = json!(dimensions);
+        }
+
+        let mut request = self.client.post(&self.endpoint).json(&body);
+        if let Some(key) = &self.api_key {
+            request = request.bearer_auth(key);
+        }
+        let response = request.send().await.map_err(|e| {
+            VectorError::EmbeddingError(format!(
+                "Embedding request to {} failed: {e}",
+                self.endpoint
+            ))
+        })?;
+        let status = response.status();
+        let payload: Value = response.json().await.map_err(|e| {
+            VectorError::EmbeddingError(format!("Invalid embedding response ({status}): {e}"))
+        })?;
+        if !status.is_success() {
+            let message = payload["error"]["message"]
+                .as_str()
+                .map(str::to_string)
+                .unwrap_or_else(|| payload.to_string());
+            return Err(VectorError::EmbeddingError(format!(
+                "Embedding API returned {status}: {message}"
+            )));
+        }
+
+        parse_embeddings_response(&payload, texts.len())
+    }
+}
+
+/// Extract vectors from an `/embeddings` response in input order
+fn parse_embeddings_response(payload: &Value, expected: usize) -> VectorResult<Vec<Vec<f32>>> {
+    let data = payload["data"].as_array().ok_or_else(|| {
+        VectorError::EmbeddingError("Embedding response has no data array".to_string())
+    })?;
+    let mut indexed = data
+        .iter()
+        .enumerate()
+        .map(|(position, item)| {
+            let index = item["index"].as_u64().map_or(position, |i| i as usize);
+            let embedding = item["embedding"]
+                .as_array()
+                .ok_or_else(|| {
+                    VectorError::EmbeddingError("Embedding item has no vector".to_string())
+                })?
+                .iter()
+                .map(|v| v.as_f64().map(|v| v as f32))
+                .collect::<Option<Vec<f32>>>()
+                .ok_or_else(|| {
+                    VectorError::EmbeddingError("Embedding vector is not numeric".to_string())
+                })?;
+            Ok((index, embedding))
+        })
+        .collect::<VectorResult<Vec<_>>>()?;
+    if indexed.len() != expected {
+        return Err(VectorError::EmbeddingError(format!(
+            "Embedding API returned {} vectors for {expected} inputs",
+            indexed.len()
+        )));
+    }
+    indexed.sort_by_key(|(index, _)| *index);
+    Ok(indexed
+        .into_iter()
+        .map(|(_, embedding)| embedding)
+        .collect())
+}
+
+#[async_trait]
+impl EmbeddingProvider for OpenAiEmbeddingProvider {
+    fn name(&self) -> &str {
+        "openai"
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension.load(Ordering::Relaxed)
+    }
+
+    async fn initialize(&self) -> VectorResult<()> {
+        if self.api_key.is_none() && self.endpoint.starts_with(OPENAI_API_URL) {
+            return Err(VectorError::ConfigurationError(
+                "The OpenAI embeddings API requires vector.embedding.api_key".to_string(),
+            ));
+        }
+        if self.dimension() == 0 {
+            // Self-hosted models have no published size, so ask the server
+            let probe = self.request(&["dimension probe".to_string()]).await?;
+            let dimension = probe.first().map_or(0, Vec::len);
+            info!(
+                "Embedding model {} produces {dimension} dimensions",
+                self.model
+            );
+            self.dimension.store(dimension, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+
+    async fn embed(&self, texts: &[String]) -> VectorResult<Vec<Vec<f32>>> {
+        let embeddings = self.request(texts).await?;
+        let expected = self.dimension();
+        if let Some(wrong) = embeddings
+            .iter()
+            .find(|e| expected != 0 && e.len() != expected)
+        {
+            return Err(VectorError::EmbeddingError(format!(
+                "Embedding model {} returned {} dimensions, expected {expected}",
+                self.model,
+                wrong.len()
+            )));
+        }
+        Ok(embeddings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokenizer() -> WordPieceTokenizer {
+        WordPieceTokenizer::from_vocab(
+            [
+                "[PAD]", "[UNK]", "[CLS]", "[SEP]", "rust", "async", "##ness", "trait", "!", "un",
+                "##safe",
+            ]
+            .into_iter()
+            .map(String::from),
+            true,
+        )
+    }
+
+    #[test]
+    fn test_provider_kind_parsing() {
+        assert_eq!(
+            "OpenAI".parse::<EmbeddingProviderKind>().unwrap(),
+            EmbeddingProviderKind::OpenAi
+        );
+        assert_eq!(EmbeddingProviderKind::Onnx.to_string(), "onnx");
+        assert!("candle".parse::<EmbeddingProviderKind>().is_err());
+
+        let config: EmbeddingBackendConfig =
+            serde_json::from_str(r#"{"provider":"openai","dimensions":256}"#).unwrap();
+        assert_eq!(config.provider, EmbeddingProviderKind::OpenAi);
+        assert_eq!(config.api_url, OPENAI_API_URL);
+    }
+
+    #[test]
+    fn test_wordpiece_encoding() {
+        let encoding = tokenizer().encode("Rust asyncness, UNSAFE trait!", 16);
+        // [CLS] rust async ##ness [UNK](",") un ##safe trait ! [SEP]
+        assert_eq!(encoding.input_ids, vec![2, 4, 5, 6, 1, 9, 10, 7, 8, 3]);
+        assert_eq!(encoding.attention_mask.len(), encoding.input_ids.len());
+
+        let truncated = tokenizer().encode("rust rust rust rust", 4);
+        assert_eq!(truncated.input_ids, vec![2, 4, 4, 3]);
+    }
+
+    #[test]
+    fn test_tokenizer_json_vocab() {
+        let json = r###"{
+            "normalizer": {"type": "BertNormalizer", "lowercase": true},
+            "model": {"type": "WordPiece", "unk_token": "[UNK]", "continuing_subword_prefix": "##",
+                      "vocab": {"[UNK]": 0, "[CLS]": 1, "[SEP]": 2, "trait": 3}}
+        }"###;
+        let tokenizer = WordPieceTokenizer::from_tokenizer_json(json, false).unwrap();
+        assert_eq!(tokenizer.encode("Trait", 8).input_ids, vec![1, 3, 2]);
+    }
+
+    #[test]
+    fn test_mean_pool_ignores_padding() {
+        let pooled = mean_pool(
+            &[vec![1.0, 0.0], vec![3.0, 0.0], vec![100.0, 100.0]],
+            &[1, 1, 0],
+        );
+        assert_eq!(pooled, vec![1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_parse_embeddings_response_orders_by_index() {
+        let payload = json!({"data": [
+            {"index": 1, "embedding": [0.0, 1.0]},
+            {"index": 0, "embedding": [1.0, 0.0]},
+        ]});
+        let embeddings = parse_embeddings_response(&payload, 2).unwrap();
+        assert_eq!(embeddings, vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+        assert!(parse_embeddings_response(&payload, 3).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_provider_selection() {
+        let mut config = EmbeddingConfig::default();
+        assert_eq!(create_embedding_provider(&config).name(), "hash");
+
+        config.backend.provider = EmbeddingProviderKind::OpenAi;
+        config.model_name = "text-embedding-3-large".to_string();
+        let openai = create_embedding_provider(&config);
+        assert_eq!(openai.name(), "openai");
+        assert_eq!(openai.dimension(), 3072);
+        assert!(matches!(
+            openai.initialize().await,
+            Err(VectorError::ConfigurationError(_))
+        ));
+
+        config.backend.provider = EmbeddingProviderKind::Onnx;
+        let onnx = create_embedding_provider(&config);
+        assert!(matches!(
+            onnx.initialize().await,
+            Err(VectorError::ConfigurationError(message)) if message.contains("model_path")
+        ));
+    }
+
+    #[cfg(not(feature = "onnx"))]
+    #[tokio::test]
+    async fn test_onnx_without_feature_reports_missing_runtime() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("model.onnx"), b"not a model").unwrap();
+        std::fs::write(
+            dir.path().join("vocab.txt"),
+            "[PAD]\n[UNK]\n[CLS]\n[SEP]\nrust\n",
+        )
+        .unwrap();
+
+        let mut config = EmbeddingConfig::default();
+        config.backend.provider = EmbeddingProviderKind::Onnx;
+        config.backend.model_path = Some(dir.path().join("model.onnx"));
+        let onnx = create_embedding_provider(&config);
+        assert!(matches!(
+            onnx.initialize().await,
+            Err(VectorError::ModelLoadError { reason, .. }) if reason.contains("`onnx` feature")
+        ));
+        assert!(onnx.embed(&["rust".to_string()]).await.is_err());
+    }
+
+    /// Needs ONNX Runtime (`ORT_DYLIB_PATH`) and a sentence-transformer export in
+    /// `FORTITUDE_TEST_ONNX_MODEL`, with its tokenizer next to it
+    #[cfg(feature = "onnx")]
+    #[tokio::test]
+    #[ignore]
+    async fn test_onnx_inference_with_local_model() {
+        let model_path = std::env::var("FORTITUDE_TEST_ONNX_MODEL")
+            .expect("FORTITUDE_TEST_ONNX_MODEL must point at model.onnx");
+        let mut config = EmbeddingConfig::default();
+        config.backend.provider = EmbeddingProviderKind::Onnx;
+        config.backend.model_path = Some(PathBuf::from(model_path));
+        let onnx = create_embedding_provider(&config);
+        onnx.initialize().await.unwrap();
+
+        let texts = [
+            "How do I write async code in Rust?",
+            "Writing asynchronous Rust with tokio",
+            "A recipe for banana bread",
+        ]
+        .map(String::from);
+        let vectors = onnx.embed(&texts).await.unwrap();
+        assert_eq!(vectors.len(), 3);
+        assert!(vectors.iter().all(|v| v.len() == onnx.dimension()));
+        let norm: f32 = vectors[0].iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-3);
+
+        let similarity = |a: &[f32], b: &[f32]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f32>();
+        assert!(similarity(&vectors[0], &vectors[1]) > similarity(&vectors[0], &vectors[2]));
+    }
+}
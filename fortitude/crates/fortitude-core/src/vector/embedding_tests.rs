@@ -45,6 +45,7 @@ mod embedding_tests {
                 remove_special_chars: false,
                 max_text_length: 1000,
             },
+            backend: EmbeddingBackendConfig::default(),
         }
     }
 
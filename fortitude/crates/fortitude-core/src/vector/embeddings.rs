@@ -13,14 +13,19 @@
 // limitations under the License.
 
 // ABOUTME: Embedding generation service for converting text to vectors
-//! This module provides text-to-vector conversion capabilities using local
-//! embedding models with caching, batch processing, and error handling.
+//! This module provides text-to-vector conversion capabilities with caching,
+//! batch processing, and error handling on top of a configurable
+//! [`EmbeddingProvider`] backend.
 
+use crate::vector::embedding_providers::{
+    create_embedding_provider, EmbeddingBackendConfig, EmbeddingProvider,
+};
 use crate::vector::error::{VectorError, VectorResult};
 use async_trait::async_trait;
 use dashmap::DashMap;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
@@ -30,12 +35,6 @@ use tracing::{debug, info, instrument};
 #[derive(Debug, Clone)]
 pub struct MockDevice;
 
-#[derive(Debug)]
-pub struct MockBertModel;
-
-#[derive(Debug)]
-pub struct MockTokenizer;
-
 /// Configuration for embedding generation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmbeddingConfig {
@@ -53,6 +52,9 @@ pub struct EmbeddingConfig {
     pub download_config: ModelDownloadConfig,
     /// Text preprocessing options
     pub preprocessing: PreprocessingConfig,
+    /// Backend that generates the vectors
+    #[serde(default)]
+    pub backend: EmbeddingBackendConfig,
 }
 
 /// Device type for model inference
@@ -162,6 +164,7 @@ impl Default for EmbeddingConfig {
             cache_config: EmbeddingCacheConfig::default(),
             download_config: ModelDownloadConfig::default(),
             preprocessing: PreprocessingConfig::default(),
+            backend: EmbeddingBackendConfig::default(),
         }
     }
 }
@@ -209,11 +212,11 @@ pub trait EmbeddingGenerator: Send + Sync {
     fn embedding_dimension(&self) -> usize;
 }
 
-/// Local embedding service using mock implementation (replace with Candle framework in production)
+/// Embedding service that caches and batches requests to an [`EmbeddingProvider`]
 pub struct LocalEmbeddingService {
     config: EmbeddingConfig,
-    model: Arc<RwLock<Option<MockBertModel>>>,
-    tokenizer: Arc<RwLock<Option<MockTokenizer>>>,
+    provider: Arc<dyn EmbeddingProvider>,
+    initialized: Arc<AtomicBool>,
     #[allow(dead_code)] // TODO: Use device for embedding model placement
     device: MockDevice,
     cache: Arc<DashMap<String, CachedEmbedding>>,
@@ -224,8 +227,8 @@ impl Clone for LocalEmbeddingService {
     fn clone(&self) -> Self {
         Self {
             config: self.config.clone(),
-            model: self.model.clone(),
-            tokenizer: self.tokenizer.clone(),
+            provider: self.provider.clone(),
+            initialized: self.initialized.clone(),
             device: self.device.clone(),
             cache: self.cache.clone(),
             stats: self.stats.clone(),
@@ -234,14 +237,20 @@ impl Clone for LocalEmbeddingService {
 }
 
 impl LocalEmbeddingService {
-    /// Create a new local embedding service
+    /// Create a new embedding service using the configured backend
     pub fn new(config: EmbeddingConfig) -> Self {
+        let provider = create_embedding_provider(&config);
+        Self::with_provider(config, provider)
+    }
+
+    /// Create a new embedding service around a custom backend
+    pub fn with_provider(config: EmbeddingConfig, provider: Arc<dyn EmbeddingProvider>) -> Self {
         let device: MockDevice = config.device.clone().into();
 
         Self {
             config,
-            model: Arc::new(RwLock::new(None)),
-            tokenizer: Arc::new(RwLock::new(None)),
+            provider,
+            initialized: Arc::new(AtomicBool::new(false)),
             device,
             cache: Arc::new(DashMap::new()),
             stats: Arc::new(RwLock::new(EmbeddingStats {
@@ -253,48 +262,28 @@ impl LocalEmbeddingService {
         }
     }
 
-    /// Initialize the model and tokenizer
+    /// Initialize the embedding backend
     #[instrument(skip(self))]
     pub async fn initialize(&self) -> VectorResult<()> {
         info!(
-            "Initializing embedding service with model: {}",
+            "Initializing {} embedding service with model: {}",
+            self.provider.name(),
             self.config.model_name
         );
 
-        // Download/load tokenizer
-        let tokenizer = self.load_tokenizer().await?;
+        self.provider.initialize().await?;
+        self.initialized.store(true, Ordering::Release);
 
-        // Download/load model
-        let model = self.load_model().await?;
-
-        // Update the shared state
-        *self.tokenizer.write().await = Some(tokenizer);
-        *self.model.write().await = Some(model);
-
-        info!("Embedding service initialized successfully");
+        info!(
+            "Embedding service initialized successfully ({} dimensions)",
+            self.provider.dimension()
+        );
         Ok(())
     }
 
-    /// Load the tokenizer (mock implementation)
-    async fn load_tokenizer(&self) -> VectorResult<MockTokenizer> {
-        debug!("Loading tokenizer for model: {}", self.config.model_name);
-
-        // Mock implementation - replace with actual tokenizer loading in production
-        // Simulate some async work
-        tokio::time::sleep(Duration::from_millis(100)).await;
-
-        Ok(MockTokenizer)
-    }
-
-    /// Load the model (mock implementation)
-    async fn load_model(&self) -> VectorResult<MockBertModel> {
-        debug!("Loading model: {}", self.config.model_name);
-
-        // Mock implementation - replace with actual model loading in production
-        // Simulate some async work and model loading time
-        tokio::time::sleep(Duration::from_millis(500)).await;
-
-        Ok(MockBertModel)
+    /// Name of the backend generating embeddings
+    pub fn provider_name(&self) -> &str {
+        self.provider.name()
     }
 
     /// Preprocess text according to configuration
@@ -397,64 +386,85 @@ impl LocalEmbeddingService {
         stats.cache_size = self.cache.len();
     }
 
-    /// Generate embedding using the model (mock implementation)
-    async fn generate_embedding_internal(&self, text: &str) -> VectorResult<Vec<f32>> {
-        let start_time = Instant::now();
+    /// Look up a cached embedding for preprocessed text, counting the hit
+    async fn cached_embedding(&self, processed_text: &str) -> Option<Vec<f32>> {
+        if !self.config.cache_config.enabled {
+            return None;
+        }
+        let cache_key = self.generate_cache_key(processed_text);
 
-        // Get model and tokenizer
-        let model_guard = self.model.read().await;
-        let tokenizer_guard = self.tokenizer.read().await;
+        let embedding = {
+            let mut entry = self.cache.get_mut(&cache_key)?;
+            if !self.is_cache_valid(&entry) {
+                return None;
+            }
+            debug!("Cache hit for embedding");
+            entry.access_count += 1;
+            entry.embedding.clone()
+        };
 
-        let _model = model_guard
-            .as_ref()
-            .ok_or_else(|| VectorError::EmbeddingError("Model not initialized".to_string()))?;
+        // Update cache hit rate
+        let mut stats = self.stats.write().await;
+        let total_requests = stats.total_generated + 1;
+        let cache_hits = (stats.cache_hit_rate * stats.total_generated as f64) + 1.0;
+        stats.cache_hit_rate = cache_hits / total_requests as f64;
 
-        let _tokenizer = tokenizer_guard
-            .as_ref()
-            .ok_or_else(|| VectorError::EmbeddingError("Tokenizer not initialized".to_string()))?;
+        Some(embedding)
+    }
 
-        // Mock implementation - replace with actual inference in production
-        // Generate a deterministic but pseudo-random embedding based on text hash
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
+    /// Store a freshly generated embedding
+    async fn cache_embedding(&self, processed_text: &str, embedding: &[f32]) {
+        if !self.config.cache_config.enabled {
+            return;
+        }
+        let cache_key = self.generate_cache_key(processed_text);
 
-        let mut hasher = DefaultHasher::new();
-        text.hash(&mut hasher);
-        let hash = hasher.finish();
+        // Check cache size limit
+        if self.cache.len() >= self.config.cache_config.max_entries {
+            self.cleanup_cache().await;
+        }
 
-        // Generate 384-dimensional embedding (matching all-MiniLM-L6-v2)
-        let mut embedding = Vec::with_capacity(384);
-        let mut rng_state = hash;
+        self.cache.insert(
+            cache_key,
+            CachedEmbedding {
+                embedding: embedding.to_vec(),
+                cached_at: Instant::now(),
+                access_count: 1,
+            },
+        );
+    }
 
-        for _ in 0..384 {
-            // Simple linear congruential generator for deterministic pseudo-randomness
-            rng_state = rng_state.wrapping_mul(1103515245).wrapping_add(12345);
-            let normalized = (rng_state % 1000) as f32 / 1000.0 - 0.5; // Range [-0.5, 0.5]
-            embedding.push(normalized);
+    /// Generate embeddings for preprocessed texts with the backend
+    async fn generate_embeddings_internal(&self, texts: &[String]) -> VectorResult<Vec<Vec<f32>>> {
+        if !self.initialized.load(Ordering::Acquire) {
+            return Err(VectorError::EmbeddingError(
+                "Embedding service not initialized".to_string(),
+            ));
         }
+        let start_time = Instant::now();
 
-        // Normalize the vector to unit length (common for embeddings)
-        let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
-        if norm > 0.0 {
-            for value in &mut embedding {
-                *value /= norm;
-            }
+        let embeddings = self.provider.embed(texts).await?;
+        if embeddings.len() != texts.len() {
+            return Err(VectorError::EmbeddingError(format!(
+                "{} backend returned {} embeddings for {} texts",
+                self.provider.name(),
+                embeddings.len(),
+                texts.len()
+            )));
         }
 
-        // Simulate some processing time
-        tokio::time::sleep(Duration::from_millis(50)).await;
-
-        // Update stats
+        // Update stats, spreading the batch time across its embeddings
         let generation_time = start_time.elapsed().as_millis() as f64;
         let mut stats = self.stats.write().await;
-        stats.total_generated += 1;
+        let previous = stats.total_generated as f64;
+        stats.total_generated += embeddings.len() as u64;
 
         // Update rolling average
         let n = stats.total_generated as f64;
         stats.avg_generation_time_ms =
-            (stats.avg_generation_time_ms * (n - 1.0) + generation_time) / n;
+            (stats.avg_generation_time_ms * previous + generation_time) / n;
 
-        Ok(embedding)
+        Ok(embeddings)
     }
 }
 
@@ -465,51 +475,18 @@ impl EmbeddingGenerator for LocalEmbeddingService {
         let processed_text = self.preprocess_text(text);
 
         // Check cache first
-        if self.config.cache_config.enabled {
-            let cache_key = self.generate_cache_key(&processed_text);
-
-            if let Some(cached) = self.cache.get(&cache_key) {
-                if self.is_cache_valid(&cached) {
-                    debug!("Cache hit for embedding");
-
-                    // Update access count
-                    drop(cached);
-                    if let Some(mut entry) = self.cache.get_mut(&cache_key) {
-                        entry.access_count += 1;
-                    }
-
-                    // Update cache hit rate
-                    let mut stats = self.stats.write().await;
-                    let total_requests = stats.total_generated + 1;
-                    let cache_hits = (stats.cache_hit_rate * stats.total_generated as f64) + 1.0;
-                    stats.cache_hit_rate = cache_hits / total_requests as f64;
-
-                    return Ok(self.cache.get(&cache_key).unwrap().embedding.clone());
-                }
-            }
+        if let Some(embedding) = self.cached_embedding(&processed_text).await {
+            return Ok(embedding);
         }
 
         // Generate new embedding
-        let embedding = self.generate_embedding_internal(&processed_text).await?;
+        let embedding = self
+            .generate_embeddings_internal(std::slice::from_ref(&processed_text))
+            .await?
+            .remove(0);
 
         // Cache the result
-        if self.config.cache_config.enabled {
-            let cache_key = self.generate_cache_key(&processed_text);
-
-            // Check cache size limit
-            if self.cache.len() >= self.config.cache_config.max_entries {
-                self.cleanup_cache().await;
-            }
-
-            self.cache.insert(
-                cache_key,
-                CachedEmbedding {
-                    embedding: embedding.clone(),
-                    cached_at: Instant::now(),
-                    access_count: 1,
-                },
-            );
-        }
+        self.cache_embedding(&processed_text, &embedding).await;
 
         Ok(embedding)
     }
@@ -517,16 +494,37 @@ impl EmbeddingGenerator for LocalEmbeddingService {
     async fn generate_embeddings(&self, texts: &[String]) -> VectorResult<Vec<Vec<f32>>> {
         let mut results = Vec::with_capacity(texts.len());
 
-        // Process in batches
-        for chunk in texts.chunks(self.config.batch_size) {
+        // Process in batches, sending only cache misses to the backend
+        for chunk in texts.chunks(self.config.batch_size.max(1)) {
+            let processed: Vec<String> = chunk.iter().map(|t| self.preprocess_text(t)).collect();
+
             let mut batch_results = Vec::with_capacity(chunk.len());
+            let mut misses = Vec::new();
+            for text in &processed {
+                let cached = self.cached_embedding(text).await;
+                if cached.is_none() {
+                    misses.push(text.clone());
+                }
+                batch_results.push(cached);
+            }
 
-            for text in chunk {
-                let embedding = self.generate_embedding(text).await?;
-                batch_results.push(embedding);
+            let mut generated = if misses.is_empty() {
+                Vec::new().into_iter()
+            } else {
+                self.generate_embeddings_internal(&misses)
+                    .await?
+                    .into_iter()
+            };
+            for (text, slot) in processed.iter().zip(batch_results.iter_mut()) {
+                if slot.is_none() {
+                    if let Some(embedding) = generated.next() {
+                        self.cache_embedding(text, &embedding).await;
+                        *slot = Some(embedding);
+                    }
+                }
             }
 
-            results.extend(batch_results);
+            results.extend(batch_results.into_iter().flatten());
         }
 
         Ok(results)
@@ -548,9 +546,7 @@ impl EmbeddingGenerator for LocalEmbeddingService {
     }
 
     fn embedding_dimension(&self) -> usize {
-        // This should match the model's output dimension
-        // For all-MiniLM-L6-v2, it's 384
-        384
+        self.provider.dimension()
     }
 }
 
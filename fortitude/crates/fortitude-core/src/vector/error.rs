@@ -62,6 +62,9 @@ pub enum VectorError {
     #[error("Tokenization error: {0}")]
     TokenizationError(String),
 
+    #[error("Invalid search query: {0}")]
+    InvalidQuery(String),
+
     #[error("Performance error: {0}")]
     PerformanceError(String),
 
@@ -76,6 +79,13 @@ pub enum VectorError {
 
     #[error("Connection pool error: {0}")]
     ConnectionPoolError(String),
+
+    #[error("Checksum mismatch for {file}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        file: String,
+        expected: String,
+        actual: String,
+    },
 }
 
 impl VectorError {
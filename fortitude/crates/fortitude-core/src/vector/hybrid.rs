@@ -18,8 +18,9 @@
 //! content discovery. It includes multiple fusion algorithms, adaptive strategy selection,
 //! and configurable search balancing.
 
+use crate::query_language::SearchExpression;
 use crate::vector::{
-    error::VectorResult,
+    error::{VectorError, VectorResult},
     search::{MatchMetadata, SearchOptions, SearchResult, SemanticSearchService},
     storage::VectorDocument,
 };
@@ -35,6 +36,12 @@ use tracing::{debug, info, instrument, warn};
 pub struct HybridSearchConfig {
     /// Default fusion method to use
     pub default_fusion_method: FusionMethod,
+    /// Default per-source score normalization for score-based fusion
+    #[serde(default)]
+    pub default_score_normalization: ScoreNormalization,
+    /// Rank constant `k` used by reciprocal rank fusion
+    #[serde(default = "default_rrf_k")]
+    pub rrf_k: f64,
     /// Default search strategy
     pub default_strategy: SearchStrategy,
     /// Weight for vector search results (0.0-1.0)
@@ -61,6 +68,8 @@ impl Default for HybridSearchConfig {
     fn default() -> Self {
         Self {
             default_fusion_method: FusionMethod::ReciprocalRankFusion,
+            default_score_normalization: ScoreNormalization::default(),
+            rrf_k: default_rrf_k(),
             default_strategy: SearchStrategy::Balanced,
             vector_weight: 0.6,
             keyword_weight: 0.4,
@@ -75,6 +84,10 @@ impl Default for HybridSearchConfig {
     }
 }
 
+fn default_rrf_k() -> f64 {
+    60.0
+}
+
 /// Search strategy determining the balance between vector and keyword search
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SearchStrategy {
@@ -132,6 +145,87 @@ pub enum FusionMethod {
     MLFusion,
 }
 
+/// Normalization applied to each source's raw scores before score-based fusion
+///
+/// Vector similarities and TF-IDF scores live on different scales, so they are
+/// normalized independently per source before being weighted and summed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum ScoreNormalization {
+    /// Divide by the highest score in the source
+    #[default]
+    MaxScore,
+    /// Rescale into 0.0-1.0 using the source's minimum and maximum
+    MinMax,
+    /// Standardize by the source's mean and standard deviation
+    ZScore,
+    /// Use raw scores unchanged
+    None,
+}
+
+impl ScoreNormalization {
+    /// Normalize a set of scores from a single source, preserving order
+    pub fn normalize(&self, scores: &[f64]) -> Vec<f64> {
+        if scores.is_empty() {
+            return Vec::new();
+        }
+
+        match self {
+            ScoreNormalization::MaxScore => {
+                let max = scores.iter().cloned().fold(0.0, f64::max);
+                scores
+                    .iter()
+                    .map(|s| if max > 0.0 { s / max } else { 0.0 })
+                    .collect()
+            }
+            ScoreNormalization::MinMax => {
+                let min = scores.iter().cloned().fold(f64::INFINITY, f64::min);
+                let max = scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                let range = max - min;
+                scores
+                    .iter()
+                    .map(|s| if range > 0.0 { (s - min) / range } else { 1.0 })
+                    .collect()
+            }
+            ScoreNormalization::ZScore => {
+                let n = scores.len() as f64;
+                let mean = scores.iter().sum::<f64>() / n;
+                let variance = scores.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n;
+                let std_dev = variance.sqrt();
+                scores
+                    .iter()
+                    .map(|s| {
+                        if std_dev > 0.0 {
+                            (s - mean) / std_dev
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect()
+            }
+            ScoreNormalization::None => scores.to_vec(),
+        }
+    }
+}
+
+/// Breakdown of how each source contributed to a fused hybrid score
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct FusionContribution {
+    /// Vector score after normalization (score-based fusion only)
+    pub vector_normalized_score: Option<f64>,
+    /// Keyword score after normalization (score-based fusion only)
+    pub keyword_normalized_score: Option<f64>,
+    /// Amount the vector source added to the hybrid score
+    pub vector_contribution: Option<f64>,
+    /// Amount the keyword source added to the hybrid score
+    pub keyword_contribution: Option<f64>,
+    /// Normalization applied to raw scores (score-based fusion only)
+    pub normalization: Option<ScoreNormalization>,
+    /// Rank constant applied (reciprocal rank fusion only)
+    pub rrf_k: Option<f64>,
+    /// Applied weights
+    pub weights: (f64, f64), // (vector_weight, keyword_weight)
+}
+
 /// Keyword search implementation using TF-IDF and term matching
 #[derive(Debug, Clone)]
 pub struct KeywordSearcher {
@@ -201,6 +295,9 @@ pub struct HybridSearchResult {
     pub strategy: SearchStrategy,
     /// Detailed score explanation
     pub explanation: Option<HybridSearchExplanation>,
+    /// Per-source contribution to the hybrid score
+    #[serde(default)]
+    pub fusion_contribution: FusionContribution,
     /// Match metadata
     pub match_metadata: HybridMatchMetadata,
     /// Source information
@@ -220,6 +317,9 @@ pub struct HybridSearchExplanation {
     pub strategy_rationale: String,
     /// Applied weights
     pub weights: (f64, f64), // (vector_weight, keyword_weight)
+    /// Score normalization applied before fusion, if any
+    #[serde(default)]
+    pub normalization: Option<ScoreNormalization>,
     /// Detailed calculation
     pub calculation: String,
 }
@@ -261,6 +361,12 @@ pub struct HybridSearchRequest {
     pub strategy: Option<SearchStrategy>,
     /// Fusion method to apply
     pub fusion_method: Option<FusionMethod>,
+    /// Per-source score normalization (overrides the configured default)
+    #[serde(default)]
+    pub score_normalization: Option<ScoreNormalization>,
+    /// Reciprocal rank fusion constant (overrides the configured default)
+    #[serde(default)]
+    pub rrf_k: Option<f64>,
     /// Search options
     pub options: SearchOptions,
     /// Include detailed explanations
@@ -378,6 +484,35 @@ pub struct HybridSearchAnalytics {
     pub strategy_effectiveness: HashMap<String, f64>,
 }
 
+/// Fusion settings resolved for a single hybrid search
+#[derive(Debug, Clone)]
+struct FusionParams {
+    method: FusionMethod,
+    weights: (f64, f64),
+    normalization: ScoreNormalization,
+    rrf_k: f64,
+}
+
+impl FusionParams {
+    /// Contribution skeleton recording the settings that apply to this method
+    fn base_contribution(&self) -> FusionContribution {
+        let rank_based = self.method == FusionMethod::ReciprocalRankFusion;
+        FusionContribution {
+            normalization: (!rank_based).then_some(self.normalization),
+            rrf_k: rank_based.then_some(self.rrf_k),
+            weights: self.weights,
+            ..Default::default()
+        }
+    }
+}
+
+/// One source's scoring of a document during fusion
+struct SourceScore {
+    rank: usize,
+    normalized: Option<f64>,
+    contribution: f64,
+}
+
 impl Default for KeywordSearcher {
     fn default() -> Self {
         Self::new()
@@ -443,14 +578,32 @@ impl KeywordSearcher {
         query: &str,
         limit: usize,
     ) -> VectorResult<Vec<KeywordSearchResult>> {
-        let query_terms = self.normalize_query(query);
-        if query_terms.is_empty() {
+        let expression =
+            SearchExpression::parse(query).map_err(|e| VectorError::InvalidQuery(e.to_string()))?;
+        let query_terms = self.normalize_query(&expression.positive_terms().join(" "));
+        // Filter-only queries such as `type:learning -deprecated` select without scoring
+        let filter_only = query_terms.is_empty() && !expression.is_empty();
+        if query_terms.is_empty() && !filter_only {
             return Ok(Vec::new());
         }
 
         let mut results = Vec::new();
 
         for doc in &self.document_corpus {
+            if !expression.is_plain() && !expression.matches(doc) {
+                continue;
+            }
+            if filter_only {
+                results.push(KeywordSearchResult {
+                    document: doc.clone(),
+                    tf_idf_score: 0.0,
+                    term_matches: Vec::new(),
+                    field_scores: HashMap::new(),
+                    matched_terms: 0,
+                    query_coverage: 1.0,
+                });
+                continue;
+            }
             if let Some(tf_scores) = self.tf_index.get(&doc.id) {
                 let mut total_score = 0.0;
                 let mut term_matches = Vec::new();
@@ -648,8 +801,9 @@ impl HybridSearchService {
         let start_time = Instant::now();
 
         // Check cache first
+        let cache_key = Self::cache_key(&request);
         if self.config.enable_caching {
-            if let Some(cached_result) = self.check_cache(&request.query).await {
+            if let Some(cached_result) = self.check_cache(&cache_key).await {
                 debug!("Cache hit for query: '{}'", request.query);
                 return Ok(cached_result);
             }
@@ -674,11 +828,18 @@ impl HybridSearchService {
             final_strategy.get_weights(&self.config)
         };
 
-        // Determine fusion method
-        let fusion_method = request
-            .fusion_method
-            .clone()
-            .unwrap_or(self.config.default_fusion_method.clone());
+        // Determine fusion method and its per-request settings
+        let fusion_params = FusionParams {
+            method: request
+                .fusion_method
+                .clone()
+                .unwrap_or(self.config.default_fusion_method.clone()),
+            weights: (vector_weight, keyword_weight),
+            normalization: request
+                .score_normalization
+                .unwrap_or(self.config.default_score_normalization),
+            rrf_k: request.rrf_k.unwrap_or(self.config.rrf_k),
+        };
 
         let mut vector_results = Vec::new();
         let mut keyword_results = Vec::new();
@@ -735,13 +896,7 @@ impl HybridSearchService {
         // Fuse results
         let fusion_start = Instant::now();
         let fused_results = self
-            .fuse_results(
-                vector_results,
-                keyword_results,
-                &fusion_method,
-                (vector_weight, keyword_weight),
-                &request,
-            )
+            .fuse_results(vector_results, keyword_results, &fusion_params, &request)
             .await?;
         let fusion_time = fusion_start.elapsed().as_millis() as f64;
 
@@ -791,7 +946,7 @@ impl HybridSearchService {
 
         // Cache result if enabled
         if self.config.enable_caching {
-            self.cache_result(&request.query, &result_set).await;
+            self.cache_result(&cache_key, &result_set).await;
         }
 
         info!(
@@ -962,35 +1117,34 @@ impl HybridSearchService {
         &self,
         vector_results: Vec<SearchResult>,
         keyword_results: Vec<KeywordSearchResult>,
-        fusion_method: &FusionMethod,
-        weights: (f64, f64),
+        params: &FusionParams,
         request: &HybridSearchRequest,
     ) -> VectorResult<Vec<HybridSearchResult>> {
-        match fusion_method {
+        match params.method {
             FusionMethod::ReciprocalRankFusion => {
-                self.reciprocal_rank_fusion(vector_results, keyword_results, weights, request)
+                self.reciprocal_rank_fusion(vector_results, keyword_results, params, request)
                     .await
             }
             FusionMethod::WeightedScoring => {
-                self.weighted_scoring_fusion(vector_results, keyword_results, weights, request)
+                self.weighted_scoring_fusion(vector_results, keyword_results, params, request)
                     .await
             }
             FusionMethod::RankFusion => {
-                self.rank_fusion(vector_results, keyword_results, weights, request)
+                self.rank_fusion(vector_results, keyword_results, params, request)
                     .await
             }
             FusionMethod::MaxScore => {
-                self.max_score_fusion(vector_results, keyword_results, weights, request)
+                self.max_score_fusion(vector_results, keyword_results, params, request)
                     .await
             }
             FusionMethod::LinearInterpolation => {
-                self.linear_interpolation_fusion(vector_results, keyword_results, weights, request)
+                self.linear_interpolation_fusion(vector_results, keyword_results, params, request)
                     .await
             }
             FusionMethod::MLFusion => {
                 // Fallback to RRF for now
                 warn!("ML Fusion not yet implemented, falling back to RRF");
-                self.reciprocal_rank_fusion(vector_results, keyword_results, weights, request)
+                self.reciprocal_rank_fusion(vector_results, keyword_results, params, request)
                     .await
             }
         }
@@ -1001,107 +1155,40 @@ impl HybridSearchService {
         &self,
         vector_results: Vec<SearchResult>,
         keyword_results: Vec<KeywordSearchResult>,
-        weights: (f64, f64),
+        params: &FusionParams,
         request: &HybridSearchRequest,
     ) -> VectorResult<Vec<HybridSearchResult>> {
+        let params = FusionParams {
+            method: FusionMethod::ReciprocalRankFusion,
+            ..params.clone()
+        };
+        let (vector_weight, keyword_weight) = params.weights;
         let mut hybrid_results = HashMap::new();
-        let k = 60.0; // RRF parameter
 
         // Process vector results
         for (rank, result) in vector_results.iter().enumerate() {
-            let rrf_score = weights.0 / (k + (rank + 1) as f64);
-
+            let score = SourceScore {
+                rank,
+                normalized: None,
+                contribution: vector_weight / (params.rrf_k + (rank + 1) as f64),
+            };
             hybrid_results.insert(
                 result.document.id.clone(),
-                HybridSearchResult {
-                    document: result.document.clone(),
-                    vector_score: Some(result.relevance_score),
-                    keyword_score: None,
-                    hybrid_score: rrf_score,
-                    fusion_method: FusionMethod::ReciprocalRankFusion,
-                    strategy: request.strategy.clone().unwrap_or(SearchStrategy::Balanced),
-                    explanation: None,
-                    match_metadata: HybridMatchMetadata {
-                        base_metadata: result.match_metadata.clone(),
-                        vector_rank: Some(rank),
-                        keyword_rank: None,
-                        matched_terms: 0,
-                        total_query_terms: self.tokenize_query(&request.query).len(),
-                        query_coverage: 0.0,
-                    },
-                    search_sources: SearchSources {
-                        from_vector: true,
-                        from_keyword: false,
-                        combined: false,
-                    },
-                },
+                self.vector_hybrid_result(result, score, &params, request),
             );
         }
 
         // Process keyword results
         for (rank, result) in keyword_results.iter().enumerate() {
-            let rrf_score = weights.1 / (k + (rank + 1) as f64);
-
-            if let Some(existing) = hybrid_results.get_mut(&result.document.id) {
-                // Combine with existing vector result
-                existing.keyword_score = Some(result.tf_idf_score);
-                existing.hybrid_score += rrf_score;
-                existing.match_metadata.keyword_rank = Some(rank);
-                existing.match_metadata.matched_terms = result.matched_terms;
-                existing.match_metadata.query_coverage = result.query_coverage;
-                existing.search_sources.from_keyword = true;
-                existing.search_sources.combined = true;
-            } else {
-                // New keyword-only result
-                hybrid_results.insert(
-                    result.document.id.clone(),
-                    HybridSearchResult {
-                        document: result.document.clone(),
-                        vector_score: None,
-                        keyword_score: Some(result.tf_idf_score),
-                        hybrid_score: rrf_score,
-                        fusion_method: FusionMethod::ReciprocalRankFusion,
-                        strategy: request.strategy.clone().unwrap_or(SearchStrategy::Balanced),
-                        explanation: None,
-                        match_metadata: HybridMatchMetadata {
-                            base_metadata: MatchMetadata {
-                                search_time_ms: 0.0,
-                                original_rank: rank,
-                                final_rank: 0, // Will be updated later
-                                matched_filters: 0,
-                                snippet: Some(self.create_snippet(&result.document.content)),
-                            },
-                            vector_rank: None,
-                            keyword_rank: Some(rank),
-                            matched_terms: result.matched_terms,
-                            total_query_terms: self.tokenize_query(&request.query).len(),
-                            query_coverage: result.query_coverage,
-                        },
-                        search_sources: SearchSources {
-                            from_vector: false,
-                            from_keyword: true,
-                            combined: false,
-                        },
-                    },
-                );
-            }
-        }
-
-        let mut results: Vec<_> = hybrid_results.into_values().collect();
-
-        // Sort by hybrid score descending
-        results.sort_by(|a, b| {
-            b.hybrid_score
-                .partial_cmp(&a.hybrid_score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-
-        // Update final ranks
-        for (index, result) in results.iter_mut().enumerate() {
-            result.match_metadata.base_metadata.final_rank = index;
+            let score = SourceScore {
+                rank,
+                normalized: None,
+                contribution: keyword_weight / (params.rrf_k + (rank + 1) as f64),
+            };
+            self.merge_keyword_result(&mut hybrid_results, result, score, &params, request);
         }
 
-        Ok(results)
+        Ok(self.rank_hybrid_results(hybrid_results))
     }
 
     /// Weighted scoring fusion algorithm
@@ -1109,113 +1196,155 @@ impl HybridSearchService {
         &self,
         vector_results: Vec<SearchResult>,
         keyword_results: Vec<KeywordSearchResult>,
-        weights: (f64, f64),
+        params: &FusionParams,
         request: &HybridSearchRequest,
     ) -> VectorResult<Vec<HybridSearchResult>> {
+        let params = FusionParams {
+            method: FusionMethod::WeightedScoring,
+            ..params.clone()
+        };
+        let (vector_weight, keyword_weight) = params.weights;
         let mut hybrid_results = HashMap::new();
 
-        // Normalize vector scores
-        let max_vector_score = vector_results
-            .iter()
-            .map(|r| r.relevance_score)
-            .fold(0.0, f64::max);
-
-        // Normalize keyword scores
-        let max_keyword_score = keyword_results
-            .iter()
-            .map(|r| r.tf_idf_score)
-            .fold(0.0, f64::max);
+        // Normalize each source independently so their scales are comparable
+        let vector_scores: Vec<f64> = vector_results.iter().map(|r| r.relevance_score).collect();
+        let keyword_scores: Vec<f64> = keyword_results.iter().map(|r| r.tf_idf_score).collect();
+        let normalized_vector = params.normalization.normalize(&vector_scores);
+        let normalized_keyword = params.normalization.normalize(&keyword_scores);
 
         // Process vector results
-        for (rank, result) in vector_results.iter().enumerate() {
-            let normalized_score = if max_vector_score > 0.0 {
-                result.relevance_score / max_vector_score
-            } else {
-                0.0
+        for (rank, (result, normalized)) in vector_results.iter().zip(normalized_vector).enumerate()
+        {
+            let score = SourceScore {
+                rank,
+                normalized: Some(normalized),
+                contribution: normalized * vector_weight,
             };
-            let weighted_score = normalized_score * weights.0;
-
             hybrid_results.insert(
                 result.document.id.clone(),
-                HybridSearchResult {
-                    document: result.document.clone(),
-                    vector_score: Some(result.relevance_score),
-                    keyword_score: None,
-                    hybrid_score: weighted_score,
-                    fusion_method: FusionMethod::WeightedScoring,
-                    strategy: request.strategy.clone().unwrap_or(SearchStrategy::Balanced),
-                    explanation: None,
-                    match_metadata: HybridMatchMetadata {
-                        base_metadata: result.match_metadata.clone(),
-                        vector_rank: Some(rank),
-                        keyword_rank: None,
-                        matched_terms: 0,
-                        total_query_terms: self.tokenize_query(&request.query).len(),
-                        query_coverage: 0.0,
-                    },
-                    search_sources: SearchSources {
-                        from_vector: true,
-                        from_keyword: false,
-                        combined: false,
-                    },
-                },
+                self.vector_hybrid_result(result, score, &params, request),
             );
         }
 
         // Process keyword results
-        for (rank, result) in keyword_results.iter().enumerate() {
-            let normalized_score = if max_keyword_score > 0.0 {
-                result.tf_idf_score / max_keyword_score
-            } else {
-                0.0
+        for (rank, (result, normalized)) in
+            keyword_results.iter().zip(normalized_keyword).enumerate()
+        {
+            let score = SourceScore {
+                rank,
+                normalized: Some(normalized),
+                contribution: normalized * keyword_weight,
             };
-            let weighted_score = normalized_score * weights.1;
-
-            if let Some(existing) = hybrid_results.get_mut(&result.document.id) {
-                // Combine with existing vector result
-                existing.keyword_score = Some(result.tf_idf_score);
-                existing.hybrid_score += weighted_score;
-                existing.match_metadata.keyword_rank = Some(rank);
-                existing.match_metadata.matched_terms = result.matched_terms;
-                existing.match_metadata.query_coverage = result.query_coverage;
-                existing.search_sources.from_keyword = true;
-                existing.search_sources.combined = true;
-            } else {
-                // New keyword-only result
-                hybrid_results.insert(
-                    result.document.id.clone(),
-                    HybridSearchResult {
-                        document: result.document.clone(),
-                        vector_score: None,
-                        keyword_score: Some(result.tf_idf_score),
-                        hybrid_score: weighted_score,
-                        fusion_method: FusionMethod::WeightedScoring,
-                        strategy: request.strategy.clone().unwrap_or(SearchStrategy::Balanced),
-                        explanation: None,
-                        match_metadata: HybridMatchMetadata {
-                            base_metadata: MatchMetadata {
-                                search_time_ms: 0.0,
-                                original_rank: rank,
-                                final_rank: 0,
-                                matched_filters: 0,
-                                snippet: Some(self.create_snippet(&result.document.content)),
-                            },
-                            vector_rank: None,
-                            keyword_rank: Some(rank),
-                            matched_terms: result.matched_terms,
-                            total_query_terms: self.tokenize_query(&request.query).len(),
-                            query_coverage: result.query_coverage,
-                        },
-                        search_sources: SearchSources {
-                            from_vector: false,
-                            from_keyword: true,
-                            combined: false,
-                        },
-                    },
-                );
-            }
+            self.merge_keyword_result(&mut hybrid_results, result, score, &params, request);
+        }
+
+        Ok(self.rank_hybrid_results(hybrid_results))
+    }
+
+    /// Build a hybrid result seeded from a vector search hit
+    fn vector_hybrid_result(
+        &self,
+        result: &SearchResult,
+        score: SourceScore,
+        params: &FusionParams,
+        request: &HybridSearchRequest,
+    ) -> HybridSearchResult {
+        HybridSearchResult {
+            document: result.document.clone(),
+            vector_score: Some(result.relevance_score),
+            keyword_score: None,
+            hybrid_score: score.contribution,
+            fusion_method: params.method.clone(),
+            strategy: request.strategy.clone().unwrap_or(SearchStrategy::Balanced),
+            explanation: None,
+            fusion_contribution: FusionContribution {
+                vector_normalized_score: score.normalized,
+                vector_contribution: Some(score.contribution),
+                ..params.base_contribution()
+            },
+            match_metadata: HybridMatchMetadata {
+                base_metadata: result.match_metadata.clone(),
+                vector_rank: Some(score.rank),
+                keyword_rank: None,
+                matched_terms: 0,
+                total_query_terms: self.tokenize_query(&request.query).len(),
+                query_coverage: 0.0,
+            },
+            search_sources: SearchSources {
+                from_vector: true,
+                from_keyword: false,
+                combined: false,
+            },
         }
+    }
 
+    /// Fold a keyword search hit into the fused results
+    fn merge_keyword_result(
+        &self,
+        hybrid_results: &mut HashMap<String, HybridSearchResult>,
+        result: &KeywordSearchResult,
+        score: SourceScore,
+        params: &FusionParams,
+        request: &HybridSearchRequest,
+    ) {
+        if let Some(existing) = hybrid_results.get_mut(&result.document.id) {
+            // Combine with existing vector result
+            existing.keyword_score = Some(result.tf_idf_score);
+            existing.hybrid_score += score.contribution;
+            existing.fusion_contribution.keyword_normalized_score = score.normalized;
+            existing.fusion_contribution.keyword_contribution = Some(score.contribution);
+            existing.match_metadata.keyword_rank = Some(score.rank);
+            existing.match_metadata.matched_terms = result.matched_terms;
+            existing.match_metadata.query_coverage = result.query_coverage;
+            existing.search_sources.from_keyword = true;
+            existing.search_sources.combined = true;
+            return;
+        }
+
+        // New keyword-only result
+        hybrid_results.insert(
+            result.document.id.clone(),
+            HybridSearchResult {
+                document: result.document.clone(),
+                vector_score: None,
+                keyword_score: Some(result.tf_idf_score),
+                hybrid_score: score.contribution,
+                fusion_method: params.method.clone(),
+                strategy: request.strategy.clone().unwrap_or(SearchStrategy::Balanced),
+                explanation: None,
+                fusion_contribution: FusionContribution {
+                    keyword_normalized_score: score.normalized,
+                    keyword_contribution: Some(score.contribution),
+                    ..params.base_contribution()
+                },
+                match_metadata: HybridMatchMetadata {
+                    base_metadata: MatchMetadata {
+                        search_time_ms: 0.0,
+                        original_rank: score.rank,
+                        final_rank: 0, // Will be updated later
+                        matched_filters: 0,
+                        snippet: Some(self.create_snippet(&result.document.content)),
+                    },
+                    vector_rank: None,
+                    keyword_rank: Some(score.rank),
+                    matched_terms: result.matched_terms,
+                    total_query_terms: self.tokenize_query(&request.query).len(),
+                    query_coverage: result.query_coverage,
+                },
+                search_sources: SearchSources {
+                    from_vector: false,
+                    from_keyword: true,
+                    combined: false,
+                },
+            },
+        );
+    }
+
+    /// Sort fused results by hybrid score and assign final ranks
+    fn rank_hybrid_results(
+        &self,
+        hybrid_results: HashMap<String, HybridSearchResult>,
+    ) -> Vec<HybridSearchResult> {
         let mut results: Vec<_> = hybrid_results.into_values().collect();
 
         // Sort by hybrid score descending
@@ -1230,7 +1359,7 @@ impl HybridSearchService {
             result.match_metadata.base_metadata.final_rank = index;
         }
 
-        Ok(results)
+        results
     }
 
     /// Simple rank-based fusion
@@ -1238,11 +1367,11 @@ impl HybridSearchService {
         &self,
         vector_results: Vec<SearchResult>,
         keyword_results: Vec<KeywordSearchResult>,
-        weights: (f64, f64),
+        params: &FusionParams,
         request: &HybridSearchRequest,
     ) -> VectorResult<Vec<HybridSearchResult>> {
         // For simplicity, delegate to RRF with different parameters
-        self.reciprocal_rank_fusion(vector_results, keyword_results, weights, request)
+        self.reciprocal_rank_fusion(vector_results, keyword_results, params, request)
             .await
     }
 
@@ -1251,11 +1380,11 @@ impl HybridSearchService {
         &self,
         vector_results: Vec<SearchResult>,
         keyword_results: Vec<KeywordSearchResult>,
-        weights: (f64, f64),
+        params: &FusionParams,
         request: &HybridSearchRequest,
     ) -> VectorResult<Vec<HybridSearchResult>> {
         // For simplicity, delegate to weighted scoring
-        self.weighted_scoring_fusion(vector_results, keyword_results, weights, request)
+        self.weighted_scoring_fusion(vector_results, keyword_results, params, request)
             .await
     }
 
@@ -1264,11 +1393,11 @@ impl HybridSearchService {
         &self,
         vector_results: Vec<SearchResult>,
         keyword_results: Vec<KeywordSearchResult>,
-        weights: (f64, f64),
+        params: &FusionParams,
         request: &HybridSearchRequest,
     ) -> VectorResult<Vec<HybridSearchResult>> {
         // For simplicity, delegate to weighted scoring
-        self.weighted_scoring_fusion(vector_results, keyword_results, weights, request)
+        self.weighted_scoring_fusion(vector_results, keyword_results, params, request)
             .await
     }
 
@@ -1351,33 +1480,88 @@ impl HybridSearchService {
 
     /// Create explanation for hybrid search result
     fn create_explanation(&self, result: &HybridSearchResult) -> HybridSearchExplanation {
-        let vector_contrib = result.vector_score.map(|score| score * 0.6); // Using default weight
-        let keyword_contrib = result.keyword_score.map(|score| score * 0.4);
+        let contribution = &result.fusion_contribution;
+        let (vector_weight, keyword_weight) = contribution.weights;
+
+        let fusion_details = match (contribution.rrf_k, contribution.normalization) {
+            (Some(k), _) => format!("{:?} fusion applied (k = {k})", result.fusion_method),
+            (None, Some(normalization)) => format!(
+                "{:?} fusion applied with {normalization:?} score normalization",
+                result.fusion_method
+            ),
+            (None, None) => format!("{:?} fusion applied", result.fusion_method),
+        };
+
+        let describe = |source: &str,
+                        weight: f64,
+                        rank: Option<usize>,
+                        normalized: Option<f64>,
+                        value: Option<f64>| {
+            value.map(|value| match (contribution.rrf_k, rank, normalized) {
+                (Some(k), Some(rank), _) => {
+                    format!("{source}({weight:.2} / ({k} + {})) = {value:.4}", rank + 1)
+                }
+                (_, _, Some(normalized)) => {
+                    format!("{source}({normalized:.3} * {weight:.2}) = {value:.4}")
+                }
+                _ => format!("{source}({value:.4})"),
+            })
+        };
 
-        let calculation = match (&result.vector_score, &result.keyword_score) {
-            (Some(vs), Some(ks)) => format!(
-                "vector({:.3} * 0.6) + keyword({:.3} * 0.4) = {:.3}",
-                vs, ks, result.hybrid_score
+        let terms: Vec<String> = [
+            describe(
+                "vector",
+                vector_weight,
+                result.match_metadata.vector_rank,
+                contribution.vector_normalized_score,
+                contribution.vector_contribution,
             ),
-            (Some(vs), None) => format!("vector_only({:.3}) = {:.3}", vs, result.hybrid_score),
-            (None, Some(ks)) => format!("keyword_only({:.3}) = {:.3}", ks, result.hybrid_score),
-            (None, None) => "no_scores_available".to_string(),
+            describe(
+                "keyword",
+                keyword_weight,
+                result.match_metadata.keyword_rank,
+                contribution.keyword_normalized_score,
+                contribution.keyword_contribution,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let calculation = if terms.is_empty() {
+            "no_scores_available".to_string()
+        } else {
+            format!("{} => {:.4}", terms.join(" + "), result.hybrid_score)
         };
 
         HybridSearchExplanation {
-            vector_contribution: vector_contrib,
-            keyword_contribution: keyword_contrib,
-            fusion_details: format!("{:?} fusion applied", result.fusion_method),
+            vector_contribution: contribution.vector_contribution,
+            keyword_contribution: contribution.keyword_contribution,
+            fusion_details,
             strategy_rationale: format!("{:?} strategy used", result.strategy),
-            weights: (0.6, 0.4), // Default weights for now
+            weights: contribution.weights,
+            normalization: contribution.normalization,
             calculation,
         }
     }
 
+    /// Cache key covering the query and every request setting that changes scoring
+    fn cache_key(request: &HybridSearchRequest) -> String {
+        format!(
+            "{}|{:?}|{:?}|{:?}|{:?}|{:?}",
+            request.query,
+            request.strategy,
+            request.fusion_method,
+            request.score_normalization,
+            request.rrf_k,
+            request.custom_weights
+        )
+    }
+
     /// Check cache for existing results
-    async fn check_cache(&self, query: &str) -> Option<HybridSearchResultSet> {
+    async fn check_cache(&self, key: &str) -> Option<HybridSearchResultSet> {
         let cache = self.cache.read().await;
-        if let Some((result_set, timestamp)) = cache.get(query) {
+        if let Some((result_set, timestamp)) = cache.get(key) {
             let age = timestamp.elapsed().as_secs();
             if age < self.config.cache_ttl_seconds {
                 return Some(result_set.clone());
@@ -1387,9 +1571,9 @@ impl HybridSearchService {
     }
 
     /// Cache search results
-    async fn cache_result(&self, query: &str, result_set: &HybridSearchResultSet) {
+    async fn cache_result(&self, key: &str, result_set: &HybridSearchResultSet) {
         let mut cache = self.cache.write().await;
-        cache.insert(query.to_string(), (result_set.clone(), Instant::now()));
+        cache.insert(key.to_string(), (result_set.clone(), Instant::now()));
 
         // Simple cache cleanup - remove entries older than TTL
         let ttl = self.config.cache_ttl_seconds;
@@ -1505,6 +1689,8 @@ impl HybridSearchOperations for HybridSearchService {
             query: query.to_string(),
             strategy: Some(query_analysis.recommended_strategy.clone()),
             fusion_method: Some(self.config.default_fusion_method.clone()),
+            score_normalization: None,
+            rrf_k: None,
             options,
             include_explanations: true,
             custom_weights: None,
@@ -1533,6 +1719,8 @@ impl HybridSearchOperations for HybridSearchService {
                 query: query.to_string(),
                 strategy: Some(strategy.clone()),
                 fusion_method: Some(self.config.default_fusion_method.clone()),
+                score_normalization: None,
+                rrf_k: None,
                 options: options.clone(),
                 include_explanations: true,
                 custom_weights: None,
@@ -1600,6 +1788,8 @@ impl HybridSearchOperations for HybridSearchService {
             query: query.to_string(),
             strategy: Some(fallback_strategy),
             fusion_method: Some(FusionMethod::ReciprocalRankFusion), // Use robust fusion method
+            score_normalization: None,
+            rrf_k: None,
             options,
             include_explanations: true,
             custom_weights: None,
@@ -1623,6 +1813,8 @@ impl HybridSearchService {
             query: query.to_string(),
             strategy: None,      // Use default or adaptive
             fusion_method: None, // Use default
+            score_normalization: None,
+            rrf_k: None,
             options: SearchOptions::default(),
             include_explanations: false,
             custom_weights: None,
@@ -1644,6 +1836,8 @@ impl HybridSearchService {
             query: query.to_string(),
             strategy: None,
             fusion_method: Some(fusion_method),
+            score_normalization: None,
+            rrf_k: None,
             options,
             include_explanations: true,
             custom_weights: None,
@@ -1666,6 +1860,8 @@ impl HybridSearchService {
             query: query.to_string(),
             strategy: Some(strategy),
             fusion_method: None,
+            score_normalization: None,
+            rrf_k: None,
             options,
             include_explanations: true,
             custom_weights: weights,
@@ -1837,6 +2033,83 @@ mod tests {
         assert_eq!(methods.len(), 6);
     }
 
+    #[test]
+    fn test_score_normalization_strategies() {
+        let scores = [2.0, 4.0, 6.0];
+
+        assert_eq!(
+            ScoreNormalization::MaxScore.normalize(&scores),
+            vec![2.0 / 6.0, 4.0 / 6.0, 1.0]
+        );
+        assert_eq!(
+            ScoreNormalization::MinMax.normalize(&scores),
+            vec![0.0, 0.5, 1.0]
+        );
+        assert_eq!(ScoreNormalization::None.normalize(&scores), scores.to_vec());
+
+        let z = ScoreNormalization::ZScore.normalize(&scores);
+        assert!(z[1].abs() < 1e-9);
+        assert!((z[0] + z[2]).abs() < 1e-9);
+        assert!((z[2] - 1.5_f64.sqrt()).abs() < 1e-9);
+
+        // Degenerate inputs stay finite
+        assert_eq!(
+            ScoreNormalization::MinMax.normalize(&[0.3, 0.3]),
+            vec![1.0, 1.0]
+        );
+        assert_eq!(
+            ScoreNormalization::ZScore.normalize(&[0.3, 0.3]),
+            vec![0.0, 0.0]
+        );
+        assert!(ScoreNormalization::MinMax.normalize(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_fusion_params_contribution() {
+        let rrf = FusionParams {
+            method: FusionMethod::ReciprocalRankFusion,
+            weights: (0.7, 0.3),
+            normalization: ScoreNormalization::ZScore,
+            rrf_k: 20.0,
+        };
+        let contribution = rrf.base_contribution();
+        assert_eq!(contribution.rrf_k, Some(20.0));
+        assert_eq!(contribution.normalization, None);
+        assert_eq!(contribution.weights, (0.7, 0.3));
+
+        let weighted = FusionParams {
+            method: FusionMethod::WeightedScoring,
+            ..rrf
+        };
+        let contribution = weighted.base_contribution();
+        assert_eq!(contribution.rrf_k, None);
+        assert_eq!(contribution.normalization, Some(ScoreNormalization::ZScore));
+    }
+
+    #[test]
+    fn test_hybrid_search_request_fusion_defaults() {
+        let request: HybridSearchRequest = serde_json::from_value(serde_json::json!({
+            "query": "rust async",
+            "strategy": null,
+            "fusion_method": "WeightedScoring",
+            "options": SearchOptions::default(),
+            "include_explanations": true,
+            "custom_weights": null,
+            "min_hybrid_score": null
+        }))
+        .unwrap();
+
+        assert_eq!(request.score_normalization, None);
+        assert_eq!(request.rrf_k, None);
+
+        let config = HybridSearchConfig::default();
+        assert_eq!(config.rrf_k, 60.0);
+        assert_eq!(
+            config.default_score_normalization,
+            ScoreNormalization::MaxScore
+        );
+    }
+
     #[test]
     fn test_query_type_detection() {
         // Test query type enumeration
@@ -1957,6 +2230,8 @@ mod tests {
             query: "test query".to_string(),
             strategy: Some(SearchStrategy::Balanced),
             fusion_method: Some(FusionMethod::ReciprocalRankFusion),
+            score_normalization: Some(ScoreNormalization::ZScore),
+            rrf_k: Some(30.0),
             options: SearchOptions::default(),
             include_explanations: true,
             custom_weights: Some((0.7, 0.3)),
@@ -2040,6 +2315,7 @@ mod tests {
             fusion_details: "RRF fusion applied".to_string(),
             strategy_rationale: "Balanced strategy used".to_string(),
             weights: (0.6, 0.4),
+            normalization: Some(ScoreNormalization::MinMax),
             calculation: "vector(0.8 * 0.6) + keyword(0.4 * 0.4) = 0.64".to_string(),
         };
 
@@ -2221,6 +2497,35 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_keyword_search_query_language() {
+        let mut searcher = KeywordSearcher::new();
+
+        let documents = vec![
+            create_test_document("doc1", "Rust async programming guide with tokio runtime"),
+            create_test_document("doc2", "Deprecated tokio APIs in old Rust code"),
+            create_test_document("doc3", "Python async programming tutorial"),
+        ];
+
+        searcher.index_documents(documents).await.unwrap();
+
+        let results = searcher
+            .search_keywords("rust AND (tokio OR smol) -deprecated", 10)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].document.id, "doc1");
+
+        let results = searcher
+            .search_keywords("\"async programming\" NOT python", 10)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+
+        let err = searcher.search_keywords("(rust", 10).await.unwrap_err();
+        assert!(matches!(err, VectorError::InvalidQuery(_)));
+    }
+
     #[tokio::test]
     async fn test_keyword_search_scoring() {
         let mut searcher = KeywordSearcher::new();
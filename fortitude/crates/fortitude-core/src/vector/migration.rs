@@ -18,10 +18,13 @@
 //! batch processing, progress tracking, error handling, and state management for
 //! large-scale migration operations.
 
+use crate::atomic_file::write_json_atomic_async;
 use crate::storage::FileStorage;
 use crate::vector::{
+    embeddings::EmbeddingGenerator,
     error::VectorError,
-    storage::{DocumentMetadata, VectorStorageService},
+    migration_validation::{DocumentValidator, ValidationReport},
+    storage::{DocumentMetadata, SearchConfig, VectorStorageService},
 };
 use chrono::{DateTime, Utc};
 use fortitude_types::{
@@ -30,7 +33,7 @@ use fortitude_types::{
     Storage,
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -118,6 +121,20 @@ pub struct MigrationConfig {
     pub dry_run: bool,
     /// Custom metadata to add to migrated documents
     pub custom_metadata: HashMap<String, serde_json::Value>,
+    /// Minimum quality score accepted by strict validation
+    #[serde(default = "default_min_quality_score")]
+    pub min_quality_score: f64,
+    /// Stored documents spot-checked by similarity search after the migration
+    #[serde(default = "default_verification_samples")]
+    pub verification_samples: usize,
+}
+
+fn default_min_quality_score() -> f64 {
+    0.5
+}
+
+fn default_verification_samples() -> usize {
+    5
 }
 
 impl Default for MigrationConfig {
@@ -131,14 +148,19 @@ impl Default for MigrationConfig {
             retry_delay_ms: 1000,
             dry_run: false,
             custom_metadata: HashMap::new(),
+            min_quality_score: default_min_quality_score(),
+            verification_samples: default_verification_samples(),
         }
     }
 }
 
 /// Level of validation to perform during migration
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Levels are ordered; each one runs the checks of the levels before it. See
+/// [`crate::vector::migration_validation`] for what each level checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum ValidationLevel {
-    /// Basic validation (existence and format)
+    /// Basic validation (existence and format), also accepted as `lenient`
     Basic,
     /// Standard validation (content integrity)
     Standard,
@@ -150,6 +172,36 @@ pub enum ValidationLevel {
     Strict,
 }
 
+impl std::fmt::Display for ValidationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ValidationLevel::Basic => "basic",
+            ValidationLevel::Standard => "standard",
+            ValidationLevel::Moderate => "moderate",
+            ValidationLevel::Comprehensive => "comprehensive",
+            ValidationLevel::Strict => "strict",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for ValidationLevel {
+    type Err = MigrationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "lenient" | "basic" => Ok(ValidationLevel::Basic),
+            "standard" => Ok(ValidationLevel::Standard),
+            "moderate" => Ok(ValidationLevel::Moderate),
+            "comprehensive" => Ok(ValidationLevel::Comprehensive),
+            "strict" => Ok(ValidationLevel::Strict),
+            other => Err(MigrationError::ValidationFailed(format!(
+                "Unknown validation level '{other}' (expected strict, moderate or lenient)"
+            ))),
+        }
+    }
+}
+
 /// Progress tracking for migration operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MigrationProgress {
@@ -255,6 +307,12 @@ pub struct MigrationStatistics {
     pub content_type_distribution: HashMap<String, u64>,
     /// Research type distribution
     pub research_type_distribution: HashMap<ResearchType, u64>,
+    /// Documents excluded by validation
+    #[serde(default)]
+    pub validation_rejected: u64,
+    /// Location of the per-document validation report
+    #[serde(default)]
+    pub validation_report: Option<PathBuf>,
 }
 
 /// Statistics for batch processing operations
@@ -287,8 +345,11 @@ pub struct MigrationState {
     pub progress: MigrationProgress,
     /// Migration statistics
     pub statistics: Option<MigrationStatistics>,
-    /// Successfully processed items
+    /// Ids of the vector documents stored by this migration
     pub processed_items: Vec<String>,
+    /// Cache keys of source items already stored; the resume checkpoint
+    #[serde(default)]
+    pub completed_keys: Vec<String>,
     /// Failed items with error information
     pub failed_items: Vec<FailedItem>,
     /// Created timestamp
@@ -297,8 +358,44 @@ pub struct MigrationState {
     pub updated_at: DateTime<Utc>,
     /// Error message if migration failed
     pub error_message: Option<String>,
+    /// Result of the verification pass run after completion
+    #[serde(default)]
+    pub verification: Option<MigrationVerification>,
+}
+
+/// Comparison of a finished migration against what vector storage holds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationVerification {
+    /// Items found in the source when the migration started
+    pub source_items: u64,
+    /// Source items stored by the migration
+    pub migrated_items: u64,
+    /// Documents the migration recorded as stored
+    pub expected_documents: u64,
+    /// Recorded documents still retrievable from vector storage
+    pub found_documents: u64,
+    /// Documents in the vector storage overall
+    pub storage_documents: u64,
+    /// Similarity checks on a sample of the stored documents
+    pub spot_checks: Vec<SpotCheck>,
+    /// All recorded documents were found and every spot check passed
+    pub passed: bool,
+    pub verified_at: DateTime<Utc>,
+}
+
+/// Search for a stored document using its own content
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpotCheck {
+    pub document_id: String,
+    pub cache_key: Option<String>,
+    /// Score of the document in its own results, if it was returned at all
+    pub score: Option<f64>,
+    pub passed: bool,
 }
 
+/// Lowest self-similarity score a spot-checked document may have
+const SPOT_CHECK_MIN_SCORE: f64 = 0.9;
+
 /// Information about a failed migration item
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FailedItem {
@@ -546,6 +643,8 @@ pub struct MigrationService {
     /// Current migrations
     active_migrations:
         Arc<tokio::sync::RwLock<HashMap<String, Arc<tokio::sync::RwLock<MigrationState>>>>>,
+    /// Embedding generator used by comprehensive and strict validation
+    embeddings: Option<Arc<dyn EmbeddingGenerator>>,
 }
 
 impl MigrationService {
@@ -559,7 +658,56 @@ impl MigrationService {
             converter: DataConverter::new(),
             state_dir,
             active_migrations: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            embeddings: None,
+        }
+    }
+
+    /// Check embeddings of migrated content at the comprehensive and strict levels
+    pub fn with_embeddings(mut self, embeddings: Arc<dyn EmbeddingGenerator>) -> Self {
+        self.embeddings = Some(embeddings);
+        self
+    }
+
+    /// Path of the validation report written for a migration
+    pub fn validation_report_path(&self, migration_id: &str) -> PathBuf {
+        self.state_dir
+            .join("reports")
+            .join(format!("{migration_id}-validation.json"))
+    }
+
+    /// Validation report of a migration, if one has been written
+    pub async fn get_validation_report(
+        &self,
+        migration_id: &str,
+    ) -> MigrationResult<Option<ValidationReport>> {
+        let path = self.validation_report_path(migration_id);
+        if !path.exists() {
+            return Ok(None);
         }
+        let content = fs::read_to_string(path).await?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    /// Write a validation report, returning its path
+    async fn write_validation_report(&self, report: &ValidationReport) -> MigrationResult<PathBuf> {
+        let path = self.validation_report_path(&report.migration_id);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).await?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(report)?).await?;
+        info!(
+            "Validation report for migration {}: {} accepted, {} rejected ({})",
+            report.migration_id,
+            report.accepted,
+            report.rejected,
+            path.display()
+        );
+        Ok(path)
+    }
+
+    fn document_validator(&self, config: &MigrationConfig) -> DocumentValidator {
+        DocumentValidator::new(config.validation_level, config.min_quality_score)
+            .with_embeddings(self.embeddings.clone())
     }
 
     /// Initialize the migration service
@@ -618,10 +766,12 @@ impl MigrationService {
             progress,
             statistics: None,
             processed_items: Vec::new(),
+            completed_keys: Vec::new(),
             failed_items: Vec::new(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
             error_message: None,
+            verification: None,
         };
 
         // Store migration state
@@ -679,9 +829,19 @@ impl MigrationService {
         let result = self.process_migration_data(&state_lock).await;
 
         // Update final state
-        {
+        let completed = {
             let mut state = state_lock.write().await;
             match &result {
+                // Paused or cancelled between batches; the checkpoint is already saved
+                Ok(statistics)
+                    if matches!(
+                        state.status,
+                        MigrationStatus::Paused | MigrationStatus::Cancelled
+                    ) =>
+                {
+                    state.statistics = Some(statistics.clone());
+                    info!("Migration {} stopped as {:?}", migration_id, state.status);
+                }
                 Ok(statistics) => {
                     state.status = MigrationStatus::Completed;
                     state.statistics = Some(statistics.clone());
@@ -696,6 +856,24 @@ impl MigrationService {
                 }
             }
             state.updated_at = Utc::now();
+            state.status == MigrationStatus::Completed
+        };
+
+        if completed {
+            match self.verify_state(&state_lock).await {
+                Ok(verification) => {
+                    if !verification.passed {
+                        warn!(
+                            "Migration {} verification failed: {} of {} documents found",
+                            migration_id,
+                            verification.found_documents,
+                            verification.expected_documents
+                        );
+                    }
+                    state_lock.write().await.verification = Some(verification);
+                }
+                Err(e) => warn!("Failed to verify migration {}: {}", migration_id, e),
+            }
         }
 
         // Persist final state
@@ -704,23 +882,126 @@ impl MigrationService {
         result.map(|_| ())
     }
 
+    /// Compare a migration against vector storage and spot-check similarity scores
+    #[instrument(skip(self))]
+    pub async fn verify_migration(
+        &self,
+        migration_id: &str,
+    ) -> MigrationResult<MigrationVerification> {
+        let state_lock = {
+            let migrations = self.active_migrations.read().await;
+            migrations
+                .get(migration_id)
+                .ok_or_else(|| MigrationError::MigrationNotFound(migration_id.to_string()))?
+                .clone()
+        };
+
+        let verification = self.verify_state(&state_lock).await?;
+        state_lock.write().await.verification = Some(verification.clone());
+        self.persist_migration_state(&state_lock).await?;
+        Ok(verification)
+    }
+
+    async fn verify_state(
+        &self,
+        state_lock: &Arc<tokio::sync::RwLock<MigrationState>>,
+    ) -> MigrationResult<MigrationVerification> {
+        let (source_items, migrated_items, document_ids, samples) = {
+            let state = state_lock.read().await;
+            (
+                state.progress.total_items,
+                state.completed_keys.len() as u64,
+                state.processed_items.clone(),
+                state.config.verification_samples,
+            )
+        };
+
+        let mut found = Vec::new();
+        for ids in document_ids.chunks(100) {
+            found.extend(
+                self.vector_storage
+                    .retrieve_batch(ids.to_vec())
+                    .await?
+                    .successful,
+            );
+        }
+        let storage_documents = self.vector_storage.get_stats().await?.total_documents;
+
+        // Spread the sample over the whole run rather than the first batch
+        let step = (found.len() / samples.max(1)).max(1);
+        let mut spot_checks = Vec::new();
+        for document in found.iter().step_by(step).take(samples) {
+            let results = self
+                .vector_storage
+                .retrieve_similar(
+                    &document.content,
+                    SearchConfig {
+                        limit: 5,
+                        ..Default::default()
+                    },
+                )
+                .await?;
+            let score = results
+                .iter()
+                .find(|r| r.document.id == document.id)
+                .map(|r| r.score);
+            spot_checks.push(SpotCheck {
+                document_id: document.id.clone(),
+                cache_key: cache_key_of(&document.metadata),
+                score,
+                passed: score.is_some_and(|s| s >= SPOT_CHECK_MIN_SCORE),
+            });
+        }
+
+        let found_documents = found.len() as u64;
+        let expected_documents = document_ids.len() as u64;
+        Ok(MigrationVerification {
+            source_items,
+            migrated_items,
+            expected_documents,
+            found_documents,
+            storage_documents,
+            passed: found_documents == expected_documents && spot_checks.iter().all(|c| c.passed),
+            spot_checks,
+            verified_at: Utc::now(),
+        })
+    }
+
     /// Process migration data in batches
     #[instrument(skip(self, state_lock))]
     async fn process_migration_data(
         &self,
         state_lock: &Arc<tokio::sync::RwLock<MigrationState>>,
     ) -> MigrationResult<MigrationStatistics> {
-        let (source, config) = {
+        let (migration_id, source, config) = {
             let state = state_lock.read().await;
-            (state.source.clone(), state.config.clone())
+            (state.id.clone(), state.source.clone(), state.config.clone())
         };
 
-        // Load data from source
-        let data_items = self.load_source_data(&source).await?;
+        // Load data from source, recording files that do not parse
+        let mut validator = self.document_validator(&config);
+        let mut data_items = self.load_validated_source(&source, &mut validator).await?;
         info!("Loaded {} items from source", data_items.len());
 
+        // Skip items stored by an earlier run of this migration
+        let already_processed = {
+            let mut state = state_lock.write().await;
+            if config.enable_resume && !state.completed_keys.is_empty() {
+                let completed: HashSet<&String> = state.completed_keys.iter().collect();
+                data_items.retain(|item| !completed.contains(&item.metadata.cache_key));
+                info!(
+                    "Resuming after {} completed items, {} remaining",
+                    completed.len(),
+                    data_items.len()
+                );
+            }
+            // Everything not yet stored is attempted again
+            state.failed_items.clear();
+            state.completed_keys.len() as u64
+        };
+
         // Initialize statistics tracking
-        let mut total_processed = 0u64;
+        let mut total_processed = already_processed;
         let mut total_failed = 0u64;
         let mut batch_times = Vec::new();
         let mut error_breakdown: HashMap<String, u64> = HashMap::new();
@@ -736,6 +1017,17 @@ impl MigrationService {
         );
 
         for (batch_index, batch) in batches.into_iter().enumerate() {
+            let status = state_lock.read().await.status.clone();
+            if matches!(status, MigrationStatus::Paused | MigrationStatus::Cancelled) {
+                info!(
+                    "Migration {} {:?} before batch {}",
+                    migration_id,
+                    status,
+                    batch_index + 1
+                );
+                break;
+            }
+
             let batch_start = Instant::now();
 
             // Update progress
@@ -749,7 +1041,9 @@ impl MigrationService {
             }
 
             // Process batch
-            let batch_result = self.process_batch(batch.to_vec(), &config).await;
+            let batch_result = self
+                .process_batch(batch.to_vec(), &config, &mut validator)
+                .await;
             let batch_time = batch_start.elapsed();
             batch_times.push(batch_time);
 
@@ -773,9 +1067,13 @@ impl MigrationService {
                         *research_type_distribution.entry(research_type).or_insert(0) += count;
                     }
 
-                    // Update failed items in state
+                    // Record the checkpoint and failed items in state
                     {
                         let mut state = state_lock.write().await;
+                        for (document_id, cache_key) in batch_stats.stored_documents {
+                            state.processed_items.push(document_id);
+                            state.completed_keys.extend(cache_key);
+                        }
                         state.failed_items.extend(batch_stats.failed_item_details);
                     }
                 }
@@ -795,10 +1093,8 @@ impl MigrationService {
                 state.progress.update(total_processed, total_failed, 0);
             }
 
-            // Persist state periodically
-            if batch_index % 10 == 0 {
-                self.persist_migration_state(state_lock).await?;
-            }
+            // Checkpoint after every batch so an interrupted run can resume
+            self.persist_migration_state(state_lock).await?;
 
             info!("Completed batch {} in {:?}", batch_index + 1, batch_time);
         }
@@ -822,6 +1118,24 @@ impl MigrationService {
         let fastest_batch = batch_times.iter().min().copied().unwrap_or_default();
         let slowest_batch = batch_times.iter().max().copied().unwrap_or_default();
 
+        let avg_embedding_time_ms = match self.vector_storage.get_stats().await {
+            Ok(stats) => stats.avg_embedding_time_ms,
+            Err(e) => {
+                warn!("Failed to read vector storage statistics: {}", e);
+                0.0
+            }
+        };
+
+        let report = validator.into_report(migration_id);
+        let validation_rejected = report.rejected;
+        let validation_report = match self.write_validation_report(&report).await {
+            Ok(path) => Some(path),
+            Err(e) => {
+                warn!("Failed to write validation report: {}", e);
+                None
+            }
+        };
+
         let statistics = MigrationStatistics {
             total_duration,
             avg_item_processing_time: if total_processed > 0 {
@@ -829,8 +1143,8 @@ impl MigrationService {
             } else {
                 Duration::default()
             },
-            peak_memory_usage_mb: 0,    // Would need system monitoring
-            avg_embedding_time_ms: 0.0, // Would get from vector storage stats
+            peak_memory_usage_mb: 0, // Would need system monitoring
+            avg_embedding_time_ms,
             batch_stats: BatchStatistics {
                 total_batches: batch_times.len() as u64,
                 avg_batch_time,
@@ -845,6 +1159,8 @@ impl MigrationService {
             error_breakdown,
             content_type_distribution,
             research_type_distribution,
+            validation_rejected,
+            validation_report,
         };
 
         info!(
@@ -856,11 +1172,12 @@ impl MigrationService {
     }
 
     /// Process a single batch of data items
-    #[instrument(skip(self, items, config))]
+    #[instrument(skip(self, items, config, validator))]
     async fn process_batch(
         &self,
         items: Vec<ResearchResult>,
         config: &MigrationConfig,
+        validator: &mut DocumentValidator,
     ) -> MigrationResult<BatchProcessingStats> {
         let mut successful_items = 0u64;
         let mut failed_items = 0u64;
@@ -868,10 +1185,31 @@ impl MigrationService {
         let mut content_type_distribution: HashMap<String, u64> = HashMap::new();
         let mut research_type_distribution: HashMap<ResearchType, u64> = HashMap::new();
         let mut failed_item_details = Vec::new();
+        let mut stored_documents = Vec::new();
 
-        // Convert items to vector documents
+        // Convert items to vector documents, skipping ones that fail validation
         let mut documents = Vec::new();
         for item in items.iter() {
+            let errors_before = validator.errors().len();
+            if !validator.validate(item, &self.converter).await {
+                failed_items += 1;
+                *error_breakdown
+                    .entry("validation_error".to_string())
+                    .or_insert(0) += 1;
+                let reasons: Vec<&str> = validator.errors()[errors_before..]
+                    .iter()
+                    .map(|e| e.message.as_str())
+                    .collect();
+                failed_item_details.push(FailedItem {
+                    item_id: item.metadata.cache_key.clone(),
+                    error: format!("Validation failed: {}", reasons.join("; ")),
+                    retry_count: 0,
+                    last_attempt: Utc::now(),
+                    should_retry: false,
+                });
+                continue;
+            }
+
             match self.converter.convert_research_result(item) {
                 Ok((content, mut metadata)) => {
                     // Add custom metadata from config
@@ -917,6 +1255,10 @@ impl MigrationService {
                 Ok(batch_result) => {
                     successful_items += batch_result.successful.len() as u64;
                     failed_items += batch_result.failed.len() as u64;
+                    stored_documents.extend(batch_result.successful.into_iter().map(|document| {
+                        let cache_key = cache_key_of(&document.metadata);
+                        (document.id, cache_key)
+                    }));
 
                     // Process failed storage operations
                     for failed in batch_result.failed {
@@ -964,6 +1306,7 @@ impl MigrationService {
             content_type_distribution,
             research_type_distribution,
             failed_item_details,
+            stored_documents,
         })
     }
 
@@ -1007,6 +1350,28 @@ impl MigrationService {
         Ok(())
     }
 
+    /// Resume from a migration id or the path of a persisted migration state
+    ///
+    /// Returns the id of the resumed migration.
+    #[instrument(skip(self))]
+    pub async fn resume_from_checkpoint(&self, checkpoint: &str) -> MigrationResult<String> {
+        let path = Path::new(checkpoint);
+        let migration_id = if path.is_file() {
+            let state = self.load_migration_state_from_file(path).await?;
+            let migration_id = state.id.clone();
+            self.active_migrations.write().await.insert(
+                migration_id.clone(),
+                Arc::new(tokio::sync::RwLock::new(state)),
+            );
+            migration_id
+        } else {
+            checkpoint.to_string()
+        };
+
+        self.resume_migration(&migration_id).await?;
+        Ok(migration_id)
+    }
+
     /// Pause an in-progress migration
     #[instrument(skip(self))]
     pub async fn pause_migration(&self, migration_id: &str) -> MigrationResult<()> {
@@ -1144,77 +1509,28 @@ impl MigrationService {
                 .clone()
         };
 
-        let (source, _config) = {
+        let (source, config) = {
             let state = state_lock.read().await;
             (state.source.clone(), state.config.clone())
         };
 
-        info!("Validating migration: {}", migration_id);
-
-        // Load source data
-        let data_items = self.load_source_data(&source).await?;
-        let mut validation_errors = Vec::new();
-        let mut warnings = Vec::new();
-        let mut total_validated = 0u64;
+        info!(
+            "Validating migration {} at level {}",
+            migration_id, config.validation_level
+        );
 
+        let mut validator = self.document_validator(&config);
+        let data_items = self.load_validated_source(&source, &mut validator).await?;
         for item in data_items.iter() {
-            total_validated += 1;
-
-            // Validate data conversion
-            match self.converter.convert_research_result(item) {
-                Ok((content, _metadata)) => {
-                    // Basic content validation
-                    if content.is_empty() {
-                        validation_errors.push(ValidationError {
-                            item_id: item.metadata.cache_key.clone(),
-                            error_type: "empty_content".to_string(),
-                            message: "Converted content is empty".to_string(),
-                            severity: ValidationSeverity::Error,
-                        });
-                    }
-
-                    if content.len() > 100_000 {
-                        warnings.push(format!(
-                            "Item {} has very large content ({} chars)",
-                            item.metadata.cache_key,
-                            content.len()
-                        ));
-                    }
-                }
-                Err(e) => {
-                    validation_errors.push(ValidationError {
-                        item_id: item.metadata.cache_key.clone(),
-                        error_type: "conversion_error".to_string(),
-                        message: e.to_string(),
-                        severity: ValidationSeverity::Critical,
-                    });
-                }
-            }
-
-            // Validate metadata completeness
-            if item.request.original_query.is_empty() {
-                validation_errors.push(ValidationError {
-                    item_id: item.metadata.cache_key.clone(),
-                    error_type: "missing_query".to_string(),
-                    message: "Original query is empty".to_string(),
-                    severity: ValidationSeverity::Error,
-                });
-            }
-
-            if item.immediate_answer.is_empty() {
-                warnings.push(format!(
-                    "Item {} has empty immediate answer",
-                    item.metadata.cache_key
-                ));
-            }
+            validator.validate(item, &self.converter).await;
         }
 
-        let error_count = validation_errors.len() as u64;
-        let passed = error_count == 0;
+        let report = validator.into_report(migration_id);
+        self.write_validation_report(&report).await?;
 
-        // Calculate validation statistics
-        let content_integrity_score = if total_validated > 0 {
-            1.0 - (error_count as f64 / total_validated as f64)
+        let error_count = report.errors.len() as u64;
+        let content_integrity_score = if report.total_validated > 0 {
+            report.accepted as f64 / report.total_validated as f64
         } else {
             1.0
         };
@@ -1227,30 +1543,49 @@ impl MigrationService {
         };
 
         Ok(ValidationResult {
-            passed,
-            total_validated,
+            passed: error_count == 0,
+            total_validated: report.total_validated,
             error_count,
-            errors: validation_errors,
-            warnings,
+            errors: report.errors,
+            warnings: report.warnings,
             statistics,
         })
     }
 
+    /// Load source data, recording files that fail to parse with the validator
+    async fn load_validated_source(
+        &self,
+        source: &MigrationSource,
+        validator: &mut DocumentValidator,
+    ) -> MigrationResult<Vec<ResearchResult>> {
+        let mut unparseable = Vec::new();
+        let items = self.load_source_data(source, &mut unparseable).await?;
+        for (item_id, message) in unparseable {
+            validator.record_unparseable(item_id, message);
+        }
+        Ok(items)
+    }
+
     /// Load source data based on migration source type
-    #[instrument(skip(self, source))]
+    ///
+    /// Files in directory sources that fail to parse are skipped and listed in
+    /// `unparseable` as `(path, error)`.
+    #[instrument(skip(self, source, unparseable))]
     async fn load_source_data(
         &self,
         source: &MigrationSource,
+        unparseable: &mut Vec<(String, String)>,
     ) -> MigrationResult<Vec<ResearchResult>> {
         match source {
             MigrationSource::ResearchCache { cache_path } => {
-                self.load_from_research_cache(cache_path).await
+                self.load_from_research_cache(cache_path, unparseable).await
             }
             MigrationSource::StorageSystem { storage_config } => {
                 self.load_from_storage_system(storage_config).await
             }
             MigrationSource::JsonDirectory { directory_path } => {
-                self.load_from_json_directory(directory_path).await
+                self.load_from_json_directory(directory_path, unparseable)
+                    .await
             }
             MigrationSource::JsonFile { file_path } => self.load_from_json_file(file_path).await,
             MigrationSource::InMemory {
@@ -1261,10 +1596,11 @@ impl MigrationService {
     }
 
     /// Load research results from a research cache directory
-    #[instrument(skip(self, cache_path))]
+    #[instrument(skip(self, cache_path, unparseable))]
     async fn load_from_research_cache(
         &self,
         cache_path: &Path,
+        unparseable: &mut Vec<(String, String)>,
     ) -> MigrationResult<Vec<ResearchResult>> {
         info!("Loading from research cache: {}", cache_path.display());
 
@@ -1289,6 +1625,7 @@ impl MigrationService {
                             path.display(),
                             e
                         );
+                        unparseable.push((path.display().to_string(), e.to_string()));
                     }
                 }
             }
@@ -1330,10 +1667,11 @@ impl MigrationService {
     }
 
     /// Load research results from a directory of JSON files
-    #[instrument(skip(self, directory_path))]
+    #[instrument(skip(self, directory_path, unparseable))]
     async fn load_from_json_directory(
         &self,
         directory_path: &Path,
+        unparseable: &mut Vec<(String, String)>,
     ) -> MigrationResult<Vec<ResearchResult>> {
         info!("Loading from JSON directory: {}", directory_path.display());
 
@@ -1345,7 +1683,7 @@ impl MigrationService {
         }
 
         let mut results = Vec::new();
-        self.scan_directory_recursive(directory_path, &mut results)
+        self.scan_directory_recursive(directory_path, &mut results, unparseable)
             .await?;
 
         info!(
@@ -1356,11 +1694,12 @@ impl MigrationService {
     }
 
     /// Recursively scan directory for JSON files
-    #[instrument(skip(self, dir_path, results))]
+    #[instrument(skip(self, dir_path, results, unparseable))]
     async fn scan_directory_recursive(
         &self,
         dir_path: &Path,
         results: &mut Vec<ResearchResult>,
+        unparseable: &mut Vec<(String, String)>,
     ) -> MigrationResult<()> {
         let mut entries = fs::read_dir(dir_path).await?;
 
@@ -1376,11 +1715,14 @@ impl MigrationService {
                             path.display(),
                             e
                         );
+                        unparseable.push((path.display().to_string(), e.to_string()));
                     }
                 }
-            } else if path.is_dir() {
-                // Recursively scan subdirectories
-                if let Err(e) = Box::pin(self.scan_directory_recursive(&path, results)).await {
+            } else if path.is_dir() && path != self.state_dir {
+                // Recursively scan subdirectories, leaving out our own migration state
+                if let Err(e) =
+                    Box::pin(self.scan_directory_recursive(&path, results, unparseable)).await
+                {
                     warn!("Failed to scan directory {}: {}", path.display(), e);
                 }
             }
@@ -1404,9 +1746,7 @@ impl MigrationService {
         &self,
         file_path: &Path,
     ) -> MigrationResult<ResearchResult> {
-        let content = fs::read_to_string(file_path).await?;
-        let result: ResearchResult = serde_json::from_str(&content)?;
-        Ok(result)
+        Ok(crate::storage::load_result_file(file_path).await?)
     }
 
     /// Scan source to determine total number of items
@@ -1456,7 +1796,7 @@ impl MigrationService {
 
             if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("json") {
                 *count += 1;
-            } else if path.is_dir() {
+            } else if path.is_dir() && path != self.state_dir {
                 if let Err(e) = Box::pin(self.count_files_recursive(&path, count)).await {
                     warn!(
                         "Failed to count files in directory {}: {}",
@@ -1479,8 +1819,7 @@ impl MigrationService {
         let state = state_lock.read().await;
         let state_file = self.state_dir.join(format!("{}.json", state.id));
 
-        let json = serde_json::to_string_pretty(&*state)?;
-        fs::write(state_file, json).await?;
+        write_json_atomic_async(&state_file, &*state).await?;
 
         Ok(())
     }
@@ -1527,7 +1866,12 @@ impl MigrationService {
         file_path: &Path,
     ) -> MigrationResult<MigrationState> {
         let content = fs::read_to_string(file_path).await?;
-        let state: MigrationState = serde_json::from_str(&content)?;
+        let mut state: MigrationState = serde_json::from_str(&content)?;
+        // The process running it is gone, so the checkpoint can be resumed
+        if state.status == MigrationStatus::InProgress {
+            state.status = MigrationStatus::Paused;
+            state.progress.set_phase("interrupted".to_string());
+        }
         Ok(state)
     }
 
@@ -1538,6 +1882,7 @@ impl MigrationService {
             converter: self.converter.clone(),
             state_dir: self.state_dir.clone(),
             active_migrations: self.active_migrations.clone(),
+            embeddings: self.embeddings.clone(),
         }
     }
 
@@ -1672,6 +2017,17 @@ struct BatchProcessingStats {
     content_type_distribution: HashMap<String, u64>,
     research_type_distribution: HashMap<ResearchType, u64>,
     failed_item_details: Vec<FailedItem>,
+    /// Stored document ids with the cache key of the item they came from
+    stored_documents: Vec<(String, Option<String>)>,
+}
+
+/// Cache key recorded by `DataConverter` on a migrated document
+fn cache_key_of(metadata: &DocumentMetadata) -> Option<String> {
+    metadata
+        .custom_fields
+        .get("cache_key")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
 }
 
 /// Summary information about a migration
@@ -1769,10 +2125,22 @@ mod tests {
 
         async fn retrieve_similar(
             &self,
-            _query: &str,
-            _config: SearchConfig,
+            query: &str,
+            config: SearchConfig,
         ) -> VectorResult<Vec<SimilaritySearchResult>> {
-            Ok(Vec::new()) // Not used in migration tests
+            // Only exact content matches are similar enough to report
+            Ok(self
+                .documents
+                .read()
+                .await
+                .values()
+                .filter(|document| document.content == query)
+                .take(config.limit)
+                .map(|document| SimilaritySearchResult {
+                    document: document.clone(),
+                    score: 1.0,
+                })
+                .collect())
         }
 
         async fn retrieve_by_id(&self, id: &str) -> VectorResult<Option<VectorDocument>> {
@@ -2244,6 +2612,125 @@ mod tests {
         }
     }
 
+    async fn wait_for_completion(service: &MigrationService, migration_id: &str) -> MigrationState {
+        for _ in 0..100 {
+            let state = service.get_migration_status(migration_id).await.unwrap();
+            if state.status == MigrationStatus::Completed {
+                return state;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        }
+        panic!("migration {migration_id} did not complete");
+    }
+
+    #[tokio::test]
+    async fn test_migration_checkpoint_and_verification() {
+        let temp_dir = TempDir::new().unwrap();
+        let migration_service = create_test_migration_service(&temp_dir).await;
+        migration_service.initialize().await.unwrap();
+
+        let data: Vec<_> = (0..4)
+            .map(|i| {
+                let mut result = create_test_research_result(&format!("key_{i}"));
+                result.immediate_answer = format!("Answer {i}");
+                result
+            })
+            .collect();
+        let config = MigrationConfig {
+            batch_size: 2,
+            verification_samples: 2,
+            ..Default::default()
+        };
+
+        let migration_id = migration_service
+            .start_migration_with_config(
+                MigrationSource::InMemory {
+                    data,
+                    source_name: "checkpoint".to_string(),
+                },
+                config,
+            )
+            .await
+            .unwrap();
+        let state = wait_for_completion(&migration_service, &migration_id).await;
+
+        assert_eq!(state.processed_items.len(), 4);
+        assert_eq!(state.completed_keys.len(), 4);
+        assert!(state.completed_keys.contains(&"key_3".to_string()));
+
+        // The verification pass is persisted with the checkpoint
+        let state_file = temp_dir
+            .path()
+            .join("migration_states")
+            .join(format!("{migration_id}.json"));
+        let persisted: MigrationState =
+            serde_json::from_str(&fs::read_to_string(&state_file).await.unwrap()).unwrap();
+        let verification = persisted.verification.unwrap();
+        assert!(verification.passed);
+        assert_eq!(verification.source_items, 4);
+        assert_eq!(verification.migrated_items, 4);
+        assert_eq!(verification.found_documents, 4);
+        assert_eq!(verification.spot_checks.len(), 2);
+        assert!(verification
+            .spot_checks
+            .iter()
+            .all(|check| check.score == Some(1.0) && check.cache_key.is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_resume_from_interrupted_checkpoint() {
+        let temp_dir = TempDir::new().unwrap();
+        let vector_storage = Arc::new(MockVectorStorage::new());
+        let state_dir = temp_dir.path().join("migration_states");
+        let migration_service = MigrationService::new(vector_storage.clone(), Some(state_dir));
+        migration_service.initialize().await.unwrap();
+
+        let data: Vec<_> = (0..4)
+            .map(|i| create_test_research_result(&format!("key_{i}")))
+            .collect();
+        let migration_id = migration_service
+            .start_migration_with_config(
+                MigrationSource::InMemory {
+                    data,
+                    source_name: "resume".to_string(),
+                },
+                MigrationConfig {
+                    dry_run: true,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        // Checkpoint of a run that stored two items before the process died
+        let mut state = migration_service
+            .get_migration_status(&migration_id)
+            .await
+            .unwrap();
+        state.config.dry_run = false;
+        state.status = MigrationStatus::InProgress;
+        state.completed_keys = vec!["key_0".to_string(), "key_1".to_string()];
+        let checkpoint = temp_dir.path().join("checkpoint.json");
+        fs::write(&checkpoint, serde_json::to_string(&state).unwrap())
+            .await
+            .unwrap();
+
+        let resumed_id = migration_service
+            .resume_from_checkpoint(checkpoint.to_str().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(resumed_id, migration_id);
+
+        let state = wait_for_completion(&migration_service, &migration_id).await;
+        assert_eq!(vector_storage.get_document_count().await, 2);
+        assert_eq!(state.completed_keys.len(), 4);
+        assert_eq!(state.progress.processed_items, 4);
+        assert!(matches!(
+            migration_service.resume_from_checkpoint("missing").await,
+            Err(MigrationError::MigrationNotFound(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_migration_cancellation() {
         let temp_dir = TempDir::new().unwrap();
@@ -2456,6 +2943,75 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_validation_level_rejects_and_reports() {
+        let temp_dir = TempDir::new().unwrap();
+        let migration_service = create_test_migration_service(&temp_dir).await;
+        migration_service.initialize().await.unwrap();
+
+        let source_dir = temp_dir.path().join("source");
+        fs::create_dir_all(&source_dir).await.unwrap();
+        let mut low_quality = create_test_research_result("low_quality");
+        low_quality.request.original_query = "Another query".to_string();
+        low_quality.metadata.quality_score = 0.1;
+        for result in [create_test_research_result("good"), low_quality] {
+            let json = serde_json::to_string(&result).unwrap();
+            fs::write(
+                source_dir.join(format!("{}.json", result.cache_key())),
+                json,
+            )
+            .await
+            .unwrap();
+        }
+        fs::write(source_dir.join("broken.json"), "{not json")
+            .await
+            .unwrap();
+
+        let lenient = MigrationConfig {
+            validation_level: "lenient".parse().unwrap(),
+            dry_run: true,
+            ..Default::default()
+        };
+        let migration_id = migration_service
+            .migrate_json_directory(source_dir.clone(), Some(lenient))
+            .await
+            .unwrap();
+        let report = migration_service
+            .get_validation_report(&migration_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(report.level, ValidationLevel::Basic);
+        assert_eq!(report.total_validated, 3);
+        assert_eq!(report.rejected, 1);
+        assert_eq!(report.errors[0].error_type, "parse_error");
+
+        let strict = MigrationConfig {
+            validation_level: ValidationLevel::Strict,
+            dry_run: true,
+            ..Default::default()
+        };
+        let migration_id = migration_service
+            .migrate_json_directory(source_dir, Some(strict))
+            .await
+            .unwrap();
+        let validation = migration_service
+            .validate_migration(&migration_id)
+            .await
+            .unwrap();
+        assert!(!validation.passed);
+        assert!(validation
+            .errors
+            .iter()
+            .any(|e| e.item_id == "low_quality" && e.error_type == "low_quality"));
+        assert!(migration_service
+            .validation_report_path(&migration_id)
+            .exists());
+
+        assert!("thorough".parse::<ValidationLevel>().is_err());
+        assert_eq!(ValidationLevel::Moderate.to_string(), "moderate");
+    }
+
     #[test]
     fn test_migration_config_defaults() {
         let config = MigrationConfig::default();
@@ -2468,6 +3024,7 @@ mod tests {
         assert_eq!(config.retry_delay_ms, 1000);
         assert!(!config.dry_run);
         assert!(config.custom_metadata.is_empty());
+        assert_eq!(config.min_quality_score, 0.5);
     }
 
     #[test]
@@ -0,0 +1,410 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Per-document validation tiers applied to research results during vector migration
+//! Each [`ValidationLevel`] adds checks on top of the previous one:
+//!
+//! - `basic` (lenient): the document parses and converts to non-empty content
+//! - `standard`: required metadata fields (query, cache key) are present
+//! - `moderate`: no duplicate cache keys or duplicate content within the migration
+//! - `comprehensive`: the content embeds to a finite, non-zero vector of the expected dimension
+//! - `strict`: classification is present and the quality score meets the configured minimum
+
+use crate::vector::embeddings::EmbeddingGenerator;
+use crate::vector::migration::{
+    DataConverter, ValidationError, ValidationLevel, ValidationSeverity,
+};
+use chrono::{DateTime, Utc};
+use fortitude_types::research::ResearchResult;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// Content longer than this is reported as a warning
+const LARGE_CONTENT_CHARS: usize = 100_000;
+
+/// Validation outcome of a whole migration, written next to the migration state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationReport {
+    /// Migration the report belongs to
+    pub migration_id: String,
+    /// Validation level applied
+    pub level: ValidationLevel,
+    /// Quality score minimum applied at the strict level
+    pub min_quality_score: f64,
+    /// When the report was produced
+    pub generated_at: DateTime<Utc>,
+    /// Documents checked, including ones that failed to parse
+    pub total_validated: u64,
+    /// Documents that passed every check of the level
+    pub accepted: u64,
+    /// Documents excluded from the migration
+    pub rejected: u64,
+    /// Every validation error, per document
+    pub errors: Vec<ValidationError>,
+    /// Non-blocking observations
+    pub warnings: Vec<String>,
+}
+
+/// Applies the checks of a validation level to documents one at a time
+///
+/// Duplicate detection spans every document passed to the same validator, so a
+/// single validator is used for a whole migration.
+pub struct DocumentValidator {
+    level: ValidationLevel,
+    min_quality_score: f64,
+    embeddings: Option<Arc<dyn EmbeddingGenerator>>,
+    seen_keys: HashSet<String>,
+    seen_content: HashMap<u64, String>,
+    errors: Vec<ValidationError>,
+    warnings: Vec<String>,
+    total_validated: u64,
+    rejected: u64,
+}
+
+impl DocumentValidator {
+    pub fn new(level: ValidationLevel, min_quality_score: f64) -> Self {
+        Self {
+            level,
+            min_quality_score,
+            embeddings: None,
+            seen_keys: HashSet::new(),
+            seen_content: HashMap::new(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            total_validated: 0,
+            rejected: 0,
+        }
+    }
+
+    /// Use an embedding generator for the comprehensive-level checks
+    pub fn with_embeddings(mut self, embeddings: Option<Arc<dyn EmbeddingGenerator>>) -> Self {
+        self.embeddings = embeddings;
+        self
+    }
+
+    /// Record a document that could not be parsed at all
+    pub fn record_unparseable(&mut self, item_id: impl Into<String>, message: impl Into<String>) {
+        self.total_validated += 1;
+        self.rejected += 1;
+        self.errors.push(ValidationError {
+            item_id: item_id.into(),
+            error_type: "parse_error".to_string(),
+            message: message.into(),
+            severity: ValidationSeverity::Critical,
+        });
+    }
+
+    /// Check one document, returning true when it may be migrated
+    pub async fn validate(&mut self, item: &ResearchResult, converter: &DataConverter) -> bool {
+        self.total_validated += 1;
+        let item_id = item.metadata.cache_key.clone();
+        let mut errors = Vec::new();
+        let error = |error_type: &str, message: String| ValidationError {
+            item_id: item_id.clone(),
+            error_type: error_type.to_string(),
+            message,
+            severity: ValidationSeverity::Error,
+        };
+
+        let content = match converter.convert_research_result(item) {
+            Ok((content, _)) => content,
+            Err(e) => {
+                errors.push(ValidationError {
+                    severity: ValidationSeverity::Critical,
+                    ..error("conversion_error", e.to_string())
+                });
+                String::new()
+            }
+        };
+        if content.trim().is_empty() && errors.is_empty() {
+            errors.push(error(
+                "empty_content",
+                "Converted content is empty".to_string(),
+            ));
+        }
+        if content.len() > LARGE_CONTENT_CHARS {
+            self.warnings.push(format!(
+                "Item {item_id} has very large content ({} chars)",
+                content.len()
+            ));
+        }
+
+        if self.level >= ValidationLevel::Standard {
+            if item.request.original_query.trim().is_empty() {
+                errors.push(error(
+                    "missing_query",
+                    "Original query is empty".to_string(),
+                ));
+            }
+            if item_id.trim().is_empty() {
+                errors.push(error("missing_cache_key", "Cache key is empty".to_string()));
+            }
+            if item.immediate_answer.trim().is_empty() {
+                self.warnings
+                    .push(format!("Item {item_id} has empty immediate answer"));
+            }
+        }
+
+        if self.level >= ValidationLevel::Moderate {
+            if !item_id.is_empty() && !self.seen_keys.insert(item_id.clone()) {
+                errors.push(error(
+                    "duplicate_id",
+                    format!("Cache key {item_id} appears more than once"),
+                ));
+            }
+            if !content.is_empty() {
+                let mut hasher = DefaultHasher::new();
+                content.hash(&mut hasher);
+                match self.seen_content.get(&hasher.finish()) {
+                    Some(original) if *original != item_id => errors.push(error(
+                        "duplicate_content",
+                        format!("Content duplicates item {original}"),
+                    )),
+                    Some(_) => {}
+                    None => {
+                        self.seen_content.insert(hasher.finish(), item_id.clone());
+                    }
+                }
+            }
+        }
+
+        if self.level >= ValidationLevel::Comprehensive && !content.is_empty() {
+            if let Some(problem) = self.check_embedding(&content).await {
+                errors.push(error("invalid_embedding", problem));
+            }
+        }
+
+        if self.level >= ValidationLevel::Strict {
+            if item.request.confidence <= 0.0 {
+                errors.push(error(
+                    "missing_classification",
+                    "Research type was never classified (confidence is 0)".to_string(),
+                ));
+            }
+            if item.metadata.quality_score < self.min_quality_score {
+                errors.push(error(
+                    "low_quality",
+                    format!(
+                        "Quality score {:.2} is below the minimum {:.2}",
+                        item.metadata.quality_score, self.min_quality_score
+                    ),
+                ));
+            }
+        }
+
+        let accepted = !errors
+            .iter()
+            .any(|e| e.severity != ValidationSeverity::Warning);
+        if !accepted {
+            self.rejected += 1;
+        }
+        self.errors.extend(errors);
+        accepted
+    }
+
+    /// Describe what is wrong with the content's embedding, if anything
+    async fn check_embedding(&mut self, content: &str) -> Option<String> {
+        let Some(embeddings) = &self.embeddings else {
+            if !self
+                .warnings
+                .iter()
+                .any(|w| w.starts_with("Embedding checks skipped"))
+            {
+                self.warnings.push(
+                    "Embedding checks skipped: no embedding generator configured".to_string(),
+                );
+            }
+            return None;
+        };
+
+        let embedding = match embeddings.generate_embedding(content).await {
+            Ok(embedding) => embedding,
+            Err(e) => return Some(format!("Embedding generation failed: {e}")),
+        };
+        let expected = embeddings.embedding_dimension();
+        if embedding.len() != expected {
+            return Some(format!(
+                "Embedding has {} dimensions, expected {expected}",
+                embedding.len()
+            ));
+        }
+        if embedding.iter().any(|v| !v.is_finite()) {
+            return Some("Embedding contains non-finite values".to_string());
+        }
+        if embedding.iter().all(|v| *v == 0.0) {
+            return Some("Embedding is the zero vector".to_string());
+        }
+        None
+    }
+
+    /// Errors collected so far
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    /// Finish validation and produce the report
+    pub fn into_report(self, migration_id: impl Into<String>) -> ValidationReport {
+        ValidationReport {
+            migration_id: migration_id.into(),
+            level: self.level,
+            min_quality_score: self.min_quality_score,
+            generated_at: Utc::now(),
+            total_validated: self.total_validated,
+            accepted: self.total_validated - self.rejected,
+            rejected: self.rejected,
+            errors: self.errors,
+            warnings: self.warnings,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector::embeddings::EmbeddingStats;
+    use crate::vector::error::VectorResult;
+    use async_trait::async_trait;
+    use fortitude_types::research::{
+        AudienceContext, ClassifiedRequest, DomainContext, ResearchMetadata, ResearchType,
+    };
+
+    struct FixedEmbeddings(Vec<f32>);
+
+    #[async_trait]
+    impl EmbeddingGenerator for FixedEmbeddings {
+        async fn generate_embedding(&self, _text: &str) -> VectorResult<Vec<f32>> {
+            Ok(self.0.clone())
+        }
+
+        async fn generate_embeddings(&self, texts: &[String]) -> VectorResult<Vec<Vec<f32>>> {
+            Ok(texts.iter().map(|_| self.0.clone()).collect())
+        }
+
+        async fn get_stats(&self) -> EmbeddingStats {
+            EmbeddingStats {
+                total_generated: 0,
+                cache_hit_rate: 0.0,
+                avg_generation_time_ms: 0.0,
+                cache_size: 0,
+            }
+        }
+
+        async fn clear_cache(&self) -> VectorResult<()> {
+            Ok(())
+        }
+
+        fn embedding_dimension(&self) -> usize {
+            3
+        }
+    }
+
+    fn result(cache_key: &str, query: &str, quality_score: f64) -> ResearchResult {
+        let request = ClassifiedRequest::new(
+            query.to_string(),
+            ResearchType::Implementation,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 100,
+            sources_consulted: vec![],
+            quality_score,
+            cache_key: cache_key.to_string(),
+            tags: HashMap::new(),
+        };
+        ResearchResult::new(
+            request,
+            format!("Answer to {query}"),
+            vec![],
+            vec![],
+            metadata,
+        )
+    }
+
+    async fn error_types(level: ValidationLevel, items: &[ResearchResult]) -> Vec<String> {
+        let converter = DataConverter::new();
+        let mut validator = DocumentValidator::new(level, 0.5);
+        for item in items {
+            validator.validate(item, &converter).await;
+        }
+        validator
+            .errors()
+            .iter()
+            .map(|e| e.error_type.clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_levels_add_checks() {
+        let items = vec![
+            result("a", "How do I use serde?", 0.9),
+            result("a", "How do I use tokio?", 0.9),
+            result("b", "How do I use serde?", 0.9),
+            result("c", "", 0.2),
+        ];
+
+        assert!(error_types(ValidationLevel::Basic, &items).await.is_empty());
+        assert_eq!(
+            error_types(ValidationLevel::Standard, &items).await,
+            vec!["missing_query"]
+        );
+        assert_eq!(
+            error_types(ValidationLevel::Moderate, &items).await,
+            vec!["duplicate_id", "duplicate_content", "missing_query"]
+        );
+        assert_eq!(
+            error_types(ValidationLevel::Strict, &items).await,
+            vec![
+                "duplicate_id",
+                "duplicate_content",
+                "missing_query",
+                "low_quality"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_embedding_sanity_and_report() {
+        let converter = DataConverter::new();
+        let item = result("a", "How do I use serde?", 0.9);
+
+        let mut without = DocumentValidator::new(ValidationLevel::Comprehensive, 0.5);
+        assert!(without.validate(&item, &converter).await);
+        let report = without.into_report("m1");
+        assert!(report.warnings[0].starts_with("Embedding checks skipped"));
+
+        let mut zero = DocumentValidator::new(ValidationLevel::Comprehensive, 0.5)
+            .with_embeddings(Some(Arc::new(FixedEmbeddings(vec![0.0; 3]))));
+        assert!(!zero.validate(&item, &converter).await);
+        zero.record_unparseable("broken.json", "expected value at line 1");
+
+        let report = zero.into_report("m2");
+        assert_eq!(report.total_validated, 2);
+        assert_eq!(report.accepted, 0);
+        assert_eq!(report.rejected, 2);
+        assert_eq!(report.errors[0].error_type, "invalid_embedding");
+        assert_eq!(report.errors[1].error_type, "parse_error");
+
+        let mut short = DocumentValidator::new(ValidationLevel::Comprehensive, 0.5)
+            .with_embeddings(Some(Arc::new(FixedEmbeddings(vec![0.1, 0.2]))));
+        assert!(!short.validate(&item, &converter).await);
+        assert!(short.errors()[0].message.contains("expected 3"));
+    }
+}
@@ -20,14 +20,18 @@ pub mod cache;
 pub mod client;
 pub mod config;
 pub mod connection_pool;
+pub mod embedding_providers;
 pub mod embeddings;
 pub mod error;
 pub mod hybrid;
 pub mod migration;
+pub mod migration_validation;
 pub mod optimized_config;
 pub mod optimized_embeddings;
 pub mod performance;
+pub mod portable;
 pub mod regression_detection;
+pub mod replicas;
 pub mod search;
 pub mod storage;
 pub mod utils;
@@ -41,8 +45,16 @@ mod storage_tests;
 pub use cache::*;
 pub use client::*;
 pub use config::*;
+pub use embedding_providers::{
+    create_embedding_provider, EmbeddingBackendConfig, EmbeddingProvider, EmbeddingProviderKind,
+    HashEmbeddingProvider, OnnxEmbeddingProvider, OpenAiEmbeddingProvider,
+};
 pub use embeddings::*;
 pub use error::*;
+pub use replicas::{
+    EndpointHealth, EndpointRole, PendingWrite, ReplicaEndpoint, ReplicaSet, ReplicationConfig,
+    VectorEndpoints, WriteOutcome,
+};
 pub use storage::*;
 pub use utils::*;
 
@@ -57,17 +69,25 @@ pub use search::{
 
 // Re-export hybrid search functionality
 pub use hybrid::{
-    FusionMethod, HybridMatchMetadata, HybridSearchAnalytics, HybridSearchConfig,
-    HybridSearchExplanation, HybridSearchOperations, HybridSearchRequest, HybridSearchResult,
-    HybridSearchResultSet, HybridSearchService, HybridSearchStats, KeywordSearchResult,
-    KeywordSearcher, PerformanceMetrics, QueryAnalysis, QueryType, SearchSources, SearchStrategy,
-    TermMatch,
+    FusionContribution, FusionMethod, HybridMatchMetadata, HybridSearchAnalytics,
+    HybridSearchConfig, HybridSearchExplanation, HybridSearchOperations, HybridSearchRequest,
+    HybridSearchResult, HybridSearchResultSet, HybridSearchService, HybridSearchStats,
+    KeywordSearchResult, KeywordSearcher, PerformanceMetrics, QueryAnalysis, QueryType,
+    ScoreNormalization, SearchSources, SearchStrategy, TermMatch,
 };
 
 // Re-export migration functionality
 pub use migration::{
     BatchStatistics, DataConverter, FailedItem, MigrationConfig, MigrationError, MigrationProgress,
     MigrationResult, MigrationService, MigrationSource, MigrationState, MigrationStatistics,
-    MigrationStatus, MigrationSummary, RollbackResult, ValidationError, ValidationLevel,
-    ValidationResult, ValidationSeverity, ValidationStatistics,
+    MigrationStatus, MigrationSummary, MigrationVerification, RollbackResult, SpotCheck,
+    ValidationError, ValidationLevel, ValidationResult, ValidationSeverity, ValidationStatistics,
+};
+pub use migration_validation::{DocumentValidator, ValidationReport};
+
+// Re-export portable dataset export/import
+pub use portable::{
+    export_dataset, import_dataset, verify_dataset, CollectionImportSummary, CollectionManifest,
+    CollectionShape, DatasetManifest, ExportOptions, ImportOptions, PointPage, PortablePoint,
+    PortableVectorStore, MANIFEST_FILE, PORTABLE_FORMAT_VERSION,
 };
@@ -0,0 +1,927 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Portable vector datasets: export collections to JSONL with a manifest and import them back
+//! A dataset is a directory holding one JSONL file per collection (one
+//! `{id, vector, payload}` object per line) and a `manifest.json` recording
+//! each collection's dimension, distance metric, point count and checksum,
+//! plus the embedding model that produced the vectors. Exports and imports
+//! keep a progress file in the dataset directory so an interrupted run can be
+//! resumed, and imports refuse datasets whose checksums, dimensions or model
+//! do not match the target.
+
+use crate::atomic_file::write_json_atomic_async;
+use crate::vector::{
+    client::QdrantClient,
+    config::DistanceMetric,
+    error::{VectorError, VectorResult},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use qdrant_client::qdrant::{
+    point_id::PointIdOptions, vectors_config::Config, vectors_output::VectorsOptions,
+    CreateCollectionBuilder, Distance, PointId, PointStruct, ScrollPointsBuilder,
+    UpsertPointsBuilder, VectorParamsBuilder,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use tokio::fs;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader};
+use tracing::{debug, info};
+
+/// Version of the dataset layout written by [`export_dataset`]
+pub const PORTABLE_FORMAT_VERSION: u32 = 1;
+
+/// Manifest file name inside a dataset directory
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Progress of an unfinished export, removed once the manifest is written
+pub const EXPORT_PROGRESS_FILE: &str = "export_progress.json";
+
+/// Progress of an unfinished import, removed once the import completes
+pub const IMPORT_PROGRESS_FILE: &str = "import_progress.json";
+
+/// A point as stored in a dataset's JSONL files
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortablePoint {
+    pub id: String,
+    pub vector: Vec<f32>,
+    #[serde(default)]
+    pub payload: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Vector layout of a collection
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectionShape {
+    pub dimension: usize,
+    pub distance: DistanceMetric,
+}
+
+/// Manifest entry for one exported collection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectionManifest {
+    pub name: String,
+    /// JSONL file, relative to the dataset directory
+    pub file: String,
+    pub dimension: usize,
+    pub distance: DistanceMetric,
+    pub points: u64,
+    /// MD5 of the JSONL file, hex encoded
+    pub checksum: String,
+}
+
+/// Description of a dataset, written last so its presence marks a complete export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatasetManifest {
+    pub format_version: u32,
+    pub created_at: DateTime<Utc>,
+    /// Embedding model that produced the vectors
+    pub embedding_model: String,
+    pub collections: Vec<CollectionManifest>,
+}
+
+/// One page of a collection scan
+#[derive(Debug, Clone, Default)]
+pub struct PointPage {
+    pub points: Vec<PortablePoint>,
+    /// Where the next page starts, `None` after the last page
+    pub next_offset: Option<String>,
+}
+
+/// Backend operations needed to export and import datasets
+#[async_trait]
+pub trait PortableVectorStore: Send + Sync {
+    /// Layout of a collection, or `None` if it does not exist
+    async fn collection_shape(&self, collection: &str) -> VectorResult<Option<CollectionShape>>;
+
+    /// Create an empty collection
+    async fn create_collection(
+        &self,
+        collection: &str,
+        shape: &CollectionShape,
+    ) -> VectorResult<()>;
+
+    /// Read up to `limit` points starting at `offset`
+    async fn scroll_points(
+        &self,
+        collection: &str,
+        offset: Option<&str>,
+        limit: usize,
+    ) -> VectorResult<PointPage>;
+
+    /// Insert or replace points
+    async fn upsert_points(&self, collection: &str, points: Vec<PortablePoint>)
+        -> VectorResult<()>;
+}
+
+/// Options for [`export_dataset`]
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    /// Points read per page
+    pub batch_size: usize,
+    /// Continue an interrupted export in the same directory
+    pub resume: bool,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 256,
+            resume: false,
+        }
+    }
+}
+
+/// Options for [`import_dataset`]
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    /// Points written per batch
+    pub batch_size: usize,
+    /// Continue an interrupted import of the same dataset
+    pub resume: bool,
+    /// Embedding model used by the target; vectors from another model are rejected
+    pub embedding_model: Option<String>,
+    /// Vector dimension used by the target
+    pub dimension: Option<usize>,
+    /// Import even if the dataset was produced by a different embedding model
+    pub allow_model_mismatch: bool,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 256,
+            resume: false,
+            embedding_model: None,
+            dimension: None,
+            allow_model_mismatch: false,
+        }
+    }
+}
+
+/// Points imported into one collection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectionImportSummary {
+    pub name: String,
+    /// Points written by this run
+    pub imported: u64,
+    /// Points already written by an earlier, interrupted run
+    pub resumed: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CollectionExportProgress {
+    points: u64,
+    bytes: u64,
+    next_offset: Option<String>,
+    complete: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ExportProgress {
+    embedding_model: String,
+    collections: HashMap<String, CollectionExportProgress>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CollectionImportProgress {
+    /// Checksum of the file being imported, so a different dataset restarts
+    checksum: String,
+    points: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ImportProgress {
+    collections: HashMap<String, CollectionImportProgress>,
+}
+
+/// Export collections into a dataset directory
+///
+/// `on_progress` is called after each page with the collection name and the
+/// number of points exported from it so far.
+pub async fn export_dataset<S: PortableVectorStore + ?Sized>(
+    store: &S,
+    dir: &Path,
+    collections: &[String],
+    embedding_model: &str,
+    options: &ExportOptions,
+    mut on_progress: impl FnMut(&str, u64),
+) -> VectorResult<DatasetManifest> {
+    fs::create_dir_all(dir)
+        .await
+        .map_err(|e| io_error("export", dir, e))?;
+    let progress_path = dir.join(EXPORT_PROGRESS_FILE);
+
+    let mut progress = if options.resume {
+        read_json::<ExportProgress>(&progress_path)
+            .await?
+            .unwrap_or_default()
+    } else {
+        if fs::try_exists(dir.join(MANIFEST_FILE))
+            .await
+            .unwrap_or(false)
+        {
+            return Err(VectorError::ConfigurationError(format!(
+                "{} already contains an exported dataset",
+                dir.display()
+            )));
+        }
+        ExportProgress::default()
+    };
+    if progress.collections.is_empty() {
+        progress.embedding_model = embedding_model.to_string();
+    } else if progress.embedding_model != embedding_model {
+        return Err(VectorError::ConfigurationError(format!(
+            "Cannot resume export made with embedding model {} using {}",
+            progress.embedding_model, embedding_model
+        )));
+    }
+
+    let batch_size = options.batch_size.max(1);
+    let mut manifest_collections = Vec::with_capacity(collections.len());
+    for name in collections {
+        let shape =
+            store
+                .collection_shape(name)
+                .await?
+                .ok_or_else(|| VectorError::CollectionNotFound {
+                    collection: name.clone(),
+                })?;
+        let file = collection_file_name(name);
+        let path = dir.join(&file);
+        let mut state = progress.collections.get(name).cloned().unwrap_or_default();
+
+        if !state.complete {
+            let mut out = fs::OpenOptions::new()
+                .create(true)
+                .truncate(false)
+                .write(true)
+                .open(&path)
+                .await
+                .map_err(|e| io_error("export", &path, e))?;
+            // Drop anything written after the last recorded page
+            out.set_len(state.bytes)
+                .await
+                .map_err(|e| io_error("export", &path, e))?;
+            out.seek(std::io::SeekFrom::End(0))
+                .await
+                .map_err(|e| io_error("export", &path, e))?;
+
+            loop {
+                let page = store
+                    .scroll_points(name, state.next_offset.as_deref(), batch_size)
+                    .await?;
+                let mut buffer = Vec::new();
+                for point in &page.points {
+                    check_dimension(shape.dimension, point)?;
+                    serde_json::to_writer(&mut buffer, point)?;
+                    buffer.push(b'\n');
+                }
+                out.write_all(&buffer)
+                    .await
+                    .map_err(|e| io_error("export", &path, e))?;
+                out.sync_data()
+                    .await
+                    .map_err(|e| io_error("export", &path, e))?;
+
+                state.points += page.points.len() as u64;
+                state.bytes += buffer.len() as u64;
+                state.complete = page.next_offset.is_none();
+                state.next_offset = page.next_offset;
+                progress.collections.insert(name.clone(), state.clone());
+                write_json(&progress_path, &progress).await?;
+                on_progress(name, state.points);
+
+                if state.complete {
+                    break;
+                }
+            }
+        }
+
+        info!("Exported {} points from collection {}", state.points, name);
+        manifest_collections.push(CollectionManifest {
+            name: name.clone(),
+            file,
+            dimension: shape.dimension,
+            distance: shape.distance,
+            points: state.points,
+            checksum: file_checksum(&path).await?,
+        });
+    }
+
+    let manifest = DatasetManifest {
+        format_version: PORTABLE_FORMAT_VERSION,
+        created_at: Utc::now(),
+        embedding_model: embedding_model.to_string(),
+        collections: manifest_collections,
+    };
+    write_json(&dir.join(MANIFEST_FILE), &manifest).await?;
+    let _ = fs::remove_file(&progress_path).await;
+    Ok(manifest)
+}
+
+/// Read and validate a dataset's manifest and files without importing
+///
+/// Checks the format version, the embedding model and every file checksum.
+pub async fn verify_dataset(dir: &Path, options: &ImportOptions) -> VectorResult<DatasetManifest> {
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let manifest = read_json::<DatasetManifest>(&manifest_path)
+        .await?
+        .ok_or_else(|| {
+            VectorError::ConfigurationError(format!(
+                "No dataset manifest found at {}",
+                manifest_path.display()
+            ))
+        })?;
+
+    if manifest.format_version > PORTABLE_FORMAT_VERSION {
+        return Err(VectorError::ConfigurationError(format!(
+            "Dataset format version {} is newer than the supported version {}",
+            manifest.format_version, PORTABLE_FORMAT_VERSION
+        )));
+    }
+    if let Some(model) = &options.embedding_model {
+        if *model != manifest.embedding_model && !options.allow_model_mismatch {
+            return Err(VectorError::ConfigurationError(format!(
+                "Dataset vectors come from embedding model {} but the target uses {}",
+                manifest.embedding_model, model
+            )));
+        }
+    }
+
+    for collection in &manifest.collections {
+        if let Some(dimension) = options.dimension {
+            if dimension != collection.dimension {
+                return Err(VectorError::InvalidVectorDimensions {
+                    expected: dimension,
+                    actual: collection.dimension,
+                });
+            }
+        }
+        let actual = file_checksum(&dir.join(&collection.file)).await?;
+        if actual != collection.checksum {
+            return Err(VectorError::ChecksumMismatch {
+                file: collection.file.clone(),
+                expected: collection.checksum.clone(),
+                actual,
+            });
+        }
+    }
+    Ok(manifest)
+}
+
+/// Import a dataset directory into a vector backend
+///
+/// Missing collections are created with the exported dimension and distance
+/// metric; existing collections must have the same dimension. `on_progress`
+/// is called after each batch with the collection name and the number of
+/// points imported into it so far.
+pub async fn import_dataset<S: PortableVectorStore + ?Sized>(
+    store: &S,
+    dir: &Path,
+    options: &ImportOptions,
+    mut on_progress: impl FnMut(&str, u64),
+) -> VectorResult<Vec<CollectionImportSummary>> {
+    let manifest = verify_dataset(dir, options).await?;
+
+    for collection in &manifest.collections {
+        if let Some(shape) = store.collection_shape(&collection.name).await? {
+            if shape.dimension != collection.dimension {
+                return Err(VectorError::InvalidVectorDimensions {
+                    expected: shape.dimension,
+                    actual: collection.dimension,
+                });
+            }
+        }
+    }
+
+    let progress_path = dir.join(IMPORT_PROGRESS_FILE);
+    let mut progress = if options.resume {
+        read_json::<ImportProgress>(&progress_path)
+            .await?
+            .unwrap_or_default()
+    } else {
+        ImportProgress::default()
+    };
+
+    let batch_size = options.batch_size.max(1);
+    let mut summaries = Vec::with_capacity(manifest.collections.len());
+    for collection in &manifest.collections {
+        let shape = CollectionShape {
+            dimension: collection.dimension,
+            distance: collection.distance.clone(),
+        };
+        if store.collection_shape(&collection.name).await?.is_none() {
+            store.create_collection(&collection.name, &shape).await?;
+        }
+
+        let mut state = progress
+            .collections
+            .get(&collection.name)
+            .filter(|state| state.checksum == collection.checksum)
+            .cloned()
+            .unwrap_or_else(|| CollectionImportProgress {
+                checksum: collection.checksum.clone(),
+                points: 0,
+            });
+        let resumed = state.points;
+        if resumed > 0 {
+            debug!(
+                "Resuming import of {} after {} points",
+                collection.name, resumed
+            );
+        }
+
+        let path = dir.join(&collection.file);
+        let file = fs::File::open(&path)
+            .await
+            .map_err(|e| io_error("import", &path, e))?;
+        let mut lines = BufReader::new(file).lines();
+        let mut line_number = 0u64;
+        let mut batch = Vec::with_capacity(batch_size);
+        loop {
+            let line = lines
+                .next_line()
+                .await
+                .map_err(|e| io_error("import", &path, e))?;
+            if let Some(line) = &line {
+                line_number += 1;
+                if line_number <= resumed || line.trim().is_empty() {
+                    continue;
+                }
+                let point: PortablePoint = serde_json::from_str(line)?;
+                check_dimension(collection.dimension, &point)?;
+                batch.push(point);
+            }
+
+            if batch.len() >= batch_size || (line.is_none() && !batch.is_empty()) {
+                let points = std::mem::take(&mut batch);
+                let count = points.len() as u64;
+                store.upsert_points(&collection.name, points).await?;
+                state.points += count;
+                progress
+                    .collections
+                    .insert(collection.name.clone(), state.clone());
+                write_json(&progress_path, &progress).await?;
+                on_progress(&collection.name, state.points);
+            }
+            if line.is_none() {
+                break;
+            }
+        }
+
+        info!(
+            "Imported {} points into collection {}",
+            state.points, collection.name
+        );
+        summaries.push(CollectionImportSummary {
+            name: collection.name.clone(),
+            imported: state.points - resumed,
+            resumed,
+        });
+    }
+
+    let _ = fs::remove_file(&progress_path).await;
+    Ok(summaries)
+}
+
+#[async_trait]
+impl PortableVectorStore for QdrantClient {
+    async fn collection_shape(&self, collection: &str) -> VectorResult<Option<CollectionShape>> {
+        let exists = self
+            .client()
+            .collection_exists(collection)
+            .await
+            .map_err(|e| VectorError::from_operation_failed("collection_exists", e.to_string()))?;
+        if !exists {
+            return Ok(None);
+        }
+
+        let info = self
+            .client()
+            .collection_info(collection)
+            .await
+            .map_err(|e| VectorError::from_operation_failed("collection_info", e.to_string()))?;
+        let params = info
+            .result
+            .and_then(|info| info.config)
+            .and_then(|config| config.params)
+            .and_then(|params| params.vectors_config)
+            .and_then(|vectors| vectors.config);
+        match params {
+            Some(Config::Params(params)) => {
+                let distance = match Distance::try_from(params.distance) {
+                    Ok(Distance::Cosine) => DistanceMetric::Cosine,
+                    Ok(Distance::Euclid) => DistanceMetric::Euclidean,
+                    Ok(Distance::Dot) => DistanceMetric::Dot,
+                    _ => {
+                        return Err(VectorError::ConfigurationError(format!(
+                            "Collection {collection} uses an unsupported distance metric"
+                        )))
+                    }
+                };
+                Ok(Some(CollectionShape {
+                    dimension: params.size as usize,
+                    distance,
+                }))
+            }
+            _ => Err(VectorError::ConfigurationError(format!(
+                "Collection {collection} does not use a single unnamed vector"
+            ))),
+        }
+    }
+
+    async fn create_collection(
+        &self,
+        collection: &str,
+        shape: &CollectionShape,
+    ) -> VectorResult<()> {
+        let params = VectorParamsBuilder::new(
+            shape.dimension as u64,
+            Distance::from(shape.distance.clone()),
+        );
+        self.client()
+            .create_collection(CreateCollectionBuilder::new(collection).vectors_config(params))
+            .await
+            .map_err(|e| {
+                VectorError::from_operation_failed(
+                    "create_collection",
+                    format!("Failed to create collection {collection}: {e}"),
+                )
+            })?;
+        info!("Created collection {} for import", collection);
+        Ok(())
+    }
+
+    async fn scroll_points(
+        &self,
+        collection: &str,
+        offset: Option<&str>,
+        limit: usize,
+    ) -> VectorResult<PointPage> {
+        let mut request = ScrollPointsBuilder::new(collection)
+            .limit(limit as u32)
+            .with_payload(true)
+            .with_vectors(true);
+        if let Some(offset) = offset {
+            request = request.offset(point_id_from_str(offset));
+        }
+        let response = self
+            .client()
+            .scroll(request)
+            .await
+            .map_err(|e| VectorError::from_operation_failed("scroll", e.to_string()))?;
+
+        let points = response
+            .result
+            .into_iter()
+            .map(|point| {
+                let vector = match point.vectors.and_then(|v| v.vectors_options) {
+                    Some(VectorsOptions::Vector(vector)) => vector.data,
+                    _ => Vec::new(),
+                };
+                PortablePoint {
+                    id: point.id.map(point_id_to_string).unwrap_or_default(),
+                    vector,
+                    payload: point
+                        .payload
+                        .into_iter()
+                        .map(|(key, value)| (key, value.into()))
+                        .collect(),
+                }
+            })
+            .collect();
+        Ok(PointPage {
+            points,
+            next_offset: response.next_page_offset.map(point_id_to_string),
+        })
+    }
+
+    async fn upsert_points(
+        &self,
+        collection: &str,
+        points: Vec<PortablePoint>,
+    ) -> VectorResult<()> {
+        let points: Vec<PointStruct> = points
+            .into_iter()
+            .map(|point| {
+                let payload: HashMap<String, qdrant_client::qdrant::Value> = point
+                    .payload
+                    .into_iter()
+                    .map(|(key, value)| (key, value.into()))
+                    .collect();
+                PointStruct::new(point_id_from_str(&point.id), point.vector, payload)
+            })
+            .collect();
+        self.client()
+            .upsert_points(UpsertPointsBuilder::new(collection, points).wait(true))
+            .await
+            .map_err(|e| VectorError::from_operation_failed("upsert_points", e.to_string()))?;
+        Ok(())
+    }
+}
+
+/// JSONL file name for a collection, keeping only filename-safe characters
+fn collection_file_name(collection: &str) -> String {
+    let safe: String = collection
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{safe}.jsonl")
+}
+
+/// Numeric IDs round-trip as numbers, everything else as UUIDs
+fn point_id_from_str(id: &str) -> PointId {
+    match id.parse::<u64>() {
+        Ok(num) => PointId::from(num),
+        Err(_) => PointId::from(id.to_string()),
+    }
+}
+
+fn point_id_to_string(id: PointId) -> String {
+    match id.point_id_options {
+        Some(PointIdOptions::Uuid(uuid)) => uuid,
+        Some(PointIdOptions::Num(num)) => num.to_string(),
+        None => String::new(),
+    }
+}
+
+fn check_dimension(expected: usize, point: &PortablePoint) -> VectorResult<()> {
+    if point.vector.len() != expected {
+        return Err(VectorError::InvalidVectorDimensions {
+            expected,
+            actual: point.vector.len(),
+        });
+    }
+    Ok(())
+}
+
+async fn file_checksum(path: &Path) -> VectorResult<String> {
+    let mut file = fs::File::open(path)
+        .await
+        .map_err(|e| io_error("checksum", path, e))?;
+    let mut context = md5::Context::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .await
+            .map_err(|e| io_error("checksum", path, e))?;
+        if read == 0 {
+            break;
+        }
+        context.consume(&buffer[..read]);
+    }
+    Ok(format!("{:x}", context.compute()))
+}
+
+async fn read_json<T: DeserializeOwned>(path: &Path) -> VectorResult<Option<T>> {
+    match fs::read(path).await {
+        Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(io_error("read", path, e)),
+    }
+}
+
+async fn write_json<T: Serialize>(path: &Path, value: &T) -> VectorResult<()> {
+    write_json_atomic_async(path, value)
+        .await
+        .map_err(|e| io_error("write", path, e))
+}
+
+fn io_error(operation: &str, path: &Path, error: std::io::Error) -> VectorError {
+    VectorError::from_operation_failed(operation, format!("{}: {error}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    type CollectionData = (CollectionShape, BTreeMap<String, PortablePoint>);
+
+    /// In-memory backend; optionally fails upserts after a number of calls
+    #[derive(Default)]
+    struct MemoryStore {
+        collections: Mutex<HashMap<String, CollectionData>>,
+        fail_after_upserts: Mutex<Option<usize>>,
+    }
+
+    impl MemoryStore {
+        fn with_points(name: &str, dimension: usize, count: usize) -> Self {
+            let store = Self::default();
+            let points = (0..count)
+                .map(|i| {
+                    let mut payload = serde_json::Map::new();
+                    payload.insert("content".to_string(), serde_json::json!(format!("doc {i}")));
+                    let point = PortablePoint {
+                        id: format!("{i:04}"),
+                        vector: vec![i as f32; dimension],
+                        payload,
+                    };
+                    (point.id.clone(), point)
+                })
+                .collect();
+            let shape = CollectionShape {
+                dimension,
+                distance: DistanceMetric::Cosine,
+            };
+            store
+                .collections
+                .lock()
+                .unwrap()
+                .insert(name.to_string(), (shape, points));
+            store
+        }
+
+        fn points(&self, name: &str) -> Vec<PortablePoint> {
+            self.collections.lock().unwrap()[name]
+                .1
+                .values()
+                .cloned()
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl PortableVectorStore for MemoryStore {
+        async fn collection_shape(
+            &self,
+            collection: &str,
+        ) -> VectorResult<Option<CollectionShape>> {
+            Ok(self
+                .collections
+                .lock()
+                .unwrap()
+                .get(collection)
+                .map(|(shape, _)| shape.clone()))
+        }
+
+        async fn create_collection(
+            &self,
+            collection: &str,
+            shape: &CollectionShape,
+        ) -> VectorResult<()> {
+            self.collections
+                .lock()
+                .unwrap()
+                .insert(collection.to_string(), (shape.clone(), BTreeMap::new()));
+            Ok(())
+        }
+
+        async fn scroll_points(
+            &self,
+            collection: &str,
+            offset: Option<&str>,
+            limit: usize,
+        ) -> VectorResult<PointPage> {
+            let collections = self.collections.lock().unwrap();
+            let mut points = collections[collection]
+                .1
+                .range(offset.unwrap_or_default().to_string()..)
+                .map(|(_, point)| point.clone());
+            let page: Vec<_> = points.by_ref().take(limit).collect();
+            Ok(PointPage {
+                points: page,
+                next_offset: points.next().map(|point| point.id),
+            })
+        }
+
+        async fn upsert_points(
+            &self,
+            collection: &str,
+            points: Vec<PortablePoint>,
+        ) -> VectorResult<()> {
+            let mut fail_after = self.fail_after_upserts.lock().unwrap();
+            if let Some(remaining) = fail_after.as_mut() {
+                if *remaining == 0 {
+                    return Err(VectorError::from_connection_error("backend went away"));
+                }
+                *remaining -= 1;
+            }
+            let mut collections = self.collections.lock().unwrap();
+            let target = &mut collections.get_mut(collection).unwrap().1;
+            for point in points {
+                target.insert(point.id.clone(), point);
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_import_round_trip_with_resume_and_checks() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = MemoryStore::with_points("research", 3, 10);
+        let collections = vec!["research".to_string()];
+        let export_options = ExportOptions {
+            batch_size: 4,
+            resume: false,
+        };
+
+        let mut pages = Vec::new();
+        let manifest = export_dataset(
+            &source,
+            dir.path(),
+            &collections,
+            "all-MiniLM-L6-v2",
+            &export_options,
+            |_, points| pages.push(points),
+        )
+        .await
+        .unwrap();
+        assert_eq!(pages, vec![4, 8, 10]);
+        assert_eq!(manifest.collections[0].points, 10);
+        assert_eq!(manifest.collections[0].file, "research.jsonl");
+        assert!(!dir.path().join(EXPORT_PROGRESS_FILE).exists());
+
+        // A second export into the same directory needs --resume semantics
+        assert!(export_dataset(
+            &source,
+            dir.path(),
+            &collections,
+            "all-MiniLM-L6-v2",
+            &export_options,
+            |_, _| {}
+        )
+        .await
+        .is_err());
+
+        // An import interrupted after the first batch resumes where it stopped
+        let target = MemoryStore::default();
+        *target.fail_after_upserts.lock().unwrap() = Some(1);
+        let import_options = ImportOptions {
+            batch_size: 4,
+            embedding_model: Some("all-MiniLM-L6-v2".to_string()),
+            dimension: Some(3),
+            ..Default::default()
+        };
+        assert!(
+            import_dataset(&target, dir.path(), &import_options, |_, _| {})
+                .await
+                .is_err()
+        );
+        assert_eq!(target.points("research").len(), 4);
+
+        *target.fail_after_upserts.lock().unwrap() = None;
+        let resume = ImportOptions {
+            resume: true,
+            ..import_options.clone()
+        };
+        let summary = import_dataset(&target, dir.path(), &resume, |_, _| {})
+            .await
+            .unwrap();
+        assert_eq!(summary[0].resumed, 4);
+        assert_eq!(summary[0].imported, 6);
+        assert_eq!(target.points("research"), source.points("research"));
+        assert!(!dir.path().join(IMPORT_PROGRESS_FILE).exists());
+
+        // Model and dimension mismatches are refused
+        let other_model = ImportOptions {
+            embedding_model: Some("bge-small".to_string()),
+            ..import_options.clone()
+        };
+        assert!(matches!(
+            import_dataset(&MemoryStore::default(), dir.path(), &other_model, |_, _| {}).await,
+            Err(VectorError::ConfigurationError(_))
+        ));
+        let wider = MemoryStore::with_points("research", 8, 0);
+        assert!(matches!(
+            import_dataset(&wider, dir.path(), &import_options, |_, _| {}).await,
+            Err(VectorError::InvalidVectorDimensions {
+                expected: 8,
+                actual: 3
+            })
+        ));
+
+        // A corrupted file fails checksum validation before anything is written
+        let data = dir.path().join("research.jsonl");
+        let mut contents = std::fs::read_to_string(&data).unwrap();
+        contents = contents.replacen("doc 1", "doc X", 1);
+        std::fs::write(&data, contents).unwrap();
+        let fresh = MemoryStore::default();
+        assert!(matches!(
+            import_dataset(&fresh, dir.path(), &import_options, |_, _| {}).await,
+            Err(VectorError::ChecksumMismatch { .. })
+        ));
+        assert!(fresh.collections.lock().unwrap().is_empty());
+    }
+}
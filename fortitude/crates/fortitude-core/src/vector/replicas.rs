@@ -0,0 +1,689 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Read replicas with latency-aware routing and buffered primary writes
+//! A [`ReplicaSet`] wraps the primary vector endpoint and any read replicas.
+//! Reads go to the healthy endpoint with the lowest observed latency and fail
+//! over to the next one on transient errors. Writes always target the primary;
+//! while it is unreachable they are queued in a bounded buffer and replayed in
+//! order once it answers again.
+
+use crate::vector::client::QdrantClient;
+use crate::vector::config::VectorConfig;
+use crate::vector::error::{VectorError, VectorResult};
+use qdrant_client::qdrant::{DeletePointsBuilder, PointId, PointStruct, UpsertPointsBuilder};
+use qdrant_client::QdrantError;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+/// Additional read-only endpoint serving the same collections as the primary
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplicaEndpoint {
+    pub url: String,
+    #[serde(default)]
+    pub api_key: Option<String>,
+}
+
+impl ReplicaEndpoint {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            api_key: None,
+        }
+    }
+}
+
+/// Replica routing and write buffering configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReplicationConfig {
+    /// Read replicas in addition to the primary `url`
+    pub replicas: Vec<ReplicaEndpoint>,
+    /// Maximum number of writes queued while the primary is unreachable
+    pub write_buffer_capacity: usize,
+    /// Consecutive failures before an endpoint is considered unhealthy
+    pub failure_threshold: u32,
+    /// How long an unhealthy endpoint is avoided before it is tried again
+    pub retry_unhealthy_after: Duration,
+    /// Weight of the newest sample in the moving latency average (0.0-1.0)
+    pub latency_smoothing: f64,
+}
+
+impl Default for ReplicationConfig {
+    fn default() -> Self {
+        Self {
+            replicas: Vec::new(),
+            write_buffer_capacity: 1000,
+            failure_threshold: 3,
+            retry_unhealthy_after: Duration::from_secs(30),
+            latency_smoothing: 0.3,
+        }
+    }
+}
+
+impl ReplicationConfig {
+    pub fn with_replica(mut self, replica: ReplicaEndpoint) -> Self {
+        self.replicas.push(replica);
+        self
+    }
+
+    pub fn with_write_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.write_buffer_capacity = capacity;
+        self
+    }
+
+    pub fn validate(&self) -> VectorResult<()> {
+        if self.replicas.iter().any(|replica| replica.url.is_empty()) {
+            return Err(VectorError::ConfigurationError(
+                "Replica URL cannot be empty".to_string(),
+            ));
+        }
+        if self.failure_threshold == 0 {
+            return Err(VectorError::ConfigurationError(
+                "Replica failure threshold must be greater than zero".to_string(),
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.latency_smoothing) || self.latency_smoothing == 0.0 {
+            return Err(VectorError::ConfigurationError(
+                "Latency smoothing must be in (0.0, 1.0]".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Whether an endpoint accepts writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EndpointRole {
+    Primary,
+    Replica,
+}
+
+impl std::fmt::Display for EndpointRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EndpointRole::Primary => write!(f, "primary"),
+            EndpointRole::Replica => write!(f, "replica"),
+        }
+    }
+}
+
+/// Health snapshot for one endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EndpointHealth {
+    pub url: String,
+    pub role: EndpointRole,
+    pub healthy: bool,
+    /// Moving average of successful request latency
+    pub latency_ms: Option<f64>,
+    pub consecutive_failures: u32,
+    pub total_requests: u64,
+    pub total_failures: u64,
+    pub last_error: Option<String>,
+}
+
+/// Result of a write routed through the replica set
+#[derive(Debug, Clone, PartialEq)]
+pub enum WriteOutcome<R> {
+    /// The primary applied the write
+    Applied(R),
+    /// The primary was unreachable; the write waits in the buffer
+    Queued { pending: usize },
+}
+
+struct Endpoint<C> {
+    url: String,
+    role: EndpointRole,
+    client: C,
+}
+
+#[derive(Debug, Default)]
+struct EndpointState {
+    latency_ms: Option<f64>,
+    consecutive_failures: u32,
+    total_requests: u64,
+    total_failures: u64,
+    last_error: Option<String>,
+    last_failure: Option<Instant>,
+}
+
+/// Primary plus read replicas of type `C`, buffering writes of type `W`
+pub struct ReplicaSet<C, W> {
+    endpoints: Vec<Endpoint<C>>,
+    state: Mutex<Vec<EndpointState>>,
+    pending: tokio::sync::Mutex<VecDeque<W>>,
+    config: ReplicationConfig,
+}
+
+impl<C, W> std::fmt::Debug for ReplicaSet<C, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReplicaSet")
+            .field(
+                "endpoints",
+                &self.endpoints.iter().map(|e| &e.url).collect::<Vec<_>>(),
+            )
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl<C: Clone, W: Clone> ReplicaSet<C, W> {
+    pub fn new(primary_url: impl Into<String>, primary: C, config: ReplicationConfig) -> Self {
+        Self {
+            endpoints: vec![Endpoint {
+                url: primary_url.into(),
+                role: EndpointRole::Primary,
+                client: primary,
+            }],
+            state: Mutex::new(vec![EndpointState::default()]),
+            pending: tokio::sync::Mutex::new(VecDeque::new()),
+            config,
+        }
+    }
+
+    pub fn with_replica(mut self, url: impl Into<String>, client: C) -> Self {
+        self.endpoints.push(Endpoint {
+            url: url.into(),
+            role: EndpointRole::Replica,
+            client,
+        });
+        self.state.get_mut().unwrap().push(EndpointState::default());
+        self
+    }
+
+    pub fn primary(&self) -> &C {
+        &self.endpoints[0].client
+    }
+
+    pub fn config(&self) -> &ReplicationConfig {
+        &self.config
+    }
+
+    pub fn replica_count(&self) -> usize {
+        self.endpoints.len() - 1
+    }
+
+    /// Endpoint indices in the order reads should try them: healthy endpoints
+    /// by failures then latency, then unhealthy ones due for another attempt
+    fn read_order(&self) -> Vec<usize> {
+        let state = self.state.lock().unwrap();
+        let threshold = self.config.failure_threshold;
+
+        let mut healthy: Vec<usize> = (0..self.endpoints.len())
+            .filter(|&i| state[i].consecutive_failures < threshold)
+            .collect();
+        healthy.sort_by(|&a, &b| {
+            state[a]
+                .consecutive_failures
+                .cmp(&state[b].consecutive_failures)
+                .then_with(|| {
+                    let latency_a = state[a].latency_ms.unwrap_or(0.0);
+                    let latency_b = state[b].latency_ms.unwrap_or(0.0);
+                    latency_a.total_cmp(&latency_b)
+                })
+        });
+
+        let unhealthy: Vec<usize> = (0..self.endpoints.len())
+            .filter(|&i| state[i].consecutive_failures >= threshold)
+            .collect();
+        let due: Vec<usize> = unhealthy
+            .iter()
+            .copied()
+            .filter(|&i| {
+                state[i]
+                    .last_failure
+                    .is_none_or(|at| at.elapsed() >= self.config.retry_unhealthy_after)
+            })
+            .collect();
+
+        if healthy.is_empty() && due.is_empty() {
+            // Everything is down; trying beats failing without a request
+            return unhealthy;
+        }
+        healthy.extend(due);
+        healthy
+    }
+
+    fn record_success(&self, index: usize, elapsed: Duration) {
+        let mut state = self.state.lock().unwrap();
+        let entry = &mut state[index];
+        let sample = elapsed.as_secs_f64() * 1000.0;
+        let alpha = self.config.latency_smoothing;
+        entry.latency_ms = Some(match entry.latency_ms {
+            Some(average) => alpha * sample + (1.0 - alpha) * average,
+            None => sample,
+        });
+        entry.total_requests += 1;
+        if entry.consecutive_failures >= self.config.failure_threshold {
+            info!("Vector endpoint {} recovered", self.endpoints[index].url);
+        }
+        entry.consecutive_failures = 0;
+    }
+
+    fn record_failure(&self, index: usize, error: &VectorError) {
+        let mut state = self.state.lock().unwrap();
+        let entry = &mut state[index];
+        entry.total_requests += 1;
+        entry.total_failures += 1;
+        entry.consecutive_failures += 1;
+        entry.last_error = Some(error.to_string());
+        entry.last_failure = Some(Instant::now());
+        if entry.consecutive_failures == self.config.failure_threshold {
+            warn!(
+                "Vector endpoint {} marked unhealthy: {error}",
+                self.endpoints[index].url
+            );
+        }
+    }
+
+    /// Run a read on the best endpoint, failing over on transient errors
+    pub async fn read<T, F, Fut>(&self, operation: F) -> VectorResult<T>
+    where
+        F: Fn(C) -> Fut,
+        Fut: Future<Output = VectorResult<T>>,
+    {
+        let mut last_error = None;
+        for index in self.read_order() {
+            let started = Instant::now();
+            match operation(self.endpoints[index].client.clone()).await {
+                Ok(value) => {
+                    self.record_success(index, started.elapsed());
+                    return Ok(value);
+                }
+                Err(e) if e.is_retryable() => {
+                    debug!(
+                        "Read from {} failed, trying next endpoint: {e}",
+                        self.endpoints[index].url
+                    );
+                    self.record_failure(index, &e);
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            VectorError::from_connection_error("No vector endpoints configured")
+        }))
+    }
+
+    /// Apply a write on the primary, queueing it if the primary is unreachable.
+    /// Earlier queued writes are replayed first so ordering is preserved.
+    pub async fn write<R, F, Fut>(&self, write: W, apply: F) -> VectorResult<WriteOutcome<R>>
+    where
+        F: Fn(C, W) -> Fut,
+        Fut: Future<Output = VectorResult<R>>,
+    {
+        let mut pending = self.pending.lock().await;
+        if !self.drain(&mut pending, &apply).await {
+            return self.enqueue(&mut pending, write);
+        }
+
+        let started = Instant::now();
+        match apply(self.primary().clone(), write.clone()).await {
+            Ok(result) => {
+                self.record_success(0, started.elapsed());
+                Ok(WriteOutcome::Applied(result))
+            }
+            Err(e) if e.is_retryable() => {
+                self.record_failure(0, &e);
+                self.enqueue(&mut pending, write)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Replay queued writes against the primary, returning how many remain
+    pub async fn flush_pending<R, F, Fut>(&self, apply: F) -> usize
+    where
+        F: Fn(C, W) -> Fut,
+        Fut: Future<Output = VectorResult<R>>,
+    {
+        let mut pending = self.pending.lock().await;
+        self.drain(&mut pending, &apply).await;
+        pending.len()
+    }
+
+    pub async fn pending_writes(&self) -> usize {
+        self.pending.lock().await.len()
+    }
+
+    /// Returns false if the primary is still unreachable
+    async fn drain<R, F, Fut>(&self, pending: &mut VecDeque<W>, apply: &F) -> bool
+    where
+        F: Fn(C, W) -> Fut,
+        Fut: Future<Output = VectorResult<R>>,
+    {
+        while let Some(write) = pending.front().cloned() {
+            let started = Instant::now();
+            match apply(self.primary().clone(), write).await {
+                Ok(_) => {
+                    self.record_success(0, started.elapsed());
+                    pending.pop_front();
+                }
+                Err(e) if e.is_retryable() => {
+                    self.record_failure(0, &e);
+                    return false;
+                }
+                Err(e) => {
+                    warn!("Dropping queued vector write that cannot be applied: {e}");
+                    pending.pop_front();
+                }
+            }
+        }
+        true
+    }
+
+    fn enqueue<R>(&self, pending: &mut VecDeque<W>, write: W) -> VectorResult<WriteOutcome<R>> {
+        if pending.len() >= self.config.write_buffer_capacity {
+            return Err(VectorError::ResourceLimitExceeded(format!(
+                "Primary vector endpoint unavailable and write buffer is full ({} writes)",
+                self.config.write_buffer_capacity
+            )));
+        }
+        pending.push_back(write);
+        warn!(
+            "Primary vector endpoint unavailable; queued write ({} pending)",
+            pending.len()
+        );
+        Ok(WriteOutcome::Queued {
+            pending: pending.len(),
+        })
+    }
+
+    /// Check every endpoint with `check` and return fresh health
+    pub async fn probe<F, Fut>(&self, check: F) -> Vec<EndpointHealth>
+    where
+        F: Fn(C) -> Fut,
+        Fut: Future<Output = VectorResult<()>>,
+    {
+        for (index, endpoint) in self.endpoints.iter().enumerate() {
+            let started = Instant::now();
+            match check(endpoint.client.clone()).await {
+                Ok(()) => self.record_success(index, started.elapsed()),
+                Err(e) => self.record_failure(index, &e),
+            }
+        }
+        self.health()
+    }
+
+    /// Health as observed from recent traffic
+    pub fn health(&self) -> Vec<EndpointHealth> {
+        let state = self.state.lock().unwrap();
+        self.endpoints
+            .iter()
+            .zip(state.iter())
+            .map(|(endpoint, entry)| EndpointHealth {
+                url: endpoint.url.clone(),
+                role: endpoint.role,
+                healthy: entry.consecutive_failures < self.config.failure_threshold,
+                latency_ms: entry.latency_ms,
+                consecutive_failures: entry.consecutive_failures,
+                total_requests: entry.total_requests,
+                total_failures: entry.total_failures,
+                last_error: entry.last_error.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Write waiting for the primary Qdrant endpoint
+#[derive(Debug, Clone)]
+pub enum PendingWrite {
+    Upsert {
+        collection: String,
+        points: Vec<PointStruct>,
+    },
+    Delete {
+        collection: String,
+        ids: Vec<PointId>,
+    },
+}
+
+/// Primary Qdrant client plus read replicas
+pub type VectorEndpoints = ReplicaSet<Arc<QdrantClient>, PendingWrite>;
+
+impl VectorEndpoints {
+    /// Build the endpoint set for `config`, connecting replicas lazily so an
+    /// unavailable replica does not prevent startup
+    pub fn connect(config: &VectorConfig, primary: Arc<QdrantClient>) -> VectorResult<Self> {
+        config.replication.validate()?;
+
+        let mut endpoints = Self::new(config.url.clone(), primary, config.replication.clone());
+        for replica in &config.replication.replicas {
+            let mut replica_config = config.clone().with_url(replica.url.clone());
+            replica_config.api_key = replica.api_key.clone().or(config.api_key.clone());
+            let client = QdrantClient::new_lazy(replica_config)?;
+            endpoints = endpoints.with_replica(replica.url.clone(), Arc::new(client));
+        }
+
+        if endpoints.replica_count() > 0 {
+            info!(
+                "Vector reads can fail over to {} replica(s)",
+                endpoints.replica_count()
+            );
+        }
+        Ok(endpoints)
+    }
+
+    /// Run a Qdrant health check against every endpoint
+    pub async fn probe_all(&self) -> Vec<EndpointHealth> {
+        self.probe(|client| async move {
+            client
+                .client()
+                .health_check()
+                .await
+                .map(|_| ())
+                .map_err(|e| VectorError::HealthCheckFailed {
+                    reason: e.to_string(),
+                })
+        })
+        .await
+    }
+}
+
+/// Apply a buffered write to a Qdrant endpoint, returning the update status
+pub async fn apply_pending_write(
+    client: Arc<QdrantClient>,
+    write: PendingWrite,
+) -> VectorResult<i32> {
+    let result = match write {
+        PendingWrite::Upsert { collection, points } => {
+            client
+                .client()
+                .upsert_points(UpsertPointsBuilder::new(collection, points))
+                .await
+                .map_err(|e| qdrant_error("upsert_points", e))?
+                .result
+        }
+        PendingWrite::Delete { collection, ids } => {
+            client
+                .client()
+                .delete_points(DeletePointsBuilder::new(collection).points(ids))
+                .await
+                .map_err(|e| qdrant_error("delete_points", e))?
+                .result
+        }
+    };
+    Ok(result.map(|r| r.status).unwrap_or(0))
+}
+
+/// Map a Qdrant error, marking transport failures as connection errors so
+/// reads fail over and writes are buffered
+pub fn qdrant_error(operation: &str, error: QdrantError) -> VectorError {
+    let transient = match &error {
+        QdrantError::ResponseError { status } => matches!(
+            format!("{:?}", status.code()).as_str(),
+            "Unavailable" | "DeadlineExceeded" | "Cancelled" | "Aborted"
+        ),
+        QdrantError::ResourceExhaustedError { .. } | QdrantError::Io(_) => true,
+        _ => false,
+    };
+
+    if transient {
+        VectorError::from_connection_error(format!("{operation}: {error}"))
+    } else {
+        VectorError::from_operation_failed(operation, error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+    #[derive(Debug, Default)]
+    struct MockEndpoint {
+        name: &'static str,
+        down: AtomicBool,
+        delay_ms: AtomicU64,
+        applied: Mutex<Vec<u32>>,
+    }
+
+    impl MockEndpoint {
+        fn new(name: &'static str, delay_ms: u64) -> Arc<Self> {
+            let endpoint = Self {
+                name,
+                ..Default::default()
+            };
+            endpoint.delay_ms.store(delay_ms, Ordering::SeqCst);
+            Arc::new(endpoint)
+        }
+
+        async fn read(self: Arc<Self>) -> VectorResult<&'static str> {
+            tokio::time::sleep(Duration::from_millis(self.delay_ms.load(Ordering::SeqCst))).await;
+            if self.down.load(Ordering::SeqCst) {
+                return Err(VectorError::from_connection_error("endpoint down"));
+            }
+            Ok(self.name)
+        }
+
+        async fn apply(self: Arc<Self>, write: u32) -> VectorResult<()> {
+            if self.down.load(Ordering::SeqCst) {
+                return Err(VectorError::from_connection_error("endpoint down"));
+            }
+            self.applied.lock().unwrap().push(write);
+            Ok(())
+        }
+    }
+
+    fn config() -> ReplicationConfig {
+        ReplicationConfig {
+            failure_threshold: 2,
+            retry_unhealthy_after: Duration::from_secs(60),
+            latency_smoothing: 1.0,
+            ..Default::default()
+        }
+        .with_write_buffer_capacity(2)
+    }
+
+    #[tokio::test]
+    async fn test_reads_prefer_fastest_healthy_endpoint() {
+        let primary = MockEndpoint::new("primary", 20);
+        let replica = MockEndpoint::new("replica", 1);
+        let set: ReplicaSet<Arc<MockEndpoint>, u32> =
+            ReplicaSet::new("http://primary", primary.clone(), config())
+                .with_replica("http://replica", replica.clone());
+
+        // Probe establishes latencies; the replica is faster
+        set.probe(|endpoint| async move { endpoint.read().await.map(|_| ()) })
+            .await;
+        assert_eq!(set.read(MockEndpoint::read).await.unwrap(), "replica");
+
+        // Failover to the primary when the replica goes away
+        replica.down.store(true, Ordering::SeqCst);
+        assert_eq!(set.read(MockEndpoint::read).await.unwrap(), "primary");
+
+        let health = set
+            .probe(|endpoint| async move { endpoint.read().await.map(|_| ()) })
+            .await;
+        assert!(health[0].healthy);
+        assert_eq!(health[1].role, EndpointRole::Replica);
+        assert!(!health[1].healthy);
+        assert_eq!(health[1].consecutive_failures, 2);
+        assert!(health[1]
+            .last_error
+            .as_ref()
+            .unwrap()
+            .contains("endpoint down"));
+
+        // Unhealthy replica is skipped entirely until its retry window passes
+        assert_eq!(set.read(MockEndpoint::read).await.unwrap(), "primary");
+        assert_eq!(set.health()[1].total_requests, 4);
+    }
+
+    #[tokio::test]
+    async fn test_read_fails_when_all_endpoints_down() {
+        let primary = MockEndpoint::new("primary", 0);
+        primary.down.store(true, Ordering::SeqCst);
+        let set: ReplicaSet<Arc<MockEndpoint>, u32> =
+            ReplicaSet::new("http://primary", primary, config());
+
+        let err = set.read(MockEndpoint::read).await.unwrap_err();
+        assert!(err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_writes_buffer_while_primary_down_and_replay_in_order() {
+        let primary = MockEndpoint::new("primary", 0);
+        let set: ReplicaSet<Arc<MockEndpoint>, u32> =
+            ReplicaSet::new("http://primary", primary.clone(), config());
+
+        assert_eq!(
+            set.write(1, MockEndpoint::apply).await.unwrap(),
+            WriteOutcome::Applied(())
+        );
+
+        primary.down.store(true, Ordering::SeqCst);
+        assert_eq!(
+            set.write(2, MockEndpoint::apply).await.unwrap(),
+            WriteOutcome::Queued { pending: 1 }
+        );
+        assert_eq!(
+            set.write(3, MockEndpoint::apply).await.unwrap(),
+            WriteOutcome::Queued { pending: 2 }
+        );
+
+        // Buffer is bounded
+        let err = set.write(4, MockEndpoint::apply).await.unwrap_err();
+        assert!(matches!(err, VectorError::ResourceLimitExceeded(_)));
+
+        primary.down.store(false, Ordering::SeqCst);
+        assert_eq!(
+            set.write(5, MockEndpoint::apply).await.unwrap(),
+            WriteOutcome::Applied(())
+        );
+        assert_eq!(set.pending_writes().await, 0);
+        assert_eq!(*primary.applied.lock().unwrap(), vec![1, 2, 3, 5]);
+    }
+
+    #[test]
+    fn test_replication_config_validation() {
+        assert!(ReplicationConfig::default().validate().is_ok());
+
+        let empty_url = ReplicationConfig::default().with_replica(ReplicaEndpoint::new(""));
+        assert!(empty_url.validate().is_err());
+
+        let no_threshold = ReplicationConfig {
+            failure_threshold: 0,
+            ..Default::default()
+        };
+        assert!(no_threshold.validate().is_err());
+    }
+}
@@ -23,13 +23,14 @@ use crate::vector::{
     config::VectorConfig,
     embeddings::{EmbeddingGenerator, LocalEmbeddingService},
     error::{VectorError, VectorResult},
+    replicas::{
+        apply_pending_write, qdrant_error, EndpointHealth, PendingWrite, VectorEndpoints,
+        WriteOutcome,
+    },
 };
 use async_trait::async_trait;
 use fortitude_types::research::*;
-use qdrant_client::qdrant::{
-    DeletePointsBuilder, GetPointsBuilder, PointStruct, SearchPointsBuilder, UpsertPointsBuilder,
-    Value,
-};
+use qdrant_client::qdrant::{GetPointsBuilder, PointStruct, SearchPointsBuilder, Value};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -182,6 +183,8 @@ pub struct VectorStorageStats {
 pub struct VectorStorage {
     /// Qdrant client for vector database operations
     pub qdrant_client: Arc<QdrantClient>,
+    /// Primary and read replicas used for routing and failover
+    endpoints: Arc<VectorEndpoints>,
     /// Embedding service for text-to-vector conversion
     pub embedding_service: Arc<LocalEmbeddingService>,
     /// Default collection name
@@ -197,9 +200,20 @@ impl VectorStorage {
         embedding_service: Arc<LocalEmbeddingService>,
     ) -> Self {
         let default_collection = qdrant_client.default_collection().to_string();
+        let config = qdrant_client.config();
+        let endpoints = VectorEndpoints::connect(config, Arc::clone(&qdrant_client))
+            .unwrap_or_else(|e| {
+                warn!("Ignoring vector replica configuration: {e}");
+                VectorEndpoints::new(
+                    config.url.clone(),
+                    Arc::clone(&qdrant_client),
+                    Default::default(),
+                )
+            });
 
         Self {
             qdrant_client,
+            endpoints: Arc::new(endpoints),
             embedding_service,
             default_collection,
             stats: Arc::new(tokio::sync::RwLock::new(VectorStorageStats {
@@ -244,6 +258,26 @@ impl VectorStorage {
         rt.block_on(Self::from_config(config))
     }
 
+    /// Primary and replica endpoints behind this storage
+    pub fn endpoints(&self) -> &Arc<VectorEndpoints> {
+        &self.endpoints
+    }
+
+    /// Endpoint health as observed from recent requests
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.endpoints.health()
+    }
+
+    /// Health-check every endpoint and replay writes queued for the primary
+    pub async fn probe_endpoints(&self) -> Vec<EndpointHealth> {
+        let health = self.endpoints.probe_all().await;
+        let remaining = self.endpoints.flush_pending(apply_pending_write).await;
+        if remaining > 0 {
+            warn!("{remaining} vector write(s) still waiting for the primary");
+        }
+        health
+    }
+
     /// Initialize the storage service (ensure collections exist)
     #[instrument(skip(self))]
     pub async fn initialize(&self) -> VectorResult<()> {
@@ -344,14 +378,12 @@ impl VectorStorage {
         // Create point for upsert
         let point = PointStruct::new(document.id.clone(), document.embedding.clone(), payload);
 
-        // Upsert the point
-        let upsert_request = UpsertPointsBuilder::new(collection.clone(), vec![point]);
-
-        self.qdrant_client
-            .client()
-            .upsert_points(upsert_request)
-            .await
-            .map_err(|e| VectorError::from_operation_failed("store_document", e.to_string()))?;
+        // Upsert the point on the primary, buffering it if the primary is down
+        let write = PendingWrite::Upsert {
+            collection: collection.clone(),
+            points: vec![point],
+        };
+        self.endpoints.write(write, apply_pending_write).await?;
 
         // Update document count
         let mut stats = self.stats.write().await;
@@ -403,14 +435,22 @@ impl VectorStorage {
         if let Some(threshold) = config.threshold {
             search_request = search_request.score_threshold(threshold as f32);
         }
+        let search_request = search_request.build();
 
-        // Execute search
+        // Execute search on the fastest healthy endpoint
         let search_response = self
-            .qdrant_client
-            .client()
-            .search_points(search_request)
-            .await
-            .map_err(|e| VectorError::from_operation_failed("search_similar", e.to_string()))?;
+            .endpoints
+            .read(|client| {
+                let request = search_request.clone();
+                async move {
+                    client
+                        .client()
+                        .search_points(request)
+                        .await
+                        .map_err(|e| qdrant_error("search_similar", e))
+                }
+            })
+            .await?;
 
         // Convert results to our format
         let mut results = Vec::new();
@@ -655,14 +695,22 @@ impl VectorStorage {
         // Get points by ID
         let get_request = GetPointsBuilder::new(collection, vec![id.into()])
             .with_payload(true)
-            .with_vectors(true);
+            .with_vectors(true)
+            .build();
 
         let response = self
-            .qdrant_client
-            .client()
-            .get_points(get_request)
-            .await
-            .map_err(|e| VectorError::from_operation_failed("retrieve_by_id", e.to_string()))?;
+            .endpoints
+            .read(|client| {
+                let request = get_request.clone();
+                async move {
+                    client
+                        .client()
+                        .get_points(request)
+                        .await
+                        .map_err(|e| qdrant_error("retrieve_by_id", e))
+                }
+            })
+            .await?;
 
         if let Some(point) = response.result.first() {
             if let Some(vector_data) = &point.vectors {
@@ -717,17 +765,16 @@ impl VectorStorage {
     pub async fn delete_document(&self, id: &str) -> VectorResult<bool> {
         let collection = &self.default_collection;
 
-        let delete_request = DeletePointsBuilder::new(collection)
-            .points(vec![qdrant_client::qdrant::PointId::from(id.to_string())]);
-
-        let response = self
-            .qdrant_client
-            .client()
-            .delete_points(delete_request)
-            .await
-            .map_err(|e| VectorError::from_operation_failed("delete_document", e.to_string()))?;
+        let write = PendingWrite::Delete {
+            collection: collection.clone(),
+            ids: vec![qdrant_client::qdrant::PointId::from(id.to_string())],
+        };
 
-        let deleted = response.result.map(|r| r.status).unwrap_or(0) > 0;
+        // A delete queued for the primary is reported as done
+        let deleted = match self.endpoints.write(write, apply_pending_write).await? {
+            WriteOutcome::Applied(status) => status > 0,
+            WriteOutcome::Queued { .. } => true,
+        };
 
         if deleted {
             // Update document count
@@ -862,14 +909,22 @@ impl VectorStorage {
 
         let get_request = GetPointsBuilder::new(collection, point_ids)
             .with_payload(true)
-            .with_vectors(true);
+            .with_vectors(true)
+            .build();
 
         let response = self
-            .qdrant_client
-            .client()
-            .get_points(get_request)
-            .await
-            .map_err(|e| VectorError::from_operation_failed("retrieve_batch", e.to_string()))?;
+            .endpoints
+            .read(|client| {
+                let request = get_request.clone();
+                async move {
+                    client
+                        .client()
+                        .get_points(request)
+                        .await
+                        .map_err(|e| qdrant_error("retrieve_batch", e))
+                }
+            })
+            .await?;
 
         let found_ids: std::collections::HashSet<String> = response
             .result
@@ -962,16 +1017,16 @@ impl VectorStorage {
         let point_ids: Vec<qdrant_client::qdrant::PointId> =
             ids.iter().map(|id| id.clone().into()).collect();
 
-        let delete_request = DeletePointsBuilder::new(collection).points(point_ids);
-
-        let response = self
-            .qdrant_client
-            .client()
-            .delete_points(delete_request)
-            .await
-            .map_err(|e| VectorError::from_operation_failed("delete_batch", e.to_string()))?;
+        let write = PendingWrite::Delete {
+            collection: collection.clone(),
+            ids: point_ids,
+        };
 
-        let deleted_count = response.result.map(|r| r.status).unwrap_or(0) as usize;
+        let deleted_count = match self.endpoints.write(write, apply_pending_write).await? {
+            WriteOutcome::Applied(status) => status as usize,
+            // Queued deletes are applied in full once the primary is back
+            WriteOutcome::Queued { .. } => ids.len(),
+        };
 
         // Determine which deletions were successful
         let mut successful = Vec::new();
@@ -1120,6 +1175,11 @@ pub trait VectorStorageService: Send + Sync {
 
     /// Initialize the service
     async fn initialize(&self) -> VectorResult<()>;
+
+    /// Endpoint health as observed from recent requests; empty when untracked
+    fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        Vec::new()
+    }
 }
 
 #[async_trait]
@@ -1179,6 +1239,10 @@ impl VectorStorageService for VectorStorage {
     async fn initialize(&self) -> VectorResult<()> {
         self.initialize().await
     }
+
+    fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        VectorStorage::endpoint_health(self)
+    }
 }
 
 #[cfg(test)]
@@ -47,6 +47,7 @@ async fn create_test_storage() -> VectorResult<VectorStorage> {
         health_check: crate::vector::config::HealthCheckConfig::default(),
         connection_pool: ConnectionPoolConfig::default(),
         embedding: EmbeddingConfig::default(),
+        replication: Default::default(),
     };
 
     // Note: In real integration tests, you would start a test Qdrant instance
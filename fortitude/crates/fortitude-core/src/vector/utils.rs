@@ -71,6 +71,7 @@ pub fn cli_config_to_vector_config(params: CliConfigParams) -> Result<VectorConf
             ),
         },
         embedding: EmbeddingConfig::default(),
+        replication: Default::default(),
     })
 }
 
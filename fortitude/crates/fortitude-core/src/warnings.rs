@@ -0,0 +1,158 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Non-fatal degradation warnings recorded by the research pipeline
+// Warnings travel in the result metadata tags as `warning.<code>` entries so they survive caching
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Prefix of the metadata tags that hold pipeline warnings
+pub const WARNING_TAG_PREFIX: &str = "warning.";
+
+/// Kind of non-fatal condition that affected a research result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningCode {
+    /// Advanced classification fell back to a simpler classifier
+    ClassificationDegraded,
+    /// Context detection fell back to defaults or failed
+    ContextDetectionDegraded,
+    /// The result was served from a cache entry older than the staleness threshold
+    StaleCacheServed,
+    /// A different provider or a placeholder answered in place of the requested one
+    ProviderSubstituted,
+    /// Steps were skipped or reduced to fit the requested time budget
+    TimeBudgetApplied,
+    /// Content filter rules blocked, annotated or rewrote the result
+    ContentFiltered,
+    /// The answer recommends a crate version with a known security advisory
+    VulnerableVersion,
+    /// The answer's markdown had problems that could not be repaired
+    MarkdownUnrepaired,
+    /// A URL cited by the result failed or did not answer
+    BrokenCitation,
+    /// The result's quality score stayed below the threshold after re-querying
+    LowQuality,
+}
+
+impl WarningCode {
+    pub const ALL: [WarningCode; 10] = [
+        WarningCode::ClassificationDegraded,
+        WarningCode::ContextDetectionDegraded,
+        WarningCode::StaleCacheServed,
+        WarningCode::ProviderSubstituted,
+        WarningCode::TimeBudgetApplied,
+        WarningCode::ContentFiltered,
+        WarningCode::VulnerableVersion,
+        WarningCode::MarkdownUnrepaired,
+        WarningCode::BrokenCitation,
+        WarningCode::LowQuality,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WarningCode::ClassificationDegraded => "classification_degraded",
+            WarningCode::ContextDetectionDegraded => "context_detection_degraded",
+            WarningCode::StaleCacheServed => "stale_cache_served",
+            WarningCode::ProviderSubstituted => "provider_substituted",
+            WarningCode::TimeBudgetApplied => "time_budget_applied",
+            WarningCode::ContentFiltered => "content_filtered",
+            WarningCode::VulnerableVersion => "vulnerable_version",
+            WarningCode::MarkdownUnrepaired => "markdown_unrepaired",
+            WarningCode::BrokenCitation => "broken_citation",
+            WarningCode::LowQuality => "low_quality",
+        }
+    }
+
+    /// Parse a code from its snake_case name
+    pub fn parse(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == code)
+    }
+}
+
+impl fmt::Display for WarningCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A non-fatal condition the caller should know about
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PipelineWarning {
+    pub code: WarningCode,
+    pub message: String,
+}
+
+impl PipelineWarning {
+    pub fn new(code: WarningCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    /// Write the warning as a `warning.<code>` tag, replacing any earlier one with the same code
+    pub fn apply_to_tags(&self, tags: &mut HashMap<String, String>) {
+        tags.insert(
+            format!("{WARNING_TAG_PREFIX}{}", self.code),
+            self.message.clone(),
+        );
+    }
+
+    /// Read the warnings recorded in metadata tags, ordered by code
+    ///
+    /// Tags with an unknown code are ignored.
+    pub fn from_tags(tags: &HashMap<String, String>) -> Vec<Self> {
+        let mut warnings: Vec<Self> = tags
+            .iter()
+            .filter_map(|(key, message)| {
+                let code = WarningCode::parse(key.strip_prefix(WARNING_TAG_PREFIX)?)?;
+                Some(Self::new(code, message.clone()))
+            })
+            .collect();
+        warnings.sort_by_key(|warning| warning.code);
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warnings_round_trip_through_tags() {
+        let mut tags = HashMap::from([
+            ("classification_ms".to_string(), "1.000".to_string()),
+            ("warning.unknown_code".to_string(), "ignored".to_string()),
+        ]);
+        PipelineWarning::new(WarningCode::ProviderSubstituted, "placeholder used")
+            .apply_to_tags(&mut tags);
+        PipelineWarning::new(WarningCode::ClassificationDegraded, "fallback")
+            .apply_to_tags(&mut tags);
+
+        let warnings = PipelineWarning::from_tags(&tags);
+        assert_eq!(
+            warnings,
+            vec![
+                PipelineWarning::new(WarningCode::ClassificationDegraded, "fallback"),
+                PipelineWarning::new(WarningCode::ProviderSubstituted, "placeholder used"),
+            ]
+        );
+        assert_eq!(
+            serde_json::to_value(WarningCode::StaleCacheServed).unwrap(),
+            serde_json::json!("stale_cache_served")
+        );
+    }
+}
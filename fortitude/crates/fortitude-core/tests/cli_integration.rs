@@ -58,6 +58,7 @@ fn create_test_cli_config() -> VectorConfig {
             },
             ..Default::default()
         },
+        replication: Default::default(),
     }
 }
 
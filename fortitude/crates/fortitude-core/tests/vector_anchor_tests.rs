@@ -58,6 +58,7 @@ fn create_test_vector_config() -> VectorConfig {
             },
             ..Default::default()
         },
+        replication: Default::default(),
     }
 }
 
@@ -556,6 +557,13 @@ async fn test_anchor_research_pipeline_integration_workflow() {
             matched_keywords: vec!["async".to_string(), "rust".to_string()],
             created_at: chrono::Utc::now(),
             enhanced_classification: None,
+            code_context: None,
+            project_context: None,
+            conversation_context: None,
+            advisory_context: None,
+            prompt_budget: None,
+            provider_selection: None,
+            secondary_labels: vec![],
         },
         ClassifiedRequest {
             id: Uuid::new_v4(),
@@ -577,6 +585,13 @@ async fn test_anchor_research_pipeline_integration_workflow() {
             matched_keywords: vec!["vector".to_string(), "database".to_string()],
             created_at: chrono::Utc::now(),
             enhanced_classification: None,
+            code_context: None,
+            project_context: None,
+            conversation_context: None,
+            advisory_context: None,
+            prompt_budget: None,
+            provider_selection: None,
+            secondary_labels: vec![],
         },
     ];
 
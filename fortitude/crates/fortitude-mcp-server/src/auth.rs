@@ -15,6 +15,7 @@
 // ABOUTME: JWT authentication and authorization system for Fortitude MCP server
 // Provides production-ready security with token generation, validation, and permission-based access control
 // Includes rate limiting, input validation, and comprehensive security middleware
+// Keys, token lifetime and rate limits can be replaced at runtime on config reload
 
 use crate::config::ServerConfig;
 use anyhow::{anyhow, Result};
@@ -25,6 +26,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::sync::RwLock as StdRwLock;
 use std::time::Instant;
 use tokio::sync::RwLock;
 use tracing::{debug, info, instrument, warn};
@@ -183,47 +185,78 @@ struct RateLimitState {
     window_start: Instant,
 }
 
-/// Authentication and authorization manager
-pub struct AuthManager {
+/// Reloadable signing keys and token settings
+struct AuthSettings {
     /// JWT encoding key
     encoding_key: EncodingKey,
     /// JWT decoding key
     decoding_key: DecodingKey,
+    /// Whether requests must carry a token
+    enabled: bool,
+    /// Lifetime of generated tokens
+    token_expiration_hours: u64,
+}
+
+impl AuthSettings {
+    fn from_config(config: &ServerConfig) -> Self {
+        let jwt_secret = config.auth.jwt_secret.as_bytes();
+        Self {
+            encoding_key: EncodingKey::from_secret(jwt_secret),
+            decoding_key: DecodingKey::from_secret(jwt_secret),
+            enabled: config.auth.enabled,
+            token_expiration_hours: config.auth.token_expiration_hours,
+        }
+    }
+}
+
+/// Authentication and authorization manager
+pub struct AuthManager {
+    /// Keys and token settings, replaced on config reload
+    settings: StdRwLock<AuthSettings>,
     /// JWT validation configuration
     validation: Validation,
-    /// Server configuration
-    config: Arc<ServerConfig>,
     /// Rate limiting state per client
     rate_limits: Arc<RwLock<HashMap<String, RateLimitState>>>,
     /// Rate limiting configuration
-    rate_limit_config: RateLimitConfig,
+    rate_limit_config: StdRwLock<RateLimitConfig>,
 }
 
 impl AuthManager {
     /// Create new authentication manager
     pub fn new(config: Arc<ServerConfig>) -> Result<Self> {
-        let jwt_secret = config.auth.jwt_secret.as_bytes();
-
-        let encoding_key = EncodingKey::from_secret(jwt_secret);
-        let decoding_key = DecodingKey::from_secret(jwt_secret);
-
         let mut validation = Validation::new(Algorithm::HS256);
         validation.set_issuer(&["fortitude-mcp-server"]);
         validation.validate_exp = true;
 
         Ok(Self {
-            encoding_key,
-            decoding_key,
+            settings: StdRwLock::new(AuthSettings::from_config(&config)),
             validation,
-            config: config.clone(),
             rate_limits: Arc::new(RwLock::new(HashMap::new())),
-            rate_limit_config: RateLimitConfig {
+            rate_limit_config: StdRwLock::new(RateLimitConfig {
                 max_requests_per_minute: config.auth.rate_limit.max_requests_per_minute,
                 window_seconds: config.auth.rate_limit.window_seconds,
-            },
+            }),
         })
     }
 
+    /// Apply the auth section of a reloaded configuration
+    ///
+    /// Tokens signed with a replaced secret stop verifying immediately;
+    /// per-client request counts are kept.
+    pub fn reload(&self, config: &ServerConfig) {
+        *self.settings.write().unwrap() = AuthSettings::from_config(config);
+        *self.rate_limit_config.write().unwrap() = RateLimitConfig {
+            max_requests_per_minute: config.auth.rate_limit.max_requests_per_minute,
+            window_seconds: config.auth.rate_limit.window_seconds,
+        };
+        info!("Authentication settings reloaded");
+    }
+
+    /// Current rate limit configuration
+    pub fn rate_limit_config(&self) -> RateLimitConfig {
+        self.rate_limit_config.read().unwrap().clone()
+    }
+
     /// Generate JWT token for a user with given permissions
     #[instrument(skip(self, permissions))]
     pub async fn generate_token(
@@ -231,8 +264,9 @@ impl AuthManager {
         user_id: &str,
         permissions: Vec<Permission>,
     ) -> Result<String> {
+        let settings = self.settings.read().unwrap();
         let now = Utc::now();
-        let exp = now + Duration::hours(self.config.auth.token_expiration_hours as i64);
+        let exp = now + Duration::hours(settings.token_expiration_hours as i64);
 
         let claims = Claims {
             sub: user_id.to_string(),
@@ -242,8 +276,9 @@ impl AuthManager {
             iss: "fortitude-mcp-server".to_string(),
         };
 
-        let token = encode(&Header::default(), &claims, &self.encoding_key)
+        let token = encode(&Header::default(), &claims, &settings.encoding_key)
             .map_err(|e| anyhow!("Token generation failed: {e}"))?;
+        drop(settings);
 
         info!("Generated JWT token for user: {}", user_id);
         debug!("Token permissions: {:?}", claims.permissions);
@@ -254,8 +289,12 @@ impl AuthManager {
     /// Verify and decode JWT token
     #[instrument(skip(self, token))]
     pub async fn verify_token(&self, token: &str) -> Result<Claims> {
-        let token_data = decode::<Claims>(token, &self.decoding_key, &self.validation)
-            .map_err(|e| anyhow!("Token validation failed: {e}"))?;
+        let token_data = decode::<Claims>(
+            token,
+            &self.settings.read().unwrap().decoding_key,
+            &self.validation,
+        )
+        .map_err(|e| anyhow!("Token validation failed: {e}"))?;
 
         let claims = token_data.claims;
 
@@ -301,6 +340,7 @@ impl AuthManager {
     /// Check rate limit for a client
     #[instrument(skip(self))]
     pub async fn check_rate_limit(&self, client_id: &str) -> Result<()> {
+        let limits = self.rate_limit_config();
         let mut rate_limits = self.rate_limits.write().await;
         let now = Instant::now();
 
@@ -313,15 +353,13 @@ impl AuthManager {
                 });
 
         // Check if we need to reset the window
-        if now.duration_since(rate_limit.window_start).as_secs()
-            >= self.rate_limit_config.window_seconds
-        {
+        if now.duration_since(rate_limit.window_start).as_secs() >= limits.window_seconds {
             rate_limit.request_count = 0;
             rate_limit.window_start = now;
         }
 
         // Check if limit exceeded
-        if rate_limit.request_count >= self.rate_limit_config.max_requests_per_minute {
+        if rate_limit.request_count >= limits.max_requests_per_minute {
             warn!("Rate limit exceeded for client: {}", client_id);
             return Err(anyhow!("Rate limit exceeded"));
         }
@@ -331,7 +369,7 @@ impl AuthManager {
 
         debug!(
             "Rate limit check passed for client: {} ({}/{})",
-            client_id, rate_limit.request_count, self.rate_limit_config.max_requests_per_minute
+            client_id, rate_limit.request_count, limits.max_requests_per_minute
         );
 
         Ok(())
@@ -340,35 +378,34 @@ impl AuthManager {
     /// Get remaining requests for a client
     #[instrument(skip(self))]
     pub async fn get_remaining_requests(&self, client_id: &str) -> u32 {
+        let limits = self.rate_limit_config();
         let rate_limits = self.rate_limits.read().await;
 
         if let Some(rate_limit) = rate_limits.get(client_id) {
             let now = Instant::now();
 
             // If window has expired, return full limit
-            if now.duration_since(rate_limit.window_start).as_secs()
-                >= self.rate_limit_config.window_seconds
-            {
-                return self.rate_limit_config.max_requests_per_minute;
+            if now.duration_since(rate_limit.window_start).as_secs() >= limits.window_seconds {
+                return limits.max_requests_per_minute;
             }
 
-            self.rate_limit_config
+            limits
                 .max_requests_per_minute
                 .saturating_sub(rate_limit.request_count)
         } else {
-            self.rate_limit_config.max_requests_per_minute
+            limits.max_requests_per_minute
         }
     }
 
     /// Cleanup expired rate limit entries
     #[instrument(skip(self))]
     pub async fn cleanup_expired_rate_limits(&self) {
+        let window_seconds = self.rate_limit_config().window_seconds;
         let mut rate_limits = self.rate_limits.write().await;
         let now = Instant::now();
 
         rate_limits.retain(|_, rate_limit| {
-            now.duration_since(rate_limit.window_start).as_secs()
-                < self.rate_limit_config.window_seconds * 2
+            now.duration_since(rate_limit.window_start).as_secs() < window_seconds * 2
         });
 
         debug!("Cleaned up expired rate limit entries");
@@ -381,12 +418,12 @@ impl AuthManager {
 
     /// Validate authentication is enabled
     pub fn is_auth_enabled(&self) -> bool {
-        self.config.auth.enabled
+        self.settings.read().unwrap().enabled
     }
 
     /// Update rate limit configuration (for testing purposes)
     pub fn set_rate_limit_config(&mut self, config: RateLimitConfig) {
-        self.rate_limit_config = config;
+        *self.rate_limit_config.get_mut().unwrap() = config;
     }
 }
 
@@ -461,20 +498,17 @@ impl AuthMiddleware {
     /// Get rate limit headers for response
     pub async fn get_rate_limit_headers(&self, client_id: &str) -> HashMap<String, String> {
         let remaining = self.auth_manager.get_remaining_requests(client_id).await;
+        let limits = self.auth_manager.rate_limit_config();
 
         let mut headers = HashMap::new();
         headers.insert(
             "X-RateLimit-Limit".to_string(),
-            self.auth_manager
-                .rate_limit_config
-                .max_requests_per_minute
-                .to_string(),
+            limits.max_requests_per_minute.to_string(),
         );
         headers.insert("X-RateLimit-Remaining".to_string(), remaining.to_string());
         headers.insert(
             "X-RateLimit-Reset".to_string(),
-            (Utc::now().timestamp() + self.auth_manager.rate_limit_config.window_seconds as i64)
-                .to_string(),
+            (Utc::now().timestamp() + limits.window_seconds as i64).to_string(),
         );
 
         headers
@@ -621,7 +655,11 @@ mod tests {
     async fn test_rate_limiting() {
         let config = create_test_config();
         let mut auth_manager = AuthManager::new(config).unwrap();
-        auth_manager.rate_limit_config.max_requests_per_minute = 2;
+        auth_manager
+            .rate_limit_config
+            .get_mut()
+            .unwrap()
+            .max_requests_per_minute = 2;
 
         let client_id = "test_client";
 
@@ -641,8 +679,16 @@ mod tests {
     async fn test_rate_limit_window_reset() {
         let config = create_test_config();
         let mut auth_manager = AuthManager::new(config).unwrap();
-        auth_manager.rate_limit_config.max_requests_per_minute = 1;
-        auth_manager.rate_limit_config.window_seconds = 1; // Very short window for testing
+        auth_manager
+            .rate_limit_config
+            .get_mut()
+            .unwrap()
+            .max_requests_per_minute = 1;
+        auth_manager
+            .rate_limit_config
+            .get_mut()
+            .unwrap()
+            .window_seconds = 1; // Very short window for testing
 
         let client_id = "test_client";
 
@@ -806,7 +852,7 @@ mod tests {
         let token = encode(
             &Header::default(),
             &expired_claims,
-            &auth_manager.encoding_key,
+            &auth_manager.settings.read().unwrap().encoding_key,
         )
         .unwrap();
 
@@ -891,7 +937,11 @@ mod tests {
     async fn test_concurrent_rate_limiting() {
         let config = create_test_config();
         let mut auth_manager = AuthManager::new(config).unwrap();
-        auth_manager.rate_limit_config.max_requests_per_minute = 10;
+        auth_manager
+            .rate_limit_config
+            .get_mut()
+            .unwrap()
+            .max_requests_per_minute = 10;
 
         let auth_manager = Arc::new(auth_manager);
 
@@ -918,7 +968,11 @@ mod tests {
     async fn test_rate_limit_per_client_isolation() {
         let config = create_test_config();
         let mut auth_manager = AuthManager::new(config).unwrap();
-        auth_manager.rate_limit_config.max_requests_per_minute = 2;
+        auth_manager
+            .rate_limit_config
+            .get_mut()
+            .unwrap()
+            .max_requests_per_minute = 2;
 
         // Exhaust limit for client1
         assert!(auth_manager.check_rate_limit("client1").await.is_ok());
@@ -969,7 +1023,7 @@ mod tests {
         let wrong_issuer_token = encode(
             &Header::default(),
             &wrong_issuer_claims,
-            &auth_manager.encoding_key,
+            &auth_manager.settings.read().unwrap().encoding_key,
         )
         .unwrap();
 
@@ -1004,7 +1058,11 @@ mod tests {
     async fn test_rate_limit_remaining_requests() {
         let config = create_test_config();
         let mut auth_manager = AuthManager::new(config).unwrap();
-        auth_manager.rate_limit_config.max_requests_per_minute = 5;
+        auth_manager
+            .rate_limit_config
+            .get_mut()
+            .unwrap()
+            .max_requests_per_minute = 5;
 
         let client_id = "test_client";
 
@@ -1110,8 +1168,16 @@ mod tests {
     async fn test_auth_middleware_rate_limit_headers() {
         let config = create_test_config();
         let mut auth_manager = AuthManager::new(config).unwrap();
-        auth_manager.rate_limit_config.max_requests_per_minute = 10;
-        auth_manager.rate_limit_config.window_seconds = 60;
+        auth_manager
+            .rate_limit_config
+            .get_mut()
+            .unwrap()
+            .max_requests_per_minute = 10;
+        auth_manager
+            .rate_limit_config
+            .get_mut()
+            .unwrap()
+            .window_seconds = 60;
 
         let auth_manager = Arc::new(auth_manager);
         let middleware = AuthMiddleware::new(auth_manager.clone());
@@ -1301,7 +1367,7 @@ mod tests {
         let token = encode(
             &Header::default(),
             &exact_exp_claims,
-            &auth_manager.encoding_key,
+            &auth_manager.settings.read().unwrap().encoding_key,
         )
         .unwrap();
 
@@ -1321,7 +1387,7 @@ mod tests {
         let future_token = encode(
             &Header::default(),
             &future_exp_claims,
-            &auth_manager.encoding_key,
+            &auth_manager.settings.read().unwrap().encoding_key,
         )
         .unwrap();
 
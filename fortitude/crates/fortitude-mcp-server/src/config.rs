@@ -32,6 +32,10 @@ pub struct ServerConfig {
     /// Server host address
     pub host: String,
 
+    /// Transport clients connect over
+    #[serde(default)]
+    pub transport: McpTransport,
+
     /// Maximum number of concurrent connections
     #[validate(range(min = 1, max = 10000))]
     pub max_connections: u32,
@@ -58,6 +62,42 @@ pub struct ServerConfig {
     /// Integration settings for fortitude-core
     #[validate(nested)]
     pub integration: IntegrationConfig,
+
+    /// Resource change notification settings
+    #[serde(default)]
+    #[validate(nested)]
+    pub notifications: NotificationConfig,
+
+    /// Background mode and shutdown settings
+    #[serde(default)]
+    #[validate(nested)]
+    pub daemon: DaemonConfig,
+}
+
+/// Transport the MCP server speaks JSON-RPC over
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum McpTransport {
+    /// stdin/stdout of a process spawned by the client
+    #[default]
+    Stdio,
+    /// TCP connections to `host:port`, one MCP session per connection
+    Tcp,
+}
+
+impl std::str::FromStr for McpTransport {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "stdio" => Ok(McpTransport::Stdio),
+            "tcp" => Ok(McpTransport::Tcp),
+            other => Err(format!(
+                "unknown transport '{}', expected stdio or tcp",
+                other
+            )),
+        }
+    }
 }
 
 /// Authentication configuration
@@ -210,6 +250,44 @@ pub struct IntegrationConfig {
 
     /// Enable pattern tracking for MCP interactions
     pub enable_pattern_tracking: Option<bool>,
+
+    /// Serve canned, watermarked research answers instead of calling providers
+    #[serde(default)]
+    pub demo_mode: bool,
+
+    /// Tools hidden from clients and refused when called
+    #[serde(default)]
+    pub disabled_tools: Vec<String>,
+}
+
+/// Resource change notification configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(default)]
+pub struct NotificationConfig {
+    /// Enable resource subscriptions and change notifications
+    pub enabled: bool,
+
+    /// Debounce window for coalescing change events in milliseconds
+    #[validate(range(min = 10, max = 60000))]
+    pub debounce_ms: u64,
+
+    /// Watch the reference library and storage directories for changes
+    pub watch_filesystem: bool,
+}
+
+/// Background mode and graceful shutdown configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(default)]
+pub struct DaemonConfig {
+    /// PID file locking out a second TCP server and read by `stop`/`status`
+    pub pid_file: PathBuf,
+
+    /// Log file a `--daemon` server writes its output to
+    pub log_file: PathBuf,
+
+    /// Seconds in-flight tool calls get to finish after a shutdown signal
+    #[validate(range(max = 600))]
+    pub shutdown_drain_seconds: u64,
 }
 
 impl Default for ServerConfig {
@@ -217,6 +295,7 @@ impl Default for ServerConfig {
         Self {
             port: 8080,
             host: "127.0.0.1".to_string(),
+            transport: McpTransport::default(),
             max_connections: 1000,
             request_timeout: 30,
             auth: AuthConfig::default(),
@@ -224,6 +303,28 @@ impl Default for ServerConfig {
             performance: PerformanceConfig::default(),
             security: SecurityConfig::default(),
             integration: IntegrationConfig::default(),
+            notifications: NotificationConfig::default(),
+            daemon: DaemonConfig::default(),
+        }
+    }
+}
+
+impl Default for DaemonConfig {
+    fn default() -> Self {
+        Self {
+            pid_file: PathBuf::from(".fortitude/mcp-server.pid"),
+            log_file: PathBuf::from(".fortitude/mcp-server.log"),
+            shutdown_drain_seconds: 30,
+        }
+    }
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            debounce_ms: 500,
+            watch_filesystem: true,
         }
     }
 }
@@ -310,6 +411,8 @@ impl Default for IntegrationConfig {
             enable_research_caching: true,
             research_cache_ttl: 3600,
             enable_pattern_tracking: Some(true),
+            demo_mode: false,
+            disabled_tools: Vec::new(),
         }
     }
 }
@@ -335,6 +438,12 @@ impl ServerConfig {
             config.host = host;
         }
 
+        if let Ok(transport) = std::env::var("MCP_SERVER_TRANSPORT") {
+            config.transport = transport
+                .parse()
+                .map_err(|e| anyhow!("Invalid MCP_SERVER_TRANSPORT: {}", e))?;
+        }
+
         if let Ok(max_conn) = std::env::var("MCP_MAX_CONNECTIONS") {
             config.max_connections = max_conn
                 .parse()
@@ -550,6 +659,54 @@ impl ServerConfig {
                 })?);
         }
 
+        if let Ok(demo_mode) = std::env::var("MCP_INTEGRATION_DEMO_MODE") {
+            config.integration.demo_mode = demo_mode
+                .parse()
+                .map_err(|e| anyhow!("Invalid MCP_INTEGRATION_DEMO_MODE: {}", e))?;
+        }
+
+        if let Ok(disabled_tools) = std::env::var("MCP_INTEGRATION_DISABLED_TOOLS") {
+            config.integration.disabled_tools = disabled_tools
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+
+        // Notification configuration
+        if let Ok(enabled) = std::env::var("MCP_NOTIFICATIONS_ENABLED") {
+            config.notifications.enabled = enabled
+                .parse()
+                .map_err(|e| anyhow!("Invalid MCP_NOTIFICATIONS_ENABLED: {}", e))?;
+        }
+
+        if let Ok(debounce_ms) = std::env::var("MCP_NOTIFICATIONS_DEBOUNCE_MS") {
+            config.notifications.debounce_ms = debounce_ms
+                .parse()
+                .map_err(|e| anyhow!("Invalid MCP_NOTIFICATIONS_DEBOUNCE_MS: {}", e))?;
+        }
+
+        if let Ok(watch) = std::env::var("MCP_NOTIFICATIONS_WATCH_FILESYSTEM") {
+            config.notifications.watch_filesystem = watch
+                .parse()
+                .map_err(|e| anyhow!("Invalid MCP_NOTIFICATIONS_WATCH_FILESYSTEM: {}", e))?;
+        }
+
+        // Daemon configuration
+        if let Ok(pid_file) = std::env::var("MCP_DAEMON_PID_FILE") {
+            config.daemon.pid_file = PathBuf::from(pid_file);
+        }
+
+        if let Ok(log_file) = std::env::var("MCP_DAEMON_LOG_FILE") {
+            config.daemon.log_file = PathBuf::from(log_file);
+        }
+
+        if let Ok(drain) = std::env::var("MCP_DAEMON_SHUTDOWN_DRAIN_SECONDS") {
+            config.daemon.shutdown_drain_seconds = drain
+                .parse()
+                .map_err(|e| anyhow!("Invalid MCP_DAEMON_SHUTDOWN_DRAIN_SECONDS: {}", e))?;
+        }
+
         // Logging configuration extensions
         if let Ok(structured) = std::env::var("MCP_LOG_STRUCTURED") {
             config.logging.structured = structured
@@ -650,6 +807,18 @@ impl ServerConfig {
         Ok(())
     }
 
+    /// Check that the server can run under a service manager
+    ///
+    /// A Windows service has no console, so it must be reachable over TCP.
+    pub fn validate_for_service(&self) -> Result<()> {
+        if self.transport == McpTransport::Stdio {
+            return Err(anyhow!(
+                "Service mode requires the TCP transport (--transport tcp or MCP_SERVER_TRANSPORT=tcp)"
+            ));
+        }
+        Ok(())
+    }
+
     /// Save configuration to file
     pub async fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
         let path = path.as_ref();
@@ -714,6 +883,9 @@ impl ServerConfig {
         if other.host != "127.0.0.1" {
             self.host = other.host;
         }
+        if other.transport != McpTransport::Stdio {
+            self.transport = other.transport;
+        }
         if other.max_connections != 1000 {
             self.max_connections = other.max_connections;
         }
@@ -735,6 +907,12 @@ impl ServerConfig {
 
         // Merge integration configuration
         self.integration.merge_with(other.integration);
+
+        // Merge notification configuration
+        self.notifications.merge_with(other.notifications);
+
+        // Merge daemon configuration
+        self.daemon.merge_with(other.daemon);
     }
 
     /// Get environment variable documentation
@@ -745,6 +923,10 @@ impl ServerConfig {
                 "Server host address (default: 127.0.0.1)",
             ),
             ("MCP_SERVER_PORT", "Server port (default: 8080)"),
+            (
+                "MCP_SERVER_TRANSPORT",
+                "Client transport, stdio or tcp (default: stdio)",
+            ),
             (
                 "MCP_MAX_CONNECTIONS",
                 "Maximum concurrent connections (default: 1000)",
@@ -881,6 +1063,38 @@ impl ServerConfig {
                 "MCP_INTEGRATION_ENABLE_PATTERN_TRACKING",
                 "Enable pattern tracking for MCP interactions (default: true)",
             ),
+            (
+                "MCP_INTEGRATION_DEMO_MODE",
+                "Serve canned demo answers without provider credentials (default: false)",
+            ),
+            (
+                "MCP_INTEGRATION_DISABLED_TOOLS",
+                "Tools hidden from clients and refused when called (comma-separated, default: none)",
+            ),
+            (
+                "MCP_NOTIFICATIONS_ENABLED",
+                "Enable resource subscriptions and change notifications (default: true)",
+            ),
+            (
+                "MCP_NOTIFICATIONS_DEBOUNCE_MS",
+                "Debounce window for resource change events in ms (default: 500)",
+            ),
+            (
+                "MCP_NOTIFICATIONS_WATCH_FILESYSTEM",
+                "Watch reference library and storage directories for changes (default: true)",
+            ),
+            (
+                "MCP_DAEMON_PID_FILE",
+                "PID file of a running TCP server (default: .fortitude/mcp-server.pid)",
+            ),
+            (
+                "MCP_DAEMON_LOG_FILE",
+                "Log file of a --daemon server (default: .fortitude/mcp-server.log)",
+            ),
+            (
+                "MCP_DAEMON_SHUTDOWN_DRAIN_SECONDS",
+                "Seconds in-flight tool calls get to finish on shutdown (default: 30)",
+            ),
         ]
     }
 }
@@ -1059,6 +1273,41 @@ impl IntegrationConfig {
         if other.enable_pattern_tracking.is_some() && other.enable_pattern_tracking != Some(true) {
             self.enable_pattern_tracking = other.enable_pattern_tracking;
         }
+        if other.demo_mode {
+            self.demo_mode = other.demo_mode;
+        }
+        if !other.disabled_tools.is_empty() {
+            self.disabled_tools = other.disabled_tools;
+        }
+    }
+}
+
+impl NotificationConfig {
+    pub fn merge_with(&mut self, other: Self) {
+        if !other.enabled {
+            self.enabled = other.enabled;
+        }
+        if other.debounce_ms != 500 {
+            self.debounce_ms = other.debounce_ms;
+        }
+        if !other.watch_filesystem {
+            self.watch_filesystem = other.watch_filesystem;
+        }
+    }
+}
+
+impl DaemonConfig {
+    pub fn merge_with(&mut self, other: Self) {
+        let defaults = Self::default();
+        if other.pid_file != defaults.pid_file {
+            self.pid_file = other.pid_file;
+        }
+        if other.log_file != defaults.log_file {
+            self.log_file = other.log_file;
+        }
+        if other.shutdown_drain_seconds != defaults.shutdown_drain_seconds {
+            self.shutdown_drain_seconds = other.shutdown_drain_seconds;
+        }
     }
 }
 
@@ -1345,6 +1594,21 @@ mod tests {
         assert_eq!(base_config.security.max_request_size, 2097152);
     }
 
+    #[test]
+    fn test_transport_parsing_and_service_mode() {
+        assert_eq!("tcp".parse::<McpTransport>().unwrap(), McpTransport::Tcp);
+        assert_eq!(
+            "STDIO".parse::<McpTransport>().unwrap(),
+            McpTransport::Stdio
+        );
+        assert!("http".parse::<McpTransport>().is_err());
+
+        let mut config = ServerConfig::default();
+        assert!(config.validate_for_service().is_err());
+        config.transport = McpTransport::Tcp;
+        assert!(config.validate_for_service().is_ok());
+    }
+
     #[test]
     fn test_env_var_documentation() {
         let docs = ServerConfig::get_env_var_documentation();
@@ -0,0 +1,447 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Background mode, PID file lock and stop/status support for the MCP server
+// A TCP server records its PID, address and start time in a PID file that also
+// locks out a second server. `start --daemon` re-executes the binary detached
+// from the terminal with output appended to a log file; `stop` signals the
+// recorded process and waits for it to drain, and `status` reports it. A PID
+// file left by a crashed server is treated as no server running.
+
+use crate::config::{DaemonConfig, McpTransport};
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+/// Set in the environment of the detached process started by `start --daemon`
+pub const DAEMON_CHILD_ENV: &str = "FORTITUDE_MCP_DAEMON_CHILD";
+
+/// Time `start --daemon` waits for the detached server to write its PID file
+const DAEMON_START_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Extra time `stop` allows beyond the drain window before giving up
+const STOP_GRACE: Duration = Duration::from_secs(5);
+
+/// Running server as recorded in the PID file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DaemonRecord {
+    pub pid: u32,
+    pub transport: McpTransport,
+    /// Listening address for the TCP transport
+    pub address: Option<String>,
+    pub started_at: DateTime<Utc>,
+    /// Log file when started with `--daemon`
+    pub log_file: Option<PathBuf>,
+}
+
+impl DaemonRecord {
+    /// Record for the current process
+    pub fn current(transport: McpTransport, address: Option<String>) -> Self {
+        Self {
+            pid: std::process::id(),
+            transport,
+            address,
+            started_at: Utc::now(),
+            log_file: std::env::var_os(DAEMON_CHILD_ENV).map(PathBuf::from),
+        }
+    }
+}
+
+/// What the PID file says about the server
+#[derive(Debug, Clone, PartialEq)]
+pub enum DaemonStatus {
+    Running(DaemonRecord),
+    /// The recorded process is gone; the file was left by a crash
+    Stale(DaemonRecord),
+    NotRunning,
+}
+
+/// Read the PID file at `pid_file`
+pub fn read_status(pid_file: &Path) -> Result<DaemonStatus> {
+    let content = match std::fs::read_to_string(pid_file) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(DaemonStatus::NotRunning),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", pid_file.display())),
+    };
+    let record: DaemonRecord = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid PID file {}", pid_file.display()))?;
+    if process_alive(record.pid) {
+        Ok(DaemonStatus::Running(record))
+    } else {
+        Ok(DaemonStatus::Stale(record))
+    }
+}
+
+/// PID file lock held while the server runs; the file is removed on drop
+#[derive(Debug)]
+pub struct PidFileGuard {
+    path: PathBuf,
+    pid: u32,
+}
+
+impl PidFileGuard {
+    /// Write `record` to `path`, failing while another live server holds it
+    pub fn acquire(path: impl Into<PathBuf>, record: &DaemonRecord) -> Result<Self> {
+        let path = path.into();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let content = serde_json::to_string_pretty(record)?;
+
+        // One retry: a stale file is removed and the exclusive create repeated
+        for _ in 0..2 {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => {
+                    file.write_all(content.as_bytes())
+                        .with_context(|| format!("Failed to write {}", path.display()))?;
+                    debug!("Wrote PID file {}", path.display());
+                    return Ok(Self {
+                        path,
+                        pid: record.pid,
+                    });
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => match read_status(&path) {
+                    Ok(DaemonStatus::Running(running)) if running.pid != record.pid => {
+                        return Err(anyhow!(
+                            "MCP server is already running (pid {}, PID file {})",
+                            running.pid,
+                            path.display()
+                        ));
+                    }
+                    Ok(_) | Err(_) => {
+                        warn!("Replacing stale PID file {}", path.display());
+                        std::fs::remove_file(&path).ok();
+                    }
+                },
+                Err(e) => {
+                    return Err(e).with_context(|| format!("Failed to create {}", path.display()))
+                }
+            }
+        }
+        Err(anyhow!("Could not lock PID file {}", path.display()))
+    }
+}
+
+impl Drop for PidFileGuard {
+    fn drop(&mut self) {
+        // Leave the file alone if another server has taken it over since
+        if let Ok(DaemonStatus::Running(record)) | Ok(DaemonStatus::Stale(record)) =
+            read_status(&self.path)
+        {
+            if record.pid != self.pid {
+                return;
+            }
+        }
+        if let Err(e) = std::fs::remove_file(&self.path) {
+            if e.kind() != ErrorKind::NotFound {
+                warn!("Failed to remove PID file {}: {}", self.path.display(), e);
+            }
+        }
+    }
+}
+
+/// Re-execute this binary detached from the terminal and wait until it runs
+///
+/// The `--daemon` flag is dropped from the arguments; output is appended to
+/// the configured log file. Returns the PID of the detached server.
+pub fn spawn_background(config: &DaemonConfig) -> Result<u32> {
+    let exe = std::env::current_exe().context("Failed to locate the server binary")?;
+    let args: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|arg| arg != "--daemon")
+        .collect();
+
+    if let Some(parent) = config
+        .log_file
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let log = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&config.log_file)
+        .with_context(|| format!("Failed to open {}", config.log_file.display()))?;
+
+    let mut command = Command::new(exe);
+    command
+        .args(&args)
+        .env(DAEMON_CHILD_ENV, &config.log_file)
+        .stdin(Stdio::null())
+        .stdout(log.try_clone()?)
+        .stderr(log);
+    detach(&mut command);
+    let mut child = command
+        .spawn()
+        .context("Failed to start the background server")?;
+    let pid = child.id();
+
+    let deadline = Instant::now() + DAEMON_START_TIMEOUT;
+    while Instant::now() < deadline {
+        if let Some(exit) = child.try_wait()? {
+            return Err(anyhow!(
+                "Background server exited during startup ({exit}); see {}",
+                config.log_file.display()
+            ));
+        }
+        if let Ok(DaemonStatus::Running(record)) = read_status(&config.pid_file) {
+            if record.pid == pid {
+                info!("MCP server running in the background (pid {})", pid);
+                return Ok(pid);
+            }
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    Err(anyhow!(
+        "Background server (pid {pid}) did not report ready within {}s; see {}",
+        DAEMON_START_TIMEOUT.as_secs(),
+        config.log_file.display()
+    ))
+}
+
+/// Result of a stop request
+#[derive(Debug, Clone, PartialEq)]
+pub enum StopOutcome {
+    Stopped(DaemonRecord),
+    /// Only a stale PID file was found, and removed
+    StaleRemoved(DaemonRecord),
+    NotRunning,
+}
+
+/// Stop the server recorded in the PID file
+///
+/// Without `force` the server is asked to shut down and given the drain
+/// window plus a short grace period; with `force` it is killed immediately.
+pub async fn stop(config: &DaemonConfig, force: bool) -> Result<StopOutcome> {
+    let record = match read_status(&config.pid_file)? {
+        DaemonStatus::NotRunning => return Ok(StopOutcome::NotRunning),
+        DaemonStatus::Stale(record) => {
+            std::fs::remove_file(&config.pid_file).ok();
+            return Ok(StopOutcome::StaleRemoved(record));
+        }
+        DaemonStatus::Running(record) => record,
+    };
+
+    send_stop_signal(record.pid, force)
+        .with_context(|| format!("Failed to signal MCP server (pid {})", record.pid))?;
+
+    let timeout = if force {
+        STOP_GRACE
+    } else {
+        Duration::from_secs(config.shutdown_drain_seconds) + STOP_GRACE
+    };
+    let deadline = Instant::now() + timeout;
+    while Instant::now() < deadline {
+        if !process_alive(record.pid) {
+            // A killed server cannot clean up after itself
+            if force {
+                std::fs::remove_file(&config.pid_file).ok();
+            }
+            return Ok(StopOutcome::Stopped(record));
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+    Err(anyhow!(
+        "MCP server (pid {}) is still running after {}s; use `stop --force` to kill it",
+        record.pid,
+        timeout.as_secs()
+    ))
+}
+
+/// Ask the server recorded in the PID file to reload its config file
+///
+/// Sends SIGHUP; the server logs which changes it applied.
+pub fn request_reload(config: &DaemonConfig) -> Result<DaemonRecord> {
+    let record = match read_status(&config.pid_file)? {
+        DaemonStatus::Running(record) => record,
+        DaemonStatus::Stale(record) => {
+            return Err(anyhow!("MCP server (pid {}) is not running", record.pid))
+        }
+        DaemonStatus::NotRunning => return Err(anyhow!("MCP server is not running")),
+    };
+    send_reload_signal(record.pid)
+        .with_context(|| format!("Failed to signal MCP server (pid {})", record.pid))?;
+    Ok(record)
+}
+
+/// Whether a process with `pid` exists
+#[cfg(unix)]
+pub fn process_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // Signal 0 only checks existence; EPERM means it exists under another user
+    let result = unsafe { libc::kill(pid, 0) };
+    result == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+/// Whether a process with `pid` exists
+#[cfg(windows)]
+pub fn process_alive(pid: u32) -> bool {
+    Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {pid}"), "/NH"])
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).contains(&pid.to_string()))
+        .unwrap_or(false)
+}
+
+#[cfg(unix)]
+fn send_stop_signal(pid: u32, force: bool) -> std::io::Result<()> {
+    let pid = libc::pid_t::try_from(pid)
+        .map_err(|_| std::io::Error::new(ErrorKind::InvalidInput, "PID out of range"))?;
+    let signal = if force { libc::SIGKILL } else { libc::SIGTERM };
+    if unsafe { libc::kill(pid, signal) } == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+#[cfg(windows)]
+fn send_stop_signal(pid: u32, force: bool) -> std::io::Result<()> {
+    let pid = pid.to_string();
+    let mut args = vec!["/PID", pid.as_str()];
+    if force {
+        args.push("/F");
+    }
+    let status = Command::new("taskkill").args(&args).status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(std::io::Error::other(format!(
+            "taskkill exited with {status}"
+        )))
+    }
+}
+
+#[cfg(unix)]
+fn send_reload_signal(pid: u32) -> std::io::Result<()> {
+    let pid = libc::pid_t::try_from(pid)
+        .map_err(|_| std::io::Error::new(ErrorKind::InvalidInput, "PID out of range"))?;
+    if unsafe { libc::kill(pid, libc::SIGHUP) } == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+#[cfg(windows)]
+fn send_reload_signal(_pid: u32) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        ErrorKind::Unsupported,
+        "reload signals are not supported on Windows; the server reloads when its config file changes",
+    ))
+}
+
+/// Start the child in its own process group so terminal signals do not reach it
+#[cfg(unix)]
+fn detach(command: &mut Command) {
+    use std::os::unix::process::CommandExt;
+    command.process_group(0);
+}
+
+#[cfg(windows)]
+fn detach(command: &mut Command) {
+    use std::os::windows::process::CommandExt;
+    const DETACHED_PROCESS: u32 = 0x0000_0008;
+    const CREATE_NEW_PROCESS_GROUP: u32 = 0x0000_0200;
+    command.creation_flags(DETACHED_PROCESS | CREATE_NEW_PROCESS_GROUP);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(pid: u32) -> DaemonRecord {
+        DaemonRecord {
+            pid,
+            transport: McpTransport::Tcp,
+            address: Some("127.0.0.1:8080".to_string()),
+            started_at: Utc::now(),
+            log_file: None,
+        }
+    }
+
+    /// PID of a process that has already exited
+    fn exited_pid() -> u32 {
+        let mut child = Command::new(std::env::current_exe().unwrap())
+            .arg("--list")
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap();
+        let pid = child.id();
+        child.wait().unwrap();
+        pid
+    }
+
+    #[test]
+    fn test_pid_file_locks_out_second_server() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("run").join("mcp-server.pid");
+
+        let guard = PidFileGuard::acquire(&path, &record(std::process::id())).unwrap();
+        assert!(matches!(
+            read_status(&path).unwrap(),
+            DaemonStatus::Running(running) if running.pid == std::process::id()
+        ));
+
+        // Another server cannot take the lock while this process is alive
+        assert!(PidFileGuard::acquire(&path, &record(u32::MAX)).is_err());
+
+        drop(guard);
+        assert_eq!(read_status(&path).unwrap(), DaemonStatus::NotRunning);
+    }
+
+    #[test]
+    fn test_stale_pid_file_is_replaced() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mcp-server.pid");
+        let stale = record(exited_pid());
+        std::fs::write(&path, serde_json::to_string(&stale).unwrap()).unwrap();
+        assert_eq!(read_status(&path).unwrap(), DaemonStatus::Stale(stale));
+
+        let _guard = PidFileGuard::acquire(&path, &record(std::process::id())).unwrap();
+        assert!(matches!(
+            read_status(&path).unwrap(),
+            DaemonStatus::Running(running) if running.pid == std::process::id()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stop_without_server() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = DaemonConfig {
+            pid_file: dir.path().join("mcp-server.pid"),
+            ..Default::default()
+        };
+        assert_eq!(stop(&config, false).await.unwrap(), StopOutcome::NotRunning);
+
+        let stale = record(exited_pid());
+        std::fs::write(&config.pid_file, serde_json::to_string(&stale).unwrap()).unwrap();
+        assert_eq!(
+            stop(&config, false).await.unwrap(),
+            StopOutcome::StaleRemoved(stale)
+        );
+        assert!(!config.pid_file.exists());
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Deterministic mock research engine backing the MCP server demo mode
+// Serves canned answers keyed by topic patterns with simulated latency so MCP
+// integrations can be developed without provider credentials
+use async_trait::async_trait;
+use chrono::Utc;
+use fortitude_core::{ResearchEngine, ResearchEngineError, VectorDocument};
+use fortitude_types::{ClassifiedRequest, Detail, Evidence, ResearchMetadata, ResearchResult};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Prefix added to every demo answer
+pub const DEMO_WATERMARK: &str =
+    "[DEMO OUTPUT] Canned response from the Fortitude MCP demo mode, not real research.";
+
+/// Source name reported for demo evidence and metadata
+pub const DEMO_SOURCE: &str = "fortitude-demo";
+
+/// Default simulated latency of a demo research request
+pub const DEFAULT_DEMO_LATENCY: Duration = Duration::from_millis(250);
+
+/// Canned answer served for queries matching any of its patterns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DemoTopic {
+    pub name: &'static str,
+    /// Lowercase substrings matched against the query
+    pub patterns: &'static [&'static str],
+    pub answer: &'static str,
+    pub example: &'static str,
+}
+
+/// Topics checked in order; the first match wins
+pub const DEMO_TOPICS: &[DemoTopic] = &[
+    DemoTopic {
+        name: "async",
+        patterns: &["async", "await", "tokio", "future", "concurren"],
+        answer: "Use tokio as the async runtime, spawn independent work with tokio::spawn and \
+                 bound fan-out with a semaphore or JoinSet.",
+        example: "let mut set = tokio::task::JoinSet::new();\nset.spawn(async { fetch().await });",
+    },
+    DemoTopic {
+        name: "error_handling",
+        patterns: &["error", "result", "panic", "thiserror", "anyhow"],
+        answer: "Define library errors with thiserror, use anyhow at application boundaries and \
+                 propagate with the ? operator instead of unwrapping.",
+        example: "#[derive(Debug, thiserror::Error)]\nenum AppError {\n    #[error(\"io: {0}\")]\n    Io(#[from] std::io::Error),\n}",
+    },
+    DemoTopic {
+        name: "testing",
+        patterns: &["test", "mock", "fixture", "assert"],
+        answer: "Keep unit tests in a #[cfg(test)] module next to the code, put integration tests \
+                 under tests/ and use #[tokio::test] for async code.",
+        example: "#[tokio::test]\nasync fn it_works() {\n    assert_eq!(add(2, 2), 4);\n}",
+    },
+    DemoTopic {
+        name: "serialization",
+        patterns: &["serde", "json", "serializ", "deserializ", "toml"],
+        answer: "Derive Serialize and Deserialize with serde and use #[serde(default)] for fields \
+                 added after the format was first published.",
+        example: "#[derive(serde::Serialize, serde::Deserialize)]\nstruct Config {\n    #[serde(default)]\n    retries: u32,\n}",
+    },
+    DemoTopic {
+        name: "performance",
+        patterns: &["performance", "optimi", "slow", "fast", "benchmark", "latency"],
+        answer: "Measure before optimizing: add criterion benchmarks, profile with a sampling \
+                 profiler and avoid needless clones and allocations in hot loops.",
+        example: "fn bench(c: &mut criterion::Criterion) {\n    c.bench_function(\"parse\", |b| b.iter(parse));\n}",
+    },
+];
+
+/// Answer served when no topic pattern matches
+pub const FALLBACK_TOPIC: DemoTopic = DemoTopic {
+    name: "general",
+    patterns: &[],
+    answer: "Demo mode only knows a handful of topics (async, error handling, testing, \
+             serialization, performance). Configure a research provider for real answers.",
+    example: "fortitude-mcp-server start",
+};
+
+/// Research engine returning canned, watermarked answers without calling any provider
+#[derive(Debug, Clone)]
+pub struct DemoResearchEngine {
+    latency: Duration,
+}
+
+impl Default for DemoResearchEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DemoResearchEngine {
+    pub fn new() -> Self {
+        Self {
+            latency: DEFAULT_DEMO_LATENCY,
+        }
+    }
+
+    /// Set the simulated latency of each research request
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Topic whose patterns match the query, or the fallback topic
+    pub fn topic_for(query: &str) -> &'static DemoTopic {
+        let query = query.to_lowercase();
+        DEMO_TOPICS
+            .iter()
+            .find(|topic| topic.patterns.iter().any(|p| query.contains(p)))
+            .unwrap_or(&FALLBACK_TOPIC)
+    }
+
+    async fn research(&self, request: &ClassifiedRequest) -> ResearchResult {
+        if !self.latency.is_zero() {
+            tokio::time::sleep(self.latency).await;
+        }
+
+        let topic = Self::topic_for(&request.original_query);
+        let mut tags = HashMap::new();
+        tags.insert("demo".to_string(), "true".to_string());
+        tags.insert("demo_topic".to_string(), topic.name.to_string());
+
+        ResearchResult::new(
+            request.clone(),
+            format!("{DEMO_WATERMARK}\n\n{}", topic.answer),
+            vec![Evidence {
+                source: DEMO_SOURCE.to_string(),
+                content: format!("Demo knowledge base entry for topic '{}'", topic.name),
+                relevance: 0.8,
+                evidence_type: "demo".to_string(),
+            }],
+            vec![Detail {
+                category: "code".to_string(),
+                content: topic.example.to_string(),
+                priority: "medium".to_string(),
+                prerequisites: vec![],
+            }],
+            ResearchMetadata {
+                completed_at: Utc::now(),
+                processing_time_ms: self.latency.as_millis() as u64,
+                sources_consulted: vec![DEMO_SOURCE.to_string()],
+                quality_score: 0.8,
+                cache_key: String::new(),
+                tags,
+            },
+        )
+    }
+}
+
+#[async_trait]
+impl ResearchEngine for DemoResearchEngine {
+    async fn generate_research(
+        &self,
+        request: &ClassifiedRequest,
+    ) -> Result<ResearchResult, ResearchEngineError> {
+        Ok(self.research(request).await)
+    }
+
+    async fn generate_research_with_context(
+        &self,
+        request: &ClassifiedRequest,
+    ) -> Result<ResearchResult, ResearchEngineError> {
+        Ok(self.research(request).await)
+    }
+
+    async fn discover_context(
+        &self,
+        _request: &ClassifiedRequest,
+    ) -> Result<Vec<VectorDocument>, ResearchEngineError> {
+        Ok(vec![])
+    }
+
+    async fn health_check(&self) -> Result<(), ResearchEngineError> {
+        Ok(())
+    }
+
+    fn estimate_processing_time(&self, _request: &ClassifiedRequest) -> Duration {
+        self.latency
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_types::{AudienceContext, DomainContext, ResearchType};
+
+    fn request(query: &str) -> ClassifiedRequest {
+        ClassifiedRequest::new(
+            query.to_string(),
+            ResearchType::Learning,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.9,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_topic_matching_is_deterministic() {
+        assert_eq!(
+            DemoResearchEngine::topic_for("How do I use Tokio tasks?").name,
+            "async"
+        );
+        assert_eq!(
+            DemoResearchEngine::topic_for("Best way to handle errors").name,
+            "error_handling"
+        );
+        assert_eq!(
+            DemoResearchEngine::topic_for("Explain ownership").name,
+            "general"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_demo_results_are_watermarked() {
+        let engine = DemoResearchEngine::new().with_latency(Duration::ZERO);
+        let result = engine
+            .generate_research(&request("serde json config"))
+            .await
+            .unwrap();
+
+        assert!(result.immediate_answer.starts_with(DEMO_WATERMARK));
+        assert_eq!(result.metadata.tags.get("demo").unwrap(), "true");
+        assert_eq!(
+            result.metadata.tags.get("demo_topic").unwrap(),
+            "serialization"
+        );
+        assert_eq!(result.metadata.sources_consulted, vec![DEMO_SOURCE]);
+        assert_eq!(result.supporting_evidence[0].evidence_type, "demo");
+    }
+}
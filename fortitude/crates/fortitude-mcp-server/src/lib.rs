@@ -18,16 +18,22 @@
 
 pub mod auth;
 pub mod config;
+pub mod daemon;
+pub mod demo;
 pub mod monitoring;
 pub mod pattern_tracking;
 pub mod proactive_tools;
 pub mod quality_tools;
+pub mod reload;
 pub mod resources;
 pub mod server;
+pub mod supervisor;
 pub mod tools;
 
 pub use auth::{AuthManager, AuthMiddleware, Claims, Permission, RateLimitConfig};
-pub use config::ServerConfig;
+pub use config::{DaemonConfig, McpTransport, ServerConfig};
+pub use daemon::{DaemonRecord, DaemonStatus, PidFileGuard, StopOutcome};
+pub use demo::{DemoResearchEngine, DEMO_WATERMARK};
 pub use monitoring::{
     McpMetrics, McpMonitoringService, McpOperationContext, McpPerformanceSummary,
 };
@@ -36,6 +42,7 @@ pub use pattern_tracking::{
 };
 pub use proactive_tools::ProactiveTools;
 pub use quality_tools::QualityTools;
+pub use reload::ReloadReport;
 pub use resources::ResourceProvider;
 pub use server::McpServer;
 pub use tools::FortitudeTools;
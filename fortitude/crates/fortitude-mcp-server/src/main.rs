@@ -14,10 +14,14 @@
 
 // ABOUTME: Main entry point for Fortitude MCP server
 // Provides command-line interface for starting the MCP server
-// Handles configuration loading and graceful shutdown
+// Handles configuration loading, background (daemon) mode and graceful shutdown
 
 use clap::{Parser, Subcommand};
-use fortitude_mcp_server::{McpServer, ServerConfig};
+use fortitude_mcp_server::daemon::{self, DAEMON_CHILD_ENV};
+use fortitude_mcp_server::server::shutdown_signal;
+use fortitude_mcp_server::{
+    DaemonRecord, DaemonStatus, McpServer, McpTransport, PidFileGuard, ServerConfig, StopOutcome,
+};
 use std::process::ExitCode;
 use tracing::{error, info, warn};
 use tracing_subscriber::EnvFilter;
@@ -53,18 +57,32 @@ enum Commands {
         #[arg(long)]
         host: Option<String>,
 
-        /// Run in daemon mode
+        /// Client transport, stdio or tcp (overrides config file)
+        #[arg(long)]
+        transport: Option<McpTransport>,
+
+        /// Run in the background, detached from the terminal (requires --transport tcp)
         #[arg(long)]
         daemon: bool,
+
+        /// Run under the Windows Service Control Manager (requires --transport tcp)
+        #[arg(long)]
+        windows_service: bool,
+
+        /// Serve canned, watermarked research answers without provider API keys
+        #[arg(long)]
+        demo: bool,
     },
-    /// Stop the MCP server
+    /// Stop the MCP server recorded in the PID file
     Stop {
-        /// Force stop (kill process)
+        /// Force stop (kill process without draining in-flight calls)
         #[arg(short, long)]
         force: bool,
     },
-    /// Show server status
+    /// Show whether the MCP server recorded in the PID file is running
     Status,
+    /// Make the running MCP server re-read its config file now
+    Reload,
     /// Validate configuration file
     ValidateConfig,
     /// Generate sample configuration file
@@ -102,13 +120,28 @@ async fn main() -> ExitCode {
     match args.command.as_ref().unwrap_or(&Commands::Start {
         port: None,
         host: None,
+        transport: None,
         daemon: false,
+        windows_service: false,
+        demo: false,
     }) {
-        Commands::Start { port, host, daemon } => {
-            start_server(&args, *port, host.clone(), *daemon).await
+        Commands::Start {
+            port,
+            host,
+            transport,
+            daemon,
+            windows_service,
+            demo,
+        } => {
+            if *windows_service {
+                run_windows_service(&args, *port, host.clone(), *transport).await
+            } else {
+                start_server(&args, *port, host.clone(), *transport, *daemon, *demo).await
+            }
         }
-        Commands::Stop { force } => stop_server(*force).await,
-        Commands::Status => show_status().await,
+        Commands::Stop { force } => stop_server(&args, *force).await,
+        Commands::Status => show_status(&args).await,
+        Commands::Reload => reload_server(&args).await,
         Commands::ValidateConfig => validate_config(&args).await,
         Commands::GenerateConfig { output, format } => {
             generate_config(output.clone(), format.clone()).await
@@ -122,10 +155,12 @@ async fn start_server(
     args: &Args,
     port: Option<u16>,
     host: Option<String>,
+    transport: Option<McpTransport>,
     daemon: bool,
+    demo: bool,
 ) -> ExitCode {
     // Load configuration
-    let config = match load_config(args, port, host).await {
+    let mut config = match load_config(args, port, host, transport).await {
         Ok(config) => config,
         Err(e) => {
             error!("Failed to load configuration: {}", e);
@@ -133,14 +168,58 @@ async fn start_server(
         }
     };
 
-    if daemon {
+    if demo {
+        config.integration.demo_mode = true;
+    }
+    if config.integration.demo_mode {
+        warn!("Running in demo mode: research answers are canned and marked as demo output");
+    }
+
+    if daemon && std::env::var_os(DAEMON_CHILD_ENV).is_none() {
+        if let Err(e) = config.validate_for_service() {
+            error!("Daemon mode: {}", e);
+            return ExitCode::FAILURE;
+        }
         info!("Starting MCP server in daemon mode...");
-        // TODO: Implement daemon mode
-        warn!("Daemon mode not yet implemented, running in foreground");
+        return match daemon::spawn_background(&config.daemon) {
+            Ok(pid) => {
+                println!(
+                    "MCP server started in the background (pid {}), logging to {}",
+                    pid,
+                    config.daemon.log_file.display()
+                );
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                error!("Failed to start daemon: {:#}", e);
+                ExitCode::FAILURE
+            }
+        };
     }
 
+    // Listen for shutdown signals before the PID file is written so a `stop`
+    // during startup still shuts down gracefully
+    let shutdown = tokio::spawn(shutdown_signal());
+
+    // A TCP server is shared, so only one may run per PID file
+    let _pid_file = if config.transport == McpTransport::Tcp {
+        let record = DaemonRecord::current(
+            McpTransport::Tcp,
+            Some(format!("{}:{}", config.host, config.port)),
+        );
+        match PidFileGuard::acquire(&config.daemon.pid_file, &record) {
+            Ok(guard) => Some(guard),
+            Err(e) => {
+                error!("{:#}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        None
+    };
+
     // Create and run server
-    let server = match McpServer::new(config).await {
+    let mut server = match McpServer::new(config).await {
         Ok(server) => server,
         Err(e) => {
             error!("Failed to create MCP server: {}", e);
@@ -148,9 +227,23 @@ async fn start_server(
         }
     };
 
+    // Apply edits to the config file without restarting and dropping sessions
+    if let Some(config_path) = find_config_file(args) {
+        server = match server.with_config_file(config_path).await {
+            Ok(server) => server,
+            Err(e) => {
+                error!("Failed to watch configuration file: {}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+    }
+
     info!("Starting Fortitude MCP server...");
 
-    if let Err(e) = server.run().await {
+    let shutdown = async {
+        let _ = shutdown.await;
+    };
+    if let Err(e) = server.run_until(shutdown).await {
         error!("Server error: {}", e);
         return ExitCode::FAILURE;
     }
@@ -159,31 +252,159 @@ async fn start_server(
     ExitCode::SUCCESS
 }
 
-async fn stop_server(force: bool) -> ExitCode {
-    // TODO: Implement server stopping logic
+#[cfg(windows)]
+async fn run_windows_service(
+    args: &Args,
+    port: Option<u16>,
+    host: Option<String>,
+    transport: Option<McpTransport>,
+) -> ExitCode {
+    let config = match load_config(args, port, host, transport).await {
+        Ok(config) => config,
+        Err(e) => {
+            error!("Failed to load configuration: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    info!("Starting Fortitude MCP server as a Windows service...");
+    match tokio::task::spawn_blocking(move || {
+        fortitude_mcp_server::supervisor::windows::run(config)
+    })
+    .await
+    {
+        Ok(Ok(())) => ExitCode::SUCCESS,
+        Ok(Err(e)) => {
+            error!("Windows service error: {:?}", e);
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            error!("Windows service dispatcher failed: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(not(windows))]
+async fn run_windows_service(
+    _args: &Args,
+    _port: Option<u16>,
+    _host: Option<String>,
+    _transport: Option<McpTransport>,
+) -> ExitCode {
+    error!("--windows-service is only supported on Windows");
+    ExitCode::FAILURE
+}
+
+async fn stop_server(args: &Args, force: bool) -> ExitCode {
+    let config = match load_config(args, None, None, None).await {
+        Ok(config) => config,
+        Err(e) => {
+            error!("Failed to load configuration: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
     if force {
         info!("Force stopping MCP server...");
-        // Find and kill the process
-        warn!("Force stop not yet implemented");
     } else {
         info!("Gracefully stopping MCP server...");
-        // Send shutdown signal
-        warn!("Graceful stop not yet implemented");
     }
-    ExitCode::SUCCESS
+
+    match daemon::stop(&config.daemon, force).await {
+        Ok(StopOutcome::Stopped(record)) => {
+            println!("Stopped MCP server (pid {})", record.pid);
+            ExitCode::SUCCESS
+        }
+        Ok(StopOutcome::StaleRemoved(record)) => {
+            println!(
+                "MCP server (pid {}) was not running; removed stale PID file {}",
+                record.pid,
+                config.daemon.pid_file.display()
+            );
+            ExitCode::SUCCESS
+        }
+        Ok(StopOutcome::NotRunning) => {
+            println!("MCP server is not running");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            error!("Failed to stop MCP server: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
 
-async fn show_status() -> ExitCode {
-    // TODO: Implement status checking logic
-    info!("Checking MCP server status...");
-    warn!("Status check not yet implemented");
-    ExitCode::SUCCESS
+async fn show_status(args: &Args) -> ExitCode {
+    let config = match load_config(args, None, None, None).await {
+        Ok(config) => config,
+        Err(e) => {
+            error!("Failed to load configuration: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match daemon::read_status(&config.daemon.pid_file) {
+        Ok(DaemonStatus::Running(record)) => {
+            println!("MCP server is running");
+            println!("  PID: {}", record.pid);
+            println!("  Transport: {:?}", record.transport);
+            if let Some(address) = &record.address {
+                println!("  Address: {}", address);
+            }
+            println!("  Started: {}", record.started_at.to_rfc3339());
+            if let Some(log_file) = &record.log_file {
+                println!("  Log file: {}", log_file.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Ok(DaemonStatus::Stale(record)) => {
+            println!(
+                "MCP server is not running (stale PID file {} for pid {})",
+                config.daemon.pid_file.display(),
+                record.pid
+            );
+            ExitCode::FAILURE
+        }
+        Ok(DaemonStatus::NotRunning) => {
+            println!("MCP server is not running");
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            error!("Failed to check MCP server status: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn reload_server(args: &Args) -> ExitCode {
+    let config = match load_config(args, None, None, None).await {
+        Ok(config) => config,
+        Err(e) => {
+            error!("Failed to load configuration: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match daemon::request_reload(&config.daemon) {
+        Ok(record) => {
+            println!(
+                "Asked MCP server (pid {}) to reload its configuration; see its log for the result",
+                record.pid
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            error!("Failed to reload MCP server: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
 
 async fn validate_config(args: &Args) -> ExitCode {
     info!("Validating configuration...");
 
-    let config = match load_config(args, None, None).await {
+    let config = match load_config(args, None, None, None).await {
         Ok(config) => config,
         Err(e) => {
             error!("Configuration validation failed: {}", e);
@@ -264,7 +485,7 @@ async fn show_config_help() -> ExitCode {
 async fn show_config(args: &Args) -> ExitCode {
     info!("Loading current configuration...");
 
-    let config = match load_config(args, None, None).await {
+    let config = match load_config(args, None, None, None).await {
         Ok(config) => config,
         Err(e) => {
             error!("Failed to load configuration: {}", e);
@@ -286,39 +507,34 @@ async fn show_config(args: &Args) -> ExitCode {
     }
 }
 
+/// Config file given with `--config`, else the first default location that exists
+fn find_config_file(args: &Args) -> Option<String> {
+    if let Some(config_path) = &args.config {
+        return Some(config_path.clone());
+    }
+
+    let default_paths = [
+        "fortitude-mcp-server.toml",
+        "fortitude-mcp-server.json",
+        "~/.config/fortitude/mcp-server.toml",
+        "~/.config/fortitude/mcp-server.json",
+    ];
+    default_paths
+        .iter()
+        .find(|path| std::path::Path::new(path).exists())
+        .map(|path| path.to_string())
+}
+
 async fn load_config(
     args: &Args,
     port: Option<u16>,
     host: Option<String>,
+    transport: Option<McpTransport>,
 ) -> anyhow::Result<ServerConfig> {
-    let mut config = if let Some(config_path) = &args.config {
-        ServerConfig::from_file_with_format(config_path).await?
-    } else {
-        // Try to load from default locations
-        let default_paths = [
-            "fortitude-mcp-server.toml",
-            "fortitude-mcp-server.json",
-            "~/.config/fortitude/mcp-server.toml",
-            "~/.config/fortitude/mcp-server.json",
-        ];
-
-        let mut loaded = false;
-        let mut config = ServerConfig::default();
-
-        for path in &default_paths {
-            if std::path::Path::new(path).exists() {
-                config = ServerConfig::from_file_with_format(path).await?;
-                loaded = true;
-                break;
-            }
-        }
-
-        if !loaded {
-            // Load from environment variables
-            config = ServerConfig::from_env()?;
-        }
-
-        config
+    let mut config = match find_config_file(args) {
+        Some(config_path) => ServerConfig::from_file_with_format(config_path).await?,
+        // Load from environment variables
+        None => ServerConfig::from_env()?,
     };
 
     // Override with command line arguments
@@ -330,5 +546,9 @@ async fn load_config(
         config.host = host;
     }
 
+    if let Some(transport) = transport {
+        config.transport = transport;
+    }
+
     Ok(config)
 }
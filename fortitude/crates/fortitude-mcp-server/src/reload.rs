@@ -0,0 +1,237 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Hot reload of the MCP server configuration file
+// Watches the config file and classifies changes as applied live or needing a restart
+// Auth keys, rate limits and tool enablement apply without dropping client sessions
+
+use crate::config::ServerConfig;
+use anyhow::Result;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+/// Window for coalescing the burst of events an editor save produces
+pub const RELOAD_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Config sections applied to a running server
+const LIVE_SECTIONS: &[&str] = &["auth", "integration.disabled_tools"];
+
+/// Outcome of comparing a reloaded configuration with the running one
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ReloadReport {
+    /// Changed sections applied without a restart
+    pub applied: Vec<String>,
+    /// Changed sections that only take effect after a restart
+    pub requires_restart: Vec<String>,
+}
+
+impl ReloadReport {
+    /// Compare two configurations section by section
+    pub fn between(old: &ServerConfig, new: &ServerConfig) -> Self {
+        let mut report = Self::default();
+        for ((section, before), (_, after)) in sections(old).into_iter().zip(sections(new)) {
+            if before == after {
+                continue;
+            }
+            if LIVE_SECTIONS.contains(&section) {
+                report.applied.push(section.to_string());
+            } else {
+                report.requires_restart.push(section.to_string());
+            }
+        }
+        report
+    }
+
+    /// Whether the file changed in any way that matters
+    pub fn is_empty(&self) -> bool {
+        self.applied.is_empty() && self.requires_restart.is_empty()
+    }
+
+    /// Log what was applied and what still needs a restart
+    pub fn log(&self) {
+        if self.is_empty() {
+            info!("Configuration reloaded, no changes");
+            return;
+        }
+        if !self.applied.is_empty() {
+            info!(
+                "Configuration reloaded, applied: {}",
+                self.applied.join(", ")
+            );
+        }
+        if !self.requires_restart.is_empty() {
+            warn!(
+                "Configuration changes need a restart to take effect: {}",
+                self.requires_restart.join(", ")
+            );
+        }
+    }
+}
+
+/// Config split into named sections for comparison
+///
+/// Serialized values are compared so the config types need no `PartialEq`.
+fn sections(config: &ServerConfig) -> Vec<(&'static str, serde_json::Value)> {
+    let mut integration = config.integration.clone();
+    let disabled_tools = std::mem::take(&mut integration.disabled_tools);
+    vec![
+        ("port", to_value(&config.port)),
+        ("host", to_value(&config.host)),
+        ("transport", to_value(&config.transport)),
+        ("max_connections", to_value(&config.max_connections)),
+        ("request_timeout", to_value(&config.request_timeout)),
+        ("auth", to_value(&config.auth)),
+        ("logging", to_value(&config.logging)),
+        ("performance", to_value(&config.performance)),
+        ("security", to_value(&config.security)),
+        ("integration", to_value(&integration)),
+        ("integration.disabled_tools", to_value(&disabled_tools)),
+        ("notifications", to_value(&config.notifications)),
+        ("daemon", to_value(&config.daemon)),
+    ]
+}
+
+fn to_value<T: Serialize>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap_or(serde_json::Value::Null)
+}
+
+/// Watches the config file and signals when it may have changed
+pub struct ConfigWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl ConfigWatcher {
+    /// Watch `path`, sending on `changed` after each modification
+    ///
+    /// The parent directory is watched so editors that save by replacing the
+    /// file are still seen.
+    pub fn start(path: &Path, changed: mpsc::UnboundedSender<()>) -> Result<Self> {
+        let path = path.canonicalize()?;
+        let directory = path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let file_name = path.file_name().map(|name| name.to_os_string());
+
+        let mut watcher =
+            notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+                let event = match result {
+                    Ok(event) => event,
+                    Err(e) => {
+                        warn!("Config watcher error: {}", e);
+                        return;
+                    }
+                };
+                if !matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                ) {
+                    return;
+                }
+                if event
+                    .paths
+                    .iter()
+                    .any(|changed| changed.file_name() == file_name.as_deref())
+                {
+                    let _ = changed.send(());
+                }
+            })?;
+        watcher.watch(&directory, RecursiveMode::NonRecursive)?;
+        info!("Watching {} for configuration changes", path.display());
+
+        Ok(Self { _watcher: watcher })
+    }
+}
+
+/// Completes each time a reload is requested with SIGHUP
+#[cfg(unix)]
+pub struct ReloadSignal(Option<tokio::signal::unix::Signal>);
+
+#[cfg(unix)]
+impl ReloadSignal {
+    pub fn new() -> Self {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup()) {
+            Ok(signal) => Self(Some(signal)),
+            Err(e) => {
+                warn!("Failed to listen for SIGHUP: {}", e);
+                Self(None)
+            }
+        }
+    }
+
+    pub async fn recv(&mut self) {
+        match &mut self.0 {
+            Some(signal) => {
+                signal.recv().await;
+            }
+            None => std::future::pending().await,
+        }
+    }
+}
+
+/// Reload signals are not available off Unix; use the file watcher instead
+#[cfg(not(unix))]
+pub struct ReloadSignal;
+
+#[cfg(not(unix))]
+impl ReloadSignal {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub async fn recv(&mut self) {
+        std::future::pending().await
+    }
+}
+
+impl Default for ReloadSignal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_separates_live_and_restart_changes() {
+        let old = ServerConfig::default();
+        assert!(ReloadReport::between(&old, &old).is_empty());
+
+        let mut new = old.clone();
+        new.auth.rate_limit.max_requests_per_minute = 5;
+        new.integration.disabled_tools = vec!["research_query".to_string()];
+        new.port = 9000;
+
+        let report = ReloadReport::between(&old, &new);
+        assert_eq!(report.applied, vec!["auth", "integration.disabled_tools"]);
+        assert_eq!(report.requires_restart, vec!["port"]);
+    }
+
+    #[test]
+    fn test_other_integration_settings_need_restart() {
+        let old = ServerConfig::default();
+        let mut new = old.clone();
+        new.integration.enable_classification = false;
+
+        let report = ReloadReport::between(&old, &new);
+        assert!(report.applied.is_empty());
+        assert_eq!(report.requires_restart, vec!["integration"]);
+    }
+}
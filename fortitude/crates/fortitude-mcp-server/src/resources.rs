@@ -15,16 +15,23 @@
 // ABOUTME: MCP resource providers for Fortitude reference library and system resources
 // Exposes reference library files, cache statistics, and configuration state via MCP protocol
 // Implements proper URI conventions and security for read-only access to docs/ directory
+// Tracks per-client subscriptions and sends debounced resource change notifications
 
 use crate::config::ServerConfig;
 use anyhow::Result;
-use rmcp::model::{RawResource, Resource, ResourceContents};
+use async_trait::async_trait;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use rmcp::model::{RawResource, Resource, ResourceContents, ResourceUpdatedNotificationParam};
+use rmcp::service::{Peer, RoleServer};
 use rmcp::Error as McpError;
 use serde_json::json;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::fs;
-use tracing::{debug, instrument};
+use tokio::sync::{mpsc, RwLock};
+use tracing::{debug, info, instrument, warn};
 
 /// Resource provider for Fortitude MCP server
 pub struct ResourceProvider {
@@ -192,10 +199,7 @@ impl ResourceProvider {
                     .strip_prefix(base_path)
                     .map_err(|e| McpError::internal_error(format!("Invalid path: {e}"), None))?;
 
-                let uri = format!(
-                    "mcp://fortitude/docs/reference_library/{}",
-                    relative_path.to_string_lossy().replace('\\', "/")
-                );
+                let uri = Self::reference_library_uri(relative_path);
 
                 let file_name = path
                     .file_name()
@@ -226,6 +230,24 @@ impl ResourceProvider {
         Ok(())
     }
 
+    /// Directory served as the reference library, if present
+    pub fn reference_library_path(&self) -> Option<PathBuf> {
+        [
+            self.docs_base_path.clone(),
+            self.docs_base_path.join("reference_library"),
+        ]
+        .into_iter()
+        .find(|path| path.exists())
+    }
+
+    /// Resource URI for a file relative to the reference library root
+    pub fn reference_library_uri(relative_path: &Path) -> String {
+        format!(
+            "mcp://fortitude/docs/reference_library/{}",
+            relative_path.to_string_lossy().replace('\\', "/")
+        )
+    }
+
     /// Detect MIME type based on file extension
     fn detect_mime_type(path: &Path) -> String {
         match path.extension().and_then(|ext| ext.to_str()) {
@@ -497,6 +519,339 @@ struct ParsedUri {
     resource_path: String,
 }
 
+/// URI of the cache statistics resource, updated on every storage write
+pub const CACHE_STATISTICS_URI: &str = "mcp://fortitude/cache/statistics";
+
+/// Kind of change observed for a resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceChangeKind {
+    Created,
+    Modified,
+    Removed,
+}
+
+/// A change to a single resource
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceChange {
+    pub uri: String,
+    pub kind: ResourceChangeKind,
+}
+
+impl ResourceChange {
+    pub fn new(uri: impl Into<String>, kind: ResourceChangeKind) -> Self {
+        Self {
+            uri: uri.into(),
+            kind,
+        }
+    }
+}
+
+/// Destination for resource notifications (an MCP client connection)
+#[async_trait]
+pub trait ResourceNotificationSink: Send + Sync {
+    /// Send `notifications/resources/updated` for a subscribed URI
+    async fn resource_updated(&self, uri: &str) -> Result<(), String>;
+
+    /// Send `notifications/resources/list_changed`
+    async fn resource_list_changed(&self) -> Result<(), String>;
+}
+
+#[async_trait]
+impl ResourceNotificationSink for Peer<RoleServer> {
+    async fn resource_updated(&self, uri: &str) -> Result<(), String> {
+        self.notify_resource_updated(ResourceUpdatedNotificationParam {
+            uri: uri.to_string(),
+        })
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn resource_list_changed(&self) -> Result<(), String> {
+        self.notify_resource_list_changed()
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+struct ClientSubscription {
+    sink: Arc<dyn ResourceNotificationSink>,
+    filters: HashSet<String>,
+}
+
+/// Per-client resource subscriptions
+///
+/// A filter is either an exact resource URI or a prefix ending in `*`
+/// (e.g. `mcp://fortitude/docs/*`).
+#[derive(Default)]
+pub struct ResourceSubscriptions {
+    clients: RwLock<HashMap<String, ClientSubscription>>,
+}
+
+impl ResourceSubscriptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validate a subscription filter
+    pub fn validate_filter(filter: &str) -> Result<(), McpError> {
+        let prefix = filter.strip_suffix('*').unwrap_or(filter);
+        if !prefix.starts_with("mcp://fortitude/")
+            || prefix.contains("..")
+            || prefix.contains('~')
+            || prefix.contains('*')
+        {
+            return Err(McpError::invalid_params(
+                format!("Invalid subscription URI: {filter}"),
+                None,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Add a subscription filter for a client
+    pub async fn subscribe(
+        &self,
+        client_id: &str,
+        sink: Arc<dyn ResourceNotificationSink>,
+        filter: &str,
+    ) -> Result<(), McpError> {
+        Self::validate_filter(filter)?;
+
+        let mut clients = self.clients.write().await;
+        let entry = clients
+            .entry(client_id.to_string())
+            .or_insert_with(|| ClientSubscription {
+                sink: sink.clone(),
+                filters: HashSet::new(),
+            });
+        entry.sink = sink;
+        entry.filters.insert(filter.to_string());
+        debug!("Client {} subscribed to {}", client_id, filter);
+        Ok(())
+    }
+
+    /// Remove a subscription filter; returns whether it existed
+    pub async fn unsubscribe(&self, client_id: &str, filter: &str) -> bool {
+        let mut clients = self.clients.write().await;
+        let Some(entry) = clients.get_mut(client_id) else {
+            return false;
+        };
+        let removed = entry.filters.remove(filter);
+        if entry.filters.is_empty() {
+            clients.remove(client_id);
+        }
+        removed
+    }
+
+    /// Drop every subscription held by a client
+    pub async fn remove_client(&self, client_id: &str) {
+        self.clients.write().await.remove(client_id);
+    }
+
+    /// Number of subscription filters held by a client
+    pub async fn subscription_count(&self, client_id: &str) -> usize {
+        self.clients
+            .read()
+            .await
+            .get(client_id)
+            .map(|c| c.filters.len())
+            .unwrap_or(0)
+    }
+
+    /// Sinks of clients with a filter matching the URI
+    async fn sinks_for(&self, uri: &str) -> Vec<Arc<dyn ResourceNotificationSink>> {
+        self.clients
+            .read()
+            .await
+            .values()
+            .filter(|c| c.filters.iter().any(|f| Self::filter_matches(f, uri)))
+            .map(|c| c.sink.clone())
+            .collect()
+    }
+
+    /// Sinks of every subscribed client
+    async fn all_sinks(&self) -> Vec<Arc<dyn ResourceNotificationSink>> {
+        self.clients
+            .read()
+            .await
+            .values()
+            .map(|c| c.sink.clone())
+            .collect()
+    }
+
+    fn filter_matches(filter: &str, uri: &str) -> bool {
+        match filter.strip_suffix('*') {
+            Some(prefix) => uri.starts_with(prefix),
+            None => filter == uri,
+        }
+    }
+}
+
+/// Debounces resource change events and delivers them to subscribed clients
+pub struct ResourceChangeNotifier {
+    sender: mpsc::UnboundedSender<ResourceChange>,
+}
+
+impl ResourceChangeNotifier {
+    /// Start the notifier; must be called within a tokio runtime
+    pub fn new(subscriptions: Arc<ResourceSubscriptions>, debounce: Duration) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(Self::run(receiver, subscriptions, debounce));
+        Self { sender }
+    }
+
+    /// Publish a resource change
+    pub fn publish(&self, change: ResourceChange) {
+        if self.sender.send(change).is_err() {
+            warn!("Resource change notifier has stopped; dropping change event");
+        }
+    }
+
+    async fn run(
+        mut receiver: mpsc::UnboundedReceiver<ResourceChange>,
+        subscriptions: Arc<ResourceSubscriptions>,
+        debounce: Duration,
+    ) {
+        while let Some(first) = receiver.recv().await {
+            let mut pending: HashMap<String, ResourceChangeKind> = HashMap::new();
+            pending.insert(first.uri, first.kind);
+
+            let window = tokio::time::sleep(debounce);
+            tokio::pin!(window);
+            loop {
+                tokio::select! {
+                    change = receiver.recv() => match change {
+                        Some(change) => Self::merge(&mut pending, change),
+                        None => break,
+                    },
+                    _ = &mut window => break,
+                }
+            }
+
+            Self::dispatch(&subscriptions, pending).await;
+        }
+    }
+
+    /// Fold a change into the pending batch, keeping the most significant kind
+    fn merge(pending: &mut HashMap<String, ResourceChangeKind>, change: ResourceChange) {
+        pending
+            .entry(change.uri)
+            .and_modify(|kind| {
+                *kind = match (*kind, change.kind) {
+                    (ResourceChangeKind::Created, ResourceChangeKind::Removed) => {
+                        ResourceChangeKind::Removed
+                    }
+                    (ResourceChangeKind::Created, _) => ResourceChangeKind::Created,
+                    (_, new) => new,
+                }
+            })
+            .or_insert(change.kind);
+    }
+
+    async fn dispatch(
+        subscriptions: &ResourceSubscriptions,
+        pending: HashMap<String, ResourceChangeKind>,
+    ) {
+        let list_changed = pending
+            .values()
+            .any(|kind| *kind != ResourceChangeKind::Modified);
+
+        for uri in pending.keys() {
+            for sink in subscriptions.sinks_for(uri).await {
+                if let Err(e) = sink.resource_updated(uri).await {
+                    warn!("Failed to send resource update for {}: {}", uri, e);
+                }
+            }
+        }
+
+        if list_changed {
+            for sink in subscriptions.all_sinks().await {
+                if let Err(e) = sink.resource_list_changed().await {
+                    warn!("Failed to send resource list change: {}", e);
+                }
+            }
+        }
+    }
+}
+
+/// Watches the reference library and storage directories and publishes changes
+pub struct ResourceWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl ResourceWatcher {
+    /// Watch `library_path` (reference library files) and `storage_path` (research cache)
+    pub fn start(
+        notifier: Arc<ResourceChangeNotifier>,
+        library_path: Option<PathBuf>,
+        storage_path: Option<PathBuf>,
+    ) -> Result<Self> {
+        let library_path = library_path.and_then(|p| p.canonicalize().ok());
+        let storage_path = storage_path.and_then(|p| p.canonicalize().ok());
+
+        let handler_library = library_path.clone();
+        let handler_storage = storage_path.clone();
+        let mut watcher =
+            notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+                let event = match result {
+                    Ok(event) => event,
+                    Err(e) => {
+                        warn!("Resource watcher error: {}", e);
+                        return;
+                    }
+                };
+                for change in Self::changes_for_event(
+                    &event,
+                    handler_library.as_deref(),
+                    handler_storage.as_deref(),
+                ) {
+                    notifier.publish(change);
+                }
+            })?;
+
+        for path in [&library_path, &storage_path].into_iter().flatten() {
+            watcher.watch(path, RecursiveMode::Recursive)?;
+            info!("Watching {} for resource changes", path.display());
+        }
+
+        Ok(Self { _watcher: watcher })
+    }
+
+    /// Map a filesystem event to resource changes
+    fn changes_for_event(
+        event: &notify::Event,
+        library_path: Option<&Path>,
+        storage_path: Option<&Path>,
+    ) -> Vec<ResourceChange> {
+        let kind = match event.kind {
+            EventKind::Create(_) => ResourceChangeKind::Created,
+            EventKind::Modify(_) => ResourceChangeKind::Modified,
+            EventKind::Remove(_) => ResourceChangeKind::Removed,
+            _ => return Vec::new(),
+        };
+
+        event
+            .paths
+            .iter()
+            .filter_map(|path| {
+                if let Some(relative) = library_path.and_then(|base| path.strip_prefix(base).ok()) {
+                    return Some(ResourceChange::new(
+                        ResourceProvider::reference_library_uri(relative),
+                        kind,
+                    ));
+                }
+                if storage_path.is_some_and(|base| path.starts_with(base)) {
+                    return Some(ResourceChange::new(
+                        CACHE_STATISTICS_URI,
+                        ResourceChangeKind::Modified,
+                    ));
+                }
+                None
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -661,4 +1016,153 @@ mod tests {
             );
         }
     }
+
+    #[derive(Default)]
+    struct RecordingSink {
+        updated: std::sync::Mutex<Vec<String>>,
+        list_changed: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl ResourceNotificationSink for RecordingSink {
+        async fn resource_updated(&self, uri: &str) -> Result<(), String> {
+            self.updated.lock().unwrap().push(uri.to_string());
+            Ok(())
+        }
+
+        async fn resource_list_changed(&self) -> Result<(), String> {
+            self.list_changed
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_subscription_filter_matching() {
+        assert!(ResourceSubscriptions::filter_matches(
+            CACHE_STATISTICS_URI,
+            CACHE_STATISTICS_URI
+        ));
+        assert!(ResourceSubscriptions::filter_matches(
+            "mcp://fortitude/docs/*",
+            "mcp://fortitude/docs/reference_library/rust/errors.md"
+        ));
+        assert!(!ResourceSubscriptions::filter_matches(
+            "mcp://fortitude/docs/*",
+            CACHE_STATISTICS_URI
+        ));
+
+        assert!(ResourceSubscriptions::validate_filter("mcp://fortitude/docs/*").is_ok());
+        assert!(ResourceSubscriptions::validate_filter("file:///etc/passwd").is_err());
+        assert!(ResourceSubscriptions::validate_filter("mcp://fortitude/docs/../*").is_err());
+        assert!(ResourceSubscriptions::validate_filter("mcp://fortitude/*/docs").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_and_unsubscribe() {
+        let subscriptions = ResourceSubscriptions::new();
+        let sink = Arc::new(RecordingSink::default());
+
+        subscriptions
+            .subscribe("client_a", sink.clone(), CACHE_STATISTICS_URI)
+            .await
+            .unwrap();
+        subscriptions
+            .subscribe("client_a", sink.clone(), "mcp://fortitude/docs/*")
+            .await
+            .unwrap();
+        assert_eq!(subscriptions.subscription_count("client_a").await, 2);
+        assert!(subscriptions
+            .subscribe("client_a", sink, "http://example.com")
+            .await
+            .is_err());
+
+        assert!(
+            subscriptions
+                .unsubscribe("client_a", CACHE_STATISTICS_URI)
+                .await
+        );
+        assert!(
+            !subscriptions
+                .unsubscribe("client_a", CACHE_STATISTICS_URI)
+                .await
+        );
+        assert_eq!(subscriptions.sinks_for(CACHE_STATISTICS_URI).await.len(), 0);
+
+        subscriptions.remove_client("client_a").await;
+        assert_eq!(subscriptions.subscription_count("client_a").await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_notifier_debounces_and_filters_per_client() {
+        let subscriptions = Arc::new(ResourceSubscriptions::new());
+        let cache_sink = Arc::new(RecordingSink::default());
+        let docs_sink = Arc::new(RecordingSink::default());
+        subscriptions
+            .subscribe("cache", cache_sink.clone(), CACHE_STATISTICS_URI)
+            .await
+            .unwrap();
+        subscriptions
+            .subscribe("docs", docs_sink.clone(), "mcp://fortitude/docs/*")
+            .await
+            .unwrap();
+
+        let notifier = ResourceChangeNotifier::new(subscriptions, Duration::from_millis(50));
+        for _ in 0..5 {
+            notifier.publish(ResourceChange::new(
+                CACHE_STATISTICS_URI,
+                ResourceChangeKind::Modified,
+            ));
+        }
+        notifier.publish(ResourceChange::new(
+            "mcp://fortitude/docs/reference_library/new.md",
+            ResourceChangeKind::Created,
+        ));
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        assert_eq!(
+            *cache_sink.updated.lock().unwrap(),
+            vec![CACHE_STATISTICS_URI.to_string()]
+        );
+        assert_eq!(
+            *docs_sink.updated.lock().unwrap(),
+            vec!["mcp://fortitude/docs/reference_library/new.md".to_string()]
+        );
+        // A created resource changes the listing for every subscribed client
+        assert_eq!(
+            cache_sink
+                .list_changed
+                .load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+        assert_eq!(
+            docs_sink
+                .list_changed
+                .load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+    }
+
+    #[test]
+    fn test_watcher_maps_paths_to_resource_uris() {
+        let library = Path::new("/srv/docs/reference_library");
+        let storage = Path::new("/srv/cache");
+        let event = notify::Event::new(EventKind::Create(notify::event::CreateKind::File))
+            .add_path(library.join("rust/async.md"))
+            .add_path(storage.join("research/entry.json"))
+            .add_path(PathBuf::from("/tmp/unrelated"));
+
+        let changes = ResourceWatcher::changes_for_event(&event, Some(library), Some(storage));
+        assert_eq!(
+            changes,
+            vec![
+                ResourceChange::new(
+                    "mcp://fortitude/docs/reference_library/rust/async.md",
+                    ResourceChangeKind::Created
+                ),
+                ResourceChange::new(CACHE_STATISTICS_URI, ResourceChangeKind::Modified),
+            ]
+        );
+    }
 }
@@ -15,27 +15,180 @@
 // ABOUTME: Production-ready MCP server implementation for Fortitude
 // Provides secure, performant Model Context Protocol server
 // Follows production patterns with authentication, error handling, and observability
+// Reports research_query progress to clients that send a progress token and honours cancellation
+// Shuts down on Ctrl+C or SIGTERM after draining in-flight tool calls
+// Reloads its config file on change or SIGHUP without dropping client sessions
 
 use crate::auth::{AuthManager, AuthMiddleware, Permission};
-use crate::config::ServerConfig;
+use crate::config::{McpTransport, ServerConfig};
 use crate::monitoring::McpMonitoringService;
 use crate::pattern_tracking::{McpPatternTracker, McpPatternTrackingConfig};
-use crate::resources::ResourceProvider;
+use crate::reload::{ConfigWatcher, ReloadReport, ReloadSignal, RELOAD_DEBOUNCE};
+use crate::resources::{
+    ResourceChange, ResourceChangeKind, ResourceChangeNotifier, ResourceProvider,
+    ResourceSubscriptions, ResourceWatcher, CACHE_STATISTICS_URI,
+};
 use crate::tools::FortitudeTools;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use fortitude_core::{supervisor, PipelineStage, StageObserver};
 use rmcp::{
     model::{
         CallToolRequestParam, CallToolResult, Implementation, InitializeRequestParam,
         InitializeResult, ListResourcesResult, ListToolsResult, PaginatedRequestParam,
-        ProtocolVersion, ReadResourceRequestParam, ReadResourceResult, ServerCapabilities,
-        ServerInfo,
+        ProgressNotificationParam, ProgressToken, ProtocolVersion, ReadResourceRequestParam,
+        ReadResourceResult, ServerCapabilities, ServerInfo, SubscribeRequestParam,
+        UnsubscribeRequestParam,
     },
-    service::{RequestContext, RoleServer},
+    service::{Peer, RequestContext, RoleServer},
     Error as McpError, ServerHandler, ServiceExt,
 };
+use std::collections::HashSet;
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tracing::{error, info, instrument};
+use std::time::Duration;
+use tokio::sync::{mpsc, Notify, RwLock};
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, instrument, warn};
+
+/// Progress steps reported for a research_query call
+pub const RESEARCH_PROGRESS_TOTAL: u32 = 3;
+
+/// Progress value and message reported when research enters `stage`
+///
+/// Context detection runs inside classification and is not reported separately.
+pub fn research_progress_step(stage: PipelineStage) -> Option<(u32, &'static str)> {
+    match stage {
+        PipelineStage::Classification => Some((0, "Classifying query")),
+        PipelineStage::ContextDetection => None,
+        PipelineStage::Research => Some((1, "Classification done; calling research provider")),
+        PipelineStage::QualityScoring => Some((2, "Scoring answer quality")),
+    }
+}
+
+/// Forwards research stages to the client as progress notifications, in order
+struct ResearchProgress {
+    sender: mpsc::UnboundedSender<(u32, &'static str)>,
+    forwarder: JoinHandle<()>,
+}
+
+impl ResearchProgress {
+    fn start(peer: Peer<RoleServer>, progress_token: ProgressToken) -> Self {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<(u32, &'static str)>();
+        let forwarder = tokio::spawn(async move {
+            while let Some((progress, message)) = receiver.recv().await {
+                let notification = ProgressNotificationParam {
+                    progress_token: progress_token.clone(),
+                    progress,
+                    total: Some(RESEARCH_PROGRESS_TOTAL),
+                    message: Some(message.to_string()),
+                };
+                if let Err(e) = peer.notify_progress(notification).await {
+                    debug!("Stopped sending research progress: {}", e);
+                    break;
+                }
+            }
+        });
+        Self { sender, forwarder }
+    }
+
+    fn observer(&self) -> StageObserver {
+        let sender = self.sender.clone();
+        StageObserver::new(move |stage| {
+            if let Some(step) = research_progress_step(stage) {
+                let _ = sender.send(step);
+            }
+        })
+    }
+
+    /// Report completion and wait until every notification has been sent
+    async fn finish(self, success: bool) {
+        if success {
+            let _ = self
+                .sender
+                .send((RESEARCH_PROGRESS_TOTAL, "Research complete"));
+        }
+        drop(self.sender);
+        let _ = self.forwarder.await;
+    }
+}
+
+/// Count of tool calls in progress, awaited on shutdown
+#[derive(Debug, Default)]
+pub struct InFlightCalls {
+    count: AtomicUsize,
+    idle: Notify,
+}
+
+/// Marks a tool call as in flight until dropped
+pub struct InFlightGuard(Arc<InFlightCalls>);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        if self.0.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.idle.notify_waiters();
+        }
+    }
+}
+
+impl InFlightCalls {
+    /// Mark a call as started
+    pub fn begin(self: &Arc<Self>) -> InFlightGuard {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        InFlightGuard(self.clone())
+    }
+
+    /// Calls currently in progress
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+
+    /// Wait up to `timeout` for every call to finish; false if some are still running
+    pub async fn drain(&self, timeout: Duration) -> bool {
+        let drained = async {
+            loop {
+                let idle = self.idle.notified();
+                if self.count() == 0 {
+                    return;
+                }
+                idle.await;
+            }
+        };
+        tokio::time::timeout(timeout, drained).await.is_ok()
+    }
+}
+
+/// Completes on Ctrl+C, or SIGTERM on Unix
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            error!("Failed to listen for Ctrl+C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+    info!("Received shutdown signal");
+}
 
 /// Production MCP server for Fortitude AI research assistant
 #[derive(Clone)]
@@ -48,12 +201,21 @@ pub struct McpServer {
     auth_middleware: Arc<AuthMiddleware>,
     pattern_tracker: Option<Arc<McpPatternTracker>>,
     monitoring_service: Arc<McpMonitoringService>,
-    _inner: Arc<RwLock<ServerState>>,
+    subscriptions: Arc<ResourceSubscriptions>,
+    notifier: Option<Arc<ResourceChangeNotifier>>,
+    _watcher: Option<Arc<ResourceWatcher>>,
+    in_flight: Arc<InFlightCalls>,
+    state: Arc<RwLock<ServerState>>,
 }
 
+/// Settings that change while the server runs
 struct ServerState {
-    // Internal state will be expanded in subsequent tasks
-    _placeholder: bool,
+    /// Config file watched for hot reload
+    config_file: Option<PathBuf>,
+    /// Config as last read from `config_file`, for comparing reloads
+    loaded: Option<ServerConfig>,
+    /// Tools hidden from clients and refused when called
+    disabled_tools: HashSet<String>,
 }
 
 impl McpServer {
@@ -86,7 +248,42 @@ impl McpServer {
         let monitoring_service = Arc::new(McpMonitoringService::for_mcp_server());
         info!("MCP monitoring service initialized successfully");
 
-        let inner = Arc::new(RwLock::new(ServerState { _placeholder: true }));
+        // Initialize resource change notifications
+        let subscriptions = Arc::new(ResourceSubscriptions::new());
+        let (notifier, watcher) = if config.notifications.enabled {
+            let notifier = Arc::new(ResourceChangeNotifier::new(
+                subscriptions.clone(),
+                Duration::from_millis(config.notifications.debounce_ms),
+            ));
+            let watcher = if config.notifications.watch_filesystem {
+                let storage_path = std::env::var("FORTITUDE_STORAGE_PATH")
+                    .unwrap_or_else(|_| "fortitude_cache".to_string());
+                match ResourceWatcher::start(
+                    notifier.clone(),
+                    resources.reference_library_path(),
+                    Some(PathBuf::from(storage_path)),
+                ) {
+                    Ok(watcher) => Some(Arc::new(watcher)),
+                    Err(e) => {
+                        warn!("Resource file watching unavailable: {}", e);
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            info!("MCP resource notifications enabled");
+            (Some(notifier), watcher)
+        } else {
+            info!("MCP resource notifications disabled");
+            (None, None)
+        };
+
+        let state = Arc::new(RwLock::new(ServerState {
+            config_file: None,
+            loaded: None,
+            disabled_tools: config.integration.disabled_tools.iter().cloned().collect(),
+        }));
 
         Ok(Self {
             config: config_arc,
@@ -96,41 +293,276 @@ impl McpServer {
             auth_middleware,
             pattern_tracker,
             monitoring_service,
-            _inner: inner,
+            subscriptions,
+            notifier,
+            _watcher: watcher,
+            in_flight: Arc::new(InFlightCalls::default()),
+            state,
         })
     }
 
-    /// Run the MCP server with graceful shutdown
-    pub async fn run(self) -> Result<()> {
-        info!("Starting MCP server on port {}", self.config.port);
+    /// Server capabilities, including resource subscriptions when notifications are enabled
+    fn capabilities(&self) -> ServerCapabilities {
+        if self.notifier.is_some() {
+            ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .enable_resources_list_changed()
+                .build()
+        } else {
+            ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .build()
+        }
+    }
 
-        // Create the MCP server using stdio transport
-        let transport = rmcp::transport::stdio();
+    /// Publish a resource change to subscribed clients
+    pub fn publish_resource_change(&self, change: ResourceChange) {
+        if let Some(notifier) = &self.notifier {
+            notifier.publish(change);
+        }
+    }
+
+    /// Resource subscriptions held by connected clients
+    pub fn subscriptions(&self) -> Arc<ResourceSubscriptions> {
+        self.subscriptions.clone()
+    }
+
+    /// Tool calls currently in progress
+    pub fn in_flight(&self) -> Arc<InFlightCalls> {
+        self.in_flight.clone()
+    }
+
+    /// Hot-reload configuration from `path`, the file the server was started with
+    pub async fn with_config_file(self, path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let loaded = ServerConfig::from_file_with_format(&path).await?;
+        {
+            let mut state = self.state.write().await;
+            state.config_file = Some(path);
+            state.loaded = Some(loaded);
+        }
+        Ok(self)
+    }
 
-        // Set up graceful shutdown
-        let shutdown = async {
-            tokio::signal::ctrl_c()
-                .await
-                .expect("Failed to install CTRL+C handler");
-            info!("Received shutdown signal");
+    /// Re-read the config file and apply auth, rate limit and tool changes
+    ///
+    /// An invalid file is rejected and the running configuration kept.
+    pub async fn reload_config(&self) -> Result<ReloadReport> {
+        let mut state = self.state.write().await;
+        let path = state
+            .config_file
+            .clone()
+            .ok_or_else(|| anyhow!("Server was not started with a config file"))?;
+        let new = ServerConfig::from_file_with_format(&path).await?;
+        let report = match &state.loaded {
+            Some(old) => ReloadReport::between(old, &new),
+            None => ReloadReport::between(&self.config, &new),
         };
 
-        // Run server with graceful shutdown
-        tokio::select! {
-            result = self.serve(transport) => {
-                match result {
-                    Ok(service) => {
-                        info!("MCP server completed successfully");
-                        service.cancel().await.ok();
+        if report.applied.iter().any(|section| section == "auth") {
+            self.auth_manager.reload(&new);
+        }
+        state.disabled_tools = new.integration.disabled_tools.iter().cloned().collect();
+        state.loaded = Some(new);
+        Ok(report)
+    }
+
+    /// Whether the current configuration allows calling `name`
+    pub async fn is_tool_enabled(&self, name: &str) -> bool {
+        !self.state.read().await.disabled_tools.contains(name)
+    }
+
+    /// Reload on config file changes and SIGHUP until aborted
+    async fn spawn_reloader(&self) -> JoinHandle<()> {
+        // Listen before serving so a reload request never hits the default SIGHUP action
+        let mut hangup = ReloadSignal::new();
+        let (changed_tx, mut changed) = mpsc::unbounded_channel();
+        let watcher = match self.state.read().await.config_file.as_deref() {
+            Some(path) => match ConfigWatcher::start(path, changed_tx) {
+                Ok(watcher) => Some(watcher),
+                Err(e) => {
+                    warn!("Config file watching unavailable: {}", e);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let server = self.clone();
+        tokio::spawn(async move {
+            let _watcher = watcher;
+            loop {
+                tokio::select! {
+                    Some(()) = changed.recv() => {
+                        // Let the editor finish writing, then coalesce the burst of events
+                        tokio::time::sleep(RELOAD_DEBOUNCE).await;
+                        while changed.try_recv().is_ok() {}
                     }
-                    Err(e) => error!("MCP server error: {:?}", e),
+                    _ = hangup.recv() => info!("Received reload signal"),
+                }
+                match server.reload_config().await {
+                    Ok(report) => report.log(),
+                    Err(e) => warn!(
+                        "Configuration reload failed, keeping the current configuration: {:#}",
+                        e
+                    ),
                 }
             }
-            _ = shutdown => {
+        })
+    }
+
+    /// Run the MCP server with graceful shutdown on Ctrl+C or SIGTERM
+    pub async fn run(self) -> Result<()> {
+        self.run_until(shutdown_signal()).await
+    }
+
+    /// Run the MCP server until `shutdown` completes
+    ///
+    /// Reports readiness, watchdog keep-alives and shutdown to the service manager.
+    pub async fn run_until<F>(self, shutdown: F) -> Result<()>
+    where
+        F: Future<Output = ()> + Send,
+    {
+        let watchdog = supervisor::spawn_watchdog();
+        let reloader = self.spawn_reloader().await;
+
+        // Boxed: the rmcp service futures are deep enough to overflow layout computation
+        let result = match self.config.transport {
+            McpTransport::Stdio => Box::pin(self.run_stdio(shutdown)).await,
+            McpTransport::Tcp => Box::pin(self.run_tcp(shutdown)).await,
+        };
+
+        supervisor::notify_stopping("Shutting down");
+        reloader.abort();
+        if let Some(watchdog) = watchdog {
+            watchdog.abort();
+        }
+
+        result
+    }
+
+    /// Give in-flight tool calls the configured drain window to finish
+    async fn drain_in_flight(&self) {
+        let pending = self.in_flight.count();
+        if pending == 0 {
+            return;
+        }
+        let timeout = Duration::from_secs(self.config.daemon.shutdown_drain_seconds);
+        supervisor::notify_stopping(&format!("Draining {pending} tool calls"));
+        info!(
+            "Waiting up to {}s for {} in-flight tool calls",
+            timeout.as_secs(),
+            pending
+        );
+        if self.in_flight.drain(timeout).await {
+            info!("In-flight tool calls finished");
+        } else {
+            warn!(
+                "Abandoning {} tool calls still running after {}s",
+                self.in_flight.count(),
+                timeout.as_secs()
+            );
+        }
+    }
+
+    async fn run_stdio<F>(self, shutdown: F) -> Result<()>
+    where
+        F: Future<Output = ()> + Send,
+    {
+        info!("Starting MCP server on stdio");
+        let transport = rmcp::transport::stdio();
+        supervisor::notify_ready("Serving MCP over stdio");
+
+        tokio::pin!(shutdown);
+        let service = tokio::select! {
+            result = self.clone().serve(transport) => match result {
+                Ok(service) => service,
+                Err(e) => {
+                    error!("MCP server error: {:?}", e);
+                    return Ok(());
+                }
+            },
+            _ = &mut shutdown => {
+                info!("Shutting down MCP server before the session started");
+                return Ok(());
+            }
+        };
+
+        // Serve until the client disconnects or a shutdown is requested
+        let cancel = service.cancellation_token();
+        let session = service.waiting();
+        tokio::pin!(session);
+        tokio::select! {
+            _ = &mut session => info!("MCP client disconnected"),
+            _ = &mut shutdown => {
                 info!("Shutting down MCP server gracefully");
+                self.drain_in_flight().await;
+                cancel.cancel();
+                let _ = session.await;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Serve one MCP session per TCP connection
+    ///
+    /// Adopts a systemd socket-activated listener when present. On shutdown
+    /// new connections are refused, in-flight tool calls get the drain window
+    /// and the remaining sessions are then cancelled.
+    async fn run_tcp<F>(self, shutdown: F) -> Result<()>
+    where
+        F: Future<Output = ()> + Send,
+    {
+        let listener = match supervisor::activated_listener()? {
+            Some(listener) => listener,
+            None => {
+                let bind_addr = format!("{}:{}", self.config.host, self.config.port);
+                tokio::net::TcpListener::bind(&bind_addr).await?
+            }
+        };
+        let local_addr = listener.local_addr()?;
+        info!("MCP server listening on {}", local_addr);
+        supervisor::notify_ready(&format!("Serving MCP over TCP on {}", local_addr));
+
+        let mut sessions = tokio::task::JoinSet::new();
+        tokio::pin!(shutdown);
+        loop {
+            tokio::select! {
+                accepted = listener.accept() => {
+                    let (stream, peer) = match accepted {
+                        Ok(accepted) => accepted,
+                        Err(e) => {
+                            warn!("Failed to accept MCP connection: {}", e);
+                            continue;
+                        }
+                    };
+                    let server = self.clone();
+                    sessions.spawn(async move {
+                        match server.serve(stream).await {
+                            Ok(service) => {
+                                let _ = service.waiting().await;
+                                info!("MCP session from {} closed", peer);
+                            }
+                            Err(e) => warn!("MCP session from {} failed: {:?}", peer, e),
+                        }
+                    });
+                }
+                // Reap finished sessions so the set does not grow
+                Some(_) = sessions.join_next(), if !sessions.is_empty() => {}
+                _ = &mut shutdown => {
+                    info!("Shutting down MCP server gracefully");
+                    break;
+                }
             }
         }
 
+        drop(listener);
+        self.drain_in_flight().await;
+        sessions.shutdown().await;
         Ok(())
     }
 }
@@ -138,10 +570,7 @@ impl McpServer {
 impl ServerHandler for McpServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
-            capabilities: ServerCapabilities::builder()
-                .enable_tools()
-                .enable_resources()
-                .build(),
+            capabilities: self.capabilities(),
             ..Default::default()
         }
     }
@@ -155,10 +584,7 @@ impl ServerHandler for McpServer {
         info!("MCP server initialized");
         Ok(InitializeResult {
             protocol_version: ProtocolVersion::V_2024_11_05,
-            capabilities: ServerCapabilities::builder()
-                .enable_tools()
-                .enable_resources()
-                .build(),
+            capabilities: self.capabilities(),
             server_info: Implementation {
                 name: "fortitude-mcp-server".to_string(),
                 version: env!("CARGO_PKG_VERSION").to_string(),
@@ -174,14 +600,19 @@ impl ServerHandler for McpServer {
         _context: RequestContext<RoleServer>,
     ) -> Result<ListToolsResult, McpError> {
         info!("Listing available tools");
-        Ok(self.tools.list_tools())
+        let mut tools = self.tools.list_tools();
+        let state = self.state.read().await;
+        tools
+            .tools
+            .retain(|tool| !state.disabled_tools.contains(tool.name.as_ref()));
+        Ok(tools)
     }
 
-    #[instrument(skip(self, _context))]
+    #[instrument(skip(self, context))]
     async fn call_tool(
         &self,
         request: CallToolRequestParam,
-        _context: RequestContext<RoleServer>,
+        context: RequestContext<RoleServer>,
     ) -> Result<CallToolResult, McpError> {
         info!("Calling tool: {}", request.name);
         let start_time = std::time::Instant::now();
@@ -217,10 +648,42 @@ impl ServerHandler for McpServer {
             }
         };
 
-        // Call the tool
-        let result = self.tools.call_tool(request.clone()).await;
+        if !self.is_tool_enabled(&request.name).await {
+            warn!("Refusing disabled tool: {}", request.name);
+            return Err(McpError::invalid_request(
+                format!(
+                    "Tool {} is disabled by the server configuration",
+                    request.name
+                ),
+                None,
+            ));
+        }
+
+        // Call the tool, reporting research progress when the client asked for it
+        let _in_flight = self.in_flight.begin();
+        let progress = match context.meta.get_progress_token() {
+            Some(token) if request.name == "research_query" => {
+                Some(ResearchProgress::start(context.peer.clone(), token))
+            }
+            _ => None,
+        };
+        let observer = progress.as_ref().map(ResearchProgress::observer);
+        // Dropping the tool future on cancellation stops the research in flight
+        let result = tokio::select! {
+            result = self.tools.call_tool_with_observer(request.clone(), observer) => result,
+            _ = context.ct.cancelled() => {
+                info!("Tool call {} cancelled by client", request.name);
+                Err(McpError::internal_error(
+                    format!("Tool call {} was cancelled", request.name),
+                    None,
+                ))
+            }
+        };
         let execution_time = start_time.elapsed();
         let success = result.is_ok();
+        if let Some(progress) = progress {
+            progress.finish(success).await;
+        }
 
         // Calculate argument and response sizes
         let args_size = request.arguments.as_ref().map(|args| {
@@ -269,6 +732,14 @@ impl ServerHandler for McpServer {
                 .await;
         }
 
+        // Research results are written to storage, which changes the cache statistics
+        if success && request.name == "research_query" {
+            self.publish_resource_change(ResourceChange::new(
+                CACHE_STATISTICS_URI,
+                ResourceChangeKind::Modified,
+            ));
+        }
+
         result
     }
 
@@ -384,4 +855,128 @@ impl ServerHandler for McpServer {
             Err(e) => Err(e),
         }
     }
+
+    #[instrument(skip(self, context))]
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        info!("Subscribing to resource: {}", request.uri);
+
+        if self.notifier.is_none() {
+            return Err(McpError::invalid_request(
+                "Resource notifications are disabled",
+                None,
+            ));
+        }
+
+        // Extract client ID from context
+        let client_id = "mcp_client"; // In production, this would come from the connection
+
+        self.auth_middleware
+            .authenticate_request(None, client_id, Permission::ResourcesRead)
+            .await?;
+
+        self.subscriptions
+            .subscribe(client_id, Arc::new(context.peer), &request.uri)
+            .await
+    }
+
+    #[instrument(skip(self, _context))]
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        info!("Unsubscribing from resource: {}", request.uri);
+
+        // Extract client ID from context
+        let client_id = "mcp_client"; // In production, this would come from the connection
+
+        self.auth_middleware
+            .authenticate_request(None, client_id, Permission::ResourcesRead)
+            .await?;
+
+        self.subscriptions
+            .unsubscribe(client_id, &request.uri)
+            .await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_drain_waits_for_in_flight_calls() {
+        let in_flight = Arc::new(InFlightCalls::default());
+        assert!(in_flight.drain(Duration::from_millis(10)).await);
+
+        let call = in_flight.begin();
+        assert_eq!(in_flight.count(), 1);
+        assert!(!in_flight.drain(Duration::from_millis(20)).await);
+
+        let finish = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            drop(call);
+        });
+        assert!(in_flight.drain(Duration::from_secs(5)).await);
+        assert_eq!(in_flight.count(), 0);
+        finish.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_reload_applies_live_settings() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::env::set_var("FORTITUDE_STORAGE_PATH", temp_dir.path().to_str().unwrap());
+        let config_path = temp_dir.path().join("mcp-server.toml");
+
+        let mut config = ServerConfig::default();
+        config.notifications.enabled = false;
+        config.save_to_file(&config_path).await.unwrap();
+        let server = McpServer::new(config.clone())
+            .await
+            .unwrap()
+            .with_config_file(&config_path)
+            .await
+            .unwrap();
+        assert!(server.is_tool_enabled("research_query").await);
+
+        config.auth.rate_limit.max_requests_per_minute = 7;
+        config.integration.disabled_tools = vec!["research_query".to_string()];
+        config.port = 9191;
+        config.save_to_file(&config_path).await.unwrap();
+
+        let report = server.reload_config().await.unwrap();
+        assert_eq!(report.applied, vec!["auth", "integration.disabled_tools"]);
+        assert_eq!(report.requires_restart, vec!["port"]);
+        assert!(!server.is_tool_enabled("research_query").await);
+        assert_eq!(
+            server
+                .auth_manager
+                .rate_limit_config()
+                .max_requests_per_minute,
+            7
+        );
+
+        // An invalid file is rejected and the running settings kept
+        tokio::fs::write(&config_path, "port = \"not a port\"")
+            .await
+            .unwrap();
+        assert!(server.reload_config().await.is_err());
+        assert!(!server.is_tool_enabled("research_query").await);
+    }
+
+    #[test]
+    fn test_research_progress_steps_increase() {
+        let steps: Vec<u32> = PipelineStage::ALL
+            .iter()
+            .filter_map(|stage| research_progress_step(*stage))
+            .map(|(progress, _)| progress)
+            .collect();
+        assert_eq!(steps, vec![0, 1, 2]);
+        assert!(steps.iter().all(|step| *step < RESEARCH_PROGRESS_TOTAL));
+    }
 }
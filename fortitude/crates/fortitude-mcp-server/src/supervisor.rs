@@ -0,0 +1,55 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Process supervisor entry points for the MCP server
+// systemd integration lives in fortitude_core::supervisor; this adds the Windows service mode
+
+/// Windows service wrapper for the MCP server
+#[cfg(windows)]
+pub mod windows {
+    use crate::config::ServerConfig;
+    use crate::server::McpServer;
+    use fortitude_core::supervisor::windows::run_service;
+    use std::ffi::OsString;
+    use tracing::error;
+    use windows_service::service_dispatcher;
+
+    /// Service name registered with the Service Control Manager
+    pub const SERVICE_NAME: &str = "FortitudeMcpServer";
+
+    windows_service::define_windows_service!(ffi_service_main, service_main);
+
+    static CONFIG: std::sync::OnceLock<ServerConfig> = std::sync::OnceLock::new();
+
+    /// Hand control to the Service Control Manager; blocks until the service stops
+    ///
+    /// Fails unless `config` uses the TCP transport, since a service has no stdio.
+    pub fn run(config: ServerConfig) -> anyhow::Result<()> {
+        config.validate_for_service()?;
+        let _ = CONFIG.set(config);
+        service_dispatcher::start(SERVICE_NAME, ffi_service_main)?;
+        Ok(())
+    }
+
+    fn service_main(_arguments: Vec<OsString>) {
+        let result = run_service(SERVICE_NAME, |stop| async move {
+            let config = CONFIG.get().cloned().unwrap_or_default();
+            let server = McpServer::new(config).await?;
+            server.run_until(stop).await
+        });
+        if let Err(e) = result {
+            error!("Windows service failed: {:?}", e);
+        }
+    }
+}
@@ -14,16 +14,21 @@
 
 // ABOUTME: MCP tool implementations that expose fortitude research functionality
 // Provides thin wrappers around existing ResearchPipeline functionality
-// Implements research_query, classify_query, and detect_context tools
+// Implements research_query, classify_query, detect_context, crate_docs and submit_feedback tools
 
 use crate::auth::validation;
 use crate::config::ServerConfig;
+use crate::demo::DemoResearchEngine;
 use crate::proactive_tools::ProactiveTools;
 use crate::quality_tools::QualityTools;
 use anyhow::{anyhow, Result};
+use fortitude::learning::{
+    FileLearningStorage, LearningStorageService, UserFeedback, DEFAULT_LEARNING_STORE_PATH,
+};
 use fortitude_core::{
-    BasicClassifier, ContextDetector, FileStorage, FortitudeContextDetector, PipelineBuilder,
-    ResearchPipeline,
+    AdvisoryService, BasicClassifier, CitationValidator, ContextDetector, CrateDocsTool,
+    FileStorage, FortitudeContextDetector, PipelineBuilder, ResearchOptions, ResearchPipeline,
+    StageObserver, ToolError, DEFAULT_ADVISORY_CACHE_PATH,
 };
 use fortitude_types::{
     AudienceContext, ClassificationConfig, Classifier, DomainContext, ResearchType, Storage,
@@ -100,6 +105,9 @@ pub struct ResearchMetadata {
     pub quality_score: Option<f64>,
     /// Learning feedback incorporated (Sprint 009)
     pub learning_applied: bool,
+    /// Whether the result is canned demo output rather than real research
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub demo: bool,
 }
 
 /// Request parameters for classify_query tool
@@ -146,6 +154,58 @@ pub struct DetectContextRequest {
     pub research_type: Option<String>,
 }
 
+/// Request parameters for crate_docs tool
+#[derive(Debug, Deserialize, Validate, Serialize)]
+pub struct CrateDocsRequest {
+    /// Crate name
+    #[serde(rename = "crate")]
+    #[validate(length(min = 1, max = 64))]
+    pub crate_name: String,
+
+    /// Crate version (optional, latest stable when omitted)
+    #[validate(length(min = 1, max = 50))]
+    pub version: Option<String>,
+
+    /// Item paths to document (optional, crate root when omitted)
+    #[validate(length(max = 8))]
+    #[serde(default)]
+    pub paths: Vec<String>,
+}
+
+/// Request parameters for submit_feedback tool
+#[derive(Debug, Deserialize, Validate, Serialize)]
+pub struct SubmitFeedbackRequest {
+    /// Result ID (cache key) returned in research_query metadata
+    #[serde(alias = "cache_key")]
+    #[validate(length(min = 1, max = 256))]
+    pub result_id: String,
+
+    /// Usefulness rating from 1 (useless) to 5 (fully answered)
+    #[validate(range(min = 1, max = 5))]
+    pub rating: u8,
+
+    /// Free-form comment (optional)
+    #[validate(length(max = 2000))]
+    pub comment: Option<String>,
+}
+
+/// Response from submit_feedback tool
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmitFeedbackResponse {
+    /// ID of the feedback entry in the learning store
+    pub feedback_id: String,
+    /// Result the feedback was recorded for
+    pub result_id: String,
+    /// Query of the rated result
+    pub query: String,
+    /// Recorded rating (1-5)
+    pub rating: u8,
+    /// Recorded comment, if any
+    pub comment: Option<String>,
+    /// When the feedback was recorded (RFC 3339)
+    pub recorded_at: String,
+}
+
 /// Response from detect_context tool
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DetectContextResponse {
@@ -190,8 +250,11 @@ pub struct FortitudeTools {
     proactive_tools: Arc<ProactiveTools>,
     /// Quality control tools
     quality_tools: Arc<QualityTools>,
+    /// docs.rs lookup shared with the research pipeline
+    crate_docs: Arc<CrateDocsTool>,
+    /// Learning store that submitted feedback is recorded in
+    learning_storage: Arc<dyn LearningStorageService>,
     /// Server configuration
-    #[allow(dead_code)] // Reserved for future configuration-based behavior
     config: Arc<ServerConfig>,
 }
 
@@ -221,13 +284,33 @@ impl FortitudeTools {
         // Initialize context detector
         let context_detector = Arc::new(FortitudeContextDetector::new());
 
+        let crate_docs = Arc::new(CrateDocsTool::new());
+
         // Build pipeline with context detection enabled
-        let pipeline = Arc::new(
-            PipelineBuilder::new()
-                .with_context_detection(true)
-                .with_caching(true)
-                .build(classifier.clone(), storage),
-        );
+        let mut builder = PipelineBuilder::new().with_context_detection(true);
+        let demo_mode = config.integration.demo_mode;
+        if demo_mode {
+            // Keep canned demo answers out of the shared research cache
+            warn!("Demo mode enabled: research_query returns canned, watermarked answers");
+            builder = builder
+                .with_caching(false)
+                .with_research_engine(Arc::new(DemoResearchEngine::new()));
+        } else {
+            builder = builder.with_caching(true);
+        }
+        let mut pipeline = builder.build(classifier.clone(), storage);
+        if !demo_mode {
+            let advisories = Arc::new(AdvisoryService::new(DEFAULT_ADVISORY_CACHE_PATH));
+            if let Err(e) = advisories.load_cached().await {
+                warn!("Ignoring advisory cache: {}", e);
+            }
+            advisories.clone().spawn_refresh();
+            pipeline = pipeline
+                .with_crate_docs(crate_docs.clone())
+                .with_advisories(advisories)
+                .with_citation_validator(Arc::new(CitationValidator::new()));
+        }
+        let pipeline = Arc::new(pipeline);
 
         // Initialize proactive tools
         let proactive_tools = Arc::new(ProactiveTools::new(config.clone()).await?);
@@ -235,6 +318,11 @@ impl FortitudeTools {
         // Initialize quality tools
         let quality_tools = Arc::new(QualityTools::new());
 
+        let learning_store_path = std::env::var("FORTITUDE_LEARNING_STORE_PATH")
+            .unwrap_or_else(|_| DEFAULT_LEARNING_STORE_PATH.to_string());
+        let learning_storage = Arc::new(FileLearningStorage::open(learning_store_path).await?)
+            as Arc<dyn LearningStorageService>;
+
         info!("Fortitude MCP tools initialized successfully");
 
         Ok(Self {
@@ -243,10 +331,22 @@ impl FortitudeTools {
             classifier,
             proactive_tools,
             quality_tools,
+            crate_docs,
+            learning_storage,
             config: Arc::new(config),
         })
     }
 
+    /// Record submitted feedback in the given learning store instead of the
+    /// one named by `FORTITUDE_LEARNING_STORE_PATH`
+    pub fn with_learning_storage(
+        mut self,
+        learning_storage: Arc<dyn LearningStorageService>,
+    ) -> Self {
+        self.learning_storage = learning_storage;
+        self
+    }
+
     /// Get list of available tools
     pub fn list_tools(&self) -> ListToolsResult {
         let mut tools = vec![
@@ -336,6 +436,62 @@ impl FortitudeTools {
                 }).as_object().unwrap().clone()),
                 annotations: None,
             },
+            Tool {
+                name: "crate_docs".into(),
+                description: Some("Fetch item signatures and documentation of a Rust crate from docs.rs".into()),
+                input_schema: Arc::new(serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "crate": {
+                            "type": "string",
+                            "description": "Crate name",
+                            "minLength": 1,
+                            "maxLength": 64
+                        },
+                        "version": {
+                            "type": "string",
+                            "description": "Crate version, latest stable when omitted",
+                            "maxLength": 50
+                        },
+                        "paths": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Item paths such as tokio::sync::Mutex; the crate root when omitted",
+                            "maxItems": 8
+                        }
+                    },
+                    "required": ["crate"]
+                }).as_object().unwrap().clone()),
+                annotations: None,
+            },
+            Tool {
+                name: "submit_feedback".into(),
+                description: Some("Rate how useful a research result was so Fortitude can learn from it".into()),
+                input_schema: Arc::new(serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "result_id": {
+                            "type": "string",
+                            "description": "Result ID (cache_key from research_query metadata)",
+                            "minLength": 1,
+                            "maxLength": 256
+                        },
+                        "rating": {
+                            "type": "integer",
+                            "description": "Usefulness rating from 1 (useless) to 5 (fully answered)",
+                            "minimum": 1,
+                            "maximum": 5
+                        },
+                        "comment": {
+                            "type": "string",
+                            "description": "What was missing or wrong",
+                            "maxLength": 2000
+                        }
+                    },
+                    "required": ["result_id", "rating"]
+                }).as_object().unwrap().clone()),
+                annotations: None,
+            },
         ];
 
         // Add Sprint 009 provider management tools
@@ -362,17 +518,28 @@ impl FortitudeTools {
     }
 
     /// Execute a tool call
-    #[instrument(skip(self, request))]
     pub async fn call_tool(
         &self,
         request: CallToolRequestParam,
+    ) -> Result<CallToolResult, McpError> {
+        self.call_tool_with_observer(request, None).await
+    }
+
+    /// Execute a tool call, telling `observer` when research_query enters a pipeline stage
+    #[instrument(skip(self, request, observer))]
+    pub async fn call_tool_with_observer(
+        &self,
+        request: CallToolRequestParam,
+        observer: Option<StageObserver>,
     ) -> Result<CallToolResult, McpError> {
         info!("Executing tool: {}", request.name);
 
         match request.name.as_ref() {
-            "research_query" => self.handle_research_query(request).await,
+            "research_query" => self.handle_research_query(request, observer).await,
             "classify_query" => self.handle_classify_query(request).await,
             "detect_context" => self.handle_detect_context(request).await,
+            "crate_docs" => self.handle_crate_docs(request).await,
+            "submit_feedback" => self.handle_submit_feedback(request).await,
 
             // Sprint 009 Provider management tools
             "provider_list" => self.handle_provider_list(request).await,
@@ -418,10 +585,11 @@ impl FortitudeTools {
     }
 
     /// Handle research_query tool call
-    #[instrument(skip(self, request))]
+    #[instrument(skip(self, request, observer))]
     async fn handle_research_query(
         &self,
         request: CallToolRequestParam,
+        observer: Option<StageObserver>,
     ) -> Result<CallToolResult, McpError> {
         // Parse and validate request
         let query_request = self.parse_research_query_request(request.arguments.as_ref())?;
@@ -451,16 +619,25 @@ impl FortitudeTools {
             .map_err(|e| McpError::invalid_params(format!("Invalid domain context: {e}"), None))?;
 
         // Execute research pipeline with sanitized query
+        let mut options = ResearchOptions::default();
+        if let Some(observer) = observer {
+            options = options.with_stage_observer(observer);
+        }
         let result = self
             .pipeline
-            .process_query(&sanitized_query, audience_context, domain_context)
+            .process_query_with_options(&sanitized_query, options, audience_context, domain_context)
             .await
             .map_err(|e| {
                 McpError::internal_error(format!("Research pipeline failed: {e}"), None)
             })?;
 
         // Sprint 009: Extract provider and quality information from request
-        let provider_used = query_request.provider.unwrap_or_else(|| "auto".to_string());
+        let demo = self.config.integration.demo_mode;
+        let provider_used = if demo {
+            "demo".to_string()
+        } else {
+            query_request.provider.unwrap_or_else(|| "auto".to_string())
+        };
         let cross_validated = query_request.cross_validate.unwrap_or(false);
         let _quality_threshold = query_request.quality_threshold.unwrap_or(0.8);
 
@@ -485,6 +662,7 @@ impl FortitudeTools {
                 cross_validated,
                 quality_score: actual_quality_score,
                 learning_applied,
+                demo,
             },
         };
 
@@ -621,6 +799,119 @@ impl FortitudeTools {
         })
     }
 
+    /// Handle crate_docs tool call
+    #[instrument(skip(self, request))]
+    async fn handle_crate_docs(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, McpError> {
+        let docs_request = self.parse_crate_docs_request(request.arguments.as_ref())?;
+        validation::validate_input(&docs_request)?;
+        debug!("Looking up crate docs for '{}'", docs_request.crate_name);
+
+        let docs = self
+            .crate_docs
+            .lookup(
+                &docs_request.crate_name,
+                docs_request.version.as_deref(),
+                &docs_request.paths,
+            )
+            .await
+            .map_err(|e| match e {
+                ToolError::InvalidArguments { .. } => McpError::invalid_params(e.to_string(), None),
+                _ => McpError::internal_error(format!("Crate docs lookup failed: {e}"), None),
+            })?;
+
+        let response_json = serde_json::to_string(&docs).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {e}"), None)
+        })?;
+
+        info!(
+            "Crate docs lookup completed: {} {} ({} items)",
+            docs.name,
+            docs.version,
+            docs.items.len()
+        );
+
+        Ok(CallToolResult {
+            content: vec![Content::text(response_json)],
+            is_error: Some(false),
+        })
+    }
+
+    /// Handle submit_feedback tool call
+    #[instrument(skip(self, request))]
+    async fn handle_submit_feedback(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, McpError> {
+        let feedback_request = self.parse_submit_feedback_request(request.arguments.as_ref())?;
+        validation::validate_input(&feedback_request)?;
+        let comment = feedback_request
+            .comment
+            .as_deref()
+            .map(validation::sanitize_string);
+        debug!(
+            "Recording feedback {} for result '{}'",
+            feedback_request.rating, feedback_request.result_id
+        );
+
+        let result = self
+            .pipeline
+            .get_result(&feedback_request.result_id)
+            .await
+            .map_err(|e| McpError::internal_error(format!("Failed to load result: {e}"), None))?
+            .ok_or_else(|| {
+                McpError::invalid_params(
+                    format!("Research result not found: {}", feedback_request.result_id),
+                    None,
+                )
+            })?;
+
+        // Ratings of 1-5 map onto the learning store's 0.0-1.0 scale
+        let score = f64::from(feedback_request.rating - 1) / 4.0;
+        let comment = comment
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty());
+        let feedback = UserFeedback::new(
+            "mcp".to_string(),
+            feedback_request.result_id.clone(),
+            "quality_rating".to_string(),
+            Some(score),
+            comment.clone(),
+        )
+        .with_result_context(&result);
+        self.learning_storage
+            .store_feedback(&feedback)
+            .await
+            .map_err(|e| {
+                McpError::internal_error(format!("Failed to record feedback: {e}"), None)
+            })?;
+
+        let response = SubmitFeedbackResponse {
+            feedback_id: feedback.id.clone(),
+            result_id: feedback.content_id.clone(),
+            query: result.request.original_query.clone(),
+            rating: feedback_request.rating,
+            comment,
+            recorded_at: feedback.timestamp.to_rfc3339(),
+        };
+
+        let response_json = serde_json::to_string(&response).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {e}"), None)
+        })?;
+
+        info!(
+            "Feedback recorded for result '{}': {}/5",
+            response.result_id, response.rating
+        );
+
+        Ok(CallToolResult {
+            content: vec![Content::text(response_json)],
+            is_error: Some(false),
+        })
+    }
+
     /// Parse research_query request from JSON arguments
     fn parse_research_query_request(
         &self,
@@ -660,6 +951,32 @@ impl FortitudeTools {
             .map_err(|e| McpError::invalid_params(format!("Invalid arguments: {e}"), None))
     }
 
+    /// Parse crate_docs request from JSON arguments
+    fn parse_crate_docs_request(
+        &self,
+        arguments: Option<&serde_json::Map<String, Value>>,
+    ) -> Result<CrateDocsRequest, McpError> {
+        let args = arguments
+            .ok_or_else(|| McpError::invalid_params("Missing arguments".to_string(), None))?;
+
+        let args_value = Value::Object(args.clone());
+        serde_json::from_value(args_value)
+            .map_err(|e| McpError::invalid_params(format!("Invalid arguments: {e}"), None))
+    }
+
+    /// Parse submit_feedback request from JSON arguments
+    fn parse_submit_feedback_request(
+        &self,
+        arguments: Option<&serde_json::Map<String, Value>>,
+    ) -> Result<SubmitFeedbackRequest, McpError> {
+        let args = arguments
+            .ok_or_else(|| McpError::invalid_params("Missing arguments".to_string(), None))?;
+
+        let args_value = Value::Object(args.clone());
+        serde_json::from_value(args_value)
+            .map_err(|e| McpError::invalid_params(format!("Invalid arguments: {e}"), None))
+    }
+
     /// Parse audience context from string
     fn parse_audience_context(&self, audience: &str) -> Result<AudienceContext, anyhow::Error> {
         // Simple parsing - in production this might be more sophisticated
@@ -1659,6 +1976,8 @@ mod tests {
         assert!(tool_names.contains(&"research_query"));
         assert!(tool_names.contains(&"classify_query"));
         assert!(tool_names.contains(&"detect_context"));
+        assert!(tool_names.contains(&"crate_docs"));
+        assert!(tool_names.contains(&"submit_feedback"));
 
         // Sprint 009 provider tools
         assert!(tool_names.contains(&"provider_list"));
@@ -1717,6 +2036,31 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_research_query_demo_mode_is_watermarked() {
+        let temp_dir = tempdir().unwrap();
+        std::env::set_var("FORTITUDE_STORAGE_PATH", temp_dir.path().to_str().unwrap());
+        let mut config = ServerConfig::default();
+        config.integration.demo_mode = true;
+        let tools = FortitudeTools::new(config).await.unwrap();
+
+        let arguments = json!({
+            "query": "How to implement async functions in Rust?",
+            "provider": "claude"
+        });
+        let request = CallToolRequestParam {
+            name: "research_query".into(),
+            arguments: arguments.as_object().cloned(),
+        };
+
+        let result = tools.call_tool(request).await.unwrap();
+        let content = result.content[0].as_text().unwrap();
+        let response: ResearchQueryResponse = serde_json::from_str(&content.text).unwrap();
+        assert!(response.result.starts_with(crate::demo::DEMO_WATERMARK));
+        assert!(response.metadata.demo);
+        assert_eq!(response.metadata.provider_used, "demo");
+    }
+
     #[tokio::test]
     async fn test_classify_query_tool() {
         let tools = create_test_tools().await;
@@ -1923,6 +2267,94 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_submit_feedback_tool() {
+        // Keep the storage directory alive so the researched result is stored
+        let temp_dir = tempdir().unwrap();
+        std::env::set_var("FORTITUDE_STORAGE_PATH", temp_dir.path().to_str().unwrap());
+        let learning_store_path = temp_dir.path().join("learning_store.json");
+        let tools = FortitudeTools::new(ServerConfig::default())
+            .await
+            .unwrap()
+            .with_learning_storage(Arc::new(
+                FileLearningStorage::open(&learning_store_path)
+                    .await
+                    .unwrap(),
+            ));
+
+        let research = tools
+            .call_tool(CallToolRequestParam {
+                name: "research_query".into(),
+                arguments: json!({"query": "How do I share state between tokio tasks?"})
+                    .as_object()
+                    .cloned(),
+            })
+            .await
+            .unwrap();
+        let content = research.content[0].as_text().unwrap();
+        let research: ResearchQueryResponse = serde_json::from_str(&content.text).unwrap();
+        let result_id = research.metadata.cache_key;
+
+        let result = tools
+            .call_tool(CallToolRequestParam {
+                name: "submit_feedback".into(),
+                arguments: json!({
+                    "result_id": result_id,
+                    "rating": 2,
+                    "comment": "Missed Arc<Mutex<_>>"
+                })
+                .as_object()
+                .cloned(),
+            })
+            .await
+            .unwrap();
+        let content = result.content[0].as_text().unwrap();
+        let response: SubmitFeedbackResponse = serde_json::from_str(&content.text).unwrap();
+        assert_eq!(response.result_id, result_id);
+        assert_eq!(response.rating, 2);
+        assert_eq!(response.comment.as_deref(), Some("Missed Arc<Mutex<_>>"));
+
+        // Ratings are appended to the learning store rather than replacing
+        // the previous one
+        tools
+            .call_tool(CallToolRequestParam {
+                name: "submit_feedback".into(),
+                arguments: json!({"cache_key": result_id, "rating": 5})
+                    .as_object()
+                    .cloned(),
+            })
+            .await
+            .unwrap();
+        let learning_storage = FileLearningStorage::open(&learning_store_path)
+            .await
+            .unwrap();
+        let mut stored = learning_storage
+            .get_feedback_for_content(&result_id)
+            .await
+            .unwrap();
+        stored.sort_by_key(|feedback| feedback.timestamp);
+        assert_eq!(stored.len(), 2);
+        assert_eq!(stored[0].id, response.feedback_id);
+        assert_eq!(stored[0].score, Some(0.25));
+        assert_eq!(
+            stored[0].text_feedback.as_deref(),
+            Some("Missed Arc<Mutex<_>>")
+        );
+        assert_eq!(stored[1].score, Some(1.0));
+
+        // Out-of-range ratings and unknown results are rejected
+        for arguments in [
+            json!({"result_id": result_id, "rating": 6}),
+            json!({"cache_key": "missing-result", "rating": 4}),
+        ] {
+            let request = CallToolRequestParam {
+                name: "submit_feedback".into(),
+                arguments: arguments.as_object().cloned(),
+            };
+            assert!(tools.call_tool(request).await.is_err());
+        }
+    }
+
     #[tokio::test]
     async fn test_monitoring_metrics_tool() {
         let tools = create_test_tools().await;
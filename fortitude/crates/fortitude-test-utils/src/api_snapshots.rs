@@ -0,0 +1,640 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Snapshot harness for API server responses
+// Runs the API server against a mock research engine and seeded storage and canonicalizes responses
+
+use crate::fixtures::{
+    sample_detail, sample_evidence, sample_research_metadata, sample_research_result,
+    sample_research_results,
+};
+use crate::helpers::setup_temp_storage;
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Method, Request};
+use fortitude_api_server::middleware::auth::Permission;
+use fortitude_api_server::routes::{cache::CacheState, research::ResearchState};
+use fortitude_api_server::{ApiServer, ApiServerConfig};
+use fortitude_core::{
+    BasicClassifier, FileStorage, PipelineBuilder, ResearchEngine, ResearchEngineError,
+    VectorDocument,
+};
+use fortitude_types::{
+    ClassificationConfig, ClassifiedRequest, ResearchResult, ResearchType, Storage,
+};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+/// Placeholder substituted for every UUID in a canonical response
+pub const CANONICAL_UUID: &str = "00000000-0000-0000-0000-000000000000";
+
+/// Placeholder substituted for content hashes (unpadded 64-bit hex)
+pub const CANONICAL_HASH: &str = "0000000000000000";
+
+/// Cache key of the seeded follow-up result
+const FOLLOW_UP_KEY: &str = "snapshot-follow-up";
+
+/// JWT signing secret used by the snapshot server
+const SNAPSHOT_JWT_SECRET: &str = "snapshot_secret_key_at_least_32_characters";
+
+/// Placeholder substituted for every RFC 3339 timestamp in a canonical response
+pub const CANONICAL_TIMESTAMP: &str = "2025-01-01T00:00:00Z";
+
+/// Object keys whose numeric values depend on timing or host load
+///
+/// A key is volatile when it ends with any of these suffixes.
+const VOLATILE_KEY_SUFFIXES: &[&str] = &[
+    "_ms",
+    "_seconds",
+    "_secs",
+    "uptime",
+    "latency",
+    "duration",
+    "cpu_usage",
+    "memory_usage",
+    "_bytes",
+    "_per_second",
+    "_per_minute",
+    "age",
+    "_histogram",
+];
+
+/// Object keys whose string values are derived from volatile content
+const VOLATILE_STRING_KEYS: &[&str] = &["content_hash"];
+
+/// Placeholder substituted for wall-clock hour ranges such as `peak_hour`
+pub const CANONICAL_HOUR_RANGE: &str = "00:00-01:00";
+
+/// Object keys holding an `HH:00-HH:00` range derived from access times
+const HOUR_RANGE_KEYS: &[&str] = &["peak_hour"];
+
+/// Object keys holding unordered keyword sets
+const UNORDERED_KEYS: &[&str] = &["matched_keywords"];
+
+/// Decimal places kept for floating point values
+///
+/// Classifier scores are summed in hash-map order, so the last digits vary.
+const FLOAT_PRECISION: i32 = 9;
+
+/// Research engine returning fixture results without calling any provider
+#[derive(Debug, Default, Clone)]
+pub struct MockResearchEngine;
+
+impl MockResearchEngine {
+    fn result_for(request: &ClassifiedRequest) -> ResearchResult {
+        ResearchResult::new(
+            request.clone(),
+            format!("Mock answer for: {}", request.original_query),
+            vec![sample_evidence()],
+            vec![sample_detail()],
+            sample_research_metadata(),
+        )
+    }
+}
+
+#[async_trait]
+impl ResearchEngine for MockResearchEngine {
+    async fn generate_research(
+        &self,
+        request: &ClassifiedRequest,
+    ) -> Result<ResearchResult, ResearchEngineError> {
+        Ok(Self::result_for(request))
+    }
+
+    async fn generate_research_with_context(
+        &self,
+        request: &ClassifiedRequest,
+    ) -> Result<ResearchResult, ResearchEngineError> {
+        Ok(Self::result_for(request))
+    }
+
+    async fn discover_context(
+        &self,
+        _request: &ClassifiedRequest,
+    ) -> Result<Vec<VectorDocument>, ResearchEngineError> {
+        Ok(vec![])
+    }
+
+    async fn health_check(&self) -> Result<(), ResearchEngineError> {
+        Ok(())
+    }
+
+    fn estimate_processing_time(&self, _request: &ClassifiedRequest) -> Duration {
+        Duration::from_millis(10)
+    }
+}
+
+/// A single request exercised by the snapshot suite
+#[derive(Debug, Clone)]
+pub struct SnapshotRequest {
+    /// Snapshot and fixture file name
+    pub name: &'static str,
+    pub method: Method,
+    pub path: String,
+    pub body: Option<Value>,
+}
+
+impl SnapshotRequest {
+    fn get(name: &'static str, path: impl Into<String>) -> Self {
+        Self {
+            name,
+            method: Method::GET,
+            path: path.into(),
+            body: None,
+        }
+    }
+
+    fn with_body(name: &'static str, method: Method, path: &str, body: Value) -> Self {
+        Self {
+            name,
+            method,
+            path: path.to_string(),
+            body: Some(body),
+        }
+    }
+}
+
+/// Canonical response captured for a snapshot
+#[derive(Debug, Clone, Serialize)]
+pub struct SnapshotResponse {
+    pub method: String,
+    pub path: String,
+    pub status: u16,
+    pub body: Value,
+}
+
+/// API server wired to mock providers and a temporary reference library
+pub struct SnapshotServer {
+    pub server: ApiServer,
+    token: String,
+    cache_keys: Vec<String>,
+    replacements: Vec<(String, String)>,
+    _temp_dir: TempDir,
+}
+
+impl SnapshotServer {
+    /// Start the server with seeded cache entries and an admin token
+    pub async fn start() -> Self {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let storage_config = setup_temp_storage(&temp_dir).await;
+        let file_storage = Arc::new(
+            FileStorage::new(storage_config)
+                .await
+                .expect("Failed to initialize file storage"),
+        );
+
+        let mut cache_keys = Vec::new();
+        for result in sample_research_results() {
+            let key = file_storage
+                .store(&result)
+                .await
+                .expect("Failed to seed cache entry");
+            cache_keys.push(key);
+        }
+
+        // A follow-up of the first seeded result, for the lineage endpoint
+        let mut follow_up = sample_research_result(
+            "How do I add retries to that async test?",
+            ResearchType::Implementation,
+        );
+        follow_up.metadata.cache_key = FOLLOW_UP_KEY.to_string();
+        let follow_up = follow_up.with_parent(cache_keys[0].clone());
+        let key = file_storage
+            .store(&follow_up)
+            .await
+            .expect("Failed to seed follow-up entry");
+        cache_keys.push(key);
+
+        let mut config = ApiServerConfig::default();
+        config.auth.enabled = true;
+        config.auth.jwt_secret = SNAPSHOT_JWT_SECRET.to_string();
+
+        // Same low threshold as the classifier unit tests so sample queries classify
+        let classification_config = ClassificationConfig {
+            default_threshold: 0.1,
+            ..Default::default()
+        };
+
+        let pipeline = PipelineBuilder::new()
+            .with_caching(true)
+            .with_context_detection(true)
+            .with_advanced_classification(false)
+            .with_research_engine(Arc::new(MockResearchEngine))
+            .build(
+                Arc::new(BasicClassifier::new(classification_config)),
+                file_storage.clone(),
+            );
+
+        let cache_state = CacheState {
+            storage: file_storage,
+            config: Arc::new(config.clone()),
+        };
+
+        let server = ApiServer::builder(config)
+            .with_research_state(ResearchState::from_pipeline(Arc::new(pipeline)))
+            .with_cache_state(cache_state)
+            .build()
+            .await
+            .expect("Failed to create API server");
+
+        let mut replacements = vec![(
+            temp_dir.path().display().to_string(),
+            "/reference_library".to_string(),
+        )];
+        replacements.extend(
+            cache_keys
+                .iter()
+                .enumerate()
+                .map(|(index, key)| (key.clone(), format!("cache-key-{index}"))),
+        );
+
+        let token = server
+            .auth_manager
+            .as_ref()
+            .expect("Authentication should be enabled")
+            .generate_token("snapshot_user", vec![Permission::Admin])
+            .await
+            .expect("Failed to generate token");
+
+        Self {
+            server,
+            token,
+            cache_keys,
+            replacements,
+            _temp_dir: temp_dir,
+        }
+    }
+
+    /// Cache keys of the seeded research results, in insertion order
+    pub fn cache_keys(&self) -> &[String] {
+        &self.cache_keys
+    }
+
+    /// Requests covering every endpoint, ordered so reads follow writes
+    pub fn requests(&self) -> Vec<SnapshotRequest> {
+        let cache_key = self.cache_keys.first().cloned().unwrap_or_default();
+
+        vec![
+            SnapshotRequest::get("health", "/health"),
+            SnapshotRequest::get("api_versions", "/api/versions"),
+            SnapshotRequest::get("health_protected", "/api/v1/health/protected"),
+            SnapshotRequest::with_body(
+                "research_submit",
+                Method::POST,
+                "/api/v1/research",
+                json!({
+                    "query": "How to implement async functions in Rust?",
+                    "priority": "medium",
+                    "audience_context": {
+                        "level": "intermediate",
+                        "domain": "rust",
+                        "format": "markdown"
+                    }
+                }),
+            ),
+            SnapshotRequest::with_body(
+                "research_dry_run",
+                Method::POST,
+                "/api/v1/research",
+                json!({
+                    "query": "Should I choose React or Vue?",
+                    "dry_run": true
+                }),
+            ),
+            SnapshotRequest::with_body(
+                "research_estimate",
+                Method::POST,
+                "/api/v1/research/estimate",
+                json!({ "query": "What is the definition of async programming?" }),
+            ),
+            SnapshotRequest::with_body(
+                "research_follow_up",
+                Method::POST,
+                "/api/v1/research",
+                json!({
+                    "query": "How to implement retries in that test?",
+                    "parent_id": cache_key
+                }),
+            ),
+            SnapshotRequest::with_body(
+                "research_follow_up_missing_parent",
+                Method::POST,
+                "/api/v1/research",
+                json!({
+                    "query": "How to implement retries in that test?",
+                    "parent_id": "missing-parent"
+                }),
+            ),
+            SnapshotRequest::get(
+                "research_lineage",
+                format!("/api/v1/research/{FOLLOW_UP_KEY}/lineage"),
+            ),
+            SnapshotRequest::get(
+                "research_lineage_not_found",
+                "/api/v1/research/missing-result/lineage",
+            ),
+            SnapshotRequest::get("research_list", "/api/v1/research?limit=5"),
+            SnapshotRequest::get(
+                "research_not_found",
+                format!("/api/v1/research/{CANONICAL_UUID}"),
+            ),
+            SnapshotRequest::with_body(
+                "classify",
+                Method::POST,
+                "/api/v1/classify",
+                json!({
+                    "content": "How do I fix a borrow checker error in my iterator?",
+                    "options": { "confidence_threshold": 0.1 }
+                }),
+            ),
+            SnapshotRequest::get("classify_list", "/api/v1/classify?limit=5"),
+            SnapshotRequest::get(
+                "classify_not_found",
+                format!("/api/v1/classify/{CANONICAL_UUID}"),
+            ),
+            SnapshotRequest::get("classify_types", "/api/v1/classify/types"),
+            SnapshotRequest::get("cache_stats", "/api/v1/cache/stats"),
+            SnapshotRequest::get("cache_stats_export", "/api/v1/cache/stats/export"),
+            SnapshotRequest::get("cache_stats_type", "/api/v1/cache/stats/implementation"),
+            SnapshotRequest::get("cache_search", "/api/v1/cache/search?query=rust&limit=5"),
+            SnapshotRequest::get("cache_item", format!("/api/v1/cache/{cache_key}")),
+            SnapshotRequest::with_body(
+                "cache_invalidate_dry_run",
+                Method::POST,
+                "/api/v1/cache/invalidate",
+                json!({ "research_type": "learning", "dry_run": true }),
+            ),
+            SnapshotRequest::with_body(
+                "cache_cleanup",
+                Method::POST,
+                "/api/v1/cache/cleanup",
+                json!({}),
+            ),
+            SnapshotRequest {
+                name: "cache_delete",
+                method: Method::DELETE,
+                path: format!("/api/v1/cache/{cache_key}"),
+                body: None,
+            },
+            SnapshotRequest::get("proactive_status", "/api/v1/proactive/status"),
+            SnapshotRequest::get("proactive_config", "/api/v1/proactive/config"),
+            SnapshotRequest::get("proactive_tasks", "/api/v1/proactive/tasks?limit=5"),
+            SnapshotRequest::get(
+                "proactive_notifications",
+                "/api/v1/proactive/notifications?limit=5",
+            ),
+            SnapshotRequest::get("learning_dashboard", "/api/v1/learning/dashboard"),
+            SnapshotRequest::get("learning_metrics", "/api/v1/learning/metrics"),
+            SnapshotRequest::get("learning_health", "/api/v1/learning/health"),
+            SnapshotRequest::get("learning_performance", "/api/v1/learning/performance"),
+            SnapshotRequest::get("monitoring_dashboard", "/api/v1/monitoring/dashboard"),
+            SnapshotRequest::get("monitoring_metrics", "/api/v1/monitoring/metrics"),
+            SnapshotRequest::get("monitoring_health", "/api/v1/monitoring/health"),
+            SnapshotRequest::get("monitoring_alerts", "/api/v1/monitoring/alerts"),
+            SnapshotRequest::get("monitoring_performance", "/api/v1/monitoring/performance"),
+            SnapshotRequest::get("admin_maintenance", "/api/v1/admin/maintenance"),
+            SnapshotRequest::get("not_found", "/api/v1/does-not-exist"),
+        ]
+    }
+
+    /// Send a request and return its canonicalized response
+    pub async fn call(&self, request: &SnapshotRequest) -> SnapshotResponse {
+        let mut builder = Request::builder()
+            .method(request.method.clone())
+            .uri(&request.path)
+            .header("authorization", format!("Bearer {}", self.token));
+        let body = match &request.body {
+            Some(body) => {
+                builder = builder.header("content-type", "application/json");
+                Body::from(body.to_string())
+            }
+            None => Body::empty(),
+        };
+
+        let response = self
+            .server
+            .app
+            .clone()
+            .oneshot(builder.body(body).expect("Invalid snapshot request"))
+            .await
+            .expect("Snapshot request failed");
+
+        let status = response.status().as_u16();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("Failed to read response body");
+        let body = if bytes.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&bytes).unwrap_or_else(|_| {
+                Value::String(canonicalize_csv(&String::from_utf8_lossy(&bytes)))
+            })
+        };
+
+        SnapshotResponse {
+            method: request.method.to_string(),
+            path: canonicalize_str(&request.path, &self.replacements),
+            status,
+            body: canonicalize(body, &self.replacements),
+        }
+    }
+}
+
+/// Replace run-dependent values with stable placeholders
+///
+/// UUIDs and timestamps are replaced in place so the payload still matches
+/// the response schema, content hashes and hash-derived ids are masked,
+/// numbers (including numeric strings such as timing tags and histogram
+/// counts) under timing or resource-usage keys are zeroed, and each
+/// `(from, to)` pair in `replacements` is substituted literally (used for
+/// seeded cache keys and the temporary storage path).
+pub fn canonicalize(value: Value, replacements: &[(String, String)]) -> Value {
+    match value {
+        Value::String(s) => Value::String(canonicalize_str(&s, replacements)),
+        Value::Number(n) => match n.as_f64() {
+            Some(f) if !n.is_i64() && !n.is_u64() => {
+                let scale = 10f64.powi(FLOAT_PRECISION);
+                json!((f * scale).round() / scale)
+            }
+            _ => Value::Number(n),
+        },
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| canonicalize(item, replacements))
+                .collect(),
+        ),
+        Value::Object(map) => {
+            let mut canonical = Map::new();
+            for (key, value) in map {
+                let key = canonicalize_str(&key, replacements);
+                let value = match value {
+                    Value::Number(_) if is_volatile_key(&key) => json!(0),
+                    Value::String(s) if is_volatile_key(&key) && s.parse::<f64>().is_ok() => {
+                        Value::String("0".to_string())
+                    }
+                    Value::Array(items) if is_volatile_key(&key) => {
+                        zero_numbers(Value::Array(items))
+                    }
+                    Value::String(_) if VOLATILE_STRING_KEYS.contains(&key.as_str()) => {
+                        Value::String(CANONICAL_HASH.to_string())
+                    }
+                    Value::String(s) if HOUR_RANGE_KEYS.contains(&key.as_str()) && s != "N/A" => {
+                        Value::String(CANONICAL_HOUR_RANGE.to_string())
+                    }
+                    Value::Array(mut items) if UNORDERED_KEYS.contains(&key.as_str()) => {
+                        items.sort_by_key(|item| item.to_string());
+                        canonicalize(Value::Array(items), replacements)
+                    }
+                    other => canonicalize(other, replacements),
+                };
+                canonical.insert(key, value);
+            }
+            Value::Object(canonical)
+        }
+        other => other,
+    }
+}
+
+fn canonicalize_str(s: &str, replacements: &[(String, String)]) -> String {
+    if uuid::Uuid::parse_str(s).is_ok() {
+        return CANONICAL_UUID.to_string();
+    }
+    if chrono::DateTime::parse_from_rfc3339(s).is_ok() {
+        return CANONICAL_TIMESTAMP.to_string();
+    }
+
+    // Hash-derived identifiers such as `classification-d922b0e68d5ce64`
+    if let Some((prefix, suffix)) = s.rsplit_once('-') {
+        if suffix.len() >= 12 && suffix.chars().all(|c| c.is_ascii_hexdigit()) {
+            return format!("{prefix}-{CANONICAL_HASH}");
+        }
+    }
+
+    let mut canonical = s.to_string();
+    for (from, to) in replacements {
+        if !from.is_empty() {
+            canonical = canonical.replace(from.as_str(), to);
+        }
+    }
+    canonical
+}
+
+/// Zero volatile columns of a CSV body, identified by their header
+///
+/// Text that does not look like CSV is returned unchanged.
+fn canonicalize_csv(text: &str) -> String {
+    let mut lines = text.lines();
+    let Some(header) = lines.next() else {
+        return text.to_string();
+    };
+    let volatile: Vec<bool> = header.split(',').map(is_volatile_key).collect();
+    if volatile.len() < 2 {
+        return text.to_string();
+    }
+
+    let mut canonical = vec![header.to_string()];
+    for line in lines {
+        let fields: Vec<&str> = line
+            .split(',')
+            .zip(&volatile)
+            .map(|(field, volatile)| if *volatile { "0" } else { field })
+            .collect();
+        canonical.push(fields.join(","));
+    }
+    canonical.join("\n") + if text.ends_with('\n') { "\n" } else { "" }
+}
+
+/// Zero every number nested in `value`
+fn zero_numbers(value: Value) -> Value {
+    match value {
+        Value::Number(_) => json!(0),
+        Value::Array(items) => Value::Array(items.into_iter().map(zero_numbers).collect()),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (key, zero_numbers(value)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+fn is_volatile_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    VOLATILE_KEY_SUFFIXES
+        .iter()
+        .any(|suffix| key.ends_with(suffix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonicalize_replaces_volatile_values() {
+        let replacements = vec![("abc123".to_string(), "cache-key-0".to_string())];
+        let value = json!({
+            "request_id": "6f1c3c1e-8d2b-4a4e-9f0e-2b8f1b7d9a10",
+            "timestamp": "2025-07-12T10:15:30.123456Z",
+            "processing_time_ms": 17,
+            "path": "/api/v1/cache/abc123",
+            "id": "classification-d922b0e68d5ce64",
+            "total_entries": 3,
+            "matched_keywords": ["fix", "error"],
+            "confidence": 0.11363636363636365,
+            "items": [{ "uptime_seconds": 42.5 }],
+            "tags": { "classification_ms": "0.412", "language": "rust" },
+            "recent_operations": { "peak_hour": "14:00-15:00" },
+            "idle": { "peak_hour": "N/A" },
+            "latency_histogram": [{ "le_ms": 1.0, "count": 3 }, { "le_ms": null, "count": 4 }]
+        });
+
+        let canonical = canonicalize(value, &replacements);
+
+        assert_eq!(canonical["request_id"], CANONICAL_UUID);
+        assert_eq!(canonical["timestamp"], CANONICAL_TIMESTAMP);
+        assert_eq!(canonical["processing_time_ms"], 0);
+        assert_eq!(canonical["path"], "/api/v1/cache/cache-key-0");
+        assert_eq!(canonical["total_entries"], 3);
+        assert_eq!(canonical["id"], "classification-0000000000000000");
+        assert_eq!(canonical["matched_keywords"], json!(["error", "fix"]));
+        assert_eq!(canonical["confidence"], 0.113636364);
+        assert_eq!(canonical["items"][0]["uptime_seconds"], 0);
+        assert_eq!(canonical["tags"]["classification_ms"], "0");
+        assert_eq!(canonical["tags"]["language"], "rust");
+        assert_eq!(
+            canonical["recent_operations"]["peak_hour"],
+            CANONICAL_HOUR_RANGE
+        );
+        assert_eq!(canonical["idle"]["peak_hour"], "N/A");
+        assert_eq!(
+            canonical["latency_histogram"],
+            json!([{ "le_ms": 0, "count": 0 }, { "le_ms": null, "count": 0 }])
+        );
+    }
+
+    #[test]
+    fn test_canonicalize_csv_zeroes_volatile_columns() {
+        let csv = "research_type,entries,size_bytes\nValidation,1,1565\n";
+        assert_eq!(
+            canonicalize_csv(csv),
+            "research_type,entries,size_bytes\nValidation,1,0\n"
+        );
+        assert_eq!(canonicalize_csv("plain text"), "plain text");
+    }
+}
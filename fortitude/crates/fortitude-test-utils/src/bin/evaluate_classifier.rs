@@ -0,0 +1,83 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Reports classifier accuracy against the labeled fixture queries
+// Usage: evaluate-classifier [MODEL_PATH] (defaults to .fortitude/classifier_model.json)
+
+use fortitude_core::classification::{
+    evaluate_classifier, BasicClassifier, ClassificationEvaluation, TrainableClassifier,
+    DEFAULT_CLASSIFIER_MODEL_PATH,
+};
+use fortitude_test_utils::labeled_classification_queries;
+use fortitude_types::ClassificationConfig;
+use std::path::PathBuf;
+
+fn print_evaluation(name: &str, evaluation: &ClassificationEvaluation) {
+    println!(
+        "{name}: {}/{} correct ({:.1}%)",
+        evaluation.correct,
+        evaluation.total,
+        evaluation.accuracy() * 100.0
+    );
+    for entry in &evaluation.per_type {
+        println!(
+            "  {:<16} {}/{}",
+            entry.research_type.to_string(),
+            entry.correct,
+            entry.total
+        );
+    }
+    for miss in &evaluation.misclassified {
+        let predicted = miss
+            .predicted
+            .as_ref()
+            .map_or_else(|| "none".to_string(), ToString::to_string);
+        println!(
+            "  ✗ \"{}\": expected {}, got {}",
+            miss.query, miss.expected, predicted
+        );
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let model_path = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_CLASSIFIER_MODEL_PATH));
+    let labeled = labeled_classification_queries();
+    // No threshold, so accuracy measures the top prediction of each classifier
+    let config = ClassificationConfig {
+        default_threshold: 0.0,
+        ..Default::default()
+    };
+
+    let rules = BasicClassifier::new(config.clone());
+    print_evaluation("Keyword rules", &evaluate_classifier(&rules, &labeled));
+
+    if model_path.exists() {
+        let trained = TrainableClassifier::load(config, &model_path)?;
+        println!();
+        print_evaluation(
+            &format!(
+                "Trained model ({} examples)",
+                trained.model().trained_examples
+            ),
+            &evaluate_classifier(&trained, &labeled),
+        );
+    } else {
+        println!();
+        println!("No trained model at {}", model_path.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,44 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Generates canonical API response fixtures for client authors
+// Usage: generate-api-fixtures [OUTPUT_DIR] (defaults to ./api-fixtures)
+
+use fortitude_test_utils::api_snapshots::SnapshotServer;
+use std::path::PathBuf;
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    let output_dir = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("api-fixtures"));
+    std::fs::create_dir_all(&output_dir)?;
+
+    let server = SnapshotServer::start().await;
+    let requests = server.requests();
+    for request in &requests {
+        let response = server.call(request).await;
+        let path = output_dir.join(format!("{}.json", request.name));
+        let json = serde_json::to_string_pretty(&response)?;
+        std::fs::write(&path, json + "\n")?;
+    }
+
+    println!(
+        "Wrote {} fixtures to {}",
+        requests.len(),
+        output_dir.display()
+    );
+    Ok(())
+}
@@ -384,6 +384,7 @@ pub fn create_advanced_classification_config() -> ClassificationConfig {
         fallback_type: ResearchType::Learning,
         enable_fuzzy_matching: true,
         max_candidates: 5,
+        ..Default::default()
     }
 }
 
@@ -394,6 +395,7 @@ pub fn create_low_threshold_config() -> ClassificationConfig {
         fallback_type: ResearchType::Learning,
         enable_fuzzy_matching: false,
         max_candidates: 10,
+        ..Default::default()
     }
 }
 
@@ -479,6 +481,22 @@ impl TestQueries {
     }
 }
 
+/// Test queries labeled with their expected research type, for measuring
+/// classifier accuracy
+pub fn labeled_classification_queries() -> Vec<(String, ResearchType)> {
+    let mut by_type = TestQueries::all_by_type();
+    ResearchType::all()
+        .into_iter()
+        .flat_map(|research_type| {
+            by_type
+                .remove(&research_type)
+                .unwrap_or_default()
+                .into_iter()
+                .map(move |query| (query.to_string(), research_type.clone()))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -514,6 +532,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_labeled_classification_queries_cover_every_type() {
+        let labeled = labeled_classification_queries();
+        assert_eq!(labeled.len(), 25);
+        for research_type in ResearchType::all() {
+            assert_eq!(
+                labeled.iter().filter(|(_, t)| *t == research_type).count(),
+                5
+            );
+        }
+    }
+
     #[test]
     fn test_mixed_complexity_queries() {
         let queries = TestQueries::mixed_complexity();
@@ -162,6 +162,7 @@ pub fn sample_classification_config() -> ClassificationConfig {
         fallback_type: ResearchType::Learning,
         enable_fuzzy_matching: false,
         max_candidates: 5,
+        ..Default::default()
     }
 }
 
@@ -247,6 +247,7 @@ impl Storage for MockStorage {
             by_research_type: HashMap::new(),
             analytics: fortitude_types::CacheAnalytics::default(),
             newest_entry: None,
+            dedup: fortitude_types::DedupStats::default(),
         })
     }
 
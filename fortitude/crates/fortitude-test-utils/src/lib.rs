@@ -16,6 +16,7 @@
 //! This crate contains shared testing utilities, fixtures, and helpers
 //! used across the Fortitude test suite.
 
+pub mod api_snapshots;
 pub mod classification_fixtures;
 pub mod fixtures;
 pub mod helpers;
@@ -0,0 +1,41 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Snapshot tests for canonical API server responses
+// Run with INSTA_UPDATE=always (or `cargo insta review`) after intentional API changes
+
+use fortitude_test_utils::api_snapshots::SnapshotServer;
+
+#[tokio::test]
+async fn test_api_response_snapshots() {
+    let server = SnapshotServer::start().await;
+
+    for request in server.requests() {
+        let response = server.call(&request).await;
+        insta::assert_json_snapshot!(request.name, response);
+    }
+}
+
+#[tokio::test]
+async fn test_api_responses_are_deterministic() {
+    let first = SnapshotServer::start().await;
+    let second = SnapshotServer::start().await;
+
+    for request in first.requests() {
+        let a = first.call(&request).await;
+        let b = second.call(&request).await;
+        assert_eq!(a.status, b.status, "status differs for {}", request.name);
+        assert_eq!(a.body, b.body, "body differs for {}", request.name);
+    }
+}
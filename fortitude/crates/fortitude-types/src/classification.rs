@@ -16,6 +16,7 @@
 use crate::research::ResearchType;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Classification rule with keywords and weights
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -77,6 +78,23 @@ impl ClassificationRule {
     }
 }
 
+/// Research type assigned to a query, with the classifier's confidence in it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassificationLabel {
+    pub research_type: ResearchType,
+    /// Confidence score (0.0-1.0)
+    pub confidence: f64,
+}
+
+impl ClassificationLabel {
+    pub fn new(research_type: ResearchType, confidence: f64) -> Self {
+        Self {
+            research_type,
+            confidence,
+        }
+    }
+}
+
 /// Classification result with confidence and matched keywords
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ClassificationResult {
@@ -90,6 +108,9 @@ pub struct ClassificationResult {
     pub rule_priority: u32,
     /// All candidate results considered
     pub candidates: Vec<ClassificationCandidate>,
+    /// Research types assigned to the query, primary type first
+    #[serde(default)]
+    pub labels: Vec<ClassificationLabel>,
 }
 
 impl ClassificationResult {
@@ -102,6 +123,7 @@ impl ClassificationResult {
         candidates: Vec<ClassificationCandidate>,
     ) -> Self {
         Self {
+            labels: vec![ClassificationLabel::new(research_type.clone(), confidence)],
             research_type,
             confidence,
             matched_keywords,
@@ -110,6 +132,41 @@ impl ClassificationResult {
         }
     }
 
+    /// Also label the query with other candidates that clear the threshold
+    ///
+    /// Candidates at or above `config.default_threshold` are added by
+    /// descending confidence until the result carries `config.max_labels`
+    /// research types.
+    pub fn with_secondary_labels(mut self, config: &ClassificationConfig) -> Self {
+        let mut secondary: Vec<&ClassificationCandidate> = self
+            .candidates
+            .iter()
+            .filter(|c| {
+                c.research_type != self.research_type && c.confidence >= config.default_threshold
+            })
+            .collect();
+        secondary.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        let room = config.max_labels.saturating_sub(self.labels.len());
+        let added: Vec<ClassificationLabel> = secondary
+            .into_iter()
+            .filter(|c| {
+                !self
+                    .labels
+                    .iter()
+                    .any(|l| l.research_type == c.research_type)
+            })
+            .take(room)
+            .map(|c| ClassificationLabel::new(c.research_type.clone(), c.confidence))
+            .collect();
+        self.labels.extend(added);
+        self
+    }
+
+    /// Labels other than the primary research type
+    pub fn secondary_labels(&self) -> &[ClassificationLabel] {
+        self.labels.get(1..).unwrap_or_default()
+    }
+
     /// Check if confidence meets threshold
     pub fn meets_threshold(&self, threshold: f64) -> bool {
         self.confidence >= threshold
@@ -157,6 +214,19 @@ pub struct ClassificationConfig {
     pub enable_fuzzy_matching: bool,
     /// Maximum number of candidates to consider
     pub max_candidates: usize,
+    /// Classifier implementation to use
+    #[serde(default)]
+    pub classifier: ClassifierKind,
+    /// Trained model file used by [`ClassifierKind::Trainable`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_path: Option<PathBuf>,
+    /// Most research types assigned to one query; 1 keeps a single label
+    #[serde(default = "default_max_labels")]
+    pub max_labels: usize,
+}
+
+fn default_max_labels() -> usize {
+    3
 }
 
 impl Default for ClassificationConfig {
@@ -166,6 +236,43 @@ impl Default for ClassificationConfig {
             fallback_type: ResearchType::Learning,
             enable_fuzzy_matching: false,
             max_candidates: 10,
+            classifier: ClassifierKind::default(),
+            model_path: None,
+            max_labels: default_max_labels(),
+        }
+    }
+}
+
+/// Classifier implementation selected by a [`ClassificationConfig`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClassifierKind {
+    /// Weighted keyword rules
+    #[default]
+    Rules,
+    /// Linear model trained from classification corrections and feedback
+    Trainable,
+}
+
+impl std::fmt::Display for ClassifierKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rules => write!(f, "rules"),
+            Self::Trainable => write!(f, "trainable"),
+        }
+    }
+}
+
+impl std::str::FromStr for ClassifierKind {
+    type Err = crate::error::ClassificationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "rules" => Ok(Self::Rules),
+            "trainable" => Ok(Self::Trainable),
+            _ => Err(crate::error::ClassificationError::InvalidInput(format!(
+                "Unknown classifier '{s}'"
+            ))),
         }
     }
 }
@@ -248,6 +355,50 @@ mod tests {
         assert_eq!(config.fallback_type, ResearchType::Learning);
         assert!(!config.enable_fuzzy_matching);
         assert_eq!(config.max_candidates, 10);
+        assert_eq!(config.classifier, ClassifierKind::Rules);
+        assert!(config.model_path.is_none());
+        assert_eq!(config.max_labels, 3);
+    }
+
+    #[test]
+    fn test_secondary_labels_clear_threshold() {
+        let candidate = |research_type, confidence| {
+            ClassificationCandidate::new(research_type, confidence, vec![], 1)
+        };
+        let result = ClassificationResult::new(
+            ResearchType::Implementation,
+            0.9,
+            vec![],
+            1,
+            vec![
+                candidate(ResearchType::Implementation, 0.9),
+                candidate(ResearchType::Learning, 0.65),
+                candidate(ResearchType::Troubleshooting, 0.8),
+                candidate(ResearchType::Decision, 0.3),
+            ],
+        );
+
+        let config = ClassificationConfig::default();
+        let labeled = result.clone().with_secondary_labels(&config);
+        let types: Vec<&ResearchType> = labeled.labels.iter().map(|l| &l.research_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                &ResearchType::Implementation,
+                &ResearchType::Troubleshooting,
+                &ResearchType::Learning
+            ]
+        );
+        assert_eq!(labeled.secondary_labels()[0].confidence, 0.8);
+
+        let single = ClassificationConfig {
+            max_labels: 1,
+            ..Default::default()
+        };
+        assert!(result
+            .with_secondary_labels(&single)
+            .secondary_labels()
+            .is_empty());
     }
 
     #[test]
@@ -99,6 +99,9 @@ pub enum StorageError {
     #[error("Index error: {0}")]
     Index(String),
 
+    #[error("Invalid search query: {0}")]
+    InvalidQuery(String),
+
     #[error("Serialization error: {0}")]
     Serialization(String),
 
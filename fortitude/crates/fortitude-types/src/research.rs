@@ -160,6 +160,48 @@ pub struct ClassifiedRequest {
     /// Enhanced classification result (optional for backward compatibility)
     pub enhanced_classification:
         Option<Box<crate::classification_result::EnhancedClassificationResult>>,
+    /// Compact summary of code attached to the query, injected into the prompt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_context: Option<String>,
+    /// Summary of the project the query is asked about, injected into the prompt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_context: Option<String>,
+    /// Earlier turns of a conversation this query continues, injected into the prompt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conversation_context: Option<String>,
+    /// Known security advisories for crates in the query, injected into the prompt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub advisory_context: Option<String>,
+    /// Limits from the prompt-budget profile selected for this request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt_budget: Option<PromptBudget>,
+    /// Provider, and optionally model, the caller asked to research with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_selection: Option<ProviderSelection>,
+    /// Research types the query also fits, besides `research_type`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub secondary_labels: Vec<crate::classification::ClassificationLabel>,
+}
+
+/// Per-request limits that research engines honour when building prompts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromptBudget {
+    /// Name of the profile the limits came from
+    pub profile: String,
+    /// Maximum number of context documents added to the prompt
+    pub max_context_docs: usize,
+    /// Maximum number of tokens requested for the answer
+    pub max_answer_tokens: u32,
+}
+
+/// Provider and model requested for a single research request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProviderSelection {
+    /// Provider name (openai, claude, gemini)
+    pub provider: String,
+    /// Model identifier; the provider's configured model when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
 }
 
 impl ClassifiedRequest {
@@ -182,6 +224,13 @@ impl ClassifiedRequest {
             matched_keywords,
             created_at: Utc::now(),
             enhanced_classification: None,
+            code_context: None,
+            project_context: None,
+            conversation_context: None,
+            advisory_context: None,
+            prompt_budget: None,
+            provider_selection: None,
+            secondary_labels: Vec::new(),
         }
     }
 
@@ -205,9 +254,75 @@ impl ClassifiedRequest {
             matched_keywords,
             created_at: Utc::now(),
             enhanced_classification: Some(Box::new(enhanced_classification)),
+            code_context: None,
+            project_context: None,
+            conversation_context: None,
+            advisory_context: None,
+            prompt_budget: None,
+            provider_selection: None,
+            secondary_labels: Vec::new(),
         }
     }
 
+    /// Attach a code context summary to the request
+    pub fn with_code_context(mut self, code_context: impl Into<String>) -> Self {
+        self.code_context = Some(code_context.into());
+        self
+    }
+
+    /// Attach a summary of the project the query is about to the request
+    pub fn with_project_context(mut self, project_context: impl Into<String>) -> Self {
+        self.project_context = Some(project_context.into());
+        self
+    }
+
+    /// Attach a summary of earlier conversation turns to the request
+    pub fn with_conversation_context(mut self, conversation_context: impl Into<String>) -> Self {
+        self.conversation_context = Some(conversation_context.into());
+        self
+    }
+
+    /// Attach the other research types the query was labeled with
+    ///
+    /// Labels for the primary research type are dropped.
+    pub fn with_secondary_labels(
+        mut self,
+        labels: impl IntoIterator<Item = crate::classification::ClassificationLabel>,
+    ) -> Self {
+        self.secondary_labels = labels
+            .into_iter()
+            .filter(|label| label.research_type != self.research_type)
+            .collect();
+        self
+    }
+
+    /// All labels of the request, primary research type first
+    pub fn labels(&self) -> Vec<crate::classification::ClassificationLabel> {
+        std::iter::once(crate::classification::ClassificationLabel::new(
+            self.research_type.clone(),
+            self.confidence,
+        ))
+        .chain(self.secondary_labels.iter().cloned())
+        .collect()
+    }
+
+    /// Research types beyond the primary one
+    pub fn secondary_types(&self) -> Vec<ResearchType> {
+        self.secondary_labels
+            .iter()
+            .map(|label| label.research_type.clone())
+            .collect()
+    }
+
+    /// Whether the request is labeled with `research_type`, as primary or secondary type
+    pub fn has_research_type(&self, research_type: &ResearchType) -> bool {
+        self.research_type == *research_type
+            || self
+                .secondary_labels
+                .iter()
+                .any(|label| label.research_type == *research_type)
+    }
+
     /// Check if this request has enhanced classification data
     pub fn has_enhanced_classification(&self) -> bool {
         self.enhanced_classification.is_some()
@@ -247,6 +362,38 @@ pub struct Detail {
     pub prerequisites: Vec<String>,
 }
 
+/// Verification state of a cited URL
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CitationStatus {
+    /// Not checked, or the citation has no URL
+    Unverified,
+    /// The URL answered with a success or redirect status
+    Verified,
+    /// The URL answered with a client or server error
+    Broken,
+    /// The URL did not answer before the timeout
+    Unreachable,
+}
+
+/// A source cited for one claim of a research result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Citation {
+    /// Sentence of the result the source supports
+    pub claim: String,
+    /// Part of the result the claim is in (answer, evidence, detail)
+    pub section: String,
+    /// Cited URL
+    pub url: Option<String>,
+    /// Quoted source text
+    pub quote: Option<String>,
+    /// Whether the URL was reachable when checked
+    pub status: CitationStatus,
+    /// HTTP status the URL answered with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_status: Option<u16>,
+}
+
 /// Metadata about research results
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResearchMetadata {
@@ -277,6 +424,12 @@ pub struct ResearchResult {
     pub implementation_details: Vec<Detail>,
     /// Research metadata
     pub metadata: ResearchMetadata,
+    /// Cache key of the result this follow-up was derived from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// Sources cited for claims of the result
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub citations: Vec<Citation>,
 }
 
 impl ResearchResult {
@@ -294,9 +447,17 @@ impl ResearchResult {
             supporting_evidence,
             implementation_details,
             metadata,
+            parent_id: None,
+            citations: Vec::new(),
         }
     }
 
+    /// Mark this result as a follow-up to another result
+    pub fn with_parent(mut self, parent_id: impl Into<String>) -> Self {
+        self.parent_id = Some(parent_id.into());
+        self
+    }
+
     /// Get the research type from the request
     pub fn research_type(&self) -> &ResearchType {
         &self.request.research_type
@@ -311,6 +472,11 @@ impl ResearchResult {
     pub fn cache_key(&self) -> &str {
         &self.metadata.cache_key
     }
+
+    /// Get the cache key of the parent result, if this is a follow-up
+    pub fn parent_id(&self) -> Option<&str> {
+        self.parent_id.as_deref()
+    }
 }
 
 #[cfg(test)]
@@ -371,6 +537,22 @@ mod tests {
         assert_eq!(request.research_type, ResearchType::Implementation);
         assert_eq!(request.confidence, 0.85);
         assert_eq!(request.matched_keywords.len(), 2);
+        assert!(request.code_context.is_none());
+
+        let request = request.with_code_context("fn main()");
+        assert_eq!(request.code_context.as_deref(), Some("fn main()"));
+
+        let request = request.with_project_context("Project: fortitude");
+        assert_eq!(
+            request.project_context.as_deref(),
+            Some("Project: fortitude")
+        );
+
+        let request = request.with_conversation_context("Q: What is Tokio?");
+        assert_eq!(
+            request.conversation_context.as_deref(),
+            Some("Q: What is Tokio?")
+        );
     }
 
     #[test]
@@ -399,5 +581,80 @@ mod tests {
         assert_eq!(result.research_type(), &ResearchType::Learning);
         assert_eq!(result.original_query(), "Test query");
         assert_eq!(result.cache_key(), "test-key");
+        assert_eq!(result.parent_id(), None);
+    }
+
+    #[test]
+    fn test_research_result_parent_round_trip() {
+        let request = ClassifiedRequest::new(
+            "Follow-up query".to_string(),
+            ResearchType::Implementation,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.9,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 10,
+            sources_consulted: vec![],
+            quality_score: 0.8,
+            cache_key: "child-key".to_string(),
+            tags: HashMap::new(),
+        };
+
+        let result = ResearchResult::new(request, "Answer".to_string(), vec![], vec![], metadata)
+            .with_parent("parent-key");
+        assert_eq!(result.parent_id(), Some("parent-key"));
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["parent_id"], "parent-key");
+        let restored: ResearchResult = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, result);
+
+        // Results stored before lineage existed have no parent
+        let mut legacy = serde_json::to_value(&restored).unwrap();
+        legacy.as_object_mut().unwrap().remove("parent_id");
+        let legacy: ResearchResult = serde_json::from_value(legacy).unwrap();
+        assert_eq!(legacy.parent_id(), None);
+    }
+
+    #[test]
+    fn test_research_result_citations_round_trip() {
+        let request = ClassifiedRequest::new(
+            "Cited query".to_string(),
+            ResearchType::Learning,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.9,
+            vec![],
+        );
+        let metadata = ResearchMetadata {
+            completed_at: Utc::now(),
+            processing_time_ms: 10,
+            sources_consulted: vec![],
+            quality_score: 0.8,
+            cache_key: "cited-key".to_string(),
+            tags: HashMap::new(),
+        };
+        let mut result =
+            ResearchResult::new(request, "Answer".to_string(), vec![], vec![], metadata);
+
+        // Results without citations serialize as before
+        let json = serde_json::to_value(&result).unwrap();
+        assert!(json.get("citations").is_none());
+
+        result.citations.push(Citation {
+            claim: "Answer".to_string(),
+            section: "answer".to_string(),
+            url: Some("https://doc.rust-lang.org/book/".to_string()),
+            quote: None,
+            status: CitationStatus::Broken,
+            http_status: Some(404),
+        });
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["citations"][0]["status"], "broken");
+        let restored: ResearchResult = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, result);
     }
 }
@@ -47,6 +47,9 @@ impl Default for StorageConfig {
     }
 }
 
+/// Cache entry metadata key listing research types beyond the primary one
+pub const SECONDARY_TYPES_METADATA_KEY: &str = "secondary_types";
+
 /// Cache entry metadata
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CacheEntry {
@@ -115,6 +118,24 @@ impl CacheEntry {
         let now = Utc::now();
         (now - self.created_at).num_seconds().max(0) as u64
     }
+
+    /// Research types recorded beyond the primary one
+    pub fn secondary_types(&self) -> Vec<ResearchType> {
+        self.metadata
+            .get(SECONDARY_TYPES_METADATA_KEY)
+            .map(|types| {
+                types
+                    .split(',')
+                    .filter_map(|t| t.trim().parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Whether the entry is labeled with `research_type`, as primary or secondary type
+    pub fn has_research_type(&self, research_type: &ResearchType) -> bool {
+        self.research_type == *research_type || self.secondary_types().contains(research_type)
+    }
 }
 
 /// Cache statistics with enhanced analytics
@@ -140,6 +161,28 @@ pub struct CacheStats {
     pub analytics: CacheAnalytics,
     /// Newest cache entry
     pub newest_entry: Option<CacheEntry>,
+    /// Savings from entries sharing content blobs
+    #[serde(default)]
+    pub dedup: DedupStats,
+}
+
+/// Space saved by content-addressed storage
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DedupStats {
+    /// Entries whose content lives in a blob
+    pub logical_entries: usize,
+    /// Distinct content blobs stored
+    pub blobs: usize,
+    /// Content bytes the entries would take without sharing
+    pub logical_bytes: u64,
+    /// Content bytes stored in blobs
+    pub stored_bytes: u64,
+}
+
+impl DedupStats {
+    pub fn saved_bytes(&self) -> u64 {
+        self.logical_bytes.saturating_sub(self.stored_bytes)
+    }
 }
 
 impl Default for CacheStats {
@@ -155,6 +198,7 @@ impl Default for CacheStats {
             by_research_type: HashMap::new(),
             analytics: CacheAnalytics::default(),
             newest_entry: None,
+            dedup: DedupStats::default(),
         }
     }
 }
@@ -495,6 +539,9 @@ pub struct IndexEntry {
     pub indexed_at: DateTime<Utc>,
     /// Full text search vector (for future implementation)
     pub search_vector: Option<Vec<f32>>,
+    /// Research types beyond the primary one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub secondary_types: Vec<ResearchType>,
 }
 
 impl IndexEntry {
@@ -519,8 +566,20 @@ impl IndexEntry {
             quality_score,
             indexed_at: Utc::now(),
             search_vector: None,
+            secondary_types: Vec::new(),
         }
     }
+
+    /// Record the research types beyond the primary one
+    pub fn with_secondary_types(mut self, secondary_types: Vec<ResearchType>) -> Self {
+        self.secondary_types = secondary_types;
+        self
+    }
+
+    /// Whether the entry is labeled with `research_type`, as primary or secondary type
+    pub fn has_research_type(&self, research_type: &ResearchType) -> bool {
+        self.research_type == *research_type || self.secondary_types.contains(research_type)
+    }
 }
 
 /// Search query parameters
@@ -647,6 +706,13 @@ pub trait Storage {
     /// Update search index
     async fn update_index(&self) -> Result<(), crate::error::StorageError>;
 
+    /// Write any indices held in memory to durable storage
+    ///
+    /// Called on shutdown; backends that persist every write need not override it.
+    async fn flush(&self) -> Result<(), crate::error::StorageError> {
+        Ok(())
+    }
+
     /// Record cache operation for performance monitoring
     async fn record_cache_operation(
         &self,
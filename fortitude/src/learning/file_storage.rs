@@ -0,0 +1,387 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: File-backed learning store for deployments without a vector database
+//! Keeps all learning data in one JSON file that is rewritten atomically after
+//! every change. Suited to the CLI and single-user setups; use
+//! [`VectorLearningStorage`](crate::learning::VectorLearningStorage) when
+//! similarity search over learning data is needed.
+
+use crate::learning::{
+    CleanupResult, FeedbackTrend, LearningData, LearningError, LearningResult,
+    LearningStorageService, PatternData, UsagePattern, UserFeedback,
+};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use fortitude_core::write_json_atomic_async;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::sync::RwLock;
+use tracing::{debug, info, instrument};
+
+/// Default location of the CLI learning store
+pub const DEFAULT_LEARNING_STORE_PATH: &str = "./learning_data/learning_store.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct LearningSnapshot {
+    #[serde(default)]
+    feedback: Vec<UserFeedback>,
+    #[serde(default)]
+    patterns: Vec<PatternData>,
+    #[serde(default)]
+    learning_data: Vec<LearningData>,
+    #[serde(default)]
+    usage_patterns: Vec<UsagePattern>,
+}
+
+/// Learning store persisted to a JSON file
+pub struct FileLearningStorage {
+    path: PathBuf,
+    snapshot: RwLock<LearningSnapshot>,
+}
+
+impl FileLearningStorage {
+    /// Open the store at `path`, starting empty if the file does not exist
+    pub async fn open(path: impl Into<PathBuf>) -> LearningResult<Self> {
+        let path = path.into();
+        let snapshot = match tokio::fs::read(&path).await {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => LearningSnapshot::default(),
+            Err(e) => {
+                return Err(LearningError::StorageError(format!(
+                    "Failed to read {}: {e}",
+                    path.display()
+                )))
+            }
+        };
+        Ok(Self {
+            path,
+            snapshot: RwLock::new(snapshot),
+        })
+    }
+
+    /// File backing the store
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Every stored feedback entry
+    pub async fn all_feedback(&self) -> Vec<UserFeedback> {
+        self.snapshot.read().await.feedback.clone()
+    }
+
+    /// Apply `change` and write the store back to disk
+    async fn update<T>(
+        &self,
+        change: impl FnOnce(&mut LearningSnapshot) -> T,
+    ) -> LearningResult<T> {
+        let mut snapshot = self.snapshot.write().await;
+        let result = change(&mut snapshot);
+        self.save(&snapshot).await?;
+        Ok(result)
+    }
+
+    async fn save(&self, snapshot: &LearningSnapshot) -> LearningResult<()> {
+        write_json_atomic_async(&self.path, snapshot)
+            .await
+            .map_err(|e| {
+                LearningError::StorageError(format!("Failed to write {}: {e}", self.path.display()))
+            })
+    }
+
+    async fn feedback_for(&self, content_id: &str) -> Vec<UserFeedback> {
+        self.snapshot
+            .read()
+            .await
+            .feedback
+            .iter()
+            .filter(|f| f.content_id == content_id)
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl LearningStorageService for FileLearningStorage {
+    #[instrument(skip(self, feedback))]
+    async fn store_feedback(&self, feedback: &UserFeedback) -> LearningResult<UserFeedback> {
+        if !feedback.is_valid() {
+            return Err(LearningError::InvalidFeedback(format!(
+                "Feedback {} is incomplete or has a score outside 0.0-1.0",
+                feedback.id
+            )));
+        }
+        debug!("Storing feedback: {}", feedback.id);
+        let stored = feedback.clone();
+        self.update(|s| s.feedback.push(stored)).await?;
+        Ok(feedback.clone())
+    }
+
+    async fn get_feedback(&self, id: &str) -> LearningResult<Option<UserFeedback>> {
+        Ok(self
+            .snapshot
+            .read()
+            .await
+            .feedback
+            .iter()
+            .find(|f| f.id == id)
+            .cloned())
+    }
+
+    async fn get_feedback_for_content(
+        &self,
+        content_id: &str,
+    ) -> LearningResult<Vec<UserFeedback>> {
+        Ok(self.feedback_for(content_id).await)
+    }
+
+    async fn store_pattern(&self, pattern: &PatternData) -> LearningResult<PatternData> {
+        let stored = pattern.clone();
+        self.update(|s| {
+            s.patterns.retain(|p| p.id != stored.id);
+            s.patterns.push(stored);
+        })
+        .await?;
+        Ok(pattern.clone())
+    }
+
+    async fn get_patterns_by_type(&self, pattern_type: &str) -> LearningResult<Vec<PatternData>> {
+        Ok(self
+            .snapshot
+            .read()
+            .await
+            .patterns
+            .iter()
+            .filter(|p| p.pattern_type == pattern_type)
+            .cloned()
+            .collect())
+    }
+
+    async fn store_learning_data(&self, data: &LearningData) -> LearningResult<LearningData> {
+        let stored = data.clone();
+        self.update(|s| {
+            s.learning_data.retain(|d| d.id != stored.id);
+            s.learning_data.push(stored);
+        })
+        .await?;
+        Ok(data.clone())
+    }
+
+    async fn get_recent_learning_data(&self, limit: usize) -> LearningResult<Vec<LearningData>> {
+        let mut data = self.snapshot.read().await.learning_data.clone();
+        data.sort_by_key(|d| std::cmp::Reverse(d.created_at));
+        data.truncate(limit);
+        Ok(data)
+    }
+
+    async fn store_usage_pattern(&self, pattern: &UsagePattern) -> LearningResult<UsagePattern> {
+        let stored = pattern.clone();
+        self.update(|s| {
+            s.usage_patterns.retain(|p| p.id != stored.id);
+            s.usage_patterns.push(stored);
+        })
+        .await?;
+        Ok(pattern.clone())
+    }
+
+    async fn get_top_patterns(
+        &self,
+        pattern_type: &str,
+        limit: usize,
+    ) -> LearningResult<Vec<UsagePattern>> {
+        let mut patterns: Vec<UsagePattern> = self
+            .snapshot
+            .read()
+            .await
+            .usage_patterns
+            .iter()
+            .filter(|p| p.pattern_type == pattern_type)
+            .cloned()
+            .collect();
+        patterns.sort_by_key(|p| std::cmp::Reverse(p.frequency));
+        patterns.truncate(limit);
+        Ok(patterns)
+    }
+
+    async fn get_trending_patterns(
+        &self,
+        pattern_type: &str,
+        days: u32,
+    ) -> LearningResult<Vec<UsagePattern>> {
+        let cutoff_date = Utc::now() - Duration::days(days as i64);
+        let mut patterns: Vec<UsagePattern> = self
+            .snapshot
+            .read()
+            .await
+            .usage_patterns
+            .iter()
+            .filter(|p| p.pattern_type == pattern_type && p.last_used >= cutoff_date)
+            .cloned()
+            .collect();
+        patterns.sort_by(|a, b| {
+            b.frequency
+                .cmp(&a.frequency)
+                .then(b.last_used.cmp(&a.last_used))
+        });
+        Ok(patterns)
+    }
+
+    async fn get_average_feedback_score(&self, content_id: &str) -> LearningResult<Option<f64>> {
+        let scores: Vec<f64> = self
+            .feedback_for(content_id)
+            .await
+            .iter()
+            .filter_map(|f| f.score)
+            .collect();
+        Ok((!scores.is_empty()).then(|| scores.iter().sum::<f64>() / scores.len() as f64))
+    }
+
+    async fn get_feedback_trend(
+        &self,
+        content_id: &str,
+        days: u32,
+    ) -> LearningResult<FeedbackTrend> {
+        let cutoff_date = Utc::now() - Duration::days(days as i64);
+        let mut recent_feedback = self.feedback_for(content_id).await;
+        recent_feedback.retain(|f| f.timestamp >= cutoff_date);
+        recent_feedback.sort_by_key(|f| f.timestamp);
+
+        let scores: Vec<f64> = recent_feedback.iter().filter_map(|f| f.score).collect();
+        let average_score = if scores.is_empty() {
+            0.0
+        } else {
+            scores.iter().sum::<f64>() / scores.len() as f64
+        };
+        let trend_direction = if scores.len() >= 4 {
+            let mid = scores.len() / 2;
+            let recent_avg = scores[mid..].iter().sum::<f64>() / (scores.len() - mid) as f64;
+            let older_avg = scores[..mid].iter().sum::<f64>() / mid as f64;
+            recent_avg - older_avg
+        } else {
+            0.0
+        };
+
+        Ok(FeedbackTrend {
+            content_id: content_id.to_string(),
+            total_feedback: recent_feedback.len(),
+            average_score,
+            trend_direction,
+        })
+    }
+
+    async fn get_recent_feedback(
+        &self,
+        content_id: &str,
+        limit: usize,
+    ) -> LearningResult<Vec<UserFeedback>> {
+        let mut feedback = self.feedback_for(content_id).await;
+        feedback.sort_by_key(|f| std::cmp::Reverse(f.timestamp));
+        feedback.truncate(limit);
+        Ok(feedback)
+    }
+
+    #[instrument(skip(self))]
+    async fn cleanup_old_data(&self, retention_days: u32) -> LearningResult<CleanupResult> {
+        let cutoff_date = Utc::now() - Duration::days(retention_days as i64);
+        let result = self
+            .update(|s| {
+                let before = (
+                    s.feedback.len(),
+                    s.patterns.len(),
+                    s.learning_data.len(),
+                    s.usage_patterns.len(),
+                );
+                s.feedback.retain(|f| f.timestamp >= cutoff_date);
+                s.patterns.retain(|p| p.last_seen >= cutoff_date);
+                s.learning_data.retain(|d| d.created_at >= cutoff_date);
+                s.usage_patterns.retain(|p| p.last_used >= cutoff_date);
+                CleanupResult {
+                    deleted_feedback: before.0 - s.feedback.len(),
+                    deleted_patterns: before.1 - s.patterns.len(),
+                    deleted_learning_data: before.2 - s.learning_data.len(),
+                    deleted_usage_patterns: before.3 - s.usage_patterns.len(),
+                    cleanup_date: Utc::now(),
+                }
+            })
+            .await?;
+        info!(
+            "Removed {} feedback entries older than {} days",
+            result.deleted_feedback, retention_days
+        );
+        Ok(result)
+    }
+
+    async fn initialize(&self) -> LearningResult<()> {
+        let snapshot = self.snapshot.read().await;
+        self.save(&snapshot).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feedback(content_id: &str, score: f64) -> UserFeedback {
+        UserFeedback::new(
+            "cli".to_string(),
+            content_id.to_string(),
+            "quality_rating".to_string(),
+            Some(score),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_feedback_survives_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("store").join("learning_store.json");
+
+        let storage = FileLearningStorage::open(&path).await.unwrap();
+        storage
+            .store_feedback(&feedback("key-1", 0.4))
+            .await
+            .unwrap();
+        storage
+            .store_feedback(&feedback("key-1", 0.8))
+            .await
+            .unwrap();
+        storage
+            .store_feedback(&feedback("key-2", 1.0))
+            .await
+            .unwrap();
+
+        let reopened = FileLearningStorage::open(&path).await.unwrap();
+        assert_eq!(reopened.all_feedback().await.len(), 3);
+        let average = reopened
+            .get_average_feedback_score("key-1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!((average - 0.6).abs() < 1e-9);
+        let trend = reopened.get_feedback_trend("key-1", 30).await.unwrap();
+        assert_eq!(trend.total_feedback, 2);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_out_of_range_score() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = FileLearningStorage::open(dir.path().join("store.json"))
+            .await
+            .unwrap();
+
+        let result = storage.store_feedback(&feedback("key-1", 1.5)).await;
+        assert!(matches!(result, Err(LearningError::InvalidFeedback(_))));
+        assert!(!dir.path().join("store.json").exists());
+    }
+}
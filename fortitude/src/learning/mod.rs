@@ -24,6 +24,7 @@
 //! - **Data Models**: Core structures for feedback, patterns, and learning insights
 //! - **Storage Layer**: Persistence and retrieval of learning data with vector database integration
 //! - **Adaptation Algorithms**: Interfaces for system improvement based on learning data
+//! - **Prompt Adaptation**: Template adjustments proposed from low-rated results
 //! - **Pattern Recognition**: Analysis of usage patterns and trends
 //! - **Configuration**: Settings and parameters for learning system behavior
 //!
@@ -46,9 +47,11 @@ use thiserror::Error;
 
 pub mod adaptation;
 pub mod config;
+pub mod file_storage;
 pub mod monitoring;
 pub mod optimization;
 pub mod pattern_recognition;
+pub mod prompt_adaptation;
 pub mod storage;
 pub mod template_integration;
 
@@ -58,6 +61,7 @@ pub use config::{
     AlertConfig, ConfigWatcher, EnhancedLearningConfig, HealthCheckConfig, LearningConfigManager,
     LearningMonitoringConfig, MonitoringThresholds,
 };
+pub use file_storage::{FileLearningStorage, DEFAULT_LEARNING_STORE_PATH};
 pub use monitoring::{
     Alert, AlertHandler, AlertSeverity, DashboardData, HealthCheck, HealthReport, HealthStatus,
     LearningHealthChecker, LearningMetrics, LearningMetricsCollector, LearningPerformanceMonitor,
@@ -68,6 +72,7 @@ pub use optimization::{
     OptimizationContext, PerformanceMetrics, PerformanceOptimizer, ProviderSelectionResult,
     QueryPerformanceResult,
 };
+pub use prompt_adaptation::{PromptAdaptationEngine, PromptAdaptationReport};
 pub use storage::{
     CleanupResult, EmbeddingCacheStats, EnhancedLearningStorageService, FeedbackTrend,
     LearningStorageService, SimilarityLearningResult, SimilarityUsagePattern,
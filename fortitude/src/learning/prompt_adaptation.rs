@@ -0,0 +1,457 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Feedback-driven adaptation of prompt templates
+//! # Prompt Adaptation Module
+//!
+//! Correlates user ratings with the prompt template and research type of the
+//! rated result. When most ratings for a template are low, an adjustment is
+//! proposed: an instruction addressing the complaints found in the text
+//! feedback. Adjustments live in [`PromptAdjustments`], which the research
+//! engines read when building prompts. With `auto_apply_adaptations` on,
+//! confident proposals are applied right away and recorded in its audit log.
+
+use crate::learning::{LearningConfig, LearningError, LearningResult, UserFeedback};
+use fortitude_core::prompts::{
+    AdjustmentAuditEntry, ExperimentArm, PromptAdjustments, TemplateAdjustment,
+    PROMPT_EXPERIMENT_ARM_TAG, PROMPT_EXPERIMENT_TAG, PROMPT_TEMPLATE_TAG,
+};
+use fortitude_types::{ResearchResult, ResearchType};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, info, instrument};
+
+/// Feedback metadata key holding the research type of the rated result
+pub const FEEDBACK_RESEARCH_TYPE_KEY: &str = "research_type";
+
+/// Name recorded in the audit log for automatically applied adjustments
+pub const AUTO_APPLIED_BY: &str = "auto";
+
+/// Ratings below this count as low
+const LOW_RATING_THRESHOLD: f64 = 0.5;
+
+/// Share of low ratings that marks a template as underperforming
+const UNDERPERFORMING_SHARE: f64 = 0.5;
+
+/// Low ratings that must mention a complaint before it shapes an adjustment
+const MIN_COMPLAINT_MENTIONS: usize = 2;
+
+/// Complaint keywords in text feedback and the instruction addressing them
+const COMPLAINT_INSTRUCTIONS: &[(&[&str], &str)] = &[
+    (
+        &["example", "code sample", "snippet"],
+        "Include concrete, runnable code examples.",
+    ),
+    (
+        &["outdated", "deprecated", "old version"],
+        "Prefer current APIs and name the versions the advice applies to.",
+    ),
+    (
+        &["vague", "generic", "unclear"],
+        "Be specific to the stated technology stack and avoid generic advice.",
+    ),
+    (
+        &["source", "citation", "reference"],
+        "Cite the documentation or sources backing each claim.",
+    ),
+    (
+        &["too long", "verbose", "wordy"],
+        "Keep the answer concise and lead with the direct recommendation.",
+    ),
+    (
+        &["incomplete", "missing", "shallow"],
+        "Cover edge cases and every step needed to finish the task.",
+    ),
+];
+
+/// Used when low ratings carry no recognisable complaint
+const FALLBACK_INSTRUCTION: &str =
+    "Answers from this template were rated poorly; give a more thorough and specific answer.";
+
+/// Outcome of one adaptation run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PromptAdaptationReport {
+    /// Adjustments newly proposed in this run
+    pub proposed: Vec<TemplateAdjustment>,
+    /// Adjustments applied in this run
+    pub applied: Vec<AdjustmentAuditEntry>,
+}
+
+/// Ratings of results built from one template for one research type
+struct TemplateRatings<'a> {
+    template_name: String,
+    research_type: ResearchType,
+    feedback: Vec<&'a UserFeedback>,
+}
+
+impl TemplateRatings<'_> {
+    fn low_ratings(&self) -> Vec<&UserFeedback> {
+        self.feedback
+            .iter()
+            .copied()
+            .filter(|f| f.score.is_some_and(|s| s < LOW_RATING_THRESHOLD))
+            .collect()
+    }
+
+    fn average_score(&self) -> f64 {
+        self.feedback.iter().filter_map(|f| f.score).sum::<f64>() / self.feedback.len() as f64
+    }
+}
+
+/// Adapts prompt templates to user feedback
+pub struct PromptAdaptationEngine {
+    config: LearningConfig,
+    adjustments: Arc<PromptAdjustments>,
+}
+
+impl PromptAdaptationEngine {
+    /// Create an engine storing its proposals in `adjustments`
+    pub fn new(config: LearningConfig, adjustments: Arc<PromptAdjustments>) -> Self {
+        Self {
+            config,
+            adjustments,
+        }
+    }
+
+    /// Adjustment store shared with the research engines
+    pub fn adjustments(&self) -> &Arc<PromptAdjustments> {
+        &self.adjustments
+    }
+
+    /// Propose adjustments for underperforming templates, applying confident
+    /// ones when `auto_apply_adaptations` is on
+    ///
+    /// Feedback without a score, template or research type is ignored.
+    #[instrument(skip(self, feedback), fields(feedback_count = feedback.len()))]
+    pub fn adapt(&self, feedback: &[UserFeedback]) -> LearningResult<PromptAdaptationReport> {
+        let mut report = PromptAdaptationReport::default();
+        if !self.config.enable_feedback_learning {
+            return Ok(report);
+        }
+
+        for ratings in group_by_template(feedback) {
+            if ratings.feedback.len() < self.config.min_feedback_threshold {
+                continue;
+            }
+            let Some(adjustment) = self.propose_for(&ratings) else {
+                continue;
+            };
+            if !self.adjustments.propose(adjustment.clone()) {
+                debug!(
+                    "Adjustment for '{}' ({}) already known",
+                    adjustment.template_name, adjustment.research_type
+                );
+                continue;
+            }
+            info!(
+                "Proposed adjustment for '{}' ({}): {}",
+                adjustment.template_name, adjustment.research_type, adjustment.reason
+            );
+
+            if self.config.adaptation.auto_apply_adaptations
+                && adjustment.confidence >= self.config.adaptation_threshold
+            {
+                let entry = self
+                    .adjustments
+                    .apply(&adjustment.id, AUTO_APPLIED_BY)
+                    .map_err(|e| LearningError::AdaptationError(e.to_string()))?;
+                info!(
+                    "Applied adjustment {} to '{}' ({}): {}",
+                    adjustment.id,
+                    adjustment.template_name,
+                    adjustment.research_type,
+                    adjustment.instruction
+                );
+                report.applied.push(entry);
+            }
+            report.proposed.push(adjustment);
+        }
+
+        Ok(report)
+    }
+
+    /// Adjustment for an underperforming template, if it is one
+    fn propose_for(&self, ratings: &TemplateRatings<'_>) -> Option<TemplateAdjustment> {
+        let total = ratings.feedback.len();
+        let low = ratings.low_ratings();
+        let low_share = low.len() as f64 / total as f64;
+        if low_share < UNDERPERFORMING_SHARE {
+            return None;
+        }
+
+        let complaints = complaint_instructions(&low);
+        let instruction = if complaints.is_empty() {
+            FALLBACK_INSTRUCTION.to_string()
+        } else {
+            complaints.join(" ")
+        };
+
+        let volume =
+            (total as f64 / (2 * self.config.min_feedback_threshold.max(1)) as f64).min(1.0);
+        let confidence = (low_share + volume) / 2.0;
+        let reason = format!(
+            "{} of {} ratings below {:.1} (average {:.2})",
+            low.len(),
+            total,
+            LOW_RATING_THRESHOLD,
+            ratings.average_score()
+        );
+
+        Some(TemplateAdjustment::new(
+            ratings.template_name.clone(),
+            ratings.research_type.clone(),
+            instruction,
+            reason,
+            confidence,
+        ))
+    }
+}
+
+/// Scored feedback grouped by template and research type, in a stable order
+fn group_by_template(feedback: &[UserFeedback]) -> Vec<TemplateRatings<'_>> {
+    let mut groups: HashMap<(String, ResearchType), Vec<&UserFeedback>> = HashMap::new();
+    for entry in feedback
+        .iter()
+        .filter(|f| f.is_valid() && f.score.is_some())
+    {
+        let Some((template_name, research_type)) = entry.prompt_context() else {
+            continue;
+        };
+        groups
+            .entry((template_name, research_type))
+            .or_default()
+            .push(entry);
+    }
+
+    let mut ratings: Vec<TemplateRatings<'_>> = groups
+        .into_iter()
+        .map(
+            |((template_name, research_type), feedback)| TemplateRatings {
+                template_name,
+                research_type,
+                feedback,
+            },
+        )
+        .collect();
+    ratings.sort_by(|a, b| {
+        (a.template_name.as_str(), a.research_type.to_string())
+            .cmp(&(b.template_name.as_str(), b.research_type.to_string()))
+    });
+    ratings
+}
+
+/// Instructions for complaints mentioned by enough low ratings
+fn complaint_instructions(low: &[&UserFeedback]) -> Vec<&'static str> {
+    let texts: Vec<String> = low
+        .iter()
+        .filter_map(|f| f.text_feedback.as_deref())
+        .map(str::to_lowercase)
+        .collect();
+
+    COMPLAINT_INSTRUCTIONS
+        .iter()
+        .filter(|(keywords, _)| {
+            texts
+                .iter()
+                .filter(|text| keywords.iter().any(|k| text.contains(k)))
+                .count()
+                >= MIN_COMPLAINT_MENTIONS
+        })
+        .map(|(_, instruction)| *instruction)
+        .collect()
+}
+
+impl UserFeedback {
+    /// Record the template and research type of the rated result
+    ///
+    /// The template name comes from the result's
+    /// [`PROMPT_TEMPLATE_TAG`] metadata tag.
+    pub fn with_prompt_context(self, template_name: &str, research_type: &ResearchType) -> Self {
+        self.with_metadata(
+            PROMPT_TEMPLATE_TAG.to_string(),
+            serde_json::Value::String(template_name.to_string()),
+        )
+        .with_metadata(
+            FEEDBACK_RESEARCH_TYPE_KEY.to_string(),
+            serde_json::Value::String(research_type.to_string()),
+        )
+    }
+
+    /// Template and research type recorded by [`Self::with_prompt_context`]
+    pub fn prompt_context(&self) -> Option<(String, ResearchType)> {
+        let template_name = self.metadata.get(PROMPT_TEMPLATE_TAG)?.as_str()?;
+        let research_type = self
+            .metadata
+            .get(FEEDBACK_RESEARCH_TYPE_KEY)?
+            .as_str()?
+            .parse()
+            .ok()?;
+        Some((template_name.to_string(), research_type))
+    }
+
+    /// Record the template and experiment tags of the rated result, so the
+    /// rating feeds prompt adaptation and experiment analysis
+    pub fn with_result_context(self, result: &ResearchResult) -> Self {
+        let tags = &result.metadata.tags;
+        let mut feedback = self;
+        if let Some(template_name) = tags.get(PROMPT_TEMPLATE_TAG) {
+            feedback = feedback.with_prompt_context(template_name, &result.request.research_type);
+        }
+        if let (Some(experiment), Some(arm)) = (
+            tags.get(PROMPT_EXPERIMENT_TAG),
+            tags.get(PROMPT_EXPERIMENT_ARM_TAG)
+                .and_then(|arm| arm.parse().ok()),
+        ) {
+            feedback = feedback.with_experiment_context(experiment, arm);
+        }
+        feedback
+    }
+
+    /// Record the prompt experiment and arm of the rated result
+    pub fn with_experiment_context(self, experiment: &str, arm: ExperimentArm) -> Self {
+        self.with_metadata(
+            PROMPT_EXPERIMENT_TAG.to_string(),
+            serde_json::Value::String(experiment.to_string()),
+        )
+        .with_metadata(
+            PROMPT_EXPERIMENT_ARM_TAG.to_string(),
+            serde_json::Value::String(arm.to_string()),
+        )
+    }
+
+    /// Experiment and arm recorded by [`Self::with_experiment_context`]
+    pub fn experiment_context(&self) -> Option<(String, ExperimentArm)> {
+        let experiment = self.metadata.get(PROMPT_EXPERIMENT_TAG)?.as_str()?;
+        let arm = self
+            .metadata
+            .get(PROMPT_EXPERIMENT_ARM_TAG)?
+            .as_str()?
+            .parse()
+            .ok()?;
+        Some((experiment.to_string(), arm))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE: &str = "Feature Implementation Guide";
+
+    fn rating(score: f64, text: Option<&str>) -> UserFeedback {
+        UserFeedback::new(
+            "user".to_string(),
+            "result".to_string(),
+            "quality_rating".to_string(),
+            Some(score),
+            text.map(str::to_string),
+        )
+        .with_prompt_context(TEMPLATE, &ResearchType::Implementation)
+    }
+
+    fn engine(auto_apply: bool) -> PromptAdaptationEngine {
+        let mut config = LearningConfig::default();
+        config.adaptation.auto_apply_adaptations = auto_apply;
+        PromptAdaptationEngine::new(config, Arc::new(PromptAdjustments::new()))
+    }
+
+    fn poorly_rated() -> Vec<UserFeedback> {
+        let mut feedback: Vec<UserFeedback> = (0..8)
+            .map(|_| rating(0.2, Some("Too vague, needs a code example")))
+            .collect();
+        feedback.push(rating(0.9, Some("Great")));
+        feedback.push(rating(0.3, None));
+        feedback
+    }
+
+    #[test]
+    fn test_prompt_context_round_trip() {
+        let feedback = rating(0.5, None);
+        assert_eq!(
+            feedback.prompt_context(),
+            Some((TEMPLATE.to_string(), ResearchType::Implementation))
+        );
+        assert_eq!(
+            UserFeedback::new(
+                "user".to_string(),
+                "result".to_string(),
+                "quality_rating".to_string(),
+                Some(0.5),
+                None,
+            )
+            .prompt_context(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_proposes_adjustment_from_complaints() {
+        let engine = engine(false);
+        let report = engine.adapt(&poorly_rated()).unwrap();
+
+        assert_eq!(report.proposed.len(), 1);
+        assert!(report.applied.is_empty());
+        let adjustment = &report.proposed[0];
+        assert_eq!(adjustment.template_name, TEMPLATE);
+        assert_eq!(adjustment.research_type, ResearchType::Implementation);
+        assert!(adjustment
+            .instruction
+            .contains("Include concrete, runnable code examples."));
+        assert!(adjustment.instruction.contains("avoid generic advice"));
+        assert!(adjustment.reason.starts_with("9 of 10 ratings"));
+        assert_eq!(engine.adjustments().pending().len(), 1);
+        assert!(engine.adjustments().audit_log().is_empty());
+
+        // Proposed once only
+        assert!(engine.adapt(&poorly_rated()).unwrap().proposed.is_empty());
+    }
+
+    #[test]
+    fn test_auto_apply_records_audit_log() {
+        let engine = engine(true);
+        let report = engine.adapt(&poorly_rated()).unwrap();
+
+        assert_eq!(report.applied.len(), 1);
+        assert_eq!(report.applied[0].applied_by, AUTO_APPLIED_BY);
+        assert_eq!(engine.adjustments().audit_log(), report.applied);
+        assert!(engine
+            .adjustments()
+            .instructions_for(TEMPLATE, &ResearchType::Implementation)
+            .is_some());
+    }
+
+    #[test]
+    fn test_ignores_well_rated_and_sparse_templates() {
+        let engine = engine(true);
+        let well_rated: Vec<UserFeedback> = (0..10).map(|_| rating(0.8, None)).collect();
+        assert!(engine.adapt(&well_rated).unwrap().proposed.is_empty());
+
+        let sparse: Vec<UserFeedback> = (0..3).map(|_| rating(0.1, None)).collect();
+        assert!(engine.adapt(&sparse).unwrap().proposed.is_empty());
+    }
+
+    #[test]
+    fn test_low_confidence_proposal_is_not_applied() {
+        let engine = engine(true);
+        // Five ratings, three low: confidence (0.6 + 0.5) / 2 is below 0.7
+        let mut feedback: Vec<UserFeedback> = (0..3).map(|_| rating(0.1, None)).collect();
+        feedback.extend((0..2).map(|_| rating(0.9, None)));
+
+        let report = engine.adapt(&feedback).unwrap();
+        assert_eq!(report.proposed.len(), 1);
+        assert_eq!(report.proposed[0].instruction, FALLBACK_INSTRUCTION);
+        assert!(report.applied.is_empty());
+    }
+}
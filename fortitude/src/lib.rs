@@ -21,6 +21,7 @@ pub mod providers;
 pub mod quality;
 pub mod research;
 pub mod research_engine_adapter;
+pub mod workflow;
 
 pub use knowledge::KnowledgeBase;
 pub use pipeline::KnowledgePipeline;
@@ -13,10 +13,15 @@
 // limitations under the License.
 
 use clap::{Parser, Subcommand};
-use fortitude::proactive::{ProactiveManager, ProactiveManagerConfig, ProactiveManagerError};
-use fortitude::providers::{HealthStatus, Provider};
-use std::path::PathBuf;
+use fortitude::proactive::{
+    ControlClient, ControlError, ControlRequest, ControlResponse, ControlServer,
+    NotificationSystem, ProactiveManager, ProactiveManagerConfig, ProactiveManagerError,
+};
+use fortitude::providers::{HealthStatus, Provider, RequestOrigin};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::Mutex;
 use tracing::{error, info, warn, Level};
 
 #[derive(Parser)]
@@ -41,6 +46,9 @@ enum Commands {
         /// Quality threshold (0.0-1.0)
         #[arg(long, default_value = "0.8")]
         quality_threshold: f64,
+        /// Show the execution plan without calling any provider
+        #[arg(long)]
+        dry_run: bool,
     },
     Pipeline {
         #[arg(short, long)]
@@ -65,6 +73,15 @@ enum Commands {
     /// Proactive research management commands
     #[command(subcommand)]
     Proactive(ProactiveCommands),
+    /// Multi-step research workflow commands
+    #[command(subcommand)]
+    Workflow(WorkflowCommands),
+    /// Prompt template management commands
+    #[command(subcommand)]
+    Prompts(PromptCommands),
+    /// Classification correction commands
+    #[command(subcommand)]
+    Classify(ClassifyCommands),
 }
 
 #[derive(Subcommand)]
@@ -105,6 +122,8 @@ enum ProviderCommands {
         #[arg(short, long)]
         force: bool,
     },
+    /// Show the primary provider selected with `provider switch`
+    Current,
     /// Configure provider settings
     Configure {
         /// Provider name
@@ -192,6 +211,18 @@ enum LearningCommands {
         /// Output format (table, json)
         #[arg(short, long, default_value = "table")]
         format: String,
+        /// Compare the arms of prompt template experiments
+        #[arg(long)]
+        experiments: bool,
+    },
+    /// Train the research type classifier from corrections and confirming feedback
+    TrainClassifier {
+        /// Passes over the training examples
+        #[arg(long, default_value = "40")]
+        epochs: usize,
+        /// Where to save the trained model
+        #[arg(short, long)]
+        output: Option<PathBuf>,
     },
     /// Configure learning system settings
     Configure {
@@ -274,6 +305,10 @@ enum ProactiveCommands {
         /// Enable verbose output
         #[arg(short, long)]
         verbose: bool,
+
+        /// Control endpoint file of the running daemon
+        #[arg(long, default_value = fortitude::proactive::DEFAULT_CONTROL_FILE)]
+        control_file: PathBuf,
     },
 
     /// Stop proactive research mode
@@ -285,6 +320,10 @@ enum ProactiveCommands {
         /// Timeout for graceful shutdown in seconds
         #[arg(long, default_value = "30")]
         timeout: u64,
+
+        /// Control endpoint file of the running daemon
+        #[arg(long, default_value = fortitude::proactive::DEFAULT_CONTROL_FILE)]
+        control_file: PathBuf,
     },
 
     /// Show proactive research status
@@ -300,6 +339,10 @@ enum ProactiveCommands {
         /// Show only recent activity (last N minutes)
         #[arg(long)]
         recent: Option<u64>,
+
+        /// Control endpoint file of the running daemon
+        #[arg(long, default_value = fortitude::proactive::DEFAULT_CONTROL_FILE)]
+        control_file: PathBuf,
     },
 
     /// Configure proactive research settings
@@ -307,6 +350,10 @@ enum ProactiveCommands {
         /// Configuration subcommand
         #[command(subcommand)]
         action: ConfigureAction,
+
+        /// Control endpoint file of the running daemon
+        #[arg(long, default_value = fortitude::proactive::DEFAULT_CONTROL_FILE)]
+        control_file: PathBuf,
     },
 }
 
@@ -337,6 +384,102 @@ enum ConfigureAction {
     },
 }
 
+#[derive(Subcommand)]
+enum WorkflowCommands {
+    /// Run a workflow definition (TOML or YAML)
+    Run {
+        /// Workflow definition file
+        file: PathBuf,
+        /// Input value as key=value (repeatable)
+        #[arg(short, long = "input", value_name = "KEY=VALUE")]
+        inputs: Vec<String>,
+        /// Directory where workflow runs are stored
+        #[arg(long, default_value = fortitude::workflow::store::DEFAULT_RUNS_DIR)]
+        runs_dir: PathBuf,
+    },
+    /// Show the status of a workflow run
+    Status {
+        /// Run identifier
+        run_id: String,
+        /// Directory where workflow runs are stored
+        #[arg(long, default_value = fortitude::workflow::store::DEFAULT_RUNS_DIR)]
+        runs_dir: PathBuf,
+        /// Output format (table, json)
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+    /// List workflow runs, most recent first
+    List {
+        /// Maximum number of runs to show
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
+        /// Directory where workflow runs are stored
+        #[arg(long, default_value = fortitude::workflow::store::DEFAULT_RUNS_DIR)]
+        runs_dir: PathBuf,
+        /// Output format (table, json)
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum PromptCommands {
+    /// List prompt templates and their versions
+    List {
+        /// Directory prompt templates are loaded from
+        #[arg(long, default_value = fortitude_core::prompts::DEFAULT_PROMPT_TEMPLATE_DIR)]
+        dir: PathBuf,
+        /// Output format (table, json)
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+    /// Print the template a research type uses
+    Show {
+        /// Research type (decision, implementation, troubleshooting, learning, validation)
+        research_type: String,
+        /// Show the variant for this provider
+        #[arg(long)]
+        provider: Option<String>,
+        /// Show this version instead of the latest
+        #[arg(long)]
+        version: Option<u32>,
+        /// Directory prompt templates are loaded from
+        #[arg(long, default_value = fortitude_core::prompts::DEFAULT_PROMPT_TEMPLATE_DIR)]
+        dir: PathBuf,
+    },
+    /// Save a new version of a template, from a file or in $EDITOR
+    Edit {
+        /// Research type (decision, implementation, troubleshooting, learning, validation)
+        research_type: String,
+        /// Edit the variant for this provider
+        #[arg(long)]
+        provider: Option<String>,
+        /// Read the new template from this file instead of opening an editor
+        #[arg(long)]
+        file: Option<PathBuf>,
+        /// Description stored with the new version
+        #[arg(long)]
+        description: Option<String>,
+        /// Directory prompt templates are loaded from
+        #[arg(long, default_value = fortitude_core::prompts::DEFAULT_PROMPT_TEMPLATE_DIR)]
+        dir: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum ClassifyCommands {
+    /// Re-label a cached result with the research type it should have
+    Correct {
+        /// Cache key of the result, or a query to find it by
+        target: String,
+        /// Research type (decision, implementation, troubleshooting, learning, validation)
+        research_type: String,
+        /// Why the original classification was wrong
+        #[arg(long)]
+        reason: Option<String>,
+    },
+}
+
 /// Handle proactive research management commands
 async fn handle_proactive_command(
     cmd: ProactiveCommands,
@@ -348,21 +491,38 @@ async fn handle_proactive_command(
             debounce,
             config,
             verbose,
+            control_file,
         } => {
-            handle_proactive_start(gap_interval, max_tasks, debounce, config, verbose).await?;
+            handle_proactive_start(
+                gap_interval,
+                max_tasks,
+                debounce,
+                config,
+                verbose,
+                control_file,
+            )
+            .await?;
         }
-        ProactiveCommands::Stop { force, timeout } => {
-            handle_proactive_stop(force, timeout).await?;
+        ProactiveCommands::Stop {
+            force,
+            timeout,
+            control_file,
+        } => {
+            handle_proactive_stop(force, timeout, control_file).await?;
         }
         ProactiveCommands::Status {
             detailed,
             metrics,
             recent,
+            control_file,
         } => {
-            handle_proactive_status(detailed, metrics, recent).await?;
+            handle_proactive_status(detailed, metrics, recent, control_file).await?;
         }
-        ProactiveCommands::Configure { action } => {
-            handle_proactive_configure(action).await?;
+        ProactiveCommands::Configure {
+            action,
+            control_file,
+        } => {
+            handle_proactive_configure(action, control_file).await?;
         }
     }
     Ok(())
@@ -375,6 +535,7 @@ async fn handle_proactive_start(
     debounce: u64,
     config_path: Option<String>,
     verbose: bool,
+    control_file: PathBuf,
 ) -> Result<(), ProactiveManagerError> {
     info!("Starting proactive research mode");
 
@@ -391,6 +552,7 @@ async fn handle_proactive_start(
     // Create configuration
     let mut config = ProactiveManagerConfig::default();
     config.executor.max_concurrent_tasks = max_tasks;
+    config.research_scheduler.gap_analysis_interval = Duration::from_secs(gap_interval * 60);
     config.config_path = config_path.as_ref().map(PathBuf::from);
 
     // Load config from file if specified
@@ -411,6 +573,27 @@ async fn handle_proactive_start(
         }
     }
 
+    // Refuse to start a second daemon over a live one
+    if let Ok(client) = ControlClient::connect(&control_file).await {
+        if client.send(ControlRequest::ListConfig).await.is_ok() {
+            println!(
+                "❌ Proactive research mode is already running (pid {})",
+                client.endpoint().pid
+            );
+            return Err(ProactiveManagerError::AlreadyRunning);
+        }
+    }
+
+    // Background research draws on the proactive share of provider budgets
+    let notifications = manager.notification_system();
+    match create_research_pipeline(RequestOrigin::Proactive, Some(notifications)).await {
+        Ok(pipeline) => manager = manager.with_research_pipeline(Arc::new(pipeline)),
+        Err(e) => {
+            warn!("Proactive research will be simulated: {}", e);
+            println!("⚠️  No research providers available, research tasks will be simulated");
+        }
+    }
+
     // Start the manager
     match manager.start().await {
         Ok(()) => {
@@ -418,14 +601,29 @@ async fn handle_proactive_start(
             info!("Proactive research mode started with gap_interval={}min, max_tasks={}, debounce={}s",
                   gap_interval, max_tasks, debounce);
 
-            // Keep the process running
-            println!("Press Ctrl+C to stop...");
-            tokio::signal::ctrl_c().await.map_err(|e| {
-                ProactiveManagerError::Configuration(format!("Signal handling error: {e}"))
-            })?;
-
-            println!("\n🛑 Shutting down proactive research mode...");
-            manager.stop(false, Duration::from_secs(30)).await?;
+            let manager = Arc::new(Mutex::new(manager));
+            let server = ControlServer::bind(&control_file).await?;
+            let mut control = tokio::spawn(server.serve(manager.clone()));
+
+            // Keep the process running until Ctrl+C or `fortitude proactive stop`
+            println!("Press Ctrl+C or run `fortitude proactive stop` to stop...");
+            tokio::select! {
+                signal = tokio::signal::ctrl_c() => {
+                    signal.map_err(|e| {
+                        ProactiveManagerError::Configuration(format!("Signal handling error: {e}"))
+                    })?;
+                    control.abort();
+                    let _ = control.await;
+                    println!("\n🛑 Shutting down proactive research mode...");
+                    manager.lock().await.stop(false, Duration::from_secs(30)).await?;
+                }
+                served = &mut control => {
+                    served.map_err(|e| {
+                        ProactiveManagerError::Configuration(format!("Control channel failed: {e}"))
+                    })??;
+                    println!("🛑 Stop requested through the control channel");
+                }
+            }
             println!("✅ Proactive research mode stopped");
         }
         Err(e) => {
@@ -439,12 +637,20 @@ async fn handle_proactive_start(
 }
 
 /// Handle proactive stop command
-async fn handle_proactive_stop(force: bool, timeout: u64) -> Result<(), ProactiveManagerError> {
+async fn handle_proactive_stop(
+    force: bool,
+    timeout: u64,
+    control_file: PathBuf,
+) -> Result<(), ProactiveManagerError> {
     info!(
         "Stopping proactive research mode (force: {}, timeout: {}s)",
         force, timeout
     );
 
+    let Some(client) = connect_proactive_daemon(&control_file).await? else {
+        return Ok(());
+    };
+
     println!("🛑 Stopping proactive research mode...");
     if force {
         println!("   Force stop requested - terminating immediately");
@@ -452,7 +658,13 @@ async fn handle_proactive_stop(force: bool, timeout: u64) -> Result<(), Proactiv
         println!("   Graceful stop requested - waiting up to {timeout} seconds");
     }
 
-    println!("⚠️  Stop functionality not yet fully implemented");
+    client
+        .send(ControlRequest::Stop {
+            force,
+            timeout_seconds: timeout,
+        })
+        .await?;
+    println!("✅ Proactive research mode stopped");
 
     Ok(())
 }
@@ -462,6 +674,7 @@ async fn handle_proactive_status(
     detailed: bool,
     metrics: bool,
     recent: Option<u64>,
+    control_file: PathBuf,
 ) -> Result<(), ProactiveManagerError> {
     info!(
         "Getting proactive research status (detailed: {}, metrics: {}, recent: {:?})",
@@ -471,53 +684,113 @@ async fn handle_proactive_status(
     println!("📊 Proactive Research System Status");
     println!("==================================");
 
-    println!("⚠️  Status functionality not yet fully implemented");
+    let Some(client) = connect_proactive_daemon(&control_file).await? else {
+        return Ok(());
+    };
+    let ControlResponse::Status { status } = client
+        .send(ControlRequest::Status {
+            detailed,
+            metrics,
+            recent_minutes: recent,
+        })
+        .await?
+    else {
+        return Err(unexpected_control_response());
+    };
+
+    let state = if status.is_running {
+        "running"
+    } else {
+        "stopped"
+    };
+    println!("   State: {state} (pid {})", client.endpoint().pid);
+    if let Some(started_at) = status.started_at {
+        println!("   Started: {}", started_at.format("%Y-%m-%d %H:%M:%S UTC"));
+    }
+    if let Some(uptime) = status.uptime {
+        println!("   Uptime: {}s", uptime.as_secs());
+    }
+    let summary = &status.config_summary;
+    println!(
+        "   Gap analysis interval: {} minutes",
+        summary.gap_interval_minutes
+    );
+    println!(
+        "   Maximum concurrent tasks: {}",
+        summary.max_concurrent_tasks
+    );
 
     if detailed {
         println!("\n📝 Detailed Task Information:");
-        println!("   - Active tasks: 0");
-        println!("   - Completed tasks: 0");
-        println!("   - Failed tasks: 0");
-        println!("   - Detected gaps: 0");
+        println!("   - Active tasks: {}", status.active_tasks);
+        println!("   - Completed tasks: {}", status.completed_tasks);
+        println!("   - Failed tasks: {}", status.failed_tasks);
+        println!("   - Detected gaps: {}", status.detected_gaps);
+        match status.last_gap_analysis {
+            Some(at) => println!(
+                "   - Last gap analysis: {}",
+                at.format("%Y-%m-%d %H:%M:%S UTC")
+            ),
+            None => println!("   - Last gap analysis: never"),
+        }
     }
 
     if metrics {
         println!("\n📈 Performance Metrics:");
-        println!("   - System uptime: N/A");
-        println!("   - Tasks per hour: N/A");
-        println!("   - Gap detection rate: N/A");
-        println!("   - Notification delivery rate: N/A");
+        let uptime_hours = status.uptime.map(|uptime| uptime.as_secs_f64() / 3600.0);
+        match uptime_hours.filter(|hours| *hours > 0.0) {
+            Some(hours) => println!(
+                "   - Tasks per hour: {:.1}",
+                status.completed_tasks as f64 / hours
+            ),
+            None => println!("   - Tasks per hour: N/A"),
+        }
+        println!(
+            "   - Notification channels: {}",
+            summary.notification_channels.join(", ")
+        );
     }
 
-    if let Some(minutes) = recent {
-        println!("\n🕐 Recent Activity (last {minutes} minutes):");
+    let heading = match recent {
+        Some(minutes) => format!("Recent Activity (last {minutes} minutes)"),
+        None => "Recent Activity".to_string(),
+    };
+    println!("\n🕐 {heading}:");
+    if status.recent_activity.is_empty() {
         println!("   - No recent activity");
     }
+    for event in &status.recent_activity {
+        println!(
+            "   - {} {}",
+            event.timestamp.format("%H:%M:%S"),
+            event.description
+        );
+    }
 
     Ok(())
 }
 
 /// Handle proactive configure command
-async fn handle_proactive_configure(action: ConfigureAction) -> Result<(), ProactiveManagerError> {
-    match action {
+async fn handle_proactive_configure(
+    action: ConfigureAction,
+    control_file: PathBuf,
+) -> Result<(), ProactiveManagerError> {
+    let request = match action {
         ConfigureAction::Set { key, value } => {
             info!("Setting configuration: {} = {}", key, value);
             println!("⚙️  Setting configuration: {key} = {value}");
-
-            println!("⚠️  Configuration set functionality not yet fully implemented");
+            ControlRequest::SetConfig { key, value }
         }
         ConfigureAction::Get { key } => {
             info!("Getting configuration value for: {}", key);
             println!("⚙️  Getting configuration value for: {key}");
-
-            println!("⚠️  Configuration get functionality not yet fully implemented");
+            ControlRequest::GetConfig { key }
         }
         ConfigureAction::List => {
             info!("Listing all configuration values");
             println!("⚙️  Current Configuration:");
             println!("========================");
-
-            println!("⚠️  Configuration list functionality not yet fully implemented");
+            ControlRequest::ListConfig
         }
         ConfigureAction::Reset { confirm } => {
             if !confirm {
@@ -527,10 +800,429 @@ async fn handle_proactive_configure(action: ConfigureAction) -> Result<(), Proac
 
             info!("Resetting configuration to defaults");
             println!("⚙️  Resetting configuration to defaults...");
+            ControlRequest::ResetConfig
+        }
+    };
+
+    let Some(client) = connect_proactive_daemon(&control_file).await? else {
+        return Ok(());
+    };
+    match client.send(request).await? {
+        ControlResponse::Value { value } => println!("   {value}"),
+        ControlResponse::Config { values } => {
+            let mut values: Vec<_> = values.into_iter().collect();
+            values.sort();
+            for (key, value) in values {
+                println!("   {key} = {value}");
+            }
+        }
+        ControlResponse::Done => println!("✅ Configuration updated"),
+        _ => return Err(unexpected_control_response()),
+    }
+
+    Ok(())
+}
+
+/// Connect to the running proactive daemon, printing a note when there is none
+async fn connect_proactive_daemon(
+    control_file: &Path,
+) -> Result<Option<ControlClient>, ProactiveManagerError> {
+    let client = match ControlClient::connect(control_file).await {
+        Ok(client) => client,
+        Err(ControlError::DaemonNotRunning(_)) => {
+            println!("⚠️  Proactive research mode is not running");
+            println!("   Start it with `fortitude proactive start`");
+            return Ok(None);
+        }
+        Err(e) => return Err(e.into()),
+    };
+    Ok(Some(client))
+}
+
+fn unexpected_control_response() -> ProactiveManagerError {
+    ControlError::Rejected("unexpected response from the proactive daemon".to_string()).into()
+}
+
+/// Handle workflow commands
+async fn handle_workflow_command(cmd: WorkflowCommands) -> Result<(), Box<dyn std::error::Error>> {
+    match cmd {
+        WorkflowCommands::Run {
+            file,
+            inputs,
+            runs_dir,
+        } => {
+            handle_workflow_run(file, inputs, runs_dir).await?;
+        }
+        WorkflowCommands::Status {
+            run_id,
+            runs_dir,
+            format,
+        } => {
+            handle_workflow_status(run_id, runs_dir, format)?;
+        }
+        WorkflowCommands::List {
+            limit,
+            runs_dir,
+            format,
+        } => {
+            handle_workflow_list(limit, runs_dir, format)?;
+        }
+    }
+    Ok(())
+}
 
-            println!("⚠️  Configuration reset functionality not yet fully implemented");
+/// Parse repeated `key=value` workflow inputs
+fn parse_workflow_inputs(
+    inputs: &[String],
+) -> Result<std::collections::BTreeMap<String, String>, Box<dyn std::error::Error>> {
+    inputs
+        .iter()
+        .map(|input| match input.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                Ok((key.trim().to_string(), value.to_string()))
+            }
+            _ => Err(format!("Invalid workflow input '{input}', expected KEY=VALUE").into()),
+        })
+        .collect()
+}
+
+fn print_workflow_run(run: &fortitude::workflow::WorkflowRun) {
+    println!("🧩 Workflow: {} (run {})", run.workflow, run.id);
+    println!("  📊 Status: {}", run.status);
+    println!(
+        "  🕐 Started: {}",
+        run.started_at.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    if let Some(finished_at) = run.finished_at {
+        println!(
+            "  🏁 Finished: {}",
+            finished_at.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+    }
+    println!("  Steps:");
+    for step in &run.steps {
+        println!(
+            "     • {} [{}] {} (attempts: {})",
+            step.id, step.operation, step.status, step.attempts
+        );
+        if let Some(error) = &step.error {
+            println!("       ↳ {error}");
         }
     }
+}
+
+/// Handle workflow run command
+async fn handle_workflow_run(
+    file: PathBuf,
+    inputs: Vec<String>,
+    runs_dir: PathBuf,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use fortitude::workflow::{
+        PipelineStepExecutor, RunStatus, WorkflowDefinition, WorkflowEngine, WorkflowRunStore,
+    };
+    use std::sync::Arc;
+
+    let definition = WorkflowDefinition::from_file(&file)?;
+    let inputs = parse_workflow_inputs(&inputs)?;
+    info!(
+        "Running workflow '{}' with {} step(s)",
+        definition.name,
+        definition.steps.len()
+    );
+
+    // Workflows run unattended, so their requests are budgeted as batch traffic
+    let pipeline = create_research_pipeline(RequestOrigin::Batch, None).await?;
+    let executor = Arc::new(PipelineStepExecutor::new(Arc::new(pipeline)));
+    let engine = WorkflowEngine::new(executor, WorkflowRunStore::new(runs_dir));
+
+    let run = engine.run(&definition, inputs).await?;
+    println!();
+    print_workflow_run(&run);
+
+    if run.status == RunStatus::Failed {
+        return Err(format!("Workflow run {} failed", run.id).into());
+    }
+    println!("\n✅ Workflow completed successfully");
+    Ok(())
+}
+
+/// Handle workflow status command
+fn handle_workflow_status(
+    run_id: String,
+    runs_dir: PathBuf,
+    format: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let store = fortitude::workflow::WorkflowRunStore::new(runs_dir);
+    let run = store.load(&run_id)?;
+
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&run)?);
+    } else {
+        print_workflow_run(&run);
+    }
+    Ok(())
+}
+
+/// Handle workflow list command
+fn handle_workflow_list(
+    limit: usize,
+    runs_dir: PathBuf,
+    format: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let store = fortitude::workflow::WorkflowRunStore::new(runs_dir);
+    let runs: Vec<_> = store.list()?.into_iter().take(limit).collect();
+
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&runs)?);
+        return Ok(());
+    }
+
+    if runs.is_empty() {
+        println!("No workflow runs found in {}", store.directory().display());
+        return Ok(());
+    }
+
+    println!(
+        "{:<38} {:<24} {:<10} {:<8} STARTED",
+        "RUN ID", "WORKFLOW", "STATUS", "STEPS"
+    );
+    for run in &runs {
+        let completed = run
+            .steps
+            .iter()
+            .filter(|step| step.status == fortitude::workflow::StepStatus::Succeeded)
+            .count();
+        println!(
+            "{:<38} {:<24} {:<10} {:<8} {}",
+            run.id,
+            run.workflow,
+            run.status.to_string(),
+            format!("{completed}/{}", run.steps.len()),
+            run.started_at.format("%Y-%m-%d %H:%M:%S")
+        );
+    }
+    Ok(())
+}
+
+fn handle_prompt_command(cmd: PromptCommands) -> Result<(), Box<dyn std::error::Error>> {
+    use fortitude_core::prompts::PromptTemplateRegistry;
+
+    match cmd {
+        PromptCommands::List { dir, format } => {
+            let registry = PromptTemplateRegistry::load(&dir)?;
+            handle_prompt_list(&registry, &format)?;
+        }
+        PromptCommands::Show {
+            research_type,
+            provider,
+            version,
+            dir,
+        } => {
+            let registry = PromptTemplateRegistry::load(&dir)?;
+            let research_type = research_type.parse()?;
+            let builtin = PromptTemplateRegistry::builtin();
+            let template = match version {
+                Some(version) => registry
+                    .versions(&research_type, provider.as_deref())
+                    .into_iter()
+                    .chain(builtin.versions(&research_type, None))
+                    .find(|template| template.version == version),
+                None => registry
+                    .resolve(&research_type, provider.as_deref())
+                    .or_else(|| builtin.resolve(&research_type, None)),
+            }
+            .ok_or_else(|| format!("No {research_type} prompt template found"))?;
+
+            println!(
+                "📝 {} (v{}, {})",
+                template.name,
+                template.version,
+                template.provider.as_deref().unwrap_or("all providers")
+            );
+            if let Some(source) = &template.source {
+                println!("   {}", source.display());
+            }
+            println!("\n{}", template.template.trim_end());
+        }
+        PromptCommands::Edit {
+            research_type,
+            provider,
+            file,
+            description,
+            dir,
+        } => {
+            let mut registry = PromptTemplateRegistry::load(&dir)?;
+            let research_type = research_type.parse()?;
+            handle_prompt_edit(&mut registry, research_type, provider, file, description)?;
+        }
+    }
+    Ok(())
+}
+
+/// Print every template version, marking the ones requests use
+fn handle_prompt_list(
+    registry: &fortitude_core::prompts::PromptTemplateRegistry,
+    format: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let builtin = fortitude_core::prompts::PromptTemplateRegistry::builtin();
+    let templates: Vec<_> = builtin
+        .templates()
+        .into_iter()
+        .chain(registry.templates())
+        .collect();
+
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&templates)?);
+        return Ok(());
+    }
+
+    println!(
+        "  {:<16} {:<10} {:<8} {:<32} SOURCE",
+        "TYPE", "PROVIDER", "VERSION", "NAME"
+    );
+    for template in templates {
+        let active = template.source.is_some()
+            && registry
+                .resolve(&template.research_type, template.provider.as_deref())
+                .is_some_and(|active| active == template);
+        println!(
+            "{} {:<16} {:<10} {:<8} {:<32} {}",
+            if active { "*" } else { " " },
+            template.research_type.to_string().to_lowercase(),
+            template.provider.as_deref().unwrap_or("all"),
+            format!("v{}", template.version),
+            template.name,
+            template
+                .source
+                .as_ref()
+                .map_or("built-in".to_string(), |source| source
+                    .display()
+                    .to_string())
+        );
+    }
+    println!(
+        "\n* used for new research requests; built-in templates are the starting point for edits"
+    );
+    Ok(())
+}
+
+/// Save a new template version from a file or an editor session
+fn handle_prompt_edit(
+    registry: &mut fortitude_core::prompts::PromptTemplateRegistry,
+    research_type: fortitude_types::ResearchType,
+    provider: Option<String>,
+    file: Option<PathBuf>,
+    description: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use fortitude_core::prompts::{PromptTemplate, PromptTemplateRegistry};
+
+    let builtin = PromptTemplateRegistry::builtin();
+    let base = registry
+        .resolve(&research_type, provider.as_deref())
+        .or_else(|| builtin.resolve(&research_type, None))
+        .cloned()
+        .ok_or_else(|| format!("No {research_type} prompt template found"))?;
+
+    let content = match file {
+        Some(file) => std::fs::read_to_string(&file)?,
+        None => {
+            let draft =
+                std::env::temp_dir().join(format!("fortitude-prompt-{}.hbs", uuid::Uuid::new_v4()));
+            std::fs::write(&draft, &base.template)?;
+            let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+            let status = std::process::Command::new(&editor).arg(&draft).status()?;
+            let content = std::fs::read_to_string(&draft)?;
+            let _ = std::fs::remove_file(&draft);
+            if !status.success() {
+                return Err(format!("{editor} exited with {status}").into());
+            }
+            content
+        }
+    };
+    if content.trim() == base.template.trim() {
+        println!("No changes to the {} template", base.name);
+        return Ok(());
+    }
+
+    let template = PromptTemplate {
+        version: registry.next_version(&research_type, provider.as_deref()),
+        provider,
+        description: description.unwrap_or(base.description),
+        template: content,
+        source: None,
+        ..base
+    };
+    let version = template.version;
+    let name = template.name.clone();
+    let path = registry.save(template)?;
+    println!("✅ Saved {name} v{version} to {}", path.display());
+    Ok(())
+}
+
+/// Print the execution plan for a research topic without calling a provider
+async fn handle_research_dry_run(
+    topic: String,
+    provider: String,
+    cross_validate: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    info!("Planning research on topic: {} (dry run)", topic);
+
+    let pipeline = create_research_pipeline(RequestOrigin::Interactive, None).await?;
+    let provider_pref = if provider == "auto" {
+        None
+    } else {
+        Some(provider)
+    };
+
+    let plan = pipeline
+        .plan_query_enhanced(&topic, None, None, provider_pref, Some(cross_validate))
+        .await?;
+
+    println!("🧭 Execution plan for: {}", plan.query);
+    println!(
+        "  🏷️  Research type: {} (confidence {:.2})",
+        plan.research_type, plan.classification_confidence
+    );
+    if let (Some(audience), Some(domain)) = (&plan.detected_audience, &plan.detected_domain) {
+        println!("  🎯 Context: {audience} / {domain}");
+    }
+    if plan.cache_hit {
+        println!("  ✅ Cache hit - no provider call needed");
+    } else {
+        println!("  ❌ Cache miss");
+    }
+    println!("  📂 Cache key: {}", plan.cache_key);
+    println!("  📚 Context documents: {}", plan.context_documents);
+    println!("  🤖 Provider: {}", plan.provider);
+    println!(
+        "  📝 Template: {}",
+        plan.template.as_deref().unwrap_or("none")
+    );
+    println!("  🔄 Cross-validation: {}", plan.cross_validate);
+    println!(
+        "  🔢 Estimated tokens: {} in / {} out",
+        plan.estimated_input_tokens, plan.estimated_output_tokens
+    );
+    println!("  💰 Estimated cost: ${:.4}", plan.estimated_cost_usd);
+    for estimate in &plan.provider_estimates {
+        println!(
+            "     • {}/{}: ${:.4} - ${:.4}{}",
+            estimate.provider,
+            estimate.model,
+            estimate.cost_min_usd,
+            estimate.cost_max_usd,
+            if estimate.fits_context {
+                ""
+            } else {
+                " (exceeds context window)"
+            }
+        );
+    }
+    println!(
+        "  ⏱️  Estimated time: {}ms",
+        plan.estimated_processing_time_ms
+    );
+    println!("\n💡 Dry run only - no provider was called");
 
     Ok(())
 }
@@ -557,7 +1249,7 @@ async fn handle_research_command(
     println!("  Quality threshold: {quality_threshold:.2}");
 
     // Create a research pipeline with the infrastructure
-    match create_research_pipeline().await {
+    match create_research_pipeline(RequestOrigin::Interactive, None).await {
         Ok(pipeline) => {
             println!("✅ Research pipeline created");
 
@@ -652,6 +1344,17 @@ async fn handle_research_command(
                         }
                     }
 
+                    let related = pipeline.related_results(&result).await;
+                    if !related.is_empty() {
+                        println!("\n🔗 Related Research:");
+                        for related in &related {
+                            let query = &related.query;
+                            let id = &related.id;
+                            let relevance = related.relevance;
+                            println!("  • {query} ({id}, relevance {relevance:.2})");
+                        }
+                    }
+
                     if !was_cached {
                         println!("\n💾 Result saved to reference library for future use");
                     }
@@ -809,24 +1512,21 @@ async fn test_claude_key_validity(api_key: &str) -> bool {
 }
 
 /// Create a research pipeline with multi-provider research engine and cache lookup
-async fn create_research_pipeline(
-) -> Result<fortitude_core::pipeline::ResearchPipeline, Box<dyn std::error::Error>> {
+///
+/// Provider requests are tagged with `origin` so they draw on that origin's
+/// share of each provider's rate limit.
+/// Build a provider manager from the provider API keys in the environment
+async fn create_provider_manager(
+) -> Result<fortitude::providers::ProviderManager, Box<dyn std::error::Error>> {
     use fortitude::providers::config::{ProviderSettings, RateLimitConfig};
     use fortitude::providers::{
+        AzureAuth, AzureDeployment, BedrockProvider, BedrockSettings, CircuitBreakerConfig,
         ClaudeProvider, GeminiProvider, OpenAIProvider, ProviderConfig, ProviderManager,
-        SelectionStrategy,
-    };
-    use fortitude::research_engine_adapter::ProviderManagerAdapter;
-    use fortitude_core::pipeline::{PipelineBuilder, PipelineConfig};
-    use fortitude_core::{
-        BasicClassifier, FileStorage, MultiProviderConfig, MultiProviderResearchEngine,
+        SelectionStrategy, DEFAULT_AZURE_API_VERSION,
     };
-    use fortitude_types::{AudienceContext, ClassificationConfig, DomainContext, StorageConfig};
     use std::sync::Arc;
     use std::time::Duration;
 
-    println!("🔧 Setting up research pipeline with multi-provider support...");
-
     // Set up provider manager with automatic provider selection
     let provider_config = ProviderConfig {
         selection_strategy: SelectionStrategy::Balanced,
@@ -839,9 +1539,16 @@ async fn create_research_pipeline(
         performance_window_size: 100,
         cost_optimization_threshold: 0.1,
         min_quality_threshold: 0.6,
+        circuit_breaker: CircuitBreakerConfig::default(),
     };
 
-    let provider_manager = ProviderManager::new(provider_config).await?;
+    let mut provider_manager = ProviderManager::new(provider_config).await?;
+    if let Some(tracker) = load_cost_tracker() {
+        provider_manager = provider_manager.with_cost_tracker(tracker);
+    }
+    if let Some(breakers) = load_circuit_breakers() {
+        provider_manager = provider_manager.with_circuit_breakers(breakers);
+    }
     let mut provider_count = 0;
 
     // Add OpenAI provider if API key is available
@@ -904,6 +1611,77 @@ async fn create_research_pipeline(
         println!("⚠️  OPENAI_API_KEY environment variable not found");
     }
 
+    // Add Azure OpenAI provider when a deployment is configured
+    if let (Ok(endpoint), Ok(deployment)) = (
+        std::env::var("AZURE_OPENAI_ENDPOINT"),
+        std::env::var("AZURE_OPENAI_DEPLOYMENT"),
+    ) {
+        let credentials = match std::env::var("AZURE_OPENAI_AD_TOKEN") {
+            Ok(token) => Some((token, AzureAuth::AdToken)),
+            Err(_) => std::env::var("AZURE_OPENAI_API_KEY")
+                .ok()
+                .map(|key| (key, AzureAuth::ApiKey)),
+        };
+        match credentials {
+            Some((credential, auth)) if !is_placeholder_key(&credential) => {
+                println!("✅ Configuring Azure OpenAI provider (deployment {deployment})...");
+                let model =
+                    std::env::var("AZURE_OPENAI_MODEL").unwrap_or_else(|_| "gpt-4".to_string());
+                let api_version = std::env::var("AZURE_OPENAI_API_VERSION")
+                    .unwrap_or_else(|_| DEFAULT_AZURE_API_VERSION.to_string());
+                let settings = ProviderSettings::new(credential, model)
+                    .with_endpoint(endpoint)
+                    .with_timeout(Duration::from_secs(30));
+                let azure = AzureDeployment::new(deployment)
+                    .with_api_version(api_version)
+                    .with_auth(auth);
+
+                match OpenAIProvider::azure(settings, azure).await {
+                    Ok(provider) => match provider.health_check().await {
+                        Ok(health_status) => {
+                            provider_manager
+                                .add_provider("azure_openai".to_string(), Arc::new(provider))
+                                .await?;
+                            provider_count += 1;
+                            println!("✅ Azure OpenAI provider added ({health_status:?})");
+                        }
+                        Err(e) => {
+                            println!("❌ Azure OpenAI provider health check failed: {e}");
+                        }
+                    },
+                    Err(e) => println!("❌ Failed to create Azure OpenAI provider: {e}"),
+                }
+            }
+            _ => println!("⚠️  Azure OpenAI needs AZURE_OPENAI_API_KEY or AZURE_OPENAI_AD_TOKEN"),
+        }
+    }
+
+    // Add AWS Bedrock provider when a model is configured
+    if let Ok(model_id) = std::env::var("BEDROCK_MODEL_ID") {
+        println!("✅ Configuring Bedrock provider (model {model_id})...");
+        let mut settings = BedrockSettings::new(model_id).with_timeout(Duration::from_secs(60));
+        if let Ok(region) = std::env::var("BEDROCK_REGION") {
+            settings = settings.with_region(region);
+        }
+        if let Ok(profile) = std::env::var("BEDROCK_PROFILE") {
+            settings = settings.with_profile(profile);
+        }
+
+        match BedrockProvider::new(settings).await {
+            Ok(provider) => match provider.health_check().await {
+                Ok(health_status) => {
+                    provider_manager
+                        .add_provider("bedrock".to_string(), Arc::new(provider))
+                        .await?;
+                    provider_count += 1;
+                    println!("✅ Bedrock provider added ({health_status:?})");
+                }
+                Err(e) => println!("❌ Bedrock provider health check failed: {e}"),
+            },
+            Err(e) => println!("❌ Failed to create Bedrock provider: {e}"),
+        }
+    }
+
     // Add Claude provider if API key is available
     if let Ok(claude_key) =
         std::env::var("CLAUDE_API_KEY").or_else(|_| std::env::var("ANTHROPIC_API_KEY"))
@@ -1033,11 +1811,55 @@ async fn create_research_pipeline(
         println!("   - OPENAI_API_KEY=your-openai-api-key");
         println!("   - CLAUDE_API_KEY=your-claude-api-key (or ANTHROPIC_API_KEY)");
         println!("   - GEMINI_API_KEY=your-gemini-api-key");
+        println!("   - AZURE_OPENAI_ENDPOINT, AZURE_OPENAI_DEPLOYMENT and AZURE_OPENAI_API_KEY");
+        println!("   - BEDROCK_MODEL_ID with AWS credentials (optionally BEDROCK_REGION, BEDROCK_PROFILE)");
         return Err("No API providers configured".into());
     }
 
     println!("🎯 Configured {provider_count} provider(s) for automatic selection");
 
+    Ok(provider_manager)
+}
+
+async fn create_research_pipeline(
+    origin: RequestOrigin,
+    notifications: Option<Arc<NotificationSystem>>,
+) -> Result<fortitude_core::pipeline::ResearchPipeline, Box<dyn std::error::Error>> {
+    use fortitude::providers::ProviderSelection;
+    use fortitude::research_engine_adapter::ProviderManagerAdapter;
+    use fortitude_core::pipeline::{PipelineBuilder, PipelineConfig};
+    use fortitude_core::{
+        create_classifier, FileStorage, MultiProviderConfig, MultiProviderResearchEngine,
+    };
+    use fortitude_types::{
+        AudienceContext, ClassificationConfig, ClassifierKind, DomainContext, StorageConfig,
+    };
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    println!("🔧 Setting up research pipeline with multi-provider support...");
+
+    let mut provider_manager = create_provider_manager().await?;
+    if let Some(notifications) = notifications {
+        provider_manager = provider_manager.with_notification_system(notifications);
+    }
+
+    // Honor the primary provider chosen with `fortitude provider switch`
+    match ProviderSelection::load(&ProviderSelection::default_path()) {
+        Ok(Some(selection)) => match provider_manager
+            .set_preferred_provider(Some(selection.provider.clone()))
+            .await
+        {
+            Ok(()) => println!("🎯 Preferring {} while it is healthy", selection.provider),
+            Err(e) => println!(
+                "⚠️  Selected provider {} is not available: {e}",
+                selection.provider
+            ),
+        },
+        Ok(None) => {}
+        Err(e) => warn!("Ignoring provider selection: {}", e),
+    }
+
     // Create multi-provider research engine
     let multi_provider_config = MultiProviderConfig {
         enable_cross_validation: false, // Disable for CLI to reduce API calls
@@ -1053,24 +1875,57 @@ async fn create_research_pipeline(
         cost_optimization_weight: 0.2,
         quality_optimization_weight: 0.6,
         latency_optimization_weight: 0.2,
+        enable_structured_output: false,
+        max_structured_output_repairs: 2,
     };
 
     // Wrap provider manager in adapter
-    let provider_adapter = ProviderManagerAdapter::new(Arc::new(provider_manager));
-
-    let research_engine = Arc::new(
-        MultiProviderResearchEngine::new(Arc::new(provider_adapter), multi_provider_config).await?,
-    );
+    let provider_adapter =
+        ProviderManagerAdapter::new(Arc::new(provider_manager)).with_origin(origin);
+
+    let mut research_engine =
+        MultiProviderResearchEngine::new(Arc::new(provider_adapter), multi_provider_config).await?;
+    let prompt_templates = fortitude_core::prompts::PromptTemplateRegistry::load(
+        fortitude_core::prompts::DEFAULT_PROMPT_TEMPLATE_DIR,
+    )?;
+    if !prompt_templates.is_empty() {
+        info!(
+            "Using {} prompt template version(s) from {}",
+            prompt_templates.templates().len(),
+            fortitude_core::prompts::DEFAULT_PROMPT_TEMPLATE_DIR
+        );
+        research_engine = research_engine.with_prompt_templates(Arc::new(prompt_templates));
+
+        let experiments = fortitude_core::prompts::PromptExperimentRunner::load(
+            fortitude_core::prompts::DEFAULT_PROMPT_EXPERIMENTS_PATH,
+            fortitude_core::prompts::DEFAULT_PROMPT_EXPERIMENT_LOG,
+        )?;
+        if !experiments.is_empty() {
+            info!(
+                "Running {} prompt experiment(s) from {}",
+                experiments.experiments().len(),
+                fortitude_core::prompts::DEFAULT_PROMPT_EXPERIMENTS_PATH
+            );
+            research_engine = research_engine.with_prompt_experiments(Arc::new(experiments));
+        }
+    }
+    let research_engine = Arc::new(research_engine);
 
     println!("✅ Multi-provider research engine created successfully");
 
     // Create basic components with proper configurations
     // Lower confidence threshold for CLI usage (demo mode)
+    // FORTITUDE_CLASSIFIER=trainable selects the model trained by `learning train-classifier`
+    let classifier_kind = match std::env::var("FORTITUDE_CLASSIFIER") {
+        Ok(kind) => kind.parse()?,
+        Err(_) => ClassifierKind::default(),
+    };
     let classification_config = ClassificationConfig {
         default_threshold: 0.05,
+        classifier: classifier_kind,
         ..Default::default()
     };
-    let classifier = Arc::new(BasicClassifier::new(classification_config));
+    let classifier = create_classifier(&classification_config);
 
     let storage_config = StorageConfig::default();
     let storage = Arc::new(FileStorage::new(storage_config).await?);
@@ -1078,6 +1933,7 @@ async fn create_research_pipeline(
     // Configure the pipeline with multi-provider support enabled
     let config = PipelineConfig {
         max_concurrent: 3,
+        priority_aging_seconds: fortitude_core::DEFAULT_PRIORITY_AGING_SECONDS,
         timeout_seconds: 300,
         enable_caching: true,
         default_audience: AudienceContext::default(),
@@ -1092,9 +1948,22 @@ async fn create_research_pipeline(
         default_provider: "auto".to_string(),
         enable_cross_validation: false,
         quality_threshold: 0.8,
+        max_quality_requeries: 1,
         enable_learning: false,
         enable_monitoring: false,
         auto_apply_learning: false,
+        model_catalog: Default::default(),
+        code_context_budget: fortitude_core::DEFAULT_CODE_CONTEXT_BUDGET,
+        conversation_context_budget: fortitude_core::DEFAULT_CONVERSATION_CONTEXT_BUDGET,
+        evidence_scoring: Default::default(),
+        prompt_budget: Default::default(),
+        cache_stale_after_seconds: Some(fortitude_core::DEFAULT_CACHE_STALE_AFTER_SECONDS),
+        content_filter: Default::default(),
+        markdown: Default::default(),
+        deepening: Default::default(),
+        semantic_cache: Default::default(),
+        related_results: Default::default(),
+        duplicate_check: Default::default(),
     };
 
     // Build the pipeline with research engine (CRITICAL FIX)
@@ -1105,14 +1974,32 @@ async fn create_research_pipeline(
         .with_default_audience(config.default_audience.clone())
         .with_default_domain(config.default_domain.clone())
         .with_context_detection(config.enable_context_detection)
+        .with_quality_threshold(config.quality_threshold)
+        .with_max_quality_requeries(config.max_quality_requeries)
         .with_research_engine(research_engine) // CRITICAL: Add research engine
-        .build(classifier, storage);
+        .build(classifier, storage)
+        .with_quality_scorer(Arc::new(
+            fortitude::quality::ComprehensiveQualityScorer::with_default_config(),
+        ))
+        .with_advisories(load_advisories().await);
 
     println!("✅ Research pipeline created with cache lookup and multi-provider support");
 
     Ok(pipeline)
 }
 
+/// Load the cached RustSec advisory database and keep it refreshed in the background
+async fn load_advisories() -> std::sync::Arc<fortitude_core::AdvisoryService> {
+    use fortitude_core::{AdvisoryService, DEFAULT_ADVISORY_CACHE_PATH};
+
+    let advisories = std::sync::Arc::new(AdvisoryService::new(DEFAULT_ADVISORY_CACHE_PATH));
+    if let Err(e) = advisories.load_cached().await {
+        warn!("Ignoring advisory cache: {}", e);
+    }
+    advisories.clone().spawn_refresh();
+    advisories
+}
+
 /// Handle provider management commands
 async fn handle_provider_command(cmd: ProviderCommands) -> Result<(), Box<dyn std::error::Error>> {
     match cmd {
@@ -1132,6 +2019,9 @@ async fn handle_provider_command(cmd: ProviderCommands) -> Result<(), Box<dyn st
         ProviderCommands::Switch { provider, force } => {
             handle_provider_switch(provider, force).await?;
         }
+        ProviderCommands::Current => {
+            handle_provider_current().await?;
+        }
         ProviderCommands::Configure {
             provider,
             key,
@@ -1182,8 +2072,15 @@ async fn handle_learning_command(cmd: LearningCommands) -> Result<(), Box<dyn st
         } => {
             handle_learning_feedback(target, rating, comment).await?;
         }
-        LearningCommands::Patterns { days, format } => {
-            handle_learning_patterns(days, format).await?;
+        LearningCommands::Patterns {
+            days,
+            format,
+            experiments,
+        } => {
+            handle_learning_patterns(days, format, experiments).await?;
+        }
+        LearningCommands::TrainClassifier { epochs, output } => {
+            handle_learning_train_classifier(epochs, output)?;
         }
         LearningCommands::Configure { key, value } => {
             handle_learning_configure(key, value).await?;
@@ -1248,14 +2145,127 @@ async fn handle_provider_performance(
         provider, period, format
     );
 
+    let Some(tracker) = load_cost_tracker() else {
+        return Err("Cost ledger could not be read".into());
+    };
+    let mut summary = tracker.summary(period.div_ceil(24).max(1) as u32);
+    if let Some(provider) = &provider {
+        summary.providers.retain(|name, _| name == provider);
+        summary.total_cost_usd = summary.providers.values().map(|s| s.cost_usd).sum();
+    }
+
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+        return Ok(());
+    }
+
     println!("📊 Provider Performance Metrics");
     println!("===============================");
 
-    println!("Implementation in progress...");
+    if summary.providers.is_empty() {
+        println!("No provider usage recorded in the last {period}h");
+    } else {
+        println!(
+            "{:<12} {:>9} {:>13} {:>13} {:>11}",
+            "Provider", "Requests", "Input tokens", "Output tokens", "Cost (USD)"
+        );
+        for (name, spend) in &summary.providers {
+            println!(
+                "{:<12} {:>9} {:>13} {:>13} {:>11.4}",
+                name, spend.requests, spend.input_tokens, spend.output_tokens, spend.cost_usd
+            );
+        }
+        println!("Total spend: ${:.4}", summary.total_cost_usd);
+    }
+
+    let budget = &summary.budget;
+    let limit = |limit: Option<f64>| limit.map_or("unlimited".to_string(), |l| format!("${l:.2}"));
+    println!();
+    println!("💰 Budget: {:?}", budget.status);
+    println!(
+        "  Today:      ${:.4} of {}",
+        budget.daily_spend_usd,
+        limit(budget.daily_limit_usd)
+    );
+    println!(
+        "  This month: ${:.4} of {}",
+        budget.monthly_spend_usd,
+        limit(budget.monthly_limit_usd)
+    );
+    if let Some(resets_at) = budget.resets_at {
+        println!("  Requests are blocked until {resets_at}");
+    }
 
     Ok(())
 }
 
+/// Open the provider spend ledger with budgets from the environment
+fn load_cost_tracker() -> Option<std::sync::Arc<fortitude_core::CostTracker>> {
+    use fortitude_core::{CostBudgetConfig, CostTracker, DEFAULT_COST_LEDGER_PATH};
+
+    let config = CostBudgetConfig::from_env();
+    if let Err(e) = config.validate() {
+        warn!("Ignoring invalid spending budget: {}", e);
+        return None;
+    }
+    match CostTracker::load(DEFAULT_COST_LEDGER_PATH, config) {
+        Ok(tracker) => Some(std::sync::Arc::new(tracker)),
+        Err(e) => {
+            warn!("Provider spend will not be tracked: {}", e);
+            None
+        }
+    }
+}
+
+/// Open the circuit breaker state shared by CLI invocations
+fn load_circuit_breakers() -> Option<std::sync::Arc<fortitude::providers::CircuitBreakers>> {
+    use fortitude::providers::{CircuitBreakers, DEFAULT_CIRCUIT_BREAKER_PATH};
+
+    match CircuitBreakers::load(DEFAULT_CIRCUIT_BREAKER_PATH, Default::default()) {
+        Ok(breakers) => Some(std::sync::Arc::new(breakers)),
+        Err(e) => {
+            warn!("Circuit breakers will not persist between runs: {}", e);
+            None
+        }
+    }
+}
+
+/// Print the circuit breaker of each provider that has been used
+fn print_circuit_breakers(provider: Option<&str>) {
+    use fortitude::providers::CircuitBreakerState;
+
+    let Some(breakers) = load_circuit_breakers() else {
+        return;
+    };
+    let status: Vec<_> = breakers
+        .status()
+        .into_iter()
+        .filter(|(name, _)| provider.is_none_or(|provider| provider == name))
+        .collect();
+    if status.is_empty() {
+        return;
+    }
+
+    println!("\n🔌 Circuit Breakers");
+    println!(
+        "{:<12} {:<10} {:>9} {:>7} {:>9}",
+        "Provider", "State", "Failures", "Opened", "Rejected"
+    );
+    for (name, breaker) in status {
+        println!(
+            "{:<12} {:<10} {:>9} {:>7} {:>9}",
+            name,
+            breaker.state.as_str(),
+            breaker.consecutive_failures,
+            breaker.times_opened,
+            breaker.rejected_requests
+        );
+        if let CircuitBreakerState::Open { recovery_time, .. } = breaker.state {
+            println!("  Next probe after {recovery_time}");
+        }
+    }
+}
+
 async fn handle_provider_health(
     provider: Option<String>,
     force: bool,
@@ -1398,6 +2408,7 @@ async fn handle_provider_health(
             checked_providers.len()
         );
     }
+    print_circuit_breakers(provider.as_deref());
 
     Ok(())
 }
@@ -1406,16 +2417,111 @@ async fn handle_provider_switch(
     provider: String,
     force: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    use fortitude::providers::ProviderSelection;
+
     info!("Switching to provider: {} (force: {})", provider, force);
 
     println!("🔄 Provider Switch");
     println!("==================");
 
-    println!("Implementation in progress...");
+    let provider = provider.to_lowercase();
+    if !["openai", "claude", "gemini"].contains(&provider.as_str()) {
+        println!("❌ Unknown provider: {provider}");
+        println!("   Available providers: openai, claude, gemini");
+        return Err(format!("Unknown provider: {provider}").into());
+    }
+
+    if force {
+        println!("⚠️  Skipping health check (--force)");
+    } else {
+        print!("Checking {provider} health... ");
+        match probe_provider_health(&provider).await {
+            Ok(true) => println!("✅ Healthy"),
+            Ok(false) => {
+                println!("❌ Unhealthy (API test failed)");
+                println!("💡 Use --force to switch anyway");
+                return Err(format!("Provider {provider} is unhealthy").into());
+            }
+            Err(reason) => {
+                println!("⚠️  {reason}");
+                println!("💡 Use --force to switch anyway");
+                return Err(reason.into());
+            }
+        }
+    }
+
+    let path = ProviderSelection::default_path();
+    let previous = ProviderSelection::load(&path)?;
+    ProviderSelection::new(provider.clone())
+        .with_forced(force)
+        .save(&path)?;
+
+    match previous {
+        Some(previous) if previous.provider != provider => {
+            println!(
+                "✅ Switched primary provider from {} to {provider}",
+                previous.provider
+            );
+        }
+        _ => println!("✅ Primary provider set to {provider}"),
+    }
+    println!("   Saved to {}", path.display());
+
+    Ok(())
+}
+
+async fn handle_provider_current() -> Result<(), Box<dyn std::error::Error>> {
+    use fortitude::providers::ProviderSelection;
+
+    println!("🎯 Current Provider");
+    println!("===================");
+
+    match ProviderSelection::load(&ProviderSelection::default_path())? {
+        Some(selection) => {
+            println!("Primary provider: {}", selection.provider);
+            println!("Selected at: {}", selection.selected_at.to_rfc3339());
+            if selection.forced {
+                println!("⚠️  Selected with --force (health check skipped)");
+            }
+        }
+        None => println!("No provider selected; providers are chosen automatically"),
+    }
 
     Ok(())
 }
 
+/// Check that a provider has a usable API key and answers a test request
+async fn probe_provider_health(provider: &str) -> Result<bool, String> {
+    let key = match provider {
+        "openai" => std::env::var("OPENAI_API_KEY").ok(),
+        "claude" => std::env::var("CLAUDE_API_KEY")
+            .or_else(|_| std::env::var("ANTHROPIC_API_KEY"))
+            .ok(),
+        "gemini" => std::env::var("GEMINI_API_KEY")
+            .or_else(|_| std::env::var("GOOGLE_API_KEY"))
+            .ok(),
+        _ => None,
+    }
+    .filter(|key| !key.is_empty() && !is_placeholder_key(key))
+    .ok_or_else(|| format!("Provider {provider} is not configured (no valid API key)"))?;
+
+    Ok(match provider {
+        "openai" => {
+            let client = reqwest::Client::new();
+            let mut accessible = false;
+            for model in ["gpt-4.1-mini", "gpt-4", "gpt-3.5-turbo"] {
+                if test_model_access(&client, &key, model).await {
+                    accessible = true;
+                    break;
+                }
+            }
+            accessible
+        }
+        "claude" => test_claude_key_validity(&key).await,
+        _ => test_gemini_key_validity(&key).await,
+    })
+}
+
 async fn handle_provider_configure(
     provider: String,
     key: String,
@@ -1459,10 +2565,95 @@ async fn handle_quality_validate(
         query, cross_validate, provider_count
     );
 
+    use fortitude::quality::{
+        ComprehensiveQualityScorer, ConsensusMethod, CrossValidationConfig, CrossValidationEngine,
+        QualityScorer, QualityWeights,
+    };
+    use std::sync::Arc;
+
+    let provider_manager = Arc::new(create_provider_manager().await?);
+    let quality_scorer = Arc::new(ComprehensiveQualityScorer::with_default_config());
+
+    if !cross_validate {
+        let response = provider_manager.execute_query(&query).await?;
+        let score = quality_scorer
+            .evaluate_quality(&query, &response, &QualityWeights::research_optimized())
+            .await?;
+
+        println!("🔍 Quality Validation");
+        println!("=====================");
+        println!("Quality score: {:.2}", score.composite);
+        println!();
+        println!("{response}");
+        return Ok(());
+    }
+
+    let config = CrossValidationConfig {
+        min_providers: 2,
+        max_providers: (provider_count as usize).max(2),
+        consensus_method: ConsensusMethod::EnsembleMerge,
+        ..Default::default()
+    };
+    let engine = CrossValidationEngine::new(config, provider_manager, quality_scorer).await?;
+    let result = engine.validate_across_providers(&query).await?;
+
     println!("🔍 Quality Validation");
     println!("=====================");
+    println!(
+        "Providers: {}  Confidence: {:.2}  Consistency: {:.2}",
+        result.validation_metrics.providers_used,
+        result.confidence_score,
+        result.consistency_analysis.overall_consistency
+    );
+    println!();
+    println!(
+        "{:<14} {:>8} {:>10} {:>10}",
+        "Provider", "Quality", "Agreement", "Time"
+    );
+    let mut providers: Vec<_> = result.provider_responses.values().collect();
+    providers.sort_by(|a, b| a.provider.cmp(&b.provider));
+    for response in providers {
+        println!(
+            "{:<14} {:>8.2} {:>10.2} {:>9.1}s",
+            response.provider,
+            response.quality_score.composite,
+            result
+                .disagreement
+                .provider_agreement
+                .get(&response.provider)
+                .copied()
+                .unwrap_or(1.0),
+            response.response_time.as_secs_f64()
+        );
+    }
 
-    println!("Implementation in progress...");
+    let report = &result.disagreement;
+    println!();
+    if report.has_disagreement() {
+        println!("⚠️  Disagreement report");
+        println!(
+            "  Agreement: {:.2} (threshold met: {})",
+            report.agreement, report.meets_threshold
+        );
+        if !report.outliers.is_empty() {
+            println!("  Outliers: {}", report.outliers.join(", "));
+        }
+        for conflict in &report.conflicts {
+            println!(
+                "  {:?} between {} (severity {:.2}): {}",
+                conflict.conflict_type,
+                conflict.providers.join(" and "),
+                conflict.severity,
+                conflict.description
+            );
+        }
+    } else {
+        println!("✅ Providers agree (agreement {:.2})", report.agreement);
+    }
+
+    println!();
+    println!("Consensus answer:");
+    println!("{}", result.consensus_result);
 
     Ok(())
 }
@@ -1537,26 +2728,180 @@ async fn handle_learning_feedback(
         target, rating
     );
 
+    use fortitude::learning::{
+        FileLearningStorage, LearningStorageService, UserFeedback, DEFAULT_LEARNING_STORE_PATH,
+    };
+    use fortitude_core::{
+        ClassificationTrainingStore, FileStorage, TrainingExample,
+        DEFAULT_CLASSIFICATION_TRAINING_PATH,
+    };
+    use fortitude_types::StorageConfig;
+
     println!("💭 Learning Feedback Submission");
     println!("===============================");
 
-    println!("Implementation in progress...");
+    if !(0.0..=1.0).contains(&rating) {
+        return Err(format!("Rating must be between 0.0 and 1.0, got {rating}").into());
+    }
+
+    let storage = FileStorage::new(StorageConfig::default()).await?;
+    let (cache_key, result) = resolve_feedback_target(&storage, &target).await?;
+    println!("Result:  {cache_key}");
+    println!("Query:   {}", result.request.original_query);
+
+    let user_id = std::env::var("USER").unwrap_or_else(|_| "cli".to_string());
+    let feedback = UserFeedback::new(
+        user_id,
+        cache_key.clone(),
+        "quality_rating".to_string(),
+        Some(rating),
+        comment.clone(),
+    )
+    .with_result_context(&result);
+
+    let learning_storage = FileLearningStorage::open(DEFAULT_LEARNING_STORE_PATH).await?;
+    learning_storage.store_feedback(&feedback).await?;
+    println!("✅ Feedback {} recorded", feedback.id);
+    if let Some(example) = TrainingExample::from_feedback(
+        &result.request.original_query,
+        &result.request.research_type,
+        rating,
+    ) {
+        ClassificationTrainingStore::open(DEFAULT_CLASSIFICATION_TRAINING_PATH).append(&example)?;
+        println!(
+            "Classification as {} kept as a training example",
+            result.request.research_type
+        );
+    }
     if let Some(comment) = comment {
         println!("Comment: {comment}");
     }
 
+    let trend = learning_storage.get_feedback_trend(&cache_key, 30).await?;
+    let average = learning_storage
+        .get_average_feedback_score(&cache_key)
+        .await?
+        .unwrap_or(rating);
+    let total = learning_storage
+        .get_feedback_for_content(&cache_key)
+        .await?
+        .len();
+    println!();
+    println!("📊 Feedback for this result");
+    println!("  Ratings:          {total}");
+    println!("  Average rating:   {average:.2}");
+    println!(
+        "  Last 30 days:     {} ratings, average {:.2}, trend {:+.2}",
+        trend.total_feedback, trend.average_score, trend.trend_direction
+    );
+
     Ok(())
 }
 
+/// Handle classification correction commands
+async fn handle_classify_command(cmd: ClassifyCommands) -> Result<(), Box<dyn std::error::Error>> {
+    match cmd {
+        ClassifyCommands::Correct {
+            target,
+            research_type,
+            reason,
+        } => handle_classify_correct(target, research_type, reason).await,
+    }
+}
+
+async fn handle_classify_correct(
+    target: String,
+    research_type: String,
+    reason: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use fortitude_core::pipeline::{PipelineConfig, ResearchPipeline};
+    use fortitude_core::{
+        create_classifier, ClassificationTrainingStore, FileStorage,
+        DEFAULT_CLASSIFICATION_TRAINING_PATH,
+    };
+    use fortitude_types::{ClassificationConfig, ResearchType, StorageConfig};
+
+    info!(
+        "Correcting classification of {} to {}",
+        target, research_type
+    );
+    let research_type: ResearchType = research_type.parse()?;
+
+    println!("🏷️  Classification Correction");
+    println!("============================");
+
+    let storage = Arc::new(FileStorage::new(StorageConfig::default()).await?);
+    let (cache_key, _) = resolve_feedback_target(&storage, &target).await?;
+    let training = ClassificationTrainingStore::open(DEFAULT_CLASSIFICATION_TRAINING_PATH);
+    let pipeline = ResearchPipeline::new(
+        create_classifier(&ClassificationConfig::default()),
+        storage,
+        PipelineConfig::default(),
+    )
+    .with_classification_training(training.clone());
+
+    let correction = pipeline
+        .correct_classification(&cache_key, research_type, reason.as_deref())
+        .await?;
+    println!("Result:  {cache_key}");
+    println!("Query:   {}", correction.query);
+    if correction.is_change() {
+        println!(
+            "✅ Re-labeled from {} to {}",
+            correction.previous, correction.corrected
+        );
+    } else {
+        println!("✅ Confirmed as {}", correction.corrected);
+    }
+    if let Some(reason) = &correction.reason {
+        println!("Reason:  {reason}");
+    }
+    println!("Training example added to {}", training.path().display());
+
+    Ok(())
+}
+
+/// Find the cached result named by a cache key, or else the best match for a query
+async fn resolve_feedback_target(
+    storage: &fortitude_core::FileStorage,
+    target: &str,
+) -> Result<(String, fortitude_types::ResearchResult), Box<dyn std::error::Error>> {
+    use fortitude_types::{SearchQuery, Storage};
+
+    if let Some(result) = storage.retrieve(target).await? {
+        return Ok((target.to_string(), result));
+    }
+
+    let matches = storage
+        .search(&SearchQuery::new(target.to_string()).with_limit(1))
+        .await?;
+    if let Some(best) = matches.into_iter().next() {
+        if let Some(result) = storage.retrieve(&best.entry.cache_key).await? {
+            println!(
+                "🔎 Matched query \"{}\" (relevance {:.2})",
+                best.entry.original_query, best.relevance_score
+            );
+            return Ok((best.entry.cache_key, result));
+        }
+    }
+
+    Err(format!("No cached research result matches '{target}'").into())
+}
+
 async fn handle_learning_patterns(
     days: u64,
     format: String,
+    experiments: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     info!(
-        "Getting usage patterns (days: {}, format: {})",
-        days, format
+        "Getting usage patterns (days: {}, format: {}, experiments: {})",
+        days, format, experiments
     );
 
+    if experiments {
+        return handle_experiment_report(days, &format).await;
+    }
+
     println!("📈 Usage Patterns Analysis");
     println!("==========================");
 
@@ -1565,6 +2910,193 @@ async fn handle_learning_patterns(
     Ok(())
 }
 
+/// Compare control and variant arms of every prompt experiment
+async fn handle_experiment_report(
+    days: u64,
+    format: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use fortitude::learning::{FileLearningStorage, DEFAULT_LEARNING_STORE_PATH};
+    use fortitude::quality::{
+        compare_prompt_experiments, ABTestResults, ABTestingConfig, ExperimentRating,
+    };
+    use fortitude_core::prompts::{
+        PromptExperimentRunner, DEFAULT_PROMPT_EXPERIMENTS_PATH, DEFAULT_PROMPT_EXPERIMENT_LOG,
+    };
+
+    let runner = PromptExperimentRunner::load(
+        DEFAULT_PROMPT_EXPERIMENTS_PATH,
+        DEFAULT_PROMPT_EXPERIMENT_LOG,
+    )?;
+    let learning_storage = FileLearningStorage::open(DEFAULT_LEARNING_STORE_PATH).await?;
+    let ratings: Vec<ExperimentRating> = learning_storage
+        .all_feedback()
+        .await
+        .into_iter()
+        .filter_map(|feedback| {
+            let (experiment, arm) = feedback.experiment_context()?;
+            Some(ExperimentRating {
+                experiment,
+                arm,
+                score: feedback.score?,
+                submitted_at: feedback.timestamp,
+            })
+        })
+        .collect();
+
+    let since = chrono::Utc::now() - chrono::Duration::days(days as i64);
+    let comparisons = compare_prompt_experiments(
+        runner.experiments(),
+        &runner.observations(),
+        &ratings,
+        &ABTestingConfig::development_defaults(),
+        since,
+    );
+
+    if format == "json" {
+        let results_json = |results: &ABTestResults| {
+            serde_json::json!({
+                "control_mean": results.variant_a_performance,
+                "variant_mean": results.variant_b_performance,
+                "control_samples": results.sample_size_a,
+                "variant_samples": results.sample_size_b,
+                "significance": results.statistical_significance,
+                "recommendation": results.recommendation,
+            })
+        };
+        let report: Vec<serde_json::Value> = comparisons
+            .iter()
+            .map(|comparison| {
+                let experiment = &comparison.experiment;
+                serde_json::json!({
+                    "name": experiment.name,
+                    "research_type": experiment.research_type.to_string(),
+                    "provider": experiment.provider,
+                    "control_version": experiment.control_version,
+                    "variant_version": experiment.variant_version,
+                    "traffic_percentage": experiment.traffic_percentage,
+                    "enabled": experiment.enabled,
+                    "quality": results_json(&comparison.quality),
+                    "ratings": results_json(&comparison.ratings),
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!("🧪 Prompt Template Experiments (last {days} days)");
+    println!("===============================================");
+    if comparisons.is_empty() {
+        println!("No experiments declared in {DEFAULT_PROMPT_EXPERIMENTS_PATH}");
+        return Ok(());
+    }
+    println!("Variant A is the control version and variant B the variant version.");
+
+    for comparison in &comparisons {
+        let experiment = &comparison.experiment;
+        println!();
+        println!(
+            "{} ({}{}): v{} vs v{}, {:.0}% variant traffic{}",
+            experiment.name,
+            experiment.research_type,
+            experiment
+                .provider
+                .as_deref()
+                .map(|provider| format!(", {provider}"))
+                .unwrap_or_default(),
+            experiment.control_version,
+            experiment.variant_version,
+            experiment.traffic_percentage,
+            if experiment.enabled { "" } else { " (paused)" }
+        );
+        println!(
+            "  {:<10} {:>18} {:>18} {:>13}",
+            "Metric", "Control", "Variant", "Significance"
+        );
+        for (metric, results) in [
+            ("Quality", &comparison.quality),
+            ("Ratings", &comparison.ratings),
+        ] {
+            println!(
+                "  {:<10} {:>18} {:>18} {:>12.1}%",
+                metric,
+                format!(
+                    "{:.3} (n={})",
+                    results.variant_a_performance, results.sample_size_a
+                ),
+                format!(
+                    "{:.3} (n={})",
+                    results.variant_b_performance, results.sample_size_b
+                ),
+                results.statistical_significance * 100.0
+            );
+        }
+        println!("  Quality: {}", comparison.quality.recommendation);
+        println!("  Ratings: {}", comparison.ratings.recommendation);
+    }
+
+    Ok(())
+}
+
+fn handle_learning_train_classifier(
+    epochs: usize,
+    output: Option<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use fortitude_core::{
+        evaluate_classifier, ClassificationTrainingStore, TrainableClassifier, TrainingOptions,
+        TrainingSource, DEFAULT_CLASSIFICATION_TRAINING_PATH, DEFAULT_CLASSIFIER_MODEL_PATH,
+    };
+    use fortitude_types::{ClassificationConfig, ClassifierKind};
+
+    info!("Training classifier (epochs: {})", epochs);
+
+    println!("🧠 Classifier Training");
+    println!("======================");
+
+    let store = ClassificationTrainingStore::open(DEFAULT_CLASSIFICATION_TRAINING_PATH);
+    let examples = store.examples()?;
+    let corrections = examples
+        .iter()
+        .filter(|example| example.source == TrainingSource::Correction)
+        .count();
+    println!(
+        "Examples: {} ({} corrections, {} from feedback and fixtures)",
+        examples.len(),
+        corrections,
+        examples.len() - corrections
+    );
+
+    let config = ClassificationConfig {
+        default_threshold: 0.0,
+        classifier: ClassifierKind::Trainable,
+        ..Default::default()
+    };
+    let options = TrainingOptions {
+        epochs,
+        ..Default::default()
+    };
+    let classifier = TrainableClassifier::train(config, &examples, &options)?;
+
+    let labeled: Vec<(String, fortitude_types::ResearchType)> = examples
+        .iter()
+        .map(|example| (example.query.clone(), example.research_type.clone()))
+        .collect();
+    let evaluation = evaluate_classifier(&classifier, &labeled);
+    println!(
+        "Training accuracy: {}/{} ({:.1}%)",
+        evaluation.correct,
+        evaluation.total,
+        evaluation.accuracy() * 100.0
+    );
+
+    let output = output.unwrap_or_else(|| PathBuf::from(DEFAULT_CLASSIFIER_MODEL_PATH));
+    classifier.save(&output)?;
+    println!("✅ Model saved to {}", output.display());
+    println!("Set FORTITUDE_CLASSIFIER=trainable to classify research queries with it");
+
+    Ok(())
+}
+
 async fn handle_learning_configure(
     key: String,
     value: String,
@@ -1675,8 +3207,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             provider,
             cross_validate,
             quality_threshold,
+            dry_run,
         } => {
-            handle_research_command(topic, provider, cross_validate, quality_threshold).await?;
+            if dry_run {
+                handle_research_dry_run(topic, provider, cross_validate).await?;
+            } else {
+                handle_research_command(topic, provider, cross_validate, quality_threshold).await?;
+            }
         }
         Commands::Pipeline { config } => {
             info!("Starting knowledge pipeline with config: {:?}", config);
@@ -1701,6 +3238,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::Proactive(proactive_cmd) => {
             handle_proactive_command(proactive_cmd).await?;
         }
+        Commands::Workflow(workflow_cmd) => {
+            handle_workflow_command(workflow_cmd).await?;
+        }
+        Commands::Prompts(prompt_cmd) => {
+            handle_prompt_command(prompt_cmd)?;
+        }
+        Commands::Classify(classify_cmd) => {
+            handle_classify_command(classify_cmd).await?;
+        }
     }
 
     Ok(())
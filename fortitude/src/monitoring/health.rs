@@ -35,6 +35,7 @@ use std::time::Duration;
 use tokio::sync::RwLock;
 
 use super::{MonitoringError, MonitoringResult};
+use crate::providers::fallback::{CircuitBreakerState, CircuitBreakers};
 
 /// Core health checker for system components
 pub struct HealthChecker {
@@ -435,6 +436,82 @@ impl HealthCheck for BasicHealthCheck {
     }
 }
 
+/// Reports the circuit breaker of each provider
+///
+/// Degraded while any circuit is open or half-open, critical once every
+/// provider's circuit is open.
+pub struct ProviderCircuitHealthCheck {
+    breakers: Arc<CircuitBreakers>,
+}
+
+impl ProviderCircuitHealthCheck {
+    pub fn new(breakers: Arc<CircuitBreakers>) -> Self {
+        Self { breakers }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for ProviderCircuitHealthCheck {
+    async fn check_health(&self) -> MonitoringResult<ComponentHealth> {
+        let start_time = std::time::Instant::now();
+        let status = self.breakers.status();
+        let open = status
+            .values()
+            .filter(|breaker| matches!(breaker.state, CircuitBreakerState::Open { .. }))
+            .count();
+        let tripped = status
+            .values()
+            .filter(|breaker| breaker.state != CircuitBreakerState::Closed)
+            .count();
+
+        let component_name = self.component_name().to_string();
+        let mut health = if open > 0 && open == status.len() {
+            ComponentHealth::critical(
+                component_name,
+                "Circuit breakers are open for every provider".to_string(),
+            )
+        } else if tripped > 0 {
+            ComponentHealth::degraded(
+                component_name,
+                format!(
+                    "{tripped} of {} provider circuits are not closed",
+                    status.len()
+                ),
+            )
+        } else {
+            ComponentHealth::healthy(component_name, "All provider circuits closed".to_string())
+        };
+
+        for (provider, breaker) in status {
+            let message = format!("Circuit {}", breaker.state.as_str());
+            let duration = start_time.elapsed();
+            let result = if breaker.state == CircuitBreakerState::Closed {
+                CheckResult::pass(message, duration)
+            } else {
+                CheckResult::fail(message, duration)
+            };
+            let result = result
+                .with_metadata("state".to_string(), breaker.state.as_str().to_string())
+                .with_metadata(
+                    "consecutive_failures".to_string(),
+                    breaker.consecutive_failures.to_string(),
+                )
+                .with_metadata("times_opened".to_string(), breaker.times_opened.to_string())
+                .with_metadata(
+                    "rejected_requests".to_string(),
+                    breaker.rejected_requests.to_string(),
+                );
+            health = health.with_check(provider, result);
+        }
+
+        Ok(health)
+    }
+
+    fn component_name(&self) -> &str {
+        "provider_circuits"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -562,6 +639,38 @@ mod tests {
         assert!(!health.checks.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_provider_circuit_health_check() {
+        use crate::providers::fallback::CircuitBreakerConfig;
+
+        let breakers = Arc::new(CircuitBreakers::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            ..Default::default()
+        }));
+        breakers.record_success("claude");
+        breakers.record_failure("openai");
+        let check = ProviderCircuitHealthCheck::new(breakers.clone());
+        assert_eq!(
+            check.check_health().await.unwrap().status,
+            HealthStatus::Healthy
+        );
+
+        breakers.record_failure("openai");
+        let health = check.check_health().await.unwrap();
+        assert_eq!(health.status, HealthStatus::Degraded);
+        let openai = &health.checks["openai"];
+        assert!(!openai.passed);
+        assert_eq!(openai.metadata["state"], "open");
+        assert_eq!(openai.metadata["times_opened"], "1");
+
+        breakers.record_failure("claude");
+        breakers.record_failure("claude");
+        assert_eq!(
+            check.check_health().await.unwrap().status,
+            HealthStatus::Critical
+        );
+    }
+
     #[test]
     fn test_check_result_creation() {
         let pass_result = CheckResult::pass("All good".to_string(), Duration::from_millis(100));
@@ -65,7 +65,9 @@ pub use metrics::{
 
 pub use tracing::{Span, SpanBuilder, SpanId, TraceContext, TraceId, TracingService};
 
-pub use health::{ComponentHealth, HealthChecker, HealthReport, HealthStatus};
+pub use health::{
+    ComponentHealth, HealthChecker, HealthReport, HealthStatus, ProviderCircuitHealthCheck,
+};
 
 pub use alerts::{Alert, AlertChannel, AlertManager, AlertRule, AlertSeverity};
 
@@ -27,6 +27,7 @@ use crate::proactive::{
     DetectedGap, EnhancedDetectedGap, GapType, StateManager, StateTransitionMetadata,
 };
 use chrono::{DateTime, Utc};
+use fortitude_core::write_json_atomic_async;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap};
@@ -797,29 +798,10 @@ impl BackgroundScheduler {
                         version: 1,
                     };
 
-                    // Write to temporary file first, then rename for atomicity
-                    let temp_file = config.queue_file.with_extension("tmp");
-                    let json_data =
-                        serde_json::to_string_pretty(&persistence_data).map_err(|e| {
-                            ProactiveError::Internal {
-                                message: format!("JSON serialization failed: {e}"),
-                                component: "background_scheduler".to_string(),
-                                recoverable: false,
-                            }
-                        })?;
-
-                    fs::write(&temp_file, json_data).await.map_err(|e| {
-                        ProactiveError::Internal {
-                            message: format!("Temporary file write failed: {e}"),
-                            component: "background_scheduler".to_string(),
-                            recoverable: true,
-                        }
-                    })?;
-
-                    fs::rename(&temp_file, &config.queue_file)
+                    write_json_atomic_async(&config.queue_file, &persistence_data)
                         .await
                         .map_err(|e| ProactiveError::Internal {
-                            message: format!("File rename failed: {e}"),
+                            message: format!("Queue persistence failed: {e}"),
                             component: "background_scheduler".to_string(),
                             recoverable: true,
                         })?;
@@ -888,12 +870,7 @@ impl BackgroundScheduler {
                 version: 1,
             };
 
-            // Write to temporary file first, then rename for atomicity
-            let temp_file = self.config.queue_file.with_extension("tmp");
-            let json_data = serde_json::to_string_pretty(&persistence_data)?;
-
-            fs::write(&temp_file, json_data).await?;
-            fs::rename(&temp_file, &self.config.queue_file).await?;
+            write_json_atomic_async(&self.config.queue_file, &persistence_data).await?;
 
             // Update last persistence time
             {
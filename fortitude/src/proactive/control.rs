@@ -0,0 +1,434 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Local control channel between `fortitude proactive` commands and a running manager
+// The daemon listens on a localhost TCP port and writes the address and a
+// random token to an endpoint file readable only by its owner. Clients read
+// that file, connect, and exchange one JSON line each way per request; requests
+// without the token are rejected. The endpoint file is removed when the server
+// stops, and a file left by a crashed daemon is reported as no daemon running.
+
+use crate::proactive::{ProactiveManager, ProactiveManagerError, ProactiveStatus};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{Mutex, Notify};
+use tracing::{debug, info, warn};
+
+/// Endpoint file used when none is configured, relative to the working directory
+pub const DEFAULT_CONTROL_FILE: &str = ".fortitude/proactive-control.json";
+
+/// Time a client has to send its request after connecting
+const REQUEST_READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time a client waits for a response to anything but a stop request
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Manager shared between the daemon and its control server
+pub type SharedManager = Arc<Mutex<ProactiveManager>>;
+
+/// Errors of the control channel
+#[derive(Error, Debug)]
+pub enum ControlError {
+    #[error("No running proactive daemon found (endpoint file: {0})")]
+    DaemonNotRunning(PathBuf),
+
+    #[error("Control channel I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid control message: {0}")]
+    Protocol(#[from] serde_json::Error),
+
+    #[error("Timed out waiting for the proactive daemon")]
+    Timeout,
+
+    #[error("Proactive daemon rejected the request: {0}")]
+    Rejected(String),
+}
+
+/// Where a running daemon listens, as written to the endpoint file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlEndpoint {
+    pub address: SocketAddr,
+    pub token: String,
+    pub pid: u32,
+    pub started_at: DateTime<Utc>,
+}
+
+/// Request sent to a running daemon
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ControlRequest {
+    Status {
+        detailed: bool,
+        metrics: bool,
+        recent_minutes: Option<u64>,
+    },
+    Stop {
+        force: bool,
+        timeout_seconds: u64,
+    },
+    GetConfig {
+        key: String,
+    },
+    SetConfig {
+        key: String,
+        value: String,
+    },
+    ListConfig,
+    ResetConfig,
+}
+
+/// Response of a running daemon
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum ControlResponse {
+    Status { status: Box<ProactiveStatus> },
+    Stopped,
+    Value { value: String },
+    Config { values: HashMap<String, String> },
+    Done,
+    Error { message: String },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ControlMessage {
+    token: String,
+    request: ControlRequest,
+}
+
+/// Removes the endpoint file when the server goes away, including on abort
+struct EndpointGuard(PathBuf);
+
+impl Drop for EndpointGuard {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_file(&self.0) {
+            if e.kind() != ErrorKind::NotFound {
+                warn!("Failed to remove control endpoint file {:?}: {}", self.0, e);
+            }
+        }
+    }
+}
+
+/// Control server of a running proactive daemon
+pub struct ControlServer {
+    listener: TcpListener,
+    endpoint: ControlEndpoint,
+    guard: EndpointGuard,
+}
+
+impl ControlServer {
+    /// Listen on a free localhost port and publish it in the endpoint file
+    pub async fn bind(endpoint_file: impl Into<PathBuf>) -> Result<Self, ControlError> {
+        let endpoint_file = endpoint_file.into();
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let endpoint = ControlEndpoint {
+            address: listener.local_addr()?,
+            token: uuid::Uuid::new_v4().simple().to_string(),
+            pid: std::process::id(),
+            started_at: Utc::now(),
+        };
+        write_endpoint_file(&endpoint_file, &endpoint).await?;
+        info!(
+            "Proactive control channel listening on {} ({:?})",
+            endpoint.address, endpoint_file
+        );
+        Ok(Self {
+            listener,
+            endpoint,
+            guard: EndpointGuard(endpoint_file),
+        })
+    }
+
+    pub fn endpoint(&self) -> &ControlEndpoint {
+        &self.endpoint
+    }
+
+    /// Answer requests until a stop request has stopped the manager
+    pub async fn serve(self, manager: SharedManager) -> Result<(), ControlError> {
+        let stopped = Arc::new(Notify::new());
+        let token: Arc<str> = self.endpoint.token.into();
+        loop {
+            tokio::select! {
+                accepted = self.listener.accept() => {
+                    let (stream, peer) = accepted?;
+                    debug!("Control connection from {}", peer);
+                    let manager = manager.clone();
+                    let stopped = stopped.clone();
+                    let token = token.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = handle_connection(stream, &token, manager, stopped).await {
+                            warn!("Control connection from {} failed: {}", peer, e);
+                        }
+                    });
+                }
+                _ = stopped.notified() => break,
+            }
+        }
+        drop(self.guard);
+        info!("Proactive control channel closed");
+        Ok(())
+    }
+}
+
+async fn handle_connection(
+    stream: TcpStream,
+    token: &str,
+    manager: SharedManager,
+    stopped: Arc<Notify>,
+) -> Result<(), ControlError> {
+    let (reader, mut writer) = stream.into_split();
+    let mut line = String::new();
+    tokio::time::timeout(
+        REQUEST_READ_TIMEOUT,
+        BufReader::new(reader).read_line(&mut line),
+    )
+    .await
+    .map_err(|_| ControlError::Timeout)??;
+
+    let message: ControlMessage = serde_json::from_str(&line)?;
+    let (response, stop) = if message.token != token {
+        warn!("Rejected control request with an invalid token");
+        (
+            ControlResponse::Error {
+                message: "invalid control token".to_string(),
+            },
+            false,
+        )
+    } else {
+        let stop = matches!(message.request, ControlRequest::Stop { .. });
+        let response = match dispatch(&manager, message.request).await {
+            Ok(response) => response,
+            Err(e) => ControlResponse::Error {
+                message: e.to_string(),
+            },
+        };
+        let stopped = stop && matches!(response, ControlResponse::Stopped);
+        (response, stopped)
+    };
+
+    let mut payload = serde_json::to_string(&response)?;
+    payload.push('\n');
+    writer.write_all(payload.as_bytes()).await?;
+    writer.shutdown().await?;
+    if stop {
+        stopped.notify_one();
+    }
+    Ok(())
+}
+
+async fn dispatch(
+    manager: &SharedManager,
+    request: ControlRequest,
+) -> Result<ControlResponse, ProactiveManagerError> {
+    let mut manager = manager.lock().await;
+    match request {
+        ControlRequest::Status {
+            detailed,
+            metrics,
+            recent_minutes,
+        } => {
+            let status = manager
+                .get_status(detailed, metrics, recent_minutes)
+                .await?;
+            Ok(ControlResponse::Status {
+                status: Box::new(status),
+            })
+        }
+        ControlRequest::Stop {
+            force,
+            timeout_seconds,
+        } => match manager
+            .stop(force, Duration::from_secs(timeout_seconds))
+            .await
+        {
+            Ok(()) | Err(ProactiveManagerError::NotRunning) => Ok(ControlResponse::Stopped),
+            Err(e) => Err(e),
+        },
+        ControlRequest::GetConfig { key } => Ok(ControlResponse::Value {
+            value: manager.get_config(&key).await?,
+        }),
+        ControlRequest::SetConfig { key, value } => {
+            manager.set_config(&key, &value).await?;
+            Ok(ControlResponse::Done)
+        }
+        ControlRequest::ListConfig => Ok(ControlResponse::Config {
+            values: manager.list_config().await?,
+        }),
+        ControlRequest::ResetConfig => {
+            manager.reset_config().await?;
+            Ok(ControlResponse::Done)
+        }
+    }
+}
+
+async fn write_endpoint_file(path: &Path, endpoint: &ControlEndpoint) -> std::io::Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let content = serde_json::to_vec_pretty(endpoint)?;
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(path).await?;
+    file.write_all(&content).await?;
+    file.flush().await
+}
+
+/// Client for the control channel of a running daemon
+#[derive(Debug, Clone)]
+pub struct ControlClient {
+    endpoint: ControlEndpoint,
+    endpoint_file: PathBuf,
+}
+
+impl ControlClient {
+    /// Read the endpoint file of a running daemon
+    pub async fn connect(endpoint_file: impl Into<PathBuf>) -> Result<Self, ControlError> {
+        let endpoint_file = endpoint_file.into();
+        let content = match tokio::fs::read(&endpoint_file).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Err(ControlError::DaemonNotRunning(endpoint_file))
+            }
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self {
+            endpoint: serde_json::from_slice(&content)?,
+            endpoint_file,
+        })
+    }
+
+    pub fn endpoint(&self) -> &ControlEndpoint {
+        &self.endpoint
+    }
+
+    /// Send a request and wait for the daemon's response
+    ///
+    /// Error responses are returned as [`ControlError::Rejected`].
+    pub async fn send(&self, request: ControlRequest) -> Result<ControlResponse, ControlError> {
+        let wait = match &request {
+            ControlRequest::Stop {
+                timeout_seconds, ..
+            } => Duration::from_secs(*timeout_seconds) + RESPONSE_TIMEOUT,
+            _ => RESPONSE_TIMEOUT,
+        };
+        let mut stream = match TcpStream::connect(self.endpoint.address).await {
+            Ok(stream) => stream,
+            Err(e) if e.kind() == ErrorKind::ConnectionRefused => {
+                return Err(ControlError::DaemonNotRunning(self.endpoint_file.clone()))
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let mut payload = serde_json::to_string(&ControlMessage {
+            token: self.endpoint.token.clone(),
+            request,
+        })?;
+        payload.push('\n');
+        stream.write_all(payload.as_bytes()).await?;
+
+        let mut line = String::new();
+        tokio::time::timeout(wait, BufReader::new(stream).read_line(&mut line))
+            .await
+            .map_err(|_| ControlError::Timeout)??;
+        match serde_json::from_str(&line)? {
+            ControlResponse::Error { message } => Err(ControlError::Rejected(message)),
+            response => Ok(response),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_control_channel_round_trip_and_stop() {
+        let dir = TempDir::new().unwrap();
+        let endpoint_file = dir.path().join("control/proactive-control.json");
+        let manager: SharedManager = Arc::new(Mutex::new(ProactiveManager::with_defaults()));
+        let server = ControlServer::bind(&endpoint_file).await.unwrap();
+        let serving = tokio::spawn(server.serve(manager.clone()));
+
+        let client = ControlClient::connect(&endpoint_file).await.unwrap();
+        let status = client
+            .send(ControlRequest::Status {
+                detailed: true,
+                metrics: false,
+                recent_minutes: None,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(status, ControlResponse::Status { status } if !status.is_running));
+
+        client
+            .send(ControlRequest::SetConfig {
+                key: "max_tasks".to_string(),
+                value: "7".to_string(),
+            })
+            .await
+            .unwrap();
+        let value = client
+            .send(ControlRequest::GetConfig {
+                key: "max_tasks".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(value, ControlResponse::Value { value } if value == "7"));
+        assert!(matches!(
+            client
+                .send(ControlRequest::GetConfig {
+                    key: "bogus".to_string()
+                })
+                .await,
+            Err(ControlError::Rejected(_))
+        ));
+
+        let mut forged = client.clone();
+        forged.endpoint.token = "forged".to_string();
+        assert!(matches!(
+            forged.send(ControlRequest::ResetConfig).await,
+            Err(ControlError::Rejected(message)) if message.contains("token")
+        ));
+        assert_eq!(
+            manager.lock().await.get_config("max_tasks").await.unwrap(),
+            "7"
+        );
+
+        let stopped = client
+            .send(ControlRequest::Stop {
+                force: false,
+                timeout_seconds: 5,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(stopped, ControlResponse::Stopped));
+        serving.await.unwrap().unwrap();
+        assert!(!endpoint_file.exists());
+        assert!(matches!(
+            ControlClient::connect(&endpoint_file).await,
+            Err(ControlError::DaemonNotRunning(_))
+        ));
+    }
+}
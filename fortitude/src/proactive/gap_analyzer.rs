@@ -307,6 +307,11 @@ impl GapAnalyzer {
         Self::new(GapAnalysisConfig::for_rust_project())
     }
 
+    /// Configuration the analyzer was created with
+    pub fn config(&self) -> &GapAnalysisConfig {
+        &self.config
+    }
+
     /// Analyze a file for knowledge gaps
     pub async fn analyze_file(
         &self,
@@ -0,0 +1,495 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Periodic gap analysis that turns uncovered project gaps into queued research tasks
+// Each scan walks the watched project files, runs the gap analyzer on them,
+// drops gaps the knowledge base already covers or whose task from an earlier
+// scan is still pending or executing, and enqueues research tasks for the
+// rest, highest priority first. A scan never queues more tasks than there are free executor slots
+// (`max_concurrent_tasks` minus queued and executing tasks); gaps left over
+// are picked up by the next scan.
+
+use crate::proactive::{
+    BackgroundScheduler, DetectedGap, GapAnalyzer, ResearchTask, TaskExecutor, TaskPriority,
+    TaskState,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, RwLock};
+use tracing::{debug, info, warn};
+
+/// Configuration for the periodic gap analysis scan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GapSchedulerConfig {
+    /// Knowledge base documents (files or directories) checked for existing coverage
+    pub knowledge_paths: Vec<PathBuf>,
+    /// Directory names skipped while walking the project
+    pub exclude_dirs: Vec<String>,
+    /// Upper bound on files analyzed in one scan
+    pub max_files_per_scan: usize,
+}
+
+impl Default for GapSchedulerConfig {
+    fn default() -> Self {
+        Self {
+            knowledge_paths: vec![PathBuf::from("reference_library")],
+            exclude_dirs: [".git", "target", "node_modules", ".fortitude"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            max_files_per_scan: 5000,
+        }
+    }
+}
+
+/// Outcome of one gap analysis scan
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GapScanReport {
+    pub started_at: DateTime<Utc>,
+    pub files_scanned: usize,
+    pub gaps_detected: usize,
+    /// Gaps the knowledge base already documents
+    pub gaps_covered: usize,
+    /// Gaps whose task from an earlier scan is still pending or executing
+    pub gaps_already_queued: usize,
+    pub tasks_enqueued: usize,
+    /// Uncovered gaps left for a later scan because every executor slot was taken
+    pub gaps_deferred: usize,
+}
+
+/// Runs gap analysis over the project and feeds the research task queue
+pub struct GapScheduler {
+    config: GapSchedulerConfig,
+    base_directory: PathBuf,
+    analyzer: Arc<GapAnalyzer>,
+    queue: Arc<BackgroundScheduler>,
+    executor: Option<Arc<TaskExecutor>>,
+    max_concurrent_tasks: usize,
+    /// Gap key to the id of the task queued for it
+    queued_gaps: RwLock<HashMap<String, String>>,
+    last_report: RwLock<Option<GapScanReport>>,
+}
+
+impl GapScheduler {
+    pub fn new(
+        config: GapSchedulerConfig,
+        base_directory: PathBuf,
+        analyzer: Arc<GapAnalyzer>,
+        queue: Arc<BackgroundScheduler>,
+        max_concurrent_tasks: usize,
+    ) -> Self {
+        Self {
+            config,
+            base_directory,
+            analyzer,
+            queue,
+            executor: None,
+            max_concurrent_tasks: max_concurrent_tasks.max(1),
+            queued_gaps: RwLock::new(HashMap::new()),
+            last_report: RwLock::new(None),
+        }
+    }
+
+    /// Count tasks the executor is running against the concurrency limit
+    pub fn with_executor(mut self, executor: Arc<TaskExecutor>) -> Self {
+        self.executor = Some(executor);
+        self
+    }
+
+    /// Configuration the scheduler was created with
+    pub fn config(&self) -> &GapSchedulerConfig {
+        &self.config
+    }
+
+    /// Report of the most recent scan
+    pub async fn last_report(&self) -> Option<GapScanReport> {
+        self.last_report.read().await.clone()
+    }
+
+    /// Scan once, then again every `interval` until `shutdown` fires
+    pub async fn run<F, Fut>(
+        self: Arc<Self>,
+        interval: Duration,
+        mut shutdown: broadcast::Receiver<()>,
+        on_scan: F,
+    ) where
+        F: Fn(GapScanReport) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            tokio::select! {
+                _ = shutdown.recv() => break,
+                _ = ticker.tick() => {
+                    let report = self.scan().await;
+                    on_scan(report).await;
+                }
+            }
+        }
+        debug!("Gap analysis scheduler stopped");
+    }
+
+    /// Analyze the project and enqueue research tasks for uncovered gaps
+    pub async fn scan(&self) -> GapScanReport {
+        let mut report = GapScanReport {
+            started_at: Utc::now(),
+            ..Default::default()
+        };
+
+        // Directory walks and knowledge reads are blocking filesystem work
+        let walk = ProjectWalk {
+            base_directory: self.base_directory.clone(),
+            exclude_dirs: self.config.exclude_dirs.clone(),
+            knowledge_paths: self.knowledge_paths(),
+            max_files: self.config.max_files_per_scan,
+            analyzer: self.analyzer.clone(),
+        };
+        let files = tokio::task::spawn_blocking(move || walk.files())
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Gap analysis project walk failed: {}", e);
+                Vec::new()
+            });
+        report.files_scanned = files.len();
+        let mut gaps = Vec::new();
+        for file in &files {
+            match self.analyzer.analyze_file(file).await {
+                Ok(found) => gaps.extend(found),
+                Err(e) => debug!("Skipping {} in gap analysis: {}", file.display(), e),
+            }
+        }
+        report.gaps_detected = gaps.len();
+        self.enqueue_gaps(gaps, &mut report).await;
+
+        info!(
+            "Gap analysis scanned {} files: {} gaps, {} covered, {} already queued, {} tasks queued, {} deferred",
+            report.files_scanned,
+            report.gaps_detected,
+            report.gaps_covered,
+            report.gaps_already_queued,
+            report.tasks_enqueued,
+            report.gaps_deferred
+        );
+        *self.last_report.write().await = Some(report.clone());
+        report
+    }
+
+    /// Queue research tasks for the gaps the knowledge base does not cover
+    ///
+    /// Gaps already covered or queued are counted and skipped; the rest are
+    /// queued highest priority first until the free executor slots run out.
+    pub async fn enqueue_gaps(&self, gaps: Vec<DetectedGap>, report: &mut GapScanReport) {
+        let knowledge_paths = self.knowledge_paths();
+        let knowledge = tokio::task::spawn_blocking(move || knowledge_text(&knowledge_paths))
+            .await
+            .unwrap_or_default();
+        self.forget_finished_tasks().await;
+        let mut candidates = Vec::new();
+        {
+            let queued = self.queued_gaps.read().await;
+            for gap in gaps {
+                if is_covered(&gap, &knowledge) {
+                    report.gaps_covered += 1;
+                } else if queued.contains_key(&gap_key(&gap)) {
+                    report.gaps_already_queued += 1;
+                } else {
+                    candidates.push(gap);
+                }
+            }
+        }
+        candidates.sort_by(|a, b| {
+            b.priority.cmp(&a.priority).then_with(|| {
+                b.confidence
+                    .partial_cmp(&a.confidence)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+        });
+
+        let slots = self.free_slots().await;
+        report.gaps_deferred += candidates.len().saturating_sub(slots);
+        for gap in candidates.into_iter().take(slots) {
+            let key = gap_key(&gap);
+            let priority = TaskPriority::from_u8(gap.priority);
+            let task = ResearchTask::from_gap(gap, priority);
+            let task_id = task.id.clone();
+            match self.queue.enqueue(task).await {
+                Ok(()) => {
+                    self.queued_gaps.write().await.insert(key, task_id);
+                    report.tasks_enqueued += 1;
+                }
+                Err(e) => {
+                    warn!("Failed to queue research task for gap: {}", e);
+                    report.gaps_deferred += 1;
+                }
+            }
+        }
+    }
+
+    /// Executor slots not already claimed by queued or executing tasks
+    async fn free_slots(&self) -> usize {
+        let executing = match &self.executor {
+            Some(executor) => executor.get_executing_tasks().await.len(),
+            None => 0,
+        };
+        let queued = self.queue.queue_size().await;
+        self.max_concurrent_tasks.saturating_sub(queued + executing)
+    }
+
+    /// Forget gaps whose task completed, failed or was cancelled
+    ///
+    /// A gap that is still uncovered afterwards is queued again by the scan.
+    async fn forget_finished_tasks(&self) {
+        let executing: HashSet<String> = match &self.executor {
+            Some(executor) => executor
+                .get_executing_tasks()
+                .await
+                .into_iter()
+                .map(|progress| progress.task_id)
+                .collect(),
+            None => HashSet::new(),
+        };
+        let tracked: Vec<(String, String)> = self
+            .queued_gaps
+            .read()
+            .await
+            .iter()
+            .map(|(key, task_id)| (key.clone(), task_id.clone()))
+            .collect();
+
+        let mut finished = Vec::new();
+        for (key, task_id) in tracked {
+            if executing.contains(&task_id) {
+                continue;
+            }
+            let live = matches!(
+                self.queue.get_task_by_id(&task_id).await,
+                Ok(Some(task)) if matches!(task.state, TaskState::Pending | TaskState::Executing)
+            );
+            if !live {
+                finished.push(key);
+            }
+        }
+
+        if !finished.is_empty() {
+            debug!("Releasing {} gaps with finished tasks", finished.len());
+            let mut queued = self.queued_gaps.write().await;
+            for key in finished {
+                queued.remove(&key);
+            }
+        }
+    }
+
+    /// Knowledge base paths resolved against the project directory
+    fn knowledge_paths(&self) -> Vec<PathBuf> {
+        self.config
+            .knowledge_paths
+            .iter()
+            .map(|path| {
+                if path.is_absolute() {
+                    path.to_path_buf()
+                } else {
+                    self.base_directory.join(path)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Inputs for walking the project off the async runtime
+struct ProjectWalk {
+    base_directory: PathBuf,
+    exclude_dirs: Vec<String>,
+    knowledge_paths: Vec<PathBuf>,
+    max_files: usize,
+    analyzer: Arc<GapAnalyzer>,
+}
+
+impl ProjectWalk {
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        let mut pending = vec![self.base_directory.clone()];
+        while let Some(dir) = pending.pop() {
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let Ok(file_type) = entry.file_type() else {
+                    continue;
+                };
+                if file_type.is_dir() {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    if !self.exclude_dirs.contains(&name) && !self.is_knowledge_path(&path) {
+                        pending.push(path);
+                    }
+                } else if file_type.is_file() && self.analyzer.config().should_analyze_file(&path) {
+                    files.push(path);
+                    if files.len() >= self.max_files {
+                        warn!("Gap analysis stopped at {} files", self.max_files);
+                        return files;
+                    }
+                }
+            }
+        }
+        files.sort();
+        files
+    }
+
+    fn is_knowledge_path(&self, path: &Path) -> bool {
+        self.knowledge_paths
+            .iter()
+            .any(|knowledge| knowledge == path)
+    }
+}
+
+/// Lowercased text of every knowledge base document
+fn knowledge_text(paths: &[PathBuf]) -> String {
+    let mut text = String::new();
+    let mut pending = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        if path.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else if let Ok(content) = std::fs::read_to_string(&path) {
+            text.push_str(&content.to_lowercase());
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// Identity of a gap across scans
+pub(crate) fn gap_key(gap: &DetectedGap) -> String {
+    format!(
+        "{:?}|{}|{}",
+        gap.gap_type,
+        gap.file_path.display(),
+        gap.description
+    )
+}
+
+/// Whether the knowledge base already mentions what the gap is about
+fn is_covered(gap: &DetectedGap, knowledge: &str) -> bool {
+    if knowledge.is_empty() {
+        return false;
+    }
+    let subject = [
+        "api_name",
+        "crate_name",
+        "function_name",
+        "struct_name",
+        "config_key",
+    ]
+    .iter()
+    .find_map(|key| gap.metadata.get(*key));
+    match subject {
+        Some(subject) => knowledge
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .any(|word| word == subject.to_lowercase()),
+        None => {
+            let description = gap.description.trim().to_lowercase();
+            description.len() >= 8 && knowledge.contains(&description)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proactive::{BackgroundSchedulerConfig, GapAnalysisConfig};
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_scan_skips_covered_gaps_and_respects_free_slots() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("src/lib.rs"),
+            "// TODO: Handle retry backoff for uploads\n// TODO: Add metrics export\n// TODO: Support offline mode\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(dir.path().join("target")).unwrap();
+        std::fs::write(dir.path().join("target/gen.rs"), "// TODO: ignored\n").unwrap();
+        std::fs::create_dir_all(dir.path().join("docs")).unwrap();
+        std::fs::write(
+            dir.path().join("docs/notes.md"),
+            "We add metrics export through the exporter crate.",
+        )
+        .unwrap();
+
+        let analyzer = Arc::new(
+            GapAnalyzer::new(GapAnalysisConfig {
+                enable_docs_detection: false,
+                enable_tech_detection: false,
+                enable_api_detection: false,
+                enable_config_detection: false,
+                ..GapAnalysisConfig::for_rust_project()
+            })
+            .unwrap(),
+        );
+        let queue = Arc::new(
+            BackgroundScheduler::new(BackgroundSchedulerConfig {
+                queue_file: dir.path().join("queue.json"),
+                ..Default::default()
+            })
+            .await
+            .unwrap(),
+        );
+        let scheduler = GapScheduler::new(
+            GapSchedulerConfig {
+                knowledge_paths: vec![PathBuf::from("docs")],
+                ..Default::default()
+            },
+            dir.path().to_path_buf(),
+            analyzer,
+            queue.clone(),
+            1,
+        );
+
+        let first = scheduler.scan().await;
+        assert_eq!(first.files_scanned, 1);
+        assert_eq!(first.gaps_detected, 3);
+        assert_eq!(first.gaps_covered, 1);
+        assert_eq!(first.tasks_enqueued, 1);
+        assert_eq!(first.gaps_deferred, 1);
+        assert_eq!(queue.queue_size().await, 1);
+
+        // The queued task still holds the only slot
+        let second = scheduler.scan().await;
+        assert_eq!(second.tasks_enqueued, 0);
+        assert_eq!(second.gaps_already_queued, 1);
+        assert_eq!(second.gaps_deferred, 1);
+
+        // Taken off the queue and not executing: the task has finished, so
+        // its gap is released and competes for the slot again
+        queue.dequeue().await.unwrap();
+        let third = scheduler.scan().await;
+        assert_eq!(third.tasks_enqueued, 1);
+        assert_eq!(third.gaps_already_queued, 0);
+        assert_eq!(third.gaps_deferred, 1);
+        assert_eq!(scheduler.queued_gaps.read().await.len(), 1);
+        assert_eq!(
+            scheduler.last_report().await.unwrap().tasks_enqueued,
+            third.tasks_enqueued
+        );
+    }
+}
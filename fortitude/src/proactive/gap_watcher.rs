@@ -0,0 +1,608 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: File watcher that queues research for gaps introduced by source changes
+// The watched files are analyzed once on start to record the gaps they already
+// have. After that, every changed file is analyzed again and only the gaps it
+// did not have before are handed to the gap scheduler: new TODO markers, newly
+// imported external APIs and dependencies newly added to a Cargo.toml. Each
+// watched directory selects its files with its own include and exclude globs.
+
+use crate::proactive::gap_scheduler::gap_key;
+use crate::proactive::{
+    DetectedGap, FileMonitor, FileMonitorConfig, GapAnalyzer, GapScanReport, GapScheduler, GapType,
+    MonitorError,
+};
+use chrono::Utc;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, RwLock};
+use tracing::{debug, info, warn};
+
+/// How long to wait for more file events before analyzing a batch
+const EVENT_BATCH_WINDOW: Duration = Duration::from_millis(100);
+
+/// A directory watched for changes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchedDirectory {
+    /// Directory to watch, relative to the project directory unless absolute
+    pub path: PathBuf,
+    /// Globs relative to `path` a file must match; empty selects every file
+    /// the gap analyzer supports
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Globs relative to `path` of files to ignore even when included
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+impl WatchedDirectory {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+        }
+    }
+
+    /// Builder method to set include globs
+    pub fn with_include(mut self, patterns: Vec<String>) -> Self {
+        self.include = patterns;
+        self
+    }
+
+    /// Builder method to set exclude globs
+    pub fn with_exclude(mut self, patterns: Vec<String>) -> Self {
+        self.exclude = patterns;
+        self
+    }
+}
+
+/// Configuration for change-driven gap detection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GapWatcherConfig {
+    /// Directories to watch; the watcher is not started when empty
+    pub directories: Vec<WatchedDirectory>,
+    /// Gap types queued for changes
+    ///
+    /// New dependencies and imported APIs are `UndocumentedTechnology` gaps.
+    pub gap_types: Vec<GapType>,
+}
+
+impl Default for GapWatcherConfig {
+    fn default() -> Self {
+        Self {
+            directories: Vec::new(),
+            gap_types: vec![GapType::TodoComment, GapType::UndocumentedTechnology],
+        }
+    }
+}
+
+/// Watches project directories and queues research for newly introduced gaps
+pub struct GapWatcher {
+    config: GapWatcherConfig,
+    filter: Arc<WatchFilter>,
+    max_files: usize,
+    analyzer: Arc<GapAnalyzer>,
+    scheduler: Arc<GapScheduler>,
+    /// Keys of the gaps each watched file had when last analyzed
+    known_gaps: RwLock<HashMap<PathBuf, HashSet<String>>>,
+    last_report: RwLock<Option<GapScanReport>>,
+}
+
+impl GapWatcher {
+    /// Watch the configured directories, resolved against `base_directory`
+    ///
+    /// Directory names the scheduler skips while scanning are skipped here
+    /// too, and its scan limit caps the files analyzed on start.
+    pub fn new(
+        config: GapWatcherConfig,
+        base_directory: &Path,
+        analyzer: Arc<GapAnalyzer>,
+        scheduler: Arc<GapScheduler>,
+    ) -> Result<Self, MonitorError> {
+        let roots = config
+            .directories
+            .iter()
+            .map(|directory| WatchRoot::new(directory, base_directory))
+            .collect::<Result<Vec<_>, _>>()?;
+        let filter = WatchFilter {
+            roots,
+            exclude_dirs: scheduler.config().exclude_dirs.clone(),
+            analyzer: analyzer.clone(),
+        };
+        Ok(Self {
+            max_files: scheduler.config().max_files_per_scan,
+            config,
+            filter: Arc::new(filter),
+            analyzer,
+            scheduler,
+            known_gaps: RwLock::new(HashMap::new()),
+            last_report: RwLock::new(None),
+        })
+    }
+
+    /// Resolved directories being watched
+    pub fn directories(&self) -> Vec<PathBuf> {
+        self.filter
+            .roots
+            .iter()
+            .map(|root| root.path.clone())
+            .collect()
+    }
+
+    /// Report of the most recent batch of changes
+    pub async fn last_report(&self) -> Option<GapScanReport> {
+        self.last_report.read().await.clone()
+    }
+
+    /// Start a file monitor over the watched directories
+    pub async fn monitor(&self, config: FileMonitorConfig) -> Result<FileMonitor, MonitorError> {
+        FileMonitor::new(self.directories(), config).await
+    }
+
+    /// Prime, then analyze the changes `monitor` reports until `shutdown` fires
+    pub async fn run<F, Fut>(
+        self: Arc<Self>,
+        mut monitor: FileMonitor,
+        mut shutdown: broadcast::Receiver<()>,
+        on_change: F,
+    ) where
+        F: Fn(GapScanReport) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        let primed = self.prime().await;
+        info!(
+            "Watching {} directories for new gaps ({} files primed)",
+            self.filter.roots.len(),
+            primed
+        );
+        loop {
+            tokio::select! {
+                _ = shutdown.recv() => break,
+                Some(event) = monitor.next_event() => {
+                    // Saving or checking out usually touches several files at once
+                    let mut paths = vec![event.path];
+                    while let Ok(Some(event)) =
+                        tokio::time::timeout(EVENT_BATCH_WINDOW, monitor.next_event()).await
+                    {
+                        paths.push(event.path);
+                    }
+                    let report = self.process_changes(&paths).await;
+                    if report.files_scanned > 0 {
+                        on_change(report).await;
+                    }
+                }
+            }
+        }
+        if let Err(e) = monitor.shutdown().await {
+            warn!("Failed to stop gap watcher file monitor: {}", e);
+        }
+        debug!("Gap watcher stopped");
+    }
+
+    /// Record the gaps the watched files have now without queueing research
+    ///
+    /// Returns the number of files analyzed.
+    pub async fn prime(&self) -> usize {
+        let filter = self.filter.clone();
+        let max_files = self.max_files;
+        let files = tokio::task::spawn_blocking(move || filter.files(max_files))
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Gap watcher directory walk failed: {}", e);
+                Vec::new()
+            });
+        for file in &files {
+            self.observe(file).await;
+        }
+        files.len()
+    }
+
+    /// Analyze changed files and queue research for the gaps they introduced
+    ///
+    /// Paths outside the watched directories or filtered out by their globs
+    /// are ignored; removed files are forgotten.
+    pub async fn process_changes(&self, paths: &[PathBuf]) -> GapScanReport {
+        let mut report = GapScanReport {
+            started_at: Utc::now(),
+            ..Default::default()
+        };
+        let mut seen = HashSet::new();
+        let mut gaps = Vec::new();
+        for path in paths {
+            if !seen.insert(path) || !self.filter.matches(path) {
+                continue;
+            }
+            report.files_scanned += 1;
+            gaps.extend(self.observe(path).await);
+        }
+        if report.files_scanned == 0 {
+            return report;
+        }
+
+        report.gaps_detected = gaps.len();
+        if !gaps.is_empty() {
+            self.scheduler.enqueue_gaps(gaps, &mut report).await;
+            info!(
+                "Changes to {} files introduced {} gaps: {} covered, {} already queued, {} tasks queued, {} deferred",
+                report.files_scanned,
+                report.gaps_detected,
+                report.gaps_covered,
+                report.gaps_already_queued,
+                report.tasks_enqueued,
+                report.gaps_deferred
+            );
+        }
+        *self.last_report.write().await = Some(report.clone());
+        report
+    }
+
+    /// Analyze a file and remember its gaps, returning those it did not have before
+    ///
+    /// A file not analyzed before counts all of its gaps as new.
+    async fn observe(&self, path: &Path) -> Vec<DetectedGap> {
+        if !path.is_file() {
+            self.known_gaps.write().await.remove(path);
+            return Vec::new();
+        }
+
+        let found = if is_manifest(path) {
+            match tokio::fs::read_to_string(path).await {
+                Ok(content) => dependency_gaps(path, &content),
+                Err(e) => {
+                    debug!("Skipping {} in gap watcher: {}", path.display(), e);
+                    return Vec::new();
+                }
+            }
+        } else {
+            match self.analyzer.analyze_file(path).await {
+                Ok(found) => found.into_iter().map(api_gap).collect(),
+                Err(e) => {
+                    debug!("Skipping {} in gap watcher: {}", path.display(), e);
+                    return Vec::new();
+                }
+            }
+        };
+
+        let mut keys = HashSet::new();
+        let gaps: Vec<DetectedGap> = found
+            .into_iter()
+            .filter(|gap| self.config.gap_types.contains(&gap.gap_type))
+            .filter(|gap| keys.insert(gap_key(gap)))
+            .collect();
+        let previous = self
+            .known_gaps
+            .write()
+            .await
+            .insert(path.to_path_buf(), keys);
+        match previous {
+            Some(previous) => gaps
+                .into_iter()
+                .filter(|gap| !previous.contains(&gap_key(gap)))
+                .collect(),
+            None => gaps,
+        }
+    }
+}
+
+/// A watched directory with its globs compiled
+#[derive(Debug)]
+struct WatchRoot {
+    path: PathBuf,
+    include: Option<GlobSet>,
+    exclude: GlobSet,
+}
+
+impl WatchRoot {
+    fn new(directory: &WatchedDirectory, base_directory: &Path) -> Result<Self, MonitorError> {
+        let path = if directory.path.is_absolute() {
+            directory.path.clone()
+        } else {
+            base_directory.join(&directory.path)
+        };
+        // File events report canonical paths
+        let path = std::fs::canonicalize(&path).unwrap_or(path);
+        let include = if directory.include.is_empty() {
+            None
+        } else {
+            Some(glob_set(&directory.include)?)
+        };
+        Ok(Self {
+            path,
+            include,
+            exclude: glob_set(&directory.exclude)?,
+        })
+    }
+}
+
+fn glob_set(patterns: &[String]) -> Result<GlobSet, MonitorError> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = Glob::new(pattern).map_err(|e| {
+            MonitorError::Configuration(format!("Invalid watch glob '{pattern}': {e}"))
+        })?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map_err(|e| MonitorError::Configuration(e.to_string()))
+}
+
+/// Decides which files the watcher analyzes
+struct WatchFilter {
+    roots: Vec<WatchRoot>,
+    exclude_dirs: Vec<String>,
+    analyzer: Arc<GapAnalyzer>,
+}
+
+impl WatchFilter {
+    fn matches(&self, path: &Path) -> bool {
+        self.roots.iter().any(|root| {
+            let Ok(relative) = path.strip_prefix(&root.path) else {
+                return false;
+            };
+            let in_excluded_dir = relative
+                .parent()
+                .into_iter()
+                .flat_map(Path::components)
+                .any(|component| {
+                    self.exclude_dirs
+                        .iter()
+                        .any(|dir| component.as_os_str() == dir.as_str())
+                });
+            if in_excluded_dir || root.exclude.is_match(relative) {
+                return false;
+            }
+            match &root.include {
+                Some(include) => include.is_match(relative),
+                None => is_manifest(path) || self.analyzer.config().should_analyze_file(path),
+            }
+        })
+    }
+
+    /// Watched files currently on disk, at most `max_files`
+    fn files(&self, max_files: usize) -> Vec<PathBuf> {
+        let mut files = BTreeSet::new();
+        let mut pending: Vec<PathBuf> = self.roots.iter().map(|root| root.path.clone()).collect();
+        while let Some(dir) = pending.pop() {
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let Ok(file_type) = entry.file_type() else {
+                    continue;
+                };
+                let path = entry.path();
+                if file_type.is_dir() {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    if !self.exclude_dirs.contains(&name) {
+                        pending.push(path);
+                    }
+                } else if file_type.is_file() && self.matches(&path) {
+                    files.insert(path);
+                    if files.len() >= max_files {
+                        warn!("Gap watcher primed only the first {} files", max_files);
+                        return files.into_iter().collect();
+                    }
+                }
+            }
+        }
+        files.into_iter().collect()
+    }
+}
+
+fn is_manifest(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == "Cargo.toml")
+}
+
+/// Name imported APIs by their path so each newly used one is its own gap
+fn api_gap(gap: DetectedGap) -> DetectedGap {
+    if gap.gap_type != GapType::UndocumentedTechnology {
+        return gap;
+    }
+    let Some(full_path) = gap.metadata.get("full_path").cloned() else {
+        return gap;
+    };
+    let Some((_, item)) = full_path.rsplit_once("::") else {
+        return gap;
+    };
+    let mut gap = gap.with_metadata("api_name", item);
+    gap.description = format!("the {full_path} API");
+    gap
+}
+
+/// One gap per dependency a manifest declares, including target-specific ones
+fn dependency_gaps(path: &Path, content: &str) -> Vec<DetectedGap> {
+    let manifest: toml::Table = match toml::from_str(content) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            debug!("Skipping unparsable manifest {}: {}", path.display(), e);
+            return Vec::new();
+        }
+    };
+
+    let mut tables = vec![&manifest];
+    if let Some(workspace) = manifest.get("workspace").and_then(toml::Value::as_table) {
+        tables.push(workspace);
+    }
+    if let Some(targets) = manifest.get("target").and_then(toml::Value::as_table) {
+        tables.extend(targets.values().filter_map(toml::Value::as_table));
+    }
+    let mut names = BTreeSet::new();
+    for table in tables {
+        for kind in ["dependencies", "dev-dependencies", "build-dependencies"] {
+            if let Some(deps) = table.get(kind).and_then(toml::Value::as_table) {
+                names.extend(deps.keys().cloned());
+            }
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|name| {
+            let (line_number, line) = content
+                .lines()
+                .enumerate()
+                .find(|(_, line)| declares(line, &name))
+                .map(|(index, line)| (index + 1, line.trim().to_string()))
+                .unwrap_or((1, name.clone()));
+            DetectedGap::new(
+                GapType::UndocumentedTechnology,
+                path.to_path_buf(),
+                line_number,
+                line,
+                format!("the {name} crate"),
+                0.9,
+            )
+            .with_metadata("crate_name", &name)
+        })
+        .collect()
+}
+
+/// Whether a manifest line starts the entry for dependency `name`
+fn declares(line: &str, name: &str) -> bool {
+    let line = line.trim_start();
+    let rest = line.strip_prefix(name).or_else(|| {
+        line.strip_prefix('[')?
+            .split('.')
+            .next_back()?
+            .strip_prefix(name)
+    });
+    rest.is_some_and(|rest| rest.trim_start().starts_with(['=', '.', ']']))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proactive::{
+        BackgroundScheduler, BackgroundSchedulerConfig, GapAnalysisConfig, GapSchedulerConfig,
+    };
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_changes_queue_only_new_gaps_in_watched_files() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("src/generated")).unwrap();
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\n\n[dependencies]\nserde = \"1\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("src/lib.rs"),
+            "use serde::Serialize;\n\n// TODO: Validate input sizes\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("docs/notes.md"),
+            "Shutdown is signalled over a broadcast channel.",
+        )
+        .unwrap();
+
+        let analyzer = Arc::new(GapAnalyzer::new(GapAnalysisConfig::for_rust_project()).unwrap());
+        let queue = Arc::new(
+            BackgroundScheduler::new(BackgroundSchedulerConfig {
+                queue_file: root.join("queue.json"),
+                ..Default::default()
+            })
+            .await
+            .unwrap(),
+        );
+        let scheduler = Arc::new(GapScheduler::new(
+            GapSchedulerConfig {
+                knowledge_paths: vec![PathBuf::from("docs")],
+                ..Default::default()
+            },
+            root.to_path_buf(),
+            analyzer.clone(),
+            queue.clone(),
+            10,
+        ));
+        let watcher = GapWatcher::new(
+            GapWatcherConfig {
+                directories: vec![WatchedDirectory::new(".")
+                    .with_include(vec!["src/**/*.rs".to_string(), "Cargo.toml".to_string()])
+                    .with_exclude(vec!["src/generated/**".to_string()])],
+                ..Default::default()
+            },
+            root,
+            analyzer,
+            scheduler,
+        )
+        .unwrap();
+        let root = watcher.directories()[0].clone();
+
+        assert_eq!(watcher.prime().await, 2);
+        assert_eq!(queue.queue_size().await, 0);
+
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\n\n[dependencies]\nserde = \"1\"\n\n[dependencies.tokio]\nversion = \"1\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("src/lib.rs"),
+            "use serde::Serialize;\nuse tokio::sync::broadcast;\nuse tokio::fs;\n\n// TODO: Validate input sizes\n// TODO: Stream large uploads\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("src/generated/out.rs"), "// TODO: ignored\n").unwrap();
+        std::fs::write(root.join("notes.rs"), "// TODO: not included\n").unwrap();
+
+        let changed = [
+            root.join("Cargo.toml"),
+            root.join("src/lib.rs"),
+            root.join("src/lib.rs"),
+            root.join("src/generated/out.rs"),
+            root.join("notes.rs"),
+        ];
+        let report = watcher.process_changes(&changed).await;
+        assert_eq!(report.files_scanned, 2);
+        // The tokio crate, two tokio APIs and the new TODO; the broadcast API is documented
+        assert_eq!(report.gaps_detected, 4);
+        assert_eq!(report.gaps_covered, 1);
+        assert_eq!(report.tasks_enqueued, 3);
+        assert_eq!(queue.queue_size().await, 3);
+
+        let mut tasks = Vec::new();
+        while let Some(task) = queue.dequeue().await.unwrap() {
+            tasks.push(task);
+        }
+        let manifest_task = tasks
+            .iter()
+            .find(|task| task.gap.file_path.ends_with("Cargo.toml"))
+            .unwrap();
+        assert_eq!(manifest_task.gap.description, "the tokio crate");
+        assert_eq!(manifest_task.gap.line_number, 7);
+
+        let unchanged = watcher.process_changes(&changed).await;
+        assert_eq!(unchanged.gaps_detected, 0);
+
+        std::fs::remove_file(root.join("src/lib.rs")).unwrap();
+        watcher.process_changes(&[root.join("src/lib.rs")]).await;
+        assert!(!watcher
+            .known_gaps
+            .read()
+            .await
+            .contains_key(&root.join("src/lib.rs")));
+    }
+}
@@ -19,14 +19,16 @@
 
 use crate::proactive::{
     BackgroundScheduler, BackgroundSchedulerConfig, ErrorHandler, ErrorHandlerConfig,
-    ExecutorMetrics, FileMonitor, FileMonitorConfig, GapAnalysisConfig, GapAnalyzer,
-    ImpactAssessmentConfig, ImpactAssessor, NotificationMetrics, NotificationSystem,
-    NotificationSystemConfig, PrioritizationConfig, PriorityScorer, ProgressPerformanceMetrics,
-    ProgressTracker, ProgressTrackerConfig, ResearchCompletionConfig, ResearchCompletionNotifier,
-    ResearchScheduler, ResearchSchedulerConfig, SchedulerMetrics, StateManager, StateManagerConfig,
-    TaskExecutor, TaskExecutorConfig, UserPreferenceManager,
+    ExecutorMetrics, FileMonitor, FileMonitorConfig, GapAnalysisConfig, GapAnalyzer, GapScanReport,
+    GapScheduler, GapSchedulerConfig, GapWatcher, GapWatcherConfig, ImpactAssessmentConfig,
+    ImpactAssessor, NotificationEvent, NotificationMetrics, NotificationSystem,
+    NotificationSystemConfig, NotificationType, PrioritizationConfig, PriorityScorer,
+    ProgressPerformanceMetrics, ProgressTracker, ProgressTrackerConfig, ResearchCompletionConfig,
+    ResearchCompletionNotifier, ResearchScheduler, ResearchSchedulerConfig, SchedulerMetrics,
+    StateManager, StateManagerConfig, TaskExecutor, TaskExecutorConfig, UserPreferenceManager,
 };
 use chrono::{DateTime, Utc};
+use fortitude_core::pipeline::ResearchPipeline;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -65,6 +67,8 @@ pub enum ProactiveManagerError {
 
     #[error("Invalid configuration value: {key} = {value}")]
     InvalidConfigValue { key: String, value: String },
+    #[error("Control channel error: {0}")]
+    Control(#[from] crate::proactive::ControlError),
 }
 
 /// Configuration for the proactive manager
@@ -76,6 +80,14 @@ pub struct ProactiveManagerConfig {
     /// Gap analysis configuration
     pub gap_analysis: GapAnalysisConfig,
 
+    /// Periodic gap analysis scan configuration
+    #[serde(default)]
+    pub gap_scheduler: GapSchedulerConfig,
+
+    /// Directories watched for changes that introduce new gaps
+    #[serde(default)]
+    pub gap_watcher: GapWatcherConfig,
+
     /// Background scheduler configuration
     pub scheduler: BackgroundSchedulerConfig,
 
@@ -124,6 +136,8 @@ impl Default for ProactiveManagerConfig {
         Self {
             file_monitor: FileMonitorConfig::default(),
             gap_analysis: GapAnalysisConfig::default(),
+            gap_scheduler: GapSchedulerConfig::default(),
+            gap_watcher: GapWatcherConfig::default(),
             scheduler: BackgroundSchedulerConfig::default(),
             executor: TaskExecutorConfig::default(),
             research_scheduler: ResearchSchedulerConfig::default(),
@@ -252,13 +266,23 @@ pub struct ProactiveManager {
     gap_analyzer: Option<Arc<GapAnalyzer>>,
 
     /// Background scheduler component
-    #[allow(dead_code)]
-    // TODO: Modular component - will be activated in scheduled research features
     background_scheduler: Option<Arc<BackgroundScheduler>>,
 
     /// Task executor component
     task_executor: Option<Arc<TaskExecutor>>,
 
+    /// Periodic gap analysis scanner
+    gap_scheduler: Option<Arc<GapScheduler>>,
+
+    /// Background task running gap analysis scans
+    gap_scan_handle: Option<tokio::task::JoinHandle<()>>,
+
+    /// Watcher queueing research for gaps introduced by file changes
+    gap_watcher: Option<Arc<GapWatcher>>,
+
+    /// Background task analyzing watched file changes
+    gap_watch_handle: Option<tokio::task::JoinHandle<()>>,
+
     /// Research scheduler component
     research_scheduler: Option<Arc<ResearchScheduler>>,
 
@@ -297,6 +321,9 @@ pub struct ProactiveManager {
     // TODO: Modular component - will be activated in impact analysis features
     impact_assessor: Option<Arc<ImpactAssessor>>,
 
+    /// Pipeline the task executor runs research through
+    research_pipeline: Option<Arc<ResearchPipeline>>,
+
     /// Event history for status reporting
     event_history: Arc<RwLock<Vec<ProactiveEvent>>>,
 
@@ -315,6 +342,10 @@ impl ProactiveManager {
             gap_analyzer: None,
             background_scheduler: None,
             task_executor: None,
+            gap_scheduler: None,
+            gap_scan_handle: None,
+            gap_watcher: None,
+            gap_watch_handle: None,
             research_scheduler: None,
             state_manager: None,
             notification_system: None,
@@ -324,6 +355,7 @@ impl ProactiveManager {
             priority_scorer: None,
             user_preferences: None,
             impact_assessor: None,
+            research_pipeline: None,
             event_history: Arc::new(RwLock::new(Vec::new())),
             shutdown_tx: None,
         }
@@ -334,6 +366,29 @@ impl ProactiveManager {
         Self::new(ProactiveManagerConfig::default())
     }
 
+    /// Run research tasks through `pipeline` instead of simulating them
+    ///
+    /// Build the pipeline's provider adapter with `RequestOrigin::Proactive`
+    /// so background research stays within its rate limit share.
+    pub fn with_research_pipeline(mut self, pipeline: Arc<ResearchPipeline>) -> Self {
+        self.research_pipeline = Some(pipeline);
+        self
+    }
+
+    /// Notification system proactive events are delivered through
+    ///
+    /// Created from the current configuration on first use, so components
+    /// outside the manager, such as provider budget alerts, can share it.
+    pub fn notification_system(&mut self) -> Arc<NotificationSystem> {
+        self.notification_system
+            .get_or_insert_with(|| {
+                Arc::new(NotificationSystem::new(
+                    self.config.notification_system.clone(),
+                ))
+            })
+            .clone()
+    }
+
     /// Start the proactive research system
     #[instrument(skip(self))]
     pub async fn start(&mut self) -> Result<(), ProactiveManagerError> {
@@ -492,24 +547,53 @@ impl ProactiveManager {
             event_history.iter().rev().take(10).cloned().collect()
         };
 
+        let last_scan = match &self.gap_scheduler {
+            Some(gap_scheduler) => gap_scheduler.last_report().await,
+            None => None,
+        };
+        let active_tasks = match &self.task_executor {
+            Some(executor) => executor.get_executing_tasks().await.len(),
+            None => 0,
+        };
+        let (completed_tasks, failed_tasks) = match &self.background_scheduler {
+            Some(queue) => {
+                let queue_metrics = queue.get_metrics().await;
+                (
+                    queue_metrics.completed_tasks as usize,
+                    queue_metrics.failed_tasks as usize,
+                )
+            }
+            None => (0, 0),
+        };
+
+        let notifications = &self.config.notification_system;
+        let mut notification_channels: Vec<String> = notifications
+            .default_channels
+            .iter()
+            .chain(notifications.event_channels.values().flatten())
+            .map(ToString::to_string)
+            .collect();
+        notification_channels.sort();
+        notification_channels.dedup();
+
         // Create configuration summary
         let config_summary = ConfigSummary {
-            gap_interval_minutes: 30, // TODO: Get from actual config
+            gap_interval_minutes: self.gap_interval_minutes(),
             max_concurrent_tasks: self.config.executor.max_concurrent_tasks,
             file_watch_debounce_seconds: 5, // TODO: Get from actual config
             auto_persist_enabled: self.config.auto_persist,
-            notification_channels: vec!["console".to_string()], // TODO: Get from actual config
+            notification_channels,
         };
 
         Ok(ProactiveStatus {
             is_running,
             started_at,
             uptime,
-            active_tasks: 0,         // TODO: Get from state manager
-            completed_tasks: 0,      // TODO: Get from state manager
-            failed_tasks: 0,         // TODO: Get from state manager
-            detected_gaps: 0,        // TODO: Get from gap analyzer
-            last_gap_analysis: None, // TODO: Get from gap analyzer
+            active_tasks,
+            completed_tasks,
+            failed_tasks,
+            detected_gaps: last_scan.as_ref().map_or(0, |scan| scan.gaps_detected),
+            last_gap_analysis: last_scan.map(|scan| scan.started_at),
             scheduler_metrics,
             executor_metrics,
             notification_metrics,
@@ -543,7 +627,8 @@ impl ProactiveManager {
                         value: value.to_string(),
                     });
                 }
-                // TODO: Update actual configuration
+                self.config.research_scheduler.gap_analysis_interval =
+                    Duration::from_secs(minutes * 60);
                 info!("Set gap_interval to {} minutes", minutes);
             }
             "max_tasks" => {
@@ -620,7 +705,7 @@ impl ProactiveManager {
     /// Get configuration value
     pub async fn get_config(&self, key: &str) -> Result<String, ProactiveManagerError> {
         match key {
-            "gap_interval" => Ok("30".to_string()), // TODO: Get from actual config
+            "gap_interval" => Ok(self.gap_interval_minutes().to_string()),
             "max_tasks" => Ok(self.config.executor.max_concurrent_tasks.to_string()),
             "debounce" => Ok("5".to_string()), // TODO: Get from actual config
             "auto_persist" => Ok(self.config.auto_persist.to_string()),
@@ -634,7 +719,10 @@ impl ProactiveManager {
     /// List all configuration values
     pub async fn list_config(&self) -> Result<HashMap<String, String>, ProactiveManagerError> {
         let mut config = HashMap::new();
-        config.insert("gap_interval".to_string(), "30".to_string()); // TODO: Get from actual config
+        config.insert(
+            "gap_interval".to_string(),
+            self.gap_interval_minutes().to_string(),
+        );
         config.insert(
             "max_tasks".to_string(),
             self.config.executor.max_concurrent_tasks.to_string(),
@@ -688,37 +776,190 @@ impl ProactiveManager {
         Ok(())
     }
 
+    /// Gap analysis interval rounded to whole minutes
+    fn gap_interval_minutes(&self) -> u64 {
+        self.config
+            .research_scheduler
+            .gap_analysis_interval
+            .as_secs()
+            .div_ceil(60)
+    }
+
     /// Initialize all components
     async fn initialize_components(&mut self) -> Result<(), ProactiveManagerError> {
-        // TODO: Initialize all components with proper error handling
-        // This is a placeholder implementation
         info!("Initializing proactive research components");
 
         // Create shutdown channel
         let (shutdown_tx, _) = broadcast::channel(1);
         self.shutdown_tx = Some(shutdown_tx);
 
+        let gap_analyzer = Arc::new(GapAnalyzer::new(self.config.gap_analysis.clone()).map_err(
+            |e| ProactiveManagerError::ComponentInitialization {
+                component: "gap_analyzer".to_string(),
+                error: e.to_string(),
+            },
+        )?);
+        let background_scheduler = Arc::new(
+            BackgroundScheduler::new(self.config.scheduler.clone())
+                .await
+                .map_err(|e| ProactiveManagerError::ComponentInitialization {
+                    component: "background_scheduler".to_string(),
+                    error: e.to_string(),
+                })?,
+        );
+        let notification_system = self.notification_system();
+        notification_system.start().await.map_err(|e| {
+            ProactiveManagerError::ComponentInitialization {
+                component: "notification_system".to_string(),
+                error: e.to_string(),
+            }
+        })?;
+        let task_executor = Arc::new(TaskExecutor::new(self.config.executor.clone()));
+        task_executor
+            .configure_notification_system(notification_system.clone())
+            .await
+            .map_err(|e| ProactiveManagerError::ComponentInitialization {
+                component: "task_executor".to_string(),
+                error: e.to_string(),
+            })?;
+        if let Some(pipeline) = &self.research_pipeline {
+            task_executor
+                .configure_research_pipeline(pipeline.clone())
+                .await
+                .map_err(|e| ProactiveManagerError::ComponentInitialization {
+                    component: "task_executor".to_string(),
+                    error: e.to_string(),
+                })?;
+        }
+        let gap_scheduler = GapScheduler::new(
+            self.config.gap_scheduler.clone(),
+            self.config.base_directory.clone(),
+            gap_analyzer.clone(),
+            background_scheduler.clone(),
+            self.config.executor.max_concurrent_tasks,
+        )
+        .with_executor(task_executor.clone());
+        let gap_scheduler = Arc::new(gap_scheduler);
+        if !self.config.gap_watcher.directories.is_empty() {
+            let gap_watcher = GapWatcher::new(
+                self.config.gap_watcher.clone(),
+                &self.config.base_directory,
+                gap_analyzer.clone(),
+                gap_scheduler.clone(),
+            )
+            .map_err(|e| ProactiveManagerError::ComponentInitialization {
+                component: "gap_watcher".to_string(),
+                error: e.to_string(),
+            })?;
+            self.gap_watcher = Some(Arc::new(gap_watcher));
+        }
+
+        self.gap_analyzer = Some(gap_analyzer);
+        self.background_scheduler = Some(background_scheduler);
+        self.task_executor = Some(task_executor);
+        self.gap_scheduler = Some(gap_scheduler);
+
         Ok(())
     }
 
     /// Start all components in dependency order
     async fn start_components(&mut self) -> Result<(), ProactiveManagerError> {
-        // TODO: Start components in proper dependency order
         info!("Starting proactive research components");
+
+        if let (Some(executor), Some(queue)) = (&self.task_executor, &self.background_scheduler) {
+            executor.start(queue.clone()).await.map_err(|e| {
+                ProactiveManagerError::ComponentInitialization {
+                    component: "task_executor".to_string(),
+                    error: e.to_string(),
+                }
+            })?;
+        }
+
+        if let (Some(gap_scheduler), Some(shutdown_tx)) = (&self.gap_scheduler, &self.shutdown_tx) {
+            let interval = self.config.research_scheduler.gap_analysis_interval;
+            let event_history = self.event_history.clone();
+            let notification_system = self.notification_system.clone();
+            info!("Running gap analysis every {:?}", interval);
+            self.gap_scan_handle = Some(tokio::spawn(gap_scheduler.clone().run(
+                interval,
+                shutdown_tx.subscribe(),
+                move |report| {
+                    record_scan_events(event_history.clone(), notification_system.clone(), report)
+                },
+            )));
+        }
+
+        if let (Some(gap_watcher), Some(shutdown_tx)) = (&self.gap_watcher, &self.shutdown_tx) {
+            let monitor = gap_watcher
+                .monitor(self.config.file_monitor.clone())
+                .await
+                .map_err(|e| ProactiveManagerError::ComponentInitialization {
+                    component: "gap_watcher".to_string(),
+                    error: e.to_string(),
+                })?;
+            let event_history = self.event_history.clone();
+            let notification_system = self.notification_system.clone();
+            info!("Watching {:?} for new gaps", gap_watcher.directories());
+            self.gap_watch_handle = Some(tokio::spawn(gap_watcher.clone().run(
+                monitor,
+                shutdown_tx.subscribe(),
+                move |report| {
+                    record_scan_events(event_history.clone(), notification_system.clone(), report)
+                },
+            )));
+        }
+
         Ok(())
     }
 
     /// Gracefully stop all components
     async fn graceful_stop_components(&mut self) -> Result<(), ProactiveManagerError> {
-        // TODO: Implement graceful component shutdown
         info!("Gracefully stopping proactive research components");
+
+        // The scan and watch loops exit on the shutdown signal once their current batch finishes
+        if let Some(handle) = self.gap_scan_handle.take() {
+            let _ = handle.await;
+        }
+        if let Some(handle) = self.gap_watch_handle.take() {
+            let _ = handle.await;
+        }
+        if let Some(executor) = &self.task_executor {
+            if let Err(e) = executor.stop().await {
+                error!("Failed to stop task executor: {}", e);
+            }
+        }
+        if let Some(queue) = &self.background_scheduler {
+            if let Err(e) = queue.persist().await {
+                error!("Failed to persist research queue: {}", e);
+            }
+        }
+        if let Some(notification_system) = &self.notification_system {
+            if let Err(e) = notification_system.stop().await {
+                error!("Failed to stop notification system: {}", e);
+            }
+        }
         Ok(())
     }
 
     /// Force stop all components
     async fn force_stop_components(&mut self) -> Result<(), ProactiveManagerError> {
-        // TODO: Implement force component shutdown
         info!("Force stopping proactive research components");
+
+        if let Some(handle) = self.gap_scan_handle.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.gap_watch_handle.take() {
+            handle.abort();
+        }
+        if let Some(executor) = &self.task_executor {
+            // Stop the executor loop without waiting on in-flight tasks
+            tokio::spawn({
+                let executor = executor.clone();
+                async move {
+                    let _ = executor.stop().await;
+                }
+            });
+        }
         Ok(())
     }
 
@@ -730,20 +971,96 @@ impl ProactiveManager {
         task_id: Option<String>,
         gap_id: Option<String>,
     ) {
-        let event = ProactiveEvent {
-            timestamp: Utc::now(),
-            event_type,
-            description,
-            task_id,
-            gap_id,
-        };
+        push_event(
+            &self.event_history,
+            ProactiveEvent {
+                timestamp: Utc::now(),
+                event_type,
+                description,
+                task_id,
+                gap_id,
+            },
+        )
+        .await;
+    }
+}
+
+/// Append an event, keeping only the last 1000
+async fn push_event(event_history: &RwLock<Vec<ProactiveEvent>>, event: ProactiveEvent) {
+    let mut history = event_history.write().await;
+    history.push(event);
 
-        let mut history = self.event_history.write().await;
-        history.push(event);
+    // Keep only last 1000 events
+    if history.len() > 1000 {
+        history.remove(0);
+    }
+}
 
-        // Keep only last 1000 events
-        if history.len() > 1000 {
-            history.remove(0);
+/// Record what a gap analysis scan found and queued
+async fn record_scan_events(
+    event_history: Arc<RwLock<Vec<ProactiveEvent>>>,
+    notification_system: Option<Arc<NotificationSystem>>,
+    report: GapScanReport,
+) {
+    let uncovered = report.gaps_detected - report.gaps_covered;
+    if uncovered > 0 {
+        if let Some(notification_system) = &notification_system {
+            let metadata = HashMap::from([
+                (
+                    "files_scanned".to_string(),
+                    report.files_scanned.to_string(),
+                ),
+                ("uncovered_gaps".to_string(), uncovered.to_string()),
+                (
+                    "tasks_enqueued".to_string(),
+                    report.tasks_enqueued.to_string(),
+                ),
+            ]);
+            if let Err(e) = notification_system
+                .notify(
+                    NotificationEvent::GapDetected,
+                    NotificationType::Info,
+                    "Knowledge gaps detected".to_string(),
+                    format!(
+                        "{} uncovered gaps in {} files, {} research tasks queued",
+                        uncovered, report.files_scanned, report.tasks_enqueued
+                    ),
+                    metadata,
+                )
+                .await
+            {
+                error!("Failed to send gap notification: {}", e);
+            }
         }
+        push_event(
+            &event_history,
+            ProactiveEvent {
+                timestamp: report.started_at,
+                event_type: ProactiveEventType::GapDetected,
+                description: format!(
+                    "Gap analysis found {} uncovered gaps in {} files",
+                    uncovered, report.files_scanned
+                ),
+                task_id: None,
+                gap_id: None,
+            },
+        )
+        .await;
+    }
+    if report.tasks_enqueued > 0 {
+        push_event(
+            &event_history,
+            ProactiveEvent {
+                timestamp: Utc::now(),
+                event_type: ProactiveEventType::TaskCreated,
+                description: format!(
+                    "Queued {} research tasks, {} deferred to the next scan",
+                    report.tasks_enqueued, report.gaps_deferred
+                ),
+                task_id: None,
+                gap_id: None,
+            },
+        )
+        .await;
     }
 }
@@ -22,9 +22,12 @@ pub mod comprehensive_config;
 pub mod config;
 pub mod configurable_analyzer;
 pub mod context_aware_scorer;
+pub mod control;
 pub mod error_handler;
 pub mod file_monitor;
 pub mod gap_analyzer;
+pub mod gap_scheduler;
+pub mod gap_watcher;
 pub mod impact_assessor;
 pub mod integrated_analyzer;
 pub mod manager;
@@ -70,6 +73,10 @@ pub use context_aware_scorer::{
     ContextAwareScoringConfig, ContextAwareScoringError, DomainPriorityWeights, ExtractedContext,
     UrgencyPriorityScaling,
 };
+pub use control::{
+    ControlClient, ControlEndpoint, ControlError, ControlRequest, ControlResponse, ControlServer,
+    SharedManager, DEFAULT_CONTROL_FILE,
+};
 pub use error_handler::{
     CircuitBreaker, CircuitBreakerConfig, CircuitState, DeadLetterEntry, ErrorClassification,
     ErrorHandler, ErrorHandlerConfig, ErrorMetrics, NetworkStatus, ProactiveError,
@@ -77,6 +84,8 @@ pub use error_handler::{
 };
 pub use file_monitor::{EventType, FileEvent, FileMonitor, FileMonitorConfig, MonitorError};
 pub use gap_analyzer::{DetectedGap, GapAnalysisConfig, GapAnalysisError, GapAnalyzer, GapType};
+pub use gap_scheduler::{GapScanReport, GapScheduler, GapSchedulerConfig};
+pub use gap_watcher::{GapWatcher, GapWatcherConfig, WatchedDirectory};
 pub use impact_assessor::{
     ApiVisibilityAnalysis, DependencyImpactAnalysis, DevelopmentActivityAnalysis,
     ImpactAssessmentConfig, ImpactAssessmentError, ImpactAssessmentMetrics, ImpactAssessmentResult,
@@ -97,9 +106,10 @@ pub use notification_delivery_verifier::{
     NotificationDeliveryVerifier, OverallDeliveryStatus,
 };
 pub use notification_system::{
-    ChannelMetrics, Notification, NotificationChannel, NotificationChannelConfig,
-    NotificationMetrics, NotificationSystem, NotificationSystemConfig, NotificationSystemError,
-    NotificationType,
+    webhook_signature, ChannelMetrics, Notification, NotificationChannel,
+    NotificationChannelConfig, NotificationEvent, NotificationMetrics, NotificationSystem,
+    NotificationSystemConfig, NotificationSystemError, NotificationType, UndeliveredNotification,
+    WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER, WEBHOOK_TIMESTAMP_TOLERANCE_SECS,
 };
 pub use prioritization::{
     DevelopmentContext, DevelopmentPhase, PrioritizationConfig, PrioritizationError,
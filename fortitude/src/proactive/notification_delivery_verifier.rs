@@ -125,6 +125,8 @@ impl DeliveryAttempt {
             NotificationChannel::CLI => "CLI".to_string(),
             NotificationChannel::File { .. } => "File".to_string(),
             NotificationChannel::API { .. } => "API".to_string(),
+            NotificationChannel::Webhook { .. } => "Webhook".to_string(),
+            NotificationChannel::Slack { .. } => "Slack".to_string(),
         }
     }
 
@@ -133,6 +135,9 @@ impl DeliveryAttempt {
             NotificationChannel::CLI => "stdout/stderr".to_string(),
             NotificationChannel::File { path } => format!("file://{}", path.display()),
             NotificationChannel::API { endpoint } => endpoint.clone(),
+            NotificationChannel::Webhook { url, .. } => url.clone(),
+            // The webhook URL embeds Slack's token
+            NotificationChannel::Slack { .. } => "slack incoming webhook".to_string(),
         }
     }
 }
@@ -513,6 +518,8 @@ impl ChannelDeliveryVerifier {
             (NotificationChannel::CLI, "CLI")
                 | (NotificationChannel::File { .. }, "File")
                 | (NotificationChannel::API { .. }, "API")
+                | (NotificationChannel::Webhook { .. }, "Webhook")
+                | (NotificationChannel::Slack { .. }, "Slack")
         )
     }
 
@@ -15,15 +15,19 @@
 // ABOUTME: Multi-channel notification system for proactive research events and status updates
 //! This module provides a comprehensive notification system with multiple delivery channels
 //! for the proactive research system. Features include:
-//! - Multiple notification channels: CLI (stdout/stderr), File (log files), API (HTTP endpoints)
+//! - Multiple notification channels: CLI (stdout/stderr), File (log files), API (HTTP endpoints),
+//!   signed webhooks and Slack
+//! - Per-event channel routing for gap detection, research outcomes and budget alerts
 //! - Different notification types: info, warning, error, progress updates
 //! - Channel-specific formatting and delivery mechanisms
-//! - Async delivery with proper error handling and retry logic
+//! - Async delivery with per-channel retries and a dead-letter log of undelivered notifications
 //! - Configurable notification preferences and filtering
 //! - Integration with existing proactive components for status updates
 
 use chrono::{DateTime, Utc};
+use hmac::{Hmac, KeyInit, Mac};
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
 use std::collections::HashMap;
 use std::io::Write;
 use std::path::PathBuf;
@@ -32,7 +36,20 @@ use thiserror::Error;
 use tokio::fs::OpenOptions;
 use tokio::io::AsyncWriteExt;
 use tokio::sync::{Mutex, RwLock};
-use tracing::{debug, error, info, instrument};
+use tracing::{debug, error, info, instrument, warn};
+
+/// Header carrying the HMAC-SHA256 signature of a webhook delivery
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Fortitude-Signature";
+
+/// Header carrying the Unix time, in seconds, a webhook delivery was signed at
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Fortitude-Timestamp";
+
+/// Largest clock difference, in seconds, a receiver should accept between its
+/// own time and [`WEBHOOK_TIMESTAMP_HEADER`]
+///
+/// The timestamp is part of the signature, so rejecting deliveries outside
+/// this window stops a captured request from being replayed later.
+pub const WEBHOOK_TIMESTAMP_TOLERANCE_SECS: i64 = 300;
 
 /// Errors that can occur in the notification system
 #[derive(Error, Debug, Clone, Serialize, Deserialize)]
@@ -86,6 +103,14 @@ pub enum NotificationChannel {
     File { path: PathBuf },
     /// API-based notifications (HTTP endpoints)
     API { endpoint: String },
+    /// JSON POST to a webhook, signed with `secret` when one is set
+    Webhook {
+        url: String,
+        #[serde(default)]
+        secret: Option<String>,
+    },
+    /// Slack incoming webhook
+    Slack { webhook_url: String },
 }
 
 impl std::fmt::Display for NotificationChannel {
@@ -94,6 +119,34 @@ impl std::fmt::Display for NotificationChannel {
             NotificationChannel::CLI => write!(f, "CLI"),
             NotificationChannel::File { path } => write!(f, "File({})", path.display()),
             NotificationChannel::API { endpoint } => write!(f, "API({endpoint})"),
+            NotificationChannel::Webhook { url, .. } => write!(f, "Webhook({url})"),
+            // Slack webhook URLs are credentials
+            NotificationChannel::Slack { .. } => write!(f, "Slack"),
+        }
+    }
+}
+
+/// Proactive events notifications can be routed by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationEvent {
+    /// Gap analysis found gaps the knowledge base does not cover
+    GapDetected,
+    /// A background research task finished
+    ResearchCompleted,
+    /// A background research task failed
+    ResearchFailed,
+    /// Provider spending reached its budget limit
+    BudgetExceeded,
+}
+
+impl std::fmt::Display for NotificationEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationEvent::GapDetected => write!(f, "gap_detected"),
+            NotificationEvent::ResearchCompleted => write!(f, "research_completed"),
+            NotificationEvent::ResearchFailed => write!(f, "research_failed"),
+            NotificationEvent::BudgetExceeded => write!(f, "budget_exceeded"),
         }
     }
 }
@@ -141,6 +194,9 @@ pub struct Notification {
     pub source_component: Option<String>,
     pub metadata: HashMap<String, String>,
     pub channels: Vec<NotificationChannel>,
+    /// Proactive event the notification reports, if any
+    #[serde(default)]
+    pub event: Option<NotificationEvent>,
 }
 
 impl Notification {
@@ -159,6 +215,7 @@ impl Notification {
             source_component: None,
             metadata: HashMap::new(),
             channels,
+            event: None,
         }
     }
 
@@ -171,6 +228,66 @@ impl Notification {
         self.metadata = metadata;
         self
     }
+
+    pub fn with_event(mut self, event: NotificationEvent) -> Self {
+        self.event = Some(event);
+        self
+    }
+}
+
+/// Notification that could not be delivered to one of its channels
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UndeliveredNotification {
+    pub failed_at: DateTime<Utc>,
+    /// Channel as displayed, without secrets
+    pub channel: String,
+    /// Delivery attempts made, 0 when the channel's rate limit dropped it
+    pub attempts: u32,
+    pub error: String,
+    /// The notification, with its channel list removed
+    pub notification: Notification,
+}
+
+/// Body posted to webhook channels
+#[derive(Debug, Serialize)]
+struct WebhookPayload<'a> {
+    id: &'a str,
+    event: Option<NotificationEvent>,
+    notification_type: String,
+    title: &'a str,
+    message: &'a str,
+    timestamp: DateTime<Utc>,
+    source_component: Option<&'a str>,
+    metadata: &'a HashMap<String, String>,
+}
+
+impl<'a> From<&'a Notification> for WebhookPayload<'a> {
+    fn from(notification: &'a Notification) -> Self {
+        Self {
+            id: &notification.id,
+            event: notification.event,
+            notification_type: notification.notification_type.to_string(),
+            title: &notification.title,
+            message: &notification.message,
+            timestamp: notification.timestamp,
+            source_component: notification.source_component.as_deref(),
+            metadata: &notification.metadata,
+        }
+    }
+}
+
+/// Hex HMAC-SHA256 of `"{timestamp}.{body}"`, as sent in
+/// [`WEBHOOK_SIGNATURE_HEADER`] after `sha256=`
+pub fn webhook_signature(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("{timestamp}.").as_bytes());
+    mac.update(body);
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
 }
 
 /// Configuration for notification channels
@@ -208,6 +325,13 @@ pub struct NotificationSystemConfig {
     pub batch_timeout_ms: u64,
     pub enable_metrics: bool,
     pub enable_delivery_verification: bool,
+    /// Channels per proactive event; events not listed go to `default_channels`
+    /// and an empty list mutes the event
+    #[serde(default)]
+    pub event_channels: HashMap<NotificationEvent, Vec<NotificationChannel>>,
+    /// JSON lines file recording notifications that failed after all retries
+    #[serde(default)]
+    pub dead_letter_path: Option<PathBuf>,
 }
 
 impl Default for NotificationSystemConfig {
@@ -216,6 +340,8 @@ impl Default for NotificationSystemConfig {
         channel_configs.insert("cli".to_string(), NotificationChannelConfig::default());
         channel_configs.insert("file".to_string(), NotificationChannelConfig::default());
         channel_configs.insert("api".to_string(), NotificationChannelConfig::default());
+        channel_configs.insert("webhook".to_string(), NotificationChannelConfig::default());
+        channel_configs.insert("slack".to_string(), NotificationChannelConfig::default());
 
         Self {
             channel_configs,
@@ -226,6 +352,8 @@ impl Default for NotificationSystemConfig {
             batch_timeout_ms: 5000,
             enable_metrics: true,
             enable_delivery_verification: false,
+            event_channels: HashMap::new(),
+            dead_letter_path: None,
         }
     }
 }
@@ -419,6 +547,69 @@ impl NotificationSystem {
         Ok(())
     }
 
+    /// Send a notification for a proactive event to the channels configured for it
+    pub async fn notify(
+        &self,
+        event: NotificationEvent,
+        notification_type: NotificationType,
+        title: String,
+        message: String,
+        metadata: HashMap<String, String>,
+    ) -> Result<(), NotificationSystemError> {
+        let channels = self.channels_for(event);
+        if channels.is_empty() {
+            debug!("Notifications for {} are muted", event);
+            return Ok(());
+        }
+        let notification = Notification::new(notification_type, title, message, channels)
+            .with_metadata(metadata)
+            .with_event(event);
+        self.send(notification).await
+    }
+
+    /// Channels `event` is delivered to
+    pub fn channels_for(&self, event: NotificationEvent) -> Vec<NotificationChannel> {
+        self.config
+            .event_channels
+            .get(&event)
+            .cloned()
+            .unwrap_or_else(|| self.config.default_channels.clone())
+    }
+
+    /// Notifications recorded in the dead-letter log, oldest first
+    pub async fn dead_letters(
+        &self,
+    ) -> Result<Vec<UndeliveredNotification>, NotificationSystemError> {
+        let Some(path) = &self.config.dead_letter_path else {
+            return Ok(Vec::new());
+        };
+        let content = match tokio::fs::read_to_string(path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                return Err(NotificationSystemError::FileIo {
+                    path: path.display().to_string(),
+                    error: e.to_string(),
+                })
+            }
+        };
+        Ok(content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match serde_json::from_str(line) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    warn!(
+                        "Skipping unreadable dead letter in {}: {}",
+                        path.display(),
+                        e
+                    );
+                    None
+                }
+            })
+            .collect())
+    }
+
     /// Send an info notification
     pub async fn info(
         &self,
@@ -567,26 +758,77 @@ impl NotificationSystem {
         let channel_key = self.get_channel_key(channel);
 
         // Check rate limiting
-        if let Some(rate_limiter) = self.rate_limiters.lock().await.get_mut(&channel_key) {
-            if !rate_limiter.can_send() {
-                return Err(NotificationSystemError::RateLimitExceeded {
-                    channel: channel_key,
+        let rate_limited =
+            if let Some(rate_limiter) = self.rate_limiters.lock().await.get_mut(&channel_key) {
+                (!rate_limiter.can_send()).then(|| NotificationSystemError::RateLimitExceeded {
+                    channel: channel_key.clone(),
                     current: rate_limiter.count,
                     limit: rate_limiter.limit,
-                });
-            }
+                })
+            } else {
+                None
+            };
+        if let Some(e) = rate_limited {
+            self.record_dead_letter(notification, channel, 0, &e).await;
+            return Err(e);
         }
 
+        let (retry_count, retry_delay_ms) = self
+            .config
+            .channel_configs
+            .get(&channel_key)
+            .map(|config| (config.retry_count, config.retry_delay_ms))
+            .unwrap_or((0, 0));
+
         let start_time = std::time::Instant::now();
 
-        let result = match channel {
-            NotificationChannel::CLI => self.deliver_to_cli(notification).await,
-            NotificationChannel::File { path } => self.deliver_to_file(notification, path).await,
-            NotificationChannel::API { endpoint } => {
-                self.deliver_to_api(notification, endpoint).await
+        let mut attempts = 0;
+        let result = loop {
+            attempts += 1;
+            let result = match channel {
+                NotificationChannel::CLI => self.deliver_to_cli(notification).await,
+                NotificationChannel::File { path } => {
+                    self.deliver_to_file(notification, path).await
+                }
+                NotificationChannel::API { endpoint } => {
+                    self.deliver_to_api(notification, endpoint).await
+                }
+                NotificationChannel::Webhook { url, secret } => {
+                    self.deliver_to_webhook(notification, url, secret.as_deref())
+                        .await
+                }
+                NotificationChannel::Slack { webhook_url } => {
+                    self.deliver_to_slack(notification, webhook_url).await
+                }
+            };
+            match result {
+                Err(e) if attempts <= retry_count && is_retryable(&e) => {
+                    // Back off exponentially between attempts
+                    let delay = retry_delay_ms.saturating_mul(1 << (attempts - 1).min(16));
+                    debug!(
+                        "Retrying notification {} on {} in {}ms: {}",
+                        notification.id, channel, delay, e
+                    );
+                    if self.config.enable_metrics {
+                        self.metrics
+                            .write()
+                            .await
+                            .channel_metrics
+                            .entry(channel_key.clone())
+                            .or_default()
+                            .retry_attempts += 1;
+                    }
+                    tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+                }
+                result => break result,
             }
         };
 
+        if let Err(e) = &result {
+            self.record_dead_letter(notification, channel, attempts, e)
+                .await;
+        }
+
         // Update success metrics
         if result.is_ok() && self.config.enable_metrics {
             let delivery_time = start_time.elapsed().as_millis() as f64;
@@ -693,6 +935,138 @@ impl NotificationSystem {
         Ok(())
     }
 
+    /// Deliver notification to a webhook as signed JSON
+    async fn deliver_to_webhook(
+        &self,
+        notification: &Notification,
+        url: &str,
+        secret: Option<&str>,
+    ) -> Result<(), NotificationSystemError> {
+        let body = serde_json::to_vec(&WebhookPayload::from(notification)).map_err(|e| {
+            NotificationSystemError::FormattingError {
+                notification_type: notification.notification_type.to_string(),
+                error: e.to_string(),
+            }
+        })?;
+
+        let mut request = self
+            .http_client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        if let Some(secret) = secret {
+            let timestamp = Utc::now().timestamp();
+            request = request.header(WEBHOOK_TIMESTAMP_HEADER, timestamp).header(
+                WEBHOOK_SIGNATURE_HEADER,
+                format!("sha256={}", webhook_signature(secret, timestamp, &body)),
+            );
+        }
+        let response =
+            request
+                .body(body)
+                .send()
+                .await
+                .map_err(|e| NotificationSystemError::HttpEndpoint {
+                    endpoint: url.to_string(),
+                    status: None,
+                    error: e.to_string(),
+                })?;
+
+        if !response.status().is_success() {
+            return Err(NotificationSystemError::HttpEndpoint {
+                endpoint: url.to_string(),
+                status: Some(response.status().as_u16()),
+                error: format!("HTTP request failed with status: {}", response.status()),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Deliver notification to a Slack incoming webhook
+    async fn deliver_to_slack(
+        &self,
+        notification: &Notification,
+        webhook_url: &str,
+    ) -> Result<(), NotificationSystemError> {
+        let payload = serde_json::json!({ "text": format_slack_notification(notification) });
+
+        // Errors name the channel rather than the URL, which holds Slack's token
+        let response = self
+            .http_client
+            .post(webhook_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| NotificationSystemError::HttpEndpoint {
+                endpoint: "slack".to_string(),
+                status: None,
+                error: e.without_url().to_string(),
+            })?;
+
+        if !response.status().is_success() {
+            return Err(NotificationSystemError::HttpEndpoint {
+                endpoint: "slack".to_string(),
+                status: Some(response.status().as_u16()),
+                error: format!("HTTP request failed with status: {}", response.status()),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Append a notification that failed on `channel` to the dead-letter log
+    async fn record_dead_letter(
+        &self,
+        notification: &Notification,
+        channel: &NotificationChannel,
+        attempts: u32,
+        error: &NotificationSystemError,
+    ) {
+        let Some(path) = &self.config.dead_letter_path else {
+            return;
+        };
+        // The channel list may hold webhook secrets
+        let entry = UndeliveredNotification {
+            failed_at: Utc::now(),
+            channel: channel.to_string(),
+            attempts,
+            error: error.to_string(),
+            notification: Notification {
+                channels: Vec::new(),
+                ..notification.clone()
+            },
+        };
+        let mut line = match serde_json::to_string(&entry) {
+            Ok(line) => line,
+            Err(e) => {
+                error!("Failed to serialize dead letter {}: {}", notification.id, e);
+                return;
+            }
+        };
+        line.push('\n');
+
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            let _ = tokio::fs::create_dir_all(parent).await;
+        }
+        let written = match OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await
+        {
+            Ok(mut file) => file.write_all(line.as_bytes()).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = written {
+            error!(
+                "Failed to record dead letter {} in {}: {}",
+                notification.id,
+                path.display(),
+                e
+            );
+        }
+    }
+
     /// Format notification for CLI output with colors
     fn format_cli_notification(
         &self,
@@ -789,6 +1163,8 @@ impl NotificationSystem {
             NotificationChannel::CLI => "cli".to_string(),
             NotificationChannel::File { .. } => "file".to_string(),
             NotificationChannel::API { .. } => "api".to_string(),
+            NotificationChannel::Webhook { .. } => "webhook".to_string(),
+            NotificationChannel::Slack { .. } => "slack".to_string(),
         }
     }
 
@@ -882,6 +1258,32 @@ impl NotificationSystem {
     }
 }
 
+/// Whether a failed delivery may succeed when tried again
+fn is_retryable(error: &NotificationSystemError) -> bool {
+    match error {
+        NotificationSystemError::HttpEndpoint { status, .. } => {
+            status.is_none_or(|status| status == 429 || status >= 500)
+        }
+        NotificationSystemError::FileIo { .. } => true,
+        _ => false,
+    }
+}
+
+/// Slack message text using Slack's markdown
+fn format_slack_notification(notification: &Notification) -> String {
+    let icon = match notification.notification_type {
+        NotificationType::Error => ":x:",
+        NotificationType::Warning => ":warning:",
+        NotificationType::Success => ":white_check_mark:",
+        _ => ":information_source:",
+    };
+    let mut text = format!("{icon} *{}*\n{}", notification.title, notification.message);
+    if let Some(source) = &notification.source_component {
+        text.push_str(&format!("\n_from {source}_"));
+    }
+    text
+}
+
 impl Clone for NotificationSystem {
     fn clone(&self) -> Self {
         Self {
@@ -1211,4 +1613,164 @@ mod tests {
             "API(http://localhost:8080/notifications)"
         );
     }
+
+    /// Read one HTTP request, headers and body
+    async fn read_request(socket: &mut tokio::net::TcpStream) -> String {
+        use tokio::io::AsyncReadExt;
+
+        let mut request = Vec::new();
+        let mut buf = [0u8; 4096];
+        loop {
+            let n = socket.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&request).to_string();
+            if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                let length = head
+                    .lines()
+                    .find_map(|line| {
+                        line.to_lowercase()
+                            .strip_prefix("content-length:")
+                            .map(|v| v.trim().parse::<usize>().unwrap())
+                    })
+                    .unwrap_or(0);
+                if body.len() >= length || n == 0 {
+                    return text;
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_event_webhook_is_signed_retried_and_dead_lettered() {
+        use tokio::io::AsyncWriteExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hooks", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for status in ["503 Service Unavailable", "200 OK"] {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                requests.push(read_request(&mut socket).await);
+                let response =
+                    format!("HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n");
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+            requests
+        });
+        // Nothing listens here once the listener is dropped
+        let closed = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let slack_url = format!(
+            "http://{}/services/T0/B0/token",
+            closed.local_addr().unwrap()
+        );
+        drop(closed);
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = NotificationSystemConfig {
+            dead_letter_path: Some(dir.path().join("dead_letters.jsonl")),
+            ..Default::default()
+        };
+        for key in ["webhook", "slack"] {
+            config.channel_configs.insert(
+                key.to_string(),
+                NotificationChannelConfig {
+                    retry_count: 1,
+                    retry_delay_ms: 1,
+                    ..Default::default()
+                },
+            );
+        }
+        config.event_channels.insert(
+            NotificationEvent::ResearchCompleted,
+            vec![NotificationChannel::Webhook {
+                url,
+                secret: Some("s3cret".to_string()),
+            }],
+        );
+        config.event_channels.insert(
+            NotificationEvent::BudgetExceeded,
+            vec![NotificationChannel::Slack {
+                webhook_url: slack_url,
+            }],
+        );
+        config
+            .event_channels
+            .insert(NotificationEvent::GapDetected, Vec::new());
+        let system = NotificationSystem::new(config);
+        system.start().await.unwrap();
+
+        system
+            .notify(
+                NotificationEvent::ResearchCompleted,
+                NotificationType::Success,
+                "Research completed".to_string(),
+                "Retry backoff for uploads".to_string(),
+                HashMap::from([("task_id".to_string(), "task-1".to_string())]),
+            )
+            .await
+            .unwrap();
+        let requests = server.await.unwrap();
+        assert_eq!(requests.len(), 2);
+        let (head, body) = requests[1].split_once("\r\n\r\n").unwrap();
+        let head = head.to_lowercase();
+        let timestamp: i64 = head
+            .lines()
+            .find_map(|line| line.strip_prefix("x-fortitude-timestamp: "))
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!((Utc::now().timestamp() - timestamp).abs() <= WEBHOOK_TIMESTAMP_TOLERANCE_SECS);
+        let signature = format!(
+            "sha256={}",
+            webhook_signature("s3cret", timestamp, body.as_bytes())
+        );
+        assert!(head.contains(&format!("x-fortitude-signature: {signature}")));
+        assert_ne!(
+            webhook_signature("s3cret", timestamp, body.as_bytes()),
+            webhook_signature("s3cret", timestamp + 1, body.as_bytes())
+        );
+        let payload: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(payload["event"], "research_completed");
+        assert_eq!(payload["metadata"]["task_id"], "task-1");
+        assert!(!body.contains("s3cret"));
+
+        // Muted events are not sent at all
+        system
+            .notify(
+                NotificationEvent::GapDetected,
+                NotificationType::Info,
+                "Gaps".to_string(),
+                "3 gaps".to_string(),
+                HashMap::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(system.get_metrics().await.total_notifications, 1);
+
+        system
+            .notify(
+                NotificationEvent::BudgetExceeded,
+                NotificationType::Warning,
+                "Budget exceeded".to_string(),
+                "Spent $10.00 today".to_string(),
+                HashMap::new(),
+            )
+            .await
+            .unwrap();
+        let metrics = system.get_metrics().await;
+        assert_eq!(metrics.channel_metrics["webhook"].retry_attempts, 1);
+        assert_eq!(metrics.channel_metrics["webhook"].successful_deliveries, 1);
+        assert_eq!(metrics.channel_metrics["slack"].failed_deliveries, 1);
+
+        let dead_letters = system.dead_letters().await.unwrap();
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].channel, "Slack");
+        assert_eq!(dead_letters[0].attempts, 2);
+        assert_eq!(
+            dead_letters[0].notification.event,
+            Some(NotificationEvent::BudgetExceeded)
+        );
+        assert!(dead_letters[0].notification.channels.is_empty());
+        assert!(!dead_letters[0].error.contains("token"));
+    }
 }
@@ -25,6 +25,7 @@
 
 use crate::proactive::{ResearchTask, TaskPriority, TaskState};
 use chrono::{DateTime, Utc};
+use fortitude_core::write_json_atomic_async;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -870,12 +871,7 @@ impl StateManager {
             last_persistence: Utc::now(),
         };
 
-        // Write to temporary file first, then rename for atomicity
-        let temp_file = self.config.persistence_file.with_extension("tmp");
-        let json_data = serde_json::to_string_pretty(&persistence_data)?;
-
-        fs::write(&temp_file, json_data).await?;
-        fs::rename(&temp_file, &self.config.persistence_file).await?;
+        write_json_atomic_async(&self.config.persistence_file, &persistence_data).await?;
 
         // Update last persistence time
         {
@@ -24,11 +24,12 @@
 //! - Performance monitoring to maintain <20% CPU usage on average
 
 use crate::proactive::{
-    NotificationSystem, ProgressTracker, QualityMetrics, QueueOperations,
-    ResearchCompletionNotifier, ResearchResult, ResearchTask, StateManager,
+    NotificationEvent, NotificationSystem, NotificationType, ProgressTracker, QualityMetrics,
+    QueueOperations, ResearchCompletionNotifier, ResearchResult, ResearchTask, StateManager,
     StateTransitionMetadata, TaskState,
 };
 use chrono::{DateTime, Utc};
+use fortitude_core::pipeline::ResearchPipeline;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -229,6 +230,8 @@ pub struct TaskExecutor {
     notification_system: Arc<RwLock<Option<Arc<NotificationSystem>>>>,
     /// Optional research completion notifier for result summaries
     completion_notifier: Arc<RwLock<Option<Arc<ResearchCompletionNotifier>>>>,
+    /// Optional pipeline that performs the research; tasks are simulated without one
+    research_pipeline: Arc<RwLock<Option<Arc<ResearchPipeline>>>>,
 }
 
 impl TaskExecutor {
@@ -266,6 +269,7 @@ impl TaskExecutor {
             progress_tracker: Arc::new(RwLock::new(None)),
             notification_system: Arc::new(RwLock::new(None)),
             completion_notifier: Arc::new(RwLock::new(None)),
+            research_pipeline: Arc::new(RwLock::new(None)),
             config,
         }
     }
@@ -290,15 +294,7 @@ impl TaskExecutor {
         self.start_resource_monitoring().await;
 
         // Start main execution loop
-        Self::start_execution_loop(
-            self.running.clone(),
-            self.executing_tasks.clone(),
-            self.concurrency_semaphore.clone(),
-            self.rate_limiter.clone(),
-            self.metrics.clone(),
-            self.config.clone(),
-            scheduler,
-        );
+        self.start_execution_loop(scheduler);
 
         Ok(())
     }
@@ -479,6 +475,24 @@ impl TaskExecutor {
         self.completion_notifier.read().await.is_some()
     }
 
+    /// Configure the research pipeline that executes task queries
+    ///
+    /// The pipeline's provider requests should be tagged as proactive so they
+    /// stay within the proactive share of each provider's rate limit.
+    pub async fn configure_research_pipeline(
+        &self,
+        research_pipeline: Arc<ResearchPipeline>,
+    ) -> Result<(), TaskExecutorError> {
+        *self.research_pipeline.write().await = Some(research_pipeline);
+        info!("Research pipeline configured for task executor");
+        Ok(())
+    }
+
+    /// Check if a research pipeline is configured
+    pub async fn has_research_pipeline(&self) -> bool {
+        self.research_pipeline.read().await.is_some()
+    }
+
     /// Get enhanced progress information for a task (if progress tracker is configured)
     pub async fn get_enhanced_task_progress(
         &self,
@@ -961,15 +975,16 @@ impl TaskExecutor {
     }
 
     /// Start main execution loop
-    fn start_execution_loop(
-        running: Arc<RwLock<bool>>,
-        executing_tasks: Arc<RwLock<HashMap<String, TaskProgress>>>,
-        concurrency_semaphore: Arc<Semaphore>,
-        rate_limiter: Arc<TokenBucket>,
-        metrics: Arc<RwLock<ExecutorMetrics>>,
-        config: TaskExecutorConfig,
-        scheduler: Arc<dyn QueueOperations + Send + Sync>,
-    ) {
+    fn start_execution_loop(&self, scheduler: Arc<dyn QueueOperations + Send + Sync>) {
+        let running = self.running.clone();
+        let executing_tasks = self.executing_tasks.clone();
+        let concurrency_semaphore = self.concurrency_semaphore.clone();
+        let rate_limiter = self.rate_limiter.clone();
+        let metrics = self.metrics.clone();
+        let config = self.config.clone();
+        let research_pipeline = self.research_pipeline.clone();
+        let notification_system = self.notification_system.clone();
+
         tokio::spawn(async move {
             while *running.read().await {
                 // Try to dequeue and execute a task
@@ -981,19 +996,31 @@ impl TaskExecutor {
                         let rate_limiter = rate_limiter.clone();
                         let metrics = metrics.clone();
                         let config = config.clone();
+                        let research_pipeline = research_pipeline.read().await.clone();
+                        let notification_system = notification_system.read().await.clone();
 
                         // Spawn task execution
                         tokio::spawn(async move {
-                            if let Err(e) = Self::execute_task_static(
+                            let finished_task = task.clone();
+                            let result = Self::execute_task_static(
                                 task,
                                 executing_tasks,
                                 concurrency_semaphore,
                                 rate_limiter,
                                 metrics,
                                 config,
+                                research_pipeline,
                             )
-                            .await
-                            {
+                            .await;
+                            if let Some(notification_system) = &notification_system {
+                                Self::notify_task_outcome(
+                                    notification_system,
+                                    &finished_task,
+                                    &result,
+                                )
+                                .await;
+                            }
+                            if let Err(e) = result {
                                 error!("Task execution failed for {}: {}", task_id, e);
                             }
                         });
@@ -1011,6 +1038,47 @@ impl TaskExecutor {
         });
     }
 
+    /// Report a finished background task as a research completed or failed event
+    async fn notify_task_outcome(
+        notification_system: &NotificationSystem,
+        task: &ResearchTask,
+        result: &Result<(), TaskExecutorError>,
+    ) {
+        let metadata = HashMap::from([
+            ("task_id".to_string(), task.id.clone()),
+            ("query".to_string(), task.research_query.clone()),
+            ("file".to_string(), task.gap.file_path.display().to_string()),
+            ("line".to_string(), task.gap.line_number.to_string()),
+        ]);
+        let sent = match result {
+            Ok(()) => {
+                notification_system
+                    .notify(
+                        NotificationEvent::ResearchCompleted,
+                        NotificationType::Success,
+                        "Research completed".to_string(),
+                        task.research_query.clone(),
+                        metadata,
+                    )
+                    .await
+            }
+            Err(e) => {
+                notification_system
+                    .notify(
+                        NotificationEvent::ResearchFailed,
+                        NotificationType::Error,
+                        "Research failed".to_string(),
+                        format!("{}: {e}", task.research_query),
+                        metadata,
+                    )
+                    .await
+            }
+        };
+        if let Err(e) = sent {
+            warn!("Failed to send notification for task {}: {}", task.id, e);
+        }
+    }
+
     /// Static version of execute_task for use in spawned tasks
     async fn execute_task_static(
         task: ResearchTask,
@@ -1019,6 +1087,7 @@ impl TaskExecutor {
         rate_limiter: Arc<TokenBucket>,
         metrics: Arc<RwLock<ExecutorMetrics>>,
         config: TaskExecutorConfig,
+        research_pipeline: Option<Arc<ResearchPipeline>>,
     ) -> Result<(), TaskExecutorError> {
         let task_id = task.id.clone();
 
@@ -1060,8 +1129,9 @@ impl TaskExecutor {
             executing.insert(task_id.clone(), progress);
         }
 
-        // Execute the task (simplified for now)
-        let result = Self::execute_single_task_static(&task_id, &executing_tasks).await;
+        let result =
+            Self::execute_single_task_static(&task, &executing_tasks, research_pipeline.as_deref())
+                .await;
 
         // Clean up executing task
         {
@@ -1087,12 +1157,27 @@ impl TaskExecutor {
 
     /// Static version of execute_single_task
     async fn execute_single_task_static(
-        task_id: &str,
+        task: &ResearchTask,
         executing_tasks: &Arc<RwLock<HashMap<String, TaskProgress>>>,
+        research_pipeline: Option<&ResearchPipeline>,
     ) -> Result<(), TaskExecutorError> {
+        let task_id = task.id.as_str();
+
         // Update progress stages
         Self::update_task_progress_static(task_id, "executing", 25.0, executing_tasks).await;
-        tokio::time::sleep(Duration::from_millis(100)).await;
+        match research_pipeline {
+            Some(pipeline) => {
+                let result = pipeline
+                    .process_query(&task.research_query, None, None)
+                    .await
+                    .map_err(|e| TaskExecutorError::ResearchPipeline(e.to_string()))?;
+                debug!(
+                    "Research for task {} stored under cache key {}",
+                    task_id, result.metadata.cache_key
+                );
+            }
+            None => tokio::time::sleep(Duration::from_millis(100)).await,
+        }
 
         Self::update_task_progress_static(task_id, "processing", 50.0, executing_tasks).await;
         tokio::time::sleep(Duration::from_millis(100)).await;
@@ -1143,6 +1228,53 @@ mod tests {
         ResearchTask::from_gap(gap, crate::proactive::TaskPriority::High)
     }
 
+    #[tokio::test]
+    async fn test_configured_pipeline_executes_task_query() {
+        use fortitude_core::pipeline::PipelineBuilder;
+        use fortitude_core::{BasicClassifier, FileStorage};
+        use fortitude_types::{ClassificationConfig, Storage, StorageConfig};
+
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Arc::new(
+            FileStorage::new(StorageConfig {
+                base_path: dir.path().to_path_buf(),
+                ..StorageConfig::default()
+            })
+            .await
+            .unwrap(),
+        );
+        let pipeline = PipelineBuilder::new().build(
+            // Same lenient threshold as the CLI pipeline
+            Arc::new(BasicClassifier::new(ClassificationConfig {
+                default_threshold: 0.05,
+                ..Default::default()
+            })),
+            storage.clone(),
+        );
+
+        let executor = TaskExecutor::new(TaskExecutorConfig::default());
+        assert!(!executor.has_research_pipeline().await);
+        executor
+            .configure_research_pipeline(Arc::new(pipeline))
+            .await
+            .unwrap();
+        assert!(executor.has_research_pipeline().await);
+
+        let task = create_test_task();
+        let pipeline = executor.research_pipeline.read().await.clone();
+        TaskExecutor::execute_single_task_static(
+            &task,
+            &executor.executing_tasks,
+            pipeline.as_deref(),
+        )
+        .await
+        .unwrap();
+
+        let entries = storage.list_cache_entries().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].original_query, task.research_query);
+    }
+
     #[tokio::test]
     async fn test_task_executor_creation() {
         let config = TaskExecutorConfig::default();
@@ -0,0 +1,1150 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: AWS Bedrock provider implementation with SigV4 request signing and response streaming
+//! This module provides a Provider implementation for models hosted on AWS Bedrock.
+//! Requests are signed with AWS Signature Version 4 using credentials from the
+//! environment or a named profile in the shared AWS credentials file. Anthropic
+//! Claude and Amazon Titan text models are supported, including streamed responses
+//! through [`BedrockProvider::research_query_stream`].
+//!
+//! # Example Usage
+//!
+//! ```rust,no_run
+//! use fortitude::providers::bedrock::{BedrockProvider, BedrockSettings};
+//! use fortitude::providers::Provider;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let settings = BedrockSettings::new("anthropic.claude-3-5-sonnet-20240620-v1:0")
+//!         .with_region("us-east-1")
+//!         .with_profile("research");
+//!
+//!     let provider = BedrockProvider::new(settings).await?;
+//!     let answer = provider
+//!         .research_query_stream("What is quantum computing?".to_string(), |chunk| {
+//!             print!("{chunk}")
+//!         })
+//!         .await?;
+//!     println!("\n{} characters", answer.len());
+//!     Ok(())
+//! }
+//! ```
+
+use crate::providers::config::{ConfigError, ConfigResult, RetryConfig};
+use crate::providers::context::ContextWindow;
+use crate::providers::{
+    HealthStatus, Provider, ProviderError, ProviderMetadata, ProviderResult, QueryCost,
+};
+use async_trait::async_trait;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, KeyInit, Mac};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{debug, error, info, warn};
+
+const PROVIDER_NAME: &str = "bedrock";
+
+/// Service name used in the SigV4 credential scope
+const SIGNING_SERVICE: &str = "bedrock";
+
+const ANTHROPIC_VERSION: &str = "bedrock-2023-05-31";
+
+/// AWS access keys used to sign requests
+#[derive(Clone, PartialEq, Eq)]
+pub struct AwsCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    /// Present for temporary credentials, e.g. from SSO or an assumed role
+    pub session_token: Option<String>,
+}
+
+impl std::fmt::Debug for AwsCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AwsCredentials")
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"<redacted>")
+            .field(
+                "session_token",
+                &self.session_token.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+impl AwsCredentials {
+    pub fn new(access_key_id: impl Into<String>, secret_access_key: impl Into<String>) -> Self {
+        Self {
+            access_key_id: access_key_id.into(),
+            secret_access_key: secret_access_key.into(),
+            session_token: None,
+        }
+    }
+
+    pub fn with_session_token(mut self, session_token: impl Into<String>) -> Self {
+        self.session_token = Some(session_token.into());
+        self
+    }
+
+    /// Read `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and `AWS_SESSION_TOKEN`
+    pub fn from_env() -> Option<Self> {
+        let access_key_id = std::env::var("AWS_ACCESS_KEY_ID").ok()?;
+        let secret_access_key = std::env::var("AWS_SECRET_ACCESS_KEY").ok()?;
+        let mut credentials = Self::new(access_key_id, secret_access_key);
+        credentials.session_token = std::env::var("AWS_SESSION_TOKEN").ok();
+        Some(credentials)
+    }
+
+    /// Read a profile from a shared credentials file
+    pub fn from_profile_file(path: &Path, profile: &str) -> ConfigResult<Self> {
+        let sections = read_ini(path)?;
+        let section = sections.get(profile).ok_or_else(|| {
+            ConfigError::Conflict(format!(
+                "AWS profile '{profile}' not found in {}",
+                path.display()
+            ))
+        })?;
+        let field = |key: &str| {
+            section.get(key).cloned().ok_or_else(|| {
+                ConfigError::Conflict(format!("AWS profile '{profile}' has no {key}"))
+            })
+        };
+        let mut credentials =
+            Self::new(field("aws_access_key_id")?, field("aws_secret_access_key")?);
+        credentials.session_token = section.get("aws_session_token").cloned();
+        Ok(credentials)
+    }
+}
+
+/// Bedrock model families with their own request and response formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BedrockModelFamily {
+    /// Anthropic Claude models using the Messages API
+    Anthropic,
+    /// Amazon Titan text generation models
+    Titan,
+}
+
+impl BedrockModelFamily {
+    /// Detect the family from a model or inference profile ID
+    pub fn from_model_id(model_id: &str) -> Option<Self> {
+        // Cross-region inference profiles prefix the model ID with a geography
+        let model = model_id
+            .split_once('.')
+            .filter(|(geo, _)| matches!(*geo, "us" | "eu" | "apac"))
+            .map_or(model_id, |(_, rest)| rest);
+        if model.starts_with("anthropic.") {
+            Some(Self::Anthropic)
+        } else if model.starts_with("amazon.titan-text") {
+            Some(Self::Titan)
+        } else {
+            None
+        }
+    }
+}
+
+/// Settings for a Bedrock-hosted model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BedrockSettings {
+    /// Model or inference profile ID, e.g. `anthropic.claude-3-5-sonnet-20240620-v1:0`
+    pub model_id: String,
+    /// AWS region; falls back to `AWS_REGION`, `AWS_DEFAULT_REGION` and the profile's region
+    pub region: Option<String>,
+    /// Named profile in the shared AWS files; falls back to `AWS_PROFILE`
+    pub profile: Option<String>,
+    /// Runtime endpoint override, e.g. a VPC endpoint
+    pub endpoint: Option<String>,
+    #[serde(with = "crate::providers::config::duration_serde")]
+    pub timeout: Duration,
+    pub max_tokens: u32,
+    pub retry: RetryConfig,
+}
+
+impl BedrockSettings {
+    pub fn new(model_id: impl Into<String>) -> Self {
+        Self {
+            model_id: model_id.into(),
+            region: None,
+            profile: None,
+            endpoint: None,
+            timeout: Duration::from_secs(60),
+            max_tokens: 1000,
+            retry: RetryConfig::default(),
+        }
+    }
+
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    pub fn with_profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = Some(endpoint.into());
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = max_tokens;
+        self
+    }
+
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn validate(&self) -> ConfigResult<()> {
+        if BedrockModelFamily::from_model_id(&self.model_id).is_none() {
+            return Err(ConfigError::Conflict(format!(
+                "Unsupported Bedrock model '{}': expected an Anthropic or Titan text model",
+                self.model_id
+            )));
+        }
+        if let Some(endpoint) = &self.endpoint {
+            if !endpoint.starts_with("http://") && !endpoint.starts_with("https://") {
+                return Err(ConfigError::InvalidUrl(format!(
+                    "Invalid endpoint URL format: {endpoint}"
+                )));
+            }
+        }
+        if self.max_tokens == 0 {
+            return Err(ConfigError::Conflict(
+                "max_tokens must be greater than 0".to_string(),
+            ));
+        }
+        self.retry.validate()
+    }
+
+    fn profile_name(&self) -> String {
+        self.profile
+            .clone()
+            .or_else(|| std::env::var("AWS_PROFILE").ok())
+            .unwrap_or_else(|| "default".to_string())
+    }
+}
+
+/// Headers added to a request by [`sign_request`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedHeaders {
+    pub authorization: String,
+    pub amz_date: String,
+    pub security_token: Option<String>,
+}
+
+/// Sign a request with AWS Signature Version 4
+///
+/// Signs the `host` and `x-amz-date` headers, plus `x-amz-security-token` for
+/// temporary credentials. `url` must already be percent-encoded.
+pub fn sign_request(
+    credentials: &AwsCredentials,
+    region: &str,
+    service: &str,
+    method: &str,
+    url: &reqwest::Url,
+    body: &[u8],
+    now: DateTime<Utc>,
+) -> SignedHeaders {
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date = now.format("%Y%m%d").to_string();
+
+    let host = match url.port() {
+        Some(port) => format!("{}:{port}", url.host_str().unwrap_or_default()),
+        None => url.host_str().unwrap_or_default().to_string(),
+    };
+    let mut headers = vec![("host", host), ("x-amz-date", amz_date.clone())];
+    if let Some(token) = &credentials.session_token {
+        headers.push(("x-amz-security-token", token.clone()));
+    }
+    let signed_headers = headers
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(";");
+    let canonical_headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{name}:{}\n", value.trim()))
+        .collect();
+
+    // Services other than S3 encode each path segment a second time
+    let canonical_uri = url
+        .path()
+        .split('/')
+        .map(uri_encode)
+        .collect::<Vec<_>>()
+        .join("/");
+    let mut query: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(k, v)| (uri_encode(&k), uri_encode(&v)))
+        .collect();
+    query.sort();
+    let canonical_query = query
+        .iter()
+        .map(|(k, v)| format!("{k}={v}"))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let canonical_request = format!(
+        "{method}\n{canonical_uri}\n{canonical_query}\n{canonical_headers}\n{signed_headers}\n{}",
+        hex(&Sha256::digest(body))
+    );
+    let scope = format!("{date}/{region}/{service}/aws4_request");
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+        hex(&Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let key = [date.as_str(), region, service, "aws4_request"]
+        .iter()
+        .fold(
+            format!("AWS4{}", credentials.secret_access_key).into_bytes(),
+            |key, part| hmac_sha256(&key, part.as_bytes()),
+        );
+    let signature = hex(&hmac_sha256(&key, string_to_sign.as_bytes()));
+
+    SignedHeaders {
+        authorization: format!(
+            "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+            credentials.access_key_id
+        ),
+        amz_date,
+        security_token: credentials.session_token.clone(),
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Percent-encode everything except RFC 3986 unreserved characters
+fn uri_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Parse an AWS shared config or credentials file into sections
+///
+/// `[profile name]` headers in the config file map to `name`.
+fn read_ini(path: &Path) -> ConfigResult<HashMap<String, HashMap<String, String>>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| ConfigError::Conflict(format!("Failed to read {}: {e}", path.display())))?;
+
+    let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
+    let mut current = None;
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let name = name.trim();
+            let name = name.strip_prefix("profile ").unwrap_or(name).trim();
+            current = Some(name.to_string());
+            sections.entry(name.to_string()).or_default();
+        } else if let (Some(section), Some((key, value))) = (&current, line.split_once('=')) {
+            sections
+                .entry(section.clone())
+                .or_default()
+                .insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+    Ok(sections)
+}
+
+fn aws_file(env_var: &str, name: &str) -> Option<PathBuf> {
+    std::env::var(env_var).map(PathBuf::from).ok().or_else(|| {
+        std::env::var("HOME")
+            .or_else(|_| std::env::var("USERPROFILE"))
+            .ok()
+            .map(|home| PathBuf::from(home).join(".aws").join(name))
+    })
+}
+
+/// AWS Bedrock provider implementation
+#[derive(Debug)]
+pub struct BedrockProvider {
+    client: Client,
+    settings: BedrockSettings,
+    family: BedrockModelFamily,
+    region: String,
+    credentials: AwsCredentials,
+}
+
+impl BedrockProvider {
+    /// Create a provider, resolving region and credentials from the environment
+    /// and shared AWS files
+    ///
+    /// An explicit profile is read from the credentials file; otherwise the
+    /// `AWS_*` environment variables win over the default profile.
+    pub async fn new(settings: BedrockSettings) -> ProviderResult<Self> {
+        let profile = settings.profile_name();
+        let credentials = match (&settings.profile, AwsCredentials::from_env()) {
+            (None, Some(credentials)) => credentials,
+            _ => {
+                let path = aws_file("AWS_SHARED_CREDENTIALS_FILE", "credentials")
+                    .ok_or_else(|| config_error("Cannot locate the AWS credentials file"))?;
+                AwsCredentials::from_profile_file(&path, &profile)
+                    .map_err(|e| config_error(e.to_string()))?
+            }
+        };
+
+        let region = settings
+            .region
+            .clone()
+            .or_else(|| std::env::var("AWS_REGION").ok())
+            .or_else(|| std::env::var("AWS_DEFAULT_REGION").ok())
+            .or_else(|| {
+                let path = aws_file("AWS_CONFIG_FILE", "config")?;
+                read_ini(&path).ok()?.get(&profile)?.get("region").cloned()
+            })
+            .ok_or_else(|| config_error("No AWS region configured"))?;
+
+        Self::with_credentials(settings.with_region(region), credentials)
+    }
+
+    /// Create a provider with explicit credentials; `settings.region` is required
+    pub fn with_credentials(
+        settings: BedrockSettings,
+        credentials: AwsCredentials,
+    ) -> ProviderResult<Self> {
+        settings
+            .validate()
+            .map_err(|e| config_error(format!("Configuration validation failed: {e}")))?;
+        let region = settings
+            .region
+            .clone()
+            .ok_or_else(|| config_error("No AWS region configured"))?;
+        let family = BedrockModelFamily::from_model_id(&settings.model_id)
+            .ok_or_else(|| config_error("Unsupported Bedrock model"))?;
+
+        let client = Client::builder()
+            .timeout(settings.timeout)
+            .build()
+            .map_err(|e| config_error(format!("Failed to create HTTP client: {e}")))?;
+
+        Ok(Self {
+            client,
+            settings,
+            family,
+            region,
+            credentials,
+        })
+    }
+
+    /// Model family the provider formats requests for
+    pub fn family(&self) -> BedrockModelFamily {
+        self.family
+    }
+
+    /// Execute a research query, passing each text fragment to `on_chunk` as it arrives
+    ///
+    /// Returns the complete response text.
+    pub async fn research_query_stream<F>(
+        &self,
+        query: String,
+        mut on_chunk: F,
+    ) -> ProviderResult<String>
+    where
+        F: FnMut(&str) + Send,
+    {
+        self.validate_query(&query)?;
+        self.check_context(&query, self.settings.max_tokens)?;
+        let body = self.request_body(&query, self.settings.max_tokens);
+        let mut response = self.send(&body, "invoke-with-response-stream").await?;
+
+        let mut buffer = Vec::new();
+        let mut answer = String::new();
+        while let Some(bytes) = response.chunk().await.map_err(network_error)? {
+            buffer.extend_from_slice(&bytes);
+            while let Some(message) = next_event(&mut buffer)? {
+                if let Some(text) = self.stream_text(&message)? {
+                    on_chunk(&text);
+                    answer.push_str(&text);
+                }
+            }
+        }
+
+        info!("Bedrock provider completed streamed research query");
+        Ok(answer)
+    }
+
+    fn runtime_url(&self, action: &str) -> String {
+        let base =
+            self.settings.endpoint.clone().unwrap_or_else(|| {
+                format!("https://bedrock-runtime.{}.amazonaws.com", self.region)
+            });
+        format!(
+            "{}/model/{}/{action}",
+            base.trim_end_matches('/'),
+            uri_encode(&self.settings.model_id)
+        )
+    }
+
+    fn request_body(&self, query: &str, max_tokens: u32) -> Value {
+        match self.family {
+            BedrockModelFamily::Anthropic => json!({
+                "anthropic_version": ANTHROPIC_VERSION,
+                "max_tokens": max_tokens,
+                "temperature": 0.7,
+                "messages": [{"role": "user", "content": query}],
+            }),
+            BedrockModelFamily::Titan => json!({
+                "inputText": query,
+                "textGenerationConfig": {
+                    "maxTokenCount": max_tokens,
+                    "temperature": 0.7,
+                },
+            }),
+        }
+    }
+
+    /// Text of a complete (non-streamed) model response
+    fn response_text(&self, response: &Value) -> Option<String> {
+        match self.family {
+            BedrockModelFamily::Anthropic => Some(
+                response["content"]
+                    .as_array()?
+                    .iter()
+                    .filter_map(|block| block["text"].as_str())
+                    .collect(),
+            ),
+            BedrockModelFamily::Titan => response["results"]
+                .as_array()?
+                .first()?
+                .get("outputText")?
+                .as_str()
+                .map(str::to_string),
+        }
+    }
+
+    /// Text carried by one event of a response stream
+    fn stream_text(&self, message: &EventMessage) -> ProviderResult<Option<String>> {
+        if message.message_type.as_deref() == Some("exception") {
+            let body: Value = serde_json::from_slice(&message.payload).unwrap_or_default();
+            return Err(map_status_error(
+                message.exception_type.as_deref().unwrap_or("exception"),
+                body["message"].as_str().unwrap_or("Stream failed"),
+            ));
+        }
+        if message.event_type.as_deref() != Some("chunk") {
+            return Ok(None);
+        }
+
+        let payload: Value = serde_json::from_slice(&message.payload).map_err(parse_error)?;
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(payload["bytes"].as_str().unwrap_or_default())
+            .map_err(parse_error)?;
+        let chunk: Value = serde_json::from_slice(&bytes).map_err(parse_error)?;
+
+        Ok(match self.family {
+            BedrockModelFamily::Anthropic => (chunk["type"] == "content_block_delta")
+                .then(|| chunk["delta"]["text"].as_str().map(str::to_string))
+                .flatten(),
+            BedrockModelFamily::Titan => chunk["outputText"].as_str().map(str::to_string),
+        })
+    }
+
+    /// Sign and send a request, retrying throttling and server errors
+    async fn send(&self, body: &Value, action: &str) -> ProviderResult<reqwest::Response> {
+        let url = reqwest::Url::parse(&self.runtime_url(action))
+            .map_err(|e| config_error(format!("Invalid Bedrock endpoint: {e}")))?;
+        let payload = serde_json::to_vec(body).map_err(parse_error)?;
+        let mut last_error = None;
+
+        for attempt in 0..=self.settings.retry.max_retries {
+            let signed = sign_request(
+                &self.credentials,
+                &self.region,
+                SIGNING_SERVICE,
+                "POST",
+                &url,
+                &payload,
+                Utc::now(),
+            );
+            let mut request = self
+                .client
+                .post(url.clone())
+                .header("authorization", signed.authorization)
+                .header("x-amz-date", signed.amz_date)
+                .header("content-type", "application/json")
+                .header("accept", "application/json")
+                .body(payload.clone());
+            if let Some(token) = signed.security_token {
+                request = request.header("x-amz-security-token", token);
+            }
+
+            let error = match request.send().await {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) => {
+                    let status = response.status();
+                    let error_type = response
+                        .headers()
+                        .get("x-amzn-errortype")
+                        .and_then(|v| v.to_str().ok())
+                        .map(|v| v.split(':').next().unwrap_or(v).to_string());
+                    let body: Value = response.json().await.unwrap_or_default();
+                    let message = body["message"]
+                        .as_str()
+                        .or_else(|| body["Message"].as_str())
+                        .unwrap_or("Request failed")
+                        .to_string();
+                    map_status_error(
+                        error_type.as_deref().unwrap_or(status_name(status)),
+                        &message,
+                    )
+                }
+                Err(e) if e.is_timeout() => ProviderError::Timeout {
+                    provider: PROVIDER_NAME.to_string(),
+                    duration: self.settings.timeout,
+                },
+                Err(e) => network_error(e),
+            };
+
+            if !error.is_retryable() {
+                return Err(error);
+            }
+            warn!(
+                "Bedrock request failed (attempt {}): {}",
+                attempt + 1,
+                error
+            );
+            last_error = Some(error);
+            if attempt < self.settings.retry.max_retries {
+                tokio::time::sleep(self.settings.retry.calculate_delay(attempt)).await;
+            }
+        }
+
+        Err(last_error.unwrap_or(ProviderError::QueryFailed {
+            provider: PROVIDER_NAME.to_string(),
+            message: "All retry attempts exhausted".to_string(),
+            error_code: None,
+        }))
+    }
+
+    /// Reject prompts that leave no room for `max_tokens` of output
+    fn check_context(&self, query: &str, max_tokens: u32) -> ProviderResult<()> {
+        let window = self.context_window();
+        window.check(PROVIDER_NAME, window.count_tokens(query), max_tokens)
+    }
+
+    async fn invoke(&self, query: &str, max_tokens: u32) -> ProviderResult<String> {
+        self.check_context(query, max_tokens)?;
+        let body = self.request_body(query, max_tokens);
+        let response: Value = self
+            .send(&body, "invoke")
+            .await?
+            .json()
+            .await
+            .map_err(parse_error)?;
+        self.response_text(&response)
+            .ok_or_else(|| ProviderError::QueryFailed {
+                provider: PROVIDER_NAME.to_string(),
+                message: "No response content in Bedrock response".to_string(),
+                error_code: None,
+            })
+    }
+
+    /// Published on-demand price per 1K input and output tokens
+    fn pricing(&self) -> Option<(f64, f64)> {
+        let model = self.settings.model_id.as_str();
+        [
+            ("claude-3-5-sonnet", (0.003, 0.015)),
+            ("claude-3-haiku", (0.00025, 0.00125)),
+            ("claude-3-opus", (0.015, 0.075)),
+            ("titan-text-express", (0.0002, 0.0006)),
+            ("titan-text-lite", (0.00015, 0.0002)),
+        ]
+        .into_iter()
+        .find(|(name, _)| model.contains(name))
+        .map(|(_, price)| price)
+    }
+}
+
+fn config_error(message: impl Into<String>) -> ProviderError {
+    ProviderError::ConfigurationError {
+        provider: PROVIDER_NAME.to_string(),
+        message: message.into(),
+    }
+}
+
+fn parse_error(e: impl std::fmt::Display) -> ProviderError {
+    ProviderError::SerializationError {
+        provider: PROVIDER_NAME.to_string(),
+        message: format!("Failed to parse response: {e}"),
+    }
+}
+
+fn network_error(e: reqwest::Error) -> ProviderError {
+    ProviderError::NetworkError {
+        provider: PROVIDER_NAME.to_string(),
+        source: Box::new(e),
+    }
+}
+
+fn status_name(status: StatusCode) -> &'static str {
+    match status.as_u16() {
+        401 | 403 => "AccessDeniedException",
+        429 => "ThrottlingException",
+        500..=599 => "ServiceUnavailableException",
+        _ => "ValidationException",
+    }
+}
+
+/// Map a Bedrock exception name to ProviderError
+fn map_status_error(error_type: &str, message: &str) -> ProviderError {
+    let provider = PROVIDER_NAME.to_string();
+    let message = message.to_string();
+    match error_type {
+        "AccessDeniedException" | "UnrecognizedClientException" | "ExpiredTokenException" => {
+            ProviderError::AuthenticationFailed { provider, message }
+        }
+        "ThrottlingException" | "throttlingException" => ProviderError::RateLimitExceeded {
+            provider,
+            message,
+            retry_after: None,
+            requests_remaining: None,
+            tokens_remaining: None,
+        },
+        "ServiceQuotaExceededException" => ProviderError::QuotaExceeded {
+            provider,
+            message,
+            reset_time: None,
+        },
+        "ServiceUnavailableException"
+        | "InternalServerException"
+        | "internalServerException"
+        | "ModelNotReadyException"
+        | "ModelTimeoutException"
+        | "modelStreamErrorException" => ProviderError::ServiceUnavailable {
+            provider,
+            message,
+            estimated_recovery: Some(Duration::from_secs(30)),
+        },
+        _ => ProviderError::QueryFailed {
+            provider,
+            message,
+            error_code: Some(error_type.to_string()),
+        },
+    }
+}
+
+/// One message of an `application/vnd.amazon.eventstream` response
+#[derive(Debug, Default, PartialEq)]
+struct EventMessage {
+    message_type: Option<String>,
+    event_type: Option<String>,
+    exception_type: Option<String>,
+    payload: Vec<u8>,
+}
+
+/// Take the next complete message off the front of `buffer`
+///
+/// Messages are a 12-byte prelude (total length, headers length, CRC), the
+/// headers, the payload and a trailing CRC. Checksums are not verified since
+/// the connection is already protected by TLS.
+fn next_event(buffer: &mut Vec<u8>) -> ProviderResult<Option<EventMessage>> {
+    if buffer.len() < 12 {
+        return Ok(None);
+    }
+    let total_len = u32::from_be_bytes([buffer[0], buffer[1], buffer[2], buffer[3]]) as usize;
+    let headers_len = u32::from_be_bytes([buffer[4], buffer[5], buffer[6], buffer[7]]) as usize;
+    if total_len < 16 + headers_len {
+        return Err(parse_error("malformed event stream message"));
+    }
+    if buffer.len() < total_len {
+        return Ok(None);
+    }
+
+    let frame: Vec<u8> = buffer.drain(..total_len).collect();
+    let mut message = EventMessage {
+        payload: frame[12 + headers_len..total_len - 4].to_vec(),
+        ..Default::default()
+    };
+
+    let mut headers = &frame[12..12 + headers_len];
+    while let Some((&name_len, rest)) = headers.split_first() {
+        let name_len = name_len as usize;
+        // Header name, a value type byte, and a 2-byte length for string values
+        if rest.len() < name_len + 3 {
+            return Err(parse_error("malformed event stream header"));
+        }
+        let name = String::from_utf8_lossy(&rest[..name_len]).to_string();
+        let value_type = rest[name_len];
+        if value_type != 7 {
+            // Bedrock only sends string headers
+            return Err(parse_error("unsupported event stream header type"));
+        }
+        let value_len = u16::from_be_bytes([rest[name_len + 1], rest[name_len + 2]]) as usize;
+        let value_start = name_len + 3;
+        if rest.len() < value_start + value_len {
+            return Err(parse_error("malformed event stream header"));
+        }
+        let value =
+            String::from_utf8_lossy(&rest[value_start..value_start + value_len]).to_string();
+        match name.as_str() {
+            ":message-type" => message.message_type = Some(value),
+            ":event-type" => message.event_type = Some(value),
+            ":exception-type" => message.exception_type = Some(value),
+            _ => {}
+        }
+        headers = &rest[value_start + value_len..];
+    }
+
+    Ok(Some(message))
+}
+
+#[async_trait]
+impl Provider for BedrockProvider {
+    async fn research_query(&self, query: String) -> ProviderResult<String> {
+        self.validate_query(&query)?;
+        debug!("Bedrock provider executing research query: {}", query);
+
+        let answer = self.invoke(&query, self.settings.max_tokens).await?;
+        info!("Bedrock provider completed research query successfully");
+        Ok(answer)
+    }
+
+    fn context_window(&self) -> ContextWindow {
+        ContextWindow::new(
+            self.metadata().max_context_length() as u32,
+            self.settings.max_tokens,
+        )
+    }
+
+    fn metadata(&self) -> ProviderMetadata {
+        let context_length = match self.family {
+            BedrockModelFamily::Anthropic => 200_000,
+            BedrockModelFamily::Titan => 8_192,
+        };
+        ProviderMetadata::new(PROVIDER_NAME.to_string(), "1.0.0".to_string())
+            .with_capabilities(vec![
+                "research".to_string(),
+                "async".to_string(),
+                "streaming".to_string(),
+                "cost_estimation".to_string(),
+            ])
+            .with_models(vec![self.settings.model_id.clone()])
+            .with_context_length(context_length)
+            .with_streaming(true)
+            .with_attribute("provider_type".to_string(), PROVIDER_NAME.to_string())
+            .with_attribute("region".to_string(), self.region.clone())
+            .with_attribute(
+                "model_family".to_string(),
+                format!("{:?}", self.family).to_lowercase(),
+            )
+    }
+
+    async fn health_check(&self) -> ProviderResult<HealthStatus> {
+        debug!("Bedrock provider performing health check");
+
+        match self.invoke("Hello", 1).await {
+            Ok(_) => Ok(HealthStatus::Healthy),
+            Err(ProviderError::RateLimitExceeded { .. }) => {
+                warn!("Bedrock provider health check: throttled but service available");
+                Ok(HealthStatus::Degraded("Rate limited".to_string()))
+            }
+            Err(ProviderError::AuthenticationFailed { message, .. }) => {
+                error!("Bedrock provider health check: access denied");
+                Ok(HealthStatus::Unhealthy(format!("Access denied: {message}")))
+            }
+            Err(e) => {
+                error!("Bedrock provider health check failed: {}", e);
+                Ok(HealthStatus::Unhealthy(format!("Health check failed: {e}")))
+            }
+        }
+    }
+
+    async fn estimate_cost(&self, query: &str) -> ProviderResult<QueryCost> {
+        let input_tokens = self.context_window().count_tokens(query).max(1);
+        let output_tokens = (input_tokens / 2).min(self.settings.max_tokens);
+        Ok(QueryCost {
+            estimated_input_tokens: input_tokens,
+            estimated_output_tokens: output_tokens,
+            estimated_duration: Duration::from_secs(3),
+            estimated_cost_usd: self.pricing().map(|(input, output)| {
+                (input_tokens as f64 / 1000.0) * input + (output_tokens as f64 / 1000.0) * output
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    fn test_credentials() -> AwsCredentials {
+        AwsCredentials::new("AKIDEXAMPLE", "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY")
+    }
+
+    fn event(event_type: &str, payload: &[u8]) -> Vec<u8> {
+        let mut headers = Vec::new();
+        for (name, value) in [(":message-type", "event"), (":event-type", event_type)] {
+            headers.push(name.len() as u8);
+            headers.extend_from_slice(name.as_bytes());
+            headers.push(7);
+            headers.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            headers.extend_from_slice(value.as_bytes());
+        }
+        let total = 12 + headers.len() + payload.len() + 4;
+        let mut frame = Vec::new();
+        frame.extend_from_slice(&(total as u32).to_be_bytes());
+        frame.extend_from_slice(&(headers.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&[0; 4]);
+        frame.extend_from_slice(&headers);
+        frame.extend_from_slice(payload);
+        frame.extend_from_slice(&[0; 4]);
+        frame
+    }
+
+    fn chunk(inner: Value) -> Vec<u8> {
+        let bytes = base64::engine::general_purpose::STANDARD.encode(inner.to_string());
+        event("chunk", json!({ "bytes": bytes }).to_string().as_bytes())
+    }
+
+    /// Serve one HTTP response and return the raw request
+    async fn serve_once(
+        content_type: &'static str,
+        body: Vec<u8>,
+    ) -> (String, tokio::task::JoinHandle<String>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 16384];
+            let n = socket.read(&mut buf).await.unwrap();
+            let head = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: {content_type}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                body.len()
+            );
+            socket.write_all(head.as_bytes()).await.unwrap();
+            socket.write_all(&body).await.unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_string()
+        });
+        (endpoint, handle)
+    }
+
+    #[test]
+    fn test_sigv4_matches_aws_test_suite() {
+        // get-vanilla from the AWS Signature Version 4 test suite
+        let url = reqwest::Url::parse("https://example.amazonaws.com/").unwrap();
+        let now = Utc.with_ymd_and_hms(2015, 8, 30, 12, 36, 0).unwrap();
+        let signed = sign_request(
+            &test_credentials(),
+            "us-east-1",
+            "service",
+            "GET",
+            &url,
+            b"",
+            now,
+        );
+        assert_eq!(signed.amz_date, "20150830T123600Z");
+        assert_eq!(
+            signed.authorization,
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+
+        let signed = sign_request(
+            &test_credentials().with_session_token("token"),
+            "us-east-1",
+            "service",
+            "GET",
+            &url,
+            b"",
+            now,
+        );
+        assert!(signed
+            .authorization
+            .contains("SignedHeaders=host;x-amz-date;x-amz-security-token"));
+    }
+
+    #[test]
+    fn test_model_family_detection_and_validation() {
+        assert_eq!(
+            BedrockModelFamily::from_model_id("anthropic.claude-3-haiku-20240307-v1:0"),
+            Some(BedrockModelFamily::Anthropic)
+        );
+        assert_eq!(
+            BedrockModelFamily::from_model_id("us.anthropic.claude-3-5-sonnet-20240620-v1:0"),
+            Some(BedrockModelFamily::Anthropic)
+        );
+        assert_eq!(
+            BedrockModelFamily::from_model_id("amazon.titan-text-express-v1"),
+            Some(BedrockModelFamily::Titan)
+        );
+        assert_eq!(BedrockModelFamily::from_model_id("meta.llama3-70b"), None);
+
+        assert!(BedrockSettings::new("meta.llama3-70b").validate().is_err());
+        assert!(matches!(
+            BedrockProvider::with_credentials(
+                BedrockSettings::new("amazon.titan-text-lite-v1"),
+                test_credentials()
+            ),
+            Err(ProviderError::ConfigurationError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_profile_credentials_are_read_from_shared_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("credentials");
+        std::fs::write(
+            &path,
+            "[default]\naws_access_key_id = AKIADEFAULT\naws_secret_access_key = secret\n\n\
+             [research]\naws_access_key_id=AKIARESEARCH\naws_secret_access_key=other\naws_session_token=tok\n",
+        )
+        .unwrap();
+
+        let credentials = AwsCredentials::from_profile_file(&path, "research").unwrap();
+        assert_eq!(
+            credentials,
+            AwsCredentials::new("AKIARESEARCH", "other").with_session_token("tok")
+        );
+        assert!(AwsCredentials::from_profile_file(&path, "missing").is_err());
+        assert!(!format!("{credentials:?}").contains("other"));
+    }
+
+    #[tokio::test]
+    async fn test_anthropic_invoke_is_signed() {
+        let body = json!({
+            "content": [{"type": "text", "text": "Claude on Bedrock"}],
+            "usage": {"input_tokens": 5, "output_tokens": 3}
+        });
+        let (endpoint, server) =
+            serve_once("application/json", body.to_string().into_bytes()).await;
+        let provider = BedrockProvider::with_credentials(
+            BedrockSettings::new("anthropic.claude-3-haiku-20240307-v1:0")
+                .with_region("eu-west-1")
+                .with_endpoint(endpoint),
+            test_credentials().with_session_token("session"),
+        )
+        .unwrap();
+
+        let answer = provider
+            .research_query("What is Bedrock?".to_string())
+            .await
+            .unwrap();
+        assert_eq!(answer, "Claude on Bedrock");
+
+        let request = server.await.unwrap();
+        assert!(request
+            .starts_with("POST /model/anthropic.claude-3-haiku-20240307-v1%3A0/invoke HTTP/1.1"));
+        let lower = request.to_lowercase();
+        assert!(lower.contains("authorization: aws4-hmac-sha256 credential=akidexample/"));
+        assert!(lower.contains("/eu-west-1/bedrock/aws4_request"));
+        assert!(lower.contains("x-amz-security-token: session"));
+        assert!(request.contains(r#""anthropic_version":"bedrock-2023-05-31""#));
+    }
+
+    #[tokio::test]
+    async fn test_titan_stream_yields_chunks() {
+        let mut body = chunk(json!({"outputText": "Titan ", "index": 0}));
+        body.extend(chunk(json!({"outputText": "streams", "index": 0})));
+        let (endpoint, server) = serve_once("application/vnd.amazon.eventstream", body).await;
+        let provider = BedrockProvider::with_credentials(
+            BedrockSettings::new("amazon.titan-text-express-v1")
+                .with_region("us-east-1")
+                .with_endpoint(endpoint),
+            test_credentials(),
+        )
+        .unwrap();
+
+        let mut chunks = Vec::new();
+        let answer = provider
+            .research_query_stream("Stream please".to_string(), |chunk| {
+                chunks.push(chunk.to_string())
+            })
+            .await
+            .unwrap();
+        assert_eq!(chunks, vec!["Titan ", "streams"]);
+        assert_eq!(answer, "Titan streams");
+
+        let request = server.await.unwrap();
+        assert!(request
+            .starts_with("POST /model/amazon.titan-text-express-v1/invoke-with-response-stream"));
+        assert!(request.contains(r#""inputText":"Stream please""#));
+    }
+
+    #[test]
+    fn test_event_stream_parsing_and_anthropic_deltas() {
+        let provider = BedrockProvider::with_credentials(
+            BedrockSettings::new("anthropic.claude-3-haiku-20240307-v1:0").with_region("us-east-1"),
+            test_credentials(),
+        )
+        .unwrap();
+
+        let mut buffer = chunk(json!({"type": "message_start", "message": {}}));
+        buffer.extend(chunk(json!({
+            "type": "content_block_delta",
+            "index": 0,
+            "delta": {"type": "text_delta", "text": "Hello"}
+        })));
+        let split = buffer.len() - 5;
+        let tail = buffer.split_off(split);
+
+        let first = next_event(&mut buffer).unwrap().unwrap();
+        assert_eq!(provider.stream_text(&first).unwrap(), None);
+        // The second message is incomplete until the rest arrives
+        assert_eq!(next_event(&mut buffer).unwrap(), None);
+        buffer.extend(tail);
+        let second = next_event(&mut buffer).unwrap().unwrap();
+        assert_eq!(
+            provider.stream_text(&second).unwrap().as_deref(),
+            Some("Hello")
+        );
+        assert!(buffer.is_empty());
+
+        let throttled = EventMessage {
+            message_type: Some("exception".to_string()),
+            exception_type: Some("throttlingException".to_string()),
+            payload: br#"{"message":"Too many requests"}"#.to_vec(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            provider.stream_text(&throttled),
+            Err(ProviderError::RateLimitExceeded { .. })
+        ));
+    }
+}
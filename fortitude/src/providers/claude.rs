@@ -40,11 +40,14 @@
 //! ```
 
 use crate::providers::config::{ProviderSettings, RateLimitConfig};
+use crate::providers::context::ContextWindow;
 use crate::providers::{
-    HealthStatus, Provider, ProviderError, ProviderMetadata, ProviderResult, QueryCost, UsageStats,
+    HealthStatus, OriginBudget, OriginUsage, Provider, ProviderError, ProviderMetadata,
+    ProviderResult, QueryCost, RequestOrigin, UsageStats,
 };
 
 use async_trait::async_trait;
+use fortitude_core::tools::{ToolCall, ToolDefinition, ToolMessage, ToolTurn};
 use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -220,12 +223,99 @@ struct ClaudeRequest {
     top_p: Option<f32>,
     top_k: Option<u32>,
     stop_sequences: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tools: Vec<ClaudeTool>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct ClaudeMessage {
     role: String,
-    content: String,
+    content: ClaudeMessageContent,
+}
+
+/// Message content: plain text, or content blocks for tool use and results
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum ClaudeMessageContent {
+    Text(String),
+    Blocks(Vec<ClaudeContent>),
+}
+
+impl From<String> for ClaudeMessageContent {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for ClaudeMessageContent {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+/// Tool offered to the model
+#[derive(Debug, Serialize)]
+struct ClaudeTool {
+    name: String,
+    description: String,
+    input_schema: serde_json::Value,
+}
+
+/// Convert a tool-calling conversation to Messages API messages
+fn tool_messages(messages: &[ToolMessage]) -> Vec<ClaudeMessage> {
+    messages
+        .iter()
+        .map(|message| match message {
+            ToolMessage::User(text) => ClaudeMessage {
+                role: "user".to_string(),
+                content: text.as_str().into(),
+            },
+            ToolMessage::Assistant(turn) => {
+                let text = (!turn.text.is_empty()).then(|| ClaudeContent::Text {
+                    text: turn.text.clone(),
+                });
+                let calls = turn.tool_calls.iter().map(|call| ClaudeContent::ToolUse {
+                    id: call.id.clone(),
+                    name: call.name.clone(),
+                    input: call.arguments.clone(),
+                });
+                ClaudeMessage {
+                    role: "assistant".to_string(),
+                    content: ClaudeMessageContent::Blocks(text.into_iter().chain(calls).collect()),
+                }
+            }
+            ToolMessage::ToolResults(outputs) => ClaudeMessage {
+                role: "user".to_string(),
+                content: ClaudeMessageContent::Blocks(
+                    outputs
+                        .iter()
+                        .map(|output| ClaudeContent::ToolResult {
+                            tool_use_id: output.call_id.clone(),
+                            content: output.content.clone(),
+                            is_error: output.is_error,
+                        })
+                        .collect(),
+                ),
+            },
+        })
+        .collect()
+}
+
+/// Convert the content blocks of a response into a tool turn
+fn tool_turn_from_content(content: Vec<ClaudeContent>) -> ToolTurn {
+    let mut turn = ToolTurn::default();
+    for block in content {
+        match block {
+            ClaudeContent::Text { text } => turn.text.push_str(&text),
+            ClaudeContent::ToolUse { id, name, input } => turn.tool_calls.push(ToolCall {
+                id,
+                name,
+                arguments: input,
+            }),
+            ClaudeContent::ToolResult { .. } => {}
+        }
+    }
+    turn
 }
 
 /// Anthropic Messages API v2 response structure
@@ -244,12 +334,23 @@ struct ClaudeResponse {
     usage: ClaudeUsage,
 }
 
-#[derive(Debug, Deserialize)]
-#[allow(dead_code)]
-struct ClaudeContent {
-    #[serde(rename = "type")]
-    content_type: String,
-    text: String,
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ClaudeContent {
+    Text {
+        text: String,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+        input: serde_json::Value,
+    },
+    ToolResult {
+        tool_use_id: String,
+        content: String,
+        #[serde(default)]
+        is_error: bool,
+    },
 }
 
 #[derive(Debug, Deserialize)]
@@ -360,8 +461,10 @@ pub struct ClaudeProvider {
     client: Client,
     settings: ProviderSettings,
     rate_limiter: ClaudeRateLimiter,
+    origin_budget: OriginBudget,
     stats: ProviderStats,
     model_costs: HashMap<String, ClaudeModelInfo>,
+    context_window: ContextWindow,
 }
 
 impl ClaudeProvider {
@@ -383,6 +486,10 @@ impl ClaudeProvider {
             })?;
 
         let rate_limiter = ClaudeRateLimiter::from_config(&settings.rate_limits);
+        let origin_budget = OriginBudget::new(
+            settings.origin_budgets.clone(),
+            settings.rate_limits.input_tokens_per_minute,
+        );
 
         let mut model_costs = HashMap::new();
         // Initialize Claude model costs (as of 2024 pricing)
@@ -414,21 +521,23 @@ impl ClaudeProvider {
             },
         );
 
+        let model_info = model_costs.get(&settings.model);
+        let context_window = ContextWindow::for_model(
+            &settings.model,
+            model_info.map(|m| m.context_length).unwrap_or(200000) as u32,
+            model_info.map(|m| m.max_output_tokens).unwrap_or(4096),
+        );
         Ok(Self {
             client,
             settings,
             rate_limiter,
+            origin_budget,
             stats: ProviderStats::default(),
             model_costs,
+            context_window,
         })
     }
 
-    /// Estimate token count for a text string using simple heuristic
-    fn estimate_tokens(&self, text: &str) -> u32 {
-        // Anthropic's estimation: roughly 1 token per 4 characters for English text
-        (text.len() / 4).max(1) as u32
-    }
-
     /// Get model-specific costs and constraints
     fn get_model_info(&self, model: &str) -> Option<&ClaudeModelInfo> {
         self.model_costs.get(model)
@@ -472,13 +581,34 @@ impl ClaudeProvider {
     }
 
     /// Execute HTTP request with retry logic
-    async fn execute_request(&self, request: ClaudeRequest) -> ProviderResult<ClaudeResponse> {
+    async fn execute_request(
+        &self,
+        request: ClaudeRequest,
+        origin: RequestOrigin,
+    ) -> ProviderResult<ClaudeResponse> {
         let start_time = Instant::now();
         let mut last_error = None;
 
+        let input_tokens = request
+            .messages
+            .iter()
+            .map(|message| match &message.content {
+                ClaudeMessageContent::Text(text) => self.context_window.estimate_tokens(text),
+                ClaudeMessageContent::Blocks(blocks) => self
+                    .context_window
+                    .estimate_tokens(&serde_json::to_string(blocks).unwrap_or_default()),
+            })
+            .sum();
+        self.origin_budget.admit(
+            "claude",
+            origin,
+            &self.context_window(),
+            input_tokens,
+            request.max_tokens,
+        )?;
+
         for attempt in 0..=self.settings.retry.max_retries {
             // Rate limiting
-            let input_tokens = self.estimate_tokens(&request.messages[0].content);
             let estimated_output_tokens = request.max_tokens / 2; // Conservative estimate
 
             let _guard = self
@@ -520,6 +650,11 @@ impl ClaudeProvider {
                                     actual_output_tokens,
                                     response_time,
                                 );
+                                self.origin_budget.record_completion(
+                                    origin,
+                                    actual_input_tokens,
+                                    actual_output_tokens,
+                                );
                                 return Ok(claude_resp);
                             }
                             Err(e) => {
@@ -592,6 +727,15 @@ impl ClaudeProvider {
 #[async_trait]
 impl Provider for ClaudeProvider {
     async fn research_query(&self, query: String) -> ProviderResult<String> {
+        self.research_query_with_origin(query, RequestOrigin::Interactive)
+            .await
+    }
+
+    async fn research_query_with_origin(
+        &self,
+        query: String,
+        origin: RequestOrigin,
+    ) -> ProviderResult<String> {
         self.validate_query(&query)?;
 
         debug!("Claude provider executing research query: {}", query);
@@ -604,21 +748,25 @@ impl Provider for ClaudeProvider {
             max_tokens,
             messages: vec![ClaudeMessage {
                 role: "user".to_string(),
-                content: query,
+                content: query.into(),
             }],
             system: None,
             temperature: Some(0.7),
             top_p: None,
             top_k: None,
             stop_sequences: None,
+            tools: Vec::new(),
         };
 
-        let response = self.execute_request(request).await?;
+        let response = self.execute_request(request, origin).await?;
 
         let content = response
             .content
-            .first()
-            .map(|content| content.text.clone())
+            .into_iter()
+            .find_map(|content| match content {
+                ClaudeContent::Text { text } => Some(text),
+                _ => None,
+            })
             .ok_or_else(|| ProviderError::QueryFailed {
                 provider: "claude".to_string(),
                 message: "No response content in Claude response".to_string(),
@@ -629,6 +777,46 @@ impl Provider for ClaudeProvider {
         Ok(content)
     }
 
+    fn supports_tools(&self) -> bool {
+        true
+    }
+
+    async fn tool_turn(
+        &self,
+        messages: &[ToolMessage],
+        tools: &[ToolDefinition],
+        origin: RequestOrigin,
+    ) -> ProviderResult<ToolTurn> {
+        let model_info = self.get_model_info(&self.settings.model);
+        let max_tokens = model_info.map(|m| m.max_output_tokens).unwrap_or(4096);
+
+        let request = ClaudeRequest {
+            model: self.settings.model.clone(),
+            max_tokens,
+            messages: tool_messages(messages),
+            system: None,
+            temperature: Some(0.7),
+            top_p: None,
+            top_k: None,
+            stop_sequences: None,
+            tools: tools
+                .iter()
+                .map(|tool| ClaudeTool {
+                    name: tool.name.clone(),
+                    description: tool.description.clone(),
+                    input_schema: tool.input_schema.clone(),
+                })
+                .collect(),
+        };
+
+        let response = self.execute_request(request, origin).await?;
+        Ok(tool_turn_from_content(response.content))
+    }
+
+    fn context_window(&self) -> ContextWindow {
+        self.context_window.clone()
+    }
+
     fn metadata(&self) -> ProviderMetadata {
         let model_info = self.get_model_info(&self.settings.model);
         let context_length = model_info.map(|m| m.context_length).unwrap_or(200000);
@@ -641,6 +829,7 @@ impl Provider for ClaudeProvider {
                 "cost_estimation".to_string(),
                 "token_counting".to_string(),
                 "anthropic_v2".to_string(),
+                "tool_calling".to_string(),
             ])
             .with_models(self.model_costs.keys().cloned().collect())
             .with_context_length(context_length)
@@ -665,16 +854,20 @@ impl Provider for ClaudeProvider {
             max_tokens: 1,
             messages: vec![ClaudeMessage {
                 role: "user".to_string(),
-                content: "Hi".to_string(),
+                content: "Hi".into(),
             }],
             system: None,
             temperature: Some(0.0),
             top_p: None,
             top_k: None,
             stop_sequences: None,
+            tools: Vec::new(),
         };
 
-        match self.execute_request(test_request).await {
+        match self
+            .execute_request(test_request, RequestOrigin::Interactive)
+            .await
+        {
             Ok(_) => {
                 info!("Claude provider health check passed");
                 Ok(HealthStatus::Healthy)
@@ -704,7 +897,7 @@ impl Provider for ClaudeProvider {
     }
 
     async fn estimate_cost(&self, query: &str) -> ProviderResult<QueryCost> {
-        let input_tokens = self.estimate_tokens(query);
+        let input_tokens = self.context_window.estimate_tokens(query);
         let estimated_output_tokens = input_tokens / 2; // Conservative estimate
 
         let model_info = self.get_model_info(&self.settings.model);
@@ -726,6 +919,10 @@ impl Provider for ClaudeProvider {
     async fn usage_stats(&self) -> ProviderResult<UsageStats> {
         Ok(self.stats.to_usage_stats().await)
     }
+
+    async fn origin_usage(&self) -> HashMap<RequestOrigin, OriginUsage> {
+        self.origin_budget.usage()
+    }
 }
 
 #[cfg(test)]
@@ -808,12 +1005,12 @@ mod tests {
         let short_text = "Hello";
         let long_text = "This is a much longer text that should result in more estimated tokens";
 
-        let short_tokens = provider.estimate_tokens(short_text);
-        let long_tokens = provider.estimate_tokens(long_text);
+        let short_tokens = provider.context_window.estimate_tokens(short_text);
+        let long_tokens = provider.context_window.estimate_tokens(long_text);
 
         assert!(short_tokens > 0);
         assert!(long_tokens > short_tokens);
-        assert_eq!(short_tokens, 1); // "Hello" has 5 chars, 5/4=1.25 -> max(1, 1) = 1
+        assert_eq!(short_tokens, 2); // "Hello" has 5 chars, 5/4=1.25 rounds up to 2
     }
 
     #[tokio::test]
@@ -1095,6 +1292,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_claude_tool_use_conversion() {
+        let content: Vec<ClaudeContent> = serde_json::from_value(serde_json::json!([
+            {"type": "text", "text": "Let me check."},
+            {"type": "tool_use", "id": "toolu_1", "name": "local_search", "input": {"query": "tokio"}}
+        ]))
+        .unwrap();
+        let turn = tool_turn_from_content(content);
+        assert_eq!(turn.text, "Let me check.");
+        assert_eq!(turn.tool_calls[0].arguments["query"], "tokio");
+
+        let messages = tool_messages(&[
+            ToolMessage::User("What about tokio?".to_string()),
+            ToolMessage::Assistant(turn),
+            ToolMessage::ToolResults(vec![fortitude_core::tools::ToolOutput {
+                call_id: "toolu_1".to_string(),
+                content: "- [abc] tokio runtime".to_string(),
+                is_error: false,
+            }]),
+        ]);
+        let json = serde_json::to_value(&messages).unwrap();
+        assert_eq!(json[0]["content"], "What about tokio?");
+        assert_eq!(json[1]["content"][1]["type"], "tool_use");
+        assert_eq!(json[2]["role"], "user");
+        assert_eq!(json[2]["content"][0]["type"], "tool_result");
+        assert_eq!(json[2]["content"][0]["tool_use_id"], "toolu_1");
+    }
+
     #[tokio::test]
     async fn test_claude_messages_api_request_structure() {
         let request = ClaudeRequest {
@@ -1102,13 +1327,14 @@ mod tests {
             max_tokens: 1000,
             messages: vec![ClaudeMessage {
                 role: "user".to_string(),
-                content: "Hello".to_string(),
+                content: "Hello".into(),
             }],
             system: Some("You are a helpful assistant".to_string()),
             temperature: Some(0.7),
             top_p: None,
             top_k: None,
             stop_sequences: None,
+            tools: Vec::new(),
         };
 
         // Test serialization
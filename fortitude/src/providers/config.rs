@@ -30,12 +30,14 @@
 //!     timeout: Duration::from_secs(30),
 //!     rate_limits: RateLimitConfig::default(),
 //!     retry: RetryConfig::default(),
+//!     origin_budgets: Default::default(),
 //! };
 //!
 //! // Validate configuration before use
 //! settings.validate().expect("Configuration should be valid");
 //! ```
 
+use super::priority::OriginBudgetConfig;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Duration;
@@ -240,6 +242,10 @@ pub struct ProviderSettings {
 
     pub rate_limits: RateLimitConfig,
     pub retry: RetryConfig,
+
+    /// Share of the input token budget available to each request origin
+    #[serde(default)]
+    pub origin_budgets: OriginBudgetConfig,
 }
 
 impl ProviderSettings {
@@ -251,6 +257,7 @@ impl ProviderSettings {
             timeout: Duration::from_secs(30),
             rate_limits: RateLimitConfig::default(),
             retry: RetryConfig::default(),
+            origin_budgets: OriginBudgetConfig::default(),
         }
     }
 
@@ -274,6 +281,11 @@ impl ProviderSettings {
         self
     }
 
+    pub fn with_origin_budgets(mut self, origin_budgets: OriginBudgetConfig) -> Self {
+        self.origin_budgets = origin_budgets;
+        self
+    }
+
     pub fn validate(&self) -> ConfigResult<()> {
         // Validate API key
         if self.api_key.trim().is_empty() {
@@ -318,6 +330,7 @@ impl ProviderSettings {
         // Validate nested configurations
         self.rate_limits.validate()?;
         self.retry.validate()?;
+        self.origin_budgets.validate()?;
 
         Ok(())
     }
@@ -510,7 +523,7 @@ impl Default for MultiProviderConfig {
 }
 
 /// Serialization helper for Duration
-mod duration_serde {
+pub(crate) mod duration_serde {
     use serde::{Deserialize, Deserializer, Serializer};
     use std::time::Duration;
 
@@ -0,0 +1,305 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Token counting and context window limits per provider model
+//! Each provider counts prompt tokens with the tokenizer of its model before a
+//! request is sent. A [`ContextWindow`] rejects prompts that would not leave
+//! room for the requested output, and fits context documents into the space
+//! that remains by truncating or dropping them.
+//!
+//! # Example Usage
+//!
+//! ```rust
+//! use fortitude::providers::context::ContextWindow;
+//!
+//! let window = ContextWindow::for_model("gpt-4", 8192, 1000);
+//! let prompt = window.fit_documents("Explain Rust lifetimes", &["...".to_string()]);
+//! assert!(window.check("openai", window.count_tokens(&prompt), 1000).is_ok());
+//! ```
+
+use super::{ProviderError, ProviderResult};
+use fortitude_core::{HeuristicTokenizer, ModelCatalog, Tokenizer, TokenizerRegistry};
+use std::sync::Arc;
+use tracing::debug;
+
+/// Output tokens reserved when a provider does not say how many it requests
+pub const DEFAULT_MAX_OUTPUT_TOKENS: u32 = 1000;
+
+/// Boundaries documents are split at, coarsest first
+const SEGMENT_SEPARATORS: [&str; 3] = ["\n\n", "\n", " "];
+
+/// Token limits and tokenizer of the model behind a provider
+#[derive(Debug, Clone)]
+pub struct ContextWindow {
+    /// Total tokens the model accepts, prompt and output together
+    pub context_length: u32,
+    /// Output tokens reserved by default when fitting documents
+    pub max_output_tokens: u32,
+    tokenizer: Arc<dyn Tokenizer>,
+}
+
+impl ContextWindow {
+    /// Window counted with the heuristic tokenizer
+    pub fn new(context_length: u32, max_output_tokens: u32) -> Self {
+        Self {
+            context_length,
+            max_output_tokens,
+            tokenizer: Arc::new(HeuristicTokenizer::default()),
+        }
+    }
+
+    /// Window counted with the tokenizer the model catalog assigns to `model`
+    ///
+    /// Providers build their window here so that prompts, budgets and cost
+    /// estimates are all counted the same way. Models missing from the catalog
+    /// fall back to the heuristic tokenizer.
+    pub fn for_model(model: &str, context_length: u32, max_output_tokens: u32) -> Self {
+        let window = Self::new(context_length, max_output_tokens);
+        match ModelCatalog::default().get(model) {
+            Some(pricing) => {
+                window.with_tokenizer(TokenizerRegistry::default().get(&pricing.tokenizer))
+            }
+            None => window,
+        }
+    }
+
+    pub fn with_tokenizer(mut self, tokenizer: Arc<dyn Tokenizer>) -> Self {
+        self.tokenizer = tokenizer;
+        self
+    }
+
+    pub fn count_tokens(&self, text: &str) -> u32 {
+        self.tokenizer.count_tokens(text)
+    }
+
+    /// Token estimate for `text`, never zero, used for budgets and costs
+    pub fn estimate_tokens(&self, text: &str) -> u32 {
+        self.count_tokens(text).max(1)
+    }
+
+    /// Tokens left for the prompt once the default output is reserved
+    pub fn prompt_budget(&self) -> u32 {
+        self.context_length.saturating_sub(self.max_output_tokens)
+    }
+
+    /// Reject a request whose prompt and requested output exceed the window
+    pub fn check(
+        &self,
+        provider: &str,
+        prompt_tokens: u32,
+        max_output_tokens: u32,
+    ) -> ProviderResult<()> {
+        if prompt_tokens.saturating_add(max_output_tokens) > self.context_length {
+            return Err(ProviderError::ContextTooLarge {
+                provider: provider.to_string(),
+                prompt_tokens,
+                max_output_tokens,
+                context_length: self.context_length,
+            });
+        }
+        Ok(())
+    }
+
+    /// Split `text` into segments of at most `max_tokens` tokens each
+    ///
+    /// Splits happen at paragraph breaks where possible, then at line breaks,
+    /// then between words; a single word longer than the limit is cut.
+    pub fn segment(&self, text: &str, max_tokens: u32) -> Vec<String> {
+        let mut segments = Vec::new();
+        if max_tokens > 0 && !text.trim().is_empty() {
+            self.split_into(text, max_tokens, &SEGMENT_SEPARATORS, &mut segments);
+        }
+        segments
+    }
+
+    fn split_into(
+        &self,
+        text: &str,
+        max_tokens: u32,
+        separators: &[&str],
+        segments: &mut Vec<String>,
+    ) {
+        if self.count_tokens(text) <= max_tokens {
+            segments.push(text.to_string());
+            return;
+        }
+        let Some((separator, finer)) = separators.split_first() else {
+            self.cut_characters(text, max_tokens, segments);
+            return;
+        };
+
+        let mut current = String::new();
+        for part in text.split(separator).filter(|part| !part.trim().is_empty()) {
+            let candidate = if current.is_empty() {
+                part.to_string()
+            } else {
+                format!("{current}{separator}{part}")
+            };
+            if self.count_tokens(&candidate) <= max_tokens {
+                current = candidate;
+                continue;
+            }
+            if !current.is_empty() {
+                segments.push(std::mem::take(&mut current));
+            }
+            if self.count_tokens(part) <= max_tokens {
+                current = part.to_string();
+            } else {
+                self.split_into(part, max_tokens, finer, segments);
+            }
+        }
+        if !current.is_empty() {
+            segments.push(current);
+        }
+    }
+
+    fn cut_characters(&self, text: &str, max_tokens: u32, segments: &mut Vec<String>) {
+        let mut current = String::new();
+        for c in text.chars() {
+            current.push(c);
+            if self.count_tokens(&current) > max_tokens && current.chars().count() > 1 {
+                current.pop();
+                segments.push(std::mem::take(&mut current));
+                current.push(c);
+            }
+        }
+        if !current.is_empty() {
+            segments.push(current);
+        }
+    }
+
+    /// Build a prompt from `query` and as many context documents as fit
+    ///
+    /// Documents are added whole in order. The first one that does not fit is
+    /// truncated to its leading segment, and the rest are dropped.
+    pub fn fit_documents(&self, query: &str, documents: &[String]) -> String {
+        let budget = self.prompt_budget();
+        let mut prompt = query.to_string();
+
+        for (index, document) in documents.iter().enumerate() {
+            let separator = if index == 0 {
+                "\n\n## Context\n\n"
+            } else {
+                "\n\n---\n\n"
+            };
+            let candidate = format!("{prompt}{separator}{document}");
+            if self.count_tokens(&candidate) <= budget {
+                prompt = candidate;
+                continue;
+            }
+
+            let remaining =
+                budget.saturating_sub(self.count_tokens(&format!("{prompt}{separator}")));
+            let truncated = self.segment(document, remaining).into_iter().next();
+            let dropped = documents.len() - index - usize::from(truncated.is_some());
+            if let Some(segment) = truncated {
+                prompt = format!("{prompt}{separator}{segment}");
+            }
+            debug!(
+                "Context window of {} tokens: truncated document {}, dropped {} document(s)",
+                self.context_length,
+                index + 1,
+                dropped
+            );
+            break;
+        }
+        prompt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_core::WhitespaceTokenizer;
+
+    fn whitespace_window(context_length: u32, max_output_tokens: u32) -> ContextWindow {
+        ContextWindow::new(context_length, max_output_tokens).with_tokenizer(Arc::new(
+            WhitespaceTokenizer {
+                tokens_per_word: 1.0,
+            },
+        ))
+    }
+
+    #[test]
+    fn test_check_rejects_prompts_without_room_for_output() {
+        let window = ContextWindow::new(1000, 200);
+        assert!(window.check("openai", 800, 200).is_ok());
+
+        match window.check("openai", 801, 200) {
+            Err(ProviderError::ContextTooLarge {
+                provider,
+                prompt_tokens,
+                context_length,
+                ..
+            }) => {
+                assert_eq!(provider, "openai");
+                assert_eq!(prompt_tokens, 801);
+                assert_eq!(context_length, 1000);
+            }
+            other => panic!("expected ContextTooLarge, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_for_model_uses_catalog_tokenizer() {
+        let code = "fn main() { let total = values.iter().sum::<u64>(); }";
+        let openai = ContextWindow::for_model("gpt-4", 8192, 1000);
+        let unknown = ContextWindow::for_model("unknown-model", 8192, 1000);
+        assert_eq!(
+            unknown.count_tokens(code),
+            HeuristicTokenizer::default().count_tokens(code)
+        );
+        assert_ne!(openai.count_tokens(code), unknown.count_tokens(code));
+    }
+
+    #[test]
+    fn test_segment_prefers_paragraph_then_word_boundaries() {
+        let window = whitespace_window(100, 0);
+        let text = "one two three\n\nfour five\n\nsix seven eight nine ten eleven";
+
+        let segments = window.segment(text, 4);
+        assert_eq!(
+            segments,
+            vec![
+                "one two three",
+                "four five",
+                "six seven eight nine",
+                "ten eleven"
+            ]
+        );
+        assert!(segments.iter().all(|s| window.count_tokens(s) <= 4));
+        assert!(window.segment(text, 0).is_empty());
+    }
+
+    #[test]
+    fn test_fit_documents_truncates_and_drops_overflow() {
+        let window = whitespace_window(20, 5);
+        let documents = vec![
+            "alpha beta gamma".to_string(),
+            "delta epsilon zeta eta theta iota kappa lambda".to_string(),
+            "never included".to_string(),
+        ];
+
+        let prompt = window.fit_documents("what is greek", &documents);
+        assert!(prompt.starts_with("what is greek\n\n## Context\n\nalpha beta gamma"));
+        assert!(prompt.contains("delta"));
+        assert!(!prompt.contains("lambda"));
+        assert!(!prompt.contains("never included"));
+        assert!(window.count_tokens(&prompt) <= window.prompt_budget());
+
+        let roomy = whitespace_window(1000, 5);
+        let prompt = roomy.fit_documents("what is greek", &documents);
+        assert!(prompt.contains("lambda") && prompt.contains("never included"));
+    }
+}
@@ -20,7 +20,8 @@
 //! # Features
 //!
 //! - **Multiple Fallback Strategies**: Round-robin, health-based, performance-based selection
-//! - **Circuit Breaker Pattern**: Prevents cascade failures by monitoring provider health
+//! - **Circuit Breaker Pattern**: Stops sending requests to a provider after repeated
+//!   failures and probes it again on an interval
 //! - **Health Monitoring**: Configurable intervals and comprehensive health checks
 //! - **Retry Mechanisms**: Exponential backoff with jitter for resilient operations
 //! - **Performance Tracking**: Real-time metrics for intelligent provider selection
@@ -59,7 +60,8 @@ use crate::providers::{Provider, ProviderError, ProviderResult};
 use chrono::{DateTime, Utc};
 use fortitude_types::ClassifiedRequest;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::path::{Path, PathBuf};
 use std::sync::{
     atomic::{AtomicBool, AtomicU64, Ordering},
     Arc,
@@ -92,6 +94,9 @@ pub enum FallbackError {
 
     #[error("Provider selection failed: {reason}")]
     SelectionFailed { reason: String },
+
+    #[error("Circuit breaker state at {path} could not be used: {reason}")]
+    BreakerState { path: String, reason: String },
 }
 
 /// Fallback strategy types for provider selection
@@ -168,6 +173,16 @@ pub enum CircuitBreakerState {
     },
 }
 
+impl CircuitBreakerState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CircuitBreakerState::Closed => "closed",
+            CircuitBreakerState::Open { .. } => "open",
+            CircuitBreakerState::HalfOpen { .. } => "half_open",
+        }
+    }
+}
+
 /// Health monitoring configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthMonitorConfig {
@@ -198,9 +213,10 @@ impl Default for HealthMonitorConfig {
 /// Circuit breaker configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CircuitBreakerConfig {
-    /// Number of failures before opening circuit
+    /// Number of consecutive failures before opening circuit
     pub failure_threshold: usize,
-    /// Duration to keep circuit open before half-open attempt
+    /// Duration to keep circuit open before half-open attempt, and between
+    /// probes while the provider keeps failing
     pub open_duration: Duration,
     /// Number of test requests in half-open state
     pub half_open_test_requests: usize,
@@ -219,6 +235,271 @@ impl Default for CircuitBreakerConfig {
     }
 }
 
+/// Where the CLI keeps circuit breaker state between invocations
+pub const DEFAULT_CIRCUIT_BREAKER_PATH: &str = ".fortitude/circuit_breakers.json";
+
+/// Circuit breaker for one provider
+///
+/// Opens after `failure_threshold` consecutive failures. Once `open_duration`
+/// has passed, up to `half_open_test_requests` probes are let through; the
+/// circuit closes if at least `recovery_threshold` of them succeed and
+/// reopens as soon as that can no longer happen. Probes still unanswered
+/// after another `open_duration` are given up on and a fresh set is granted.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CircuitBreaker {
+    pub state: CircuitBreakerState,
+    pub consecutive_failures: usize,
+    /// Times the circuit has opened
+    pub times_opened: u64,
+    /// Requests turned away while the circuit was open
+    pub rejected_requests: u64,
+    /// When the state last changed
+    pub last_transition: Option<DateTime<Utc>>,
+    /// Probe results since the circuit went half-open
+    #[serde(default)]
+    probe_successes: usize,
+    #[serde(default)]
+    probe_failures: usize,
+}
+
+impl CircuitBreaker {
+    /// Whether a request may be sent, moving an open circuit to half-open
+    /// once its interval has passed
+    ///
+    /// Does not use up a half-open probe; see [`CircuitBreaker::acquire`].
+    pub fn admits(&mut self, config: &CircuitBreakerConfig) -> bool {
+        let now = Utc::now();
+        match &self.state {
+            CircuitBreakerState::Open { recovery_time, .. } if now >= *recovery_time => {
+                self.start_probing(config);
+            }
+            // Probes that never report back, such as cancelled requests, must
+            // not hold the circuit half-open for good
+            CircuitBreakerState::HalfOpen { started_at, .. }
+                if now >= *started_at + open_duration(config) =>
+            {
+                self.start_probing(config);
+            }
+            _ => {}
+        }
+
+        let admitted = match &self.state {
+            CircuitBreakerState::Closed => true,
+            CircuitBreakerState::HalfOpen { test_attempts, .. } => *test_attempts > 0,
+            CircuitBreakerState::Open { .. } => false,
+        };
+        if !admitted {
+            self.rejected_requests += 1;
+        }
+        admitted
+    }
+
+    /// Claim permission to send a request, using up a probe while half-open
+    pub fn acquire(&mut self, config: &CircuitBreakerConfig) -> bool {
+        if !self.admits(config) {
+            return false;
+        }
+        if let CircuitBreakerState::HalfOpen { test_attempts, .. } = &mut self.state {
+            *test_attempts -= 1;
+        }
+        true
+    }
+
+    pub fn record_success(&mut self, config: &CircuitBreakerConfig) {
+        self.consecutive_failures = 0;
+        if matches!(self.state, CircuitBreakerState::HalfOpen { .. }) {
+            self.probe_successes += 1;
+            self.evaluate_probes(config);
+        }
+    }
+
+    pub fn record_failure(&mut self, config: &CircuitBreakerConfig) {
+        self.consecutive_failures += 1;
+        match self.state {
+            CircuitBreakerState::Closed => {
+                if self.consecutive_failures >= config.failure_threshold.max(1) {
+                    self.open(config);
+                }
+            }
+            CircuitBreakerState::HalfOpen { .. } => {
+                self.probe_failures += 1;
+                self.evaluate_probes(config);
+            }
+            // Results of requests sent before the circuit opened
+            CircuitBreakerState::Open { .. } => {}
+        }
+    }
+
+    fn evaluate_probes(&mut self, config: &CircuitBreakerConfig) {
+        let probes = config.half_open_test_requests.max(1);
+        let results = self.probe_successes + self.probe_failures;
+        let best_case =
+            (self.probe_successes + probes.saturating_sub(results)) as f64 / probes as f64;
+        if best_case < config.recovery_threshold {
+            self.open(config);
+        } else if results >= probes {
+            self.transition(CircuitBreakerState::Closed);
+        }
+    }
+
+    fn start_probing(&mut self, config: &CircuitBreakerConfig) {
+        self.probe_successes = 0;
+        self.probe_failures = 0;
+        self.transition(CircuitBreakerState::HalfOpen {
+            started_at: Utc::now(),
+            test_attempts: config.half_open_test_requests.max(1),
+        });
+    }
+
+    fn open(&mut self, config: &CircuitBreakerConfig) {
+        let now = Utc::now();
+        self.times_opened += 1;
+        self.transition(CircuitBreakerState::Open {
+            opened_at: now,
+            failure_count: self.consecutive_failures,
+            recovery_time: now + open_duration(config),
+        });
+    }
+
+    fn transition(&mut self, state: CircuitBreakerState) {
+        self.state = state;
+        self.last_transition = Some(Utc::now());
+    }
+}
+
+fn open_duration(config: &CircuitBreakerConfig) -> chrono::Duration {
+    chrono::Duration::from_std(config.open_duration)
+        .unwrap_or_else(|_| chrono::Duration::seconds(60))
+}
+
+/// Circuit breakers for a set of providers
+///
+/// When loaded from a file the state is written back after every change, so
+/// an open circuit outlives a single CLI invocation.
+#[derive(Debug)]
+pub struct CircuitBreakers {
+    config: CircuitBreakerConfig,
+    path: Option<PathBuf>,
+    breakers: std::sync::Mutex<BTreeMap<String, CircuitBreaker>>,
+}
+
+impl CircuitBreakers {
+    /// In-memory breakers that are not persisted
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            path: None,
+            breakers: std::sync::Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Breakers backed by the state file at `path`, which need not exist yet
+    pub fn load(
+        path: impl Into<PathBuf>,
+        config: CircuitBreakerConfig,
+    ) -> Result<Self, FallbackError> {
+        let path = path.into();
+        let state_error = |reason: String| FallbackError::BreakerState {
+            path: path.display().to_string(),
+            reason,
+        };
+        let breakers = match std::fs::read_to_string(&path) {
+            Ok(content) => {
+                serde_json::from_str(&content).map_err(|e| state_error(e.to_string()))?
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(state_error(e.to_string())),
+        };
+        Ok(Self {
+            config,
+            path: Some(path),
+            breakers: std::sync::Mutex::new(breakers),
+        })
+    }
+
+    pub fn config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    /// Whether `provider` may be selected; see [`CircuitBreaker::admits`]
+    pub fn admits(&self, provider: &str) -> bool {
+        self.update(provider, CircuitBreaker::admits)
+    }
+
+    /// Claim permission to send a request to `provider`
+    pub fn acquire(&self, provider: &str) -> bool {
+        self.update(provider, CircuitBreaker::acquire)
+    }
+
+    pub fn record_success(&self, provider: &str) {
+        self.update(provider, CircuitBreaker::record_success)
+    }
+
+    pub fn record_failure(&self, provider: &str) {
+        self.update(provider, CircuitBreaker::record_failure)
+    }
+
+    pub fn state(&self, provider: &str) -> CircuitBreakerState {
+        self.breakers
+            .lock()
+            .unwrap()
+            .get(provider)
+            .map(|breaker| breaker.state.clone())
+            .unwrap_or_default()
+    }
+
+    /// Breaker of every provider that has been used
+    pub fn status(&self) -> BTreeMap<String, CircuitBreaker> {
+        self.breakers.lock().unwrap().clone()
+    }
+
+    fn update<T>(
+        &self,
+        provider: &str,
+        apply: impl FnOnce(&mut CircuitBreaker, &CircuitBreakerConfig) -> T,
+    ) -> T {
+        let mut breakers = self.breakers.lock().unwrap();
+        let breaker = breakers.entry(provider.to_string()).or_default();
+        let before = breaker.clone();
+        let result = apply(breaker, &self.config);
+        if *breaker == before {
+            return result;
+        }
+
+        if breaker.state.as_str() != before.state.as_str() {
+            match &breaker.state {
+                CircuitBreakerState::Open { recovery_time, .. } => warn!(
+                    "Circuit breaker opened for provider '{}' after {} consecutive failures, probing again at {}",
+                    provider, breaker.consecutive_failures, recovery_time
+                ),
+                CircuitBreakerState::HalfOpen { .. } => {
+                    info!("Circuit breaker half-open for provider '{}'", provider)
+                }
+                CircuitBreakerState::Closed => {
+                    info!("Circuit breaker closed for provider '{}'", provider)
+                }
+            }
+        }
+        if let Some(path) = &self.path {
+            if let Err(e) = save_breakers(path, &breakers) {
+                warn!("Failed to save circuit breaker state: {}", e);
+            }
+        }
+        result
+    }
+}
+
+fn save_breakers(path: &Path, breakers: &BTreeMap<String, CircuitBreaker>) -> std::io::Result<()> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    let content = serde_json::to_string_pretty(breakers)?;
+    std::fs::write(path, content)
+}
+
 /// Retry configuration with exponential backoff
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RetryConfig {
@@ -385,8 +666,8 @@ pub struct RequestMetric {
 
 /// Health monitor for tracking provider health
 pub struct HealthMonitor {
-    config: HealthMonitorConfig,
     provider_metrics: Arc<RwLock<HashMap<String, ProviderMetrics>>>,
+    breakers: CircuitBreakers,
     #[allow(dead_code)] // TODO: Will be used for background health monitoring
     is_running: Arc<AtomicBool>,
     #[allow(dead_code)] // TODO: Will be used for periodic health checks
@@ -397,8 +678,8 @@ impl HealthMonitor {
     /// Create a new health monitor
     pub fn new(config: HealthMonitorConfig) -> Self {
         Self {
-            config,
             provider_metrics: Arc::new(RwLock::new(HashMap::new())),
+            breakers: CircuitBreakers::new(config.circuit_breaker),
             is_running: Arc::new(AtomicBool::new(false)),
             last_check: Arc::new(Mutex::new(Instant::now())),
         }
@@ -436,89 +717,31 @@ impl HealthMonitor {
 
     /// Update circuit breaker state based on request result
     async fn update_circuit_breaker_state(&self, metrics: &mut ProviderMetrics, success: bool) {
-        let config = &self.config.circuit_breaker;
-
-        match &metrics.circuit_breaker_state.clone() {
-            CircuitBreakerState::Closed => {
-                if !success && metrics.failed_requests >= config.failure_threshold as u64 {
-                    let recovery_time =
-                        Utc::now() + chrono::Duration::from_std(config.open_duration).unwrap();
-                    metrics.circuit_breaker_state = CircuitBreakerState::Open {
-                        opened_at: Utc::now(),
-                        failure_count: metrics.failed_requests as usize,
-                        recovery_time,
-                    };
-                    warn!(
-                        "Circuit breaker opened for provider: {}",
-                        metrics.provider_name
-                    );
-                }
-            }
-
-            CircuitBreakerState::Open { recovery_time, .. } => {
-                if Utc::now() >= *recovery_time {
-                    metrics.circuit_breaker_state = CircuitBreakerState::HalfOpen {
-                        started_at: Utc::now(),
-                        test_attempts: config.half_open_test_requests,
-                    };
-                    info!(
-                        "Circuit breaker half-open for provider: {}",
-                        metrics.provider_name
-                    );
-                }
-            }
-
-            CircuitBreakerState::HalfOpen { test_attempts, .. } => {
-                if success {
-                    // Check if we have enough successful requests to close circuit
-                    let recent_success_rate =
-                        self.calculate_recent_success_rate(metrics, config.half_open_test_requests);
-                    if recent_success_rate >= config.recovery_threshold {
-                        metrics.circuit_breaker_state = CircuitBreakerState::Closed;
-                        info!(
-                            "Circuit breaker closed for provider: {}",
-                            metrics.provider_name
-                        );
-                    }
-                } else if *test_attempts <= 1 {
-                    // Failed test attempt, reopen circuit
-                    let recovery_time =
-                        Utc::now() + chrono::Duration::from_std(config.open_duration).unwrap();
-                    metrics.circuit_breaker_state = CircuitBreakerState::Open {
-                        opened_at: Utc::now(),
-                        failure_count: metrics.failed_requests as usize,
-                        recovery_time,
-                    };
-                    warn!(
-                        "Circuit breaker reopened for provider: {}",
-                        metrics.provider_name
-                    );
-                } else {
-                    // Decrement test attempts
-                    metrics.circuit_breaker_state = CircuitBreakerState::HalfOpen {
-                        started_at: Utc::now(),
-                        test_attempts: test_attempts - 1,
-                    };
-                }
-            }
+        if success {
+            self.breakers.record_success(&metrics.provider_name);
+        } else {
+            self.breakers.record_failure(&metrics.provider_name);
         }
+        self.sync_circuit_breaker_state(metrics);
     }
 
-    /// Calculate recent success rate for circuit breaker decisions
-    fn calculate_recent_success_rate(&self, metrics: &ProviderMetrics, window_size: usize) -> f64 {
-        let recent_requests: Vec<_> = metrics
-            .performance_window
-            .iter()
-            .rev()
-            .take(window_size)
-            .collect();
-
-        if recent_requests.is_empty() {
-            return 0.0;
+    /// Copy the breaker state into `metrics`, rescoring health if it changed
+    fn sync_circuit_breaker_state(&self, metrics: &mut ProviderMetrics) {
+        let state = self.breakers.state(&metrics.provider_name);
+        if state != metrics.circuit_breaker_state {
+            metrics.circuit_breaker_state = state;
+            metrics.update_health_score();
         }
+    }
+
+    /// Claim permission to send a request to `provider_name`
+    pub fn acquire(&self, provider_name: &str) -> bool {
+        self.breakers.acquire(provider_name)
+    }
 
-        let successes = recent_requests.iter().filter(|m| m.success).count();
-        successes as f64 / recent_requests.len() as f64
+    /// Circuit breaker of every provider that has been used
+    pub fn circuit_breakers(&self) -> BTreeMap<String, CircuitBreaker> {
+        self.breakers.status()
     }
 
     /// Get current provider metrics
@@ -542,12 +765,17 @@ impl HealthMonitor {
     }
 
     /// Get healthy providers
+    ///
+    /// Providers whose circuit is open are left out until their next probe.
     pub async fn get_healthy_providers(&self) -> Vec<String> {
-        let metrics = self.provider_metrics.read().await;
+        let mut metrics = self.provider_metrics.write().await;
         metrics
-            .iter()
-            .filter(|(_, m)| m.is_healthy() && m.health_score >= 0.5)
-            .map(|(name, _)| name.clone())
+            .iter_mut()
+            .filter_map(|(name, m)| {
+                let admitted = self.breakers.admits(name);
+                self.sync_circuit_breaker_state(m);
+                (admitted && m.health_score >= 0.5).then(|| name.clone())
+            })
             .collect()
     }
 }
@@ -714,6 +942,18 @@ impl FallbackEngine {
 
             match self.select_provider(request).await {
                 Ok((provider_name, provider)) => {
+                    if !self.health_monitor.acquire(&provider_name) {
+                        debug!(
+                            "Circuit breaker for '{}' is not admitting requests",
+                            provider_name
+                        );
+                        last_error = Some(ProviderError::ServiceUnavailable {
+                            provider: provider_name,
+                            message: "Circuit breaker is open".to_string(),
+                            estimated_recovery: None,
+                        });
+                        continue;
+                    }
                     let start_time = Instant::now();
 
                     match provider
@@ -1203,6 +1443,118 @@ mod tests {
         );
     }
 
+    /// Move an open circuit's recovery time to now
+    fn elapse_open_interval(breaker: &mut CircuitBreaker) {
+        if let CircuitBreakerState::Open { recovery_time, .. } = &mut breaker.state {
+            *recovery_time = Utc::now();
+        }
+    }
+
+    #[test]
+    fn test_circuit_breaker_opens_probes_and_closes() {
+        let config = CircuitBreakerConfig {
+            failure_threshold: 3,
+            open_duration: Duration::from_secs(60),
+            half_open_test_requests: 2,
+            recovery_threshold: 1.0,
+        };
+        let mut breaker = CircuitBreaker::default();
+
+        // A success resets the run of failures
+        breaker.record_failure(&config);
+        breaker.record_failure(&config);
+        breaker.record_success(&config);
+        breaker.record_failure(&config);
+        breaker.record_failure(&config);
+        assert_eq!(breaker.state, CircuitBreakerState::Closed);
+        breaker.record_failure(&config);
+        assert!(matches!(breaker.state, CircuitBreakerState::Open { .. }));
+        assert_eq!(breaker.times_opened, 1);
+
+        // The probe interval has passed, so two probes are let through
+        assert!(!breaker.acquire(&config));
+        elapse_open_interval(&mut breaker);
+        assert!(breaker.acquire(&config));
+        assert!(matches!(
+            breaker.state,
+            CircuitBreakerState::HalfOpen { .. }
+        ));
+        assert!(breaker.acquire(&config));
+        assert!(!breaker.acquire(&config));
+        assert_eq!(breaker.rejected_requests, 2);
+        breaker.record_success(&config);
+        assert!(matches!(
+            breaker.state,
+            CircuitBreakerState::HalfOpen { .. }
+        ));
+        breaker.record_success(&config);
+        assert_eq!(breaker.state, CircuitBreakerState::Closed);
+
+        // A failed probe reopens the circuit straight away
+        for _ in 0..3 {
+            breaker.record_failure(&config);
+        }
+        elapse_open_interval(&mut breaker);
+        assert!(breaker.acquire(&config));
+        breaker.record_failure(&config);
+        assert!(matches!(breaker.state, CircuitBreakerState::Open { .. }));
+        assert_eq!(breaker.times_opened, 3);
+    }
+
+    #[test]
+    fn test_circuit_breaker_regrants_probes_that_never_report() {
+        let config = CircuitBreakerConfig {
+            failure_threshold: 1,
+            open_duration: Duration::from_secs(60),
+            half_open_test_requests: 1,
+            recovery_threshold: 1.0,
+        };
+        let mut breaker = CircuitBreaker {
+            state: CircuitBreakerState::HalfOpen {
+                started_at: Utc::now(),
+                test_attempts: 1,
+            },
+            ..Default::default()
+        };
+
+        // The only probe is taken and its request never finishes
+        assert!(breaker.acquire(&config));
+        assert!(!breaker.acquire(&config));
+
+        breaker.state = CircuitBreakerState::HalfOpen {
+            started_at: Utc::now() - chrono::Duration::seconds(61),
+            test_attempts: 0,
+        };
+        assert!(breaker.acquire(&config));
+        assert!(!breaker.acquire(&config));
+        breaker.record_success(&config);
+        assert_eq!(breaker.state, CircuitBreakerState::Closed);
+    }
+
+    #[test]
+    fn test_circuit_breakers_persist_open_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("breakers.json");
+        let config = CircuitBreakerConfig {
+            failure_threshold: 2,
+            ..Default::default()
+        };
+
+        let breakers = CircuitBreakers::load(&path, config.clone()).unwrap();
+        breakers.record_failure("openai");
+        breakers.record_failure("openai");
+        assert!(!breakers.admits("openai"));
+        assert!(breakers.admits("claude"));
+
+        let reloaded = CircuitBreakers::load(&path, config).unwrap();
+        assert!(matches!(
+            reloaded.state("openai"),
+            CircuitBreakerState::Open { .. }
+        ));
+        assert!(!reloaded.acquire("openai"));
+        assert_eq!(reloaded.status()["openai"].rejected_requests, 2);
+    }
+
     #[tokio::test]
     async fn test_retry_mechanism() {
         let strategy = FallbackStrategy::RoundRobin { reset_after: None };
@@ -40,8 +40,10 @@
 //! ```
 
 use crate::providers::config::{ProviderSettings, RateLimitConfig};
+use crate::providers::context::ContextWindow;
 use crate::providers::{
-    HealthStatus, Provider, ProviderError, ProviderMetadata, ProviderResult, QueryCost, UsageStats,
+    HealthStatus, OriginBudget, OriginUsage, Provider, ProviderError, ProviderMetadata,
+    ProviderResult, QueryCost, RequestOrigin, UsageStats,
 };
 
 use async_trait::async_trait;
@@ -401,8 +403,10 @@ pub struct GeminiProvider {
     client: Client,
     settings: ProviderSettings,
     rate_limiter: GeminiRateLimiter,
+    origin_budget: OriginBudget,
     stats: ProviderStats,
     model_costs: HashMap<String, GeminiModelInfo>,
+    context_window: ContextWindow,
 }
 
 impl GeminiProvider {
@@ -433,6 +437,10 @@ impl GeminiProvider {
             })?;
 
         let rate_limiter = GeminiRateLimiter::from_config(&settings.rate_limits);
+        let origin_budget = OriginBudget::new(
+            settings.origin_budgets.clone(),
+            settings.rate_limits.input_tokens_per_minute,
+        );
 
         let mut model_costs = HashMap::new();
         // Initialize Gemini model costs (as of 2024 pricing)
@@ -467,21 +475,23 @@ impl GeminiProvider {
             },
         );
 
+        let model_info = model_costs.get(&settings.model);
+        let context_window = ContextWindow::for_model(
+            &settings.model,
+            model_info.map(|m| m.context_length).unwrap_or(30720) as u32,
+            model_info.map(|m| m.max_output_tokens).unwrap_or(2048),
+        );
         Ok(Self {
             client,
             settings,
             rate_limiter,
+            origin_budget,
             stats: ProviderStats::default(),
             model_costs,
+            context_window,
         })
     }
 
-    /// Estimate token count for a text string using simple heuristic
-    fn estimate_tokens(&self, text: &str) -> u32 {
-        // Google's estimation: roughly 1 token per 4 characters for English text
-        (text.len() / 4).max(1) as u32
-    }
-
     /// Get model-specific costs and constraints
     fn get_model_info(&self, model: &str) -> Option<&GeminiModelInfo> {
         self.model_costs.get(model)
@@ -547,28 +557,39 @@ impl GeminiProvider {
     }
 
     /// Execute HTTP request with retry logic
-    async fn execute_request(&self, request: GeminiRequest) -> ProviderResult<GeminiResponse> {
+    async fn execute_request(
+        &self,
+        request: GeminiRequest,
+        origin: RequestOrigin,
+    ) -> ProviderResult<GeminiResponse> {
         let start_time = Instant::now();
         let mut last_error = None;
 
-        for attempt in 0..=self.settings.retry.max_retries {
-            // Rate limiting
-            let input_tokens = if let Some(content) = request.contents.first() {
-                if let Some(part) = content.parts.first() {
-                    self.estimate_tokens(&part.text)
-                } else {
-                    0
-                }
+        let input_tokens = if let Some(content) = request.contents.first() {
+            if let Some(part) = content.parts.first() {
+                self.context_window.estimate_tokens(&part.text)
             } else {
                 0
-            };
+            }
+        } else {
+            0
+        };
+        let max_output_tokens = request
+            .generation_config
+            .as_ref()
+            .and_then(|config| config.max_output_tokens)
+            .unwrap_or(2048);
+        self.origin_budget.admit(
+            "gemini",
+            origin,
+            &self.context_window(),
+            input_tokens,
+            max_output_tokens,
+        )?;
 
-            let estimated_output_tokens = request
-                .generation_config
-                .as_ref()
-                .and_then(|config| config.max_output_tokens)
-                .unwrap_or(2048)
-                / 2; // Conservative estimate
+        for attempt in 0..=self.settings.retry.max_retries {
+            // Rate limiting
+            let estimated_output_tokens = max_output_tokens / 2; // Conservative estimate
 
             let _guard = self
                 .rate_limiter
@@ -614,6 +635,11 @@ impl GeminiProvider {
                                     actual_output_tokens,
                                     response_time,
                                 );
+                                self.origin_budget.record_completion(
+                                    origin,
+                                    actual_input_tokens,
+                                    actual_output_tokens,
+                                );
                                 return Ok(gemini_resp);
                             }
                             Err(e) => {
@@ -686,6 +712,15 @@ impl GeminiProvider {
 #[async_trait]
 impl Provider for GeminiProvider {
     async fn research_query(&self, query: String) -> ProviderResult<String> {
+        self.research_query_with_origin(query, RequestOrigin::Interactive)
+            .await
+    }
+
+    async fn research_query_with_origin(
+        &self,
+        query: String,
+        origin: RequestOrigin,
+    ) -> ProviderResult<String> {
         self.validate_query(&query)?;
 
         debug!("Gemini provider executing research query: {}", query);
@@ -708,7 +743,7 @@ impl Provider for GeminiProvider {
             safety_settings: Some(Self::default_safety_settings()),
         };
 
-        let response = self.execute_request(request).await?;
+        let response = self.execute_request(request, origin).await?;
 
         let content = response
             .candidates
@@ -725,6 +760,10 @@ impl Provider for GeminiProvider {
         Ok(content)
     }
 
+    fn context_window(&self) -> ContextWindow {
+        self.context_window.clone()
+    }
+
     fn metadata(&self) -> ProviderMetadata {
         let model_info = self.get_model_info(&self.settings.model);
         let context_length = model_info.map(|m| m.context_length).unwrap_or(30720);
@@ -780,7 +819,10 @@ impl Provider for GeminiProvider {
             safety_settings: Some(Self::default_safety_settings()),
         };
 
-        match self.execute_request(test_request).await {
+        match self
+            .execute_request(test_request, RequestOrigin::Interactive)
+            .await
+        {
             Ok(_) => {
                 info!("Gemini provider health check passed");
                 Ok(HealthStatus::Healthy)
@@ -810,7 +852,7 @@ impl Provider for GeminiProvider {
     }
 
     async fn estimate_cost(&self, query: &str) -> ProviderResult<QueryCost> {
-        let input_tokens = self.estimate_tokens(query);
+        let input_tokens = self.context_window.estimate_tokens(query);
         let estimated_output_tokens = input_tokens / 2; // Conservative estimate
 
         let model_info = self.get_model_info(&self.settings.model);
@@ -832,6 +874,10 @@ impl Provider for GeminiProvider {
     async fn usage_stats(&self) -> ProviderResult<UsageStats> {
         Ok(self.stats.to_usage_stats().await)
     }
+
+    async fn origin_usage(&self) -> HashMap<RequestOrigin, OriginUsage> {
+        self.origin_budget.usage()
+    }
 }
 
 #[cfg(test)]
@@ -915,12 +961,12 @@ mod tests {
         let short_text = "Hello";
         let long_text = "This is a much longer text that should result in more estimated tokens";
 
-        let short_tokens = provider.estimate_tokens(short_text);
-        let long_tokens = provider.estimate_tokens(long_text);
+        let short_tokens = provider.context_window.estimate_tokens(short_text);
+        let long_tokens = provider.context_window.estimate_tokens(long_text);
 
         assert!(short_tokens > 0);
         assert!(long_tokens > short_tokens);
-        assert_eq!(short_tokens, 1); // "Hello" has 5 chars, 5/4=1.25 -> max(1, 1) = 1
+        assert_eq!(short_tokens, 2); // "Hello" has 5 chars, 5/4=1.25 rounds up to 2
     }
 
     #[tokio::test]
@@ -66,8 +66,15 @@
 //! }
 //! ```
 
-use crate::providers::{HealthStatus, Provider, ProviderError, ProviderResult};
-use chrono::Utc;
+use crate::proactive::{NotificationEvent, NotificationSystem, NotificationType};
+use crate::providers::fallback::{CircuitBreakerConfig, CircuitBreakers};
+use crate::providers::{
+    HealthStatus, OriginUsage, Provider, ProviderError, ProviderResult, QueryCost, RequestOrigin,
+};
+use chrono::{DateTime, Utc};
+use fortitude_core::cost_tracking::{BudgetStatus, CostTracker};
+use fortitude_core::model_catalog::estimate_token_count;
+use fortitude_core::tools::ToolRegistry;
 use fortitude_types::{
     AudienceContext, ClassifiedRequest, DomainContext, ResearchMetadata, ResearchResult,
     ResearchType,
@@ -101,10 +108,16 @@ pub enum ProviderManagerError {
 
     #[error("Health check failed for provider '{provider}': {reason}")]
     HealthCheckFailed { provider: String, reason: String },
+
+    #[error("Spending budget exceeded: {message}")]
+    BudgetExceeded {
+        message: String,
+        resets_at: Option<chrono::DateTime<chrono::Utc>>,
+    },
 }
 
 /// Provider selection strategies
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub enum SelectionStrategy {
     /// Round-robin across all healthy providers
     RoundRobin,
@@ -112,8 +125,15 @@ pub enum SelectionStrategy {
     LowestLatency,
     /// Choose provider with highest success rate
     HighestSuccessRate,
-    /// Choose most cost-effective provider
-    CostOptimized,
+    /// Choose the cheapest provider that meets the quality threshold
+    ///
+    /// Estimates are corrected by each provider's history of actual against
+    /// estimated spend. Providers predicted to cost more than
+    /// `max_cost_per_query` USD are never selected.
+    CostOptimized {
+        #[serde(default)]
+        max_cost_per_query: Option<f64>,
+    },
     /// Choose provider based on research type characteristics
     ResearchTypeOptimized,
     /// Balanced approach considering latency, success rate, and cost
@@ -153,6 +173,10 @@ pub struct ProviderConfig {
 
     /// Minimum quality score threshold
     pub min_quality_threshold: f64,
+
+    /// When to stop sending requests to a failing provider and probe it again
+    #[serde(default)]
+    pub circuit_breaker: CircuitBreakerConfig,
 }
 
 impl Default for ProviderConfig {
@@ -168,6 +192,7 @@ impl Default for ProviderConfig {
             performance_window_size: 100,
             cost_optimization_threshold: 0.1, // 10% quality difference tolerance
             min_quality_threshold: 0.6,
+            circuit_breaker: CircuitBreakerConfig::default(),
         }
     }
 }
@@ -185,6 +210,12 @@ pub struct ProviderPerformance {
     pub last_failure: Option<chrono::DateTime<chrono::Utc>>,
     pub consecutive_failures: u32,
     pub health_status: HealthStatus,
+    /// Rate limit budget consumption per request origin
+    #[serde(default)]
+    pub origin_usage: HashMap<RequestOrigin, OriginUsage>,
+    /// Estimated against actual spend of completed requests
+    #[serde(default)]
+    pub cost_calibration: CostCalibration,
 }
 
 impl Default for ProviderPerformance {
@@ -200,6 +231,8 @@ impl Default for ProviderPerformance {
             last_failure: None,
             consecutive_failures: 0,
             health_status: HealthStatus::Healthy,
+            origin_usage: HashMap::new(),
+            cost_calibration: CostCalibration::default(),
         }
     }
 }
@@ -275,6 +308,52 @@ impl ProviderPerformance {
     }
 }
 
+/// Estimated and actual spend of a provider's completed requests
+///
+/// Used to correct cost estimates before comparing providers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CostCalibration {
+    pub samples: u64,
+    pub estimated_cost: f64,
+    pub actual_cost: f64,
+}
+
+impl CostCalibration {
+    pub fn record(&mut self, estimated_cost: f64, actual_cost: f64) {
+        self.samples += 1;
+        self.estimated_cost += estimated_cost;
+        self.actual_cost += actual_cost;
+    }
+
+    /// Actual spend per estimated dollar; 1.0 until there is spend to compare
+    pub fn ratio(&self) -> f64 {
+        if self.samples == 0 || self.estimated_cost <= 0.0 {
+            1.0
+        } else {
+            self.actual_cost / self.estimated_cost
+        }
+    }
+
+    /// Correct a fresh estimate by the recorded ratio
+    pub fn calibrate(&self, estimated_cost: f64) -> f64 {
+        estimated_cost * self.ratio()
+    }
+}
+
+/// Cost of a completed request
+///
+/// The estimate is scaled by the tokens actually produced, since the output
+/// length is only guessed before the request runs.
+fn actual_cost(estimate: &QueryCost, response: &str) -> Option<f64> {
+    let estimated_cost = estimate.estimated_cost_usd?;
+    let estimated_tokens = estimate.estimated_input_tokens + estimate.estimated_output_tokens;
+    if estimated_tokens == 0 {
+        return Some(estimated_cost);
+    }
+    let actual_tokens = estimate.estimated_input_tokens + estimate_token_count(response);
+    Some(estimated_cost * actual_tokens as f64 / estimated_tokens as f64)
+}
+
 /// Provider wrapper with performance tracking
 struct ManagedProvider {
     provider: Arc<dyn Provider>,
@@ -336,6 +415,14 @@ impl ManagedProvider {
         }
     }
 
+    async fn record_cost_calibration(&self, estimated_cost: f64, actual_cost: f64) {
+        self.performance
+            .lock()
+            .await
+            .cost_calibration
+            .record(estimated_cost, actual_cost);
+    }
+
     async fn get_performance(&self) -> ProviderPerformance {
         self.performance.lock().await.clone()
     }
@@ -358,6 +445,16 @@ pub struct ProviderManager {
     config: ProviderConfig,
     selection_state: Arc<Mutex<SelectionState>>,
     performance_tracker: Arc<RwLock<HashMap<String, ProviderPerformance>>>,
+    /// Provider tried first while it is healthy, ahead of the selection strategy
+    preferred_provider: Arc<RwLock<Option<String>>>,
+    /// Records spend per request and enforces the spending budget
+    cost_tracker: Option<Arc<CostTracker>>,
+    /// Keeps failing providers out of selection until they recover
+    circuit_breakers: Arc<CircuitBreakers>,
+    /// Receives budget exceeded alerts
+    notification_system: Option<Arc<NotificationSystem>>,
+    /// Reset time of the exhausted budget last alerted on
+    budget_alerted_for: Arc<Mutex<Option<Option<DateTime<Utc>>>>>,
 }
 
 #[derive(Debug, Default)]
@@ -372,12 +469,53 @@ impl ProviderManager {
     pub async fn new(config: ProviderConfig) -> Result<Self, ProviderManagerError> {
         Ok(Self {
             providers: Arc::new(RwLock::new(HashMap::new())),
-            config,
             selection_state: Arc::new(Mutex::new(SelectionState::default())),
             performance_tracker: Arc::new(RwLock::new(HashMap::new())),
+            preferred_provider: Arc::new(RwLock::new(None)),
+            cost_tracker: None,
+            circuit_breakers: Arc::new(CircuitBreakers::new(config.circuit_breaker.clone())),
+            notification_system: None,
+            budget_alerted_for: Arc::new(Mutex::new(None)),
+            config,
         })
     }
 
+    /// Share circuit breakers with other components or persist them between runs
+    pub fn with_circuit_breakers(mut self, breakers: Arc<CircuitBreakers>) -> Self {
+        self.circuit_breakers = breakers;
+        self
+    }
+
+    /// Circuit breaker state of every provider that has been used
+    pub fn circuit_breakers(&self) -> &Arc<CircuitBreakers> {
+        &self.circuit_breakers
+    }
+
+    /// Record provider spend and enforce the tracker's budget
+    ///
+    /// Near the budget limit the cheapest provider is selected regardless of
+    /// strategy or preference; once the budget is exceeded requests fail.
+    pub fn with_cost_tracker(mut self, tracker: Arc<CostTracker>) -> Self {
+        self.cost_tracker = Some(tracker);
+        self
+    }
+
+    /// Send a budget exceeded notification when the spending budget runs out
+    ///
+    /// One alert is sent each time a limit is used up, not one per refused request.
+    pub fn with_notification_system(
+        mut self,
+        notification_system: Arc<NotificationSystem>,
+    ) -> Self {
+        self.notification_system = Some(notification_system);
+        self
+    }
+
+    /// Spend ledger shared with reporting, if cost tracking is enabled
+    pub fn cost_tracker(&self) -> Option<&Arc<CostTracker>> {
+        self.cost_tracker.as_ref()
+    }
+
     /// Add a provider to the manager
     pub async fn add_provider(
         &self,
@@ -434,6 +572,28 @@ impl ProviderManager {
         providers.keys().cloned().collect()
     }
 
+    /// Prefer a registered provider over the selection strategy while it is healthy
+    ///
+    /// `None` clears the preference.
+    pub async fn set_preferred_provider(
+        &self,
+        name: Option<String>,
+    ) -> Result<(), ProviderManagerError> {
+        if let Some(name) = &name {
+            if !self.providers.read().await.contains_key(name) {
+                return Err(ProviderManagerError::ProviderNotFound(name.clone()));
+            }
+            info!("Preferring provider: {}", name);
+        }
+        *self.preferred_provider.write().await = name;
+        Ok(())
+    }
+
+    /// Provider preferred over the selection strategy, if any
+    pub async fn preferred_provider(&self) -> Option<String> {
+        self.preferred_provider.read().await.clone()
+    }
+
     /// Select the optimal provider for a research request
     pub async fn select_provider(
         &self,
@@ -445,17 +605,30 @@ impl ProviderManager {
             return Err(ProviderManagerError::NoProviders);
         }
 
-        // Filter to healthy providers
-        let healthy_providers: Vec<_> = {
-            let mut healthy = Vec::new();
-            for (name, managed_provider) in providers.iter() {
-                let performance = managed_provider.get_performance().await;
-                if performance.is_healthy() {
-                    healthy.push((name.clone(), managed_provider.provider.clone(), performance));
-                }
+        // Filter to healthy providers whose circuit admits requests
+        let mut admitted = Vec::new();
+        let mut healthy_providers = Vec::new();
+        for (name, managed_provider) in providers.iter() {
+            if !self.circuit_breakers.admits(name) {
+                debug!("Skipping provider '{}': circuit breaker is open", name);
+                continue;
             }
-            healthy
-        };
+            admitted.push((name, managed_provider));
+            let performance = managed_provider.get_performance().await;
+            if performance.is_healthy() {
+                healthy_providers.push((
+                    name.clone(),
+                    managed_provider.provider.clone(),
+                    performance,
+                ));
+            }
+        }
+
+        if admitted.is_empty() {
+            return Err(ProviderManagerError::SelectionFailed(
+                "Circuit breakers are open for every provider".to_string(),
+            ));
+        }
 
         if healthy_providers.is_empty() {
             // Debug: Let's see what's wrong with health checks
@@ -467,23 +640,85 @@ impl ProviderManager {
                     performance.consecutive_failures, performance.success_rate());
             }
             warn!("No healthy providers available, falling back to all providers");
-            // If no healthy providers, try any available provider
-            let (name, managed_provider) = providers
-                .iter()
-                .next()
-                .ok_or(ProviderManagerError::NoProviders)?;
+            // If no healthy providers, try any provider with a closed circuit
+            let (name, managed_provider) = admitted[0];
             return Ok((name.clone(), managed_provider.provider.clone()));
         }
 
+        if let Some(tracker) = &self.cost_tracker {
+            let budget = tracker.budget();
+            match budget.status {
+                BudgetStatus::Exceeded => {
+                    return Err(ProviderManagerError::BudgetExceeded {
+                        message: format!(
+                            "spent ${:.2} today and ${:.2} this month",
+                            budget.daily_spend_usd, budget.monthly_spend_usd
+                        ),
+                        resets_at: budget.resets_at,
+                    });
+                }
+                BudgetStatus::NearLimit => {
+                    if let Some((name, provider, _)) = self
+                        .select_cost_optimized(&healthy_providers, request, None)
+                        .await
+                    {
+                        info!(
+                            "Near spending budget, selected cheapest provider '{}'",
+                            name
+                        );
+                        return Ok((name, provider));
+                    }
+                }
+                BudgetStatus::WithinBudget => {}
+            }
+        }
+
+        // A provider chosen for this request wins over the standing preference
+        if let Some(selection) = &request.provider_selection {
+            if let Some((name, provider, _)) = healthy_providers
+                .iter()
+                .find(|(name, _, _)| name.eq_ignore_ascii_case(&selection.provider))
+            {
+                debug!("Selected requested provider '{}'", name);
+                return Ok((name.clone(), provider.clone()));
+            }
+            warn!(
+                "Requested provider '{}' is unavailable, selecting another",
+                selection.provider
+            );
+        }
+
+        if let Some(preferred) = self.preferred_provider.read().await.as_deref() {
+            if let Some((name, provider, _)) = healthy_providers
+                .iter()
+                .find(|(name, _, _)| name == preferred)
+            {
+                debug!("Selected preferred provider '{}'", name);
+                return Ok((name.clone(), provider.clone()));
+            }
+            warn!(
+                "Preferred provider '{}' is unavailable, using strategy '{:?}'",
+                preferred, self.config.selection_strategy
+            );
+        }
+
         let selected = match &self.config.selection_strategy {
             SelectionStrategy::RoundRobin => self.select_round_robin(&healthy_providers).await,
             SelectionStrategy::LowestLatency => self.select_lowest_latency(&healthy_providers),
             SelectionStrategy::HighestSuccessRate => {
                 self.select_highest_success_rate(&healthy_providers)
             }
-            SelectionStrategy::CostOptimized => {
-                self.select_cost_optimized(&healthy_providers, request)
-                    .await
+            SelectionStrategy::CostOptimized { max_cost_per_query } => {
+                let selected = self
+                    .select_cost_optimized(&healthy_providers, request, *max_cost_per_query)
+                    .await;
+                if let (None, Some(ceiling)) = (&selected, max_cost_per_query) {
+                    return Err(ProviderManagerError::SelectionFailed(format!(
+                        "No provider can answer within ${:.4} per query",
+                        ceiling
+                    )));
+                }
+                selected
             }
             SelectionStrategy::ResearchTypeOptimized => {
                 self.select_research_type_optimized(&healthy_providers, request)
@@ -512,6 +747,31 @@ impl ProviderManager {
     pub async fn execute_research(
         &self,
         request: &ClassifiedRequest,
+    ) -> ProviderResult<ResearchResult> {
+        self.execute_research_with_origin(request, RequestOrigin::Interactive)
+            .await
+    }
+
+    /// Execute research on behalf of `origin` with automatic failover
+    ///
+    /// Providers charge the request against the origin's share of their rate
+    /// limit budget, so proactive and batch work cannot starve interactive use.
+    pub async fn execute_research_with_origin(
+        &self,
+        request: &ClassifiedRequest,
+        origin: RequestOrigin,
+    ) -> ProviderResult<ResearchResult> {
+        self.execute_research_with_tools(request, origin, &ToolRegistry::default())
+            .await
+    }
+
+    /// Execute research on behalf of `origin`, offering `tools` to providers
+    /// that support tool calling
+    pub async fn execute_research_with_tools(
+        &self,
+        request: &ClassifiedRequest,
+        origin: RequestOrigin,
+        tools: &ToolRegistry,
     ) -> ProviderResult<ResearchResult> {
         let start_time = Instant::now();
         let mut attempts = 0;
@@ -522,22 +782,59 @@ impl ProviderManager {
 
             match self.select_provider(request).await {
                 Ok((provider_name, provider)) => {
+                    // An oversize prompt is the caller's fault, so it must not
+                    // count against the provider's circuit breaker
+                    let window = provider.context_window();
+                    let prompt_tokens = window.count_tokens(&request.original_query);
+                    if let Err(error) =
+                        window.check(&provider_name, prompt_tokens, window.max_output_tokens)
+                    {
+                        warn!("Rejecting request for '{}': {}", provider_name, error);
+                        last_error = Some(error);
+                        break;
+                    }
+                    if !self.circuit_breakers.acquire(&provider_name) {
+                        // Another request took the last half-open probe
+                        last_error = Some(ProviderError::ServiceUnavailable {
+                            provider: provider_name,
+                            message: "Circuit breaker is open".to_string(),
+                            estimated_recovery: None,
+                        });
+                        continue;
+                    }
                     debug!(
                         "Attempting research with provider '{}' (attempt {})",
                         provider_name, attempts
                     );
 
                     let request_start = Instant::now();
-                    match self.execute_with_timeout(&provider, request).await {
+                    match self
+                        .execute_with_timeout(&provider, request, origin, tools)
+                        .await
+                    {
                         Ok(response) => {
                             let latency = request_start.elapsed();
+                            self.circuit_breakers.record_success(&provider_name);
 
                             // Estimate cost and quality (would be more sophisticated in real implementation)
-                            let cost_estimate = provider
-                                .estimate_cost(&request.original_query)
-                                .await
-                                .ok()
-                                .and_then(|cost| cost.estimated_cost_usd);
+                            let query_cost =
+                                provider.estimate_cost(&request.original_query).await.ok();
+                            let cost_estimate =
+                                query_cost.as_ref().and_then(|cost| cost.estimated_cost_usd);
+                            let cost_actual = query_cost
+                                .as_ref()
+                                .and_then(|cost| actual_cost(cost, &response));
+
+                            if let (Some(tracker), Some(cost)) = (&self.cost_tracker, &query_cost) {
+                                if let Err(e) = tracker.record(
+                                    &provider_name,
+                                    cost.estimated_input_tokens,
+                                    cost.estimated_output_tokens,
+                                    cost.estimated_cost_usd.unwrap_or(0.0),
+                                ) {
+                                    warn!("Failed to record provider spend: {}", e);
+                                }
+                            }
 
                             // Update provider performance
                             let providers = self.providers.read().await;
@@ -546,10 +843,21 @@ impl ProviderManager {
                                     .update_performance(
                                         true,
                                         latency,
-                                        cost_estimate,
+                                        cost_actual,
                                         Some(0.8), // Mock quality score
                                     )
                                     .await;
+                                if let (Some(estimated), Some(actual)) =
+                                    (cost_estimate, cost_actual)
+                                {
+                                    debug!(
+                                        "Provider '{}' cost ${:.6}, estimated ${:.6}",
+                                        provider_name, actual, estimated
+                                    );
+                                    managed_provider
+                                        .record_cost_calibration(estimated, actual)
+                                        .await;
+                                }
                             }
 
                             info!(
@@ -584,6 +892,7 @@ impl ProviderManager {
                         Err(error) => {
                             let latency = request_start.elapsed();
                             warn!("Provider '{}' failed: {}", provider_name, error);
+                            self.circuit_breakers.record_failure(&provider_name);
 
                             // Update provider performance
                             let providers = self.providers.read().await;
@@ -601,6 +910,16 @@ impl ProviderManager {
                         }
                     }
                 }
+                Err(ProviderManagerError::BudgetExceeded { message, resets_at }) => {
+                    warn!("Spending budget exceeded: {}", message);
+                    self.alert_budget_exceeded(&message, resets_at).await;
+                    last_error = Some(ProviderError::QuotaExceeded {
+                        provider: "budget".to_string(),
+                        message,
+                        reset_time: resets_at,
+                    });
+                    break;
+                }
                 Err(manager_error) => {
                     error!("Provider selection failed: {}", manager_error);
                     last_error = Some(ProviderError::ServiceUnavailable {
@@ -620,17 +939,57 @@ impl ProviderManager {
         }))
     }
 
+    /// Notify the notification system, once per exhausted budget period
+    async fn alert_budget_exceeded(&self, message: &str, resets_at: Option<DateTime<Utc>>) {
+        let Some(notification_system) = &self.notification_system else {
+            return;
+        };
+        {
+            let mut alerted_for = self.budget_alerted_for.lock().await;
+            if *alerted_for == Some(resets_at) {
+                return;
+            }
+            *alerted_for = Some(resets_at);
+        }
+
+        let mut metadata = HashMap::new();
+        let text = match resets_at {
+            Some(resets_at) => {
+                metadata.insert("resets_at".to_string(), resets_at.to_rfc3339());
+                format!(
+                    "{message}; provider requests resume at {}",
+                    resets_at.format("%Y-%m-%d %H:%M UTC")
+                )
+            }
+            None => message.to_string(),
+        };
+        if let Err(e) = notification_system
+            .notify(
+                NotificationEvent::BudgetExceeded,
+                NotificationType::Warning,
+                "Spending budget exceeded".to_string(),
+                text,
+                metadata,
+            )
+            .await
+        {
+            warn!("Failed to send budget exceeded notification: {}", e);
+        }
+    }
+
     /// Execute provider request with timeout
     async fn execute_with_timeout(
         &self,
         provider: &Arc<dyn Provider>,
         request: &ClassifiedRequest,
+        origin: RequestOrigin,
+        tools: &ToolRegistry,
     ) -> ProviderResult<String> {
         let timeout_duration = self.config.provider_timeout;
 
         match tokio::time::timeout(
             timeout_duration,
-            provider.research_query(request.original_query.clone()),
+            provider.research_query_with_tools(request.original_query.clone(), origin, tools),
         )
         .await
         {
@@ -682,7 +1041,8 @@ impl ProviderManager {
         let mut stats = HashMap::new();
 
         for (name, managed_provider) in providers.iter() {
-            let performance = managed_provider.get_performance().await;
+            let mut performance = managed_provider.get_performance().await;
+            performance.origin_usage = managed_provider.provider.origin_usage().await;
             stats.insert(name.clone(), performance);
         }
 
@@ -745,29 +1105,50 @@ impl ProviderManager {
             .cloned()
     }
 
+    /// Cheapest provider for the prompt, skipping any predicted to exceed `max_cost`
+    ///
+    /// Providers whose average quality is below the configured threshold are
+    /// only chosen when no provider within the ceiling meets it.
     async fn select_cost_optimized(
         &self,
         providers: &[(String, Arc<dyn Provider>, ProviderPerformance)],
         request: &ClassifiedRequest,
+        max_cost: Option<f64>,
     ) -> Option<(String, Arc<dyn Provider>, ProviderPerformance)> {
         let mut cost_candidates = Vec::new();
 
         for (name, provider, performance) in providers {
-            if let Ok(cost) = provider.estimate_cost(&request.original_query).await {
-                if let Some(cost_usd) = cost.estimated_cost_usd {
-                    cost_candidates.push((
-                        name.clone(),
-                        provider.clone(),
-                        performance.clone(),
-                        cost_usd,
-                    ));
-                }
+            let Ok(cost) = provider.estimate_cost(&request.original_query).await else {
+                continue;
+            };
+            let Some(cost_usd) = cost.estimated_cost_usd else {
+                continue;
+            };
+            let predicted = performance.cost_calibration.calibrate(cost_usd);
+            if max_cost.is_some_and(|ceiling| predicted > ceiling) {
+                debug!(
+                    "Skipping provider '{}': predicted ${:.6} exceeds the per-query ceiling",
+                    name, predicted
+                );
+                continue;
             }
+            cost_candidates.push((
+                name.clone(),
+                provider.clone(),
+                performance.clone(),
+                predicted,
+            ));
         }
 
         cost_candidates.sort_by(|a, b| a.3.partial_cmp(&b.3).unwrap_or(std::cmp::Ordering::Equal));
+        let meets_quality = |perf: &ProviderPerformance| {
+            perf.quality_scores.is_empty()
+                || perf.average_quality() >= self.config.min_quality_threshold
+        };
         cost_candidates
-            .first()
+            .iter()
+            .find(|(_, _, perf, _)| meets_quality(perf))
+            .or_else(|| cost_candidates.first())
             .map(|(name, provider, perf, _)| (name.clone(), provider.clone(), perf.clone()))
     }
 
@@ -900,7 +1281,7 @@ mod tests {
     use super::*;
     use crate::providers::{ProviderMetadata, QueryCost, UsageStats};
     use async_trait::async_trait;
-    use fortitude_types::{AudienceContext, DomainContext};
+    use fortitude_types::{AudienceContext, DomainContext, ProviderSelection};
 
     // Mock provider for testing
     #[derive(Debug, Clone)]
@@ -966,6 +1347,50 @@ mod tests {
         }
     }
 
+    /// Provider that charges every request against a per-origin budget
+    #[derive(Debug)]
+    struct BudgetedProvider {
+        budget: crate::providers::OriginBudget,
+    }
+
+    #[async_trait]
+    impl Provider for BudgetedProvider {
+        async fn research_query(&self, query: String) -> ProviderResult<String> {
+            self.research_query_with_origin(query, RequestOrigin::Interactive)
+                .await
+        }
+
+        async fn research_query_with_origin(
+            &self,
+            query: String,
+            origin: RequestOrigin,
+        ) -> ProviderResult<String> {
+            self.budget.try_acquire(origin, 200).map_err(|wait| {
+                ProviderError::RateLimitExceeded {
+                    provider: "budgeted".to_string(),
+                    message: format!("Token budget for {origin} requests exceeded"),
+                    retry_after: Some(wait),
+                    requests_remaining: None,
+                    tokens_remaining: Some(0),
+                }
+            })?;
+            self.budget.record_completion(origin, 200, 50);
+            Ok(format!("budgeted response: {query}"))
+        }
+
+        fn metadata(&self) -> ProviderMetadata {
+            ProviderMetadata::new("budgeted".to_string(), "1.0.0".to_string())
+        }
+
+        async fn health_check(&self) -> ProviderResult<HealthStatus> {
+            Ok(HealthStatus::Healthy)
+        }
+
+        async fn origin_usage(&self) -> HashMap<RequestOrigin, OriginUsage> {
+            self.budget.usage()
+        }
+    }
+
     fn create_test_request() -> ClassifiedRequest {
         ClassifiedRequest::new(
             "Test query".to_string(),
@@ -1049,6 +1474,308 @@ mod tests {
         assert!(selected_name == "fast" || selected_name == "slow");
     }
 
+    #[tokio::test]
+    async fn test_spending_budget_fails_over_to_cheapest_then_blocks() {
+        use crate::proactive::{NotificationChannel, NotificationSystemConfig};
+        use fortitude_core::cost_tracking::CostBudgetConfig;
+
+        let dir = tempfile::tempdir().unwrap();
+        let alerts_path = dir.path().join("alerts.log");
+        let mut notification_config = NotificationSystemConfig::default();
+        notification_config.event_channels.insert(
+            NotificationEvent::BudgetExceeded,
+            vec![NotificationChannel::File {
+                path: alerts_path.clone(),
+            }],
+        );
+        let notification_system = Arc::new(NotificationSystem::new(notification_config));
+        notification_system.start().await.unwrap();
+
+        let tracker = Arc::new(CostTracker::new(
+            CostBudgetConfig::default().with_daily_limit(1.0),
+        ));
+        let manager = ProviderManager::new(ProviderConfig::default())
+            .await
+            .unwrap()
+            .with_cost_tracker(tracker.clone())
+            .with_notification_system(notification_system);
+        for (name, cost) in [("premium", 0.5), ("budget", 0.05)] {
+            let provider = Arc::new(TestProvider::new(
+                name,
+                true,
+                Duration::from_millis(1),
+                cost,
+                1.0,
+            ));
+            manager
+                .add_provider(name.to_string(), provider)
+                .await
+                .unwrap();
+        }
+        manager
+            .set_preferred_provider(Some("premium".to_string()))
+            .await
+            .unwrap();
+
+        let request = create_test_request();
+        manager.execute_research(&request).await.unwrap();
+        let spend = tracker.summary(1).providers;
+        assert_eq!(spend["premium"].requests, 1);
+        assert_eq!(spend["premium"].input_tokens, 10);
+
+        // Past 80% of the daily limit the cheapest provider wins over the preference
+        tracker.record("premium", 0, 0, 0.35).unwrap();
+        let (selected, _) = manager.select_provider(&request).await.unwrap();
+        assert_eq!(selected, "budget");
+
+        tracker.record("budget", 0, 0, 0.2).unwrap();
+        for _ in 0..2 {
+            assert!(matches!(
+                manager.execute_research(&request).await,
+                Err(ProviderError::QuotaExceeded {
+                    reset_time: Some(_),
+                    ..
+                })
+            ));
+        }
+
+        // Refused requests alert once per exhausted budget
+        let alerts = std::fs::read_to_string(&alerts_path).unwrap();
+        assert_eq!(alerts.lines().count(), 1);
+        assert!(alerts.contains("Spending budget exceeded"));
+    }
+
+    #[tokio::test]
+    async fn test_cost_optimized_respects_ceiling_quality_and_calibration() {
+        let config = ProviderConfig {
+            selection_strategy: SelectionStrategy::CostOptimized {
+                max_cost_per_query: Some(0.1),
+            },
+            ..Default::default()
+        };
+        let manager = ProviderManager::new(config).await.unwrap();
+        for (name, cost) in [("premium", 0.5), ("mid", 0.05), ("cheap", 0.02)] {
+            let provider = Arc::new(TestProvider::new(
+                name,
+                true,
+                Duration::from_millis(1),
+                cost,
+                1.0,
+            ));
+            manager
+                .add_provider(name.to_string(), provider)
+                .await
+                .unwrap();
+        }
+        let request = create_test_request();
+        let (selected, _) = manager.select_provider(&request).await.unwrap();
+        assert_eq!(selected, "cheap");
+
+        // Below the quality threshold the next cheapest provider wins
+        {
+            let providers = manager.providers.read().await;
+            providers["cheap"]
+                .performance
+                .lock()
+                .await
+                .quality_scores
+                .push(0.3);
+        }
+        let (selected, _) = manager.select_provider(&request).await.unwrap();
+        assert_eq!(selected, "mid");
+
+        // Spending five times its estimate puts "mid" over the ceiling
+        {
+            let providers = manager.providers.read().await;
+            providers["mid"].record_cost_calibration(0.05, 0.25).await;
+        }
+        let (selected, _) = manager.select_provider(&request).await.unwrap();
+        assert_eq!(selected, "cheap");
+
+        let strict = ProviderManager::new(ProviderConfig {
+            selection_strategy: SelectionStrategy::CostOptimized {
+                max_cost_per_query: Some(0.01),
+            },
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        let provider = Arc::new(TestProvider::new(
+            "cheap",
+            true,
+            Duration::from_millis(1),
+            0.02,
+            1.0,
+        ));
+        strict
+            .add_provider("cheap".to_string(), provider)
+            .await
+            .unwrap();
+        assert!(matches!(
+            strict.select_provider(&request).await,
+            Err(ProviderManagerError::SelectionFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_completed_requests_record_cost_calibration() {
+        let manager = ProviderManager::new(ProviderConfig::default())
+            .await
+            .unwrap();
+        let provider = Arc::new(TestProvider::new(
+            "tracked",
+            true,
+            Duration::from_millis(1),
+            0.03,
+            1.0,
+        ));
+        manager
+            .add_provider("tracked".to_string(), provider)
+            .await
+            .unwrap();
+
+        manager
+            .execute_research(&create_test_request())
+            .await
+            .unwrap();
+
+        // "tracked response: Test query" is 7 tokens against the 20 estimated
+        let calibration = &manager.get_performance_stats().await["tracked"].cost_calibration;
+        assert_eq!(calibration.samples, 1);
+        assert!((calibration.estimated_cost - 0.03).abs() < 1e-9);
+        assert!((calibration.actual_cost - 0.017).abs() < 1e-9);
+        assert!(calibration.ratio() < 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_preferred_provider_wins_while_healthy() {
+        let manager = ProviderManager::new(ProviderConfig::default())
+            .await
+            .unwrap();
+        for (name, healthy) in [("primary", true), ("backup", true), ("down", false)] {
+            let provider = Arc::new(TestProvider::new(
+                name,
+                healthy,
+                Duration::from_millis(1),
+                0.01,
+                1.0,
+            ));
+            manager
+                .add_provider(name.to_string(), provider)
+                .await
+                .unwrap();
+        }
+        assert!(matches!(
+            manager
+                .set_preferred_provider(Some("missing".to_string()))
+                .await,
+            Err(ProviderManagerError::ProviderNotFound(_))
+        ));
+
+        let request = create_test_request();
+        for preferred in ["primary", "backup"] {
+            manager
+                .set_preferred_provider(Some(preferred.to_string()))
+                .await
+                .unwrap();
+            let (selected, _) = manager.select_provider(&request).await.unwrap();
+            assert_eq!(selected, preferred);
+        }
+
+        manager
+            .set_preferred_provider(Some("down".to_string()))
+            .await
+            .unwrap();
+        let (selected, _) = manager.select_provider(&request).await.unwrap();
+        assert_ne!(selected, "down");
+        assert_eq!(manager.preferred_provider().await.as_deref(), Some("down"));
+    }
+
+    #[tokio::test]
+    async fn test_request_provider_selection_wins_over_preference() {
+        let manager = ProviderManager::new(ProviderConfig::default())
+            .await
+            .unwrap();
+        for (name, healthy) in [("primary", true), ("backup", true), ("down", false)] {
+            let provider = Arc::new(TestProvider::new(
+                name,
+                healthy,
+                Duration::from_millis(1),
+                0.01,
+                1.0,
+            ));
+            manager
+                .add_provider(name.to_string(), provider)
+                .await
+                .unwrap();
+        }
+        manager
+            .set_preferred_provider(Some("primary".to_string()))
+            .await
+            .unwrap();
+
+        let mut request = create_test_request();
+        request.provider_selection = Some(ProviderSelection {
+            provider: "backup".to_string(),
+            model: None,
+        });
+        let (selected, _) = manager.select_provider(&request).await.unwrap();
+        assert_eq!(selected, "backup");
+
+        // An unhealthy selection falls back to the preference
+        request.provider_selection = Some(ProviderSelection {
+            provider: "down".to_string(),
+            model: None,
+        });
+        let (selected, _) = manager.select_provider(&request).await.unwrap();
+        assert_eq!(selected, "primary");
+    }
+
+    #[tokio::test]
+    async fn test_open_circuit_stops_requests_to_failing_provider() {
+        let config = ProviderConfig {
+            max_failover_attempts: 1,
+            circuit_breaker: CircuitBreakerConfig {
+                failure_threshold: 2,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let manager = ProviderManager::new(config).await.unwrap();
+        let provider = Arc::new(TestProvider::new(
+            "failing",
+            true,
+            Duration::from_millis(1),
+            0.01,
+            0.0,
+        ));
+        manager
+            .add_provider("failing".to_string(), provider)
+            .await
+            .unwrap();
+
+        let request = create_test_request();
+        for _ in 0..2 {
+            assert!(matches!(
+                manager.execute_research(&request).await,
+                Err(ProviderError::QueryFailed { .. })
+            ));
+        }
+
+        // The provider is no longer tried until the probe interval passes
+        assert!(matches!(
+            manager.execute_research(&request).await,
+            Err(ProviderError::ServiceUnavailable { .. })
+        ));
+        let breaker = &manager.circuit_breakers().status()["failing"];
+        assert_eq!(breaker.state.as_str(), "open");
+        assert_eq!(breaker.rejected_requests, 1);
+        assert_eq!(
+            manager.get_performance_stats().await["failing"].total_requests,
+            2
+        );
+    }
+
     #[tokio::test]
     async fn test_health_check_all() {
         let config = ProviderConfig::default();
@@ -1160,6 +1887,42 @@ mod tests {
         assert!(provider_stats.total_requests > 0);
     }
 
+    #[tokio::test]
+    async fn test_origin_budget_reported_in_performance_stats() {
+        let manager = ProviderManager::new(ProviderConfig::default())
+            .await
+            .unwrap();
+        let provider = Arc::new(BudgetedProvider {
+            budget: crate::providers::OriginBudget::new(Default::default(), 1_000),
+        });
+        manager
+            .add_provider("budgeted".to_string(), provider)
+            .await
+            .unwrap();
+
+        let request = create_test_request();
+        for _ in 0..3 {
+            manager.execute_research(&request).await.unwrap();
+        }
+
+        // Proactive traffic is capped at 30% of 1,000 tokens per minute
+        assert!(manager
+            .execute_research_with_origin(&request, RequestOrigin::Proactive)
+            .await
+            .is_ok());
+        assert!(manager
+            .execute_research_with_origin(&request, RequestOrigin::Proactive)
+            .await
+            .is_err());
+
+        let stats = manager.get_performance_stats().await;
+        let usage = &stats["budgeted"].origin_usage;
+        assert_eq!(usage[&RequestOrigin::Interactive].requests, 3);
+        assert_eq!(usage[&RequestOrigin::Interactive].input_tokens, 600);
+        assert_eq!(usage[&RequestOrigin::Proactive].requests, 1);
+        assert!(usage[&RequestOrigin::Proactive].throttled_requests >= 1);
+    }
+
     #[test]
     fn test_provider_health_check_fix() {
         // Test case 1: Brand new provider should be healthy
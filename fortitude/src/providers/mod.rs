@@ -35,25 +35,39 @@
 //! ```
 
 use async_trait::async_trait;
+use fortitude_core::tools::{ToolDefinition, ToolMessage, ToolRegistry, ToolTurn};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Duration;
 use thiserror::Error;
 
+pub mod bedrock;
 pub mod claude;
 pub mod config;
+pub mod context;
 pub mod fallback;
 pub mod gemini;
 pub mod manager;
 pub mod mock;
 pub mod openai;
+pub mod priority;
+pub mod selection;
+pub mod tools;
 
+pub use bedrock::{AwsCredentials, BedrockModelFamily, BedrockProvider, BedrockSettings};
 pub use claude::ClaudeProvider;
 pub use config::*;
-pub use fallback::{FallbackEngine, FallbackError, FallbackStrategy, HealthMonitor, RetryConfig};
+pub use context::{ContextWindow, DEFAULT_MAX_OUTPUT_TOKENS};
+pub use fallback::{
+    CircuitBreaker, CircuitBreakerConfig, CircuitBreakerState, CircuitBreakers, FallbackEngine,
+    FallbackError, FallbackStrategy, HealthMonitor, RetryConfig, DEFAULT_CIRCUIT_BREAKER_PATH,
+};
 pub use gemini::GeminiProvider;
 pub use manager::{ProviderConfig, ProviderManager, ProviderManagerError, SelectionStrategy};
-pub use openai::OpenAIProvider;
+pub use openai::{AzureAuth, AzureDeployment, OpenAIProvider, DEFAULT_AZURE_API_VERSION};
+pub use priority::{OriginBudget, OriginBudgetConfig, OriginUsage, RequestOrigin};
+pub use selection::{ProviderSelection, SelectionStoreError};
+pub use tools::run_tool_loop;
 
 /// Result type for provider operations
 pub type ProviderResult<T> = Result<T, ProviderError>;
@@ -115,6 +129,16 @@ pub enum ProviderError {
         message: String,
         estimated_recovery: Option<Duration>,
     },
+
+    #[error(
+        "Prompt of {prompt_tokens} tokens plus {max_output_tokens} output tokens exceeds the {context_length} token context window of provider {provider}"
+    )]
+    ContextTooLarge {
+        provider: String,
+        prompt_tokens: u32,
+        max_output_tokens: u32,
+        context_length: u32,
+    },
 }
 
 impl ProviderError {
@@ -153,6 +177,7 @@ impl ProviderError {
             ProviderError::SerializationError { provider, .. } => provider,
             ProviderError::QuotaExceeded { provider, .. } => provider,
             ProviderError::ServiceUnavailable { provider, .. } => provider,
+            ProviderError::ContextTooLarge { provider, .. } => provider,
         }
     }
 }
@@ -284,9 +309,80 @@ pub trait Provider: Send + Sync {
     /// Execute a research query against the provider
     async fn research_query(&self, query: String) -> ProviderResult<String>;
 
+    /// Execute a research query tagged with the origin of the request
+    ///
+    /// Providers that enforce per-origin budgets override this; the default
+    /// ignores the origin.
+    async fn research_query_with_origin(
+        &self,
+        query: String,
+        _origin: RequestOrigin,
+    ) -> ProviderResult<String> {
+        self.research_query(query).await
+    }
+
+    /// Whether the provider can hold a tool-calling conversation
+    fn supports_tools(&self) -> bool {
+        false
+    }
+
+    /// Send one turn of a tool-calling conversation and return the model's reply
+    async fn tool_turn(
+        &self,
+        _messages: &[ToolMessage],
+        _tools: &[ToolDefinition],
+        _origin: RequestOrigin,
+    ) -> ProviderResult<ToolTurn> {
+        Err(ProviderError::ConfigurationError {
+            provider: self.metadata().name().to_string(),
+            message: "Tool calling is not supported".to_string(),
+        })
+    }
+
+    /// Execute a research query, letting the model call `tools` before it answers
+    ///
+    /// Providers without tool support and empty registries fall back to a
+    /// plain query.
+    async fn research_query_with_tools(
+        &self,
+        query: String,
+        origin: RequestOrigin,
+        tools: &ToolRegistry,
+    ) -> ProviderResult<String> {
+        if tools.is_empty() || !self.supports_tools() {
+            return self.research_query_with_origin(query, origin).await;
+        }
+        run_tool_loop(self, query, origin, tools).await
+    }
+
     /// Get provider metadata including capabilities and rate limits
     fn metadata(&self) -> ProviderMetadata;
 
+    /// Token limits and tokenizer of the model behind the provider
+    ///
+    /// The default counts with the heuristic tokenizer against the context
+    /// length in the provider metadata.
+    fn context_window(&self) -> ContextWindow {
+        ContextWindow::new(
+            self.metadata().max_context_length() as u32,
+            DEFAULT_MAX_OUTPUT_TOKENS,
+        )
+    }
+
+    /// Execute a research query with supporting documents
+    ///
+    /// Documents are truncated or dropped so the prompt fits the provider's
+    /// context window.
+    async fn research_query_with_context(
+        &self,
+        query: String,
+        documents: &[String],
+        origin: RequestOrigin,
+    ) -> ProviderResult<String> {
+        let prompt = self.context_window().fit_documents(&query, documents);
+        self.research_query_with_origin(prompt, origin).await
+    }
+
     /// Check the health status of the provider
     async fn health_check(&self) -> ProviderResult<HealthStatus>;
 
@@ -318,6 +414,11 @@ pub trait Provider: Send + Sync {
         // Default implementation returns empty stats
         Ok(UsageStats::default())
     }
+
+    /// Get consumption per request origin
+    async fn origin_usage(&self) -> HashMap<RequestOrigin, OriginUsage> {
+        HashMap::new()
+    }
 }
 
 /// Cost estimation for a query
@@ -15,7 +15,8 @@
 // ABOUTME: OpenAI provider implementation with rate limiting, error handling, and token counting
 //! This module provides a concrete implementation of the Provider trait for OpenAI's API.
 //! Features include token bucket rate limiting, comprehensive error mapping, cost estimation,
-//! and health checking functionality.
+//! and health checking functionality. The same provider talks to Azure OpenAI deployments
+//! when created with [`OpenAIProvider::azure`].
 //!
 //! # Example Usage
 //!
@@ -38,13 +39,30 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! Azure OpenAI resources are addressed by deployment rather than model name:
+//!
+//! ```rust,no_run
+//! use fortitude::providers::config::ProviderSettings;
+//! use fortitude::providers::openai::{AzureDeployment, OpenAIProvider};
+//!
+//! # async fn azure() -> Result<(), Box<dyn std::error::Error>> {
+//! let settings = ProviderSettings::new(std::env::var("AZURE_OPENAI_API_KEY")?, "gpt-4".to_string())
+//!     .with_endpoint("https://my-resource.openai.azure.com".to_string());
+//! let provider = OpenAIProvider::azure(settings, AzureDeployment::new("gpt4-research")).await?;
+//! # Ok(())
+//! # }
+//! ```
 
 use crate::providers::config::{ProviderSettings, RateLimitConfig};
+use crate::providers::context::{ContextWindow, DEFAULT_MAX_OUTPUT_TOKENS};
 use crate::providers::{
-    HealthStatus, Provider, ProviderError, ProviderMetadata, ProviderResult, QueryCost, UsageStats,
+    HealthStatus, OriginBudget, OriginUsage, Provider, ProviderError, ProviderMetadata,
+    ProviderResult, QueryCost, RequestOrigin, UsageStats,
 };
 
 use async_trait::async_trait;
+use fortitude_core::tools::{ToolCall, ToolDefinition, ToolMessage, ToolTurn};
 use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -219,12 +237,119 @@ struct OpenAIRequest {
     top_p: Option<f32>,
     frequency_penalty: Option<f32>,
     presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tools: Vec<OpenAITool>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct OpenAIMessage {
     role: String,
-    content: String,
+    /// Null when an assistant message only carries tool calls
+    #[serde(default)]
+    content: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tool_calls: Vec<OpenAIToolCall>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tool_call_id: Option<String>,
+}
+
+impl OpenAIMessage {
+    fn text(role: &str, content: String) -> Self {
+        Self {
+            role: role.to_string(),
+            content: Some(content),
+            tool_calls: Vec::new(),
+            tool_call_id: None,
+        }
+    }
+}
+
+/// Function tool offered to the model
+#[derive(Debug, Serialize)]
+struct OpenAITool {
+    #[serde(rename = "type")]
+    tool_type: String,
+    function: OpenAIFunction,
+}
+
+#[derive(Debug, Serialize)]
+struct OpenAIFunction {
+    name: String,
+    description: String,
+    parameters: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OpenAIToolCall {
+    id: String,
+    #[serde(rename = "type")]
+    call_type: String,
+    function: OpenAIFunctionCall,
+}
+
+/// Function call with its arguments encoded as a JSON string
+#[derive(Debug, Serialize, Deserialize)]
+struct OpenAIFunctionCall {
+    name: String,
+    arguments: String,
+}
+
+/// Convert a tool-calling conversation to chat messages
+fn tool_messages(messages: &[ToolMessage]) -> Vec<OpenAIMessage> {
+    let mut converted = Vec::new();
+    for message in messages {
+        match message {
+            ToolMessage::User(text) => converted.push(OpenAIMessage::text("user", text.clone())),
+            ToolMessage::Assistant(turn) => converted.push(OpenAIMessage {
+                role: "assistant".to_string(),
+                content: (!turn.text.is_empty()).then(|| turn.text.clone()),
+                tool_calls: turn
+                    .tool_calls
+                    .iter()
+                    .map(|call| OpenAIToolCall {
+                        id: call.id.clone(),
+                        call_type: "function".to_string(),
+                        function: OpenAIFunctionCall {
+                            name: call.name.clone(),
+                            arguments: call.arguments.to_string(),
+                        },
+                    })
+                    .collect(),
+                tool_call_id: None,
+            }),
+            ToolMessage::ToolResults(outputs) => {
+                converted.extend(outputs.iter().map(|output| OpenAIMessage {
+                    role: "tool".to_string(),
+                    content: Some(if output.is_error {
+                        format!("Error: {}", output.content)
+                    } else {
+                        output.content.clone()
+                    }),
+                    tool_calls: Vec::new(),
+                    tool_call_id: Some(output.call_id.clone()),
+                }))
+            }
+        }
+    }
+    converted
+}
+
+/// Convert a chat message from the model into a tool turn
+fn tool_turn_from_message(message: OpenAIMessage) -> ToolTurn {
+    ToolTurn {
+        text: message.content.unwrap_or_default(),
+        tool_calls: message
+            .tool_calls
+            .into_iter()
+            .map(|call| ToolCall {
+                id: call.id,
+                name: call.function.name,
+                // Malformed arguments reach the tool, which reports them to the model
+                arguments: serde_json::from_str(&call.function.arguments)
+                    .unwrap_or(serde_json::Value::String(call.function.arguments)),
+            })
+            .collect(),
+    }
 }
 
 /// OpenAI API response structure
@@ -266,7 +391,8 @@ struct OpenAIErrorResponse {
 #[allow(dead_code)]
 struct OpenAIError {
     message: String,
-    #[serde(rename = "type")]
+    /// Not sent by Azure OpenAI, which only reports a code
+    #[serde(rename = "type", default)]
     error_type: String,
     param: Option<String>,
     code: Option<String>,
@@ -343,14 +469,66 @@ impl ProviderStats {
     }
 }
 
+/// Azure OpenAI API version used when none is configured
+pub const DEFAULT_AZURE_API_VERSION: &str = "2024-06-01";
+
+/// How requests to an Azure OpenAI resource are authenticated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AzureAuth {
+    /// Resource key sent in the `api-key` header
+    #[default]
+    ApiKey,
+    /// Microsoft Entra ID (Azure AD) access token sent as a bearer token
+    AdToken,
+}
+
+/// Azure OpenAI deployment that requests are routed to
+///
+/// The resource URL comes from the provider settings' `endpoint`, and the
+/// settings' `api_key` holds the resource key or Azure AD token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AzureDeployment {
+    /// Deployment name chosen when the model was deployed
+    pub deployment: String,
+    /// Value of the `api-version` query parameter
+    pub api_version: String,
+    pub auth: AzureAuth,
+}
+
+impl AzureDeployment {
+    pub fn new(deployment: impl Into<String>) -> Self {
+        Self {
+            deployment: deployment.into(),
+            api_version: DEFAULT_AZURE_API_VERSION.to_string(),
+            auth: AzureAuth::default(),
+        }
+    }
+
+    pub fn with_api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = api_version.into();
+        self
+    }
+
+    pub fn with_auth(mut self, auth: AzureAuth) -> Self {
+        self.auth = auth;
+        self
+    }
+}
+
 /// OpenAI provider implementation
 #[derive(Debug)]
 pub struct OpenAIProvider {
+    /// "openai", or "azure_openai" when routed to an Azure deployment
+    name: &'static str,
     client: Client,
     settings: ProviderSettings,
+    azure: Option<AzureDeployment>,
     rate_limiter: RateLimiter,
+    origin_budget: OriginBudget,
     stats: ProviderStats,
     model_costs: HashMap<String, ModelCosts>,
+    context_window: ContextWindow,
 }
 
 #[derive(Debug, Clone)]
@@ -363,22 +541,59 @@ struct ModelCosts {
 impl OpenAIProvider {
     /// Create a new OpenAI provider instance
     pub async fn new(settings: ProviderSettings) -> ProviderResult<Self> {
+        Self::build(settings, None)
+    }
+
+    /// Create a provider for an Azure OpenAI deployment
+    ///
+    /// `settings.endpoint` must be the resource URL, e.g.
+    /// `https://my-resource.openai.azure.com`, and `settings.model` the model
+    /// behind the deployment, which is used for cost estimation.
+    pub async fn azure(
+        settings: ProviderSettings,
+        deployment: AzureDeployment,
+    ) -> ProviderResult<Self> {
+        Self::build(settings, Some(deployment))
+    }
+
+    fn build(settings: ProviderSettings, azure: Option<AzureDeployment>) -> ProviderResult<Self> {
+        let name = if azure.is_some() {
+            "azure_openai"
+        } else {
+            "openai"
+        };
+        let config_error = |message: String| ProviderError::ConfigurationError {
+            provider: name.to_string(),
+            message,
+        };
+
         settings
             .validate()
-            .map_err(|e| ProviderError::ConfigurationError {
-                provider: "openai".to_string(),
-                message: format!("Configuration validation failed: {e}"),
-            })?;
+            .map_err(|e| config_error(format!("Configuration validation failed: {e}")))?;
+
+        if let Some(azure) = &azure {
+            if settings.endpoint.is_none() {
+                return Err(config_error(
+                    "Azure OpenAI requires the resource endpoint URL".to_string(),
+                ));
+            }
+            if azure.deployment.trim().is_empty() || azure.api_version.trim().is_empty() {
+                return Err(config_error(
+                    "Azure OpenAI deployment name and API version cannot be empty".to_string(),
+                ));
+            }
+        }
 
         let client = Client::builder()
             .timeout(settings.timeout)
             .build()
-            .map_err(|e| ProviderError::ConfigurationError {
-                provider: "openai".to_string(),
-                message: format!("Failed to create HTTP client: {e}"),
-            })?;
+            .map_err(|e| config_error(format!("Failed to create HTTP client: {e}")))?;
 
         let rate_limiter = RateLimiter::from_config(&settings.rate_limits);
+        let origin_budget = OriginBudget::new(
+            settings.origin_budgets.clone(),
+            settings.rate_limits.input_tokens_per_minute,
+        );
 
         let mut model_costs = HashMap::new();
         // Initialize model costs - would typically load from config
@@ -407,52 +622,93 @@ impl OpenAIProvider {
             },
         );
 
+        let context_length = model_costs
+            .get(&settings.model)
+            .map(|m| m.context_length)
+            .unwrap_or(8192);
+        let context_window = ContextWindow::for_model(
+            &settings.model,
+            context_length as u32,
+            DEFAULT_MAX_OUTPUT_TOKENS,
+        );
         Ok(Self {
+            name,
             client,
             settings,
+            azure,
             rate_limiter,
+            origin_budget,
             stats: ProviderStats::default(),
             model_costs,
+            context_window,
         })
     }
 
-    /// Estimate token count for a text string using simple heuristic
-    fn estimate_tokens(&self, text: &str) -> u32 {
-        // Rough estimation: 1 token ≈ 4 characters for English text
-        (text.len() / 4).max(1) as u32
-    }
-
     /// Get model-specific costs and constraints
     fn get_model_info(&self, model: &str) -> Option<&ModelCosts> {
         self.model_costs.get(model)
     }
 
+    /// Chat completions URL for the configured endpoint or Azure deployment
+    fn chat_completions_url(&self) -> String {
+        let base = self
+            .settings
+            .endpoint
+            .as_deref()
+            .map(|e| e.trim_end_matches('/'));
+        match (&self.azure, base) {
+            (Some(azure), Some(base)) => format!(
+                "{base}/openai/deployments/{}/chat/completions?api-version={}",
+                azure.deployment, azure.api_version
+            ),
+            (_, Some(base)) => format!("{base}/chat/completions"),
+            (_, None) => "https://api.openai.com/v1/chat/completions".to_string(),
+        }
+    }
+
+    /// Attach the credentials expected by OpenAI or the Azure resource
+    fn authorize(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match self.azure.as_ref().map(|azure| azure.auth) {
+            Some(AzureAuth::ApiKey) => request.header("api-key", &self.settings.api_key),
+            Some(AzureAuth::AdToken) | None => {
+                request.header("Authorization", format!("Bearer {}", self.settings.api_key))
+            }
+        }
+    }
+
     /// Map OpenAI API errors to ProviderError
-    fn map_openai_error(&self, error: &OpenAIError, _status_code: StatusCode) -> ProviderError {
-        match error.error_type.as_str() {
+    fn map_openai_error(&self, error: &OpenAIError, status_code: StatusCode) -> ProviderError {
+        // Azure reports only a code, so fall back to the HTTP status
+        let error_type = match (error.error_type.as_str(), status_code.as_u16()) {
+            ("", 401 | 403) => "authentication",
+            ("", 429) => "rate_limit_exceeded",
+            ("", 500..=599) => "server_error",
+            (error_type, _) => error_type,
+        };
+        match error_type {
             "authentication" => ProviderError::AuthenticationFailed {
-                provider: "openai".to_string(),
+                provider: self.name.to_string(),
                 message: error.message.clone(),
             },
             "rate_limit_exceeded" => ProviderError::RateLimitExceeded {
-                provider: "openai".to_string(),
+                provider: self.name.to_string(),
                 message: error.message.clone(),
                 retry_after: Some(Duration::from_secs(60)), // Default retry after
                 requests_remaining: Some(0),
                 tokens_remaining: Some(0),
             },
             "quota_exceeded" => ProviderError::QuotaExceeded {
-                provider: "openai".to_string(),
+                provider: self.name.to_string(),
                 message: error.message.clone(),
                 reset_time: None, // Would parse from headers if available
             },
             "server_error" | "service_unavailable" => ProviderError::ServiceUnavailable {
-                provider: "openai".to_string(),
+                provider: self.name.to_string(),
                 message: error.message.clone(),
                 estimated_recovery: Some(Duration::from_secs(30)),
             },
             _ => ProviderError::QueryFailed {
-                provider: "openai".to_string(),
+                provider: self.name.to_string(),
                 message: error.message.clone(),
                 error_code: error.code.clone(),
             },
@@ -460,13 +716,30 @@ impl OpenAIProvider {
     }
 
     /// Execute HTTP request with retry logic
-    async fn execute_request(&self, request: OpenAIRequest) -> ProviderResult<OpenAIResponse> {
+    async fn execute_request(
+        &self,
+        request: OpenAIRequest,
+        origin: RequestOrigin,
+    ) -> ProviderResult<OpenAIResponse> {
         let start_time = Instant::now();
         let mut last_error = None;
 
+        let input_tokens = request
+            .messages
+            .iter()
+            .filter_map(|message| message.content.as_deref())
+            .map(|content| self.context_window.estimate_tokens(content))
+            .sum();
+        self.origin_budget.admit(
+            self.name,
+            origin,
+            &self.context_window(),
+            input_tokens,
+            request.max_tokens.unwrap_or(0),
+        )?;
+
         for attempt in 0..=self.settings.retry.max_retries {
             // Rate limiting
-            let input_tokens = self.estimate_tokens(&request.messages[0].content);
             let estimated_output_tokens = input_tokens / 2; // Rough estimate
 
             let _guard = self
@@ -474,17 +747,8 @@ impl OpenAIProvider {
                 .acquire(input_tokens, estimated_output_tokens)
                 .await?;
 
-            let endpoint = self
-                .settings
-                .endpoint
-                .as_ref()
-                .map(|e| format!("{}/chat/completions", e.trim_end_matches('/')))
-                .unwrap_or_else(|| "https://api.openai.com/v1/chat/completions".to_string());
-
             let response = self
-                .client
-                .post(&endpoint)
-                .header("Authorization", format!("Bearer {}", self.settings.api_key))
+                .authorize(self.client.post(self.chat_completions_url()))
                 .header("Content-Type", "application/json")
                 .json(&request)
                 .send()
@@ -515,11 +779,16 @@ impl OpenAIProvider {
                                     actual_output_tokens,
                                     response_time,
                                 );
+                                self.origin_budget.record_completion(
+                                    origin,
+                                    actual_input_tokens,
+                                    actual_output_tokens,
+                                );
                                 return Ok(openai_resp);
                             }
                             Err(e) => {
                                 last_error = Some(ProviderError::SerializationError {
-                                    provider: "openai".to_string(),
+                                    provider: self.name.to_string(),
                                     message: format!("Failed to parse response: {e}"),
                                 });
                             }
@@ -539,7 +808,7 @@ impl OpenAIProvider {
                             }
                             Err(_) => {
                                 let provider_error = ProviderError::QueryFailed {
-                                    provider: "openai".to_string(),
+                                    provider: self.name.to_string(),
                                     message: format!("HTTP {} error", status.as_u16()),
                                     error_code: Some(status.as_u16().to_string()),
                                 };
@@ -553,12 +822,12 @@ impl OpenAIProvider {
                 Err(e) => {
                     let provider_error = if e.is_timeout() {
                         ProviderError::Timeout {
-                            provider: "openai".to_string(),
+                            provider: self.name.to_string(),
                             duration: self.settings.timeout,
                         }
                     } else {
                         ProviderError::NetworkError {
-                            provider: "openai".to_string(),
+                            provider: self.name.to_string(),
                             source: Box::new(e),
                         }
                     };
@@ -577,7 +846,7 @@ impl OpenAIProvider {
         }
 
         Err(last_error.unwrap_or(ProviderError::QueryFailed {
-            provider: "openai".to_string(),
+            provider: self.name.to_string(),
             message: "All retry attempts exhausted".to_string(),
             error_code: None,
         }))
@@ -587,31 +856,38 @@ impl OpenAIProvider {
 #[async_trait]
 impl Provider for OpenAIProvider {
     async fn research_query(&self, query: String) -> ProviderResult<String> {
+        self.research_query_with_origin(query, RequestOrigin::Interactive)
+            .await
+    }
+
+    async fn research_query_with_origin(
+        &self,
+        query: String,
+        origin: RequestOrigin,
+    ) -> ProviderResult<String> {
         self.validate_query(&query)?;
 
         debug!("OpenAI provider executing research query: {}", query);
 
         let request = OpenAIRequest {
             model: self.settings.model.clone(),
-            messages: vec![OpenAIMessage {
-                role: "user".to_string(),
-                content: query,
-            }],
+            messages: vec![OpenAIMessage::text("user", query)],
             temperature: Some(0.7),
             max_tokens: Some(1000),
             top_p: None,
             frequency_penalty: None,
             presence_penalty: None,
+            tools: Vec::new(),
         };
 
-        let response = self.execute_request(request).await?;
+        let response = self.execute_request(request, origin).await?;
 
         let content = response
             .choices
             .first()
-            .map(|choice| choice.message.content.clone())
+            .and_then(|choice| choice.message.content.clone())
             .ok_or_else(|| ProviderError::QueryFailed {
-                provider: "openai".to_string(),
+                provider: self.name.to_string(),
                 message: "No response content in OpenAI response".to_string(),
                 error_code: None,
             })?;
@@ -620,17 +896,67 @@ impl Provider for OpenAIProvider {
         Ok(content)
     }
 
+    fn supports_tools(&self) -> bool {
+        true
+    }
+
+    async fn tool_turn(
+        &self,
+        messages: &[ToolMessage],
+        tools: &[ToolDefinition],
+        origin: RequestOrigin,
+    ) -> ProviderResult<ToolTurn> {
+        let request = OpenAIRequest {
+            model: self.settings.model.clone(),
+            messages: tool_messages(messages),
+            temperature: Some(0.7),
+            max_tokens: Some(1000),
+            top_p: None,
+            frequency_penalty: None,
+            presence_penalty: None,
+            tools: tools
+                .iter()
+                .map(|tool| OpenAITool {
+                    tool_type: "function".to_string(),
+                    function: OpenAIFunction {
+                        name: tool.name.clone(),
+                        description: tool.description.clone(),
+                        parameters: tool.input_schema.clone(),
+                    },
+                })
+                .collect(),
+        };
+
+        let response = self.execute_request(request, origin).await?;
+        let message = response
+            .choices
+            .into_iter()
+            .next()
+            .map(|choice| choice.message)
+            .ok_or_else(|| ProviderError::QueryFailed {
+                provider: self.name.to_string(),
+                message: "No response content in OpenAI response".to_string(),
+                error_code: None,
+            })?;
+        Ok(tool_turn_from_message(message))
+    }
+
+    fn context_window(&self) -> ContextWindow {
+        self.context_window.clone()
+    }
+
     fn metadata(&self) -> ProviderMetadata {
         let model_info = self.get_model_info(&self.settings.model);
         let context_length = model_info.map(|m| m.context_length).unwrap_or(8192);
 
-        ProviderMetadata::new("openai".to_string(), "1.0.0".to_string())
+        ProviderMetadata::new(self.name.to_string(), "1.0.0".to_string())
             .with_capabilities(vec![
                 "research".to_string(),
                 "async".to_string(),
                 "rate_limited".to_string(),
                 "cost_estimation".to_string(),
                 "token_counting".to_string(),
+                "tool_calling".to_string(),
             ])
             .with_models(self.model_costs.keys().cloned().collect())
             .with_context_length(context_length)
@@ -641,8 +967,13 @@ impl Provider for OpenAIProvider {
                 output_tokens_per_minute: self.settings.rate_limits.output_tokens_per_minute,
                 max_concurrent_requests: self.settings.rate_limits.max_concurrent_requests,
             })
-            .with_attribute("provider_type".to_string(), "openai".to_string())
-            .with_attribute("api_version".to_string(), "v1".to_string())
+            .with_attribute("provider_type".to_string(), self.name.to_string())
+            .with_attribute(
+                "api_version".to_string(),
+                self.azure
+                    .as_ref()
+                    .map_or("v1".to_string(), |azure| azure.api_version.clone()),
+            )
     }
 
     async fn health_check(&self) -> ProviderResult<HealthStatus> {
@@ -651,18 +982,19 @@ impl Provider for OpenAIProvider {
         // Use a simple test request to check API availability
         let test_request = OpenAIRequest {
             model: self.settings.model.clone(),
-            messages: vec![OpenAIMessage {
-                role: "user".to_string(),
-                content: "Hello".to_string(),
-            }],
+            messages: vec![OpenAIMessage::text("user", "Hello".to_string())],
             temperature: Some(0.0),
             max_tokens: Some(1),
             top_p: None,
             frequency_penalty: None,
             presence_penalty: None,
+            tools: Vec::new(),
         };
 
-        match self.execute_request(test_request).await {
+        match self
+            .execute_request(test_request, RequestOrigin::Interactive)
+            .await
+        {
             Ok(_) => {
                 info!("OpenAI provider health check passed");
                 Ok(HealthStatus::Healthy)
@@ -692,7 +1024,7 @@ impl Provider for OpenAIProvider {
     }
 
     async fn estimate_cost(&self, query: &str) -> ProviderResult<QueryCost> {
-        let input_tokens = self.estimate_tokens(query);
+        let input_tokens = self.context_window.estimate_tokens(query);
         let estimated_output_tokens = input_tokens / 2; // Conservative estimate
 
         let model_info = self.get_model_info(&self.settings.model);
@@ -714,6 +1046,10 @@ impl Provider for OpenAIProvider {
     async fn usage_stats(&self) -> ProviderResult<UsageStats> {
         Ok(self.stats.to_usage_stats().await)
     }
+
+    async fn origin_usage(&self) -> HashMap<RequestOrigin, OriginUsage> {
+        self.origin_budget.usage()
+    }
 }
 
 #[cfg(test)]
@@ -839,14 +1175,38 @@ mod tests {
         let short_text = "Hello";
         let long_text = "This is a much longer text that should result in more estimated tokens";
 
-        let short_tokens = provider.estimate_tokens(short_text);
-        let long_tokens = provider.estimate_tokens(long_text);
+        let short_tokens = provider.context_window.estimate_tokens(short_text);
+        let long_tokens = provider.context_window.estimate_tokens(long_text);
 
         assert!(short_tokens > 0);
         assert!(long_tokens > short_tokens);
         assert_eq!(short_tokens, 1); // "Hello" has 5 chars, 5/4=1.25 -> max(1, 1) = 1
     }
 
+    #[tokio::test]
+    async fn test_oversize_prompt_is_rejected_before_sending() {
+        let provider = OpenAIProvider::new(test_settings()).await.unwrap();
+        let window = provider.context_window();
+        assert_eq!(window.context_length, 16385);
+
+        let query = "explain ownership ".repeat(16000);
+        match provider.research_query(query.clone()).await {
+            Err(ProviderError::ContextTooLarge {
+                provider,
+                prompt_tokens,
+                context_length,
+                ..
+            }) => {
+                assert_eq!(provider, "openai");
+                assert!(prompt_tokens > context_length);
+            }
+            other => panic!("expected ContextTooLarge, got {other:?}"),
+        }
+
+        let fitted = window.fit_documents("explain ownership", &[query]);
+        assert!(window.count_tokens(&fitted) <= window.prompt_budget());
+    }
+
     #[tokio::test]
     async fn test_cost_estimation() {
         let settings = test_settings();
@@ -863,6 +1223,41 @@ mod tests {
         assert!(cost.estimated_cost_usd.unwrap() > 0.0);
     }
 
+    #[test]
+    fn test_tool_call_message_conversion() {
+        let message: OpenAIMessage = serde_json::from_value(serde_json::json!({
+            "role": "assistant",
+            "content": null,
+            "tool_calls": [{
+                "id": "call_1",
+                "type": "function",
+                "function": {"name": "crate_lookup", "arguments": "{\"name\":\"serde\"}"}
+            }]
+        }))
+        .unwrap();
+        let turn = tool_turn_from_message(message);
+        assert_eq!(turn.text, "");
+        assert_eq!(turn.tool_calls[0].arguments["name"], "serde");
+
+        let messages = tool_messages(&[
+            ToolMessage::User("Which serde?".to_string()),
+            ToolMessage::Assistant(turn),
+            ToolMessage::ToolResults(vec![fortitude_core::tools::ToolOutput {
+                call_id: "call_1".to_string(),
+                content: "not found".to_string(),
+                is_error: true,
+            }]),
+        ]);
+        let json = serde_json::to_value(&messages).unwrap();
+        assert_eq!(
+            json[1]["tool_calls"][0]["function"]["arguments"],
+            "{\"name\":\"serde\"}"
+        );
+        assert_eq!(json[2]["role"], "tool");
+        assert_eq!(json[2]["tool_call_id"], "call_1");
+        assert_eq!(json[2]["content"], "Error: not found");
+    }
+
     #[tokio::test]
     async fn test_error_mapping() {
         let settings = test_settings();
@@ -1070,4 +1465,82 @@ mod tests {
         );
         assert_eq!(provider.settings.retry.backoff_multiplier, 2.0);
     }
+
+    #[tokio::test]
+    async fn test_azure_deployment_url_auth_and_errors() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 8192];
+            let n = socket.read(&mut buf).await.unwrap();
+            let body = r#"{"id":"chatcmpl-1","object":"chat.completion","created":1,"model":"gpt-4","choices":[{"index":0,"message":{"role":"assistant","content":"Azure answer"},"finish_reason":"stop"}]}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_string()
+        });
+
+        let settings = test_settings().with_endpoint(format!("{endpoint}/"));
+        let provider = OpenAIProvider::azure(
+            settings,
+            AzureDeployment::new("research").with_api_version("2024-10-21"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(provider.metadata().name(), "azure_openai");
+
+        let answer = provider
+            .research_query("What is Azure?".to_string())
+            .await
+            .unwrap();
+        assert_eq!(answer, "Azure answer");
+
+        let request = server.await.unwrap().to_lowercase();
+        assert!(request.starts_with(
+            "post /openai/deployments/research/chat/completions?api-version=2024-10-21 "
+        ));
+        assert!(request.contains("api-key: test-api-key"));
+        assert!(!request.contains("authorization:"));
+
+        // Azure errors carry only a code
+        let error: OpenAIErrorResponse = serde_json::from_str(
+            r#"{"error":{"code":"401","message":"Access denied due to invalid subscription key"}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            provider.map_openai_error(&error.error, StatusCode::UNAUTHORIZED),
+            ProviderError::AuthenticationFailed { provider, .. } if provider == "azure_openai"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_azure_ad_token_and_required_endpoint() {
+        let provider = OpenAIProvider::azure(
+            test_settings().with_endpoint("https://example.openai.azure.com".to_string()),
+            AzureDeployment::new("research").with_auth(AzureAuth::AdToken),
+        )
+        .await
+        .unwrap();
+        let request = provider
+            .authorize(provider.client.post(provider.chat_completions_url()))
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.url().as_str(),
+            format!(
+                "https://example.openai.azure.com/openai/deployments/research/chat/completions?api-version={DEFAULT_AZURE_API_VERSION}"
+            )
+        );
+        assert_eq!(request.headers()["authorization"], "Bearer test-api-key");
+
+        assert!(matches!(
+            OpenAIProvider::azure(test_settings(), AzureDeployment::new("research")).await,
+            Err(ProviderError::ConfigurationError { .. })
+        ));
+    }
 }
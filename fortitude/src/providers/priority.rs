@@ -0,0 +1,380 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Request origin tagging and per-origin rate limit budget splits
+//! Interactive, batch and proactive requests share each provider's token budget.
+//! Every provider request is tagged with a [`RequestOrigin`], and an
+//! [`OriginBudget`] caps the tokens each origin may consume per minute so that
+//! background research cannot starve interactive users.
+//!
+//! # Example Usage
+//!
+//! ```rust
+//! use fortitude::providers::priority::{OriginBudget, OriginBudgetConfig, RequestOrigin};
+//!
+//! // Proactive research may use at most 30% of 10,000 tokens per minute
+//! let budget = OriginBudget::new(OriginBudgetConfig::default(), 10_000);
+//! assert!(budget.try_acquire(RequestOrigin::Proactive, 2_500).is_ok());
+//! assert!(budget.try_acquire(RequestOrigin::Proactive, 1_000).is_err());
+//! assert!(budget.try_acquire(RequestOrigin::Interactive, 5_000).is_ok());
+//! ```
+
+use super::config::{ConfigError, ConfigResult};
+use super::context::ContextWindow;
+use super::{ProviderError, ProviderResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Length of the sliding window budgets are measured over
+const BUDGET_WINDOW: Duration = Duration::from_secs(60);
+
+/// Where a provider request came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestOrigin {
+    /// A user waiting on the answer (CLI, API, MCP)
+    #[default]
+    Interactive,
+    /// Bulk or scripted work submitted by a user
+    Batch,
+    /// Background research started by the proactive system
+    Proactive,
+}
+
+impl RequestOrigin {
+    /// All origins, highest priority first
+    pub fn all() -> [RequestOrigin; 3] {
+        [
+            RequestOrigin::Interactive,
+            RequestOrigin::Batch,
+            RequestOrigin::Proactive,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RequestOrigin::Interactive => "interactive",
+            RequestOrigin::Batch => "batch",
+            RequestOrigin::Proactive => "proactive",
+        }
+    }
+}
+
+impl std::fmt::Display for RequestOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Share of a provider's input tokens per minute each origin may consume
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OriginBudgetConfig {
+    pub interactive_share: f64,
+    pub batch_share: f64,
+    pub proactive_share: f64,
+}
+
+impl Default for OriginBudgetConfig {
+    fn default() -> Self {
+        Self {
+            interactive_share: 1.0,
+            batch_share: 0.6,
+            proactive_share: 0.3,
+        }
+    }
+}
+
+impl OriginBudgetConfig {
+    /// Configuration that lets every origin use the full budget
+    pub fn unrestricted() -> Self {
+        Self {
+            interactive_share: 1.0,
+            batch_share: 1.0,
+            proactive_share: 1.0,
+        }
+    }
+
+    pub fn share(&self, origin: RequestOrigin) -> f64 {
+        match origin {
+            RequestOrigin::Interactive => self.interactive_share,
+            RequestOrigin::Batch => self.batch_share,
+            RequestOrigin::Proactive => self.proactive_share,
+        }
+    }
+
+    pub fn validate(&self) -> ConfigResult<()> {
+        for origin in RequestOrigin::all() {
+            let share = self.share(origin);
+            if !(0.0..=1.0).contains(&share) {
+                return Err(ConfigError::Conflict(format!(
+                    "Budget share for {origin} requests must be between 0.0 and 1.0, got {share}"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Consumption recorded for one origin
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OriginUsage {
+    /// Requests admitted by the budget
+    pub requests: u64,
+    /// Requests rejected because the origin exhausted its share
+    pub throttled_requests: u64,
+    /// Input tokens reported by completed requests
+    pub input_tokens: u64,
+    /// Output tokens reported by completed requests
+    pub output_tokens: u64,
+    /// Tokens charged against the budget in the current window
+    pub window_tokens: u64,
+}
+
+#[derive(Debug, Default)]
+struct OriginBudgetState {
+    windows: HashMap<RequestOrigin, VecDeque<(Instant, u32)>>,
+    usage: HashMap<RequestOrigin, OriginUsage>,
+}
+
+impl OriginBudgetState {
+    fn expire(&mut self, now: Instant) {
+        for window in self.windows.values_mut() {
+            while let Some(&(at, _)) = window.front() {
+                if now.duration_since(at) < BUDGET_WINDOW {
+                    break;
+                }
+                window.pop_front();
+            }
+        }
+    }
+
+    fn window_tokens(&self, origin: RequestOrigin) -> u64 {
+        self.windows
+            .get(&origin)
+            .map(|window| window.iter().map(|&(_, tokens)| tokens as u64).sum())
+            .unwrap_or(0)
+    }
+}
+
+/// Per-origin token budget over a sliding one-minute window
+#[derive(Debug)]
+pub struct OriginBudget {
+    config: OriginBudgetConfig,
+    tokens_per_minute: u32,
+    state: Mutex<OriginBudgetState>,
+}
+
+impl OriginBudget {
+    pub fn new(config: OriginBudgetConfig, tokens_per_minute: u32) -> Self {
+        Self {
+            config,
+            tokens_per_minute,
+            state: Mutex::new(OriginBudgetState::default()),
+        }
+    }
+
+    pub fn config(&self) -> &OriginBudgetConfig {
+        &self.config
+    }
+
+    /// Tokens per minute available to `origin`
+    pub fn limit(&self, origin: RequestOrigin) -> u64 {
+        (self.tokens_per_minute as f64 * self.config.share(origin)).floor() as u64
+    }
+
+    /// Charge `tokens` to `origin`, or return how long until they would fit
+    pub fn try_acquire(&self, origin: RequestOrigin, tokens: u32) -> Result<(), Duration> {
+        let now = Instant::now();
+        let limit = self.limit(origin);
+        let mut state = self.state.lock().unwrap();
+        state.expire(now);
+
+        let used = state.window_tokens(origin);
+        if used + tokens as u64 <= limit {
+            state
+                .windows
+                .entry(origin)
+                .or_default()
+                .push_back((now, tokens));
+            state.usage.entry(origin).or_default().requests += 1;
+            return Ok(());
+        }
+
+        state.usage.entry(origin).or_default().throttled_requests += 1;
+
+        // Wait until enough of the oldest charges leave the window
+        let mut excess = (used + tokens as u64).saturating_sub(limit);
+        let window = state.windows.get(&origin);
+        for &(at, charged) in window.into_iter().flatten() {
+            excess = excess.saturating_sub(charged as u64);
+            if excess == 0 {
+                return Err((at + BUDGET_WINDOW).saturating_duration_since(now));
+            }
+        }
+        // The request is larger than the origin's whole share
+        Err(BUDGET_WINDOW)
+    }
+
+    /// Charge `tokens` to `origin` before a request to `provider`
+    ///
+    /// An exhausted share is reported as a rate limit.
+    pub fn acquire(
+        &self,
+        provider: &str,
+        origin: RequestOrigin,
+        tokens: u32,
+    ) -> ProviderResult<()> {
+        self.try_acquire(origin, tokens)
+            .map_err(|wait_time| ProviderError::RateLimitExceeded {
+                provider: provider.to_string(),
+                message: format!("Token budget for {origin} requests exceeded"),
+                retry_after: Some(wait_time),
+                requests_remaining: None,
+                tokens_remaining: Some(0),
+            })
+    }
+
+    /// Admit a request to `provider` before its retry loop
+    ///
+    /// The prompt must fit `window` with room for the requested output. It is
+    /// then charged to the origin's share once for the whole request, ahead of
+    /// the provider's shared rate limiter, so each origin stays within its
+    /// share and retries are neither charged again nor cut short by the budget.
+    pub fn admit(
+        &self,
+        provider: &str,
+        origin: RequestOrigin,
+        window: &ContextWindow,
+        input_tokens: u32,
+        max_output_tokens: u32,
+    ) -> ProviderResult<()> {
+        window.check(provider, input_tokens, max_output_tokens)?;
+        self.acquire(provider, origin, input_tokens)
+    }
+
+    /// Record the tokens a completed request actually used
+    pub fn record_completion(&self, origin: RequestOrigin, input_tokens: u32, output_tokens: u32) {
+        let mut state = self.state.lock().unwrap();
+        let usage = state.usage.entry(origin).or_default();
+        usage.input_tokens += input_tokens as u64;
+        usage.output_tokens += output_tokens as u64;
+    }
+
+    /// Consumption per origin, including tokens charged in the current window
+    pub fn usage(&self) -> HashMap<RequestOrigin, OriginUsage> {
+        let mut state = self.state.lock().unwrap();
+        state.expire(Instant::now());
+
+        let mut usage = state.usage.clone();
+        for (origin, entry) in usage.iter_mut() {
+            entry.window_tokens = state.window_tokens(*origin);
+        }
+        usage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proactive_share_is_capped() {
+        let budget = OriginBudget::new(OriginBudgetConfig::default(), 1_000);
+
+        assert_eq!(budget.limit(RequestOrigin::Proactive), 300);
+        assert!(budget.try_acquire(RequestOrigin::Proactive, 200).is_ok());
+        assert!(budget.try_acquire(RequestOrigin::Proactive, 100).is_ok());
+
+        let retry_after = budget
+            .try_acquire(RequestOrigin::Proactive, 50)
+            .unwrap_err();
+        assert!(retry_after > Duration::ZERO && retry_after <= BUDGET_WINDOW);
+
+        // Interactive traffic keeps its own, larger share
+        assert!(budget.try_acquire(RequestOrigin::Interactive, 900).is_ok());
+
+        match budget.acquire("claude", RequestOrigin::Proactive, 50) {
+            Err(ProviderError::RateLimitExceeded {
+                provider,
+                retry_after: Some(_),
+                ..
+            }) => assert_eq!(provider, "claude"),
+            other => panic!("expected a rate limit error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_request_larger_than_share_waits_full_window() {
+        let budget = OriginBudget::new(OriginBudgetConfig::default(), 1_000);
+        assert_eq!(
+            budget.try_acquire(RequestOrigin::Proactive, 500),
+            Err(BUDGET_WINDOW)
+        );
+    }
+
+    #[test]
+    fn test_usage_reports_per_origin_consumption() {
+        let budget = OriginBudget::new(OriginBudgetConfig::default(), 1_000);
+        budget.try_acquire(RequestOrigin::Batch, 400).unwrap();
+        budget.record_completion(RequestOrigin::Batch, 380, 120);
+        let _ = budget.try_acquire(RequestOrigin::Batch, 400);
+
+        let usage = budget.usage();
+        let batch = &usage[&RequestOrigin::Batch];
+        assert_eq!(batch.requests, 1);
+        assert_eq!(batch.throttled_requests, 1);
+        assert_eq!(batch.input_tokens, 380);
+        assert_eq!(batch.output_tokens, 120);
+        assert_eq!(batch.window_tokens, 400);
+        assert!(!usage.contains_key(&RequestOrigin::Interactive));
+    }
+
+    #[test]
+    fn test_admit_checks_context_before_charging_once() {
+        let budget = OriginBudget::new(OriginBudgetConfig::default(), 1_000);
+        let window = ContextWindow::new(500, 100);
+
+        match budget.admit("claude", RequestOrigin::Batch, &window, 450, 100) {
+            Err(ProviderError::ContextTooLarge { .. }) => {}
+            other => panic!("expected a context error, got {other:?}"),
+        }
+        budget
+            .admit("claude", RequestOrigin::Batch, &window, 300, 100)
+            .unwrap();
+
+        let batch = &budget.usage()[&RequestOrigin::Batch];
+        assert_eq!(batch.requests, 1);
+        assert_eq!(batch.window_tokens, 300);
+    }
+
+    #[test]
+    fn test_config_validation_and_serialization() {
+        assert!(OriginBudgetConfig::default().validate().is_ok());
+        assert!(OriginBudgetConfig::unrestricted().validate().is_ok());
+
+        let invalid = OriginBudgetConfig {
+            proactive_share: 1.5,
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
+
+        assert_eq!(
+            serde_json::to_string(&RequestOrigin::Proactive).unwrap(),
+            "\"proactive\""
+        );
+        assert_eq!(RequestOrigin::default(), RequestOrigin::Interactive);
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Persisted primary provider selection made with `fortitude provider switch`
+// Stored as a small JSON file in the user config directory that is read back
+// when the provider manager is set up
+use chrono::{DateTime, Utc};
+use fortitude_core::write_json_atomic;
+use serde::{Deserialize, Serialize};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Selection location when no user config directory can be found
+const FALLBACK_SELECTION_PATH: &str = ".fortitude/provider.json";
+
+/// Errors reading or writing the provider selection
+#[derive(Error, Debug)]
+pub enum SelectionStoreError {
+    #[error("Failed to access provider selection at {path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Invalid provider selection at {path}: {source}")]
+    Parse {
+        path: String,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// Primary provider chosen by the user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProviderSelection {
+    /// Provider name (openai, claude, gemini)
+    pub provider: String,
+    pub selected_at: DateTime<Utc>,
+    /// Whether the health check was skipped when switching
+    #[serde(default)]
+    pub forced: bool,
+}
+
+impl ProviderSelection {
+    pub fn new(provider: impl Into<String>) -> Self {
+        Self {
+            provider: provider.into(),
+            selected_at: Utc::now(),
+            forced: false,
+        }
+    }
+
+    pub fn with_forced(mut self, forced: bool) -> Self {
+        self.forced = forced;
+        self
+    }
+
+    /// `fortitude/provider.json` under the user config directory
+    /// (`$XDG_CONFIG_HOME`, else `~/.config`), so the selection holds whatever
+    /// directory fortitude runs from
+    pub fn default_path() -> PathBuf {
+        selection_path(
+            std::env::var_os("XDG_CONFIG_HOME"),
+            std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE")),
+        )
+    }
+
+    /// Read the selection, `None` when no provider has been selected
+    pub fn load(path: &Path) -> Result<Option<Self>, SelectionStoreError> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => {
+                return Err(SelectionStoreError::Io {
+                    path: path.display().to_string(),
+                    source,
+                })
+            }
+        };
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|source| SelectionStoreError::Parse {
+                path: path.display().to_string(),
+                source,
+            })
+    }
+
+    /// Write the selection, creating the parent directory when needed
+    pub fn save(&self, path: &Path) -> Result<(), SelectionStoreError> {
+        write_json_atomic(path, self).map_err(|source| SelectionStoreError::Io {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+}
+
+fn selection_path(xdg_config_home: Option<OsString>, home: Option<OsString>) -> PathBuf {
+    // Relative XDG_CONFIG_HOME values are invalid and ignored
+    let config_dir = xdg_config_home
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| {
+            home.filter(|home| !home.is_empty())
+                .map(|home| PathBuf::from(home).join(".config"))
+        });
+    match config_dir {
+        Some(dir) => dir.join("fortitude").join("provider.json"),
+        None => PathBuf::from(FALLBACK_SELECTION_PATH),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_selection_round_trips_and_missing_is_none() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("nested").join("provider.json");
+        assert_eq!(ProviderSelection::load(&path).unwrap(), None);
+
+        let selection = ProviderSelection::new("claude").with_forced(true);
+        selection.save(&path).unwrap();
+        assert_eq!(ProviderSelection::load(&path).unwrap(), Some(selection));
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(matches!(
+            ProviderSelection::load(&path),
+            Err(SelectionStoreError::Parse { .. })
+        ));
+    }
+
+    #[test]
+    fn test_default_path_is_under_user_config_dir() {
+        assert_eq!(
+            selection_path(Some("/xdg".into()), Some("/home/dev".into())),
+            Path::new("/xdg/fortitude/provider.json")
+        );
+        assert_eq!(
+            selection_path(Some("relative".into()), Some("/home/dev".into())),
+            Path::new("/home/dev/.config/fortitude/provider.json")
+        );
+        assert_eq!(
+            selection_path(None, None),
+            Path::new(FALLBACK_SELECTION_PATH)
+        );
+
+        // A selection saved from one directory is read back from the config dir
+        let config_home = tempdir().unwrap();
+        let path = selection_path(Some(config_home.path().into()), None);
+        let selection = ProviderSelection::new("gemini");
+        selection.save(&path).unwrap();
+        assert_eq!(
+            ProviderSelection::load(&config_home.path().join("fortitude").join("provider.json"))
+                .unwrap(),
+            Some(selection)
+        );
+    }
+}
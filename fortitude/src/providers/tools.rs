@@ -0,0 +1,189 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Tool-call loop shared by providers that support tool calling
+//! Drives a conversation in which the model may call registered tools
+//! before giving its final answer. Providers only translate single turns
+//! to and from their wire format through [`Provider::tool_turn`].
+
+use super::{Provider, ProviderError, ProviderResult, RequestOrigin};
+use fortitude_core::tools::{ToolMessage, ToolRegistry};
+use tracing::{debug, info};
+
+/// Run `query` as a tool-calling conversation until the model answers
+///
+/// Each round executes every requested call and sends the outputs back.
+/// The loop fails once the model is still calling tools after
+/// `tools.max_rounds()` rounds.
+pub async fn run_tool_loop<P: Provider + ?Sized>(
+    provider: &P,
+    query: String,
+    origin: RequestOrigin,
+    tools: &ToolRegistry,
+) -> ProviderResult<String> {
+    provider.validate_query(&query)?;
+
+    let definitions = tools.definitions();
+    let mut messages = vec![ToolMessage::User(query)];
+    let mut calls_made = 0;
+
+    for round in 0..=tools.max_rounds() {
+        let turn = provider.tool_turn(&messages, &definitions, origin).await?;
+        if turn.tool_calls.is_empty() {
+            if calls_made > 0 {
+                info!(
+                    "{} answered after {} tool calls in {} rounds",
+                    provider.metadata().name(),
+                    calls_made,
+                    round
+                );
+            }
+            return Ok(turn.text);
+        }
+        if round == tools.max_rounds() {
+            break;
+        }
+
+        let mut outputs = Vec::with_capacity(turn.tool_calls.len());
+        for call in &turn.tool_calls {
+            debug!("Round {}: calling tool {}", round + 1, call.name);
+            outputs.push(tools.execute(call).await);
+        }
+        calls_made += outputs.len();
+        messages.push(ToolMessage::Assistant(turn));
+        messages.push(ToolMessage::ToolResults(outputs));
+    }
+
+    Err(ProviderError::QueryFailed {
+        message: format!(
+            "Model was still calling tools after {} rounds",
+            tools.max_rounds()
+        ),
+        provider: provider.metadata().name().to_string(),
+        error_code: Some("TOOL_ROUNDS_EXCEEDED".to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::{HealthStatus, ProviderMetadata};
+    use async_trait::async_trait;
+    use fortitude_core::tools::{
+        ResearchTool, ToolCall, ToolDefinition, ToolError, ToolOutput, ToolTurn,
+    };
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+
+    struct AddTool;
+
+    #[async_trait]
+    impl ResearchTool for AddTool {
+        fn definition(&self) -> ToolDefinition {
+            ToolDefinition {
+                name: "add".to_string(),
+                description: "Add two numbers".to_string(),
+                input_schema: json!({"type": "object"}),
+            }
+        }
+
+        async fn call(&self, arguments: Value) -> Result<String, ToolError> {
+            let a = arguments["a"].as_i64().unwrap_or(0);
+            let b = arguments["b"].as_i64().unwrap_or(0);
+            Ok((a + b).to_string())
+        }
+    }
+
+    /// Calls `add` until it has seen a tool output, then answers with it
+    struct ScriptedProvider {
+        always_call: bool,
+    }
+
+    #[async_trait]
+    impl Provider for ScriptedProvider {
+        async fn research_query(&self, _query: String) -> ProviderResult<String> {
+            Ok("plain answer".to_string())
+        }
+
+        fn supports_tools(&self) -> bool {
+            true
+        }
+
+        async fn tool_turn(
+            &self,
+            messages: &[ToolMessage],
+            tools: &[ToolDefinition],
+            _origin: RequestOrigin,
+        ) -> ProviderResult<ToolTurn> {
+            assert_eq!(tools[0].name, "add");
+            let output = messages.iter().rev().find_map(|message| match message {
+                ToolMessage::ToolResults(outputs) => outputs.first().cloned(),
+                _ => None,
+            });
+            match output {
+                Some(ToolOutput { content, .. }) if !self.always_call => Ok(ToolTurn {
+                    text: format!("The sum is {content}"),
+                    tool_calls: vec![],
+                }),
+                _ => Ok(ToolTurn {
+                    text: String::new(),
+                    tool_calls: vec![ToolCall {
+                        id: format!("call-{}", messages.len()),
+                        name: "add".to_string(),
+                        arguments: json!({"a": 2, "b": 3}),
+                    }],
+                }),
+            }
+        }
+
+        fn metadata(&self) -> ProviderMetadata {
+            ProviderMetadata::new("scripted".to_string(), "1.0.0".to_string())
+        }
+
+        async fn health_check(&self) -> ProviderResult<HealthStatus> {
+            Ok(HealthStatus::Healthy)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tool_loop_answers_after_tool_results() {
+        let tools = ToolRegistry::new()
+            .with_tool(Arc::new(AddTool))
+            .with_max_rounds(2);
+        let provider = ScriptedProvider { always_call: false };
+
+        let answer = provider
+            .research_query_with_tools("2 + 3?".to_string(), RequestOrigin::Interactive, &tools)
+            .await
+            .unwrap();
+        assert_eq!(answer, "The sum is 5");
+
+        let plain = provider
+            .research_query_with_tools(
+                "2 + 3?".to_string(),
+                RequestOrigin::Interactive,
+                &ToolRegistry::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(plain, "plain answer");
+
+        let looping = ScriptedProvider { always_call: true };
+        let error = looping
+            .research_query_with_tools("2 + 3?".to_string(), RequestOrigin::Interactive, &tools)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("after 2 rounds"));
+    }
+}
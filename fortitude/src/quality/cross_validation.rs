@@ -59,15 +59,19 @@
 //! }
 //! ```
 
-use crate::providers::ProviderManager;
+use crate::providers::{Provider, ProviderManager};
 use crate::quality::{QualityScore, QualityScorer};
 use async_trait::async_trait;
+use fortitude_core::vector::EmbeddingGenerator;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use thiserror::Error;
-use tracing::info;
+use tracing::{debug, info, warn};
+
+/// How far below the mean agreement a provider must fall to count as an outlier
+const OUTLIER_MARGIN: f64 = 0.15;
 
 /// Configuration for cross-provider validation
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -213,6 +217,9 @@ pub struct ValidationResult {
     pub bias_analysis: Option<BiasAnalysis>,
     /// Performance metrics for the validation process
     pub validation_metrics: ValidationMetrics,
+    /// Where and how strongly the providers disagree
+    #[serde(default)]
+    pub disagreement: DisagreementReport,
 }
 
 /// Individual provider response with quality assessment
@@ -247,6 +254,88 @@ pub struct ConsistencyAnalysis {
     pub conflicts: Vec<ConsistencyConflict>,
 }
 
+/// Summary of provider disagreement for a validation run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DisagreementReport {
+    /// Mean pairwise semantic similarity across responses (0.0-1.0)
+    pub agreement: f64,
+    /// Each provider's mean semantic similarity to the other responses
+    pub provider_agreement: HashMap<String, f64>,
+    /// Whether overall consistency reached the configured threshold
+    pub meets_threshold: bool,
+    /// Providers whose answers diverge from the rest (needs 3+ responses)
+    pub outliers: Vec<String>,
+    /// Conflicts detected between response pairs
+    pub conflicts: Vec<ConsistencyConflict>,
+}
+
+impl DisagreementReport {
+    /// Build the report from a consistency analysis of `providers`
+    pub fn from_analysis(
+        analysis: &ConsistencyAnalysis,
+        providers: &[String],
+        consistency_threshold: f64,
+    ) -> Self {
+        let agreement = if analysis.semantic_similarities.is_empty() {
+            1.0
+        } else {
+            analysis.semantic_similarities.values().sum::<f64>()
+                / analysis.semantic_similarities.len() as f64
+        };
+
+        let provider_agreement: HashMap<String, f64> = providers
+            .iter()
+            .map(|provider| {
+                let scores: Vec<f64> = providers
+                    .iter()
+                    .filter(|other| *other != provider)
+                    .filter_map(|other| {
+                        analysis
+                            .semantic_similarities
+                            .get(&format!("{provider}_{other}"))
+                            .or_else(|| {
+                                analysis
+                                    .semantic_similarities
+                                    .get(&format!("{other}_{provider}"))
+                            })
+                            .copied()
+                    })
+                    .collect();
+                let mean = if scores.is_empty() {
+                    1.0
+                } else {
+                    scores.iter().sum::<f64>() / scores.len() as f64
+                };
+                (provider.clone(), mean)
+            })
+            .collect();
+
+        let mut outliers: Vec<String> = if providers.len() >= 3 {
+            provider_agreement
+                .iter()
+                .filter(|(_, score)| **score < agreement - OUTLIER_MARGIN)
+                .map(|(provider, _)| provider.clone())
+                .collect()
+        } else {
+            Vec::new()
+        };
+        outliers.sort();
+
+        Self {
+            agreement,
+            provider_agreement,
+            meets_threshold: analysis.overall_consistency >= consistency_threshold,
+            outliers,
+            conflicts: analysis.conflicts.clone(),
+        }
+    }
+
+    /// Whether the providers disagreed in any way worth reporting
+    pub fn has_disagreement(&self) -> bool {
+        !self.meets_threshold || !self.outliers.is_empty() || !self.conflicts.is_empty()
+    }
+}
+
 /// Detected conflict between provider responses
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConsistencyConflict {
@@ -600,196 +689,281 @@ impl CrossValidationEngine {
         Ok(validation_result)
     }
 
-    /// Execute parallel validation strategy
-    async fn execute_parallel_validation(
-        &self,
-        query: &str,
-    ) -> CrossValidationResult<ValidationResult> {
-        // Get healthy providers for parallel execution
-        let healthy_providers = self.provider_manager.get_healthy_providers().await;
+    /// Use `generator` to compare responses by embedding similarity
+    pub fn with_embedding_generator(mut self, generator: Arc<dyn EmbeddingGenerator>) -> Self {
+        self.consistency_analyzer =
+            Arc::new(ComprehensiveConsistencyAnalyzer::new().with_embedding_generator(generator));
+        self
+    }
 
-        if healthy_providers.is_empty() {
+    /// Healthy providers to consult, capped at `max_providers`
+    async fn candidate_providers(&self) -> CrossValidationResult<Vec<(String, Arc<dyn Provider>)>> {
+        let mut providers = self.provider_manager.get_healthy_providers().await;
+        if providers.is_empty() {
             return Err(CrossValidationError::InsufficientProviders {
                 min_required: self.config.min_providers,
                 available: 0,
             });
         }
+        providers.sort_by(|a, b| a.0.cmp(&b.0));
+        providers.truncate(self.config.max_providers);
+        Ok(providers)
+    }
 
-        // Limit to max_providers
-        let providers_to_use = std::cmp::min(healthy_providers.len(), self.config.max_providers);
-        let selected_providers = &healthy_providers[0..providers_to_use];
-
-        // Execute queries in parallel across all selected providers
+    /// Query `providers` concurrently and score every successful answer
+    ///
+    /// Failed, timed-out and unscoreable answers are logged and left out.
+    async fn query_providers(
+        &self,
+        query: &str,
+        providers: &[(String, Arc<dyn Provider>)],
+    ) -> HashMap<String, ProviderResponse> {
         let mut tasks = Vec::new();
 
-        for (provider_name, provider) in selected_providers {
-            let provider_clone = provider.clone();
-            let query_clone = query.to_string();
-            let provider_name_clone = provider_name.clone();
+        for (provider_name, provider) in providers {
+            let provider = provider.clone();
+            let query = query.to_string();
+            let provider_name = provider_name.clone();
             let timeout = self.config.timeout;
 
-            let task = tokio::spawn(async move {
-                let start_time = std::time::Instant::now();
-
-                // Execute query with timeout
-                let result = tokio::time::timeout(
-                    timeout,
-                    provider_clone.research_query(query_clone.clone()),
-                )
-                .await;
-
+            tasks.push(tokio::spawn(async move {
+                let start_time = Instant::now();
+                let result = tokio::time::timeout(timeout, provider.research_query(query)).await;
                 let response_time = start_time.elapsed();
 
                 match result {
-                    Ok(Ok(response)) => {
-                        // Return successful response with timing info
-                        Ok((provider_name_clone, response, response_time))
-                    }
-                    Ok(Err(provider_error)) => {
-                        // Provider returned an error
-                        Err(CrossValidationError::ProviderQueryFailed {
-                            provider: provider_name_clone,
-                            error: provider_error.to_string(),
-                        })
-                    }
-                    Err(_) => {
-                        // Timeout occurred
-                        Err(CrossValidationError::ProviderQueryFailed {
-                            provider: provider_name_clone,
-                            error: format!("Query timeout after {timeout:?}"),
-                        })
-                    }
+                    Ok(Ok(response)) => Ok((provider_name, response, response_time)),
+                    Ok(Err(provider_error)) => Err(CrossValidationError::ProviderQueryFailed {
+                        provider: provider_name,
+                        error: provider_error.to_string(),
+                    }),
+                    Err(_) => Err(CrossValidationError::ProviderQueryFailed {
+                        provider: provider_name,
+                        error: format!("Query timeout after {timeout:?}"),
+                    }),
                 }
-            });
-
-            tasks.push(task);
+            }));
         }
 
-        // Wait for all tasks to complete
-        let mut results = Vec::new();
-        for task in tasks {
-            results.push(task.await);
-        }
-
-        // Process results and collect successful responses
+        let weights = crate::quality::QualityWeights::research_optimized();
         let mut provider_responses = HashMap::new();
-        let mut errors = Vec::new();
-
-        for task_result in results {
-            match task_result {
-                Ok(query_result) => {
-                    match query_result {
-                        Ok((provider_name, response, response_time)) => {
-                            // Evaluate quality of the response
-                            let weights = crate::quality::QualityWeights::research_optimized();
-                            match self
-                                .quality_scorer
-                                .evaluate_quality(query, &response, &weights)
-                                .await
-                            {
-                                Ok(quality_score) => {
-                                    provider_responses.insert(
-                                        provider_name.clone(),
-                                        ProviderResponse {
-                                            provider: provider_name,
-                                            response: response.to_string(),
-                                            quality_score,
-                                            response_time,
-                                            metadata: HashMap::new(),
-                                        },
-                                    );
-                                }
-                                Err(quality_error) => {
-                                    errors.push(format!(
-                                        "Quality evaluation failed: {quality_error}"
-                                    ));
-                                }
-                            }
-                        }
-                        Err(validation_error) => {
-                            errors.push(validation_error.to_string());
-                        }
-                    }
+
+        for task in tasks {
+            let (provider_name, response, response_time) = match task.await {
+                Ok(Ok(answer)) => answer,
+                Ok(Err(query_error)) => {
+                    warn!("Cross-validation query failed: {}", query_error);
+                    continue;
                 }
                 Err(join_error) => {
-                    errors.push(format!("Task execution failed: {join_error}"));
+                    warn!("Cross-validation task failed: {}", join_error);
+                    continue;
+                }
+            };
+
+            match self
+                .quality_scorer
+                .evaluate_quality(query, &response, &weights)
+                .await
+            {
+                Ok(quality_score) => {
+                    provider_responses.insert(
+                        provider_name.clone(),
+                        ProviderResponse {
+                            provider: provider_name,
+                            response,
+                            quality_score,
+                            response_time,
+                            metadata: HashMap::new(),
+                        },
+                    );
+                }
+                Err(quality_error) => {
+                    warn!(
+                        "Quality evaluation failed for {}: {}",
+                        provider_name, quality_error
+                    );
                 }
             }
         }
 
-        // Check if we have enough successful responses
-        if provider_responses.len() < self.config.min_providers {
+        provider_responses
+    }
+
+    /// Ensure enough providers answered to compare them
+    fn require_min_responses(
+        &self,
+        responses: &HashMap<String, ProviderResponse>,
+    ) -> CrossValidationResult<()> {
+        if responses.len() < self.config.min_providers {
             return Err(CrossValidationError::InsufficientProviders {
                 min_required: self.config.min_providers,
-                available: provider_responses.len(),
+                available: responses.len(),
             });
         }
+        Ok(())
+    }
 
-        // Perform consistency analysis
+    /// Analyze agreement, generate the consensus and assemble the result
+    async fn build_result(
+        &self,
+        query: &str,
+        provider_responses: HashMap<String, ProviderResponse>,
+        consensus_generator: &dyn ConsensusGenerator,
+        start_time: Instant,
+    ) -> CrossValidationResult<ValidationResult> {
         let consistency_analysis = self
             .consistency_analyzer
             .analyze_consistency(&provider_responses, query)
             .await?;
 
-        // Generate consensus
-        let (consensus_result, confidence_score) = self
-            .consensus_generator
+        let consensus_start = Instant::now();
+        let (consensus_result, confidence_score) = consensus_generator
             .generate_consensus(&provider_responses, query)
             .await?;
-
-        // Check if consensus meets consistency threshold
-        let consistency_score = consistency_analysis.overall_consistency;
-        if consistency_score < self.config.consistency_threshold {
-            return Err(CrossValidationError::ConsistencyThresholdNotMet {
-                required: self.config.consistency_threshold,
-                actual: consistency_score,
-            });
+        let consensus_time = consensus_start.elapsed();
+
+        let mut providers: Vec<String> = provider_responses.keys().cloned().collect();
+        providers.sort();
+        let disagreement = DisagreementReport::from_analysis(
+            &consistency_analysis,
+            &providers,
+            self.config.consistency_threshold,
+        );
+        if disagreement.has_disagreement() {
+            debug!(
+                "Providers disagree: agreement {:.2}, outliers {:?}, {} conflicts",
+                disagreement.agreement,
+                disagreement.outliers,
+                disagreement.conflicts.len()
+            );
         }
 
-        // Create validation result
+        let provider_times = provider_responses
+            .iter()
+            .map(|(name, response)| (name.clone(), response.response_time))
+            .collect();
+
         Ok(ValidationResult {
             consensus_result,
             confidence_score,
-            consensus_strength: confidence_score, // Use confidence_score as consensus_strength
-            consistency_analysis,
-            provider_responses: provider_responses.clone(),
-            bias_analysis: None, // TODO: Implement bias analysis
+            consensus_strength: consistency_analysis.overall_consistency,
             validation_metrics: ValidationMetrics {
-                total_time: std::time::Duration::from_millis(0), // Would be calculated properly
-                provider_times: HashMap::new(), // Would track individual provider times
+                total_time: start_time.elapsed(),
+                provider_times,
                 providers_used: provider_responses.len(),
-                consensus_time: std::time::Duration::from_millis(0), // Would be tracked
-                memory_usage: 0,                                     // Would be tracked
-                cache_hit_ratio: 0.0,                                // Would be tracked
+                consensus_time,
+                memory_usage: 0,
+                cache_hit_ratio: 0.0,
             },
+            provider_responses,
+            consistency_analysis,
+            bias_analysis: None,
+            disagreement,
         })
     }
 
+    /// Execute parallel validation strategy
+    ///
+    /// Reports disagreement instead of failing when consistency is low.
+    async fn execute_parallel_validation(
+        &self,
+        query: &str,
+    ) -> CrossValidationResult<ValidationResult> {
+        let start_time = Instant::now();
+        let providers = self.candidate_providers().await?;
+        let responses = self.query_providers(query, &providers).await;
+        self.require_min_responses(&responses)?;
+
+        self.build_result(
+            query,
+            responses,
+            self.consensus_generator.as_ref(),
+            start_time,
+        )
+        .await
+    }
+
     /// Execute sequential validation strategy
+    ///
+    /// Consults providers one at a time and stops as soon as `min_providers`
+    /// answers agree, so further providers are only paid for on disagreement.
     async fn execute_sequential_validation(
         &self,
-        _query: &str,
+        query: &str,
     ) -> CrossValidationResult<ValidationResult> {
-        // This is a placeholder - will be implemented after tests
-        todo!("Sequential validation implementation")
+        let start_time = Instant::now();
+        let providers = self.candidate_providers().await?;
+        let mut responses = HashMap::new();
+
+        for provider in &providers {
+            responses.extend(
+                self.query_providers(query, std::slice::from_ref(provider))
+                    .await,
+            );
+            if responses.len() < self.config.min_providers {
+                continue;
+            }
+
+            let analysis = self
+                .consistency_analyzer
+                .analyze_consistency(&responses, query)
+                .await?;
+            if analysis.overall_consistency >= self.config.consistency_threshold {
+                break;
+            }
+            debug!(
+                "Consistency {:.2} below {:.2} after {} providers, consulting another",
+                analysis.overall_consistency,
+                self.config.consistency_threshold,
+                responses.len()
+            );
+        }
+
+        self.require_min_responses(&responses)?;
+        self.build_result(
+            query,
+            responses,
+            self.consensus_generator.as_ref(),
+            start_time,
+        )
+        .await
     }
 
     /// Execute ensemble validation strategy
+    ///
+    /// Queries providers in parallel and merges their answers regardless of
+    /// the configured consensus method.
     async fn execute_ensemble_validation(
         &self,
-        _query: &str,
+        query: &str,
     ) -> CrossValidationResult<ValidationResult> {
-        // This is a placeholder - will be implemented after tests
-        todo!("Ensemble validation implementation")
+        let start_time = Instant::now();
+        let providers = self.candidate_providers().await?;
+        let responses = self.query_providers(query, &providers).await;
+        self.require_min_responses(&responses)?;
+
+        let merger = WeightedConsensusGenerator::new(ConsensusMethod::EnsembleMerge);
+        self.build_result(query, responses, &merger, start_time)
+            .await
     }
 
     /// Execute threshold-based validation strategy
+    ///
+    /// Like the parallel strategy, but fails when consistency stays below
+    /// `consistency_threshold`.
     async fn execute_threshold_validation(
         &self,
-        _query: &str,
+        query: &str,
     ) -> CrossValidationResult<ValidationResult> {
-        // This is a placeholder - will be implemented after tests
-        todo!("Threshold-based validation implementation")
+        let result = self.execute_parallel_validation(query).await?;
+        if !result.disagreement.meets_threshold {
+            return Err(CrossValidationError::ConsistencyThresholdNotMet {
+                actual: result.consistency_analysis.overall_consistency,
+                required: self.config.consistency_threshold,
+            });
+        }
+        Ok(result)
     }
 }
 
@@ -1009,33 +1183,68 @@ impl ConsensusGenerator for WeightedConsensusGenerator {
 }
 
 /// Comprehensive consistency analyzer implementation
-#[derive(Debug)]
-pub struct ComprehensiveConsistencyAnalyzer;
+///
+/// Semantic similarity uses embedding cosine similarity when an embedding
+/// generator is configured and lexical overlap otherwise.
+#[derive(Default)]
+pub struct ComprehensiveConsistencyAnalyzer {
+    embeddings: Option<Arc<dyn EmbeddingGenerator>>,
+}
 
-impl Default for ComprehensiveConsistencyAnalyzer {
-    fn default() -> Self {
-        Self::new()
+impl std::fmt::Debug for ComprehensiveConsistencyAnalyzer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComprehensiveConsistencyAnalyzer")
+            .field(
+                "embeddings",
+                &self.embeddings.as_ref().map(|_| "<EmbeddingGenerator>"),
+            )
+            .finish()
     }
 }
 
 impl ComprehensiveConsistencyAnalyzer {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Compare responses by the cosine similarity of their embeddings
+    pub fn with_embedding_generator(mut self, generator: Arc<dyn EmbeddingGenerator>) -> Self {
+        self.embeddings = Some(generator);
+        self
     }
 
-    /// Calculate semantic similarities between response pairs using text-based metrics
+    /// Calculate semantic similarities between response pairs
     async fn calculate_semantic_similarities(
         &self,
         response_texts: &[&str],
         provider_names: &[String],
     ) -> CrossValidationResult<HashMap<String, f64>> {
+        let embeddings = match &self.embeddings {
+            Some(generator) => {
+                let texts: Vec<String> = response_texts.iter().map(|t| t.to_string()).collect();
+                match generator.generate_embeddings(&texts).await {
+                    Ok(embeddings) => Some(embeddings),
+                    Err(e) => {
+                        warn!(
+                            "Embedding responses failed, using lexical similarity: {}",
+                            e
+                        );
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+
         let mut similarities = HashMap::new();
 
         for i in 0..response_texts.len() {
             for j in (i + 1)..response_texts.len() {
                 let pair_key = format!("{}_{}", provider_names[i], provider_names[j]);
-                let similarity =
-                    self.calculate_text_similarity(response_texts[i], response_texts[j]);
+                let similarity = match &embeddings {
+                    Some(embeddings) => cosine_similarity(&embeddings[i], &embeddings[j]),
+                    None => self.calculate_text_similarity(response_texts[i], response_texts[j]),
+                };
                 similarities.insert(pair_key, similarity);
             }
         }
@@ -1332,6 +1541,17 @@ impl ComprehensiveConsistencyAnalyzer {
     }
 }
 
+/// Cosine similarity of two embeddings, clamped to 0.0-1.0
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| *x as f64 * *y as f64).sum();
+    let norm_a: f64 = a.iter().map(|x| (*x as f64).powi(2)).sum::<f64>().sqrt();
+    let norm_b: f64 = b.iter().map(|x| (*x as f64).powi(2)).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    (dot / (norm_a * norm_b)).clamp(0.0, 1.0)
+}
+
 #[async_trait]
 impl ConsistencyAnalyzer for ComprehensiveConsistencyAnalyzer {
     async fn analyze_consistency(
@@ -1544,9 +1764,204 @@ mod tests {
             .await
             .unwrap();
 
-        // This should fail with todo! until we implement the functionality
-        let result = engine.validate_across_providers("test query").await;
-        assert!(result.is_err()); // Will panic with todo! for now
+        let result = engine
+            .validate_across_providers("test query")
+            .await
+            .unwrap();
+        assert_eq!(result.provider_responses.len(), 3);
+        assert_eq!(result.validation_metrics.providers_used, 3);
+        assert_eq!(result.validation_metrics.provider_times.len(), 3);
+        assert_eq!(result.disagreement.provider_agreement.len(), 3);
+        assert!(result
+            .consensus_result
+            .starts_with("Response from provider"));
+    }
+
+    async fn provider_manager_with(responses: &[(&str, &str)]) -> Arc<ProviderManager> {
+        let manager = ProviderManager::new(crate::providers::ProviderConfig::default())
+            .await
+            .unwrap();
+        for (name, response) in responses {
+            manager
+                .add_provider(
+                    name.to_string(),
+                    Arc::new(MockProvider::new(name).with_response(response)),
+                )
+                .await
+                .unwrap();
+        }
+        Arc::new(manager)
+    }
+
+    const TOKIO_ANSWER: &str = "Use tokio spawn to run async tasks concurrently on the runtime worker threads because it schedules them cooperatively.";
+    const UNRELATED_ANSWER: &str =
+        "Bake the bread at high heat until golden brown then cool it on a wire rack overnight.";
+
+    #[tokio::test]
+    async fn test_threshold_strategy_rejects_disagreeing_providers() {
+        let provider_manager =
+            provider_manager_with(&[("alpha", TOKIO_ANSWER), ("beta", UNRELATED_ANSWER)]).await;
+        let config = CrossValidationConfig {
+            strategy: ValidationStrategy::ThresholdBased,
+            ..Default::default()
+        };
+        let engine =
+            CrossValidationEngine::new(config, provider_manager, create_test_quality_scorer())
+                .await
+                .unwrap();
+
+        let result = engine
+            .validate_across_providers("How do I run async tasks?")
+            .await;
+        assert!(matches!(
+            result,
+            Err(CrossValidationError::ConsistencyThresholdNotMet { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sequential_strategy_stops_once_providers_agree() {
+        let provider_manager = provider_manager_with(&[
+            ("alpha", TOKIO_ANSWER),
+            ("beta", TOKIO_ANSWER),
+            ("gamma", UNRELATED_ANSWER),
+        ])
+        .await;
+        let config = CrossValidationConfig {
+            strategy: ValidationStrategy::Sequential,
+            consistency_threshold: 0.7,
+            ..Default::default()
+        };
+        let engine =
+            CrossValidationEngine::new(config, provider_manager, create_test_quality_scorer())
+                .await
+                .unwrap();
+
+        let result = engine
+            .validate_across_providers("How do I run async tasks?")
+            .await
+            .unwrap();
+        assert_eq!(result.validation_metrics.providers_used, 2);
+        assert!(!result.provider_responses.contains_key("gamma"));
+        assert!(result.disagreement.meets_threshold);
+    }
+
+    #[tokio::test]
+    async fn test_ensemble_strategy_reports_outlier() {
+        let similar =
+            "Use tokio spawn to run async tasks concurrently on the runtime worker threads.";
+        let provider_manager = provider_manager_with(&[
+            ("alpha", TOKIO_ANSWER),
+            ("beta", similar),
+            ("gamma", UNRELATED_ANSWER),
+        ])
+        .await;
+        let config = CrossValidationConfig {
+            strategy: ValidationStrategy::Ensemble,
+            consistency_threshold: 0.7,
+            ..Default::default()
+        };
+        let engine =
+            CrossValidationEngine::new(config, provider_manager, create_test_quality_scorer())
+                .await
+                .unwrap();
+
+        let result = engine
+            .validate_across_providers("How do I run async tasks?")
+            .await
+            .unwrap();
+        let report = &result.disagreement;
+        assert_eq!(report.outliers, vec!["gamma".to_string()]);
+        assert!(report.has_disagreement());
+        assert!(report
+            .conflicts
+            .iter()
+            .any(|c| c.providers.contains(&"gamma".to_string())));
+        assert!(result.consensus_result.contains("tokio spawn"));
+    }
+
+    /// Embeds every text to the same vector, so all responses agree
+    struct ConstantEmbeddings;
+
+    #[async_trait]
+    impl EmbeddingGenerator for ConstantEmbeddings {
+        async fn generate_embedding(
+            &self,
+            _text: &str,
+        ) -> fortitude_core::vector::VectorResult<Vec<f32>> {
+            Ok(vec![1.0, 0.0])
+        }
+
+        async fn generate_embeddings(
+            &self,
+            texts: &[String],
+        ) -> fortitude_core::vector::VectorResult<Vec<Vec<f32>>> {
+            Ok(texts.iter().map(|_| vec![1.0, 0.0]).collect())
+        }
+
+        async fn get_stats(&self) -> fortitude_core::vector::EmbeddingStats {
+            fortitude_core::vector::EmbeddingStats {
+                total_generated: 0,
+                cache_hit_rate: 0.0,
+                avg_generation_time_ms: 0.0,
+                cache_size: 0,
+            }
+        }
+
+        async fn clear_cache(&self) -> fortitude_core::vector::VectorResult<()> {
+            Ok(())
+        }
+
+        fn embedding_dimension(&self) -> usize {
+            2
+        }
+    }
+
+    #[tokio::test]
+    async fn test_semantic_similarity_uses_embeddings() {
+        let analyzer = ComprehensiveConsistencyAnalyzer::new()
+            .with_embedding_generator(Arc::new(ConstantEmbeddings));
+        let responses: HashMap<String, ProviderResponse> =
+            [("alpha", TOKIO_ANSWER), ("beta", UNRELATED_ANSWER)]
+                .into_iter()
+                .map(|(provider, response)| {
+                    (
+                        provider.to_string(),
+                        ProviderResponse {
+                            provider: provider.to_string(),
+                            response: response.to_string(),
+                            quality_score: QualityScore::new(),
+                            response_time: Duration::from_millis(100),
+                            metadata: HashMap::new(),
+                        },
+                    )
+                })
+                .collect();
+
+        let analysis = analyzer
+            .analyze_consistency(&responses, "How do I run async tasks?")
+            .await
+            .unwrap();
+        assert_eq!(
+            analysis
+                .semantic_similarities
+                .values()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![1.0]
+        );
+        assert!(!analysis
+            .conflicts
+            .iter()
+            .any(|c| c.conflict_type == ConflictType::SemanticInconsistency));
+    }
+
+    #[test]
+    fn test_cosine_similarity() {
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[2.0, 0.0]), 1.0);
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]), 0.0);
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[-1.0, 0.0]), 0.0);
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]), 0.0);
     }
 
     #[tokio::test]
@@ -0,0 +1,233 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Statistical comparison of prompt template experiment arms
+//! Compares the control and variant arms of each prompt experiment twice: on
+//! the quality scores recorded when results were produced, and on the user
+//! ratings submitted for them afterwards. The control arm is variant A and
+//! the variant arm is variant B of the resulting [`ABTestResults`].
+
+use crate::quality::{ABTestConfig, ABTestResults, ABTestingConfig};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use fortitude_core::prompts::{ExperimentArm, ExperimentObservation, PromptExperiment};
+
+/// User rating of a result produced under an experiment
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExperimentRating {
+    pub experiment: String,
+    pub arm: ExperimentArm,
+    /// Rating on a 0.0-1.0 scale
+    pub score: f64,
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// Quality and rating comparison of one prompt experiment
+#[derive(Debug, Clone)]
+pub struct PromptExperimentComparison {
+    pub experiment: PromptExperiment,
+    /// Quality scores recorded by the research engine
+    pub quality: ABTestResults,
+    /// User feedback ratings
+    pub ratings: ABTestResults,
+}
+
+impl ABTestConfig {
+    /// A/B test settings for a prompt experiment
+    pub fn for_prompt_experiment(
+        experiment: &PromptExperiment,
+        settings: &ABTestingConfig,
+    ) -> Self {
+        let variant_share = experiment.traffic_percentage / 100.0;
+        Self {
+            test_name: experiment.name.clone(),
+            variant_a_weight: 1.0 - variant_share,
+            variant_b_weight: variant_share,
+            min_sample_size: settings.min_sample_size,
+            confidence_level: 1.0 - settings.significance_threshold,
+            duration: ChronoDuration::days(i64::from(settings.default_duration_days)),
+        }
+    }
+}
+
+/// Compare the arms of every experiment
+///
+/// Only observations and ratings recorded at or after `since` are counted.
+pub fn compare_prompt_experiments(
+    experiments: &[PromptExperiment],
+    observations: &[ExperimentObservation],
+    ratings: &[ExperimentRating],
+    settings: &ABTestingConfig,
+    since: DateTime<Utc>,
+) -> Vec<PromptExperimentComparison> {
+    experiments
+        .iter()
+        .map(|experiment| {
+            let config = ABTestConfig::for_prompt_experiment(experiment, settings);
+            let observed: Vec<&ExperimentObservation> = observations
+                .iter()
+                .filter(|o| o.experiment == experiment.name && o.recorded_at >= since)
+                .collect();
+            let rated: Vec<&ExperimentRating> = ratings
+                .iter()
+                .filter(|r| r.experiment == experiment.name && r.submitted_at >= since)
+                .collect();
+
+            let quality_scores = |arm| -> Vec<f64> {
+                observed
+                    .iter()
+                    .filter(|o| o.arm == arm)
+                    .map(|o| o.quality_score)
+                    .collect()
+            };
+            let rating_scores = |arm| -> Vec<f64> {
+                rated
+                    .iter()
+                    .filter(|r| r.arm == arm)
+                    .map(|r| r.score)
+                    .collect()
+            };
+            let duration = observed
+                .iter()
+                .map(|o| o.recorded_at)
+                .min()
+                .map_or_else(ChronoDuration::zero, |start| Utc::now() - start);
+
+            PromptExperimentComparison {
+                experiment: experiment.clone(),
+                quality: ABTestResults::from_samples(
+                    &config,
+                    &quality_scores(ExperimentArm::Control),
+                    &quality_scores(ExperimentArm::Variant),
+                    duration,
+                ),
+                ratings: ABTestResults::from_samples(
+                    &config,
+                    &rating_scores(ExperimentArm::Control),
+                    &rating_scores(ExperimentArm::Variant),
+                    duration,
+                ),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fortitude_types::ResearchType;
+    use uuid::Uuid;
+
+    fn experiment() -> PromptExperiment {
+        PromptExperiment {
+            name: "decision-tradeoffs".to_string(),
+            research_type: ResearchType::Decision,
+            provider: None,
+            control_version: 1,
+            variant_version: 2,
+            traffic_percentage: 50.0,
+            enabled: true,
+        }
+    }
+
+    fn observation(arm: ExperimentArm, quality_score: f64) -> ExperimentObservation {
+        ExperimentObservation {
+            experiment: "decision-tradeoffs".to_string(),
+            arm,
+            template_version: 1,
+            request_id: Uuid::new_v4(),
+            quality_score,
+            recorded_at: Utc::now(),
+        }
+    }
+
+    fn settings(min_sample_size: usize) -> ABTestingConfig {
+        ABTestingConfig {
+            min_sample_size,
+            ..ABTestingConfig::development_defaults()
+        }
+    }
+
+    #[test]
+    fn test_clearly_better_variant_is_significant() {
+        let observations: Vec<ExperimentObservation> = (0..40)
+            .flat_map(|i| {
+                let jitter = f64::from(i % 5) * 0.01;
+                [
+                    observation(ExperimentArm::Control, 0.60 + jitter),
+                    observation(ExperimentArm::Variant, 0.80 + jitter),
+                ]
+            })
+            .collect();
+        let ratings = vec![ExperimentRating {
+            experiment: "decision-tradeoffs".to_string(),
+            arm: ExperimentArm::Variant,
+            score: 0.9,
+            submitted_at: Utc::now(),
+        }];
+
+        let comparisons = compare_prompt_experiments(
+            &[experiment()],
+            &observations,
+            &ratings,
+            &settings(30),
+            Utc::now() - ChronoDuration::days(7),
+        );
+        let quality = &comparisons[0].quality;
+        assert_eq!((quality.sample_size_a, quality.sample_size_b), (40, 40));
+        assert!((quality.variant_b_performance - 0.82).abs() < 1e-9);
+        assert!(quality.statistical_significance > 0.99);
+        assert_eq!(quality.recommendation, "Variant B performs better");
+
+        let rated = &comparisons[0].ratings;
+        assert_eq!((rated.sample_size_a, rated.sample_size_b), (0, 1));
+        assert_eq!(rated.statistical_significance, 0.0);
+        assert!(rated.recommendation.starts_with("Insufficient data"));
+    }
+
+    #[test]
+    fn test_overlapping_arms_are_not_significant() {
+        let observations: Vec<ExperimentObservation> = (0..40)
+            .flat_map(|i| {
+                let score = 0.5 + f64::from(i % 10) * 0.05;
+                [
+                    observation(ExperimentArm::Control, score),
+                    observation(ExperimentArm::Variant, score + 0.005),
+                ]
+            })
+            .collect();
+
+        let comparisons = compare_prompt_experiments(
+            &[experiment()],
+            &observations,
+            &[],
+            &settings(30),
+            Utc::now() - ChronoDuration::days(7),
+        );
+        let quality = &comparisons[0].quality;
+        assert!(quality.statistical_significance < 0.5);
+        assert_eq!(
+            quality.recommendation,
+            "No significant difference between variants"
+        );
+
+        let later = compare_prompt_experiments(
+            &[experiment()],
+            &observations,
+            &[],
+            &settings(30),
+            Utc::now() + ChronoDuration::days(1),
+        );
+        assert_eq!(later[0].quality.sample_size_a, 0);
+    }
+}
@@ -1115,54 +1115,19 @@ impl LearningEngine {
     pub async fn analyze_ab_test(&self, test_name: &str) -> Result<ABTestResults, FeedbackError> {
         let tests = self.ab_tests.read().await;
         if let Some(test) = tests.get(test_name) {
-            let sample_size_a = test.feedback_a.len();
-            let sample_size_b = test.feedback_b.len();
-
-            // Calculate average ratings for each variant
-            let avg_rating_a = if sample_size_a > 0 {
-                test.feedback_a
-                    .iter()
-                    .filter_map(|f| f.rating)
-                    .map(|r| r as f64)
-                    .sum::<f64>()
-                    / sample_size_a as f64
-            } else {
-                0.0
-            };
-
-            let avg_rating_b = if sample_size_b > 0 {
-                test.feedback_b
+            let ratings = |feedback: &[UserFeedback]| -> Vec<f64> {
+                feedback
                     .iter()
                     .filter_map(|f| f.rating)
-                    .map(|r| r as f64)
-                    .sum::<f64>()
-                    / sample_size_b as f64
-            } else {
-                0.0
-            };
-
-            // Simple statistical significance calculation (placeholder)
-            let statistical_significance = if sample_size_a > 10 && sample_size_b > 10 {
-                let diff = (avg_rating_a - avg_rating_b).abs();
-                (diff / 5.0).min(1.0) // Normalize to 0-1 scale
-            } else {
-                0.0
+                    .map(f64::from)
+                    .collect()
             };
-
-            Ok(ABTestResults {
-                test_name: test_name.to_string(),
-                variant_a_performance: avg_rating_a,
-                variant_b_performance: avg_rating_b,
-                statistical_significance,
-                sample_size_a,
-                sample_size_b,
-                test_duration: Utc::now() - test.start_time,
-                recommendation: if avg_rating_a > avg_rating_b {
-                    "Variant A performs better".to_string()
-                } else {
-                    "Variant B performs better".to_string()
-                },
-            })
+            Ok(ABTestResults::from_samples(
+                &test.config,
+                &ratings(&test.feedback_a),
+                &ratings(&test.feedback_b),
+                Utc::now() - test.start_time,
+            ))
         } else {
             Err(FeedbackError::ABTestError {
                 test_name: test_name.to_string(),
@@ -1935,6 +1900,96 @@ pub struct ABTestResults {
     pub recommendation: String,
 }
 
+impl ABTestResults {
+    /// Compare two samples of scores with Welch's t-test
+    ///
+    /// `statistical_significance` is one minus the two-sided p-value, using
+    /// the normal approximation of the t distribution. A winner is only
+    /// recommended once both samples reach `min_sample_size` and the
+    /// significance reaches `confidence_level`.
+    pub fn from_samples(
+        config: &ABTestConfig,
+        samples_a: &[f64],
+        samples_b: &[f64],
+        test_duration: ChronoDuration,
+    ) -> Self {
+        let (mean_a, variance_a) = mean_and_variance(samples_a);
+        let (mean_b, variance_b) = mean_and_variance(samples_b);
+
+        let statistical_significance = if samples_a.len() > 1 && samples_b.len() > 1 {
+            let standard_error =
+                (variance_a / samples_a.len() as f64 + variance_b / samples_b.len() as f64).sqrt();
+            if standard_error > 0.0 {
+                let t = (mean_a - mean_b).abs() / standard_error;
+                2.0 * standard_normal_cdf(t) - 1.0
+            } else if mean_a != mean_b {
+                1.0
+            } else {
+                0.0
+            }
+        } else {
+            0.0
+        };
+
+        let recommendation = if samples_a.len() < config.min_sample_size
+            || samples_b.len() < config.min_sample_size
+        {
+            format!(
+                "Insufficient data: {} of {} samples per variant collected",
+                samples_a.len().min(samples_b.len()),
+                config.min_sample_size
+            )
+        } else if statistical_significance < config.confidence_level {
+            "No significant difference between variants".to_string()
+        } else if mean_b > mean_a {
+            "Variant B performs better".to_string()
+        } else {
+            "Variant A performs better".to_string()
+        };
+
+        Self {
+            test_name: config.test_name.clone(),
+            variant_a_performance: mean_a,
+            variant_b_performance: mean_b,
+            statistical_significance,
+            sample_size_a: samples_a.len(),
+            sample_size_b: samples_b.len(),
+            test_duration,
+            recommendation,
+        }
+    }
+}
+
+/// Mean and unbiased sample variance
+fn mean_and_variance(samples: &[f64]) -> (f64, f64) {
+    if samples.is_empty() {
+        return (0.0, 0.0);
+    }
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+    let variance = if samples.len() > 1 {
+        samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (samples.len() - 1) as f64
+    } else {
+        0.0
+    };
+    (mean, variance)
+}
+
+/// Standard normal CDF (Abramowitz and Stegun 7.1.26, error below 1.5e-7)
+fn standard_normal_cdf(x: f64) -> f64 {
+    let z = x.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.327_591_1 * z);
+    let polynomial = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erf = 1.0 - polynomial * (-z * z).exp();
+    if x >= 0.0 {
+        0.5 * (1.0 + erf)
+    } else {
+        0.5 * (1.0 - erf)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AccuracyImprovementMetrics {
     pub baseline_accuracy: f64,
@@ -52,6 +52,7 @@ use thiserror::Error;
 
 pub mod config;
 pub mod cross_validation;
+pub mod experiments;
 pub mod feedback;
 pub mod metrics;
 pub mod optimization;
@@ -66,8 +67,10 @@ pub use config::{
 };
 pub use cross_validation::{
     BiasAnalysis, ConsensusMethod, ConsistencyAnalysis, CrossValidationConfig,
-    CrossValidationEngine, ValidationMetrics, ValidationResult, ValidationStrategy,
+    CrossValidationEngine, DisagreementReport, ValidationMetrics, ValidationResult,
+    ValidationStrategy,
 };
+pub use experiments::{compare_prompt_experiments, ExperimentRating, PromptExperimentComparison};
 pub use feedback::{
     ABTestConfig, ABTestResults, AccuracyImprovementMetrics, AlgorithmVariant,
     BatchProcessingResult, CorrectionValidation, FeedbackAnalytics, FeedbackAnalyticsReport,
@@ -418,6 +418,7 @@ impl QualityOptimizationEngine {
                 memory_usage: 1024,
                 cache_hit_ratio: 0.8,
             },
+            disagreement: Default::default(),
         })
     }
 
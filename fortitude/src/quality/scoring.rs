@@ -32,6 +32,7 @@
 //! - Specificity: Detail level and precision measurement
 
 use async_trait::async_trait;
+use fortitude_types::ResearchResult;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
@@ -266,6 +267,52 @@ impl QualityScorer for ComprehensiveQualityScorer {
     }
 }
 
+/// Quality gate scoring for the research pipeline
+#[async_trait]
+impl fortitude_core::ResultScorer for ComprehensiveQualityScorer {
+    async fn score(
+        &self,
+        result: &ResearchResult,
+    ) -> Result<fortitude_core::QualityAssessment, String> {
+        let mut text = result.immediate_answer.clone();
+        for evidence in &result.supporting_evidence {
+            text.push_str("\n\n");
+            text.push_str(&evidence.content);
+        }
+        for detail in &result.implementation_details {
+            text.push_str("\n\n");
+            text.push_str(&detail.content);
+        }
+
+        let score = self
+            .evaluate_quality(
+                &result.request.original_query,
+                &text,
+                &QualityWeights::research_optimized(),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let dimensions = [
+            ("relevance", score.relevance),
+            ("accuracy", score.accuracy),
+            ("completeness", score.completeness),
+            ("clarity", score.clarity),
+            ("credibility", score.credibility),
+            ("timeliness", score.timeliness),
+            ("specificity", score.specificity),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value))
+        .collect();
+
+        Ok(fortitude_core::QualityAssessment {
+            composite: score.composite,
+            dimensions,
+        })
+    }
+}
+
 impl ComprehensiveQualityScorer {
     fn calculate_confidence(&self, scores: &[f64]) -> f64 {
         // Calculate confidence based on score variance and consistency
@@ -1072,6 +1119,46 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_result_scorer_assesses_research_result() {
+        use fortitude_core::ResultScorer;
+        use fortitude_types::{
+            AudienceContext, ClassifiedRequest, DomainContext, ResearchMetadata, ResearchType,
+        };
+
+        let request = ClassifiedRequest::new(
+            "What is machine learning?".to_string(),
+            ResearchType::Learning,
+            AudienceContext::default(),
+            DomainContext::default(),
+            0.8,
+            vec![],
+        );
+        let result = ResearchResult::new(
+            request,
+            "Machine learning is a subset of artificial intelligence that learns from data."
+                .to_string(),
+            vec![],
+            vec![],
+            ResearchMetadata {
+                completed_at: chrono::Utc::now(),
+                processing_time_ms: 0,
+                sources_consulted: vec![],
+                quality_score: 0.0,
+                cache_key: String::new(),
+                tags: std::collections::HashMap::new(),
+            },
+        );
+
+        let assessment = ComprehensiveQualityScorer::with_default_config()
+            .score(&result)
+            .await
+            .unwrap();
+        assert!((0.0..=1.0).contains(&assessment.composite));
+        assert_eq!(assessment.dimensions.len(), 7);
+        assert!(assessment.dimensions.contains_key("relevance"));
+    }
+
     #[test]
     fn test_scorer_config_defaults() {
         let config = ScorerConfig::default();
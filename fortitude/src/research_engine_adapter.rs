@@ -19,11 +19,12 @@
 
 use crate::providers::{
     manager::{ProviderManager, ProviderPerformance},
-    HealthStatus,
+    HealthStatus, RequestOrigin,
 };
 use fortitude_core::multi_provider_research_engine::{
     ProviderHealthStatus, ProviderManagerTrait, ProviderPerformanceStats,
 };
+use fortitude_core::tools::ToolRegistry;
 use fortitude_types::ClassifiedRequest;
 
 use std::collections::HashMap;
@@ -33,12 +34,26 @@ use tracing::{debug, info};
 /// Adapter that implements ProviderManagerTrait for ProviderManager
 pub struct ProviderManagerAdapter {
     provider_manager: Arc<ProviderManager>,
+    origin: RequestOrigin,
 }
 
 impl ProviderManagerAdapter {
     /// Create a new adapter wrapping the provider manager
     pub fn new(provider_manager: Arc<ProviderManager>) -> Self {
-        Self { provider_manager }
+        Self {
+            provider_manager,
+            origin: RequestOrigin::Interactive,
+        }
+    }
+
+    /// Tag every request sent through this adapter with `origin`
+    pub fn with_origin(mut self, origin: RequestOrigin) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    pub fn origin(&self) -> RequestOrigin {
+        self.origin
     }
 
     /// Get reference to the underlying provider manager
@@ -78,15 +93,18 @@ impl ProviderManagerTrait for ProviderManagerAdapter {
     {
         let provider_manager = Arc::clone(&self.provider_manager);
         let request = request.clone();
+        let origin = self.origin;
 
         async move {
             debug!(
-                "Executing research through provider manager for query: '{}'",
-                request.original_query
+                "Executing {} research through provider manager for query: '{}'",
+                origin, request.original_query
             );
 
             // Execute research through the provider manager
-            let result = provider_manager.execute_research(&request).await?;
+            let result = provider_manager
+                .execute_research_with_origin(&request, origin)
+                .await?;
 
             // The provider manager returns a ResearchResult, but we need just the response text
             // Extract the immediate answer for the research engine
@@ -94,6 +112,32 @@ impl ProviderManagerTrait for ProviderManagerAdapter {
         }
     }
 
+    fn execute_research_with_tools(
+        &self,
+        request: &ClassifiedRequest,
+        tools: &ToolRegistry,
+    ) -> impl std::future::Future<Output = Result<String, Box<dyn std::error::Error + Send + Sync>>> + Send
+    {
+        let provider_manager = Arc::clone(&self.provider_manager);
+        let request = request.clone();
+        let tools = tools.clone();
+        let origin = self.origin;
+
+        async move {
+            debug!(
+                "Executing {} research with {} tools for query: '{}'",
+                origin,
+                tools.len(),
+                request.original_query
+            );
+
+            let result = provider_manager
+                .execute_research_with_tools(&request, origin, &tools)
+                .await?;
+            Ok(result.immediate_answer)
+        }
+    }
+
     fn get_performance_stats(
         &self,
     ) -> impl std::future::Future<Output = HashMap<String, ProviderPerformanceStats>> + Send {
@@ -239,6 +283,19 @@ mod tests {
         assert!(adapter.inner().list_providers().await.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_adapter_origin_tagging() {
+        let config = ProviderConfig::default();
+        let provider_manager = Arc::new(ProviderManager::new(config).await.unwrap());
+
+        let adapter = ProviderManagerAdapter::new(Arc::clone(&provider_manager));
+        assert_eq!(adapter.origin(), RequestOrigin::Interactive);
+
+        let adapter =
+            ProviderManagerAdapter::new(provider_manager).with_origin(RequestOrigin::Proactive);
+        assert_eq!(adapter.origin(), RequestOrigin::Proactive);
+    }
+
     #[tokio::test]
     async fn test_adapter_health_check() {
         let config = ProviderConfig::default();
@@ -0,0 +1,412 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Workflow definition model, TOML/YAML loading and DAG validation
+use super::template::step_references;
+use super::{WorkflowError, WorkflowResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::path::Path;
+
+/// Pipeline operation a workflow step performs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StepOperation {
+    /// Run a research query through the pipeline (`query`)
+    Research,
+    /// Classify a query without researching it (`query`)
+    Classify,
+    /// Condense text to its leading sentences (`text`, `max_sentences`)
+    Summarize,
+    /// Deliver a message (`message`, `channel`, `path`)
+    Notify,
+}
+
+impl std::fmt::Display for StepOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            StepOperation::Research => "research",
+            StepOperation::Classify => "classify",
+            StepOperation::Summarize => "summarize",
+            StepOperation::Notify => "notify",
+        };
+        write!(f, "{name}")
+    }
+}
+
+fn default_retry_delay_ms() -> u64 {
+    1000
+}
+
+/// A single node in the workflow graph
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowStep {
+    pub id: String,
+    pub operation: StepOperation,
+    /// Operation parameters; values may contain `{{ ... }}` templates
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+    /// Steps that must succeed before this one runs
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    /// Condition that must hold for the step to run; otherwise it and its
+    /// dependents are skipped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when: Option<String>,
+    /// Additional attempts after the first failure
+    #[serde(default)]
+    pub retries: u32,
+    /// Delay before the first retry, doubled for each further attempt
+    #[serde(default = "default_retry_delay_ms")]
+    pub retry_delay_ms: u64,
+}
+
+impl WorkflowStep {
+    pub fn new(id: impl Into<String>, operation: StepOperation) -> Self {
+        Self {
+            id: id.into(),
+            operation,
+            params: BTreeMap::new(),
+            depends_on: Vec::new(),
+            when: None,
+            retries: 0,
+            retry_delay_ms: default_retry_delay_ms(),
+        }
+    }
+
+    pub fn with_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn with_dependency(mut self, step_id: impl Into<String>) -> Self {
+        self.depends_on.push(step_id.into());
+        self
+    }
+
+    pub fn with_condition(mut self, when: impl Into<String>) -> Self {
+        self.when = Some(when.into());
+        self
+    }
+
+    pub fn with_retries(mut self, retries: u32, retry_delay_ms: u64) -> Self {
+        self.retries = retries;
+        self.retry_delay_ms = retry_delay_ms;
+        self
+    }
+}
+
+/// A named DAG of steps
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowDefinition {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Input names and their default values
+    #[serde(default)]
+    pub inputs: BTreeMap<String, String>,
+    pub steps: Vec<WorkflowStep>,
+}
+
+impl WorkflowDefinition {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            inputs: BTreeMap::new(),
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn with_input(mut self, name: impl Into<String>, default: impl Into<String>) -> Self {
+        self.inputs.insert(name.into(), default.into());
+        self
+    }
+
+    pub fn with_step(mut self, step: WorkflowStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    pub fn from_toml_str(content: &str) -> WorkflowResult<Self> {
+        let definition: Self =
+            toml::from_str(content).map_err(|e| WorkflowError::Parse(e.to_string()))?;
+        definition.validate()?;
+        Ok(definition)
+    }
+
+    pub fn from_yaml_str(content: &str) -> WorkflowResult<Self> {
+        let definition: Self =
+            serde_yaml::from_str(content).map_err(|e| WorkflowError::Parse(e.to_string()))?;
+        definition.validate()?;
+        Ok(definition)
+    }
+
+    /// Load a definition, choosing the format from the file extension
+    pub fn from_file(path: &Path) -> WorkflowResult<Self> {
+        let content = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml_str(&content),
+            Some("yaml") | Some("yml") => Self::from_yaml_str(&content),
+            _ => Err(WorkflowError::Parse(format!(
+                "Unsupported workflow file '{}': expected .toml, .yaml or .yml",
+                path.display()
+            ))),
+        }
+    }
+
+    pub fn step(&self, id: &str) -> Option<&WorkflowStep> {
+        self.steps.iter().find(|step| step.id == id)
+    }
+
+    /// Check ids, dependencies, cycles and that templates only read from
+    /// steps that are guaranteed to have run first
+    pub fn validate(&self) -> WorkflowResult<()> {
+        if self.name.trim().is_empty() {
+            return Err(WorkflowError::InvalidDefinition(
+                "Workflow name cannot be empty".to_string(),
+            ));
+        }
+        if self.steps.is_empty() {
+            return Err(WorkflowError::InvalidDefinition(format!(
+                "Workflow '{}' has no steps",
+                self.name
+            )));
+        }
+
+        let mut ids = HashSet::new();
+        for step in &self.steps {
+            if step.id.trim().is_empty() {
+                return Err(WorkflowError::InvalidDefinition(
+                    "Step id cannot be empty".to_string(),
+                ));
+            }
+            if !ids.insert(step.id.as_str()) {
+                return Err(WorkflowError::InvalidDefinition(format!(
+                    "Duplicate step id '{}'",
+                    step.id
+                )));
+            }
+        }
+
+        for step in &self.steps {
+            for dependency in &step.depends_on {
+                if dependency == &step.id {
+                    return Err(WorkflowError::InvalidDefinition(format!(
+                        "Step '{}' depends on itself",
+                        step.id
+                    )));
+                }
+                if !ids.contains(dependency.as_str()) {
+                    return Err(WorkflowError::InvalidDefinition(format!(
+                        "Step '{}' depends on unknown step '{}'",
+                        step.id, dependency
+                    )));
+                }
+            }
+        }
+
+        let order = self.execution_order()?;
+
+        // Ancestors of each step, built in execution order
+        let mut ancestors: HashMap<&str, HashSet<&str>> = HashMap::new();
+        for step in &order {
+            let mut reachable = HashSet::new();
+            for dependency in &step.depends_on {
+                reachable.insert(dependency.as_str());
+                reachable.extend(ancestors[dependency.as_str()].iter().copied());
+            }
+
+            let texts = step.params.values().chain(step.when.iter());
+            for referenced in texts.flat_map(|text| step_references(text)) {
+                if !reachable.contains(referenced.as_str()) {
+                    return Err(WorkflowError::InvalidDefinition(format!(
+                        "Step '{}' references step '{}' without depending on it",
+                        step.id, referenced
+                    )));
+                }
+            }
+            ancestors.insert(step.id.as_str(), reachable);
+        }
+
+        Ok(())
+    }
+
+    /// Topological order of the steps, keeping declaration order among
+    /// steps that are ready at the same time
+    pub fn execution_order(&self) -> WorkflowResult<Vec<&WorkflowStep>> {
+        let mut remaining: HashMap<&str, usize> = self
+            .steps
+            .iter()
+            .map(|step| (step.id.as_str(), step.depends_on.len()))
+            .collect();
+        let mut ready: VecDeque<&WorkflowStep> = self
+            .steps
+            .iter()
+            .filter(|step| step.depends_on.is_empty())
+            .collect();
+        let mut order = Vec::with_capacity(self.steps.len());
+
+        while let Some(step) = ready.pop_front() {
+            order.push(step);
+            for dependent in &self.steps {
+                let edges = dependent
+                    .depends_on
+                    .iter()
+                    .filter(|dependency| *dependency == &step.id)
+                    .count();
+                if edges == 0 {
+                    continue;
+                }
+                if let Some(count) = remaining.get_mut(dependent.id.as_str()) {
+                    *count -= edges;
+                    if *count == 0 {
+                        ready.push_back(dependent);
+                    }
+                }
+            }
+        }
+
+        if order.len() != self.steps.len() {
+            let mut cyclic: Vec<&str> = self
+                .steps
+                .iter()
+                .filter(|step| !order.iter().any(|done| done.id == step.id))
+                .map(|step| step.id.as_str())
+                .collect();
+            cyclic.sort_unstable();
+            return Err(WorkflowError::InvalidDefinition(format!(
+                "Workflow '{}' contains a dependency cycle between: {}",
+                self.name,
+                cyclic.join(", ")
+            )));
+        }
+
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGEST_TOML: &str = r#"
+name = "research-digest"
+description = "Research a topic and share a summary"
+
+[inputs]
+topic = "async rust"
+
+[[steps]]
+id = "notify"
+operation = "notify"
+depends_on = ["summarize"]
+params = { message = "{{ steps.summarize.summary }}" }
+
+[[steps]]
+id = "research"
+operation = "research"
+retries = 2
+retry_delay_ms = 10
+params = { query = "{{ inputs.topic }}" }
+
+[[steps]]
+id = "summarize"
+operation = "summarize"
+depends_on = ["research"]
+when = "steps.research.answer"
+params = { text = "{{ steps.research.answer }}", max_sentences = "2" }
+"#;
+
+    #[test]
+    fn test_parse_toml_and_order_steps() {
+        let definition = WorkflowDefinition::from_toml_str(DIGEST_TOML).unwrap();
+        assert_eq!(definition.name, "research-digest");
+        assert_eq!(definition.inputs["topic"], "async rust");
+
+        let research = definition.step("research").unwrap();
+        assert_eq!(research.retries, 2);
+        assert_eq!(research.retry_delay_ms, 10);
+        assert_eq!(definition.step("notify").unwrap().retry_delay_ms, 1000);
+
+        let order: Vec<&str> = definition
+            .execution_order()
+            .unwrap()
+            .iter()
+            .map(|step| step.id.as_str())
+            .collect();
+        assert_eq!(order, vec!["research", "summarize", "notify"]);
+    }
+
+    #[test]
+    fn test_parse_yaml() {
+        let yaml = r#"
+name: classify-only
+steps:
+  - id: classify
+    operation: classify
+    params:
+      query: "{{ inputs.topic }}"
+"#;
+        let definition = WorkflowDefinition::from_yaml_str(yaml).unwrap();
+        assert_eq!(definition.steps[0].operation, StepOperation::Classify);
+        assert!(definition.steps[0].when.is_none());
+    }
+
+    #[test]
+    fn test_validation_rejects_invalid_graphs() {
+        let cycle = WorkflowDefinition::new("cycle")
+            .with_step(WorkflowStep::new("a", StepOperation::Research).with_dependency("b"))
+            .with_step(WorkflowStep::new("b", StepOperation::Summarize).with_dependency("a"));
+        let err = cycle.validate().unwrap_err().to_string();
+        assert!(err.contains("cycle between: a, b"), "{err}");
+
+        let unknown = WorkflowDefinition::new("unknown")
+            .with_step(WorkflowStep::new("a", StepOperation::Research).with_dependency("missing"));
+        assert!(unknown.validate().is_err());
+
+        let duplicate = WorkflowDefinition::new("duplicate")
+            .with_step(WorkflowStep::new("a", StepOperation::Research))
+            .with_step(WorkflowStep::new("a", StepOperation::Classify));
+        assert!(duplicate.validate().is_err());
+
+        assert!(WorkflowDefinition::new("empty").validate().is_err());
+    }
+
+    #[test]
+    fn test_validation_requires_dependency_for_step_references() {
+        let unordered = WorkflowDefinition::new("unordered")
+            .with_step(WorkflowStep::new("research", StepOperation::Research))
+            .with_step(
+                WorkflowStep::new("summarize", StepOperation::Summarize)
+                    .with_param("text", "{{ steps.research.answer }}"),
+            );
+        let err = unordered.validate().unwrap_err().to_string();
+        assert!(err.contains("without depending on it"), "{err}");
+
+        // Transitive dependencies are enough
+        let transitive = WorkflowDefinition::new("transitive")
+            .with_step(WorkflowStep::new("research", StepOperation::Research))
+            .with_step(
+                WorkflowStep::new("summarize", StepOperation::Summarize)
+                    .with_dependency("research"),
+            )
+            .with_step(
+                WorkflowStep::new("notify", StepOperation::Notify)
+                    .with_dependency("summarize")
+                    .with_condition("steps.research.answer != ''"),
+            );
+        assert!(transitive.validate().is_ok());
+    }
+}
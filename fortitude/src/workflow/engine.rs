@@ -0,0 +1,374 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Workflow engine that runs step DAGs with conditions, retries and persistence
+//! Steps run one at a time in dependency order. A step whose `when` condition
+//! is false is skipped together with everything that depends on it. A step
+//! that still fails after its retries fails the run and the remaining steps
+//! are skipped. The run record is saved after every state change so
+//! `fortitude workflow status` can follow a run in progress.
+
+use super::definition::{StepOperation, WorkflowDefinition, WorkflowStep};
+use super::store::{RunStatus, StepStatus, WorkflowRun, WorkflowRunStore};
+use super::template::{evaluate_condition, render};
+use super::{WorkflowError, WorkflowResult};
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Performs the pipeline operation behind a workflow step
+#[async_trait]
+pub trait StepExecutor: Send + Sync {
+    /// Execute `operation` with fully rendered parameters and return its
+    /// output, which later steps can reference as `steps.<id>.<field>`
+    async fn execute(
+        &self,
+        operation: StepOperation,
+        params: &BTreeMap<String, String>,
+    ) -> WorkflowResult<Value>;
+}
+
+/// Runs workflow definitions and persists their runs
+pub struct WorkflowEngine {
+    executor: Arc<dyn StepExecutor>,
+    store: WorkflowRunStore,
+}
+
+impl WorkflowEngine {
+    pub fn new(executor: Arc<dyn StepExecutor>, store: WorkflowRunStore) -> Self {
+        Self { executor, store }
+    }
+
+    pub fn store(&self) -> &WorkflowRunStore {
+        &self.store
+    }
+
+    /// Run `definition` to completion; `inputs` override the declared defaults
+    pub async fn run(
+        &self,
+        definition: &WorkflowDefinition,
+        inputs: BTreeMap<String, String>,
+    ) -> WorkflowResult<WorkflowRun> {
+        definition.validate()?;
+
+        let mut resolved_inputs = definition.inputs.clone();
+        resolved_inputs.extend(inputs);
+
+        let mut run = WorkflowRun::new(definition, resolved_inputs)?;
+        self.store.save(&run)?;
+        info!("Started workflow '{}' as run {}", run.workflow, run.id);
+
+        let mut context = json!({ "inputs": run.inputs, "steps": {} });
+        let order: Vec<WorkflowStep> = definition.execution_order()?.into_iter().cloned().collect();
+        let mut failed = false;
+
+        for step in &order {
+            if failed {
+                self.skip(&mut run, &step.id, "an earlier step failed")?;
+                continue;
+            }
+
+            let blocked_by = step.depends_on.iter().find(|dependency| {
+                run.step(dependency).map(|s| s.status) != Some(StepStatus::Succeeded)
+            });
+            if let Some(dependency) = blocked_by {
+                let reason = format!("dependency '{dependency}' did not run");
+                self.skip(&mut run, &step.id, &reason)?;
+                continue;
+            }
+
+            if let Some(condition) = &step.when {
+                match evaluate_condition(condition, &context) {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        let reason = format!("condition not met: {condition}");
+                        self.skip(&mut run, &step.id, &reason)?;
+                        continue;
+                    }
+                    Err(e) => {
+                        self.fail(&mut run, &step.id, e.to_string())?;
+                        failed = true;
+                        continue;
+                    }
+                }
+            }
+
+            let params = match render_params(step, &context) {
+                Ok(params) => params,
+                Err(e) => {
+                    self.fail(&mut run, &step.id, e.to_string())?;
+                    failed = true;
+                    continue;
+                }
+            };
+
+            match self.execute_with_retries(&mut run, step, &params).await? {
+                Ok(output) => {
+                    context["steps"][step.id.as_str()] = output.clone();
+                    let record = run.step_mut(&step.id).expect("step recorded in run");
+                    record.status = StepStatus::Succeeded;
+                    record.output = Some(output);
+                    record.error = None;
+                    record.finished_at = Some(Utc::now());
+                    self.store.save(&run)?;
+                }
+                Err(e) => {
+                    self.fail(&mut run, &step.id, e.to_string())?;
+                    failed = true;
+                }
+            }
+        }
+
+        run.status = if failed {
+            RunStatus::Failed
+        } else {
+            RunStatus::Succeeded
+        };
+        run.finished_at = Some(Utc::now());
+        self.store.save(&run)?;
+        info!(
+            "Workflow '{}' run {} finished: {}",
+            run.workflow, run.id, run.status
+        );
+
+        Ok(run)
+    }
+
+    /// Execute a step, retrying with a doubling delay. The outer result
+    /// carries storage errors, the inner one the step's own outcome.
+    async fn execute_with_retries(
+        &self,
+        run: &mut WorkflowRun,
+        step: &WorkflowStep,
+        params: &BTreeMap<String, String>,
+    ) -> WorkflowResult<WorkflowResult<Value>> {
+        let max_attempts = step.retries.saturating_add(1);
+        {
+            let record = run.step_mut(&step.id).expect("step recorded in run");
+            record.status = StepStatus::Running;
+            record.started_at = Some(Utc::now());
+        }
+
+        for attempt in 1..=max_attempts {
+            run.step_mut(&step.id)
+                .expect("step recorded in run")
+                .attempts = attempt;
+            self.store.save(run)?;
+
+            match self.executor.execute(step.operation, params).await {
+                Ok(output) => return Ok(Ok(output)),
+                Err(e) if attempt < max_attempts => {
+                    let delay = step
+                        .retry_delay_ms
+                        .saturating_mul(1u64 << (attempt - 1).min(16));
+                    warn!(
+                        "Step '{}' attempt {attempt}/{max_attempts} failed: {e}; retrying in {delay}ms",
+                        step.id
+                    );
+                    run.step_mut(&step.id).expect("step recorded in run").error =
+                        Some(e.to_string());
+                    self.store.save(run)?;
+                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                }
+                Err(e) => {
+                    return Ok(Err(WorkflowError::StepFailed {
+                        step: step.id.clone(),
+                        message: format!("{e} (after {attempt} attempt(s))"),
+                    }))
+                }
+            }
+        }
+
+        unreachable!("a step always makes at least one attempt")
+    }
+
+    fn skip(&self, run: &mut WorkflowRun, step_id: &str, reason: &str) -> WorkflowResult<()> {
+        info!("Skipping step '{step_id}': {reason}");
+        let record = run.step_mut(step_id).expect("step recorded in run");
+        record.status = StepStatus::Skipped;
+        record.error = Some(reason.to_string());
+        self.store.save(run)
+    }
+
+    fn fail(&self, run: &mut WorkflowRun, step_id: &str, message: String) -> WorkflowResult<()> {
+        warn!("Step '{step_id}' failed: {message}");
+        let record = run.step_mut(step_id).expect("step recorded in run");
+        record.status = StepStatus::Failed;
+        record.error = Some(message);
+        record.finished_at = Some(Utc::now());
+        self.store.save(run)
+    }
+}
+
+fn render_params(step: &WorkflowStep, context: &Value) -> WorkflowResult<BTreeMap<String, String>> {
+    step.params
+        .iter()
+        .map(|(key, template)| Ok((key.clone(), render(template, context)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    /// Executor that records calls and fails the first `failures` attempts
+    /// of each operation listed in `failing`
+    struct ScriptedExecutor {
+        calls: Mutex<Vec<(StepOperation, BTreeMap<String, String>)>>,
+        failing: Mutex<BTreeMap<String, u32>>,
+    }
+
+    impl ScriptedExecutor {
+        fn new(failing: &[(StepOperation, u32)]) -> Self {
+            Self {
+                calls: Mutex::new(Vec::new()),
+                failing: Mutex::new(
+                    failing
+                        .iter()
+                        .map(|(operation, count)| (operation.to_string(), *count))
+                        .collect(),
+                ),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl StepExecutor for ScriptedExecutor {
+        async fn execute(
+            &self,
+            operation: StepOperation,
+            params: &BTreeMap<String, String>,
+        ) -> WorkflowResult<Value> {
+            self.calls.lock().unwrap().push((operation, params.clone()));
+
+            if let Some(remaining) = self.failing.lock().unwrap().get_mut(&operation.to_string()) {
+                if *remaining > 0 {
+                    *remaining -= 1;
+                    return Err(WorkflowError::StepFailed {
+                        step: operation.to_string(),
+                        message: "provider unavailable".to_string(),
+                    });
+                }
+            }
+
+            Ok(match operation {
+                StepOperation::Classify => json!({ "research_type": "implementation" }),
+                StepOperation::Research => {
+                    json!({ "answer": format!("Answer about {}", params["query"]) })
+                }
+                StepOperation::Summarize => json!({ "summary": params["text"].clone() }),
+                StepOperation::Notify => json!({ "delivered": true }),
+            })
+        }
+    }
+
+    fn digest_workflow() -> WorkflowDefinition {
+        WorkflowDefinition::new("digest")
+            .with_input("topic", "async rust")
+            .with_step(
+                WorkflowStep::new("classify", StepOperation::Classify)
+                    .with_param("query", "{{ inputs.topic }}"),
+            )
+            .with_step(
+                WorkflowStep::new("research", StepOperation::Research)
+                    .with_dependency("classify")
+                    .with_condition("steps.classify.research_type == 'implementation'")
+                    .with_retries(2, 1)
+                    .with_param("query", "{{ inputs.topic }}"),
+            )
+            .with_step(
+                WorkflowStep::new("summarize", StepOperation::Summarize)
+                    .with_dependency("research")
+                    .with_param("text", "{{ steps.research.answer }}"),
+            )
+            .with_step(
+                WorkflowStep::new("notify", StepOperation::Notify)
+                    .with_dependency("classify")
+                    .with_condition("steps.classify.research_type == 'learning'")
+                    .with_param("message", "learning topic"),
+            )
+    }
+
+    #[tokio::test]
+    async fn test_run_templates_conditions_and_persistence() {
+        let temp_dir = TempDir::new().unwrap();
+        let executor = Arc::new(ScriptedExecutor::new(&[]));
+        let engine = WorkflowEngine::new(executor.clone(), WorkflowRunStore::new(temp_dir.path()));
+
+        let inputs = BTreeMap::from([("topic".to_string(), "tokio".to_string())]);
+        let run = engine.run(&digest_workflow(), inputs).await.unwrap();
+
+        assert_eq!(run.status, RunStatus::Succeeded);
+        assert_eq!(run.inputs["topic"], "tokio");
+        assert_eq!(
+            run.step("summarize").unwrap().output,
+            Some(json!({ "summary": "Answer about tokio" }))
+        );
+
+        let notify = run.step("notify").unwrap();
+        assert_eq!(notify.status, StepStatus::Skipped);
+        assert!(notify.error.as_ref().unwrap().contains("condition not met"));
+        assert_eq!(executor.calls.lock().unwrap().len(), 3);
+
+        assert_eq!(engine.store().load(&run.id).unwrap(), run);
+    }
+
+    #[tokio::test]
+    async fn test_step_retries_then_succeeds() {
+        let temp_dir = TempDir::new().unwrap();
+        let executor = Arc::new(ScriptedExecutor::new(&[(StepOperation::Research, 2)]));
+        let engine = WorkflowEngine::new(executor, WorkflowRunStore::new(temp_dir.path()));
+
+        let run = engine
+            .run(&digest_workflow(), BTreeMap::new())
+            .await
+            .unwrap();
+        assert_eq!(run.status, RunStatus::Succeeded);
+
+        let research = run.step("research").unwrap();
+        assert_eq!(research.status, StepStatus::Succeeded);
+        assert_eq!(research.attempts, 3);
+        assert!(research.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_retries_fail_run_and_skip_rest() {
+        let temp_dir = TempDir::new().unwrap();
+        let executor = Arc::new(ScriptedExecutor::new(&[(StepOperation::Research, 5)]));
+        let engine = WorkflowEngine::new(executor, WorkflowRunStore::new(temp_dir.path()));
+
+        let run = engine
+            .run(&digest_workflow(), BTreeMap::new())
+            .await
+            .unwrap();
+        assert_eq!(run.status, RunStatus::Failed);
+
+        let research = run.step("research").unwrap();
+        assert_eq!(research.status, StepStatus::Failed);
+        assert_eq!(research.attempts, 3);
+        assert!(research
+            .error
+            .as_ref()
+            .unwrap()
+            .contains("after 3 attempt(s)"));
+        assert_eq!(run.step("summarize").unwrap().status, StepStatus::Skipped);
+        assert_eq!(run.step("notify").unwrap().status, StepStatus::Skipped);
+    }
+}
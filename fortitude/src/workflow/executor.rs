@@ -0,0 +1,231 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Step executor that maps workflow operations onto the research pipeline
+//! Operation parameters and outputs:
+//!
+//! | Operation   | Parameters                                      | Output fields |
+//! |-------------|-------------------------------------------------|---------------|
+//! | `research`  | `query`                                         | `answer`, `research_type`, `quality_score`, `cache_key`, `evidence_count` |
+//! | `classify`  | `query`                                         | `research_type`, `confidence`, `keywords` |
+//! | `summarize` | `text`, `max_sentences` (default 3)             | `summary`, `sentences` |
+//! | `notify`    | `message`, `channel` (`log`, `stdout`, `file`), `path` | `delivered`, `channel` |
+
+use super::definition::StepOperation;
+use super::engine::StepExecutor;
+use super::{WorkflowError, WorkflowResult};
+use async_trait::async_trait;
+use fortitude_core::pipeline::ResearchPipeline;
+use fortitude_core::BasicClassifier;
+use fortitude_types::{ClassificationConfig, Classifier};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::sync::Arc;
+use tracing::info;
+
+const DEFAULT_SUMMARY_SENTENCES: usize = 3;
+
+/// Runs workflow steps against a research pipeline
+pub struct PipelineStepExecutor {
+    pipeline: Arc<ResearchPipeline>,
+    classifier: BasicClassifier,
+}
+
+impl PipelineStepExecutor {
+    pub fn new(pipeline: Arc<ResearchPipeline>) -> Self {
+        Self {
+            pipeline,
+            classifier: BasicClassifier::new(ClassificationConfig::default()),
+        }
+    }
+
+    async fn research(&self, params: &BTreeMap<String, String>) -> WorkflowResult<Value> {
+        let query = required(StepOperation::Research, params, "query")?;
+        let result = self
+            .pipeline
+            .process_query(query, None, None)
+            .await
+            .map_err(|e| step_error(StepOperation::Research, e))?;
+
+        Ok(json!({
+            "answer": result.immediate_answer,
+            "research_type": result.research_type().to_string().to_lowercase(),
+            "quality_score": result.metadata.quality_score,
+            "cache_key": result.metadata.cache_key,
+            "evidence_count": result.supporting_evidence.len(),
+        }))
+    }
+
+    fn classify(&self, params: &BTreeMap<String, String>) -> WorkflowResult<Value> {
+        let query = required(StepOperation::Classify, params, "query")?;
+        let result = self
+            .classifier
+            .classify(query)
+            .map_err(|e| step_error(StepOperation::Classify, e))?;
+
+        Ok(json!({
+            "research_type": result.research_type.to_string().to_lowercase(),
+            "confidence": result.confidence,
+            "keywords": result.matched_keywords,
+        }))
+    }
+
+    fn summarize(params: &BTreeMap<String, String>) -> WorkflowResult<Value> {
+        let text = required(StepOperation::Summarize, params, "text")?;
+        let max_sentences = match params.get("max_sentences") {
+            Some(value) => value.parse::<usize>().map_err(|_| {
+                step_error(
+                    StepOperation::Summarize,
+                    format!("max_sentences must be a positive integer, got '{value}'"),
+                )
+            })?,
+            None => DEFAULT_SUMMARY_SENTENCES,
+        };
+
+        let sentences = leading_sentences(text, max_sentences);
+        Ok(json!({
+            "summary": sentences.join(" "),
+            "sentences": sentences.len(),
+        }))
+    }
+
+    fn notify(params: &BTreeMap<String, String>) -> WorkflowResult<Value> {
+        let message = required(StepOperation::Notify, params, "message")?;
+        let channel = params.get("channel").map(String::as_str).unwrap_or("log");
+
+        match channel {
+            "log" => info!("Workflow notification: {message}"),
+            "stdout" => println!("{message}"),
+            "file" => {
+                let path = required(StepOperation::Notify, params, "path")?;
+                let mut file = std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)?;
+                writeln!(file, "{message}")?;
+            }
+            other => {
+                return Err(step_error(
+                    StepOperation::Notify,
+                    format!("Unknown notification channel '{other}'"),
+                ))
+            }
+        }
+
+        Ok(json!({ "delivered": true, "channel": channel }))
+    }
+}
+
+#[async_trait]
+impl StepExecutor for PipelineStepExecutor {
+    async fn execute(
+        &self,
+        operation: StepOperation,
+        params: &BTreeMap<String, String>,
+    ) -> WorkflowResult<Value> {
+        match operation {
+            StepOperation::Research => self.research(params).await,
+            StepOperation::Classify => self.classify(params),
+            StepOperation::Summarize => Self::summarize(params),
+            StepOperation::Notify => Self::notify(params),
+        }
+    }
+}
+
+fn required<'a>(
+    operation: StepOperation,
+    params: &'a BTreeMap<String, String>,
+    key: &str,
+) -> WorkflowResult<&'a str> {
+    params
+        .get(key)
+        .map(String::as_str)
+        .filter(|value| !value.trim().is_empty())
+        .ok_or_else(|| step_error(operation, format!("missing required parameter '{key}'")))
+}
+
+fn step_error(operation: StepOperation, message: impl std::fmt::Display) -> WorkflowError {
+    WorkflowError::StepFailed {
+        step: operation.to_string(),
+        message: message.to_string(),
+    }
+}
+
+/// Split text into sentences and keep the first `limit`
+fn leading_sentences(text: &str, limit: usize) -> Vec<String> {
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+    for c in text.chars() {
+        current.push(c);
+        if matches!(c, '.' | '!' | '?' | '\n') {
+            let sentence = current.trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence.to_string());
+            }
+            current.clear();
+        }
+    }
+    if !current.trim().is_empty() {
+        sentences.push(current.trim().to_string());
+    }
+    sentences.truncate(limit);
+    sentences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_keeps_leading_sentences() {
+        let params = BTreeMap::from([
+            (
+                "text".to_string(),
+                "Tokio is a runtime. It schedules tasks!\nIt also has timers. Extra".to_string(),
+            ),
+            ("max_sentences".to_string(), "2".to_string()),
+        ]);
+        let output = PipelineStepExecutor::summarize(&params).unwrap();
+        assert_eq!(output["summary"], "Tokio is a runtime. It schedules tasks!");
+        assert_eq!(output["sentences"], 2);
+
+        let invalid = BTreeMap::from([
+            ("text".to_string(), "Text.".to_string()),
+            ("max_sentences".to_string(), "many".to_string()),
+        ]);
+        assert!(PipelineStepExecutor::summarize(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_notify_appends_to_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("notifications.log");
+        let params = BTreeMap::from([
+            ("message".to_string(), "digest ready".to_string()),
+            ("channel".to_string(), "file".to_string()),
+            ("path".to_string(), path.display().to_string()),
+        ]);
+
+        PipelineStepExecutor::notify(&params).unwrap();
+        PipelineStepExecutor::notify(&params).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "digest ready\ndigest ready\n"
+        );
+
+        let missing = BTreeMap::from([("channel".to_string(), "log".to_string())]);
+        assert!(PipelineStepExecutor::notify(&missing).is_err());
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Declarative multi-step research workflows executed as DAGs
+//! # Workflow Module
+//!
+//! Some knowledge tasks need several dependent steps, for example
+//! research → classify → summarize → notify. A workflow describes those steps
+//! in TOML or YAML and the engine runs them in dependency order.
+//!
+//! ## Core Components
+//!
+//! - **Definitions**: Steps, their pipeline operation, parameters and dependencies
+//! - **Templating**: `{{ inputs.topic }}` and `{{ steps.research.answer }}` references
+//! - **Conditional Edges**: `when` expressions that skip a step and its dependents
+//! - **Engine**: Ordered execution with per-step retries
+//! - **Run Store**: JSON persistence of every run for `fortitude workflow status`
+//!
+//! ## Example Definition
+//!
+//! ```toml
+//! name = "research-digest"
+//!
+//! [inputs]
+//! topic = "async rust"
+//!
+//! [[steps]]
+//! id = "classify"
+//! operation = "classify"
+//! params = { query = "{{ inputs.topic }}" }
+//!
+//! [[steps]]
+//! id = "research"
+//! operation = "research"
+//! depends_on = ["classify"]
+//! when = "steps.classify.research_type != \"validation\""
+//! retries = 2
+//! params = { query = "{{ inputs.topic }}" }
+//! ```
+
+use thiserror::Error;
+
+pub mod definition;
+pub mod engine;
+pub mod executor;
+pub mod store;
+pub mod template;
+
+pub use definition::{StepOperation, WorkflowDefinition, WorkflowStep};
+pub use engine::{StepExecutor, WorkflowEngine};
+pub use executor::PipelineStepExecutor;
+pub use store::{RunStatus, StepRun, StepStatus, WorkflowRun, WorkflowRunStore};
+
+/// Result type for workflow operations
+pub type WorkflowResult<T> = Result<T, WorkflowError>;
+
+/// Errors that can occur while loading or running workflows
+#[derive(Debug, Error)]
+pub enum WorkflowError {
+    #[error("Invalid workflow definition: {0}")]
+    InvalidDefinition(String),
+
+    #[error("Failed to parse workflow: {0}")]
+    Parse(String),
+
+    #[error("Template error: {0}")]
+    Template(String),
+
+    #[error("Step '{step}' failed: {message}")]
+    StepFailed { step: String, message: String },
+
+    #[error("Workflow run not found: {0}")]
+    RunNotFound(String),
+
+    #[error("Storage error: {0}")]
+    Storage(String),
+}
+
+impl From<std::io::Error> for WorkflowError {
+    fn from(error: std::io::Error) -> Self {
+        WorkflowError::Storage(error.to_string())
+    }
+}
+
+impl From<serde_json::Error> for WorkflowError {
+    fn from(error: serde_json::Error) -> Self {
+        WorkflowError::Storage(error.to_string())
+    }
+}
@@ -0,0 +1,259 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Workflow run records and their JSON file persistence
+use super::definition::{StepOperation, WorkflowDefinition};
+use super::{WorkflowError, WorkflowResult};
+use chrono::{DateTime, Utc};
+use fortitude_core::write_json_atomic;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Default directory for persisted workflow runs
+pub const DEFAULT_RUNS_DIR: &str = ".fortitude/workflow-runs";
+
+/// Overall state of a workflow run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStatus {
+    Running,
+    Succeeded,
+    Failed,
+}
+
+impl std::fmt::Display for RunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            RunStatus::Running => "running",
+            RunStatus::Succeeded => "succeeded",
+            RunStatus::Failed => "failed",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// State of a single step within a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StepStatus {
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+    Skipped,
+}
+
+impl std::fmt::Display for StepStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            StepStatus::Pending => "pending",
+            StepStatus::Running => "running",
+            StepStatus::Succeeded => "succeeded",
+            StepStatus::Failed => "failed",
+            StepStatus::Skipped => "skipped",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// Execution record for one step
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepRun {
+    pub id: String,
+    pub operation: StepOperation,
+    pub status: StepStatus,
+    pub attempts: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<serde_json::Value>,
+    /// Failure message, or the reason the step was skipped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// Execution record for a whole workflow
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowRun {
+    pub id: String,
+    pub workflow: String,
+    pub status: RunStatus,
+    pub inputs: BTreeMap<String, String>,
+    /// Steps in execution order
+    pub steps: Vec<StepRun>,
+    pub started_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl WorkflowRun {
+    /// Create a run with every step pending
+    pub fn new(
+        definition: &WorkflowDefinition,
+        inputs: BTreeMap<String, String>,
+    ) -> WorkflowResult<Self> {
+        let steps = definition
+            .execution_order()?
+            .into_iter()
+            .map(|step| StepRun {
+                id: step.id.clone(),
+                operation: step.operation,
+                status: StepStatus::Pending,
+                attempts: 0,
+                output: None,
+                error: None,
+                started_at: None,
+                finished_at: None,
+            })
+            .collect();
+
+        Ok(Self {
+            id: Uuid::new_v4().to_string(),
+            workflow: definition.name.clone(),
+            status: RunStatus::Running,
+            inputs,
+            steps,
+            started_at: Utc::now(),
+            finished_at: None,
+        })
+    }
+
+    pub fn step(&self, id: &str) -> Option<&StepRun> {
+        self.steps.iter().find(|step| step.id == id)
+    }
+
+    pub fn step_mut(&mut self, id: &str) -> Option<&mut StepRun> {
+        self.steps.iter_mut().find(|step| step.id == id)
+    }
+}
+
+/// Stores each run as `<run id>.json` in a directory
+#[derive(Debug, Clone)]
+pub struct WorkflowRunStore {
+    directory: PathBuf,
+}
+
+impl WorkflowRunStore {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn run_path(&self, run_id: &str) -> PathBuf {
+        self.directory.join(format!("{run_id}.json"))
+    }
+
+    /// Write the run, replacing any earlier snapshot atomically
+    pub fn save(&self, run: &WorkflowRun) -> WorkflowResult<()> {
+        write_json_atomic(&self.run_path(&run.id), run)?;
+        Ok(())
+    }
+
+    pub fn load(&self, run_id: &str) -> WorkflowResult<WorkflowRun> {
+        let valid_id = !run_id.is_empty()
+            && run_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-');
+        let path = self.run_path(run_id);
+        if !valid_id || !path.exists() {
+            return Err(WorkflowError::RunNotFound(run_id.to_string()));
+        }
+        let content = std::fs::read(&path)?;
+        Ok(serde_json::from_slice(&content)?)
+    }
+
+    /// All stored runs, most recent first
+    pub fn list(&self) -> WorkflowResult<Vec<WorkflowRun>> {
+        if !self.directory.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut runs = Vec::new();
+        for entry in std::fs::read_dir(&self.directory)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            match std::fs::read(&path)
+                .map_err(WorkflowError::from)
+                .and_then(|content| Ok(serde_json::from_slice::<WorkflowRun>(&content)?))
+            {
+                Ok(run) => runs.push(run),
+                Err(e) => {
+                    tracing::warn!("Skipping unreadable workflow run {}: {e}", path.display())
+                }
+            }
+        }
+        runs.sort_by(|a, b| {
+            b.started_at
+                .cmp(&a.started_at)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        Ok(runs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::definition::WorkflowStep;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_save_load_and_list_runs() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = WorkflowRunStore::new(temp_dir.path().join("runs"));
+        assert!(store.list().unwrap().is_empty());
+
+        let definition = WorkflowDefinition::new("digest")
+            .with_step(WorkflowStep::new("research", StepOperation::Research));
+        let mut first = WorkflowRun::new(&definition, BTreeMap::new()).unwrap();
+        first.started_at -= chrono::Duration::minutes(5);
+        store.save(&first).unwrap();
+
+        let mut second = WorkflowRun::new(&definition, BTreeMap::new()).unwrap();
+        second.status = RunStatus::Succeeded;
+        second.step_mut("research").unwrap().status = StepStatus::Succeeded;
+        store.save(&second).unwrap();
+
+        let loaded = store.load(&second.id).unwrap();
+        assert_eq!(loaded, second);
+
+        let ids: Vec<String> = store
+            .list()
+            .unwrap()
+            .into_iter()
+            .map(|run| run.id)
+            .collect();
+        assert_eq!(ids, vec![second.id.clone(), first.id.clone()]);
+
+        assert!(matches!(
+            store.load("../etc/passwd"),
+            Err(WorkflowError::RunNotFound(_))
+        ));
+        assert!(matches!(
+            store.load("missing"),
+            Err(WorkflowError::RunNotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,213 @@
+// Copyright 2025 CE-DPS Project
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// ABOUTME: Parameter templating and condition evaluation for workflow steps
+//! Step parameters may reference workflow inputs and earlier step outputs with
+//! `{{ inputs.name }}` or `{{ steps.<id>.<field> }}`. Conditions on steps use
+//! the same dotted paths and support `==`, `!=` or a bare path that is tested
+//! for truthiness.
+
+use super::{WorkflowError, WorkflowResult};
+use regex::Regex;
+use serde_json::Value;
+use std::sync::OnceLock;
+
+fn placeholder_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"\{\{\s*([^{}]+?)\s*\}\}").unwrap())
+}
+
+fn step_reference_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"\bsteps\.([A-Za-z0-9_-]+)").unwrap())
+}
+
+/// Resolve a dotted path such as `steps.research.answer` against the context
+pub fn lookup<'a>(context: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(context, |value, segment| match value {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+}
+
+/// Render a value the way it should appear inside a parameter string
+pub fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// Replace every `{{ path }}` placeholder in `template`
+pub fn render(template: &str, context: &Value) -> WorkflowResult<String> {
+    let mut missing = None;
+    let rendered = placeholder_pattern().replace_all(template, |caps: &regex::Captures| {
+        let path = &caps[1];
+        match lookup(context, path) {
+            Some(value) => value_to_string(value),
+            None => {
+                missing.get_or_insert_with(|| path.to_string());
+                String::new()
+            }
+        }
+    });
+
+    match missing {
+        Some(path) => Err(WorkflowError::Template(format!(
+            "'{path}' is not defined in '{template}'"
+        ))),
+        None => Ok(rendered.into_owned()),
+    }
+}
+
+/// Ids of the steps referenced by a template or condition
+pub fn step_references(text: &str) -> Vec<String> {
+    step_reference_pattern()
+        .captures_iter(text)
+        .map(|caps| caps[1].to_string())
+        .collect()
+}
+
+/// Evaluate a step condition against the context
+pub fn evaluate_condition(expression: &str, context: &Value) -> WorkflowResult<bool> {
+    let expression = expression.trim();
+    if expression.is_empty() {
+        return Err(WorkflowError::Template("Empty condition".to_string()));
+    }
+
+    match find_operator(expression) {
+        Some((index, operator)) => {
+            let left = operand(expression[..index].trim(), context)?;
+            let right = operand(expression[index + 2..].trim(), context)?;
+            let equal = left == right || value_to_string(&left) == value_to_string(&right);
+            Ok(if operator == "==" { equal } else { !equal })
+        }
+        None => Ok(is_truthy(&operand(expression, context)?)),
+    }
+}
+
+/// Locate the first `==` or `!=` outside a quoted string
+fn find_operator(expression: &str) -> Option<(usize, &'static str)> {
+    let bytes = expression.as_bytes();
+    let mut quote = None;
+    for i in 0..bytes.len() {
+        match (quote, bytes[i]) {
+            (None, b'"' | b'\'') => quote = Some(bytes[i]),
+            (Some(q), c) if c == q => quote = None,
+            (None, b'=' | b'!') if bytes.get(i + 1) == Some(&b'=') => {
+                return Some((i, if bytes[i] == b'=' { "==" } else { "!=" }));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn operand(token: &str, context: &Value) -> WorkflowResult<Value> {
+    if token.is_empty() {
+        return Err(WorkflowError::Template(
+            "Condition is missing an operand".to_string(),
+        ));
+    }
+
+    let quoted = token.len() >= 2
+        && ((token.starts_with('"') && token.ends_with('"'))
+            || (token.starts_with('\'') && token.ends_with('\'')));
+    if quoted {
+        return Ok(Value::String(token[1..token.len() - 1].to_string()));
+    }
+
+    match token {
+        "true" => return Ok(Value::Bool(true)),
+        "false" => return Ok(Value::Bool(false)),
+        "null" => return Ok(Value::Null),
+        _ => {}
+    }
+    if let Ok(number) = token.parse::<f64>() {
+        return Ok(serde_json::json!(number));
+    }
+
+    // Outputs of skipped steps are simply absent
+    Ok(lookup(context, token).cloned().unwrap_or(Value::Null))
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(map) => !map.is_empty(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn context() -> Value {
+        json!({
+            "inputs": { "topic": "async rust" },
+            "steps": {
+                "classify": { "research_type": "implementation", "confidence": 0.8 },
+                "research": { "answer": "Use tokio", "sources": ["docs.rs"] }
+            }
+        })
+    }
+
+    #[test]
+    fn test_render_substitutes_inputs_and_step_outputs() {
+        let rendered = render(
+            "Summarize {{ steps.research.answer }} about {{inputs.topic}} from {{ steps.research.sources.0 }}",
+            &context(),
+        )
+        .unwrap();
+        assert_eq!(
+            rendered,
+            "Summarize Use tokio about async rust from docs.rs"
+        );
+    }
+
+    #[test]
+    fn test_render_reports_missing_reference() {
+        let err = render("{{ steps.notify.delivered }}", &context()).unwrap_err();
+        assert!(err.to_string().contains("steps.notify.delivered"));
+    }
+
+    #[test]
+    fn test_evaluate_condition() {
+        let ctx = context();
+        assert!(
+            evaluate_condition("steps.classify.research_type == \"implementation\"", &ctx).unwrap()
+        );
+        assert!(evaluate_condition("steps.classify.research_type != 'learning'", &ctx).unwrap());
+        assert!(evaluate_condition("steps.classify.confidence == 0.8", &ctx).unwrap());
+        assert!(evaluate_condition("steps.research.answer", &ctx).unwrap());
+        assert!(!evaluate_condition("steps.summary.text", &ctx).unwrap());
+        assert!(evaluate_condition("== 'x'", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_step_references() {
+        assert_eq!(
+            step_references("{{ steps.a.x }} and steps.b-2.y == inputs.z"),
+            vec!["a".to_string(), "b-2".to_string()]
+        );
+    }
+}